@@ -0,0 +1,23 @@
+;; tak with its continuations made into closures, as ctak captures one with
+;; call/cc at every call. There is no call/cc, so they are passed explicitly.
+
+(define (ctak-aux k x y z)
+  (if (< y x)
+      (ctak-aux (lambda (v1)
+                  (ctak-aux (lambda (v2)
+                              (ctak-aux (lambda (v3) (ctak-aux k v1 v2 v3))
+                                        (- z 1) x y))
+                            (- y 1) z x))
+                (- x 1) y z)
+      (k z)))
+
+(define (ctak x y z)
+  (ctak-aux (lambda (v) v) x y z))
+
+(define (repeat n)
+  (if (= n 0)
+      #t
+      (let ((_ (assert (= (ctak 18 12 6) 7))))
+        (repeat (- n 1)))))
+
+(repeat 20)
@@ -0,0 +1,8 @@
+;; Doubly recursive fibonacci, mostly calls and fixnum arithmetic.
+
+(define (fib n)
+  (if (< n 2)
+      n
+      (+ (fib (- n 1)) (fib (- n 2)))))
+
+(assert (= (fib 27) 196418))
@@ -0,0 +1,36 @@
+;; Counts the ways to place n queens on an n by n board, allocating lists.
+
+(define (one-to n)
+  (define (loop i acc)
+    (if (= i 0)
+        acc
+        (loop (- i 1) (cons i acc))))
+  (loop n '()))
+
+(define (append xs ys)
+  (if (null? xs)
+      ys
+      (cons (car xs) (append (cdr xs) ys))))
+
+(define (ok? row dist placed)
+  (if (null? placed)
+      #t
+      (if (= (car placed) (+ row dist))
+          #f
+          (if (= (car placed) (- row dist))
+              #f
+              (ok? row (+ dist 1) (cdr placed))))))
+
+(define (try-it x y z)
+  (if (null? x)
+      (if (null? y) 1 0)
+      (+ (if (ok? (car x) 1 z)
+             (try-it (append (cdr x) y) '() (cons (car x) z))
+             0)
+         (try-it (cdr x) (cons (car x) y) z))))
+
+(define (queens n)
+  (try-it (one-to n) '() '()))
+
+(assert (= (queens 8) 92))
+(assert (= (queens 9) 352))
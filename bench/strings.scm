@@ -0,0 +1,36 @@
+;; Builds strings with string-append and takes them apart again with
+;; substring and string-ref.
+
+(define (digits n acc)
+  (if (= n 0)
+      acc
+      (digits (- n 1) (string-append acc (number->string n)))))
+
+;; how many times the character c is in s
+(define (count-char c s)
+  (define (loop i acc)
+    (if (= i (string-length s))
+        acc
+        (loop (+ i 1) (if (= (string-ref s i) c) (+ acc 1) acc))))
+  (loop 0 0))
+
+;; the string in pieces of n characters, put back together backwards
+(define (reverse-chunks s n)
+  (define (loop i acc)
+    (if (< (- (string-length s) i) n)
+        (string-append (substring s i (string-length s)) acc)
+        (loop (+ i n) (string-append (substring s i (+ i n)) acc))))
+  (loop 0 ""))
+
+(define (repeat n)
+  (if (= n 0)
+      #t
+      (let ((s (digits 1000 "")))
+        (assert (= (string-length s) 2893))
+        (assert (= (count-char 49 s) 301))
+        (let ((r (reverse-chunks s 7)))
+          (assert (= (string-length r) 2893))
+          (assert (string=? (substring r 0 7) "2198765")))
+        (repeat (- n 1)))))
+
+(repeat 20)
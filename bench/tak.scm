@@ -0,0 +1,16 @@
+;; Takeuchi's function, many non-tail calls on small integers.
+
+(define (tak x y z)
+  (if (< y x)
+      (tak (tak (- x 1) y z)
+           (tak (- y 1) z x)
+           (tak (- z 1) x y))
+      z))
+
+(define (repeat n)
+  (if (= n 0)
+      #t
+      (let ((_ (assert (= (tak 18 12 6) 7))))
+        (repeat (- n 1)))))
+
+(repeat 50)
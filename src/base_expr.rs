@@ -1,14 +1,14 @@
-use moniker::Binder;
-use moniker::{FreeVar, Ignore, Scope, Var};
+use failure::{format_err, Error};
+use moniker::{FreeVar, Ignore, Var};
 use pretty::{BoxAllocator, DocAllocator, DocBuilder};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use termcolor::{Color, ColorSpec, WriteColor};
 
-use std::{io::Result, rc::Rc};
+use std::{cell::Cell, io, rc::Rc};
 
-use crate::expr::Expr;
+use crate::expr::{Atom, Expr};
 use crate::literals::Literal;
-use crate::utils::clone_rc;
+use crate::utils::{clone_rc, open_scope};
 
 // TODO: Type Families magick?
 
@@ -20,10 +20,22 @@ pub enum BExpr {
     If(Rc<BExpr>, Rc<BExpr>, Rc<BExpr>),
     Set(String, Rc<BExpr>),
     Let(Vec<(String, BExpr)>, BExprBody),
-    Lam(Vec<String>, BExprBody),
+    /// the line of the source the lambda is written on, if it was
+    Lam(Vec<String>, BExprBody, Option<usize>),
+    /// the clauses, each a `Lam`, of which a call runs the first taking as
+    /// many arguments as it is given
+    CaseLam(Vec<BExpr>),
     App(Rc<BExpr>, Vec<BExpr>),
 }
 
+/// The name of a variable made by the compiler rather than written in the
+/// program. Once read, no name in the source has a bar, so these, which start
+/// with one, can't capture the program's variables or be captured by them.
+/// `name` mustn't start with a bar itself.
+pub(crate) fn generated_name(name: &str) -> String {
+    format!("|{}", name)
+}
+
 #[derive(Debug, Clone)]
 pub enum BExprBodyExpr {
     Def(String, BExpr),
@@ -87,7 +99,7 @@ impl BExpr {
                     .group()
                     .parens()
             }
-            BExpr::Lam(pat, body) => {
+            BExpr::Lam(pat, body, _) => {
                 let pat_pret = allocator
                     .intersperse(
                         pat.iter().map(|p| {
@@ -110,6 +122,17 @@ impl BExpr {
                     .group()
                     .parens()
             }
+            BExpr::CaseLam(clauses) => allocator
+                .text("case-lambda")
+                .annotate(ColorSpec::new().set_fg(Some(Color::Magenta)).clone())
+                .append(allocator.line())
+                .append(allocator.intersperse(
+                    clauses.iter().map(|c| c.pretty(allocator)),
+                    allocator.line(),
+                ))
+                .nest(1)
+                .group()
+                .parens(),
             BExpr::If(c, ift, iff) => {
                 let c_pret = c.pretty(allocator);
                 let ift_pret = ift.pretty(allocator);
@@ -141,7 +164,7 @@ impl BExpr {
         }
     }
 
-    pub fn pretty_print(&self, out: impl WriteColor) -> Result<()> {
+    pub fn pretty_print(&self, out: impl WriteColor) -> io::Result<()> {
         let allocator = BoxAllocator;
 
         self.pretty(&allocator).1.render_colored(70, out)?;
@@ -166,7 +189,10 @@ impl BExpr {
 
                 BExpr::Let(new_bindings, body.rewrite(f))
             }
-            BExpr::Lam(n, body) => BExpr::Lam(n, body.rewrite(f)),
+            BExpr::Lam(n, body, line) => BExpr::Lam(n, body.rewrite(f), line),
+            BExpr::CaseLam(clauses) => {
+                BExpr::CaseLam(clauses.into_iter().map(|c| c.rewrite(f)).collect())
+            }
             BExpr::App(r, es) => {
                 let new_es = es.into_iter().map(|e| e.rewrite(f)).collect();
 
@@ -183,7 +209,7 @@ impl BExpr {
                 BExpr::Let(b, e) => {
                     let (names, params) = b.into_iter().unzip();
 
-                    let lam = Rc::new(BExpr::Lam(names, e));
+                    let lam = Rc::new(BExpr::Lam(names, e, None));
                     BExpr::App(lam, params)
                 }
                 _ => e,
@@ -197,7 +223,7 @@ impl BExpr {
         fn t(e: BExpr) -> BExpr {
             match e {
                 BExpr::Let(e, body) => BExpr::Let(e, body.pull_defines()),
-                BExpr::Lam(e, body) => BExpr::Lam(e, body.pull_defines()),
+                BExpr::Lam(e, body, line) => BExpr::Lam(e, body.pull_defines(), line),
                 _ => e,
             }
         }
@@ -205,46 +231,351 @@ impl BExpr {
         self.rewrite(&t)
     }
 
-    pub fn into_expr(self) -> Expr {
-        let env = HashMap::new();
-        self.lift_defines().remove_let().into_expr_inner(&env)
+    /// Rename the free variables that `names` maps, variables bound inside
+    /// the expression shadow the renaming.
+    pub fn rename_free(self, names: &HashMap<String, String>) -> BExpr {
+        let rename = |n: String| names.get(&n).cloned().unwrap_or(n);
+
+        match self {
+            BExpr::Var(n) => BExpr::Var(rename(n)),
+            BExpr::Lit(_) | BExpr::BuiltinIdent(_) => self,
+            BExpr::Set(n, e) => BExpr::Set(rename(n), Rc::new(clone_rc(e).rename_free(names))),
+            BExpr::If(c, ift, iff) => BExpr::If(
+                Rc::new(clone_rc(c).rename_free(names)),
+                Rc::new(clone_rc(ift).rename_free(names)),
+                Rc::new(clone_rc(iff).rename_free(names)),
+            ),
+            BExpr::Let(bindings, body) => {
+                let inner = shadowed(names, bindings.iter().map(|(n, _)| n));
+                let bindings = bindings
+                    .into_iter()
+                    .map(|(n, e)| (n, e.rename_free(names)))
+                    .collect();
+
+                BExpr::Let(bindings, body.rename_free(&inner))
+            }
+            BExpr::Lam(params, body, line) => {
+                let inner = shadowed(names, params.iter());
+                BExpr::Lam(params, body.rename_free(&inner), line)
+            }
+            BExpr::CaseLam(clauses) => {
+                BExpr::CaseLam(clauses.into_iter().map(|c| c.rename_free(names)).collect())
+            }
+            BExpr::App(f, args) => BExpr::App(
+                Rc::new(clone_rc(f).rename_free(names)),
+                args.into_iter().map(|e| e.rename_free(names)).collect(),
+            ),
+        }
     }
 
-    fn into_expr_inner(self, env: &HashMap<String, FreeVar<String>>) -> Expr {
+    /// Follow each `set!` of a variable of `names` that isn't shadowed with
+    /// what `after` gives for the variable, the `set!` still giving void.
+    pub fn follow_sets(self, names: &HashSet<String>, after: &dyn Fn(&str) -> BExpr) -> BExpr {
         match self {
-            BExpr::Var(n) => Expr::Var(Var::Free(
-                env.get(&n)
-                    .unwrap_or_else(|| panic!("unbound arg: {}", n))
-                    .clone(),
-            )),
-            BExpr::Lit(l) => Expr::Lit(Ignore(l)),
-            BExpr::BuiltinIdent(l) => Expr::BuiltinIdent(Ignore(l)),
+            BExpr::Var(_) | BExpr::Lit(_) | BExpr::BuiltinIdent(_) => self,
+            BExpr::Set(n, e) => {
+                let set = BExpr::Set(n.clone(), Rc::new(clone_rc(e).follow_sets(names, after)));
+
+                if !names.contains(&n) {
+                    return set;
+                }
+
+                BExpr::Let(
+                    Vec::new(),
+                    BExprBody(vec![BExprBodyExpr::Expr(set)], Rc::new(after(&n))),
+                )
+            }
+            BExpr::If(c, ift, iff) => BExpr::If(
+                Rc::new(clone_rc(c).follow_sets(names, after)),
+                Rc::new(clone_rc(ift).follow_sets(names, after)),
+                Rc::new(clone_rc(iff).follow_sets(names, after)),
+            ),
+            BExpr::Let(bindings, body) => {
+                let inner = unshadowed(names, bindings.iter().map(|(n, _)| n));
+                let bindings = bindings
+                    .into_iter()
+                    .map(|(n, e)| (n, e.follow_sets(names, after)))
+                    .collect();
+
+                BExpr::Let(bindings, body.follow_sets(&inner, after))
+            }
+            BExpr::Lam(params, body, line) => {
+                let inner = unshadowed(names, params.iter());
+                BExpr::Lam(params, body.follow_sets(&inner, after), line)
+            }
+            BExpr::CaseLam(clauses) => BExpr::CaseLam(
+                clauses
+                    .into_iter()
+                    .map(|c| c.follow_sets(names, after))
+                    .collect(),
+            ),
+            BExpr::App(f, args) => BExpr::App(
+                Rc::new(clone_rc(f).follow_sets(names, after)),
+                args.into_iter()
+                    .map(|e| e.follow_sets(names, after))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Bind each case-lambda that a define or let binds to a variable for
+    /// each of its clauses, and call the clause taking as many arguments as a
+    /// call of it gives. Calls are curried when binding, so how many arguments
+    /// there are is only known here.
+    ///
+    /// A case-lambda that is used other than by calling it is made into a
+    /// procedure holding its clauses, and then every call of a procedure not
+    /// known here picks the clause of what it calls at runtime.
+    fn resolve_case_lambdas(self, cases: &CaseLambdas, mode: &CaseMode) -> Result<BExpr, Error> {
+        let expr = match self {
+            BExpr::Var(n) if cases.contains_key(&n) && !mode.first_class => {
+                mode.passed.set(true);
+                BExpr::Var(n)
+            }
+            BExpr::Var(_) | BExpr::Lit(_) | BExpr::BuiltinIdent(_) => self,
+            BExpr::Set(n, _) if cases.contains_key(&n) => {
+                return Err(format_err!(
+                    "can't set! {}, it is bound to a case-lambda",
+                    n
+                ))
+            }
+            BExpr::Set(n, e) => {
+                BExpr::Set(n, Rc::new(clone_rc(e).resolve_case_lambdas(cases, mode)?))
+            }
+            BExpr::If(c, ift, iff) => BExpr::If(
+                Rc::new(clone_rc(c).resolve_case_lambdas(cases, mode)?),
+                Rc::new(clone_rc(ift).resolve_case_lambdas(cases, mode)?),
+                Rc::new(clone_rc(iff).resolve_case_lambdas(cases, mode)?),
+            ),
+            BExpr::Let(bindings, body) => {
+                let mut inner = cases.clone();
+                let mut new_bindings = Vec::new();
+                let mut procedures = Vec::new();
+
+                for (n, e) in bindings {
+                    inner.remove(&n);
+
+                    match e {
+                        BExpr::CaseLam(clauses) => {
+                            let vars = clause_vars(&n, &clauses);
+                            new_bindings.extend(case_clauses(&n, clauses, cases, mode)?);
+
+                            if mode.first_class {
+                                procedures.push((n.clone(), case_lambda_of(&vars)));
+                            }
+                            inner.insert(n, vars);
+                        }
+                        e => new_bindings.push((n, e.resolve_case_lambdas(cases, mode)?)),
+                    }
+                }
+
+                let body = body.resolve_case_lambdas(&inner, mode)?;
+
+                // the procedures are made from the clauses, so are bound after
+                // them
+                let body = match procedures.is_empty() {
+                    true => body,
+                    false => BExprBody(Vec::new(), Rc::new(BExpr::Let(procedures, body))),
+                };
+
+                BExpr::Let(new_bindings, body)
+            }
+            BExpr::Lam(params, body, line) => {
+                let mut inner = cases.clone();
+                for p in &params {
+                    inner.remove(p);
+                }
+
+                BExpr::Lam(params, body.resolve_case_lambdas(&inner, mode)?, line)
+            }
+            BExpr::CaseLam(clauses) => {
+                mode.passed.set(true);
+
+                let clauses = clauses
+                    .into_iter()
+                    .map(|c| Ok((clause_arity(&c), c.resolve_case_lambdas(cases, mode)?)))
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                make_case_lambda(clauses)
+            }
+            BExpr::App(f, args) => {
+                let f = match &*f {
+                    BExpr::Var(n) if cases.contains_key(n) => {
+                        let clauses = &cases[n];
+                        let i = clause_for(Some(n), clauses.iter().map(|c| c.0), args.len())?;
+                        BExpr::Var(clauses[i].1.clone())
+                    }
+                    BExpr::CaseLam(clauses) => {
+                        let i = clause_for(None, clauses.iter().map(clause_arity), args.len())?;
+                        clauses[i].clone().resolve_case_lambdas(cases, mode)?
+                    }
+                    _ => {
+                        let f = clone_rc(f).resolve_case_lambdas(cases, mode)?;
+
+                        match f {
+                            BExpr::Lam(..) | BExpr::BuiltinIdent(_) => f,
+                            f if mode.first_class => BExpr::App(
+                                Rc::new(BExpr::BuiltinIdent("case-lambda-clause".to_owned())),
+                                vec![f, BExpr::Lit(Literal::Int(args.len() as i64))],
+                            ),
+                            f => f,
+                        }
+                    }
+                };
+
+                let args = args
+                    .into_iter()
+                    .map(|e| e.resolve_case_lambdas(cases, mode))
+                    .collect::<Result<_, _>>()?;
+
+                BExpr::App(Rc::new(f), args)
+            }
+        };
+
+        Ok(expr)
+    }
+
+    /// Check that no body reads a variable it defines before the define has
+    /// run, defines being `letrec*` and the variable void until then.
+    fn check_define_order(&self) -> Result<(), Error> {
+        match self {
+            BExpr::Var(_) | BExpr::Lit(_) | BExpr::BuiltinIdent(_) => Ok(()),
+            BExpr::Set(_, e) => e.check_define_order(),
+            BExpr::If(c, ift, iff) => {
+                c.check_define_order()?;
+                ift.check_define_order()?;
+                iff.check_define_order()
+            }
+            BExpr::Let(bindings, body) => {
+                for (_, e) in bindings {
+                    e.check_define_order()?;
+                }
+                body.check_define_order()
+            }
+            BExpr::Lam(_, body, _) => body.check_define_order(),
+            BExpr::CaseLam(clauses) => clauses.iter().try_for_each(BExpr::check_define_order),
+            BExpr::App(f, args) => {
+                f.check_define_order()?;
+                args.iter().try_for_each(BExpr::check_define_order)
+            }
+        }
+    }
+
+    /// The first of `names` that evaluating the expression is sure to read,
+    /// the lambdas it makes might only be called once they are defined and
+    /// only the condition of an `if` is always evaluated.
+    fn reads_now(&self, names: &HashSet<String>) -> Option<String> {
+        match self {
+            BExpr::Var(n) => Some(n.clone()).filter(|n| names.contains(n)),
+            BExpr::Lit(_) | BExpr::BuiltinIdent(_) | BExpr::Lam(..) | BExpr::CaseLam(_) => None,
+            BExpr::Set(_, e) => e.reads_now(names),
+            BExpr::If(c, _, _) => c.reads_now(names),
+            BExpr::Let(bindings, body) => bindings
+                .iter()
+                .find_map(|(_, e)| e.reads_now(names))
+                .or_else(|| {
+                    let mut inner = names.clone();
+                    for (n, _) in bindings {
+                        inner.remove(n);
+                    }
+                    body.reads_now(inner)
+                }),
+            BExpr::App(f, args) => {
+                let called = match &**f {
+                    BExpr::Lam(params, body, _) => {
+                        let mut inner = names.clone();
+                        for p in params {
+                            inner.remove(p);
+                        }
+                        body.reads_now(inner)
+                    }
+                    f => f.reads_now(names),
+                };
+
+                called.or_else(|| args.iter().find_map(|e| e.reads_now(names)))
+            }
+        }
+    }
+
+    pub fn into_expr(self) -> Result<Expr, Error> {
+        self.into_expr_with_env(&HashMap::new())
+    }
+
+    /// Bind the expression where `env` gives the variables already in scope,
+    /// this lets the repl carry definitions over between inputs.
+    pub fn into_expr_with_env(self, env: &HashMap<String, FreeVar<String>>) -> Result<Expr, Error> {
+        self.check_define_order()?;
+
+        let mode = CaseMode::default();
+        let mut resolved = self.clone().resolve_case_lambdas(&HashMap::new(), &mode)?;
+
+        if mode.passed.get() {
+            let mode = CaseMode {
+                first_class: true,
+                ..mode
+            };
+            resolved = self.resolve_case_lambdas(&HashMap::new(), &mode)?;
+        }
+
+        // the scopes are made open and closed together at the end
+        Ok(resolved
+            .lift_defines()
+            .remove_let()
+            .into_expr_inner(&Bindings::Top(env))?
+            .close())
+    }
+
+    fn into_expr_inner(self, env: &Bindings) -> Result<Expr, Error> {
+        let lookup = |n: &String| {
+            env.get(n)
+                .cloned()
+                .ok_or_else(|| format_err!("unbound variable: {}", n))
+        };
+
+        let expr = match self {
+            BExpr::Var(n) => Expr::Atom(Atom::Var(Var::Free(lookup(&n)?))),
+            BExpr::Lit(l) => Expr::Atom(Atom::Lit(Ignore(l))),
+            BExpr::BuiltinIdent(l) => Expr::Atom(Atom::BuiltinIdent(Ignore(l))),
             BExpr::Set(n, e) => Expr::Set(
-                Var::Free(
-                    env.get(&n)
-                        .unwrap_or_else(|| panic!("unbound arg: {}", n))
-                        .clone(),
-                ),
-                Rc::new(clone_rc(e).into_expr_inner(env)),
+                Var::Free(lookup(&n)?),
+                Rc::new(clone_rc(e).into_expr_inner(env)?),
             ),
-            BExpr::Lam(params, body) => {
-                let mut env = env.clone();
-                env.extend(params.iter().map(|n| (n.clone(), FreeVar::fresh_named(n))));
+            BExpr::Lam(params, body, line) => {
+                // lets are lambdas by now, so this covers both binding lists
+                if let Some(dup) = params
+                    .iter()
+                    .enumerate()
+                    .find(|(i, p)| params[..*i].contains(p))
+                    .map(|(_, p)| p)
+                {
+                    return Err(format_err!("duplicate variable in binding list: {}", dup));
+                }
+
+                let env = Bindings::Frame(
+                    params
+                        .iter()
+                        .map(|n| (n.clone(), FreeVar::fresh_named(n)))
+                        .collect(),
+                    env,
+                );
                 let body = body.as_expressions();
 
                 let body = match body.as_slice() {
-                    [] => Expr::Lit(Ignore(Literal::Void)),
+                    [] => Expr::Atom(Atom::Lit(Ignore(Literal::Void))),
                     [first, rest @ ..] => {
                         rest.iter()
-                            .fold(first.clone().into_expr_inner(&env), |acc, e| {
-                                Expr::App(
-                                    Rc::new(Expr::Lam(Scope::new(
-                                        Binder(FreeVar::fresh_named("_unused")),
-                                        Rc::new(e.clone().into_expr_inner(&env)),
+                            .try_fold(first.clone().into_expr_inner(&env)?, |acc, e| {
+                                Ok::<_, Error>(Expr::App(
+                                    Rc::new(Expr::Atom(Atom::Lam(
+                                        open_scope(
+                                            FreeVar::fresh_named("_unused"),
+                                            Rc::new(e.clone().into_expr_inner(&env)?),
+                                        ),
+                                        Ignore(None),
                                     ))),
                                     Rc::new(acc),
-                                )
-                            })
+                                ))
+                            })?
                     }
                 };
 
@@ -252,43 +583,167 @@ impl BExpr {
                     [] => {
                         // for zero param functions, we turn it into a 1-param function
                         // and then on calls with zero parameters, we add in a parameter of null
-                        Expr::Lam(Scope::new(
-                            Binder(FreeVar::fresh_named("_unused")),
-                            Rc::new(body),
+                        Expr::Atom(Atom::Lam(
+                            open_scope(FreeVar::fresh_named("_unused"), Rc::new(body)),
+                            Ignore(line),
                         ))
                     }
                     [rest @ .., last] => {
-                        let last = Expr::Lam(Scope::new(
-                            Binder(env.get(last).unwrap().clone()),
-                            Rc::new(body),
+                        let last = Expr::Atom(Atom::Lam(
+                            open_scope(env.get(last).unwrap().clone(), Rc::new(body)),
+                            Ignore(line),
                         ));
                         rest.iter().rev().fold(last, |acc, p| {
-                            Expr::Lam(Scope::new(
-                                Binder(env.get(p).unwrap().clone()),
-                                Rc::new(acc),
+                            Expr::Atom(Atom::Lam(
+                                open_scope(env.get(p).unwrap().clone(), Rc::new(acc)),
+                                Ignore(line),
                             ))
                         })
                     }
                 }
             }
             BExpr::If(c, ift, iff) => {
-                let c = clone_rc(c).into_expr_inner(env);
-                let ift = clone_rc(ift).into_expr_inner(env);
-                let iff = clone_rc(iff).into_expr_inner(env);
+                let c = clone_rc(c).into_expr_inner(env)?;
+                let ift = clone_rc(ift).into_expr_inner(env)?;
+                let iff = clone_rc(iff).into_expr_inner(env)?;
 
                 Expr::If(Rc::new(c), Rc::new(ift), Rc::new(iff))
             }
             BExpr::App(expr, params) => {
-                let expr = clone_rc(expr).into_expr_inner(env);
+                let expr = clone_rc(expr).into_expr_inner(env)?;
 
                 match params.as_slice() {
-                    [] => Expr::App(Rc::new(expr), Rc::new(Expr::Lit(Ignore(Literal::Void)))),
-                    args => args.iter().fold(expr, |acc, p| {
-                        Expr::App(Rc::new(acc), Rc::new(p.clone().into_expr_inner(env)))
-                    }),
+                    [] => Expr::App(
+                        Rc::new(expr),
+                        Rc::new(Expr::Atom(Atom::Lit(Ignore(Literal::Void)))),
+                    ),
+                    args => args.iter().try_fold(expr, |acc, p| {
+                        Ok::<_, Error>(Expr::App(
+                            Rc::new(acc),
+                            Rc::new(p.clone().into_expr_inner(env)?),
+                        ))
+                    })?,
                 }
             }
-            BExpr::Let(_, _) => unreachable!(),
+            BExpr::Let(_, _) | BExpr::CaseLam(_) => unreachable!(),
+        };
+
+        Ok(expr)
+    }
+}
+
+/// The case-lambdas in scope, with the number of parameters of each of their
+/// clauses and the variable the clause is bound to.
+type CaseLambdas = HashMap<String, Vec<(usize, String)>>;
+
+/// Whether case-lambdas are made into procedures. Only calling them is
+/// cheaper, so a program is resolved that way first, and again making them
+/// procedures if it turned out to pass one around.
+#[derive(Default)]
+struct CaseMode {
+    first_class: bool,
+    passed: Cell<bool>,
+}
+
+/// The procedure of a case-lambda, holding each clause with its arity.
+fn make_case_lambda(clauses: Vec<(usize, BExpr)>) -> BExpr {
+    let cons = |a: BExpr, b: BExpr| {
+        BExpr::App(Rc::new(BExpr::BuiltinIdent("cons".to_owned())), vec![a, b])
+    };
+    let list = clauses
+        .into_iter()
+        .rev()
+        .fold(BExpr::Lit(Literal::Void), |l, (arity, c)| {
+            cons(cons(BExpr::Lit(Literal::Int(arity as i64)), c), l)
+        });
+
+    BExpr::App(
+        Rc::new(BExpr::BuiltinIdent("make-case-lambda".to_owned())),
+        vec![list],
+    )
+}
+
+/// The procedure of a case-lambda whose clauses are bound to `vars`.
+fn case_lambda_of(vars: &[(usize, String)]) -> BExpr {
+    make_case_lambda(
+        vars.iter()
+            .map(|(arity, var)| (*arity, BExpr::Var(var.clone())))
+            .collect(),
+    )
+}
+
+fn clause_arity(clause: &BExpr) -> usize {
+    match clause {
+        BExpr::Lam(params, _, _) => params.len(),
+        _ => unreachable!("case-lambda clauses are lambdas"),
+    }
+}
+
+/// The clauses of the case-lambda bound to `name`, with the variables they
+/// are bound to.
+fn clause_vars(name: &str, clauses: &[BExpr]) -> Vec<(usize, String)> {
+    clauses
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (clause_arity(c), generated_name(&format!("{} {}", name, i))))
+        .collect()
+}
+
+/// The clauses of the case-lambda bound to `name` as bindings of their
+/// variables.
+fn case_clauses(
+    name: &str,
+    clauses: Vec<BExpr>,
+    cases: &CaseLambdas,
+    mode: &CaseMode,
+) -> Result<Vec<(String, BExpr)>, Error> {
+    clause_vars(name, &clauses)
+        .into_iter()
+        .zip(clauses)
+        .map(|((_, var), c)| Ok((var, c.resolve_case_lambdas(cases, mode)?)))
+        .collect()
+}
+
+/// Which clause of a case-lambda, if it is bound to `name`, a call with
+/// `args` arguments runs.
+fn clause_for(
+    name: Option<&str>,
+    mut arities: impl Iterator<Item = usize>,
+    args: usize,
+) -> Result<usize, Error> {
+    arities.position(|a| a == args).ok_or_else(|| {
+        format_err!(
+            "no clause of {} takes {} argument{}",
+            name.map_or("the case-lambda".to_owned(), |n| format!(
+                "case-lambda {}",
+                n
+            )),
+            args,
+            if args == 1 { "" } else { "s" }
+        )
+    })
+}
+
+/// The variables in scope while binding. A lambda puts a frame of its
+/// parameters in front of the frames around it rather than copying them, so
+/// entering a lambda doesn't cost more the more variables are in scope.
+enum Bindings<'a> {
+    Top(&'a HashMap<String, FreeVar<String>>),
+    Frame(HashMap<String, FreeVar<String>>, &'a Bindings<'a>),
+}
+
+impl Bindings<'_> {
+    fn get(&self, name: &str) -> Option<&FreeVar<String>> {
+        let mut bindings = self;
+
+        loop {
+            match bindings {
+                Bindings::Top(env) => return env.get(name),
+                Bindings::Frame(frame, parent) => match frame.get(name) {
+                    Some(var) => return Some(var),
+                    None => bindings = parent,
+                },
+            }
         }
     }
 }
@@ -329,6 +784,30 @@ impl BExprBodyExpr {
     }
 }
 
+/// `names` without the renamings of the variables in `bound`.
+fn shadowed<'a>(
+    names: &HashMap<String, String>,
+    bound: impl Iterator<Item = &'a String>,
+) -> HashMap<String, String> {
+    let mut names = names.clone();
+    for name in bound {
+        names.remove(name);
+    }
+    names
+}
+
+/// `names` without the variables in `bound`.
+fn unshadowed<'a>(
+    names: &HashSet<String>,
+    bound: impl Iterator<Item = &'a String>,
+) -> HashSet<String> {
+    let mut names = names.clone();
+    for name in bound {
+        names.remove(name);
+    }
+    names
+}
+
 impl BExprBody {
     pub fn pretty<'a, D>(&self, allocator: &'a D) -> DocBuilder<'a, D, ColorSpec>
     where
@@ -351,7 +830,61 @@ impl BExprBody {
         )
     }
 
+    /// Rename the free variables that `names` maps, the defines of the body
+    /// shadow the renaming in all of it.
+    pub fn rename_free(self, names: &HashMap<String, String>) -> BExprBody {
+        let names = shadowed(
+            names,
+            self.0.iter().filter_map(|e| match e {
+                BExprBodyExpr::Def(n, _) => Some(n),
+                BExprBodyExpr::Expr(_) => None,
+            }),
+        );
+
+        let body = self
+            .0
+            .into_iter()
+            .map(|e| match e {
+                BExprBodyExpr::Def(n, e) => BExprBodyExpr::Def(n, e.rename_free(&names)),
+                BExprBodyExpr::Expr(e) => BExprBodyExpr::Expr(e.rename_free(&names)),
+            })
+            .collect();
+
+        BExprBody(body, Rc::new(clone_rc(self.1).rename_free(&names)))
+    }
+
+    /// `BExpr::follow_sets` for each expression of the body, which its
+    /// defines shadow in all of it.
+    pub fn follow_sets(self, names: &HashSet<String>, after: &dyn Fn(&str) -> BExpr) -> BExprBody {
+        let names = unshadowed(names, self.define_names());
+
+        let body = self
+            .0
+            .into_iter()
+            .map(|e| match e {
+                BExprBodyExpr::Def(n, e) => BExprBodyExpr::Def(n, e.follow_sets(&names, after)),
+                BExprBodyExpr::Expr(e) => BExprBodyExpr::Expr(e.follow_sets(&names, after)),
+            })
+            .collect();
+
+        BExprBody(body, Rc::new(clone_rc(self.1).follow_sets(&names, after)))
+    }
+
     pub fn pull_defines(self) -> BExprBody {
+        let (defines, body) = self.defines_to_sets();
+
+        let let_bindings = defines
+            .into_iter()
+            .map(|n| (n, BExpr::Lit(Literal::Void)))
+            .collect();
+        let let_ = BExpr::Let(let_bindings, body);
+
+        BExprBody(Vec::new(), Rc::new(let_))
+    }
+
+    /// Turn the defines of the body into assignments, giving the names they
+    /// define. Separately compiled files keep these as globals.
+    pub fn defines_to_sets(self) -> (Vec<String>, BExprBody) {
         let mut defines = Vec::new();
 
         let body = self
@@ -359,20 +892,106 @@ impl BExprBody {
             .into_iter()
             .map(|e| match e {
                 BExprBodyExpr::Def(n, e) => {
-                    defines.push(n.clone());
+                    // a repeated define of the same name is just another assignment
+                    if !defines.contains(&n) {
+                        defines.push(n.clone());
+                    }
                     BExprBodyExpr::Expr(BExpr::Set(n, Rc::new(e)))
                 }
                 BExprBodyExpr::Expr(_) => e,
             })
             .collect();
 
-        let let_bindings = defines
-            .into_iter()
-            .map(|n| (n, BExpr::Lit(Literal::Void)))
-            .collect();
-        let let_ = BExpr::Let(let_bindings, BExprBody(body, self.1));
+        (defines, BExprBody(body, self.1))
+    }
 
-        BExprBody(Vec::new(), Rc::new(let_))
+    fn resolve_case_lambdas(
+        self,
+        cases: &CaseLambdas,
+        mode: &CaseMode,
+    ) -> Result<BExprBody, Error> {
+        // a define is in scope in all of the body
+        let mut inner = cases.clone();
+        for e in &self.0 {
+            if let BExprBodyExpr::Def(n, e) = e {
+                inner.remove(n);
+
+                if let BExpr::CaseLam(clauses) = e {
+                    inner.insert(n.clone(), clause_vars(n, clauses));
+                }
+            }
+        }
+
+        let mut body = Vec::new();
+
+        for e in self.0 {
+            match e {
+                BExprBodyExpr::Def(n, BExpr::CaseLam(clauses)) => {
+                    let clauses = case_clauses(&n, clauses, &inner, mode)?;
+                    body.extend(clauses.into_iter().map(|(n, c)| BExprBodyExpr::Def(n, c)));
+
+                    if mode.first_class {
+                        body.push(BExprBodyExpr::Def(n.clone(), case_lambda_of(&inner[&n])));
+                    }
+                }
+                BExprBodyExpr::Def(n, e) => {
+                    body.push(BExprBodyExpr::Def(n, e.resolve_case_lambdas(&inner, mode)?))
+                }
+                BExprBodyExpr::Expr(e) => {
+                    body.push(BExprBodyExpr::Expr(e.resolve_case_lambdas(&inner, mode)?))
+                }
+            }
+        }
+
+        Ok(BExprBody(
+            body,
+            Rc::new(clone_rc(self.1).resolve_case_lambdas(&inner, mode)?),
+        ))
+    }
+
+    fn define_names(&self) -> impl Iterator<Item = &String> {
+        self.0.iter().filter_map(|e| match e {
+            BExprBodyExpr::Def(n, _) => Some(n),
+            BExprBodyExpr::Expr(_) => None,
+        })
+    }
+
+    fn check_define_order(&self) -> Result<(), Error> {
+        let mut undefined = self.define_names().cloned().collect::<HashSet<_>>();
+
+        for e in &self.0 {
+            let (defined, e) = match e {
+                BExprBodyExpr::Def(n, e) => (Some(n), e),
+                BExprBodyExpr::Expr(e) => (None, e),
+            };
+
+            if let Some(n) = e.reads_now(&undefined) {
+                return Err(format_err!("{} is used before it is defined", n));
+            }
+
+            if let Some(n) = defined {
+                undefined.remove(n);
+            }
+
+            e.check_define_order()?;
+        }
+
+        self.1.check_define_order()
+    }
+
+    /// What the body is sure to read of `names` that it doesn't define.
+    fn reads_now(&self, mut names: HashSet<String>) -> Option<String> {
+        for n in self.define_names() {
+            names.remove(n);
+        }
+
+        self.0
+            .iter()
+            .map(|e| match e {
+                BExprBodyExpr::Def(_, e) | BExprBodyExpr::Expr(e) => e,
+            })
+            .chain(Some(&*self.1))
+            .find_map(|e| e.reads_now(&names))
     }
 
     pub fn as_expressions(self) -> Vec<BExpr> {
@@ -0,0 +1,74 @@
+//! The results of `bench`, which compiles each benchmark at several `-O`
+//! levels and runs it. Each level of a benchmark is compared against the
+//! first level it was built at, usually `-O0`.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub benchmark: String,
+    pub opt_level: u8,
+    pub compile_time: Duration,
+    /// The size of the binary in bytes
+    pub size: u64,
+    /// The fastest of the times it was run
+    pub runtime: Duration,
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// How much bigger, or faster, `new` is than `old`, as a ratio.
+fn ratio(new: f64, old: f64) -> String {
+    if old == 0.0 {
+        String::new()
+    } else {
+        format!("{:.2}x", new / old)
+    }
+}
+
+/// A table of the runs in the order they were made, with the size and runtime
+/// of each relative to the first run of the same benchmark.
+pub fn report(runs: &[Run]) -> String {
+    let width = runs
+        .iter()
+        .map(|r| r.benchmark.len())
+        .chain(Some("benchmark".len()))
+        .max()
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:<width$} {:>3} {:>11} {:>10} {:>6} {:>11} {:>7}",
+        "benchmark",
+        "-O",
+        "compile",
+        "size",
+        "",
+        "runtime",
+        "speedup",
+        width = width
+    );
+
+    for r in runs {
+        let first = runs.iter().find(|f| f.benchmark == r.benchmark).unwrap();
+
+        let _ = writeln!(
+            out,
+            "{:<width$} {:>3} {:>9.1}ms {:>10} {:>6} {:>9.1}ms {:>7}",
+            r.benchmark,
+            r.opt_level,
+            ms(r.compile_time),
+            r.size,
+            ratio(r.size as f64, first.size as f64),
+            ms(r.runtime),
+            ratio(ms(first.runtime), ms(r.runtime)),
+            width = width
+        );
+    }
+
+    out
+}
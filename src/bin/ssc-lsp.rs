@@ -0,0 +1,15 @@
+//! The language server, see `some_scheme_compiler::lsp`, speaking the
+//! protocol on stdin and stdout.
+
+use some_scheme_compiler::{lsp, utils};
+
+use failure::Error;
+use std::io::{stdin, stdout};
+
+fn main() -> Result<(), Error> {
+    // binding the program recurses on it, as the compiler does
+    let shut_down = utils::with_stack(|| lsp::serve(stdin().lock(), stdout()))?;
+
+    // exiting without being shut down is an error
+    std::process::exit(if shut_down { 0 } else { 1 })
+}
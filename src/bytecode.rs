@@ -0,0 +1,629 @@
+//! A compact bytecode for the lifted lambdas, run by `vm`, and the `.scmbc`
+//! files it is saved in.
+//!
+//! Each lambda is a sequence of instructions working on a stack of values.
+//! Environments are laid out the same way as in the C backend, the slots of a
+//! lambda's environment hold cells and a linked environment holds its parent
+//! in the first slot. A lambda body always ends by calling a closure, which
+//! hands control back to the loop in `vm`.
+
+use failure::{format_err, Error};
+use moniker::{FreeVar, Ignore};
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::foreign::Foreign;
+use crate::interp::{builtin_arity, unescape};
+use crate::lifted_expr::{self, EnvRepr, LExpr, LambdaParams, LiftedLambda};
+use crate::literals::Literal;
+
+const MAGIC: &[u8] = b"SCMBC";
+const VERSION: u8 = 3;
+
+/// The operands of each instruction follow it as unsigned LEB128, except for
+/// the target of `JumpUnless` which is a little endian u32 so that it can be
+/// filled in once the target is known.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum Op {
+    /// `constant`: push a constant
+    Const,
+    /// `arg slot`: put a new cell holding argument `arg` in a slot of the
+    /// lambda's own environment
+    Param,
+    /// `depth slot`: push the value of the cell in `slot` of the environment
+    /// `depth` parents up
+    Load,
+    /// `depth slot`: pop a value into the cell in `slot` of the environment
+    /// `depth` parents up
+    Store,
+    /// `lambda`: push a closure of `lambda` with a new environment
+    Closure,
+    /// `slot depth src`: put the cell in `src` of the environment `depth`
+    /// parents up in `slot` of the environment of the closure on the stack
+    Capture,
+    /// put the current environment in the first slot of the environment of
+    /// the closure on the stack
+    Link,
+    /// `target`: pop a value and jump to `target` unless it's truthy
+    JumpUnless,
+    /// pop an argument and a closure, and call the closure
+    CallOne,
+    /// pop a continuation, an argument and a closure, and call the closure
+    CallTwo,
+}
+
+impl TryFrom<u8> for Op {
+    type Error = Error;
+
+    fn try_from(b: u8) -> Result<Self, Error> {
+        Ok(match b {
+            0 => Op::Const,
+            1 => Op::Param,
+            2 => Op::Load,
+            3 => Op::Store,
+            4 => Op::Closure,
+            5 => Op::Capture,
+            6 => Op::Link,
+            7 => Op::JumpUnless,
+            8 => Op::CallOne,
+            9 => Op::CallTwo,
+            _ => return Err(format_err!("unknown instruction: {}", b)),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant {
+    Void,
+    Int(i64),
+    Rational(i64, i64),
+    Float(f64),
+    Str(String),
+    Symbol(String),
+    Bool(bool),
+    Builtin(String),
+    /// A quoted list, made once when the program is loaded
+    List(Vec<Constant>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lambda {
+    pub name: String,
+    /// 1 for a continuation, 2 for a lambda also taking its continuation
+    pub params: u8,
+    /// How big the environment of a closure of this lambda is
+    pub slots: usize,
+    pub code: Vec<u8>,
+}
+
+/// The first lambda is the toplevel, which is called with void and the
+/// environment of no lambda.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub constants: Vec<Constant>,
+    pub lambdas: Vec<Lambda>,
+}
+
+/// Compile a lifted program to bytecode.
+pub fn compile(lifted: &lifted_expr::Program) -> Result<Program, Error> {
+    let (expr, lambdas) = (lifted.entry(), lifted.lambdas());
+    let mut ids = lambdas.keys().cloned().collect::<Vec<_>>();
+    ids.sort();
+
+    let mut ctx = BytecodeCtx {
+        lambdas,
+        // the toplevel comes first
+        indices: ids.iter().enumerate().map(|(i, id)| (*id, i + 1)).collect(),
+        slots: lambdas.values().map(|l| (l.id, l.env_slots())).collect(),
+        constants: Vec::new(),
+        current: None,
+        code: Vec::new(),
+    };
+
+    ctx.tail(expr)?;
+    let mut program_lambdas = vec![Lambda {
+        name: "main_lambda".to_owned(),
+        params: 1,
+        slots: 0,
+        code: std::mem::take(&mut ctx.code),
+    }];
+
+    for id in &ids {
+        let lambda = &lambdas[id];
+        ctx.generate_func(lambda)?;
+
+        program_lambdas.push(Lambda {
+            name: lambda.name.clone(),
+            params: match lambda.params {
+                LambdaParams::One(_) => 1,
+                LambdaParams::Two(..) => 2,
+            },
+            slots: ctx.slots[id].len(),
+            code: std::mem::take(&mut ctx.code),
+        });
+    }
+
+    Ok(Program {
+        constants: ctx.constants,
+        lambdas: program_lambdas,
+    })
+}
+
+struct BytecodeCtx<'a> {
+    lambdas: &'a BTreeMap<usize, LiftedLambda>,
+    /// where each lambda is in the program
+    indices: HashMap<usize, usize>,
+    /// the slots of the environment of each lambda, in order
+    slots: HashMap<usize, Vec<FreeVar<String>>>,
+    constants: Vec<Constant>,
+    current: Option<usize>,
+    code: Vec<u8>,
+}
+
+impl<'a> BytecodeCtx<'a> {
+    fn emit(&mut self, op: Op, operands: &[usize]) {
+        self.code.push(op as u8);
+
+        for &o in operands {
+            write_uleb(&mut self.code, o as u64);
+        }
+    }
+
+    fn constant(&mut self, c: Constant) -> usize {
+        match self.constants.iter().position(|e| *e == c) {
+            Some(i) => i,
+            None => {
+                self.constants.push(c);
+                self.constants.len() - 1
+            }
+        }
+    }
+
+    /// How many parents up the environment holding `var` is, and its slot
+    /// there.
+    fn slot(&self, var: &FreeVar<String>) -> (usize, usize) {
+        let mut depth = 0;
+        let mut lambda = &self.lambdas[&self.current.expect("variable outside of any lambda")];
+
+        while !lambda.holds(var) {
+            depth += 1;
+            lambda = &self.lambdas[&lambda.parent.expect("variable not in any environment")];
+        }
+
+        (depth, slot_index(&self.slots[&lambda.id], var))
+    }
+
+    fn generate_func(&mut self, lambda: &LiftedLambda) -> Result<(), Error> {
+        self.current = Some(lambda.id);
+
+        for (arg, param) in lambda.params.iter().enumerate() {
+            // parameters that aren't used in the body are discarded
+            if lambda.freevars.contains(param) {
+                let slot = slot_index(&self.slots[&lambda.id], param);
+                self.emit(Op::Param, &[arg, slot]);
+            }
+        }
+
+        self.tail(&lambda.body)
+    }
+
+    fn tail(&mut self, e: &LExpr) -> Result<(), Error> {
+        match e {
+            LExpr::SetThen(v, e, c) => {
+                self.set(v, e)?;
+                self.tail(c)
+            }
+            LExpr::If(c, ift, iff) => {
+                self.atom(c)?;
+
+                self.emit(Op::JumpUnless, &[]);
+                let target = self.code.len();
+                self.code.extend_from_slice(&[0; 4]);
+
+                self.tail(ift)?;
+
+                let iff_start = self.code.len() as u32;
+                self.code[target..target + 4].copy_from_slice(&iff_start.to_le_bytes());
+
+                self.tail(iff)
+            }
+            LExpr::CallOne(c, a) => {
+                self.atom(c)?;
+                self.atom(a)?;
+                self.emit(Op::CallOne, &[]);
+                Ok(())
+            }
+            LExpr::CallTwo(c, a, k) => {
+                self.atom(c)?;
+                self.atom(a)?;
+                self.atom(k)?;
+                self.emit(Op::CallTwo, &[]);
+                Ok(())
+            }
+            _ => Err(format_err!("lambda body isn't a call: {:?}", e)),
+        }
+    }
+
+    fn set(&mut self, v: &moniker::Var<String>, e: &LExpr) -> Result<(), Error> {
+        self.atom(e)?;
+
+        let (depth, slot) = match v {
+            moniker::Var::Free(f) => self.slot(f),
+            moniker::Var::Bound(_) => panic!("bound var: {:?}", v),
+        };
+
+        self.emit(Op::Store, &[depth, slot]);
+        Ok(())
+    }
+
+    /// Push the value of `e`.
+    fn atom(&mut self, e: &LExpr) -> Result<(), Error> {
+        match e {
+            LExpr::Var(moniker::Var::Free(f)) => {
+                let (depth, slot) = self.slot(f);
+                self.emit(Op::Load, &[depth, slot]);
+            }
+            LExpr::Var(v) => panic!("bound var: {:?}", v),
+            LExpr::Lit(Ignore(l)) => {
+                let c = self.constant(literal_constant(l));
+                self.emit(Op::Const, &[c]);
+            }
+            LExpr::BuiltinIdent(Ignore(name)) => {
+                if Foreign::from_builtin_name(name).is_some() {
+                    return Err(format_err!(
+                        "foreign functions can't be called by the bytecode vm"
+                    ));
+                }
+
+                if !supported_builtin(name) {
+                    return Err(format_err!("{} isn't supported by the bytecode vm", name));
+                }
+
+                let c = self.constant(Constant::Builtin(name.clone()));
+                self.emit(Op::Const, &[c]);
+            }
+            LExpr::SetThen(v, e, c) => {
+                self.set(v, e)?;
+                self.atom(c)?;
+            }
+            LExpr::Lifted(Ignore(id)) => {
+                let lambda = &self.lambdas[id];
+                self.emit(Op::Closure, &[self.indices[id]]);
+
+                if lambda.env_repr == EnvRepr::Linked {
+                    self.emit(Op::Link, &[]);
+                } else {
+                    for var in lambda.captures() {
+                        let (depth, src) = self.slot(var);
+                        let slot = slot_index(&self.slots[id], var);
+                        self.emit(Op::Capture, &[slot, depth, src]);
+                    }
+                }
+            }
+            LExpr::If(..) | LExpr::CallOne(..) | LExpr::CallTwo(..) => {
+                return Err(format_err!("call in argument position: {:?}", e))
+            }
+            LExpr::IntOp(..) => unreachable!("integer ops are only made for C"),
+        }
+
+        Ok(())
+    }
+}
+
+fn slot_index(slots: &[FreeVar<String>], var: &FreeVar<String>) -> usize {
+    slots
+        .iter()
+        .position(|s| s == var)
+        .expect("variable not in the environment")
+}
+
+fn literal_constant(l: &Literal) -> Constant {
+    match l {
+        Literal::Void => Constant::Void,
+        Literal::Int(i) => Constant::Int(*i),
+        Literal::Rational(n, d) => Constant::Rational(*n, *d),
+        Literal::Float(f) => Constant::Float(*f),
+        Literal::String(s) => Constant::Str(unescape(s)),
+        Literal::Symbol(s) => Constant::Symbol(s.clone()),
+        Literal::Bool(b) => Constant::Bool(*b),
+        Literal::Quoted(items) => Constant::List(items.iter().map(literal_constant).collect()),
+    }
+}
+
+impl Program {
+    /// The contents of a `.scmbc` file: a magic number and version, then the
+    /// constants and the lambdas, with lengths and counts as little endian
+    /// u32s.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = MAGIC.to_vec();
+        out.push(VERSION);
+
+        write_u32(&mut out, self.constants.len());
+        for c in &self.constants {
+            write_constant(&mut out, c);
+        }
+
+        write_u32(&mut out, self.lambdas.len());
+        for l in &self.lambdas {
+            write_bytes(&mut out, l.name.as_bytes());
+            out.push(l.params);
+            write_u32(&mut out, l.slots);
+            write_bytes(&mut out, &l.code);
+        }
+
+        out
+    }
+
+    /// Read a program written by `to_bytes`. Instructions are only checked as
+    /// they are run.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Program, Error> {
+        let mut r = Reader { bytes, pos: 0 };
+
+        if r.take(MAGIC.len())? != MAGIC {
+            return Err(format_err!("not a .scmbc file"));
+        }
+
+        let version = r.byte()?;
+        if version != VERSION {
+            return Err(format_err!(
+                "the file is version {} of the bytecode, this is version {}",
+                version,
+                VERSION
+            ));
+        }
+
+        let mut constants = Vec::new();
+        for _ in 0..r.count()? {
+            constants.push(read_constant(&mut r)?);
+        }
+
+        let mut lambdas = Vec::new();
+        for _ in 0..r.count()? {
+            let name = r.string()?;
+            let params = r.byte()?;
+            let slots = r.u32()?;
+            let len = r.u32()?;
+
+            // each slot is filled by an instruction somewhere in the file, so
+            // a closure of a corrupted file can't take all the memory
+            if slots > bytes.len() {
+                return Err(format_err!(
+                    "{} has more slots than the program fills",
+                    name
+                ));
+            }
+
+            lambdas.push(Lambda {
+                name,
+                params,
+                slots,
+                code: r.take(len)?.to_vec(),
+            });
+        }
+
+        if lambdas.is_empty() {
+            return Err(format_err!("the program has no toplevel"));
+        }
+
+        if r.pos != bytes.len() {
+            return Err(format_err!("trailing bytes after the program"));
+        }
+
+        Ok(Program { constants, lambdas })
+    }
+}
+
+/// Lists the constants, then the instructions of each lambda.
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, c) in self.constants.iter().enumerate() {
+            writeln!(f, "constant {}: {:?}", i, c)?;
+        }
+
+        for (i, l) in self.lambdas.iter().enumerate() {
+            writeln!(
+                f,
+                "\nlambda {} {} ({} params, {} slots):",
+                i, l.name, l.params, l.slots
+            )?;
+
+            let mut r = Reader {
+                bytes: &l.code,
+                pos: 0,
+            };
+
+            while r.pos < l.code.len() {
+                let pos = r.pos;
+                match r.instruction() {
+                    Ok((op, operands)) => writeln!(f, "  {:4} {:?} {:?}", pos, op, operands)?,
+                    Err(e) => return writeln!(f, "  {:4} {}", pos, e),
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_uleb(out: &mut Vec<u8>, mut v: u64) {
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+
+        if v == 0 {
+            out.push(byte);
+            return;
+        }
+
+        out.push(byte | 0x80);
+    }
+}
+
+/// The builtins applied by the vm, foreign functions being rejected before.
+fn supported_builtin(name: &str) -> bool {
+    name == "halt" || builtin_arity(name).is_some()
+}
+
+fn write_u32(out: &mut Vec<u8>, v: usize) {
+    out.extend_from_slice(&(v as u32).to_le_bytes());
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_u32(out, bytes.len());
+    out.extend_from_slice(bytes);
+}
+
+/// A kind byte, then what the kind of constant has, the items of a list
+/// after their count.
+fn write_constant(out: &mut Vec<u8>, c: &Constant) {
+    match c {
+        Constant::Void => out.push(0),
+        Constant::Int(i) => {
+            out.push(1);
+            out.extend_from_slice(&i.to_le_bytes());
+        }
+        Constant::Rational(n, d) => {
+            out.push(2);
+            out.extend_from_slice(&n.to_le_bytes());
+            out.extend_from_slice(&d.to_le_bytes());
+        }
+        Constant::Float(f) => {
+            out.push(3);
+            out.extend_from_slice(&f.to_bits().to_le_bytes());
+        }
+        Constant::Str(s) => {
+            out.push(4);
+            write_bytes(out, s.as_bytes());
+        }
+        Constant::Symbol(s) => {
+            out.push(5);
+            write_bytes(out, s.as_bytes());
+        }
+        Constant::Bool(b) => {
+            out.push(6);
+            out.push(*b as u8);
+        }
+        Constant::Builtin(name) => {
+            out.push(7);
+            write_bytes(out, name.as_bytes());
+        }
+        Constant::List(items) => {
+            out.push(8);
+            write_u32(out, items.len());
+
+            for item in items {
+                write_constant(out, item);
+            }
+        }
+    }
+}
+
+fn read_constant(r: &mut Reader) -> Result<Constant, Error> {
+    Ok(match r.byte()? {
+        0 => Constant::Void,
+        1 => Constant::Int(r.i64()?),
+        2 => Constant::Rational(r.i64()?, r.i64()?),
+        3 => Constant::Float(f64::from_bits(r.i64()? as u64)),
+        4 => Constant::Str(r.string()?),
+        5 => Constant::Symbol(r.string()?),
+        6 => Constant::Bool(r.byte()? != 0),
+        7 => {
+            let name = r.string()?;
+
+            if !supported_builtin(&name) || Foreign::from_builtin_name(&name).is_some() {
+                return Err(format_err!("unknown builtin: {}", name));
+            }
+            Constant::Builtin(name)
+        }
+        8 => Constant::List(
+            (0..r.count()?)
+                .map(|_| read_constant(r))
+                .collect::<Result<_, _>>()?,
+        ),
+        t => return Err(format_err!("unknown kind of constant: {}", t)),
+    })
+}
+
+/// Reads `.scmbc` files and the code in them, `pos` is where the next read
+/// starts.
+pub(crate) struct Reader<'a> {
+    pub(crate) bytes: &'a [u8],
+    pub(crate) pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .bytes
+            .get(self.pos..self.pos + n)
+            .ok_or_else(|| format_err!("unexpected end of the bytecode"))?;
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    pub(crate) fn byte(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<usize, Error> {
+        let b = self.take(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+    }
+
+    /// How many of something follow, each at least a byte.
+    fn count(&mut self) -> Result<usize, Error> {
+        let count = self.u32()?;
+
+        if count > self.bytes.len() - self.pos {
+            return Err(format_err!("unexpected end of the bytecode"));
+        }
+        Ok(count)
+    }
+
+    fn i64(&mut self) -> Result<i64, Error> {
+        let mut b = [0; 8];
+        b.copy_from_slice(self.take(8)?);
+        Ok(i64::from_le_bytes(b))
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let len = self.u32()?;
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|e| format_err!("{}", e))
+    }
+
+    pub(crate) fn uleb(&mut self) -> Result<usize, Error> {
+        let mut v = 0u64;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.byte()?;
+            if shift >= 64 {
+                return Err(format_err!("operand too large"));
+            }
+            v |= u64::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(v as usize);
+            }
+            shift += 7;
+        }
+    }
+
+    /// The next instruction and its operands.
+    fn instruction(&mut self) -> Result<(Op, Vec<usize>), Error> {
+        let op = Op::try_from(self.byte()?)?;
+
+        let operands = match op {
+            Op::Const | Op::Closure => vec![self.uleb()?],
+            Op::Param | Op::Load | Op::Store => vec![self.uleb()?, self.uleb()?],
+            Op::Capture => vec![self.uleb()?, self.uleb()?, self.uleb()?],
+            Op::JumpUnless => vec![self.u32()?],
+            Op::Link | Op::CallOne | Op::CallTwo => vec![],
+        };
+
+        Ok((op, operands))
+    }
+}
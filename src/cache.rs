@@ -0,0 +1,79 @@
+use failure::Error;
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+
+/// A directory of the outputs of earlier compilations, each stored under a
+/// hash of everything that went into making it. Nothing is ever out of date,
+/// changing an input changes the key, so the directory can be emptied at any
+/// time.
+#[derive(Debug)]
+pub struct Cache {
+    dir: PathBuf,
+    /// what the compiler that made the entries was, part of every key
+    compiler: String,
+}
+
+impl Cache {
+    pub fn open(dir: &Path) -> Result<Self, Error> {
+        fs::create_dir_all(dir)?;
+
+        Ok(Self {
+            dir: dir.to_owned(),
+            compiler: compiler_identity(),
+        })
+    }
+
+    /// The key of the output made from `inputs`, the `kind` of output keeps
+    /// outputs made from the same inputs apart.
+    pub fn key(&self, kind: &str, inputs: &[&str]) -> String {
+        let mut hasher = DefaultHasher::new();
+
+        for part in [kind, &self.compiler].iter().chain(inputs) {
+            // the lengths keep the parts from running into each other
+            hasher.write_usize(part.len());
+            hasher.write(part.as_bytes());
+        }
+
+        format!("{}-{:016x}", kind, hasher.finish())
+    }
+
+    pub fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(key)
+    }
+
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        fs::read(self.path(key)).ok()
+    }
+
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        String::from_utf8(self.get(key)?).ok()
+    }
+
+    /// Entries are written to a temporary file and renamed into place, so a
+    /// compilation that is interrupted, or runs alongside another, never
+    /// leaves half an entry.
+    pub fn put(&self, key: &str, contents: &[u8]) -> Result<(), Error> {
+        let tmp = self
+            .dir
+            .join(format!(".{}.{}.tmp", key, std::process::id()));
+
+        fs::write(&tmp, contents)?;
+        fs::rename(&tmp, self.path(key))?;
+
+        Ok(())
+    }
+}
+
+/// The version of the compiler along with the size and modification time of
+/// its executable, so that entries made by a rebuilt compiler aren't reused.
+fn compiler_identity() -> String {
+    let exe = std::env::current_exe()
+        .and_then(fs::metadata)
+        .map(|m| format!("{} {:?}", m.len(), m.modified().ok()))
+        .unwrap_or_default();
+
+    format!("{} {}", env!("CARGO_PKG_VERSION"), exe)
+}
@@ -1,6 +1,84 @@
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::fmt::Write;
-use std::{borrow::Cow, rc::Rc};
+use std::iter::FromIterator;
+use std::{borrow::Cow, sync::Arc};
+
+/// A C identifier standing for the scheme name `name`, characters that C
+/// doesn't allow are spelt out. Callers add a prefix so that the result never
+/// starts with a digit or collides with the runtime.
+pub fn mangle(name: &str) -> String {
+    let mut out = String::new();
+
+    for c in name.chars() {
+        match c {
+            c if c.is_ascii_alphanumeric() || c == '_' => out.push(c),
+            '-' => out.push('_'),
+            '?' => out.push_str("_p"),
+            '!' => out.push_str("_x"),
+            '*' => out.push_str("_star"),
+            '+' => out.push_str("_plus"),
+            '/' => out.push_str("_slash"),
+            '<' => out.push_str("_lt"),
+            '>' => out.push_str("_gt"),
+            '=' => out.push_str("_eq"),
+            c => {
+                let _ = write!(out, "_u{:x}", c as u32);
+            }
+        }
+    }
+
+    out
+}
+
+/// Like `mangle`, but distinct names always give distinct identifiers, for
+/// names that have to agree between separately compiled files.
+pub fn mangle_exact(name: &str) -> String {
+    let mut out = String::new();
+
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c);
+        } else {
+            let _ = write!(out, "_{:x}_", c as u32);
+        }
+    }
+
+    out
+}
+
+/// The names `unique_name` has given out, or that it is not to give.
+#[derive(Debug, Clone, Default)]
+pub struct TakenNames {
+    taken: HashSet<String>,
+    /// the suffix to try first for each base, those before it being taken, so
+    /// that many names on one base don't each try every suffix
+    next: HashMap<String, usize>,
+}
+
+impl FromIterator<String> for TakenNames {
+    fn from_iter<I: IntoIterator<Item = String>>(names: I) -> Self {
+        TakenNames {
+            taken: names.into_iter().collect(),
+            next: HashMap::new(),
+        }
+    }
+}
+
+/// `base`, or `base` with the first numeric suffix that isn't in `taken`,
+/// recording the result as taken.
+pub fn unique_name(taken: &mut TakenNames, base: String) -> String {
+    let mut name = base.clone();
+    let n = taken.next.entry(base.clone()).or_insert(2);
+
+    while taken.taken.contains(&name) {
+        name = format!("{}_{}", base, n);
+        *n += 1;
+    }
+
+    taken.taken.insert(name.clone());
+    name
+}
 
 pub trait ToCDC: ToC + Debug {}
 impl<T: ToC + Debug> ToCDC for T {}
@@ -9,63 +87,69 @@ impl<T: ToC + Debug> ToCDC for T {}
 pub enum CExpr<'a> {
     BinOp {
         op: Cow<'a, str>,
-        left: Rc<CExpr<'a>>,
-        right: Rc<CExpr<'a>>,
+        left: Arc<CExpr<'a>>,
+        right: Arc<CExpr<'a>>,
     },
     PreUnOp {
         op: Cow<'a, str>,
-        ex: Rc<CExpr<'a>>,
+        ex: Arc<CExpr<'a>>,
     },
     PostUnOp {
         op: Cow<'a, str>,
-        ex: Rc<CExpr<'a>>,
+        ex: Arc<CExpr<'a>>,
     },
     ArrIndexOp {
-        index: Rc<CExpr<'a>>,
-        expr: Rc<CExpr<'a>>,
+        index: Arc<CExpr<'a>>,
+        expr: Arc<CExpr<'a>>,
     },
     Dot {
-        expr: Rc<CExpr<'a>>,
+        expr: Arc<CExpr<'a>>,
         attr: Cow<'a, str>,
     },
     Arrow {
-        expr: Rc<CExpr<'a>>,
+        expr: Arc<CExpr<'a>>,
         attr: Cow<'a, str>,
     },
     FunCallOp {
-        expr: Rc<CExpr<'a>>,
-        params: Vec<Rc<CExpr<'a>>>,
+        expr: Arc<CExpr<'a>>,
+        params: Vec<Arc<CExpr<'a>>>,
     },
     Cast {
-        ex: Rc<CExpr<'a>>,
+        ex: Arc<CExpr<'a>>,
         typ: CType<'a>,
     },
     MacroCall {
         name: Cow<'a, str>,
-        args: Vec<Rc<dyn ToCDC + 'a>>,
+        args: Vec<Arc<dyn ToCDC + Send + Sync + 'a>>,
     },
     If {
-        cond: Rc<CExpr<'a>>,
-        ift: Rc<CExpr<'a>>,
-        iff: Rc<CExpr<'a>>,
+        cond: Arc<CExpr<'a>>,
+        ift: Arc<CExpr<'a>>,
+        iff: Arc<CExpr<'a>>,
     },
     InitList(Vec<CExpr<'a>>),
     Ident(Cow<'a, str>),
     LitStr(Cow<'a, str>),
     LitUInt(usize),
     LitIInt(isize),
+    LitFloat(f64),
 }
 
 #[derive(Debug)]
 pub enum CType<'a> {
-    Ptr(Rc<CType<'a>>),
-    Arr(Rc<CType<'a>>, Option<usize>),
-    Int { size: usize, sign: bool },
+    Ptr(Arc<CType<'a>>),
+    Arr(Arc<CType<'a>>, Option<usize>),
+    Int {
+        size: usize,
+        sign: bool,
+    },
     Struct(Cow<'a, str>),
     Union(Cow<'a, str>),
     Other(Cow<'a, str>),
-    Static(Rc<CType<'a>>),
-    Const(Rc<CType<'a>>),
+    Static(Arc<CType<'a>>),
+    /// a definition that the linker merges with others of the same name
+    Weak(Arc<CType<'a>>),
+    Const(Arc<CType<'a>>),
     Void,
 }
 
@@ -73,22 +157,34 @@ pub enum CType<'a> {
 pub enum CStmt<'a> {
     If {
         cond: CExpr<'a>,
-        ift: Rc<CStmt<'a>>,
-        iff: Rc<CStmt<'a>>,
+        ift: Arc<CStmt<'a>>,
+        iff: Arc<CStmt<'a>>,
     },
     While {
         cond: CExpr<'a>,
-        body: Rc<CStmt<'a>>,
+        body: Arc<CStmt<'a>>,
     },
     For {
         init: CExpr<'a>,
         test: CExpr<'a>,
         updt: CExpr<'a>,
-        body: Rc<CStmt<'a>>,
+        body: Arc<CStmt<'a>>,
     },
     Decl(CDecl<'a>),
-    Block(Vec<Rc<CStmt<'a>>>),
+    Block(Vec<Arc<CStmt<'a>>>),
     Expr(CExpr<'a>),
+    /// Each case runs on into the next unless it ends with a `Break` or
+    /// `Return`, as in C
+    Switch {
+        cond: CExpr<'a>,
+        cases: Vec<(CExpr<'a>, Vec<Arc<CStmt<'a>>>)>,
+        default: Option<Vec<Arc<CStmt<'a>>>>,
+    },
+    Return(Option<CExpr<'a>>),
+    Break,
+    /// Labels the empty statement, so that a declaration can follow it
+    Label(Cow<'a, str>),
+    Goto(Cow<'a, str>),
 }
 
 #[derive(Debug)]
@@ -103,7 +199,10 @@ pub enum CDecl<'a> {
         name: Cow<'a, str>,
         typ: CType<'a>,
         args: Vec<(Cow<'a, str>, CType<'a>)>,
-        body: Vec<Rc<CStmt<'a>>>,
+        body: Vec<Arc<CStmt<'a>>>,
+        /// the file and line the function was compiled from, exported as a
+        /// `#line` directive so that the C compiler reports it
+        source: Option<(Cow<'a, str>, usize)>,
     },
     Struct {
         name: Cow<'a, str>,
@@ -128,6 +227,18 @@ pub trait ToC {
         self.export_internal(&mut s);
         s
     }
+
+    /// Like `export_internal`, but laid out to be read: a statement to a line,
+    /// indented `depth` levels, and no more parentheses than C needs.
+    fn pretty_internal(&self, s: &mut String, _depth: usize) {
+        self.export_internal(s);
+    }
+
+    fn pretty(&self) -> String {
+        let mut s = String::new();
+        self.pretty_internal(&mut s, 0);
+        s
+    }
 }
 
 macro_rules! export_helper {
@@ -152,6 +263,10 @@ macro_rules! export_helper {
 }
 
 impl<'a> ToC for CExpr<'a> {
+    fn pretty_internal(&self, s: &mut String, _depth: usize) {
+        self.pretty_prec(s, ASSIGN);
+    }
+
     fn export_internal(&self, mut s: &mut String) {
         use self::CExpr::*;
 
@@ -186,7 +301,7 @@ impl<'a> ToC for CExpr<'a> {
                 exp expr,
                 str ")[",
                 exp index,
-                chr ')'
+                chr ']'
             ),
             Dot { expr, attr } => export_helper!(
                 s, chr '(', exp expr, str ").", str attr
@@ -244,7 +359,12 @@ impl<'a> ToC for CExpr<'a> {
                 )
             }
             LitUInt(lit) => export_helper!(s, str & lit.to_string()),
+            // C reads -9223372036854775808 as negating a constant too big
+            LitIInt(lit) if *lit == isize::MIN => {
+                export_helper!(s, str & format!("({} - 1)", isize::MIN + 1))
+            }
             LitIInt(lit) => export_helper!(s, str & lit.to_string()),
+            LitFloat(lit) => export_helper!(s, str & format!("{:?}", lit)),
         }
     }
 }
@@ -291,10 +411,15 @@ impl<'a> CType<'a> {
                 let _ = write!(s, "{} ", tname);
                 name_writer(s);
             }
-            Static(of) => of.export_with_name(s, &|s| {
+            // storage classes go in front of the whole declaration
+            Static(of) => {
                 let _ = write!(s, "static ");
-                name_writer(s);
-            }),
+                of.export_with_name(s, name_writer);
+            }
+            Weak(of) => {
+                let _ = write!(s, "__attribute__((weak)) ");
+                of.export_with_name(s, name_writer);
+            }
             Const(of) => of.export_with_name(s, &|s| {
                 let _ = write!(s, "const ");
                 name_writer(s);
@@ -308,6 +433,98 @@ impl<'a> CType<'a> {
 }
 
 impl<'a> ToC for CStmt<'a> {
+    fn pretty_internal(&self, s: &mut String, depth: usize) {
+        use self::CStmt::*;
+
+        match self {
+            If { cond, ift, iff } => {
+                s.push_str("if (");
+                cond.pretty_internal(s, depth);
+                s.push(')');
+                ift.pretty_body(s, depth);
+
+                match &**iff {
+                    Block(body) if body.is_empty() => {}
+                    If { .. } => {
+                        s.push_str(" else ");
+                        iff.pretty_internal(s, depth);
+                    }
+                    _ => {
+                        s.push_str(" else");
+                        iff.pretty_body(s, depth);
+                    }
+                }
+            }
+            While { cond, body } => {
+                s.push_str("while (");
+                cond.pretty_internal(s, depth);
+                s.push(')');
+                body.pretty_body(s, depth);
+            }
+            For {
+                init,
+                test,
+                updt,
+                body,
+            } => {
+                s.push_str("for (");
+                init.pretty_internal(s, depth);
+                s.push_str("; ");
+                test.pretty_internal(s, depth);
+                s.push_str("; ");
+                updt.pretty_internal(s, depth);
+                s.push(')');
+                body.pretty_body(s, depth);
+            }
+            Decl(decl) => decl.pretty_internal(s, depth),
+            Block(body) => Self::pretty_block(body, s, depth),
+            Expr(body) => {
+                body.pretty_internal(s, depth);
+                s.push(';');
+            }
+            Switch {
+                cond,
+                cases,
+                default,
+            } => {
+                s.push_str("switch (");
+                cond.pretty_internal(s, depth);
+                s.push_str(") {\n");
+
+                let labelled = cases
+                    .iter()
+                    .map(|(label, body)| (Some(label), body))
+                    .chain(default.iter().map(|body| (None, body)));
+
+                for (label, body) in labelled {
+                    indent(s, depth);
+                    match label {
+                        Some(label) => {
+                            s.push_str("case ");
+                            label.pretty_internal(s, depth);
+                            s.push_str(":\n");
+                        }
+                        None => s.push_str("default:\n"),
+                    }
+                    for stmt in body {
+                        indent(s, depth + 1);
+                        stmt.pretty_internal(s, depth + 1);
+                        s.push('\n');
+                    }
+                }
+
+                indent(s, depth);
+                s.push('}');
+            }
+            Return(Some(ex)) => {
+                s.push_str("return ");
+                ex.pretty_internal(s, depth);
+                s.push(';');
+            }
+            Return(None) | Break | Label(_) | Goto(_) => self.export_internal(s),
+        }
+    }
+
     fn export_internal(&self, mut s: &mut String) {
         use self::CStmt::*;
 
@@ -356,11 +573,84 @@ impl<'a> ToC for CStmt<'a> {
                 exp body,
                 chr ';'
             ),
+            Switch {
+                cond,
+                cases,
+                default,
+            } => {
+                export_helper!(s, str "switch (", exp cond, str "){");
+                for (label, body) in cases {
+                    export_helper!(s, str "case ", exp label, chr ':', vec body);
+                }
+                if let Some(body) = default {
+                    export_helper!(s, str "default:", vec body);
+                }
+                s.push('}');
+            }
+            Return(None) => s.push_str("return;"),
+            Return(Some(ex)) => export_helper!(s, str "return ", exp ex, chr ';'),
+            Break => s.push_str("break;"),
+            Label(name) => {
+                let _ = write!(s, "{}:;", name);
+            }
+            Goto(name) => {
+                let _ = write!(s, "goto {};", name);
+            }
         }
     }
 }
 
 impl<'a> ToC for CDecl<'a> {
+    fn pretty_internal(&self, s: &mut String, depth: usize) {
+        use self::CDecl::*;
+
+        match self {
+            Fun {
+                name,
+                typ,
+                args,
+                body,
+                source,
+            } => {
+                Self::export_fun_header(s, name, typ, args, source);
+                s.push(' ');
+                CStmt::pretty_block(body, s, depth);
+            }
+            Struct { name, members } | Union { name, members } => {
+                let keyword = if let Struct { .. } = self {
+                    "struct"
+                } else {
+                    "union"
+                };
+                let _ = writeln!(s, "{} {} {{", keyword, name);
+
+                for (aname, atyp) in members {
+                    indent(s, depth + 1);
+                    atyp.export_with_name(s, &|s| {
+                        s.push_str(aname);
+                    });
+                    s.push_str(";\n");
+                }
+
+                indent(s, depth);
+                s.push_str("};");
+            }
+            Var {
+                name,
+                typ,
+                init: Some(init),
+            } => {
+                typ.export_with_name(s, &|s| {
+                    s.push_str(name);
+                });
+                s.push_str(" = ");
+                init.pretty_internal(s, depth);
+                s.push(';');
+            }
+            FunProto { .. } | Var { init: None, .. } => self.export_internal(s),
+        }
+    }
+
     fn export_internal(&self, mut s: &mut String) {
         use self::CDecl::*;
 
@@ -399,27 +689,9 @@ impl<'a> ToC for CDecl<'a> {
                 typ,
                 args,
                 body,
+                source,
             } => {
-                typ.export_with_name(s, &|s| {
-                    let _ = write!(s, "{}(", name);
-
-                    let mut it = args.iter();
-
-                    if let Some((aname, atyp)) = it.next() {
-                        atyp.export_with_name(s, &|s| {
-                            s.push_str(aname);
-                        });
-                    }
-
-                    for (aname, atyp) in it {
-                        let _ = write!(s, ", ");
-                        atyp.export_with_name(s, &|s| {
-                            s.push_str(aname);
-                        });
-                    }
-
-                    let _ = write!(s, ")");
-                });
+                Self::export_fun_header(s, name, typ, args, source);
 
                 export_helper!(s,
                                chr '{',
@@ -461,3 +733,226 @@ impl<'a> ToC for CDecl<'a> {
         }
     }
 }
+
+/// How tightly C binds each operator, lower binding tighter. Assignment is the
+/// only right associative binary operator here.
+fn binop_precedence(op: &str) -> u8 {
+    match op {
+        "*" | "/" | "%" => 3,
+        "+" | "-" => 4,
+        "<<" | ">>" => 5,
+        "<" | "<=" | ">" | ">=" => 6,
+        "==" | "!=" => 7,
+        "&" => 8,
+        "^" => 9,
+        "|" => 10,
+        "&&" => 11,
+        "||" => 12,
+        "=" | "+=" | "-=" | "*=" | "/=" | "%=" | "<<=" | ">>=" | "&=" | "^=" | "|=" => 14,
+        // anything else, the comma included, is always parenthesised
+        _ => 15,
+    }
+}
+
+const POSTFIX: u8 = 1;
+const UNARY: u8 = 2;
+const TERNARY: u8 = 13;
+const ASSIGN: u8 = 14;
+
+fn indent(s: &mut String, depth: usize) {
+    for _ in 0..depth {
+        s.push_str("  ");
+    }
+}
+
+impl<'a> CExpr<'a> {
+    fn precedence(&self) -> u8 {
+        use self::CExpr::*;
+
+        match self {
+            BinOp { op, .. } => binop_precedence(op),
+            PreUnOp { .. } | Cast { .. } => UNARY,
+            PostUnOp { .. } | ArrIndexOp { .. } | Dot { .. } | Arrow { .. } => POSTFIX,
+            FunCallOp { .. } | MacroCall { .. } => POSTFIX,
+            If { .. } => TERNARY,
+            LitIInt(lit) if *lit < 0 => UNARY,
+            LitFloat(lit) if *lit < 0.0 => UNARY,
+            InitList(_) | Ident(_) | LitStr(_) | LitUInt(_) | LitIInt(_) | LitFloat(_) => 0,
+        }
+    }
+
+    /// Writes the expression where operators binding looser than `loosest`
+    /// would take it apart, parenthesising it if it is one of them.
+    fn pretty_prec(&self, s: &mut String, loosest: u8) {
+        let parens = self.precedence() > loosest;
+
+        if parens {
+            s.push('(');
+        }
+        self.pretty_bare(s);
+        if parens {
+            s.push(')');
+        }
+    }
+
+    fn pretty_bare(&self, s: &mut String) {
+        use self::CExpr::*;
+
+        match self {
+            BinOp { op, left, right } => {
+                let prec = binop_precedence(op);
+                let (l, r) = if prec == ASSIGN {
+                    (UNARY, prec)
+                } else {
+                    (prec, prec - 1)
+                };
+
+                left.pretty_prec(s, l);
+                let _ = write!(s, " {} ", op);
+                right.pretty_prec(s, r);
+            }
+            PreUnOp { op, ex } => {
+                let mut operand = String::new();
+                ex.pretty_prec(&mut operand, UNARY);
+
+                s.push_str(op);
+                // `- -x` mustn't become `--x`
+                if op.ends_with(['+', '-', '&']) && operand.starts_with(['+', '-', '&']) {
+                    s.push(' ');
+                }
+                s.push_str(&operand);
+            }
+            PostUnOp { op, ex } => {
+                ex.pretty_prec(s, POSTFIX);
+                s.push_str(op);
+            }
+            ArrIndexOp { index, expr } => {
+                expr.pretty_prec(s, POSTFIX);
+                s.push('[');
+                index.pretty_prec(s, 15);
+                s.push(']');
+            }
+            Dot { expr, attr } => {
+                expr.pretty_prec(s, POSTFIX);
+                let _ = write!(s, ".{}", attr);
+            }
+            Arrow { expr, attr } => {
+                expr.pretty_prec(s, POSTFIX);
+                let _ = write!(s, "->{}", attr);
+            }
+            FunCallOp { expr, params } => {
+                expr.pretty_prec(s, POSTFIX);
+                s.push('(');
+                for (i, param) in params.iter().enumerate() {
+                    if i > 0 {
+                        s.push_str(", ");
+                    }
+                    param.pretty_prec(s, ASSIGN);
+                }
+                s.push(')');
+            }
+            Cast { ex, typ } => {
+                let _ = write!(s, "({})", typ.export().trim_end());
+                ex.pretty_prec(s, UNARY);
+            }
+            MacroCall { name, args } => {
+                let _ = write!(s, "{}(", name);
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        s.push_str(", ");
+                    }
+                    // types are exported with a space after them
+                    s.push_str(arg.pretty().trim_end());
+                }
+                s.push(')');
+            }
+            If { cond, ift, iff } => {
+                cond.pretty_prec(s, TERNARY - 1);
+                s.push_str(" ? ");
+                ift.pretty_prec(s, ASSIGN);
+                s.push_str(" : ");
+                iff.pretty_prec(s, TERNARY);
+            }
+            InitList(exprs) => {
+                s.push('{');
+                for (i, expr) in exprs.iter().enumerate() {
+                    if i > 0 {
+                        s.push_str(", ");
+                    }
+                    expr.pretty_prec(s, ASSIGN);
+                }
+                s.push('}');
+            }
+            Ident(_) | LitStr(_) | LitUInt(_) | LitIInt(_) | LitFloat(_) => self.export_internal(s),
+        }
+    }
+}
+
+impl<'a> CDecl<'a> {
+    /// The `#line` directive and signature of a function, laid out the same
+    /// whether exported or pretty printed.
+    fn export_fun_header(
+        s: &mut String,
+        name: &str,
+        typ: &CType<'a>,
+        args: &[(Cow<'a, str>, CType<'a>)],
+        source: &Option<(Cow<'a, str>, usize)>,
+    ) {
+        if let Some((file, line)) = source {
+            let _ = writeln!(
+                s,
+                "\n#line {} \"{}\"",
+                line,
+                file.replace('\\', "\\\\").replace('"', "\\\"")
+            );
+        }
+
+        typ.export_with_name(s, &|s| {
+            let _ = write!(s, "{}(", name);
+
+            let mut it = args.iter();
+
+            if let Some((aname, atyp)) = it.next() {
+                atyp.export_with_name(s, &|s| {
+                    s.push_str(aname);
+                });
+            }
+
+            for (aname, atyp) in it {
+                let _ = write!(s, ", ");
+                atyp.export_with_name(s, &|s| {
+                    s.push_str(aname);
+                });
+            }
+
+            let _ = write!(s, ")");
+        });
+    }
+}
+
+impl<'a> CStmt<'a> {
+    /// A block's statements each on their own line, then the closing brace
+    /// back at `depth`.
+    fn pretty_block(body: &[Arc<CStmt<'a>>], s: &mut String, depth: usize) {
+        s.push_str("{\n");
+        for stmt in body {
+            indent(s, depth + 1);
+            stmt.pretty_internal(s, depth + 1);
+            s.push('\n');
+        }
+        indent(s, depth);
+        s.push('}');
+    }
+
+    /// The body of an `if`, `while` or `for`, following its header.
+    fn pretty_body(&self, s: &mut String, depth: usize) {
+        if let CStmt::Block(body) = self {
+            s.push(' ');
+            Self::pretty_block(body, s, depth);
+        } else {
+            s.push('\n');
+            indent(s, depth + 1);
+            self.pretty_internal(s, depth + 1);
+        }
+    }
+}
@@ -1,5 +1,10 @@
-use std::collections::HashMap;
-use std::rc::Rc;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::Hasher;
+use std::iter;
+use std::panic;
+use std::sync::Arc;
+use std::thread;
 
 use moniker::FreeVar;
 use moniker::Ignore;
@@ -8,24 +13,124 @@ use crate::cdsl::CDecl;
 use crate::cdsl::CExpr;
 use crate::cdsl::CStmt;
 use crate::cdsl::CType;
+use crate::cdsl::ToCDC;
+use crate::cdsl::{mangle, mangle_exact, unique_name, TakenNames};
+use crate::foreign::Foreign;
+use crate::interp::unescape;
+use crate::lifted_expr;
+use crate::lifted_expr::boxed_vars;
+use crate::lifted_expr::known_lambdas;
+use crate::lifted_expr::lambda_spans;
+use crate::lifted_expr::local_params;
+use crate::lifted_expr::source_lines;
+use crate::lifted_expr::EnvRepr;
 use crate::lifted_expr::LExpr;
+use crate::lifted_expr::LambdaParams;
 use crate::lifted_expr::LiftedLambda;
+use crate::link::Module;
 use crate::literals::Literal;
+use crate::target::TargetConfig;
+use crate::utils::STACK_SIZE;
+
+/// What every function of the program is generated from, which is only read
+/// while generating them.
+struct Program<'a> {
+    lambdas: &'a BTreeMap<usize, LiftedLambda>,
+    /// the environment member holding each variable
+    var_names: HashMap<FreeVar<String>, String>,
+    /// the name of the file being compiled, for `#line` directives
+    source_file: &'a str,
+    /// variables outside of every environment to the C variable holding them
+    globals: &'a HashMap<FreeVar<String>, String>,
+    /// the variables kept in cells, the rest are kept in their slots
+    boxed: HashSet<FreeVar<String>>,
+    /// parameters kept in locals of their lambda's function
+    local_params: HashSet<FreeVar<String>>,
+    /// variables only holding closures of the lambda they map to
+    known: HashMap<FreeVar<String>, usize>,
+    /// the machine the C is built for, which decides what fits in a fixnum
+    target: &'a TargetConfig,
+    /// see `lambda_spans`
+    spans: HashMap<usize, (usize, usize)>,
+    /// see `source_lines`
+    source_lines: HashMap<usize, Option<usize>>,
+}
+
+impl<'a> Program<'a> {
+    /// Whether the lambda `inner` is `outer` or is made by a run of it.
+    fn within(&self, inner: usize, outer: usize) -> bool {
+        let (inner, outer) = (self.spans[&inner], self.spans[&outer]);
+        outer.0 <= inner.0 && inner.1 <= outer.1
+    }
+
+    fn var_name(&self, var: &FreeVar<String>) -> String {
+        self.var_names
+            .get(var)
+            .cloned()
+            .unwrap_or_else(|| name_for_free_var(var))
+    }
+}
 
-pub struct CodegenCtx<'a> {
+/// Generating one function, which doesn't touch any other, so that the
+/// functions can be generated at the same time and merged after.
+struct CodegenCtx<'a> {
+    program: &'a Program<'a>,
     unique_var_id: usize,
     protos: Vec<CDecl<'static>>,
     declarations: Vec<CDecl<'static>>,
-    lambdas: &'a HashMap<usize, LiftedLambda>,
+    /// the symbols used, their objects are emitted once for the program
+    symbols: BTreeSet<String>,
+    /// the quoted lists used by their names, emitted once like the symbols
+    quoted: BTreeMap<String, Arc<Vec<Literal>>>,
+    /// the foreign functions called, their wrappers are emitted once for the
+    /// program
+    foreign: BTreeMap<String, Foreign>,
+    /// the lambda whose body is being generated
+    current: Option<usize>,
+    /// the local holding each of those in the function being generated
+    locals: HashMap<FreeVar<String>, String>,
+    /// the local holding each shared expression made in the enclosing blocks
+    shared: HashMap<String, String>,
+    /// the parameters of the function being generated
+    params: Vec<String>,
+    /// whether the function jumps back to its start
+    loops: bool,
+}
+
+/// What generating a function made.
+struct Generated {
+    protos: Vec<CDecl<'static>>,
+    declarations: Vec<CDecl<'static>>,
+    symbols: BTreeSet<String>,
+    quoted: BTreeMap<String, Arc<Vec<Literal>>>,
+    foreign: BTreeMap<String, Foreign>,
 }
 
 impl<'a> CodegenCtx<'a> {
-    pub fn new(lambdas: &'a HashMap<usize, LiftedLambda>) -> Self {
+    fn new(program: &'a Program<'a>) -> Self {
         Self {
+            program,
             unique_var_id: 0,
             protos: Vec::new(),
             declarations: Vec::new(),
-            lambdas,
+            symbols: BTreeSet::new(),
+            quoted: BTreeMap::new(),
+            foreign: BTreeMap::new(),
+            current: None,
+            locals: HashMap::new(),
+            shared: HashMap::new(),
+            params: Vec::new(),
+            loops: false,
+        }
+    }
+
+    fn finish(self) -> Generated {
+        Generated {
+            protos: self.protos,
+            declarations: self.declarations,
+            symbols: self.symbols,
+            quoted: self.quoted,
+            foreign: self.foreign,
         }
     }
 
@@ -46,6 +151,449 @@ impl<'a> CodegenCtx<'a> {
     fn add_decl(&mut self, decl: CDecl<'static>) {
         self.declarations.push(decl);
     }
+
+    /// The name of the wrapper calling a foreign function from a closure.
+    fn foreign_wrapper(&mut self, foreign: &Foreign) -> String {
+        self.foreign
+            .entry(foreign.name.clone())
+            .or_insert_with(|| foreign.clone());
+
+        foreign.wrapper_name()
+    }
+
+    /// Symbols are emitted as static objects, one per distinct name, so that
+    /// each use of a symbol is the same object.
+    fn symbol_var(&mut self, name: &str) -> String {
+        self.symbols.insert(name.to_owned());
+        symbol_var_name(name)
+    }
+
+    /// Quoted lists are emitted as static objects, one per distinct list, so
+    /// that evaluating a quote makes nothing.
+    fn quoted_var(&mut self, items: &Arc<Vec<Literal>>) -> String {
+        let name = quoted_var_name(items);
+
+        if !self.quoted.contains_key(&name) {
+            for item in items.iter() {
+                match item {
+                    Literal::Symbol(s) => {
+                        self.symbol_var(s);
+                    }
+                    Literal::Quoted(items) => {
+                        self.quoted_var(items);
+                    }
+                    _ => {}
+                }
+            }
+
+            self.quoted.insert(name.clone(), items.clone());
+        }
+
+        name
+    }
+
+    /// The environment holding `var`, walking up linked environments until
+    /// one that holds it, as its `env_obj` and as the struct of its lambda.
+    fn env_holding(&self, var: &FreeVar<String>) -> (Arc<CExpr<'static>>, Arc<CExpr<'static>>) {
+        let lambdas = self.program.lambdas;
+        let mut obj = Arc::new(CExpr::Ident("env_in".into()));
+        let mut env = Arc::new(CExpr::Ident("env".into()));
+        let mut lambda = &lambdas[&self.current.expect("variable outside of any lambda")];
+
+        while !lambda.holds(var) {
+            let parent = &lambdas[&lambda.parent.expect("variable not in any environment")];
+            obj = Arc::new(CExpr::Cast {
+                typ: CType::Ptr(Arc::new(CType::Struct("env_obj".into()))),
+                ex: Arc::new(CExpr::Arrow {
+                    expr: env,
+                    attr: "parent".into(),
+                }),
+            });
+            env = Arc::new(parent.generate_env_cast(obj.clone()));
+            lambda = parent;
+        }
+
+        (obj, env)
+    }
+
+    /// The member of the current environment holding `var` or its cell.
+    fn env_slot(&self, var: &FreeVar<String>) -> CExpr<'static> {
+        if let Some(local) = self.locals.get(var) {
+            return CExpr::Ident(local.clone().into());
+        }
+
+        CExpr::Arrow {
+            expr: self.env_holding(var).1,
+            attr: self.var_name(var).into(),
+        }
+    }
+
+    /// Where the value of `var` is, which is its slot unless it is boxed.
+    fn var_place(&self, var: &FreeVar<String>) -> CExpr<'static> {
+        let slot = self.env_slot(var);
+
+        if !self.program.boxed.contains(var) {
+            return slot;
+        }
+
+        CExpr::Arrow {
+            expr: Arc::new(CExpr::Cast {
+                typ: CType::Ptr(Arc::new(CType::Struct("cell_obj".into()))),
+                ex: Arc::new(slot),
+            }),
+            attr: "val".into(),
+        }
+    }
+
+    /// The object that `var_place` is in, which is given to the write barrier
+    /// after a `set!`, or none when the place is a local.
+    fn var_holder(&self, var: &FreeVar<String>) -> Option<Arc<CExpr<'static>>> {
+        if self.program.boxed.contains(var) {
+            Some(Arc::new(self.env_slot(var)))
+        } else if self.locals.contains_key(var) {
+            None
+        } else {
+            Some(self.env_holding(var).0)
+        }
+    }
+
+    fn var_name(&self, var: &FreeVar<String>) -> String {
+        self.program.var_name(var)
+    }
+}
+
+/// The text of a C string literal holding `s`, the bytes that aren't printable
+/// ASCII written as octal escapes, which can't take in the digits after them.
+fn c_string(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'\\' | b'"' => format!("\\{}", b as char),
+            b' '..=b'~' => (b as char).to_string(),
+            b => format!("\\{:03o}", b),
+        })
+        .collect()
+}
+
+fn symbol_var_name(name: &str) -> String {
+    format!("symbol_{}", mangle_exact(name))
+}
+
+fn symbol_object(name: &str, module: Option<&Module>) -> CDecl<'static> {
+    // modules each have their own copy of a symbol, which the linker merges
+    // so that they stay `eq?`
+    let storage = match module {
+        Some(_) => CType::Weak,
+        None => CType::Static,
+    };
+
+    CDecl::Var {
+        name: symbol_var_name(name).into(),
+        typ: storage(Arc::new(CType::Struct("symbol_obj".into()))),
+        init: Some(CExpr::MacroCall {
+            name: "SYMBOL_OBJ_INIT".into(),
+            args: vec![Arc::new(CExpr::LitStr(c_string(name).into()))],
+        }),
+    }
+}
+
+/// Named for what is in the list, so that the lambdas generated apart agree.
+fn quoted_var_name(items: &[Literal]) -> String {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(format!("{:?}", items).as_bytes());
+
+    format!("quoted_{:016x}", hasher.finish())
+}
+
+/// The static objects of a quoted list and of the lists in it, those it
+/// points to coming first.
+fn quoted_objects(
+    items: &[Literal],
+    target: &TargetConfig,
+    emitted: &mut BTreeSet<String>,
+    decls: &mut Vec<CDecl<'static>>,
+) {
+    let name = quoted_var_name(items);
+
+    if !emitted.insert(name.clone()) {
+        return;
+    }
+
+    let static_var = |name: String, typ: &str, init: &str, args: Vec<CExpr<'static>>| CDecl::Var {
+        name: name.into(),
+        typ: CType::Static(Arc::new(CType::Struct(typ.to_owned().into()))),
+        init: Some(CExpr::MacroCall {
+            name: init.to_owned().into(),
+            args: args
+                .into_iter()
+                .map(|a| Arc::new(a) as Arc<dyn ToCDC + Send + Sync>)
+                .collect(),
+        }),
+    };
+    let address = |name: String| CExpr::Cast {
+        typ: object_type(),
+        ex: Arc::new(CExpr::PreUnOp {
+            op: "&".into(),
+            ex: Arc::new(CExpr::Ident(name.into())),
+        }),
+    };
+
+    let mut cdr = CExpr::Ident("NULL".into());
+
+    for (i, item) in items.iter().enumerate().rev() {
+        let boxed = format!("{}_item_{}", name, i);
+        let car = match item {
+            Literal::Int(n) if target.fixnum_fits(*n) => CExpr::MacroCall {
+                name: "MAKE_FIXNUM".into(),
+                args: vec![Arc::new(CExpr::LitIInt(*n as isize))],
+            },
+            Literal::Int(n) => {
+                decls.push(static_var(
+                    boxed.clone(),
+                    "int_obj",
+                    "INT_OBJ_INIT",
+                    vec![CExpr::LitIInt(*n as isize)],
+                ));
+                address(boxed)
+            }
+            Literal::Rational(n, d) => {
+                decls.push(static_var(
+                    boxed.clone(),
+                    "rational_obj",
+                    "RATIONAL_OBJ_INIT",
+                    vec![CExpr::LitIInt(*n as isize), CExpr::LitIInt(*d as isize)],
+                ));
+                address(boxed)
+            }
+            Literal::Float(f) => {
+                decls.push(static_var(
+                    boxed.clone(),
+                    "float_obj",
+                    "FLOAT_OBJ_INIT",
+                    vec![CExpr::LitFloat(*f)],
+                ));
+                address(boxed)
+            }
+            Literal::String(s) => {
+                decls.push(static_var(
+                    boxed.clone(),
+                    "string_obj",
+                    "STRING_OBJ_INIT",
+                    vec![CExpr::LitStr(c_string(&unescape(s)).into())],
+                ));
+                address(boxed)
+            }
+            Literal::Bool(b) => address(if *b { "scheme_true" } else { "scheme_false" }.to_owned()),
+            Literal::Symbol(s) => address(symbol_var_name(s)),
+            Literal::Void => CExpr::Ident("NULL".into()),
+            Literal::Quoted(items) => {
+                quoted_objects(items, target, emitted, decls);
+                address(quoted_var_name(items))
+            }
+        };
+
+        let cell = match i {
+            0 => name.clone(),
+            i => format!("{}_{}", name, i),
+        };
+        decls.push(static_var(
+            cell.clone(),
+            "cons_obj",
+            "CONS_OBJ_INIT",
+            vec![car, cdr],
+        ));
+        cdr = address(cell);
+    }
+}
+
+/// The table of every symbol in the program, the runtime interns these on
+/// startup so that `string->symbol` finds them.
+fn symbol_table(symbols: &BTreeSet<String>, module: Option<&Module>) -> CDecl<'static> {
+    let mut vars = symbols
+        .iter()
+        .map(|s| symbol_var_name(s))
+        .collect::<Vec<_>>();
+    vars.sort();
+
+    let entries = vars
+        .into_iter()
+        .map(|v| CExpr::PreUnOp {
+            op: "&".into(),
+            ex: Arc::new(CExpr::Ident(v.into())),
+        })
+        .chain(iter::once(CExpr::Ident("NULL".into())))
+        .collect();
+
+    let typ = CType::Arr(
+        Arc::new(CType::Ptr(Arc::new(CType::Struct("symbol_obj".into())))),
+        None,
+    );
+
+    // the link unit registers the table of each module
+    let (name, typ) = match module {
+        Some(module) => (module.symbols().into(), typ),
+        None => ("scheme_symbols".into(), CType::Static(Arc::new(typ))),
+    };
+
+    CDecl::Var {
+        name,
+        typ,
+        init: Some(CExpr::InitList(entries)),
+    }
+}
+
+/// The table of where each lambda is, which the runtime names the lambda
+/// running with in the errors it stops the program with.
+fn lambda_table(declarations: &[CDecl<'static>], module: Option<&Module>) -> CDecl<'static> {
+    let entries = declarations
+        .iter()
+        .filter_map(|decl| match decl {
+            CDecl::Fun {
+                name,
+                source: Some((file, line)),
+                ..
+            } => Some(CExpr::InitList(vec![
+                CExpr::Cast {
+                    typ: CType::Ptr(Arc::new(CType::Void)),
+                    ex: Arc::new(CExpr::Ident(name.clone())),
+                },
+                CExpr::LitStr(name.clone()),
+                CExpr::LitStr(file.clone()),
+                CExpr::LitUInt(*line),
+            ])),
+            _ => None,
+        })
+        .chain(iter::once(CExpr::InitList(vec![CExpr::Ident(
+            "NULL".into(),
+        )])))
+        .collect();
+
+    let typ = CType::Arr(Arc::new(CType::Struct("lambda_info".into())), None);
+
+    let (name, typ) = match module {
+        Some(module) => (module.lambdas().into(), typ),
+        None => ("scheme_lambdas".into(), CType::Static(Arc::new(typ))),
+    };
+
+    CDecl::Var {
+        name,
+        typ,
+        init: Some(CExpr::InitList(entries)),
+    }
+}
+
+/// Declare a foreign function and the wrapper calling it from a closure,
+/// returning the prototypes and the wrapper.
+fn foreign_wrapper(foreign: &Foreign) -> (Vec<CDecl<'static>>, CDecl<'static>) {
+    let wrapper = foreign.wrapper_name();
+
+    let mut params = foreign
+        .params
+        .iter()
+        .map(|p| CType::Other(p.c_param_type().into()))
+        .collect::<Vec<_>>();
+
+    if params.is_empty() {
+        params.push(CType::Void);
+    }
+
+    let obj_s = Arc::new(CType::Struct("obj".into()));
+    let env_obj_s = Arc::new(CType::Struct("env_obj".into()));
+
+    let protos = vec![
+        CDecl::FunProto {
+            name: foreign.name.clone().into(),
+            typ: CType::Other(foreign.ret.c_return_type().into()),
+            args: params,
+            noreturn: false,
+        },
+        CDecl::FunProto {
+            name: wrapper.clone().into(),
+            typ: CType::Static(Arc::new(CType::Void)),
+            args: vec![
+                CType::Ptr(obj_s.clone()),
+                CType::Ptr(obj_s.clone()),
+                CType::Ptr(env_obj_s.clone()),
+            ],
+            noreturn: true,
+        },
+    ];
+
+    // the arguments are unboxed in order before the call, as C doesn't fix
+    // the order in which the arguments of a call are evaluated
+    let mut body = Vec::new();
+    let mut args = Vec::new();
+
+    for (i, param) in foreign.params.iter().enumerate() {
+        let name = format!("arg_{}", i);
+        let next_arg = CExpr::FunCallOp {
+            expr: Arc::new(CExpr::Ident("foreign_next_arg".into())),
+            params: vec![Arc::new(CExpr::PreUnOp {
+                op: "&".into(),
+                ex: Arc::new(CExpr::Ident("args".into())),
+            })],
+        };
+
+        body.push(Arc::new(CStmt::Decl(CDecl::Var {
+            name: name.clone().into(),
+            typ: CType::Other(param.c_param_type().into()),
+            init: Some(CExpr::FunCallOp {
+                expr: Arc::new(CExpr::Ident(param.unboxer().into())),
+                params: vec![
+                    Arc::new(next_arg),
+                    Arc::new(CExpr::LitStr(foreign.name.clone().into())),
+                ],
+            }),
+        })));
+        args.push(Arc::new(CExpr::Ident(name.into())));
+    }
+
+    let call = CExpr::FunCallOp {
+        expr: Arc::new(CExpr::Ident(foreign.name.clone().into())),
+        params: args,
+    };
+
+    body.push(Arc::new(CStmt::Expr(CExpr::MacroCall {
+        name: foreign.ret.returner().into(),
+        args: vec![Arc::new(CExpr::Ident("k".into())), Arc::new(call)],
+    })));
+    body.push(Arc::new(CStmt::Expr(CExpr::MacroCall {
+        name: "__builtin_unreachable".into(),
+        args: vec![],
+    })));
+
+    let fun = CDecl::Fun {
+        name: wrapper.into(),
+        typ: CType::Static(Arc::new(CType::Void)),
+        args: vec![
+            ("args".into(), CType::Ptr(obj_s.clone())),
+            ("k".into(), CType::Ptr(obj_s)),
+            ("env".into(), CType::Ptr(env_obj_s)),
+        ],
+        body,
+        source: None,
+    };
+
+    (protos, fun)
+}
+
+/// Variables are named after their binder, numbered only when a name is
+/// reused, in the order the lambdas binding them were lifted.
+fn var_names(lambdas: &BTreeMap<usize, LiftedLambda>) -> HashMap<FreeVar<String>, String> {
+    let mut ids = lambdas.keys().collect::<Vec<_>>();
+    ids.sort();
+
+    let mut taken = TakenNames::default();
+    let mut names = HashMap::new();
+
+    for param in ids.into_iter().flat_map(|id| lambdas[id].params.iter()) {
+        let base = format!(
+            "v_{}",
+            mangle(param.pretty_name.as_deref().unwrap_or("anon"))
+        );
+        names.insert(param.clone(), unique_name(&mut taken, base));
+    }
+
+    names
 }
 
 fn name_for_free_var(var: &FreeVar<String>) -> String {
@@ -61,19 +609,44 @@ fn name_for_free_var(var: &FreeVar<String>) -> String {
 }
 
 fn object_type() -> CType<'static> {
-    CType::Ptr(Rc::new(CType::Struct("obj".into())))
+    CType::Ptr(Arc::new(CType::Struct("obj".into())))
 }
 
 impl LiftedLambda {
-    fn env_struct(&self) -> CDecl<'static> {
-        let members = self
-            .freevars
-            .iter()
-            .map(|v| (name_for_free_var(v).into(), object_type()))
+    /// Whether `var` is a member of the environment struct, the parameters
+    /// kept in locals aren't.
+    fn in_env(&self, var: &FreeVar<String>, program: &Program) -> bool {
+        self.holds(var)
+            && !(program.local_params.contains(var) && self.params.iter().any(|p| p == var))
+    }
+
+    /// Whether the closures of this lambda need an environment, those that
+    /// would have nothing in it are given NULL.
+    fn has_env(&self, program: &Program) -> bool {
+        self.env_repr == EnvRepr::Linked || self.freevars.iter().any(|v| self.in_env(v, program))
+    }
+
+    /// Each variable held by the environment gets its own member, so a
+    /// variable reference is a constant offset load from `env` or from one of
+    /// the linked environments above it.
+    fn env_struct(&self, program: &Program) -> CDecl<'static> {
+        let parent = match self.env_repr {
+            EnvRepr::Flat => None,
+            EnvRepr::Linked => Some(("parent".into(), object_type())),
+        };
+
+        let members = parent
+            .into_iter()
+            .chain(
+                self.env_slots()
+                    .into_iter()
+                    .filter(|v| self.in_env(v, program))
+                    .map(|v| (program.var_name(&v).into(), object_type())),
+            )
             .collect();
 
         CDecl::Struct {
-            name: format!("env_{}", self.id).into(),
+            name: format!("{}_env", self.name).into(),
             members,
         }
     }
@@ -82,41 +655,52 @@ impl LiftedLambda {
         CStmt::Expr(CExpr::MacroCall {
             name: "OBJECT_ENV_OBJ_NEW".into(),
             args: vec![
-                Rc::new(CExpr::Ident(dest.to_owned().into())),
-                Rc::new(CType::Struct(format!("env_{}", self.id).into())),
+                Arc::new(CExpr::Ident(dest.to_owned().into())),
+                Arc::new(CType::Struct(format!("{}_env", self.name).into())),
             ],
         })
     }
 
     fn make_env_code(
         &self,
-        src_env: &Rc<CExpr<'static>>,
         ctx: &mut CodegenCtx,
-        supporting_stmts: &mut Vec<Rc<CStmt<'static>>>,
-    ) -> Rc<CExpr<'static>> {
+        supporting_stmts: &mut Vec<Arc<CStmt<'static>>>,
+    ) -> Arc<CExpr<'static>> {
+        if !self.has_env(ctx.program) {
+            return Arc::new(CExpr::Ident("NULL".into()));
+        }
+
         let var_name = ctx.gen_var();
 
-        supporting_stmts.push(Rc::new(self.construct_env_code(&var_name)));
+        supporting_stmts.push(Arc::new(self.construct_env_code(&var_name)));
 
-        let env_expr = Rc::new(CExpr::Ident(var_name.into()));
-        let env_access = Rc::new(self.generate_env_cast(env_expr.clone()));
+        let env_expr = Arc::new(CExpr::Ident(var_name.into()));
+        let env_access = Arc::new(self.generate_env_cast(env_expr.clone()));
+
+        if self.env_repr == EnvRepr::Linked {
+            supporting_stmts.push(Arc::new(CStmt::Expr(CExpr::BinOp {
+                op: "=".into(),
+                left: Arc::new(CExpr::Arrow {
+                    expr: env_access,
+                    attr: "parent".into(),
+                }),
+                right: Arc::new(CExpr::Cast {
+                    typ: object_type(),
+                    ex: Arc::new(CExpr::Ident("env_in".into())),
+                }),
+            })));
 
-        let mut vars_to_copy = self.freevars.clone();
-        for param in &self.params {
-            vars_to_copy.remove(param);
+            return env_expr;
         }
 
-        for var in &vars_to_copy {
-            supporting_stmts.push(Rc::new(CStmt::Expr(CExpr::BinOp {
+        for var in self.captures() {
+            supporting_stmts.push(Arc::new(CStmt::Expr(CExpr::BinOp {
                 op: "=".into(),
-                left: Rc::new(CExpr::Arrow {
+                left: Arc::new(CExpr::Arrow {
                     expr: env_access.clone(),
-                    attr: name_for_free_var(var).into(),
-                }),
-                right: Rc::new(CExpr::Arrow {
-                    expr: src_env.clone(),
-                    attr: name_for_free_var(var).into(),
+                    attr: ctx.var_name(var).into(),
                 }),
+                right: Arc::new(ctx.env_slot(var)),
             })));
         }
 
@@ -125,16 +709,24 @@ impl LiftedLambda {
 
     fn generate_closure(
         &self,
-        current_env: &Rc<CExpr<'static>>,
         ctx: &mut CodegenCtx,
-        supporting_stmts: &mut Vec<Rc<CStmt<'static>>>,
+        supporting_stmts: &mut Vec<Arc<CStmt<'static>>>,
     ) -> CExpr<'static> {
-        let env_expr = self.make_env_code(current_env, ctx, supporting_stmts);
+        if !self.has_env(ctx.program) {
+            return CExpr::Cast {
+                typ: object_type(),
+                ex: Arc::new(CExpr::PreUnOp {
+                    op: "&".into(),
+                    ex: Arc::new(CExpr::Ident(self.static_closure_name().into())),
+                }),
+            };
+        }
 
-        let init_name = match self.params.len() {
-            1 => "OBJECT_CLOSURE_ONE_NEW",
-            2 => "OBJECT_CLOSURE_TWO_NEW",
-            n => panic!("closure was not one or two parameters, was: {}", n),
+        let env_expr = self.make_env_code(ctx, supporting_stmts);
+
+        let init_name = match self.params {
+            LambdaParams::One(_) => "OBJECT_CLOSURE_ONE_NEW",
+            LambdaParams::Two(_, _) => "OBJECT_CLOSURE_TWO_NEW",
         };
 
         let var_name = ctx.gen_var();
@@ -142,22 +734,48 @@ impl LiftedLambda {
         let init_stmt = CStmt::Expr(CExpr::MacroCall {
             name: init_name.into(),
             args: vec![
-                Rc::new(CExpr::Ident(var_name.to_owned().into())),
-                Rc::new(CExpr::Ident(format!("lambda_{}", self.id).into())),
+                Arc::new(CExpr::Ident(var_name.to_owned().into())),
+                Arc::new(CExpr::Ident(self.name.clone().into())),
                 env_expr,
             ],
         });
 
-        supporting_stmts.push(Rc::new(init_stmt));
+        supporting_stmts.push(Arc::new(init_stmt));
 
         CExpr::Ident(var_name.into())
     }
 
-    fn generate_env_cast(&self, in_expr: Rc<CExpr<'static>>) -> CExpr<'static> {
+    fn static_closure_name(&self) -> String {
+        format!("{}_closure", self.name)
+    }
+
+    /// A closure without an environment is the same wherever it's made, so
+    /// the lambda's one closure is a static object that every maker shares.
+    fn static_closure(&self, init: bool) -> CDecl<'static> {
+        let init_name = match self.params {
+            LambdaParams::One(_) => "CLOSURE_ONE_OBJ_INIT",
+            LambdaParams::Two(_, _) => "CLOSURE_TWO_OBJ_INIT",
+        };
+
+        CDecl::Var {
+            name: self.static_closure_name().into(),
+            typ: CType::Static(Arc::new(CType::Struct("closure_obj".into()))),
+            init: if init {
+                Some(CExpr::MacroCall {
+                    name: init_name.into(),
+                    args: vec![Arc::new(CExpr::Ident(self.name.clone().into()))],
+                })
+            } else {
+                None
+            },
+        }
+    }
+
+    fn generate_env_cast(&self, in_expr: Arc<CExpr<'static>>) -> CExpr<'static> {
         CExpr::Cast {
-            ex: Rc::new(CExpr::PreUnOp {
+            ex: Arc::new(CExpr::PreUnOp {
                 op: "&".into(),
-                ex: Rc::new(CExpr::Arrow {
+                ex: Arc::new(CExpr::Arrow {
                     expr: in_expr,
                     attr: "env".into(),
                 }),
@@ -167,7 +785,28 @@ impl LiftedLambda {
     }
 
     fn generate_env_ptr_typ(&self) -> CType<'static> {
-        CType::Ptr(Rc::new(CType::Struct(format!("env_{}", self.id).into())))
+        CType::Ptr(Arc::new(CType::Struct(format!("{}_env", self.name).into())))
+    }
+
+    /// The function of the lambda, with its environment struct. Temporaries
+    /// are numbered per function, so an edit to one lambda doesn't rename
+    /// those of every other.
+    fn generate(&self, program: &Program) -> Generated {
+        let mut ctx = CodegenCtx::new(program);
+
+        if self.has_env(program) {
+            ctx.add_proto(self.env_struct(program));
+        }
+
+        self.generate_func(&mut ctx);
+
+        // declared with the prototypes, so that the makers before it see it
+        if !self.has_env(program) {
+            ctx.add_proto(self.static_closure(false));
+            ctx.add_decl(self.static_closure(true));
+        }
+
+        ctx.finish()
     }
 
     fn generate_func(&self, ctx: &mut CodegenCtx) {
@@ -177,8 +816,8 @@ impl LiftedLambda {
             .map(|p| (p.clone(), ctx.gen_var()))
             .collect::<Vec<_>>();
 
-        let env_obj_s = Rc::new(CType::Struct("env_obj".into()));
-        let obj_s = Rc::new(CType::Struct("obj".into()));
+        let env_obj_s = Arc::new(CType::Struct("env_obj".into()));
+        let obj_s = Arc::new(CType::Struct("obj".into()));
 
         let (mut with_names, mut types_only): (Vec<_>, Vec<_>) = params
             .iter()
@@ -193,24 +832,30 @@ impl LiftedLambda {
         with_names.push(("env_in".into(), CType::Ptr(env_obj_s.clone())));
         types_only.push(CType::Ptr(env_obj_s.clone()));
 
+        // lambdas are private to the file, so separately compiled files can
+        // reuse their names
         let proto = CDecl::FunProto {
-            name: format!("lambda_{}", self.id).into(),
-            typ: CType::Void,
+            name: self.name.clone().into(),
+            typ: CType::Static(Arc::new(CType::Void)),
             args: types_only,
             noreturn: true,
         };
 
         ctx.add_proto(proto);
 
-        let env_move_stmt = Rc::new(CStmt::Decl(CDecl::Var {
-            name: "env".into(),
-            typ: self.generate_env_ptr_typ(),
-            init: Some(self.generate_env_cast(Rc::new(CExpr::Ident("env_in".into())))),
-        }));
+        let env_expr = Arc::new(CExpr::Ident("env".into()));
 
-        let env_expr = Rc::new(CExpr::Ident("env".into()));
+        let mut stmts: Vec<Arc<CStmt<'static>>> = Vec::new();
 
-        let mut stmts: Vec<Rc<CStmt<'static>>> = vec![env_move_stmt];
+        // a lambda whose closures are made without an environment is given
+        // NULL, and never touches it
+        if self.has_env(ctx.program) {
+            stmts.push(Arc::new(CStmt::Decl(CDecl::Var {
+                name: "env".into(),
+                typ: self.generate_env_ptr_typ(),
+                init: Some(self.generate_env_cast(Arc::new(CExpr::Ident("env_in".into())))),
+            })));
+        }
 
         for (dest_var, in_var) in &params {
             if !self.freevars.contains(dest_var) {
@@ -218,77 +863,239 @@ impl LiftedLambda {
                 continue;
             }
 
-            let tmp_name = ctx.gen_var();
-            let tmp_var = Rc::new(CExpr::Ident(tmp_name.into()));
+            let mut value = in_var.to_owned();
 
-            stmts.push(Rc::new(CStmt::Expr(CExpr::MacroCall {
-                name: "OBJECT_CELL_OBJ_NEW".into(),
-                args: vec![
-                    tmp_var.clone(),
-                    Rc::new(CExpr::Ident(in_var.to_owned().into())),
-                ],
-            })));
+            if ctx.program.boxed.contains(dest_var) {
+                let cell = ctx.gen_var();
+
+                stmts.push(Arc::new(CStmt::Expr(CExpr::MacroCall {
+                    name: "OBJECT_CELL_OBJ_NEW".into(),
+                    args: vec![
+                        Arc::new(CExpr::Ident(cell.clone().into())),
+                        Arc::new(CExpr::Ident(value.into())),
+                    ],
+                })));
+
+                value = cell;
+            }
 
-            stmts.push(Rc::new(CStmt::Expr(CExpr::BinOp {
+            if ctx.program.local_params.contains(dest_var) {
+                ctx.locals.insert(dest_var.clone(), value);
+                continue;
+            }
+
+            stmts.push(Arc::new(CStmt::Expr(CExpr::BinOp {
                 op: "=".into(),
-                left: Rc::new(CExpr::Arrow {
+                left: Arc::new(CExpr::Arrow {
                     expr: env_expr.clone(),
-                    attr: name_for_free_var(dest_var).into(),
+                    attr: ctx.var_name(dest_var).into(),
                 }),
-                right: tmp_var,
+                right: Arc::new(CExpr::Ident(value.clone().into())),
+            })));
+
+            // the env was made with the closure, which may be on the heap by now
+            stmts.push(Arc::new(CStmt::Expr(CExpr::MacroCall {
+                name: "GC_WRITE_BARRIER".into(),
+                args: vec![
+                    Arc::new(CExpr::Ident("env_in".into())),
+                    Arc::new(CExpr::Ident(value.into())),
+                ],
             })));
         }
 
+        ctx.current = Some(self.id);
+        ctx.params = params.iter().map(|(_, n)| n.clone()).collect();
+        let outer = share_common(&self.body, ctx, &mut stmts);
         let final_expr = do_codegen_internal(&self.body, ctx, &mut stmts);
-        stmts.push(Rc::new(CStmt::Expr(final_expr)));
+        ctx.shared = outer;
+        stmts.push(Arc::new(CStmt::Expr(final_expr)));
+
+        if ctx.loops {
+            stmts.insert(0, Arc::new(CStmt::Label(SELF_CALL_LABEL.into())));
+        }
 
-        stmts.push(Rc::new(CStmt::Expr(CExpr::MacroCall {
+        stmts.push(Arc::new(CStmt::Expr(CExpr::MacroCall {
             name: "__builtin_unreachable".into(),
             args: vec![],
         })));
 
         let fun = CDecl::Fun {
-            name: format!("lambda_{}", self.id).into(),
-            typ: CType::Void,
+            name: self.name.clone().into(),
+            typ: CType::Static(Arc::new(CType::Void)),
             args: with_names,
             body: stmts,
+            // lambdas outside of any written lambda are part of the toplevel,
+            // which starts at the first line
+            source: Some((
+                ctx.program.source_file.to_owned().into(),
+                ctx.program.source_lines[&self.id].unwrap_or(1),
+            )),
         };
 
         ctx.add_decl(fun);
     }
 }
 
+/// Where a lambda calling itself jumps to.
+const SELF_CALL_LABEL: &str = "self_call";
+
+/// Fewer lambdas than this for each thread are generated on the calling thread.
+const LAMBDAS_PER_THREAD: usize = 64;
+
 pub fn do_codegen(
-    e: LExpr,
-    lambdas: &HashMap<usize, LiftedLambda>,
+    lifted: &lifted_expr::Program,
+    source_file: &str,
+    module: Option<&Module>,
+    globals: &HashMap<FreeVar<String>, String>,
+    target: &TargetConfig,
+    jobs: usize,
 ) -> (
-    Vec<Rc<CStmt<'static>>>,
+    Vec<Arc<CStmt<'static>>>,
     Vec<CDecl<'static>>,
     Vec<CDecl<'static>>,
 ) {
-    let mut ctx = CodegenCtx::new(lambdas);
+    let (e, lambdas) = (lifted.entry(), lifted.lambdas());
+
+    // globals can be set by the other inputs
+    let mut known = known_lambdas(e, lambdas);
+    known.retain(|v, _| !globals.contains_key(v));
+
+    let program = Program {
+        lambdas,
+        var_names: var_names(lambdas),
+        source_file,
+        globals,
+        boxed: boxed_vars(e, lambdas),
+        local_params: local_params(lambdas),
+        known,
+        target,
+        spans: lambda_spans(lambdas),
+        source_lines: source_lines(lambdas),
+    };
+
+    let mut generated = generate_funcs(&program, jobs);
+
+    let mut ctx = CodegenCtx::new(&program);
     let mut stmts = Vec::new();
+    let final_expr = do_codegen_internal(e, &mut ctx, &mut stmts);
+    stmts.push(Arc::new(CStmt::Expr(final_expr)));
+    generated.push(ctx.finish());
+
+    // merged in the order of the lambdas, so the output doesn't depend on
+    // which thread finished first
+    let mut protos = Vec::new();
+    let mut declarations = Vec::new();
+    let mut symbols = BTreeSet::new();
+    let mut quoted = BTreeMap::new();
+    let mut foreign = BTreeMap::new();
+
+    for g in generated {
+        protos.extend(g.protos);
+        declarations.extend(g.declarations);
+        symbols.extend(g.symbols);
+        quoted.extend(g.quoted);
+        foreign.extend(g.foreign);
+    }
 
-    for lambda in lambdas.values() {
-        ctx.add_proto(lambda.env_struct());
-        lambda.generate_func(&mut ctx);
+    for f in foreign.values() {
+        let (wrapper_protos, wrapper) = foreign_wrapper(f);
+        protos.extend(wrapper_protos);
+        declarations.push(wrapper);
     }
 
-    let final_expr = do_codegen_internal(&e, &mut ctx, &mut stmts);
-    stmts.push(Rc::new(CStmt::Expr(final_expr)));
+    protos.extend(symbols.iter().map(|s| symbol_object(s, module)));
+    protos.push(symbol_table(&symbols, module));
+
+    let mut emitted = BTreeSet::new();
+    for items in quoted.values() {
+        quoted_objects(items, target, &mut emitted, &mut protos);
+    }
+    protos.push(lambda_table(&declarations, module));
 
-    (stmts, ctx.protos, ctx.declarations)
+    (stmts, protos, declarations)
+}
+
+/// The function of each lambda, in order. Each is generated on its own, so
+/// large programs split the lambdas between up to `jobs` threads, 0 being as
+/// many as there are cores.
+fn generate_funcs(program: &Program, jobs: usize) -> Vec<Generated> {
+    let lambdas = program.lambdas.values().collect::<Vec<_>>();
+    let jobs = match jobs {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        jobs => jobs,
+    };
+    let threads = jobs.min(lambdas.len() / LAMBDAS_PER_THREAD).max(1);
+
+    if threads == 1 {
+        return lambdas.iter().map(|l| l.generate(program)).collect();
+    }
+
+    thread::scope(|scope| {
+        let handles = lambdas
+            .chunks(lambdas.len().div_ceil(threads))
+            .map(|chunk| {
+                thread::Builder::new()
+                    .stack_size(STACK_SIZE)
+                    .spawn_scoped(scope, move || {
+                        chunk
+                            .iter()
+                            .map(|l| l.generate(program))
+                            .collect::<Vec<_>>()
+                    })
+                    .expect("couldn't start a codegen thread")
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    })
 }
 
 fn builtin_ident_codegen(
     ident: &str,
     ctx: &mut CodegenCtx,
-    supporting_stmts: &mut Vec<Rc<CStmt<'static>>>,
+    supporting_stmts: &mut Vec<Arc<CStmt<'static>>>,
 ) -> CExpr<'static> {
-    let (num_params, runtime_name) = match ident {
+    let foreign = Foreign::from_builtin_name(ident).map(|f| ctx.foreign_wrapper(&f));
+
+    let (num_params, runtime_name) = match &foreign {
+        Some(wrapper) => (2, wrapper.as_str()),
+        None => runtime_builtin(ident),
+    };
+
+    let init_name = match num_params {
+        1 => "OBJECT_CLOSURE_ONE_NEW",
+        2 => "OBJECT_CLOSURE_TWO_NEW",
+        n => panic!("closure was not one or two parameters, was: {}", n),
+    };
+
+    let var_name = ctx.gen_var();
+
+    let init_stmt = CStmt::Expr(CExpr::MacroCall {
+        name: init_name.into(),
+        args: vec![
+            Arc::new(CExpr::Ident(var_name.to_owned().into())),
+            Arc::new(CExpr::Ident(runtime_name.to_owned().into())),
+            Arc::new(CExpr::Ident("NULL".into())),
+        ],
+    });
+
+    supporting_stmts.push(Arc::new(init_stmt));
+
+    CExpr::Ident(var_name.into())
+}
+
+/// The number of parameters and the name of the runtime function of a builtin,
+/// the closures of builtins taking arguments take their continuation too.
+pub fn runtime_builtin(ident: &str) -> (usize, &'static str) {
+    match ident {
         "tostring" => (2, "to_string_k"), // these are two-param because they take the cont param
         "display" => (2, "display_k"),
-        "exit" => (1, "exit_k"),
+        "halt" => (1, "halt_k"),
+        "exit" => (2, "exit_k"),
+        "module-done" => (1, "module_done_k"),
         "+" => (2, "add_k"),
         "-" => (2, "sub_k"),
         "*" => (2, "mul_k"),
@@ -299,152 +1106,499 @@ fn builtin_ident_codegen(
         "<=" => (2, "leq_k"),
         ">" => (2, "gt_k"),
         ">=" => (2, "geq_k"),
+        "=" => (2, "num_eq_k"),
+        "zero?" => (2, "is_zero_k"),
+        "number?" => (2, "is_number_k"),
         "cons" => (2, "cons_k"),
-        "cons?" => (2, "is_cons_k"),
+        "cons?" | "pair?" => (2, "is_cons_k"),
+        "procedure?" => (2, "is_procedure_k"),
+        "string?" => (2, "is_string_k"),
+        "boolean?" => (2, "is_boolean_k"),
         "null?" => (2, "is_null_k"),
         "car" => (2, "car_k"),
         "cdr" => (2, "cdr_k"),
         "string-concat" => (2, "string_concat_k"),
         "string-chars" => (2, "string_chars_k"),
+        "string->list" => (2, "string_to_list_k"),
+        "string-length" => (2, "string_length_k"),
+        "string-ref" => (2, "string_ref_k"),
+        "substring" => (2, "substring_k"),
+        "string-append" => (2, "string_append_k"),
+        "string=?" => (2, "is_string_eq_k"),
+        "string->number" => (2, "string_to_number_k"),
+        "number->string" => (2, "number_to_string_k"),
         "ht-new" => (2, "ht_new_k"),
         "ht-set!" => (2, "ht_set_k"),
         "ht-get" => (2, "ht_get_k"),
         "ht-del!" => (2, "ht_del_k"),
         "ht-keys" => (2, "ht_keys_k"),
+        "make-hash-table" => (2, "ht_new_k"),
+        "hash-table?" => (2, "is_hash_table_k"),
+        "hash-table-ref" => (2, "hash_table_ref_k"),
+        "hash-table-ref/default" => (2, "hash_table_ref_default_k"),
+        "hash-table-set!" => (2, "hash_table_set_k"),
+        "hash-table-delete!" => (2, "hash_table_delete_k"),
+        "hash-table->alist" => (2, "hash_table_to_alist_k"),
         "eq?" => (2, "eq_k"),
+        "eqv?" => (2, "eqv_k"),
+        "equal?" => (2, "equal_k"),
+        "symbol->string" => (2, "symbol_to_string_k"),
+        "string->symbol" => (2, "string_to_symbol_k"),
+        "string->uninterned-symbol" => (2, "string_to_uninterned_symbol_k"),
+        "gensym" => (2, "gensym_k"),
+        "symbol?" => (2, "is_symbol_k"),
+        "make-vector" => (2, "make_vector_k"),
+        "vector-ref" => (2, "vector_ref_k"),
+        "vector-set!" => (2, "vector_set_k"),
+        "vector-length" => (2, "vector_length_k"),
+        "vector?" => (2, "is_vector_k"),
+        "vector->list" => (2, "vector_to_list_k"),
+        "list->vector" => (2, "list_to_vector_k"),
+        "make-bytevector" => (2, "make_bytevector_k"),
+        "bytevector-u8-ref" => (2, "bytevector_ref_k"),
+        "bytevector-u8-set!" => (2, "bytevector_set_k"),
+        "bytevector-length" => (2, "bytevector_length_k"),
+        "bytevector?" => (2, "is_bytevector_k"),
+        "list->bytevector" => (2, "list_to_bytevector_k"),
+        "current-input-port" => (2, "current_input_port_k"),
+        "current-output-port" => (2, "current_output_port_k"),
+        "command-line" => (2, "command_line_k"),
+        "get-environment-variable" => (2, "get_environment_variable_k"),
+        "current-second" => (2, "current_second_k"),
+        "dump-heap" => (2, "dump_heap_k"),
+        "open-input-file" => (2, "open_input_file_k"),
+        "open-output-file" => (2, "open_output_file_k"),
+        "read-char" => (2, "read_char_k"),
+        "read-line" => (2, "read_line_k"),
+        "read" => (2, "read_k"),
+        "eval" => (2, "eval_k"),
+        "interaction-environment" => (2, "interaction_environment_k"),
+        "interaction-define!" => (2, "interaction_define_k"),
+        "spawn" => (2, "spawn_k"),
+        "yield" => (2, "yield_k"),
+        "write" => (2, "write_k"),
+        "write-string" => (2, "write_string_k"),
+        "newline" => (2, "newline_k"),
+        "close-port" => (2, "close_port_k"),
+        "with-exception-handler" => (2, "with_exception_handler_k"),
+        "raise" => (2, "raise_k"),
+        "error" => (2, "error_k"),
+        "error-object?" => (2, "is_error_object_k"),
+        "error-object-message" => (2, "error_object_message_k"),
+        "error-object-irritants" => (2, "error_object_irritants_k"),
+        "dynamic-wind" => (2, "dynamic_wind_k"),
+        "delay" => (2, "delay_k"),
+        "make-promise" => (2, "make_promise_k"),
+        "promise?" => (2, "is_promise_k"),
+        "force" => (2, "force_k"),
+        "make-parameter" => (2, "make_parameter_k"),
+        "parameter-converter" => (2, "parameter_converter_k"),
+        "parameter-push!" => (2, "parameter_push_k"),
+        "parameter-pop!" => (2, "parameter_pop_k"),
+        "make-case-lambda" => (2, "make_case_lambda_k"),
+        "case-lambda-clause" => (2, "case_lambda_clause_k"),
+        "make-record-type" => (2, "make_record_type_k"),
+        "make-record" => (2, "make_record_k"),
+        "record?" => (2, "is_record_k"),
+        "record-check" => (2, "record_check_k"),
+        "record-ref" => (2, "record_ref_k"),
+        "record-set!" => (2, "record_set_k"),
         _ => panic!("unknown builtin: {}", ident),
-    };
+    }
+}
 
-    let init_name = match num_params {
-        1 => "OBJECT_CLOSURE_ONE_NEW",
-        2 => "OBJECT_CLOSURE_TWO_NEW",
-        n => panic!("closure was not one or two parameters, was: {}", n),
-    };
+/// Pure expressions that take an allocation to make, builtin closures and
+/// boxed numbers, are keyed by what they make so that repeats can be found.
+fn shared_key(e: &LExpr, target: &TargetConfig) -> Option<String> {
+    if !e.is_pure() {
+        return None;
+    }
 
-    let var_name = ctx.gen_var();
+    match e {
+        LExpr::BuiltinIdent(Ignore(i)) => Some(format!("builtin {}", i)),
+        LExpr::Lit(Ignore(Literal::Int(i))) if !target.fixnum_fits(*i) => {
+            Some(format!("int {}", i))
+        }
+        LExpr::Lit(Ignore(Literal::Rational(n, d))) => Some(format!("rational {}/{}", n, d)),
+        LExpr::Lit(Ignore(Literal::Float(f))) => Some(format!("float {:x}", f.to_bits())),
+        _ => None,
+    }
+}
 
-    let init_stmt = CStmt::Expr(CExpr::MacroCall {
-        name: init_name.into(),
-        args: vec![
-            Rc::new(CExpr::Ident(var_name.to_owned().into())),
-            Rc::new(CExpr::Ident(runtime_name.into())),
-            Rc::new(CExpr::Ident("NULL".into())),
-        ],
-    });
+/// How many times each shared expression is made by `e`, on any path, and
+/// which of them are made on every path.
+fn shared_uses(
+    e: &LExpr,
+    target: &TargetConfig,
+    uses: &mut HashMap<String, usize>,
+) -> HashSet<String> {
+    if let Some(key) = shared_key(e, target) {
+        *uses.entry(key.clone()).or_default() += 1;
+        return iter::once(key).collect();
+    }
 
-    supporting_stmts.push(Rc::new(init_stmt));
+    match e {
+        LExpr::If(c, ift, iff) => {
+            let ift = shared_uses(ift, target, uses);
+            let iff = shared_uses(iff, target, uses);
+            let mut always = shared_uses(c, target, uses);
+            always.extend(ift.intersection(&iff).cloned());
+            always
+        }
+        e => e
+            .children()
+            .into_iter()
+            .flat_map(|child| shared_uses(child, target, uses))
+            .collect(),
+    }
+}
 
-    CExpr::Ident(var_name.into())
+/// Make the shared expressions that the block `e` makes more than once, and
+/// on every path through it, once at its start. Only these are shared so that
+/// no path makes anything it wouldn't have otherwise. Gives back what was
+/// shared before, to restore at the end of the block.
+fn share_common(
+    e: &LExpr,
+    ctx: &mut CodegenCtx,
+    supporting_stmts: &mut Vec<Arc<CStmt<'static>>>,
+) -> HashMap<String, String> {
+    let outer = ctx.shared.clone();
+
+    let mut uses = HashMap::new();
+    let mut common = shared_uses(e, ctx.program.target, &mut uses)
+        .into_iter()
+        .filter(|key| uses[key] > 1 && !ctx.shared.contains_key(key))
+        .collect::<HashSet<_>>();
+
+    // each is generated from its first use, so that `shared_key` is the only
+    // thing that has to know what these expressions are
+    fn make(
+        e: &LExpr,
+        common: &mut HashSet<String>,
+        ctx: &mut CodegenCtx,
+        supporting_stmts: &mut Vec<Arc<CStmt<'static>>>,
+    ) {
+        match (shared_key(e, ctx.program.target), e) {
+            (Some(key), _) if common.remove(&key) => {
+                if let CExpr::Ident(local) = do_codegen_internal(e, ctx, supporting_stmts) {
+                    ctx.shared.insert(key, local.into_owned());
+                }
+            }
+            (Some(_), _) => {}
+            (None, e) => {
+                for child in e.children() {
+                    make(child, common, ctx, supporting_stmts);
+                }
+            }
+        }
+    }
+
+    make(e, &mut common, ctx, supporting_stmts);
+
+    outer
 }
 
 fn do_codegen_internal(
     e: &LExpr,
     ctx: &mut CodegenCtx,
-    supporting_stmts: &mut Vec<Rc<CStmt<'static>>>,
+    supporting_stmts: &mut Vec<Arc<CStmt<'static>>>,
 ) -> CExpr<'static> {
+    if let Some(local) = shared_key(e, ctx.program.target).and_then(|key| ctx.shared.get(&key)) {
+        return CExpr::Ident(local.clone().into());
+    }
+
     match e {
-        LExpr::Var(v) => {
-            let resolved_name = match v {
-                moniker::Var::Free(f) => name_for_free_var(f),
-                moniker::Var::Bound(_) => panic!("bound var: {:?}", v),
-            };
-            CExpr::Arrow {
-                expr: Rc::new(CExpr::Cast {
-                    typ: CType::Ptr(Rc::new(CType::Struct("cell_obj".into()))),
-                    ex: Rc::new(CExpr::Arrow {
-                        expr: Rc::new(CExpr::Ident("env".into())),
-                        attr: resolved_name.into(),
-                    }),
-                }),
-                attr: "val".into(),
+        LExpr::Var(moniker::Var::Free(f)) if ctx.program.globals.contains_key(f) => {
+            CExpr::Ident(ctx.program.globals[f].clone().into())
+        }
+        LExpr::Var(v) => match v {
+            moniker::Var::Free(f) => ctx.var_place(f),
+            moniker::Var::Bound(_) => panic!("bound var: {:?}", v),
+        },
+        LExpr::Lit(Ignore(Literal::Int(i))) if ctx.program.target.fixnum_fits(*i) => {
+            CExpr::MacroCall {
+                name: "MAKE_FIXNUM".into(),
+                args: vec![Arc::new(CExpr::LitIInt(*i as isize))],
             }
         }
+        LExpr::Lit(Ignore(Literal::Bool(b))) => CExpr::MacroCall {
+            name: "MAKE_BOOL".into(),
+            args: vec![Arc::new(CExpr::LitIInt(*b as isize))],
+        },
+        LExpr::Lit(Ignore(Literal::Symbol(s))) => CExpr::Cast {
+            typ: object_type(),
+            ex: Arc::new(CExpr::PreUnOp {
+                op: "&".into(),
+                ex: Arc::new(CExpr::Ident(ctx.symbol_var(s).into())),
+            }),
+        },
+        LExpr::Lit(Ignore(Literal::Quoted(items))) => CExpr::Cast {
+            typ: object_type(),
+            ex: Arc::new(CExpr::PreUnOp {
+                op: "&".into(),
+                ex: Arc::new(CExpr::Ident(ctx.quoted_var(items).into())),
+            }),
+        },
         LExpr::Lit(Ignore(l)) => {
-            let (ctor_name, expr) = match l {
-                Literal::String(s) => ("OBJECT_STRING_OBJ_NEW", CExpr::LitStr(s.to_owned().into())),
-                Literal::Int(i) => ("OBJECT_INT_OBJ_NEW", CExpr::LitIInt(*i as isize)),
-                Literal::Float(_f) => panic!("not yet"),
+            let (ctor_name, args) = match l {
+                Literal::String(s) => (
+                    "OBJECT_STRING_OBJ_NEW",
+                    vec![CExpr::LitStr(c_string(&unescape(s)).into())],
+                ),
+                Literal::Int(i) => ("OBJECT_INT_OBJ_NEW", vec![CExpr::LitIInt(*i as isize)]),
+                Literal::Rational(n, d) => (
+                    "OBJECT_RATIONAL_OBJ_NEW",
+                    vec![CExpr::LitIInt(*n as isize), CExpr::LitIInt(*d as isize)],
+                ),
+                Literal::Float(f) => ("OBJECT_FLOAT_OBJ_NEW", vec![CExpr::LitFloat(*f)]),
                 Literal::Void => return CExpr::Ident("NULL".into()),
+                Literal::Symbol(_) | Literal::Bool(_) | Literal::Quoted(_) => unreachable!(),
             };
 
             let dest = ctx.gen_var();
 
             let init_stmt = CStmt::Expr(CExpr::MacroCall {
                 name: ctor_name.to_owned().into(),
-                args: vec![Rc::new(CExpr::Ident(dest.to_owned().into())), Rc::new(expr)],
+                args: iter::once(CExpr::Ident(dest.to_owned().into()))
+                    .chain(args)
+                    .map(|a| Arc::new(a) as Arc<dyn ToCDC + Send + Sync>)
+                    .collect(),
             });
 
-            supporting_stmts.push(Rc::new(init_stmt));
+            supporting_stmts.push(Arc::new(init_stmt));
 
             CExpr::Ident(dest.into())
         }
         LExpr::BuiltinIdent(Ignore(i)) => builtin_ident_codegen(i.as_ref(), ctx, supporting_stmts),
         LExpr::SetThen(v, e, c) => {
             let e_expr = do_codegen_internal(e, ctx, supporting_stmts);
-            let resolved_name = match v {
-                moniker::Var::Free(f) => name_for_free_var(f),
+            let (var_exp, holder) = match v {
+                moniker::Var::Free(f) if ctx.program.globals.contains_key(f) => {
+                    (CExpr::Ident(ctx.program.globals[f].clone().into()), None)
+                }
+                moniker::Var::Free(f) => (ctx.var_place(f), ctx.var_holder(f)),
                 moniker::Var::Bound(_) => panic!("bound var: {:?}", v),
             };
-            let var_exp = CExpr::Arrow {
-                expr: Rc::new(CExpr::Cast {
-                    typ: CType::Ptr(Rc::new(CType::Struct("cell_obj".into()))),
-                    ex: Rc::new(CExpr::Arrow {
-                        expr: Rc::new(CExpr::Ident("env".into())),
-                        attr: resolved_name.into(),
-                    }),
-                }),
-                attr: "val".into(),
-            };
 
-            supporting_stmts.push(Rc::new(CStmt::Expr(CExpr::BinOp {
+            supporting_stmts.push(Arc::new(CStmt::Expr(CExpr::BinOp {
                 op: "=".into(),
-                left: Rc::new(var_exp),
-                right: Rc::new(e_expr),
+                left: Arc::new(var_exp),
+                right: Arc::new(e_expr),
             })));
 
+            // globals are gc roots, but an env or cell can be older than the
+            // last minor gc, which then doesn't look inside it
+            if let (Some(holder), moniker::Var::Free(f)) = (holder, v) {
+                supporting_stmts.push(Arc::new(CStmt::Expr(CExpr::MacroCall {
+                    name: "GC_WRITE_BARRIER".into(),
+                    args: vec![holder, Arc::new(ctx.var_place(f))],
+                })));
+            }
+
             do_codegen_internal(c, ctx, supporting_stmts)
         }
         LExpr::Lifted(Ignore(id)) => {
-            let lambda = ctx.lambdas.get(id).unwrap();
-            let env_expr = Rc::new(CExpr::Ident("env".into()));
-            lambda.generate_closure(&env_expr, ctx, supporting_stmts)
+            let lambda = ctx.program.lambdas.get(id).unwrap();
+            lambda.generate_closure(ctx, supporting_stmts)
         }
         LExpr::If(c, ift, iff) => {
             let mut ift_stmts = Vec::new();
+            let outer = share_common(ift, ctx, &mut ift_stmts);
             let ift = do_codegen_internal(ift, ctx, &mut ift_stmts);
-            ift_stmts.push(Rc::new(CStmt::Expr(ift)));
+            ift_stmts.push(Arc::new(CStmt::Expr(ift)));
+            ctx.shared = outer;
 
             let mut iff_stmts = Vec::new();
+            let outer = share_common(iff, ctx, &mut iff_stmts);
             let iff = do_codegen_internal(iff, ctx, &mut iff_stmts);
-            iff_stmts.push(Rc::new(CStmt::Expr(iff)));
+            iff_stmts.push(Arc::new(CStmt::Expr(iff)));
+            ctx.shared = outer;
 
             let stmt = CStmt::If {
                 cond: CExpr::MacroCall {
                     name: "obj_is_truthy".into(),
-                    args: vec![Rc::new(do_codegen_internal(c, ctx, supporting_stmts))],
+                    args: vec![Arc::new(do_codegen_internal(c, ctx, supporting_stmts))],
                 },
-                ift: Rc::new(CStmt::Block(ift_stmts)),
-                iff: Rc::new(CStmt::Block(iff_stmts)),
+                ift: Arc::new(CStmt::Block(ift_stmts)),
+                iff: Arc::new(CStmt::Block(iff_stmts)),
             };
 
-            supporting_stmts.push(Rc::new(stmt));
+            supporting_stmts.push(Arc::new(stmt));
 
             CExpr::LitIInt(0)
         }
-        LExpr::CallOne(c, a) => CExpr::MacroCall {
-            name: "call_closure_one".into(),
-            args: vec![
-                Rc::new(do_codegen_internal(c, ctx, supporting_stmts)),
-                Rc::new(do_codegen_internal(a, ctx, supporting_stmts)),
-            ],
-        },
-        LExpr::CallTwo(c, a, k) => CExpr::MacroCall {
-            name: "call_closure_two".into(),
-            args: vec![
-                Rc::new(do_codegen_internal(c, ctx, supporting_stmts)),
-                Rc::new(do_codegen_internal(a, ctx, supporting_stmts)),
-                Rc::new(do_codegen_internal(k, ctx, supporting_stmts)),
-            ],
+        LExpr::CallOne(c, a) => call_codegen(c, &[a], ctx, supporting_stmts),
+        LExpr::CallTwo(c, a, k) => call_codegen(c, &[a, k], ctx, supporting_stmts),
+        LExpr::IntOp(Ignore(op), a, b) => int_op_codegen(op, a, b, ctx, supporting_stmts),
+    }
+}
+
+/// Arithmetic on the `int64_t`s of two objects known to be integers, only the
+/// result is boxed.
+fn int_op_codegen(
+    op: &str,
+    a: &LExpr,
+    b: &LExpr,
+    ctx: &mut CodegenCtx,
+    supporting_stmts: &mut Vec<Arc<CStmt<'static>>>,
+) -> CExpr<'static> {
+    let mut int_val = |e: &LExpr| -> Arc<CExpr<'static>> {
+        Arc::new(match e {
+            LExpr::Lit(Ignore(Literal::Int(i))) => CExpr::LitIInt(*i as isize),
+            e => CExpr::MacroCall {
+                name: "obj_int_val".into(),
+                args: vec![Arc::new(do_codegen_internal(e, ctx, supporting_stmts))],
+            },
+        })
+    };
+    let (left, right) = (int_val(a), int_val(b));
+
+    let builtin = match op {
+        "+" => "add",
+        "-" => "sub",
+        "*" => "mul",
+        op => {
+            let op = if op == "=" { "==" } else { op };
+            return CExpr::MacroCall {
+                name: "MAKE_BOOL".into(),
+                args: vec![Arc::new(CExpr::BinOp {
+                    op: op.to_owned().into(),
+                    left,
+                    right,
+                })],
+            };
+        }
+    };
+
+    let dest = ctx.gen_var();
+    supporting_stmts.push(Arc::new(CStmt::Expr(CExpr::MacroCall {
+        name: "INT_ARITH_OBJ_NEW".into(),
+        args: vec![
+            Arc::new(CExpr::Ident(dest.clone().into())),
+            Arc::new(CExpr::Ident(builtin.into())),
+            left,
+            right,
+        ],
+    })));
+
+    CExpr::Ident(dest.into())
+}
+
+/// A call goes through the runtime, which checks what is called and collects
+/// once the stack runs out. A call of a known lambda, from the function of the
+/// lambda or of one made by a run of it, is made directly while there is
+/// stack left: the function jumps back to its start when it calls itself, and
+/// calls the lambda's function with the closure's environment otherwise.
+fn call_codegen(
+    f: &LExpr,
+    args: &[&Arc<LExpr>],
+    ctx: &mut CodegenCtx,
+    supporting_stmts: &mut Vec<Arc<CStmt<'static>>>,
+) -> CExpr<'static> {
+    let runtime_call = match args.len() {
+        1 => "call_closure_one",
+        _ => "call_closure_two",
+    };
+
+    let known = match (f, ctx.current) {
+        (LExpr::Var(moniker::Var::Free(v)), Some(current)) => ctx
+            .program
+            .known
+            .get(v)
+            .copied()
+            .filter(|id| ctx.program.within(current, *id))
+            .filter(|id| ctx.program.lambdas[id].params.iter().count() == args.len()),
+        _ => None,
+    };
+
+    let f = do_codegen_internal(f, ctx, supporting_stmts);
+    let args = args
+        .iter()
+        .map(|a| do_codegen_internal(a, ctx, supporting_stmts))
+        .collect::<Vec<_>>();
+
+    let id = match known {
+        Some(id) => id,
+        None => {
+            return CExpr::MacroCall {
+                name: runtime_call.into(),
+                args: iter::once(f)
+                    .chain(args)
+                    .map(|a| Arc::new(a) as Arc<dyn ToCDC + Send + Sync>)
+                    .collect(),
+            }
+        }
+    };
+
+    // the arguments can read the parameters they replace
+    let mut temp = |e: CExpr<'static>| {
+        let name = ctx.gen_var();
+        supporting_stmts.push(Arc::new(CStmt::Decl(CDecl::Var {
+            name: name.clone().into(),
+            typ: object_type(),
+            init: Some(e),
+        })));
+        name
+    };
+    let f = temp(f);
+    let args = args.into_iter().map(temp).collect::<Vec<_>>();
+
+    let env = CExpr::Arrow {
+        expr: Arc::new(CExpr::Cast {
+            typ: CType::Ptr(Arc::new(CType::Struct("closure_obj".into()))),
+            ex: Arc::new(CExpr::Ident(f.clone().into())),
+        }),
+        attr: "env".into(),
+    };
+
+    let direct = if Some(id) == ctx.current {
+        ctx.loops = true;
+
+        let mut jump = ctx
+            .params
+            .iter()
+            .map(|p| p.as_str())
+            .chain(iter::once("env_in"))
+            .zip(
+                args.iter()
+                    .map(|a| CExpr::Ident(a.clone().into()))
+                    .chain(iter::once(env)),
+            )
+            .map(|(param, arg)| {
+                Arc::new(CStmt::Expr(CExpr::BinOp {
+                    op: "=".into(),
+                    left: Arc::new(CExpr::Ident(param.to_owned().into())),
+                    right: Arc::new(arg),
+                }))
+            })
+            .collect::<Vec<_>>();
+        jump.push(Arc::new(CStmt::Goto(SELF_CALL_LABEL.into())));
+        jump
+    } else {
+        vec![Arc::new(CStmt::Expr(CExpr::MacroCall {
+            name: ctx.program.lambdas[&id].name.clone().into(),
+            args: args
+                .iter()
+                .map(|a| Arc::new(CExpr::Ident(a.clone().into())) as Arc<dyn ToCDC + Send + Sync>)
+                .chain(iter::once(Arc::new(env) as Arc<dyn ToCDC + Send + Sync>))
+                .collect(),
+        }))]
+    };
+
+    supporting_stmts.push(Arc::new(CStmt::If {
+        cond: CExpr::MacroCall {
+            name: "stack_check".into(),
+            args: vec![],
         },
+        ift: Arc::new(CStmt::Block(direct)),
+        iff: Arc::new(CStmt::Block(vec![])),
+    }));
+
+    CExpr::MacroCall {
+        name: runtime_call.into(),
+        args: iter::once(f)
+            .chain(args)
+            .map(|a| Arc::new(CExpr::Ident(a.into())) as Arc<dyn ToCDC + Send + Sync>)
+            .collect(),
     }
 }
@@ -0,0 +1,674 @@
+use failure::{format_err, Error};
+use moniker::{FreeVar, Ignore};
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::io::Write;
+use std::iter;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::base_expr::BExpr;
+use crate::cdsl::{CDecl, CExpr, CStmt, CType, ToC};
+use crate::codegen;
+use crate::cont_expr::AExp;
+use crate::expr::Expr;
+use crate::features::{self, Features};
+use crate::flat_expr::FExpr;
+use crate::inline;
+use crate::interp::{self, Value};
+use crate::lifted_expr::Program;
+use crate::link::Module;
+use crate::parse;
+use crate::partial_eval;
+use crate::shrink;
+use crate::target::TargetConfig;
+use crate::unbox;
+use crate::utils;
+
+/// Compiles or evaluates a program from a string, for using the compiler from
+/// another program rather than through the command line.
+///
+/// ```no_run
+/// use some_scheme_compiler::compiler::Compiler;
+///
+/// let c = Compiler::new()
+///     .source("(display (+ 1 2))")
+///     .optimize(2)
+///     .compile_to_c()?;
+/// # Ok::<(), failure::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Compiler {
+    source: String,
+    path: Option<PathBuf>,
+    optimize: u8,
+    inline_threshold: usize,
+    profile: bool,
+    checked: bool,
+    trace: bool,
+    jobs: usize,
+    pretty: bool,
+    target: TargetConfig,
+    features: Vec<String>,
+}
+
+impl Default for Compiler {
+    fn default() -> Self {
+        Self {
+            source: String::new(),
+            path: None,
+            optimize: 0,
+            inline_threshold: inline::DEFAULT_THRESHOLD,
+            profile: false,
+            checked: false,
+            trace: false,
+            jobs: 0,
+            pretty: false,
+            target: TargetConfig::host(),
+            features: Vec::new(),
+        }
+    }
+}
+
+impl Compiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// The file the source was read from, includes are resolved relative to
+    /// it and the `#line` directives of the C name it.
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// How hard to optimise, from 0 to 3. From 2, arithmetic on values known
+    /// to be integers is done in C, see `unbox`.
+    pub fn optimize(mut self, level: u8) -> Self {
+        self.optimize = level;
+        self
+    }
+
+    pub fn optimization_level(&self) -> u8 {
+        self.optimize
+    }
+
+    /// Like `--inline-threshold`, 0 turns inlining off.
+    pub fn inline_threshold(mut self, threshold: usize) -> Self {
+        self.inline_threshold = threshold;
+        self
+    }
+
+    /// Like `--profile`, counts the calls of each lambda.
+    pub fn profile(mut self, profile: bool) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Like `--checked`, builtins check the arguments they otherwise trust and
+    /// runtime errors name the lambda they happened in.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Like `--trace`, each call is logged as it is made.
+    pub fn trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// Like `--jobs`, how many threads generate the C of the lambdas, 0 being
+    /// as many as there are cores.
+    pub fn jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs;
+        self
+    }
+
+    /// Like `--target-triple`, the machine the C is built for.
+    pub fn target(mut self, target: TargetConfig) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Like `--feature`, a feature for `cond-expand` to choose clauses by, on
+    /// top of those every program has.
+    pub fn feature(mut self, name: impl Into<String>) -> Self {
+        self.features.push(name.into());
+        self
+    }
+
+    /// The features of the program when compiled by `backend`.
+    pub fn features(&self, backend: &str) -> Features {
+        let mut features = Features::new(backend, &self.target);
+
+        for name in &self.features {
+            features.add(name.clone());
+        }
+
+        features
+    }
+
+    /// Lay the C out to be read, indented and without redundant parentheses.
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    fn source_file(&self) -> String {
+        match &self.path {
+            Some(path) => path.display().to_string(),
+            None => "<string>".to_owned(),
+        }
+    }
+
+    /// The program as the body of a lambda that is called straight away.
+    pub fn parse(&self) -> Result<BExpr, Error> {
+        self.parse_for("c")
+    }
+
+    fn parse_for(&self, backend: &str) -> Result<BExpr, Error> {
+        let source = features::expand(&self.source, self.path.as_deref(), &self.features(backend))?;
+        let body = parse::parse(&source).map_err(|e| format_err!("{}", e))?;
+
+        Ok(BExpr::App(
+            Rc::new(BExpr::Lam(Vec::new(), body, None)),
+            Vec::new(),
+        ))
+    }
+
+    pub fn bind(&self) -> Result<Expr, Error> {
+        self.parse()?.into_expr()
+    }
+
+    /// The C of the whole program, which builds against the runtime in
+    /// `src/core` like the output of `--emit c`.
+    pub fn compile_to_c(&self) -> Result<String, Error> {
+        utils::with_stack(|| self.generate_c())
+    }
+
+    /// The program with its lambdas lifted and inlined, as the C is generated
+    /// from.
+    pub fn lift(&self) -> Result<Program, Error> {
+        utils::with_stack(|| self.lift_program())
+    }
+
+    fn lift_program(&self) -> Result<Program, Error> {
+        self.lift_expr(self.bind()?, "c", &mut ())
+    }
+
+    /// Lift the lambdas of a bound program compiled by `backend`, telling
+    /// `observer` about each pass on the way. Only the C is unboxed.
+    pub fn lift_expr(
+        &self,
+        expr: Expr,
+        backend: &str,
+        observer: &mut dyn PassObserver,
+    ) -> Result<Program, Error> {
+        self.lift_with(expr, "halt", &HashSet::new(), backend == "c", observer)
+    }
+
+    /// Like `lift_expr` for one of several inputs compiled to C on their own,
+    /// which continues into the next input rather than exiting and never
+    /// captures the `globals` the inputs share.
+    pub fn lift_module(
+        &self,
+        expr: Expr,
+        globals: &HashSet<FreeVar<String>>,
+        observer: &mut dyn PassObserver,
+    ) -> Result<Program, Error> {
+        self.lift_with(expr, "module-done", globals, true, observer)
+    }
+
+    fn lift_with(
+        &self,
+        expr: Expr,
+        k: &str,
+        globals: &HashSet<FreeVar<String>>,
+        unbox: bool,
+        observer: &mut dyn PassObserver,
+    ) -> Result<Program, Error> {
+        observer.start("partial-eval");
+        let (expr, stats) = partial_eval::partial_eval(expr);
+        observer.finish(Stage::PartialEval(&expr, &stats));
+
+        let k = Rc::new(AExp::BuiltinIdent(Ignore(k.into())));
+        observer.start("cps");
+        let expr = expr.into_fexpr(k);
+        observer.finish(Stage::Cps(&expr));
+
+        observer.start("shrink");
+        let (mut expr, stats) = shrink::shrink(expr);
+        observer.finish(Stage::Shrink(&expr, &stats));
+
+        if unbox && self.optimize >= 2 {
+            observer.start("unbox");
+            let (unboxed, stats) = unbox::unbox_integers(expr);
+            observer.finish(Stage::Unbox(&unboxed, &stats));
+            expr = unboxed;
+        }
+        expr.check_tail_calls()?;
+
+        observer.start("lift");
+        let mut program = expr.lift_lambdas_with_globals(globals);
+        observer.finish(Stage::Lift(&program));
+
+        observer.start("inline");
+        inline::inline_lambdas(&mut program, self.inline_threshold);
+        observer.finish(Stage::Inline(&program));
+
+        observer.start("dead-lambdas");
+        program.drop_dead_lambdas();
+        observer.finish(Stage::DeadLambdas(&program));
+
+        Ok(program)
+    }
+
+    /// The C of a lifted program read from `source_file`, counting calls,
+    /// blaming lambdas or tracing calls as asked. `module` is the input it is
+    /// when there are several, which refer to what they share by `globals`.
+    pub fn codegen(
+        &self,
+        program: &Program,
+        source_file: &str,
+        module: Option<&Module>,
+        globals: &HashMap<FreeVar<String>, String>,
+    ) -> GeneratedC {
+        let mut generated = GeneratedC::new(
+            program,
+            source_file,
+            module,
+            globals,
+            &self.target,
+            self.jobs,
+        );
+
+        if self.profile {
+            generated.instrument();
+        }
+
+        if self.checked {
+            generated.track_blame();
+        }
+
+        if self.trace {
+            generated.trace();
+        }
+
+        generated
+    }
+
+    fn generate_c(&self) -> Result<String, Error> {
+        let program = self.lift_program()?;
+        let generated = self.codegen(&program, &self.source_file(), None, &HashMap::new());
+
+        let c = if self.pretty {
+            generated.export_pretty()
+        } else {
+            generated.export()
+        };
+
+        Ok(program_source(&c))
+    }
+
+    /// Run the program on the interpreter, with what it displays written to
+    /// `out`, returning the value of its last expression.
+    pub fn eval(&self, out: &mut dyn Write) -> Result<Value, Error> {
+        interp::eval(&self.parse_for("interpreter")?.into_expr()?, out)
+    }
+}
+
+/// The program as one of the passes of `Compiler` left it, along with what the
+/// pass did.
+pub enum Stage<'a> {
+    PartialEval(&'a Expr, &'a partial_eval::Stats),
+    Cps(&'a FExpr),
+    Shrink(&'a FExpr, &'a shrink::Stats),
+    Unbox(&'a FExpr, &'a unbox::Stats),
+    Lift(&'a Program),
+    Inline(&'a Program),
+    DeadLambdas(&'a Program),
+}
+
+impl Stage<'_> {
+    /// The name of the pass, as `--time-passes` prints it.
+    pub fn pass(&self) -> &'static str {
+        match self {
+            Stage::PartialEval(..) => "partial-eval",
+            Stage::Cps(_) => "cps",
+            Stage::Shrink(..) => "shrink",
+            Stage::Unbox(..) => "unbox",
+            Stage::Lift(_) => "lift",
+            Stage::Inline(_) => "inline",
+            Stage::DeadLambdas(_) => "dead-lambdas",
+        }
+    }
+
+    /// How many nodes the program has after the pass.
+    pub fn size(&self) -> usize {
+        match self {
+            Stage::PartialEval(expr, _) => expr.size(),
+            Stage::Cps(expr) | Stage::Shrink(expr, _) | Stage::Unbox(expr, _) => expr.size(),
+            Stage::Lift(program) | Stage::Inline(program) | Stage::DeadLambdas(program) => {
+                program.size()
+            }
+        }
+    }
+}
+
+/// Told about each pass `Compiler` runs, for the command line to time them and
+/// print what they did.
+pub trait PassObserver {
+    /// The pass named `pass` is about to run.
+    fn start(&mut self, _pass: &'static str) {}
+
+    /// A pass is done.
+    fn finish(&mut self, _stage: Stage<'_>) {}
+}
+
+impl PassObserver for () {}
+
+/// The C generated for a lifted program, the function running its toplevel
+/// comes after the lambdas it uses.
+pub struct GeneratedC {
+    pub protos: Vec<CDecl<'static>>,
+    pub decls: Vec<CDecl<'static>>,
+    pub main: CDecl<'static>,
+    /// checks that the C is built for the machine it was generated for
+    pub checks: String,
+}
+
+impl GeneratedC {
+    pub fn new(
+        program: &Program,
+        source_file: &str,
+        module: Option<&Module>,
+        globals: &HashMap<FreeVar<String>, String>,
+        target: &TargetConfig,
+        jobs: usize,
+    ) -> Self {
+        let (mut root_stmts, protos, decls) =
+            codegen::do_codegen(program, source_file, module, globals, target, jobs);
+
+        root_stmts.push(Arc::new(CStmt::Expr(CExpr::MacroCall {
+            name: "__builtin_unreachable".into(),
+            args: vec![],
+        })));
+
+        let main = CDecl::Fun {
+            name: module
+                .map_or("main_lambda".to_owned(), Module::entry)
+                .into(),
+            typ: CType::Void,
+            args: vec![
+                (
+                    "input_obj".into(),
+                    CType::Ptr(Arc::new(CType::Struct("obj".into()))),
+                ),
+                (
+                    "input_env".into(),
+                    CType::Ptr(Arc::new(CType::Struct("env_obj".into()))),
+                ),
+            ],
+            body: root_stmts,
+            source: Some((source_file.to_owned().into(), 1)),
+        };
+
+        Self {
+            protos,
+            decls,
+            main,
+            checks: target.c_checks(),
+        }
+    }
+
+    /// Count the calls of each lambda in `scheme_profile`, which the runtime
+    /// writes out when the program exits.
+    pub fn instrument(&mut self) {
+        let mut entries = Vec::new();
+
+        for decl in &mut self.decls {
+            // only the lambdas have a place in the source
+            if let CDecl::Fun {
+                name,
+                body,
+                source: Some((file, line)),
+                ..
+            } = decl
+            {
+                let entry = CExpr::ArrIndexOp {
+                    index: Arc::new(CExpr::LitUInt(entries.len())),
+                    expr: Arc::new(CExpr::Ident("scheme_profile".into())),
+                };
+
+                // a lambda calling itself jumps to its start, which counts
+                push_front(
+                    body,
+                    CStmt::Expr(CExpr::PostUnOp {
+                        op: "++".into(),
+                        ex: Arc::new(CExpr::Dot {
+                            expr: Arc::new(entry),
+                            attr: "count".into(),
+                        }),
+                    }),
+                );
+
+                entries.push(CExpr::InitList(vec![
+                    CExpr::LitStr(name.clone()),
+                    CExpr::LitStr(file.clone()),
+                    CExpr::LitUInt(*line),
+                    CExpr::LitUInt(0),
+                ]));
+            }
+        }
+
+        entries.push(CExpr::InitList(vec![CExpr::Ident("NULL".into())]));
+
+        self.protos.push(CDecl::Var {
+            name: "scheme_profile".into(),
+            typ: CType::Arr(Arc::new(CType::Struct("profile_entry".into())), None),
+            init: Some(CExpr::InitList(entries)),
+        });
+    }
+
+    /// Name the lambda running in `scheme_blame`, for the runtime errors to
+    /// blame, which also has the builtins check the arguments they otherwise
+    /// trust.
+    pub fn track_blame(&mut self) {
+        for decl in self.decls.iter_mut().chain(iter::once(&mut self.main)) {
+            if let CDecl::Fun {
+                name,
+                body,
+                source: Some((file, line)),
+                ..
+            } = decl
+            {
+                let blame = format!("{} ({}:{})", name, file, line);
+
+                push_front(
+                    body,
+                    CStmt::Expr(CExpr::BinOp {
+                        op: "=".into(),
+                        left: Arc::new(CExpr::Ident("scheme_blame".into())),
+                        right: Arc::new(CExpr::LitStr(blame.into())),
+                    }),
+                );
+            }
+        }
+
+        // each module defines it, the linker keeps one
+        self.protos.push(CDecl::Var {
+            name: "scheme_blame".into(),
+            typ: CType::Weak(Arc::new(CType::Ptr(Arc::new(CType::Const(Arc::new(
+                CType::Other("char".into()),
+            )))))),
+            init: None,
+        });
+    }
+
+    /// Log each call with `trace_call` before it is made, naming the function
+    /// making it. A known lambda is logged before it is called directly too.
+    pub fn trace(&mut self) {
+        for decl in self.decls.iter_mut().chain(iter::once(&mut self.main)) {
+            if let CDecl::Fun { name, body, .. } = decl {
+                trace_calls(name, body);
+            }
+        }
+    }
+
+    pub fn export(&self) -> String {
+        self.export_with(|decl| decl.export())
+    }
+
+    pub fn export_pretty(&self) -> String {
+        self.export_with(|decl| decl.pretty())
+    }
+
+    fn export_with(&self, export: impl Fn(&CDecl<'static>) -> String) -> String {
+        let mut out = self.checks.clone();
+
+        for decl in self.protos.iter().chain(&self.decls) {
+            let _ = writeln!(out, "{}", export(decl));
+        }
+
+        let _ = writeln!(out, "{}", export(&self.main));
+
+        out
+    }
+}
+
+/// Add `stmt` to the start of the body of a function, after the label that a
+/// lambda calling itself jumps to.
+fn push_front(body: &mut Vec<Arc<CStmt<'static>>>, stmt: CStmt<'static>) {
+    let start = match body.first().map(|s| &**s) {
+        Some(CStmt::Label(_)) => 1,
+        _ => 0,
+    };
+
+    body.insert(start, Arc::new(stmt));
+}
+
+/// Put a call of `trace_call` before each call of a closure in `stmts` and the
+/// blocks within them, or before the check of the stack in front of it if the
+/// call is of a known lambda.
+fn trace_calls(caller: &str, stmts: &mut Vec<Arc<CStmt<'static>>>) {
+    let mut i = 0;
+
+    while i < stmts.len() {
+        let trace = match Arc::get_mut(&mut stmts[i]) {
+            Some(CStmt::Expr(CExpr::MacroCall { name, args }))
+                if name == "call_closure_one" || name == "call_closure_two" =>
+            {
+                let mut args = args.clone();
+                if args.len() == 2 {
+                    args.push(Arc::new(CExpr::Ident("NULL".into())));
+                }
+                args.insert(0, Arc::new(CExpr::LitStr(caller.to_owned().into())));
+
+                Some(CStmt::Expr(CExpr::MacroCall {
+                    name: "trace_call".into(),
+                    args,
+                }))
+            }
+            Some(CStmt::Block(body)) => {
+                trace_calls(caller, body);
+                None
+            }
+            Some(CStmt::If { ift, iff, .. }) => {
+                for branch in [ift, iff] {
+                    if let Some(CStmt::Block(body)) = Arc::get_mut(branch) {
+                        trace_calls(caller, body);
+                    }
+                }
+                None
+            }
+            Some(CStmt::While { body, .. }) | Some(CStmt::For { body, .. }) => {
+                if let Some(CStmt::Block(body)) = Arc::get_mut(body) {
+                    trace_calls(caller, body);
+                }
+                None
+            }
+            Some(CStmt::Switch { cases, default, .. }) => {
+                for body in cases.iter_mut().map(|(_, b)| b).chain(default) {
+                    trace_calls(caller, body);
+                }
+                None
+            }
+            _ => None,
+        };
+
+        let trace = match trace {
+            Some(trace) => trace,
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+
+        let at = match i.checked_sub(1).map(|j| &*stmts[j]) {
+            Some(CStmt::If {
+                cond: CExpr::MacroCall { name, .. },
+                ..
+            }) if name == "stack_check" => i - 1,
+            _ => i,
+        };
+
+        stmts.insert(at, Arc::new(trace));
+        i += 2;
+    }
+}
+
+/// Wrap the generated C of a program with the entry point that starts it,
+/// along with `main` unless `SOMESCHEME_NO_MAIN` is defined.
+pub fn program_source(src: &str) -> String {
+    let prelude = format!(
+        "{}{}",
+        r#"
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+"#,
+        src
+    );
+
+    // the lambdas map themselves back to the scheme source, so point what
+    // follows back at the generated file
+    let next_line = prelude.matches('\n').count() + 2;
+
+    format!(
+        "{}#line {} \"compiled_result.c\"{}",
+        prelude,
+        next_line,
+        r#"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
+"#
+    )
+}
@@ -6,11 +6,20 @@ use termcolor::{Color, ColorSpec, WriteColor};
 
 use std::{io::Result, rc::Rc};
 
-use crate::{expr::Expr, flat_expr::FExpr, literals::Literal, utils::clone_rc};
+use crate::{
+    expr::{Atom, Expr},
+    flat_expr::FExpr,
+    literals::Literal,
+    shrink::Node,
+    utils::{clone_rc, open_scope},
+};
 
 #[derive(Debug, Clone, BoundTerm)]
 pub enum AExp {
-    Lam2(Scope<Binder<String>, Scope<Binder<String>, Rc<CExp>>>),
+    Lam2(
+        Scope<Binder<String>, Scope<Binder<String>, Rc<CExp>>>,
+        Ignore<Option<usize>>,
+    ),
     Lam1(Scope<Binder<String>, Rc<CExp>>),
     Var(Var<String>),
     BuiltinIdent(Ignore<String>),
@@ -24,7 +33,7 @@ impl AExp {
         D::Doc: Clone,
     {
         match self {
-            AExp::Lam2(s) => {
+            AExp::Lam2(s, _) => {
                 let Scope {
                     unsafe_pattern: pat,
                     unsafe_body:
@@ -87,24 +96,27 @@ impl AExp {
     }
 
     pub fn into_fexpr(self) -> FExpr {
+        self.into_node().close()
+    }
+
+    /// The scopes the transform leaves open are read as they are.
+    fn into_node(self) -> Node {
         match self {
-            AExp::Lam2(s) => {
-                let (pat, body) = s.unbind();
-                let (cont, body) = body.unbind();
-
-                FExpr::LamTwo(Scope::new(
-                    pat,
-                    Scope::new(cont, Rc::new(clone_rc(body).into_fexpr())),
-                ))
+            AExp::Lam2(s, Ignore(line)) => {
+                let Binder(x) = s.unsafe_pattern;
+                let Binder(k) = s.unsafe_body.unsafe_pattern;
+                let body = clone_rc(s.unsafe_body.unsafe_body).into_node();
+
+                Node::LamTwo(x, k, Box::new(body), line)
             }
             AExp::Lam1(s) => {
-                let (pat, body) = s.unbind();
+                let Binder(x) = s.unsafe_pattern;
 
-                FExpr::LamOne(Scope::new(pat, Rc::new(clone_rc(body).into_fexpr())))
+                Node::LamOne(x, Box::new(clone_rc(s.unsafe_body).into_node()))
             }
-            AExp::BuiltinIdent(s) => FExpr::BuiltinIdent(s),
-            AExp::Var(s) => FExpr::Var(s),
-            AExp::Lit(l) => FExpr::Lit(l),
+            AExp::BuiltinIdent(Ignore(s)) => Node::BuiltinIdent(s),
+            AExp::Var(s) => Node::Var(s),
+            AExp::Lit(Ignore(l)) => Node::Lit(l),
         }
     }
 }
@@ -199,26 +211,23 @@ impl CExp {
         Ok(())
     }
 
+    /// The program with the scopes left open by the transform closed, all at
+    /// once.
     pub fn into_fexpr(self) -> FExpr {
+        self.into_node().close()
+    }
+
+    fn into_node(self) -> Node {
+        let node = |e: Rc<AExp>| Box::new(clone_rc(e).into_node());
+
         match self {
-            CExp::SetThen(n, v, c) => FExpr::SetThen(
-                n,
-                Rc::new(clone_rc(v).into_fexpr()),
-                Rc::new(clone_rc(c).into_fexpr()),
-            ),
-            CExp::Call2(f, v, c) => FExpr::CallTwo(
-                Rc::new(clone_rc(f).into_fexpr()),
-                Rc::new(clone_rc(v).into_fexpr()),
-                Rc::new(clone_rc(c).into_fexpr()),
-            ),
-            CExp::Call1(f, v) => FExpr::CallOne(
-                Rc::new(clone_rc(f).into_fexpr()),
-                Rc::new(clone_rc(v).into_fexpr()),
-            ),
-            CExp::If(c, ift, iff) => FExpr::If(
-                Rc::new(clone_rc(c).into_fexpr()),
-                Rc::new(clone_rc(ift).into_fexpr()),
-                Rc::new(clone_rc(iff).into_fexpr()),
+            CExp::SetThen(n, v, c) => Node::SetThen(n, node(v), Box::new(clone_rc(c).into_node())),
+            CExp::Call2(f, v, c) => Node::CallTwo(node(f), node(v), node(c)),
+            CExp::Call1(f, v) => Node::CallOne(node(f), node(v)),
+            CExp::If(c, ift, iff) => Node::If(
+                node(c),
+                Box::new(clone_rc(ift).into_node()),
+                Box::new(clone_rc(iff).into_node()),
             ),
         }
     }
@@ -226,7 +235,7 @@ impl CExp {
 
 fn t_k(expr: Expr, fk: &dyn Fn(Rc<AExp>) -> CExp) -> CExp {
     match expr {
-        Expr::Lam(_) | Expr::Var(_) | Expr::Lit(_) | Expr::BuiltinIdent(_) => fk(Rc::new(m(expr))),
+        Expr::Atom(a) => fk(Rc::new(m(a))),
         Expr::Set(n, e) => t_k(clone_rc(e), &|e| {
             CExp::SetThen(
                 n.clone(),
@@ -236,8 +245,8 @@ fn t_k(expr: Expr, fk: &dyn Fn(Rc<AExp>) -> CExp) -> CExp {
         }),
         Expr::If(c, ift, iff) => {
             let rv_v = FreeVar::fresh_named("rv");
-            let cont = Rc::new(AExp::Lam1(Scope::new(
-                Binder(rv_v.clone()),
+            let cont = Rc::new(AExp::Lam1(open_scope(
+                rv_v.clone(),
                 Rc::new(fk(Rc::new(AExp::Var(Var::Free(rv_v))))),
             )));
             t_k(clone_rc(c), &|c| {
@@ -250,8 +259,8 @@ fn t_k(expr: Expr, fk: &dyn Fn(Rc<AExp>) -> CExp) -> CExp {
         }
         Expr::App(f, e) => {
             let rv_v = FreeVar::fresh_named("rv");
-            let cont = Rc::new(AExp::Lam1(Scope::new(
-                Binder(rv_v.clone()),
+            let cont = Rc::new(AExp::Lam1(open_scope(
+                rv_v.clone(),
                 Rc::new(fk(Rc::new(AExp::Var(Var::Free(rv_v))))),
             )));
 
@@ -264,11 +273,12 @@ fn t_k(expr: Expr, fk: &dyn Fn(Rc<AExp>) -> CExp) -> CExp {
     }
 }
 
+/// The CPS transform of `expr`, which has its scopes opened by `Expr::open`,
+/// passing its value to `c`. The scopes of the result are left open, binding
+/// their variables by name, for `CExp::into_fexpr` to close.
 pub fn t_c(expr: Expr, c: Rc<AExp>) -> CExp {
     match expr {
-        e @ (Expr::Lam(_) | Expr::Var(_) | Expr::Lit(_) | Expr::BuiltinIdent(_)) => {
-            CExp::Call1(c, Rc::new(m(e)))
-        }
+        Expr::Atom(a) => CExp::Call1(c, Rc::new(m(a))),
         Expr::Set(n, e) => t_k(clone_rc(e), &|e| {
             CExp::SetThen(
                 n.clone(),
@@ -282,8 +292,8 @@ pub fn t_c(expr: Expr, c: Rc<AExp>) -> CExp {
         Expr::If(cond, ift, iff) => {
             let k = FreeVar::fresh_named("k");
             CExp::Call1(
-                Rc::new(AExp::Lam1(Scope::new(
-                    Binder(k.clone()),
+                Rc::new(AExp::Lam1(open_scope(
+                    k.clone(),
                     Rc::new(t_k(clone_rc(cond.clone()), &|cond| {
                         CExp::If(
                             cond,
@@ -309,17 +319,19 @@ pub fn t_c(expr: Expr, c: Rc<AExp>) -> CExp {
     }
 }
 
-pub fn m(expr: Expr) -> AExp {
-    match expr {
-        Expr::Lam(s) => {
-            let (p, t) = s.unbind();
+pub fn m(atom: Atom) -> AExp {
+    match atom {
+        Atom::Lam(s, line) => {
+            let Binder(p) = s.unsafe_pattern;
             let k = FreeVar::fresh_named("k");
-            let body = t_c(clone_rc(t), Rc::new(AExp::Var(Var::Free(k.clone()))));
-            AExp::Lam2(Scope::new(p, Scope::new(Binder(k), Rc::new(body))))
+            let body = t_c(
+                clone_rc(s.unsafe_body),
+                Rc::new(AExp::Var(Var::Free(k.clone()))),
+            );
+            AExp::Lam2(open_scope(p, open_scope(k, Rc::new(body))), line)
         }
-        Expr::Var(v) => AExp::Var(v),
-        Expr::BuiltinIdent(v) => AExp::BuiltinIdent(v),
-        Expr::Lit(v) => AExp::Lit(v),
-        _ => unreachable!(),
+        Atom::Var(v) => AExp::Var(v),
+        Atom::BuiltinIdent(v) => AExp::BuiltinIdent(v),
+        Atom::Lit(v) => AExp::Lit(v),
     }
 }
@@ -5,6 +5,7 @@
 #include <stdlib.h>
 #include <string.h>
 #include <sys/resource.h>
+#include <time.h>
 
 #include "base.h"
 #include "common.h"
@@ -12,16 +13,84 @@
 #include "hash_table.h"
 #include "vec.h"
 
-static bool stack_check(void);
+struct bool_obj scheme_true = {
+    .base = {.tag = OBJ_BOOL, .mark = WHITE, .on_stack = false}, .val = true};
+struct bool_obj scheme_false = {
+    .base = {.tag = OBJ_BOOL, .mark = WHITE, .on_stack = false}, .val = false};
+
+struct rt_stats rt_stats;
+
+static const char *const tag_names[LAST_OBJ_TYPE + 1] = {
+    [OBJ_CLOSURE] = "closure",
+    [ENV_OBJ] = "env",
+    [OBJ_INT] = "int",
+    [OBJ_STR] = "string",
+    [OBJ_CONS] = "cons",
+    [OBJ_CELL] = "cell",
+    [OBJ_HT] = "hash table",
+    [OBJ_FLOAT] = "float",
+    [OBJ_RATIONAL] = "rational",
+    [OBJ_SYMBOL] = "symbol",
+    [OBJ_VECTOR] = "vector",
+    [OBJ_BYTEVECTOR] = "bytevector",
+    [OBJ_BOOL] = "bool",
+    [OBJ_PORT] = "port",
+    [OBJ_ERROR] = "error",
+    [OBJ_PROMISE] = "promise",
+    [OBJ_RECORD_TYPE] = "record type",
+    [OBJ_RECORD] = "record",
+    [OBJ_ENVIRONMENT] = "environment",
+};
+
+const char *object_tag_name(enum object_tag tag) { return tag_names[tag]; }
+
+void rt_stats_env(size_t len) {
+  rt_stats.envs++;
+  rt_stats.env_slots += len;
+
+  if (len > rt_stats.largest_env) {
+    rt_stats.largest_env = len;
+  }
+}
+
+static void rt_stats_print(void) {
+  fprintf(stderr, "allocations:\n");
+
+  for (int tag = 1; tag <= LAST_OBJ_TYPE; tag++) {
+    if (rt_stats.allocs[tag]) {
+      fprintf(stderr, "  %-12s %zu\n", tag_names[tag], rt_stats.allocs[tag]);
+    }
+  }
+
+  fprintf(stderr,
+          "heap: %zu allocations, %zu bytes, at most %zu objects at once\n",
+          rt_stats.heap_allocs, rt_stats.heap_bytes,
+          rt_stats.peak_heap_objects);
+  fprintf(stderr,
+          "gc: %zu collections, %zu of the whole heap, %.3f ms in total, "
+          "longest %.3f ms\n",
+          rt_stats.collections, rt_stats.major_collections,
+          rt_stats.gc_seconds * 1000, rt_stats.longest_gc_seconds * 1000);
+  fprintf(stderr, "environments: %zu, %.2f slots on average, largest %zu\n",
+          rt_stats.envs,
+          rt_stats.envs ? (double)rt_stats.env_slots / rt_stats.envs : 0.0,
+          rt_stats.largest_env);
+}
+
+static double seconds(void) {
+  struct timespec t;
+  clock_gettime(CLOCK_MONOTONIC, &t);
+  return t.tv_sec + t.tv_nsec / 1e9;
+}
 
 static struct thunk *current_thunk;
 static void *stack_initial;
 static jmp_buf setjmp_env_buf;
 
 void call_closure_one(struct obj *rator, struct obj *rand) {
-  if (rator->tag != OBJ_CLOSURE) {
+  if (obj_tag(rator) != OBJ_CLOSURE) {
     RUNTIME_ERROR("Called object (%p) was not a closure but was: %d", rator,
-                  rator->tag);
+                  obj_tag(rator));
   }
 
   struct closure_obj *closure = (struct closure_obj *)rator;
@@ -43,9 +112,9 @@ void call_closure_one(struct obj *rator, struct obj *rand) {
 }
 
 void call_closure_two(struct obj *rator, struct obj *rand, struct obj *cont) {
-  if (rator->tag != OBJ_CLOSURE) {
+  if (obj_tag(rator) != OBJ_CLOSURE) {
     RUNTIME_ERROR("Called object (%p) was not a closure but was: %d", rator,
-                  rator->tag);
+                  obj_tag(rator));
   }
 
   struct closure_obj *closure = (struct closure_obj *)rator;
@@ -82,9 +151,10 @@ static size_t get_stack_limit(void) {
 static void *stack_ptr(void) { return __builtin_frame_address(0); }
 
 /*
- * Are we above the stack limit
+ * Are we above the stack limit, called by compiled lambdas before calling
+ * one directly
  */
-static bool stack_check(void) {
+bool stack_check(void) {
   // buffer area at the end of the stack since idk how accurate this is
   // so reserve 256K for anything we might do after getting to the 'limit'
   static size_t stack_buffer = 1024 * 256;
@@ -95,12 +165,46 @@ static bool stack_check(void) {
   return stack_ptr_val > stack_end_val;
 }
 
+// Writes the call counts to $SCHEME_PROFILE, or scheme.prof, for `report`
+static void profile_dump(void) {
+  const char *path = getenv("SCHEME_PROFILE");
+  FILE *fp = fopen(path ? path : "scheme.prof", "w");
+
+  if (!fp) {
+    perror("writing the profile");
+    return;
+  }
+
+  for (struct profile_entry *e = scheme_profile; e->name; e++) {
+    fprintf(fp, "%zu\t%s\t%s:%zu\n", e->count, e->name, e->file, e->line);
+  }
+
+  fclose(fp);
+}
+
+int scheme_argc;
+char **scheme_argv;
+
+void scheme_set_args(int argc, char **argv) {
+  scheme_argc = argc;
+  scheme_argv = argv;
+}
+
 void scheme_start(struct thunk *initial_thunk) {
   stack_initial = stack_ptr();
   current_thunk = initial_thunk;
 
   gc_init();
 
+  if (getenv("SCHEME_RT_STATS")) {
+    rt_stats.enabled = true;
+    atexit(rt_stats_print);
+  }
+
+  if (scheme_profile) {
+    atexit(profile_dump);
+  }
+
   // This is our trampoline, when we come back from a longjmp a different
   // current_thunk will be set and we will just trampoline into the new
   // thunk
@@ -129,15 +233,31 @@ void scheme_start(struct thunk *initial_thunk) {
 void run_minor_gc(struct thunk *thnk) {
   current_thunk = thnk;
 
+  double start = rt_stats.enabled ? seconds() : 0;
+
   struct gc_context ctx = gc_make_context();
   gc_minor(&ctx, thnk);
   gc_free_context(&ctx);
 
+  if (rt_stats.enabled) {
+    double pause = seconds() - start;
+    rt_stats.collections++;
+    rt_stats.gc_seconds += pause;
+
+    if (pause > rt_stats.longest_gc_seconds) {
+      rt_stats.longest_gc_seconds = pause;
+    }
+  }
+
   // Jump back to the start
   longjmp(setjmp_env_buf, 1);
 }
 
 struct obj object_base_new(enum object_tag tag) {
+  if (rt_stats.enabled) {
+    rt_stats.allocs[tag]++;
+  }
+
   return (struct obj){
       .tag = tag,
       .mark = WHITE,
@@ -170,11 +290,141 @@ struct int_obj object_int_obj_new(int64_t val) {
   return (struct int_obj){.base = object_base_new(OBJ_INT), .val = val};
 }
 
+// Makes an integer that doesn't live on the stack, a fixnum if it fits and a
+// heap allocated int_obj otherwise
+struct obj *object_int_new(int64_t val) {
+  if (FIXNUM_FITS(val)) {
+    return MAKE_FIXNUM(val);
+  }
+
+  struct int_obj *obj = gc_malloc(sizeof(struct int_obj));
+  *obj = object_int_obj_new(val);
+  obj->base.on_stack = false;
+
+  return (struct obj *)obj;
+}
+
 struct cons_obj object_cons_obj_new(struct obj *car, struct obj *cdr) {
   return (struct cons_obj){
       .base = object_base_new(OBJ_CONS), .car = car, .cdr = cdr};
 }
 
+struct float_obj object_float_obj_new(double val) {
+  return (struct float_obj){.base = object_base_new(OBJ_FLOAT), .val = val};
+}
+
+struct obj *object_float_new(double val) {
+  struct float_obj *obj = gc_malloc(sizeof(struct float_obj));
+  *obj = object_float_obj_new(val);
+  obj->base.on_stack = false;
+
+  return (struct obj *)obj;
+}
+
+// Makes a string that doesn't live on the stack from the first len bytes of
+// buf
+struct obj *object_string_new(const char *buf, size_t len) {
+  struct string_obj *obj = gc_malloc(sizeof(struct string_obj) + len + 1);
+  obj->base = object_base_new(OBJ_STR);
+  obj->base.on_stack = false;
+  obj->len = len + 1;
+  memcpy((char *)&obj->buf, buf, len);
+  ((char *)&obj->buf)[len] = '\0';
+
+  return (struct obj *)obj;
+}
+
+struct rational_obj object_rational_obj_new(int64_t num, int64_t den) {
+  return (struct rational_obj){
+      .base = object_base_new(OBJ_RATIONAL), .num = num, .den = den};
+}
+
+static uint64_t gcd(uint64_t a, uint64_t b) {
+  while (b != 0) {
+    uint64_t t = a % b;
+    a = b;
+    b = t;
+  }
+
+  return a;
+}
+
+static uint64_t unsigned_abs(int64_t v) {
+  return v < 0 ? -(uint64_t)v : (uint64_t)v;
+}
+
+// Puts the ratio into lowest terms, giving back an integer if that's what it
+// ends up as
+struct obj *object_rational_new(int64_t num, int64_t den) {
+  if (den == 0)
+    RUNTIME_ERROR("Rational with a zero denominator");
+
+  uint64_t g = gcd(unsigned_abs(num), unsigned_abs(den));
+  if (g > 1) {
+    num /= (int64_t)g;
+    den /= (int64_t)g;
+  }
+
+  if (den < 0) {
+    if (__builtin_sub_overflow(0, num, &num) ||
+        __builtin_sub_overflow(0, den, &den))
+      RUNTIME_ERROR("Integer overflow in rational");
+  }
+
+  if (den == 1)
+    return object_int_new(num);
+
+  struct rational_obj *obj = gc_malloc(sizeof(struct rational_obj));
+  *obj = object_rational_obj_new(num, den);
+  obj->base.on_stack = false;
+
+  return (struct obj *)obj;
+}
+
+struct vector_obj *object_vector_new(size_t len) {
+  struct vector_obj *vec =
+      gc_malloc(sizeof(struct vector_obj) + len * sizeof(struct obj *));
+  vec->base = object_base_new(OBJ_VECTOR);
+  vec->base.on_stack = false;
+  vec->len = len;
+
+  return vec;
+}
+
+struct record_type_obj *object_record_type_new(struct symbol_obj *name,
+                                               size_t len) {
+  struct record_type_obj *type = gc_malloc(sizeof(struct record_type_obj));
+  type->base = object_base_new(OBJ_RECORD_TYPE);
+  type->base.on_stack = false;
+  type->name = name;
+  type->len = len;
+
+  return type;
+}
+
+struct record_obj *object_record_new(struct record_type_obj *type) {
+  struct record_obj *rec =
+      gc_malloc(sizeof(struct record_obj) + type->len * sizeof(struct obj *));
+  rec->base = object_base_new(OBJ_RECORD);
+  rec->base.on_stack = false;
+  rec->type = type;
+
+  for (size_t i = 0; i < type->len; i++) {
+    rec->fields[i] = NULL;
+  }
+
+  return rec;
+}
+
+struct bytevector_obj *object_bytevector_new(size_t len) {
+  struct bytevector_obj *bvec = gc_malloc(sizeof(struct bytevector_obj) + len);
+  bvec->base = object_base_new(OBJ_BYTEVECTOR);
+  bvec->base.on_stack = false;
+  bvec->len = len;
+
+  return bvec;
+}
+
 static size_t hash_string(const char *buf, size_t len) {
   size_t hash = 14695981039346656037ull;
 
@@ -196,47 +446,98 @@ static size_t hash_combine(size_t a, size_t b) {
   return hash;
 }
 
-size_t hash_obj_impl(struct obj *obj) {
+// pairs and vectors hashed before the rest of an object is left out of its
+// hash, so that a cyclic object has one
+#define HASH_CONTAINER_LIMIT 64
+
+// The budget is shared by everything hashed, so equal objects, which have the
+// same elements in the same order, run out of it at the same place.
+static size_t hash_obj_bounded(struct obj *obj, size_t *budget) {
   if (!obj) {
     return 0;
   }
 
-  switch (obj->tag) {
+  switch (obj_tag(obj)) {
   case OBJ_INT:
-    return hash_table_default_size_t_hash_fun(((struct int_obj *)obj)->val);
+    return hash_table_default_size_t_hash_fun(obj_int_val(obj));
   case OBJ_STR: {
     struct string_obj *str_obj = (struct string_obj *)obj;
     return hash_string(str_obj->buf, str_obj->len);
   }
   case OBJ_CONS: {
     struct cons_obj *cons_obj = (struct cons_obj *)obj;
-    size_t a = hash_obj_impl(cons_obj->car);
-    size_t b = hash_obj_impl(cons_obj->cdr);
+
+    if (*budget == 0)
+      return OBJ_CONS;
+    (*budget)--;
+
+    size_t a = hash_obj_bounded(cons_obj->car, budget);
+    size_t b = hash_obj_bounded(cons_obj->cdr, budget);
     return hash_combine(a, b);
   }
-  case OBJ_HT: {
-    struct ht_obj *ht_obj = (struct ht_obj *)obj;
+  case OBJ_FLOAT: {
+    double val = ((struct float_obj *)obj)->val;
+    uint64_t bits;
+
+    // 0.0 and -0.0 are equal so need to hash the same
+    if (val == 0.0)
+      val = 0.0;
+    memcpy(&bits, &val, sizeof(bits));
+
+    return hash_table_default_size_t_hash_fun(bits);
+  }
+  case OBJ_RATIONAL: {
+    struct rational_obj *r = (struct rational_obj *)obj;
+    return hash_combine(hash_table_default_size_t_hash_fun(r->num),
+                        hash_table_default_size_t_hash_fun(r->den));
+  }
+  // equal? compares these by identity
+  case OBJ_HT:
+  case OBJ_SYMBOL:
+  case OBJ_BOOL:
+  case OBJ_PORT:
+  case OBJ_RECORD_TYPE:
+  case OBJ_RECORD:
+  case OBJ_ENVIRONMENT:
+    return hash_table_default_size_t_hash_fun((size_t)obj);
+  case OBJ_VECTOR: {
+    struct vector_obj *vec = (struct vector_obj *)obj;
     size_t hash = 14695981039346656037ull;
 
-    HASH_TABLE_ITER(obj, key, val, ht_obj->ht, {
-      hash = hash_combine(hash, hash_obj_impl(*key));
-      hash = hash_combine(hash, hash_obj_impl(*val));
-    });
+    if (*budget == 0)
+      return OBJ_VECTOR;
+    (*budget)--;
+
+    for (size_t i = 0; i < vec->len; i++) {
+      hash = hash_combine(hash, hash_obj_bounded(vec->elems[i], budget));
+    }
 
     return hash;
   }
+  case OBJ_BYTEVECTOR: {
+    struct bytevector_obj *bvec = (struct bytevector_obj *)obj;
+    return hash_string((const char *)bvec->bytes, bvec->len);
+  }
   case OBJ_CELL:
-    return hash_obj_impl(((struct cell_obj *)obj)->val);
+    return hash_obj_bounded(((struct cell_obj *)obj)->val, budget);
   default:
-    RUNTIME_ERROR("Unhashable type: %d", obj->tag);
+    RUNTIME_ERROR("Unhashable type: %d", obj_tag(obj));
   }
 }
 
-bool eq_obj_impl(struct obj *a, struct obj *b) {
-  if (!a && !b)
+size_t hash_obj_impl(struct obj *obj) {
+  size_t budget = HASH_CONTAINER_LIMIT;
+
+  return hash_obj_bounded(obj, &budget);
+}
+
+// same object, or numbers of the same exactness and value
+bool eqv_obj_impl(struct obj *a, struct obj *b) {
+  // fixnums, null and symbols are all decided here without touching memory
+  if (a == b)
     return true;
 
-  if (!a || !b)
+  if (!a || !b || IS_FIXNUM(a) || IS_FIXNUM(b))
     return false;
 
   if (a->tag != b->tag)
@@ -245,58 +546,247 @@ bool eq_obj_impl(struct obj *a, struct obj *b) {
   switch (a->tag) {
   case OBJ_INT:
     return ((struct int_obj *)a)->val == ((struct int_obj *)b)->val;
-  case OBJ_STR: {
-    struct string_obj *str_obj_a = (struct string_obj *)a;
-    struct string_obj *str_obj_b = (struct string_obj *)b;
+  case OBJ_FLOAT:
+    // compare bits so that nans are eqv to themselves and 0.0 isn't -0.0
+    return memcmp(&((struct float_obj *)a)->val, &((struct float_obj *)b)->val,
+                  sizeof(double)) == 0;
+  case OBJ_RATIONAL: {
+    struct rational_obj *ra = (struct rational_obj *)a;
+    struct rational_obj *rb = (struct rational_obj *)b;
+    return ra->num == rb->num && ra->den == rb->den;
+  }
+  default:
+    return false;
+  }
+}
 
-    if (str_obj_a->len != str_obj_b->len)
-      return false;
+// equal? first compares this many pairs and vectors without keeping track of
+// them, which is enough unless the objects are cyclic or very large
+#define EQUAL_FAST_LIMIT 1000
+
+struct equal_pair {
+  struct obj *a;
+  struct obj *b;
+};
+
+static size_t hash_equal_pair(struct equal_pair p) {
+  return hash_combine(hash_table_default_size_t_hash_fun((size_t)p.a),
+                      hash_table_default_size_t_hash_fun((size_t)p.b));
+}
+
+static bool equal_pair_eq(struct equal_pair x, struct equal_pair y) {
+  return x.a == y.a && x.b == y.b;
+}
+
+DEFINE_HASH(struct equal_pair, bool, equal_seen);
+MAKE_HASH(struct equal_pair, bool, hash_equal_pair, equal_pair_eq, equal_seen);
+
+// Without seen this gives up with -1 once the budget runs out. With seen it
+// holds the pairs and vectors compared so far, which are taken to be equal
+// when they are met again, so that comparing cyclic objects ends.
+static int equal_inner(struct obj *a, struct obj *b, size_t *budget,
+                       struct hash_table_equal_seen *seen) {
+  // the cdrs of lists are compared in the loop
+  for (;;) {
+    if (eqv_obj_impl(a, b))
+      return 1;
+
+    if (!a || !b || obj_tag(a) != obj_tag(b))
+      return 0;
+
+    switch (obj_tag(a)) {
+    case OBJ_STR: {
+      struct string_obj *str_obj_a = (struct string_obj *)a;
+      struct string_obj *str_obj_b = (struct string_obj *)b;
+
+      return str_obj_a->len == str_obj_b->len &&
+             strncmp(str_obj_a->buf, str_obj_b->buf, str_obj_a->len) == 0;
+    }
+    case OBJ_BYTEVECTOR: {
+      struct bytevector_obj *bvec_a = (struct bytevector_obj *)a;
+      struct bytevector_obj *bvec_b = (struct bytevector_obj *)b;
+
+      return bvec_a->len == bvec_b->len &&
+             memcmp(bvec_a->bytes, bvec_b->bytes, bvec_a->len) == 0;
+    }
+    case OBJ_CONS:
+    case OBJ_VECTOR:
+      break;
+    default:
+      return 0;
+    }
+
+    if (seen) {
+      struct equal_pair p = {a, b};
+
+      if (hash_table_equal_seen_lookup(seen, p))
+        return 1;
+
+      hash_table_equal_seen_insert(seen, p, true);
+    } else {
+      if (*budget == 0)
+        return -1;
+      (*budget)--;
+    }
+
+    if (obj_tag(a) == OBJ_VECTOR) {
+      struct vector_obj *vec_a = (struct vector_obj *)a;
+      struct vector_obj *vec_b = (struct vector_obj *)b;
+
+      if (vec_a->len != vec_b->len)
+        return 0;
+
+      for (size_t i = 0; i < vec_a->len; i++) {
+        int res = equal_inner(vec_a->elems[i], vec_b->elems[i], budget, seen);
+
+        if (res != 1)
+          return res;
+      }
+
+      return 1;
+    }
 
-    return strncmp(str_obj_a->buf, str_obj_b->buf, str_obj_a->len) == 0;
-  }
-  case OBJ_CONS: {
     struct cons_obj *cons_obj_a = (struct cons_obj *)a;
     struct cons_obj *cons_obj_b = (struct cons_obj *)b;
-    return eq_obj_impl(cons_obj_a->car, cons_obj_b->car) &&
-           eq_obj_impl(cons_obj_a->cdr, cons_obj_b->cdr);
+    int res = equal_inner(cons_obj_a->car, cons_obj_b->car, budget, seen);
+
+    if (res != 1)
+      return res;
+
+    a = cons_obj_a->cdr;
+    b = cons_obj_b->cdr;
   }
-  case OBJ_HT: {
-    struct ht_obj *ht_obj_a = (struct ht_obj *)a;
-    struct ht_obj *ht_obj_b = (struct ht_obj *)b;
+}
 
-    HASH_TABLE_ITER(obj, key, val, ht_obj_a->ht, {
-      typeof(*val) *b_val = hash_table_obj_lookup(ht_obj_b->ht, *key);
+// structural equality over strings, pairs and vectors, eqv for everything
+// else
+bool equal_obj_impl(struct obj *a, struct obj *b) {
+  size_t budget = EQUAL_FAST_LIMIT;
+  int res = equal_inner(a, b, &budget, NULL);
 
-      if (!b_val)
-        return false;
+  if (res >= 0)
+    return res;
 
-      if (!eq_obj_impl(*val, *b_val))
-        return false;
-    });
+  struct hash_table_equal_seen *seen = hash_table_equal_seen_new();
+  res = equal_inner(a, b, NULL, seen);
+  hash_table_equal_seen_free(seen);
+  free(seen);
 
-    HASH_TABLE_ITER(obj, key, val, ht_obj_b->ht, {
-      typeof(*val) *a_val = hash_table_obj_lookup(ht_obj_a->ht, *key);
+  return res;
+}
 
-      if (!a_val)
-        return false;
+MAKE_HASH(struct obj *, struct obj *, hash_obj_impl, equal_obj_impl, obj);
 
-      // we don't need to check equality of values this time
-    });
+struct ht_obj object_ht_obj_new() {
+  struct hash_table_obj *ht = hash_table_obj_new();
 
-    return true;
+  return (struct ht_obj){.base = object_base_new(OBJ_HT), .ht = ht};
+}
+
+static size_t hash_c_string(const char *s) { return hash_string(s, strlen(s)); }
+
+static bool c_string_eq(const char *a, const char *b) {
+  return strcmp(a, b) == 0;
+}
+
+DEFINE_HASH(const char *, struct symbol_obj *, symbol);
+MAKE_HASH(const char *, struct symbol_obj *, hash_c_string, c_string_eq,
+          symbol);
+
+static struct hash_table_symbol *symbol_table;
+
+static struct hash_table_symbol *get_symbol_table(void) {
+  if (!symbol_table) {
+    symbol_table = hash_table_symbol_new();
   }
-  case OBJ_CELL:
-    return eq_obj_impl(((struct cell_obj *)a)->val,
-                       ((struct cell_obj *)b)->val);
-  default:
-    return false;
+
+  return symbol_table;
+}
+
+// Adds the NULL terminated array of symbols to the intern table
+void symbol_register_static(struct symbol_obj **symbols) {
+  for (; *symbols; symbols++) {
+    hash_table_symbol_insert(get_symbol_table(), (*symbols)->name, *symbols);
   }
 }
 
-MAKE_HASH(struct obj *, struct obj *, hash_obj_impl, eq_obj_impl, obj);
+static struct lambda_info **lambda_tables;
+static size_t lambda_tables_len;
 
-struct ht_obj object_ht_obj_new() {
-  struct hash_table_obj *ht = hash_table_obj_new();
+void lambda_info_register(struct lambda_info *table) {
+  lambda_tables =
+      realloc(lambda_tables, (lambda_tables_len + 1) * sizeof(*lambda_tables));
+  lambda_tables[lambda_tables_len++] = table;
+}
 
-  return (struct ht_obj){.base = object_base_new(OBJ_HT), .ht = ht};
+// The lambda of the program that closure calls, NULL for the closures of the
+// runtime. Only used for errors, so it just searches every table.
+struct lambda_info *lambda_info_of(struct closure_obj *closure) {
+  for (size_t i = 0; i < lambda_tables_len; i++) {
+    for (struct lambda_info *info = lambda_tables[i]; info->fn; info++) {
+      if (info->fn == (void *)closure->fn_1)
+        return info;
+    }
+  }
+
+  return NULL;
+}
+
+static struct symbol_obj *symbol_new(const char *name) {
+  size_t len = strlen(name);
+  char *name_copy = malloc(len + 1);
+  memcpy(name_copy, name, len + 1);
+
+  struct symbol_obj *sym = malloc(sizeof(struct symbol_obj));
+  *sym = (struct symbol_obj){.base = object_base_new(OBJ_SYMBOL),
+                             .len = len,
+                             .name = name_copy};
+  sym->base.on_stack = false;
+
+  return sym;
+}
+
+struct obj *symbol_intern(const char *name) {
+  struct symbol_obj **existing =
+      hash_table_symbol_lookup(get_symbol_table(), name);
+
+  if (existing) {
+    return (struct obj *)*existing;
+  }
+
+  struct symbol_obj *sym = symbol_new(name);
+  hash_table_symbol_insert(get_symbol_table(), sym->name, sym);
+
+  return (struct obj *)sym;
+}
+
+// How many uninterned symbols have been made, gensym numbers its names by it
+static size_t uninterned_count;
+
+// A symbol that isn't in the intern table, so is only eq? to itself and never
+// to one of the same name that is. Like interned ones it is never freed.
+struct obj *symbol_uninterned(const char *name) {
+  uninterned_count++;
+
+  return (struct obj *)symbol_new(name);
+}
+
+// An uninterned symbol named `prefix` followed by the number of uninterned
+// symbols made so far, including it.
+struct obj *symbol_gensym(const char *prefix) {
+  size_t len = strlen(prefix) + 21;
+  char *name = malloc(len);
+  snprintf(name, len, "%s%zu", prefix, uninterned_count + 1);
+
+  struct obj *sym = symbol_uninterned(name);
+  free(name);
+
+  return sym;
+}
+
+bool symbol_is_interned(struct obj *sym) {
+  struct symbol_obj **existing = hash_table_symbol_lookup(
+      get_symbol_table(), ((struct symbol_obj *)sym)->name);
+
+  return existing && (struct obj *)*existing == sym;
 }
@@ -2,6 +2,8 @@
 #define SOMESCHEME_H
 
 #include <stdbool.h>
+#include <stdint.h>
+#include <stdio.h>
 #include <stdlib.h>
 
 #include "common.h"
@@ -23,15 +25,66 @@
     (NAME) = (struct obj *)new_obj;                                            \
   } while (0)
 
+// Small integers are stored in the pointer itself with the low bit set, real
+// objects are always word aligned so the bit is otherwise unused. On a 32 bit
+// machine they have 31 bits, larger integers are int_objs as they are here.
+#define FIXNUM_MIN (INTPTR_MIN >> 1)
+#define FIXNUM_MAX (INTPTR_MAX >> 1)
+#define FIXNUM_FITS(n) ((n) >= FIXNUM_MIN && (n) <= FIXNUM_MAX)
+#define IS_FIXNUM(OBJ) (((uintptr_t)(OBJ)) & 1)
+#define FIXNUM_VAL(OBJ) ((int64_t)(intptr_t)(OBJ) >> 1)
+#define MAKE_FIXNUM(n) ((struct obj *)(((uintptr_t)(int64_t)(n) << 1) | 1))
+
 #define OBJECT_INT_OBJ_NEW(NAME, n)                                            \
   struct obj *(NAME);                                                          \
   do {                                                                         \
+    int64_t new_val = (n);                                                     \
+    if (FIXNUM_FITS(new_val)) {                                                \
+      (NAME) = MAKE_FIXNUM(new_val);                                           \
+      break;                                                                   \
+    }                                                                          \
     struct int_obj *new_obj = alloca(sizeof(struct int_obj));                  \
-    *new_obj = object_int_obj_new((n));                                        \
+    *new_obj = object_int_obj_new(new_val);                                    \
     TOUCH_OBJECT(new_obj, "int_obj_new");                                      \
     (NAME) = (struct obj *)new_obj;                                            \
   } while (0)
 
+// Arithmetic the compiler knows is on two integers, OP is add, sub or mul
+#define INT_ARITH_OBJ_NEW(NAME, OP, LHS, RHS)                                  \
+  struct obj *(NAME);                                                          \
+  do {                                                                         \
+    int64_t new_val;                                                           \
+    if (__builtin_##OP##_overflow((LHS), (RHS), &new_val))                     \
+      RUNTIME_ERROR("Integer overflow in binary " #OP);                        \
+    if (FIXNUM_FITS(new_val)) {                                                \
+      (NAME) = MAKE_FIXNUM(new_val);                                           \
+      break;                                                                   \
+    }                                                                          \
+    struct int_obj *new_obj = alloca(sizeof(struct int_obj));                  \
+    *new_obj = object_int_obj_new(new_val);                                    \
+    TOUCH_OBJECT(new_obj, "int_arith_obj_new");                                \
+    (NAME) = (struct obj *)new_obj;                                            \
+  } while (0)
+
+#define OBJECT_FLOAT_OBJ_NEW(NAME, f)                                          \
+  struct obj *(NAME);                                                          \
+  do {                                                                         \
+    struct float_obj *new_obj = alloca(sizeof(struct float_obj));              \
+    *new_obj = object_float_obj_new((f));                                      \
+    TOUCH_OBJECT(new_obj, "float_obj_new");                                    \
+    (NAME) = (struct obj *)new_obj;                                            \
+  } while (0)
+
+// The ratio should already be normalized, see object_rational_new otherwise
+#define OBJECT_RATIONAL_OBJ_NEW(NAME, n, d)                                    \
+  struct obj *(NAME);                                                          \
+  do {                                                                         \
+    struct rational_obj *new_obj = alloca(sizeof(struct rational_obj));        \
+    *new_obj = object_rational_obj_new((n), (d));                              \
+    TOUCH_OBJECT(new_obj, "rational_obj_new");                                 \
+    (NAME) = (struct obj *)new_obj;                                            \
+  } while (0)
+
 #define ENV_STRUCT(T)                                                          \
   struct {                                                                     \
     struct obj base;                                                           \
@@ -46,6 +99,9 @@
     new_env->base = object_base_new(ENV_OBJ);                                  \
     new_env->len = sizeof(S) / sizeof(struct obj *);                           \
     memset(&new_env->env, 0, sizeof(S));                                       \
+    if (rt_stats.enabled) {                                                    \
+      rt_stats_env(new_env->len);                                              \
+    }                                                                          \
     (NAME) = (struct env_obj *)new_env;                                        \
   } while (0)
 
@@ -67,6 +123,19 @@
     (NAME) = (struct obj *)new_obj;                                            \
   } while (0)
 
+// The closures of lambdas capturing nothing are static objects, made once
+#define CLOSURE_ONE_OBJ_INIT(FN)                                               \
+  {                                                                            \
+    .base = {.tag = OBJ_CLOSURE, .mark = WHITE, .on_stack = false},            \
+    .size = CLOSURE_ONE, .fn_1 = (FN), .env = NULL                             \
+  }
+
+#define CLOSURE_TWO_OBJ_INIT(FN)                                               \
+  {                                                                            \
+    .base = {.tag = OBJ_CLOSURE, .mark = WHITE, .on_stack = false},            \
+    .size = CLOSURE_TWO, .fn_2 = (FN), .env = NULL                             \
+  }
+
 #define OBJECT_CELL_OBJ_NEW(NAME, VAL)                                         \
   struct obj *(NAME);                                                          \
   do {                                                                         \
@@ -109,9 +178,44 @@ enum __attribute__((__packed__)) object_tag {
   OBJ_CONS,
   OBJ_CELL,
   OBJ_HT,
+  OBJ_FLOAT,
+  OBJ_RATIONAL,
+  OBJ_SYMBOL,
+  OBJ_VECTOR,
+  OBJ_BYTEVECTOR,
+  OBJ_BOOL,
+  OBJ_PORT,
+  OBJ_ERROR,
+  OBJ_PROMISE,
+  OBJ_RECORD_TYPE,
+  OBJ_RECORD,
+  OBJ_ENVIRONMENT,
 };
 
-#define LAST_OBJ_TYPE OBJ_HT
+#define LAST_OBJ_TYPE OBJ_ENVIRONMENT
+
+// Kept when SCHEME_RT_STATS is set in the environment, and printed to stderr
+// when the program exits
+struct rt_stats {
+  bool enabled;
+  // objects made by the program, by tag, not counting copies made by the gc
+  size_t allocs[LAST_OBJ_TYPE + 1];
+  size_t heap_allocs;
+  size_t heap_bytes;
+  size_t peak_heap_objects;
+  size_t collections;
+  // the collections that went through the whole heap
+  size_t major_collections;
+  double gc_seconds;
+  double longest_gc_seconds;
+  size_t envs;
+  size_t env_slots;
+  size_t largest_env;
+};
+
+extern struct rt_stats rt_stats;
+
+void rt_stats_env(size_t);
 
 enum __attribute__((__packed__)) gc_mark_type { WHITE = 0, GREY, BLACK };
 
@@ -158,12 +262,121 @@ struct int_obj {
   int64_t val;
 };
 
+struct float_obj {
+  struct obj base;
+  double val;
+};
+
+// always in lowest terms with den > 1, anything else is an integer
+struct rational_obj {
+  struct obj base;
+  int64_t num;
+  int64_t den;
+};
+
+// Symbols are interned, unless made by string->uninterned-symbol or gensym,
+// and never freed. The ones appearing in the program are static objects
+// emitted by the compiler
+struct symbol_obj {
+  struct obj base;
+  size_t len;
+  const char *name;
+};
+
+#define SYMBOL_OBJ_INIT(S)                                                     \
+  {                                                                            \
+    .base = {.tag = OBJ_SYMBOL, .mark = WHITE, .on_stack = false},             \
+    .len = sizeof(S) - 1, .name = (S)                                          \
+  }
+
+// There are only ever the two static booleans, so they can be compared by
+// pointer
+struct bool_obj {
+  struct obj base;
+  bool val;
+};
+
+extern struct bool_obj scheme_true, scheme_false;
+
+#define MAKE_BOOL(b)                                                           \
+  ((b) ? (struct obj *)&scheme_true : (struct obj *)&scheme_false)
+
+// A closed port has a NULL stream
+struct port_obj {
+  struct obj base;
+  FILE *fp;
+  bool input;
+  // whether read last took #!fold-case rather than #!no-fold-case from it
+  bool fold_case;
+};
+
+// The condition objects made by error
+struct error_obj {
+  struct obj base;
+  struct obj *message;
+  struct obj *irritants;
+};
+
+// Until the promise is forced val holds the thunk that computes it
+struct promise_obj {
+  struct obj base;
+  bool done;
+  struct obj *val;
+};
+
+// Vectors are sized at runtime so are always allocated on the heap
+struct vector_obj {
+  struct obj base;
+  size_t len;
+  struct obj *elems[];
+};
+
+// Made by define-record-type, the type is compared by identity
+struct record_type_obj {
+  struct obj base;
+  struct symbol_obj *name;
+  size_t len;
+};
+
+struct record_obj {
+  struct obj base;
+  struct record_type_obj *type;
+  struct obj *fields[];
+};
+
+struct bytevector_obj {
+  struct obj base;
+  size_t len;
+  uint8_t bytes[];
+};
+
 struct string_obj {
   struct obj base;
   size_t len;
   const char buf[];
 };
 
+// The lists of quotes are static objects emitted by the compiler, made of
+// pairs and of the objects in them, which are never changed
+#define STATIC_OBJ_BASE(TAG)                                                   \
+  { .tag = (TAG), .mark = WHITE, .on_stack = false }
+
+#define CONS_OBJ_INIT(CAR, CDR)                                                \
+  { .base = STATIC_OBJ_BASE(OBJ_CONS), .car = (CAR), .cdr = (CDR) }
+
+#define INT_OBJ_INIT(N)                                                        \
+  { .base = STATIC_OBJ_BASE(OBJ_INT), .val = (N) }
+
+#define FLOAT_OBJ_INIT(F)                                                      \
+  { .base = STATIC_OBJ_BASE(OBJ_FLOAT), .val = (F) }
+
+#define RATIONAL_OBJ_INIT(N, D)                                                \
+  { .base = STATIC_OBJ_BASE(OBJ_RATIONAL), .num = (N), .den = (D) }
+
+// the length counts the null byte, as OBJECT_STRING_OBJ_NEW's does
+#define STRING_OBJ_INIT(S)                                                     \
+  { .base = STATIC_OBJ_BASE(OBJ_STR), .len = sizeof(S), .buf = S }
+
 
 DEFINE_HASH(struct obj *, struct obj *, obj);
 
@@ -185,11 +398,59 @@ struct thunk {
   };
 };
 
+// The call counts of a program compiled with --profile, one entry per lambda
+// and ended by one with a NULL name
+struct profile_entry {
+  const char *name;
+  const char *file;
+  size_t line;
+  size_t count;
+};
+
+// Only defined by programs compiled with --profile
+extern struct profile_entry scheme_profile[] __attribute__((weak));
+
+// Where a lambda of the program is, each compiled file has a table of these
+// ended by one with a NULL fn
+struct lambda_info {
+  void *fn;
+  const char *name;
+  const char *file;
+  size_t line;
+};
+
+static inline enum object_tag obj_tag(struct obj *obj) {
+  return IS_FIXNUM(obj) ? OBJ_INT : obj->tag;
+}
+
+static inline int64_t obj_int_val(struct obj *obj) {
+  return IS_FIXNUM(obj) ? FIXNUM_VAL(obj) : ((struct int_obj *)obj)->val;
+}
+
 void call_closure_one(struct obj *, struct obj *);
 void call_closure_two(struct obj *, struct obj *, struct obj *);
+bool stack_check(void);
 void scheme_start(struct thunk *);
+// the arguments the program was run with, for command-line
+extern int scheme_argc;
+extern char **scheme_argv;
+void scheme_set_args(int, char **);
 void run_minor_gc(struct thunk *);
 
+void gc_remember(struct obj *);
+void gc_write_barrier(struct obj *, struct obj *);
+
+// Goes after storing a pointer to VAL in OBJ, see gc_write_barrier. The checks
+// are inline since most stores don't need any more.
+#define GC_WRITE_BARRIER(OBJ, VAL)                                             \
+  do {                                                                         \
+    struct obj *gc_wb_obj = (struct obj *)(OBJ);                               \
+    struct obj *gc_wb_val = (struct obj *)(VAL);                               \
+    if (!gc_wb_obj->on_stack && gc_wb_val && !IS_FIXNUM(gc_wb_val) &&          \
+        gc_wb_val->on_stack)                                                   \
+      gc_remember(gc_wb_obj);                                                  \
+  } while (0)
+
 struct obj object_base_new(enum object_tag);
 struct closure_obj object_closure_one_new(void (*)(struct obj *,
                                                    struct env_obj *),
@@ -198,9 +459,31 @@ struct closure_obj object_closure_two_new(void (*)(struct obj *, struct obj *,
                                                    struct env_obj *),
                                           struct env_obj *);
 struct int_obj object_int_obj_new(int64_t);
+struct obj *object_int_new(int64_t);
+struct float_obj object_float_obj_new(double);
+struct obj *object_float_new(double);
+struct obj *object_string_new(const char *, size_t);
+struct rational_obj object_rational_obj_new(int64_t, int64_t);
+struct obj *object_rational_new(int64_t, int64_t);
 struct cons_obj object_cons_obj_new(struct obj *, struct obj *);
 struct ht_obj object_ht_obj_new(void);
-
-bool eq_obj_impl(struct obj *, struct obj *);
+struct vector_obj *object_vector_new(size_t);
+struct bytevector_obj *object_bytevector_new(size_t);
+struct record_type_obj *object_record_type_new(struct symbol_obj *, size_t);
+struct record_obj *object_record_new(struct record_type_obj *);
+
+const char *object_tag_name(enum object_tag);
+void symbol_register_static(struct symbol_obj **);
+void lambda_info_register(struct lambda_info *);
+struct lambda_info *lambda_info_of(struct closure_obj *);
+// called before each call by programs compiled with --trace, in print.c
+void trace_call(const char *, struct obj *, struct obj *, struct obj *);
+struct obj *symbol_intern(const char *);
+struct obj *symbol_uninterned(const char *);
+struct obj *symbol_gensym(const char *);
+bool symbol_is_interned(struct obj *);
+
+bool eqv_obj_impl(struct obj *, struct obj *);
+bool equal_obj_impl(struct obj *, struct obj *);
 
 #endif /* SOMESCHEME_H */
@@ -2,82 +2,84 @@
 #include "base.h"
 #include "common.h"
 #include "gc.h"
+#include "number.h"
+#include "exception.h"
+#include "port.h"
+#include "print.h"
+#include <ctype.h>
+#include <errno.h>
+#include <inttypes.h>
+#include <limits.h>
 #include <stdbool.h>
+#include <time.h>
 
 #define MAKE_INT_BINOP(NAME, OP)                                               \
-  struct int_obj object_int_obj_##NAME(struct obj *lhs, struct obj *rhs) {     \
-    if (lhs->tag != OBJ_INT)                                                   \
+  struct obj *object_int_obj_##NAME(struct obj *lhs, struct obj *rhs) {        \
+    if (obj_tag(lhs) != OBJ_INT)                                               \
       RUNTIME_ERROR("Left operand to binary " #NAME " not of integer type");   \
-    if (rhs->tag != OBJ_INT)                                                   \
+    if (obj_tag(rhs) != OBJ_INT)                                               \
       RUNTIME_ERROR("Right operand to binary " #NAME " not of integer type");  \
                                                                                \
-    struct int_obj *lhs_int = (struct int_obj *)lhs;                           \
-    struct int_obj *rhs_int = (struct int_obj *)rhs;                           \
-                                                                               \
-    return object_int_obj_new(lhs_int->val OP rhs_int->val);                   \
-  } MAKE_TWO_ARG_FROM_BUILTIN(NAME, object_int_obj_##NAME, struct int_obj)
-
-MAKE_INT_BINOP(add, +);
-MAKE_INT_BINOP(sub, -);
-MAKE_INT_BINOP(mul, *);
-MAKE_INT_BINOP(div, /);
+    return object_int_new(obj_int_val(lhs) OP obj_int_val(rhs));               \
+  } MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(NAME, object_int_obj_##NAME)
+
+#define MAKE_NUM_BINOP(NAME, OP)                                               \
+  struct obj *object_num_##NAME(struct obj *lhs, struct obj *rhs) {            \
+    return number_arith(OP, lhs, rhs);                                         \
+  } MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(NAME, object_num_##NAME)
+
+#define MAKE_NUM_CMP(NAME, CMP)                                                \
+  struct obj *object_num_##NAME(struct obj *lhs, struct obj *rhs) {            \
+    return MAKE_BOOL(number_compare(CMP, lhs, rhs));                           \
+  } MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(NAME, object_num_##NAME)
+
+MAKE_NUM_BINOP(add, NUM_ADD);
+MAKE_NUM_BINOP(sub, NUM_SUB);
+MAKE_NUM_BINOP(mul, NUM_MUL);
+MAKE_NUM_BINOP(div, NUM_DIV);
 MAKE_INT_BINOP(xor, ^);
-MAKE_INT_BINOP(lt, <);
-MAKE_INT_BINOP(leq, <=);
-MAKE_INT_BINOP(gt, >);
-MAKE_INT_BINOP(geq, >=);
-
-struct int_obj object_int_obj_mod(struct obj *lhs, struct obj *rhs) {
-  if (lhs->tag != OBJ_INT)
+MAKE_NUM_CMP(lt, NUM_LT);
+MAKE_NUM_CMP(leq, NUM_LEQ);
+MAKE_NUM_CMP(gt, NUM_GT);
+MAKE_NUM_CMP(geq, NUM_GEQ);
+MAKE_NUM_CMP(num_eq, NUM_EQ);
+
+struct obj *object_int_obj_mod(struct obj *lhs, struct obj *rhs) {
+  if (obj_tag(lhs) != OBJ_INT)
     RUNTIME_ERROR("Left operand to binary mod not of integer type");
-  if (rhs->tag != OBJ_INT)
+  if (obj_tag(rhs) != OBJ_INT)
     RUNTIME_ERROR("Right operand to binary mod not of integer type");
 
-  struct int_obj *lhs_int = (struct int_obj *)lhs;
-  struct int_obj *rhs_int = (struct int_obj *)rhs;
+  int64_t lhs_val = obj_int_val(lhs);
+  int64_t rhs_val = obj_int_val(rhs);
 
-  if (rhs_int->val == 0)
-    RUNTIME_ERROR("Divide by zero (%d %% %d)", lhs_int->val, rhs_int->val);
+  if (rhs_val == 0)
+    RUNTIME_ERROR("Divide by zero (%" PRId64 " %% %" PRId64 ")", lhs_val,
+                  rhs_val);
 
-  return object_int_obj_new(lhs_int->val % rhs_int->val);
+  return object_int_new(lhs_val % rhs_val);
 }
 
-MAKE_TWO_ARG_FROM_BUILTIN(mod, object_int_obj_mod, struct int_obj);
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(mod, object_int_obj_mod);
 
 MAKE_TWO_ARG_FROM_BUILTIN(cons, object_cons_obj_new, struct cons_obj);
 
-int exit_inner() { exit(0); }
+// the program ends once every thread has finished
+int halt_inner() {
+  thread_run_next();
+  exit(0);
+}
 
-MAKE_ZERO_ARG_FROM_BUILTIN(exit, exit_inner, int);
+MAKE_ZERO_ARG_FROM_BUILTIN(halt, halt_inner, int);
 
+// The text display prints for val, the caller frees it
 char *obj_to_string_internal(struct obj *val) {
   char *res;
+  size_t len;
+  FILE *fp = open_memstream(&res, &len);
 
-  if (!val) {
-    ALLOC_SPRINTF(res, "()");
-  }
-
-  switch (val->tag) {
-  case OBJ_CONS:
-    ALLOC_SPRINTF(res, "cons");
-    break;
-  case OBJ_CLOSURE:
-    ALLOC_SPRINTF(res, "closure|%p", (void *)((struct closure_obj *)val)->fn_1);
-    break;
-  case OBJ_INT:
-    ALLOC_SPRINTF(res, "%ld", ((struct int_obj *)val)->val);
-    break;
-  case OBJ_STR:
-    ALLOC_SPRINTF(res, "%s", ((struct string_obj *)val)->buf);
-    break;
-  case OBJ_CELL:
-    return obj_to_string_internal(((struct cell_obj *)val)->val);
-  case OBJ_HT:
-    ALLOC_SPRINTF(res, "hash table");
-    break;
-  default:
-    RUNTIME_ERROR("Unexpected object tag to to_string: %d", val->tag);
-  }
+  obj_print(fp, val, false);
+  fclose(fp);
 
   return res;
 }
@@ -94,22 +96,18 @@ void to_string_k(struct obj *v, struct obj *k, struct env_obj *env) {
   __builtin_unreachable();
 }
 
-void display_k(struct obj *v, struct obj *k, struct env_obj *env) {
-  char *res = obj_to_string_internal(v);
-
-  printf("%s\n", res);
-
-  free(res);
-
-  call_closure_one(k, NULL);
-
-  __builtin_unreachable();
-}
 
 _Bool obj_is_truthy(struct obj *obj) {
-  switch (obj->tag) {
+  if (!obj)
+    return false;
+
+  switch (obj_tag(obj)) {
+  case OBJ_BOOL:
+    return ((struct bool_obj *)obj)->val;
   case OBJ_INT:
-    return ((struct int_obj *)obj)->val != 0;
+    return obj_int_val(obj) != 0;
+  case OBJ_FLOAT:
+    return ((struct float_obj *)obj)->val != 0.0;
   case OBJ_STR:
     return ((struct string_obj *)obj)->len != 0;
   default:
@@ -117,8 +115,16 @@ _Bool obj_is_truthy(struct obj *obj) {
   }
 }
 
+// Only programs compiled with --checked have pairs checked for
+static struct cons_obj *cons_arg(const char *name, struct obj *v) {
+  if (&scheme_blame && (!v || obj_tag(v) != OBJ_CONS))
+    RUNTIME_ERROR("Argument to %s was not a pair", name);
+
+  return (struct cons_obj *)v;
+}
+
 void car_k(struct obj *cons, struct obj *k, struct env_obj *env) {
-  struct obj *car = ((struct cons_obj *)cons)->car;
+  struct obj *car = cons_arg("car", cons)->car;
 
   call_closure_one(k, car);
 
@@ -126,7 +132,7 @@ void car_k(struct obj *cons, struct obj *k, struct env_obj *env) {
 }
 
 void cdr_k(struct obj *cons, struct obj *k, struct env_obj *env) {
-  struct obj *cdr = ((struct cons_obj *)cons)->cdr;
+  struct obj *cdr = cons_arg("cdr", cons)->cdr;
 
   call_closure_one(k, cdr);
 
@@ -134,21 +140,46 @@ void cdr_k(struct obj *cons, struct obj *k, struct env_obj *env) {
 }
 
 void is_cons_k(struct obj *v, struct obj *k, struct env_obj *env) {
-  _Bool r = v->tag == OBJ_CONS;
+  call_closure_one(k, MAKE_BOOL(v && obj_tag(v) == OBJ_CONS));
+}
+
+void is_null_k(struct obj *v, struct obj *k, struct env_obj *env) {
+  call_closure_one(k, MAKE_BOOL(v == NULL));
+}
 
-  OBJECT_INT_OBJ_NEW(res, r);
+struct obj *is_zero_inner(struct obj *v) {
+  if (!obj_is_number(v))
+    RUNTIME_ERROR("Argument to zero? was not a number");
 
-  call_closure_one(k, res);
+  return MAKE_BOOL(number_compare(NUM_EQ, v, MAKE_FIXNUM(0)));
 }
 
-void is_null_k(struct obj *v, struct obj *k, struct env_obj *env) {
-  _Bool r = v == NULL;
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_zero, is_zero_inner);
+
+struct obj *is_number_inner(struct obj *v) {
+  return MAKE_BOOL(obj_is_number(v));
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_number, is_number_inner);
+
+struct obj *is_procedure_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_CLOSURE);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_procedure, is_procedure_inner);
+
+struct obj *is_string_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_STR);
+}
 
-  OBJECT_INT_OBJ_NEW(res, r);
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_string, is_string_inner);
 
-  call_closure_one(k, res);
+struct obj *is_boolean_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_BOOL);
 }
 
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_boolean, is_boolean_inner);
+
 void string_concat_k(struct obj *v, struct obj *k, struct env_obj *env) {
   OBJECT_ENV_OBJ_NEW(tmp_env, struct unary_env);
   tmp_env->env[0] = v;
@@ -163,9 +194,9 @@ void string_concat_k(struct obj *v, struct obj *k, struct env_obj *env) {
 static char *convert_to_str(struct obj *v) {
   char *res;
 
-  switch (v->tag) {
+  switch (obj_tag(v)) {
   case OBJ_INT:
-    ALLOC_SPRINTF(res, "%c", (int)((struct int_obj *)v)->val);
+    ALLOC_SPRINTF(res, "%c", (int)obj_int_val(v));
     break;
   case OBJ_STR:
     ALLOC_SPRINTF(res, "%s", ((struct string_obj *)v)->buf);
@@ -174,7 +205,7 @@ static char *convert_to_str(struct obj *v) {
     res = convert_to_str(((struct cell_obj *)v)->val);
     break;
   default:
-    RUNTIME_ERROR("Unexpected object tag to convert_to_str: %d", v->tag);
+    RUNTIME_ERROR("Unexpected object tag to convert_to_str: %d", obj_tag(v));
   }
 
   return res;
@@ -199,31 +230,52 @@ void string_concat_k_2(struct obj *v, struct obj *k, struct env_obj *env) {
   __builtin_unreachable();
 }
 
-struct ht_obj ht_new_inner(struct obj *always_void) {
-  return object_ht_obj_new();
+// hash tables own malloc'd storage, so they start out on the heap where the
+// major gc frees it, a stack allocated table that died before a minor gc would
+// leak it
+struct obj *ht_new_inner(struct obj *always_void) {
+  struct ht_obj *ht = gc_malloc(sizeof(struct ht_obj));
+  *ht = object_ht_obj_new();
+  ht->base.on_stack = false;
+
+  return (struct obj *)ht;
 }
 
-MAKE_ONE_ARG_FROM_BUILTIN(ht_new, ht_new_inner, struct ht_obj);
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(ht_new, ht_new_inner);
+
+// the table's insert doesn't look for an existing entry, so replace the value
+// of one if it's there
+static void ht_store(struct ht_obj *ht, struct obj *k, struct obj *v) {
+  struct obj **existing = hash_table_obj_lookup(ht->ht, k);
+
+  if (existing)
+    *existing = v;
+  else
+    hash_table_obj_insert(ht->ht, k, v);
+
+  GC_WRITE_BARRIER(ht, k);
+  GC_WRITE_BARRIER(ht, v);
+}
 
 struct obj *ht_set_inner(struct obj *ht_obj, struct obj *k, struct obj *v) {
   struct ht_obj *ht = (struct ht_obj *)ht_obj;
 
-  hash_table_obj_insert(ht->ht, k, v);
+  ht_store(ht, k, v);
 
   return NULL;
 }
 
 MAKE_THREE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(ht_set, ht_set_inner);
 
-struct int_obj ht_del_inner(struct obj *ht_obj, struct obj *k) {
+struct obj *ht_del_inner(struct obj *ht_obj, struct obj *k) {
   struct ht_obj *ht = (struct ht_obj *)ht_obj;
 
   bool ret = hash_table_obj_delete(ht->ht, k);
 
-  return object_int_obj_new(ret);
+  return MAKE_BOOL(ret);
 }
 
-MAKE_TWO_ARG_FROM_BUILTIN(ht_del, ht_del_inner, struct int_obj);
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(ht_del, ht_del_inner);
 
 struct obj *ht_get_inner(struct obj *ht_obj, struct obj *k) {
   struct ht_obj *ht = (struct ht_obj *)ht_obj;
@@ -245,6 +297,7 @@ struct obj *ht_keys_inner(struct obj *ht_obj) {
   HASH_TABLE_ITER(obj, key, val, ht->ht, {
     struct cons_obj *c2 = gc_malloc(sizeof(struct cons_obj));
     *c2 = object_cons_obj_new(*key, (struct obj *)c);
+    c2->base.on_stack = false;
     c = c2;
   });
 
@@ -253,25 +306,1727 @@ struct obj *ht_keys_inner(struct obj *ht_obj) {
 
 MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(ht_keys, ht_keys_inner);
 
-struct int_obj eq_inner(struct obj *a, struct obj *b) {
-  return object_int_obj_new(eq_obj_impl(a, b));
+static struct ht_obj *ht_arg(const char *name, struct obj *v) {
+  if (!v || obj_tag(v) != OBJ_HT)
+    RUNTIME_ERROR("Argument to %s was not a hash table", name);
+
+  return (struct ht_obj *)v;
 }
 
-MAKE_TWO_ARG_FROM_BUILTIN(eq, eq_inner, struct int_obj);
+struct obj *is_hash_table_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_HT);
+}
 
-struct obj *string_chars_innner(struct obj *string_obj) {
-  struct string_obj *str = (struct string_obj *)string_obj;
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_hash_table, is_hash_table_inner);
+
+struct obj *hash_table_ref_inner(struct obj *ht_obj, struct obj *k) {
+  struct ht_obj *ht = ht_arg("hash-table-ref", ht_obj);
+
+  struct obj **ret = hash_table_obj_lookup(ht->ht, k);
+
+  if (!ret) {
+    char *key = obj_to_string_internal(k);
+    RUNTIME_ERROR("Key %s not found in hash-table-ref", key);
+  }
+
+  return *ret;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(hash_table_ref, hash_table_ref_inner);
+
+struct obj *hash_table_ref_default_inner(struct obj *ht_obj, struct obj *k,
+                                         struct obj *def) {
+  struct ht_obj *ht = ht_arg("hash-table-ref/default", ht_obj);
+
+  struct obj **ret = hash_table_obj_lookup(ht->ht, k);
+
+  return ret ? *ret : def;
+}
+
+MAKE_THREE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(hash_table_ref_default,
+                                            hash_table_ref_default_inner);
+
+struct obj *hash_table_set_inner(struct obj *ht_obj, struct obj *k,
+                                 struct obj *v) {
+  struct ht_obj *ht = ht_arg("hash-table-set!", ht_obj);
+
+  ht_store(ht, k, v);
+
+  return NULL;
+}
+
+MAKE_THREE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(hash_table_set,
+                                            hash_table_set_inner);
+
+struct obj *hash_table_delete_inner(struct obj *ht_obj, struct obj *k) {
+  struct ht_obj *ht = ht_arg("hash-table-delete!", ht_obj);
+
+  hash_table_obj_delete(ht->ht, k);
+
+  return NULL;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(hash_table_delete,
+                                          hash_table_delete_inner);
+
+struct obj *hash_table_to_alist_inner(struct obj *ht_obj) {
+  struct ht_obj *ht = ht_arg("hash-table->alist", ht_obj);
+  struct cons_obj *c = NULL;
+
+  HASH_TABLE_ITER(obj, key, val, ht->ht, {
+    struct cons_obj *entry = gc_malloc(sizeof(struct cons_obj));
+    *entry = object_cons_obj_new(*key, *val);
+    entry->base.on_stack = false;
+
+    struct cons_obj *c2 = gc_malloc(sizeof(struct cons_obj));
+    *c2 = object_cons_obj_new((struct obj *)entry, (struct obj *)c);
+    c2->base.on_stack = false;
+    c = c2;
+  });
+
+  return (struct obj *)c;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(hash_table_to_alist,
+                                          hash_table_to_alist_inner);
+
+struct obj *eq_inner(struct obj *a, struct obj *b) {
+  return MAKE_BOOL(a == b);
+}
+
+struct obj *eqv_inner(struct obj *a, struct obj *b) {
+  return MAKE_BOOL(eqv_obj_impl(a, b));
+}
+
+struct obj *equal_inner(struct obj *a, struct obj *b) {
+  return MAKE_BOOL(equal_obj_impl(a, b));
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(eq, eq_inner);
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(eqv, eqv_inner);
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(equal, equal_inner);
+
+static size_t length_arg(const char *name, struct obj *k) {
+  if (!k || obj_tag(k) != OBJ_INT || obj_int_val(k) < 0)
+    RUNTIME_ERROR("Length given to %s was not a non negative integer", name);
+
+  return obj_int_val(k);
+}
+
+static size_t index_arg(const char *name, struct obj *idx, size_t len) {
+  if (!idx || obj_tag(idx) != OBJ_INT)
+    RUNTIME_ERROR("Index to %s not of integer type", name);
+
+  int64_t i = obj_int_val(idx);
+  if (i < 0 || (size_t)i >= len)
+    RUNTIME_ERROR("Index %" PRId64 " out of range in %s of length %zu", i,
+                  name, len);
+
+  return i;
+}
+
+static struct string_obj *string_obj_arg(const char *name, struct obj *v) {
+  if (!v || obj_tag(v) != OBJ_STR)
+    RUNTIME_ERROR("Argument to %s was not a string", name);
+
+  return (struct string_obj *)v;
+}
+
+// the stored length counts the null byte
+static size_t string_length(struct string_obj *str) { return str->len - 1; }
+
+static struct obj *string_to_list(const char *name, struct obj *string_obj) {
+  struct string_obj *str = string_obj_arg(name, string_obj);
+  size_t len = string_length(str);
   struct cons_obj *c = NULL;
 
-  for (size_t i = 0; i < str->len; i++) {
+  for (size_t i = 0; i < len; i++) {
     struct cons_obj *c2 = gc_malloc(sizeof(struct cons_obj));
-    struct int_obj *chr = gc_malloc(sizeof(struct int_obj));
-    *chr = object_int_obj_new(str->buf[str->len - (i + 1)]);
-    *c2 = object_cons_obj_new((struct obj *)chr, (struct obj *)c);
+    struct obj *chr = MAKE_FIXNUM((unsigned char)str->buf[len - (i + 1)]);
+    *c2 = object_cons_obj_new(chr, (struct obj *)c);
+    c2->base.on_stack = false;
     c = c2;
   }
 
   return (struct obj *)c;
 }
 
+struct obj *string_chars_innner(struct obj *string_obj) {
+  return string_to_list("string-chars", string_obj);
+}
+
 MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(string_chars, string_chars_innner);
+
+struct obj *string_to_list_inner(struct obj *string_obj) {
+  return string_to_list("string->list", string_obj);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(string_to_list, string_to_list_inner);
+
+struct obj *string_length_inner(struct obj *v) {
+  return object_int_new(string_length(string_obj_arg("string-length", v)));
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(string_length, string_length_inner);
+
+struct obj *string_ref_inner(struct obj *v, struct obj *idx) {
+  struct string_obj *str = string_obj_arg("string-ref", v);
+
+  size_t i = index_arg("string-ref", idx, string_length(str));
+
+  return MAKE_FIXNUM((unsigned char)str->buf[i]);
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(string_ref, string_ref_inner);
+
+struct obj *substring_inner(struct obj *v, struct obj *start_obj,
+                            struct obj *end_obj) {
+  struct string_obj *str = string_obj_arg("substring", v);
+
+  if (!start_obj || obj_tag(start_obj) != OBJ_INT || !end_obj ||
+      obj_tag(end_obj) != OBJ_INT)
+    RUNTIME_ERROR("Index to substring not of integer type");
+
+  int64_t start = obj_int_val(start_obj);
+  int64_t end = obj_int_val(end_obj);
+  size_t len = string_length(str);
+
+  if (start < 0 || end < start || (size_t)end > len)
+    RUNTIME_ERROR("Range %" PRId64 " to %" PRId64
+                  " out of range in substring of length %zu",
+                  start, end, len);
+
+  return object_string_new(str->buf + start, end - start);
+}
+
+MAKE_THREE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(substring, substring_inner);
+
+struct obj *string_append_inner(struct obj *a, struct obj *b) {
+  struct string_obj *str_a = string_obj_arg("string-append", a);
+  struct string_obj *str_b = string_obj_arg("string-append", b);
+  size_t len_a = string_length(str_a);
+  size_t len_b = string_length(str_b);
+
+  char *buf = malloc(len_a + len_b);
+  memcpy(buf, str_a->buf, len_a);
+  memcpy(buf + len_a, str_b->buf, len_b);
+
+  struct obj *res = object_string_new(buf, len_a + len_b);
+  free(buf);
+
+  return res;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(string_append, string_append_inner);
+
+struct obj *is_string_eq_inner(struct obj *a, struct obj *b) {
+  struct string_obj *str_a = string_obj_arg("string=?", a);
+  struct string_obj *str_b = string_obj_arg("string=?", b);
+
+  return MAKE_BOOL(str_a->len == str_b->len &&
+                   memcmp(str_a->buf, str_b->buf, str_a->len) == 0);
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_string_eq, is_string_eq_inner);
+
+static const char *skip_digits(const char *p) {
+  while (isdigit((unsigned char)*p))
+    p++;
+
+  return p;
+}
+
+// Accepts the numbers of programs along with a decimal without digits on one
+// side of the point, anything else gives #f
+static struct obj *parse_number(const char *s) {
+  const char *p = s;
+
+  if (*p == '#') {
+    int radix;
+
+    switch (tolower((unsigned char)p[1])) {
+    case 'x':
+      radix = 16;
+      break;
+    case 'o':
+      radix = 8;
+      break;
+    case 'b':
+      radix = 2;
+      break;
+    case 'd':
+      radix = 10;
+      break;
+    default:
+      return MAKE_BOOL(false);
+    }
+
+    // strtoll would take spaces or 0x as well, and a sign before those
+    const char *sign = p + 2;
+    const char *digits = *sign == '+' || *sign == '-' ? sign + 1 : sign;
+
+    for (p = digits; *p; p++) {
+      int c = tolower((unsigned char)*p);
+      int digit = isdigit(c) ? c - '0' : isalpha(c) ? c - 'a' + 10 : radix;
+
+      if (digit >= radix)
+        return MAKE_BOOL(false);
+    }
+
+    if (p == digits)
+      return MAKE_BOOL(false);
+
+    errno = 0;
+    int64_t num = strtoll(sign, NULL, radix);
+
+    return errno == ERANGE ? MAKE_BOOL(false) : object_int_new(num);
+  }
+
+  if (*p == '+' || *p == '-')
+    p++;
+
+  const char *int_end = skip_digits(p);
+  bool has_int = int_end != p;
+  p = int_end;
+
+  if (has_int && (!*p || *p == '/')) {
+    errno = 0;
+    int64_t num = strtoll(s, NULL, 10);
+
+    if (!*p)
+      return errno == ERANGE ? MAKE_BOOL(false) : object_int_new(num);
+
+    const char *den_start = p + 1;
+    p = skip_digits(den_start);
+
+    if (*p || p == den_start)
+      return MAKE_BOOL(false);
+
+    int64_t den = strtoll(den_start, NULL, 10);
+
+    if (errno == ERANGE || den == 0)
+      return MAKE_BOOL(false);
+
+    return object_rational_new(num, den);
+  }
+
+  bool has_frac = false;
+  bool is_float = false;
+
+  if (*p == '.') {
+    const char *frac_end = skip_digits(p + 1);
+    has_frac = frac_end != p + 1;
+    is_float = true;
+    p = frac_end;
+  }
+
+  if (!has_int && !has_frac)
+    return MAKE_BOOL(false);
+
+  if (*p == 'e' || *p == 'E') {
+    p++;
+
+    if (*p == '+' || *p == '-')
+      p++;
+
+    const char *exp_end = skip_digits(p);
+
+    if (exp_end == p)
+      return MAKE_BOOL(false);
+
+    is_float = true;
+    p = exp_end;
+  }
+
+  if (*p || !is_float)
+    return MAKE_BOOL(false);
+
+  return object_float_new(strtod(s, NULL));
+}
+
+struct obj *string_to_number_inner(struct obj *v) {
+  return parse_number(string_obj_arg("string->number", v)->buf);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(string_to_number,
+                                          string_to_number_inner);
+
+struct obj *number_to_string_inner(struct obj *v) {
+  if (!obj_is_number(v))
+    RUNTIME_ERROR("Argument to number->string was not a number");
+
+  char *buf = number_to_string(v);
+  struct obj *res = object_string_new(buf, strlen(buf));
+  free(buf);
+
+  return res;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(number_to_string,
+                                          number_to_string_inner);
+
+void symbol_to_string_k(struct obj *v, struct obj *k, struct env_obj *env) {
+  if (!v || obj_tag(v) != OBJ_SYMBOL)
+    RUNTIME_ERROR("Argument to symbol->string was not a symbol");
+
+  OBJECT_STRING_OBJ_NEW(result_str, ((struct symbol_obj *)v)->name);
+
+  call_closure_one(k, result_str);
+
+  __builtin_unreachable();
+}
+
+struct obj *string_to_symbol_inner(struct obj *v) {
+  if (!v || obj_tag(v) != OBJ_STR)
+    RUNTIME_ERROR("Argument to string->symbol was not a string");
+
+  return symbol_intern(((struct string_obj *)v)->buf);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(string_to_symbol,
+                                          string_to_symbol_inner);
+
+struct obj *string_to_uninterned_symbol_inner(struct obj *v) {
+  if (!v || obj_tag(v) != OBJ_STR)
+    RUNTIME_ERROR("Argument to string->uninterned-symbol was not a string");
+
+  return symbol_uninterned(((struct string_obj *)v)->buf);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(string_to_uninterned_symbol,
+                                          string_to_uninterned_symbol_inner);
+
+// (gensym) is called with null and names its symbol g and a number, a string
+// or symbol given is the prefix instead
+struct obj *gensym_inner(struct obj *prefix) {
+  if (!prefix)
+    return symbol_gensym("g");
+
+  switch (obj_tag(prefix)) {
+  case OBJ_STR:
+    return symbol_gensym(((struct string_obj *)prefix)->buf);
+  case OBJ_SYMBOL:
+    return symbol_gensym(((struct symbol_obj *)prefix)->name);
+  default:
+    RUNTIME_ERROR("Argument to gensym was not a string or symbol");
+  }
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(gensym, gensym_inner);
+
+struct obj *is_symbol_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_SYMBOL);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_symbol, is_symbol_inner);
+
+static struct vector_obj *vector_arg(const char *name, struct obj *v) {
+  if (!v || obj_tag(v) != OBJ_VECTOR)
+    RUNTIME_ERROR("Argument to %s was not a vector", name);
+
+  return (struct vector_obj *)v;
+}
+
+static struct bytevector_obj *bytevector_arg(const char *name, struct obj *v) {
+  if (!v || obj_tag(v) != OBJ_BYTEVECTOR)
+    RUNTIME_ERROR("Argument to %s was not a bytevector", name);
+
+  return (struct bytevector_obj *)v;
+}
+
+static uint8_t byte_arg(const char *name, struct obj *b) {
+  if (!b || obj_tag(b) != OBJ_INT || obj_int_val(b) < 0 ||
+      obj_int_val(b) > 255)
+    RUNTIME_ERROR("Value given to %s was not a byte", name);
+
+  return obj_int_val(b);
+}
+
+static size_t list_length(const char *name, struct obj *l) {
+  size_t len = 0;
+
+  for (; l; l = ((struct cons_obj *)l)->cdr, len++) {
+    if (obj_tag(l) != OBJ_CONS)
+      RUNTIME_ERROR("Argument to %s was not a list", name);
+  }
+
+  return len;
+}
+
+struct obj *make_vector_inner(struct obj *k, struct obj *fill) {
+  size_t len = length_arg("make-vector", k);
+  struct vector_obj *vec = object_vector_new(len);
+
+  for (size_t i = 0; i < len; i++) {
+    vec->elems[i] = fill;
+  }
+
+  return (struct obj *)vec;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(make_vector, make_vector_inner);
+
+struct obj *vector_ref_inner(struct obj *v, struct obj *idx) {
+  struct vector_obj *vec = vector_arg("vector-ref", v);
+
+  return vec->elems[index_arg("vector-ref", idx, vec->len)];
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(vector_ref, vector_ref_inner);
+
+struct obj *vector_set_inner(struct obj *v, struct obj *idx, struct obj *val) {
+  struct vector_obj *vec = vector_arg("vector-set!", v);
+
+  vec->elems[index_arg("vector-set!", idx, vec->len)] = val;
+  GC_WRITE_BARRIER(vec, val);
+
+  return NULL;
+}
+
+MAKE_THREE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(vector_set, vector_set_inner);
+
+struct obj *vector_length_inner(struct obj *v) {
+  return object_int_new(vector_arg("vector-length", v)->len);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(vector_length, vector_length_inner);
+
+struct obj *is_vector_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_VECTOR);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_vector, is_vector_inner);
+
+struct obj *list_to_vector_inner(struct obj *l) {
+  struct vector_obj *vec = object_vector_new(list_length("list->vector", l));
+
+  for (size_t i = 0; l; l = ((struct cons_obj *)l)->cdr, i++) {
+    vec->elems[i] = ((struct cons_obj *)l)->car;
+  }
+
+  return (struct obj *)vec;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(list_to_vector, list_to_vector_inner);
+
+struct obj *vector_to_list_inner(struct obj *v) {
+  struct vector_obj *vec = vector_arg("vector->list", v);
+  struct cons_obj *c = NULL;
+
+  for (size_t i = vec->len; i > 0; i--) {
+    struct cons_obj *c2 = gc_malloc(sizeof(struct cons_obj));
+    *c2 = object_cons_obj_new(vec->elems[i - 1], (struct obj *)c);
+    c2->base.on_stack = false;
+    c = c2;
+  }
+
+  return (struct obj *)c;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(vector_to_list, vector_to_list_inner);
+
+struct obj *make_bytevector_inner(struct obj *k, struct obj *fill) {
+  size_t len = length_arg("make-bytevector", k);
+  struct bytevector_obj *bvec = object_bytevector_new(len);
+
+  memset(bvec->bytes, byte_arg("make-bytevector", fill), len);
+
+  return (struct obj *)bvec;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(make_bytevector,
+                                          make_bytevector_inner);
+
+struct obj *bytevector_ref_inner(struct obj *v, struct obj *idx) {
+  struct bytevector_obj *bvec = bytevector_arg("bytevector-u8-ref", v);
+
+  return MAKE_FIXNUM(
+      bvec->bytes[index_arg("bytevector-u8-ref", idx, bvec->len)]);
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(bytevector_ref, bytevector_ref_inner);
+
+struct obj *bytevector_set_inner(struct obj *v, struct obj *idx,
+                                 struct obj *val) {
+  struct bytevector_obj *bvec = bytevector_arg("bytevector-u8-set!", v);
+
+  bvec->bytes[index_arg("bytevector-u8-set!", idx, bvec->len)] =
+      byte_arg("bytevector-u8-set!", val);
+
+  return NULL;
+}
+
+MAKE_THREE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(bytevector_set,
+                                            bytevector_set_inner);
+
+struct obj *bytevector_length_inner(struct obj *v) {
+  return object_int_new(bytevector_arg("bytevector-length", v)->len);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(bytevector_length,
+                                          bytevector_length_inner);
+
+struct obj *is_bytevector_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_BYTEVECTOR);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_bytevector, is_bytevector_inner);
+
+struct obj *list_to_bytevector_inner(struct obj *l) {
+  struct bytevector_obj *bvec =
+      object_bytevector_new(list_length("list->bytevector", l));
+
+  for (size_t i = 0; l; l = ((struct cons_obj *)l)->cdr, i++) {
+    bvec->bytes[i] = byte_arg("list->bytevector", ((struct cons_obj *)l)->car);
+  }
+
+  return (struct obj *)bvec;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(list_to_bytevector,
+                                          list_to_bytevector_inner);
+
+struct obj *current_input_port_inner(struct obj *unused) {
+  (void)unused;
+  return port_current_input();
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(current_input_port,
+                                          current_input_port_inner);
+
+struct obj *current_output_port_inner(struct obj *unused) {
+  (void)unused;
+  return port_current_output();
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(current_output_port,
+                                          current_output_port_inner);
+
+struct obj *command_line_inner(struct obj *unused) {
+  (void)unused;
+  struct cons_obj *c = NULL;
+
+  for (int i = scheme_argc - 1; i >= 0; i--) {
+    struct obj *arg = object_string_new(scheme_argv[i], strlen(scheme_argv[i]));
+    struct cons_obj *c2 = gc_malloc(sizeof(struct cons_obj));
+    *c2 = object_cons_obj_new(arg, (struct obj *)c);
+    c2->base.on_stack = false;
+    c = c2;
+  }
+
+  return (struct obj *)c;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(command_line, command_line_inner);
+
+static const char *string_arg(const char *name, struct obj *v) {
+  return string_obj_arg(name, v)->buf;
+}
+
+// the value of the variable, or #f when it isn't set
+struct obj *get_environment_variable_inner(struct obj *name) {
+  const char *val = getenv(string_arg("get-environment-variable", name));
+
+  if (!val)
+    return MAKE_BOOL(false);
+
+  return object_string_new(val, strlen(val));
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(get_environment_variable,
+                                          get_environment_variable_inner);
+
+struct obj *current_second_inner(struct obj *unused) {
+  (void)unused;
+  struct timespec t;
+  clock_gettime(CLOCK_REALTIME, &t);
+
+  return object_float_new(t.tv_sec + t.tv_nsec / 1e9);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(current_second, current_second_inner);
+
+// an integer is the exit status, #f is 1 and anything else 0
+struct obj *exit_inner(struct obj *status) {
+  if (!status)
+    exit(0);
+
+  if (obj_tag(status) == OBJ_INT)
+    exit(obj_int_val(status));
+
+  if (obj_tag(status) == OBJ_BOOL && !((struct bool_obj *)status)->val)
+    exit(1);
+
+  exit(0);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(exit, exit_inner);
+
+// Collects the whole heap right away, writing the dump as the objects kept
+// alive by the continuation are marked
+void dump_heap_k(struct obj *path, struct obj *k, struct env_obj *env) {
+  (void)env;
+  gc_dump_heap_next(string_arg("dump-heap", path));
+
+  struct thunk *thnk = malloc(sizeof(struct thunk));
+  thnk->closr = (struct closure_obj *)k;
+  thnk->one.rand = NULL;
+  run_minor_gc(thnk);
+
+  __builtin_unreachable();
+}
+
+struct obj *open_input_file_inner(struct obj *path) {
+  return port_open(string_arg("open-input-file", path), true);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(open_input_file,
+                                          open_input_file_inner);
+
+struct obj *open_output_file_inner(struct obj *path) {
+  return port_open(string_arg("open-output-file", path), false);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(open_output_file,
+                                          open_output_file_inner);
+
+// both reads give null once the end of the file is reached
+struct obj *read_char_inner(struct obj *p) {
+  int c = fgetc(port_arg("read-char", p, true)->fp);
+
+  if (c == EOF)
+    return NULL;
+
+  return MAKE_FIXNUM(c);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(read_char, read_char_inner);
+
+void read_line_k(struct obj *p, struct obj *k, struct env_obj *env) {
+  struct port_obj *port = port_arg("read-line", p, true);
+  char *line = NULL;
+  size_t cap = 0;
+
+  ssize_t len = getline(&line, &cap, port->fp);
+
+  if (len < 0) {
+    free(line);
+    call_closure_one(k, NULL);
+  }
+
+  if (len > 0 && line[len - 1] == '\n')
+    line[len - 1] = '\0';
+
+  OBJECT_STRING_OBJ_NEW(result_str, line);
+
+  free(line);
+
+  call_closure_one(k, result_str);
+
+  __builtin_unreachable();
+}
+
+// The reader behind read, which takes the literals of programs without their
+// quotes as the interpreter does, an atom being a boolean, a number if
+// string->number takes it or else a symbol. Null is given at the end of the
+// file. #!fold-case and #!no-fold-case are comments that turn folding the
+// atoms after them to lower case on and off for the rest of the port.
+
+static struct obj *read_datum(struct port_obj *port, int c);
+
+// after the #| starting it
+static void read_skip_block_comment(struct port_obj *port) {
+  int depth = 1;
+  int last = 0;
+  int c;
+
+  while (depth > 0) {
+    if ((c = fgetc(port->fp)) == EOF)
+      RUNTIME_ERROR("read: end of file in a comment");
+
+    if (last == '#' && c == '|') {
+      depth++;
+      c = 0;
+    } else if (last == '|' && c == '#') {
+      depth--;
+      c = 0;
+    }
+
+    last = c;
+  }
+}
+
+// a growable buffer for the text of strings and atoms
+struct read_buf {
+  char *buf;
+  size_t len, cap;
+};
+
+static void read_buf_push(struct read_buf *b, char c) {
+  if (b->len + 1 >= b->cap) {
+    b->cap = b->cap ? b->cap * 2 : 32;
+    b->buf = realloc(b->buf, b->cap);
+  }
+
+  b->buf[b->len++] = c;
+  b->buf[b->len] = '\0';
+}
+
+static bool read_is_delimiter(int c) {
+  return c == EOF || isspace(c) || c == '(' || c == ')' || c == '"' ||
+         c == '\'' || c == ';' || c == '|';
+}
+
+// after the #! starting it
+static void read_directive(struct port_obj *port) {
+  struct read_buf b = {0};
+  int c;
+
+  while (!read_is_delimiter(c = fgetc(port->fp)))
+    read_buf_push(&b, c);
+
+  ungetc(c, port->fp);
+
+  if (b.buf && !strcmp(b.buf, "fold-case"))
+    port->fold_case = true;
+  else if (b.buf && !strcmp(b.buf, "no-fold-case"))
+    port->fold_case = false;
+  else
+    RUNTIME_ERROR("read: bad syntax: #!%s", b.buf ? b.buf : "");
+
+  free(b.buf);
+}
+
+// the first character after the whitespace and comments, datum comments
+// taking the datum after them
+static int read_skip_space(struct port_obj *port) {
+  for (;;) {
+    int c = fgetc(port->fp);
+
+    if (isspace(c))
+      continue;
+
+    if (c == ';') {
+      while ((c = fgetc(port->fp)) != EOF && c != '\n')
+        ;
+
+      continue;
+    }
+
+    if (c == '#') {
+      int next = fgetc(port->fp);
+
+      if (next == '|') {
+        read_skip_block_comment(port);
+        continue;
+      }
+
+      if (next == ';') {
+        read_datum(port, read_skip_space(port));
+        continue;
+      }
+
+      if (next == '!') {
+        read_directive(port);
+        continue;
+      }
+
+      ungetc(next, port->fp);
+    }
+
+    return c;
+  }
+}
+
+static struct obj *read_cons(struct obj *car, struct obj *cdr) {
+  struct cons_obj *c = gc_malloc(sizeof(struct cons_obj));
+  *c = object_cons_obj_new(car, cdr);
+  c->base.on_stack = false;
+
+  return (struct obj *)c;
+}
+
+// the elements up to the closing paren, after the opening one
+static struct obj *read_list(struct port_obj *port) {
+  struct obj *head = NULL;
+  struct cons_obj *last = NULL;
+
+  for (;;) {
+    int c = read_skip_space(port);
+
+    if (c == EOF)
+      RUNTIME_ERROR("read: end of file in a list");
+
+    if (c == ')')
+      return head;
+
+    if (c == '.') {
+      int next = fgetc(port->fp);
+      ungetc(next, port->fp);
+
+      if (read_is_delimiter(next) && next != ')' && next != EOF) {
+        if (!last)
+          RUNTIME_ERROR("read: dot at the start of a list");
+
+        last->cdr = read_datum(port, read_skip_space(port));
+
+        if (read_skip_space(port) != ')')
+          RUNTIME_ERROR("read: more than one datum after a dot");
+
+        return head;
+      }
+    }
+
+    struct obj *cell = read_cons(read_datum(port, c), NULL);
+
+    if (last)
+      last->cdr = cell;
+    else
+      head = cell;
+
+    last = (struct cons_obj *)cell;
+  }
+}
+
+// the UTF-8 of a scalar value, those that aren't replaced as the
+// interpreter does
+static void read_buf_push_utf8(struct read_buf *b, unsigned long scalar) {
+  if (scalar > 0x10ffff || (scalar >= 0xd800 && scalar < 0xe000))
+    scalar = 0xfffd;
+
+  if (scalar < 0x80) {
+    read_buf_push(b, scalar);
+  } else if (scalar < 0x800) {
+    read_buf_push(b, 0xc0 | scalar >> 6);
+    read_buf_push(b, 0x80 | (scalar & 0x3f));
+  } else if (scalar < 0x10000) {
+    read_buf_push(b, 0xe0 | scalar >> 12);
+    read_buf_push(b, 0x80 | (scalar >> 6 & 0x3f));
+    read_buf_push(b, 0x80 | (scalar & 0x3f));
+  } else {
+    read_buf_push(b, 0xf0 | scalar >> 18);
+    read_buf_push(b, 0x80 | (scalar >> 12 & 0x3f));
+    read_buf_push(b, 0x80 | (scalar >> 6 & 0x3f));
+    read_buf_push(b, 0x80 | (scalar & 0x3f));
+  }
+}
+
+// the digits of a \u escape, which has four, or of a \x escape, which ends
+// with a semicolon
+static unsigned long read_hex_escape(struct port_obj *port, int kind) {
+  unsigned long scalar = 0;
+  int digits = 0;
+  int c;
+
+  while ((c = fgetc(port->fp)) != EOF && isxdigit(c)) {
+    int digit = isdigit(c) ? c - '0' : tolower(c) - 'a' + 10;
+
+    // one too big already is replaced whatever follows
+    if (scalar <= 0x10ffff)
+      scalar = scalar * 16 + digit;
+
+    digits++;
+
+    if (kind == 'u' && digits == 4)
+      return scalar;
+  }
+
+  if (kind == 'u' || c != ';' || digits == 0)
+    RUNTIME_ERROR("read: bad syntax: \\%c escape in a string", kind);
+
+  return scalar;
+}
+
+static struct obj *read_string(struct port_obj *port) {
+  struct read_buf b = {0};
+  int c;
+
+  while ((c = fgetc(port->fp)) != '"') {
+    if (c == EOF)
+      RUNTIME_ERROR("read: end of file in a string");
+
+    if (c == '\\') {
+      switch (c = fgetc(port->fp)) {
+      case 'n':
+        c = '\n';
+        break;
+      case 't':
+        c = '\t';
+        break;
+      case 'r':
+        c = '\r';
+        break;
+      case 'a':
+        c = '\a';
+        break;
+      case 'b':
+        c = '\b';
+        break;
+      case 'f':
+        c = '\f';
+        break;
+      case '"':
+      case '\\':
+      case '/':
+        break;
+      case 'u':
+      case 'x':
+        read_buf_push_utf8(&b, read_hex_escape(port, c));
+        continue;
+      case EOF:
+        RUNTIME_ERROR("read: end of file in a string");
+      default:
+        RUNTIME_ERROR("read: bad syntax: unknown escape \\%c in a string", c);
+      }
+    }
+
+    read_buf_push(&b, c);
+  }
+
+  struct obj *s = object_string_new(b.buf ? b.buf : "", b.len);
+  free(b.buf);
+
+  return s;
+}
+
+// a symbol between bars, after the opening one
+static struct obj *read_bar_symbol(struct port_obj *port) {
+  struct read_buf b = {0};
+  int c;
+
+  while ((c = fgetc(port->fp)) != '|') {
+    if (c == EOF)
+      RUNTIME_ERROR("read: end of file in a symbol");
+
+    read_buf_push(&b, c);
+  }
+
+  struct obj *sym = symbol_intern(b.buf ? b.buf : "");
+  free(b.buf);
+
+  return sym;
+}
+
+static struct obj *read_bytevector(struct obj *l) {
+  struct bytevector_obj *bvec = object_bytevector_new(list_length("read", l));
+
+  for (size_t i = 0; l; l = ((struct cons_obj *)l)->cdr, i++) {
+    bvec->bytes[i] = byte_arg("read", ((struct cons_obj *)l)->car);
+  }
+
+  return (struct obj *)bvec;
+}
+
+// the code point of the character #\name, characters being integers, if
+// name is one character, the name of one or x and its hex code point, else -1
+static long read_char_code(const char *name) {
+  static const struct {
+    const char *name;
+    long code;
+  } names[] = {{"alarm", 7},      {"backspace", 8},  {"delete", 0x7f},
+               {"escape", 0x1b},  {"newline", '\n'}, {"null", 0},
+               {"return", '\r'},  {"space", ' '},    {"tab", '\t'}};
+
+  unsigned char lead = name[0];
+  size_t len = lead < 0x80   ? 1
+               : lead >= 0xf0 ? 4
+               : lead >= 0xe0 ? 3
+               : lead >= 0xc0 ? 2
+                              : 0;
+
+  if (len && strlen(name) == len) {
+    long code = len == 1 ? lead : lead & (0x7f >> len);
+
+    for (size_t i = 1; i < len; i++)
+      code = code << 6 | (name[i] & 0x3f);
+
+    return code;
+  }
+
+  for (size_t i = 0; i < sizeof(names) / sizeof(names[0]); i++) {
+    if (!strcmp(name, names[i].name))
+      return names[i].code;
+  }
+
+  if (name[0] != 'x')
+    return -1;
+
+  long code = 0;
+
+  for (const char *p = name + 1; *p; p++) {
+    if (!isxdigit((unsigned char)*p))
+      return -1;
+
+    int digit = isdigit((unsigned char)*p) ? *p - '0' : tolower(*p) - 'a' + 10;
+
+    // one too big already stays too big
+    if (code <= 0x10ffff)
+      code = code * 16 + digit;
+  }
+
+  return code > 0x10ffff || (code >= 0xd800 && code < 0xe000) ? -1 : code;
+}
+
+static struct obj *read_atom(struct port_obj *port, int c) {
+  struct read_buf b = {0};
+
+  // the character after #\ is taken whatever it is
+  if (c == '#') {
+    int next = fgetc(port->fp);
+
+    if (next == '\\') {
+      read_buf_push(&b, '#');
+      read_buf_push(&b, '\\');
+      c = fgetc(port->fp);
+
+      if (c != EOF) {
+        read_buf_push(&b, port->fold_case ? tolower(c) : c);
+        c = fgetc(port->fp);
+      }
+    } else {
+      ungetc(next, port->fp);
+    }
+  }
+
+  for (; !read_is_delimiter(c); c = fgetc(port->fp))
+    read_buf_push(&b, port->fold_case ? tolower(c) : c);
+
+  struct obj *atom;
+
+  // the list of a bytevector follows
+  if (!strcmp(b.buf, "#u8") && c == '(') {
+    free(b.buf);
+    return read_bytevector(read_list(port));
+  }
+
+  ungetc(c, port->fp);
+
+  if (b.len > 2 && !strncmp(b.buf, "#\\", 2)) {
+    long code = read_char_code(b.buf + 2);
+
+    if (code < 0)
+      RUNTIME_ERROR("read: unknown character: %s", b.buf);
+
+    // strings are indexed by byte, see MAX_CHAR in parse.rs
+    if (code > 0x7f)
+      RUNTIME_ERROR("read: non-ASCII character: %s", b.buf);
+
+    atom = object_int_new(code);
+  } else if (!strcmp(b.buf, "#t") || !strcmp(b.buf, "#true")) {
+    atom = MAKE_BOOL(true);
+  } else if (!strcmp(b.buf, "#f") || !strcmp(b.buf, "#false")) {
+    atom = MAKE_BOOL(false);
+  } else {
+    atom = parse_number(b.buf);
+
+    if (obj_tag(atom) == OBJ_BOOL)
+      atom = symbol_intern(b.buf);
+  }
+
+  free(b.buf);
+
+  return atom;
+}
+
+// the datum starting with c, which has been taken from the port
+static struct obj *read_datum(struct port_obj *port, int c) {
+  switch (c) {
+  case EOF:
+    RUNTIME_ERROR("read: end of file before a datum");
+  case '(':
+    return read_list(port);
+  case ')':
+    RUNTIME_ERROR("read: unexpected )");
+  case '"':
+    return read_string(port);
+  case '|':
+    return read_bar_symbol(port);
+  case '\'':
+    return read_cons(symbol_intern("quote"),
+                     read_cons(read_datum(port, read_skip_space(port)), NULL));
+  case '#': {
+    int next = fgetc(port->fp);
+
+    if (next == '(')
+      return list_to_vector_inner(read_list(port));
+
+    ungetc(next, port->fp);
+    break;
+  }
+  }
+
+  return read_atom(port, c);
+}
+
+struct obj *read_inner(struct obj *p) {
+  struct port_obj *port = port_arg("read", p, true);
+  int c = read_skip_space(port);
+
+  if (c == EOF)
+    return NULL;
+
+  return read_datum(port, c);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(read, read_inner);
+
+struct obj *display_inner(struct obj *v, struct obj *p) {
+  obj_print(port_arg("display", p, false)->fp, v, false);
+
+  return NULL;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(display, display_inner);
+
+struct obj *write_inner(struct obj *v, struct obj *p) {
+  obj_print(port_arg("write", p, false)->fp, v, true);
+
+  return NULL;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(write, write_inner);
+
+struct obj *write_string_inner(struct obj *s, struct obj *p) {
+  fputs(string_arg("write-string", s), port_arg("write-string", p, false)->fp);
+
+  return NULL;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(write_string, write_string_inner);
+
+struct obj *newline_inner(struct obj *p) {
+  fputc('\n', port_arg("newline", p, false)->fp);
+
+  return NULL;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(newline, newline_inner);
+
+struct obj *close_port_inner(struct obj *p) {
+  if (!p || obj_tag(p) != OBJ_PORT)
+    RUNTIME_ERROR("Argument to close-port was not a port");
+
+  port_close((struct port_obj *)p);
+
+  return NULL;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(close_port, close_port_inner);
+
+static void exception_handler_return(struct obj *v, struct env_obj *env) {
+  exception_pop_handler();
+
+  call_closure_one(env->env[0], v);
+
+  __builtin_unreachable();
+}
+
+// When the thunk raises, the handler is called with the raised object and
+// what it returns is returned from with-exception-handler
+void with_exception_handler_k_2(struct obj *thunk, struct obj *k,
+                                struct env_obj *env) {
+  exception_push_handler(env->env[0], k);
+
+  OBJECT_ENV_OBJ_NEW(ret_env, struct unary_env);
+  ret_env->env[0] = k;
+  OBJECT_CLOSURE_ONE_NEW(ret_k, exception_handler_return, ret_env);
+
+  call_closure_two(thunk, NULL, ret_k);
+
+  __builtin_unreachable();
+}
+
+void with_exception_handler_k(struct obj *handler, struct obj *k,
+                              struct env_obj *env) {
+  OBJECT_ENV_OBJ_NEW(tmp_env, struct unary_env);
+  tmp_env->env[0] = handler;
+  struct closure_obj func_2_clos =
+      object_closure_two_new(with_exception_handler_k_2, tmp_env);
+
+  call_closure_one(k, (struct obj *)&func_2_clos);
+
+  __builtin_unreachable();
+}
+
+struct unwind_env {
+  struct obj *raised;
+  struct obj *handler;
+  struct obj *k;
+  struct obj *winders;
+};
+
+// Run the after thunks of every dynamic-wind being left, innermost first, then
+// hand the raised object to the handler
+static void unwind_step(struct obj *unused, struct env_obj *env) {
+  (void)unused;
+  struct unwind_env *uenv = (struct unwind_env *)&env->env;
+
+  if (wind_current() == uenv->winders) {
+    call_closure_two(uenv->handler, uenv->raised, uenv->k);
+  }
+
+  struct cons_obj *w =
+      (struct cons_obj *)((struct cons_obj *)wind_current())->car;
+  wind_pop();
+
+  OBJECT_CLOSURE_ONE_NEW(next, unwind_step, env);
+
+  call_closure_two(w->cdr, NULL, next);
+
+  __builtin_unreachable();
+}
+
+// The continuation of raise is dropped, the handler runs with the handler
+// stack and wind list as they were outside of its with-exception-handler
+void raise_k(struct obj *v, struct obj *k, struct env_obj *env) {
+  struct cons_obj *top = exception_top_handler();
+
+  if (!top) {
+    char *res = obj_to_string_internal(v);
+    RUNTIME_ERROR("Uncaught exception: %s", res);
+  }
+
+  exception_pop_handler();
+
+  struct cons_obj *rest = (struct cons_obj *)top->cdr;
+
+  OBJECT_ENV_OBJ_NEW(unwind_env, struct unwind_env);
+  unwind_env->env[0] = v;
+  unwind_env->env[1] = top->car;
+  unwind_env->env[2] = rest->car;
+  unwind_env->env[3] = rest->cdr;
+
+  unwind_step(NULL, unwind_env);
+
+  __builtin_unreachable();
+}
+
+void error_k_2(struct obj *irritants, struct obj *k, struct env_obj *env) {
+  struct error_obj err = {.base = object_base_new(OBJ_ERROR),
+                          .message = env->env[0],
+                          .irritants = irritants};
+
+  raise_k((struct obj *)&err, k, NULL);
+}
+
+void error_k(struct obj *message, struct obj *k, struct env_obj *env) {
+  OBJECT_ENV_OBJ_NEW(tmp_env, struct unary_env);
+  tmp_env->env[0] = message;
+  struct closure_obj func_2_clos = object_closure_two_new(error_k_2, tmp_env);
+
+  call_closure_one(k, (struct obj *)&func_2_clos);
+
+  __builtin_unreachable();
+}
+
+static struct error_obj *error_arg(const char *name, struct obj *v) {
+  if (!v || obj_tag(v) != OBJ_ERROR)
+    RUNTIME_ERROR("Argument to %s was not an error object", name);
+
+  return (struct error_obj *)v;
+}
+
+struct obj *is_error_object_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_ERROR);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_error_object,
+                                          is_error_object_inner);
+
+struct obj *error_object_message_inner(struct obj *v) {
+  return error_arg("error-object-message", v)->message;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(error_object_message,
+                                          error_object_message_inner);
+
+struct obj *error_object_irritants_inner(struct obj *v) {
+  return error_arg("error-object-irritants", v)->irritants;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(error_object_irritants,
+                                          error_object_irritants_inner);
+
+struct wind_env {
+  struct obj *before;
+  struct obj *thunk;
+  struct obj *after;
+  struct obj *k;
+};
+
+static void dynamic_wind_after_done(struct obj *unused, struct env_obj *env) {
+  (void)unused;
+
+  call_closure_one(env->env[1], env->env[0]);
+
+  __builtin_unreachable();
+}
+
+static void dynamic_wind_thunk_done(struct obj *result, struct env_obj *env) {
+  struct wind_env *wenv = (struct wind_env *)&env->env;
+  wind_pop();
+
+  OBJECT_ENV_OBJ_NEW(done_env, struct binary_env);
+  done_env->env[0] = result;
+  done_env->env[1] = wenv->k;
+  OBJECT_CLOSURE_ONE_NEW(done_k, dynamic_wind_after_done, done_env);
+
+  call_closure_two(wenv->after, NULL, done_k);
+
+  __builtin_unreachable();
+}
+
+static void dynamic_wind_before_done(struct obj *unused, struct env_obj *env) {
+  (void)unused;
+  struct wind_env *wenv = (struct wind_env *)&env->env;
+  wind_push(wenv->before, wenv->after);
+
+  OBJECT_CLOSURE_ONE_NEW(thunk_k, dynamic_wind_thunk_done, env);
+
+  call_closure_two(wenv->thunk, NULL, thunk_k);
+
+  __builtin_unreachable();
+}
+
+void dynamic_wind_k_3(struct obj *after, struct obj *k, struct env_obj *env) {
+  OBJECT_ENV_OBJ_NEW(wind_env, struct wind_env);
+  wind_env->env[0] = env->env[0];
+  wind_env->env[1] = env->env[1];
+  wind_env->env[2] = after;
+  wind_env->env[3] = k;
+  OBJECT_CLOSURE_ONE_NEW(before_k, dynamic_wind_before_done, wind_env);
+
+  call_closure_two(env->env[0], NULL, before_k);
+
+  __builtin_unreachable();
+}
+
+void dynamic_wind_k_2(struct obj *thunk, struct obj *k, struct env_obj *env) {
+  env->env[1] = thunk;
+  GC_WRITE_BARRIER(env, thunk);
+  struct closure_obj func_3_clos =
+      object_closure_two_new(dynamic_wind_k_3, env);
+
+  call_closure_one(k, (struct obj *)&func_3_clos);
+
+  __builtin_unreachable();
+}
+
+void dynamic_wind_k(struct obj *before, struct obj *k, struct env_obj *env) {
+  OBJECT_ENV_OBJ_NEW(tmp_env, struct binary_env);
+  tmp_env->env[0] = before;
+  struct closure_obj func_2_clos =
+      object_closure_two_new(dynamic_wind_k_2, tmp_env);
+
+  call_closure_one(k, (struct obj *)&func_2_clos);
+
+  __builtin_unreachable();
+}
+
+void delay_k(struct obj *thunk, struct obj *k, struct env_obj *env) {
+  struct promise_obj promise = {
+      .base = object_base_new(OBJ_PROMISE), .done = false, .val = thunk};
+
+  call_closure_one(k, (struct obj *)&promise);
+
+  __builtin_unreachable();
+}
+
+void make_promise_k(struct obj *v, struct obj *k, struct env_obj *env) {
+  if (v && obj_tag(v) == OBJ_PROMISE) {
+    call_closure_one(k, v);
+    __builtin_unreachable();
+  }
+
+  struct promise_obj promise = {
+      .base = object_base_new(OBJ_PROMISE), .done = true, .val = v};
+
+  call_closure_one(k, (struct obj *)&promise);
+
+  __builtin_unreachable();
+}
+
+struct obj *is_promise_inner(struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_PROMISE);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_promise, is_promise_inner);
+
+// the thunk may have forced the promise itself, in which case the first value
+// to arrive is the one that is kept
+static void force_thunk_done(struct obj *result, struct env_obj *env) {
+  struct promise_obj *promise = (struct promise_obj *)env->env[0];
+
+  if (!promise->done) {
+    promise->done = true;
+    promise->val = result;
+    GC_WRITE_BARRIER(promise, result);
+  }
+
+  call_closure_one(env->env[1], promise->val);
+
+  __builtin_unreachable();
+}
+
+void force_k(struct obj *v, struct obj *k, struct env_obj *env) {
+  if (!v || obj_tag(v) != OBJ_PROMISE) {
+    call_closure_one(k, v);
+    __builtin_unreachable();
+  }
+
+  struct promise_obj *promise = (struct promise_obj *)v;
+
+  if (promise->done) {
+    call_closure_one(k, promise->val);
+    __builtin_unreachable();
+  }
+
+  OBJECT_ENV_OBJ_NEW(force_env, struct binary_env);
+  force_env->env[0] = v;
+  force_env->env[1] = k;
+  OBJECT_CLOSURE_ONE_NEW(thunk_k, force_thunk_done, force_env);
+
+  call_closure_two(promise->val, NULL, thunk_k);
+
+  __builtin_unreachable();
+}
+
+// A parameter is a closure holding its value and converter in its
+// environment, calling it gives the value parameterize gave it in this thread
+// if it did, or that one
+static void parameter_k(struct obj *unused, struct obj *k, struct env_obj *env) {
+  (void)unused;
+  struct cons_obj *binding = parameter_binding((struct obj *)env);
+
+  call_closure_one(k, binding ? binding->cdr : env->env[0]);
+
+  __builtin_unreachable();
+}
+
+void make_parameter_k_2(struct obj *converter, struct obj *k,
+                        struct env_obj *env) {
+  OBJECT_ENV_OBJ_NEW(param_env, struct binary_env);
+  param_env->env[0] = env->env[0];
+  param_env->env[1] = converter;
+  struct closure_obj param = object_closure_two_new(parameter_k, param_env);
+
+  call_closure_one(k, (struct obj *)&param);
+
+  __builtin_unreachable();
+}
+
+void make_parameter_k(struct obj *v, struct obj *k, struct env_obj *env) {
+  OBJECT_ENV_OBJ_NEW(tmp_env, struct unary_env);
+  tmp_env->env[0] = v;
+  struct closure_obj func_2_clos =
+      object_closure_two_new(make_parameter_k_2, tmp_env);
+
+  call_closure_one(k, (struct obj *)&func_2_clos);
+
+  __builtin_unreachable();
+}
+
+static struct env_obj *parameter_arg(struct obj *p) {
+  if (!p || obj_tag(p) != OBJ_CLOSURE ||
+      ((struct closure_obj *)p)->fn_2 != parameter_k) {
+    char *res = obj_to_string_internal(p);
+    RUNTIME_ERROR("parameterize: %s is not a parameter", res);
+  }
+
+  return ((struct closure_obj *)p)->env;
+}
+
+struct obj *parameter_converter_inner(struct obj *p) {
+  return parameter_arg(p)->env[1];
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(parameter_converter,
+                                          parameter_converter_inner);
+
+struct obj *parameter_push_inner(struct obj *p, struct obj *v) {
+  parameter_push((struct obj *)parameter_arg(p), v);
+
+  return NULL;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(parameter_push, parameter_push_inner);
+
+struct obj *parameter_pop_inner(struct obj *p) {
+  parameter_arg(p);
+  parameter_pop();
+
+  return NULL;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(parameter_pop, parameter_pop_inner);
+
+// The clause of the (arity . clause) pairs taking `arity` arguments, or NULL
+static struct obj *case_lambda_find(struct obj *clauses, int64_t arity) {
+  for (struct obj *l = clauses; l; l = ((struct cons_obj *)l)->cdr) {
+    struct cons_obj *clause = (struct cons_obj *)((struct cons_obj *)l)->car;
+
+    if (obj_int_val(clause->car) == arity)
+      return clause->cdr;
+  }
+
+  return NULL;
+}
+
+// A case-lambda that is passed around rather than only called is a closure
+// holding its (arity . clause) pairs in its environment. Called like any
+// closure it is given one argument, which a call without arguments is too.
+static void case_lambda_k(struct obj *v, struct obj *k, struct env_obj *env) {
+  struct obj *clause = case_lambda_find(env->env[0], 1);
+
+  if (!clause)
+    clause = case_lambda_find(env->env[0], 0);
+
+  if (!clause)
+    RUNTIME_ERROR("no clause of the case-lambda takes 1 argument");
+
+  call_closure_two(clause, v, k);
+
+  __builtin_unreachable();
+}
+
+void make_case_lambda_k(struct obj *clauses, struct obj *k,
+                        struct env_obj *env) {
+  (void)env;
+
+  OBJECT_ENV_OBJ_NEW(case_env, struct unary_env);
+  case_env->env[0] = clauses;
+  struct closure_obj f = object_closure_two_new(case_lambda_k, case_env);
+
+  call_closure_one(k, (struct obj *)&f);
+
+  __builtin_unreachable();
+}
+
+// The clause a call of `f` with `arity` arguments runs, `f` itself unless it
+// is a case-lambda
+struct obj *case_lambda_clause_inner(struct obj *f, struct obj *arity) {
+  if (!f || obj_tag(f) != OBJ_CLOSURE ||
+      ((struct closure_obj *)f)->fn_2 != case_lambda_k)
+    return f;
+
+  int64_t n = obj_int_val(arity);
+  struct obj *clause =
+      case_lambda_find(((struct closure_obj *)f)->env->env[0], n);
+
+  if (!clause)
+    RUNTIME_ERROR("no clause of the case-lambda takes %" PRId64
+                  " argument%s",
+                  n, n == 1 ? "" : "s");
+
+  return clause;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(case_lambda_clause,
+                                          case_lambda_clause_inner);
+
+struct obj *make_record_type_inner(struct obj *name, struct obj *len) {
+  return (struct obj *)object_record_type_new(
+      (struct symbol_obj *)name, length_arg("make-record-type", len));
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(make_record_type,
+                                          make_record_type_inner);
+
+struct obj *make_record_inner(struct obj *type) {
+  return (struct obj *)object_record_new((struct record_type_obj *)type);
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(make_record, make_record_inner);
+
+struct obj *is_record_inner(struct obj *type, struct obj *v) {
+  return MAKE_BOOL(v && obj_tag(v) == OBJ_RECORD &&
+                   (struct obj *)((struct record_obj *)v)->type == type);
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(is_record, is_record_inner);
+
+// name is the symbol of the accessor or modifier doing the check
+struct obj *record_check_inner(struct obj *type, struct obj *name,
+                               struct obj *v) {
+  if (!obj_is_truthy(is_record_inner(type, v)))
+    RUNTIME_ERROR("Argument to %s was not a record of type %s",
+                  ((struct symbol_obj *)name)->name,
+                  ((struct record_type_obj *)type)->name->name);
+
+  return v;
+}
+
+MAKE_THREE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(record_check, record_check_inner);
+
+struct obj *record_ref_inner(struct obj *r, struct obj *idx) {
+  return ((struct record_obj *)r)->fields[obj_int_val(idx)];
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(record_ref, record_ref_inner);
+
+// returns the record so that a constructor can chain the stores
+struct obj *record_set_inner(struct obj *r, struct obj *idx, struct obj *v) {
+  ((struct record_obj *)r)->fields[obj_int_val(idx)] = v;
+  GC_WRITE_BARRIER(r, v);
+
+  return r;
+}
+
+MAKE_THREE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(record_set, record_set_inner);
+
+struct obj *foreign_next_arg(struct obj **args) {
+  struct cons_obj *cell = (struct cons_obj *)*args;
+  *args = cell->cdr;
+
+  return cell->car;
+}
+
+long foreign_to_long(struct obj *v, const char *name) {
+  if (!v || obj_tag(v) != OBJ_INT)
+    RUNTIME_ERROR("Argument to foreign function %s was not an integer", name);
+
+  return obj_int_val(v);
+}
+
+int foreign_to_int(struct obj *v, const char *name) {
+  long i = foreign_to_long(v, name);
+
+  if (i < INT_MIN || i > INT_MAX)
+    RUNTIME_ERROR("Argument %ld to foreign function %s does not fit an int", i,
+                  name);
+
+  return i;
+}
+
+double foreign_to_double(struct obj *v, const char *name) {
+  if (!v)
+    RUNTIME_ERROR("Argument to foreign function %s was not a number", name);
+
+  switch (obj_tag(v)) {
+  case OBJ_INT:
+    return obj_int_val(v);
+  case OBJ_FLOAT:
+    return ((struct float_obj *)v)->val;
+  case OBJ_RATIONAL:
+    return (double)((struct rational_obj *)v)->num /
+           ((struct rational_obj *)v)->den;
+  default:
+    RUNTIME_ERROR("Argument to foreign function %s was not a number", name);
+  }
+}
+
+// the same truthiness as if
+bool foreign_to_bool(struct obj *v, const char *name) {
+  (void)name;
+
+  return obj_is_truthy(v);
+}
+
+// the string is only valid until the next collection, the function shouldn't
+// keep it
+const char *foreign_to_string(struct obj *v, const char *name) {
+  if (!v || obj_tag(v) != OBJ_STR)
+    RUNTIME_ERROR("Argument to foreign function %s was not a string", name);
+
+  return ((struct string_obj *)v)->buf;
+}
@@ -111,6 +111,7 @@ struct binary_env {
   }                                                                            \
   void NAME##_k_2(struct obj *v, struct obj *k, struct env_obj *env) {         \
     env->env[1] = v;                                                           \
+    GC_WRITE_BARRIER(env, v);                                                  \
     struct closure_obj func_3_clos = object_closure_two_new(NAME##_k_3, env);  \
                                                                                \
     call_closure_one(k, (struct obj *)&func_3_clos);                           \
@@ -136,19 +137,34 @@ DEFINE_TWO_ARG_FROM_BUILTIN(lt);
 DEFINE_TWO_ARG_FROM_BUILTIN(leq);
 DEFINE_TWO_ARG_FROM_BUILTIN(gt);
 DEFINE_TWO_ARG_FROM_BUILTIN(geq);
+DEFINE_TWO_ARG_FROM_BUILTIN(num_eq);
 
 DEFINE_TWO_ARG_FROM_BUILTIN(cons);
 
 DEFINE_TWO_ARG_FROM_BUILTIN(string_concat);
 DEFINE_ONE_ARG_FROM_BUILTIN(string_chars);
+DEFINE_ONE_ARG_FROM_BUILTIN(string_to_list);
+DEFINE_ONE_ARG_FROM_BUILTIN(string_length);
+DEFINE_TWO_ARG_FROM_BUILTIN(string_ref);
+DEFINE_THREE_ARG_FROM_BUILTIN(substring);
+DEFINE_TWO_ARG_FROM_BUILTIN(string_append);
+DEFINE_TWO_ARG_FROM_BUILTIN(is_string_eq);
+DEFINE_ONE_ARG_FROM_BUILTIN(string_to_number);
+DEFINE_ONE_ARG_FROM_BUILTIN(number_to_string);
 
-DEFINE_ZERO_ARG_FROM_BUILTIN(exit);
+DEFINE_ZERO_ARG_FROM_BUILTIN(halt);
+// defined by the link unit of a separately compiled program
+DEFINE_ZERO_ARG_FROM_BUILTIN(module_done);
 
 DEFINE_ONE_ARG_FROM_BUILTIN(to_string);
-DEFINE_ONE_ARG_FROM_BUILTIN(display);
 
 DEFINE_ONE_ARG_FROM_BUILTIN(is_cons);
 DEFINE_ONE_ARG_FROM_BUILTIN(is_null);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_zero);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_number);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_procedure);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_string);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_boolean);
 DEFINE_ONE_ARG_FROM_BUILTIN(car);
 DEFINE_ONE_ARG_FROM_BUILTIN(cdr);
 
@@ -158,9 +174,133 @@ DEFINE_TWO_ARG_FROM_BUILTIN(ht_del);
 DEFINE_TWO_ARG_FROM_BUILTIN(ht_get);
 DEFINE_TWO_ARG_FROM_BUILTIN(ht_get);
 DEFINE_ONE_ARG_FROM_BUILTIN(ht_keys);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_hash_table);
+DEFINE_TWO_ARG_FROM_BUILTIN(hash_table_ref);
+DEFINE_THREE_ARG_FROM_BUILTIN(hash_table_ref_default);
+DEFINE_THREE_ARG_FROM_BUILTIN(hash_table_set);
+DEFINE_TWO_ARG_FROM_BUILTIN(hash_table_delete);
+DEFINE_ONE_ARG_FROM_BUILTIN(hash_table_to_alist);
 
 DEFINE_TWO_ARG_FROM_BUILTIN(eq);
+DEFINE_TWO_ARG_FROM_BUILTIN(eqv);
+DEFINE_TWO_ARG_FROM_BUILTIN(equal);
+
+DEFINE_ONE_ARG_FROM_BUILTIN(symbol_to_string);
+DEFINE_ONE_ARG_FROM_BUILTIN(string_to_symbol);
+DEFINE_ONE_ARG_FROM_BUILTIN(string_to_uninterned_symbol);
+DEFINE_ONE_ARG_FROM_BUILTIN(gensym);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_symbol);
+
+DEFINE_TWO_ARG_FROM_BUILTIN(make_vector);
+DEFINE_TWO_ARG_FROM_BUILTIN(vector_ref);
+DEFINE_THREE_ARG_FROM_BUILTIN(vector_set);
+DEFINE_ONE_ARG_FROM_BUILTIN(vector_length);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_vector);
+DEFINE_ONE_ARG_FROM_BUILTIN(list_to_vector);
+DEFINE_ONE_ARG_FROM_BUILTIN(vector_to_list);
+
+DEFINE_TWO_ARG_FROM_BUILTIN(make_bytevector);
+DEFINE_TWO_ARG_FROM_BUILTIN(bytevector_ref);
+DEFINE_THREE_ARG_FROM_BUILTIN(bytevector_set);
+DEFINE_ONE_ARG_FROM_BUILTIN(bytevector_length);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_bytevector);
+DEFINE_ONE_ARG_FROM_BUILTIN(list_to_bytevector);
+
+DEFINE_ONE_ARG_FROM_BUILTIN(current_input_port);
+DEFINE_ONE_ARG_FROM_BUILTIN(current_output_port);
+DEFINE_ONE_ARG_FROM_BUILTIN(command_line);
+DEFINE_ONE_ARG_FROM_BUILTIN(get_environment_variable);
+DEFINE_ONE_ARG_FROM_BUILTIN(current_second);
+DEFINE_ONE_ARG_FROM_BUILTIN(exit);
+DEFINE_ONE_ARG_FROM_BUILTIN(dump_heap);
+DEFINE_ONE_ARG_FROM_BUILTIN(open_input_file);
+DEFINE_ONE_ARG_FROM_BUILTIN(open_output_file);
+DEFINE_ONE_ARG_FROM_BUILTIN(read_char);
+DEFINE_ONE_ARG_FROM_BUILTIN(read_line);
+DEFINE_ONE_ARG_FROM_BUILTIN(read);
+DEFINE_TWO_ARG_FROM_BUILTIN(display);
+DEFINE_TWO_ARG_FROM_BUILTIN(write);
+DEFINE_TWO_ARG_FROM_BUILTIN(write_string);
+DEFINE_ONE_ARG_FROM_BUILTIN(newline);
+DEFINE_ONE_ARG_FROM_BUILTIN(close_port);
+
+DEFINE_TWO_ARG_FROM_BUILTIN(with_exception_handler);
+DEFINE_TWO_ARG_FROM_BUILTIN(error);
+void raise_k(struct obj *, struct obj *, struct env_obj *)
+    __attribute__((noreturn));
+DEFINE_ONE_ARG_FROM_BUILTIN(is_error_object);
+DEFINE_ONE_ARG_FROM_BUILTIN(error_object_message);
+DEFINE_ONE_ARG_FROM_BUILTIN(error_object_irritants);
+DEFINE_THREE_ARG_FROM_BUILTIN(dynamic_wind);
+
+DEFINE_ONE_ARG_FROM_BUILTIN(delay);
+DEFINE_ONE_ARG_FROM_BUILTIN(make_promise);
+DEFINE_ONE_ARG_FROM_BUILTIN(is_promise);
+DEFINE_ONE_ARG_FROM_BUILTIN(force);
+
+DEFINE_TWO_ARG_FROM_BUILTIN(make_parameter);
+DEFINE_ONE_ARG_FROM_BUILTIN(parameter_converter);
+DEFINE_TWO_ARG_FROM_BUILTIN(parameter_push);
+DEFINE_ONE_ARG_FROM_BUILTIN(parameter_pop);
+DEFINE_ONE_ARG_FROM_BUILTIN(make_case_lambda);
+DEFINE_TWO_ARG_FROM_BUILTIN(case_lambda_clause);
+
+// in eval.c
+DEFINE_TWO_ARG_FROM_BUILTIN(eval);
+DEFINE_ONE_ARG_FROM_BUILTIN(interaction_environment);
+DEFINE_TWO_ARG_FROM_BUILTIN(interaction_define);
+
+// in thread.c
+DEFINE_ONE_ARG_FROM_BUILTIN(spawn);
+DEFINE_ONE_ARG_FROM_BUILTIN(yield);
+void thread_run_next(void);
+
+DEFINE_TWO_ARG_FROM_BUILTIN(make_record_type);
+DEFINE_ONE_ARG_FROM_BUILTIN(make_record);
+DEFINE_TWO_ARG_FROM_BUILTIN(is_record);
+DEFINE_THREE_ARG_FROM_BUILTIN(record_check);
+DEFINE_TWO_ARG_FROM_BUILTIN(record_ref);
+DEFINE_THREE_ARG_FROM_BUILTIN(record_set);
 
 _Bool obj_is_truthy(struct obj *);
 
+// Calling the C functions declared by define-foreign, the arguments come as a
+// list and are unboxed one at a time, name is the function for errors
+struct obj *foreign_next_arg(struct obj **);
+int foreign_to_int(struct obj *, const char *);
+long foreign_to_long(struct obj *, const char *);
+double foreign_to_double(struct obj *, const char *);
+bool foreign_to_bool(struct obj *, const char *);
+const char *foreign_to_string(struct obj *, const char *);
+
+#define FOREIGN_RETURN_INT(K, CALL)                                            \
+  do {                                                                         \
+    OBJECT_INT_OBJ_NEW(foreign_result, (CALL));                                \
+    call_closure_one((K), foreign_result);                                     \
+  } while (0)
+
+#define FOREIGN_RETURN_DOUBLE(K, CALL)                                         \
+  do {                                                                         \
+    OBJECT_FLOAT_OBJ_NEW(foreign_result, (CALL));                              \
+    call_closure_one((K), foreign_result);                                     \
+  } while (0)
+
+#define FOREIGN_RETURN_BOOL(K, CALL) call_closure_one((K), MAKE_BOOL(CALL))
+
+// a NULL string is returned as void
+#define FOREIGN_RETURN_STRING(K, CALL)                                         \
+  do {                                                                         \
+    const char *foreign_str = (CALL);                                          \
+    if (!foreign_str)                                                          \
+      call_closure_one((K), NULL);                                             \
+    OBJECT_STRING_OBJ_NEW(foreign_result, foreign_str);                        \
+    call_closure_one((K), foreign_result);                                     \
+  } while (0)
+
+#define FOREIGN_RETURN_VOID(K, CALL)                                           \
+  do {                                                                         \
+    (CALL);                                                                    \
+    call_closure_one((K), NULL);                                               \
+  } while (0)
+
 #endif // SOMESCHEME_BUILTIN_H
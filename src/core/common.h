@@ -1,10 +1,16 @@
 #ifndef SOMESCHEME_COMMON_H
 #define SOMESCHEME_COMMON_H
 
+// Only defined by programs compiled with --checked, names the lambda of the
+// program running, which runtime errors are blamed on
+extern const char *scheme_blame __attribute__((weak));
+
 #define RUNTIME_ERROR(F, ...)                                                  \
   do {                                                                         \
     fprintf(stderr, "Runtime Error (%s:%d): ", __func__, __LINE__);            \
     fprintf(stderr, F "\n", ##__VA_ARGS__);                                    \
+    if (&scheme_blame && scheme_blame)                                         \
+      fprintf(stderr, "  in %s\n", scheme_blame);                              \
     exit(1);                                                                   \
   } while (0)
 
@@ -0,0 +1,628 @@
+#include <stdbool.h>
+#include <string.h>
+
+#include "base.h"
+#include "builtin.h"
+#include "gc.h"
+#include "print.h"
+
+// The evaluator behind eval. A datum is walked in continuation passing style
+// like compiled code runs, so the lambdas it makes are closures that compiled
+// code can call as any other, taking their arguments one at a time. The local
+// variables are kept in a list of (symbol . value) pairs, innermost first.
+
+// What interaction-environment gives, there is only the one
+static struct obj interaction_environment = {
+    .tag = OBJ_ENVIRONMENT, .mark = WHITE, .on_stack = false};
+
+// The (symbol . value) pairs of the globals defined by eval, kept on the heap
+// and a gc root
+static struct obj *globals = NULL;
+
+static bool roots_registered = false;
+
+// The builtins of the grammar, which eval'd code can't rebind either
+static const struct {
+  const char *name;
+  void (*fn)(struct obj *, struct obj *, struct env_obj *);
+} eval_builtins[] = {
+    {"tostring", to_string_k},
+    {"display", display_k},
+    {"+", add_k},
+    {"-", sub_k},
+    {"*", mul_k},
+    {"/", div_k},
+    {"%", mod_k},
+    {"^", xor_k},
+    {"<=", leq_k},
+    {"<", lt_k},
+    {">=", geq_k},
+    {">", gt_k},
+    {"=", num_eq_k},
+    {"zero?", is_zero_k},
+    {"number?", is_number_k},
+    {"pair?", is_cons_k},
+    {"procedure?", is_procedure_k},
+    {"string?", is_string_k},
+    {"boolean?", is_boolean_k},
+    {"cons?", is_cons_k},
+    {"cons", cons_k},
+    {"null?", is_null_k},
+    {"car", car_k},
+    {"cdr", cdr_k},
+    {"string-concat", string_concat_k},
+    {"string-chars", string_chars_k},
+    {"string-length", string_length_k},
+    {"string-ref", string_ref_k},
+    {"substring", substring_k},
+    {"string-append", string_append_k},
+    {"string->number", string_to_number_k},
+    {"number->string", number_to_string_k},
+    {"string=?", is_string_eq_k},
+    {"string->list", string_to_list_k},
+    {"ht-new", ht_new_k},
+    {"ht-set!", ht_set_k},
+    {"ht-del!", ht_del_k},
+    {"ht-keys", ht_keys_k},
+    {"ht-get", ht_get_k},
+    {"eq?", eq_k},
+    {"eqv?", eqv_k},
+    {"equal?", equal_k},
+    {"make-hash-table", ht_new_k},
+    {"hash-table-ref/default", hash_table_ref_default_k},
+    {"hash-table-ref", hash_table_ref_k},
+    {"hash-table-set!", hash_table_set_k},
+    {"hash-table-delete!", hash_table_delete_k},
+    {"hash-table->alist", hash_table_to_alist_k},
+    {"hash-table?", is_hash_table_k},
+    {"symbol->string", symbol_to_string_k},
+    {"string->symbol", string_to_symbol_k},
+    {"string->uninterned-symbol", string_to_uninterned_symbol_k},
+    {"gensym", gensym_k},
+    {"symbol?", is_symbol_k},
+    {"make-vector", make_vector_k},
+    {"vector-ref", vector_ref_k},
+    {"vector-set!", vector_set_k},
+    {"vector-length", vector_length_k},
+    {"vector?", is_vector_k},
+    {"vector->list", vector_to_list_k},
+    {"list->vector", list_to_vector_k},
+    {"make-bytevector", make_bytevector_k},
+    {"bytevector-u8-ref", bytevector_ref_k},
+    {"bytevector-u8-set!", bytevector_set_k},
+    {"bytevector-length", bytevector_length_k},
+    {"bytevector?", is_bytevector_k},
+    {"list->bytevector", list_to_bytevector_k},
+    {"current-input-port", current_input_port_k},
+    {"current-output-port", current_output_port_k},
+    {"command-line", command_line_k},
+    {"exit", exit_k},
+    {"dump-heap", dump_heap_k},
+    {"get-environment-variable", get_environment_variable_k},
+    {"current-second", current_second_k},
+    {"open-input-file", open_input_file_k},
+    {"open-output-file", open_output_file_k},
+    {"read-char", read_char_k},
+    {"read-line", read_line_k},
+    {"read", read_k},
+    {"write-string", write_string_k},
+    {"write", write_k},
+    {"newline", newline_k},
+    {"close-port", close_port_k},
+    {"with-exception-handler", with_exception_handler_k},
+    {"raise", raise_k},
+    {"error-object?", is_error_object_k},
+    {"error-object-message", error_object_message_k},
+    {"error-object-irritants", error_object_irritants_k},
+    {"error", error_k},
+    {"dynamic-wind", dynamic_wind_k},
+    {"make-promise", make_promise_k},
+    {"promise?", is_promise_k},
+    {"force", force_k},
+    {"eval", eval_k},
+    {"interaction-environment", interaction_environment_k},
+    {"spawn", spawn_k},
+    {"yield", yield_k},
+};
+
+struct lambda_env {
+  struct obj *params;
+  struct obj *body;
+  struct obj *env;
+};
+
+struct if_env {
+  struct obj *then;
+  struct obj *otherwise;
+  struct obj *env;
+  struct obj *k;
+};
+
+struct assign_env {
+  struct obj *name;
+  struct obj *env;
+  struct obj *k;
+};
+
+struct seq_env {
+  struct obj *rest;
+  struct obj *env;
+  struct obj *k;
+};
+
+struct arg_env {
+  struct obj *f;
+  struct obj *rest;
+  struct obj *env;
+  struct obj *k;
+};
+
+static void eval_expr(struct obj *, struct obj *, struct obj *)
+    __attribute__((noreturn));
+static void eval_form(struct obj *, struct obj *, struct obj *)
+    __attribute__((noreturn));
+static void eval_body(struct obj *, struct obj *, struct obj *, struct obj *)
+    __attribute__((noreturn));
+
+static struct obj *heap_cons(struct obj *car, struct obj *cdr) {
+  struct cons_obj *c = gc_malloc(sizeof(struct cons_obj));
+  *c = object_cons_obj_new(car, cdr);
+  c->base.on_stack = false;
+
+  return (struct obj *)c;
+}
+
+static void bad_syntax(struct obj *form) __attribute__((noreturn));
+
+static void bad_syntax(struct obj *form) {
+  fputs("Runtime Error (eval): bad syntax: ", stderr);
+  obj_print(stderr, form, true);
+  fputc('\n', stderr);
+  exit(1);
+}
+
+static bool is_cons(struct obj *v) { return v && obj_tag(v) == OBJ_CONS; }
+
+static bool is_symbol(struct obj *v) { return v && obj_tag(v) == OBJ_SYMBOL; }
+
+// An uninterned symbol of the same name, as gensym makes, is a variable like
+// any other rather than the keyword
+static bool is_keyword(struct obj *v, const char *name) {
+  return is_symbol(v) && strcmp(((struct symbol_obj *)v)->name, name) == 0 &&
+         symbol_is_interned(v);
+}
+
+static struct obj *car(struct obj *v) { return ((struct cons_obj *)v)->car; }
+
+static struct obj *cdr(struct obj *v) { return ((struct cons_obj *)v)->cdr; }
+
+// The length of the list `l`, a form that isn't a proper list is bad syntax
+static size_t form_length(struct obj *l, struct obj *form) {
+  size_t len = 0;
+
+  for (; l; l = cdr(l)) {
+    if (!is_cons(l))
+      bad_syntax(form);
+    len++;
+  }
+
+  return len;
+}
+
+static void check_params(struct obj *params, struct obj *form) {
+  form_length(params, form);
+
+  for (; params; params = cdr(params)) {
+    if (!is_symbol(car(params)))
+      bad_syntax(form);
+  }
+}
+
+// The name a (define name e) or (define (name x ...) body) defines, or NULL
+static struct obj *define_name(struct obj *form) {
+  if (!is_cons(form) || !is_keyword(car(form), "define") ||
+      !is_cons(cdr(form)))
+    return NULL;
+
+  struct obj *target = car(cdr(form));
+
+  if (is_cons(target))
+    target = car(target);
+
+  return is_symbol(target) ? target : NULL;
+}
+
+// The (symbol . value) pair of the variable `name`, or NULL
+static struct obj *lookup(struct obj *name, struct obj *env) {
+  for (struct obj *l = env; l; l = cdr(l)) {
+    if (car(car(l)) == name)
+      return car(l);
+  }
+
+  for (struct obj *l = globals; l; l = cdr(l)) {
+    if (car(car(l)) == name)
+      return car(l);
+  }
+
+  return NULL;
+}
+
+static void assign(struct obj *binding, struct obj *val) {
+  ((struct cons_obj *)binding)->cdr = val;
+  GC_WRITE_BARRIER(binding, val);
+}
+
+static void eval_apply(struct obj *arg, struct obj *k, struct env_obj *env) {
+  struct lambda_env *lenv = (struct lambda_env *)&env->env;
+
+  // a lambda without parameters is still given null
+  if (!lenv->params)
+    eval_body(lenv->body, lenv->env, lenv->body, k);
+
+  struct obj *locals =
+      heap_cons(heap_cons(car(lenv->params), arg), lenv->env);
+
+  if (!cdr(lenv->params))
+    eval_body(lenv->body, locals, lenv->body, k);
+
+  OBJECT_ENV_OBJ_NEW(rest_env, struct lambda_env);
+  rest_env->env[0] = cdr(lenv->params);
+  rest_env->env[1] = lenv->body;
+  rest_env->env[2] = locals;
+  OBJECT_CLOSURE_TWO_NEW(rest, eval_apply, rest_env);
+
+  call_closure_one(k, rest);
+
+  __builtin_unreachable();
+}
+
+static void make_lambda(struct obj *params, struct obj *body, struct obj *env,
+                        struct obj *k) __attribute__((noreturn));
+
+static void make_lambda(struct obj *params, struct obj *body, struct obj *env,
+                        struct obj *k) {
+  OBJECT_ENV_OBJ_NEW(lambda_env, struct lambda_env);
+  lambda_env->env[0] = params;
+  lambda_env->env[1] = body;
+  lambda_env->env[2] = env;
+  OBJECT_CLOSURE_TWO_NEW(lambda, eval_apply, lambda_env);
+
+  call_closure_one(k, lambda);
+
+  __builtin_unreachable();
+}
+
+static void eval_if_test_done(struct obj *v, struct env_obj *env) {
+  struct if_env *ienv = (struct if_env *)&env->env;
+
+  if (obj_is_truthy(v))
+    eval_expr(ienv->then, ienv->env, ienv->k);
+
+  if (!ienv->otherwise)
+    call_closure_one(ienv->k, NULL);
+
+  eval_expr(car(ienv->otherwise), ienv->env, ienv->k);
+}
+
+static void eval_set_done(struct obj *v, struct env_obj *env) {
+  struct assign_env *aenv = (struct assign_env *)&env->env;
+  struct obj *binding = lookup(aenv->name, aenv->env);
+
+  if (!binding)
+    RUNTIME_ERROR("eval: unbound variable %s",
+                  ((struct symbol_obj *)aenv->name)->name);
+
+  assign(binding, v);
+
+  call_closure_one(aenv->k, NULL);
+
+  __builtin_unreachable();
+}
+
+static void eval_define_done(struct obj *v, struct env_obj *env) {
+  struct assign_env *aenv = (struct assign_env *)&env->env;
+
+  // the defines of a body are bound before it runs, those of the toplevel are
+  // globals
+  struct obj *binding = lookup(aenv->name, aenv->env);
+
+  if (binding)
+    assign(binding, v);
+  else
+    globals = heap_cons(heap_cons(aenv->name, v), globals);
+
+  call_closure_one(aenv->k, NULL);
+
+  __builtin_unreachable();
+}
+
+static void eval_seq_done(struct obj *unused, struct env_obj *env) {
+  (void)unused;
+  struct seq_env *senv = (struct seq_env *)&env->env;
+
+  eval_body(senv->rest, senv->env, NULL, senv->k);
+}
+
+static void eval_arg_done(struct obj *, struct env_obj *);
+
+static void eval_fn_done(struct obj *f, struct env_obj *env) {
+  struct seq_env *senv = (struct seq_env *)&env->env;
+
+  // a call without arguments gives null, as in compiled code
+  if (!senv->rest)
+    call_closure_two(f, NULL, senv->k);
+
+  OBJECT_ENV_OBJ_NEW(arg_env, struct arg_env);
+  arg_env->env[0] = f;
+  arg_env->env[1] = cdr(senv->rest);
+  arg_env->env[2] = senv->env;
+  arg_env->env[3] = senv->k;
+  OBJECT_CLOSURE_ONE_NEW(arg_k, eval_arg_done, arg_env);
+
+  eval_expr(car(senv->rest), senv->env, arg_k);
+}
+
+// Calls are curried, the closure each argument gives is called with the next
+static void eval_arg_done(struct obj *arg, struct env_obj *env) {
+  struct arg_env *aenv = (struct arg_env *)&env->env;
+
+  if (!aenv->rest)
+    call_closure_two(aenv->f, arg, aenv->k);
+
+  OBJECT_ENV_OBJ_NEW(next_env, struct seq_env);
+  next_env->env[0] = aenv->rest;
+  next_env->env[1] = aenv->env;
+  next_env->env[2] = aenv->k;
+  OBJECT_CLOSURE_ONE_NEW(next_k, eval_fn_done, next_env);
+
+  call_closure_two(aenv->f, arg, next_k);
+
+  __builtin_unreachable();
+}
+
+static void eval_variable(struct obj *name, struct obj *env, struct obj *k)
+    __attribute__((noreturn));
+
+static void eval_variable(struct obj *name, struct obj *env, struct obj *k) {
+  const char *s = ((struct symbol_obj *)name)->name;
+
+  if (is_keyword(name, "null"))
+    call_closure_one(k, NULL);
+
+  for (size_t i = 0; i < sizeof(eval_builtins) / sizeof(eval_builtins[0]);
+       i++) {
+    if (is_keyword(name, eval_builtins[i].name)) {
+      OBJECT_CLOSURE_TWO_NEW(builtin, eval_builtins[i].fn, NULL);
+      call_closure_one(k, builtin);
+    }
+  }
+
+  struct obj *binding = lookup(name, env);
+
+  if (!binding)
+    RUNTIME_ERROR("eval: unbound variable %s", s);
+
+  call_closure_one(k, cdr(binding));
+
+  __builtin_unreachable();
+}
+
+// Turn (let ((x e) ...) body) into ((lambda (x ...) body) e ...)
+static struct obj *let_to_app(struct obj *form) {
+  struct obj *bindings = car(cdr(form));
+  struct obj *names = NULL, *inits = NULL;
+  struct cons_obj *last_name = NULL, *last_init = NULL;
+
+  form_length(bindings, form);
+
+  for (; bindings; bindings = cdr(bindings)) {
+    struct obj *b = car(bindings);
+
+    if (form_length(b, form) != 2 || !is_symbol(car(b)))
+      bad_syntax(form);
+
+    struct obj *name = heap_cons(car(b), NULL);
+    struct obj *init = heap_cons(car(cdr(b)), NULL);
+
+    if (last_name) {
+      last_name->cdr = name;
+      last_init->cdr = init;
+    } else {
+      names = name;
+      inits = init;
+    }
+
+    last_name = (struct cons_obj *)name;
+    last_init = (struct cons_obj *)init;
+  }
+
+  struct obj *lambda = heap_cons(symbol_intern("lambda"),
+                                 heap_cons(names, cdr(cdr(form))));
+
+  return heap_cons(lambda, inits);
+}
+
+static void eval_expr(struct obj *e, struct obj *env, struct obj *k) {
+  if (is_symbol(e))
+    eval_variable(e, env, k);
+
+  if (!is_cons(e))
+    call_closure_one(k, e);
+
+  struct obj *head = car(e);
+  size_t len = form_length(e, e);
+
+  if (is_keyword(head, "quote")) {
+    if (len != 2)
+      bad_syntax(e);
+
+    call_closure_one(k, car(cdr(e)));
+  }
+
+  if (is_keyword(head, "if")) {
+    if (len != 3 && len != 4)
+      bad_syntax(e);
+
+    OBJECT_ENV_OBJ_NEW(if_env, struct if_env);
+    if_env->env[0] = car(cdr(cdr(e)));
+    if_env->env[1] = cdr(cdr(cdr(e)));
+    if_env->env[2] = env;
+    if_env->env[3] = k;
+    OBJECT_CLOSURE_ONE_NEW(test_k, eval_if_test_done, if_env);
+
+    eval_expr(car(cdr(e)), env, test_k);
+  }
+
+  if (is_keyword(head, "set!")) {
+    if (len != 3 || !is_symbol(car(cdr(e))))
+      bad_syntax(e);
+
+    OBJECT_ENV_OBJ_NEW(set_env, struct assign_env);
+    set_env->env[0] = car(cdr(e));
+    set_env->env[1] = env;
+    set_env->env[2] = k;
+    OBJECT_CLOSURE_ONE_NEW(set_k, eval_set_done, set_env);
+
+    eval_expr(car(cdr(cdr(e))), env, set_k);
+  }
+
+  if (is_keyword(head, "lambda")) {
+    if (len < 3)
+      bad_syntax(e);
+
+    check_params(car(cdr(e)), e);
+    make_lambda(car(cdr(e)), cdr(cdr(e)), env, k);
+  }
+
+  if (is_keyword(head, "let")) {
+    if (len < 3)
+      bad_syntax(e);
+
+    eval_expr(let_to_app(e), env, k);
+  }
+
+  if (is_keyword(head, "define"))
+    bad_syntax(e);
+
+  OBJECT_ENV_OBJ_NEW(app_env, struct seq_env);
+  app_env->env[0] = cdr(e);
+  app_env->env[1] = env;
+  app_env->env[2] = k;
+  OBJECT_CLOSURE_ONE_NEW(fn_k, eval_fn_done, app_env);
+
+  eval_expr(head, env, fn_k);
+}
+
+// An expression or a define, of the toplevel or of a body
+static void eval_form(struct obj *form, struct obj *env, struct obj *k) {
+  struct obj *name = define_name(form);
+
+  if (!name)
+    eval_expr(form, env, k);
+
+  size_t len = form_length(form, form);
+  struct obj *target = car(cdr(form));
+
+  OBJECT_ENV_OBJ_NEW(define_env, struct assign_env);
+  define_env->env[0] = name;
+  define_env->env[1] = env;
+  define_env->env[2] = k;
+  OBJECT_CLOSURE_ONE_NEW(define_k, eval_define_done, define_env);
+
+  if (is_symbol(target)) {
+    if (len != 3)
+      bad_syntax(form);
+
+    eval_expr(car(cdr(cdr(form))), env, define_k);
+  }
+
+  if (len < 3)
+    bad_syntax(form);
+
+  check_params(cdr(target), form);
+  make_lambda(cdr(target), cdr(cdr(form)), env, define_k);
+}
+
+// Run the forms left of a body, binding the names its defines define first
+// when `body` is all of it
+static void eval_body(struct obj *forms, struct obj *env, struct obj *body,
+                      struct obj *k) {
+  if (body) {
+    for (struct obj *l = body; l; l = cdr(l)) {
+      struct obj *name = define_name(car(l));
+
+      if (name)
+        env = heap_cons(heap_cons(name, NULL), env);
+    }
+  }
+
+  if (!cdr(forms)) {
+    if (define_name(car(forms)))
+      bad_syntax(car(forms));
+
+    eval_form(car(forms), env, k);
+  }
+
+  OBJECT_ENV_OBJ_NEW(seq_env, struct seq_env);
+  seq_env->env[0] = cdr(forms);
+  seq_env->env[1] = env;
+  seq_env->env[2] = k;
+  OBJECT_CLOSURE_ONE_NEW(seq_k, eval_seq_done, seq_env);
+
+  eval_form(car(forms), env, seq_k);
+}
+
+struct obj *interaction_environment_inner(struct obj *unused) {
+  (void)unused;
+
+  return &interaction_environment;
+}
+
+MAKE_ONE_ARG_FROM_BUILTIN_EXPLICIT_RETURN(interaction_environment,
+                                          interaction_environment_inner);
+
+static void register_roots(void) {
+  if (!roots_registered) {
+    gc_register_root(&globals);
+    roots_registered = true;
+  }
+}
+
+// Defines a toplevel variable of the program in the interaction environment,
+// the compiler calls this after the program defines or sets one
+struct obj *interaction_define_inner(struct obj *name, struct obj *v) {
+  register_roots();
+
+  struct obj *binding = lookup(name, NULL);
+
+  if (binding)
+    assign(binding, v);
+  else
+    globals = heap_cons(heap_cons(name, v), globals);
+
+  return NULL;
+}
+
+MAKE_TWO_ARG_FROM_BUILTIN_EXPLICIT_RETURN(interaction_define,
+                                          interaction_define_inner);
+
+void eval_k_2(struct obj *environment, struct obj *k, struct env_obj *env) {
+  if (environment != &interaction_environment)
+    RUNTIME_ERROR("Argument to eval was not an environment");
+
+  register_roots();
+
+  eval_form(env->env[0], NULL, k);
+}
+
+void eval_k(struct obj *datum, struct obj *k, struct env_obj *env) {
+  (void)env;
+
+  OBJECT_ENV_OBJ_NEW(datum_env, struct unary_env);
+  datum_env->env[0] = datum;
+  struct closure_obj func_2_clos = object_closure_two_new(eval_k_2, datum_env);
+
+  call_closure_one(k, (struct obj *)&func_2_clos);
+
+  __builtin_unreachable();
+}
@@ -0,0 +1,124 @@
+#include <stdbool.h>
+
+#include "base.h"
+#include "exception.h"
+#include "gc.h"
+
+// A list of (handler continuation . winders) entries, the continuation being
+// the one of the with-exception-handler call that installed the handler and
+// winders the wind list at that point. The cells are kept on the heap and the
+// list is a gc root so that the handlers and continuations survive minor gcs.
+static struct obj *handler_stack = NULL;
+
+// The wind list, (before . after) pairs for each dynamic-wind we are inside of
+// with the innermost first.
+static struct obj *winders = NULL;
+
+// The values parameterize gives parameters, (parameter . value) pairs with the
+// innermost first, a parameter being the environment of its closure.
+static struct obj *parameterization = NULL;
+
+static bool roots_registered = false;
+
+static void register_roots(void) {
+  if (roots_registered)
+    return;
+
+  gc_register_root(&handler_stack);
+  gc_register_root(&winders);
+  gc_register_root(&parameterization);
+  roots_registered = true;
+}
+
+static struct obj *heap_cons(struct obj *car, struct obj *cdr) {
+  struct cons_obj *c = gc_malloc(sizeof(struct cons_obj));
+  *c = object_cons_obj_new(car, cdr);
+  c->base.on_stack = false;
+
+  return (struct obj *)c;
+}
+
+void exception_push_handler(struct obj *handler, struct obj *k) {
+  register_roots();
+
+  struct obj *entry = heap_cons(handler, heap_cons(k, winders));
+  handler_stack = heap_cons(entry, handler_stack);
+}
+
+void exception_pop_handler(void) {
+  if (DEBUG_ONLY(!handler_stack)) {
+    RUNTIME_ERROR("Popping from an empty handler stack");
+  }
+
+  handler_stack = ((struct cons_obj *)handler_stack)->cdr;
+}
+
+// NULL if there are no handlers installed
+struct cons_obj *exception_top_handler(void) {
+  if (!handler_stack)
+    return NULL;
+
+  return (struct cons_obj *)((struct cons_obj *)handler_stack)->car;
+}
+
+void wind_push(struct obj *before, struct obj *after) {
+  register_roots();
+
+  winders = heap_cons(heap_cons(before, after), winders);
+}
+
+void wind_pop(void) {
+  if (DEBUG_ONLY(!winders)) {
+    RUNTIME_ERROR("Popping from an empty wind list");
+  }
+
+  winders = ((struct cons_obj *)winders)->cdr;
+}
+
+struct obj *wind_current(void) { return winders; }
+
+void parameter_push(struct obj *parameter, struct obj *v) {
+  register_roots();
+
+  parameterization = heap_cons(heap_cons(parameter, v), parameterization);
+}
+
+void parameter_pop(void) {
+  if (DEBUG_ONLY(!parameterization)) {
+    RUNTIME_ERROR("Popping from an empty parameterization");
+  }
+
+  parameterization = ((struct cons_obj *)parameterization)->cdr;
+}
+
+// The (parameter . value) pair parameterize gave the parameter, or NULL
+struct cons_obj *parameter_binding(struct obj *parameter) {
+  for (struct obj *l = parameterization; l; l = ((struct cons_obj *)l)->cdr) {
+    struct cons_obj *binding = (struct cons_obj *)((struct cons_obj *)l)->car;
+
+    if (binding->car == parameter)
+      return binding;
+  }
+
+  return NULL;
+}
+
+// The handler stack, wind list and parameterization together, what a thread
+// has of its own
+struct obj *exception_state(void) {
+  if (!handler_stack && !winders && !parameterization)
+    return NULL;
+
+  return heap_cons(handler_stack, heap_cons(winders, parameterization));
+}
+
+void exception_restore(struct obj *state) {
+  register_roots();
+
+  struct cons_obj *rest =
+      state ? (struct cons_obj *)((struct cons_obj *)state)->cdr : NULL;
+
+  handler_stack = state ? ((struct cons_obj *)state)->car : NULL;
+  winders = rest ? rest->car : NULL;
+  parameterization = rest ? rest->cdr : NULL;
+}
@@ -0,0 +1,22 @@
+#ifndef SOMESCHEME_EXCEPTION_H
+#define SOMESCHEME_EXCEPTION_H
+
+#include "base.h"
+
+void exception_push_handler(struct obj *, struct obj *);
+void exception_pop_handler(void);
+struct cons_obj *exception_top_handler(void);
+
+void wind_push(struct obj *, struct obj *);
+void wind_pop(void);
+struct obj *wind_current(void);
+
+void parameter_push(struct obj *, struct obj *);
+void parameter_pop(void);
+struct cons_obj *parameter_binding(struct obj *);
+
+// switched between the threads of thread.c
+struct obj *exception_state(void);
+void exception_restore(struct obj *);
+
+#endif // SOMESCHEME_EXCEPTION_H
@@ -1,5 +1,8 @@
 #include <assert.h>
+#include <signal.h>
 #include <stdbool.h>
+#include <stdio.h>
+#include <stdlib.h>
 #include <string.h>
 
 #include "base.h"
@@ -10,6 +13,7 @@
 #include "vec.h"
 
 MAKE_VECTOR(struct obj *, gc_heap_nodes);
+MAKE_VECTOR(struct obj **, gc_roots);
 MAKE_VECTOR(size_t, size_t);
 MAKE_QUEUE(struct obj *, gc_grey_nodes);
 MAKE_QUEUE(struct ptr_toupdate_pair, ptr_toupdate_pair);
@@ -21,6 +25,18 @@ MAKE_HASH(size_t, struct obj *, hash_table_default_size_t_hash_fun, size_t_eq,
 
 static struct gc_data gc_global_data;
 
+// enough heap nodes that a program allocating on the heap doesn't collect
+// before it gets going
+#define GC_MIN_HEAP_NODES 100000
+
+// the heap is full once it has this many nodes, see gc_heap_full
+static size_t gc_collect_at = GC_MIN_HEAP_NODES;
+
+// how many objects can be left after a major gc unless SCHEME_MAX_HEAP says
+#define GC_DEFAULT_MAX_HEAP ((size_t)1 << 24)
+
+static size_t gc_max_heap = GC_DEFAULT_MAX_HEAP;
+
 // array of gc_funcs for each object type
 static struct gc_funcs gc_func_map[] = {
     [OBJ_CLOSURE] = (struct gc_funcs){.toheap = toheap_closure,
@@ -47,6 +63,42 @@ static struct gc_funcs gc_func_map[] = {
     [OBJ_HT] = (struct gc_funcs){.toheap = toheap_ht,
                                  .mark = mark_ht,
                                  .free = free_ht},
+    [OBJ_FLOAT] = (struct gc_funcs){.toheap = toheap_float_obj,
+                                    .mark = gc_mark_noop,
+                                    .free = gc_free_noop},
+    [OBJ_RATIONAL] = (struct gc_funcs){.toheap = toheap_rational_obj,
+                                       .mark = gc_mark_noop,
+                                       .free = gc_free_noop},
+    [OBJ_SYMBOL] = (struct gc_funcs){.toheap = toheap_symbol,
+                                     .mark = gc_mark_noop,
+                                     .free = gc_free_noop},
+    [OBJ_VECTOR] = (struct gc_funcs){.toheap = toheap_vector,
+                                     .mark = mark_vector,
+                                     .free = gc_free_noop},
+    [OBJ_BYTEVECTOR] = (struct gc_funcs){.toheap = toheap_bytevector,
+                                         .mark = gc_mark_noop,
+                                         .free = gc_free_noop},
+    [OBJ_BOOL] = (struct gc_funcs){.toheap = toheap_bool,
+                                   .mark = gc_mark_noop,
+                                   .free = gc_free_noop},
+    [OBJ_PORT] = (struct gc_funcs){.toheap = toheap_port,
+                                   .mark = gc_mark_noop,
+                                   .free = free_port},
+    [OBJ_ERROR] = (struct gc_funcs){.toheap = toheap_error,
+                                    .mark = mark_error,
+                                    .free = gc_free_noop},
+    [OBJ_PROMISE] = (struct gc_funcs){.toheap = toheap_promise,
+                                      .mark = mark_promise,
+                                      .free = gc_free_noop},
+    [OBJ_RECORD_TYPE] = (struct gc_funcs){.toheap = toheap_record_type,
+                                          .mark = gc_mark_noop,
+                                          .free = gc_free_noop},
+    [OBJ_RECORD] = (struct gc_funcs){.toheap = toheap_record,
+                                     .mark = mark_record,
+                                     .free = gc_free_noop},
+    [OBJ_ENVIRONMENT] = (struct gc_funcs){.toheap = toheap_environment,
+                                          .mark = gc_mark_noop,
+                                          .free = gc_free_noop},
 };
 
 // This does nothing, the gc will call free() on the object if it was heap
@@ -60,12 +112,220 @@ void gc_mark_noop(struct obj *obj, struct gc_context *ctx) {
   (void)ctx;
 }
 
+// A dump of the live objects, asked for by dump-heap or by SIGUSR1 when
+// SCHEME_HEAP_DUMP names the file, is written by the next major gc as it marks
+// them. A path ending in .json gets JSON, anything else DOT.
+static struct {
+  // set by dump-heap, a copy the dump frees
+  char *path;
+  volatile sig_atomic_t signalled;
+  FILE *fp;
+  bool json;
+  // the objects written so far, the static objects of the program stay
+  // marked after the first gc that reaches them so have to be told apart
+  struct hash_table_ptr_map *written;
+  // the object whose pointers are being marked, NULL for the roots
+  struct obj *from;
+  size_t objects, refs;
+} heap_dump;
+
+static void heap_dump_signal(int sig) {
+  (void)sig;
+  heap_dump.signalled = 1;
+}
+
+void gc_dump_heap_next(const char *path) {
+  free(heap_dump.path);
+  heap_dump.path = strdup(path);
+}
+
+static bool heap_dump_wanted(void) {
+  return heap_dump.path || heap_dump.signalled;
+}
+
+static size_t obj_size(struct obj *obj) {
+  switch (obj->tag) {
+  case OBJ_CLOSURE:
+    return sizeof(struct closure_obj);
+  case ENV_OBJ:
+    return sizeof(struct env_obj) +
+           ((struct env_obj *)obj)->len * sizeof(struct obj *);
+  case OBJ_INT:
+    return sizeof(struct int_obj);
+  case OBJ_STR:
+    return sizeof(struct string_obj) + ((struct string_obj *)obj)->len;
+  case OBJ_CONS:
+    return sizeof(struct cons_obj);
+  case OBJ_CELL:
+    return sizeof(struct cell_obj);
+  case OBJ_HT:
+    return sizeof(struct ht_obj);
+  case OBJ_FLOAT:
+    return sizeof(struct float_obj);
+  case OBJ_RATIONAL:
+    return sizeof(struct rational_obj);
+  case OBJ_SYMBOL:
+    return sizeof(struct symbol_obj) + ((struct symbol_obj *)obj)->len + 1;
+  case OBJ_VECTOR:
+    return sizeof(struct vector_obj) +
+           ((struct vector_obj *)obj)->len * sizeof(struct obj *);
+  case OBJ_BYTEVECTOR:
+    return sizeof(struct bytevector_obj) + ((struct bytevector_obj *)obj)->len;
+  case OBJ_BOOL:
+    return sizeof(struct bool_obj);
+  case OBJ_PORT:
+    return sizeof(struct port_obj);
+  case OBJ_ERROR:
+    return sizeof(struct error_obj);
+  case OBJ_PROMISE:
+    return sizeof(struct promise_obj);
+  case OBJ_RECORD_TYPE:
+    return sizeof(struct record_type_obj);
+  case OBJ_RECORD:
+    return sizeof(struct record_obj) +
+           ((struct record_obj *)obj)->type->len * sizeof(struct obj *);
+  case OBJ_ENVIRONMENT:
+    return sizeof(struct obj);
+  }
+
+  return sizeof(struct obj);
+}
+
+static void heap_dump_begin(void) {
+  const char *path =
+      heap_dump.path ? heap_dump.path : getenv("SCHEME_HEAP_DUMP");
+  size_t len = strlen(path);
+
+  heap_dump.signalled = 0;
+  heap_dump.json = len >= 5 && strcmp(path + len - 5, ".json") == 0;
+  heap_dump.fp = fopen(path, "w");
+
+  if (!heap_dump.fp)
+    RUNTIME_ERROR("Couldn't write the heap dump to %s", path);
+
+  heap_dump.written = hash_table_ptr_map_new();
+  heap_dump.objects = 0;
+  fputs(heap_dump.json ? "{\"objects\": [" : "digraph heap {\n", heap_dump.fp);
+}
+
+// Writes the string s in quotes for JSON, and without them for a DOT label
+static void heap_dump_string(const char *s) {
+  if (heap_dump.json)
+    fputc('"', heap_dump.fp);
+
+  for (; *s; s++) {
+    if (*s == '"' || *s == '\\')
+      fputc('\\', heap_dump.fp);
+    fputc(*s, heap_dump.fp);
+  }
+
+  if (heap_dump.json)
+    fputc('"', heap_dump.fp);
+}
+
+// Writes obj before its pointers are marked, the closures of the program's
+// lambdas are named by them
+static void heap_dump_object(struct obj *obj) {
+  FILE *fp = heap_dump.fp;
+  struct lambda_info *info =
+      obj->tag == OBJ_CLOSURE ? lambda_info_of((struct closure_obj *)obj) : NULL;
+
+  if (heap_dump.json) {
+    fprintf(fp, "%s\n{\"id\": \"%p\", \"type\": \"%s\", \"size\": %zu",
+            heap_dump.objects ? "," : "", (void *)obj,
+            object_tag_name(obj->tag), obj_size(obj));
+    if (info) {
+      fprintf(fp, ", \"lambda\": \"%s\", \"file\": ", info->name);
+      heap_dump_string(info->file);
+      fprintf(fp, ", \"line\": %zu", info->line);
+    }
+    fputs(", \"refs\": [", fp);
+  } else {
+    fprintf(fp, "  \"%p\" [label=\"%s %zu", (void *)obj,
+            object_tag_name(obj->tag), obj_size(obj));
+    if (info) {
+      fprintf(fp, "\\n%s (", info->name);
+      heap_dump_string(info->file);
+      fprintf(fp, ":%zu)", info->line);
+    }
+    fputs("\"];\n", fp);
+  }
+
+  hash_table_ptr_map_insert(heap_dump.written, (size_t)obj, obj);
+  heap_dump.objects++;
+  heap_dump.from = obj;
+  heap_dump.refs = 0;
+}
+
+static void heap_dump_object_end(void) {
+  if (heap_dump.json)
+    fputs("]}", heap_dump.fp);
+
+  heap_dump.from = NULL;
+}
+
+static void heap_dump_ref(struct obj *to) {
+  if (heap_dump.json)
+    fprintf(heap_dump.fp, "%s\"%p\"", heap_dump.refs ? ", " : "", (void *)to);
+  else
+    fprintf(heap_dump.fp, "  \"%p\" -> \"%p\";\n", (void *)heap_dump.from,
+            (void *)to);
+
+  heap_dump.refs++;
+}
+
+static void heap_dump_root(struct obj *root, bool first) {
+  if (!root || IS_FIXNUM(root))
+    return;
+
+  if (heap_dump.json)
+    fprintf(heap_dump.fp, "%s\"%p\"", first ? "" : ", ", (void *)root);
+  else
+    fprintf(heap_dump.fp, "  roots -> \"%p\";\n", (void *)root);
+}
+
+// The roots are the thunk being called and those registered
+static void heap_dump_end(struct thunk *thnk) {
+  fputs(heap_dump.json ? "\n],\n\"roots\": [" : "  roots [shape=box];\n",
+        heap_dump.fp);
+
+  heap_dump_root((struct obj *)thnk->closr, true);
+  heap_dump_root(thnk->closr->size == CLOSURE_ONE ? thnk->one.rand
+                                                  : thnk->two.rand,
+                 false);
+  if (thnk->closr->size == CLOSURE_TWO)
+    heap_dump_root(thnk->two.cont, false);
+
+  for (size_t i = 0; i < gc_global_data.roots.length; i++)
+    heap_dump_root(*vector_gc_roots_index(&gc_global_data.roots, i), false);
+
+  fputs(heap_dump.json ? "]}\n" : "}\n", heap_dump.fp);
+  fclose(heap_dump.fp);
+  heap_dump.fp = NULL;
+
+  hash_table_ptr_map_free(heap_dump.written);
+  free(heap_dump.path);
+  heap_dump.path = NULL;
+}
+
 // Mark an object as grey and add it to the queue of grey nodes 'if' it is not
 // already grey or black
 static bool maybe_mark_grey_and_queue(struct gc_context *ctx, struct obj *obj) {
   if (DEBUG_ONLY(!obj)) {
     DEBUG_FPRINTF(stderr, "trying to mark NULL!\n");
   }
+  if (IS_FIXNUM(obj)) {
+    return false;
+  }
+  if (heap_dump.fp) {
+    if (heap_dump.from)
+      heap_dump_ref(obj);
+
+    // a static object marked by an earlier gc is still written once
+    if (obj->mark == BLACK &&
+        !hash_table_ptr_map_lookup(heap_dump.written, (size_t)obj))
+      obj->mark = WHITE;
+  }
   switch (obj->mark) {
   case BLACK:
   case GREY:
@@ -120,6 +380,53 @@ void free_ht(struct obj *ht_obj) {
   hash_table_obj_free(ht->ht);
 }
 
+// vectors are created on the heap, but may hold pointers to stack objects
+struct obj *toheap_vector(struct obj *vec_obj, struct gc_context *ctx) {
+  struct vector_obj *vec = (struct vector_obj *)vec_obj;
+
+  if (vec->base.on_stack) {
+    TOUCH_OBJECT(vec, "toheap_vector");
+    size_t size = sizeof(struct vector_obj) + vec->len * sizeof(struct obj *);
+    struct vector_obj *heap_vec = gc_malloc(size);
+    memcpy(heap_vec, vec, size);
+    vec = heap_vec;
+  }
+
+  for (size_t i = 0; i < vec->len; i++) {
+    if (!vec->elems[i])
+      continue;
+
+    struct ptr_toupdate_pair p = {.toupdate = &vec->elems[i],
+                                  .on_stack = vec->elems[i]};
+    queue_ptr_toupdate_pair_enqueue(&ctx->pointers_toupdate, p);
+  }
+
+  return (struct obj *)vec;
+}
+
+void mark_vector(struct obj *vec_obj, struct gc_context *ctx) {
+  struct vector_obj *vec = (struct vector_obj *)vec_obj;
+
+  for (size_t i = 0; i < vec->len; i++) {
+    if (vec->elems[i])
+      maybe_mark_grey_and_queue(ctx, vec->elems[i]);
+  }
+}
+
+struct obj *toheap_bytevector(struct obj *obj, struct gc_context *ctx) {
+  struct bytevector_obj *bvec = (struct bytevector_obj *)obj;
+
+  if (obj->on_stack) {
+    TOUCH_OBJECT(obj, "toheap_bytevector");
+    size_t size = sizeof(struct bytevector_obj) + bvec->len;
+    struct bytevector_obj *heap_bvec = gc_malloc(size);
+    memcpy(heap_bvec, bvec, size);
+    bvec = heap_bvec;
+  }
+
+  return (struct obj *)bvec;
+}
+
 struct obj *toheap_cons(struct obj *cons_obj, struct gc_context *ctx) {
   struct cons_obj *cons = (struct cons_obj *)cons_obj;
 
@@ -157,6 +464,102 @@ void mark_cons(struct obj *cons_obj, struct gc_context *ctx) {
   }
 }
 
+struct obj *toheap_error(struct obj *error_obj, struct gc_context *ctx) {
+  struct error_obj *err = (struct error_obj *)error_obj;
+
+  if (err->base.on_stack) {
+    TOUCH_OBJECT(err, "toheap_error");
+    struct error_obj *heap_err = gc_malloc(sizeof(struct error_obj));
+    *heap_err = *err;
+    err = heap_err;
+  }
+
+  if (err->message) {
+    struct ptr_toupdate_pair p = {.toupdate = (struct obj **)&err->message,
+                                  .on_stack = (struct obj *)err->message};
+    queue_ptr_toupdate_pair_enqueue(&ctx->pointers_toupdate, p);
+  }
+
+  if (err->irritants) {
+    struct ptr_toupdate_pair p = {.toupdate = (struct obj **)&err->irritants,
+                                  .on_stack = (struct obj *)err->irritants};
+    queue_ptr_toupdate_pair_enqueue(&ctx->pointers_toupdate, p);
+  }
+
+  return (struct obj *)err;
+}
+
+void mark_error(struct obj *error_obj, struct gc_context *ctx) {
+  struct error_obj *err = (struct error_obj *)error_obj;
+
+  if (err->message) {
+    maybe_mark_grey_and_queue(ctx, err->message);
+  }
+
+  if (err->irritants) {
+    maybe_mark_grey_and_queue(ctx, err->irritants);
+  }
+}
+
+struct obj *toheap_promise(struct obj *promise_obj, struct gc_context *ctx) {
+  struct promise_obj *promise = (struct promise_obj *)promise_obj;
+
+  if (promise->base.on_stack) {
+    TOUCH_OBJECT(promise, "toheap_promise");
+    struct promise_obj *heap_promise = gc_malloc(sizeof(struct promise_obj));
+    *heap_promise = *promise;
+    promise = heap_promise;
+  }
+
+  if (promise->val) {
+    struct ptr_toupdate_pair p = {.toupdate = (struct obj **)&promise->val,
+                                  .on_stack = (struct obj *)promise->val};
+    queue_ptr_toupdate_pair_enqueue(&ctx->pointers_toupdate, p);
+  }
+
+  return (struct obj *)promise;
+}
+
+void mark_promise(struct obj *promise_obj, struct gc_context *ctx) {
+  struct promise_obj *promise = (struct promise_obj *)promise_obj;
+
+  if (promise->val) {
+    maybe_mark_grey_and_queue(ctx, promise->val);
+  }
+}
+
+// Record types are only allocated on the heap and their names are symbols,
+// which are never collected
+struct obj *toheap_record_type(struct obj *obj, struct gc_context *ctx) {
+  return obj;
+}
+
+struct obj *toheap_record(struct obj *rec_obj, struct gc_context *ctx) {
+  struct record_obj *rec = (struct record_obj *)rec_obj;
+
+  for (size_t i = 0; i < rec->type->len; i++) {
+    if (!rec->fields[i])
+      continue;
+
+    struct ptr_toupdate_pair p = {.toupdate = &rec->fields[i],
+                                  .on_stack = rec->fields[i]};
+    queue_ptr_toupdate_pair_enqueue(&ctx->pointers_toupdate, p);
+  }
+
+  return rec_obj;
+}
+
+void mark_record(struct obj *rec_obj, struct gc_context *ctx) {
+  struct record_obj *rec = (struct record_obj *)rec_obj;
+
+  maybe_mark_grey_and_queue(ctx, (struct obj *)rec->type);
+
+  for (size_t i = 0; i < rec->type->len; i++) {
+    if (rec->fields[i])
+      maybe_mark_grey_and_queue(ctx, rec->fields[i]);
+  }
+}
+
 struct obj *toheap_cell(struct obj *cell_obj, struct gc_context *ctx) {
   struct cell_obj *cell = (struct cell_obj *)cell_obj;
 
@@ -262,6 +665,55 @@ struct obj *toheap_int_obj(struct obj *obj, struct gc_context *ctx) {
   return (struct obj *)intobj;
 }
 
+struct obj *toheap_float_obj(struct obj *obj, struct gc_context *ctx) {
+  if (obj->on_stack) {
+    TOUCH_OBJECT(obj, "toheap_float");
+    struct float_obj *heap_floatobj = gc_malloc(sizeof(struct float_obj));
+    memcpy(heap_floatobj, obj, sizeof(struct float_obj));
+    obj = (struct obj *)heap_floatobj;
+  }
+
+  return obj;
+}
+
+struct obj *toheap_rational_obj(struct obj *obj, struct gc_context *ctx) {
+  if (obj->on_stack) {
+    TOUCH_OBJECT(obj, "toheap_rational");
+    struct rational_obj *heap_ratobj = gc_malloc(sizeof(struct rational_obj));
+    memcpy(heap_ratobj, obj, sizeof(struct rational_obj));
+    obj = (struct obj *)heap_ratobj;
+  }
+
+  return obj;
+}
+
+// Symbols live forever outside of the gc heap
+struct obj *toheap_symbol(struct obj *obj, struct gc_context *ctx) {
+  return obj;
+}
+
+// The two booleans are static as well
+struct obj *toheap_bool(struct obj *obj, struct gc_context *ctx) {
+  return obj;
+}
+
+// There is only the one static interaction environment
+struct obj *toheap_environment(struct obj *obj, struct gc_context *ctx) {
+  return obj;
+}
+
+// Ports are either static or already on the heap
+struct obj *toheap_port(struct obj *obj, struct gc_context *ctx) {
+  return obj;
+}
+
+void free_port(struct obj *obj) {
+  struct port_obj *port = (struct port_obj *)obj;
+
+  if (port->fp)
+    fclose(port->fp);
+}
+
 struct obj *toheap_string_obj(struct obj *obj, struct gc_context *ctx) {
   struct string_obj *strobj = (struct string_obj *)obj;
 
@@ -294,14 +746,34 @@ void gc_free_context(struct gc_context *ctx) {
 }
 
 void gc_mark_obj(struct gc_context *ctx, struct obj *obj) {
+  if (IS_FIXNUM(obj)) {
+    return;
+  }
+
+  if (heap_dump.fp) {
+    if (hash_table_ptr_map_lookup(heap_dump.written, (size_t)obj))
+      return;
+    heap_dump_object(obj);
+  }
+
   obj->mark = BLACK;
   gc_func_map[obj->tag].mark(obj, ctx);
+
+  if (heap_dump.fp)
+    heap_dump_object_end();
 }
 
 // Moves all live objects on the stack over to the heap
 struct obj *gc_toheap(struct gc_context *ctx, struct obj *obj) {
-  if (!obj) {
-    return NULL;
+  // fixnums aren't allocated anywhere so are already where they need to be
+  if (!obj || IS_FIXNUM(obj)) {
+    return obj;
+  }
+
+  // the heap objects that can point to the stack are looked inside by
+  // gc_minor, the rest only point to other heap objects
+  if (!obj->on_stack) {
+    return obj;
   }
 
   // if we've already copied this object,
@@ -322,20 +794,71 @@ struct obj *gc_toheap(struct gc_context *ctx, struct obj *obj) {
   // mark the object as now being on the heap
   new_obj->on_stack = false;
 
-  // Add it to the updated map
-  // Even if it was on the heap already we still insert
-  // since we then won't process child objects further
+  // Add it to the updated map so that it's only copied once
   hash_table_ptr_map_insert(ctx->updated_pointers, (size_t)obj, new_obj);
 
   return new_obj;
 }
 
+// Queues the pointers held by a heap object, which may be to the stack
+static void gc_scan_heap_obj(struct gc_context *ctx, struct obj *obj) {
+  gc_func_map[obj->tag].toheap(obj, ctx);
+}
+
+// The continuations of calls not in tail position are kept on the heap, so a
+// recursion that doesn't end fills the heap instead of the stack. The program
+// is stopped once more than gc_max_heap objects are left after a major gc,
+// naming the lambda of the pending call, or of its continuation, if they're
+// the program's.
+static void gc_heap_exhausted(struct thunk *thnk) {
+  struct obj *callees[] = {
+      (struct obj *)thnk->closr,
+      thnk->closr->size == CLOSURE_TWO ? thnk->two.cont : NULL,
+  };
+
+  for (size_t i = 0; i < sizeof(callees) / sizeof(callees[0]); i++) {
+    if (!callees[i] || obj_tag(callees[i]) != OBJ_CLOSURE)
+      continue;
+
+    struct lambda_info *info =
+        lambda_info_of((struct closure_obj *)callees[i]);
+
+    if (info)
+      RUNTIME_ERROR("recursion too deep, %zu objects are live calling %s "
+                    "(%s:%zu)",
+                    gc_global_data.nodes.length, info->name, info->file,
+                    info->line);
+  }
+
+  RUNTIME_ERROR("recursion too deep, %zu objects are live",
+                gc_global_data.nodes.length);
+}
+
 // The minor gc, moves all stack objects to the heap
 // The parameter 'thnk' is the current thunk holding everything together
 // The thunk should be heap allocated and freed after being called
+//
+// Only the heap objects made since the last minor gc, and the older ones
+// remembered by the write barrier, can point to the stack, so those are the
+// only heap objects looked inside. The whole heap is only collected by
+// gc_major once it has grown enough.
 void gc_minor(struct gc_context *ctx, struct thunk *thnk) {
   DEBUG_FPRINTF(stderr, "minor gc occuring\n");
 
+  // copying adds to the nodes, those are done with as they're copied
+  size_t young_end = gc_global_data.nodes.length;
+
+  for (size_t i = gc_global_data.young_start; i < young_end; i++) {
+    struct obj *obj = vector_gc_heap_nodes_index(&gc_global_data.nodes, i);
+
+    if (obj)
+      gc_scan_heap_obj(ctx, obj);
+  }
+
+  HASH_TABLE_ITER(ptr_map, key, obj, gc_global_data.remembered,
+                  { gc_scan_heap_obj(ctx, *obj); });
+  hash_table_ptr_map_clear(gc_global_data.remembered);
+
   // initially mark the closure and it's arguments to be applied
   thnk->closr = (struct closure_obj *)gc_toheap(ctx, (struct obj *)thnk->closr);
 
@@ -355,6 +878,11 @@ void gc_minor(struct gc_context *ctx, struct thunk *thnk) {
     break;
   }
 
+  for (size_t i = 0; i < gc_global_data.roots.length; i++) {
+    struct obj **root = vector_gc_roots_index(&gc_global_data.roots, i);
+    *root = gc_toheap(ctx, *root);
+  }
+
   // work through each pointer that needs to be updated
   while (queue_ptr_toupdate_pair_len(&ctx->pointers_toupdate) > 0) {
     struct ptr_toupdate_pair to_update =
@@ -379,7 +907,17 @@ void gc_minor(struct gc_context *ctx, struct thunk *thnk) {
     }
   }
 
-  gc_major(ctx, thnk);
+  if (gc_heap_full() || heap_dump_wanted()) {
+    gc_major(ctx, thnk);
+
+    if (rt_stats.enabled)
+      rt_stats.major_collections++;
+
+    if (gc_global_data.nodes.length > gc_max_heap)
+      gc_heap_exhausted(thnk);
+  }
+
+  gc_global_data.young_start = gc_global_data.nodes.length;
 }
 
 // The major gc, collects objects on the heap
@@ -387,6 +925,9 @@ void gc_major(struct gc_context *ctx, struct thunk *thnk) {
   size_t num_freed = 0;
   size_t num_marked = 0;
 
+  if (heap_dump_wanted())
+    heap_dump_begin();
+
   gc_mark_obj(ctx, &thnk->closr->base);
   num_marked++;
 
@@ -406,6 +947,12 @@ void gc_major(struct gc_context *ctx, struct thunk *thnk) {
     break;
   }
 
+  for (size_t i = 0; i < gc_global_data.roots.length; i++) {
+    struct obj *root = *vector_gc_roots_index(&gc_global_data.roots, i);
+    if (root)
+      maybe_mark_grey_and_queue(ctx, root);
+  }
+
   while (queue_gc_grey_nodes_len(&ctx->grey_nodes) > 0) {
     struct obj *next_obj = queue_gc_grey_nodes_dequeue(&ctx->grey_nodes);
     if (DEBUG_ONLY(!next_obj)) {
@@ -415,7 +962,10 @@ void gc_major(struct gc_context *ctx, struct thunk *thnk) {
     num_marked++;
   }
 
-  DEBUG_FPRINTF("marked %zu objects\n", num_marked);
+  DEBUG_FPRINTF(stderr, "marked %zu objects\n", num_marked);
+
+  if (heap_dump.fp)
+    heap_dump_end(thnk);
 
 #ifdef DEBUG
   int seen_types[LAST_OBJ_TYPE] = {0};
@@ -473,13 +1023,48 @@ void gc_major(struct gc_context *ctx, struct thunk *thnk) {
   gc_heap_maintain();
 }
 
-void gc_init(void) { gc_global_data.nodes = vector_gc_heap_nodes_new(100); }
+void gc_init(void) {
+  gc_global_data.nodes = vector_gc_heap_nodes_new(100);
+  gc_global_data.remembered = hash_table_ptr_map_new();
+  gc_global_data.roots = vector_gc_roots_new(10);
+
+  const char *max_heap = getenv("SCHEME_MAX_HEAP");
+
+  if (max_heap && strtoull(max_heap, NULL, 10) > 0)
+    gc_max_heap = strtoull(max_heap, NULL, 10);
+
+  if (getenv("SCHEME_HEAP_DUMP"))
+    signal(SIGUSR1, heap_dump_signal);
+}
+
+void gc_remember(struct obj *obj) {
+  if (!hash_table_ptr_map_lookup(gc_global_data.remembered, (size_t)obj))
+    hash_table_ptr_map_insert(gc_global_data.remembered, (size_t)obj, obj);
+}
+
+// Called after a pointer to val is stored in obj, by the runtime through
+// GC_WRITE_BARRIER and by the code emitted for set!. The minor gc doesn't look
+// inside heap objects older than the last one, so one of those given a pointer
+// to the stack is remembered for the next minor gc to update.
+void gc_write_barrier(struct obj *obj, struct obj *val) {
+  GC_WRITE_BARRIER(obj, val);
+}
 
 // wrapped malloc that adds allocated stuff to the bookkeeper
 void *gc_malloc(size_t size) {
   void *ptr = malloc(size);
 
   vector_gc_heap_nodes_push(&gc_global_data.nodes, ptr);
+
+  if (rt_stats.enabled) {
+    rt_stats.heap_allocs++;
+    rt_stats.heap_bytes += size;
+
+    if (gc_global_data.nodes.length > rt_stats.peak_heap_objects) {
+      rt_stats.peak_heap_objects = gc_global_data.nodes.length;
+    }
+  }
+
   return ptr;
 }
 
@@ -496,4 +1081,28 @@ void gc_heap_maintain(void) {
   gc_global_data.nodes.length = last_i;
   if (last_i && (original_len / last_i) > 2)
     vector_gc_heap_nodes_shrink_to_fit(&gc_global_data.nodes);
+
+  gc_collect_at = 2 * last_i + GC_MIN_HEAP_NODES;
+}
+
+// Whether the heap has grown enough since the last collection to collect
+// again, for code that allocates on the heap instead of the stack and so never
+// runs out of stack to trigger a minor gc
+bool gc_heap_full(void) {
+  return gc_global_data.nodes.length > gc_collect_at;
+}
+
+// Registers a pointer to an object that the runtime keeps alive across
+// bounces, the object it points to is moved to the heap and kept alive by each
+// gc and the pointer is updated to the new location
+void gc_register_root(struct obj **root) {
+  vector_gc_roots_push(&gc_global_data.roots, root);
+}
+
+void gc_unregister_root(struct obj **root) {
+  size_t idx = vector_gc_roots_indexof(&gc_global_data.roots, root);
+
+  if (idx < gc_global_data.roots.length) {
+    vector_gc_roots_remove(&gc_global_data.roots, idx);
+  }
 }
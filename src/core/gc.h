@@ -8,6 +8,7 @@
 
 DEFINE_VECTOR(size_t, size_t);
 DEFINE_VECTOR(struct obj *, gc_heap_nodes);
+DEFINE_VECTOR(struct obj **, gc_roots);
 DEFINE_QUEUE(struct obj *, gc_grey_nodes);
 DEFINE_HASH(size_t, struct obj *, ptr_map);
 
@@ -51,6 +52,17 @@ struct gc_funcs {
 
 struct gc_data {
   struct vector_gc_heap_nodes nodes;
+
+  // the index in nodes of the first object put on the heap since the last
+  // minor gc, which looks inside the objects from there on
+  size_t young_start;
+
+  // older heap objects given pointers to the stack since the last minor gc,
+  // which it also looks inside, see gc_write_barrier
+  struct hash_table_ptr_map *remembered;
+
+  // pointers to objects that are live outside of the current thunk
+  struct vector_gc_roots roots;
 };
 
 void gc_init(void);
@@ -69,12 +81,34 @@ struct obj *toheap_int_obj(struct obj *, struct gc_context *);
 
 struct obj *toheap_string_obj(struct obj *, struct gc_context *);
 
+struct obj *toheap_float_obj(struct obj *, struct gc_context *);
+
+struct obj *toheap_rational_obj(struct obj *, struct gc_context *);
+
+struct obj *toheap_symbol(struct obj *, struct gc_context *);
+struct obj *toheap_bool(struct obj *, struct gc_context *);
+struct obj *toheap_port(struct obj *, struct gc_context *);
+struct obj *toheap_environment(struct obj *, struct gc_context *);
+void free_port(struct obj *);
+struct obj *toheap_error(struct obj *, struct gc_context *);
+void mark_error(struct obj *, struct gc_context *);
+struct obj *toheap_promise(struct obj *, struct gc_context *);
+void mark_promise(struct obj *, struct gc_context *);
+struct obj *toheap_record_type(struct obj *, struct gc_context *);
+struct obj *toheap_record(struct obj *, struct gc_context *);
+void mark_record(struct obj *, struct gc_context *);
+
 struct obj *toheap_cell(struct obj *, struct gc_context *);
 void mark_cell(struct obj *, struct gc_context *);
 
 struct obj *toheap_cons(struct obj *, struct gc_context *);
 void mark_cons(struct obj *, struct gc_context *);
 
+struct obj *toheap_vector(struct obj *, struct gc_context *);
+void mark_vector(struct obj *, struct gc_context *);
+
+struct obj *toheap_bytevector(struct obj *, struct gc_context *);
+
 struct obj *toheap_ht(struct obj *, struct gc_context *);
 void mark_ht(struct obj *, struct gc_context *);
 void free_ht(struct obj *);
@@ -89,6 +123,13 @@ struct obj *gc_toheap(struct gc_context *, struct obj *);
 void gc_mark_obj(struct gc_context *, struct obj *);
 
 void gc_heap_maintain(void);
+bool gc_heap_full(void);
 void *gc_malloc(size_t);
 
+// the next gc writes a dump of the live objects to the file
+void gc_dump_heap_next(const char *);
+
+void gc_register_root(struct obj **);
+void gc_unregister_root(struct obj **);
+
 #endif // SOMESCHEME_GC_H
@@ -0,0 +1,100 @@
+#include <stdlib.h>
+#include <string.h>
+
+#include "base.h"
+#include "common.h"
+#include "gc.h"
+
+// Support for programs compiled with --target llvm. Their lambdas allocate
+// everything on the heap and tail call each other instead of going through
+// call_closure_one and call_closure_two, so the stack only grows when a
+// builtin calls back into the program. Before each call they check
+// gc_heap_full and collect through llvm_collect_one or llvm_collect_two.
+
+struct obj *llvm_cell_new(struct obj *val) {
+  struct cell_obj *cell = gc_malloc(sizeof(struct cell_obj));
+  cell->base = object_base_new(OBJ_CELL);
+  cell->base.on_stack = false;
+  cell->val = val;
+
+  return (struct obj *)cell;
+}
+
+// The slots are zeroed since the lambda given the env only fills in the cells
+// of its parameters once it's called
+struct env_obj *llvm_env_new(size_t len) {
+  struct env_obj *env =
+      gc_malloc(sizeof(struct env_obj) + len * sizeof(struct obj *));
+  env->base = object_base_new(ENV_OBJ);
+  env->base.on_stack = false;
+  env->len = len;
+  memset(&env->env, 0, len * sizeof(struct obj *));
+
+  if (rt_stats.enabled) {
+    rt_stats_env(len);
+  }
+
+  return env;
+}
+
+struct obj *llvm_closure_new(enum closure_size size, void *fn,
+                             struct env_obj *env) {
+  struct closure_obj *closure = gc_malloc(sizeof(struct closure_obj));
+  closure->base = object_base_new(OBJ_CLOSURE);
+  closure->base.on_stack = false;
+  closure->size = size;
+  closure->fn_1 = fn;
+  closure->env = env;
+
+  return (struct obj *)closure;
+}
+
+// The function of `rator`, checking that it's a closure taking `size` args
+void *llvm_closure_fn(struct obj *rator, enum closure_size size) {
+  if (!rator || obj_tag(rator) != OBJ_CLOSURE) {
+    RUNTIME_ERROR("Called object (%p) was not a closure but was: %d", rator,
+                  rator ? obj_tag(rator) : 0);
+  }
+
+  struct closure_obj *closure = (struct closure_obj *)rator;
+
+  if (closure->size != size) {
+    RUNTIME_ERROR("Called a closure that takes %d args with %d args",
+                  closure->size + 1, size + 1);
+  }
+
+  return closure->fn_1;
+}
+
+void llvm_collect_one(struct obj *rator, struct obj *rand) {
+  struct thunk *thnk = malloc(sizeof(struct thunk));
+  thnk->closr = (struct closure_obj *)rator;
+  thnk->one.rand = rand;
+  run_minor_gc(thnk);
+}
+
+void llvm_collect_two(struct obj *rator, struct obj *rand, struct obj *cont) {
+  struct thunk *thnk = malloc(sizeof(struct thunk));
+  thnk->closr = (struct closure_obj *)rator;
+  thnk->two.rand = rand;
+  thnk->two.cont = cont;
+  run_minor_gc(thnk);
+}
+
+int main(int argc, char **argv) {
+  extern struct symbol_obj *scheme_symbols[];
+  extern struct lambda_info scheme_lambdas[];
+  extern void main_lambda(struct obj *, struct env_obj *);
+
+  struct closure_obj initial_closure =
+      object_closure_one_new(main_lambda, NULL);
+
+  struct thunk *thnk = malloc(sizeof(struct thunk));
+  thnk->closr = &initial_closure;
+  thnk->one.rand = NULL;
+
+  scheme_set_args(argc, argv);
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk);
+}
@@ -0,0 +1,274 @@
+#include <inttypes.h>
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "base.h"
+#include "common.h"
+#include "number.h"
+
+static const char *number_op_names[] = {
+    [NUM_ADD] = "add",
+    [NUM_SUB] = "sub",
+    [NUM_MUL] = "mul",
+    [NUM_DIV] = "div",
+};
+
+static const char *number_cmp_names[] = {
+    [NUM_LT] = "lt",
+    [NUM_LEQ] = "leq",
+    [NUM_GT] = "gt",
+    [NUM_GEQ] = "geq",
+    [NUM_EQ] = "eq",
+};
+
+bool obj_is_number(struct obj *obj) {
+  if (!obj) {
+    return false;
+  }
+
+  switch (obj_tag(obj)) {
+  case OBJ_INT:
+  case OBJ_FLOAT:
+  case OBJ_RATIONAL:
+    return true;
+  default:
+    return false;
+  }
+}
+
+static void check_operands(const char *name, struct obj *lhs,
+                           struct obj *rhs) {
+  if (!obj_is_number(lhs))
+    RUNTIME_ERROR("Left operand to binary %s not of numeric type", name);
+  if (!obj_is_number(rhs))
+    RUNTIME_ERROR("Right operand to binary %s not of numeric type", name);
+}
+
+static bool either_float(struct obj *lhs, struct obj *rhs) {
+  return obj_tag(lhs) == OBJ_FLOAT || obj_tag(rhs) == OBJ_FLOAT;
+}
+
+static double number_to_double(struct obj *obj) {
+  switch (obj_tag(obj)) {
+  case OBJ_FLOAT:
+    return ((struct float_obj *)obj)->val;
+  case OBJ_RATIONAL: {
+    struct rational_obj *r = (struct rational_obj *)obj;
+    return (double)r->num / (double)r->den;
+  }
+  default:
+    return (double)obj_int_val(obj);
+  }
+}
+
+// exact numbers as a ratio, integers have a denominator of 1
+static void number_to_ratio(struct obj *obj, int64_t *num, int64_t *den) {
+  if (obj_tag(obj) == OBJ_RATIONAL) {
+    struct rational_obj *r = (struct rational_obj *)obj;
+    *num = r->num;
+    *den = r->den;
+  } else {
+    *num = obj_int_val(obj);
+    *den = 1;
+  }
+}
+
+static int64_t checked_add(const char *name, int64_t a, int64_t b) {
+  int64_t res;
+  if (__builtin_add_overflow(a, b, &res))
+    RUNTIME_ERROR("Integer overflow in binary %s", name);
+  return res;
+}
+
+static int64_t checked_sub(const char *name, int64_t a, int64_t b) {
+  int64_t res;
+  if (__builtin_sub_overflow(a, b, &res))
+    RUNTIME_ERROR("Integer overflow in binary %s", name);
+  return res;
+}
+
+static int64_t checked_mul(const char *name, int64_t a, int64_t b) {
+  int64_t res;
+  if (__builtin_mul_overflow(a, b, &res))
+    RUNTIME_ERROR("Integer overflow in binary %s", name);
+  return res;
+}
+
+// Anything involving a float is done in floating point, otherwise the result
+// is exact
+struct obj *number_arith(enum number_op op, struct obj *lhs,
+                         struct obj *rhs) {
+  const char *name = number_op_names[op];
+  check_operands(name, lhs, rhs);
+
+  if (either_float(lhs, rhs)) {
+    double a = number_to_double(lhs);
+    double b = number_to_double(rhs);
+
+    switch (op) {
+    case NUM_ADD:
+      return object_float_new(a + b);
+    case NUM_SUB:
+      return object_float_new(a - b);
+    case NUM_MUL:
+      return object_float_new(a * b);
+    case NUM_DIV:
+      return object_float_new(a / b);
+    }
+  }
+
+  // the common case, skip the ratio arithmetic
+  if (obj_tag(lhs) == OBJ_INT && obj_tag(rhs) == OBJ_INT && op != NUM_DIV) {
+    int64_t a = obj_int_val(lhs);
+    int64_t b = obj_int_val(rhs);
+
+    switch (op) {
+    case NUM_ADD:
+      return object_int_new(checked_add(name, a, b));
+    case NUM_SUB:
+      return object_int_new(checked_sub(name, a, b));
+    default:
+      return object_int_new(checked_mul(name, a, b));
+    }
+  }
+
+  int64_t an, ad, bn, bd;
+  number_to_ratio(lhs, &an, &ad);
+  number_to_ratio(rhs, &bn, &bd);
+
+  switch (op) {
+  case NUM_ADD:
+    return object_rational_new(checked_add(name, checked_mul(name, an, bd),
+                                           checked_mul(name, bn, ad)),
+                               checked_mul(name, ad, bd));
+  case NUM_SUB:
+    return object_rational_new(checked_sub(name, checked_mul(name, an, bd),
+                                           checked_mul(name, bn, ad)),
+                               checked_mul(name, ad, bd));
+  case NUM_MUL:
+    return object_rational_new(checked_mul(name, an, bn),
+                               checked_mul(name, ad, bd));
+  case NUM_DIV:
+    if (bn == 0)
+      RUNTIME_ERROR("Divide by zero in binary %s", name);
+    return object_rational_new(checked_mul(name, an, bd),
+                               checked_mul(name, ad, bn));
+  }
+
+  __builtin_unreachable();
+}
+
+bool number_compare(enum number_cmp cmp, struct obj *lhs, struct obj *rhs) {
+  const char *name = number_cmp_names[cmp];
+  check_operands(name, lhs, rhs);
+
+  if (either_float(lhs, rhs)) {
+    double a = number_to_double(lhs);
+    double b = number_to_double(rhs);
+
+    switch (cmp) {
+    case NUM_LT:
+      return a < b;
+    case NUM_LEQ:
+      return a <= b;
+    case NUM_GT:
+      return a > b;
+    case NUM_GEQ:
+      return a >= b;
+    case NUM_EQ:
+      return a == b;
+    }
+  }
+
+  int64_t an, ad, bn, bd;
+  number_to_ratio(lhs, &an, &ad);
+  number_to_ratio(rhs, &bn, &bd);
+
+  // denominators are always positive so cross multiplying keeps the order
+  int64_t a = checked_mul(name, an, bd);
+  int64_t b = checked_mul(name, bn, ad);
+
+  switch (cmp) {
+  case NUM_LT:
+    return a < b;
+  case NUM_LEQ:
+    return a <= b;
+  case NUM_GT:
+    return a > b;
+  case NUM_GEQ:
+    return a >= b;
+  case NUM_EQ:
+    return a == b;
+  }
+
+  __builtin_unreachable();
+}
+
+// The shortest digits that read back as the same value, with the point among
+// them when the exponent is from -6 to 20, and .0 on the end if they would
+// otherwise look like an integer, or with an exponent after them outside that
+static char *flonum_to_string(double val) {
+  static const char zeros[] = "00000000000000000000";
+  char *res;
+
+  if (isnan(val)) {
+    ALLOC_SPRINTF(res, "+nan.0");
+    return res;
+  }
+
+  if (isinf(val)) {
+    ALLOC_SPRINTF(res, "%s", val > 0 ? "+inf.0" : "-inf.0");
+    return res;
+  }
+
+  char sci[32];
+  for (int prec = 1; prec <= 17; prec++) {
+    snprintf(sci, sizeof(sci), "%.*e", prec - 1, val);
+    if (strtod(sci, NULL) == val)
+      break;
+  }
+
+  // the digits without the sign and the point, then the exponent
+  const char *sign = *sci == '-' ? "-" : "";
+  char digits[32];
+  int len = 0;
+  const char *p = sci + strlen(sign);
+  for (; *p != 'e'; p++)
+    if (*p != '.')
+      digits[len++] = *p;
+  digits[len] = '\0';
+  int exp = atoi(p + 1);
+
+  if (exp < -6 || exp > 20) {
+    ALLOC_SPRINTF(res, "%s%c%s%se%d", sign, digits[0], len > 1 ? "." : "",
+                  digits + 1, exp);
+  } else if (exp < 0) {
+    ALLOC_SPRINTF(res, "%s0.%.*s%s", sign, -exp - 1, zeros, digits);
+  } else if (exp + 1 >= len) {
+    ALLOC_SPRINTF(res, "%s%s%.*s.0", sign, digits, exp + 1 - len, zeros);
+  } else {
+    ALLOC_SPRINTF(res, "%s%.*s.%s", sign, exp + 1, digits, digits + exp + 1);
+  }
+
+  return res;
+}
+
+char *number_to_string(struct obj *obj) {
+  char *res;
+
+  switch (obj_tag(obj)) {
+  case OBJ_FLOAT:
+    return flonum_to_string(((struct float_obj *)obj)->val);
+  case OBJ_RATIONAL: {
+    struct rational_obj *r = (struct rational_obj *)obj;
+    ALLOC_SPRINTF(res, "%" PRId64 "/%" PRId64, r->num, r->den);
+    break;
+  }
+  default:
+    ALLOC_SPRINTF(res, "%" PRId64, obj_int_val(obj));
+    break;
+  }
+
+  return res;
+}
@@ -0,0 +1,16 @@
+#ifndef SOMESCHEME_NUMBER_H
+#define SOMESCHEME_NUMBER_H
+
+#include <stdbool.h>
+
+#include "base.h"
+
+enum number_op { NUM_ADD, NUM_SUB, NUM_MUL, NUM_DIV };
+enum number_cmp { NUM_LT, NUM_LEQ, NUM_GT, NUM_GEQ, NUM_EQ };
+
+bool obj_is_number(struct obj *);
+struct obj *number_arith(enum number_op, struct obj *, struct obj *);
+bool number_compare(enum number_cmp, struct obj *, struct obj *);
+char *number_to_string(struct obj *);
+
+#endif // SOMESCHEME_NUMBER_H
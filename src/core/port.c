@@ -0,0 +1,86 @@
+#include <errno.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "base.h"
+#include "common.h"
+#include "gc.h"
+#include "port.h"
+
+// The default ports are static like the booleans, their streams are filled in
+// on first use since stdin and stdout aren't constant
+static struct port_obj stdin_port = {
+    .base = {.tag = OBJ_PORT, .mark = WHITE, .on_stack = false},
+    .fp = NULL,
+    .input = true};
+static struct port_obj stdout_port = {
+    .base = {.tag = OBJ_PORT, .mark = WHITE, .on_stack = false},
+    .fp = NULL,
+    .input = false};
+static bool std_ports_opened = false;
+
+static void open_std_ports(void) {
+  if (std_ports_opened)
+    return;
+
+  stdin_port.fp = stdin;
+  stdout_port.fp = stdout;
+  std_ports_opened = true;
+}
+
+struct obj *port_current_input(void) {
+  open_std_ports();
+  return (struct obj *)&stdin_port;
+}
+
+struct obj *port_current_output(void) {
+  open_std_ports();
+  return (struct obj *)&stdout_port;
+}
+
+// Ports are allocated on the heap so that the gc can close the file when the
+// port becomes unreachable
+struct obj *port_open(const char *path, bool input) {
+  FILE *fp = fopen(path, input ? "r" : "w");
+
+  if (!fp)
+    RUNTIME_ERROR("Failed to open '%s': %s", path, strerror(errno));
+
+  struct port_obj *port = gc_malloc(sizeof(struct port_obj));
+  port->base = object_base_new(OBJ_PORT);
+  port->base.on_stack = false;
+  port->fp = fp;
+  port->input = input;
+  port->fold_case = false;
+
+  return (struct obj *)port;
+}
+
+struct port_obj *port_arg(const char *name, struct obj *v, bool input) {
+  if (!v || obj_tag(v) != OBJ_PORT)
+    RUNTIME_ERROR("Argument to %s was not a port", name);
+
+  struct port_obj *port = (struct port_obj *)v;
+
+  if (port->input != input)
+    RUNTIME_ERROR("Port given to %s was not an %s port", name,
+                  input ? "input" : "output");
+
+  if (!port->fp)
+    RUNTIME_ERROR("Port given to %s has been closed", name);
+
+  return port;
+}
+
+void port_close(struct port_obj *port) {
+  if (!port->fp)
+    return;
+
+  // closing the standard streams would break display, so just flush them
+  if (port->fp == stdin || port->fp == stdout)
+    fflush(port->fp);
+  else
+    fclose(port->fp);
+
+  port->fp = NULL;
+}
@@ -0,0 +1,14 @@
+#ifndef SOMESCHEME_PORT_H
+#define SOMESCHEME_PORT_H
+
+#include <stdbool.h>
+
+#include "base.h"
+
+struct obj *port_current_input(void);
+struct obj *port_current_output(void);
+struct obj *port_open(const char *, bool);
+struct port_obj *port_arg(const char *, struct obj *, bool);
+void port_close(struct port_obj *);
+
+#endif // SOMESCHEME_PORT_H
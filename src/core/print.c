@@ -0,0 +1,349 @@
+// The printer behind display and write. Pairs and vectors are printed with
+// their elements, and one that is reached again from inside itself is given a
+// label, printed as `#0=` where it is first printed and as `#0#` after that,
+// so that printing a cyclic object ends. The interpreter prints the same way.
+
+#include <ctype.h>
+#include <stdbool.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <time.h>
+
+#include "base.h"
+#include "hash_table.h"
+#include "number.h"
+#include "print.h"
+
+// on the path from the object being printed
+#define PRINT_ON_PATH 1
+// reached again from inside itself, so it needs a label
+#define PRINT_CYCLIC 2
+
+struct print_mark {
+  int flags;
+  // the label given when it is first printed, -1 before then
+  int label;
+};
+
+static bool print_key_eq(size_t a, size_t b) { return a == b; }
+
+DEFINE_HASH(size_t, struct print_mark, print_marks);
+MAKE_HASH(size_t, struct print_mark, hash_table_default_size_t_hash_fun,
+          print_key_eq, print_marks);
+
+struct print_ctx {
+  FILE *fp;
+  bool write;
+  struct hash_table_print_marks *marks;
+  int next_label;
+};
+
+static bool print_is_container(struct obj *val) {
+  return val && (obj_tag(val) == OBJ_CONS || obj_tag(val) == OBJ_VECTOR);
+}
+
+static struct print_mark *print_mark_of(struct hash_table_print_marks *marks,
+                                        struct obj *val) {
+  return hash_table_print_marks_lookup(marks, (size_t)val);
+}
+
+// Marks the pairs and vectors reached again from inside themselves. The cdrs
+// of a list are followed in a loop so that a long list doesn't take a frame
+// for each element.
+static void print_find_cycles(struct hash_table_print_marks *marks,
+                              struct obj *val) {
+  struct obj *start = val;
+
+  while (print_is_container(val)) {
+    struct print_mark *m = print_mark_of(marks, val);
+
+    if (m) {
+      if (m->flags & PRINT_ON_PATH)
+        m->flags |= PRINT_CYCLIC;
+      break;
+    }
+
+    hash_table_print_marks_insert(marks, (size_t)val,
+                                  (struct print_mark){PRINT_ON_PATH, -1});
+
+    if (obj_tag(val) == OBJ_VECTOR) {
+      struct vector_obj *v = (struct vector_obj *)val;
+
+      for (size_t i = 0; i < v->len; i++)
+        print_find_cycles(marks, v->elems[i]);
+
+      val = NULL;
+      break;
+    }
+
+    print_find_cycles(marks, ((struct cons_obj *)val)->car);
+    val = ((struct cons_obj *)val)->cdr;
+  }
+
+  // everything walked from start is finished with, val is where it stopped
+  for (struct obj *o = start; o != val;) {
+    print_mark_of(marks, o)->flags &= ~PRINT_ON_PATH;
+
+    if (obj_tag(o) == OBJ_VECTOR)
+      break;
+
+    o = ((struct cons_obj *)o)->cdr;
+  }
+}
+
+static bool print_is_cyclic(struct print_ctx *ctx, struct obj *val) {
+  struct print_mark *m = print_mark_of(ctx->marks, val);
+
+  return m && (m->flags & PRINT_CYCLIC);
+}
+
+// Prints the label of val if it has one, true when val has been printed
+// already and the reference to it is all there is to print.
+static bool print_label(struct print_ctx *ctx, struct obj *val) {
+  struct print_mark *m = print_mark_of(ctx->marks, val);
+
+  if (!m || !(m->flags & PRINT_CYCLIC))
+    return false;
+
+  if (m->label >= 0) {
+    fprintf(ctx->fp, "#%d#", m->label);
+    return true;
+  }
+
+  m->label = ctx->next_label++;
+  fprintf(ctx->fp, "#%d=", m->label);
+
+  return false;
+}
+
+static void print_string(struct print_ctx *ctx, const char *s) {
+  if (!ctx->write) {
+    fputs(s, ctx->fp);
+    return;
+  }
+
+  fputc('"', ctx->fp);
+
+  for (const char *c = s; *c; c++) {
+    switch (*c) {
+    case '"':
+      fputs("\\\"", ctx->fp);
+      break;
+    case '\\':
+      fputs("\\\\", ctx->fp);
+      break;
+    case '\n':
+      fputs("\\n", ctx->fp);
+      break;
+    case '\t':
+      fputs("\\t", ctx->fp);
+      break;
+    default:
+      fputc(*c, ctx->fp);
+    }
+  }
+
+  fputc('"', ctx->fp);
+}
+
+// Whether read would take the name for something else without bars around
+// it, or not read it at all
+static bool print_needs_bars(const char *name) {
+  if (!*name || *name == '#' || isdigit((unsigned char)*name))
+    return true;
+
+  if ((*name == '+' || *name == '-' || *name == '.') &&
+      isdigit((unsigned char)name[1]))
+    return true;
+
+  return strpbrk(name, " \t\n\r\f()\"';|") != NULL;
+}
+
+static void print_inner(struct print_ctx *ctx, struct obj *val) {
+  FILE *fp = ctx->fp;
+
+  if (!val) {
+    fputs("()", fp);
+    return;
+  }
+
+  switch (obj_tag(val)) {
+  case OBJ_CONS: {
+    if (print_label(ctx, val))
+      return;
+
+    fputc('(', fp);
+    print_inner(ctx, ((struct cons_obj *)val)->car);
+    val = ((struct cons_obj *)val)->cdr;
+
+    // a labelled pair in the tail is printed after a dot with its label
+    while (val && obj_tag(val) == OBJ_CONS && !print_is_cyclic(ctx, val)) {
+      fputc(' ', fp);
+      print_inner(ctx, ((struct cons_obj *)val)->car);
+      val = ((struct cons_obj *)val)->cdr;
+    }
+
+    if (val) {
+      fputs(" . ", fp);
+      print_inner(ctx, val);
+    }
+
+    fputc(')', fp);
+    break;
+  }
+  case OBJ_VECTOR: {
+    if (print_label(ctx, val))
+      return;
+
+    struct vector_obj *v = (struct vector_obj *)val;
+
+    fputs("#(", fp);
+
+    for (size_t i = 0; i < v->len; i++) {
+      if (i)
+        fputc(' ', fp);
+      print_inner(ctx, v->elems[i]);
+    }
+
+    fputc(')', fp);
+    break;
+  }
+  case OBJ_CLOSURE:
+    fputs("#<procedure>", fp);
+    break;
+  case OBJ_INT:
+  case OBJ_FLOAT:
+  case OBJ_RATIONAL: {
+    char *res = number_to_string(val);
+    fputs(res, fp);
+    free(res);
+    break;
+  }
+  case OBJ_STR:
+    print_string(ctx, ((struct string_obj *)val)->buf);
+    break;
+  case OBJ_CELL:
+    print_inner(ctx, ((struct cell_obj *)val)->val);
+    break;
+  case OBJ_HT:
+    fputs("hash table", fp);
+    break;
+  case OBJ_SYMBOL:
+    // so that read gives the symbol back
+    if (ctx->write && print_needs_bars(((struct symbol_obj *)val)->name))
+      fprintf(fp, "|%s|", ((struct symbol_obj *)val)->name);
+    else
+      fputs(((struct symbol_obj *)val)->name, fp);
+    break;
+  case OBJ_BYTEVECTOR:
+    fputs("bytevector", fp);
+    break;
+  case OBJ_BOOL:
+    fputs(((struct bool_obj *)val)->val ? "#t" : "#f", fp);
+    break;
+  case OBJ_PORT:
+    fputs("port", fp);
+    break;
+  case OBJ_PROMISE:
+    fputs("promise", fp);
+    break;
+  case OBJ_RECORD_TYPE:
+    fputs("record type", fp);
+    break;
+  case OBJ_ENVIRONMENT:
+    fputs("environment", fp);
+    break;
+  case OBJ_RECORD:
+    fprintf(fp, "%s record", ((struct record_obj *)val)->type->name->name);
+    break;
+  case OBJ_ERROR: {
+    struct error_obj *err = (struct error_obj *)val;
+    bool write = ctx->write;
+
+    // the message is always displayed
+    ctx->write = false;
+    print_inner(ctx, err->message);
+    ctx->write = write;
+
+    // followed by each irritant
+    for (struct obj *tail = err->irritants;
+         tail && obj_tag(tail) == OBJ_CONS;
+         tail = ((struct cons_obj *)tail)->cdr) {
+      fputc(' ', fp);
+      print_inner(ctx, ((struct cons_obj *)tail)->car);
+    }
+    break;
+  }
+  default:
+    RUNTIME_ERROR("Unexpected object tag to print: %d", obj_tag(val));
+  }
+}
+
+// Prints val to fp the way write does, or display when write is false
+void obj_print(FILE *fp, struct obj *val, bool write) {
+  struct print_ctx ctx = {
+      .fp = fp, .write = write, .marks = hash_table_print_marks_new()};
+
+  print_find_cycles(ctx.marks, val);
+  print_inner(&ctx, val);
+
+  hash_table_print_marks_free(ctx.marks);
+  free(ctx.marks);
+}
+
+// The name of the lambda a closure calls, or of the builtin for those of the
+// runtime, which have no name.
+static const char *trace_name(struct obj *f) {
+  if (!f || obj_tag(f) != OBJ_CLOSURE)
+    return "?";
+
+  struct lambda_info *info = lambda_info_of((struct closure_obj *)f);
+  return info ? info->name : "builtin";
+}
+
+// Logs a call made by a program compiled with --trace to stderr: f called
+// from the function caller with arg and, if f is a procedure rather than a
+// continuation, the continuation k. Only calls from or to lambdas whose names
+// contain $SCHEME_TRACE are logged, if it is set, and at most
+// $SCHEME_TRACE_RATE a second, 100 by default.
+void trace_call(const char *caller, struct obj *f, struct obj *arg,
+                struct obj *k) {
+  static bool init;
+  static const char *filter;
+  static size_t rate = 100;
+  static time_t second;
+  static size_t logged, dropped;
+
+  if (!init) {
+    init = true;
+    filter = getenv("SCHEME_TRACE");
+    const char *r = getenv("SCHEME_TRACE_RATE");
+    if (r)
+      rate = strtoul(r, NULL, 10);
+  }
+
+  const char *callee = trace_name(f);
+
+  if (filter && !strstr(caller, filter) && !strstr(callee, filter))
+    return;
+
+  time_t now = time(NULL);
+  if (now != second) {
+    if (dropped)
+      fprintf(stderr, "trace: %zu calls not logged\n", dropped);
+    second = now;
+    logged = dropped = 0;
+  }
+
+  if (logged++ >= rate) {
+    dropped++;
+    return;
+  }
+
+  fprintf(stderr, "trace: %s -> %s ", caller, callee);
+  obj_print(stderr, arg, true);
+  if (k)
+    fprintf(stderr, " k=%s", trace_name(k));
+  fputc('\n', stderr);
+}
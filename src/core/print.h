@@ -0,0 +1,11 @@
+#ifndef SOMESCHEME_PRINT_H
+#define SOMESCHEME_PRINT_H
+
+#include <stdbool.h>
+#include <stdio.h>
+
+#include "base.h"
+
+void obj_print(FILE *, struct obj *, bool);
+
+#endif // SOMESCHEME_PRINT_H
@@ -0,0 +1,102 @@
+#include <stdbool.h>
+
+#include "base.h"
+#include "builtin.h"
+#include "exception.h"
+#include "gc.h"
+
+// The green threads of spawn and yield. A thread is only ever a continuation,
+// so switching threads is calling another one. The threads waiting to run are
+// kept in a queue of (resume . state) entries, resume being the closure that
+// carries on with the thread when given null, and state the handlers and wind
+// list it had. The queue is a list on the heap whose first and last cells are
+// gc roots, threads join the end and the one at the front runs next.
+static struct obj *ready = NULL;
+static struct obj *ready_last = NULL;
+
+static bool roots_registered = false;
+
+static void register_roots(void) {
+  if (roots_registered)
+    return;
+
+  gc_register_root(&ready);
+  gc_register_root(&ready_last);
+  roots_registered = true;
+}
+
+static struct obj *heap_cons(struct obj *car, struct obj *cdr) {
+  struct cons_obj *c = gc_malloc(sizeof(struct cons_obj));
+  *c = object_cons_obj_new(car, cdr);
+  c->base.on_stack = false;
+
+  return (struct obj *)c;
+}
+
+static void enqueue(struct obj *resume, struct obj *state) {
+  register_roots();
+
+  struct obj *cell = heap_cons(heap_cons(resume, state), NULL);
+
+  // both cells are on the heap, which needs no write barrier
+  if (ready_last) {
+    ((struct cons_obj *)ready_last)->cdr = cell;
+  } else {
+    ready = cell;
+  }
+
+  ready_last = cell;
+}
+
+// Run the thread at the front of the queue, returning only when there is none
+void thread_run_next(void) {
+  if (!ready)
+    return;
+
+  struct cons_obj *entry =
+      (struct cons_obj *)((struct cons_obj *)ready)->car;
+  ready = ((struct cons_obj *)ready)->cdr;
+
+  if (!ready)
+    ready_last = NULL;
+
+  exception_restore(entry->cdr);
+  call_closure_one(entry->car, NULL);
+
+  __builtin_unreachable();
+}
+
+// A spawned thread calls its thunk with halt as the continuation, so that
+// when it finishes the next thread runs as when the program's toplevel does
+static void thread_start(struct obj *unused, struct env_obj *env) {
+  (void)unused;
+  OBJECT_CLOSURE_ONE_NEW(done, halt_k, NULL);
+
+  call_closure_two(env->env[0], NULL, done);
+
+  __builtin_unreachable();
+}
+
+// The new thread waits for the current one to yield or finish
+void spawn_k(struct obj *thunk, struct obj *k, struct env_obj *env) {
+  if (!thunk || obj_tag(thunk) != OBJ_CLOSURE)
+    RUNTIME_ERROR("Argument to spawn was not a procedure");
+
+  OBJECT_ENV_OBJ_NEW(start_env, struct unary_env);
+  start_env->env[0] = thunk;
+  OBJECT_CLOSURE_ONE_NEW(start, thread_start, start_env);
+
+  // a new thread starts outside of any handler or dynamic-wind
+  enqueue(start, NULL);
+
+  call_closure_one(k, NULL);
+
+  __builtin_unreachable();
+}
+
+void yield_k(struct obj *v, struct obj *k, struct env_obj *env) {
+  enqueue(k, exception_state());
+  thread_run_next();
+
+  __builtin_unreachable();
+}
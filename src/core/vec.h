@@ -78,7 +78,8 @@
     if (DEBUG_ONLY(idx < 0 || idx >= vec->length)) {                           \
       RUNTIME_ERROR("Indexing vector out of bounds");                          \
     }                                                                          \
-    memmove(&vec->data[idx], &vec->data[idx + 1], vec->length - idx - 1);      \
+    memmove(&vec->data[idx], &vec->data[idx + 1],                              \
+            (vec->length - idx - 1) * sizeof(TYPE));                           \
     vec->length--;                                                             \
   }                                                                            \
   size_t vector_##TNAME##_indexof(struct vector_##TNAME *vec, TYPE val) {      \
@@ -0,0 +1,26 @@
+// Runs a program compiled with `--target wasm32`: node run.js program.wasm
+//
+// The module imports `env.write`, which writes bytes of its memory to stdout,
+// and `env.fail`, which reports a runtime error and exits.
+
+const fs = require("fs");
+
+const bytes = fs.readFileSync(process.argv[2]);
+let memory;
+
+const slice = (ptr, len) => Buffer.from(memory.buffer, ptr, len);
+
+const imports = {
+  env: {
+    write: (ptr, len) => fs.writeSync(1, slice(ptr, len)),
+    fail: (ptr, len) => {
+      fs.writeSync(2, `Runtime Error: ${slice(ptr, len).toString()}\n`);
+      process.exit(1);
+    },
+  },
+};
+
+WebAssembly.instantiate(bytes, imports).then(({ instance }) => {
+  memory = instance.exports.memory;
+  instance.exports._start();
+});
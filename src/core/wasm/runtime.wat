@@ -0,0 +1,607 @@
+;; The runtime of the wasm32 target, the module fields here are joined with
+;; those of the program by `src/wasm.rs`.
+;;
+;; Values are i32s: fixnums are `(n << 1) | 1`, void and the empty list are 0,
+;; and anything else is the address of an object. Integers are 64 bits like in
+;; the C runtime, those that don't fit in a fixnum are boxed. Every object
+;; starts with a header of `(size << 8) | tag`, its size in bytes including the
+;; header.
+;;
+;; Lambdas never call each other directly, they store the call in the `$next_*`
+;; globals and return to the loop in `$run`, which makes the call. Between two
+;; calls the only live objects are those reachable from the pending call, so
+;; that is when the heap is collected.
+
+(import "env" "write" (func $write (param i32 i32)))
+(import "env" "fail" (func $host_fail (param i32 i32)))
+
+(type $lambda (func (param i32 i32 i32)))
+
+;; object tags, a forwarded object has the tag 0
+(global $tag_cons i32 (i32.const 1))
+(global $tag_closure i32 (i32.const 2))
+(global $tag_string i32 (i32.const 3))
+(global $tag_symbol i32 (i32.const 4))
+(global $tag_bool i32 (i32.const 5))
+(global $tag_env i32 (i32.const 6))
+(global $tag_cell i32 (i32.const 7))
+(global $tag_int i32 (i32.const 8))
+(global $tag_port i32 (i32.const 9))
+
+;; static objects below the data of the program, which starts at 1024
+(global $false i32 (i32.const 16))
+(global $true i32 (i32.const 24))
+;; the standard output, the only port
+(global $stdout_port i32 (i32.const 32))
+;; digits are formatted backwards from here
+(global $scratch_end i32 (i32.const 64))
+(global $str_nil i32 (i32.const 128))
+(global $str_open i32 (i32.const 192))
+(global $str_close i32 (i32.const 208))
+(global $str_space i32 (i32.const 224))
+(global $str_dot i32 (i32.const 240))
+(global $str_procedure i32 (i32.const 256))
+(global $str_true i32 (i32.const 320))
+(global $str_false i32 (i32.const 384))
+(global $str_newline i32 (i32.const 448))
+(global $err_not_procedure i32 (i32.const 512))
+(global $err_not_integer i32 (i32.const 576))
+(global $err_overflow i32 (i32.const 640))
+(global $err_divide_by_zero i32 (i32.const 704))
+(global $err_not_pair i32 (i32.const 768))
+(global $err_not_string i32 (i32.const 832))
+(global $err_out_of_memory i32 (i32.const 896))
+(global $str_port i32 (i32.const 960))
+(global $err_not_port i32 (i32.const 976))
+
+(data (i32.const 16) "\05\08\00\00\00\00\00\00" "\05\08\00\00\01\00\00\00")
+(data (i32.const 32) "\09\08\00\00\00\00\00\00")
+(data (i32.const 128) "\03\00\00\00\02\00\00\00()")
+(data (i32.const 192) "\03\00\00\00\01\00\00\00(")
+(data (i32.const 208) "\03\00\00\00\01\00\00\00)")
+(data (i32.const 224) "\03\00\00\00\01\00\00\00 ")
+(data (i32.const 240) "\03\00\00\00\03\00\00\00 . ")
+(data (i32.const 256) "\03\00\00\00\0c\00\00\00#<procedure>")
+(data (i32.const 320) "\03\00\00\00\02\00\00\00#t")
+(data (i32.const 384) "\03\00\00\00\02\00\00\00#f")
+(data (i32.const 448) "\03\00\00\00\01\00\00\00\n")
+(data (i32.const 512) "\03\00\00\00\2e\00\00\00Tried to call something that isn't a procedure")
+(data (i32.const 576) "\03\00\00\00\35\00\00\00Operand to an arithmetic operation was not an integer")
+(data (i32.const 640) "\03\00\00\00\10\00\00\00Integer overflow")
+(data (i32.const 704) "\03\00\00\00\0e\00\00\00Divide by zero")
+(data (i32.const 768) "\03\00\00\00\17\00\00\00Argument was not a pair")
+(data (i32.const 832) "\03\00\00\00\19\00\00\00Argument was not a string")
+(data (i32.const 896) "\03\00\00\00\0d\00\00\00Out of memory")
+(data (i32.const 960) "\03\00\00\00\04\00\00\00port")
+(data (i32.const 976) "\03\00\00\00\17\00\00\00Argument was not a port")
+
+;; the pending call
+(global $next_fn (mut i32) (i32.const 0))
+(global $next_a (mut i32) (i32.const 0))
+(global $next_b (mut i32) (i32.const 0))
+(global $next_env (mut i32) (i32.const 0))
+(global $running (mut i32) (i32.const 0))
+
+;; the heap is the objects from $from to $hp, it's collected once $hp passes
+;; $limit
+(global $from (mut i32) (i32.const 0))
+(global $hp (mut i32) (i32.const 0))
+(global $limit (mut i32) (i32.const 0))
+(global $min_heap i32 (i32.const 1048576))
+
+;; where the collector is copying to, and the heap it's copying from
+(global $free (mut i32) (i32.const 0))
+(global $old_from (mut i32) (i32.const 0))
+(global $old_hp (mut i32) (i32.const 0))
+
+(func $fail (param $msg i32)
+  (call $host_fail
+    (i32.add (local.get $msg) (i32.const 8))
+    (i32.load offset=4 (local.get $msg)))
+  unreachable)
+
+;; Run the program from the lambda at `entry` in the table until it exits.
+(func $run (param $entry i32)
+  (global.set $from (global.get $heap_start))
+  (global.set $hp (global.get $heap_start))
+  (global.set $limit (i32.add (global.get $heap_start) (global.get $min_heap)))
+  (global.set $next_fn (local.get $entry))
+  (global.set $running (i32.const 1))
+
+  (loop $next
+    (if (global.get $running)
+      (then
+        (if (i32.gt_u (global.get $hp) (global.get $limit))
+          (then (call $collect)))
+        (call_indirect (type $lambda)
+          (global.get $next_a)
+          (global.get $next_b)
+          (global.get $next_env)
+          (global.get $next_fn))
+        (br $next)))))
+
+(func $call_one (param $f i32) (param $v i32)
+  (call $call_two (local.get $f) (local.get $v) (i32.const 0)))
+
+(func $call_two (param $f i32) (param $v i32) (param $k i32)
+  (if (i32.ne (call $tag (local.get $f)) (global.get $tag_closure))
+    (then (call $fail (global.get $err_not_procedure))))
+  (global.set $next_fn (i32.load offset=4 (local.get $f)))
+  (global.set $next_env (i32.load offset=8 (local.get $f)))
+  (global.set $next_a (local.get $v))
+  (global.set $next_b (local.get $k)))
+
+(func $halt
+  (global.set $running (i32.const 0)))
+
+;; The tag of an object, or -1 for fixnums and void.
+(func $tag (param $v i32) (result i32)
+  (if (result i32)
+    (i32.or (i32.eqz (local.get $v)) (i32.and (local.get $v) (i32.const 1)))
+    (then (i32.const -1))
+    (else (i32.and (i32.load (local.get $v)) (i32.const 255)))))
+
+(func $is_tagged (param $v i32) (param $tag i32) (result i32)
+  (i32.eq (call $tag (local.get $v)) (local.get $tag)))
+
+(func $bool (param $b i32) (result i32)
+  (if (result i32) (local.get $b)
+    (then (global.get $true))
+    (else (global.get $false))))
+
+(func $is_truthy (param $v i32) (result i32)
+  (if (i32.eqz (local.get $v))
+    (then (return (i32.const 0))))
+  (if (i32.and (local.get $v) (i32.const 1))
+    (then (return (i32.ne (local.get $v) (i32.const 1)))))
+  (if (call $is_tagged (local.get $v) (global.get $tag_bool))
+    (then (return (i32.load offset=4 (local.get $v)))))
+  (if (call $is_tagged (local.get $v) (global.get $tag_string))
+    (then (return (i32.ne (i32.load offset=4 (local.get $v)) (i32.const 0)))))
+  (i32.const 1))
+
+;; Make sure the memory reaches `end`, growing it if not.
+(func $reserve (param $end i32)
+  (local $pages i32)
+  (local.set $pages
+    (i32.sub
+      (i32.shr_u (i32.add (local.get $end) (i32.const 65535)) (i32.const 16))
+      (memory.size)))
+  (if (i32.gt_s (local.get $pages) (i32.const 0))
+    (then
+      (if (i32.eq (memory.grow (local.get $pages)) (i32.const -1))
+        (then (call $fail (global.get $err_out_of_memory)))))))
+
+;; Sizes are rounded up to a multiple of 4, so that addresses stay even. The
+;; fields start out as void, as the slots of an environment are only filled in
+;; once it's called and the memory may hold objects from an earlier heap.
+(func $alloc (param $size i32) (param $tag i32) (result i32)
+  (local $obj i32)
+  (local.set $size (i32.and (i32.add (local.get $size) (i32.const 3)) (i32.const -4)))
+  (local.set $obj (global.get $hp))
+  (global.set $hp (i32.add (local.get $obj) (local.get $size)))
+  (call $reserve (global.get $hp))
+  (memory.fill (local.get $obj) (i32.const 0) (local.get $size))
+  (i32.store (local.get $obj)
+    (i32.or (i32.shl (local.get $size) (i32.const 8)) (local.get $tag)))
+  (local.get $obj))
+
+(func $closure_new (param $fn i32) (param $env i32) (result i32)
+  (local $obj i32)
+  (local.set $obj (call $alloc (i32.const 12) (global.get $tag_closure)))
+  (i32.store offset=4 (local.get $obj) (local.get $fn))
+  (i32.store offset=8 (local.get $obj) (local.get $env))
+  (local.get $obj))
+
+;; Environments without any slots aren't allocated.
+(func $env_new (param $slots i32) (result i32)
+  (if (i32.eqz (local.get $slots))
+    (then (return (i32.const 0))))
+  (call $alloc
+    (i32.add (i32.const 4) (i32.shl (local.get $slots) (i32.const 2)))
+    (global.get $tag_env)))
+
+(func $cell_new (param $v i32) (result i32)
+  (local $obj i32)
+  (local.set $obj (call $alloc (i32.const 8) (global.get $tag_cell)))
+  (i32.store offset=4 (local.get $obj) (local.get $v))
+  (local.get $obj))
+
+(func $string_new (param $len i32) (result i32)
+  (local $obj i32)
+  (local.set $obj
+    (call $alloc (i32.add (i32.const 8) (local.get $len)) (global.get $tag_string)))
+  (i32.store offset=4 (local.get $obj) (local.get $len))
+  (local.get $obj))
+
+;; Copy everything reachable from the pending call to a new heap. The new heap
+;; goes below the old one when there's room for all of it there, and above it
+;; otherwise, so the memory used stays within about twice the live objects.
+(func $collect
+  (local $scan i32)
+  (local $header i32)
+  (local $tag i32)
+  (local $field i32)
+  (local $end i32)
+  (global.set $old_from (global.get $from))
+  (global.set $old_hp (global.get $hp))
+
+  (if (i32.ge_u
+        (i32.sub (global.get $from) (global.get $heap_start))
+        (i32.sub (global.get $hp) (global.get $from)))
+    (then (global.set $free (global.get $heap_start)))
+    (else (global.set $free (global.get $hp))))
+
+  (call $reserve
+    (i32.add (global.get $free) (i32.sub (global.get $hp) (global.get $from))))
+  (global.set $from (global.get $free))
+
+  (global.set $next_a (call $forward (global.get $next_a)))
+  (global.set $next_b (call $forward (global.get $next_b)))
+  (global.set $next_env (call $forward (global.get $next_env)))
+
+  (local.set $scan (global.get $from))
+  (block $done
+    (loop $objects
+      (br_if $done (i32.ge_u (local.get $scan) (global.get $free)))
+      (local.set $header (i32.load (local.get $scan)))
+      (local.set $end
+        (i32.add (local.get $scan) (i32.shr_u (local.get $header) (i32.const 8))))
+
+      ;; the fields of strings and integers aren't values, and the first field
+      ;; of a closure is the index of its function
+      (local.set $tag (i32.and (local.get $header) (i32.const 255)))
+      (local.set $field (i32.add (local.get $scan) (i32.const 4)))
+      (if (i32.eq (local.get $tag) (global.get $tag_closure))
+        (then (local.set $field (i32.add (local.get $scan) (i32.const 8)))))
+      (if (i32.or
+            (i32.eq (local.get $tag) (global.get $tag_string))
+            (i32.eq (local.get $tag) (global.get $tag_int)))
+        (then (local.set $field (local.get $end))))
+
+      (block $fields_done
+        (loop $fields
+          (br_if $fields_done (i32.ge_u (local.get $field) (local.get $end)))
+          (i32.store (local.get $field) (call $forward (i32.load (local.get $field))))
+          (local.set $field (i32.add (local.get $field) (i32.const 4)))
+          (br $fields)))
+
+      (local.set $scan (local.get $end))
+      (br $objects)))
+
+  (global.set $hp (global.get $free))
+  (global.set $limit
+    (i32.add
+      (global.get $hp)
+      (i32.add
+        (global.get $min_heap)
+        (i32.shl (i32.sub (global.get $hp) (global.get $from)) (i32.const 1))))))
+
+;; The copy of the object `v`, copying it if this is the first time it has been
+;; reached. Fixnums, void and static objects are left alone.
+(func $forward (param $v i32) (result i32)
+  (local $header i32)
+  (local $size i32)
+  (local $copy i32)
+  (if (i32.or
+        (i32.and (local.get $v) (i32.const 1))
+        (i32.or
+          (i32.lt_u (local.get $v) (global.get $old_from))
+          (i32.ge_u (local.get $v) (global.get $old_hp))))
+    (then (return (local.get $v))))
+
+  (local.set $header (i32.load (local.get $v)))
+  (if (i32.eqz (i32.and (local.get $header) (i32.const 255)))
+    (then (return (i32.load offset=4 (local.get $v)))))
+
+  (local.set $size (i32.shr_u (local.get $header) (i32.const 8)))
+  (local.set $copy (global.get $free))
+  (memory.copy (local.get $copy) (local.get $v) (local.get $size))
+  (global.set $free (i32.add (local.get $copy) (local.get $size)))
+
+  (i32.store (local.get $v) (i32.const 0))
+  (i32.store offset=4 (local.get $v) (local.get $copy))
+  (local.get $copy))
+
+;; integers
+
+(func $is_integer (param $v i32) (result i32)
+  (i32.or
+    (i32.and (local.get $v) (i32.const 1))
+    (call $is_tagged (local.get $v) (global.get $tag_int))))
+
+(func $int_val (param $v i32) (result i64)
+  (if (i32.and (local.get $v) (i32.const 1))
+    (then (return (i64.extend_i32_s (i32.shr_s (local.get $v) (i32.const 1))))))
+  (if (i32.eqz (call $is_tagged (local.get $v) (global.get $tag_int)))
+    (then (call $fail (global.get $err_not_integer))))
+  (i64.load offset=8 (local.get $v)))
+
+;; A fixnum when `n` fits in 31 bits, and a boxed integer otherwise.
+(func $int (param $n i64) (result i32)
+  (local $obj i32)
+  (if (i64.lt_u
+        (i64.add (local.get $n) (i64.const 0x40000000))
+        (i64.const 0x80000000))
+    (then
+      (return (i32.or
+        (i32.shl (i32.wrap_i64 (local.get $n)) (i32.const 1))
+        (i32.const 1)))))
+  (local.set $obj (call $alloc (i32.const 16) (global.get $tag_int)))
+  (i64.store offset=8 (local.get $obj) (local.get $n))
+  (local.get $obj))
+
+(func $add (param $a i32) (param $b i32) (result i32)
+  (local $x i64)
+  (local $y i64)
+  (local $r i64)
+  (local.set $x (call $int_val (local.get $a)))
+  (local.set $y (call $int_val (local.get $b)))
+  (local.set $r (i64.add (local.get $x) (local.get $y)))
+  ;; the sign of the result differs from the sign of both operands
+  (if (i64.lt_s
+        (i64.and
+          (i64.xor (local.get $x) (local.get $r))
+          (i64.xor (local.get $y) (local.get $r)))
+        (i64.const 0))
+    (then (call $fail (global.get $err_overflow))))
+  (call $int (local.get $r)))
+
+(func $sub (param $a i32) (param $b i32) (result i32)
+  (local $x i64)
+  (local $y i64)
+  (local $r i64)
+  (local.set $x (call $int_val (local.get $a)))
+  (local.set $y (call $int_val (local.get $b)))
+  (local.set $r (i64.sub (local.get $x) (local.get $y)))
+  (if (i64.lt_s
+        (i64.and
+          (i64.xor (local.get $x) (local.get $y))
+          (i64.xor (local.get $x) (local.get $r)))
+        (i64.const 0))
+    (then (call $fail (global.get $err_overflow))))
+  (call $int (local.get $r)))
+
+(func $mul (param $a i32) (param $b i32) (result i32)
+  (local $x i64)
+  (local $y i64)
+  (local $r i64)
+  (local.set $x (call $int_val (local.get $a)))
+  (local.set $y (call $int_val (local.get $b)))
+  (local.set $r (i64.mul (local.get $x) (local.get $y)))
+  (if (i32.eqz (i64.eqz (local.get $x)))
+    (then
+      ;; dividing back only fails to give `y` on overflow, except for the
+      ;; one division that traps
+      (if (i32.and
+            (i64.eq (local.get $x) (i64.const -1))
+            (i64.eq (local.get $y) (i64.const 0x8000000000000000)))
+        (then (call $fail (global.get $err_overflow))))
+      (if (i64.ne (i64.div_s (local.get $r) (local.get $x)) (local.get $y))
+        (then (call $fail (global.get $err_overflow))))))
+  (call $int (local.get $r)))
+
+(func $rem (param $a i32) (param $b i32) (result i32)
+  (local $divisor i64)
+  (local.set $divisor (call $int_val (local.get $b)))
+  (if (i64.eqz (local.get $divisor))
+    (then (call $fail (global.get $err_divide_by_zero))))
+  (call $int (i64.rem_s (call $int_val (local.get $a)) (local.get $divisor))))
+
+(func $xor (param $a i32) (param $b i32) (result i32)
+  (call $int (i64.xor (call $int_val (local.get $a)) (call $int_val (local.get $b)))))
+
+(func $lt (param $a i32) (param $b i32) (result i32)
+  (call $bool (i64.lt_s (call $int_val (local.get $a)) (call $int_val (local.get $b)))))
+
+(func $le (param $a i32) (param $b i32) (result i32)
+  (call $bool (i64.le_s (call $int_val (local.get $a)) (call $int_val (local.get $b)))))
+
+(func $gt (param $a i32) (param $b i32) (result i32)
+  (call $bool (i64.gt_s (call $int_val (local.get $a)) (call $int_val (local.get $b)))))
+
+(func $ge (param $a i32) (param $b i32) (result i32)
+  (call $bool (i64.ge_s (call $int_val (local.get $a)) (call $int_val (local.get $b)))))
+
+(func $num_eq (param $a i32) (param $b i32) (result i32)
+  (call $bool (i64.eq (call $int_val (local.get $a)) (call $int_val (local.get $b)))))
+
+(func $is_zero (param $v i32) (result i32)
+  (call $bool (i64.eqz (call $int_val (local.get $v)))))
+
+(func $is_number (param $v i32) (result i32)
+  (call $bool (call $is_integer (local.get $v))))
+
+;; pairs and predicates
+
+(func $cons (param $car i32) (param $cdr i32) (result i32)
+  (local $obj i32)
+  (local.set $obj (call $alloc (i32.const 12) (global.get $tag_cons)))
+  (i32.store offset=4 (local.get $obj) (local.get $car))
+  (i32.store offset=8 (local.get $obj) (local.get $cdr))
+  (local.get $obj))
+
+(func $check_pair (param $v i32)
+  (if (i32.eqz (call $is_tagged (local.get $v) (global.get $tag_cons)))
+    (then (call $fail (global.get $err_not_pair)))))
+
+(func $car (param $v i32) (result i32)
+  (call $check_pair (local.get $v))
+  (i32.load offset=4 (local.get $v)))
+
+(func $cdr (param $v i32) (result i32)
+  (call $check_pair (local.get $v))
+  (i32.load offset=8 (local.get $v)))
+
+(func $is_cons (param $v i32) (result i32)
+  (call $bool (call $is_tagged (local.get $v) (global.get $tag_cons))))
+
+(func $is_null (param $v i32) (result i32)
+  (call $bool (i32.eqz (local.get $v))))
+
+(func $is_procedure (param $v i32) (result i32)
+  (call $bool (call $is_tagged (local.get $v) (global.get $tag_closure))))
+
+(func $is_string (param $v i32) (result i32)
+  (call $bool (call $is_tagged (local.get $v) (global.get $tag_string))))
+
+(func $is_boolean (param $v i32) (result i32)
+  (call $bool (call $is_tagged (local.get $v) (global.get $tag_bool))))
+
+(func $is_symbol (param $v i32) (result i32)
+  (call $bool (call $is_tagged (local.get $v) (global.get $tag_symbol))))
+
+;; Integers are compared by value, everything else by address.
+(func $eq (param $a i32) (param $b i32) (result i32)
+  (if (i32.and (call $is_integer (local.get $a)) (call $is_integer (local.get $b)))
+    (then (return (call $num_eq (local.get $a) (local.get $b)))))
+  (call $bool (i32.eq (local.get $a) (local.get $b))))
+
+;; strings
+
+(func $check_string (param $v i32)
+  (if (i32.eqz (call $is_tagged (local.get $v) (global.get $tag_string)))
+    (then (call $fail (global.get $err_not_string)))))
+
+(func $string_length (param $v i32) (result i32)
+  (call $check_string (local.get $v))
+  (call $int (i64.extend_i32_u (i32.load offset=4 (local.get $v)))))
+
+(func $string_append (param $a i32) (param $b i32) (result i32)
+  (call $check_string (local.get $a))
+  (call $check_string (local.get $b))
+  (call $string_concat (local.get $a) (local.get $b)))
+
+;; Like string-append, but fixnums are taken as characters.
+(func $string_concat (param $a i32) (param $b i32) (result i32)
+  (local $obj i32)
+  (local $a_len i32)
+  (local.set $a (call $as_string (local.get $a)))
+  (local.set $b (call $as_string (local.get $b)))
+  (local.set $a_len (i32.load offset=4 (local.get $a)))
+  (local.set $obj
+    (call $string_new (i32.add (local.get $a_len) (i32.load offset=4 (local.get $b)))))
+  (memory.copy
+    (i32.add (local.get $obj) (i32.const 8))
+    (i32.add (local.get $a) (i32.const 8))
+    (local.get $a_len))
+  (memory.copy
+    (i32.add (i32.add (local.get $obj) (i32.const 8)) (local.get $a_len))
+    (i32.add (local.get $b) (i32.const 8))
+    (i32.load offset=4 (local.get $b)))
+  (local.get $obj))
+
+(func $as_string (param $v i32) (result i32)
+  (local $obj i32)
+  (if (i32.and (local.get $v) (i32.const 1))
+    (then
+      (local.set $obj (call $string_new (i32.const 1)))
+      (i32.store8 offset=8 (local.get $obj) (i32.wrap_i64 (call $int_val (local.get $v))))
+      (return (local.get $obj))))
+  (call $check_string (local.get $v))
+  (local.get $v))
+
+(func $string_eq (param $a i32) (param $b i32) (result i32)
+  (local $i i32)
+  (call $check_string (local.get $a))
+  (call $check_string (local.get $b))
+  (if (i32.ne (i32.load offset=4 (local.get $a)) (i32.load offset=4 (local.get $b)))
+    (then (return (global.get $false))))
+  (block $differ
+    (loop $chars
+      (if (i32.ge_u (local.get $i) (i32.load offset=4 (local.get $a)))
+        (then (return (global.get $true))))
+      (br_if $differ
+        (i32.ne
+          (i32.load8_u offset=8 (i32.add (local.get $a) (local.get $i)))
+          (i32.load8_u offset=8 (i32.add (local.get $b) (local.get $i)))))
+      (local.set $i (i32.add (local.get $i) (i32.const 1)))
+      (br $chars)))
+  (global.get $false))
+
+;; output
+
+(func $write_string (param $s i32)
+  (call $write
+    (i32.add (local.get $s) (i32.const 8))
+    (i32.load offset=4 (local.get $s))))
+
+(func $write_int (param $n i64)
+  (local $p i32)
+  (local $negative i32)
+  (local.set $p (global.get $scratch_end))
+  (local.set $negative (i64.lt_s (local.get $n) (i64.const 0)))
+  ;; the digits of the smallest integer are still right taken as unsigned
+  (if (local.get $negative)
+    (then (local.set $n (i64.sub (i64.const 0) (local.get $n)))))
+  (loop $digits
+    (local.set $p (i32.sub (local.get $p) (i32.const 1)))
+    (i32.store8 (local.get $p)
+      (i32.add (i32.const 48) (i32.wrap_i64 (i64.rem_u (local.get $n) (i64.const 10)))))
+    (local.set $n (i64.div_u (local.get $n) (i64.const 10)))
+    (br_if $digits (i32.eqz (i64.eqz (local.get $n)))))
+  (if (local.get $negative)
+    (then
+      (local.set $p (i32.sub (local.get $p) (i32.const 1)))
+      (i32.store8 (local.get $p) (i32.const 45))))
+  (call $write (local.get $p) (i32.sub (global.get $scratch_end) (local.get $p))))
+
+;; Write `v` the way the C runtime's `display` does.
+(func $write_value (param $v i32)
+  (local $tag i32)
+  (if (i32.eqz (local.get $v))
+    (then (call $write_string (global.get $str_nil)) (return)))
+  (if (call $is_integer (local.get $v))
+    (then (call $write_int (call $int_val (local.get $v))) (return)))
+
+  (local.set $tag (call $tag (local.get $v)))
+  ;; pairs can't be changed here, so a list can't contain itself
+  (if (i32.eq (local.get $tag) (global.get $tag_cons))
+    (then
+      (call $write_string (global.get $str_open))
+      (call $write_value (i32.load offset=4 (local.get $v)))
+      (local.set $v (i32.load offset=8 (local.get $v)))
+      (block $end
+        (loop $elems
+          (br_if $end (i32.eqz (call $is_tagged (local.get $v) (global.get $tag_cons))))
+          (call $write_string (global.get $str_space))
+          (call $write_value (i32.load offset=4 (local.get $v)))
+          (local.set $v (i32.load offset=8 (local.get $v)))
+          (br $elems)))
+      (if (local.get $v)
+        (then
+          (call $write_string (global.get $str_dot))
+          (call $write_value (local.get $v))))
+      (call $write_string (global.get $str_close))
+      (return)))
+  (if (i32.eq (local.get $tag) (global.get $tag_closure))
+    (then (call $write_string (global.get $str_procedure)) (return)))
+  (if (i32.eq (local.get $tag) (global.get $tag_bool))
+    (then
+      (if (i32.load offset=4 (local.get $v))
+        (then (call $write_string (global.get $str_true)))
+        (else (call $write_string (global.get $str_false))))
+      (return)))
+  (if (i32.eq (local.get $tag) (global.get $tag_cell))
+    (then (call $write_value (i32.load offset=4 (local.get $v))) (return)))
+  (if (i32.eq (local.get $tag) (global.get $tag_port))
+    (then (call $write_string (global.get $str_port)) (return)))
+  ;; strings and symbols
+  (call $write_string (local.get $v)))
+
+(func $current_output_port (param $v i32) (result i32)
+  (global.get $stdout_port))
+
+(func $output_port_arg (param $p i32)
+  (if (i32.ne (local.get $p) (global.get $stdout_port))
+    (then (call $fail (global.get $err_not_port)))))
+
+(func $display (param $v i32) (param $p i32) (result i32)
+  (call $output_port_arg (local.get $p))
+  (call $write_value (local.get $v))
+  (i32.const 0))
+
+(func $newline (param $p i32) (result i32)
+  (call $output_port_arg (local.get $p))
+  (call $write_string (global.get $str_newline))
+  (i32.const 0))
@@ -0,0 +1,136 @@
+use moniker::{FreeVar, Ignore};
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::lifted_expr::{walk, EnvRepr, LExpr, Program, Visitor};
+
+/// What an edge of the graph means.
+enum Edge {
+    /// the lambda's body creates a closure of the target
+    Creates,
+    /// the lambda's body calls the target directly
+    Calls,
+    /// the lambda captures a variable bound by the target
+    Captures(String),
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn var_name(v: &FreeVar<String>) -> String {
+    format!("{}", v)
+}
+
+fn node_name(id: Option<usize>) -> String {
+    match id {
+        Some(id) => format!("lambda_{}", id),
+        None => "main".to_owned(),
+    }
+}
+
+impl LExpr {
+    /// The lambdas created and called directly by this expression, a call of
+    /// a lambda literal is only recorded as a call.
+    fn dot_edges(&self, out: &mut Vec<(usize, Edge)>) {
+        struct Edges<'a>(&'a mut Vec<(usize, Edge)>);
+
+        impl Visitor for Edges<'_> {
+            fn visit(&mut self, e: &LExpr) {
+                match e {
+                    LExpr::Lifted(Ignore(id)) => self.0.push((*id, Edge::Creates)),
+                    LExpr::CallOne(f, _) | LExpr::CallTwo(f, _, _) => match **f {
+                        LExpr::Lifted(Ignore(id)) => {
+                            self.0.push((id, Edge::Calls));
+
+                            for arg in &e.children()[1..] {
+                                self.visit(arg);
+                            }
+                        }
+                        _ => walk(self, e),
+                    },
+                    e => walk(self, e),
+                }
+            }
+        }
+
+        Edges(out).visit(self);
+    }
+}
+
+/// Render the lifted lambdas as a graphviz graph, the entry of the program is
+/// drawn as the node `main`.
+pub fn lambdas_to_dot(program: &Program) -> String {
+    let (root, lambdas) = (program.entry(), program.lambdas());
+    let mut ids = lambdas.keys().cloned().collect::<Vec<_>>();
+    ids.sort();
+
+    let binders = lambdas
+        .values()
+        .flat_map(|l| l.params.iter().map(move |p| (p.clone(), l.id)))
+        .collect::<HashMap<_, _>>();
+
+    let mut out = String::new();
+
+    writeln!(out, "digraph lambdas {{").unwrap();
+    writeln!(out, "  node [shape=box];").unwrap();
+    writeln!(out, "  main [shape=doublecircle];").unwrap();
+
+    for id in &ids {
+        let lambda = &lambdas[id];
+        let params = lambda.params.iter().map(var_name).collect::<Vec<_>>();
+        let style = match lambda.env_repr {
+            EnvRepr::Flat => "solid",
+            EnvRepr::Linked => "rounded",
+        };
+
+        writeln!(
+            out,
+            "  {} [label=\"{}\\n({})\", style={}];",
+            node_name(Some(*id)),
+            escape(&lambda.name),
+            escape(&params.join(" ")),
+            style
+        )
+        .unwrap();
+    }
+
+    let bodies =
+        std::iter::once((None, root)).chain(ids.iter().map(|id| (Some(*id), &*lambdas[id].body)));
+
+    for (from, body) in bodies {
+        let mut edges = Vec::new();
+        body.dot_edges(&mut edges);
+
+        if let Some(lambda) = from.map(|id| &lambdas[&id]) {
+            let mut captures = lambda
+                .captures()
+                .filter_map(|v| binders.get(v).map(|b| (*b, var_name(v))))
+                .collect::<Vec<_>>();
+            captures.sort();
+            edges.extend(captures.into_iter().map(|(b, v)| (b, Edge::Captures(v))));
+        }
+
+        for (to, edge) in edges {
+            let attrs = match edge {
+                Edge::Creates => "".to_owned(),
+                Edge::Calls => " [color=blue, style=bold]".to_owned(),
+                Edge::Captures(v) => format!(" [style=dashed, label=\"{}\"]", escape(&v)),
+            };
+
+            writeln!(
+                out,
+                "  {} -> {}{};",
+                node_name(from),
+                node_name(Some(to)),
+                attrs
+            )
+            .unwrap();
+        }
+    }
+
+    writeln!(out, "}}").unwrap();
+
+    out
+}
@@ -9,46 +9,38 @@ use std::{io::Result, rc::Rc};
 use crate::cont_expr;
 use crate::flat_expr;
 use crate::literals::Literal;
+use crate::utils::{clone_rc, open_scope, Scopes};
 
+/// Expressions that are already values, these are the only things that can be
+/// passed directly to a continuation by the CPS transform.
 #[derive(Debug, Clone, BoundTerm)]
-pub enum Expr {
+pub enum Atom {
     Var(Var<String>),
     Lit(Ignore<Literal>),
     BuiltinIdent(Ignore<String>),
+    /// the line of the source the lambda was written on, if any
+    Lam(Scope<Binder<String>, Rc<Expr>>, Ignore<Option<usize>>),
+}
+
+#[derive(Debug, Clone, BoundTerm)]
+pub enum Expr {
+    Atom(Atom),
     If(Rc<Expr>, Rc<Expr>, Rc<Expr>),
     Set(Var<String>, Rc<Expr>),
-    Lam(Scope<Binder<String>, Rc<Expr>>),
     App(Rc<Expr>, Rc<Expr>),
 }
 
-impl Expr {
+impl Atom {
     pub fn pretty<'a, D>(&self, allocator: &'a D) -> DocBuilder<'a, D, ColorSpec>
     where
         D: DocAllocator<'a, ColorSpec>,
         D::Doc: Clone,
     {
         match self {
-            Expr::Var(s) => allocator.as_string(s),
-            Expr::Lit(Ignore(l)) => l.pretty(allocator),
-            Expr::BuiltinIdent(Ignore(s)) => allocator.as_string(s),
-            Expr::Set(s, e) => {
-                let e_pret = e.pretty(allocator);
-
-                allocator
-                    .text("set!")
-                    .annotate(ColorSpec::new().set_fg(Some(Color::Magenta)).clone())
-                    .append(allocator.space())
-                    .append(
-                        allocator
-                            .as_string(s)
-                            .annotate(ColorSpec::new().set_fg(Some(Color::Green)).clone()),
-                    )
-                    .append(allocator.space())
-                    .append(e_pret)
-                    .group()
-                    .parens()
-            }
-            Expr::Lam(s) => {
+            Atom::Var(s) => allocator.as_string(s),
+            Atom::Lit(Ignore(l)) => l.pretty(allocator),
+            Atom::BuiltinIdent(Ignore(s)) => allocator.as_string(s),
+            Atom::Lam(s, _) => {
                 let Scope {
                     unsafe_pattern: pat,
                     unsafe_body: body,
@@ -71,6 +63,35 @@ impl Expr {
                     .group()
                     .parens()
             }
+        }
+    }
+}
+
+impl Expr {
+    pub fn pretty<'a, D>(&self, allocator: &'a D) -> DocBuilder<'a, D, ColorSpec>
+    where
+        D: DocAllocator<'a, ColorSpec>,
+        D::Doc: Clone,
+    {
+        match self {
+            Expr::Atom(a) => a.pretty(allocator),
+            Expr::Set(s, e) => {
+                let e_pret = e.pretty(allocator);
+
+                allocator
+                    .text("set!")
+                    .annotate(ColorSpec::new().set_fg(Some(Color::Magenta)).clone())
+                    .append(allocator.space())
+                    .append(
+                        allocator
+                            .as_string(s)
+                            .annotate(ColorSpec::new().set_fg(Some(Color::Green)).clone()),
+                    )
+                    .append(allocator.space())
+                    .append(e_pret)
+                    .group()
+                    .parens()
+            }
             Expr::If(c, ift, iff) => {
                 let c_pret = c.pretty(allocator);
                 let ift_pret = ift.pretty(allocator);
@@ -109,7 +130,71 @@ impl Expr {
         Ok(())
     }
 
+    /// The number of nodes, counting the bodies of lambdas.
+    pub fn size(&self) -> usize {
+        1 + match self {
+            Expr::Atom(Atom::Lam(s, _)) => s.unsafe_body.size(),
+            Expr::Atom(_) => 0,
+            Expr::If(c, ift, iff) => c.size() + ift.size() + iff.size(),
+            Expr::Set(_, e) => e.size(),
+            Expr::App(f, a) => f.size() + a.size(),
+        }
+    }
+
     pub fn into_fexpr(self, k: Rc<cont_expr::AExp>) -> flat_expr::FExpr {
-        cont_expr::t_c(self, k).into_fexpr()
+        cont_expr::t_c(self.open(), k).into_fexpr()
+    }
+
+    /// The expression with the scope of every lambda opened, their binders
+    /// made fresh and the variables they bind free, for a pass to read the
+    /// fields of the scopes directly. `close` binds them again.
+    pub(crate) fn open(self) -> Expr {
+        self.open_in(&mut Scopes::default())
+    }
+
+    fn open_in(self, scopes: &mut Scopes) -> Expr {
+        let open = |e: Rc<Expr>, scopes: &mut Scopes| Rc::new(clone_rc(e).open_in(scopes));
+
+        match self {
+            Expr::Atom(Atom::Lam(s, line)) => {
+                let x = scopes.push_fresh(&s.unsafe_pattern.0);
+                let body = open(s.unsafe_body, scopes);
+                scopes.pop();
+                Expr::Atom(Atom::Lam(open_scope(x, body), line))
+            }
+            Expr::Atom(Atom::Var(v)) => Expr::Atom(Atom::Var(scopes.open(v))),
+            e @ Expr::Atom(_) => e,
+            Expr::If(c, ift, iff) => {
+                Expr::If(open(c, scopes), open(ift, scopes), open(iff, scopes))
+            }
+            Expr::Set(v, e) => Expr::Set(scopes.open(v), open(e, scopes)),
+            Expr::App(f, a) => Expr::App(open(f, scopes), open(a, scopes)),
+        }
+    }
+
+    /// Bind the variables of the scopes left open by `open`, or made open.
+    pub(crate) fn close(self) -> Expr {
+        self.close_in(&mut Scopes::default())
+    }
+
+    fn close_in(self, scopes: &mut Scopes) -> Expr {
+        let close = |e: Rc<Expr>, scopes: &mut Scopes| Rc::new(clone_rc(e).close_in(scopes));
+
+        match self {
+            Expr::Atom(Atom::Lam(s, line)) => {
+                let Binder(x) = s.unsafe_pattern;
+                scopes.push(x.clone());
+                let body = close(s.unsafe_body, scopes);
+                scopes.pop();
+                Expr::Atom(Atom::Lam(open_scope(x, body), line))
+            }
+            Expr::Atom(Atom::Var(v)) => Expr::Atom(Atom::Var(scopes.close(v))),
+            e @ Expr::Atom(_) => e,
+            Expr::If(c, ift, iff) => {
+                Expr::If(close(c, scopes), close(ift, scopes), close(iff, scopes))
+            }
+            Expr::Set(v, e) => Expr::Set(scopes.close(v), close(e, scopes)),
+            Expr::App(f, a) => Expr::App(close(f, scopes), close(a, scopes)),
+        }
     }
 }
@@ -0,0 +1,214 @@
+//! The features a program is compiled with, which `cond-expand` chooses its
+//! clause by and `(features)` lists. Every program has `r7rs`, `ratios`,
+//! `some-scheme`, the backend it is compiled for and the architecture, word
+//! size and byte order of the machine it is built for, and `--feature` adds
+//! more.
+
+use failure::Error;
+use pest::iterators::Pair;
+
+use std::path::Path;
+
+use crate::include;
+use crate::lexical;
+use crate::library::is_standard;
+use crate::parse::{parse_forms, Rule};
+use crate::target::{Endian, TargetConfig};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Features {
+    names: Vec<String>,
+}
+
+impl Features {
+    /// The features of a program compiled by `backend`, one of `c`, `wasm32`,
+    /// `llvm`, `bytecode` or `interpreter`, for the machine `target`.
+    pub fn new(backend: &str, target: &TargetConfig) -> Self {
+        let mut features = Self { names: Vec::new() };
+
+        for name in &["r7rs", "ratios", "some-scheme", backend] {
+            features.add(*name);
+        }
+
+        let triple = target.triple.clone().unwrap_or_else(|| {
+            format!(
+                "{}-unknown-{}",
+                std::env::consts::ARCH,
+                std::env::consts::OS
+            )
+        });
+        let mut parts = triple.split('-');
+
+        // R7RS spells them with dashes, as x86-64
+        if let Some(arch) = parts.next() {
+            features.add(arch.replace('_', "-"));
+        }
+
+        if let Some(os) = parts.find(|p| OPERATING_SYSTEMS.contains(p)) {
+            features.add("posix");
+            features.add(os);
+        }
+
+        features.add(if target.word_bits == 64 {
+            "lp64"
+        } else {
+            "ilp32"
+        });
+        features.add(match target.endian {
+            Endian::Little => "little-endian",
+            Endian::Big => "big-endian",
+        });
+
+        features
+    }
+
+    pub fn add(&mut self, name: impl Into<String>) {
+        let name = name.into();
+
+        if !self.has(&name) {
+            self.names.push(name);
+        }
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.names.iter().any(|n| n == name)
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+}
+
+/// The systems that have a `posix` feature along with their own.
+const OPERATING_SYSTEMS: &[&str] = &["linux", "macos", "darwin", "freebsd", "netbsd", "openbsd"];
+
+/// Expand the includes of `source`, as `include::expand_includes` does, and
+/// replace each `cond-expand` with the forms of its first clause whose
+/// requirement holds, or with nothing if none does, and each `(features)` with
+/// a quoted list of the features. The rest of a `cond-expand` is blanked out,
+/// so that what follows stays on the same line. Each can make more of the other, so this
+/// goes on until neither is left.
+///
+/// A `(library name)` requirement holds for the standard libraries and those
+/// defined before it. Source that doesn't parse is returned for the parser to
+/// report.
+pub fn expand(source: &str, path: Option<&Path>, features: &Features) -> Result<String, Error> {
+    let mut source = source.to_owned();
+
+    loop {
+        let expanded = include::expand_includes(&expand_cond_expand(&source, features), path)?;
+
+        if expanded == source {
+            return Ok(expanded);
+        }
+
+        source = expanded;
+    }
+}
+
+fn expand_cond_expand(source: &str, features: &Features) -> String {
+    let prepared = lexical::prepare(source);
+
+    let pairs = match parse_forms(&prepared) {
+        Ok(pairs) => pairs,
+        Err(_) => return source.to_owned(),
+    };
+
+    let mut libraries = Vec::new();
+    let mut expanded = String::new();
+    let mut copied = 0;
+
+    for pair in pairs.flatten() {
+        let span = pair.as_span();
+
+        // nested in one already replaced
+        if span.start() < copied {
+            continue;
+        }
+
+        let replacement = match pair.as_rule() {
+            Rule::define_library_form => {
+                libraries.push(library_name(pair.into_inner().next().unwrap()));
+                continue;
+            }
+            Rule::cond_expand_form => pair
+                .into_inner()
+                .skip(1)
+                .find_map(|clause| {
+                    let mut inner = clause.into_inner();
+                    let requirement = inner.next().unwrap();
+                    let holds = requirement.as_rule() == Rule::cond_expand_else
+                        || holds(requirement, features, &libraries);
+
+                    let forms = inner.map(|form| form.as_span()).collect::<Vec<_>>();
+                    holds.then(|| match (forms.first(), forms.last()) {
+                        (Some(first), Some(last)) => (first.start(), last.end()),
+                        _ => (span.start(), span.start()),
+                    })
+                })
+                // the prepared source keeps the offsets of the original, which
+                // is copied from as its case may have been folded
+                .map(|(start, end)| {
+                    format!(
+                        "{}{}{}",
+                        blank(&source[span.start()..start]),
+                        &source[start..end],
+                        blank(&source[end..span.end()])
+                    )
+                })
+                .unwrap_or_else(|| blank(span.as_str())),
+            Rule::features_form => {
+                let names = features
+                    .names()
+                    .iter()
+                    .map(|n| format!("'{}", n))
+                    .collect::<Vec<_>>();
+                format!("'({})", names.join(" "))
+            }
+            _ => continue,
+        };
+
+        expanded.push_str(&source[copied..span.start()]);
+        expanded.push_str(&replacement);
+        copied = span.end();
+    }
+
+    expanded.push_str(&source[copied..]);
+    expanded
+}
+
+fn blank(text: &str) -> String {
+    text.chars()
+        .map(|c| if c == '\n' { c } else { ' ' })
+        .collect()
+}
+
+fn library_name(pair: Pair<Rule>) -> Vec<String> {
+    pair.into_inner().map(|p| p.as_str().to_owned()).collect()
+}
+
+fn holds(requirement: Pair<Rule>, features: &Features, libraries: &[Vec<String>]) -> bool {
+    let requirement = match requirement.as_rule() {
+        Rule::feature_requirement => requirement.into_inner().next().unwrap(),
+        _ => requirement,
+    };
+
+    match requirement.as_rule() {
+        Rule::feature_and => requirement
+            .into_inner()
+            .all(|r| holds(r, features, libraries)),
+        Rule::feature_or => requirement
+            .into_inner()
+            .any(|r| holds(r, features, libraries)),
+        Rule::feature_not => !holds(
+            requirement.into_inner().next().unwrap(),
+            features,
+            libraries,
+        ),
+        Rule::feature_library => {
+            let name = library_name(requirement.into_inner().next().unwrap());
+            is_standard(&name) || libraries.contains(&name)
+        }
+        _ => features.has(requirement.as_str()),
+    }
+}
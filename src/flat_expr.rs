@@ -1,39 +1,51 @@
+use failure::{format_err, Error};
 use moniker::BoundTerm;
-use moniker::{Binder, Ignore, Scope, Var};
+use moniker::{Binder, FreeVar, Ignore, Scope, Var};
 
 use pretty::{BoxAllocator, DocAllocator, DocBuilder};
 use termcolor::{Color, ColorSpec, WriteColor};
 
-use std::collections::HashMap;
-use std::{io::Result, rc::Rc};
+use std::collections::{BTreeMap, HashSet};
+use std::{io::Result, rc::Rc, sync::Arc};
 
-use crate::lifted_expr::{LExpr, LiftedLambda};
+use crate::lifted_expr::{
+    assign_names, select_env_reprs, LExpr, LambdaParams, LiftedLambda, Program,
+};
 use crate::literals::Literal;
-use crate::utils::clone_rc;
+use crate::shrink::Node;
 
 #[derive(Debug, Clone, BoundTerm)]
 pub enum FExpr {
     If(Rc<FExpr>, Rc<FExpr>, Rc<FExpr>),
     LamOne(Scope<Binder<String>, Rc<FExpr>>),
-    LamTwo(Scope<Binder<String>, Scope<Binder<String>, Rc<FExpr>>>),
+    LamTwo(
+        Scope<Binder<String>, Scope<Binder<String>, Rc<FExpr>>>,
+        Ignore<Option<usize>>,
+    ),
     Var(Var<String>),
     Lit(Ignore<Literal>),
     BuiltinIdent(Ignore<String>),
     SetThen(Var<String>, Rc<FExpr>, Rc<FExpr>),
     CallOne(Rc<FExpr>, Rc<FExpr>),
     CallTwo(Rc<FExpr>, Rc<FExpr>, Rc<FExpr>),
+    /// An arithmetic builtin or comparison applied to two integers, computed
+    /// in place instead of through the curried builtin, see `unbox`
+    IntOp(Ignore<String>, Rc<FExpr>, Rc<FExpr>),
 }
 
 struct LiftingCtx {
     id_counter: usize,
-    lambdas: HashMap<usize, LiftedLambda>,
+    lambdas: BTreeMap<usize, LiftedLambda>,
+    /// the variable the lambda about to be lifted is bound to
+    hint: Option<String>,
 }
 
 impl LiftingCtx {
     fn new() -> Self {
         Self {
             id_counter: 0,
-            lambdas: HashMap::new(),
+            lambdas: BTreeMap::new(),
+            hint: None,
         }
     }
     fn get(&mut self) -> usize {
@@ -111,7 +123,7 @@ impl FExpr {
                     .group()
                     .parens()
             }
-            FExpr::LamTwo(s) => {
+            FExpr::LamTwo(s, _) => {
                 let Scope {
                     unsafe_pattern: pat,
                     unsafe_body:
@@ -172,6 +184,15 @@ impl FExpr {
                     .group()
                     .parens()
             }
+            FExpr::IntOp(Ignore(op), a, b) => allocator
+                .text(format!("int{}", op))
+                .annotate(ColorSpec::new().set_fg(Some(Color::Blue)).clone())
+                .append(allocator.space())
+                .append(a.pretty(allocator))
+                .append(allocator.space())
+                .append(b.pretty(allocator))
+                .group()
+                .parens(),
         }
     }
 
@@ -183,66 +204,176 @@ impl FExpr {
         Ok(())
     }
 
-    pub fn lift_lambdas(self) -> (LExpr, HashMap<usize, LiftedLambda>) {
-        let mut ctx = LiftingCtx::new();
-        let expr = self.lift_lambdas_internal(&mut ctx);
-        (expr, ctx.lambdas)
+    /// The number of nodes, counting the bodies of lambdas.
+    pub fn size(&self) -> usize {
+        1 + match self {
+            FExpr::LamOne(s) => s.unsafe_body.size(),
+            FExpr::LamTwo(s, _) => s.unsafe_body.unsafe_body.size(),
+            FExpr::Var(_) | FExpr::Lit(_) | FExpr::BuiltinIdent(_) => 0,
+            FExpr::SetThen(_, e, c) | FExpr::CallOne(e, c) | FExpr::IntOp(_, e, c) => {
+                e.size() + c.size()
+            }
+            FExpr::If(a, b, c) | FExpr::CallTwo(a, b, c) => a.size() + b.size() + c.size(),
+        }
+    }
+
+    /// Checks that the CPS transform left every call in tail position, calls
+    /// never return so anything that isn't would be building up stack that the
+    /// generated code can't unwind, breaking proper tail calls.
+    pub fn check_tail_calls(&self) -> std::result::Result<(), Error> {
+        self.check_tail_calls_internal(true)
     }
 
-    fn lift_lambdas_internal(self, ctx: &mut LiftingCtx) -> LExpr {
+    fn check_tail_calls_internal(&self, tail: bool) -> std::result::Result<(), Error> {
         match self {
-            FExpr::LamOne(s) => {
-                let (param, body) = s.unbind();
-                let free_vars = body.free_vars();
-                let body = clone_rc(body).lift_lambdas_internal(ctx);
-                let id = ctx.get();
-                ctx.add(LiftedLambda::new(
-                    id,
-                    vec![param.0],
-                    free_vars,
-                    Rc::new(body),
-                ));
-                LExpr::Lifted(Ignore(id))
-            }
-            FExpr::LamTwo(s) => {
-                let (param0, body) = s.unbind();
-                let (param1, body) = body.unbind();
-                let free_vars = body.free_vars();
-                let body = clone_rc(body).lift_lambdas_internal(ctx);
-                let id = ctx.get();
-                ctx.add(LiftedLambda::new(
-                    id,
-                    vec![param0.0, param1.0],
-                    free_vars,
-                    Rc::new(body),
-                ));
-                LExpr::Lifted(Ignore(id))
-            }
-            FExpr::Var(v) => LExpr::Var(v),
-            FExpr::Lit(l) => LExpr::Lit(l),
-            FExpr::BuiltinIdent(i) => LExpr::BuiltinIdent(i),
-            FExpr::SetThen(n, v, c) => {
-                let v = clone_rc(v).lift_lambdas_internal(ctx);
-                let c = clone_rc(c).lift_lambdas_internal(ctx);
-                LExpr::SetThen(n, Rc::new(v), Rc::new(c))
+            FExpr::LamOne(s) => s.unsafe_body.check_tail_calls_internal(true),
+            FExpr::LamTwo(s, _) => s.unsafe_body.unsafe_body.check_tail_calls_internal(true),
+            FExpr::Var(_) | FExpr::Lit(_) | FExpr::BuiltinIdent(_) => Ok(()),
+            FExpr::IntOp(_, a, b) => {
+                a.check_tail_calls_internal(false)?;
+                b.check_tail_calls_internal(false)
             }
-            FExpr::CallOne(f, p) => {
-                let f = clone_rc(f).lift_lambdas_internal(ctx);
-                let p = clone_rc(p).lift_lambdas_internal(ctx);
-                LExpr::CallOne(Rc::new(f), Rc::new(p))
-            }
-            FExpr::CallTwo(f, p, k) => {
-                let f = clone_rc(f).lift_lambdas_internal(ctx);
-                let p = clone_rc(p).lift_lambdas_internal(ctx);
-                let k = clone_rc(k).lift_lambdas_internal(ctx);
-                LExpr::CallTwo(Rc::new(f), Rc::new(p), Rc::new(k))
+            _ if !tail => {
+                let mut buf = Vec::new();
+                self.pretty(&BoxAllocator).1.render(70, &mut buf)?;
+
+                Err(format_err!(
+                    "Expression not in tail position after CPS conversion: {}",
+                    String::from_utf8_lossy(&buf)
+                ))
             }
             FExpr::If(c, ift, iff) => {
-                let c = clone_rc(c).lift_lambdas_internal(ctx);
-                let ift = clone_rc(ift).lift_lambdas_internal(ctx);
-                let iff = clone_rc(iff).lift_lambdas_internal(ctx);
-                LExpr::If(Rc::new(c), Rc::new(ift), Rc::new(iff))
+                c.check_tail_calls_internal(false)?;
+                ift.check_tail_calls_internal(true)?;
+                iff.check_tail_calls_internal(true)
+            }
+            FExpr::SetThen(_, v, c) => {
+                v.check_tail_calls_internal(false)?;
+                c.check_tail_calls_internal(true)
+            }
+            FExpr::CallOne(f, v) => {
+                f.check_tail_calls_internal(false)?;
+                v.check_tail_calls_internal(false)
+            }
+            FExpr::CallTwo(f, v, c) => {
+                f.check_tail_calls_internal(false)?;
+                v.check_tail_calls_internal(false)?;
+                c.check_tail_calls_internal(false)
             }
         }
     }
+
+    pub fn lift_lambdas(self) -> Program {
+        self.lift_lambdas_with_globals(&HashSet::new())
+    }
+
+    /// Lift the lambdas of an expression where `globals` live outside of
+    /// every environment, so are never captured.
+    pub fn lift_lambdas_with_globals(self, globals: &HashSet<FreeVar<String>>) -> Program {
+        let mut ctx = LiftingCtx::new();
+        let (expr, _) = lift_lambdas_internal(Node::open(self, &mut HashSet::new()), &mut ctx);
+        for lambda in ctx.lambdas.values_mut() {
+            lambda.freevars.retain(|v| !globals.contains(v));
+        }
+        select_env_reprs(&mut ctx.lambdas);
+        assign_names(&mut ctx.lambdas);
+        Program::new(expr, ctx.lambdas)
+    }
+}
+
+/// Lift the lambdas of the opened program, giving the lifted expression and
+/// its free variables. These are gathered on the way up rather than asked of
+/// the body of each lambda, which would go through the lambdas inside it
+/// again.
+fn lift_lambdas_internal(node: Node, ctx: &mut LiftingCtx) -> (LExpr, HashSet<FreeVar<String>>) {
+    let lift = |n: Box<Node>, ctx: &mut LiftingCtx, free: &mut HashSet<FreeVar<String>>| {
+        let (e, mut vars) = lift_lambdas_internal(*n, ctx);
+        if vars.len() > free.len() {
+            std::mem::swap(&mut vars, free);
+        }
+        free.extend(vars);
+        Arc::new(e)
+    };
+    let mut free = HashSet::new();
+
+    let expr = match node {
+        Node::LamOne(param, body) => {
+            let body = lift(body, ctx, &mut free);
+            let id = ctx.get();
+            ctx.add(LiftedLambda::new(
+                id,
+                LambdaParams::One(param.clone()),
+                free.clone(),
+                body,
+            ));
+            free.remove(&param);
+            LExpr::Lifted(Ignore(id))
+        }
+        Node::LamTwo(param0, param1, body, line) => {
+            let hint = ctx.hint.take();
+            let body = lift(body, ctx, &mut free);
+            let id = ctx.get();
+            let mut lambda = LiftedLambda::new(
+                id,
+                LambdaParams::Two(param0.clone(), param1.clone()),
+                free.clone(),
+                body,
+            );
+            lambda.line = line;
+            lambda.hint = hint;
+            ctx.add(lambda);
+            free.remove(&param0);
+            free.remove(&param1);
+            LExpr::Lifted(Ignore(id))
+        }
+        Node::Var(v) => {
+            if let Var::Free(x) = &v {
+                free.insert(x.clone());
+            }
+            LExpr::Var(v)
+        }
+        Node::Lit(l) => LExpr::Lit(Ignore(l)),
+        Node::BuiltinIdent(i) => LExpr::BuiltinIdent(Ignore(i)),
+        Node::SetThen(n, v, c) => {
+            if let (Node::LamTwo(..), Var::Free(name)) = (&*v, &n) {
+                ctx.hint = name.pretty_name.clone();
+            }
+            if let Var::Free(x) = &n {
+                free.insert(x.clone());
+            }
+            let v = lift(v, ctx, &mut free);
+            let c = lift(c, ctx, &mut free);
+            LExpr::SetThen(n, v, c)
+        }
+        Node::CallOne(f, p) => {
+            let f = lift(f, ctx, &mut free);
+            let p = lift(p, ctx, &mut free);
+            LExpr::CallOne(f, p)
+        }
+        Node::CallTwo(f, p, k) => {
+            // a let binding a lambda
+            let binder = match (&*f, &*p) {
+                (Node::LamTwo(x, ..), Node::LamTwo(..)) => x.pretty_name.clone(),
+                _ => None,
+            };
+            let f = lift(f, ctx, &mut free);
+            ctx.hint = binder;
+            let p = lift(p, ctx, &mut free);
+            let k = lift(k, ctx, &mut free);
+            LExpr::CallTwo(f, p, k)
+        }
+        Node::If(c, ift, iff) => {
+            let c = lift(c, ctx, &mut free);
+            let ift = lift(ift, ctx, &mut free);
+            let iff = lift(iff, ctx, &mut free);
+            LExpr::If(c, ift, iff)
+        }
+        Node::IntOp(op, a, b) => {
+            let a = lift(a, ctx, &mut free);
+            let b = lift(b, ctx, &mut free);
+            LExpr::IntOp(Ignore(op), a, b)
+        }
+    };
+
+    (expr, free)
 }
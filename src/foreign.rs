@@ -0,0 +1,143 @@
+use std::fmt;
+
+/// The C types a foreign function can take and return, along with how their
+/// values are converted to and from scheme objects.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ForeignType {
+    Int,
+    Long,
+    Double,
+    Bool,
+    String,
+    /// only a return type, the call evaluates to void
+    Void,
+}
+
+impl ForeignType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "int" => ForeignType::Int,
+            "long" => ForeignType::Long,
+            "double" => ForeignType::Double,
+            "bool" => ForeignType::Bool,
+            "string" => ForeignType::String,
+            "void" => ForeignType::Void,
+            _ => return None,
+        })
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ForeignType::Int => "int",
+            ForeignType::Long => "long",
+            ForeignType::Double => "double",
+            ForeignType::Bool => "bool",
+            ForeignType::String => "string",
+            ForeignType::Void => "void",
+        }
+    }
+
+    pub fn c_param_type(self) -> &'static str {
+        match self {
+            ForeignType::String => "const char *",
+            _ => self.c_return_type(),
+        }
+    }
+
+    /// Strings are returned as `char *` as that's how the C library declares
+    /// them, and the declaration we emit has to agree with its headers.
+    pub fn c_return_type(self) -> &'static str {
+        match self {
+            ForeignType::Int => "int",
+            ForeignType::Long => "long",
+            ForeignType::Double => "double",
+            ForeignType::Bool => "bool",
+            ForeignType::String => "char *",
+            ForeignType::Void => "void",
+        }
+    }
+
+    /// The runtime function unboxing an argument of this type.
+    pub fn unboxer(self) -> &'static str {
+        match self {
+            ForeignType::Int => "foreign_to_int",
+            ForeignType::Long => "foreign_to_long",
+            ForeignType::Double => "foreign_to_double",
+            ForeignType::Bool => "foreign_to_bool",
+            ForeignType::String => "foreign_to_string",
+            ForeignType::Void => unreachable!("void parameter"),
+        }
+    }
+
+    /// The runtime macro boxing a result of this type and passing it on.
+    pub fn returner(self) -> &'static str {
+        match self {
+            ForeignType::Int | ForeignType::Long => "FOREIGN_RETURN_INT",
+            ForeignType::Double => "FOREIGN_RETURN_DOUBLE",
+            ForeignType::Bool => "FOREIGN_RETURN_BOOL",
+            ForeignType::String => "FOREIGN_RETURN_STRING",
+            ForeignType::Void => "FOREIGN_RETURN_VOID",
+        }
+    }
+}
+
+/// A C function declared by `define-foreign`.
+///
+/// The calls go through a builtin that takes the arguments as a list, the
+/// name of the builtin carries the signature down to codegen, which emits an
+/// `extern` declaration of the function and a wrapper converting the
+/// arguments and result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Foreign {
+    pub name: String,
+    pub params: Vec<ForeignType>,
+    pub ret: ForeignType,
+}
+
+impl fmt::Display for Foreign {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let params = self.params.iter().map(|p| p.name()).collect::<Vec<_>>();
+
+        write!(
+            f,
+            "{} ({}) -> {}",
+            self.name,
+            params.join(" "),
+            self.ret.name()
+        )
+    }
+}
+
+impl Foreign {
+    pub fn builtin_name(&self) -> String {
+        format!("foreign {}", self)
+    }
+
+    pub fn from_builtin_name(name: &str) -> Option<Self> {
+        let sig = name.strip_prefix("foreign ")?;
+        let (name, sig) = sig.split_once(" (")?;
+        let (params, ret) = sig.split_once(") -> ")?;
+
+        Some(Foreign {
+            name: name.to_owned(),
+            params: params
+                .split_whitespace()
+                .map(ForeignType::from_name)
+                .collect::<Option<_>>()?,
+            ret: ForeignType::from_name(ret)?,
+        })
+    }
+
+    /// The function the closure of the builtin calls.
+    pub fn wrapper_name(&self) -> String {
+        format!("foreign_{}_k", self.name)
+    }
+}
+
+/// Can `name` be used as is in the generated C.
+pub fn is_c_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -0,0 +1,156 @@
+use failure::{format_err, Error};
+
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+use crate::lexical;
+use crate::parse::{parse_forms, ParseError, Rule};
+
+/// Where an include is in the source, whether it folds case and the files it
+/// names.
+type Include = (usize, usize, bool, Vec<String>);
+
+/// Replace every `(include "file" ...)` in `source` with the contents of the
+/// files, which are read relative to the directory of `path` (or the current
+/// directory for input without a path) and have their own includes expanded
+/// relative to themselves. `include-ci` reads them as if they started with
+/// `#!fold-case`.
+///
+/// Source that doesn't parse is returned as is for the parser to report, the
+/// errors of included files are reported here.
+pub fn expand_includes(source: &str, path: Option<&Path>) -> Result<String, Error> {
+    let mut including = Vec::new();
+
+    if let Some(path) = path {
+        including.push(path.canonicalize()?);
+    }
+
+    let dir = path
+        .and_then(Path::parent)
+        .map(Path::to_owned)
+        .unwrap_or_default();
+
+    match includes(source) {
+        Ok(spans) => expand(source, spans, &dir, &mut including),
+        Err(_) => Ok(source.to_owned()),
+    }
+}
+
+/// The files `source` includes, and those they include, for watching them.
+/// Those named by includes in the clauses of a `cond-expand` are among them,
+/// whichever clause is chosen, and those that can't be read are kept so that
+/// they are noticed once they can be.
+pub fn included_files(source: &str, path: Option<&Path>) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let dir = path
+        .and_then(Path::parent)
+        .map(Path::to_owned)
+        .unwrap_or_default();
+
+    add_included_files(source, &dir, &mut files);
+    files
+}
+
+fn add_included_files(source: &str, dir: &Path, files: &mut Vec<PathBuf>) {
+    let source = &lexical::prepare(source);
+
+    let pairs = match parse_forms(source) {
+        Ok(pairs) => pairs,
+        Err(_) => return,
+    };
+
+    for pair in pairs.flatten() {
+        let mut inner = pair.clone().into_inner();
+
+        let is_include = match pair.as_rule() {
+            Rule::include_form => true,
+            Rule::datum_list => inner
+                .next()
+                .is_some_and(|p| matches!(p.as_str(), "include" | "include-ci")),
+            _ => false,
+        };
+
+        if !is_include {
+            continue;
+        }
+
+        for name in inner.filter(|p| p.as_rule() == Rule::quoted_string) {
+            let file = dir.join(name.into_inner().next().unwrap().as_str());
+
+            if files.contains(&file) {
+                continue;
+            }
+
+            files.push(file.clone());
+
+            if let Ok(contents) = read_to_string(&file) {
+                add_included_files(&contents, file.parent().unwrap(), files);
+            }
+        }
+    }
+}
+
+fn includes(source: &str) -> Result<Vec<Include>, ParseError> {
+    let source = &lexical::prepare(source);
+
+    Ok(parse_forms(source)?
+        .flatten()
+        .filter(|pair| pair.as_rule() == Rule::include_form)
+        .map(|pair| {
+            let span = pair.as_span();
+            let mut inner = pair.into_inner().peekable();
+            let ci = inner.next_if(|p| p.as_rule() == Rule::include_ci).is_some();
+            let files = inner
+                .map(|file| file.into_inner().next().unwrap().as_str().to_owned())
+                .collect();
+
+            (span.start(), span.end(), ci, files)
+        })
+        .collect())
+}
+
+fn expand(
+    source: &str,
+    spans: Vec<Include>,
+    dir: &Path,
+    including: &mut Vec<PathBuf>,
+) -> Result<String, Error> {
+    let mut expanded = String::new();
+    let mut copied = 0;
+
+    for (start, end, ci, files) in spans {
+        expanded.push_str(&source[copied..start]);
+        copied = end;
+
+        for name in files {
+            let file = dir.join(name);
+            let couldnt = |e| format_err!("Couldn't include {}: {}", file.display(), e);
+
+            let canonical = file.canonicalize().map_err(couldnt)?;
+
+            if including.contains(&canonical) {
+                return Err(format_err!("{} includes itself", file.display()));
+            }
+
+            let contents = read_to_string(&file).map_err(couldnt)?;
+            let nested =
+                includes(&contents).map_err(|e| format_err!("{}: {}", file.display(), e))?;
+
+            including.push(canonical);
+            let contents = expand(&contents, nested, file.parent().unwrap(), including)?;
+            including.pop();
+
+            expanded.push('\n');
+            if ci {
+                expanded.push_str(&lexical::prepare_folding(&contents, true));
+            } else {
+                expanded.push_str(&contents);
+            }
+            expanded.push('\n');
+        }
+    }
+
+    expanded.push_str(&source[copied..]);
+
+    Ok(expanded)
+}
@@ -0,0 +1,463 @@
+//! Inlining over the lifted lambdas. A lambda that is called where it is
+//! created is only ever called there, so its body replaces the call. A lambda
+//! bound to a variable that is never `set!` is known at every call of that
+//! variable, and a copy of its body replaces those calls when it is small.
+//!
+//! Parameters are replaced by the arguments of the call, so a call is only
+//! inlined when that doesn't change what the program does: the parameters are
+//! never `set!`, and each argument is a constant, a variable that is never
+//! `set!`, or a lambda used at most once by the body and not by the lambdas it
+//! creates.
+
+use moniker::{FreeVar, Ignore, Var};
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::cdsl::{unique_name, TakenNames};
+use crate::lifted_expr::{
+    drop_dead_lambdas, fold_children, select_env_reprs, walk, EnvRepr, Folder, LExpr, LiftedLambda,
+    Program, Visitor,
+};
+
+/// The largest lambda, counting the lambdas it creates, inlined at every call
+/// of a variable bound to it.
+pub const DEFAULT_THRESHOLD: usize = 12;
+
+/// Inlining a lambda can make more calls inlinable, this bounds how much code
+/// repeated inlining of small lambdas can make.
+const MAX_ROUNDS: usize = 4;
+
+/// Inline the calls in the lambdas of `program` that can be, its entry is left
+/// as it is. With a `threshold` of 0 nothing is inlined.
+pub fn inline_lambdas(program: &mut Program, threshold: usize) {
+    if threshold == 0 {
+        return;
+    }
+
+    let (expr, lambdas) = program.parts_mut();
+
+    let mut set = HashSet::new();
+    expr.set_vars(&mut set);
+    for lambda in lambdas.values() {
+        lambda.body.set_vars(&mut set);
+    }
+
+    let mut ctx = Inliner {
+        next_id: lambdas.keys().max().map_or(0, |id| id + 1),
+        taken: lambdas.values().map(|l| l.name.clone()).collect(),
+        lambdas,
+        set,
+        threshold,
+    };
+
+    for _ in 0..MAX_ROUNDS {
+        let direct = ctx.inline_direct_calls();
+        let known = ctx.inline_known_calls(expr);
+        ctx.prune(expr);
+
+        if !direct && !known {
+            break;
+        }
+    }
+
+    for lambda in ctx.lambdas.values_mut() {
+        lambda.env_repr = EnvRepr::Flat;
+    }
+    select_env_reprs(ctx.lambdas);
+}
+
+struct Inliner<'a> {
+    lambdas: &'a mut BTreeMap<usize, LiftedLambda>,
+    /// every variable that is `set!` somewhere
+    set: HashSet<FreeVar<String>>,
+    threshold: usize,
+    next_id: usize,
+    /// the names of the lambdas, copies need names of their own
+    taken: TakenNames,
+}
+
+impl<'a> Inliner<'a> {
+    fn sorted_ids(&self) -> Vec<usize> {
+        let mut ids = self.lambdas.keys().cloned().collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+
+    /// Replace the calls of lambdas where they are created with their bodies.
+    fn inline_direct_calls(&mut self) -> bool {
+        let mut changed = false;
+
+        for id in self.sorted_ids() {
+            // the lambda may have been inlined into one before it
+            let body = match self.lambdas.get(&id) {
+                Some(l) => l.body.clone(),
+                None => continue,
+            };
+
+            if let Some(body) = self.direct_calls_in(&body) {
+                self.lambdas.get_mut(&id).unwrap().body = Arc::new(body);
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// The body with its direct calls inlined, if it has any that can be.
+    fn direct_calls_in(&mut self, e: &LExpr) -> Option<LExpr> {
+        match e {
+            LExpr::SetThen(v, e, c) => {
+                let c = self.direct_calls_in(c)?;
+                Some(LExpr::SetThen(v.clone(), e.clone(), Arc::new(c)))
+            }
+            LExpr::If(c, ift, iff) => {
+                let new_ift = self.direct_calls_in(ift);
+                let new_iff = self.direct_calls_in(iff);
+
+                if new_ift.is_none() && new_iff.is_none() {
+                    return None;
+                }
+
+                Some(LExpr::If(
+                    c.clone(),
+                    new_ift.map_or_else(|| ift.clone(), Arc::new),
+                    new_iff.map_or_else(|| iff.clone(), Arc::new),
+                ))
+            }
+            LExpr::CallOne(f, _) | LExpr::CallTwo(f, _, _) => {
+                let id = match &**f {
+                    LExpr::Lifted(Ignore(id)) => *id,
+                    _ => return None,
+                };
+
+                let subst = self.substitution(id, &call_args(e))?;
+                let lambda = self.lambdas.remove(&id).unwrap();
+                let body = self.substitute(&lambda.body, &subst);
+
+                // the body may hold more direct calls
+                Some(self.direct_calls_in(&body).unwrap_or(body))
+            }
+            _ => None,
+        }
+    }
+
+    /// Replace the calls of variables bound to small lambdas with copies of
+    /// their bodies.
+    fn inline_known_calls(&mut self, expr: &LExpr) -> bool {
+        let mut parents = HashMap::new();
+        let mut known = HashMap::new();
+
+        expr.known_lambdas(None, self.lambdas, &self.set, &mut known);
+        for lambda in self.lambdas.values() {
+            lambda
+                .body
+                .known_lambdas(Some(lambda.id), self.lambdas, &self.set, &mut known);
+
+            let mut children = Vec::new();
+            lambda.body.lifted_ids(&mut children);
+            parents.extend(children.into_iter().map(|c| (c, lambda.id)));
+        }
+
+        known.retain(|_, (id, _)| self.size(*id, self.threshold) <= self.threshold);
+
+        let mut changed = false;
+
+        for id in self.sorted_ids() {
+            let body = self.lambdas[&id].body.clone();
+
+            if let Some(body) = self.known_calls_in(id, &body, &known, &parents) {
+                self.lambdas.get_mut(&id).unwrap().body = Arc::new(body);
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    fn known_calls_in(
+        &mut self,
+        current: usize,
+        e: &LExpr,
+        known: &HashMap<FreeVar<String>, (usize, Option<usize>)>,
+        parents: &HashMap<usize, usize>,
+    ) -> Option<LExpr> {
+        match e {
+            LExpr::SetThen(v, e, c) => {
+                let c = self.known_calls_in(current, c, known, parents)?;
+                Some(LExpr::SetThen(v.clone(), e.clone(), Arc::new(c)))
+            }
+            LExpr::If(c, ift, iff) => {
+                let new_ift = self.known_calls_in(current, ift, known, parents);
+                let new_iff = self.known_calls_in(current, iff, known, parents);
+
+                if new_ift.is_none() && new_iff.is_none() {
+                    return None;
+                }
+
+                Some(LExpr::If(
+                    c.clone(),
+                    new_ift.map_or_else(|| ift.clone(), Arc::new),
+                    new_iff.map_or_else(|| iff.clone(), Arc::new),
+                ))
+            }
+            LExpr::CallOne(f, _) | LExpr::CallTwo(f, _, _) => {
+                let (id, creator) = match &**f {
+                    LExpr::Var(Var::Free(f)) => *known.get(f)?,
+                    _ => return None,
+                };
+
+                // the lambdas between this one and the one that created the
+                // inlined lambda need to capture what it captures
+                let mut chain = vec![current];
+                while parents.get(chain.last().unwrap()).cloned() != creator {
+                    chain.push(*parents.get(chain.last().unwrap())?);
+                }
+
+                let subst = self.substitution(id, &call_args(e))?;
+                let lambda = self.lambdas[&id].clone();
+                let body = self.copy(&lambda.body, &subst);
+
+                for link in chain {
+                    let freevars = &mut self.lambdas.get_mut(&link).unwrap().freevars;
+                    freevars.extend(lambda.captures().cloned());
+                }
+
+                Some(body)
+            }
+            _ => None,
+        }
+    }
+
+    /// What to replace each parameter of a lambda with when it is called with
+    /// `args`, if it can be inlined.
+    fn substitution(&self, id: usize, args: &[&LExpr]) -> Option<HashMap<FreeVar<String>, LExpr>> {
+        let lambda = &self.lambdas[&id];
+
+        let params = lambda.params.iter().collect::<Vec<_>>();
+        if params.len() != args.len() {
+            return None;
+        }
+
+        let mut subst = HashMap::new();
+
+        for (param, arg) in params.into_iter().zip(args) {
+            if self.set.contains(param) {
+                return None;
+            }
+
+            match arg {
+                LExpr::Lit(_) | LExpr::BuiltinIdent(_) => {}
+                LExpr::Var(Var::Free(v)) if !self.set.contains(v) => {}
+                // creating the lambda anywhere else could create it more than
+                // once
+                LExpr::Lifted(_) => {
+                    let mut children = Vec::new();
+                    lambda.body.lifted_ids(&mut children);
+
+                    if lambda.body.uses(param) > 1
+                        || children
+                            .iter()
+                            .any(|c| self.lambdas[c].freevars.contains(param))
+                    {
+                        return None;
+                    }
+                }
+                _ => return None,
+            }
+
+            subst.insert(param.clone(), (*arg).clone());
+        }
+
+        Some(subst)
+    }
+
+    /// `e` with the parameters replaced, including in the lambdas it creates.
+    fn substitute(&mut self, e: &LExpr, subst: &HashMap<FreeVar<String>, LExpr>) -> LExpr {
+        self.rewrite(e, subst, false)
+    }
+
+    /// Like `substitute`, but the lambdas created are copies, leaving the
+    /// originals for the other places the lambda is called.
+    fn copy(&mut self, e: &LExpr, subst: &HashMap<FreeVar<String>, LExpr>) -> LExpr {
+        self.rewrite(e, subst, true)
+    }
+
+    fn rewrite(&mut self, e: &LExpr, subst: &HashMap<FreeVar<String>, LExpr>, copy: bool) -> LExpr {
+        Rewrite {
+            inliner: self,
+            subst,
+            copy,
+        }
+        .fold(e)
+    }
+
+    /// The number of nodes in the body of a lambda and the lambdas it creates,
+    /// counted only until there are more than `limit`, so that a lambda
+    /// creating the rest of the program isn't counted whole for every call.
+    fn size(&self, id: usize, limit: usize) -> usize {
+        let body = &self.lambdas[&id].body;
+
+        let mut children = Vec::new();
+        body.lifted_ids(&mut children);
+
+        let mut size = body.size();
+        for child in children {
+            if size > limit {
+                break;
+            }
+            size += self.size(child, limit - size);
+        }
+        size
+    }
+
+    /// Drop the lambdas that are no longer created, and the free variables
+    /// that are no longer used.
+    fn prune(&mut self, expr: &LExpr) {
+        drop_dead_lambdas(expr, self.lambdas);
+
+        let mut roots = Vec::new();
+        expr.lifted_ids(&mut roots);
+
+        for id in roots {
+            self.prune_lambda(id);
+        }
+    }
+
+    fn prune_lambda(&mut self, id: usize) {
+        let mut children = Vec::new();
+        self.lambdas[&id].body.lifted_ids(&mut children);
+
+        let mut used = HashSet::new();
+        self.lambdas[&id].body.vars(&mut used);
+
+        for child in children {
+            self.prune_lambda(child);
+
+            let child = &self.lambdas[&child];
+            used.extend(child.captures().cloned());
+        }
+
+        self.lambdas
+            .get_mut(&id)
+            .unwrap()
+            .freevars
+            .retain(|v| used.contains(v));
+    }
+}
+
+/// Replaces parameters, see `Inliner::substitute` and `Inliner::copy`.
+struct Rewrite<'i, 'a, 's> {
+    inliner: &'i mut Inliner<'a>,
+    subst: &'s HashMap<FreeVar<String>, LExpr>,
+    copy: bool,
+}
+
+impl Folder for Rewrite<'_, '_, '_> {
+    fn fold(&mut self, e: &LExpr) -> LExpr {
+        let (subst, copy) = (self.subst, self.copy);
+
+        match e {
+            LExpr::Var(Var::Free(v)) => subst.get(v).cloned().unwrap_or_else(|| e.clone()),
+            LExpr::Lifted(Ignore(id)) => {
+                let lambda = &self.inliner.lambdas[id];
+
+                if !copy && !subst.keys().any(|p| lambda.freevars.contains(p)) {
+                    return e.clone();
+                }
+
+                let mut lambda = lambda.clone();
+                lambda.body = Arc::new(self.fold(&lambda.body));
+
+                for (param, arg) in subst {
+                    if lambda.freevars.remove(param) {
+                        if let LExpr::Var(Var::Free(v)) = arg {
+                            lambda.freevars.insert(v.clone());
+                        }
+                    }
+                }
+
+                if copy {
+                    let inliner = &mut *self.inliner;
+                    lambda.id = inliner.next_id;
+                    lambda.name =
+                        unique_name(&mut inliner.taken, format!("{}_inlined", lambda.name));
+                    inliner.next_id += 1;
+                }
+
+                let id = lambda.id;
+                self.inliner.lambdas.insert(id, lambda);
+                LExpr::Lifted(Ignore(id))
+            }
+            e => fold_children(self, e),
+        }
+    }
+}
+
+fn call_args(e: &LExpr) -> Vec<&LExpr> {
+    match e {
+        LExpr::CallOne(_, a) => vec![a],
+        LExpr::CallTwo(_, a, k) => vec![a, k],
+        _ => vec![],
+    }
+}
+
+impl LExpr {
+    /// The variables referred to or set by this expression, not counting the
+    /// lambdas it creates.
+    fn vars(&self, out: &mut HashSet<FreeVar<String>>) {
+        struct Vars<'a>(&'a mut HashSet<FreeVar<String>>);
+
+        impl Visitor for Vars<'_> {
+            fn visit(&mut self, e: &LExpr) {
+                if let LExpr::Var(Var::Free(v)) | LExpr::SetThen(Var::Free(v), _, _) = e {
+                    self.0.insert(v.clone());
+                }
+
+                walk(self, e);
+            }
+        }
+
+        Vars(out).visit(self);
+    }
+
+    /// How many times `var` is referred to, not counting the lambdas this
+    /// creates.
+    fn uses(&self, var: &FreeVar<String>) -> usize {
+        let own = matches!(self, LExpr::Var(Var::Free(v)) if v == var) as usize;
+
+        own + self.children().iter().map(|c| c.uses(var)).sum::<usize>()
+    }
+
+    /// The parameters that are never `set!` and are bound by a direct call to
+    /// a lambda, with the lambda they are bound to and `current`, the lambda
+    /// creating it.
+    fn known_lambdas(
+        &self,
+        current: Option<usize>,
+        lambdas: &BTreeMap<usize, LiftedLambda>,
+        set: &HashSet<FreeVar<String>>,
+        out: &mut HashMap<FreeVar<String>, (usize, Option<usize>)>,
+    ) {
+        let (f, args) = match self {
+            LExpr::SetThen(_, _, c) => return c.known_lambdas(current, lambdas, set, out),
+            LExpr::If(_, ift, iff) => {
+                ift.known_lambdas(current, lambdas, set, out);
+                return iff.known_lambdas(current, lambdas, set, out);
+            }
+            LExpr::CallOne(f, _) | LExpr::CallTwo(f, _, _) => (f, call_args(self)),
+            _ => return,
+        };
+
+        let callee = match &**f {
+            LExpr::Lifted(Ignore(id)) => &lambdas[id],
+            _ => return,
+        };
+
+        for (param, arg) in callee.params.iter().zip(args) {
+            if let (LExpr::Lifted(Ignore(id)), false) = (arg, set.contains(param)) {
+                out.insert(param.clone(), (*id, current));
+            }
+        }
+    }
+}
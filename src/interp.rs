@@ -0,0 +1,2447 @@
+//! A tree walking interpreter over `Expr`, this gives a reference semantics
+//! that the compiled output can be checked against, and a way to run programs
+//! without a C toolchain.
+
+use failure::{format_err, Error, Fail};
+use moniker::{FreeVar, Ignore, Var};
+use pest::iterators::Pair;
+use pest::Parser;
+
+use pretty::{BoxAllocator, DocAllocator, DocBuilder};
+use termcolor::{Color, ColorSpec, WriteColor};
+
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write as _};
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::base_expr::{generated_name, BExpr, BExprBody, BExprBodyExpr};
+use crate::expr::{Atom, Expr};
+use crate::foreign::Foreign;
+use crate::lexical;
+use crate::literals::{format_flonum, normalize_ratio, Literal};
+use crate::parse::{char_code, symbol_name, Rule, SchemeParser, MAX_CHAR};
+use crate::utils;
+use crate::vm;
+
+#[derive(Clone)]
+pub enum Value {
+    Void,
+    Int(i64),
+    Rational(i64, i64),
+    Float(f64),
+    Str(Rc<str>),
+    Symbol(Rc<str>),
+    /// Made by `string->uninterned-symbol` or `gensym`, only the same as
+    /// itself, which is told by the number the interpreter gave it
+    Uninterned(Rc<str>, usize),
+    Bool(bool),
+    Cons(Rc<Value>, Rc<Value>),
+    HashTable(Rc<RefCell<HashTable>>),
+    Vector(Rc<RefCell<Vec<Value>>>),
+    Bytevector(Rc<RefCell<Vec<u8>>>),
+    Port(Rc<RefCell<Port>>),
+    /// The message and irritants of an object made by `error`
+    Condition(Rc<Value>, Rc<Value>),
+    Promise(Rc<RefCell<Promise>>),
+    RecordType(Rc<RecordType>),
+    Record(Rc<RecordType>, Rc<RefCell<Vec<Value>>>),
+    Closure(Rc<Closure>),
+    Builtin(Rc<str>, Vec<Value>),
+    /// A closure of a program run by `vm`, which builtins can call back into
+    Compiled(Rc<vm::Closure>),
+    /// Made by `make-parameter`, called to give its value
+    Parameter(Rc<Parameter>),
+    /// A case-lambda passed around rather than only called, with the number
+    /// of arguments each of its clauses takes
+    CaseLambda(Rc<Vec<(usize, Value)>>),
+    /// What `interaction-environment` gives, there is only the one
+    Environment,
+}
+
+/// Input ports keep whether `read` has seen `#!fold-case` more recently than
+/// `#!no-fold-case` in them.
+pub enum Port {
+    Stdin {
+        fold_case: bool,
+    },
+    /// Writes to the interpreter's output along with `display`
+    Stdout,
+    Input {
+        reader: BufReader<File>,
+        fold_case: bool,
+    },
+    Output(File),
+    Closed {
+        input: bool,
+    },
+}
+
+/// Made by `define-record-type`, records are of the same type when it is the
+/// same allocation
+pub struct RecordType {
+    name: Rc<str>,
+    len: usize,
+}
+
+/// The converter is given the values of `parameterize`, the initial value has
+/// already been converted
+pub struct Parameter {
+    value: Value,
+    converter: Value,
+}
+
+/// What a thread has of its own, saved with it when it yields
+#[derive(Clone, Default)]
+pub(crate) struct Dynamic {
+    /// The values `parameterize` gave parameters, the innermost last
+    pub(crate) parameters: Vec<(Rc<Parameter>, Value)>,
+    /// The after thunks of the `dynamic-wind`s the bytecode is inside of
+    pub(crate) winds: Vec<Value>,
+}
+
+pub enum Promise {
+    /// Holds the thunk that will compute the value
+    Pending(Value),
+    Done(Value),
+}
+
+impl Port {
+    fn is_input(&self) -> bool {
+        match self {
+            Port::Stdin { .. } | Port::Input { .. } => true,
+            Port::Stdout | Port::Output(_) => false,
+            Port::Closed { input } => *input,
+        }
+    }
+}
+
+pub struct Closure {
+    param: FreeVar<String>,
+    body: Rc<Expr>,
+    env: Env,
+    line: Option<usize>,
+}
+
+struct Frame {
+    var: FreeVar<String>,
+    val: RefCell<Value>,
+    parent: Env,
+}
+
+/// Each frame holds a single binding, which works out fine since every lambda
+/// has been curried down to one parameter by this point.
+#[derive(Clone, Default)]
+struct Env(Option<Rc<Frame>>);
+
+impl Env {
+    fn extend(&self, var: FreeVar<String>, val: Value) -> Env {
+        Env(Some(Rc::new(Frame {
+            var,
+            val: RefCell::new(val),
+            parent: self.clone(),
+        })))
+    }
+
+    fn lookup(&self, var: &Var<String>) -> Result<&RefCell<Value>, Error> {
+        let var = match var {
+            Var::Free(f) => f,
+            Var::Bound(_) => return Err(format_err!("bound var: {:?}", var)),
+        };
+
+        let mut frame = &self.0;
+        while let Some(f) = frame {
+            if f.var == *var {
+                return Ok(&f.val);
+            }
+            frame = &f.parent.0;
+        }
+
+        Err(format_err!("unbound var: {}", var))
+    }
+}
+
+impl Value {
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            Value::Void => false,
+            Value::Bool(b) => *b,
+            Value::Int(v) => *v != 0,
+            Value::Float(v) => *v != 0.0,
+            _ => true,
+        }
+    }
+
+    pub fn pretty<'a, D>(&self, allocator: &'a D) -> DocBuilder<'a, D, ColorSpec>
+    where
+        D: DocAllocator<'a, ColorSpec>,
+        D::Doc: Clone,
+    {
+        match self {
+            Value::Void => allocator.text("()"),
+            Value::Int(v) => Literal::Int(*v).pretty(allocator),
+            Value::Rational(n, d) => Literal::Rational(*n, *d).pretty(allocator),
+            Value::Float(v) => Literal::Float(*v).pretty(allocator),
+            Value::Str(s) => Literal::String(s.to_string()).pretty(allocator),
+            Value::Symbol(s) | Value::Uninterned(s, _) => {
+                Literal::Symbol(s.to_string()).pretty(allocator)
+            }
+            Value::Bool(b) => Literal::Bool(*b).pretty(allocator),
+            Value::Cons(car, cdr) => {
+                let mut elems = vec![car.pretty(allocator)];
+                let mut tail = cdr.as_ref();
+                while let Value::Cons(car, cdr) = tail {
+                    elems.push(car.pretty(allocator));
+                    tail = cdr;
+                }
+
+                let elems_pret = allocator.intersperse(elems, allocator.line());
+                let elems_pret = match tail {
+                    Value::Void => elems_pret,
+                    t => elems_pret
+                        .append(allocator.line())
+                        .append(allocator.text("."))
+                        .append(allocator.line())
+                        .append(t.pretty(allocator)),
+                };
+
+                elems_pret.nest(1).group().parens()
+            }
+            Value::Vector(v) => allocator.text("#").append(
+                allocator
+                    .intersperse(
+                        v.borrow().iter().map(|e| e.pretty(allocator)),
+                        allocator.line(),
+                    )
+                    .nest(2)
+                    .group()
+                    .parens(),
+            ),
+            Value::Bytevector(v) => allocator.text("#u8").append(
+                allocator
+                    .intersperse(
+                        v.borrow().iter().map(|b| allocator.as_string(b)),
+                        allocator.line(),
+                    )
+                    .nest(4)
+                    .group()
+                    .parens(),
+            ),
+            Value::Condition(..) => allocator
+                .text(self.to_string())
+                .annotate(ColorSpec::new().set_fg(Some(Color::Red)).clone()),
+            Value::RecordType(_) | Value::Record(..) => allocator
+                .text(self.to_string())
+                .annotate(ColorSpec::new().set_fg(Some(Color::Blue)).clone()),
+            Value::Promise(_) => allocator
+                .text("promise")
+                .annotate(ColorSpec::new().set_fg(Some(Color::Blue)).clone()),
+            Value::Port(_) => allocator
+                .text("port")
+                .annotate(ColorSpec::new().set_fg(Some(Color::Blue)).clone()),
+            Value::Environment => allocator
+                .text("environment")
+                .annotate(ColorSpec::new().set_fg(Some(Color::Blue)).clone()),
+            Value::HashTable(_) => allocator
+                .text("hash table")
+                .annotate(ColorSpec::new().set_fg(Some(Color::Blue)).clone()),
+            Value::Closure(_)
+            | Value::Builtin(_, _)
+            | Value::Compiled(_)
+            | Value::Parameter(_)
+            | Value::CaseLambda(_) => allocator
+                .text("#<procedure>")
+                .annotate(ColorSpec::new().set_fg(Some(Color::Blue)).clone()),
+        }
+    }
+
+    pub fn pretty_print(&self, mut out: impl WriteColor) -> io::Result<()> {
+        // the layout below would never end
+        if Printer::is_cyclic(self) {
+            return out.write_all(Printer::print(self, true).as_bytes());
+        }
+
+        let allocator = BoxAllocator;
+
+        self.pretty(&allocator).1.render_colored(70, out)?;
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&Printer::print(self, false))
+    }
+}
+
+/// Prints values the way the runtime's print.c does, a pair or vector that is
+/// reached again from inside itself is labelled `#0=` where it is first
+/// printed and printed as `#0#` after that.
+struct Printer {
+    marks: HashMap<usize, Mark>,
+    next_label: usize,
+    write: bool,
+    out: String,
+}
+
+#[derive(Default)]
+struct Mark {
+    on_path: bool,
+    cyclic: bool,
+    label: Option<usize>,
+}
+
+/// What tells pairs and vectors apart, a pair's car is unique to it.
+fn container_id(v: &Value) -> Option<usize> {
+    match v {
+        Value::Cons(car, _) => Some(Rc::as_ptr(car) as usize),
+        Value::Vector(v) => Some(Rc::as_ptr(v) as usize),
+        _ => None,
+    }
+}
+
+impl Printer {
+    fn new(v: &Value, write: bool) -> Printer {
+        let mut printer = Printer {
+            marks: HashMap::new(),
+            next_label: 0,
+            write,
+            out: String::new(),
+        };
+
+        printer.find_cycles(v);
+
+        printer
+    }
+
+    /// The text `write` prints for `v`, or `display` when `write` is false.
+    fn print(v: &Value, write: bool) -> String {
+        let mut printer = Printer::new(v, write);
+        printer.print_inner(v);
+
+        printer.out
+    }
+
+    fn is_cyclic(v: &Value) -> bool {
+        Printer::new(v, false).marks.values().any(|m| m.cyclic)
+    }
+
+    /// The cdrs of a list are followed in a loop, as in the runtime.
+    fn find_cycles(&mut self, start: &Value) {
+        let mut v = start;
+        let mut walked = Vec::new();
+
+        while let Some(id) = container_id(v) {
+            if let Some(m) = self.marks.get_mut(&id) {
+                m.cyclic |= m.on_path;
+                break;
+            }
+
+            self.marks.insert(
+                id,
+                Mark {
+                    on_path: true,
+                    ..Mark::default()
+                },
+            );
+            walked.push(id);
+
+            match v {
+                Value::Cons(car, cdr) => {
+                    self.find_cycles(car);
+                    v = cdr;
+                }
+                Value::Vector(elems) => {
+                    for e in elems.borrow().iter() {
+                        self.find_cycles(e);
+                    }
+                    break;
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        for id in walked {
+            self.marks.get_mut(&id).unwrap().on_path = false;
+        }
+    }
+
+    fn is_labelled(&self, v: &Value) -> bool {
+        container_id(v).is_some_and(|id| self.marks[&id].cyclic)
+    }
+
+    /// Prints the label of `v` if it has one, true when `v` has been printed
+    /// already and the reference to it is all there is to print.
+    fn label(&mut self, v: &Value) -> bool {
+        let marks = &mut self.marks;
+        let m = match container_id(v).and_then(|id| marks.get_mut(&id)) {
+            Some(m) if m.cyclic => m,
+            _ => return false,
+        };
+
+        if let Some(label) = m.label {
+            let _ = write!(self.out, "#{}#", label);
+            return true;
+        }
+
+        m.label = Some(self.next_label);
+        let _ = write!(self.out, "#{}=", self.next_label);
+        self.next_label += 1;
+
+        false
+    }
+
+    fn print_inner(&mut self, v: &Value) {
+        match v {
+            Value::Cons(car, cdr) => {
+                if self.label(v) {
+                    return;
+                }
+
+                self.out.push('(');
+                self.print_inner(car);
+
+                let mut tail = cdr.as_ref();
+                while let Value::Cons(car, cdr) = tail {
+                    if self.is_labelled(tail) {
+                        break;
+                    }
+
+                    self.out.push(' ');
+                    self.print_inner(car);
+                    tail = cdr;
+                }
+
+                if !matches!(tail, Value::Void) {
+                    self.out.push_str(" . ");
+                    self.print_inner(tail);
+                }
+
+                self.out.push(')');
+            }
+            Value::Vector(elems) => {
+                if self.label(v) {
+                    return;
+                }
+
+                self.out.push_str("#(");
+
+                for (i, e) in elems.borrow().iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(' ');
+                    }
+                    self.print_inner(e);
+                }
+
+                self.out.push(')');
+            }
+            Value::Void => self.out.push_str("()"),
+            Value::Int(v) => {
+                let _ = write!(self.out, "{}", v);
+            }
+            Value::Rational(n, d) => {
+                let _ = write!(self.out, "{}/{}", n, d);
+            }
+            Value::Float(v) => self.out.push_str(&format_flonum(*v)),
+            Value::Str(s) if self.write => {
+                self.out.push('"');
+                for c in s.chars() {
+                    match c {
+                        '"' => self.out.push_str("\\\""),
+                        '\\' => self.out.push_str("\\\\"),
+                        '\n' => self.out.push_str("\\n"),
+                        '\t' => self.out.push_str("\\t"),
+                        c => self.out.push(c),
+                    }
+                }
+                self.out.push('"');
+            }
+            // so that read gives the symbol back
+            Value::Symbol(s) | Value::Uninterned(s, _)
+                if self.write && lexical::needs_bars(s.as_bytes()) =>
+            {
+                let _ = write!(self.out, "|{}|", s);
+            }
+            Value::Str(s) | Value::Symbol(s) | Value::Uninterned(s, _) => self.out.push_str(s),
+            Value::Bool(b) => self.out.push_str(if *b { "#t" } else { "#f" }),
+            Value::HashTable(_) => self.out.push_str("hash table"),
+            Value::Bytevector(_) => self.out.push_str("bytevector"),
+            Value::Port(_) => self.out.push_str("port"),
+            Value::Environment => self.out.push_str("environment"),
+            Value::Promise(_) => self.out.push_str("promise"),
+            Value::RecordType(_) => self.out.push_str("record type"),
+            Value::Record(t, _) => {
+                let _ = write!(self.out, "{} record", t.name);
+            }
+            Value::Condition(m, i) => {
+                // the message is always displayed
+                let write = std::mem::replace(&mut self.write, false);
+                self.print_inner(m);
+                self.write = write;
+
+                // followed by each irritant
+                let mut tail = i.as_ref();
+                while let Value::Cons(car, cdr) = tail {
+                    self.out.push(' ');
+                    self.print_inner(car);
+                    tail = cdr;
+                }
+            }
+            Value::Closure(_)
+            | Value::Builtin(_, _)
+            | Value::Compiled(_)
+            | Value::Parameter(_)
+            | Value::CaseLambda(_) => self.out.push_str("#<procedure>"),
+        }
+    }
+}
+
+/// Object identity, numbers and symbols aren't objects here so `eqv?` takes
+/// care of them.
+fn values_identical(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Str(a), Value::Str(b)) => Rc::ptr_eq(a, b),
+        // a pair is only ever built by `cons`, so its car is unique to it
+        (Value::Cons(a_car, a_cdr), Value::Cons(b_car, b_cdr)) => {
+            Rc::ptr_eq(a_car, b_car) && Rc::ptr_eq(a_cdr, b_cdr)
+        }
+        (Value::HashTable(a), Value::HashTable(b)) => Rc::ptr_eq(a, b),
+        (Value::Vector(a), Value::Vector(b)) => Rc::ptr_eq(a, b),
+        (Value::Bytevector(a), Value::Bytevector(b)) => Rc::ptr_eq(a, b),
+        (Value::Port(a), Value::Port(b)) => Rc::ptr_eq(a, b),
+        (Value::Condition(a, _), Value::Condition(b, _)) => Rc::ptr_eq(a, b),
+        (Value::Promise(a), Value::Promise(b)) => Rc::ptr_eq(a, b),
+        (Value::RecordType(a), Value::RecordType(b)) => Rc::ptr_eq(a, b),
+        (Value::Record(_, a), Value::Record(_, b)) => Rc::ptr_eq(a, b),
+        (Value::Closure(a), Value::Closure(b)) => Rc::ptr_eq(a, b),
+        (Value::Compiled(a), Value::Compiled(b)) => Rc::ptr_eq(a, b),
+        (Value::Parameter(a), Value::Parameter(b)) => Rc::ptr_eq(a, b),
+        (Value::CaseLambda(a), Value::CaseLambda(b)) => Rc::ptr_eq(a, b),
+        (Value::Environment, Value::Environment) => true,
+        _ => false,
+    }
+}
+
+fn values_eqv(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Void, Value::Void) => true,
+        (Value::Bool(a), Value::Bool(b)) => a == b,
+        (Value::Int(a), Value::Int(b)) => a == b,
+        (Value::Rational(an, ad), Value::Rational(bn, bd)) => an == bn && ad == bd,
+        (Value::Float(a), Value::Float(b)) => a.to_bits() == b.to_bits(),
+        (Value::Symbol(a), Value::Symbol(b)) => a == b,
+        (Value::Uninterned(_, a), Value::Uninterned(_, b)) => a == b,
+        _ => values_identical(a, b),
+    }
+}
+
+/// `equal?` first compares this many pairs and vectors without keeping track
+/// of them, as in the runtime.
+const EQUAL_FAST_LIMIT: usize = 1000;
+
+/// How `equal_inner` stops on cyclic values, either by giving up when the
+/// budget runs out or by taking the containers it meets again to be equal.
+enum EqualGuard {
+    Budget(usize),
+    Seen(HashSet<(usize, usize)>),
+}
+
+fn values_equal(a: &Value, b: &Value) -> bool {
+    equal_inner(a, b, &mut EqualGuard::Budget(EQUAL_FAST_LIMIT))
+        .unwrap_or_else(|| equal_inner(a, b, &mut EqualGuard::Seen(HashSet::new())) == Some(true))
+}
+
+fn equal_inner(mut a: &Value, mut b: &Value, guard: &mut EqualGuard) -> Option<bool> {
+    // the cdrs of lists are compared in the loop
+    loop {
+        if values_identical(a, b) {
+            return Some(true);
+        }
+
+        match (a, b) {
+            (Value::Str(a), Value::Str(b)) => return Some(a == b),
+            (Value::Bytevector(a), Value::Bytevector(b)) => return Some(a == b),
+            (Value::Cons(..), Value::Cons(..)) | (Value::Vector(_), Value::Vector(_)) => {}
+            _ => return Some(values_eqv(a, b)),
+        }
+
+        match guard {
+            EqualGuard::Budget(0) => return None,
+            EqualGuard::Budget(n) => *n -= 1,
+            EqualGuard::Seen(seen) => {
+                if !seen.insert((container_id(a)?, container_id(b)?)) {
+                    return Some(true);
+                }
+            }
+        }
+
+        match (a, b) {
+            (Value::Cons(a_car, a_cdr), Value::Cons(b_car, b_cdr)) => {
+                if !equal_inner(a_car, b_car, guard)? {
+                    return Some(false);
+                }
+
+                a = a_cdr;
+                b = b_cdr;
+            }
+            (Value::Vector(a), Value::Vector(b)) => {
+                let (a, b) = (a.borrow(), b.borrow());
+
+                if a.len() != b.len() {
+                    return Some(false);
+                }
+
+                for (a, b) in a.iter().zip(b.iter()) {
+                    if !equal_inner(a, b, guard)? {
+                        return Some(false);
+                    }
+                }
+
+                return Some(true);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// The entries of a hash table, in buckets by the `equal_hash` of their keys,
+/// whose keys are told apart with `equal?`.
+#[derive(Default)]
+pub struct HashTable(HashMap<u64, Vec<(Value, Value)>>);
+
+impl HashTable {
+    fn get(&self, key: &Value) -> Option<&Value> {
+        self.0
+            .get(&equal_hash(key))?
+            .iter()
+            .find(|(k, _)| values_equal(k, key))
+            .map(|(_, v)| v)
+    }
+
+    fn insert(&mut self, key: Value, value: Value) {
+        let bucket = self.0.entry(equal_hash(&key)).or_default();
+
+        match bucket.iter_mut().find(|(k, _)| values_equal(k, &key)) {
+            Some((_, v)) => *v = value,
+            None => bucket.push((key, value)),
+        }
+    }
+
+    /// Whether there was an entry for `key` to remove.
+    fn remove(&mut self, key: &Value) -> bool {
+        let hash = equal_hash(key);
+        let bucket = match self.0.get_mut(&hash) {
+            Some(bucket) => bucket,
+            None => return false,
+        };
+
+        let len = bucket.len();
+        bucket.retain(|(k, _)| !values_equal(k, key));
+        let removed = bucket.len() != len;
+
+        if bucket.is_empty() {
+            self.0.remove(&hash);
+        }
+        removed
+    }
+
+    fn entries(&self) -> impl Iterator<Item = &(Value, Value)> {
+        self.0.values().flatten()
+    }
+}
+
+/// Pairs and vectors hashed before the rest of a value are left out of its
+/// hash, so that a cyclic value has one, as in the runtime.
+const HASH_CONTAINER_LIMIT: usize = 64;
+
+/// A hash of `v` that every value `equal?` to it shares.
+fn equal_hash(v: &Value) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    let mut budget = HASH_CONTAINER_LIMIT;
+    hash_bounded(v, &mut budget, &mut hasher);
+    hasher.finish()
+}
+
+/// The budget is shared by everything hashed, so equal values, which have the
+/// same elements in the same order, run out of it at the same place.
+fn hash_bounded(v: &Value, budget: &mut usize, hasher: &mut DefaultHasher) {
+    fn ptr<T>(rc: &Rc<T>) -> usize {
+        Rc::as_ptr(rc) as usize
+    }
+
+    std::mem::discriminant(v).hash(hasher);
+
+    match v {
+        Value::Int(i) => i.hash(hasher),
+        Value::Rational(n, d) => (n, d).hash(hasher),
+        Value::Float(f) => f.to_bits().hash(hasher),
+        Value::Str(s) | Value::Symbol(s) => s.hash(hasher),
+        Value::Uninterned(_, id) => id.hash(hasher),
+        Value::Bool(b) => b.hash(hasher),
+        Value::Bytevector(b) => b.borrow().hash(hasher),
+        Value::Cons(..) | Value::Vector(_) if *budget == 0 => {}
+        Value::Cons(car, cdr) => {
+            *budget -= 1;
+            hash_bounded(car, budget, hasher);
+            hash_bounded(cdr, budget, hasher);
+        }
+        Value::Vector(elems) => {
+            *budget -= 1;
+            for e in elems.borrow().iter() {
+                hash_bounded(e, budget, hasher);
+            }
+        }
+        // `equal?` compares the rest by identity
+        Value::HashTable(p) => ptr(p).hash(hasher),
+        Value::Port(p) => ptr(p).hash(hasher),
+        Value::Condition(p, _) => ptr(p).hash(hasher),
+        Value::Promise(p) => ptr(p).hash(hasher),
+        Value::RecordType(p) => ptr(p).hash(hasher),
+        Value::Record(_, p) => ptr(p).hash(hasher),
+        Value::Closure(p) => ptr(p).hash(hasher),
+        Value::Compiled(p) => ptr(p).hash(hasher),
+        Value::Parameter(p) => ptr(p).hash(hasher),
+        Value::CaseLambda(p) => ptr(p).hash(hasher),
+        Value::Void | Value::Builtin(..) | Value::Environment => {}
+    }
+}
+
+/// The value of a literal, which is made anew each time it is evaluated.
+fn literal_value(l: &Literal) -> Value {
+    match l {
+        Literal::String(s) => Value::Str(unescape(s).into()),
+        Literal::Int(i) => Value::Int(*i),
+        Literal::Rational(n, d) => Value::Rational(*n, *d),
+        Literal::Float(f) => Value::Float(*f),
+        Literal::Symbol(s) => Value::Symbol(s.as_str().into()),
+        Literal::Bool(b) => Value::Bool(*b),
+        Literal::Void => Value::Void,
+        Literal::Quoted(items) => prepend_all(Value::Void, items.iter().rev().map(literal_value)),
+    }
+}
+
+fn prepend_all(acc: Value, vals: impl Iterator<Item = Value>) -> Value {
+    vals.fold(acc, |acc, v| Value::Cons(Rc::new(v), Rc::new(acc)))
+}
+
+pub(crate) fn builtin_arity(name: &str) -> Option<usize> {
+    let arity = match name {
+        "tostring"
+        | "cons?"
+        | "null?"
+        | "car"
+        | "cdr"
+        | "string-chars"
+        | "string->list"
+        | "string-length"
+        | "string->number"
+        | "number->string"
+        | "ht-new"
+        | "ht-keys"
+        | "symbol->string"
+        | "string->symbol"
+        | "string->uninterned-symbol"
+        | "gensym"
+        | "symbol?"
+        | "vector-length"
+        | "vector?"
+        | "vector->list"
+        | "list->vector"
+        | "bytevector-length"
+        | "bytevector?"
+        | "list->bytevector"
+        | "zero?"
+        | "number?"
+        | "pair?"
+        | "procedure?"
+        | "string?"
+        | "boolean?"
+        | "current-input-port"
+        | "current-output-port"
+        | "command-line"
+        | "exit"
+        | "get-environment-variable"
+        | "current-second"
+        | "dump-heap"
+        | "open-input-file"
+        | "open-output-file"
+        | "read-char"
+        | "read-line"
+        | "read"
+        | "newline"
+        | "close-port"
+        | "raise"
+        | "error-object?"
+        | "error-object-message"
+        | "error-object-irritants"
+        | "delay"
+        | "make-promise"
+        | "promise?"
+        | "force"
+        | "make-record"
+        | "make-hash-table"
+        | "hash-table?"
+        | "hash-table->alist"
+        | "interaction-environment"
+        | "spawn"
+        | "yield"
+        | "parameter-converter"
+        | "parameter-pop!"
+        | "make-case-lambda" => 1,
+        "+"
+        | "-"
+        | "*"
+        | "/"
+        | "%"
+        | "^"
+        | "<"
+        | "<="
+        | ">"
+        | ">="
+        | "="
+        | "cons"
+        | "string-concat"
+        | "string-ref"
+        | "string-append"
+        | "string=?"
+        | "ht-get"
+        | "make-record-type"
+        | "hash-table-ref"
+        | "hash-table-delete!"
+        | "record?"
+        | "record-ref"
+        | "ht-del!"
+        | "eq?"
+        | "eqv?"
+        | "equal?"
+        | "make-vector"
+        | "vector-ref"
+        | "make-bytevector"
+        | "bytevector-u8-ref"
+        | "display"
+        | "write"
+        | "write-string"
+        | "with-exception-handler"
+        | "error"
+        | "eval"
+        | "interaction-define!"
+        | "make-parameter"
+        | "parameter-push!"
+        | "case-lambda-clause" => 2,
+        "ht-set!"
+        | "vector-set!"
+        | "bytevector-u8-set!"
+        | "dynamic-wind"
+        | "record-check"
+        | "record-set!"
+        | "hash-table-set!"
+        | "substring"
+        | "hash-table-ref/default" => 3,
+        _ if Foreign::from_builtin_name(name).is_some() => 1,
+        _ => return None,
+    };
+
+    Some(arity)
+}
+
+fn length_arg(name: &str, k: &Value) -> Result<usize, Error> {
+    match k {
+        Value::Int(k) if *k >= 0 => Ok(*k as usize),
+        _ => Err(format_err!(
+            "Length given to {} was not a non negative integer",
+            name
+        )),
+    }
+}
+
+fn index_arg(name: &str, idx: &Value, len: usize) -> Result<usize, Error> {
+    match idx {
+        Value::Int(i) if *i >= 0 && (*i as usize) < len => Ok(*i as usize),
+        Value::Int(i) => Err(format_err!(
+            "Index {} out of range in {} of length {}",
+            i,
+            name,
+            len
+        )),
+        _ => Err(format_err!("Index to {} not of integer type", name)),
+    }
+}
+
+fn byte_arg(name: &str, b: &Value) -> Result<u8, Error> {
+    match b {
+        Value::Int(b) if (0..=255).contains(b) => Ok(*b as u8),
+        _ => Err(format_err!("Value given to {} was not a byte", name)),
+    }
+}
+
+/// The clause of a case-lambda a call with `args` arguments runs.
+pub(crate) fn case_lambda_clause(clauses: &[(usize, Value)], args: usize) -> Result<Value, Error> {
+    clauses
+        .iter()
+        .find(|(arity, _)| *arity == args)
+        .map(|(_, clause)| clause.clone())
+        .ok_or_else(|| {
+            format_err!(
+                "no clause of the case-lambda takes {} argument{}",
+                args,
+                if args == 1 { "" } else { "s" }
+            )
+        })
+}
+
+/// The clause of a case-lambda called like any procedure, with one argument,
+/// which a call without arguments is given too.
+pub(crate) fn direct_clause(clauses: &[(usize, Value)]) -> Result<Value, Error> {
+    case_lambda_clause(clauses, 1).or_else(|_| case_lambda_clause(clauses, 0))
+}
+
+fn list_elems(name: &str, mut l: &Value) -> Result<Vec<Value>, Error> {
+    let mut elems = Vec::new();
+
+    loop {
+        match l {
+            Value::Void => return Ok(elems),
+            Value::Cons(car, cdr) => {
+                elems.push(car.as_ref().clone());
+                l = cdr;
+            }
+            _ => return Err(format_err!("Argument to {} was not a list", name)),
+        }
+    }
+}
+
+fn int_binop(name: &str, lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+    let (op_name, op): (&str, fn(i64, i64) -> i64) = match name {
+        "%" => ("mod", i64::wrapping_rem),
+        "^" => ("xor", |a, b| a ^ b),
+        _ => unreachable!("not an integer binop: {}", name),
+    };
+
+    let lhs = match lhs {
+        Value::Int(v) => *v,
+        _ => {
+            return Err(format_err!(
+                "Left operand to binary {} not of integer type",
+                op_name
+            ))
+        }
+    };
+    let rhs = match rhs {
+        Value::Int(v) => *v,
+        _ => {
+            return Err(format_err!(
+                "Right operand to binary {} not of integer type",
+                op_name
+            ))
+        }
+    };
+
+    if name == "%" && rhs == 0 {
+        return Err(format_err!("Divide by zero ({} {} {})", lhs, name, rhs));
+    }
+
+    Ok(Value::Int(op(lhs, rhs)))
+}
+
+enum Num {
+    Exact(i64, i64),
+    Float(f64),
+}
+
+fn as_num(side: &str, op_name: &str, v: &Value) -> Result<Num, Error> {
+    match v {
+        Value::Int(i) => Ok(Num::Exact(*i, 1)),
+        Value::Rational(n, d) => Ok(Num::Exact(*n, *d)),
+        Value::Float(f) => Ok(Num::Float(*f)),
+        _ => Err(format_err!(
+            "{} operand to binary {} not of numeric type",
+            side,
+            op_name
+        )),
+    }
+}
+
+fn as_float(n: &Num) -> f64 {
+    match n {
+        Num::Exact(n, d) => *n as f64 / *d as f64,
+        Num::Float(f) => *f,
+    }
+}
+
+fn make_rational(n: i64, d: i64) -> Result<Value, Error> {
+    let (n, d) =
+        normalize_ratio(n, d).ok_or_else(|| format_err!("Integer overflow in rational"))?;
+
+    if d == 1 {
+        Ok(Value::Int(n))
+    } else {
+        Ok(Value::Rational(n, d))
+    }
+}
+
+/// Mirrors the arithmetic in the runtime's number.c, anything involving a
+/// float is done in floating point and everything else stays exact.
+fn num_binop(name: &str, lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+    let op_name = match name {
+        "+" => "add",
+        "-" => "sub",
+        "*" => "mul",
+        "/" => "div",
+        _ => unreachable!("not a numeric binop: {}", name),
+    };
+
+    let lhs = as_num("Left", op_name, lhs)?;
+    let rhs = as_num("Right", op_name, rhs)?;
+
+    let (an, ad, bn, bd) = match (&lhs, &rhs) {
+        (Num::Exact(an, ad), Num::Exact(bn, bd)) => (*an, *ad, *bn, *bd),
+        _ => {
+            let (a, b) = (as_float(&lhs), as_float(&rhs));
+            let res = match name {
+                "+" => a + b,
+                "-" => a - b,
+                "*" => a * b,
+                _ => a / b,
+            };
+            return Ok(Value::Float(res));
+        }
+    };
+
+    let overflow = || format_err!("Integer overflow in binary {}", op_name);
+    let mul = |a: i64, b: i64| a.checked_mul(b).ok_or_else(overflow);
+
+    match name {
+        "+" => make_rational(
+            mul(an, bd)?
+                .checked_add(mul(bn, ad)?)
+                .ok_or_else(overflow)?,
+            mul(ad, bd)?,
+        ),
+        "-" => make_rational(
+            mul(an, bd)?
+                .checked_sub(mul(bn, ad)?)
+                .ok_or_else(overflow)?,
+            mul(ad, bd)?,
+        ),
+        "*" => make_rational(mul(an, bn)?, mul(ad, bd)?),
+        _ => {
+            if bn == 0 {
+                return Err(format_err!("Divide by zero in binary {}", op_name));
+            }
+            make_rational(mul(an, bd)?, mul(ad, bn)?)
+        }
+    }
+}
+
+fn num_cmp(name: &str, lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+    let op_name = match name {
+        "<" => "lt",
+        "<=" => "leq",
+        ">" => "gt",
+        ">=" => "geq",
+        "=" => "eq",
+        _ => unreachable!("not a numeric comparison: {}", name),
+    };
+
+    let lhs = as_num("Left", op_name, lhs)?;
+    let rhs = as_num("Right", op_name, rhs)?;
+
+    let ord = match (&lhs, &rhs) {
+        (Num::Exact(an, ad), Num::Exact(bn, bd)) => {
+            // denominators are always positive so cross multiplying keeps the order
+            let overflow = || format_err!("Integer overflow in binary {}", op_name);
+            let a = an.checked_mul(*bd).ok_or_else(overflow)?;
+            let b = bn.checked_mul(*ad).ok_or_else(overflow)?;
+            a.partial_cmp(&b)
+        }
+        _ => as_float(&lhs).partial_cmp(&as_float(&rhs)),
+    };
+
+    let res = match (name, ord) {
+        (_, None) => false,
+        ("<", Some(o)) => o.is_lt(),
+        ("<=", Some(o)) => o.is_le(),
+        (">", Some(o)) => o.is_gt(),
+        (">=", Some(o)) => o.is_ge(),
+        (_, Some(o)) => o.is_eq(),
+    };
+
+    Ok(Value::Bool(res))
+}
+
+/// String literals are kept escaped by the parser, the escapes being those of
+/// the grammar, which `read` and the runtime's reader take as well.
+pub(crate) fn unescape(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            res.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => res.push('\n'),
+            Some('t') => res.push('\t'),
+            Some('r') => res.push('\r'),
+            Some('a') => res.push('\u{7}'),
+            Some('b') => res.push('\u{8}'),
+            Some('f') => res.push('\u{c}'),
+            Some(c @ ('u' | 'x')) => {
+                let digits = match c {
+                    'u' => chars.by_ref().take(4).collect::<String>(),
+                    _ => chars.by_ref().take_while(|&c| c != ';').collect(),
+                };
+                let scalar = u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(char::from_u32);
+                res.push(scalar.unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            Some(c) => res.push(c),
+            None => {}
+        }
+    }
+
+    res
+}
+
+fn concat_part(v: &Value) -> Result<String, Error> {
+    match v {
+        Value::Int(c) => Ok(char::from(*c as u8).to_string()),
+        Value::Str(s) => Ok(s.to_string()),
+        v => Err(format_err!("Unexpected object to string-concat: {}", v)),
+    }
+}
+
+fn string_arg<'a>(name: &str, v: &'a Value) -> Result<&'a str, Error> {
+    match v {
+        Value::Str(s) => Ok(s),
+        _ => Err(format_err!("Argument to {} was not a string", name)),
+    }
+}
+
+/// Mirrors the runtime's `string->number`, which accepts the numbers of
+/// programs along with a decimal without digits on one side of the point.
+fn string_to_number(s: &str) -> Result<Value, Error> {
+    let b = s.as_bytes();
+
+    if let [b'#', prefix, digits @ ..] = b {
+        let radix = match prefix.to_ascii_lowercase() {
+            b'x' => 16,
+            b'o' => 8,
+            b'b' => 2,
+            b'd' => 10,
+            _ => return Ok(Value::Bool(false)),
+        };
+
+        // a sign can come before the digits, but only one
+        let digits = match digits {
+            [b'+' | b'-', digits @ ..] => digits,
+            digits => digits,
+        };
+
+        if digits.is_empty() || !digits.iter().all(|&c| (c as char).is_digit(radix)) {
+            return Ok(Value::Bool(false));
+        }
+
+        return Ok(i64::from_str_radix(&s[2..], radix)
+            .map(Value::Int)
+            .unwrap_or(Value::Bool(false)));
+    }
+
+    let skip_digits = |mut i: usize| {
+        while i < b.len() && b[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let start = if matches!(b.first(), Some(b'+' | b'-')) {
+        1
+    } else {
+        0
+    };
+    let int_end = skip_digits(start);
+    let has_int = int_end != start;
+    let mut i = int_end;
+
+    if has_int && i == b.len() {
+        return Ok(s.parse().map(Value::Int).unwrap_or(Value::Bool(false)));
+    }
+
+    if has_int && b[i] == b'/' {
+        let den_end = skip_digits(i + 1);
+
+        if den_end != b.len() || den_end == i + 1 {
+            return Ok(Value::Bool(false));
+        }
+
+        return match (s[..i].parse::<i64>(), s[i + 1..].parse::<i64>()) {
+            (Ok(n), Ok(d)) if d != 0 => make_rational(n, d),
+            _ => Ok(Value::Bool(false)),
+        };
+    }
+
+    let mut has_frac = false;
+    let mut is_float = false;
+
+    if i < b.len() && b[i] == b'.' {
+        let frac_end = skip_digits(i + 1);
+        has_frac = frac_end != i + 1;
+        is_float = true;
+        i = frac_end;
+    }
+
+    if !has_int && !has_frac {
+        return Ok(Value::Bool(false));
+    }
+
+    if i < b.len() && (b[i] == b'e' || b[i] == b'E') {
+        i += 1;
+
+        if i < b.len() && (b[i] == b'+' || b[i] == b'-') {
+            i += 1;
+        }
+
+        let exp_end = skip_digits(i);
+
+        if exp_end == i {
+            return Ok(Value::Bool(false));
+        }
+
+        is_float = true;
+        i = exp_end;
+    }
+
+    if i != b.len() || !is_float {
+        return Ok(Value::Bool(false));
+    }
+
+    Ok(s.parse().map(Value::Float).unwrap_or(Value::Bool(false)))
+}
+
+fn port_arg<'a>(name: &str, v: &'a Value, input: bool) -> Result<&'a RefCell<Port>, Error> {
+    let port = match v {
+        Value::Port(p) => p,
+        _ => return Err(format_err!("Argument to {} was not a port", name)),
+    };
+
+    if port.borrow().is_input() != input {
+        return Err(format_err!(
+            "Port given to {} was not an {} port",
+            name,
+            if input { "input" } else { "output" }
+        ));
+    }
+
+    if let Port::Closed { .. } = *port.borrow() {
+        return Err(format_err!("Port given to {} has been closed", name));
+    }
+
+    Ok(port)
+}
+
+/// The text of the next datum of `r`, or `None` at the end of the file,
+/// taking no more from `r` than the datum and the whitespace and comments
+/// before it. Comments in lists are left for `lexical::prepare`, the
+/// `#!fold-case` and `#!no-fold-case` directives are taken out, setting
+/// `fold_case`, and the atoms after them folded while it is set.
+fn read_datum_text(r: &mut dyn BufRead, fold_case: &mut bool) -> io::Result<Option<String>> {
+    fn peek(r: &mut dyn BufRead) -> io::Result<Option<u8>> {
+        Ok(r.fill_buf()?.first().copied())
+    }
+
+    // takes what follows the c just taken up to the end of the comment it
+    // starts, if it starts one that isn't a datum comment
+    fn comment(r: &mut dyn BufRead, c: u8, text: &mut Vec<u8>) -> io::Result<bool> {
+        match (c, peek(r)?) {
+            (b';', _) => {
+                while let Some(c) = peek(r)?.filter(|&c| c != b'\n') {
+                    r.consume(1);
+                    text.push(c);
+                }
+            }
+            (b'#', Some(b'|')) => {
+                let mut depth = 0;
+                let mut last = c;
+
+                while let Some(c) = peek(r)? {
+                    r.consume(1);
+                    text.push(c);
+
+                    match (last, c) {
+                        (b'#', b'|') => depth += 1,
+                        (b'|', b'#') => depth -= 1,
+                        _ => {}
+                    }
+
+                    if depth == 0 {
+                        break;
+                    }
+
+                    // so that |#| doesn't both open and close
+                    last = if (last, c) == (b'#', b'|') { 0 } else { c };
+                }
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    let is_delimiter = |c: u8| c.is_ascii_whitespace() || b"()\"';|".contains(&c);
+    let mut text = Vec::new();
+    let mut depth = 0;
+    // where the text of each datum commented out at the top starts
+    let mut commented = Vec::new();
+
+    loop {
+        while let Some(c) = peek(r)?.filter(u8::is_ascii_whitespace) {
+            r.consume(1);
+
+            if depth > 0 {
+                text.push(c);
+            }
+        }
+
+        let c = match peek(r)? {
+            Some(c) => c,
+            None if text.is_empty() => return Ok(None),
+            // what there is fails to parse
+            None => break,
+        };
+
+        r.consume(1);
+
+        if depth == 0 {
+            let mut skipped = Vec::new();
+
+            if comment(r, c, &mut skipped)? {
+                continue;
+            }
+
+            if c == b'#' && peek(r)? == Some(b';') {
+                r.consume(1);
+                commented.push(text.len());
+                continue;
+            }
+        }
+
+        text.push(c);
+
+        if depth > 0 && comment(r, c, &mut text)? {
+            continue;
+        }
+
+        match c {
+            b'(' => depth += 1,
+            b')' => depth -= 1,
+            b'"' | b'|' => {
+                let mut escaped = false;
+
+                while let Some(next) = peek(r)? {
+                    r.consume(1);
+                    text.push(next);
+
+                    match next {
+                        _ if next == c && !escaped => break,
+                        b'\\' => escaped = !escaped,
+                        _ => escaped = false,
+                    }
+                }
+            }
+            // at least the datum quoted or commented out follows
+            b'\'' => continue,
+            b'#' if peek(r)? == Some(b'(') => continue,
+            b'#' if peek(r)? == Some(b';') => {
+                r.consume(1);
+                text.push(b';');
+                continue;
+            }
+            _ => {
+                let start = text.len() - 1;
+
+                // the character after #\ is taken whatever it is
+                if c == b'#' && peek(r)? == Some(b'\\') {
+                    r.consume(1);
+                    text.push(b'\\');
+
+                    if let Some(c) = peek(r)? {
+                        r.consume(1);
+                        text.push(c);
+                    }
+                }
+
+                while let Some(c) = peek(r)?.filter(|c| !is_delimiter(*c)) {
+                    r.consume(1);
+                    text.push(c);
+                }
+
+                match &text[start..] {
+                    b"#!fold-case" | b"#!no-fold-case" => {
+                        *fold_case = text[start..] == *b"#!fold-case";
+                        text.truncate(start);
+                        continue;
+                    }
+                    _ if *fold_case => text[start..].make_ascii_lowercase(),
+                    _ => {}
+                }
+
+                // the list of a bytevector follows
+                if text[start..] == *b"#u8" && peek(r)? == Some(b'(') {
+                    continue;
+                }
+            }
+        }
+
+        if depth < 0 {
+            break;
+        }
+
+        if depth == 0 {
+            match commented.pop() {
+                Some(start) => text.truncate(start),
+                None => break,
+            }
+        }
+    }
+
+    Ok(Some(String::from_utf8_lossy(&text).into_owned()))
+}
+
+/// The value of a datum parsed by `read`.
+fn datum_value(pair: Pair<Rule>) -> Result<Value, Error> {
+    let value = match pair.as_rule() {
+        Rule::quoted_string => {
+            Value::Str(unescape(pair.into_inner().next().unwrap().as_str()).into())
+        }
+        Rule::datum_list => {
+            let mut items = Vec::new();
+            let mut tail = Value::Void;
+            let mut inner = pair.into_inner();
+
+            while let Some(p) = inner.next() {
+                match p.as_rule() {
+                    Rule::datum_dot => tail = datum_value(inner.next().unwrap())?,
+                    _ => items.push(datum_value(p)?),
+                }
+            }
+
+            prepend_all(tail, items.into_iter().rev())
+        }
+        Rule::datum_vector => Value::Vector(Rc::new(RefCell::new(
+            pair.into_inner()
+                .map(datum_value)
+                .collect::<Result<_, _>>()?,
+        ))),
+        Rule::datum_bytevector => Value::Bytevector(Rc::new(RefCell::new(
+            pair.into_inner()
+                .map(|p| byte_arg("read", &datum_value(p)?))
+                .collect::<Result<_, _>>()?,
+        ))),
+        Rule::datum_quote => prepend_all(
+            Value::Void,
+            vec![
+                datum_value(pair.into_inner().next().unwrap())?,
+                Value::Symbol("quote".into()),
+            ]
+            .into_iter(),
+        ),
+        Rule::character => match char_code(&pair.as_str()[2..]) {
+            Some(code) if code <= MAX_CHAR => Value::Int(code),
+            Some(_) => return Err(format_err!("read: non-ASCII character: {}", pair.as_str())),
+            None => return Err(format_err!("read: unknown character: {}", pair.as_str())),
+        },
+        Rule::identifier => match pair.as_str() {
+            "#t" | "#true" => Value::Bool(true),
+            "#f" | "#false" => Value::Bool(false),
+            s if s.starts_with('|') => Value::Symbol(symbol_name(s).into()),
+            s if s.starts_with("#!") => return Err(format_err!("read: bad syntax: {}", s)),
+            s => match string_to_number(s)? {
+                Value::Bool(false) => Value::Symbol(s.into()),
+                n => n,
+            },
+        },
+        r => unreachable!("{:?}", r),
+    };
+
+    Ok(value)
+}
+
+/// Read a datum from `r` the way the compiled program's `read` does, null at
+/// the end of the file.
+fn read_datum(r: &mut dyn BufRead, fold_case: &mut bool) -> Result<Value, Error> {
+    let text = match read_datum_text(r, fold_case)? {
+        Some(text) => text,
+        None => return Ok(Value::Void),
+    };
+
+    let prepared = lexical::prepare(&text);
+    let mut pairs = SchemeParser::parse(Rule::read_datum, &prepared)
+        .map_err(|_| format_err!("read: bad syntax: {}", text))?;
+
+    datum_value(pairs.next().unwrap())
+}
+
+fn open_port(path: &str, input: bool) -> Result<Value, Error> {
+    let file = if input {
+        File::open(path)
+    } else {
+        File::create(path)
+    };
+
+    // drop the "(os error n)" rust adds so this reads the same as the runtime
+    let file = file.map_err(|e| {
+        let msg = e.to_string();
+        let msg = msg.split(" (os error").next().unwrap_or_default();
+        format_err!("Failed to open '{}': {}", path, msg)
+    })?;
+
+    let port = if input {
+        Port::Input {
+            reader: BufReader::new(file),
+            fold_case: false,
+        }
+    } else {
+        Port::Output(file)
+    };
+
+    Ok(Value::Port(Rc::new(RefCell::new(port))))
+}
+
+/// An expression made from a datum given to `eval`. The values in the datum
+/// that aren't code, like quoted data or procedures, are bound to variables in
+/// `quoted` that the expression refers to, and `free` has the globals its
+/// lambdas use, which needn't be defined until they are called.
+#[derive(Default)]
+struct EvalForm {
+    quoted: Vec<(String, Value)>,
+    free: HashSet<String>,
+    /// The variables bound around the part being made, innermost last
+    locals: Vec<String>,
+    /// How many lambdas the part being made is in
+    depth: usize,
+}
+
+fn is_builtin_name(s: &str) -> bool {
+    SchemeParser::parse(Rule::builtin, s)
+        .ok()
+        .and_then(|mut p| p.next())
+        .is_some_and(|p| p.as_str() == s)
+}
+
+fn bad_syntax(d: &Value) -> Error {
+    format_err!("eval: bad syntax: {}", Printer::print(d, true))
+}
+
+/// The elements of the list `l` in `form`, which is bad syntax if it isn't one.
+fn form_elems(l: &Value, form: &Value) -> Result<Vec<Value>, Error> {
+    list_elems("eval", l).map_err(|_| bad_syntax(form))
+}
+
+/// The name of the variable the symbol `v` is in a datum being evaluated.
+/// Those that start with a bar could be taken for the names the compiler
+/// makes, so get another, and an uninterned symbol is named by its number so
+/// that it is only ever the same variable as itself.
+fn variable_name(v: &Value) -> Option<String> {
+    match v {
+        Value::Symbol(s) if s.starts_with('|') => Some(generated_name(s)),
+        Value::Symbol(s) => Some(s.to_string()),
+        Value::Uninterned(s, n) => Some(generated_name(&format!("uninterned {} {}", n, s))),
+        _ => None,
+    }
+}
+
+/// The name a `(define name e)` or `(define (name x ...) body)` defines.
+fn define_name(d: &Value) -> Option<String> {
+    let (keyword, rest) = match d {
+        Value::Cons(keyword, rest) => (keyword.as_ref(), rest.as_ref()),
+        _ => return None,
+    };
+
+    match (keyword, rest) {
+        (Value::Symbol(k), Value::Cons(target, _)) if &**k == "define" => match target.as_ref() {
+            Value::Cons(name, _) => variable_name(name),
+            target => variable_name(target),
+        },
+        _ => None,
+    }
+}
+
+impl EvalForm {
+    fn quote(&mut self, v: &Value) -> BExpr {
+        let name = generated_name(&format!("eval {}", self.quoted.len()));
+        self.quoted.push((name.clone(), v.clone()));
+        BExpr::Var(name)
+    }
+
+    fn var(&mut self, name: &str) -> String {
+        if self.depth > 0 && !self.locals.iter().any(|l| l == name) {
+            self.free.insert(name.to_owned());
+        }
+
+        name.to_owned()
+    }
+
+    fn symbols(&self, d: &Value, form: &Value) -> Result<Vec<String>, Error> {
+        form_elems(d, form)?
+            .into_iter()
+            .map(|v| variable_name(&v).ok_or_else(|| bad_syntax(form)))
+            .collect()
+    }
+
+    fn lambda(&mut self, params: &Value, body: &[Value], form: &Value) -> Result<BExpr, Error> {
+        let params = self.symbols(params, form)?;
+
+        let outer = self.locals.len();
+        self.locals.extend(params.iter().cloned());
+        self.depth += 1;
+        let body = self.body(body, form);
+        self.depth -= 1;
+        self.locals.truncate(outer);
+
+        Ok(BExpr::Lam(params, body?, None))
+    }
+
+    fn body_expr(&mut self, d: &Value) -> Result<BExprBodyExpr, Error> {
+        if define_name(d).is_none() {
+            return Ok(BExprBodyExpr::Expr(self.expr(d)?));
+        }
+
+        match &form_elems(d, d)?[1..] {
+            [Value::Cons(_, params), body @ ..] => Ok(BExprBodyExpr::Def(
+                define_name(d).unwrap(),
+                self.lambda(params, body, d)?,
+            )),
+            [_, e] => Ok(BExprBodyExpr::Def(define_name(d).unwrap(), self.expr(e)?)),
+            _ => Err(bad_syntax(d)),
+        }
+    }
+
+    /// The forms of a lambda or `let` body, whose defines are local to it.
+    fn body(&mut self, forms: &[Value], form: &Value) -> Result<BExprBody, Error> {
+        let (last, init) = forms.split_last().ok_or_else(|| bad_syntax(form))?;
+
+        let outer = self.locals.len();
+        self.locals.extend(init.iter().filter_map(define_name));
+
+        let init = init
+            .iter()
+            .map(|f| self.body_expr(f))
+            .collect::<Result<_, _>>();
+        let last = self.body_expr(last);
+        self.locals.truncate(outer);
+
+        match last? {
+            BExprBodyExpr::Expr(e) => Ok(BExprBody(init?, Rc::new(e))),
+            BExprBodyExpr::Def(..) => Err(bad_syntax(form)),
+        }
+    }
+
+    fn expr(&mut self, d: &Value) -> Result<BExpr, Error> {
+        let f = match d {
+            Value::Symbol(s) if &**s == "null" => return Ok(BExpr::Lit(Literal::Void)),
+            Value::Symbol(s) if is_builtin_name(s) => {
+                return Ok(BExpr::BuiltinIdent(s.to_string()))
+            }
+            Value::Symbol(_) | Value::Uninterned(..) => {
+                return Ok(BExpr::Var(self.var(&variable_name(d).unwrap())))
+            }
+            Value::Void => return Ok(BExpr::Lit(Literal::Void)),
+            Value::Int(i) => return Ok(BExpr::Lit(Literal::Int(*i))),
+            Value::Bool(b) => return Ok(BExpr::Lit(Literal::Bool(*b))),
+            Value::Cons(f, _) => f,
+            v => return Ok(self.quote(v)),
+        };
+
+        let elems = form_elems(d, d)?;
+        let keyword = match f.as_ref() {
+            Value::Symbol(s) => &**s,
+            _ => "",
+        };
+
+        let e = match (keyword, &elems[1..]) {
+            ("quote", [v]) => self.quote(v),
+            ("if", [c, t]) => BExpr::If(
+                Rc::new(self.expr(c)?),
+                Rc::new(self.expr(t)?),
+                Rc::new(BExpr::Lit(Literal::Void)),
+            ),
+            ("if", [c, t, e]) => BExpr::If(
+                Rc::new(self.expr(c)?),
+                Rc::new(self.expr(t)?),
+                Rc::new(self.expr(e)?),
+            ),
+            ("set!", [name, e]) if variable_name(name).is_some() => BExpr::Set(
+                self.var(&variable_name(name).unwrap()),
+                Rc::new(self.expr(e)?),
+            ),
+            ("lambda", [params, body @ ..]) => self.lambda(params, body, d)?,
+            ("let", [bindings, body @ ..]) => {
+                let bindings = form_elems(bindings, d)?
+                    .iter()
+                    .map(|b| match &form_elems(b, d)?[..] {
+                        [name, e] => Ok((
+                            variable_name(name).ok_or_else(|| bad_syntax(d))?,
+                            self.expr(e)?,
+                        )),
+                        _ => Err(bad_syntax(d)),
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                let outer = self.locals.len();
+                self.locals
+                    .extend(bindings.iter().map(|(name, _)| name.clone()));
+                let body = self.body(body, d);
+                self.locals.truncate(outer);
+
+                BExpr::Let(bindings, body?)
+            }
+            ("quote" | "if" | "set!" | "lambda" | "let" | "define", _) => {
+                return Err(bad_syntax(d))
+            }
+            (_, args) => BExpr::App(
+                Rc::new(self.expr(f)?),
+                args.iter()
+                    .map(|a| self.expr(a))
+                    .collect::<Result<_, _>>()?,
+            ),
+        };
+
+        Ok(e)
+    }
+}
+
+/// What `exit` stops the program with, the status it is to exit with.
+#[derive(Debug)]
+pub struct Exit(pub i32);
+
+impl fmt::Display for Exit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "exit with status {}", self.0)
+    }
+}
+
+impl Fail for Exit {}
+
+pub struct InterpCtx<'a> {
+    out: &'a mut dyn Write,
+    globals: Env,
+    stdin_port: Rc<RefCell<Port>>,
+    stdout_port: Rc<RefCell<Port>>,
+    /// Set by `raise` as it unwinds to the nearest `with-exception-handler`
+    pub(crate) raised: Option<Value>,
+    /// The line of the lambda whose body is being evaluated, the lambdas made
+    /// for a body or a `let` have none and keep the line of the one around them
+    line: Option<usize>,
+    /// What `command-line` gives, the program's name and its arguments
+    command_line: Vec<String>,
+    /// The globals of the interaction environment, defined by `eval` or typed
+    /// into the REPL
+    interaction: HashMap<String, FreeVar<String>>,
+    /// How many uninterned symbols have been made, which number them
+    uninterned: usize,
+    pub(crate) dynamic: Dynamic,
+}
+
+impl<'a> InterpCtx<'a> {
+    pub fn new(out: &'a mut dyn Write) -> Self {
+        Self {
+            out,
+            globals: Env::default(),
+            stdin_port: Rc::new(RefCell::new(Port::Stdin { fold_case: false })),
+            stdout_port: Rc::new(RefCell::new(Port::Stdout)),
+            raised: None,
+            line: None,
+            command_line: Vec::new(),
+            interaction: HashMap::new(),
+            uninterned: 0,
+            dynamic: Dynamic::default(),
+        }
+    }
+
+    /// Give the program the name and arguments `command-line` returns.
+    pub fn set_command_line(&mut self, command_line: Vec<String>) {
+        self.command_line = command_line;
+    }
+
+    pub(crate) fn apply(&mut self, f: Value, a: Value) -> Result<Value, Error> {
+        match f {
+            Value::Closure(c) => {
+                let line = self.line;
+                self.line = c.line.or(line);
+                let result = self.eval_in(c.body.clone(), &c.env.extend(c.param.clone(), a));
+                self.line = line;
+                result
+            }
+            Value::Builtin(name, mut args) => {
+                args.push(a);
+                self.apply_builtin(name, args)
+            }
+            Value::Compiled(c) => vm::apply(self, c, a),
+            Value::Parameter(p) => Ok(self
+                .dynamic
+                .parameters
+                .iter()
+                .rev()
+                .find(|(q, _)| Rc::ptr_eq(&p, q))
+                .map_or_else(|| p.value.clone(), |(_, v)| v.clone())),
+            Value::CaseLambda(clauses) => {
+                let clause = direct_clause(&clauses)?;
+                self.apply(clause, a)
+            }
+            v => Err(format_err!(
+                "Called object was not a closure but was: {}",
+                v
+            )),
+        }
+    }
+
+    fn raise(&mut self, v: Value) -> Result<Value, Error> {
+        let err = format_err!("Uncaught exception: {}", v);
+        self.raised = Some(v);
+        Err(err)
+    }
+
+    fn port_write(&mut self, port: &RefCell<Port>, s: &str) -> Result<(), Error> {
+        match &mut *port.borrow_mut() {
+            Port::Stdout => self.out.write_all(s.as_bytes())?,
+            Port::Output(f) => f.write_all(s.as_bytes())?,
+            _ => unreachable!("writing to an input port"),
+        }
+
+        Ok(())
+    }
+
+    pub fn eval(&mut self, expr: &Expr) -> Result<Value, Error> {
+        let globals = self.globals.clone();
+        self.eval_in(Rc::new(expr.clone()), &globals)
+    }
+
+    /// Add a global binding holding null, everything evaluated after this can
+    /// refer to it.
+    pub fn define(&mut self, var: FreeVar<String>) {
+        self.globals = self.globals.extend(var, Value::Void);
+    }
+
+    pub fn set_global(&mut self, var: &FreeVar<String>, val: Value) -> Result<(), Error> {
+        *self.globals.lookup(&Var::Free(var.clone()))?.borrow_mut() = val;
+        Ok(())
+    }
+
+    /// The global `name` of the interaction environment, defining it to be
+    /// null if it isn't yet.
+    pub fn declare(&mut self, name: &str) -> FreeVar<String> {
+        if let Some(var) = self.interaction.get(name) {
+            return var.clone();
+        }
+
+        let var = FreeVar::fresh_named(name);
+        self.interaction.insert(name.to_owned(), var.clone());
+        self.define(var.clone());
+        var
+    }
+
+    pub fn interaction(&self) -> &HashMap<String, FreeVar<String>> {
+        &self.interaction
+    }
+
+    fn uninterned(&mut self, name: &str) -> Value {
+        self.uninterned += 1;
+        Value::Uninterned(name.into(), self.uninterned)
+    }
+
+    /// Evaluate the datum `d` given to `eval` at the toplevel of the
+    /// interaction environment. The globals its lambdas use are declared
+    /// first, so that they can call what a later `eval` defines.
+    fn eval_datum(&mut self, d: &Value) -> Result<Value, Error> {
+        let mut form = EvalForm::default();
+        let (name, e) = match form.body_expr(d)? {
+            BExprBodyExpr::Def(name, e) => (Some(name), e),
+            BExprBodyExpr::Expr(e) => (None, e),
+        };
+
+        let var = name.map(|n| self.declare(&n));
+        for free in &form.free {
+            self.declare(free);
+        }
+
+        let mut env = self.interaction.clone();
+        let mut globals = self.globals.clone();
+
+        for (name, v) in form.quoted {
+            let var = FreeVar::fresh_named(name.clone());
+            env.insert(name, var.clone());
+            globals = globals.extend(var, v);
+        }
+
+        let val = self.eval_in(Rc::new(e.into_expr_with_env(&env)?), &globals)?;
+
+        match var {
+            Some(var) => {
+                self.set_global(&var, val)?;
+                Ok(Value::Void)
+            }
+            None => Ok(val),
+        }
+    }
+
+    fn eval_atom(&mut self, atom: &Atom, env: &Env) -> Result<Value, Error> {
+        let val = match atom {
+            Atom::Var(v) => env.lookup(v)?.borrow().clone(),
+            Atom::Lit(Ignore(l)) => literal_value(l),
+            Atom::BuiltinIdent(Ignore(i)) => {
+                if builtin_arity(i).is_none() {
+                    return Err(format_err!("unknown builtin: {}", i));
+                }
+                Value::Builtin(i.as_str().into(), Vec::new())
+            }
+            Atom::Lam(s, Ignore(line)) => {
+                let (param, body) = s.clone().unbind();
+                Value::Closure(Rc::new(Closure {
+                    param: param.0,
+                    body,
+                    env: env.clone(),
+                    line: *line,
+                }))
+            }
+        };
+
+        Ok(val)
+    }
+
+    /// Evaluates `expr`, stopping the program with an error once its non-tail
+    /// calls have nearly used up the stack instead of overflowing it.
+    fn eval_in(&mut self, expr: Rc<Expr>, env: &Env) -> Result<Value, Error> {
+        if utils::stack_exhausted() {
+            return Err(match self.line {
+                Some(line) => format_err!("recursion too deep, in the lambda on line {}", line),
+                None => format_err!("recursion too deep"),
+            });
+        }
+
+        // the closures called in tail position replace the line until then
+        let line = self.line;
+        let result = self.eval_loop(expr, env);
+        self.line = line;
+
+        result
+    }
+
+    /// Expressions in tail position are evaluated by looping instead of
+    /// recursing, so that tail calls in the program don't grow the Rust stack.
+    fn eval_loop(&mut self, mut expr: Rc<Expr>, env: &Env) -> Result<Value, Error> {
+        let mut env = env.clone();
+
+        loop {
+            let (next_expr, next_env) = match expr.as_ref() {
+                Expr::Atom(a) => return self.eval_atom(a, &env),
+                Expr::Set(v, e) => {
+                    let val = self.eval_in(e.clone(), &env)?;
+                    *env.lookup(v)?.borrow_mut() = val;
+                    return Ok(Value::Void);
+                }
+                Expr::If(c, ift, iff) => {
+                    if self.eval_in(c.clone(), &env)?.is_truthy() {
+                        (ift.clone(), env)
+                    } else {
+                        (iff.clone(), env)
+                    }
+                }
+                Expr::App(f, a) => {
+                    let f = self.eval_in(f.clone(), &env)?;
+                    let a = self.eval_in(a.clone(), &env)?;
+
+                    match f {
+                        Value::Closure(c) => {
+                            self.line = c.line.or(self.line);
+                            (c.body.clone(), c.env.extend(c.param.clone(), a))
+                        }
+                        Value::Builtin(name, mut args) => {
+                            args.push(a);
+                            return self.apply_builtin(name, args);
+                        }
+                        // a program run by the vm giving eval its closures
+                        f => return self.apply(f, a),
+                    }
+                }
+            };
+
+            expr = next_expr;
+            env = next_env;
+        }
+    }
+
+    pub(crate) fn apply_builtin(
+        &mut self,
+        name: Rc<str>,
+        args: Vec<Value>,
+    ) -> Result<Value, Error> {
+        if args.len() < builtin_arity(&name).unwrap() {
+            return Ok(Value::Builtin(name, args));
+        }
+
+        let val = match (name.as_ref(), args.as_slice()) {
+            ("tostring", [v]) => Value::Str(v.to_string().into()),
+            (n, [_]) if n.starts_with("foreign ") => {
+                return Err(format_err!(
+                    "{} is a foreign function, which only compiled programs can call",
+                    Foreign::from_builtin_name(n).unwrap().name
+                ))
+            }
+            ("cons?" | "pair?", [v]) => Value::Bool(matches!(v, Value::Cons(_, _))),
+            ("null?", [v]) => Value::Bool(matches!(v, Value::Void)),
+            ("zero?", [v @ (Value::Int(_) | Value::Rational(..) | Value::Float(_))]) => {
+                num_cmp("=", v, &Value::Int(0))?
+            }
+            ("zero?", [_]) => return Err(format_err!("Argument to zero? was not a number")),
+            ("number?", [v]) => Value::Bool(matches!(
+                v,
+                Value::Int(_) | Value::Rational(..) | Value::Float(_)
+            )),
+            ("procedure?", [v]) => Value::Bool(matches!(
+                v,
+                Value::Closure(_)
+                    | Value::Builtin(..)
+                    | Value::Compiled(_)
+                    | Value::Parameter(_)
+                    | Value::CaseLambda(_)
+            )),
+            ("string?", [v]) => Value::Bool(matches!(v, Value::Str(_))),
+            ("boolean?", [v]) => Value::Bool(matches!(v, Value::Bool(_))),
+            ("car", [Value::Cons(car, _)]) => car.as_ref().clone(),
+            ("cdr", [Value::Cons(_, cdr)]) => cdr.as_ref().clone(),
+            ("car", [v]) | ("cdr", [v]) => {
+                return Err(format_err!("Argument to {} was not a cons: {}", name, v))
+            }
+            ("cons", [a, b]) => Value::Cons(Rc::new(a.clone()), Rc::new(b.clone())),
+            ("string-concat", [a, b]) => {
+                Value::Str(format!("{}{}", concat_part(a)?, concat_part(b)?).into())
+            }
+            ("string-chars" | "string->list", [v]) => prepend_all(
+                Value::Void,
+                string_arg(&name, v)?
+                    .bytes()
+                    .rev()
+                    .map(|c| Value::Int(c as i64)),
+            ),
+            ("string-length", [v]) => Value::Int(string_arg(&name, v)?.len() as i64),
+            ("string-ref", [v, i]) => {
+                let s = string_arg(&name, v)?.as_bytes();
+                Value::Int(s[index_arg(&name, i, s.len())?] as i64)
+            }
+            ("substring", [v, start, end]) => {
+                let s = string_arg(&name, v)?.as_bytes();
+                let (start, end) = match (start, end) {
+                    (Value::Int(start), Value::Int(end)) => (*start, *end),
+                    _ => return Err(format_err!("Index to substring not of integer type")),
+                };
+
+                if start < 0 || end < start || end as usize > s.len() {
+                    return Err(format_err!(
+                        "Range {} to {} out of range in substring of length {}",
+                        start,
+                        end,
+                        s.len()
+                    ));
+                }
+
+                Value::Str(String::from_utf8_lossy(&s[start as usize..end as usize]).into())
+            }
+            ("string-append", [a, b]) => {
+                Value::Str(format!("{}{}", string_arg(&name, a)?, string_arg(&name, b)?).into())
+            }
+            ("string=?", [a, b]) => Value::Bool(string_arg(&name, a)? == string_arg(&name, b)?),
+            ("string->number", [v]) => string_to_number(string_arg(&name, v)?)?,
+            ("number->string", [v @ (Value::Int(_) | Value::Rational(..) | Value::Float(_))]) => {
+                Value::Str(v.to_string().into())
+            }
+            ("number->string", [_]) => {
+                return Err(format_err!("Argument to number->string was not a number"))
+            }
+            ("ht-new" | "make-hash-table", [_]) => {
+                Value::HashTable(Rc::new(RefCell::new(HashTable::default())))
+            }
+            ("ht-set!" | "hash-table-set!", [Value::HashTable(ht), k, v]) => {
+                ht.borrow_mut().insert(k.clone(), v.clone());
+                Value::Void
+            }
+            ("ht-get", [Value::HashTable(ht), k]) => {
+                ht.borrow().get(k).cloned().unwrap_or(Value::Void)
+            }
+            ("ht-del!", [Value::HashTable(ht), k]) => Value::Bool(ht.borrow_mut().remove(k)),
+            ("hash-table?", [v]) => Value::Bool(matches!(v, Value::HashTable(_))),
+            ("hash-table-ref", [Value::HashTable(ht), k]) => match ht.borrow().get(k) {
+                Some(v) => v.clone(),
+                None => return Err(format_err!("Key {} not found in hash-table-ref", k)),
+            },
+            ("hash-table-ref/default", [Value::HashTable(ht), k, default]) => {
+                ht.borrow().get(k).unwrap_or(default).clone()
+            }
+            ("hash-table-delete!", [Value::HashTable(ht), k]) => {
+                ht.borrow_mut().remove(k);
+                Value::Void
+            }
+            ("hash-table->alist", [Value::HashTable(ht)]) => prepend_all(
+                Value::Void,
+                ht.borrow()
+                    .entries()
+                    .map(|(k, v)| Value::Cons(Rc::new(k.clone()), Rc::new(v.clone()))),
+            ),
+            (
+                "hash-table-ref"
+                | "hash-table-ref/default"
+                | "hash-table-set!"
+                | "hash-table-delete!"
+                | "hash-table->alist",
+                [_, ..],
+            ) => return Err(format_err!("Argument to {} was not a hash table", name)),
+            ("ht-keys", [Value::HashTable(ht)]) => {
+                prepend_all(Value::Void, ht.borrow().entries().map(|(k, _)| k.clone()))
+            }
+            // numbers aren't boxed in the interpreter, so `eq?` on them acts like `eqv?`,
+            // which is also what the runtime does for anything that fits in a fixnum
+            ("eq?", [a, b]) | ("eqv?", [a, b]) => Value::Bool(values_eqv(a, b)),
+            ("equal?", [a, b]) => Value::Bool(values_equal(a, b)),
+            ("make-vector", [k, fill]) => {
+                Value::Vector(Rc::new(RefCell::new(vec![
+                    fill.clone();
+                    length_arg(&name, k)?
+                ])))
+            }
+            ("vector-ref", [Value::Vector(v), i]) => {
+                let v = v.borrow();
+                v[index_arg(&name, i, v.len())?].clone()
+            }
+            ("vector-set!", [Value::Vector(v), i, x]) => {
+                let mut v = v.borrow_mut();
+                let i = index_arg(&name, i, v.len())?;
+                v[i] = x.clone();
+                Value::Void
+            }
+            ("vector-length", [Value::Vector(v)]) => Value::Int(v.borrow().len() as i64),
+            ("vector?", [v]) => Value::Bool(matches!(v, Value::Vector(_))),
+            ("vector->list", [Value::Vector(v)]) => {
+                prepend_all(Value::Void, v.borrow().iter().rev().cloned())
+            }
+            ("list->vector", [l]) => Value::Vector(Rc::new(RefCell::new(list_elems(&name, l)?))),
+            ("make-bytevector", [k, fill]) => Value::Bytevector(Rc::new(RefCell::new(vec![
+                    byte_arg(&name, fill)?;
+                    length_arg(&name, k)?
+                ]))),
+            ("bytevector-u8-ref", [Value::Bytevector(v), i]) => {
+                let v = v.borrow();
+                Value::Int(v[index_arg(&name, i, v.len())?] as i64)
+            }
+            ("bytevector-u8-set!", [Value::Bytevector(v), i, b]) => {
+                let mut v = v.borrow_mut();
+                let i = index_arg(&name, i, v.len())?;
+                v[i] = byte_arg(&name, b)?;
+                Value::Void
+            }
+            ("bytevector-length", [Value::Bytevector(v)]) => Value::Int(v.borrow().len() as i64),
+            ("bytevector?", [v]) => Value::Bool(matches!(v, Value::Bytevector(_))),
+            ("list->bytevector", [l]) => Value::Bytevector(Rc::new(RefCell::new(
+                list_elems(&name, l)?
+                    .iter()
+                    .map(|b| byte_arg(&name, b))
+                    .collect::<Result<_, _>>()?,
+            ))),
+            ("current-input-port", [_]) => Value::Port(self.stdin_port.clone()),
+            ("current-output-port", [_]) => Value::Port(self.stdout_port.clone()),
+            ("command-line", [_]) => prepend_all(
+                Value::Void,
+                self.command_line
+                    .iter()
+                    .rev()
+                    .map(|a| Value::Str(a.as_str().into())),
+            ),
+            // an integer is the exit status, #f is 1 and anything else 0
+            ("exit", [status]) => {
+                let status = match status {
+                    Value::Int(i) => *i as i32,
+                    Value::Bool(false) => 1,
+                    _ => 0,
+                };
+
+                return Err(Exit(status).into());
+            }
+            ("get-environment-variable", [var]) => {
+                match std::env::var_os(string_arg(&name, var)?) {
+                    Some(val) => Value::Str(val.to_string_lossy().into()),
+                    None => Value::Bool(false),
+                }
+            }
+            ("current-second", [_]) => Value::Float(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0.0, |d| d.as_secs_f64()),
+            ),
+            ("dump-heap", [_]) => {
+                return Err(format_err!(
+                    "dump-heap: only compiled programs have a heap to dump"
+                ))
+            }
+            ("open-input-file", [path]) => open_port(string_arg(&name, path)?, true)?,
+            ("open-output-file", [path]) => open_port(string_arg(&name, path)?, false)?,
+            // both reads give null once the end of the file is reached
+            ("read-char", [p]) => {
+                let mut buf = [0];
+                let n = match &mut *port_arg(&name, p, true)?.borrow_mut() {
+                    Port::Stdin { .. } => io::stdin().lock().read(&mut buf)?,
+                    Port::Input { reader, .. } => reader.read(&mut buf)?,
+                    _ => unreachable!(),
+                };
+
+                if n == 0 {
+                    Value::Void
+                } else {
+                    Value::Int(buf[0] as i64)
+                }
+            }
+            ("read-line", [p]) => {
+                let mut line = String::new();
+                let n = match &mut *port_arg(&name, p, true)?.borrow_mut() {
+                    Port::Stdin { .. } => io::stdin().lock().read_line(&mut line)?,
+                    Port::Input { reader, .. } => reader.read_line(&mut line)?,
+                    _ => unreachable!(),
+                };
+
+                if line.ends_with('\n') {
+                    line.pop();
+                }
+
+                if n == 0 {
+                    Value::Void
+                } else {
+                    Value::Str(line.into())
+                }
+            }
+            ("read", [p]) => match &mut *port_arg(&name, p, true)?.borrow_mut() {
+                Port::Stdin { fold_case } => read_datum(&mut io::stdin().lock(), fold_case)?,
+                Port::Input { reader, fold_case } => read_datum(reader, fold_case)?,
+                _ => unreachable!(),
+            },
+            ("make-parameter", [v, converter]) => Value::Parameter(Rc::new(Parameter {
+                value: v.clone(),
+                converter: converter.clone(),
+            })),
+            ("parameter-converter", [Value::Parameter(p)]) => p.converter.clone(),
+            ("parameter-push!", [Value::Parameter(p), v]) => {
+                self.dynamic.parameters.push((p.clone(), v.clone()));
+                Value::Void
+            }
+            ("parameter-pop!", [Value::Parameter(_)]) => {
+                self.dynamic.parameters.pop();
+                Value::Void
+            }
+            ("parameter-converter", [v])
+            | ("parameter-push!", [v, _])
+            | ("parameter-pop!", [v]) => {
+                return Err(format_err!("parameterize: {} is not a parameter", v))
+            }
+            ("make-case-lambda", [clauses]) => Value::CaseLambda(Rc::new(
+                list_elems(&name, clauses)?
+                    .into_iter()
+                    .map(|c| match c {
+                        Value::Cons(arity, clause) => match &*arity {
+                            Value::Int(n) => Ok((*n as usize, (*clause).clone())),
+                            _ => Err(format_err!("make-case-lambda: bad clause")),
+                        },
+                        _ => Err(format_err!("make-case-lambda: bad clause")),
+                    })
+                    .collect::<Result<_, Error>>()?,
+            )),
+            ("case-lambda-clause", [Value::CaseLambda(clauses), Value::Int(n)]) => {
+                case_lambda_clause(clauses, *n as usize)?
+            }
+            ("case-lambda-clause", [f, _]) => f.clone(),
+            // the vm switches threads itself, at its toplevel
+            ("spawn" | "yield", [_]) => {
+                return Err(format_err!(
+                    "{} needs the continuation of the call, which the interpreter doesn't have: \
+                     compile the program or run it with the bytecode target",
+                    name
+                ))
+            }
+            ("interaction-environment", [_]) => Value::Environment,
+            ("eval", [d, Value::Environment]) => self.eval_datum(d)?,
+            ("interaction-define!", [Value::Symbol(name), v]) => {
+                let var = self.declare(name);
+                self.set_global(&var, v.clone())?;
+                Value::Void
+            }
+            ("eval", [_, e]) => {
+                return Err(format_err!(
+                    "Argument to eval was not an environment: {}",
+                    e
+                ))
+            }
+            ("display" | "write", [v, p]) => {
+                let text = Printer::print(v, &*name == "write");
+                self.port_write(port_arg(&name, p, false)?, &text)?;
+                Value::Void
+            }
+            ("write-string", [s, p]) => {
+                let s = string_arg(&name, s)?;
+                self.port_write(port_arg(&name, p, false)?, s)?;
+                Value::Void
+            }
+            ("newline", [p]) => {
+                self.port_write(port_arg(&name, p, false)?, "\n")?;
+                Value::Void
+            }
+            ("close-port", [Value::Port(p)]) => {
+                let input = p.borrow().is_input();
+                if let Port::Stdout = *p.borrow() {
+                    self.out.flush()?;
+                }
+                *p.borrow_mut() = Port::Closed { input };
+                Value::Void
+            }
+            ("close-port", [_]) => {
+                return Err(format_err!("Argument to close-port was not a port"))
+            }
+            // the handler's result is returned from with-exception-handler
+            ("with-exception-handler", [handler, thunk]) => {
+                match self.apply(thunk.clone(), Value::Void) {
+                    Ok(v) => v,
+                    Err(e) => match self.raised.take() {
+                        Some(raised) => self.apply(handler.clone(), raised)?,
+                        None => return Err(e),
+                    },
+                }
+            }
+            // only raise leaves a dynamic-wind early, and it runs the after thunk
+            // as it unwinds past it, before the handler is called
+            ("dynamic-wind", [before, thunk, after]) => {
+                self.apply(before.clone(), Value::Void)?;
+
+                match self.apply(thunk.clone(), Value::Void) {
+                    Ok(v) => {
+                        self.apply(after.clone(), Value::Void)?;
+                        v
+                    }
+                    Err(e) => match self.raised.take() {
+                        Some(raised) => {
+                            self.apply(after.clone(), Value::Void)?;
+                            self.raised = Some(raised);
+                            return Err(e);
+                        }
+                        None => return Err(e),
+                    },
+                }
+            }
+            ("delay", [thunk]) => {
+                Value::Promise(Rc::new(RefCell::new(Promise::Pending(thunk.clone()))))
+            }
+            ("make-promise", [v @ Value::Promise(_)]) => v.clone(),
+            ("make-promise", [v]) => {
+                Value::Promise(Rc::new(RefCell::new(Promise::Done(v.clone()))))
+            }
+            ("promise?", [v]) => Value::Bool(matches!(v, Value::Promise(_))),
+            // the thunk may have forced the promise itself, in which case the
+            // first value to arrive is the one that is kept
+            ("force", [Value::Promise(p)]) => {
+                let thunk = match &*p.borrow() {
+                    Promise::Done(v) => return Ok(v.clone()),
+                    Promise::Pending(thunk) => thunk.clone(),
+                };
+
+                let v = self.apply(thunk, Value::Void)?;
+                let mut p = p.borrow_mut();
+
+                match &*p {
+                    Promise::Done(v) => v.clone(),
+                    Promise::Pending(_) => {
+                        *p = Promise::Done(v.clone());
+                        v
+                    }
+                }
+            }
+            ("force", [v]) => v.clone(),
+            ("make-record-type", [Value::Symbol(name), Value::Int(len)]) => {
+                Value::RecordType(Rc::new(RecordType {
+                    name: name.clone(),
+                    len: *len as usize,
+                }))
+            }
+            ("make-record", [Value::RecordType(t)]) => {
+                Value::Record(t.clone(), Rc::new(RefCell::new(vec![Value::Void; t.len])))
+            }
+            ("record?", [Value::RecordType(t), v]) => {
+                Value::Bool(matches!(v, Value::Record(vt, _) if Rc::ptr_eq(t, vt)))
+            }
+            ("record-check", [Value::RecordType(t), Value::Symbol(proc_name), v]) => match v {
+                Value::Record(vt, _) if Rc::ptr_eq(t, vt) => v.clone(),
+                _ => {
+                    return Err(format_err!(
+                        "Argument to {} was not a record of type {}",
+                        proc_name,
+                        t.name
+                    ))
+                }
+            },
+            ("record-ref", [Value::Record(_, fields), Value::Int(idx)]) => {
+                fields.borrow()[*idx as usize].clone()
+            }
+            ("record-set!", [r @ Value::Record(_, fields), Value::Int(idx), v]) => {
+                fields.borrow_mut()[*idx as usize] = v.clone();
+                r.clone()
+            }
+            ("raise", [v]) => return self.raise(v.clone()),
+            ("error", [m, i]) => {
+                return self.raise(Value::Condition(Rc::new(m.clone()), Rc::new(i.clone())))
+            }
+            ("error-object?", [v]) => Value::Bool(matches!(v, Value::Condition(..))),
+            ("error-object-message", [Value::Condition(m, _)]) => m.as_ref().clone(),
+            ("error-object-irritants", [Value::Condition(_, i)]) => i.as_ref().clone(),
+            ("error-object-message" | "error-object-irritants", [_]) => {
+                return Err(format_err!("Argument to {} was not an error object", name))
+            }
+            ("symbol->string", [Value::Symbol(s) | Value::Uninterned(s, _)]) => {
+                Value::Str(s.clone())
+            }
+            ("string->symbol", [Value::Str(s)]) => Value::Symbol(s.clone()),
+            ("string->uninterned-symbol", [Value::Str(s)]) => self.uninterned(s),
+            // `(gensym)` is given null and names its symbol g and a number, a
+            // string or symbol given is the prefix instead
+            ("gensym", [prefix]) => {
+                let prefix = match prefix {
+                    Value::Void => "g",
+                    Value::Str(s) | Value::Symbol(s) | Value::Uninterned(s, _) => s,
+                    _ => return Err(format_err!("Argument to gensym was not a string or symbol")),
+                };
+                let name = format!("{}{}", prefix, self.uninterned + 1);
+                self.uninterned(&name)
+            }
+            ("symbol?", [v]) => Value::Bool(matches!(v, Value::Symbol(_) | Value::Uninterned(..))),
+            ("+" | "-" | "*" | "/", [a, b]) => num_binop(&name, a, b)?,
+            ("<" | "<=" | ">" | ">=" | "=", [a, b]) => num_cmp(&name, a, b)?,
+            ("%" | "^", [a, b]) => int_binop(&name, a, b)?,
+            (_, args) => {
+                return Err(format_err!(
+                    "Unexpected arguments to {}: {}",
+                    name,
+                    args.iter()
+                        .map(|a| a.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                ))
+            }
+        };
+
+        Ok(val)
+    }
+}
+
+pub fn eval(expr: &Expr, out: &mut dyn Write) -> Result<Value, Error> {
+    InterpCtx::new(out).eval(expr)
+}
@@ -0,0 +1,1411 @@
+use failure::{format_err, Error};
+use moniker::{Binder, FreeVar, Ignore, Var};
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::base_expr::{BExpr, BExprBody, BExprBodyExpr};
+use crate::cdsl::{CDecl, CExpr, CStmt, CType, ToCDC};
+use crate::expr::{Atom, Expr};
+use crate::flat_expr::FExpr;
+use crate::lifted_expr::{EnvRepr, LExpr, LambdaParams, LiftedLambda, Program};
+use crate::literals::Literal;
+use crate::shrink::Node;
+use crate::utils::open_scope;
+
+#[derive(Debug, Clone)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Arr(Vec<Json>),
+    Obj(Vec<(String, Json)>),
+}
+
+pub trait ToJson {
+    fn to_json(&self) -> Json;
+}
+
+/// Reads back what `ToJson` wrote, so a tool can give the compiler an IR it
+/// has looked at or changed.
+pub trait FromJson: Sized {
+    fn from_json(json: &Json) -> Result<Self, Error>;
+}
+
+impl Json {
+    /// An object describing one node of an IR, `kind` names the variant.
+    fn node(kind: &str, fields: Vec<(&str, Json)>) -> Json {
+        Json::obj(
+            std::iter::once(("kind", Json::str(kind)))
+                .chain(fields)
+                .collect(),
+        )
+    }
+
+    pub fn obj(fields: Vec<(&str, Json)>) -> Json {
+        Json::Obj(fields.into_iter().map(|(k, v)| (k.to_owned(), v)).collect())
+    }
+
+    pub fn str(s: impl Into<String>) -> Json {
+        Json::Str(s.into())
+    }
+
+    /// The field `key` of an object.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Obj(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Json::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Json::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_arr(&self) -> Option<&[Json]> {
+        match self {
+            Json::Arr(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Read the field `key` of an object, which has to be there.
+    pub fn read<T: FromJson>(&self, key: &str) -> Result<T, Error> {
+        T::from_json(self.field(key)?)
+    }
+
+    fn field(&self, key: &str) -> Result<&Json, Error> {
+        self.get(key)
+            .ok_or_else(|| format_err!("invalid IR: missing field {}", key))
+    }
+
+    /// The variant of the IR node this is.
+    fn kind(&self) -> Result<String, Error> {
+        self.read("kind")
+    }
+
+    /// Read a json value, as the language server does its messages.
+    pub fn parse(text: &str) -> Result<Json, Error> {
+        let mut reader = Reader {
+            text: text.as_bytes(),
+            at: 0,
+        };
+        let value = reader.value()?;
+
+        reader.skip_whitespace();
+        if reader.at < text.len() {
+            return Err(reader.error("trailing characters"));
+        }
+
+        Ok(value)
+    }
+
+    fn arr<'a, T: ToJson + 'a>(items: impl IntoIterator<Item = &'a T>) -> Json {
+        Json::Arr(items.into_iter().map(ToJson::to_json).collect())
+    }
+}
+
+fn write_str(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Int(i) => write!(f, "{}", i),
+            // json has no representation of these
+            Json::Float(v) if !v.is_finite() => write_str(f, &v.to_string()),
+            Json::Float(v) => write!(f, "{:?}", v),
+            Json::Str(s) => write_str(f, s),
+            Json::Arr(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                f.write_char(']')
+            }
+            Json::Obj(fields) => {
+                f.write_char('{')?;
+                for (i, (k, v)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_str(f, k)?;
+                    write!(f, ":{}", v)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+struct Reader<'a> {
+    text: &'a [u8],
+    at: usize,
+}
+
+impl Reader<'_> {
+    fn error(&self, what: &str) -> Error {
+        format_err!("invalid json at byte {}: {}", self.at, what)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.text.get(self.at).is_some_and(u8::is_ascii_whitespace) {
+            self.at += 1;
+        }
+    }
+
+    fn eat(&mut self, expected: &str) -> Result<(), Error> {
+        if self.text[self.at..].starts_with(expected.as_bytes()) {
+            self.at += expected.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {}", expected)))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, Error> {
+        self.skip_whitespace();
+
+        match self.text.get(self.at) {
+            Some(b'n') => self.eat("null").map(|_| Json::Null),
+            Some(b't') => self.eat("true").map(|_| Json::Bool(true)),
+            Some(b'f') => self.eat("false").map(|_| Json::Bool(false)),
+            Some(b'"') => self.string().map(Json::Str),
+            Some(b'[') => {
+                self.at += 1;
+                let mut items = Vec::new();
+
+                self.skip_whitespace();
+                if self.eat("]").is_ok() {
+                    return Ok(Json::Arr(items));
+                }
+
+                loop {
+                    items.push(self.value()?);
+                    self.skip_whitespace();
+
+                    if self.eat("]").is_ok() {
+                        return Ok(Json::Arr(items));
+                    }
+                    self.eat(",")?;
+                }
+            }
+            Some(b'{') => {
+                self.at += 1;
+                let mut fields = Vec::new();
+
+                self.skip_whitespace();
+                if self.eat("}").is_ok() {
+                    return Ok(Json::Obj(fields));
+                }
+
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string()?;
+                    self.skip_whitespace();
+                    self.eat(":")?;
+                    fields.push((key, self.value()?));
+                    self.skip_whitespace();
+
+                    if self.eat("}").is_ok() {
+                        return Ok(Json::Obj(fields));
+                    }
+                    self.eat(",")?;
+                }
+            }
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, Error> {
+        let start = self.at;
+
+        while self
+            .text
+            .get(self.at)
+            .is_some_and(|c| c.is_ascii_digit() || b"+-.eE".contains(c))
+        {
+            self.at += 1;
+        }
+
+        // only ascii was taken
+        let text = std::str::from_utf8(&self.text[start..self.at]).unwrap();
+
+        text.parse()
+            .map(Json::Int)
+            .or_else(|_| text.parse().map(Json::Float))
+            .map_err(|_| self.error("invalid number"))
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        self.eat("\"")?;
+        let mut bytes = Vec::new();
+
+        loop {
+            let c = *self
+                .text
+                .get(self.at)
+                .ok_or_else(|| self.error("unterminated string"))?;
+            self.at += 1;
+
+            match c {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = match self.text.get(self.at) {
+                        Some(b'u') => {
+                            self.at += 1;
+                            let mut c = self.hex4()?;
+
+                            // the high half of a character outside the basic
+                            // plane, the low half follows
+                            if (0xd800..0xdc00).contains(&c) {
+                                self.eat("\\u")?;
+                                let low = self.hex4()?.wrapping_sub(0xdc00);
+
+                                if low >= 0x400 {
+                                    return Err(self.error("invalid escape"));
+                                }
+                                c = 0x10000 + ((c - 0xd800) << 10) + low;
+                            }
+
+                            let c =
+                                char::from_u32(c).ok_or_else(|| self.error("invalid escape"))?;
+                            bytes.extend(c.to_string().bytes());
+                            continue;
+                        }
+                        Some(b'n') => b'\n',
+                        Some(b'r') => b'\r',
+                        Some(b't') => b'\t',
+                        Some(b'b') => 8,
+                        Some(b'f') => 12,
+                        Some(&c @ (b'"' | b'\\' | b'/')) => c,
+                        _ => return Err(self.error("invalid escape")),
+                    };
+
+                    self.at += 1;
+                    bytes.push(escaped);
+                }
+                c => bytes.push(c),
+            }
+        }
+
+        String::from_utf8(bytes).map_err(|_| self.error("invalid utf-8"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let digits = self
+            .text
+            .get(self.at..self.at + 4)
+            .and_then(|d| std::str::from_utf8(d).ok())
+            .and_then(|d| u32::from_str_radix(d, 16).ok())
+            .ok_or_else(|| self.error("invalid escape"))?;
+
+        self.at += 4;
+        Ok(digits)
+    }
+}
+
+impl<T: ToJson> ToJson for std::rc::Rc<T> {
+    fn to_json(&self) -> Json {
+        (**self).to_json()
+    }
+}
+
+impl<T: ToJson> ToJson for std::sync::Arc<T> {
+    fn to_json(&self) -> Json {
+        (**self).to_json()
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> Json {
+        Json::str(self.as_str())
+    }
+}
+
+impl ToJson for FreeVar<String> {
+    fn to_json(&self) -> Json {
+        Json::str(format!("{}", self))
+    }
+}
+
+impl ToJson for Var<String> {
+    fn to_json(&self) -> Json {
+        Json::str(format!("{}", self))
+    }
+}
+
+impl ToJson for Binder<String> {
+    fn to_json(&self) -> Json {
+        self.0.to_json()
+    }
+}
+
+impl ToJson for Literal {
+    fn to_json(&self) -> Json {
+        match self {
+            Literal::String(s) => Json::node("string", vec![("value", Json::str(s.as_str()))]),
+            Literal::Int(i) => Json::node("int", vec![("value", Json::Int(*i))]),
+            Literal::Rational(n, d) => Json::node(
+                "rational",
+                vec![("num", Json::Int(*n)), ("den", Json::Int(*d))],
+            ),
+            Literal::Float(v) => Json::node("float", vec![("value", Json::Float(*v))]),
+            Literal::Symbol(s) => Json::node("symbol", vec![("value", Json::str(s.as_str()))]),
+            Literal::Bool(b) => Json::node("bool", vec![("value", Json::Bool(*b))]),
+            Literal::Void => Json::node("void", vec![]),
+            Literal::Quoted(items) => Json::node(
+                "quoted",
+                vec![(
+                    "items",
+                    Json::Arr(items.iter().map(|l| l.to_json()).collect()),
+                )],
+            ),
+        }
+    }
+}
+
+impl ToJson for BExprBody {
+    fn to_json(&self) -> Json {
+        let items = self.0.iter().map(|e| match e {
+            BExprBodyExpr::Def(n, e) => Json::node(
+                "define",
+                vec![("name", Json::str(n.as_str())), ("value", e.to_json())],
+            ),
+            BExprBodyExpr::Expr(e) => e.to_json(),
+        });
+
+        Json::Arr(items.chain(std::iter::once(self.1.to_json())).collect())
+    }
+}
+
+impl ToJson for BExpr {
+    fn to_json(&self) -> Json {
+        match self {
+            BExpr::Var(n) => Json::node("var", vec![("name", Json::str(n.as_str()))]),
+            BExpr::Lit(l) => Json::node("lit", vec![("value", l.to_json())]),
+            BExpr::BuiltinIdent(n) => Json::node("builtin", vec![("name", Json::str(n.as_str()))]),
+            BExpr::If(c, ift, iff) => Json::node(
+                "if",
+                vec![
+                    ("cond", c.to_json()),
+                    ("then", ift.to_json()),
+                    ("else", iff.to_json()),
+                ],
+            ),
+            BExpr::Set(n, e) => Json::node(
+                "set",
+                vec![("name", Json::str(n.as_str())), ("value", e.to_json())],
+            ),
+            BExpr::Let(bindings, body) => Json::node(
+                "let",
+                vec![
+                    (
+                        "bindings",
+                        Json::Arr(
+                            bindings
+                                .iter()
+                                .map(|(n, e)| Json::Arr(vec![Json::str(n.as_str()), e.to_json()]))
+                                .collect(),
+                        ),
+                    ),
+                    ("body", body.to_json()),
+                ],
+            ),
+            BExpr::Lam(params, body, line) => Json::node(
+                "lambda",
+                vec![
+                    ("params", Json::arr(params)),
+                    ("body", body.to_json()),
+                    ("line", line.map_or(Json::Null, |l| Json::Int(l as i64))),
+                ],
+            ),
+            BExpr::CaseLam(clauses) => {
+                Json::node("case-lambda", vec![("clauses", Json::arr(clauses))])
+            }
+            BExpr::App(f, args) => {
+                Json::node("app", vec![("fn", f.to_json()), ("args", Json::arr(args))])
+            }
+        }
+    }
+}
+
+impl ToJson for Atom {
+    fn to_json(&self) -> Json {
+        match self {
+            Atom::Var(v) => Json::node("var", vec![("name", v.to_json())]),
+            Atom::Lit(Ignore(l)) => Json::node("lit", vec![("value", l.to_json())]),
+            Atom::BuiltinIdent(Ignore(n)) => {
+                Json::node("builtin", vec![("name", Json::str(n.as_str()))])
+            }
+            Atom::Lam(s, Ignore(line)) => {
+                let (param, body) = s.clone().unbind();
+                Json::node(
+                    "lambda",
+                    vec![
+                        ("param", param.to_json()),
+                        ("body", body.to_json()),
+                        ("line", line.map_or(Json::Null, |l| Json::Int(l as i64))),
+                    ],
+                )
+            }
+        }
+    }
+}
+
+impl ToJson for Expr {
+    fn to_json(&self) -> Json {
+        match self {
+            Expr::Atom(a) => a.to_json(),
+            Expr::If(c, ift, iff) => Json::node(
+                "if",
+                vec![
+                    ("cond", c.to_json()),
+                    ("then", ift.to_json()),
+                    ("else", iff.to_json()),
+                ],
+            ),
+            Expr::Set(v, e) => {
+                Json::node("set", vec![("name", v.to_json()), ("value", e.to_json())])
+            }
+            Expr::App(f, a) => Json::node("app", vec![("fn", f.to_json()), ("arg", a.to_json())]),
+        }
+    }
+}
+
+impl ToJson for FExpr {
+    fn to_json(&self) -> Json {
+        match self {
+            FExpr::If(c, ift, iff) => Json::node(
+                "if",
+                vec![
+                    ("cond", c.to_json()),
+                    ("then", ift.to_json()),
+                    ("else", iff.to_json()),
+                ],
+            ),
+            FExpr::LamOne(s) => {
+                let (param, body) = s.clone().unbind();
+                Json::node(
+                    "lambda",
+                    vec![("params", Json::arr(&[param])), ("body", body.to_json())],
+                )
+            }
+            FExpr::LamTwo(s, Ignore(line)) => {
+                let (param0, body) = s.clone().unbind();
+                let (param1, body) = body.unbind();
+                Json::node(
+                    "lambda",
+                    vec![
+                        ("params", Json::arr(&[param0, param1])),
+                        ("body", body.to_json()),
+                        ("line", line.map_or(Json::Null, |l| Json::Int(l as i64))),
+                    ],
+                )
+            }
+            FExpr::Var(v) => Json::node("var", vec![("name", v.to_json())]),
+            FExpr::Lit(Ignore(l)) => Json::node("lit", vec![("value", l.to_json())]),
+            FExpr::BuiltinIdent(Ignore(n)) => {
+                Json::node("builtin", vec![("name", Json::str(n.as_str()))])
+            }
+            FExpr::SetThen(v, e, c) => Json::node(
+                "set_then",
+                vec![
+                    ("name", v.to_json()),
+                    ("value", e.to_json()),
+                    ("then", c.to_json()),
+                ],
+            ),
+            FExpr::CallOne(f, a) => Json::node(
+                "call",
+                vec![("fn", f.to_json()), ("args", Json::Arr(vec![a.to_json()]))],
+            ),
+            FExpr::CallTwo(f, a, k) => Json::node(
+                "call",
+                vec![
+                    ("fn", f.to_json()),
+                    ("args", Json::Arr(vec![a.to_json(), k.to_json()])),
+                ],
+            ),
+            FExpr::IntOp(Ignore(op), a, b) => Json::node(
+                "int_op",
+                vec![
+                    ("op", Json::str(op.as_str())),
+                    ("args", Json::Arr(vec![a.to_json(), b.to_json()])),
+                ],
+            ),
+        }
+    }
+}
+
+impl ToJson for LExpr {
+    fn to_json(&self) -> Json {
+        match self {
+            LExpr::Var(v) => Json::node("var", vec![("name", v.to_json())]),
+            LExpr::Lit(Ignore(l)) => Json::node("lit", vec![("value", l.to_json())]),
+            LExpr::BuiltinIdent(Ignore(n)) => {
+                Json::node("builtin", vec![("name", Json::str(n.as_str()))])
+            }
+            LExpr::SetThen(v, e, c) => Json::node(
+                "set_then",
+                vec![
+                    ("name", v.to_json()),
+                    ("value", e.to_json()),
+                    ("then", c.to_json()),
+                ],
+            ),
+            LExpr::If(c, ift, iff) => Json::node(
+                "if",
+                vec![
+                    ("cond", c.to_json()),
+                    ("then", ift.to_json()),
+                    ("else", iff.to_json()),
+                ],
+            ),
+            LExpr::Lifted(Ignore(id)) => Json::node("lifted", vec![("id", Json::Int(*id as i64))]),
+            LExpr::IntOp(Ignore(op), a, b) => Json::node(
+                "int_op",
+                vec![
+                    ("op", Json::str(op.as_str())),
+                    ("args", Json::Arr(vec![a.to_json(), b.to_json()])),
+                ],
+            ),
+            LExpr::CallOne(f, a) => Json::node(
+                "call",
+                vec![("fn", f.to_json()), ("args", Json::Arr(vec![a.to_json()]))],
+            ),
+            LExpr::CallTwo(f, a, k) => Json::node(
+                "call",
+                vec![
+                    ("fn", f.to_json()),
+                    ("args", Json::Arr(vec![a.to_json(), k.to_json()])),
+                ],
+            ),
+        }
+    }
+}
+
+impl ToJson for LiftedLambda {
+    fn to_json(&self) -> Json {
+        let mut freevars = self
+            .freevars
+            .iter()
+            .map(ToJson::to_json)
+            .collect::<Vec<_>>();
+        freevars.sort_by_key(|v| v.to_string());
+
+        let env = match self.env_repr {
+            EnvRepr::Flat => "flat",
+            EnvRepr::Linked => "linked",
+        };
+
+        Json::Obj(vec![
+            ("id".to_owned(), Json::Int(self.id as i64)),
+            ("name".to_owned(), Json::str(self.name.as_str())),
+            (
+                "params".to_owned(),
+                match &self.params {
+                    LambdaParams::One(a) => Json::Arr(vec![a.to_json()]),
+                    LambdaParams::Two(a, b) => Json::Arr(vec![a.to_json(), b.to_json()]),
+                },
+            ),
+            ("freevars".to_owned(), Json::Arr(freevars)),
+            (
+                "parent".to_owned(),
+                self.parent.map_or(Json::Null, |p| Json::Int(p as i64)),
+            ),
+            ("env".to_owned(), Json::str(env)),
+            (
+                "line".to_owned(),
+                self.line.map_or(Json::Null, |l| Json::Int(l as i64)),
+            ),
+            (
+                "hint".to_owned(),
+                self.hint.as_ref().map_or(Json::Null, ToJson::to_json),
+            ),
+            ("body".to_owned(), self.body.to_json()),
+        ])
+    }
+}
+
+impl ToJson for Program {
+    fn to_json(&self) -> Json {
+        Json::obj(vec![
+            ("main", self.entry().to_json()),
+            ("lambdas", Json::arr(self.lambdas().values())),
+        ])
+    }
+}
+
+impl<'a> ToJson for CType<'a> {
+    fn to_json(&self) -> Json {
+        match self {
+            CType::Ptr(t) => Json::node("ptr", vec![("to", t.to_json())]),
+            CType::Arr(t, len) => Json::node(
+                "arr",
+                vec![
+                    ("of", t.to_json()),
+                    ("len", len.map_or(Json::Null, |l| Json::Int(l as i64))),
+                ],
+            ),
+            CType::Int { size, sign } => Json::node(
+                "int",
+                vec![
+                    ("size", Json::Int(*size as i64)),
+                    ("sign", Json::Bool(*sign)),
+                ],
+            ),
+            CType::Struct(n) => Json::node("struct", vec![("name", Json::str(n.as_ref()))]),
+            CType::Union(n) => Json::node("union", vec![("name", Json::str(n.as_ref()))]),
+            CType::Other(n) => Json::node("other", vec![("name", Json::str(n.as_ref()))]),
+            CType::Static(t) => Json::node("static", vec![("of", t.to_json())]),
+            CType::Weak(t) => Json::node("weak", vec![("of", t.to_json())]),
+            CType::Const(t) => Json::node("const", vec![("of", t.to_json())]),
+            CType::Void => Json::node("void", vec![]),
+        }
+    }
+}
+
+impl<'a> ToJson for CExpr<'a> {
+    fn to_json(&self) -> Json {
+        use self::CExpr::*;
+
+        match self {
+            BinOp { op, left, right } => Json::node(
+                "binop",
+                vec![
+                    ("op", Json::str(op.as_ref())),
+                    ("left", left.to_json()),
+                    ("right", right.to_json()),
+                ],
+            ),
+            PreUnOp { op, ex } => Json::node(
+                "pre_unop",
+                vec![("op", Json::str(op.as_ref())), ("ex", ex.to_json())],
+            ),
+            PostUnOp { op, ex } => Json::node(
+                "post_unop",
+                vec![("op", Json::str(op.as_ref())), ("ex", ex.to_json())],
+            ),
+            ArrIndexOp { index, expr } => Json::node(
+                "index",
+                vec![("expr", expr.to_json()), ("index", index.to_json())],
+            ),
+            Dot { expr, attr } => Json::node(
+                "dot",
+                vec![("expr", expr.to_json()), ("attr", Json::str(attr.as_ref()))],
+            ),
+            Arrow { expr, attr } => Json::node(
+                "arrow",
+                vec![("expr", expr.to_json()), ("attr", Json::str(attr.as_ref()))],
+            ),
+            FunCallOp { expr, params } => Json::node(
+                "call",
+                vec![("fn", expr.to_json()), ("args", Json::arr(params))],
+            ),
+            Cast { ex, typ } => {
+                Json::node("cast", vec![("type", typ.to_json()), ("ex", ex.to_json())])
+            }
+            // macro arguments can be types or expressions, so they are given as C
+            MacroCall { name, args } => Json::node(
+                "macro_call",
+                vec![
+                    ("name", Json::str(name.as_ref())),
+                    (
+                        "args",
+                        Json::Arr(args.iter().map(|a| Json::str(a.export())).collect()),
+                    ),
+                ],
+            ),
+            If { cond, ift, iff } => Json::node(
+                "if",
+                vec![
+                    ("cond", cond.to_json()),
+                    ("then", ift.to_json()),
+                    ("else", iff.to_json()),
+                ],
+            ),
+            InitList(items) => Json::node("init_list", vec![("items", Json::arr(items))]),
+            Ident(n) => Json::node("ident", vec![("name", Json::str(n.as_ref()))]),
+            LitStr(s) => Json::node("str", vec![("value", Json::str(s.as_ref()))]),
+            LitUInt(i) => Json::node("uint", vec![("value", Json::Int(*i as i64))]),
+            LitIInt(i) => Json::node("int", vec![("value", Json::Int(*i as i64))]),
+            LitFloat(v) => Json::node("float", vec![("value", Json::Float(*v))]),
+        }
+    }
+}
+
+impl<'a> ToJson for CStmt<'a> {
+    fn to_json(&self) -> Json {
+        match self {
+            CStmt::If { cond, ift, iff } => Json::node(
+                "if",
+                vec![
+                    ("cond", cond.to_json()),
+                    ("then", ift.to_json()),
+                    ("else", iff.to_json()),
+                ],
+            ),
+            CStmt::While { cond, body } => Json::node(
+                "while",
+                vec![("cond", cond.to_json()), ("body", body.to_json())],
+            ),
+            CStmt::For {
+                init,
+                test,
+                updt,
+                body,
+            } => Json::node(
+                "for",
+                vec![
+                    ("init", init.to_json()),
+                    ("test", test.to_json()),
+                    ("update", updt.to_json()),
+                    ("body", body.to_json()),
+                ],
+            ),
+            CStmt::Decl(d) => d.to_json(),
+            CStmt::Block(stmts) => Json::node("block", vec![("stmts", Json::arr(stmts))]),
+            CStmt::Expr(e) => Json::node("expr", vec![("expr", e.to_json())]),
+            CStmt::Switch {
+                cond,
+                cases,
+                default,
+            } => Json::node(
+                "switch",
+                vec![
+                    ("cond", cond.to_json()),
+                    (
+                        "cases",
+                        Json::Arr(
+                            cases
+                                .iter()
+                                .map(|(label, body)| {
+                                    Json::Arr(vec![label.to_json(), Json::arr(body)])
+                                })
+                                .collect(),
+                        ),
+                    ),
+                    ("default", default.as_ref().map_or(Json::Null, Json::arr)),
+                ],
+            ),
+            CStmt::Return(ex) => Json::node(
+                "return",
+                vec![("ex", ex.as_ref().map_or(Json::Null, ToJson::to_json))],
+            ),
+            CStmt::Break => Json::node("break", vec![]),
+            CStmt::Label(name) => Json::node("label", vec![("name", Json::str(name.as_ref()))]),
+            CStmt::Goto(name) => Json::node("goto", vec![("label", Json::str(name.as_ref()))]),
+        }
+    }
+}
+
+fn members_json(members: &[(std::borrow::Cow<str>, CType)]) -> Json {
+    Json::Arr(
+        members
+            .iter()
+            .map(|(n, t)| Json::Arr(vec![Json::str(n.as_ref()), t.to_json()]))
+            .collect(),
+    )
+}
+
+impl<'a> ToJson for CDecl<'a> {
+    fn to_json(&self) -> Json {
+        match self {
+            CDecl::FunProto {
+                name,
+                typ,
+                args,
+                noreturn,
+            } => Json::node(
+                "fun_proto",
+                vec![
+                    ("name", Json::str(name.as_ref())),
+                    ("type", typ.to_json()),
+                    ("args", Json::arr(args)),
+                    ("noreturn", Json::Bool(*noreturn)),
+                ],
+            ),
+            CDecl::Fun {
+                name,
+                typ,
+                args,
+                body,
+                source,
+            } => Json::node(
+                "fun",
+                vec![
+                    ("name", Json::str(name.as_ref())),
+                    ("type", typ.to_json()),
+                    ("args", members_json(args)),
+                    ("body", Json::arr(body)),
+                    (
+                        "source",
+                        source.as_ref().map_or(Json::Null, |(file, line)| {
+                            Json::Arr(vec![Json::str(file.as_ref()), Json::Int(*line as i64)])
+                        }),
+                    ),
+                ],
+            ),
+            CDecl::Struct { name, members } => Json::node(
+                "struct",
+                vec![
+                    ("name", Json::str(name.as_ref())),
+                    ("members", members_json(members)),
+                ],
+            ),
+            CDecl::Union { name, members } => Json::node(
+                "union",
+                vec![
+                    ("name", Json::str(name.as_ref())),
+                    ("members", members_json(members)),
+                ],
+            ),
+            CDecl::Var { name, typ, init } => Json::node(
+                "var",
+                vec![
+                    ("name", Json::str(name.as_ref())),
+                    ("type", typ.to_json()),
+                    ("init", init.as_ref().map_or(Json::Null, ToJson::to_json)),
+                ],
+            ),
+        }
+    }
+}
+
+fn invalid(what: &str) -> Error {
+    format_err!("invalid IR: expected {}", what)
+}
+
+fn unknown(kind: &str) -> Error {
+    format_err!("invalid IR: unknown kind of node {}", kind)
+}
+
+impl<T: FromJson> FromJson for Rc<T> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        T::from_json(json).map(Rc::new)
+    }
+}
+
+impl<T: FromJson> FromJson for Arc<T> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        T::from_json(json).map(Arc::new)
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        match json {
+            Json::Null => Ok(None),
+            json => T::from_json(json).map(Some),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        json.as_arr()
+            .ok_or_else(|| invalid("an array"))?
+            .iter()
+            .map(T::from_json)
+            .collect()
+    }
+}
+
+impl<A: FromJson, B: FromJson> FromJson for (A, B) {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        match json.as_arr() {
+            Some([a, b]) => Ok((A::from_json(a)?, B::from_json(b)?)),
+            _ => Err(invalid("a pair")),
+        }
+    }
+}
+
+impl FromJson for String {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        json.as_str()
+            .map(str::to_owned)
+            .ok_or_else(|| invalid("a string"))
+    }
+}
+
+impl FromJson for Cow<'static, str> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        String::from_json(json).map(Cow::Owned)
+    }
+}
+
+impl FromJson for bool {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        json.as_bool().ok_or_else(|| invalid("a bool"))
+    }
+}
+
+impl FromJson for i64 {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        json.as_i64().ok_or_else(|| invalid("an integer"))
+    }
+}
+
+impl FromJson for usize {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        usize::try_from(i64::from_json(json)?).map_err(|_| invalid("a size"))
+    }
+}
+
+impl FromJson for f64 {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        match json {
+            Json::Float(v) => Ok(*v),
+            Json::Int(i) => Ok(*i as f64),
+            // infinities and nan are written as strings
+            Json::Str(s) => s.parse().map_err(|_| invalid("a float")),
+            _ => Err(invalid("a float")),
+        }
+    }
+}
+
+/// The variables of an IR being read, by what they were written as, so each
+/// use of one is the same variable. They are made afresh, as moniker can't
+/// make a variable with the id it was written with.
+#[derive(Default)]
+struct Vars(HashMap<String, FreeVar<String>>);
+
+impl Vars {
+    fn read(&mut self, json: &Json) -> Result<FreeVar<String>, Error> {
+        let written = json.as_str().ok_or_else(|| invalid("a variable"))?;
+
+        let var = self.0.entry(written.to_owned()).or_insert_with(|| {
+            let name = written.rsplit_once('$').map_or(written, |(name, _)| name);
+            FreeVar::fresh(Some(name.to_owned()).filter(|name| !name.is_empty()))
+        });
+
+        Ok(var.clone())
+    }
+}
+
+impl FromJson for Literal {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        Ok(match json.kind()?.as_str() {
+            "string" => Literal::String(json.read("value")?),
+            "int" => Literal::Int(json.read("value")?),
+            "rational" => Literal::Rational(json.read("num")?, json.read("den")?),
+            "float" => Literal::Float(json.read("value")?),
+            "symbol" => Literal::Symbol(json.read("value")?),
+            "bool" => Literal::Bool(json.read("value")?),
+            "void" => Literal::Void,
+            "quoted" => Literal::Quoted(json.read("items")?),
+            kind => return Err(unknown(kind)),
+        })
+    }
+}
+
+impl FromJson for BExprBody {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        let items = json.as_arr().ok_or_else(|| invalid("a body"))?;
+        let (last, items) = items
+            .split_last()
+            .ok_or_else(|| invalid("a body ending in an expression"))?;
+
+        let items = items
+            .iter()
+            .map(|item| match item.kind()?.as_str() {
+                "define" => Ok(BExprBodyExpr::Def(item.read("name")?, item.read("value")?)),
+                _ => BExpr::from_json(item).map(BExprBodyExpr::Expr),
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(BExprBody(items, Rc::from_json(last)?))
+    }
+}
+
+impl FromJson for BExpr {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        Ok(match json.kind()?.as_str() {
+            "var" => BExpr::Var(json.read("name")?),
+            "lit" => BExpr::Lit(json.read("value")?),
+            "builtin" => BExpr::BuiltinIdent(json.read("name")?),
+            "if" => BExpr::If(json.read("cond")?, json.read("then")?, json.read("else")?),
+            "set" => BExpr::Set(json.read("name")?, json.read("value")?),
+            "let" => BExpr::Let(json.read("bindings")?, json.read("body")?),
+            "lambda" => BExpr::Lam(json.read("params")?, json.read("body")?, json.read("line")?),
+            "case-lambda" => BExpr::CaseLam(json.read("clauses")?),
+            "app" => BExpr::App(json.read("fn")?, json.read("args")?),
+            kind => return Err(unknown(kind)),
+        })
+    }
+}
+
+impl FromJson for Expr {
+    /// The lambdas are read with their scopes left open, and closed all at
+    /// once.
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        Ok(read_expr(json, &mut Vars::default())?.close())
+    }
+}
+
+fn read_expr(json: &Json, vars: &mut Vars) -> Result<Expr, Error> {
+    let read = |key: &str, vars: &mut Vars| read_expr(json.field(key)?, vars).map(Rc::new);
+
+    Ok(match json.kind()?.as_str() {
+        "var" => Expr::Atom(Atom::Var(Var::Free(vars.read(json.field("name")?)?))),
+        "lit" => Expr::Atom(Atom::Lit(Ignore(json.read("value")?))),
+        "builtin" => Expr::Atom(Atom::BuiltinIdent(Ignore(json.read("name")?))),
+        "lambda" => {
+            let x = vars.read(json.field("param")?)?;
+            let body = read("body", vars)?;
+            Expr::Atom(Atom::Lam(open_scope(x, body), Ignore(json.read("line")?)))
+        }
+        "if" => Expr::If(
+            read("cond", vars)?,
+            read("then", vars)?,
+            read("else", vars)?,
+        ),
+        "set" => Expr::Set(
+            Var::Free(vars.read(json.field("name")?)?),
+            read("value", vars)?,
+        ),
+        "app" => Expr::App(read("fn", vars)?, read("arg", vars)?),
+        kind => return Err(unknown(kind)),
+    })
+}
+
+impl FromJson for FExpr {
+    /// The lambdas are read with their scopes left open, and closed all at
+    /// once.
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        Ok(read_node(json, &mut Vars::default())?.close())
+    }
+}
+
+fn read_node(json: &Json, vars: &mut Vars) -> Result<Node, Error> {
+    let read = |json: &Json, vars: &mut Vars| read_node(json, vars).map(Box::new);
+    let field = |key: &str, vars: &mut Vars| read(json.field(key)?, vars);
+
+    Ok(match json.kind()?.as_str() {
+        "lambda" => match json.field("params")?.as_arr() {
+            Some([x]) => Node::LamOne(vars.read(x)?, field("body", vars)?),
+            Some([x, k]) => Node::LamTwo(
+                vars.read(x)?,
+                vars.read(k)?,
+                field("body", vars)?,
+                json.read("line")?,
+            ),
+            _ => return Err(invalid("one or two parameters")),
+        },
+        "var" => Node::Var(Var::Free(vars.read(json.field("name")?)?)),
+        "lit" => Node::Lit(json.read("value")?),
+        "builtin" => Node::BuiltinIdent(json.read("name")?),
+        "set_then" => Node::SetThen(
+            Var::Free(vars.read(json.field("name")?)?),
+            field("value", vars)?,
+            field("then", vars)?,
+        ),
+        "call" => match json.field("args")?.as_arr() {
+            Some([a]) => Node::CallOne(field("fn", vars)?, read(a, vars)?),
+            Some([a, k]) => Node::CallTwo(field("fn", vars)?, read(a, vars)?, read(k, vars)?),
+            _ => return Err(invalid("one or two arguments")),
+        },
+        "if" => Node::If(
+            field("cond", vars)?,
+            field("then", vars)?,
+            field("else", vars)?,
+        ),
+        "int_op" => match json.field("args")?.as_arr() {
+            Some([a, b]) => Node::IntOp(json.read("op")?, read(a, vars)?, read(b, vars)?),
+            _ => return Err(invalid("two arguments")),
+        },
+        kind => return Err(unknown(kind)),
+    })
+}
+
+impl FromJson for LExpr {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        read_lexpr(json, &mut Vars::default())
+    }
+}
+
+fn read_lexpr(json: &Json, vars: &mut Vars) -> Result<LExpr, Error> {
+    let read = |json: &Json, vars: &mut Vars| read_lexpr(json, vars).map(Arc::new);
+    let field = |key: &str, vars: &mut Vars| read(json.field(key)?, vars);
+
+    Ok(match json.kind()?.as_str() {
+        "var" => LExpr::Var(Var::Free(vars.read(json.field("name")?)?)),
+        "lit" => LExpr::Lit(Ignore(json.read("value")?)),
+        "builtin" => LExpr::BuiltinIdent(Ignore(json.read("name")?)),
+        "set_then" => LExpr::SetThen(
+            Var::Free(vars.read(json.field("name")?)?),
+            field("value", vars)?,
+            field("then", vars)?,
+        ),
+        "if" => LExpr::If(
+            field("cond", vars)?,
+            field("then", vars)?,
+            field("else", vars)?,
+        ),
+        "lifted" => LExpr::Lifted(Ignore(json.read("id")?)),
+        "int_op" => match json.field("args")?.as_arr() {
+            Some([a, b]) => LExpr::IntOp(Ignore(json.read("op")?), read(a, vars)?, read(b, vars)?),
+            _ => return Err(invalid("two arguments")),
+        },
+        "call" => match json.field("args")?.as_arr() {
+            Some([a]) => LExpr::CallOne(field("fn", vars)?, read(a, vars)?),
+            Some([a, k]) => LExpr::CallTwo(field("fn", vars)?, read(a, vars)?, read(k, vars)?),
+            _ => return Err(invalid("one or two arguments")),
+        },
+        kind => return Err(unknown(kind)),
+    })
+}
+
+impl FromJson for LiftedLambda {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        read_lambda(json, &mut Vars::default())
+    }
+}
+
+fn read_lambda(json: &Json, vars: &mut Vars) -> Result<LiftedLambda, Error> {
+    let params = match json.field("params")?.as_arr() {
+        Some([x]) => LambdaParams::One(vars.read(x)?),
+        Some([x, k]) => LambdaParams::Two(vars.read(x)?, vars.read(k)?),
+        _ => return Err(invalid("one or two parameters")),
+    };
+
+    let freevars = json
+        .field("freevars")?
+        .as_arr()
+        .ok_or_else(|| invalid("an array"))?
+        .iter()
+        .map(|v| vars.read(v))
+        .collect::<Result<_, Error>>()?;
+
+    let body = read_lexpr(json.field("body")?, vars)?;
+    let mut lambda = LiftedLambda::new(json.read("id")?, params, freevars, Arc::new(body));
+
+    lambda.parent = json.read("parent")?;
+    lambda.env_repr = match json.read::<String>("env")?.as_str() {
+        "flat" => EnvRepr::Flat,
+        "linked" => EnvRepr::Linked,
+        _ => return Err(invalid("an environment representation")),
+    };
+    lambda.line = json.read("line")?;
+    lambda.hint = json.read("hint")?;
+    lambda.name = json.read("name")?;
+
+    Ok(lambda)
+}
+
+impl FromJson for Program {
+    /// The variables are shared by the toplevel and the lambdas, so are read
+    /// for the whole program at once.
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        let mut vars = Vars::default();
+        let entry = read_lexpr(json.field("main")?, &mut vars)?;
+
+        let lambdas = json
+            .field("lambdas")?
+            .as_arr()
+            .ok_or_else(|| invalid("an array"))?
+            .iter()
+            .map(|l| read_lambda(l, &mut vars).map(|l| (l.id, l)))
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Program::new(entry, lambdas))
+    }
+}
+
+impl FromJson for CType<'static> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        Ok(match json.kind()?.as_str() {
+            "ptr" => CType::Ptr(json.read("to")?),
+            "arr" => CType::Arr(json.read("of")?, json.read("len")?),
+            "int" => CType::Int {
+                size: json.read("size")?,
+                sign: json.read("sign")?,
+            },
+            "struct" => CType::Struct(json.read("name")?),
+            "union" => CType::Union(json.read("name")?),
+            "other" => CType::Other(json.read("name")?),
+            "static" => CType::Static(json.read("of")?),
+            "weak" => CType::Weak(json.read("of")?),
+            "const" => CType::Const(json.read("of")?),
+            "void" => CType::Void,
+            kind => return Err(unknown(kind)),
+        })
+    }
+}
+
+impl FromJson for CExpr<'static> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        use self::CExpr::*;
+
+        Ok(match json.kind()?.as_str() {
+            "binop" => BinOp {
+                op: json.read("op")?,
+                left: json.read("left")?,
+                right: json.read("right")?,
+            },
+            "pre_unop" => PreUnOp {
+                op: json.read("op")?,
+                ex: json.read("ex")?,
+            },
+            "post_unop" => PostUnOp {
+                op: json.read("op")?,
+                ex: json.read("ex")?,
+            },
+            "index" => ArrIndexOp {
+                index: json.read("index")?,
+                expr: json.read("expr")?,
+            },
+            "dot" => Dot {
+                expr: json.read("expr")?,
+                attr: json.read("attr")?,
+            },
+            "arrow" => Arrow {
+                expr: json.read("expr")?,
+                attr: json.read("attr")?,
+            },
+            "call" => FunCallOp {
+                expr: json.read("fn")?,
+                params: json.read("args")?,
+            },
+            "cast" => Cast {
+                ex: json.read("ex")?,
+                typ: json.read("type")?,
+            },
+            // the arguments were written as C, which an identifier exports as
+            // it is
+            "macro_call" => MacroCall {
+                name: json.read("name")?,
+                args: json
+                    .read::<Vec<Cow<str>>>("args")?
+                    .into_iter()
+                    .map(|a| Arc::new(Ident(a)) as Arc<dyn ToCDC + Send + Sync>)
+                    .collect(),
+            },
+            "if" => If {
+                cond: json.read("cond")?,
+                ift: json.read("then")?,
+                iff: json.read("else")?,
+            },
+            "init_list" => InitList(json.read("items")?),
+            "ident" => Ident(json.read("name")?),
+            "str" => LitStr(json.read("value")?),
+            // written as an i64 however big
+            "uint" => LitUInt(json.read::<i64>("value")? as usize),
+            "int" => LitIInt(json.read::<i64>("value")? as isize),
+            "float" => LitFloat(json.read("value")?),
+            kind => return Err(unknown(kind)),
+        })
+    }
+}
+
+impl FromJson for CStmt<'static> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        Ok(match json.kind()?.as_str() {
+            "if" => CStmt::If {
+                cond: json.read("cond")?,
+                ift: json.read("then")?,
+                iff: json.read("else")?,
+            },
+            "while" => CStmt::While {
+                cond: json.read("cond")?,
+                body: json.read("body")?,
+            },
+            "for" => CStmt::For {
+                init: json.read("init")?,
+                test: json.read("test")?,
+                updt: json.read("update")?,
+                body: json.read("body")?,
+            },
+            "block" => CStmt::Block(json.read("stmts")?),
+            "expr" => CStmt::Expr(json.read("expr")?),
+            "switch" => CStmt::Switch {
+                cond: json.read("cond")?,
+                cases: json.read("cases")?,
+                default: json.read("default")?,
+            },
+            "return" => CStmt::Return(json.read("ex")?),
+            "break" => CStmt::Break,
+            "label" => CStmt::Label(json.read("name")?),
+            "goto" => CStmt::Goto(json.read("label")?),
+            // declarations are written as they are
+            _ => CStmt::Decl(CDecl::from_json(json)?),
+        })
+    }
+}
+
+impl FromJson for CDecl<'static> {
+    fn from_json(json: &Json) -> Result<Self, Error> {
+        Ok(match json.kind()?.as_str() {
+            "fun_proto" => CDecl::FunProto {
+                name: json.read("name")?,
+                typ: json.read("type")?,
+                args: json.read("args")?,
+                noreturn: json.read("noreturn")?,
+            },
+            "fun" => CDecl::Fun {
+                name: json.read("name")?,
+                typ: json.read("type")?,
+                args: json.read("args")?,
+                body: json.read("body")?,
+                source: json.read("source")?,
+            },
+            "struct" => CDecl::Struct {
+                name: json.read("name")?,
+                members: json.read("members")?,
+            },
+            "union" => CDecl::Union {
+                name: json.read("name")?,
+                members: json.read("members")?,
+            },
+            "var" => CDecl::Var {
+                name: json.read("name")?,
+                typ: json.read("type")?,
+                init: json.read("init")?,
+            },
+            kind => return Err(unknown(kind)),
+        })
+    }
+}
@@ -0,0 +1,260 @@
+//! The parts of the lexical syntax the grammar doesn't see. Comments are
+//! blanked out: `;` to the end of the line, `#| |#`, which nest, and `#;`
+//! along with the datum after it. `#!fold-case` and `#!no-fold-case` turn
+//! folding the source to lower case on and off for what follows them, strings
+//! and names between bars are left as they are. A name between bars that
+//! doesn't need them, like `|foo|`, loses them. The character of a character
+//! literal like `#\(` is never taken for a bracket, bar or anything else.
+//!
+//! The source keeps its length and lines, so that the errors of the parser
+//! point where they would have.
+
+/// `source` ready for the grammar, starting without folding case.
+pub fn prepare(source: &str) -> String {
+    prepare_folding(source, false)
+}
+
+/// Like `prepare`, but starting with case folded if `fold` is set, as
+/// `include-ci` reads files.
+pub fn prepare_folding(source: &str, fold: bool) -> String {
+    let src = source.as_bytes();
+    let mut out = Vec::with_capacity(src.len());
+    let mut fold = fold;
+    let mut i = 0;
+
+    let blank = |out: &mut Vec<u8>, text: &[u8]| {
+        out.extend(text.iter().map(|&c| if c == b'\n' { c } else { b' ' }));
+    };
+
+    while i < src.len() {
+        let rest = &src[i..];
+
+        let end = match rest {
+            // a character like #\( or #\| is none of what the bracket or bar
+            // would start
+            [b'#', b'\\', c, ..] if !c.is_ascii_alphanumeric() => {
+                out.extend_from_slice(&rest[..3]);
+                i += 3;
+                continue;
+            }
+            [b'"', ..] => {
+                let end = string_end(src, i);
+                out.extend_from_slice(&src[i..end]);
+                i = end;
+                continue;
+            }
+            [b';', ..] => line_end(src, i),
+            [b'#', b';', ..] => datum_end(src, i + 2),
+            // unclosed ones are left for the parser to report
+            [b'#', b'|', ..] => match block_comment_end(src, i) {
+                Some(end) => end,
+                None => {
+                    out.extend_from_slice(rest);
+                    break;
+                }
+            },
+            _ if directive(rest, b"#!fold-case") => {
+                fold = true;
+                i + b"#!fold-case".len()
+            }
+            _ if directive(rest, b"#!no-fold-case") => {
+                fold = false;
+                i + b"#!no-fold-case".len()
+            }
+            [b'|', ..] => {
+                let end = match bar_end(src, i) {
+                    Some(end) => end,
+                    None => {
+                        out.extend_from_slice(rest);
+                        break;
+                    }
+                };
+                let name = &src[i + 1..end - 1];
+
+                if needs_bars(name) {
+                    out.extend_from_slice(&src[i..end]);
+                } else {
+                    out.extend_from_slice(name);
+                    out.extend_from_slice(b"  ");
+                }
+
+                i = end;
+                continue;
+            }
+            [c, ..] => {
+                out.push(if fold { c.to_ascii_lowercase() } else { *c });
+                i += 1;
+                continue;
+            }
+            [] => unreachable!(),
+        };
+
+        blank(&mut out, &src[i..end]);
+        i = end;
+    }
+
+    // only ASCII was changed, or blanked out whole
+    String::from_utf8(out).expect("prepared source isn't UTF-8")
+}
+
+fn is_delimiter(c: u8) -> bool {
+    c.is_ascii_whitespace() || b"()\"';|".contains(&c)
+}
+
+/// Whether the name between bars would be read as something else without
+/// them, or not read at all.
+pub(crate) fn needs_bars(name: &[u8]) -> bool {
+    match name {
+        [] | [b'#', ..] | [b'0'..=b'9', ..] | [b'+' | b'-' | b'.', b'0'..=b'9', ..] => true,
+        name => name.iter().any(|&c| is_delimiter(c)),
+    }
+}
+
+/// Whether `text` starts with the directive `name`, ending where it does.
+fn directive(text: &[u8], name: &[u8]) -> bool {
+    text.starts_with(name) && text.get(name.len()).is_none_or(|c| c.is_ascii_whitespace())
+}
+
+/// The end of the line comment starting at `i`, before the newline.
+fn line_end(src: &[u8], i: usize) -> usize {
+    match src[i..].iter().position(|&c| c == b'\n') {
+        Some(end) => i + end,
+        None => src.len(),
+    }
+}
+
+/// The end of the string starting at `i`, after its closing quote.
+fn string_end(src: &[u8], mut i: usize) -> usize {
+    i += 1;
+
+    while i < src.len() {
+        match src[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+
+    src.len()
+}
+
+/// The end of the name between bars starting at `i`, after the closing bar.
+fn bar_end(src: &[u8], i: usize) -> Option<usize> {
+    src[i + 1..]
+        .iter()
+        .position(|&c| c == b'|')
+        .map(|end| i + 1 + end + 1)
+}
+
+/// The end of the block comment starting at `i`, after its `|#`.
+fn block_comment_end(src: &[u8], mut i: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    while i < src.len() {
+        match &src[i..] {
+            [b'#', b'|', ..] => {
+                depth += 1;
+                i += 2;
+            }
+            [b'|', b'#', ..] => {
+                depth -= 1;
+                i += 2;
+
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+/// The end of the whitespace and comments, other than datum comments,
+/// starting at `i`.
+fn atmosphere_end(src: &[u8], mut i: usize) -> usize {
+    loop {
+        match &src[i..] {
+            [c, ..] if c.is_ascii_whitespace() => i += 1,
+            [b';', ..] => i = line_end(src, i),
+            [b'#', b'|', ..] => i = block_comment_end(src, i).unwrap_or(src.len()),
+            _ => return i,
+        }
+    }
+}
+
+/// The end of the datum after the whitespace and comments starting at `i`.
+/// Lists are counted rather than recursed into, so nesting doesn't overflow
+/// the stack before the parser can reject it.
+fn datum_end(src: &[u8], mut i: usize) -> usize {
+    let mut depth = 0usize;
+    // datums still to end at the top, each `#;` there adding one
+    let mut wanted = 1usize;
+
+    loop {
+        i = atmosphere_end(src, i);
+
+        match &src[i..] {
+            [] => return i,
+            [b')', ..] if depth == 0 => return i,
+            [b')', ..] => {
+                depth -= 1;
+                i += 1;
+            }
+            [b'(', ..] => {
+                depth += 1;
+                i += 1;
+                continue;
+            }
+            [b'#', b'(', ..] => {
+                depth += 1;
+                i += 2;
+                continue;
+            }
+            [b'#', b'u', b'8', b'(', ..] => {
+                depth += 1;
+                i += 4;
+                continue;
+            }
+            // within a list the datum after it is skipped all the same
+            [b'#', b';', ..] => {
+                if depth == 0 {
+                    wanted += 1;
+                }
+                i += 2;
+                continue;
+            }
+            [b',', b'@', ..] => {
+                i += 2;
+                continue;
+            }
+            [b'\'' | b'`' | b',', ..] => {
+                i += 1;
+                continue;
+            }
+            [b'"', ..] => i = string_end(src, i),
+            [b'|', ..] => i = bar_end(src, i).unwrap_or(src.len()),
+            _ => {
+                // the character after #\ is taken whatever it is
+                if src[i..].starts_with(b"#\\") {
+                    i = (i + 3).min(src.len());
+                }
+
+                let len = src[i..]
+                    .iter()
+                    .position(|&c| is_delimiter(c))
+                    .unwrap_or(src.len() - i);
+                i += len;
+            }
+        }
+
+        if depth == 0 {
+            wanted -= 1;
+
+            if wanted == 0 {
+                return i;
+            }
+        }
+    }
+}
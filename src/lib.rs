@@ -0,0 +1,43 @@
+#![allow(
+    non_local_definitions,
+    reason = "the BoundTerm derive of moniker implements it inside a const, next to the \
+              expression types rather than on them, so they can't allow it themselves"
+)]
+
+pub mod base_expr;
+pub mod bench;
+pub mod bytecode;
+pub mod cache;
+pub mod cdsl;
+pub mod codegen;
+pub mod compiler;
+pub mod cont_expr;
+pub mod dot;
+pub mod expr;
+pub mod features;
+pub mod flat_expr;
+pub mod foreign;
+pub mod include;
+pub mod inline;
+pub mod interp;
+pub mod json;
+pub mod lexical;
+pub mod library;
+pub mod lifted_expr;
+pub mod link;
+pub mod lint;
+pub mod literals;
+pub mod llvm;
+pub mod lsp;
+pub mod parse;
+pub mod partial_eval;
+pub mod profile;
+pub mod repl;
+pub mod shrink;
+pub mod target;
+pub mod timing;
+pub mod unbox;
+pub mod utils;
+pub mod vm;
+pub mod wasm;
+pub mod wat;
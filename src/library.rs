@@ -0,0 +1,231 @@
+use pest::{
+    error::{Error, ErrorVariant},
+    iterators::Pair,
+};
+
+use std::collections::HashMap;
+
+use crate::base_expr::{BExprBody, BExprBodyExpr};
+use crate::parse::{build_bodyexprs_from_expr, ParseError, Rule};
+
+/// The names a set of imports or a library makes visible, mapped to the
+/// variables holding them.
+type Bindings = HashMap<String, String>;
+
+/// The libraries defined so far in a program along with the toplevel forms
+/// their bodies expand to.
+///
+/// Every definition of a library is renamed to include the name of the
+/// library, which contains a space and parens so that it can't be written in
+/// source, so libraries can use the same names without clashing.
+#[derive(Debug, Default)]
+pub struct Libraries {
+    registry: HashMap<Vec<String>, Bindings>,
+    items: Vec<BExprBodyExpr>,
+}
+
+fn error(message: String, pair: &Pair<Rule>) -> ParseError {
+    Box::new(Error::new_from_span(
+        ErrorVariant::CustomError { message },
+        pair.as_span(),
+    ))
+}
+
+fn library_name(pair: Pair<Rule>) -> Vec<String> {
+    pair.into_inner().map(|p| p.as_str().to_owned()).collect()
+}
+
+fn display_name(name: &[String]) -> String {
+    format!("({})", name.join(" "))
+}
+
+/// The builtins are always in scope, so the standard libraries don't need to
+/// provide anything.
+pub(crate) fn is_standard(name: &[String]) -> bool {
+    matches!(name.first().map(String::as_str), Some("scheme" | "srfi"))
+}
+
+impl Libraries {
+    /// The bindings an `import_set` makes visible.
+    fn import_set(&self, pair: Pair<Rule>) -> Result<Bindings, ParseError> {
+        let pair = pair.into_inner().next().unwrap();
+        let rule = pair.as_rule();
+
+        if rule == Rule::library_name {
+            let name = library_name(pair.clone());
+
+            return match self.registry.get(&name) {
+                Some(exports) => Ok(exports.clone()),
+                None if is_standard(&name) => Ok(Bindings::new()),
+                None => Err(error(
+                    format!("Unknown library: {}", display_name(&name)),
+                    &pair,
+                )),
+            };
+        }
+
+        let mut inner = pair.into_inner();
+        let mut bindings = self.import_set(inner.next().unwrap())?;
+
+        let missing = |name: &Pair<Rule>| {
+            error(
+                format!("Imported library doesn't export: {}", name.as_str()),
+                name,
+            )
+        };
+
+        match rule {
+            Rule::import_only => {
+                let mut only = Bindings::new();
+
+                for name in inner {
+                    let value = bindings.get(name.as_str()).ok_or_else(|| missing(&name))?;
+                    only.insert(name.as_str().to_owned(), value.clone());
+                }
+
+                Ok(only)
+            }
+            Rule::import_except => {
+                for name in inner {
+                    bindings
+                        .remove(name.as_str())
+                        .ok_or_else(|| missing(&name))?;
+                }
+
+                Ok(bindings)
+            }
+            Rule::import_prefix => {
+                let prefix = inner.next().unwrap().as_str();
+
+                Ok(bindings
+                    .into_iter()
+                    .map(|(name, value)| (format!("{}{}", prefix, name), value))
+                    .collect())
+            }
+            Rule::import_rename => {
+                let mut renamed = Bindings::new();
+
+                for rename in inner {
+                    let mut rename = rename.into_inner();
+                    let from = rename.next().unwrap();
+                    let to = rename.next().unwrap().as_str().to_owned();
+
+                    let value = bindings
+                        .remove(from.as_str())
+                        .ok_or_else(|| missing(&from))?;
+                    renamed.insert(to, value);
+                }
+
+                renamed.extend(bindings);
+                Ok(renamed)
+            }
+            r => unreachable!("{:?}", r),
+        }
+    }
+
+    /// Add the bindings of an `import_decl` to `bindings`, importing two
+    /// different things under one name is an error.
+    pub fn import(&self, pair: Pair<Rule>, bindings: &mut Bindings) -> Result<(), ParseError> {
+        for set in pair.into_inner() {
+            let span = set.clone();
+
+            for (name, value) in self.import_set(set)? {
+                match bindings.get(&name) {
+                    Some(existing) if *existing != value => {
+                        return Err(error(format!("{} is imported twice", name), &span));
+                    }
+                    _ => {
+                        bindings.insert(name, value);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Register a `define_library_form` and expand its body.
+    pub fn define(&mut self, pair: Pair<Rule>) -> Result<(), ParseError> {
+        let mut inner = pair.into_inner();
+        let name_pair = inner.next().unwrap();
+        let name = library_name(name_pair.clone());
+
+        if self.registry.contains_key(&name) {
+            return Err(error(
+                format!("Library defined twice: {}", display_name(&name)),
+                &name_pair,
+            ));
+        }
+
+        let mut exports = Vec::new();
+        let mut scope = Bindings::new();
+        let mut items = Vec::new();
+
+        for decl in inner {
+            match decl.as_rule() {
+                Rule::export_decl => exports.extend(decl.into_inner()),
+                Rule::import_decl => self.import(decl, &mut scope)?,
+                Rule::library_begin => {
+                    for item in decl.into_inner().flat_map(build_bodyexprs_from_expr) {
+                        if let BExprBodyExpr::Def(n, _) = &item {
+                            let internal = format!("{} {}", display_name(&name), n);
+                            scope.insert(n.clone(), internal);
+                        }
+
+                        items.push(item);
+                    }
+                }
+                r => unreachable!("{:?}", r),
+            }
+        }
+
+        // definitions are visible throughout the library, like at the
+        // toplevel, so rename once they are all known
+        self.items.extend(items.into_iter().map(|item| match item {
+            BExprBodyExpr::Def(n, e) => {
+                BExprBodyExpr::Def(scope[&n].clone(), e.rename_free(&scope))
+            }
+            BExprBodyExpr::Expr(e) => BExprBodyExpr::Expr(e.rename_free(&scope)),
+        }));
+
+        let mut exported = Bindings::new();
+
+        for export in exports {
+            let (inside, outside) = match export.as_rule() {
+                Rule::export_rename => {
+                    let mut names = export.into_inner();
+                    (names.next().unwrap(), names.next().unwrap())
+                }
+                _ => (export.clone(), export),
+            };
+
+            let value = scope.get(inside.as_str()).ok_or_else(|| {
+                error(
+                    format!(
+                        "{} exports {}, which it doesn't define or import",
+                        display_name(&name),
+                        inside.as_str()
+                    ),
+                    &inside,
+                )
+            })?;
+
+            exported.insert(outside.as_str().to_owned(), value.clone());
+        }
+
+        self.registry.insert(name, exported);
+
+        Ok(())
+    }
+
+    /// Put the bodies of the libraries before the program, which refers to the
+    /// libraries through `imports`.
+    pub fn link(self, program: BExprBody, imports: &Bindings) -> BExprBody {
+        let BExprBody(mut items, last) = program.rename_free(imports);
+
+        let mut body = self.items;
+        body.append(&mut items);
+
+        BExprBody(body, last)
+    }
+}
@@ -5,9 +5,10 @@ use moniker::{Ignore, Var};
 use pretty::{BoxAllocator, DocAllocator, DocBuilder};
 use termcolor::{Color, ColorSpec, WriteColor};
 
-use std::collections::HashSet;
-use std::{io::Result, rc::Rc};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::{io::Result, sync::Arc};
 
+use crate::cdsl::{mangle, unique_name, TakenNames};
 use crate::literals::Literal;
 
 #[derive(Debug, Clone, BoundTerm)]
@@ -15,38 +16,677 @@ pub enum LExpr {
     Var(Var<String>),
     Lit(Ignore<Literal>),
     BuiltinIdent(Ignore<String>),
-    SetThen(Var<String>, Rc<LExpr>, Rc<LExpr>),
-    If(Rc<LExpr>, Rc<LExpr>, Rc<LExpr>),
+    SetThen(Var<String>, Arc<LExpr>, Arc<LExpr>),
+    If(Arc<LExpr>, Arc<LExpr>, Arc<LExpr>),
     Lifted(Ignore<usize>),
-    CallOne(Rc<LExpr>, Rc<LExpr>),
-    CallTwo(Rc<LExpr>, Rc<LExpr>, Rc<LExpr>),
+    CallOne(Arc<LExpr>, Arc<LExpr>),
+    CallTwo(Arc<LExpr>, Arc<LExpr>, Arc<LExpr>),
+    /// Only made for the C backend, see `unbox`
+    IntOp(Ignore<String>, Arc<LExpr>, Arc<LExpr>),
 }
 
+/// After CPS every lambda is either a continuation taking a single value, or a
+/// function taking a value and a continuation.
+#[derive(Debug, Clone)]
+pub enum LambdaParams {
+    One(FreeVar<String>),
+    Two(FreeVar<String>, FreeVar<String>),
+}
+
+impl LambdaParams {
+    pub fn iter(&self) -> impl Iterator<Item = &FreeVar<String>> {
+        let (first, second) = match self {
+            LambdaParams::One(a) => (a, None),
+            LambdaParams::Two(a, b) => (a, Some(b)),
+        };
+
+        std::iter::once(first).chain(second)
+    }
+}
+
+/// How the environment of a lambda is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvRepr {
+    /// Every free variable is copied into the lambda's own environment, access
+    /// is a single load but creating the closure copies each capture.
+    Flat,
+    /// The environment holds the lambda's own parameters and a pointer to the
+    /// environment of the lambda that created it, so creation is cheap but
+    /// captured variables are found by walking up the chain.
+    Linked,
+}
+
+/// Sets of variables iterate in a different order on each run, so anything
+/// that ends up in the output goes through them sorted by this.
+pub fn var_order(var: &FreeVar<String>) -> (Option<String>, String) {
+    (var.pretty_name.clone(), format!("{:?}", var.unique_id))
+}
+
+/// Lambdas capturing at least this many variables are worth linking.
+const LINKED_MIN_CAPTURES: usize = 4;
+
+/// The longest chain of linked environments an access can walk.
+const MAX_LINK_DEPTH: usize = 3;
+
 #[derive(Debug, Clone)]
 pub struct LiftedLambda {
     pub id: usize,
-    pub params: Vec<FreeVar<String>>,
+    pub params: LambdaParams,
     pub freevars: HashSet<FreeVar<String>>,
-    pub body: Rc<LExpr>,
+    pub body: Arc<LExpr>,
+    /// The lambda whose body creates this one, `None` for the toplevel.
+    pub parent: Option<usize>,
+    pub env_repr: EnvRepr,
+    /// The source line of the lambda this was lifted from, only known for
+    /// lambdas written in the source.
+    pub line: Option<usize>,
+    /// The variable this lambda was bound to where it was written, if any.
+    pub hint: Option<String>,
+    /// The name of the C function holding the body.
+    pub name: String,
 }
 
 impl LiftedLambda {
     pub fn new(
         id: usize,
-        params: Vec<FreeVar<String>>,
+        params: LambdaParams,
         freevars: HashSet<FreeVar<String>>,
-        body: Rc<LExpr>,
+        body: Arc<LExpr>,
     ) -> Self {
         Self {
             id,
             params,
             freevars,
             body,
+            parent: None,
+            env_repr: EnvRepr::Flat,
+            line: None,
+            hint: None,
+            name: format!("lambda_{}", id),
+        }
+    }
+
+    /// The source line this lambda's code comes from, continuations and
+    /// other lambdas introduced by the compiler belong to the nearest
+    /// enclosing lambda that has one.
+    pub fn source_line(&self, lambdas: &BTreeMap<usize, LiftedLambda>) -> Option<usize> {
+        let mut lambda = self;
+
+        loop {
+            match (lambda.line, lambda.parent) {
+                (Some(line), _) => return Some(line),
+                (None, Some(parent)) => lambda = &lambdas[&parent],
+                (None, None) => return None,
+            }
+        }
+    }
+
+    /// The free variables that aren't parameters of this lambda, in the same
+    /// order on every run.
+    pub fn captures(&self) -> impl Iterator<Item = &FreeVar<String>> {
+        let mut captures = self
+            .freevars
+            .iter()
+            .filter(move |v| !self.params.iter().any(|p| p == *v))
+            .collect::<Vec<_>>();
+        captures.sort_by_key(|v| var_order(v));
+        captures.into_iter()
+    }
+
+    /// Whether `var` has a slot in this lambda's own environment.
+    pub fn holds(&self, var: &FreeVar<String>) -> bool {
+        self.freevars.contains(var)
+            && (self.env_repr == EnvRepr::Flat || self.params.iter().any(|p| p == var))
+    }
+
+    /// The variables in this lambda's own environment, in slot order. A
+    /// linked environment has the environment of its parent in the first
+    /// slot, which a placeholder variable stands for.
+    pub fn env_slots(&self) -> Vec<FreeVar<String>> {
+        let mut held = self
+            .freevars
+            .iter()
+            .filter(|v| self.holds(v))
+            .cloned()
+            .collect::<Vec<_>>();
+        held.sort_by_key(var_order);
+
+        match self.env_repr {
+            EnvRepr::Flat => held,
+            EnvRepr::Linked => std::iter::once(FreeVar::fresh_named("parent"))
+                .chain(held)
+                .collect(),
         }
     }
+
+    /// Continuations are entered once for each time they are created, so
+    /// slower access to their captures is paid for at most once, while saving
+    /// the copy of each capture at creation.
+    fn prefers_linked(&self) -> bool {
+        matches!(self.params, LambdaParams::One(_))
+            && self.captures().count() >= LINKED_MIN_CAPTURES
+    }
+}
+
+/// A program once its lambdas are lifted: the expression running its toplevel,
+/// and the lambdas it and they create, by id.
+#[derive(Debug, Clone)]
+pub struct Program {
+    entry: LExpr,
+    lambdas: BTreeMap<usize, LiftedLambda>,
+}
+
+impl Program {
+    pub fn new(entry: LExpr, lambdas: BTreeMap<usize, LiftedLambda>) -> Self {
+        Self { entry, lambdas }
+    }
+
+    /// The expression running the toplevel.
+    pub fn entry(&self) -> &LExpr {
+        &self.entry
+    }
+
+    pub fn lambda(&self, id: usize) -> Option<&LiftedLambda> {
+        self.lambdas.get(&id)
+    }
+
+    /// Every lambda, in order of id.
+    pub fn lambdas(&self) -> &BTreeMap<usize, LiftedLambda> {
+        &self.lambdas
+    }
+
+    /// The entry, and the lambdas to change while reading it.
+    pub fn parts_mut(&mut self) -> (&LExpr, &mut BTreeMap<usize, LiftedLambda>) {
+        (&self.entry, &mut self.lambdas)
+    }
+
+    /// The variables lambda `id` captures, in the same order on every run.
+    pub fn captures(&self, id: usize) -> Vec<&FreeVar<String>> {
+        self.lambdas[&id].captures().collect()
+    }
+
+    /// The lambdas that can call lambda `id` without looking at the closure
+    /// they call: those that make a closure of it, and those calling a
+    /// variable that only holds its closures. `None` stands for the entry.
+    pub fn callers_of(&self, id: usize) -> Vec<Option<usize>> {
+        struct Calls<'a> {
+            id: usize,
+            known: &'a HashMap<FreeVar<String>, usize>,
+            found: bool,
+        }
+
+        impl Visitor for Calls<'_> {
+            fn visit(&mut self, e: &LExpr) {
+                match e {
+                    LExpr::Lifted(Ignore(id)) if *id == self.id => self.found = true,
+                    LExpr::CallOne(f, _) | LExpr::CallTwo(f, _, _) => {
+                        if let LExpr::Var(Var::Free(v)) = &**f {
+                            self.found |= self.known.get(v) == Some(&self.id);
+                        }
+                        walk(self, e);
+                    }
+                    e => walk(self, e),
+                }
+            }
+        }
+
+        let known = known_lambdas(&self.entry, &self.lambdas);
+        let calls = |e: &LExpr| {
+            let mut calls = Calls {
+                id,
+                known: &known,
+                found: false,
+            };
+            calls.visit(e);
+            calls.found
+        };
+
+        std::iter::once((None, &self.entry))
+            .chain(self.lambdas.values().map(|l| (Some(l.id), &*l.body)))
+            .filter(|(_, body)| calls(body))
+            .map(|(caller, _)| caller)
+            .collect()
+    }
+
+    /// Drop the lambdas that are no longer made, see `drop_dead_lambdas`.
+    pub fn drop_dead_lambdas(&mut self) {
+        drop_dead_lambdas(&self.entry, &mut self.lambdas);
+    }
+
+    /// The number of nodes in the entry and the bodies of the lambdas.
+    pub fn size(&self) -> usize {
+        self.entry.size() + self.lambdas.values().map(|l| l.body.size()).sum::<usize>()
+    }
+}
+
+/// Record which lambda creates each lambda, and pick the environment
+/// representation of each one.
+pub fn select_env_reprs(lambdas: &mut BTreeMap<usize, LiftedLambda>) {
+    let mut parents = HashMap::new();
+
+    for lambda in lambdas.values() {
+        let mut children = Vec::new();
+        lambda.body.lifted_ids(&mut children);
+        parents.extend(children.into_iter().map(|c| (c, lambda.id)));
+    }
+
+    let mut depths = HashMap::new();
+    let ids = lambdas.keys().cloned().collect::<Vec<_>>();
+
+    for id in ids {
+        link_depth(id, lambdas, &parents, &mut depths);
+    }
+
+    for lambda in lambdas.values_mut() {
+        lambda.parent = parents.get(&lambda.id).cloned();
+
+        if depths[&lambda.id] > 0 {
+            lambda.env_repr = EnvRepr::Linked;
+        }
+    }
+}
+
+/// Drop the lambdas that aren't created by `expr` or by a lambda that is
+/// kept, so that no code is generated for them.
+pub fn drop_dead_lambdas(expr: &LExpr, lambdas: &mut BTreeMap<usize, LiftedLambda>) {
+    let mut live = HashSet::new();
+    let mut pending = Vec::new();
+    expr.lifted_ids(&mut pending);
+
+    while let Some(id) = pending.pop() {
+        if live.insert(id) {
+            lambdas[&id].body.lifted_ids(&mut pending);
+        }
+    }
+
+    lambdas.retain(|id, _| live.contains(id));
+}
+
+/// The variables that need a cell of their own: those that are `set!` and
+/// captured by a lambda, so that every environment holding one sees the
+/// assignments to it. The rest are kept in their environment slots as they
+/// are.
+pub fn boxed_vars(
+    expr: &LExpr,
+    lambdas: &BTreeMap<usize, LiftedLambda>,
+) -> HashSet<FreeVar<String>> {
+    let mut set = HashSet::new();
+    expr.set_vars(&mut set);
+    for lambda in lambdas.values() {
+        lambda.body.set_vars(&mut set);
+    }
+
+    let captured = lambdas
+        .values()
+        .flat_map(|l| l.captures())
+        .collect::<HashSet<_>>();
+
+    set.into_iter().filter(|v| captured.contains(v)).collect()
+}
+
+/// The parameters that are only used while the lambda binding them runs, by
+/// its body and by the closures it makes, which copy them. These can be kept
+/// in locals of the lambda's function rather than in its environment. A linked
+/// lambda goes through its parent's environment whenever it runs, so the
+/// parameters it captures stay there.
+pub fn local_params(lambdas: &BTreeMap<usize, LiftedLambda>) -> HashSet<FreeVar<String>> {
+    let linked = lambdas
+        .values()
+        .filter(|l| l.env_repr == EnvRepr::Linked)
+        .flat_map(|l| l.captures())
+        .collect::<HashSet<_>>();
+
+    lambdas
+        .values()
+        .flat_map(|l| l.params.iter())
+        .filter(|p| !linked.contains(p))
+        .cloned()
+        .collect()
+}
+
+/// The variables that only ever hold closures of one lambda, as a define or
+/// letrec leaves them: they are `set!` once, to the only place the lambda is
+/// made. The closure a variable holds while its lambda runs, or while the
+/// lambdas made by it run, is then the closure of that run.
+pub fn known_lambdas(
+    expr: &LExpr,
+    lambdas: &BTreeMap<usize, LiftedLambda>,
+) -> HashMap<FreeVar<String>, usize> {
+    #[derive(Default)]
+    struct Sets {
+        /// the lambda each `set!` of a variable makes, or none
+        sets: HashMap<FreeVar<String>, Vec<Option<usize>>>,
+        /// how many places make each lambda
+        made: HashMap<usize, usize>,
+    }
+
+    impl Visitor for Sets {
+        fn visit(&mut self, e: &LExpr) {
+            match e {
+                LExpr::SetThen(Var::Free(v), e, _) => {
+                    let made = match **e {
+                        LExpr::Lifted(Ignore(id)) => Some(id),
+                        _ => None,
+                    };
+                    self.sets.entry(v.clone()).or_default().push(made);
+                }
+                LExpr::Lifted(Ignore(id)) => *self.made.entry(*id).or_default() += 1,
+                _ => {}
+            }
+
+            walk(self, e);
+        }
+    }
+
+    let mut sets = Sets::default();
+    sets.visit(expr);
+    for lambda in lambdas.values() {
+        sets.visit(&lambda.body);
+    }
+
+    let Sets { sets, made } = sets;
+
+    sets.into_iter()
+        .filter_map(|(v, sets)| match sets[..] {
+            [Some(id)] if made[&id] == 1 => Some((v, id)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Name each lambda after the variable it was bound to, other lambdas are
+/// numbered within the nearest named lambda enclosing them. This only depends
+/// on the shape of the program, so an edit elsewhere leaves names alone.
+pub fn assign_names(lambdas: &mut BTreeMap<usize, LiftedLambda>) {
+    let mut lineages = HashMap::new();
+    for &id in lambdas.keys() {
+        lineage(id, lambdas, &mut lineages);
+    }
+
+    // parents are named before their children
+    let mut ids = lambdas.keys().cloned().collect::<Vec<_>>();
+    ids.sort_by_key(|id| (lineages[id].0, *id));
+
+    let mut taken = TakenNames::default();
+    let mut counts = HashMap::new();
+
+    for id in ids {
+        let name = match &lambdas[&id].hint {
+            Some(hint) => format!("scm_{}", mangle(hint)),
+            None => {
+                let owner = lineages[&id].1;
+                let count = counts.entry(owner).or_insert(0);
+                *count += 1;
+
+                match owner {
+                    Some(o) => format!("{}_lambda_{}", lambdas[&o].name, count),
+                    None => format!("scm_lambda_{}", count),
+                }
+            }
+        };
+
+        lambdas.get_mut(&id).unwrap().name = unique_name(&mut taken, name);
+    }
+}
+
+/// How many lambdas enclose this one, and the nearest of them with a hint.
+fn lineage(
+    id: usize,
+    lambdas: &BTreeMap<usize, LiftedLambda>,
+    lineages: &mut HashMap<usize, (usize, Option<usize>)>,
+) -> (usize, Option<usize>) {
+    if let Some(&lineage) = lineages.get(&id) {
+        return lineage;
+    }
+
+    let lineage = match lambdas[&id].parent {
+        Some(parent) => {
+            let (depth, owner) = lineage(parent, lambdas, lineages);
+            let owner = match lambdas[&parent].hint {
+                Some(_) => Some(parent),
+                None => owner,
+            };
+            (depth + 1, owner)
+        }
+        None => (0, None),
+    };
+
+    lineages.insert(id, lineage);
+    lineage
+}
+
+/// The source line of each lambda, as `LiftedLambda::source_line` gives it,
+/// found for all of them at once rather than by walking up from each.
+pub fn source_lines(lambdas: &BTreeMap<usize, LiftedLambda>) -> HashMap<usize, Option<usize>> {
+    fn source_line(
+        id: usize,
+        lambdas: &BTreeMap<usize, LiftedLambda>,
+        lines: &mut HashMap<usize, Option<usize>>,
+    ) -> Option<usize> {
+        if let Some(&line) = lines.get(&id) {
+            return line;
+        }
+
+        let line = match (lambdas[&id].line, lambdas[&id].parent) {
+            (Some(line), _) => Some(line),
+            (None, Some(parent)) => source_line(parent, lambdas, lines),
+            (None, None) => None,
+        };
+
+        lines.insert(id, line);
+        line
+    }
+
+    let mut lines = HashMap::new();
+    for &id in lambdas.keys() {
+        source_line(id, lambdas, &mut lines);
+    }
+
+    lines
+}
+
+/// Where each lambda starts and ends in a walk of the lambdas, each visited
+/// after the lambda making it, so that a lambda is made by a run of another
+/// exactly when its span is inside the other's.
+pub fn lambda_spans(lambdas: &BTreeMap<usize, LiftedLambda>) -> HashMap<usize, (usize, usize)> {
+    let mut children = BTreeMap::<Option<usize>, Vec<usize>>::new();
+    for lambda in lambdas.values() {
+        children.entry(lambda.parent).or_default().push(lambda.id);
+    }
+
+    let mut spans = HashMap::new();
+    let mut pending = children
+        .get(&None)
+        .map(|roots| roots.iter().map(|&id| (id, false)).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let mut step = 0;
+
+    while let Some((id, done)) = pending.pop() {
+        step += 1;
+
+        if done {
+            spans
+                .entry(id)
+                .and_modify(|span: &mut (usize, usize)| span.1 = step);
+        } else {
+            spans.insert(id, (step, step));
+            pending.push((id, true));
+            if let Some(made) = children.get(&Some(id)) {
+                pending.extend(made.iter().map(|&child| (child, false)));
+            }
+        }
+    }
+
+    spans
+}
+
+/// The number of linked environments ending at this lambda, zero if it is
+/// flat.
+fn link_depth(
+    id: usize,
+    lambdas: &BTreeMap<usize, LiftedLambda>,
+    parents: &HashMap<usize, usize>,
+    depths: &mut HashMap<usize, usize>,
+) -> usize {
+    if let Some(&depth) = depths.get(&id) {
+        return depth;
+    }
+
+    let depth = match parents.get(&id) {
+        Some(&parent) if lambdas[&id].prefers_linked() => {
+            let parent_depth = link_depth(parent, lambdas, parents, depths);
+            if parent_depth < MAX_LINK_DEPTH {
+                parent_depth + 1
+            } else {
+                0
+            }
+        }
+        _ => 0,
+    };
+
+    depths.insert(id, depth);
+    depth
+}
+
+/// What evaluating an expression does besides giving its value, not counting
+/// the bodies of the lambdas it creates. Expressions without effects can be
+/// evaluated in any order, shared or dropped, once the variables they read
+/// are known not to be `set!` in between.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Effects {
+    /// assigns a variable
+    pub assigns: bool,
+    /// makes an object that `eq?` tells apart from any other, a closure or
+    /// the string of a literal
+    pub allocates: bool,
+    /// passes control to another closure, which never returns
+    pub calls: bool,
+}
+
+impl Effects {
+    pub fn is_pure(self) -> bool {
+        self == Effects::default()
+    }
+
+    fn union(self, other: Effects) -> Effects {
+        Effects {
+            assigns: self.assigns || other.assigns,
+            allocates: self.allocates || other.allocates,
+            calls: self.calls || other.calls,
+        }
+    }
+}
+
+/// A pass that looks at an expression. `visit` is called on each node and by
+/// default goes on to its subexpressions, so a pass matches the nodes it looks
+/// at and leaves the rest to `walk`. The bodies of the lambdas an expression
+/// creates aren't part of it.
+pub trait Visitor {
+    fn visit(&mut self, e: &LExpr) {
+        walk(self, e);
+    }
+}
+
+/// Visit each subexpression of `e`, in order.
+pub fn walk<V: Visitor + ?Sized>(visitor: &mut V, e: &LExpr) {
+    for child in e.children() {
+        visitor.visit(child);
+    }
+}
+
+/// A pass that rebuilds an expression, by default each node is rebuilt from
+/// its folded subexpressions.
+pub trait Folder {
+    fn fold(&mut self, e: &LExpr) -> LExpr {
+        fold_children(self, e)
+    }
+}
+
+/// `e` with each of its subexpressions folded.
+pub fn fold_children<F: Folder + ?Sized>(folder: &mut F, e: &LExpr) -> LExpr {
+    let mut go = |e: &Arc<LExpr>| Arc::new(folder.fold(e));
+
+    match e {
+        LExpr::Var(_) | LExpr::Lit(_) | LExpr::BuiltinIdent(_) | LExpr::Lifted(_) => e.clone(),
+        LExpr::SetThen(v, e, c) => LExpr::SetThen(v.clone(), go(e), go(c)),
+        LExpr::If(c, ift, iff) => LExpr::If(go(c), go(ift), go(iff)),
+        LExpr::CallOne(f, a) => LExpr::CallOne(go(f), go(a)),
+        LExpr::CallTwo(f, a, k) => LExpr::CallTwo(go(f), go(a), go(k)),
+        LExpr::IntOp(op, a, b) => LExpr::IntOp(op.clone(), go(a), go(b)),
+    }
 }
 
 impl LExpr {
+    /// The subexpressions, in the order they are evaluated in.
+    pub fn children(&self) -> Vec<&LExpr> {
+        match self {
+            LExpr::Var(_) | LExpr::Lit(_) | LExpr::BuiltinIdent(_) | LExpr::Lifted(_) => vec![],
+            LExpr::SetThen(_, a, b) | LExpr::CallOne(a, b) | LExpr::IntOp(_, a, b) => vec![a, b],
+            LExpr::If(a, b, c) | LExpr::CallTwo(a, b, c) => vec![a, b, c],
+        }
+    }
+
+    /// The effects of this expression, from those of its parts. Variables,
+    /// builtins and literals other than strings have none.
+    pub fn effects(&self) -> Effects {
+        let own = Effects {
+            assigns: matches!(self, LExpr::SetThen(..)),
+            allocates: matches!(
+                self,
+                LExpr::Lit(Ignore(Literal::String(_))) | LExpr::Lifted(_)
+            ),
+            calls: matches!(self, LExpr::CallOne(..) | LExpr::CallTwo(..)),
+        };
+
+        self.children()
+            .into_iter()
+            .fold(own, |effects, child| effects.union(child.effects()))
+    }
+
+    pub fn is_pure(&self) -> bool {
+        self.effects().is_pure()
+    }
+
+    /// The number of nodes, not counting the bodies of the lambdas this
+    /// creates.
+    pub fn size(&self) -> usize {
+        1 + self.children().iter().map(|c| c.size()).sum::<usize>()
+    }
+
+    /// The lambdas created directly by this expression.
+    pub(crate) fn lifted_ids(&self, out: &mut Vec<usize>) {
+        struct Lifted<'a>(&'a mut Vec<usize>);
+
+        impl Visitor for Lifted<'_> {
+            fn visit(&mut self, e: &LExpr) {
+                match e {
+                    LExpr::Lifted(Ignore(id)) => self.0.push(*id),
+                    e => walk(self, e),
+                }
+            }
+        }
+
+        Lifted(out).visit(self);
+    }
+
+    /// The variables `set!` by this expression, not counting the lambdas it
+    /// creates.
+    pub(crate) fn set_vars(&self, out: &mut HashSet<FreeVar<String>>) {
+        struct Set<'a>(&'a mut HashSet<FreeVar<String>>);
+
+        impl Visitor for Set<'_> {
+            fn visit(&mut self, e: &LExpr) {
+                if let LExpr::SetThen(Var::Free(v), _, _) = e {
+                    self.0.insert(v.clone());
+                }
+
+                walk(self, e);
+            }
+        }
+
+        Set(out).visit(self);
+    }
+
     pub fn pretty<'a, D>(&self, allocator: &'a D) -> DocBuilder<'a, D, ColorSpec>
     where
         D: DocAllocator<'a, ColorSpec>,
@@ -120,6 +760,15 @@ impl LExpr {
                     .group()
                     .parens()
             }
+            LExpr::IntOp(Ignore(op), a, b) => allocator
+                .text(format!("int{}", op))
+                .annotate(ColorSpec::new().set_fg(Some(Color::Blue)).clone())
+                .append(allocator.space())
+                .append(a.pretty(allocator))
+                .append(allocator.space())
+                .append(b.pretty(allocator))
+                .group()
+                .parens(),
         }
     }
 
@@ -0,0 +1,237 @@
+use failure::{format_err, Error};
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::cdsl::mangle;
+
+/// A file compiled to its own C file, its toplevel defines are globals that
+/// the other files of the program can refer to.
+#[derive(Debug, Clone)]
+pub struct Module {
+    /// the prefix of everything the module's C file exports
+    pub name: String,
+    /// the path the module was read from
+    pub source: String,
+    /// the names defined at the toplevel
+    pub exports: Vec<String>,
+}
+
+/// The C variable holding the global `name`.
+pub fn global_name(name: &str) -> String {
+    format!("scm_global_{}", mangle(name))
+}
+
+impl Module {
+    pub fn new(source: &Path, exports: Vec<String>) -> Self {
+        let stem = source.file_stem().unwrap_or_default().to_string_lossy();
+
+        Self {
+            name: format!("module_{}", mangle(&stem)),
+            source: source.display().to_string(),
+            exports,
+        }
+    }
+
+    /// The function running the toplevel of the module.
+    pub fn entry(&self) -> String {
+        format!("{}_entry", self.name)
+    }
+
+    /// The table of the symbols the module uses.
+    pub fn symbols(&self) -> String {
+        format!("{}_symbols", self.name)
+    }
+
+    /// The table of where the module's lambdas are.
+    pub fn lambdas(&self) -> String {
+        format!("{}_lambdas", self.name)
+    }
+
+    pub fn c_file(&self) -> String {
+        format!("{}.c", self.name)
+    }
+
+    pub fn object_file(&self) -> String {
+        format!("{}.o", self.name)
+    }
+
+    pub fn header_file(&self) -> String {
+        format!("{}.h", self.name)
+    }
+
+    /// The header declaring what the module defines, every module includes
+    /// the headers of all of them.
+    pub fn header(&self) -> String {
+        let guard = self.name.to_uppercase();
+        let mut out = String::new();
+
+        let _ = writeln!(out, "#ifndef {}_H", guard);
+        let _ = writeln!(out, "#define {}_H\n", guard);
+        let _ = writeln!(out, "#include \"base.h\"\n");
+        let _ = writeln!(out, "// globals defined by {}", self.source);
+
+        for export in &self.exports {
+            let _ = writeln!(out, "extern struct obj *{};", global_name(export));
+        }
+
+        let _ = writeln!(
+            out,
+            "\nvoid {}(struct obj *, struct env_obj *) __attribute__((noreturn));",
+            self.entry()
+        );
+        let _ = writeln!(out, "extern struct symbol_obj *{}[];", self.symbols());
+        let _ = writeln!(out, "extern struct lambda_info {}[];", self.lambdas());
+        let _ = writeln!(out, "\n#endif // {}_H", guard);
+
+        out
+    }
+}
+
+/// Every module has to have a distinct name, and every global has to be
+/// defined by only one module.
+pub fn check_modules(modules: &[Module]) -> Result<(), Error> {
+    let mut names = HashMap::new();
+    let mut globals = HashMap::new();
+
+    for module in modules {
+        if let Some(other) = names.insert(&module.name, &module.source) {
+            return Err(format_err!(
+                "{} and {} would both be compiled to {}",
+                other,
+                module.source,
+                module.c_file()
+            ));
+        }
+
+        for export in &module.exports {
+            if let Some((other, other_name)) =
+                globals.insert(global_name(export), (&module.source, export))
+            {
+                return Err(if other_name == export {
+                    format_err!(
+                        "{} is defined in both {} and {}",
+                        export,
+                        other,
+                        module.source
+                    )
+                } else {
+                    format_err!(
+                        "{} in {} and {} in {} have the same C name",
+                        other_name,
+                        other,
+                        export,
+                        module.source
+                    )
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The C file tying the modules together, it defines the globals and runs the
+/// toplevel of each module in order, each continuing into the next.
+pub fn link_unit(modules: &[Module]) -> String {
+    let mut out = String::new();
+
+    out.push_str(
+        r#"
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "gc.h"
+#include "compiled_result.h"
+"#,
+    );
+
+    for module in modules {
+        let _ = writeln!(out, "#include \"{}\"", module.header_file());
+    }
+
+    out.push('\n');
+
+    let globals = modules
+        .iter()
+        .flat_map(|m| m.exports.iter().map(|e| global_name(e)))
+        .collect::<Vec<_>>();
+
+    for global in &globals {
+        let _ = writeln!(out, "struct obj *{} = NULL;", global);
+    }
+
+    let _ = writeln!(
+        out,
+        "\nstatic void (*const scheme_modules[])(struct obj *, struct env_obj *) = {{"
+    );
+    for module in modules {
+        let _ = writeln!(out, "  {},", module.entry());
+    }
+    let _ = writeln!(out, "}};");
+
+    out.push_str(
+        r#"
+static size_t scheme_next_module = 0;
+
+// Each module continues here once its toplevel has run.
+void module_done_k(struct obj *rv, struct env_obj *env) {
+  (void)rv;
+  (void)env;
+
+  if (scheme_next_module == sizeof(scheme_modules) / sizeof(scheme_modules[0])) {
+    exit(0);
+  }
+
+  struct closure_obj next =
+      object_closure_one_new(scheme_modules[scheme_next_module++], NULL);
+  call_closure_one((struct obj *)&next, NULL);
+  __builtin_unreachable();
+}
+
+static void link_start(struct obj *rv, struct env_obj *env) {
+"#,
+    );
+
+    for global in &globals {
+        let _ = writeln!(out, "  gc_register_root(&{});", global);
+    }
+
+    out.push_str(
+        r#"  module_done_k(rv, env);
+}
+
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(link_start, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+"#,
+    );
+
+    for module in modules {
+        let _ = writeln!(out, "  symbol_register_static({});", module.symbols());
+        let _ = writeln!(out, "  lambda_info_register({});", module.lambdas());
+    }
+
+    out.push_str(
+        r#"  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
+"#,
+    );
+
+    out
+}
@@ -0,0 +1,575 @@
+//! Warnings about code that is most likely a mistake. The lints look at the
+//! parse tree, before anything is expanded or renamed, so that their warnings
+//! can point at the source. Each lint is allowed, warns or is denied, and a
+//! denied lint stops the program from being compiled.
+//!
+//! Following the scopes of the program to check it, each variable is resolved
+//! to where it is bound, which `references` gives for the language server.
+
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use failure::{format_err, Error};
+use pest::error::{Error as PestError, ErrorVariant};
+use pest::iterators::Pair;
+use pest::{Parser, Span};
+
+use crate::interp::builtin_arity;
+use crate::lexical;
+use crate::parse::{Rule, SchemeParser};
+use crate::partial_eval::PURE_BUILTINS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lint {
+    /// A parameter, `let` binding or internal define that is never used
+    UnusedVariable,
+    /// A binding of a name already bound around it, other than by a define at
+    /// the toplevel
+    ShadowedBinding,
+    /// An expression without effects whose value is thrown away in a body
+    DiscardedValue,
+    /// A builtin given more or fewer arguments than it takes
+    BuiltinArity,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 4] = [
+        Lint::UnusedVariable,
+        Lint::ShadowedBinding,
+        Lint::DiscardedValue,
+        Lint::BuiltinArity,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::UnusedVariable => "unused-variable",
+            Lint::ShadowedBinding => "shadowed-binding",
+            Lint::DiscardedValue => "discarded-value",
+            Lint::BuiltinArity => "builtin-arity",
+        }
+    }
+
+    fn default_level(self) -> Level {
+        match self {
+            Lint::ShadowedBinding => Level::Allow,
+            _ => Level::Warn,
+        }
+    }
+}
+
+impl FromStr for Lint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Lint::ALL
+            .iter()
+            .copied()
+            .find(|l| l.name() == s)
+            .ok_or_else(|| format_err!("unknown lint: {}", s))
+    }
+}
+
+/// The names `--warn`, `--deny` and `--allow` take, `all` being every lint.
+pub const NAMES: &[&str] = &[
+    "all",
+    "unused-variable",
+    "shadowed-binding",
+    "discarded-value",
+    "builtin-arity",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+/// The level of each lint, shadowed bindings are allowed unless asked for and
+/// the rest warn.
+#[derive(Debug, Clone)]
+pub struct Lints {
+    levels: [Level; 4],
+}
+
+impl Default for Lints {
+    fn default() -> Self {
+        Self {
+            levels: Lint::ALL.map(Lint::default_level),
+        }
+    }
+}
+
+impl Lints {
+    pub fn level(&self, lint: Lint) -> Level {
+        self.levels[lint as usize]
+    }
+
+    /// Set the level of the lint `name`, or of every lint for `all`.
+    pub fn set(&mut self, name: &str, level: Level) -> Result<(), Error> {
+        if name == "all" {
+            self.levels = [level; 4];
+        } else {
+            self.levels[name.parse::<Lint>()? as usize] = level;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Warning {
+    pub lint: Lint,
+    pub level: Level,
+    pub line: usize,
+    pub message: String,
+    /// Where the warning points, as offsets of the source
+    pub span: Range<usize>,
+    error: PestError<Rule>,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+/// What a name in the program refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    Builtin,
+    /// Bound by a lambda, `let` or internal define, at these offsets
+    Local(Range<usize>),
+    /// Defined at the toplevel of the program or of a library, at these
+    /// offsets
+    Global(Range<usize>),
+    /// Bound nowhere in the source, so imported, included or unbound
+    Free,
+}
+
+/// A variable or builtin in the program, or a variable being bound, which
+/// refers to itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    pub name: String,
+    /// Where the name is, as offsets of the source
+    pub span: Range<usize>,
+    pub resolution: Resolution,
+}
+
+/// The warnings of the lints that aren't allowed for the program `source`,
+/// read from `path`. A program that doesn't parse has none, the parser
+/// reports what is wrong with it.
+pub fn check(source: &str, path: Option<&str>, lints: &Lints) -> Vec<Warning> {
+    walk(source, path, lints).0
+}
+
+/// The names in the program `source`, in the order they are written, with
+/// what each refers to. The includes and `cond-expand`s aren't expanded, so
+/// what they define is free. A program that doesn't parse has none.
+pub fn references(source: &str) -> Vec<Reference> {
+    let lints = Lints {
+        levels: [Level::Allow; 4],
+    };
+
+    walk(source, None, &lints).1
+}
+
+fn walk(source: &str, path: Option<&str>, lints: &Lints) -> (Vec<Warning>, Vec<Reference>) {
+    let source = &lexical::prepare(source);
+    let pairs = match SchemeParser::parse(Rule::program, source) {
+        Ok(pairs) => pairs,
+        Err(_) => return (Vec::new(), Vec::new()),
+    };
+
+    let mut checker = Checker {
+        lints,
+        path,
+        scopes: Vec::new(),
+        warnings: Vec::new(),
+        references: Vec::new(),
+    };
+
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::define_library_form => {
+                let forms = pair
+                    .into_inner()
+                    .filter(|p| p.as_rule() == Rule::library_begin)
+                    .flat_map(|begin| begin.into_inner())
+                    .collect();
+                checker.toplevel(forms, false);
+            }
+            Rule::body => checker.toplevel(pair.into_inner().collect(), true),
+            _ => {}
+        }
+    }
+
+    checker.warnings.sort_by_key(|w| w.span.start);
+    checker.references.sort_by_key(|r| r.span.start);
+    (checker.warnings, checker.references)
+}
+
+struct Binding<'i> {
+    name: &'i str,
+    span: Span<'i>,
+    used: bool,
+    /// Defined at the toplevel, which is never reported
+    global: bool,
+}
+
+impl Binding<'_> {
+    fn resolution(&self) -> Resolution {
+        let span = self.span.start()..self.span.end();
+
+        if self.global {
+            Resolution::Global(span)
+        } else {
+            Resolution::Local(span)
+        }
+    }
+}
+
+struct Checker<'a, 'i> {
+    lints: &'a Lints,
+    path: Option<&'a str>,
+    /// The bindings in scope, innermost last, starting with the toplevel
+    /// defines of the program or library being checked
+    scopes: Vec<Vec<Binding<'i>>>,
+    warnings: Vec<Warning>,
+    references: Vec<Reference>,
+}
+
+impl<'a, 'i> Checker<'a, 'i> {
+    fn warn(&mut self, lint: Lint, span: Span<'i>, message: String) {
+        let level = self.lints.level(lint);
+
+        let prefix = match level {
+            Level::Allow => return,
+            Level::Warn => "warning",
+            Level::Deny => "error",
+        };
+
+        let error = PestError::new_from_span(
+            ErrorVariant::CustomError {
+                message: format!("{}: {} [{}]", prefix, message, lint.name()),
+            },
+            span,
+        );
+
+        self.warnings.push(Warning {
+            lint,
+            level,
+            line: span.start_pos().line_col().0,
+            message,
+            span: span.start()..span.end(),
+            error: match self.path {
+                Some(path) => error.with_path(path),
+                None => error,
+            },
+        });
+    }
+
+    /// Start a scope binding the variables `binders`.
+    fn push_scope(&mut self, binders: Vec<Pair<'i, Rule>>) {
+        for binder in &binders {
+            let shadowed = self
+                .scopes
+                .iter()
+                .flatten()
+                .rev()
+                .find(|b| !b.global && b.name == binder.as_str())
+                .map(|b| b.span.start_pos().line_col().0);
+
+            if let Some(line) = shadowed {
+                self.warn(
+                    Lint::ShadowedBinding,
+                    binder.as_span(),
+                    format!("{} shadows the binding on line {}", binder.as_str(), line),
+                );
+            }
+        }
+
+        self.scopes.push(Vec::new());
+        self.bind(binders, false, false);
+    }
+
+    /// Add the variables `binders` to the innermost scope.
+    fn bind(&mut self, binders: Vec<Pair<'i, Rule>>, used: bool, global: bool) {
+        for binder in binders {
+            let binding = Binding {
+                name: binder.as_str(),
+                span: binder.as_span(),
+                used,
+                global,
+            };
+
+            self.references.push(Reference {
+                name: binding.name.to_owned(),
+                span: binding.span.start()..binding.span.end(),
+                resolution: binding.resolution(),
+            });
+            self.scopes.last_mut().unwrap().push(binding);
+        }
+    }
+
+    fn pop_scope(&mut self) {
+        for binding in self.scopes.pop().unwrap() {
+            // a name starting with an underscore is meant to go unused
+            if !binding.used && !binding.global && !binding.name.starts_with('_') {
+                self.warn(
+                    Lint::UnusedVariable,
+                    binding.span,
+                    format!("unused variable {}", binding.name),
+                );
+            }
+        }
+    }
+
+    /// What `name` refers to where it is used, marking the binding used.
+    fn use_var(&mut self, name: &str) -> Resolution {
+        let binding = self
+            .scopes
+            .iter_mut()
+            .flatten()
+            .rev()
+            .find(|b| b.name == name);
+
+        match binding {
+            Some(binding) => {
+                binding.used = true;
+                binding.resolution()
+            }
+            None => Resolution::Free,
+        }
+    }
+
+    fn refer(&mut self, pair: &Pair<'i, Rule>, resolution: Resolution) {
+        let span = pair.as_span();
+
+        self.references.push(Reference {
+            name: pair.as_str().to_owned(),
+            span: span.start()..span.end(),
+            resolution,
+        });
+    }
+
+    /// The forms of a program or library, whose defines are in scope in all
+    /// of them.
+    fn toplevel(&mut self, forms: Vec<Pair<'i, Rule>>, last_kept: bool) {
+        let (binders, quiet) = binders(&forms);
+
+        self.scopes.push(Vec::new());
+        self.bind(binders, false, true);
+        self.bind(quiet, true, true);
+        self.forms(forms, last_kept);
+        self.pop_scope();
+    }
+
+    /// The definitions and expressions of a body, the value of each expression
+    /// is thrown away but for the last one when `last_kept`.
+    fn forms(&mut self, forms: Vec<Pair<'i, Rule>>, last_kept: bool) {
+        let kept = if last_kept {
+            forms.len() - 1
+        } else {
+            forms.len()
+        };
+
+        for (i, form) in forms.into_iter().enumerate() {
+            match form.as_rule() {
+                Rule::define_form => self.expr(form.into_inner().nth(1).unwrap()),
+                Rule::define_procedure_form => {
+                    let mut inner = form.into_inner().skip(1);
+                    self.lambda(inner.next().unwrap(), inner.next().unwrap());
+                }
+                Rule::expr => {
+                    if i != kept && is_pure(&form) {
+                        self.warn(
+                            Lint::DiscardedValue,
+                            form.as_span(),
+                            "the value of this expression is thrown away".to_owned(),
+                        );
+                    }
+
+                    self.expr(form);
+                }
+                Rule::cond_expand_form => self.expr(form),
+                _ => {}
+            }
+        }
+    }
+
+    /// The body of a lambda or a `let`, where defines are local.
+    fn body(&mut self, body: Pair<'i, Rule>) {
+        let forms = body.into_inner().collect::<Vec<_>>();
+        let (binders, quiet) = binders(&forms);
+
+        self.push_scope(binders);
+        self.bind(quiet, true, false);
+        self.forms(forms, true);
+        self.pop_scope();
+    }
+
+    fn expr(&mut self, pair: Pair<'i, Rule>) {
+        match pair.as_rule() {
+            Rule::variable => {
+                let resolution = self.use_var(pair.as_str());
+                self.refer(&pair, resolution);
+            }
+            Rule::builtin => self.refer(&pair, Resolution::Builtin),
+            Rule::let_form => {
+                let mut inner = pair.into_inner();
+                let mut binders = Vec::new();
+
+                for binder in inner.next().unwrap().into_inner() {
+                    let mut binder = binder.into_inner();
+                    binders.push(binder.next().unwrap());
+                    self.expr(binder.next().unwrap());
+                }
+
+                self.push_scope(binders);
+                self.body(inner.next().unwrap());
+                self.pop_scope();
+            }
+            Rule::parameterize_form => {
+                let mut inner = pair.into_inner().skip(1).collect::<Vec<_>>();
+                let body = inner.pop().unwrap();
+
+                for child in inner {
+                    self.expr(child);
+                }
+
+                self.body(body);
+            }
+            Rule::lambda_form | Rule::case_lambda_clause => {
+                let mut inner = pair.into_inner();
+                self.lambda(inner.next().unwrap(), inner.next().unwrap());
+            }
+            // which clause is kept isn't known yet, so a name in any of them
+            // might be a use
+            Rule::cond_expand_form => {
+                for name in pair.into_inner().flatten() {
+                    if name.as_rule() == Rule::identifier {
+                        self.use_var(name.as_str());
+                    }
+                }
+            }
+            Rule::app => {
+                self.check_arity(&pair);
+
+                for child in pair.into_inner() {
+                    self.expr(child);
+                }
+            }
+            _ => {
+                for child in pair.into_inner() {
+                    self.expr(child);
+                }
+            }
+        }
+    }
+
+    fn lambda(&mut self, bindings: Pair<'i, Rule>, body: Pair<'i, Rule>) {
+        self.push_scope(bindings.into_inner().collect());
+        self.body(body);
+        self.pop_scope();
+    }
+
+    fn check_arity(&mut self, app: &Pair<'i, Rule>) {
+        let mut inner = app.clone().into_inner();
+        let f = unwrap_expr(inner.next().unwrap());
+
+        if f.as_rule() != Rule::builtin {
+            return;
+        }
+
+        let arity = match builtin_arity(f.as_str()) {
+            Some(arity) => arity,
+            None => return,
+        };
+        let args = inner.count();
+
+        // those taking no arguments are given null
+        if args == arity || (args == 0 && arity == 1) {
+            return;
+        }
+
+        self.warn(
+            Lint::BuiltinArity,
+            app.as_span(),
+            format!(
+                "{} takes {} argument{} but is given {}",
+                f.as_str(),
+                arity,
+                if arity == 1 { "" } else { "s" },
+                args
+            ),
+        );
+    }
+}
+
+/// The variables the defines of a body bind, and those bound by records and
+/// foreign functions, which bind several names at once that needn't all be
+/// used.
+fn binders<'i>(forms: &[Pair<'i, Rule>]) -> (Vec<Pair<'i, Rule>>, Vec<Pair<'i, Rule>>) {
+    let mut binders = Vec::new();
+    let mut quiet = Vec::new();
+
+    for form in forms {
+        match form.as_rule() {
+            Rule::define_form | Rule::define_procedure_form => {
+                binders.push(form.clone().into_inner().next().unwrap())
+            }
+            Rule::define_record_form | Rule::define_foreign_form => quiet.extend(
+                form.clone()
+                    .into_inner()
+                    .flatten()
+                    .filter(|p| p.as_rule() == Rule::variable),
+            ),
+            _ => {}
+        }
+    }
+
+    (binders, quiet)
+}
+
+fn unwrap_expr(pair: Pair<Rule>) -> Pair<Rule> {
+    match pair.as_rule() {
+        Rule::expr => unwrap_expr(pair.into_inner().next().unwrap()),
+        _ => pair,
+    }
+}
+
+/// Whether evaluating the expression can have no effect, other than failing.
+fn is_pure(pair: &Pair<Rule>) -> bool {
+    match pair.as_rule() {
+        Rule::variable
+        | Rule::builtin
+        | Rule::lambda_form
+        | Rule::case_lambda_form
+        | Rule::number
+        | Rule::decimal
+        | Rule::radix_number
+        | Rule::character
+        | Rule::rational
+        | Rule::quoted_string
+        | Rule::symbol_literal
+        | Rule::boolean
+        | Rule::null => true,
+        Rule::expr
+        | Rule::literal
+        | Rule::list_literal
+        | Rule::vector_literal
+        | Rule::bytevector_literal
+        | Rule::if_form => pair.clone().into_inner().all(|p| is_pure(&p)),
+        Rule::app => {
+            let mut inner = pair.clone().into_inner();
+            let f = unwrap_expr(inner.next().unwrap());
+
+            f.as_rule() == Rule::builtin
+                && PURE_BUILTINS.contains(&f.as_str())
+                && inner.all(|p| is_pure(&p))
+        }
+        _ => false,
+    }
+}
@@ -1,12 +1,22 @@
 use pretty::{DocAllocator, DocBuilder};
 use termcolor::{Color, ColorSpec};
 
+use std::sync::Arc;
+
 #[derive(Debug, Clone)]
 pub enum Literal {
     String(String),
     Int(i64),
+    /// Always in lowest terms with a positive denominator that isn't 1
+    Rational(i64, i64),
     Float(f64),
+    Symbol(String),
+    Bool(bool),
     Void,
+    /// A quoted list of constants, which compiled programs make once rather
+    /// than with a `cons` for each element every time it's evaluated. The
+    /// copies of a quote share its list, so are the same object.
+    Quoted(Arc<Vec<Literal>>),
 }
 
 impl Literal {
@@ -22,12 +32,101 @@ impl Literal {
             Literal::Int(v) => allocator
                 .as_string(v)
                 .annotate(ColorSpec::new().set_fg(Some(Color::Yellow)).clone()),
+            Literal::Rational(n, d) => allocator
+                .text(format!("{}/{}", n, d))
+                .annotate(ColorSpec::new().set_fg(Some(Color::Yellow)).clone()),
             Literal::Float(v) => allocator
-                .as_string(v)
+                .text(format_flonum(*v))
+                .annotate(ColorSpec::new().set_fg(Some(Color::Yellow)).clone()),
+            Literal::Symbol(s) => allocator
+                .text(format!("'{}", s))
+                .annotate(ColorSpec::new().set_fg(Some(Color::Magenta)).clone()),
+            Literal::Bool(b) => allocator
+                .text(if *b { "#t" } else { "#f" })
                 .annotate(ColorSpec::new().set_fg(Some(Color::Yellow)).clone()),
             Literal::Void => allocator
                 .text("void")
                 .annotate(ColorSpec::new().set_fg(Some(Color::Yellow)).clone()),
+            Literal::Quoted(items) => allocator
+                .text("'")
+                .append(
+                    allocator
+                        .intersperse(items.iter().map(|l| l.pretty(allocator)), allocator.space())
+                        .parens(),
+                )
+                .group(),
         }
     }
 }
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// Put a ratio into lowest terms with a positive denominator, the runtime
+/// does the same steps in the same order so that both overflow in the same
+/// places. Returns `None` on overflow.
+pub fn normalize_ratio(n: i64, d: i64) -> Option<(i64, i64)> {
+    assert!(d != 0, "ratio with a zero denominator");
+
+    let g = gcd(n.unsigned_abs(), d.unsigned_abs());
+    let (n, d) = if g > 1 {
+        (n / g as i64, d / g as i64)
+    } else {
+        (n, d)
+    };
+
+    if d < 0 {
+        Some((n.checked_neg()?, d.checked_neg()?))
+    } else {
+        Some((n, d))
+    }
+}
+
+/// Formats a float the same way the runtime does, the shortest digits that
+/// read back as the same value, with the point among them when the exponent
+/// is from -6 to 20, and `.0` on the end if they would otherwise look like an
+/// integer, or with an exponent after them outside that.
+pub fn format_flonum(f: f64) -> String {
+    if f.is_nan() {
+        return "+nan.0".to_owned();
+    }
+
+    if f.is_infinite() {
+        return if f > 0.0 { "+inf.0" } else { "-inf.0" }.to_owned();
+    }
+
+    let sci = (1..=17)
+        .map(|prec| format!("{:.*e}", prec - 1, f))
+        .find(|s| s.parse::<f64>() == Ok(f))
+        .unwrap_or_else(|| format!("{:.16e}", f));
+
+    let (mantissa, exp) = sci.split_at(sci.find('e').unwrap());
+    let exp: i32 = exp[1..].parse().unwrap();
+    let sign = if mantissa.starts_with('-') { "-" } else { "" };
+    let digits = mantissa[sign.len()..].replace('.', "");
+    let len = digits.len() as i32;
+
+    if !(-6..=20).contains(&exp) {
+        let rest = &digits[1..];
+        let point = if rest.is_empty() { "" } else { "." };
+        format!("{}{}{}{}e{}", sign, &digits[..1], point, rest, exp)
+    } else if exp < 0 {
+        format!("{}0.{}{}", sign, "0".repeat((-exp - 1) as usize), digits)
+    } else if exp + 1 >= len {
+        format!(
+            "{}{}{}.0",
+            sign,
+            digits,
+            "0".repeat((exp + 1 - len) as usize)
+        )
+    } else {
+        let (int, frac) = digits.split_at(exp as usize + 1);
+        format!("{}{}.{}", sign, int, frac)
+    }
+}
@@ -0,0 +1,687 @@
+use failure::{format_err, Error};
+use moniker::{FreeVar, Ignore};
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::codegen::runtime_builtin;
+use crate::foreign::Foreign;
+use crate::interp::unescape;
+use crate::lifted_expr::{
+    boxed_vars, source_lines, EnvRepr, LExpr, LambdaParams, LiftedLambda, Program,
+};
+use crate::literals::Literal;
+
+// The tags of `enum object_tag` in base.h, the first word of a static object
+// is its tag followed by a white mark and `on_stack` being false, then the
+// `size` of a closure
+const OBJ_CLOSURE: u32 = 1;
+const OBJ_INT: u32 = 3;
+const OBJ_STR: u32 = 4;
+const OBJ_CONS: u32 = 5;
+const OBJ_FLOAT: u32 = 8;
+const OBJ_RATIONAL: u32 = 9;
+const OBJ_SYMBOL: u32 = 10;
+
+// `enum closure_size`
+const CLOSURE_ONE: u8 = 0;
+const CLOSURE_TWO: u8 = 1;
+
+/// The runtime functions and objects the generated code uses, from base.c,
+/// gc.c, builtin.c and llvm.c.
+const DECLARATIONS: &str = "\
+%closure = type { i32, i8*, i8* }
+%symbol = type { i32, i64, i8* }
+%lambda_info = type { i8*, i8*, i8*, i64 }
+
+@scheme_true = external global i8
+@scheme_false = external global i8
+
+declare i8* @llvm_cell_new(i8*)
+declare i8* @llvm_env_new(i64)
+declare i8* @llvm_closure_new(i8 zeroext, i8*, i8*)
+declare i8* @llvm_closure_fn(i8*, i8 zeroext)
+declare void @llvm_collect_one(i8*, i8*) noreturn
+declare void @llvm_collect_two(i8*, i8*, i8*) noreturn
+declare zeroext i1 @gc_heap_full()
+declare void @gc_write_barrier(i8*, i8*)
+declare zeroext i1 @obj_is_truthy(i8*)
+declare i8* @object_int_new(i64)
+declare i8* @object_float_new(double)
+declare i8* @object_rational_new(i64, i64)
+declare i8* @object_string_new(i8*, i64)
+";
+
+/// Compile a lifted program to LLVM IR, which is built with `llc` and linked
+/// with the C runtime.
+///
+/// Lambdas have the same signatures as the functions the C backend emits, so
+/// the builtins of the runtime can call them, but they allocate everything on
+/// the heap and make their calls with `tail call`. The stack then only grows
+/// when a builtin calls back into the program, and no trampoline is needed
+/// except to collect, see `src/core/llvm.c`.
+pub fn compile(program: &Program, source_file: &str) -> Result<String, Error> {
+    let (expr, lambdas) = (program.entry(), program.lambdas());
+    let mut ctx = LlvmCtx::new(lambdas, boxed_vars(expr, lambdas));
+
+    let mut ids = lambdas.keys().cloned().collect::<Vec<_>>();
+    ids.sort();
+
+    for id in &ids {
+        ctx.generate_func(&lambdas[id])?;
+    }
+
+    ctx.lambda_table(&ids, source_file);
+
+    ctx.current = None;
+    ctx.temps = 0;
+    let mut body = Vec::new();
+    ctx.tail(expr, &mut body)?;
+    ctx.funcs
+        .push(function("void @main_lambda(i8* %a, i8* %env)", &body));
+
+    Ok(ctx.module())
+}
+
+struct LlvmCtx<'a> {
+    lambdas: &'a BTreeMap<usize, LiftedLambda>,
+    /// the slots of the environment of each lambda, in order
+    slots: HashMap<usize, Vec<FreeVar<String>>>,
+    /// the string constants, by their contents
+    strings: HashMap<String, String>,
+    /// the static symbol objects, by name
+    symbols: BTreeMap<String, String>,
+    /// the runtime function of each builtin used, and whether it takes two
+    /// arguments
+    builtins: BTreeMap<&'static str, bool>,
+    globals: Vec<String>,
+    funcs: Vec<String>,
+    current: Option<usize>,
+    temps: usize,
+    /// the variables kept in cells, the rest are kept in their slots
+    boxed: HashSet<FreeVar<String>>,
+}
+
+impl<'a> LlvmCtx<'a> {
+    fn new(lambdas: &'a BTreeMap<usize, LiftedLambda>, boxed: HashSet<FreeVar<String>>) -> Self {
+        Self {
+            lambdas,
+            slots: lambdas.values().map(|l| (l.id, l.env_slots())).collect(),
+            strings: HashMap::new(),
+            symbols: BTreeMap::new(),
+            builtins: BTreeMap::new(),
+            globals: Vec::new(),
+            funcs: Vec::new(),
+            current: None,
+            temps: 0,
+            boxed,
+        }
+    }
+
+    fn gen_temp(&mut self) -> String {
+        self.temps += 1;
+        format!("%v{}", self.temps)
+    }
+
+    fn gen_label(&mut self, hint: &str) -> String {
+        self.temps += 1;
+        format!("{}{}", hint, self.temps)
+    }
+
+    /// Load the pointer `offset` bytes into the object `ptr`.
+    fn load(&mut self, ptr: &str, offset: usize, body: &mut Vec<String>) -> String {
+        let field = self.field(ptr, offset, body);
+        let value = self.gen_temp();
+        body.push(format!("{} = load i8*, i8** {}", value, field));
+        value
+    }
+
+    fn store(&mut self, ptr: &str, offset: usize, value: &str, body: &mut Vec<String>) {
+        let field = self.field(ptr, offset, body);
+        body.push(format!("store i8* {}, i8** {}", value, field));
+    }
+
+    /// A store into an object that was already made, which may need the gc to
+    /// look inside it again, see `gc_write_barrier` in the runtime.
+    fn store_barrier(&mut self, ptr: &str, offset: usize, value: &str, body: &mut Vec<String>) {
+        self.store(ptr, offset, value, body);
+        body.push(format!(
+            "call void @gc_write_barrier(i8* {}, i8* {})",
+            ptr, value
+        ));
+    }
+
+    fn field(&mut self, ptr: &str, offset: usize, body: &mut Vec<String>) -> String {
+        let byte = self.gen_temp();
+        let field = self.gen_temp();
+        body.push(format!(
+            "{} = getelementptr i8, i8* {}, i64 {}",
+            byte, ptr, offset
+        ));
+        body.push(format!("{} = bitcast i8* {} to i8**", field, byte));
+        field
+    }
+
+    /// A pointer to a string constant holding `s` with a null byte after it.
+    fn string(&mut self, s: &str) -> String {
+        if let Some(name) = self.strings.get(s) {
+            return name.clone();
+        }
+
+        let name = format!("@.str.{}", self.strings.len());
+        self.globals.push(format!(
+            "{} = private unnamed_addr constant [{} x i8] c\"{}\\00\"",
+            name,
+            s.len() + 1,
+            escape(s.as_bytes())
+        ));
+
+        let ptr = format!(
+            "getelementptr ([{0} x i8], [{0} x i8]* {1}, i64 0, i64 0)",
+            s.len() + 1,
+            name
+        );
+        self.strings.insert(s.to_owned(), ptr.clone());
+        ptr
+    }
+
+    /// Symbols are static objects, registered with the runtime by `main` in
+    /// `llvm.c` like the C backend's `scheme_symbols`.
+    fn symbol(&mut self, s: &str) -> String {
+        if let Some(name) = self.symbols.get(s) {
+            return format!("bitcast (%symbol* {} to i8*)", name);
+        }
+
+        let name = format!("@.sym.{}", self.symbols.len());
+        let chars = self.string(s);
+        self.globals.push(format!(
+            "{} = internal global %symbol {{ i32 {}, i64 {}, i8* {} }}",
+            name,
+            OBJ_SYMBOL,
+            s.len(),
+            chars
+        ));
+
+        self.symbols.insert(s.to_owned(), name.clone());
+        format!("bitcast (%symbol* {} to i8*)", name)
+    }
+
+    /// A static object of the LLVM type `typ`, as an `i8*`.
+    fn static_object(&mut self, typ: &str, init: &str) -> String {
+        let name = format!("@.obj.{}", self.globals.len());
+        self.globals
+            .push(format!("{} = internal global {} {}", name, typ, init));
+
+        format!("bitcast ({}* {} to i8*)", typ, name)
+    }
+
+    /// A quoted list, made of static objects that are made once like symbols
+    /// rather than each time the quote is evaluated.
+    fn quoted(&mut self, items: &[Literal]) -> String {
+        let mut list = "null".to_owned();
+
+        for item in items.iter().rev() {
+            let car = self.constant(item);
+            list = self.static_object(
+                "{ i32, i8*, i8* }",
+                &format!("{{ i32 {}, i8* {}, i8* {} }}", OBJ_CONS, car, list),
+            );
+        }
+
+        list
+    }
+
+    /// A literal as a constant, for the items of a quoted list.
+    fn constant(&mut self, l: &Literal) -> String {
+        match l {
+            Literal::Int(i) if (i64::MIN >> 1..=i64::MAX >> 1).contains(i) => {
+                format!("inttoptr (i64 {} to i8*)", (i << 1) | 1)
+            }
+            Literal::Int(i) => {
+                self.static_object("{ i32, i64 }", &format!("{{ i32 {}, i64 {} }}", OBJ_INT, i))
+            }
+            Literal::Float(f) => self.static_object(
+                "{ i32, double }",
+                &format!("{{ i32 {}, double 0x{:016X} }}", OBJ_FLOAT, f.to_bits()),
+            ),
+            Literal::Rational(n, d) => self.static_object(
+                "{ i32, i64, i64 }",
+                &format!("{{ i32 {}, i64 {}, i64 {} }}", OBJ_RATIONAL, n, d),
+            ),
+            Literal::Bool(true) => "@scheme_true".to_owned(),
+            Literal::Bool(false) => "@scheme_false".to_owned(),
+            // the length of a string object counts the null byte
+            Literal::String(s) => {
+                let s = unescape(s);
+                let typ = format!("{{ i32, i64, [{} x i8] }}", s.len() + 1);
+                let init = format!(
+                    "{{ i32 {}, i64 {}, [{} x i8] c\"{}\\00\" }}",
+                    OBJ_STR,
+                    s.len() + 1,
+                    s.len() + 1,
+                    escape(s.as_bytes())
+                );
+                self.static_object(&typ, &init)
+            }
+            Literal::Symbol(s) => self.symbol(s),
+            Literal::Void => "null".to_owned(),
+            Literal::Quoted(items) => self.quoted(items),
+        }
+    }
+
+    /// Where each lambda is, registered with the runtime by `main` in `llvm.c`
+    /// like the C backend's `scheme_lambdas`.
+    fn lambda_table(&mut self, ids: &[usize], source_file: &str) {
+        let file = self.string(source_file);
+        let lines = source_lines(self.lambdas);
+        let mut entries = String::new();
+
+        for id in ids {
+            let lambda = &self.lambdas[id];
+            let size = match lambda.params {
+                LambdaParams::One(_) => CLOSURE_ONE,
+                LambdaParams::Two(..) => CLOSURE_TWO,
+            };
+            let line = lines[id].unwrap_or(1);
+            let name = self.string(&lambda.name);
+
+            let _ = write!(
+                entries,
+                "%lambda_info {{ i8* bitcast ({}* @{} to i8*), i8* {}, i8* {}, i64 {} }}, ",
+                lambda_type(size),
+                lambda.name,
+                name,
+                file,
+                line
+            );
+        }
+
+        self.globals.push(format!(
+            "@scheme_lambdas = global [{} x %lambda_info] [{}%lambda_info zeroinitializer]",
+            ids.len() + 1,
+            entries
+        ));
+    }
+
+    /// The static closure of a builtin, which has no environment.
+    fn builtin_closure(&mut self, name: &str) -> Result<String, Error> {
+        if Foreign::from_builtin_name(name).is_some() {
+            return Err(format_err!(
+                "foreign functions can't be called on the llvm target"
+            ));
+        }
+
+        let (params, runtime_name) = runtime_builtin(name);
+        self.builtins.insert(runtime_name, params == 2);
+
+        Ok(format!(
+            "bitcast (%closure* @.builtin.{} to i8*)",
+            runtime_name
+        ))
+    }
+
+    /// What the slot of `var` holds, `var` or its cell.
+    fn slot(&mut self, var: &FreeVar<String>, body: &mut Vec<String>) -> String {
+        let (env, offset) = self.locate(var, body);
+        self.load(&env, offset, body)
+    }
+
+    /// The environment holding `var`, walking up linked environments until
+    /// one that holds it, and the offset of its slot.
+    fn locate(&mut self, var: &FreeVar<String>, body: &mut Vec<String>) -> (String, usize) {
+        let lambdas = self.lambdas;
+        let mut env = "%env".to_owned();
+        let mut lambda = &lambdas[&self.current.expect("variable outside of any lambda")];
+
+        while !lambda.holds(var) {
+            // the parent of a linked environment is in its first slot
+            env = self.load(&env, 16, body);
+            lambda = &lambdas[&lambda.parent.expect("variable not in any environment")];
+        }
+
+        (env, slot_offset(&self.slots[&lambda.id], var))
+    }
+
+    fn generate_func(&mut self, lambda: &LiftedLambda) -> Result<(), Error> {
+        self.current = Some(lambda.id);
+        self.temps = 0;
+
+        let (signature, params) = match &lambda.params {
+            LambdaParams::One(a) => ("(i8* %a, i8* %env)", vec![(a, "%a")]),
+            LambdaParams::Two(a, b) => ("(i8* %a, i8* %b, i8* %env)", vec![(a, "%a"), (b, "%b")]),
+        };
+
+        let mut body = Vec::new();
+
+        for (param, value) in params {
+            // parameters that aren't used in the body are discarded
+            if lambda.freevars.contains(param) {
+                let mut value = value.to_owned();
+
+                if self.boxed.contains(param) {
+                    let cell = self.gen_temp();
+                    body.push(format!("{} = call i8* @llvm_cell_new(i8* {})", cell, value));
+                    value = cell;
+                }
+
+                let offset = slot_offset(&self.slots[&lambda.id], param);
+                self.store_barrier("%env", offset, &value, &mut body);
+            }
+        }
+
+        self.tail(&lambda.body, &mut body)?;
+
+        self.funcs.push(function(
+            &format!("internal void @{}{}", lambda.name, signature),
+            &body,
+        ));
+
+        Ok(())
+    }
+
+    /// The instructions of a lambda body, which ends in a call.
+    fn tail(&mut self, e: &LExpr, body: &mut Vec<String>) -> Result<(), Error> {
+        match e {
+            LExpr::SetThen(v, e, c) => {
+                self.set(v, e, body)?;
+                self.tail(c, body)
+            }
+            LExpr::If(c, ift, iff) => {
+                let cond = self.atom(c, body)?;
+                let truthy = self.gen_temp();
+                let then = self.gen_label("then");
+                let els = self.gen_label("else");
+
+                body.push(format!(
+                    "{} = call zeroext i1 @obj_is_truthy(i8* {})",
+                    truthy, cond
+                ));
+                body.push(format!("br i1 {}, label %{}, label %{}", truthy, then, els));
+
+                body.push(format!("{}:", then));
+                self.tail(ift, body)?;
+                body.push(format!("{}:", els));
+                self.tail(iff, body)
+            }
+            LExpr::CallOne(c, a) => {
+                let c = self.atom(c, body)?;
+                let a = self.atom(a, body)?;
+                self.call(&c, &[a], body);
+                Ok(())
+            }
+            LExpr::CallTwo(c, a, k) => {
+                let c = self.atom(c, body)?;
+                let a = self.atom(a, body)?;
+                let k = self.atom(k, body)?;
+                self.call(&c, &[a, k], body);
+                Ok(())
+            }
+            _ => Err(format_err!("lambda body isn't a call: {:?}", e)),
+        }
+    }
+
+    /// Tail call the closure `c`, unless the heap is full in which case the
+    /// call is made after collecting.
+    fn call(&mut self, c: &str, args: &[String], body: &mut Vec<String>) {
+        let (size, collect) = match args.len() {
+            1 => (CLOSURE_ONE, "llvm_collect_one"),
+            _ => (CLOSURE_TWO, "llvm_collect_two"),
+        };
+
+        let fn_ptr = self.gen_temp();
+        body.push(format!(
+            "{} = call i8* @llvm_closure_fn(i8* {}, i8 zeroext {})",
+            fn_ptr, c, size
+        ));
+        let env = self.load(c, 16, body);
+
+        let full = self.gen_temp();
+        let gc = self.gen_label("collect");
+        let go = self.gen_label("call");
+        body.push(format!("{} = call zeroext i1 @gc_heap_full()", full));
+        body.push(format!("br i1 {}, label %{}, label %{}", full, gc, go));
+
+        let args = args
+            .iter()
+            .map(|a| format!("i8* {}", a))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        body.push(format!("{}:", gc));
+        body.push(format!("call void @{}(i8* {}, {})", collect, c, args));
+        body.push("unreachable".to_owned());
+
+        let typed = self.gen_temp();
+        body.push(format!("{}:", go));
+        body.push(format!(
+            "{} = bitcast i8* {} to {}*",
+            typed,
+            fn_ptr,
+            lambda_type(size)
+        ));
+        body.push(format!("tail call void {}({}, i8* {})", typed, args, env));
+        body.push("ret void".to_owned());
+    }
+
+    fn set(
+        &mut self,
+        v: &moniker::Var<String>,
+        e: &LExpr,
+        body: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        let value = self.atom(e, body)?;
+        let var = match v {
+            moniker::Var::Free(f) => f,
+            moniker::Var::Bound(_) => panic!("bound var: {:?}", v),
+        };
+
+        if self.boxed.contains(var) {
+            let cell = self.slot(var, body);
+            self.store_barrier(&cell, 8, &value, body);
+        } else {
+            let (env, offset) = self.locate(var, body);
+            self.store_barrier(&env, offset, &value, body);
+        }
+
+        Ok(())
+    }
+
+    /// An operand for the value of `e`, with anything it needs done first
+    /// added to `body`.
+    fn atom(&mut self, e: &LExpr, body: &mut Vec<String>) -> Result<String, Error> {
+        Ok(match e {
+            LExpr::Var(moniker::Var::Free(f)) if self.boxed.contains(f) => {
+                let cell = self.slot(f, body);
+                self.load(&cell, 8, body)
+            }
+            LExpr::Var(moniker::Var::Free(f)) => self.slot(f, body),
+            LExpr::Var(v) => panic!("bound var: {:?}", v),
+            LExpr::Lit(Ignore(l)) => match l {
+                Literal::Int(i) if (i64::MIN >> 1..=i64::MAX >> 1).contains(i) => {
+                    format!("inttoptr (i64 {} to i8*)", (i << 1) | 1)
+                }
+                Literal::Int(i) => self.runtime_call("object_int_new", &format!("i64 {}", i), body),
+                Literal::Float(f) => self.runtime_call(
+                    "object_float_new",
+                    &format!("double 0x{:016X}", f.to_bits()),
+                    body,
+                ),
+                Literal::Rational(n, d) => self.runtime_call(
+                    "object_rational_new",
+                    &format!("i64 {}, i64 {}", n, d),
+                    body,
+                ),
+                Literal::Bool(true) => "@scheme_true".to_owned(),
+                Literal::Bool(false) => "@scheme_false".to_owned(),
+                // strings are made each time they're evaluated, like in C
+                Literal::String(s) => {
+                    let s = unescape(s);
+                    let chars = self.string(&s);
+                    self.runtime_call(
+                        "object_string_new",
+                        &format!("i8* {}, i64 {}", chars, s.len()),
+                        body,
+                    )
+                }
+                Literal::Symbol(s) => self.symbol(s),
+                Literal::Void => "null".to_owned(),
+                Literal::Quoted(items) => self.quoted(items),
+            },
+            LExpr::BuiltinIdent(Ignore(name)) => self.builtin_closure(name)?,
+            LExpr::SetThen(v, e, c) => {
+                self.set(v, e, body)?;
+                self.atom(c, body)?
+            }
+            LExpr::Lifted(Ignore(id)) => {
+                let lambdas = self.lambdas;
+                self.closure(&lambdas[id], body)
+            }
+            LExpr::If(..) | LExpr::CallOne(..) | LExpr::CallTwo(..) => {
+                return Err(format_err!("call in argument position: {:?}", e))
+            }
+            LExpr::IntOp(..) => unreachable!("integer ops are only made for C"),
+        })
+    }
+
+    fn runtime_call(&mut self, func: &str, args: &str, body: &mut Vec<String>) -> String {
+        let value = self.gen_temp();
+        body.push(format!("{} = call i8* @{}({})", value, func, args));
+        value
+    }
+
+    fn closure(&mut self, lambda: &LiftedLambda, body: &mut Vec<String>) -> String {
+        let slots = self.slots[&lambda.id].clone();
+        let env = if slots.is_empty() {
+            "null".to_owned()
+        } else {
+            self.runtime_call("llvm_env_new", &format!("i64 {}", slots.len()), body)
+        };
+
+        if lambda.env_repr == EnvRepr::Linked {
+            self.store(&env, 16, "%env", body);
+        } else {
+            for var in lambda.captures() {
+                let slot = self.slot(var, body);
+                self.store(&env, slot_offset(&slots, var), &slot, body);
+            }
+        }
+
+        let size = match lambda.params {
+            LambdaParams::One(_) => CLOSURE_ONE,
+            LambdaParams::Two(..) => CLOSURE_TWO,
+        };
+
+        self.runtime_call(
+            "llvm_closure_new",
+            &format!(
+                "i8 zeroext {}, i8* bitcast ({}* @{} to i8*), i8* {}",
+                size,
+                lambda_type(size),
+                lambda.name,
+                env
+            ),
+            body,
+        )
+    }
+
+    fn module(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(out, "{}", DECLARATIONS);
+
+        for (runtime_name, two) in &self.builtins {
+            let size = if *two { CLOSURE_TWO } else { CLOSURE_ONE };
+            let _ = writeln!(
+                out,
+                "declare void @{}{}",
+                runtime_name,
+                if *two {
+                    "(i8*, i8*, i8*)"
+                } else {
+                    "(i8*, i8*)"
+                }
+            );
+            let _ = writeln!(
+                out,
+                "@.builtin.{0} = internal global %closure {{ i32 {1}, i8* bitcast ({2}* @{0} to i8*), i8* null }}",
+                runtime_name,
+                OBJ_CLOSURE | (u32::from(size) << 24),
+                lambda_type(size)
+            );
+        }
+
+        for global in &self.globals {
+            let _ = writeln!(out, "{}", global);
+        }
+
+        let symbols = self
+            .symbols
+            .values()
+            .map(|s| format!("i8* bitcast (%symbol* {} to i8*), ", s))
+            .collect::<String>();
+        let _ = writeln!(
+            out,
+            "@scheme_symbols = global [{} x i8*] [{}i8* null]",
+            self.symbols.len() + 1,
+            symbols
+        );
+
+        for func in &self.funcs {
+            let _ = writeln!(out, "\n{}", func);
+        }
+
+        out
+    }
+}
+
+fn lambda_type(size: u8) -> &'static str {
+    if size == CLOSURE_ONE {
+        "void (i8*, i8*)"
+    } else {
+        "void (i8*, i8*, i8*)"
+    }
+}
+
+/// The offset of the slot of `var` in an `env_obj`, after its header and
+/// length.
+fn slot_offset(slots: &[FreeVar<String>], var: &FreeVar<String>) -> usize {
+    let index = slots
+        .iter()
+        .position(|s| s == var)
+        .expect("variable not in the environment");
+
+    16 + 8 * index
+}
+
+fn function(signature: &str, body: &[String]) -> String {
+    let mut out = format!("define {} {{", signature);
+
+    for line in body {
+        if line.ends_with(':') {
+            let _ = write!(out, "\n{}", line);
+        } else {
+            let _ = write!(out, "\n  {}", line);
+        }
+    }
+
+    out.push_str("\n}");
+    out
+}
+
+fn escape(bytes: &[u8]) -> String {
+    let mut out = String::new();
+
+    for &b in bytes {
+        match b {
+            b'"' | b'\\' => {
+                let _ = write!(out, "\\{:02X}", b);
+            }
+            0x20..=0x7e => out.push(b as char),
+            _ => {
+                let _ = write!(out, "\\{:02X}", b);
+            }
+        }
+    }
+
+    out
+}
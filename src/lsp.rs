@@ -0,0 +1,466 @@
+//! A language server, which `ssc-lsp` runs on stdin and stdout, for editors to
+//! show what is wrong with a program as it is written, jump from a variable to
+//! where it is bound and say what a name refers to. The errors are those of the
+//! parser, the lints and binding the program, and names are resolved through
+//! the scopes the lints follow, see `lint::references`.
+//!
+//! Documents are synced whole, and positions count UTF-16 code units as the
+//! protocol has them by default.
+
+use failure::{format_err, Error};
+use pest::error::InputLocation;
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::compiler::Compiler;
+use crate::interp::builtin_arity;
+use crate::json::Json;
+use crate::lint::{self, Level, Lints, Reference, Resolution};
+use crate::parse;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Where the problem is, as offsets of the source
+    pub span: Range<usize>,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// What is wrong with the program `source`, read from `path`, the error
+/// parsing it or else the warnings of the lints and the error binding it.
+pub fn diagnostics(source: &str, path: Option<&Path>) -> Vec<Diagnostic> {
+    if let Err(err) = parse::check(source) {
+        let span = match err.location {
+            InputLocation::Pos(at) => at..at,
+            InputLocation::Span((start, end)) => start..end,
+        };
+
+        return vec![Diagnostic {
+            span,
+            severity: Severity::Error,
+            message: err.variant.message().into_owned(),
+        }];
+    }
+
+    let mut diagnostics = lint::check(source, None, &Lints::default())
+        .into_iter()
+        .map(|w| Diagnostic {
+            span: w.span,
+            severity: match w.level {
+                Level::Deny => Severity::Error,
+                _ => Severity::Warning,
+            },
+            message: format!("{} [{}]", w.message, w.lint.name()),
+        })
+        .collect::<Vec<_>>();
+
+    let mut compiler = Compiler::new().source(source);
+
+    // includes are read relative to it, an unsaved document has none
+    if let Some(path) = path.filter(|p| p.exists()) {
+        compiler = compiler.path(path);
+    }
+
+    if let Err(err) = compiler.bind() {
+        let message = err.to_string();
+
+        // the binder doesn't know where the name is, the first use of it
+        // that isn't bound in the source is
+        let span = message
+            .strip_prefix("unbound variable: ")
+            .and_then(|name| {
+                lint::references(source)
+                    .into_iter()
+                    .find(|r| r.name == name && r.resolution == Resolution::Free)
+            })
+            .map_or(0..0, |r| r.span);
+
+        diagnostics.push(Diagnostic {
+            span,
+            severity: Severity::Error,
+            message,
+        });
+    }
+
+    diagnostics
+}
+
+/// The name at `offset` of `source`, or just before it.
+pub fn reference_at(source: &str, offset: usize) -> Option<Reference> {
+    lint::references(source)
+        .into_iter()
+        .find(|r| r.span.start <= offset && offset <= r.span.end)
+}
+
+/// Where the variable at `offset` is bound.
+pub fn definition(source: &str, offset: usize) -> Option<Range<usize>> {
+    match reference_at(source, offset)?.resolution {
+        Resolution::Local(span) | Resolution::Global(span) => Some(span),
+        Resolution::Builtin | Resolution::Free => None,
+    }
+}
+
+/// What the name at `offset` refers to, and where the name is.
+pub fn hover(source: &str, offset: usize) -> Option<(Range<usize>, String)> {
+    let reference = reference_at(source, offset)?;
+    let name = &reference.name;
+    let line = |span: &Range<usize>| source[..span.start].matches('\n').count() + 1;
+
+    let text = match &reference.resolution {
+        Resolution::Builtin => match builtin_arity(name) {
+            Some(arity) => format!(
+                "builtin {}, taking {} argument{}",
+                name,
+                arity,
+                if arity == 1 { "" } else { "s" }
+            ),
+            None => format!("builtin {}", name),
+        },
+        Resolution::Local(span) => format!("local {}, bound on line {}", name, line(span)),
+        Resolution::Global(span) => format!("global {}, defined on line {}", name, line(span)),
+        Resolution::Free => format!("{} is imported, included or unbound", name),
+    };
+
+    Some((reference.span, text))
+}
+
+/// The position of `offset` in `source`, a line and the UTF-16 code units
+/// before it on that line, both from 0.
+fn position(source: &str, offset: usize) -> Json {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    Json::obj(vec![
+        ("line", Json::Int(before.matches('\n').count() as i64)),
+        (
+            "character",
+            Json::Int(before[line_start..].encode_utf16().count() as i64),
+        ),
+    ])
+}
+
+/// The offset of a position in `source`, the end of the line or of the source
+/// when it is past them.
+fn offset(source: &str, position: &Json) -> usize {
+    let field = |name| position.get(name).and_then(Json::as_i64).unwrap_or(0) as usize;
+    let (line, character) = (field("line"), field("character"));
+
+    let line_start = match line {
+        0 => 0,
+        n => match source.match_indices('\n').nth(n - 1) {
+            Some((i, _)) => i + 1,
+            None => return source.len(),
+        },
+    };
+
+    let mut units = 0;
+    for (i, c) in source[line_start..].char_indices() {
+        if units >= character || c == '\n' {
+            return line_start + i;
+        }
+        units += c.len_utf16();
+    }
+
+    source.len()
+}
+
+fn range(source: &str, span: &Range<usize>) -> Json {
+    Json::obj(vec![
+        ("start", position(source, span.start)),
+        ("end", position(source, span.end)),
+    ])
+}
+
+/// The path of a `file:` URI.
+fn uri_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?.as_bytes();
+    let mut bytes = Vec::new();
+    let mut i = 0;
+
+    while i < path.len() {
+        let escaped = path
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+
+        match (path[i], escaped) {
+            (b'%', Some(byte)) => {
+                bytes.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                bytes.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+/// Read a message, the json after a header giving its length, `None` once
+/// the input ends. A message that can't be read is the inner error, which is
+/// answered, the outer one is the input failing.
+fn read_message(input: &mut impl BufRead) -> Result<Option<Result<Json, Error>>, Error> {
+    let mut length = None;
+
+    loop {
+        let mut line = String::new();
+
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        // found anywhere in the line, so that after a message whose length
+        // was wrong the header of the next one is still found
+        if let Some(at) = line.to_ascii_lowercase().find("content-length:") {
+            length = Some(line[at + "content-length:".len()..].trim().parse::<usize>());
+        }
+    }
+
+    let length = match length {
+        Some(Ok(length)) => length,
+        Some(Err(err)) => return Ok(Some(Err(format_err!("invalid length: {}", err)))),
+        None => return Ok(Some(Err(format_err!("message without a length")))),
+    };
+
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+
+    Ok(Some(
+        String::from_utf8(body)
+            .map_err(Error::from)
+            .and_then(|body| Json::parse(&body)),
+    ))
+}
+
+/// An error to answer a request with, the code and message.
+type Failure = (i64, String);
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_PARAMS: i64 = -32602;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+struct Server<W> {
+    output: W,
+    /// The text of the open documents, by URI
+    documents: HashMap<String, String>,
+    shut_down: bool,
+}
+
+/// Answer the requests read from `input` on `output`, and publish the
+/// diagnostics of each document when it is opened or changed, until the
+/// client says to exit or the input ends. Whether the client shut the server
+/// down first, as it should.
+pub fn serve(mut input: impl BufRead, output: impl Write) -> Result<bool, Error> {
+    let mut server = Server {
+        output,
+        documents: HashMap::new(),
+        shut_down: false,
+    };
+
+    while let Some(message) = read_message(&mut input)? {
+        // the id of a message that can't be read isn't known
+        let message = match message {
+            Ok(message) => message,
+            Err(err) => {
+                server.respond(Json::Null, Err((PARSE_ERROR, err.to_string())))?;
+                continue;
+            }
+        };
+        let method = message.get("method").and_then(Json::as_str);
+
+        if method == Some("exit") {
+            return Ok(server.shut_down);
+        }
+
+        server.handle(method.unwrap_or_default(), &message)?;
+    }
+
+    Ok(false)
+}
+
+impl<W: Write> Server<W> {
+    fn send(&mut self, message: Json) -> Result<(), Error> {
+        let body = message.to_string();
+        write!(
+            self.output,
+            "Content-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )?;
+        self.output.flush()?;
+        Ok(())
+    }
+
+    fn handle(&mut self, method: &str, message: &Json) -> Result<(), Error> {
+        let params = message.get("params").unwrap_or(&Json::Null);
+        let uri = params
+            .get("textDocument")
+            .and_then(|d| d.get("uri"))
+            .and_then(Json::as_str)
+            .unwrap_or_default()
+            .to_owned();
+
+        let result = match method {
+            "initialize" => Ok(Json::obj(vec![
+                (
+                    "capabilities",
+                    Json::obj(vec![
+                        // the whole text on every change
+                        ("textDocumentSync", Json::Int(1)),
+                        ("hoverProvider", Json::Bool(true)),
+                        ("definitionProvider", Json::Bool(true)),
+                    ]),
+                ),
+                (
+                    "serverInfo",
+                    Json::obj(vec![("name", Json::str("ssc-lsp"))]),
+                ),
+            ])),
+            "shutdown" => {
+                self.shut_down = true;
+                Ok(Json::Null)
+            }
+            "textDocument/didOpen" | "textDocument/didChange" => {
+                let text = match method {
+                    "textDocument/didOpen" => {
+                        params.get("textDocument").and_then(|d| d.get("text"))
+                    }
+                    _ => match params.get("contentChanges") {
+                        Some(Json::Arr(changes)) => changes.last().and_then(|c| c.get("text")),
+                        _ => None,
+                    },
+                };
+
+                if let Some(text) = text.and_then(Json::as_str) {
+                    self.documents.insert(uri.clone(), text.to_owned());
+                    return self.publish(&uri);
+                }
+
+                return Ok(());
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                return self.publish(&uri);
+            }
+            "textDocument/hover" => self.at_position(&uri, params, |source, at| {
+                hover(source, at).map(|(span, text)| {
+                    Json::obj(vec![
+                        (
+                            "contents",
+                            Json::obj(vec![
+                                ("kind", Json::str("plaintext")),
+                                ("value", Json::Str(text)),
+                            ]),
+                        ),
+                        ("range", range(source, &span)),
+                    ])
+                })
+            }),
+            "textDocument/definition" => self.at_position(&uri, params, |source, at| {
+                definition(source, at).map(|span| {
+                    Json::obj(vec![
+                        ("uri", Json::str(uri.as_str())),
+                        ("range", range(source, &span)),
+                    ])
+                })
+            }),
+            _ => Err((METHOD_NOT_FOUND, format!("unknown method {}", method))),
+        };
+
+        // notifications aren't answered, even those that aren't understood
+        match message.get("id") {
+            Some(id) => self.respond(id.clone(), result),
+            None => Ok(()),
+        }
+    }
+
+    fn respond(&mut self, id: Json, result: Result<Json, Failure>) -> Result<(), Error> {
+        let outcome = match result {
+            Ok(result) => ("result", result),
+            Err((code, message)) => (
+                "error",
+                Json::obj(vec![
+                    ("code", Json::Int(code)),
+                    ("message", Json::Str(message)),
+                ]),
+            ),
+        };
+
+        self.send(Json::obj(vec![
+            ("jsonrpc", Json::str("2.0")),
+            ("id", id),
+            outcome,
+        ]))
+    }
+
+    /// Answer a request about the position in an open document, with null
+    /// when there is nothing there.
+    fn at_position(
+        &self,
+        uri: &str,
+        params: &Json,
+        f: impl FnOnce(&str, usize) -> Option<Json>,
+    ) -> Result<Json, Failure> {
+        let source = self
+            .documents
+            .get(uri)
+            .ok_or_else(|| (INVALID_PARAMS, format!("{} isn't open", uri)))?;
+        let position = params
+            .get("position")
+            .ok_or_else(|| (INVALID_PARAMS, "no position given".to_owned()))?;
+
+        Ok(f(source, offset(source, position)).unwrap_or(Json::Null))
+    }
+
+    /// Send the diagnostics of a document, none once it is closed.
+    fn publish(&mut self, uri: &str) -> Result<(), Error> {
+        let diagnostics = match self.documents.get(uri) {
+            Some(source) => diagnostics(source, uri_path(uri).as_deref())
+                .iter()
+                .map(|d| {
+                    Json::obj(vec![
+                        ("range", range(source, &d.span)),
+                        (
+                            "severity",
+                            Json::Int(match d.severity {
+                                Severity::Error => 1,
+                                Severity::Warning => 2,
+                            }),
+                        ),
+                        ("source", Json::str("some-scheme")),
+                        ("message", Json::str(d.message.as_str())),
+                    ])
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        self.send(Json::obj(vec![
+            ("jsonrpc", Json::str("2.0")),
+            ("method", Json::str("textDocument/publishDiagnostics")),
+            (
+                "params",
+                Json::obj(vec![
+                    ("uri", Json::str(uri)),
+                    ("diagnostics", Json::Arr(diagnostics)),
+                ]),
+            ),
+        ]))
+    }
+}
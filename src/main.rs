@@ -1,45 +1,224 @@
-#![feature(box_syntax, box_patterns, or_patterns)]
-
-pub mod base_expr;
-pub mod cdsl;
-pub mod codegen;
-pub mod cont_expr;
-pub mod expr;
-pub mod flat_expr;
-pub mod lifted_expr;
-pub mod literals;
-pub mod parse;
-pub mod utils;
-
-use base_expr::BExpr;
-use cdsl::CDecl;
-use cdsl::CExpr;
-use cdsl::CStmt;
-use cdsl::CType;
-use cdsl::ToC;
+use some_scheme_compiler::{
+    base_expr, bench, bytecode, cache, compiler, dot, features, include, interp, json, lifted_expr,
+    link, lint, llvm, parse, profile, repl, target, timing, utils, vm, wasm, wat,
+};
+
+use base_expr::{BExpr, BExprBody};
+use cache::Cache;
+use compiler::{Compiler, PassObserver, Stage};
 use failure::{format_err, Error};
 use include_dir::{include_dir, Dir};
-use std::collections::HashMap;
-use std::fmt::Write;
+use json::{Json, ToJson};
+use link::Module;
+use moniker::FreeVar;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 use std::{
     fs::{self, read_to_string, File},
-    io::{stdin, Read},
-    path::PathBuf,
-    process::Command,
+    io::{stdin, stdout, Read, Write},
+    os::unix::process::{CommandExt, ExitStatusExt},
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus},
+    str::FromStr,
 };
 use structopt::StructOpt;
+use target::TargetConfig;
 use tempdir::TempDir;
 use termcolor::ColorChoice;
 use termcolor::StandardStream;
+use timing::PassTimes;
 
 const RUNTIME_DIR: Dir<'_> = include_dir!("src/core");
+const BENCH_DIR: Dir<'_> = include_dir!("bench");
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CrateType {
+    Bin,
+    StaticLib,
+}
+
+impl FromStr for CrateType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bin" => Ok(CrateType::Bin),
+            "staticlib" => Ok(CrateType::StaticLib),
+            _ => Err(format!("unknown crate type: {}", s)),
+        }
+    }
+}
+
+impl CrateType {
+    fn make_target(self) -> &'static str {
+        match self {
+            CrateType::Bin => "compiled_result",
+            CrateType::StaticLib => "libcompiled_result.a",
+        }
+    }
+}
+
+/// The intermediate forms that can be printed with `--dump-pass`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pass {
+    Parse,
+    Bind,
+    Cps,
+    Lift,
+    C,
+}
+
+impl FromStr for Pass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Pass::Parse),
+            "bind" => Ok(Pass::Bind),
+            "cps" => Ok(Pass::Cps),
+            "lift" => Ok(Pass::Lift),
+            "c" => Ok(Pass::C),
+            _ => Err(format!("unknown pass: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Emit {
+    Json,
+    C,
+    Wat,
+    Ll,
+    Bytecode,
+}
+
+impl FromStr for Emit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Emit::Json),
+            "c" => Ok(Emit::C),
+            "wat" => Ok(Emit::Wat),
+            "ll" => Ok(Emit::Ll),
+            "bytecode" => Ok(Emit::Bytecode),
+            _ => Err(format!("unknown emit kind: {}", s)),
+        }
+    }
+}
+
+/// What the program is compiled to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    /// C built against the runtime in src/core
+    C,
+    /// a WebAssembly module, run with node
+    Wasm32,
+    /// LLVM IR built with llc against the runtime in src/core
+    Llvm,
+    /// bytecode run by the vm, no C toolchain needed
+    Bytecode,
+}
+
+impl FromStr for Target {
+    type Err = String;
 
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "c" => Ok(Target::C),
+            "wasm32" => Ok(Target::Wasm32),
+            "llvm" => Ok(Target::Llvm),
+            "bytecode" => Ok(Target::Bytecode),
+            _ => Err(format!("unknown target: {}", s)),
+        }
+    }
+}
 
-#[derive(StructOpt, Debug)]
+#[derive(StructOpt, Debug, Clone)]
 enum Cmd {
-    /// Run the progam
-    Run,
+    /// Run the progam, exiting with its exit status
+    Run {
+        /// The program, as if given with -i after the others
+        #[structopt(parse(from_os_str))]
+        file: Option<PathBuf>,
+
+        /// The arguments the program gets from command-line
+        #[structopt(last = true)]
+        args: Vec<String>,
+    },
+    /// Run the program with the interpreter, no C toolchain needed
+    Interpret {
+        /// The arguments the program gets from command-line
+        #[structopt(last = true)]
+        args: Vec<String>,
+    },
+    /// Start an interactive session on the interpreter
+    Repl,
+    /// Run a .scmbc file made by compiling with --target bytecode
+    Exec {
+        #[structopt(parse(from_os_str))]
+        file: PathBuf,
+
+        /// The arguments the program gets from command-line
+        #[structopt(last = true)]
+        args: Vec<String>,
+    },
+    /// Print the most called lambdas of a profile written by a program
+    /// compiled with --profile
+    Report {
+        #[structopt(parse(from_os_str), default_value = "scheme.prof")]
+        file: PathBuf,
+
+        /// How many lambdas to print
+        #[structopt(short = "n", long = "top", default_value = "20")]
+        top: usize,
+    },
+    /// Compile each benchmark at each of the levels and run it, printing how
+    /// long it took to compile and run and how big it was
+    Bench {
+        /// The benchmarks to run, the ones bundled with the compiler if none
+        /// are given
+        #[structopt(parse(from_os_str))]
+        files: Vec<PathBuf>,
+
+        /// The -O levels to build each benchmark at
+        #[structopt(long = "opt-levels", default_value = "0,1,2", use_delimiter = true)]
+        opt_levels: Vec<u8>,
+
+        /// How many times to run each build, its fastest time is reported
+        #[structopt(short = "r", long = "runs", default_value = "3")]
+        runs: usize,
+    },
+    /// Build the program each time it or a file it includes changes,
+    /// recompiling only what changed
+    Watch {
+        /// The program, as if given with -i after the others
+        #[structopt(parse(from_os_str))]
+        file: Option<PathBuf>,
+
+        #[structopt(
+            short = "o",
+            long = "output",
+            parse(from_os_str),
+            default_value = "a.out"
+        )]
+        output: PathBuf,
+
+        /// Run the program after each build, stopping it when a file changes
+        #[structopt(long = "run")]
+        run: bool,
+
+        /// How often to look for changes, in milliseconds
+        #[structopt(long = "interval", default_value = "250")]
+        interval: u64,
+
+        /// The arguments the program gets from command-line, with --run
+        #[structopt(last = true)]
+        args: Vec<String>,
+    },
     /// Compile the program
     Compile {
         #[structopt(
@@ -49,29 +228,412 @@ enum Cmd {
             default_value = "a.out"
         )]
         output: PathBuf,
-    }
+
+        /// What to produce, a staticlib also emits a header next to the archive
+        #[structopt(
+            long = "crate-type",
+            default_value = "bin",
+            possible_values = &["bin", "staticlib"],
+            use_delimiter = true
+        )]
+        crate_type: Vec<CrateType>,
+    },
 }
 
-#[derive(StructOpt, Debug)]
+#[derive(StructOpt, Debug, Clone)]
 #[structopt(name = "somescheme")]
 struct Opt {
     #[structopt(subcommand)]
     cmd: Cmd,
 
-    #[structopt(short = "i", long = "input", parse(from_os_str))]
-    input: Option<PathBuf>,
+    /// The program to compile, given more than once each file is compiled on
+    /// its own and the results linked together
+    #[structopt(short = "i", long = "input", parse(from_os_str), number_of_values = 1)]
+    input: Vec<PathBuf>,
 
+    /// Print the program after every pass
     #[structopt(short = "d", long = "debug")]
     debug: bool,
 
+    /// Print what the optimisation passes did
+    #[structopt(short = "v", long = "verbose")]
+    verbose: bool,
+
+    /// Print the program after the given passes
+    #[structopt(
+        long = "dump-pass",
+        possible_values = &["parse", "bind", "cps", "lift", "c"],
+        use_delimiter = true
+    )]
+    dump_pass: Vec<Pass>,
+
+    /// A C library to link the program with, for the functions declared by
+    /// define-foreign
+    #[structopt(short = "l", long = "link", number_of_values = 1)]
+    link: Vec<String>,
+
+    /// Keep the C and object files of separately compiled inputs here, and
+    /// reuse them for the inputs that haven't changed
+    #[structopt(long = "cache-dir", parse(from_os_str))]
+    cache_dir: Option<PathBuf>,
+
+    /// Inline lambdas of up to this many nodes, counting the lambdas they
+    /// create, at every call, lambdas only called where they are created are
+    /// inlined whatever their size, and 0 turns inlining off
+    #[structopt(long = "inline-threshold", default_value = "12")]
+    inline_threshold: usize,
+
+    /// How hard to optimise, from 2 arithmetic on values known to be integers
+    /// is done in C instead of through the builtins
+    #[structopt(short = "O", long = "opt-level", default_value = "0")]
+    opt_level: u8,
+
+    /// Count the calls of each lambda, the program writes the counts to
+    /// scheme.prof, or $SCHEME_PROFILE, when it exits
+    #[structopt(long = "profile")]
+    profile: bool,
+
+    /// Have builtins check the arguments they otherwise trust, like car being
+    /// given a pair, and runtime errors name the lambda they happened in
+    #[structopt(long = "checked")]
+    checked: bool,
+
+    /// Log each call the program makes to stderr, with what is called and its
+    /// argument, as it is made. SCHEME_TRACE only logs the calls from or to
+    /// lambdas whose names contain it, and SCHEME_TRACE_RATE limits the calls
+    /// logged a second
+    #[structopt(long = "trace")]
+    trace: bool,
+
+    /// Print what the runtime allocated and how long it spent collecting when
+    /// the program exits, as running it with SCHEME_RT_STATS set does
+    #[structopt(long = "rt-stats")]
+    rt_stats: bool,
+
+    /// How many threads generate the C of the lambdas, 0 being as many as
+    /// there are cores
+    #[structopt(short = "j", long = "jobs", default_value = "0")]
+    jobs: usize,
+
     #[structopt(short = "k", long = "keep-tmp")]
     keep_tmpdir: bool,
+
+    /// Print how long each pass took and how many nodes the program had after
+    /// it, once the program is built
+    #[structopt(long = "time-passes")]
+    time_passes: bool,
+
+    #[structopt(skip)]
+    pass_times: RefCell<PassTimes>,
+
+    /// Lints to say nothing about
+    #[structopt(long = "allow", possible_values = lint::NAMES, require_delimiter = true)]
+    allow: Vec<String>,
+
+    /// Lints to print warnings for
+    #[structopt(long = "warn", possible_values = lint::NAMES, require_delimiter = true)]
+    warn: Vec<String>,
+
+    /// Lints that stop the program from being compiled, given after --allow
+    /// and --warn whatever the order
+    #[structopt(long = "deny", possible_values = lint::NAMES, require_delimiter = true)]
+    deny: Vec<String>,
+
+    /// Write the lifted lambdas as a graphviz graph of creations, direct
+    /// calls and captures
+    #[structopt(long = "dot", parse(from_os_str))]
+    dot: Option<PathBuf>,
+
+    /// Print every intermediate form as json, or the generated C, WebAssembly
+    /// text, LLVM IR or disassembled bytecode, to stdout instead of building or
+    /// running the program
+    #[structopt(
+        long = "emit",
+        possible_values = &["json", "c", "wat", "ll", "bytecode"]
+    )]
+    emit: Option<Emit>,
+
+    /// Compile to C, to a WebAssembly module that is run with node, to LLVM IR,
+    /// or to bytecode
+    #[structopt(
+        long = "target",
+        default_value = "c",
+        possible_values = &["c", "wasm32", "llvm", "bytecode"]
+    )]
+    target: Target,
+
+    /// The machine to build the C for, like armv7-unknown-linux-gnueabihf,
+    /// with <triple>-gcc unless --cc is given. The program can only be
+    /// compiled, not run
+    #[structopt(long = "target-triple")]
+    target_triple: Option<String>,
+
+    #[structopt(skip)]
+    target_config: TargetConfig,
+
+    /// The C compiler to build with, instead of $CC
+    #[structopt(long = "cc")]
+    cc: Option<String>,
+
+    /// The flags to give the C compiler, instead of $CFLAGS
+    #[structopt(long = "cflags", allow_hyphen_values = true)]
+    cflags: Option<String>,
+
+    /// A feature for cond-expand to choose clauses by, on top of those every
+    /// program has
+    #[structopt(long = "feature", number_of_values = 1)]
+    feature: Vec<String>,
+
+    /// Compile through the cache even with a single input, as watch does
+    #[structopt(skip)]
+    incremental: bool,
 }
 
-fn main() -> Result<(), Error> {
-    let opts = Opt::from_args();
+impl Opt {
+    fn dumps(&self, pass: Pass) -> bool {
+        self.debug || self.dump_pass.contains(&pass)
+    }
+
+    fn emits_json(&self) -> bool {
+        self.emit == Some(Emit::Json)
+    }
+
+    /// Run a pass, adding the time it took to those `--time-passes` prints.
+    fn time<T>(&self, pass: &'static str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.pass_times.borrow_mut().add_time(pass, start.elapsed());
+        result
+    }
+
+    /// Count the nodes of the program after a pass, with `--time-passes`.
+    fn count_nodes(&self, pass: &'static str, nodes: impl FnOnce() -> usize) {
+        if self.time_passes {
+            self.pass_times.borrow_mut().add_nodes(pass, nodes());
+        }
+    }
+
+    /// Print the passes timed so far, with `--time-passes`.
+    fn report_passes(&self) {
+        if self.time_passes {
+            eprint!("{}", self.pass_times.borrow().report());
+        }
+    }
+
+    /// Print the warnings of the lints for the source read from `path`,
+    /// failing if any of them are denied.
+    fn lint(&self, source: &str, path: Option<&Path>) -> Result<(), Error> {
+        let mut lints = lint::Lints::default();
+
+        for (names, level) in &[
+            (&self.allow, lint::Level::Allow),
+            (&self.warn, lint::Level::Warn),
+            (&self.deny, lint::Level::Deny),
+        ] {
+            for name in names.iter() {
+                lints.set(name, *level)?;
+            }
+        }
+
+        let path = path.map(|p| p.display().to_string());
+        let warnings = self.time("lint", || lint::check(source, path.as_deref(), &lints));
+
+        for warning in &warnings {
+            eprintln!("{}", warning);
+        }
+
+        let denied = warnings
+            .iter()
+            .filter(|w| w.level == lint::Level::Deny)
+            .count();
+
+        if denied > 0 {
+            return Err(format_err!(
+                "not compiled because of {} denied lint{}",
+                denied,
+                if denied == 1 { "" } else { "s" }
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// The variables make builds the C with, the libraries to link and the
+    /// compiler and its flags when they were given or the target triple
+    /// implies them.
+    fn make_vars(&self) -> Vec<String> {
+        let mut vars = Vec::new();
+
+        if let Some(cc) = self.cc.clone().or_else(|| self.target_config.default_cc()) {
+            vars.push(format!("CC={}", cc));
+        }
 
-    let input_exp = if let Some(input_path) = opts.input.as_ref() {
+        if let Some(cflags) = &self.cflags {
+            vars.push(format!("CFLAGS={}", cflags));
+        }
+
+        if let Some(ar) = self.target_config.default_ar() {
+            vars.push(format!("AR={}", ar));
+        }
+
+        if !self.link.is_empty() {
+            let libs = self
+                .link
+                .iter()
+                .map(|l| format!("-l{}", l))
+                .collect::<Vec<_>>();
+            vars.push(format!("LDLIBS={}", libs.join(" ")));
+        }
+
+        vars
+    }
+
+    /// What runs the program, as `cond-expand` names it.
+    fn backend(&self) -> &'static str {
+        match (&self.cmd, self.target) {
+            (Cmd::Interpret { .. } | Cmd::Repl, _) => "interpreter",
+            (_, Target::C) => "c",
+            (_, Target::Wasm32) => "wasm32",
+            (_, Target::Llvm) => "llvm",
+            (_, Target::Bytecode) => "bytecode",
+        }
+    }
+
+    /// The features the program is compiled with, see `features`.
+    fn features(&self) -> features::Features {
+        self.compiler().features(self.backend())
+    }
+
+    /// The compiler running the passes, set up as the flags ask.
+    fn compiler(&self) -> Compiler {
+        let compiler = Compiler::new()
+            .optimize(self.opt_level)
+            .inline_threshold(self.inline_threshold)
+            .profile(self.profile)
+            .checked(self.checked)
+            .trace(self.trace)
+            .jobs(self.jobs)
+            .target(self.target_config.clone());
+
+        self.feature
+            .iter()
+            .fold(compiler, |compiler, name| compiler.feature(name.clone()))
+    }
+
+    /// What `command-line` gives the program, the file it is run from
+    /// followed by the arguments after `--`.
+    fn command_line(&self) -> Vec<String> {
+        let (file, args) = match &self.cmd {
+            Cmd::Exec { file, args } => (Some(file), &args[..]),
+            Cmd::Run { args, .. } | Cmd::Interpret { args } | Cmd::Watch { args, .. } => {
+                (self.input.last(), &args[..])
+            }
+            _ => (self.input.last(), &[][..]),
+        };
+
+        let name = match file {
+            Some(path) => path.display().to_string(),
+            None => "<stdin>".to_owned(),
+        };
+
+        Some(name).into_iter().chain(args.iter().cloned()).collect()
+    }
+}
+
+fn main() {
+    let mut opts = Opt::from_args();
+
+    if let Cmd::Run { file, .. } | Cmd::Watch { file, .. } = &mut opts.cmd {
+        opts.input.extend(file.take());
+    }
+
+    let result = match &opts.target_triple {
+        Some(triple) => TargetConfig::from_triple(triple).map(|t| opts.target_config = t),
+        None => Ok(()),
+    };
+
+    // a program that can't be compiled fails like one that fails when run
+    if let Err(err) = result.and_then(|_| utils::with_stack(move || run(opts))) {
+        let _ = stdout().flush();
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(opts: Opt) -> Result<(), Error> {
+    if let Cmd::Repl = opts.cmd {
+        return repl::run(opts.features());
+    }
+
+    if let Cmd::Exec { file, .. } = &opts.cmd {
+        let program = bytecode::Program::from_bytes(&fs::read(file)?)?;
+        return run_bytecode(program, opts.command_line());
+    }
+
+    if let Cmd::Report { file, top } = &opts.cmd {
+        print!(
+            "{}",
+            profile::report(profile::parse(&read_to_string(file)?)?, *top)
+        );
+        return Ok(());
+    }
+
+    if let Cmd::Bench {
+        files,
+        opt_levels,
+        runs,
+    } = &opts.cmd
+    {
+        return run_benchmarks(&opts, files, opt_levels, *runs);
+    }
+
+    if let Cmd::Watch { .. } = opts.cmd {
+        return watch(&opts);
+    }
+
+    if opts.profile && (opts.target != Target::C || opts.input.len() > 1) {
+        return Err(format_err!("--profile needs --target c and a single input"));
+    }
+
+    if opts.checked && opts.target != Target::C {
+        return Err(format_err!("--checked needs --target c"));
+    }
+
+    if opts.trace && opts.target != Target::C {
+        return Err(format_err!("--trace needs --target c"));
+    }
+
+    if opts.target_triple.is_some() {
+        if opts.target != Target::C {
+            return Err(format_err!("--target-triple needs --target c"));
+        }
+
+        if opts.emit.is_none() && !matches!(opts.cmd, Cmd::Compile { .. }) {
+            return Err(format_err!(
+                "a program built with --target-triple can only be compiled"
+            ));
+        }
+    }
+
+    match (opts.target, opts.emit) {
+        (Target::C, Some(Emit::C))
+        | (Target::Wasm32, Some(Emit::Wat))
+        | (Target::Llvm, Some(Emit::Ll))
+        | (Target::Bytecode, Some(Emit::Bytecode)) => {}
+        (_, Some(Emit::C)) => return Err(format_err!("--emit c needs --target c")),
+        (_, Some(Emit::Wat)) => return Err(format_err!("--emit wat needs --target wasm32")),
+        (_, Some(Emit::Ll)) => return Err(format_err!("--emit ll needs --target llvm")),
+        (_, Some(Emit::Bytecode)) => {
+            return Err(format_err!("--emit bytecode needs --target bytecode"))
+        }
+        _ => {}
+    }
+
+    if opts.input.len() > 1 || opts.incremental {
+        return compile_separately(&opts);
+    }
+
+    let input_exp = if let Some(input_path) = opts.input.first() {
         read_to_string(input_path)?
     } else {
         let mut buf = String::new();
@@ -79,73 +641,695 @@ fn main() -> Result<(), Error> {
         buf
     };
 
-    let body = match parse::parse(&input_exp) {
-        Ok(body) => body,
-        Err(err) => {
-            println!("{}", err);
-            return Ok(())
-        }
-    };
+    let input_path = opts.input.first().map(PathBuf::as_path);
+    opts.lint(&input_exp, input_path)?;
 
-    let expr = BExpr::App(Rc::new(BExpr::Lam(Vec::new(), body)), Vec::new());
+    let features = opts.features();
+    let input_exp = opts.time("parse", || {
+        features::expand(&input_exp, input_path, &features)
+    })?;
 
-    if opts.debug {
+    let body = opts
+        .time("parse", || parse::parse(&input_exp))
+        .map_err(|e| format_err!("{}", e))?;
+
+    let expr = BExpr::App(Rc::new(BExpr::Lam(Vec::new(), body, None)), Vec::new());
+
+    let mut stages = Vec::new();
+
+    if opts.emits_json() {
+        stages.push(("parse".to_owned(), expr.to_json()));
+    }
+
+    if opts.dumps(Pass::Parse) {
         eprintln!("\n\nexpr after parsing: ");
         let _ = expr.pretty_print(StandardStream::stderr(ColorChoice::Auto));
-        eprintln!("");
+        eprintln!();
+    }
+
+    let expr = opts.time("bind", || expr.into_expr())?;
+    opts.count_nodes("bind", || expr.size());
+
+    if opts.dumps(Pass::Bind) {
         eprintln!("\n\nexpr after binding: ");
-        let _ = expr.clone().into_expr().pretty_print(StandardStream::stderr(ColorChoice::Auto));
-        eprintln!("");
+        let _ = expr.pretty_print(StandardStream::stderr(ColorChoice::Auto));
+        eprintln!();
     }
 
-    let k = Rc::new(cont_expr::AExp::BuiltinIdent(moniker::Ignore(
-        "exit".into(),
-    )));
+    if opts.emits_json() {
+        stages.push(("bind".to_owned(), expr.to_json()));
+    }
 
-    if opts.debug {
-        eprintln!("\n\nexpr after converting: ");
-        let _ = expr
-            .clone()
-            .into_expr()
-            .into_fexpr(k.clone())
-            .pretty_print(StandardStream::stderr(ColorChoice::Auto));
-        eprintln!("");
+    if let (Cmd::Interpret { .. }, None) = (&opts.cmd, opts.emit) {
+        let mut out = stdout().lock();
+        let mut ctx = interp::InterpCtx::new(&mut out);
+        ctx.set_command_line(opts.command_line());
+
+        if let Err(e) = ctx.eval(&expr) {
+            exit_after(e);
+        }
+
+        return Ok(());
     }
 
-    let (expr, lambdas) = expr.into_expr().into_fexpr(k).lift_lambdas();
+    let compiler = opts.compiler();
+    let program = compiler.lift_expr(
+        expr,
+        opts.backend(),
+        &mut PassReporter::new(&opts, None, &mut stages),
+    )?;
 
-    let generated_source = do_codegen(&opts, expr, lambdas)?;
+    if let Some(path) = &opts.dot {
+        fs::write(path, dot::lambdas_to_dot(&program))?;
+    }
 
-    let full_source = generate_program_source(&generated_source);
+    if opts.emits_json() {
+        stages.push(("lift".to_owned(), program.to_json()));
+    }
 
-    if opts.debug {
+    let source_file = match opts.input.first() {
+        Some(path) => path.display().to_string(),
+        None => "<stdin>".to_owned(),
+    };
+
+    if opts.target != Target::C {
+        if opts.emits_json() {
+            println!("{}", Json::Obj(stages));
+            return Ok(());
+        }
+
+        return match opts.target {
+            Target::Wasm32 => build_wasm(&opts, &program),
+            Target::Bytecode => build_bytecode(&opts, &program),
+            _ => build_llvm(&opts, &program, &source_file),
+        };
+    }
+
+    let generated_source = do_codegen(
+        &opts,
+        &compiler,
+        &program,
+        &mut stages,
+        &source_file,
+        None,
+        &HashMap::new(),
+    )?;
+
+    if opts.emits_json() {
+        println!("{}", Json::Obj(stages));
+        return Ok(());
+    }
+
+    let full_source = compiler::program_source(&generated_source);
+
+    if opts.dumps(Pass::C) {
         eprintln!("{}", full_source);
     }
 
+    if opts.emit == Some(Emit::C) {
+        print!("{}", full_source);
+        return Ok(());
+    }
+
+    build(
+        &opts,
+        &[("compiled_result.c".to_owned(), full_source)],
+        &[],
+        None,
+    )
+}
+
+/// Compile each input to its own C file and link them into one program, the
+/// toplevels run in the order the files were given and the defines of each
+/// are visible to all of them.
+fn compile_separately(opts: &Opt) -> Result<(), Error> {
+    if let Cmd::Interpret { .. } = opts.cmd {
+        return Err(format_err!("several inputs can only be compiled or run"));
+    }
+
+    if opts.target != Target::C {
+        return Err(format_err!("several inputs can only be compiled to C"));
+    }
+
+    if opts.emits_json() {
+        return Err(format_err!("--emit json takes a single input"));
+    }
+
+    // dumping passes needs them to run
+    let cache = match &opts.cache_dir {
+        Some(dir) if !opts.debug && opts.dump_pass.is_empty() => Some(Cache::open(dir)?),
+        _ => None,
+    };
+
+    let features = opts.features();
+    let mut parsed = Vec::new();
+
+    for path in &opts.input {
+        let source = read_to_string(path)?;
+        opts.lint(&source, Some(path))?;
+
+        let source = features::expand(&source, Some(path), &features)
+            .map_err(|e| format_err!("{}: {}", path.display(), e))?;
+
+        // every file's exports are needed before any of them is compiled, so
+        // they are kept apart from the C to save parsing files that are cached
+        let exports_key = cache.as_ref().map(|c| c.key("exports", &[&source]));
+        let cached_exports = cache
+            .as_ref()
+            .zip(exports_key.as_ref())
+            .and_then(|(c, k)| c.get_string(k))
+            .map(|e| e.lines().map(str::to_owned).collect());
+
+        let (exports, body) = match cached_exports {
+            Some(exports) => (exports, None),
+            None => {
+                let body = opts.time("parse", || {
+                    parse_input(&path.display().to_string(), &source)
+                })?;
+
+                let (exports, body) = body.defines_to_sets();
+
+                if let Some((c, k)) = cache.as_ref().zip(exports_key.as_ref()) {
+                    c.put(k, exports.join("\n").as_bytes())?;
+                }
+
+                (exports, Some(body))
+            }
+        };
+
+        parsed.push((Module::new(path, exports), source, body));
+    }
+
+    let modules = parsed.iter().map(|(m, _, _)| m.clone()).collect::<Vec<_>>();
+
+    link::check_modules(&modules)?;
+
+    let env = modules
+        .iter()
+        .flat_map(|m| m.exports.iter())
+        .map(|n| (n.clone(), FreeVar::fresh_named(n.as_str())))
+        .collect::<HashMap<_, _>>();
+    let globals = env
+        .iter()
+        .map(|(n, v)| (v.clone(), link::global_name(n)))
+        .collect::<HashMap<_, _>>();
+    let global_vars = env.values().cloned().collect::<HashSet<_>>();
+
+    let headers = modules
+        .iter()
+        .map(|m| format!("#include \"{}\"\n", m.header_file()))
+        .collect::<String>();
+
+    let mut files = Vec::new();
+
+    // what a file's free variables refer to depends on the globals of all of
+    // them, so the C of a file is only reusable if they are the same
+    let mut all_exports = env.keys().map(String::as_str).collect::<Vec<_>>();
+    all_exports.sort_unstable();
+    let all_exports = all_exports.join("\n");
+
+    let mut objects = Vec::new();
+    let compiler = opts.compiler();
+
+    // the C also depends on how much is inlined and optimised, on whether it's
+    // checked or traced, and on the machine it's for
+    let checks = opts.target_config.c_checks();
+    let inline_threshold = opts.inline_threshold.to_string();
+    let opt_level = opts.opt_level.to_string();
+    let checked = opts.checked.to_string();
+    let trace = opts.trace.to_string();
+
+    for (module, source, body) in parsed {
+        let c_key = cache.as_ref().map(|c| {
+            c.key(
+                "c",
+                &[
+                    &source,
+                    &module.source,
+                    &module.name,
+                    &all_exports,
+                    &headers,
+                    &inline_threshold,
+                    &opt_level,
+                    &checked,
+                    &trace,
+                    &checks,
+                ],
+            )
+        });
+        let cached_c = cache
+            .as_ref()
+            .zip(c_key.as_ref())
+            .and_then(|(c, k)| c.get_string(k));
+
+        let generated_source = match cached_c {
+            Some(generated_source) => generated_source,
+            None => {
+                let body = match body {
+                    Some(body) => body,
+                    None => opts.time("parse", || parse_input(&module.source, &source))?,
+                }
+                .defines_to_sets()
+                .1;
+
+                let expr = BExpr::App(Rc::new(BExpr::Lam(Vec::new(), body, None)), Vec::new());
+
+                let expr = opts
+                    .time("bind", || expr.into_expr_with_env(&env))
+                    .map_err(|e| format_err!("{}: {}", module.source, e))?;
+                opts.count_nodes("bind", || expr.size());
+
+                let program = compiler.lift_module(
+                    expr,
+                    &global_vars,
+                    &mut PassReporter::new(opts, Some(&module.source), &mut Vec::new()),
+                )?;
+                let generated_source = do_codegen(
+                    opts,
+                    &compiler,
+                    &program,
+                    &mut Vec::new(),
+                    &module.source,
+                    Some(&module),
+                    &globals,
+                )?;
+
+                let generated_source = format!("{}{}{}", MODULE_PRELUDE, headers, generated_source);
+
+                if let Some((c, k)) = cache.as_ref().zip(c_key.as_ref()) {
+                    c.put(k, generated_source.as_bytes())?;
+                }
+
+                generated_source
+            }
+        };
+
+        if let Some(c) = &cache {
+            let cc = std::env::var("CC").unwrap_or_default();
+            let cflags = std::env::var("CFLAGS").unwrap_or_default();
+            let make_vars = opts.make_vars().join("\n");
+            objects.push((
+                module.object_file(),
+                c.key(
+                    "o",
+                    &[&generated_source, &all_exports, &cc, &cflags, &make_vars],
+                ),
+            ));
+        }
+
+        files.push((module.c_file(), generated_source));
+        files.push((module.header_file(), module.header()));
+    }
+
+    files.push(("compiled_result.c".to_owned(), link::link_unit(&modules)));
+
+    if opts.dumps(Pass::C) || opts.emit == Some(Emit::C) {
+        for (name, source) in &files {
+            let listing = format!("// {}\n{}", name, source);
+
+            if opts.emit == Some(Emit::C) {
+                println!("{}", listing);
+            } else {
+                eprintln!("{}", listing);
+            }
+        }
+    }
+
+    if opts.emit == Some(Emit::C) {
+        return Ok(());
+    }
+
+    let module_files = modules.iter().map(|m| m.c_file()).collect::<Vec<_>>();
+    let objects = cache.as_ref().map(|c| (c, objects.as_slice()));
+
+    build(opts, &files, &module_files, objects)
+}
+
+/// Parse one of several inputs, the error naming the input.
+fn parse_input(name: &str, source: &str) -> Result<BExprBody, Error> {
+    parse::parse(source).map_err(|e| format_err!("{}: {}", name, e))
+}
+
+/// Times the passes the compiler runs and prints what they did as the flags
+/// ask, for the input `name` when there are several, collecting the program
+/// after them into `stages` for `--emit json`.
+struct PassReporter<'a> {
+    opts: &'a Opt,
+    name: Option<&'a str>,
+    stages: &'a mut Vec<(String, Json)>,
+    started: Instant,
+}
+
+impl<'a> PassReporter<'a> {
+    fn new(opts: &'a Opt, name: Option<&'a str>, stages: &'a mut Vec<(String, Json)>) -> Self {
+        Self {
+            opts,
+            name,
+            stages,
+            started: Instant::now(),
+        }
+    }
+}
+
+impl PassObserver for PassReporter<'_> {
+    fn start(&mut self, _pass: &'static str) {
+        self.started = Instant::now();
+    }
+
+    fn finish(&mut self, stage: Stage<'_>) {
+        let opts = self.opts;
+        opts.pass_times
+            .borrow_mut()
+            .add_time(stage.pass(), self.started.elapsed());
+        opts.count_nodes(stage.pass(), || stage.size());
+
+        let name = self.name.map(|n| format!("{}: ", n)).unwrap_or_default();
+
+        match stage {
+            Stage::PartialEval(_, stats) if opts.verbose => eprintln!(
+                "{}partial evaluation: {} calls, {} ifs and {} bindings evaluated",
+                name, stats.calls, stats.ifs, stats.bindings,
+            ),
+            Stage::Cps(expr) => {
+                if opts.emits_json() {
+                    self.stages.push(("cps".to_owned(), expr.to_json()));
+                }
+
+                if opts.dumps(Pass::Cps) {
+                    eprintln!("\n\nexpr after converting: ");
+                    let _ = expr.pretty_print(StandardStream::stderr(ColorChoice::Auto));
+                    eprintln!();
+                }
+            }
+            Stage::Shrink(_, stats) if opts.verbose => eprintln!(
+                "{}shrink: {} eta and {} beta reductions in {} rounds removed {} of {} nodes",
+                name,
+                stats.eta_reductions,
+                stats.beta_reductions,
+                stats.rounds,
+                stats.nodes_before - stats.nodes_after,
+                stats.nodes_before,
+            ),
+            Stage::Unbox(_, stats) if opts.verbose => eprintln!(
+                "{}unbox: {} variables only hold integers, {} builtin calls done in C",
+                name, stats.integer_vars, stats.ops,
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Write the generated files into a build directory with the runtime and build
+/// them, then run the program or copy out what was asked for. `modules` are
+/// the separately compiled files linked into the program, and `objects` the
+/// object files to take from the cache when it has them, or to put in it.
+fn build(
+    opts: &Opt,
+    files: &[(String, String)],
+    modules: &[String],
+    objects: Option<(&Cache, &[(String, String)])>,
+) -> Result<(), Error> {
     let build_dir = generate_build_dir();
 
-    insert_source_into_build_dir(&build_dir, &full_source);
+    for (name, source) in files {
+        insert_file_into_build_dir(&build_dir, name, source);
+    }
 
-    let make_stdout = match invoke_make(&build_dir) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("{}", e);
-            return Ok(());
+    insert_file_into_build_dir(&build_dir, "compiled_result.h", PROGRAM_HEADER);
+
+    // written after their sources so that make sees them as up to date
+    if let Some((cache, objects)) = objects {
+        for (name, key) in objects {
+            if let Some(object) = cache.get(key) {
+                fs::write(build_dir.path().join(name), object)?;
+            }
         }
+    }
+
+    let make_targets = match &opts.cmd {
+        Cmd::Compile { crate_type, .. } => crate_type.iter().map(|c| c.make_target()).collect(),
+        _ => vec![CrateType::Bin.make_target()],
     };
 
+    let make_stdout = opts.time("cc", || {
+        invoke_make(&build_dir, &make_targets, modules, &opts.make_vars())
+    })?;
+
     if opts.debug {
         eprintln!("{}", make_stdout);
     }
 
-    if let Cmd::Compile { output } = &opts.cmd {
-        copy_binary(&build_dir, output);
+    opts.report_passes();
+
+    if let Some((cache, objects)) = objects {
+        for (name, key) in objects {
+            if !cache.path(key).exists() {
+                cache.put(key, &fs::read(build_dir.path().join(name))?)?;
+            }
+        }
+    }
+
+    let status = if let Cmd::Compile { output, crate_type } = &opts.cmd {
+        for c in crate_type {
+            match c {
+                CrateType::Bin => copy_artifact(&build_dir, "compiled_result", output)?,
+                CrateType::StaticLib => {
+                    let stem = output.file_stem().unwrap_or_default().to_string_lossy();
+                    let lib_path = output.with_file_name(format!("lib{}.a", stem));
+                    copy_artifact(&build_dir, "libcompiled_result.a", &lib_path)?;
+                    copy_artifact(&build_dir, "compiled_result.h", &output.with_extension("h"))?;
+                }
+            }
+        }
+
+        None
     } else {
-        Command::new(build_dir.path().join("compiled_result"))
-            .status()
-            .expect("Failed to run?");
+        Some(run_program(opts, &build_dir.path().join("compiled_result")).expect("Failed to run?"))
+    };
+
+    close_build_dir(opts, build_dir)?;
+    status.map_or(Ok(()), exit_with)
+}
+
+/// Build each benchmark at each opt level with this compiler, then run it
+/// `runs` times and print how they compare.
+fn run_benchmarks(
+    opts: &Opt,
+    files: &[PathBuf],
+    opt_levels: &[u8],
+    runs: usize,
+) -> Result<(), Error> {
+    let build_dir = TempDir::new("some_scheme_bench")?;
+    let compiler = std::env::current_exe()?;
+
+    let benchmarks = if files.is_empty() {
+        let mut files = Vec::new();
+
+        for file in BENCH_DIR.files() {
+            let path = build_dir.path().join(file.path());
+            fs::write(&path, file.contents())?;
+            files.push(path);
+        }
+
+        files.sort();
+        files
+    } else {
+        files.to_vec()
+    };
+
+    let mut results = Vec::new();
+
+    for path in &benchmarks {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+
+        for &opt_level in opt_levels {
+            let binary = build_dir.path().join(format!("{}-O{}", name, opt_level));
+
+            let start = Instant::now();
+            let output = Command::new(&compiler)
+                .arg("-i")
+                .arg(path)
+                .arg(format!("-O{}", opt_level))
+                .arg(format!("--inline-threshold={}", opts.inline_threshold))
+                .arg("compile")
+                .arg("-o")
+                .arg(&binary)
+                .output()?;
+            let compile_time = start.elapsed();
+
+            if !output.status.success() || !binary.exists() {
+                return Err(format_err!(
+                    "{} didn't compile at -O{}:\n{}{}",
+                    path.display(),
+                    opt_level,
+                    String::from_utf8_lossy(&output.stdout),
+                    String::from_utf8_lossy(&output.stderr)
+                ));
+            }
+
+            let mut times = Vec::new();
+
+            for _ in 0..runs.max(1) {
+                let start = Instant::now();
+                let output = Command::new(&binary).output()?;
+                times.push(start.elapsed());
+
+                if !output.status.success() {
+                    return Err(format_err!(
+                        "{} failed at -O{} with {}:\n{}",
+                        path.display(),
+                        opt_level,
+                        output.status,
+                        String::from_utf8_lossy(&output.stderr)
+                    ));
+                }
+            }
+
+            results.push(bench::Run {
+                benchmark: name.to_string(),
+                opt_level,
+                compile_time,
+                size: fs::metadata(&binary)?.len(),
+                runtime: times.into_iter().min().unwrap_or_default(),
+            });
+        }
+    }
+
+    print!("{}", bench::report(&results));
+    close_build_dir(opts, build_dir)
+}
+
+/// Run a program built against the C runtime, with the name and arguments of
+/// the command line.
+fn run_program(opts: &Opt, program: &Path) -> std::io::Result<ExitStatus> {
+    program_command(opts, program).status()
+}
+
+fn program_command(opts: &Opt, program: &Path) -> Command {
+    let mut command_line = opts.command_line();
+    let mut command = Command::new(program);
+    command.arg0(command_line.remove(0)).args(command_line);
+
+    if opts.rt_stats {
+        command.env("SCHEME_RT_STATS", "1");
+    }
+
+    command
+}
+
+/// Build the program, and run it with `--run`, then wait for one of the
+/// inputs or the files they include to change and do it again, until
+/// interrupted. Each build goes through the cache, in `--cache-dir` or in a
+/// directory kept for the session, so only the inputs that changed are
+/// compiled again.
+fn watch(opts: &Opt) -> Result<(), Error> {
+    let (output, run_after, interval) = match &opts.cmd {
+        Cmd::Watch {
+            output,
+            run,
+            interval,
+            ..
+        } => (output, *run, Duration::from_millis(*interval)),
+        _ => unreachable!(),
+    };
+
+    if opts.input.is_empty() {
+        return Err(format_err!("watch needs a file to watch"));
     }
 
+    if opts.target != Target::C || opts.emit.is_some() {
+        return Err(format_err!("watch builds with --target c"));
+    }
+
+    let session_cache = TempDir::new("some_scheme_watch")?;
+    // a relative path would be run from $PATH
+    let program = Path::new(".").join(output);
+    let mut child: Option<Child> = None;
+
+    loop {
+        let files = watched_files(&opts.input);
+        let stamps = modified_times(&files);
+
+        let mut build = opts.clone();
+        build.cmd = Cmd::Compile {
+            output: output.clone(),
+            crate_type: vec![CrateType::Bin],
+        };
+        build.incremental = true;
+        build
+            .cache_dir
+            .get_or_insert_with(|| session_cache.path().to_owned());
+
+        eprintln!("watch: building {}", output.display());
+        let start = Instant::now();
+
+        match run(build) {
+            Err(err) => eprintln!("watch: failed: {}", err),
+            Ok(()) => {
+                eprintln!("watch: built in {:.2}s", start.elapsed().as_secs_f64());
+
+                if run_after {
+                    child = Some(program_command(opts, &program).spawn()?);
+                }
+            }
+        }
+
+        eprintln!("watch: waiting for changes to {} files", files.len());
+
+        while modified_times(&watched_files(&opts.input)) == stamps {
+            thread::sleep(interval);
+
+            if let Some(status) = child.as_mut().map(Child::try_wait).transpose()?.flatten() {
+                eprintln!("watch: program exited with {}", status);
+                child = None;
+            }
+        }
+
+        if let Some(mut child) = child.take() {
+            eprintln!("watch: stopping program");
+            let _ = child.kill();
+            child.wait()?;
+        }
+    }
+}
+
+/// The inputs and every file they include.
+fn watched_files(inputs: &[PathBuf]) -> Vec<PathBuf> {
+    let mut files = inputs.to_vec();
+
+    for input in inputs {
+        if let Ok(source) = read_to_string(input) {
+            files.extend(include::included_files(&source, Some(input)));
+        }
+    }
+
+    files
+}
+
+/// When each file was last changed, `None` for those that can't be read.
+fn modified_times(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|f| fs::metadata(f).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Exit with the status of a program that was run and failed, a program
+/// killed by a signal exits with 128 and the signal as a shell gives it.
+fn exit_with(status: ExitStatus) -> Result<(), Error> {
+    if status.success() {
+        return Ok(());
+    }
+
+    std::process::exit(
+        status
+            .code()
+            .unwrap_or_else(|| 128 + status.signal().unwrap_or_default()),
+    )
+}
+
+/// Remove the build directory, unless it was asked to be kept.
+fn close_build_dir(opts: &Opt, build_dir: TempDir) -> Result<(), Error> {
     if !opts.keep_tmpdir {
         build_dir.close()?;
     } else {
@@ -158,13 +1342,156 @@ fn main() -> Result<(), Error> {
     Ok(())
 }
 
-fn copy_binary(tmp_dir: &TempDir, output_path: &PathBuf) {
-    fs::copy(tmp_dir.path().join("compiled_result"), output_path)
-        .expect("failed copying compiled binary");
+/// Compile the program to a WebAssembly module, then run it with node or write
+/// it to the output.
+fn build_wasm(opts: &Opt, program: &lifted_expr::Program) -> Result<(), Error> {
+    let text = opts.time("codegen", || wasm::compile(program))?;
+
+    if opts.emit == Some(Emit::Wat) {
+        print!("{}", text);
+        return Ok(());
+    }
+
+    let module = opts.time("assemble", || wat::assemble(&text))?;
+    opts.report_passes();
+
+    if let Cmd::Compile { output, crate_type } = &opts.cmd {
+        if crate_type.iter().any(|c| *c != CrateType::Bin) {
+            return Err(format_err!("the wasm32 target only builds bins"));
+        }
+
+        fs::write(output, module)?;
+        return Ok(());
+    }
+
+    let build_dir = TempDir::new("some_scheme").expect("unable to create temp dir");
+    fs::write(build_dir.path().join("compiled_result.wasm"), module)?;
+    insert_file_into_build_dir(&build_dir, "run.js", wasm::RUNNER);
+
+    let status = Command::new("node")
+        .arg("run.js")
+        .arg("compiled_result.wasm")
+        .current_dir(build_dir.path())
+        .status()
+        .map_err(|e| format_err!("Couldn't run node, which runs wasm32 programs: {}", e))?;
+
+    close_build_dir(opts, build_dir)?;
+    exit_with(status)
+}
+
+/// Compile the program to LLVM IR and build it against the runtime, then run
+/// it or copy it to the output.
+fn build_llvm(opts: &Opt, program: &lifted_expr::Program, source_file: &str) -> Result<(), Error> {
+    let ir = opts.time("codegen", || llvm::compile(program, source_file))?;
+
+    if opts.emit == Some(Emit::Ll) {
+        print!("{}", ir);
+        return Ok(());
+    }
+
+    if let Cmd::Compile { crate_type, .. } = &opts.cmd {
+        if crate_type.iter().any(|c| *c != CrateType::Bin) {
+            return Err(format_err!("the llvm target only builds bins"));
+        }
+    }
+
+    let build_dir = generate_build_dir();
+    insert_file_into_build_dir(&build_dir, "compiled_result.ll", &ir);
+
+    let make_stdout = opts.time("cc", || {
+        invoke_make(&build_dir, &["llvm_result"], &[], &opts.make_vars())
+    })?;
+
+    if opts.debug {
+        eprintln!("{}", make_stdout);
+    }
+
+    opts.report_passes();
+
+    let status = if let Cmd::Compile { output, .. } = &opts.cmd {
+        copy_artifact(&build_dir, "llvm_result", output)?;
+        None
+    } else {
+        Some(run_program(opts, &build_dir.path().join("llvm_result")).expect("Failed to run?"))
+    };
+
+    close_build_dir(opts, build_dir)?;
+    status.map_or(Ok(()), exit_with)
+}
+
+/// Compile the program to bytecode, then run it in the vm or write it to the
+/// output as a .scmbc file.
+fn build_bytecode(opts: &Opt, lifted: &lifted_expr::Program) -> Result<(), Error> {
+    let program = opts.time("codegen", || bytecode::compile(lifted))?;
+
+    if opts.emit == Some(Emit::Bytecode) {
+        print!("{}", program);
+        return Ok(());
+    }
+
+    opts.report_passes();
+
+    if let Cmd::Compile { output, crate_type } = &opts.cmd {
+        if crate_type.iter().any(|c| *c != CrateType::Bin) {
+            return Err(format_err!("the bytecode target only builds bins"));
+        }
+
+        fs::write(output, program.to_bytes())?;
+        return Ok(());
+    }
+
+    run_bytecode(program, opts.command_line())
+}
+
+fn run_bytecode(program: bytecode::Program, command_line: Vec<String>) -> Result<(), Error> {
+    if let Err(e) = vm::run(program, command_line, &mut stdout().lock()) {
+        exit_after(e);
+    }
+
+    Ok(())
+}
+
+/// Exit with the status given to `exit`, or with 1 after printing the error
+/// the program stopped with.
+fn exit_after(e: Error) -> ! {
+    let _ = stdout().flush();
+
+    if let Some(interp::Exit(status)) = e.downcast_ref() {
+        std::process::exit(*status);
+    }
+
+    eprintln!("Runtime Error: {}", e);
+    std::process::exit(1);
 }
 
-fn invoke_make(tmp_dir: &TempDir) -> Result<String, Error> {
-    let output = Command::new("make")
+fn copy_artifact(tmp_dir: &TempDir, name: &str, output_path: &Path) -> Result<(), Error> {
+    fs::copy(tmp_dir.path().join(name), output_path).map_err(|e| {
+        format_err!(
+            "failed copying {} to {}: {}",
+            name,
+            output_path.display(),
+            e
+        )
+    })?;
+    Ok(())
+}
+
+fn invoke_make(
+    tmp_dir: &TempDir,
+    targets: &[&str],
+    modules: &[String],
+    vars: &[String],
+) -> Result<String, Error> {
+    let mut make = Command::new("make");
+
+    if !modules.is_empty() {
+        make.arg(format!("MODULES={}", modules.join(" ")));
+    }
+
+    make.args(vars);
+
+    let output = make
+        .args(targets)
         .current_dir(tmp_dir.path())
         .output()
         .expect("Failed to build source");
@@ -181,10 +1508,10 @@ fn invoke_make(tmp_dir: &TempDir) -> Result<String, Error> {
     }
 }
 
-fn insert_source_into_build_dir(tmp_dir: &TempDir, source: &str) {
+fn insert_file_into_build_dir(tmp_dir: &TempDir, name: &str, source: &str) {
     use std::io::Write;
 
-    let tmp_path = tmp_dir.path().join("compiled_result.c");
+    let tmp_path = tmp_dir.path().join(name);
 
     let mut file = File::create(tmp_path).unwrap();
 
@@ -211,78 +1538,66 @@ fn generate_build_dir() -> TempDir {
     tmp_dir
 }
 
-fn generate_program_source(src: &str) -> String {
-    format!(
-        "{}{}{}",
-        r#"
-#include <stdlib.h>
-#include <string.h>
+const PROGRAM_HEADER: &str = r#"#ifndef SOMESCHEME_COMPILED_RESULT_H
+#define SOMESCHEME_COMPILED_RESULT_H
+
+// Runs the compiled scheme program, this never returns since the program
+// exits the process once it has completed.
+void scheme_entry(void);
+
+#endif // SOMESCHEME_COMPILED_RESULT_H
+"#;
+
+const MODULE_PRELUDE: &str = r#"
 #include "base.h"
 #include "builtin.h"
-"#,
-        src,
-        r#"
-int main() {
-  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
-  struct thunk initial_thunk = {
-    .closr = &initial_closure,
-    .one = {NULL},
-  };
-
-  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
-  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
-  scheme_start(thnk_heap);
-}
-"#
-    )
-}
+"#;
 
 fn do_codegen(
     opts: &Opt,
-    expr: lifted_expr::LExpr,
-    lambdas: HashMap<usize, lifted_expr::LiftedLambda>,
+    compiler: &Compiler,
+    program: &lifted_expr::Program,
+    stages: &mut Vec<(String, Json)>,
+    source_file: &str,
+    module: Option<&Module>,
+    globals: &HashMap<FreeVar<String>, String>,
 ) -> Result<String, Error> {
-    let mut output_buffer = String::new();
-
-    if opts.debug {
+    if opts.dumps(Pass::Lift) {
         eprintln!("\n\nfinal expr before codegen: ");
-        let _ = expr.pretty_print(StandardStream::stderr(ColorChoice::Auto));
-        eprintln!("");
+        let _ = program
+            .entry()
+            .pretty_print(StandardStream::stderr(ColorChoice::Auto));
+        eprintln!();
 
-        for l in lambdas.values() {
-            eprint!("lambda {}: ", l.id);
+        for l in program.lambdas().values() {
+            eprint!("lambda {} {} ({:?} env): ", l.id, l.name, l.env_repr);
             let _ = l
                 .body
                 .pretty_print(StandardStream::stderr(ColorChoice::Auto));
-            eprintln!("");
+            eprintln!();
         }
     }
 
-    let (mut root_stmts, protos, decls) = codegen::do_codegen(expr, &lambdas);
+    let generated = opts.time("codegen", || {
+        compiler.codegen(program, source_file, module, globals)
+    });
 
-    for proto in &protos {
-        writeln!(&mut output_buffer, "{}", proto.export())?;
+    if opts.emits_json() {
+        stages.push((
+            "c".to_owned(),
+            Json::Obj(vec![
+                (
+                    "protos".to_owned(),
+                    Json::Arr(generated.protos.iter().map(ToJson::to_json).collect()),
+                ),
+                (
+                    "decls".to_owned(),
+                    Json::Arr(generated.decls.iter().map(ToJson::to_json).collect()),
+                ),
+                ("main".to_owned(), generated.main.to_json()),
+            ]),
+        ));
     }
 
-    for decl in &decls {
-        writeln!(&mut output_buffer, "{}", decl.export())?;
-    }
-
-    root_stmts.push(Rc::new(CStmt::Expr(CExpr::MacroCall {
-        name: "__builtin_unreachable".into(),
-        args: vec![],
-    })));
-
-    let main_lambda = CDecl::Fun {
-        name: "main_lambda".into(),
-        typ: CType::Void,
-        args: vec![("input_obj".into(), CType::Ptr(Rc::new(CType::Struct("obj".into())))),
-                   ("input_env".into(), CType::Ptr(Rc::new(CType::Struct("env_obj".into()))))
-        ],
-        body: root_stmts,
-    };
-
-    writeln!(&mut output_buffer, "{}", main_lambda.export())?;
-
-    Ok(output_buffer)
+    Ok(opts.time("codegen", || generated.export()))
 }
@@ -1,19 +1,377 @@
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
+use std::sync::Arc;
 
-use crate::base_expr::{BExpr, BExprBody, BExprBodyExpr};
-use crate::literals::Literal;
-use pest::{error::Error, Parser};
+use crate::base_expr::{generated_name, BExpr, BExprBody, BExprBodyExpr};
+use crate::foreign::{is_c_identifier, Foreign, ForeignType};
+use crate::lexical;
+use crate::library::Libraries;
+use crate::literals::{normalize_ratio, Literal};
+use crate::utils::clone_rc;
+use pest::{
+    error::{Error, ErrorVariant},
+    iterators::Pairs,
+    Parser, Position,
+};
 use pest_derive::Parser;
 
 #[derive(Parser)]
 #[grammar = "grammar.pest"]
 pub struct SchemeParser;
 
-pub fn parse(s: &str) -> Result<BExprBody, Error<Rule>> {
-    let mut pairs = SchemeParser::parse(Rule::program, s)?;
+/// Errors of parsing, boxed as they are much bigger than what they're
+/// returned with.
+pub type ParseError = Box<Error<Rule>>;
 
-    let body = pairs.next().unwrap();
-    Ok(build_body_from_expr(body))
+/// Deeper nesting than this would overflow the stack of the parser.
+const MAX_NESTING: usize = 1000;
+
+pub fn parse(s: &str) -> Result<BExprBody, ParseError> {
+    let s = &lexical::prepare(s);
+    let pairs = parse_checked(s)?;
+    check_expanded(pairs.clone())?;
+
+    let interactive = pairs
+        .clone()
+        .flatten()
+        .any(|p| p.as_rule() == Rule::builtin && p.as_str() == "interaction-environment");
+
+    let mut libraries = Libraries::default();
+    let mut imports = HashMap::new();
+
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::define_library_form => libraries.define(pair)?,
+            Rule::import_decl => libraries.import(pair, &mut imports)?,
+            Rule::body if interactive => {
+                let body = define_in_interaction(build_body_from_expr(pair));
+                return Ok(libraries.link(body, &imports));
+            }
+            Rule::body => return Ok(libraries.link(build_body_from_expr(pair), &imports)),
+            r => unreachable!("{:?}", r),
+        }
+    }
+
+    unreachable!() // grammar should prevent this
+}
+
+/// Find the errors `parse` would in the program as it is written, before its
+/// includes and `cond-expand`s are expanded, so that they point at the
+/// source rather than at the expanded program.
+pub fn check(s: &str) -> Result<(), ParseError> {
+    parse_checked(&lexical::prepare(s)).map(|_| ())
+}
+
+fn parse_checked(s: &str) -> Result<Pairs<'_, Rule>, ParseError> {
+    check_nesting(s)?;
+    let pairs = SchemeParser::parse(Rule::program, s)?;
+    check_record_definitions(pairs.clone())?;
+    check_foreign_definitions(pairs.clone())?;
+    check_literals(pairs.clone())?;
+    Ok(pairs)
+}
+
+/// The toplevel definitions of a program that can `eval` in the interaction
+/// environment are defined there as well, and again after each `set!` of
+/// them, so that what it evaluates can refer to them.
+fn define_in_interaction(body: BExprBody) -> BExprBody {
+    let define = |name: &str| {
+        BExpr::App(
+            Rc::new(BExpr::BuiltinIdent("interaction-define!".to_owned())),
+            vec![
+                BExpr::Lit(Literal::Symbol(name.to_owned())),
+                BExpr::Var(name.to_owned()),
+            ],
+        )
+    };
+
+    let names = body
+        .0
+        .iter()
+        .filter_map(|e| match e {
+            BExprBodyExpr::Def(n, _) => Some(n.clone()),
+            BExprBodyExpr::Expr(_) => None,
+        })
+        .collect::<HashSet<_>>();
+
+    let mut exprs = Vec::new();
+
+    for e in body.0 {
+        match e {
+            BExprBodyExpr::Def(n, e) => {
+                exprs.push(BExprBodyExpr::Def(
+                    n.clone(),
+                    e.follow_sets(&names, &define),
+                ));
+                exprs.push(BExprBodyExpr::Expr(define(&n)));
+            }
+            BExprBodyExpr::Expr(e) => {
+                exprs.push(BExprBodyExpr::Expr(e.follow_sets(&names, &define)))
+            }
+        }
+    }
+
+    BExprBody(
+        exprs,
+        Rc::new(clone_rc(body.1).follow_sets(&names, &define)),
+    )
+}
+
+/// Parse a sequence of top level forms, unlike `parse` this doesn't require
+/// the input to end with an expression.
+pub fn parse_toplevel(s: &str) -> Result<Vec<BExprBodyExpr>, ParseError> {
+    let s = &lexical::prepare(s);
+    check_nesting(s)?;
+    let pairs = SchemeParser::parse(Rule::toplevel, s)?;
+    check_record_definitions(pairs.clone())?;
+    check_foreign_definitions(pairs.clone())?;
+    check_literals(pairs.clone())?;
+    check_expanded(pairs.clone())?;
+
+    Ok(pairs
+        .take_while(|pair| pair.as_rule() != Rule::EOI)
+        .flat_map(build_bodyexprs_from_expr)
+        .collect())
+}
+
+/// Entry point for fuzzing the front end, whatever the input both parsers
+/// should return an error rather than panic.
+pub fn parse_fuzz(data: &[u8]) {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = parse(s);
+        let _ = parse_toplevel(s);
+    }
+}
+
+/// Parse a sequence of forms without checking or building them, for the
+/// passes expanding the source before it is parsed.
+pub(crate) fn parse_forms(s: &str) -> Result<Pairs<'_, Rule>, ParseError> {
+    check_nesting(s)?;
+    Ok(SchemeParser::parse(Rule::forms, s)?)
+}
+
+/// Reject input nested deeper than `MAX_NESTING` before the parser recurses
+/// into it.
+pub(crate) fn check_nesting(s: &str) -> Result<(), ParseError> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (idx, c) in s.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        // the character after #\ is data, a bracket there opens nothing
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '\\' => escaped = s[..idx].ends_with('#'),
+            '(' => {
+                depth += 1;
+
+                if depth > MAX_NESTING {
+                    return Err(Box::new(Error::new_from_pos(
+                        ErrorVariant::CustomError {
+                            message: format!("Expressions nested more than {} deep", MAX_NESTING),
+                        },
+                        Position::new(s, idx).unwrap(),
+                    )));
+                }
+            }
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Integer literals become an `i64` and characters their code point, report
+/// the ones that can't while we can still point at them.
+fn check_literals(pairs: Pairs<Rule>) -> Result<(), ParseError> {
+    for pair in pairs.flatten() {
+        let s = pair.as_str();
+        let message = match pair.as_rule() {
+            Rule::number if s.parse::<i64>().is_err() => "Number literal out of range",
+            Rule::radix_number if parse_radix(s).is_none() => "Number literal out of range",
+            Rule::rational if !s.split('/').all(|part| part.parse::<i64>().is_ok()) => {
+                "Number literal out of range"
+            }
+            Rule::character => match char_code(&s[2..]) {
+                None => "Unknown character",
+                Some(code) if code > MAX_CHAR => "Non-ASCII character, strings are indexed by byte",
+                Some(_) => continue,
+            },
+            _ => continue,
+        };
+
+        return Err(Box::new(Error::new_from_span(
+            ErrorVariant::CustomError {
+                message: format!("{}: {}", message, s),
+            },
+            pair.as_span(),
+        )));
+    }
+
+    Ok(())
+}
+
+/// The largest character a literal can name. Strings are UTF-8 that
+/// `string-ref` indexes by byte, so `#\λ` would never equal a character taken
+/// from a string.
+pub(crate) const MAX_CHAR: i64 = 0x7f;
+
+/// The code point of the character `#\name` is, those being integers, if
+/// `name` is one character, the name of one or `x` and its hex code point.
+pub(crate) fn char_code(name: &str) -> Option<i64> {
+    let mut chars = name.chars();
+
+    let c = match (chars.next()?, chars.next()) {
+        (c, None) => c,
+        _ => match name {
+            "alarm" => '\u{7}',
+            "backspace" => '\u{8}',
+            "delete" => '\u{7f}',
+            "escape" => '\u{1b}',
+            "newline" => '\n',
+            "null" => '\0',
+            "return" => '\r',
+            "space" => ' ',
+            "tab" => '\t',
+            _ => {
+                let hex = name.strip_prefix('x')?;
+
+                // from_str_radix would take a sign as well
+                if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+                    return None;
+                }
+
+                char::from_u32(u32::from_str_radix(hex, 16).ok()?)?
+            }
+        },
+    };
+
+    Some(c as i64)
+}
+
+/// The value of a `radix_number` like `#x1F`, if it fits in an `i64`.
+pub(crate) fn parse_radix(s: &str) -> Option<i64> {
+    let radix = match s.as_bytes()[1].to_ascii_lowercase() {
+        b'x' => 16,
+        b'o' => 8,
+        b'b' => 2,
+        _ => 10,
+    };
+
+    i64::from_str_radix(&s[2..], radix).ok()
+}
+
+/// The name of the symbol an identifier reads as, without the bars around it
+/// if it has them.
+pub(crate) fn symbol_name(identifier: &str) -> &str {
+    match identifier.strip_prefix('|') {
+        Some(name) if name.ends_with('|') => &name[..name.len() - 1],
+        _ => identifier,
+    }
+}
+
+/// Includes, `cond-expand` and `(features)` are expanded in the text before
+/// it is parsed, by `include::expand_includes` and `features::expand`.
+fn check_expanded(pairs: Pairs<Rule>) -> Result<(), ParseError> {
+    let unexpanded = pairs.flatten().find_map(|p| match p.as_rule() {
+        Rule::include_form => Some((p, "Include")),
+        Rule::cond_expand_form => Some((p, "cond-expand")),
+        Rule::features_form => Some((p, "(features)")),
+        _ => None,
+    });
+
+    match unexpanded {
+        Some((pair, what)) => Err(Box::new(Error::new_from_span(
+            ErrorVariant::CustomError {
+                message: format!("{} wasn't expanded", what),
+            },
+            pair.as_span(),
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// The name of a foreign function is also used verbatim in the C we emit.
+fn check_foreign_definitions(pairs: Pairs<Rule>) -> Result<(), ParseError> {
+    for pair in pairs.flatten() {
+        if pair.as_rule() != Rule::define_foreign_form {
+            continue;
+        }
+
+        let name = pair.into_inner().next().unwrap();
+
+        if !is_c_identifier(name.as_str()) {
+            return Err(Box::new(Error::new_from_span(
+                ErrorVariant::CustomError {
+                    message: format!(
+                        "Foreign function name is not a C identifier: {}",
+                        name.as_str()
+                    ),
+                },
+                name.as_span(),
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// The expansion of `define-record-type` assumes its field names are
+/// consistent, so check that here where we can still point at the source.
+fn check_record_definitions(pairs: Pairs<Rule>) -> Result<(), ParseError> {
+    for pair in pairs.flatten() {
+        if pair.as_rule() != Rule::define_record_form {
+            continue;
+        }
+
+        let mut pair = pair.into_inner();
+        let constructor = pair.nth(1).unwrap();
+        let mut fields = Vec::new();
+
+        for field in pair.skip(1) {
+            let name = field.into_inner().next().unwrap();
+
+            if fields.contains(&name.as_str()) {
+                return Err(Box::new(Error::new_from_span(
+                    ErrorVariant::CustomError {
+                        message: format!("Duplicate record field: {}", name.as_str()),
+                    },
+                    name.as_span(),
+                )));
+            }
+
+            fields.push(name.as_str());
+        }
+
+        for name in constructor.into_inner().skip(1) {
+            if !fields.contains(&name.as_str()) {
+                return Err(Box::new(Error::new_from_span(
+                    ErrorVariant::CustomError {
+                        message: format!("Constructor argument is not a field: {}", name.as_str()),
+                    },
+                    name.as_span(),
+                )));
+            }
+        }
+    }
+
+    Ok(())
 }
 
 fn build_bexpr_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
@@ -25,6 +383,17 @@ fn build_bexpr_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
         Rule::set_form => build_set_from_expr(pair),
         Rule::let_form => build_let_from_expr(pair),
         Rule::lambda_form => build_lambda_from_expr(pair),
+        Rule::case_lambda_form => {
+            BExpr::CaseLam(pair.into_inner().map(build_lambda_from_expr).collect())
+        }
+        Rule::delay_form => build_delay_from_expr(pair),
+        Rule::make_parameter_form => build_make_parameter_from_expr(pair),
+        Rule::parameterize_form => build_parameterize_from_expr(pair),
+        Rule::assert_form => build_assert_from_expr(pair),
+        Rule::check_arg_form => build_check_arg_from_expr(pair),
+        Rule::write_form => build_write_from_expr(pair),
+        Rule::port_form => build_port_from_expr(pair),
+        Rule::error_form => build_error_from_expr(pair),
         Rule::app => build_app_from_expr(pair),
         Rule::variable => BExpr::Var(pair.as_str().to_owned()),
         e => unreachable!("{:?}", e),
@@ -53,17 +422,20 @@ fn build_set_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
     BExpr::Set(name, Rc::new(expr))
 }
 
-fn build_bodyexpr_from_expr(pair: pest::iterators::Pair<Rule>) -> BExprBodyExpr {
+pub(crate) fn build_bodyexprs_from_expr(pair: pest::iterators::Pair<Rule>) -> Vec<BExprBodyExpr> {
     match pair.as_rule() {
-        Rule::define_form => build_bexprbodyexpr_from_define(pair),
-        Rule::expr => BExprBodyExpr::Expr(build_bexpr_from_expr(pair)),
+        Rule::define_form => vec![build_bexprbodyexpr_from_define(pair)],
+        Rule::define_procedure_form => vec![build_bexprbodyexpr_from_define_procedure(pair)],
+        Rule::define_record_form => build_bexprbodyexprs_from_define_record(pair),
+        Rule::define_foreign_form => vec![build_bexprbodyexpr_from_define_foreign(pair)],
+        Rule::expr => vec![BExprBodyExpr::Expr(build_bexpr_from_expr(pair))],
         r => unreachable!("{:?}", r),
     }
 }
 
 fn build_body_from_expr(pair: pest::iterators::Pair<Rule>) -> BExprBody {
     let pair = pair.into_inner();
-    let mut things = pair.map(build_bodyexpr_from_expr).collect::<Vec<_>>();
+    let mut things = pair.flat_map(build_bodyexprs_from_expr).collect::<Vec<_>>();
     let last = things.pop().unwrap();
     if let BExprBodyExpr::Expr(e) = last {
         BExprBody(things, Rc::new(e))
@@ -92,7 +464,261 @@ fn build_let_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
     BExpr::Let(bindings, body)
 }
 
+/// `(delay e)` becomes an application of the delay builtin to a thunk of `e`,
+/// the builtin isn't nameable in source so it only appears here.
+fn build_delay_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let line = line_of(&pair);
+    let expr = build_bexpr_from_expr(pair.into_inner().nth(1).unwrap());
+    let thunk = BExpr::Lam(vec![], BExprBody(vec![], Rc::new(expr)), Some(line));
+
+    BExpr::App(
+        Rc::new(BExpr::BuiltinIdent("delay".to_owned())),
+        vec![thunk],
+    )
+}
+
+/// `(make-parameter v converter)` gives the converter to the builtin along
+/// with the value converted by it, without one the converter is the identity.
+fn build_make_parameter_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let line = line_of(&pair);
+    let mut pair = pair.into_inner().skip(1);
+    let value = build_bexpr_from_expr(pair.next().unwrap());
+    let converter = match pair.next() {
+        Some(converter) => build_bexpr_from_expr(converter),
+        None => BExpr::Lam(
+            vec![generated_name("x")],
+            BExprBody(vec![], Rc::new(BExpr::Var(generated_name("x")))),
+            Some(line),
+        ),
+    };
+
+    BExpr::Let(
+        vec![(generated_name("converter"), converter)],
+        BExprBody(
+            vec![],
+            Rc::new(BExpr::App(
+                Rc::new(BExpr::BuiltinIdent("make-parameter".to_owned())),
+                vec![
+                    BExpr::App(
+                        Rc::new(BExpr::Var(generated_name("converter"))),
+                        vec![value],
+                    ),
+                    BExpr::Var(generated_name("converter")),
+                ],
+            )),
+        ),
+    )
+}
+
+/// `parameterize` converts the values with the converters of the parameters,
+/// then gives the parameters them in the before thunk of a `dynamic-wind`
+/// around the body and takes them back in the after thunk, so that leaving the
+/// body by `raise` restores them too. The values are kept with the thread
+/// rather than in the parameters, so other threads don't see them.
+fn build_parameterize_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let line = line_of(&pair);
+    let mut inner = pair.into_inner().skip(1).collect::<Vec<_>>();
+    let body = build_body_from_expr(inner.pop().unwrap());
+
+    let var = |name: String| BExpr::Var(name);
+    let param = |i: usize| generated_name(&format!("parameter{}", i));
+    let value = |i: usize| generated_name(&format!("value{}", i));
+    let thunk =
+        |exprs: Vec<BExpr>, last: BExpr| BExpr::Lam(vec![], body_of(exprs, last), Some(line));
+
+    let mut params = Vec::new();
+    let mut converted = Vec::new();
+
+    for (i, binding) in inner.into_iter().enumerate() {
+        let mut binding = binding.into_inner();
+        params.push((param(i), build_bexpr_from_expr(binding.next().unwrap())));
+        params.push((value(i), build_bexpr_from_expr(binding.next().unwrap())));
+
+        let converter = BExpr::App(
+            Rc::new(BExpr::BuiltinIdent("parameter-converter".to_owned())),
+            vec![var(param(i))],
+        );
+        converted.push((
+            value(i),
+            BExpr::App(Rc::new(converter), vec![var(value(i))]),
+        ));
+    }
+
+    let pushes = (0..converted.len())
+        .map(|i| {
+            BExpr::App(
+                Rc::new(BExpr::BuiltinIdent("parameter-push!".to_owned())),
+                vec![var(param(i)), var(value(i))],
+            )
+        })
+        .collect::<Vec<_>>();
+    let pops = (0..converted.len())
+        .rev()
+        .map(|i| {
+            BExpr::App(
+                Rc::new(BExpr::BuiltinIdent("parameter-pop!".to_owned())),
+                vec![var(param(i))],
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let wind = BExpr::App(
+        Rc::new(BExpr::BuiltinIdent("dynamic-wind".to_owned())),
+        vec![
+            thunk(pushes, BExpr::Lit(Literal::Void)),
+            BExpr::Lam(vec![], body, Some(line)),
+            thunk(pops, BExpr::Lit(Literal::Void)),
+        ],
+    );
+
+    BExpr::Let(
+        params,
+        body_of(vec![], BExpr::Let(converted, body_of(vec![], wind))),
+    )
+}
+
+/// `(assert e)` gives the value of `e` unless it is `#f`, when an error
+/// quoting `e` and its line is raised instead.
+fn build_assert_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let line = line_of(&pair);
+    let expr = pair.into_inner().nth(1).unwrap();
+    let message = format!("assertion {} failed on line {}", source_text(&expr), line);
+
+    let value = || BExpr::Var(generated_name("value"));
+
+    BExpr::Let(
+        vec![(generated_name("value"), build_bexpr_from_expr(expr))],
+        body_of(
+            vec![],
+            BExpr::If(
+                Rc::new(value()),
+                Rc::new(value()),
+                Rc::new(error(message, BExpr::Lit(Literal::Void))),
+            ),
+        ),
+    )
+}
+
+/// `(check-arg pred v caller)` gives `v` if `(pred v)` holds, and otherwise
+/// raises an error blaming `caller`, with `v` as its irritant.
+fn build_check_arg_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let line = line_of(&pair);
+    let mut inner = pair.into_inner().skip(1);
+    let (pred, value, caller) = (
+        inner.next().unwrap(),
+        inner.next().unwrap(),
+        inner.next().unwrap(),
+    );
+    let message = format!(
+        "{}: argument {} failed {} on line {}",
+        source_text(&caller).trim_start_matches('\''),
+        source_text(&value),
+        source_text(&pred),
+        line
+    );
+
+    let var = |name: &str| BExpr::Var(generated_name(name));
+
+    BExpr::Let(
+        vec![
+            (generated_name("pred"), build_bexpr_from_expr(pred)),
+            (generated_name("value"), build_bexpr_from_expr(value)),
+        ],
+        body_of(
+            vec![],
+            BExpr::If(
+                Rc::new(BExpr::App(Rc::new(var("pred")), vec![var("value")])),
+                Rc::new(var("value")),
+                Rc::new(error(
+                    message,
+                    BExpr::App(
+                        Rc::new(BExpr::BuiltinIdent("cons".to_owned())),
+                        vec![var("value"), BExpr::Lit(Literal::Void)],
+                    ),
+                )),
+            ),
+        ),
+    )
+}
+
+/// `(display obj port)` and `(write obj port)`, writing to the current output
+/// port when not given one.
+fn build_write_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let mut inner = pair.into_inner();
+    let name = inner.next().unwrap().as_str().to_owned();
+    let value = build_bexpr_from_expr(inner.next().unwrap());
+    let port = port_or_current(inner.next(), "current-output-port");
+
+    BExpr::App(Rc::new(BExpr::BuiltinIdent(name)), vec![value, port])
+}
+
+/// `(newline port)` and the reads, `(read-char port)`, `(read-line port)` and
+/// `(read port)`, which use the current output or input port when not given
+/// one.
+fn build_port_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let mut inner = pair.into_inner();
+    let name = inner.next().unwrap().as_str().to_owned();
+    let current = if name == "newline" {
+        "current-output-port"
+    } else {
+        "current-input-port"
+    };
+    let port = port_or_current(inner.next(), current);
+
+    BExpr::App(Rc::new(BExpr::BuiltinIdent(name)), vec![port])
+}
+
+/// The port `pair` gives, or `(current)` without one.
+fn port_or_current(pair: Option<pest::iterators::Pair<Rule>>, current: &str) -> BExpr {
+    pair.map(build_bexpr_from_expr)
+        .unwrap_or_else(|| BExpr::App(Rc::new(BExpr::BuiltinIdent(current.to_owned())), vec![]))
+}
+
+/// `(error message irritant ...)` raises an error with the list of its
+/// irritants, which are printed after the message when nothing handles it.
+fn build_error_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let mut inner = pair.into_inner();
+    let message = build_bexpr_from_expr(inner.nth(1).unwrap());
+
+    BExpr::App(
+        Rc::new(BExpr::BuiltinIdent("error".to_owned())),
+        vec![message, build_list(inner)],
+    )
+}
+
+/// The error with `message` and the list `irritants`, with the message
+/// escaped like the strings of the source are.
+fn error(message: String, irritants: BExpr) -> BExpr {
+    let message = message.replace('\\', "\\\\").replace('"', "\\\"");
+
+    BExpr::App(
+        Rc::new(BExpr::BuiltinIdent("error".to_owned())),
+        vec![BExpr::Lit(Literal::String(message)), irritants],
+    )
+}
+
+/// The source of `pair` on one line, for quoting in messages.
+fn source_text(pair: &pest::iterators::Pair<Rule>) -> String {
+    pair.as_str()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A body of the expressions `exprs` whose value is that of `last`.
+fn body_of(exprs: Vec<BExpr>, last: BExpr) -> BExprBody {
+    BExprBody(
+        exprs.into_iter().map(BExprBodyExpr::Expr).collect(),
+        Rc::new(last),
+    )
+}
+
+fn line_of(pair: &pest::iterators::Pair<Rule>) -> usize {
+    pair.line_col().0
+}
+
 fn build_lambda_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
+    let line = line_of(&pair);
     let mut pair = pair.into_inner();
     let bindings = pair
         .next()
@@ -103,7 +729,7 @@ fn build_lambda_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
     let body = pair.next().unwrap();
     let body = build_body_from_expr(body);
 
-    BExpr::Lam(bindings, body)
+    BExpr::Lam(bindings, body, Some(line))
 }
 
 fn build_app_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
@@ -116,25 +742,63 @@ fn build_app_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
     BExpr::App(Rc::new(function), params)
 }
 
+/// A list of constants is a `Literal::Quoted`, otherwise it's made by `cons`.
+fn build_list(pairs: pest::iterators::Pairs<Rule>) -> BExpr {
+    let items = pairs.map(build_bexpr_from_expr).collect::<Vec<_>>();
+
+    if !items.is_empty() && items.iter().all(|e| matches!(e, BExpr::Lit(_))) {
+        let items = items
+            .into_iter()
+            .map(|e| match e {
+                BExpr::Lit(l) => l,
+                _ => unreachable!(),
+            })
+            .collect();
+
+        return BExpr::Lit(Literal::Quoted(Arc::new(items)));
+    }
+
+    let cons = Rc::new(BExpr::BuiltinIdent("cons".to_owned()));
+    items
+        .into_iter()
+        .rev()
+        .fold(BExpr::Lit(Literal::Void), |a, e| {
+            BExpr::App(cons.clone(), vec![e, a])
+        })
+}
+
 fn build_literal_from_expr(pair: pest::iterators::Pair<Rule>) -> BExpr {
     match pair.as_rule() {
-        Rule::list_literal => {
-            let pair = pair.into_inner();
-            let cons = Rc::new(BExpr::BuiltinIdent("cons".to_owned()));
-            let expr = pair
-                .map(build_bexpr_from_expr)
-                .collect::<Vec<_>>()
-                .into_iter()
-                .rev()
-                .fold(BExpr::Lit(Literal::Void), |a, e| {
-                    BExpr::App(cons.clone(), vec![e, a])
-                });
-            expr
-        }
+        Rule::list_literal => build_list(pair.into_inner()),
+        Rule::vector_literal => BExpr::App(
+            Rc::new(BExpr::BuiltinIdent("list->vector".to_owned())),
+            vec![build_list(pair.into_inner())],
+        ),
+        Rule::bytevector_literal => BExpr::App(
+            Rc::new(BExpr::BuiltinIdent("list->bytevector".to_owned())),
+            vec![build_list(pair.into_inner())],
+        ),
         Rule::number => BExpr::Lit(Literal::Int(pair.as_str().parse().unwrap())),
+        Rule::radix_number => BExpr::Lit(Literal::Int(parse_radix(pair.as_str()).unwrap())),
+        Rule::character => BExpr::Lit(Literal::Int(char_code(&pair.as_str()[2..]).unwrap())),
+        Rule::decimal => BExpr::Lit(Literal::Float(pair.as_str().parse().unwrap())),
+        Rule::rational => {
+            let (n, d) = pair.as_str().split_at(pair.as_str().find('/').unwrap());
+            let (n, d) = normalize_ratio(n.parse().unwrap(), d[1..].parse().unwrap()).unwrap();
+
+            if d == 1 {
+                BExpr::Lit(Literal::Int(n))
+            } else {
+                BExpr::Lit(Literal::Rational(n, d))
+            }
+        }
         Rule::quoted_string => BExpr::Lit(Literal::String(
             pair.into_inner().next().unwrap().as_str().to_owned(),
         )),
+        Rule::symbol_literal => BExpr::Lit(Literal::Symbol(
+            symbol_name(pair.into_inner().next().unwrap().as_str()).to_owned(),
+        )),
+        Rule::boolean => BExpr::Lit(Literal::Bool(pair.as_str().starts_with("#t"))),
         Rule::null => BExpr::Lit(Literal::Void),
         _ => unreachable!(),
     }
@@ -148,3 +812,174 @@ fn build_bexprbodyexpr_from_define(pair: pest::iterators::Pair<Rule>) -> BExprBo
 
     BExprBodyExpr::Def(name, expr)
 }
+
+/// `(define (f x ...) body)` defines `f` as `(lambda (x ...) body)`.
+fn build_bexprbodyexpr_from_define_procedure(pair: pest::iterators::Pair<Rule>) -> BExprBodyExpr {
+    let line = line_of(&pair);
+    let mut pair = pair.into_inner();
+    let name = pair.next().unwrap().as_str().to_owned();
+    let params = pair
+        .next()
+        .unwrap()
+        .into_inner()
+        .map(|pair| pair.as_str().to_owned())
+        .collect();
+    let body = build_body_from_expr(pair.next().unwrap());
+
+    BExprBodyExpr::Def(name, BExpr::Lam(params, body, Some(line)))
+}
+
+/// Expands a record definition into definitions of the record type and its
+/// procedures, which are built from builtins that aren't nameable in source.
+/// The parameters of the procedures contain a space so that they can't capture
+/// any user variables.
+fn build_bexprbodyexprs_from_define_record(
+    pair: pest::iterators::Pair<Rule>,
+) -> Vec<BExprBodyExpr> {
+    let line = line_of(&pair);
+    let mut pair = pair.into_inner();
+    let type_name = pair.next().unwrap().as_str().to_owned();
+    let mut constructor = pair
+        .next()
+        .unwrap()
+        .into_inner()
+        .map(|p| p.as_str().to_owned());
+    let constructor_name = constructor.next().unwrap();
+    let constructor_fields = constructor.collect::<Vec<_>>();
+    let predicate_name = pair.next().unwrap().as_str().to_owned();
+    let fields = pair
+        .map(|field| {
+            field
+                .into_inner()
+                .map(|p| p.as_str().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let builtin = |name: &str| Rc::new(BExpr::BuiltinIdent(name.to_owned()));
+    let int = |i: usize| BExpr::Lit(Literal::Int(i as i64));
+    let symbol = |name: &str| BExpr::Lit(Literal::Symbol(name.to_owned()));
+    let param = generated_name;
+    let lambda = |params: Vec<String>, body: BExpr| {
+        BExpr::Lam(params, BExprBody(vec![], Rc::new(body)), Some(line))
+    };
+    let record_type = || BExpr::Var(type_name.clone());
+    let checked_record = |proc_name: &str| {
+        BExpr::App(
+            builtin("record-check"),
+            vec![
+                record_type(),
+                symbol(proc_name),
+                BExpr::Var(param("record")),
+            ],
+        )
+    };
+
+    let mut defs = vec![BExprBodyExpr::Def(
+        type_name.clone(),
+        BExpr::App(
+            builtin("make-record-type"),
+            vec![symbol(&type_name), int(fields.len())],
+        ),
+    )];
+
+    let constructor_body = constructor_fields.iter().fold(
+        BExpr::App(builtin("make-record"), vec![record_type()]),
+        |record, name| {
+            let idx = fields.iter().position(|f| &f[0] == name).unwrap();
+
+            BExpr::App(
+                builtin("record-set!"),
+                vec![record, int(idx), BExpr::Var(param(name))],
+            )
+        },
+    );
+    defs.push(BExprBodyExpr::Def(
+        constructor_name,
+        lambda(
+            constructor_fields.iter().map(|f| param(f)).collect(),
+            constructor_body,
+        ),
+    ));
+
+    defs.push(BExprBodyExpr::Def(
+        predicate_name,
+        lambda(
+            vec![param("obj")],
+            BExpr::App(
+                builtin("record?"),
+                vec![record_type(), BExpr::Var(param("obj"))],
+            ),
+        ),
+    ));
+
+    for (idx, field) in fields.iter().enumerate() {
+        let accessor = &field[1];
+        defs.push(BExprBodyExpr::Def(
+            accessor.clone(),
+            lambda(
+                vec![param("record")],
+                BExpr::App(
+                    builtin("record-ref"),
+                    vec![checked_record(accessor), int(idx)],
+                ),
+            ),
+        ));
+
+        if let Some(modifier) = field.get(2) {
+            defs.push(BExprBodyExpr::Def(
+                modifier.clone(),
+                lambda(
+                    vec![param("record"), param("value")],
+                    BExpr::App(
+                        builtin("record-set!"),
+                        vec![
+                            checked_record(modifier),
+                            int(idx),
+                            BExpr::Var(param("value")),
+                        ],
+                    ),
+                ),
+            ));
+        }
+    }
+
+    defs
+}
+
+/// The foreign function is bound to a lambda passing its arguments as a list
+/// to the builtin that calls it.
+fn build_bexprbodyexpr_from_define_foreign(pair: pest::iterators::Pair<Rule>) -> BExprBodyExpr {
+    let line = line_of(&pair);
+    let mut pair = pair.into_inner();
+    let name = pair.next().unwrap().as_str().to_owned();
+    let mut types = pair
+        .map(|p| ForeignType::from_name(p.as_str().trim()).unwrap())
+        .collect::<Vec<_>>();
+    let ret = types.pop().unwrap();
+
+    let params = (0..types.len())
+        .map(|i| generated_name(&format!("arg{}", i)))
+        .collect::<Vec<_>>();
+    let args = params.iter().rev().fold(BExpr::Lit(Literal::Void), |a, p| {
+        BExpr::App(
+            Rc::new(BExpr::BuiltinIdent("cons".to_owned())),
+            vec![BExpr::Var(p.clone()), a],
+        )
+    });
+
+    let foreign = Foreign {
+        name: name.clone(),
+        params: types,
+        ret,
+    };
+    let call = BExpr::App(
+        Rc::new(BExpr::BuiltinIdent(foreign.builtin_name())),
+        vec![args],
+    );
+
+    BExprBodyExpr::Def(
+        name,
+        BExpr::Lam(params, BExprBody(vec![], Rc::new(call)), Some(line)),
+    )
+}
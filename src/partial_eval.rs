@@ -0,0 +1,239 @@
+//! Partial evaluation of the bound program. A call of a pure builtin with
+//! constant arguments is evaluated by the interpreter while compiling, an `if`
+//! on a constant is replaced with the branch it takes, and a lambda applied to
+//! a constant where it is made has its parameter replaced with the constant,
+//! so that `let`s of constants fold away along with what is computed from
+//! them. What is left are literals, which the backends emit as constants.
+
+use moniker::{Binder, FreeVar, Ignore, Var};
+
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::expr::{Atom, Expr};
+use crate::interp::{self, builtin_arity, Value};
+use crate::literals::Literal;
+use crate::utils::{clone_rc, open_scope};
+
+/// The builtins that only compute a result from their arguments, and give a
+/// constant when those are constants.
+pub(crate) const PURE_BUILTINS: &[&str] = &[
+    "+",
+    "-",
+    "*",
+    "/",
+    "%",
+    "^",
+    "<",
+    "<=",
+    ">",
+    ">=",
+    "=",
+    "zero?",
+    "number?",
+    "string?",
+    "boolean?",
+    "symbol?",
+    "null?",
+    "pair?",
+    "cons?",
+    "equal?",
+    "string-length",
+    "string-ref",
+    "substring",
+    "string-append",
+    "string=?",
+    "string->number",
+    "number->string",
+    "symbol->string",
+    "string->symbol",
+];
+
+/// What partial evaluation did, printed with `--verbose`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub calls: usize,
+    pub ifs: usize,
+    pub bindings: usize,
+}
+
+/// Evaluate what can be of `expr` without running it.
+pub fn partial_eval(expr: Expr) -> (Expr, Stats) {
+    let expr = expr.open();
+    let mut set = HashSet::new();
+    expr.set_vars(&mut set);
+
+    let mut ctx = Evaluator {
+        constants: HashMap::new(),
+        set,
+        stats: Stats::default(),
+    };
+
+    // like `shrink`, the call of the program's lambda is kept so that the
+    // inliner has a lambda to inline into
+    let expr = match expr {
+        Expr::App(f, a) if matches!(&*f, Expr::Atom(Atom::Lam(..))) => Expr::App(
+            Rc::new(ctx.eval(clone_rc(f))),
+            Rc::new(ctx.eval(clone_rc(a))),
+        ),
+        expr => ctx.eval(expr),
+    };
+
+    (expr.close(), ctx.stats)
+}
+
+struct Evaluator {
+    /// parameters replaced with the constant they were bound to
+    constants: HashMap<FreeVar<String>, Literal>,
+    /// the variables `set!` in the program, its binders are all distinct
+    set: HashSet<FreeVar<String>>,
+    stats: Stats,
+}
+
+impl Evaluator {
+    fn eval(&mut self, expr: Expr) -> Expr {
+        match expr {
+            Expr::Atom(Atom::Var(Var::Free(v))) if self.constants.contains_key(&v) => {
+                lit(self.constants[&v].clone())
+            }
+            Expr::Atom(Atom::Lam(s, line)) => {
+                let Binder(x) = s.unsafe_pattern;
+                let body = self.eval(clone_rc(s.unsafe_body));
+                Expr::Atom(Atom::Lam(open_scope(x, Rc::new(body)), line))
+            }
+            e @ Expr::Atom(_) => e,
+            Expr::If(c, ift, iff) => {
+                let c = self.eval(clone_rc(c));
+
+                match as_literal(&c) {
+                    Some(_) => {
+                        self.stats.ifs += 1;
+
+                        if interp::eval(&c, &mut std::io::sink()).map_or(true, |v| v.is_truthy()) {
+                            self.eval(clone_rc(ift))
+                        } else {
+                            self.eval(clone_rc(iff))
+                        }
+                    }
+                    None => Expr::If(
+                        Rc::new(c),
+                        Rc::new(self.eval(clone_rc(ift))),
+                        Rc::new(self.eval(clone_rc(iff))),
+                    ),
+                }
+            }
+            Expr::Set(v, e) => Expr::Set(v, Rc::new(self.eval(clone_rc(e)))),
+            Expr::App(f, a) => {
+                let a = self.eval(clone_rc(a));
+
+                // the lambda of a let of several bindings is made by applying
+                // the lambda of the bindings before
+                let (f, evaluated) = match &*f {
+                    Expr::Atom(Atom::Lam(..)) => (clone_rc(f), false),
+                    _ => (self.eval(clone_rc(f)), true),
+                };
+
+                if let (Expr::Atom(Atom::Lam(s, _)), Some(l)) = (&f, as_literal(&a)) {
+                    // each evaluation of a string literal makes a new string,
+                    // and the interpreter makes a new list for a quoted one
+                    if !matches!(l, Literal::String(_) | Literal::Quoted(_)) {
+                        let Binder(x) = &s.unsafe_pattern;
+
+                        if !self.set.contains(x) {
+                            self.stats.bindings += 1;
+                            self.constants.insert(x.clone(), l.clone());
+                            return self.eval(clone_rc(s.unsafe_body.clone()));
+                        }
+                    }
+                }
+
+                let f = if evaluated { f } else { self.eval(f) };
+                let e = Expr::App(Rc::new(f), Rc::new(a));
+
+                match fold_call(&e) {
+                    Some(l) => {
+                        self.stats.calls += 1;
+                        lit(l)
+                    }
+                    None => e,
+                }
+            }
+        }
+    }
+}
+
+/// The result of a call of a pure builtin with all of its arguments, when
+/// they are all constants and the call doesn't fail.
+fn fold_call(e: &Expr) -> Option<Literal> {
+    let mut args = 0;
+    let mut head = e;
+
+    while let Expr::App(f, a) = head {
+        as_literal(a)?;
+        args += 1;
+        head = f;
+    }
+
+    match head {
+        Expr::Atom(Atom::BuiltinIdent(Ignore(name)))
+            if PURE_BUILTINS.contains(&name.as_str()) && builtin_arity(name) == Some(args) => {}
+        _ => return None,
+    }
+
+    let value = interp::eval(e, &mut std::io::sink()).ok()?;
+
+    let l = match value {
+        Value::Void => Literal::Void,
+        Value::Int(i) => Literal::Int(i),
+        Value::Rational(n, d) => Literal::Rational(n, d),
+        Value::Float(f) if f.is_finite() => Literal::Float(f),
+        Value::Str(s) if is_plain(&s) => Literal::String(s.to_string()),
+        Value::Symbol(s) if is_plain(&s) => Literal::Symbol(s.to_string()),
+        Value::Bool(b) => Literal::Bool(b),
+        _ => return None,
+    };
+
+    Some(l)
+}
+
+/// Whether a string needs no escaping to be a literal.
+fn is_plain(s: &str) -> bool {
+    s.bytes()
+        .all(|c| (b' '..=b'~').contains(&c) && c != b'\\' && c != b'"')
+}
+
+fn lit(l: Literal) -> Expr {
+    Expr::Atom(Atom::Lit(Ignore(l)))
+}
+
+fn as_literal(e: &Expr) -> Option<&Literal> {
+    match e {
+        Expr::Atom(Atom::Lit(Ignore(l))) => Some(l),
+        _ => None,
+    }
+}
+
+impl Expr {
+    /// The variables `set!` anywhere in the expression.
+    fn set_vars(&self, out: &mut HashSet<FreeVar<String>>) {
+        match self {
+            Expr::Atom(Atom::Lam(s, _)) => s.unsafe_body.set_vars(out),
+            Expr::Atom(_) => {}
+            Expr::If(c, ift, iff) => {
+                c.set_vars(out);
+                ift.set_vars(out);
+                iff.set_vars(out);
+            }
+            Expr::Set(v, e) => {
+                if let Var::Free(v) = v {
+                    out.insert(v.clone());
+                }
+                e.set_vars(out);
+            }
+            Expr::App(f, a) => {
+                f.set_vars(out);
+                a.set_vars(out);
+            }
+        }
+    }
+}
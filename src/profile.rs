@@ -0,0 +1,82 @@
+//! Reading the profiles written by programs compiled with `--profile`. The
+//! runtime writes a line for each lambda, with its call count, the name of its
+//! C function and where it is in the source, separated by tabs.
+
+use failure::{format_err, Error};
+
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub calls: u64,
+    pub lambda: String,
+    pub location: String,
+}
+
+pub fn parse(profile: &str) -> Result<Vec<Entry>, Error> {
+    profile
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut fields = line.splitn(3, '\t');
+
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some(calls), Some(lambda), Some(location)) => Ok(Entry {
+                    calls: calls
+                        .parse()
+                        .map_err(|_| format_err!("line {}: bad call count {:?}", i + 1, calls))?,
+                    lambda: lambda.to_owned(),
+                    location: location.to_owned(),
+                }),
+                _ => Err(format_err!("line {}: not a profile entry", i + 1)),
+            }
+        })
+        .collect()
+}
+
+/// The `top` most called lambdas, with their share of all the calls.
+pub fn report(mut entries: Vec<Entry>, top: usize) -> String {
+    let total = entries.iter().map(|e| e.calls).sum::<u64>();
+
+    entries.sort_by(|a, b| b.calls.cmp(&a.calls).then_with(|| a.lambda.cmp(&b.lambda)));
+    entries.truncate(top);
+
+    let width = entries
+        .iter()
+        .map(|e| e.lambda.len())
+        .chain(Some("lambda".len()))
+        .max()
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:>12} {:>6}  {:<width$}  source",
+        "calls",
+        "%",
+        "lambda",
+        width = width
+    );
+
+    for e in &entries {
+        let share = if total == 0 {
+            0.0
+        } else {
+            100.0 * e.calls as f64 / total as f64
+        };
+
+        let _ = writeln!(
+            out,
+            "{:>12} {:>6.1}  {:<width$}  {}",
+            e.calls,
+            share,
+            e.lambda,
+            e.location,
+            width = width
+        );
+    }
+
+    let _ = writeln!(out, "{} calls in total", total);
+
+    out
+}
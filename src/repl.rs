@@ -0,0 +1,154 @@
+use failure::{format_err, Error};
+use termcolor::{ColorChoice, StandardStream};
+
+use std::fs::read_to_string;
+use std::io::{stdin, stdout, Write};
+use std::path::Path;
+
+use crate::base_expr::{BExpr, BExprBodyExpr};
+use crate::features::{self, Features};
+use crate::interp::{Exit, InterpCtx, Value};
+use crate::literals::Literal;
+use crate::parse;
+
+/// Is the input unfinished, i.e. is there an unclosed paren or string
+fn is_incomplete(input: &str) -> bool {
+    let mut depth = 0isize;
+    let mut in_string = false;
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '(' if !in_string => depth += 1,
+            ')' if !in_string => depth -= 1,
+            _ => {}
+        }
+    }
+
+    in_string || depth > 0
+}
+
+/// The globals typed in are those of the interaction environment, which
+/// `eval` sees too.
+struct Repl<'a> {
+    interp: InterpCtx<'a>,
+    /// what `cond-expand` chooses its clauses by
+    features: Features,
+}
+
+impl<'a> Repl<'a> {
+    fn eval_forms(&mut self, forms: Vec<BExprBodyExpr>) -> Result<(), Error> {
+        // declare everything up front so that definitions in the same input
+        // can refer to each other
+        for form in &forms {
+            if let BExprBodyExpr::Def(name, _) = form {
+                self.interp.declare(name);
+            }
+        }
+
+        for form in forms {
+            match form {
+                BExprBodyExpr::Def(name, e) => {
+                    let expr = e.into_expr_with_env(self.interp.interaction())?;
+                    let val = self.interp.eval(&expr)?;
+                    let var = self.interp.declare(&name);
+                    self.interp.set_global(&var, val)?;
+                }
+                BExprBodyExpr::Expr(e) => {
+                    if let Some(path) = self.load_path(&e) {
+                        self.load(&path)?;
+                        continue;
+                    }
+
+                    let expr = e.into_expr_with_env(self.interp.interaction())?;
+                    let val = self.interp.eval(&expr)?;
+
+                    if let Value::Void = val {
+                        continue;
+                    }
+
+                    let _ = val.pretty_print(StandardStream::stdout(ColorChoice::Auto));
+                    println!();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The file of a `(load "file")`, unless `load` has been defined.
+    fn load_path(&self, e: &BExpr) -> Option<String> {
+        match e {
+            BExpr::App(f, args) if !self.interp.interaction().contains_key("load") => {
+                match (&**f, &args[..]) {
+                    (BExpr::Var(f), [BExpr::Lit(Literal::String(path))]) if f == "load" => {
+                        Some(path.clone())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluate the forms of a file as though they had been typed in, the path
+    /// is relative to the current directory.
+    fn load(&mut self, path: &str) -> Result<(), Error> {
+        let path = Path::new(path);
+        let source = read_to_string(path).map_err(|e| format_err!("{}: {}", path.display(), e))?;
+        let source = features::expand(&source, Some(path), &self.features)?;
+        let forms =
+            parse::parse_toplevel(&source).map_err(|e| format_err!("{}: {}", path.display(), e))?;
+
+        self.eval_forms(forms)
+    }
+}
+
+pub fn run(features: Features) -> Result<(), Error> {
+    let mut out = stdout();
+    let mut repl = Repl {
+        interp: InterpCtx::new(&mut out),
+        features,
+    };
+
+    let mut input = String::new();
+
+    loop {
+        print!("{}", if input.is_empty() { "> " } else { "... " });
+        stdout().flush()?;
+
+        let mut line = String::new();
+        if stdin().read_line(&mut line)? == 0 {
+            println!();
+            return Ok(());
+        }
+
+        input.push_str(&line);
+
+        if is_incomplete(&input) {
+            continue;
+        }
+
+        let forms = features::expand(&input, None, &repl.features)
+            .and_then(|input| parse::parse_toplevel(&input).map_err(|e| format_err!("{}", e)));
+
+        match forms {
+            Ok(forms) => {
+                if let Err(e) = repl.eval_forms(forms) {
+                    if let Some(Exit(status)) = e.downcast_ref() {
+                        std::process::exit(*status);
+                    }
+
+                    println!("Error: {}", e);
+                }
+            }
+            Err(e) => println!("{}", e),
+        }
+
+        input.clear();
+    }
+}
@@ -0,0 +1,385 @@
+//! Shrinking the CPS program before its lambdas are lifted. The transform
+//! wraps continuations in lambdas that only pass their argument on, so
+//! `(lambda (x) (k x))` is replaced by `k`, and a lambda that is called where
+//! it is made, with constants or variables, is replaced by its body, as the
+//! body of every lambda is. Both are repeated until neither applies.
+//!
+//! A variable only takes the place of a lambda or a parameter when it is
+//! bound in the program and never `set!`, so that it has the same value
+//! wherever it is used.
+
+use moniker::{Binder, FreeVar, Ignore, Scope, Var};
+
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::flat_expr::FExpr;
+use crate::literals::Literal;
+use crate::utils::{clone_rc, Scopes};
+
+/// What shrinking did, printed with `--verbose`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// passes over the program, the last finds nothing to do
+    pub rounds: usize,
+    pub eta_reductions: usize,
+    pub beta_reductions: usize,
+    pub nodes_before: usize,
+    pub nodes_after: usize,
+}
+
+/// The program with every scope opened, so that substituting a variable
+/// doesn't have to go through binders.
+#[derive(Clone)]
+pub(crate) enum Node {
+    LamOne(FreeVar<String>, Box<Node>),
+    LamTwo(FreeVar<String>, FreeVar<String>, Box<Node>, Option<usize>),
+    Var(Var<String>),
+    Lit(Literal),
+    BuiltinIdent(String),
+    SetThen(Var<String>, Box<Node>, Box<Node>),
+    CallOne(Box<Node>, Box<Node>),
+    CallTwo(Box<Node>, Box<Node>, Box<Node>),
+    If(Box<Node>, Box<Node>, Box<Node>),
+    IntOp(String, Box<Node>, Box<Node>),
+}
+
+/// Eta and beta reduce `expr` until neither applies.
+pub fn shrink(expr: FExpr) -> (FExpr, Stats) {
+    let mut bound = HashSet::new();
+    let mut node = Node::open(expr, &mut bound);
+
+    let mut set = HashSet::new();
+    node.set_vars(&mut set);
+
+    // reductions never add a `set!`, so this holds for every round
+    let mut ctx = Shrinker {
+        fixed: bound.difference(&set).cloned().collect(),
+        substituted: HashMap::new(),
+        eta_reductions: 0,
+        beta_reductions: 0,
+    };
+
+    let mut stats = Stats {
+        nodes_before: node.size(),
+        ..Stats::default()
+    };
+
+    loop {
+        let reductions = ctx.eta_reductions + ctx.beta_reductions;
+        node = ctx.shrink_toplevel(node);
+        stats.rounds += 1;
+
+        if ctx.eta_reductions + ctx.beta_reductions == reductions {
+            break;
+        }
+    }
+
+    stats.eta_reductions = ctx.eta_reductions;
+    stats.beta_reductions = ctx.beta_reductions;
+    stats.nodes_after = node.size();
+
+    (node.close(), stats)
+}
+
+struct Shrinker {
+    /// variables bound in the program that are never `set!`
+    fixed: HashSet<FreeVar<String>>,
+    /// the parameters of the lambdas beta reduced, with what they were bound
+    /// to, their uses are replaced as the next round reaches them rather than
+    /// by going through the body of each lambda as it is reduced
+    substituted: HashMap<FreeVar<String>, Node>,
+    eta_reductions: usize,
+    beta_reductions: usize,
+}
+
+impl Shrinker {
+    /// The toplevel has no environment to hold the variables of a lambda it
+    /// calls, so its call is left for the inliner, which inlines into lambdas.
+    fn shrink_toplevel(&mut self, node: Node) -> Node {
+        match node {
+            Node::CallOne(f, a) => {
+                Node::CallOne(Box::new(self.shrink(*f)), Box::new(self.shrink(*a)))
+            }
+            Node::CallTwo(f, a, c) => Node::CallTwo(
+                Box::new(self.shrink(*f)),
+                Box::new(self.shrink(*a)),
+                Box::new(self.shrink(*c)),
+            ),
+            node => self.shrink(node),
+        }
+    }
+
+    /// Shrink the parts of `node` and then `node`. Each kind of node is done by
+    /// a method of its own, so that the frame kept for each level of a deeply
+    /// nested program is only as big as that of the method for its node.
+    fn shrink(&mut self, node: Node) -> Node {
+        match node {
+            Node::LamOne(x, body) => self.shrink_lam_one(x, *body),
+            Node::LamTwo(x, k, body, line) => self.shrink_lam_two(x, k, *body, line),
+            Node::CallOne(f, a) => self.shrink_call_one(*f, *a),
+            Node::CallTwo(f, a, c) => self.shrink_call_two(*f, *a, *c),
+            node => self.shrink_parts(node),
+        }
+    }
+
+    fn shrink_lam_one(&mut self, x: FreeVar<String>, body: Node) -> Node {
+        match self.shrink(body) {
+            // (lambda (x) (f x)) => f
+            Node::CallOne(f, a)
+                if self.resolve(&a).is_var(&x)
+                    && !self.resolve(&f).is_var(&x)
+                    && self.is_fixed_atom(&f) =>
+            {
+                self.eta_reductions += 1;
+                *f
+            }
+            body => Node::LamOne(x, Box::new(body)),
+        }
+    }
+
+    fn shrink_lam_two(
+        &mut self,
+        x: FreeVar<String>,
+        k: FreeVar<String>,
+        body: Node,
+        line: Option<usize>,
+    ) -> Node {
+        match self.shrink(body) {
+            // (lambda (x k) (f x k)) => f
+            Node::CallTwo(f, a, c)
+                if self.resolve(&a).is_var(&x)
+                    && self.resolve(&c).is_var(&k)
+                    && !self.resolve(&f).is_var(&x)
+                    && !self.resolve(&f).is_var(&k)
+                    && matches!(self.resolve(&f), Node::Var(Var::Free(v)) if self.fixed.contains(v)) =>
+            {
+                self.eta_reductions += 1;
+                *f
+            }
+            body => Node::LamTwo(x, k, Box::new(body), line),
+        }
+    }
+
+    fn shrink_call_one(&mut self, f: Node, a: Node) -> Node {
+        let a = self.shrink(a);
+
+        match self.shrink(f) {
+            // ((lambda (x) body) a) => body[x := a]
+            Node::LamOne(x, body) if self.fixed.contains(&x) && self.is_copyable(&a) => {
+                self.beta_reductions += 1;
+                self.substitute(x, &a);
+                *body
+            }
+            f => Node::CallOne(Box::new(f), Box::new(a)),
+        }
+    }
+
+    fn shrink_call_two(&mut self, f: Node, a: Node, c: Node) -> Node {
+        let a = self.shrink(a);
+        let c = self.shrink(c);
+
+        match self.shrink(f) {
+            // ((lambda (x k) body) a c) => body[x := a, k := c]
+            Node::LamTwo(x, k, body, _)
+                if self.fixed.contains(&x)
+                    && self.fixed.contains(&k)
+                    && self.is_copyable(&a)
+                    && self.is_copyable(&c) =>
+            {
+                self.beta_reductions += 1;
+                self.substitute(x, &a);
+                self.substitute(k, &c);
+                *body
+            }
+            f => Node::CallTwo(Box::new(f), Box::new(a), Box::new(c)),
+        }
+    }
+
+    /// Shrink the parts of a node that is never reduced itself.
+    fn shrink_parts(&mut self, node: Node) -> Node {
+        match node {
+            Node::SetThen(v, e, c) => {
+                Node::SetThen(v, Box::new(self.shrink(*e)), Box::new(self.shrink(*c)))
+            }
+            Node::If(c, ift, iff) => Node::If(
+                Box::new(self.shrink(*c)),
+                Box::new(self.shrink(*ift)),
+                Box::new(self.shrink(*iff)),
+            ),
+            Node::IntOp(op, a, b) => {
+                Node::IntOp(op, Box::new(self.shrink(*a)), Box::new(self.shrink(*b)))
+            }
+            node @ Node::Var(Var::Free(_)) => self.resolve(&node).clone(),
+            node => node,
+        }
+    }
+
+    /// Replace the uses of `x` with `a`, the binders are all distinct so no
+    /// variable of `a` can be captured.
+    fn substitute(&mut self, x: FreeVar<String>, a: &Node) {
+        let a = self.resolve(a).clone();
+        self.substituted.insert(x, a);
+    }
+
+    /// What `node` stands for once the substitutions made are, it is only
+    /// different for a variable this round hasn't reached since it was
+    /// substituted.
+    fn resolve<'a>(&'a self, mut node: &'a Node) -> &'a Node {
+        while let Node::Var(Var::Free(v)) = node {
+            match self.substituted.get(v) {
+                Some(a) => node = a,
+                None => break,
+            }
+        }
+
+        node
+    }
+
+    /// Whether `node` means the same thing wherever it is evaluated.
+    fn is_fixed_atom(&self, node: &Node) -> bool {
+        match self.resolve(node) {
+            Node::Var(Var::Free(v)) => self.fixed.contains(v),
+            Node::BuiltinIdent(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether `node` can be put in place of each use of a variable bound to
+    /// it, each evaluation of a string literal makes a new string.
+    fn is_copyable(&self, node: &Node) -> bool {
+        match self.resolve(node) {
+            Node::Lit(Literal::String(_)) => false,
+            Node::Lit(_) => true,
+            node => self.is_fixed_atom(node),
+        }
+    }
+}
+
+impl Node {
+    pub(crate) fn open(expr: FExpr, bound: &mut HashSet<FreeVar<String>>) -> Node {
+        Node::open_in(expr, &mut Scopes::default(), bound)
+    }
+
+    fn open_in(expr: FExpr, scopes: &mut Scopes, bound: &mut HashSet<FreeVar<String>>) -> Node {
+        let mut open =
+            |e: Rc<FExpr>, scopes: &mut Scopes| Box::new(Node::open_in(clone_rc(e), scopes, bound));
+
+        match expr {
+            FExpr::LamOne(s) => {
+                let x = scopes.push_fresh(&s.unsafe_pattern.0);
+                let body = open(s.unsafe_body, scopes);
+                scopes.pop();
+                bound.insert(x.clone());
+                Node::LamOne(x, body)
+            }
+            FExpr::LamTwo(s, Ignore(line)) => {
+                let x = scopes.push_fresh(&s.unsafe_pattern.0);
+                let k = scopes.push_fresh(&s.unsafe_body.unsafe_pattern.0);
+                let body = open(s.unsafe_body.unsafe_body, scopes);
+                scopes.pop();
+                scopes.pop();
+                bound.insert(x.clone());
+                bound.insert(k.clone());
+                Node::LamTwo(x, k, body, line)
+            }
+            FExpr::Var(v) => Node::Var(scopes.open(v)),
+            FExpr::Lit(Ignore(l)) => Node::Lit(l),
+            FExpr::BuiltinIdent(Ignore(i)) => Node::BuiltinIdent(i),
+            FExpr::SetThen(v, e, c) => {
+                Node::SetThen(scopes.open(v), open(e, scopes), open(c, scopes))
+            }
+            FExpr::CallOne(f, a) => Node::CallOne(open(f, scopes), open(a, scopes)),
+            FExpr::CallTwo(f, a, c) => {
+                Node::CallTwo(open(f, scopes), open(a, scopes), open(c, scopes))
+            }
+            FExpr::If(c, ift, iff) => {
+                Node::If(open(c, scopes), open(ift, scopes), open(iff, scopes))
+            }
+            FExpr::IntOp(Ignore(op), a, b) => Node::IntOp(op, open(a, scopes), open(b, scopes)),
+        }
+    }
+
+    pub(crate) fn close(self) -> FExpr {
+        self.close_in(&mut Scopes::default())
+    }
+
+    fn close_in(self, scopes: &mut Scopes) -> FExpr {
+        let close = |n: Box<Node>, scopes: &mut Scopes| Rc::new(n.close_in(scopes));
+
+        match self {
+            Node::LamOne(x, body) => {
+                scopes.push(x.clone());
+                let body = close(body, scopes);
+                scopes.pop();
+                // the body is closed already, so goes in the scope as it is
+                FExpr::LamOne(Scope {
+                    unsafe_pattern: Binder(x),
+                    unsafe_body: body,
+                })
+            }
+            Node::LamTwo(x, k, body, line) => {
+                scopes.push(x.clone());
+                scopes.push(k.clone());
+                let body = close(body, scopes);
+                scopes.pop();
+                scopes.pop();
+                FExpr::LamTwo(
+                    Scope {
+                        unsafe_pattern: Binder(x),
+                        unsafe_body: Scope {
+                            unsafe_pattern: Binder(k),
+                            unsafe_body: body,
+                        },
+                    },
+                    Ignore(line),
+                )
+            }
+            Node::Var(v) => FExpr::Var(scopes.close(v)),
+            Node::Lit(l) => FExpr::Lit(Ignore(l)),
+            Node::BuiltinIdent(i) => FExpr::BuiltinIdent(Ignore(i)),
+            Node::SetThen(v, e, c) => {
+                FExpr::SetThen(scopes.close(v), close(e, scopes), close(c, scopes))
+            }
+            Node::CallOne(f, a) => FExpr::CallOne(close(f, scopes), close(a, scopes)),
+            Node::CallTwo(f, a, c) => {
+                FExpr::CallTwo(close(f, scopes), close(a, scopes), close(c, scopes))
+            }
+            Node::If(c, ift, iff) => {
+                FExpr::If(close(c, scopes), close(ift, scopes), close(iff, scopes))
+            }
+            Node::IntOp(op, a, b) => FExpr::IntOp(Ignore(op), close(a, scopes), close(b, scopes)),
+        }
+    }
+
+    pub(crate) fn is_var(&self, x: &FreeVar<String>) -> bool {
+        matches!(self, Node::Var(Var::Free(v)) if v == x)
+    }
+
+    pub(crate) fn children(&self) -> Vec<&Node> {
+        match self {
+            Node::LamOne(_, b) | Node::LamTwo(_, _, b, _) => vec![b],
+            Node::Var(_) | Node::Lit(_) | Node::BuiltinIdent(_) => vec![],
+            Node::SetThen(_, e, c) | Node::CallOne(e, c) | Node::IntOp(_, e, c) => vec![e, c],
+            Node::CallTwo(a, b, c) | Node::If(a, b, c) => vec![a, b, c],
+        }
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        1 + self.children().iter().map(|c| c.size()).sum::<usize>()
+    }
+
+    pub(crate) fn uses(&self, x: &FreeVar<String>) -> bool {
+        self.is_var(x) || self.children().iter().any(|c| c.uses(x))
+    }
+
+    fn set_vars(&self, out: &mut HashSet<FreeVar<String>>) {
+        if let Node::SetThen(Var::Free(v), _, _) = self {
+            out.insert(v.clone());
+        }
+
+        for child in self.children() {
+            child.set_vars(out);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! The machine the C of a program is built for, with `--target-triple`. Only
+//! the word size changes what is generated, as a fixnum is a word with its low
+//! bit set, the layout of the objects and environments is left to the C
+//! compiler. The generated C checks that it is built for the word size and
+//! byte order it was generated for, so that a `--cc` for some other machine
+//! fails to build it rather than making a broken program.
+
+use failure::{format_err, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetConfig {
+    /// The triple given, `None` when building for the machine compiling
+    pub triple: Option<String>,
+    pub word_bits: u32,
+    pub endian: Endian,
+}
+
+impl Default for TargetConfig {
+    fn default() -> Self {
+        Self::host()
+    }
+}
+
+impl TargetConfig {
+    /// The machine the compiler is running on.
+    pub fn host() -> Self {
+        Self {
+            triple: None,
+            word_bits: usize::BITS,
+            endian: if cfg!(target_endian = "big") {
+                Endian::Big
+            } else {
+                Endian::Little
+            },
+        }
+    }
+
+    /// The word size and byte order of a triple like `armv7-unknown-linux-gnueabihf`,
+    /// from its architecture.
+    pub fn from_triple(triple: &str) -> Result<Self, Error> {
+        let arch = triple.split('-').next().unwrap_or_default();
+
+        let (word_bits, endian) = match arch {
+            "x86_64" | "aarch64" | "arm64" | "riscv64" | "riscv64gc" | "powerpc64le"
+            | "mips64el" | "loongarch64" => (64, Endian::Little),
+            "aarch64_be" | "powerpc64" | "mips64" | "s390x" | "sparc64" => (64, Endian::Big),
+            "i386" | "i486" | "i586" | "i686" | "riscv32" | "riscv32imac" | "mipsel" | "wasm32" => {
+                (32, Endian::Little)
+            }
+            "powerpc" | "mips" | "sparc" | "m68k" => (32, Endian::Big),
+            a if a.starts_with("armeb") || a.starts_with("thumbeb") => (32, Endian::Big),
+            a if a.starts_with("arm") || a.starts_with("thumb") => (32, Endian::Little),
+            a if a.starts_with("avr") || a.starts_with("msp430") => {
+                return Err(format_err!("{} has 16 bit words, which are too small", a))
+            }
+            _ => {
+                return Err(format_err!(
+                    "unknown architecture in target triple {}",
+                    triple
+                ))
+            }
+        };
+
+        Ok(Self {
+            triple: Some(triple.to_owned()),
+            word_bits,
+            endian,
+        })
+    }
+
+    /// Can the integer be represented as an immediate in the runtime, a word
+    /// with one bit taken for the tag.
+    pub fn fixnum_fits(&self, i: i64) -> bool {
+        let shift = 65 - self.word_bits.min(64);
+        (i64::MIN >> shift..=i64::MAX >> shift).contains(&i)
+    }
+
+    /// The C compiler to build with when none is given, the cross compiler
+    /// named after the triple.
+    pub fn default_cc(&self) -> Option<String> {
+        self.triple.as_ref().map(|t| format!("{}-gcc", t))
+    }
+
+    /// The archiver to build a staticlib with when cross compiling.
+    pub fn default_ar(&self) -> Option<String> {
+        self.triple.as_ref().map(|t| format!("{}-ar", t))
+    }
+
+    /// Checks that the C compiler targets a machine with this word size and
+    /// byte order.
+    pub fn c_checks(&self) -> String {
+        let order = match self.endian {
+            Endian::Little => "__ORDER_LITTLE_ENDIAN__",
+            Endian::Big => "__ORDER_BIG_ENDIAN__",
+        };
+        let name = self.triple.as_deref().unwrap_or("the host");
+
+        format!(
+            "_Static_assert(sizeof(void *) * 8 == {bits}, \"generated for {name}, with {bits} bit words\");\n\
+             _Static_assert(__BYTE_ORDER__ == {order}, \"generated for {name}, which is {endian} endian\");\n",
+            bits = self.word_bits,
+            name = name,
+            order = order,
+            endian = match self.endian {
+                Endian::Little => "little",
+                Endian::Big => "big",
+            },
+        )
+    }
+}
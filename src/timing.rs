@@ -0,0 +1,112 @@
+//! How long each pass of a compilation took and how many nodes the program had
+//! after it, for `--time-passes`. A pass that is run for each of several inputs
+//! is reported once, with the times and sizes of its runs added up.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pass {
+    pub name: &'static str,
+    pub time: Duration,
+    /// The size of the program after the pass, for the passes that make one
+    pub nodes: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PassTimes {
+    passes: Vec<Pass>,
+}
+
+impl PassTimes {
+    fn pass(&mut self, name: &'static str) -> &mut Pass {
+        match self.passes.iter().position(|p| p.name == name) {
+            Some(i) => &mut self.passes[i],
+            None => {
+                self.passes.push(Pass {
+                    name,
+                    time: Duration::default(),
+                    nodes: None,
+                });
+                self.passes.last_mut().unwrap()
+            }
+        }
+    }
+
+    pub fn add_time(&mut self, name: &'static str, time: Duration) {
+        self.pass(name).time += time;
+    }
+
+    pub fn add_nodes(&mut self, name: &'static str, nodes: usize) {
+        let pass = self.pass(name);
+        pass.nodes = Some(pass.nodes.unwrap_or_default() + nodes);
+    }
+
+    /// The passes in the order they were first run.
+    pub fn passes(&self) -> &[Pass] {
+        &self.passes
+    }
+
+    /// A table of the passes with their share of the total time, and how many
+    /// nodes each added to or removed from the program.
+    pub fn report(&self) -> String {
+        let total = self.passes.iter().map(|p| p.time).sum::<Duration>();
+        let width = self
+            .passes
+            .iter()
+            .map(|p| p.name.len())
+            .chain(Some("total".len()))
+            .max()
+            .unwrap_or_default();
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:<width$} {:>11} {:>6} {:>9} {:>8}",
+            "pass",
+            "time",
+            "%",
+            "nodes",
+            "change",
+            width = width
+        );
+
+        let mut last_nodes = None;
+
+        for p in &self.passes {
+            let share = if total.is_zero() {
+                0.0
+            } else {
+                100.0 * p.time.as_secs_f64() / total.as_secs_f64()
+            };
+
+            let (nodes, change) = match (p.nodes, last_nodes) {
+                (Some(n), Some(last)) => (n.to_string(), format!("{:+}", n as i64 - last as i64)),
+                (Some(n), None) => (n.to_string(), String::new()),
+                (None, _) => (String::new(), String::new()),
+            };
+            last_nodes = p.nodes.or(last_nodes);
+
+            let row = format!(
+                "{:<width$} {:>9.3}ms {:>6.1} {:>9} {:>8}",
+                p.name,
+                p.time.as_secs_f64() * 1000.0,
+                share,
+                nodes,
+                change,
+                width = width
+            );
+            let _ = writeln!(out, "{}", row.trim_end());
+        }
+
+        let _ = writeln!(
+            out,
+            "{:<width$} {:>9.3}ms",
+            "total",
+            total.as_secs_f64() * 1000.0,
+            width = width
+        );
+
+        out
+    }
+}
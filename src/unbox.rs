@@ -0,0 +1,309 @@
+//! Compiling arithmetic on integers to C arithmetic. Builtins are curried, so
+//! `(+ a b)` in the CPS program calls `+` with `a`, then calls the closure that
+//! gives back with `b`, before the sum gets to its continuation. A flow
+//! analysis finds the variables that only ever hold exact integers, such as
+//! loop counters started from a literal and stepped with `+` or `-`, and such
+//! a call on two of them, or on integer literals, is replaced with an `IntOp`
+//! that the C backend computes from the `int64_t`s of its arguments. Only the
+//! result is boxed, as a fixnum unless it needs more bits than one has.
+//!
+//! The analysis follows values through the calls of the program's lambdas. A
+//! lambda that escapes, to a builtin, a global or a call of something it
+//! can't follow, could be called with anything.
+
+use moniker::{FreeVar, Var};
+
+use std::collections::{HashMap, HashSet};
+
+use crate::flat_expr::FExpr;
+use crate::literals::Literal;
+use crate::shrink::Node;
+
+/// The builtins computed in place, those giving an integer and the
+/// comparisons.
+const ARITHMETIC: &[&str] = &["+", "-", "*"];
+const COMPARISONS: &[&str] = &["<", "<=", ">", ">=", "="];
+
+/// What unboxing did, printed with `--verbose`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// variables found to only hold integers
+    pub integer_vars: usize,
+    /// builtin calls replaced with C arithmetic
+    pub ops: usize,
+}
+
+/// Replace the calls of arithmetic builtins on integers with `IntOp`s.
+pub fn unbox_integers(expr: FExpr) -> (FExpr, Stats) {
+    let mut bound = HashSet::new();
+    let node = Node::open(expr, &mut bound);
+
+    let mut flows = Flows::default();
+    flows.collect(&node);
+    flows.solve();
+
+    let mut stats = Stats {
+        integer_vars: flows
+            .values
+            .keys()
+            .filter(|v| flows.holds_integers(v))
+            .count(),
+        ..Stats::default()
+    };
+    let node = flows.rewrite(node, &mut stats);
+
+    (node.close(), stats)
+}
+
+fn is_int_op(op: &str) -> bool {
+    ARITHMETIC.contains(&op) || COMPARISONS.contains(&op)
+}
+
+/// What the analysis knows a value might be.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Value {
+    Int,
+    /// something that fails when called
+    Datum,
+    /// a closure of the lambda with this as its first parameter
+    Lambda(FreeVar<String>),
+    Builtin(String),
+    /// an arithmetic builtin given its first argument, and whether that was
+    /// an integer
+    Partial(String, bool),
+    Unknown,
+}
+
+/// An argument, the values of a variable are only known once solved.
+#[derive(Debug, Clone)]
+enum Arg {
+    Var(FreeVar<String>),
+    Value(Value),
+}
+
+#[derive(Default)]
+struct Flows {
+    /// the parameters of each lambda, by its first
+    lambdas: HashMap<FreeVar<String>, Vec<FreeVar<String>>>,
+    /// what each parameter might hold
+    values: HashMap<FreeVar<String>, HashSet<Value>>,
+    assigns: Vec<(FreeVar<String>, Arg)>,
+    calls: Vec<(Arg, Vec<Arg>)>,
+}
+
+impl Flows {
+    fn collect(&mut self, node: &Node) {
+        match node {
+            Node::LamOne(x, _) => self.lambda(vec![x.clone()]),
+            Node::LamTwo(x, k, _, _) => self.lambda(vec![x.clone(), k.clone()]),
+            Node::SetThen(Var::Free(v), e, _) => self.assigns.push((v.clone(), arg(e))),
+            Node::CallOne(f, a) => self.calls.push((arg(f), vec![arg(a)])),
+            Node::CallTwo(f, a, k) => self.calls.push((arg(f), vec![arg(a), arg(k)])),
+            _ => {}
+        }
+
+        for child in node.children() {
+            self.collect(child);
+        }
+    }
+
+    fn lambda(&mut self, params: Vec<FreeVar<String>>) {
+        for p in &params {
+            self.values.insert(p.clone(), HashSet::new());
+        }
+        self.lambdas.insert(params[0].clone(), params);
+    }
+
+    /// Follow the values through the assignments and calls until nothing new
+    /// reaches any parameter.
+    fn solve(&mut self) {
+        loop {
+            let mut changed = false;
+
+            for (v, a) in self.assigns.clone() {
+                if self.values.contains_key(&v) {
+                    changed |= self.add(&v, self.of(&a));
+                } else {
+                    changed |= self.escape(&a);
+                }
+            }
+
+            for (f, args) in self.calls.clone() {
+                for callee in self.of(&f) {
+                    changed |= self.call(callee, &args);
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    fn call(&mut self, callee: Value, args: &[Arg]) -> bool {
+        match (callee, args) {
+            (Value::Lambda(l), args) => {
+                let params = self.lambdas[&l].clone();
+                // a call with the wrong number of arguments fails
+                if params.len() != args.len() {
+                    return false;
+                }
+
+                let mut changed = false;
+                for (p, a) in params.iter().zip(args) {
+                    changed |= self.add(p, self.of(a));
+                }
+                changed
+            }
+            (Value::Int, _) | (Value::Datum, _) => false,
+            (Value::Builtin(op), [a, k]) if is_int_op(&op) => {
+                let partial = Value::Partial(op, self.is_int(a));
+                self.give(k, partial)
+            }
+            (Value::Partial(op, int), [b, k]) => {
+                let result = if int && self.is_int(b) && ARITHMETIC.contains(&op.as_str()) {
+                    Value::Int
+                } else {
+                    Value::Datum
+                };
+                self.give(k, result)
+            }
+            _ => {
+                let mut changed = false;
+                for a in args {
+                    changed |= self.escape(a);
+                }
+                changed
+            }
+        }
+    }
+
+    /// Pass a builtin's result to its continuation.
+    fn give(&mut self, k: &Arg, result: Value) -> bool {
+        let mut changed = false;
+
+        for cont in self.of(k) {
+            if let Value::Lambda(l) = cont {
+                if let [p] = &self.lambdas[&l].clone()[..] {
+                    changed |= self.add(p, iter_one(result.clone()));
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// The lambdas `a` might be can be called with anything.
+    fn escape(&mut self, a: &Arg) -> bool {
+        let mut changed = false;
+
+        for value in self.of(a) {
+            if let Value::Lambda(l) = value {
+                for p in self.lambdas[&l].clone() {
+                    changed |= self.add(&p, iter_one(Value::Unknown));
+                }
+            }
+        }
+
+        changed
+    }
+
+    fn add(&mut self, p: &FreeVar<String>, values: HashSet<Value>) -> bool {
+        let held = self.values.get_mut(p).unwrap();
+        let before = held.len();
+        held.extend(values);
+        held.len() != before
+    }
+
+    /// What `a` might be, anything for a variable that isn't a parameter.
+    fn of(&self, a: &Arg) -> HashSet<Value> {
+        match a {
+            Arg::Var(v) => match self.values.get(v) {
+                Some(values) => values.clone(),
+                None => iter_one(Value::Unknown),
+            },
+            Arg::Value(value) => iter_one(value.clone()),
+        }
+    }
+
+    fn is_int(&self, a: &Arg) -> bool {
+        self.of(a).iter().all(|v| *v == Value::Int)
+    }
+
+    fn holds_integers(&self, v: &FreeVar<String>) -> bool {
+        let values = &self.values[v];
+        !values.is_empty() && values.iter().all(|v| *v == Value::Int)
+    }
+
+    /// `(op a (lambda (f) (f b k)))` => `(k (int-op a b))`, for integers `a`
+    /// and `b`.
+    fn rewrite(&self, node: Node, stats: &mut Stats) -> Node {
+        let node = match node {
+            Node::CallTwo(f, a, k) => match (*f, *k) {
+                (Node::BuiltinIdent(op), Node::LamOne(g, body))
+                    if is_int_op(&op) && self.is_int_atom(&a) =>
+                {
+                    match *body {
+                        Node::CallTwo(h, b, k)
+                            if h.is_var(&g)
+                                && self.is_int_atom(&b)
+                                && !b.uses(&g)
+                                && !k.uses(&g) =>
+                        {
+                            stats.ops += 1;
+                            Node::CallOne(k, Box::new(Node::IntOp(op, a, b)))
+                        }
+                        body => Node::CallTwo(
+                            Box::new(Node::BuiltinIdent(op)),
+                            a,
+                            Box::new(Node::LamOne(g, Box::new(body))),
+                        ),
+                    }
+                }
+                (f, k) => Node::CallTwo(Box::new(f), a, Box::new(k)),
+            },
+            node => node,
+        };
+
+        let mut rewrite = |n: Box<Node>| Box::new(self.rewrite(*n, stats));
+
+        match node {
+            Node::LamOne(x, body) => Node::LamOne(x, rewrite(body)),
+            Node::LamTwo(x, k, body, line) => Node::LamTwo(x, k, rewrite(body), line),
+            Node::SetThen(v, e, c) => Node::SetThen(v, rewrite(e), rewrite(c)),
+            Node::CallOne(f, a) => Node::CallOne(rewrite(f), rewrite(a)),
+            Node::CallTwo(f, a, k) => Node::CallTwo(rewrite(f), rewrite(a), rewrite(k)),
+            Node::If(c, ift, iff) => Node::If(rewrite(c), rewrite(ift), rewrite(iff)),
+            node @ Node::Var(_)
+            | node @ Node::Lit(_)
+            | node @ Node::BuiltinIdent(_)
+            | node @ Node::IntOp(..) => node,
+        }
+    }
+
+    fn is_int_atom(&self, node: &Node) -> bool {
+        match node {
+            Node::Lit(Literal::Int(_)) => true,
+            Node::Var(Var::Free(v)) => self.values.contains_key(v) && self.holds_integers(v),
+            _ => false,
+        }
+    }
+}
+
+/// The argument a node is, arguments are atoms after the CPS transform.
+fn arg(node: &Node) -> Arg {
+    let value = match node {
+        Node::Var(Var::Free(v)) => return Arg::Var(v.clone()),
+        Node::Lit(Literal::Int(_)) => Value::Int,
+        Node::Lit(_) => Value::Datum,
+        Node::BuiltinIdent(b) => Value::Builtin(b.clone()),
+        Node::LamOne(x, _) | Node::LamTwo(x, _, _, _) => Value::Lambda(x.clone()),
+        _ => Value::Unknown,
+    };
+
+    Arg::Value(value)
+}
+
+fn iter_one(value: Value) -> HashSet<Value> {
+    std::iter::once(value).collect()
+}
@@ -1,5 +1,141 @@
+use moniker::{Binder, BinderIndex, BoundVar, FreeVar, Scope, ScopeOffset, Var};
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hint;
+use std::panic;
 use std::rc::Rc;
+use std::thread;
+
+/// The stack the compiler runs on. Its passes, and moniker's, recurse on the
+/// program, so a long body or a deep chain of calls needs much more than the
+/// main thread has.
+pub const STACK_SIZE: usize = 256 << 20;
+
+/// How much of the stack is kept for what runs after `stack_exhausted` first
+/// says it is, like unwinding with an error.
+const STACK_RED_ZONE: usize = 4 << 20;
+
+/// How much a thread not started by `with_stack` can use after its first
+/// check, since its size isn't known.
+const UNKNOWN_STACK_BUDGET: usize = 1 << 20;
+
+thread_local! {
+    /// The address below which the stack of this thread is exhausted, 0 until
+    /// it is known.
+    static STACK_END: Cell<usize> = const { Cell::new(0) };
+}
+
+fn stack_address() -> usize {
+    let here = 0u8;
+    hint::black_box(&here) as *const u8 as usize
+}
+
+/// Run `f` on a thread with a stack of `STACK_SIZE`.
+pub fn with_stack<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    thread::scope(|scope| {
+        thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, || {
+                STACK_END.with(|end| end.set(stack_address() - STACK_SIZE + STACK_RED_ZONE));
+                f()
+            })
+            .expect("couldn't start the compiler's thread")
+            .join()
+            .unwrap_or_else(|e| panic::resume_unwind(e))
+    })
+}
+
+/// Whether recursing any further would overflow the stack, for the code that
+/// recurses on what the program does rather than on its source.
+pub fn stack_exhausted() -> bool {
+    let here = stack_address();
+
+    STACK_END.with(|end| {
+        if end.get() == 0 {
+            end.set(here.saturating_sub(UNKNOWN_STACK_BUDGET));
+        }
+
+        here < end.get()
+    })
+}
 
 pub fn clone_rc<T: Clone>(r: Rc<T>) -> T {
     Rc::try_unwrap(r).unwrap_or_else(|t| t.as_ref().clone())
 }
+
+/// The binders of the scopes around the part of a program being opened or
+/// closed. A pass that opens every scope of the program once on the way in
+/// and closes them once on the way out is linear in its size, where
+/// `Scope::unbind` and `Scope::new` go through the whole body of each scope
+/// they open or close, so take time quadratic in how deeply scopes nest, as
+/// they do in a long body. Every scope binds one variable.
+#[derive(Debug, Default)]
+pub(crate) struct Scopes {
+    binders: Vec<(FreeVar<String>, Option<usize>)>,
+    /// how many scopes are around the scope binding each variable
+    depths: HashMap<FreeVar<String>, usize>,
+}
+
+impl Scopes {
+    /// Enter a scope binding `x`.
+    pub(crate) fn push(&mut self, x: FreeVar<String>) {
+        let outer = self.depths.insert(x.clone(), self.binders.len());
+        self.binders.push((x, outer));
+    }
+
+    /// Enter a scope that is being opened, binding a fresh variable named
+    /// like `x` as `Scope::unbind` would, which is returned.
+    pub(crate) fn push_fresh(&mut self, x: &FreeVar<String>) -> FreeVar<String> {
+        let fresh = FreeVar::fresh(x.pretty_name.clone());
+        self.push(fresh.clone());
+        fresh
+    }
+
+    pub(crate) fn pop(&mut self) {
+        let (x, outer) = self
+            .binders
+            .pop()
+            .expect("popped a scope that wasn't pushed");
+        match outer {
+            Some(depth) => self.depths.insert(x, depth),
+            None => self.depths.remove(&x),
+        };
+    }
+
+    /// The variable `v` refers to with the scopes around it opened.
+    pub(crate) fn open(&self, v: Var<String>) -> Var<String> {
+        match v {
+            Var::Bound(b) if (b.scope.0 as usize) < self.binders.len() => Var::Free(
+                self.binders[self.binders.len() - 1 - b.scope.0 as usize]
+                    .0
+                    .clone(),
+            ),
+            v => v,
+        }
+    }
+
+    /// `v` bound to the scope binding it, if that is one of those around it.
+    pub(crate) fn close(&self, v: Var<String>) -> Var<String> {
+        match v {
+            Var::Free(x) => match self.depths.get(&x) {
+                Some(depth) => Var::Bound(BoundVar {
+                    scope: ScopeOffset((self.binders.len() - 1 - depth) as u32),
+                    binder: BinderIndex(0),
+                    pretty_name: x.pretty_name,
+                }),
+                None => Var::Free(x),
+            },
+            v => v,
+        }
+    }
+}
+
+/// A scope binding `x` left open, with `x` free in `body`, for a pass that
+/// closes all of the scopes it makes at once.
+pub(crate) fn open_scope<T>(x: FreeVar<String>, body: T) -> Scope<Binder<String>, T> {
+    Scope {
+        unsafe_pattern: Binder(x),
+        unsafe_body: body,
+    }
+}
@@ -0,0 +1,369 @@
+//! Runs programs compiled by `bytecode`. Builtins are applied by the
+//! interpreter so that both give the same results, and the builtins that call
+//! back into the program do so by running another loop until the callback
+//! returns to them. `dynamic-wind` is the exception, it runs in the loop so
+//! that its thunks can yield.
+
+use failure::{format_err, Error};
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::io::Write;
+use std::mem;
+use std::rc::Rc;
+
+use crate::bytecode::{Constant, Lambda, Op, Program, Reader};
+use crate::interp::{direct_clause, Dynamic, InterpCtx, Value};
+
+/// A closure is also the environment of its lambda, the closure of the
+/// lambda that made it is the parent of a linked environment.
+pub struct Closure {
+    program: Rc<Loaded>,
+    lambda: usize,
+    slots: Vec<RefCell<Slot>>,
+}
+
+/// A program with its constants made into values.
+struct Loaded {
+    lambdas: Vec<Lambda>,
+    constants: Vec<Value>,
+}
+
+#[derive(Clone)]
+enum Slot {
+    Empty,
+    Cell(Rc<RefCell<Value>>),
+    Parent(Rc<Closure>),
+}
+
+impl Closure {
+    fn slot(&self, slot: usize) -> Result<&RefCell<Slot>, Error> {
+        self.slots
+            .get(slot)
+            .ok_or_else(|| format_err!("no slot {} in the environment", slot))
+    }
+
+    /// The environment `depth` parents up.
+    fn ancestor(self: &Rc<Self>, depth: usize) -> Result<Rc<Closure>, Error> {
+        let mut env = self.clone();
+
+        for _ in 0..depth {
+            let parent = match &*env.slot(0)?.borrow() {
+                Slot::Parent(p) => p.clone(),
+                _ => return Err(format_err!("the environment isn't linked")),
+            };
+            env = parent;
+        }
+
+        Ok(env)
+    }
+
+    fn cell(self: &Rc<Self>, depth: usize, slot: usize) -> Result<Rc<RefCell<Value>>, Error> {
+        match &*self.ancestor(depth)?.slot(slot)?.borrow() {
+            Slot::Cell(c) => Ok(c.clone()),
+            _ => Err(format_err!("slot {} holds no variable", slot)),
+        }
+    }
+}
+
+/// The next call to make, with the continuation when there are two arguments.
+type Call = (Value, Value, Option<Value>);
+
+/// A thread waiting to run, with the parameterization and winds it had.
+type Thread = (Call, Dynamic);
+
+fn halt() -> Value {
+    Value::Builtin("halt".into(), Vec::new())
+}
+
+fn constant_value(c: &Constant) -> Value {
+    match c {
+        Constant::Void => Value::Void,
+        Constant::Int(i) => Value::Int(*i),
+        Constant::Rational(n, d) => Value::Rational(*n, *d),
+        Constant::Float(f) => Value::Float(*f),
+        Constant::Str(s) => Value::Str(s.as_str().into()),
+        Constant::Symbol(s) => Value::Symbol(s.as_str().into()),
+        Constant::Bool(b) => Value::Bool(*b),
+        Constant::Builtin(name) => Value::Builtin(name.as_str().into(), Vec::new()),
+        Constant::List(items) => items.iter().rev().fold(Value::Void, |tail, item| {
+            Value::Cons(Rc::new(constant_value(item)), Rc::new(tail))
+        }),
+    }
+}
+
+/// Run a program, writing what it displays to `out`, with the name and
+/// arguments `command-line` gives.
+pub fn run(program: Program, command_line: Vec<String>, out: &mut dyn Write) -> Result<(), Error> {
+    let mut ctx = InterpCtx::new(out);
+    ctx.set_command_line(command_line);
+
+    let constants = program.constants.iter().map(constant_value).collect();
+
+    let main = Closure {
+        program: Rc::new(Loaded {
+            lambdas: program.lambdas,
+            constants,
+        }),
+        lambda: 0,
+        slots: Vec::new(),
+    };
+
+    run_calls(
+        &mut ctx,
+        (Value::Compiled(Rc::new(main)), Value::Void, None),
+        Some(&mut VecDeque::new()),
+    )?;
+
+    Ok(())
+}
+
+/// Call a closure from a builtin, giving it a continuation that returns to
+/// the builtin.
+pub(crate) fn apply(ctx: &mut InterpCtx, c: Rc<Closure>, a: Value) -> Result<Value, Error> {
+    run_calls(ctx, (Value::Compiled(c), a, Some(halt())), None)
+}
+
+/// Make calls until one is to `halt`, returning what it was given.
+///
+/// The loop running the program's toplevel is given the threads waiting to
+/// run, and switches to the next of them on `yield` and on a `halt` while
+/// there are any left. The loops of builtins calling back into the program
+/// have no threads to switch to, as the continuations of the others end in
+/// the loop of the toplevel, so `spawn` and `yield` fail there.
+///
+/// A raise leaving the loop runs the after thunks of the `dynamic-wind`s
+/// entered in it on its way out, as the interpreter's `dynamic-wind` does.
+fn run_calls(
+    ctx: &mut InterpCtx,
+    call: Call,
+    threads: Option<&mut VecDeque<Thread>>,
+) -> Result<Value, Error> {
+    let base = ctx.dynamic.winds.len();
+    let result = make_calls(ctx, call, threads);
+
+    if result.is_err() {
+        if let Some(raised) = ctx.raised.take() {
+            while ctx.dynamic.winds.len() > base {
+                let after = ctx.dynamic.winds.pop().unwrap();
+                ctx.apply(after, Value::Void)?;
+            }
+
+            ctx.raised = Some(raised);
+        }
+    }
+
+    result
+}
+
+fn make_calls(
+    ctx: &mut InterpCtx,
+    mut call: Call,
+    mut threads: Option<&mut VecDeque<Thread>>,
+) -> Result<Value, Error> {
+    let mut stack = Vec::new();
+
+    loop {
+        call = match call {
+            (Value::Compiled(c), a, k) => execute(&c, a, k, &mut stack)?,
+            (Value::Builtin(name, _), a, _) if &*name == "halt" => {
+                match threads.as_mut().and_then(|t| t.pop_front()) {
+                    Some((next, dynamic)) => {
+                        ctx.dynamic = dynamic;
+                        next
+                    }
+                    None => return Ok(a),
+                }
+            }
+            (Value::Builtin(name, _), a, Some(k)) if &*name == "spawn" || &*name == "yield" => {
+                let threads = threads.as_mut().ok_or_else(|| {
+                    format_err!(
+                        "{} was called from a procedure given to a builtin, \
+                         where the vm can't switch threads",
+                        name
+                    )
+                })?;
+
+                if &*name == "yield" {
+                    let dynamic = mem::take(&mut ctx.dynamic);
+                    threads.push_back(((k, Value::Void, None), dynamic));
+
+                    let (next, dynamic) = threads.pop_front().unwrap();
+                    ctx.dynamic = dynamic;
+                    next
+                } else if matches!(
+                    a,
+                    Value::Compiled(_)
+                        | Value::Closure(_)
+                        | Value::Builtin(..)
+                        | Value::Parameter(_)
+                        | Value::CaseLambda(_)
+                ) {
+                    // a spawned thread finishes with a halt like the toplevel, and
+                    // starts without the parameterization of the one spawning it
+                    threads.push_back(((a, Value::Void, Some(halt())), Dynamic::default()));
+                    (k, Value::Void, None)
+                } else {
+                    return Err(format_err!("Argument to spawn was not a procedure: {}", a));
+                }
+            }
+            // the thunks are called with continuations that go through the
+            // steps below, the after thunk is kept while inside for a raise
+            (Value::Builtin(name, args), after, Some(k))
+                if &*name == "dynamic-wind" && args.len() == 2 =>
+            {
+                let [before, thunk] = <[Value; 2]>::try_from(args).ok().unwrap();
+                let enter = Value::Builtin("wind-enter".into(), vec![thunk, after, k]);
+                (before, Value::Void, Some(enter))
+            }
+            (Value::Builtin(name, args), _, None) if &*name == "wind-enter" => {
+                let [thunk, after, k] = <[Value; 3]>::try_from(args).ok().unwrap();
+                ctx.dynamic.winds.push(after.clone());
+                let exit = Value::Builtin("wind-exit".into(), vec![after, k]);
+                (thunk, Value::Void, Some(exit))
+            }
+            (Value::Builtin(name, args), v, None) if &*name == "wind-exit" => {
+                let [after, k] = <[Value; 2]>::try_from(args).ok().unwrap();
+                ctx.dynamic.winds.pop();
+                let done = Value::Builtin("wind-done".into(), vec![v, k]);
+                (after, Value::Void, Some(done))
+            }
+            (Value::Builtin(name, args), _, None) if &*name == "wind-done" => {
+                let [v, k] = <[Value; 2]>::try_from(args).ok().unwrap();
+                (k, v, None)
+            }
+            (Value::Builtin(name, mut args), a, Some(k)) => {
+                args.push(a);
+                (k, ctx.apply_builtin(name, args)?, None)
+            }
+            (Value::Builtin(name, _), _, None) => {
+                return Err(format_err!("{} was called without a continuation", name))
+            }
+            (Value::CaseLambda(clauses), a, k) => (direct_clause(&clauses)?, a, k),
+            // closures made by eval, and parameters, which the interpreter runs
+            (f @ (Value::Closure(_) | Value::Parameter(_)), a, Some(k)) => {
+                (k, ctx.apply(f, a)?, None)
+            }
+            (v, _, _) => {
+                return Err(format_err!(
+                    "Called object was not a closure but was: {}",
+                    v
+                ))
+            }
+        };
+    }
+}
+
+/// Run the body of a closure's lambda up to the call it ends in, the stack
+/// is empty before and after.
+fn execute(
+    c: &Rc<Closure>,
+    a: Value,
+    k: Option<Value>,
+    stack: &mut Vec<Value>,
+) -> Result<Call, Error> {
+    let lambda = &c.program.lambdas[c.lambda];
+
+    let args = match (lambda.params, k) {
+        (1, None) => [a, Value::Void],
+        (2, Some(k)) => [a, k],
+        (n, k) => {
+            return Err(format_err!(
+                "{} takes {} arguments but was given {}",
+                lambda.name,
+                n,
+                1 + k.is_some() as u8
+            ))
+        }
+    };
+
+    let mut r = Reader {
+        bytes: &lambda.code,
+        pos: 0,
+    };
+
+    loop {
+        match Op::try_from(r.byte()?)? {
+            Op::Const => {
+                let v = c
+                    .program
+                    .constants
+                    .get(r.uleb()?)
+                    .ok_or_else(|| format_err!("no such constant"))?;
+
+                stack.push(match v {
+                    // each evaluation of a string literal makes a new string
+                    Value::Str(s) => Value::Str(s.as_ref().into()),
+                    v => v.clone(),
+                });
+            }
+            Op::Param => {
+                let arg = args
+                    .get(r.uleb()?)
+                    .ok_or_else(|| format_err!("no such argument"))?;
+                let cell = Rc::new(RefCell::new(arg.clone()));
+                *c.slot(r.uleb()?)?.borrow_mut() = Slot::Cell(cell);
+            }
+            Op::Load => {
+                let (depth, slot) = (r.uleb()?, r.uleb()?);
+                stack.push(c.cell(depth, slot)?.borrow().clone());
+            }
+            Op::Store => {
+                let (depth, slot) = (r.uleb()?, r.uleb()?);
+                *c.cell(depth, slot)?.borrow_mut() = pop(stack)?;
+            }
+            Op::Closure => {
+                let id = r.uleb()?;
+                let lambda = c
+                    .program
+                    .lambdas
+                    .get(id)
+                    .ok_or_else(|| format_err!("no such lambda"))?;
+
+                stack.push(Value::Compiled(Rc::new(Closure {
+                    program: c.program.clone(),
+                    lambda: id,
+                    slots: vec![RefCell::new(Slot::Empty); lambda.slots],
+                })));
+            }
+            Op::Capture => {
+                let (slot, depth, src) = (r.uleb()?, r.uleb()?, r.uleb()?);
+                let cell = c.cell(depth, src)?;
+                *top_closure(stack)?.slot(slot)?.borrow_mut() = Slot::Cell(cell);
+            }
+            Op::Link => {
+                *top_closure(stack)?.slot(0)?.borrow_mut() = Slot::Parent(c.clone());
+            }
+            Op::JumpUnless => {
+                let target = r.u32()?;
+
+                if !pop(stack)?.is_truthy() {
+                    r.pos = target;
+                }
+            }
+            Op::CallOne => {
+                let a = pop(stack)?;
+                return Ok((pop(stack)?, a, None));
+            }
+            Op::CallTwo => {
+                let k = pop(stack)?;
+                let a = pop(stack)?;
+                return Ok((pop(stack)?, a, Some(k)));
+            }
+        }
+    }
+}
+
+fn pop(stack: &mut Vec<Value>) -> Result<Value, Error> {
+    stack
+        .pop()
+        .ok_or_else(|| format_err!("popped from an empty stack"))
+}
+
+fn top_closure(stack: &[Value]) -> Result<&Closure, Error> {
+    match stack.last() {
+        Some(Value::Compiled(c)) => Ok(c),
+        _ => Err(format_err!("no closure on the stack")),
+    }
+}
@@ -0,0 +1,579 @@
+use failure::{format_err, Error};
+use moniker::{FreeVar, Ignore};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use crate::foreign::Foreign;
+use crate::interp::unescape;
+use crate::lifted_expr::{EnvRepr, LExpr, LambdaParams, LiftedLambda, Program};
+use crate::literals::Literal;
+
+/// The runtime the generated code is joined with.
+pub const RUNTIME: &str = include_str!("core/wasm/runtime.wat");
+
+/// The node script that runs a compiled program.
+pub const RUNNER: &str = include_str!("core/wasm/run.js");
+
+/// Where the static objects of the program start, the runtime's own are below.
+const DATA_START: u32 = 1024;
+
+const TAG_CONS: u32 = 1;
+const TAG_CLOSURE: u32 = 2;
+const TAG_STRING: u32 = 3;
+const TAG_SYMBOL: u32 = 4;
+const TAG_INT: u32 = 8;
+
+/// The runtime's `$false` and `$true`.
+const FALSE: i32 = 16;
+const TRUE: i32 = 24;
+
+/// The builtins the wasm32 runtime implements, and the function in
+/// `runtime.wat` implementing each.
+#[derive(Debug, Clone, Copy)]
+enum Builtin {
+    Halt,
+    One(&'static str),
+    Two(&'static str),
+}
+
+fn builtin(name: &str) -> Option<Builtin> {
+    Some(match name {
+        "halt" => Builtin::Halt,
+        "display" => Builtin::Two("display"),
+        "newline" => Builtin::One("newline"),
+        "current-output-port" => Builtin::One("current_output_port"),
+        "+" => Builtin::Two("add"),
+        "-" => Builtin::Two("sub"),
+        "*" => Builtin::Two("mul"),
+        "%" => Builtin::Two("rem"),
+        "^" => Builtin::Two("xor"),
+        "<" => Builtin::Two("lt"),
+        "<=" => Builtin::Two("le"),
+        ">" => Builtin::Two("gt"),
+        ">=" => Builtin::Two("ge"),
+        "=" => Builtin::Two("num_eq"),
+        "zero?" => Builtin::One("is_zero"),
+        "number?" => Builtin::One("is_number"),
+        "cons" => Builtin::Two("cons"),
+        "cons?" | "pair?" => Builtin::One("is_cons"),
+        "car" => Builtin::One("car"),
+        "cdr" => Builtin::One("cdr"),
+        "null?" => Builtin::One("is_null"),
+        "procedure?" => Builtin::One("is_procedure"),
+        "string?" => Builtin::One("is_string"),
+        "boolean?" => Builtin::One("is_boolean"),
+        "symbol?" => Builtin::One("is_symbol"),
+        "eq?" | "eqv?" => Builtin::Two("eq"),
+        "string-length" => Builtin::One("string_length"),
+        "string-append" => Builtin::Two("string_append"),
+        "string-concat" => Builtin::Two("string_concat"),
+        "string=?" => Builtin::Two("string_eq"),
+        _ => return None,
+    })
+}
+
+/// Compile a lifted program to a WebAssembly module in the text format, which
+/// `wat::assemble` turns into a binary.
+///
+/// Each lambda becomes a function in the table, and closures hold the index
+/// of their function along with their environment, which is laid out the same
+/// way as the C backend lays out its env structs. Calls are made by the loop
+/// in the runtime rather than by the lambdas, see `runtime.wat`.
+pub fn compile(program: &Program) -> Result<String, Error> {
+    let (expr, lambdas) = (program.entry(), program.lambdas());
+    let mut ctx = WasmCtx::new(lambdas);
+
+    let mut ids = lambdas.keys().cloned().collect::<Vec<_>>();
+    ids.sort();
+
+    for id in &ids {
+        ctx.generate_func(&lambdas[id])?;
+    }
+
+    ctx.current = None;
+    ctx.temps = 0;
+    let mut stmts = Vec::new();
+    ctx.tail(expr, &mut stmts)?;
+    ctx.funcs.push(function(
+        "$main_lambda",
+        ["$a", "$b", "$env"],
+        ctx.temps,
+        &stmts,
+    ));
+
+    Ok(ctx.module())
+}
+
+struct WasmCtx<'a> {
+    lambdas: &'a BTreeMap<usize, LiftedLambda>,
+    /// the functions in the table, in order
+    table: Vec<String>,
+    /// the slots of the environment of each lambda, in order
+    slots: HashMap<usize, Vec<FreeVar<String>>>,
+    data: Vec<u8>,
+    strings: HashMap<String, u32>,
+    symbols: HashMap<String, u32>,
+    /// the static closure of each builtin used
+    builtins: HashMap<String, u32>,
+    funcs: Vec<String>,
+    current: Option<usize>,
+    temps: usize,
+}
+
+impl<'a> WasmCtx<'a> {
+    fn new(lambdas: &'a BTreeMap<usize, LiftedLambda>) -> Self {
+        let mut ids = lambdas.keys().cloned().collect::<Vec<_>>();
+        ids.sort();
+
+        let table = std::iter::once("$main_lambda".to_owned())
+            .chain(ids.iter().map(|id| format!("${}", lambdas[id].name)))
+            .collect();
+
+        let slots = lambdas.values().map(|l| (l.id, l.env_slots())).collect();
+
+        Self {
+            lambdas,
+            table,
+            slots,
+            data: Vec::new(),
+            strings: HashMap::new(),
+            symbols: HashMap::new(),
+            builtins: HashMap::new(),
+            funcs: Vec::new(),
+            current: None,
+            temps: 0,
+        }
+    }
+
+    fn table_index(&self, func: &str) -> usize {
+        self.table.iter().position(|f| f == func).unwrap()
+    }
+
+    fn gen_temp(&mut self) -> String {
+        self.temps += 1;
+        format!("$t{}", self.temps)
+    }
+
+    /// Place a static object, returning its address.
+    fn add_data(&mut self, words: &[u32], bytes: &[u8]) -> u32 {
+        while !self.data.len().is_multiple_of(8) {
+            self.data.push(0);
+        }
+
+        let address = DATA_START + self.data.len() as u32;
+
+        for w in words {
+            self.data.extend_from_slice(&w.to_le_bytes());
+        }
+        self.data.extend_from_slice(bytes);
+
+        address
+    }
+
+    fn string_object(&mut self, tag: u32, s: &str) -> u32 {
+        let size = (8 + s.len() as u32 + 3) & !3;
+        self.add_data(&[(size << 8) | tag, s.len() as u32], s.as_bytes())
+    }
+
+    /// A boxed integer, for those that don't fit in a fixnum.
+    fn int_object(&mut self, i: i64) -> u32 {
+        self.add_data(&[(16 << 8) | TAG_INT, 0], &i.to_le_bytes())
+    }
+
+    fn string(&mut self, s: &str) -> u32 {
+        if let Some(&address) = self.strings.get(s) {
+            return address;
+        }
+
+        let address = self.string_object(TAG_STRING, s);
+        self.strings.insert(s.to_owned(), address);
+        address
+    }
+
+    /// Symbols are static objects, one per distinct name, so that each use of
+    /// a symbol is the same object.
+    fn symbol(&mut self, s: &str) -> u32 {
+        if let Some(&address) = self.symbols.get(s) {
+            return address;
+        }
+
+        let address = self.string_object(TAG_SYMBOL, s);
+        self.symbols.insert(s.to_owned(), address);
+        address
+    }
+
+    /// The value of a literal, an immediate or the address of a static object.
+    fn constant(&mut self, l: &Literal) -> Result<i32, Error> {
+        Ok(match l {
+            Literal::Int(i) if (-(1 << 30)..1 << 30).contains(i) => ((i << 1) | 1) as i32,
+            Literal::Int(i) => self.int_object(*i) as i32,
+            Literal::Bool(b) => {
+                if *b {
+                    TRUE
+                } else {
+                    FALSE
+                }
+            }
+            Literal::String(s) => self.string(&unescape(s)) as i32,
+            Literal::Symbol(s) => self.symbol(s) as i32,
+            Literal::Void => 0,
+            Literal::Quoted(items) => self.quoted(items)? as i32,
+            Literal::Float(_) | Literal::Rational(_, _) => {
+                return Err(format_err!(
+                    "floats and rationals aren't supported on the wasm32 target"
+                ))
+            }
+        })
+    }
+
+    /// A quoted list, made of static pairs like strings are static.
+    fn quoted(&mut self, items: &[Literal]) -> Result<u32, Error> {
+        let mut list = 0;
+
+        for item in items.iter().rev() {
+            let car = self.constant(item)? as u32;
+            list = self.add_data(&[(12 << 8) | TAG_CONS, car, list], &[]);
+        }
+
+        Ok(list)
+    }
+
+    /// The static closure of a builtin, emitting the functions that take its
+    /// arguments the first time it's used.
+    fn builtin_closure(&mut self, name: &str) -> Result<u32, Error> {
+        if Foreign::from_builtin_name(name).is_some() {
+            return Err(format_err!(
+                "foreign functions can't be called on the wasm32 target"
+            ));
+        }
+
+        let builtin = builtin(name)
+            .ok_or_else(|| format_err!("{} isn't supported on the wasm32 target", name))?;
+
+        // builtins with several names share their closure
+        let key = match builtin {
+            Builtin::Halt => "halt",
+            Builtin::One(f) | Builtin::Two(f) => f,
+        };
+
+        if let Some(&address) = self.builtins.get(key) {
+            return Ok(address);
+        }
+
+        let entry = match builtin {
+            Builtin::Halt => {
+                self.funcs.push(function(
+                    "$builtin_halt",
+                    ["$v", "$k", "$env"],
+                    0,
+                    &["(call $halt)".to_owned()],
+                ));
+                "$builtin_halt".to_owned()
+            }
+            Builtin::One(f) => {
+                let entry = format!("$builtin_{}", f);
+                self.funcs.push(function(
+                    &entry,
+                    ["$v", "$k", "$env"],
+                    0,
+                    &[format!(
+                        "(call $call_one (local.get $k) (call ${} (local.get $v)))",
+                        f
+                    )],
+                ));
+                entry
+            }
+            Builtin::Two(f) => {
+                // the first argument is kept in a cell, which is the
+                // environment of the closure taking the second
+                let entry = format!("$builtin_{}", f);
+                let second = format!("$builtin_{}_2", f);
+                self.table.push(second.clone());
+
+                self.funcs.push(function(
+                    &entry,
+                    ["$v", "$k", "$env"],
+                    0,
+                    &[format!(
+                        "(call $call_one (local.get $k) (call $closure_new (i32.const {}) (call $cell_new (local.get $v))))",
+                        self.table_index(&second)
+                    )],
+                ));
+                self.funcs.push(function(
+                    &second,
+                    ["$v", "$k", "$env"],
+                    0,
+                    &[format!(
+                        "(call $call_one (local.get $k) (call ${} (i32.load offset=4 (local.get $env)) (local.get $v)))",
+                        f
+                    )],
+                ));
+                entry
+            }
+        };
+
+        self.table.push(entry.clone());
+        let index = self.table_index(&entry) as u32;
+        let address = self.add_data(&[(12 << 8) | TAG_CLOSURE, index, 0], &[]);
+
+        self.builtins.insert(key.to_owned(), address);
+        Ok(address)
+    }
+
+    /// The address of the cell holding `var`, walking up linked environments
+    /// until one that holds it.
+    fn slot(&self, var: &FreeVar<String>) -> String {
+        let mut env = "(local.get $env)".to_owned();
+        let mut lambda = &self.lambdas[&self.current.expect("variable outside of any lambda")];
+
+        while !lambda.holds(var) {
+            env = format!("(i32.load offset=4 {})", env);
+            lambda = &self.lambdas[&lambda.parent.expect("variable not in any environment")];
+        }
+
+        format!(
+            "(i32.load offset={} {})",
+            slot_offset(&self.slots[&lambda.id], var),
+            env
+        )
+    }
+
+    fn generate_func(&mut self, lambda: &LiftedLambda) -> Result<(), Error> {
+        self.current = Some(lambda.id);
+        self.temps = 0;
+
+        let params = match &lambda.params {
+            LambdaParams::One(a) => vec![(a, "$a")],
+            LambdaParams::Two(a, b) => vec![(a, "$a"), (b, "$b")],
+        };
+
+        let mut stmts = Vec::new();
+
+        for (param, local) in params {
+            // parameters that aren't used in the body are discarded
+            if lambda.freevars.contains(param) {
+                stmts.push(format!(
+                    "(i32.store offset={} (local.get $env) (call $cell_new (local.get {})))",
+                    slot_offset(&self.slots[&lambda.id], param),
+                    local
+                ));
+            }
+        }
+
+        self.tail(&lambda.body, &mut stmts)?;
+
+        let name = format!("${}", lambda.name);
+        self.funcs
+            .push(function(&name, ["$a", "$b", "$env"], self.temps, &stmts));
+
+        Ok(())
+    }
+
+    /// The statements of a lambda body, which ends in a call.
+    fn tail(&mut self, e: &LExpr, stmts: &mut Vec<String>) -> Result<(), Error> {
+        match e {
+            LExpr::SetThen(v, e, c) => {
+                self.set(v, e, stmts)?;
+                self.tail(c, stmts)
+            }
+            LExpr::If(c, ift, iff) => {
+                let cond = self.atom(c, stmts)?;
+
+                let mut ift_stmts = Vec::new();
+                self.tail(ift, &mut ift_stmts)?;
+
+                let mut iff_stmts = Vec::new();
+                self.tail(iff, &mut iff_stmts)?;
+
+                stmts.push(format!(
+                    "(if (call $is_truthy {})\n    (then\n      {})\n    (else\n      {}))",
+                    cond,
+                    ift_stmts.join("\n      "),
+                    iff_stmts.join("\n      ")
+                ));
+
+                Ok(())
+            }
+            LExpr::CallOne(c, a) => {
+                let c = self.atom(c, stmts)?;
+                let a = self.atom(a, stmts)?;
+                stmts.push(format!("(call $call_one {} {})", c, a));
+                Ok(())
+            }
+            LExpr::CallTwo(c, a, k) => {
+                let c = self.atom(c, stmts)?;
+                let a = self.atom(a, stmts)?;
+                let k = self.atom(k, stmts)?;
+                stmts.push(format!("(call $call_two {} {} {})", c, a, k));
+                Ok(())
+            }
+            _ => Err(format_err!("lambda body isn't a call: {:?}", e)),
+        }
+    }
+
+    fn set(
+        &mut self,
+        v: &moniker::Var<String>,
+        e: &LExpr,
+        stmts: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        let value = self.atom(e, stmts)?;
+        let cell = match v {
+            moniker::Var::Free(f) => self.slot(f),
+            moniker::Var::Bound(_) => panic!("bound var: {:?}", v),
+        };
+
+        stmts.push(format!("(i32.store offset=4 {} {})", cell, value));
+        Ok(())
+    }
+
+    /// An expression for the value of `e`, with anything it needs done first
+    /// added to `stmts`.
+    fn atom(&mut self, e: &LExpr, stmts: &mut Vec<String>) -> Result<String, Error> {
+        Ok(match e {
+            LExpr::Var(moniker::Var::Free(f)) => format!("(i32.load offset=4 {})", self.slot(f)),
+            LExpr::Var(v) => panic!("bound var: {:?}", v),
+            LExpr::Lit(Ignore(l)) => match l {
+                Literal::Bool(true) => "(global.get $true)".to_owned(),
+                Literal::Bool(false) => "(global.get $false)".to_owned(),
+                l => format!("(i32.const {})", self.constant(l)?),
+            },
+            LExpr::BuiltinIdent(Ignore(name)) => {
+                format!("(i32.const {})", self.builtin_closure(name)?)
+            }
+            LExpr::SetThen(v, e, c) => {
+                self.set(v, e, stmts)?;
+                self.atom(c, stmts)?
+            }
+            LExpr::Lifted(Ignore(id)) => {
+                let lambdas = self.lambdas;
+                self.closure(&lambdas[id], stmts)
+            }
+            LExpr::If(..) | LExpr::CallOne(..) | LExpr::CallTwo(..) => {
+                return Err(format_err!("call in argument position: {:?}", e))
+            }
+            LExpr::IntOp(..) => unreachable!("integer ops are only made for C"),
+        })
+    }
+
+    fn closure(&mut self, lambda: &LiftedLambda, stmts: &mut Vec<String>) -> String {
+        let env = self.gen_temp();
+        let slots = &self.slots[&lambda.id];
+
+        stmts.push(format!(
+            "(local.set {} (call $env_new (i32.const {})))",
+            env,
+            slots.len()
+        ));
+
+        if lambda.env_repr == EnvRepr::Linked {
+            stmts.push(format!(
+                "(i32.store offset=4 (local.get {}) (local.get $env))",
+                env
+            ));
+        } else {
+            for var in lambda.captures() {
+                stmts.push(format!(
+                    "(i32.store offset={} (local.get {}) {})",
+                    slot_offset(slots, var),
+                    env,
+                    self.slot(var)
+                ));
+            }
+        }
+
+        format!(
+            "(call $closure_new (i32.const {}) (local.get {}))",
+            self.table_index(&format!("${}", lambda.name)),
+            env
+        )
+    }
+
+    fn module(&self) -> String {
+        let heap_start = (DATA_START + self.data.len() as u32 + 7) & !7;
+        let pages = heap_start / 65536 + 1;
+
+        let mut out = String::new();
+
+        let _ = writeln!(out, "(module");
+        let _ = writeln!(out, "{}", RUNTIME);
+        let _ = writeln!(out, "(memory (export \"memory\") {})", pages);
+        let _ = writeln!(out, "(global $heap_start i32 (i32.const {}))", heap_start);
+        let _ = writeln!(out, "(table {} funcref)", self.table.len());
+        let _ = writeln!(out, "(elem (i32.const 0) {})", self.table.join(" "));
+
+        if !self.data.is_empty() {
+            let _ = writeln!(
+                out,
+                "(data (i32.const {}) \"{}\")",
+                DATA_START,
+                escape(&self.data)
+            );
+        }
+
+        for func in &self.funcs {
+            let _ = writeln!(out, "\n{}", func);
+        }
+
+        let _ = writeln!(
+            out,
+            "\n(func $start (export \"_start\")\n  (call $run (i32.const {})))",
+            self.table_index("$main_lambda")
+        );
+        let _ = writeln!(out, ")");
+
+        out
+    }
+}
+
+fn slot_offset(slots: &[FreeVar<String>], var: &FreeVar<String>) -> usize {
+    let index = slots
+        .iter()
+        .position(|s| s == var)
+        .expect("variable not in the environment");
+
+    4 + 4 * index
+}
+
+fn function<'p>(
+    name: &str,
+    params: impl IntoIterator<Item = &'p str>,
+    temps: usize,
+    stmts: &[String],
+) -> String {
+    let mut out = format!("(func {} (type $lambda)", name);
+
+    for p in params {
+        let _ = write!(out, " (param {} i32)", p);
+    }
+
+    for t in 1..=temps {
+        let _ = write!(out, " (local $t{} i32)", t);
+    }
+
+    for stmt in stmts {
+        let _ = write!(out, "\n  {}", stmt);
+    }
+
+    out.push(')');
+    out
+}
+
+fn escape(bytes: &[u8]) -> String {
+    let mut out = String::new();
+
+    for &b in bytes {
+        match b {
+            b'"' | b'\\' => {
+                let _ = write!(out, "\\{:02x}", b);
+            }
+            0x20..=0x7e => out.push(b as char),
+            _ => {
+                let _ = write!(out, "\\{:02x}", b);
+            }
+        }
+    }
+
+    out
+}
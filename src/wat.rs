@@ -0,0 +1,1056 @@
+use failure::{format_err, Error};
+
+use std::collections::HashMap;
+
+/// An assembler for the part of the WebAssembly text format that the wasm32
+/// backend and its runtime are written in, producing a binary module.
+///
+/// Instructions can be written flat or folded, and everything can be referred
+/// to by `$name` or by index. Only `i32` and `i64` values, a single memory and
+/// a single funcref table are supported.
+pub fn assemble(source: &str) -> Result<Vec<u8>, Error> {
+    let mut sexps = parse(source)?;
+
+    let fields = match sexps.pop() {
+        Some(Sexp::List(mut items))
+            if sexps.is_empty() && items.first() == Some(&atom("module")) =>
+        {
+            items.remove(0);
+            items
+        }
+        _ => return Err(format_err!("expected a single module")),
+    };
+
+    Module::new(&fields)?.encode()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Sexp {
+    Atom(String),
+    Str(Vec<u8>),
+    List(Vec<Sexp>),
+}
+
+fn atom(s: &str) -> Sexp {
+    Sexp::Atom(s.to_owned())
+}
+
+impl Sexp {
+    fn as_atom(&self) -> Option<&str> {
+        match self {
+            Sexp::Atom(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The keyword a list starts with.
+    fn head(&self) -> Option<&str> {
+        match self {
+            Sexp::List(items) => items.first().and_then(Sexp::as_atom),
+            _ => None,
+        }
+    }
+
+    fn items(&self) -> &[Sexp] {
+        match self {
+            Sexp::List(items) => items,
+            _ => &[],
+        }
+    }
+}
+
+fn parse(source: &str) -> Result<Vec<Sexp>, Error> {
+    let mut stack = vec![Vec::new()];
+    let bytes = source.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b';' if bytes.get(i + 1) == Some(&b';') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'(' if bytes.get(i + 1) == Some(&b';') => {
+                let end = source[i..]
+                    .find(";)")
+                    .ok_or_else(|| format_err!("unterminated block comment"))?;
+                i += end + 2;
+            }
+            b'(' => {
+                stack.push(Vec::new());
+                i += 1;
+            }
+            b')' => {
+                let list = stack.pop().unwrap();
+                stack
+                    .last_mut()
+                    .ok_or_else(|| format_err!("unbalanced `)`"))?
+                    .push(Sexp::List(list));
+                i += 1;
+            }
+            b'"' => {
+                let (s, len) = parse_string(&bytes[i + 1..])?;
+                stack.last_mut().unwrap().push(Sexp::Str(s));
+                i += len + 2;
+            }
+            c if c.is_ascii_whitespace() => i += 1,
+            _ => {
+                let start = i;
+                while i < bytes.len()
+                    && !bytes[i].is_ascii_whitespace()
+                    && !b"()\";".contains(&bytes[i])
+                {
+                    i += 1;
+                }
+                stack
+                    .last_mut()
+                    .unwrap()
+                    .push(Sexp::Atom(source[start..i].to_owned()));
+            }
+        }
+    }
+
+    match stack.pop() {
+        Some(sexps) if stack.is_empty() => Ok(sexps),
+        _ => Err(format_err!("unbalanced `(`")),
+    }
+}
+
+/// The contents of a string up to its closing quote, along with how many
+/// bytes of source they took.
+fn parse_string(bytes: &[u8]) -> Result<(Vec<u8>, usize), Error> {
+    let mut s = Vec::new();
+    let mut i = 0;
+
+    loop {
+        match bytes.get(i) {
+            None => return Err(format_err!("unterminated string")),
+            Some(b'"') => return Ok((s, i)),
+            Some(b'\\') => {
+                let escaped = match bytes.get(i + 1) {
+                    Some(b'n') => b'\n',
+                    Some(b't') => b'\t',
+                    Some(b'\\') => b'\\',
+                    Some(b'"') => b'"',
+                    Some(b'\'') => b'\'',
+                    _ => {
+                        let hex = bytes
+                            .get(i + 1..i + 3)
+                            .and_then(|h| std::str::from_utf8(h).ok())
+                            .and_then(|h| u8::from_str_radix(h, 16).ok())
+                            .ok_or_else(|| format_err!("bad escape in string"))?;
+                        s.push(hex);
+                        i += 3;
+                        continue;
+                    }
+                };
+                s.push(escaped);
+                i += 2;
+            }
+            Some(&c) => {
+                s.push(c);
+                i += 1;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ValType {
+    I32,
+    I64,
+}
+
+impl ValType {
+    fn parse(s: &Sexp) -> Result<Self, Error> {
+        match s.as_atom() {
+            Some("i32") => Ok(ValType::I32),
+            Some("i64") => Ok(ValType::I64),
+            _ => Err(format_err!("unsupported value type {:?}", s)),
+        }
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            ValType::I32 => 0x7f,
+            ValType::I64 => 0x7e,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct FuncType {
+    params: Vec<ValType>,
+    results: Vec<ValType>,
+}
+
+/// A function's signature, along with the names of its parameters.
+struct Signature {
+    typ: usize,
+    param_names: Vec<Option<String>>,
+}
+
+struct Func<'a> {
+    name: Option<String>,
+    sig: Signature,
+    locals: Vec<(Option<String>, ValType)>,
+    body: &'a [Sexp],
+}
+
+struct Global {
+    typ: ValType,
+    mutable: bool,
+    init: Sexp,
+}
+
+enum ExportKind {
+    Func,
+    Memory,
+    Global,
+}
+
+struct Module<'a> {
+    types: Vec<FuncType>,
+    type_names: HashMap<String, usize>,
+    imports: Vec<(String, String, usize)>,
+    funcs: Vec<Func<'a>>,
+    func_names: HashMap<String, usize>,
+    globals: Vec<Global>,
+    global_names: HashMap<String, usize>,
+    memory: Option<(u32, Option<u32>)>,
+    table: Option<u32>,
+    exports: Vec<(String, ExportKind, &'a Sexp)>,
+    elems: Vec<(&'a Sexp, &'a [Sexp])>,
+    data: Vec<(&'a Sexp, Vec<u8>)>,
+}
+
+impl<'a> Module<'a> {
+    fn new(fields: &'a [Sexp]) -> Result<Self, Error> {
+        let mut module = Module {
+            types: Vec::new(),
+            type_names: HashMap::new(),
+            imports: Vec::new(),
+            funcs: Vec::new(),
+            func_names: HashMap::new(),
+            globals: Vec::new(),
+            global_names: HashMap::new(),
+            memory: None,
+            table: None,
+            exports: Vec::new(),
+            elems: Vec::new(),
+            data: Vec::new(),
+        };
+
+        // explicit types come first so that their indices are as written
+        for field in fields.iter().filter(|f| f.head() == Some("type")) {
+            let items = field.items();
+            let (name, rest) = take_name(&items[1..]);
+            let sig = module.signature(
+                rest.first()
+                    .map_or(&[][..], Sexp::items)
+                    .get(1..)
+                    .unwrap_or(&[]),
+            )?;
+
+            if let Some(name) = name {
+                module.type_names.insert(name, sig.typ);
+            }
+        }
+
+        for field in fields {
+            let items = field.items();
+
+            match field.head() {
+                Some("type") => {}
+                Some("import") => {
+                    let (module_name, field_name) = match (items.get(1), items.get(2)) {
+                        (Some(Sexp::Str(m)), Some(Sexp::Str(f))) => (utf8(m)?, utf8(f)?),
+                        _ => return Err(format_err!("bad import {:?}", field)),
+                    };
+
+                    let desc = items.get(3).filter(|d| d.head() == Some("func"));
+                    let desc = desc
+                        .ok_or_else(|| format_err!("only functions can be imported"))?
+                        .items();
+                    let (name, rest) = take_name(&desc[1..]);
+                    let sig = module.signature(rest)?;
+
+                    if !module.funcs.is_empty() {
+                        return Err(format_err!("imports have to come before functions"));
+                    }
+
+                    if let Some(name) = name {
+                        module.func_names.insert(name, module.imports.len());
+                    }
+                    module.imports.push((module_name, field_name, sig.typ));
+                }
+                Some("func") => {
+                    let (name, mut rest) = take_name(&items[1..]);
+
+                    while let Some(export) = rest.first().filter(|r| r.head() == Some("export")) {
+                        module
+                            .exports
+                            .push((export_name(export)?, ExportKind::Func, &items[1]));
+                        rest = &rest[1..];
+                    }
+
+                    let sig = module.signature(rest)?;
+                    let signature_len = rest
+                        .iter()
+                        .take_while(|r| {
+                            matches!(r.head(), Some("param") | Some("result") | Some("type"))
+                        })
+                        .count();
+                    rest = &rest[signature_len..];
+
+                    let mut locals = Vec::new();
+                    while let Some(local) = rest.first().filter(|r| r.head() == Some("local")) {
+                        let (local_name, types) = take_name(&local.items()[1..]);
+                        match local_name {
+                            Some(local_name) => {
+                                locals.push((Some(local_name), ValType::parse(&types[0])?))
+                            }
+                            None => {
+                                for t in types {
+                                    locals.push((None, ValType::parse(t)?));
+                                }
+                            }
+                        }
+                        rest = &rest[1..];
+                    }
+
+                    let index = module.imports.len() + module.funcs.len();
+                    if let Some(name) = &name {
+                        module.func_names.insert(name.clone(), index);
+                    }
+
+                    module.funcs.push(Func {
+                        name,
+                        sig,
+                        locals,
+                        body: rest,
+                    });
+                }
+                Some("global") => {
+                    let (name, rest) = take_name(&items[1..]);
+                    let (typ, mutable) = match &rest[0] {
+                        Sexp::List(m) if m.first() == Some(&atom("mut")) => {
+                            (ValType::parse(&m[1])?, true)
+                        }
+                        t => (ValType::parse(t)?, false),
+                    };
+
+                    if let Some(name) = name {
+                        module.global_names.insert(name, module.globals.len());
+                    }
+                    module.globals.push(Global {
+                        typ,
+                        mutable,
+                        init: rest[1].clone(),
+                    });
+                }
+                Some("memory") => {
+                    let (_, mut rest) = take_name(&items[1..]);
+
+                    while let Some(export) = rest.first().filter(|r| r.head() == Some("export")) {
+                        module
+                            .exports
+                            .push((export_name(export)?, ExportKind::Memory, &items[0]));
+                        rest = &rest[1..];
+                    }
+
+                    let min = number(&rest[0])? as u32;
+                    let max = rest.get(1).map(number).transpose()?.map(|m| m as u32);
+                    module.memory = Some((min, max));
+                }
+                Some("table") => {
+                    let (_, rest) = take_name(&items[1..]);
+                    if rest.get(1).and_then(Sexp::as_atom) != Some("funcref") {
+                        return Err(format_err!("only funcref tables are supported"));
+                    }
+                    module.table = Some(number(&rest[0])? as u32);
+                }
+                Some("export") => {
+                    let kind = match items.get(2).and_then(Sexp::head) {
+                        Some("func") => ExportKind::Func,
+                        Some("memory") => ExportKind::Memory,
+                        Some("global") => ExportKind::Global,
+                        _ => return Err(format_err!("bad export {:?}", field)),
+                    };
+                    module
+                        .exports
+                        .push((export_name(field)?, kind, &items[2].items()[1]));
+                }
+                Some("elem") => module.elems.push((&items[1], &items[2..])),
+                Some("data") => {
+                    let mut bytes = Vec::new();
+                    for s in &items[2..] {
+                        match s {
+                            Sexp::Str(s) => bytes.extend_from_slice(s),
+                            _ => return Err(format_err!("bad data {:?}", field)),
+                        }
+                    }
+                    module.data.push((&items[1], bytes));
+                }
+                _ => return Err(format_err!("unsupported module field {:?}", field)),
+            }
+        }
+
+        Ok(module)
+    }
+
+    /// The signature given by `(type $t)` or by `(param ...)` and `(result
+    /// ...)`, adding the type if no earlier one matches.
+    fn signature(&mut self, items: &[Sexp]) -> Result<Signature, Error> {
+        let mut typ = FuncType {
+            params: Vec::new(),
+            results: Vec::new(),
+        };
+        let mut param_names = Vec::new();
+        let mut explicit = None;
+
+        for item in items {
+            match item.head() {
+                Some("type") => explicit = Some(self.resolve(&self.type_names, &item.items()[1])?),
+                Some("param") => {
+                    let (name, types) = take_name(&item.items()[1..]);
+                    if name.is_some() {
+                        typ.params.push(ValType::parse(&types[0])?);
+                        param_names.push(name);
+                    } else {
+                        for t in types {
+                            typ.params.push(ValType::parse(t)?);
+                            param_names.push(None);
+                        }
+                    }
+                }
+                Some("result") => {
+                    for t in &item.items()[1..] {
+                        typ.results.push(ValType::parse(t)?);
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        let index = match explicit {
+            Some(index) => {
+                if param_names.is_empty() {
+                    param_names = vec![None; self.types[index].params.len()];
+                }
+                index
+            }
+            None => match self.types.iter().position(|t| *t == typ) {
+                Some(index) => index,
+                None => {
+                    self.types.push(typ);
+                    self.types.len() - 1
+                }
+            },
+        };
+
+        Ok(Signature {
+            typ: index,
+            param_names,
+        })
+    }
+
+    fn resolve(&self, names: &HashMap<String, usize>, s: &Sexp) -> Result<usize, Error> {
+        match s.as_atom() {
+            Some(name) if name.starts_with('$') => names
+                .get(name)
+                .cloned()
+                .ok_or_else(|| format_err!("unknown name {}", name)),
+            _ => Ok(number(s)? as usize),
+        }
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut out = b"\0asm".to_vec();
+        out.extend_from_slice(&1u32.to_le_bytes());
+
+        section(&mut out, 1, self.types.len(), |body| {
+            for t in &self.types {
+                body.push(0x60);
+                uleb(body, t.params.len() as u64);
+                body.extend(t.params.iter().map(|p| p.code()));
+                uleb(body, t.results.len() as u64);
+                body.extend(t.results.iter().map(|r| r.code()));
+            }
+            Ok(())
+        })?;
+
+        section(&mut out, 2, self.imports.len(), |body| {
+            for (module, field, typ) in &self.imports {
+                name(body, module);
+                name(body, field);
+                body.push(0x00);
+                uleb(body, *typ as u64);
+            }
+            Ok(())
+        })?;
+
+        section(&mut out, 3, self.funcs.len(), |body| {
+            for f in &self.funcs {
+                uleb(body, f.sig.typ as u64);
+            }
+            Ok(())
+        })?;
+
+        if let Some(size) = self.table {
+            section(&mut out, 4, 1, |body| {
+                body.extend_from_slice(&[0x70, 0x00]);
+                uleb(body, size as u64);
+                Ok(())
+            })?;
+        }
+
+        if let Some((min, max)) = self.memory {
+            section(&mut out, 5, 1, |body| {
+                limits(body, min, max);
+                Ok(())
+            })?;
+        }
+
+        section(&mut out, 6, self.globals.len(), |body| {
+            for g in &self.globals {
+                body.push(g.typ.code());
+                body.push(g.mutable as u8);
+                self.const_expr(body, &g.init)?;
+            }
+            Ok(())
+        })?;
+
+        section(&mut out, 7, self.exports.len(), |body| {
+            for (export, kind, target) in &self.exports {
+                name(body, export);
+                match kind {
+                    ExportKind::Func => {
+                        body.push(0x00);
+                        uleb(body, self.resolve(&self.func_names, target)? as u64);
+                    }
+                    ExportKind::Memory => body.extend_from_slice(&[0x02, 0x00]),
+                    ExportKind::Global => {
+                        body.push(0x03);
+                        uleb(body, self.resolve(&self.global_names, target)? as u64);
+                    }
+                }
+            }
+            Ok(())
+        })?;
+
+        section(&mut out, 9, self.elems.len(), |body| {
+            for (offset, funcs) in &self.elems {
+                body.push(0x00);
+                self.const_expr(body, offset)?;
+                uleb(body, funcs.len() as u64);
+                for f in funcs.iter() {
+                    uleb(body, self.resolve(&self.func_names, f)? as u64);
+                }
+            }
+            Ok(())
+        })?;
+
+        section(&mut out, 10, self.funcs.len(), |body| {
+            for f in &self.funcs {
+                let code = self.function(f)?;
+                uleb(body, code.len() as u64);
+                body.extend(code);
+            }
+            Ok(())
+        })?;
+
+        section(&mut out, 11, self.data.len(), |body| {
+            for (offset, bytes) in &self.data {
+                body.push(0x00);
+                self.const_expr(body, offset)?;
+                uleb(body, bytes.len() as u64);
+                body.extend_from_slice(bytes);
+            }
+            Ok(())
+        })?;
+
+        Ok(out)
+    }
+
+    fn const_expr(&self, out: &mut Vec<u8>, expr: &Sexp) -> Result<(), Error> {
+        let mut body = FuncBody {
+            module: self,
+            locals: HashMap::new(),
+            labels: Vec::new(),
+            out,
+        };
+
+        body.instrs(std::slice::from_ref(expr))?;
+        body.out.push(0x0b);
+
+        Ok(())
+    }
+
+    fn function(&self, f: &Func) -> Result<Vec<u8>, Error> {
+        let mut code = Vec::new();
+
+        uleb(&mut code, f.locals.len() as u64);
+        for (_, typ) in &f.locals {
+            code.push(0x01);
+            code.push(typ.code());
+        }
+
+        let locals = f
+            .sig
+            .param_names
+            .iter()
+            .chain(f.locals.iter().map(|(n, _)| n))
+            .enumerate()
+            .filter_map(|(i, n)| Some((n.clone()?, i)))
+            .collect();
+
+        let mut body = FuncBody {
+            module: self,
+            locals,
+            labels: Vec::new(),
+            out: &mut code,
+        };
+
+        body.instrs(f.body)
+            .map_err(|e| format_err!("in function {}: {}", f.name.as_deref().unwrap_or("?"), e))?;
+        code.push(0x0b);
+
+        Ok(code)
+    }
+}
+
+struct FuncBody<'m, 'a, 'o> {
+    module: &'m Module<'a>,
+    locals: HashMap<String, usize>,
+    /// the names of the enclosing blocks, innermost last
+    labels: Vec<Option<String>>,
+    out: &'o mut Vec<u8>,
+}
+
+impl FuncBody<'_, '_, '_> {
+    fn instrs(&mut self, items: &[Sexp]) -> Result<(), Error> {
+        let mut i = 0;
+
+        while i < items.len() {
+            match &items[i] {
+                Sexp::List(folded) => {
+                    self.folded(folded)?;
+                    i += 1;
+                }
+                Sexp::Atom(op) => match op.as_str() {
+                    "block" | "loop" | "if" => {
+                        let (label, rest) = take_name(&items[i + 1..]);
+                        let (result, rest) = take_result(rest)?;
+                        i = items.len() - rest.len();
+
+                        self.out.push(opcode_of_block(op));
+                        self.out.push(result);
+                        self.labels.push(label);
+                    }
+                    "else" => {
+                        self.out.push(0x05);
+                        i += 1;
+                    }
+                    "end" => {
+                        self.out.push(0x0b);
+                        self.labels.pop();
+                        i += 1;
+                    }
+                    _ => {
+                        let used = self.plain(op, &items[i + 1..])?;
+                        i += 1 + used;
+                    }
+                },
+                Sexp::Str(_) => return Err(format_err!("unexpected string")),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn folded(&mut self, items: &[Sexp]) -> Result<(), Error> {
+        let op = items
+            .first()
+            .and_then(Sexp::as_atom)
+            .ok_or_else(|| format_err!("expected an instruction"))?;
+
+        match op {
+            "block" | "loop" => {
+                let (label, rest) = take_name(&items[1..]);
+                let (result, rest) = take_result(rest)?;
+
+                self.out.push(opcode_of_block(op));
+                self.out.push(result);
+                self.labels.push(label);
+                self.instrs(rest)?;
+                self.labels.pop();
+                self.out.push(0x0b);
+            }
+            "if" => {
+                let (label, rest) = take_name(&items[1..]);
+                let (result, rest) = take_result(rest)?;
+
+                let arms = rest
+                    .iter()
+                    .position(|r| r.head() == Some("then"))
+                    .ok_or_else(|| format_err!("folded if without then"))?;
+                self.instrs(&rest[..arms])?;
+
+                self.out.push(0x04);
+                self.out.push(result);
+                self.labels.push(label);
+                self.instrs(&rest[arms].items()[1..])?;
+
+                if let Some(iff) = rest.get(arms + 1) {
+                    self.out.push(0x05);
+                    self.instrs(&iff.items()[1..])?;
+                }
+
+                self.labels.pop();
+                self.out.push(0x0b);
+            }
+            _ => {
+                let immediates = immediate_count(op, &items[1..]);
+                self.instrs(&items[1 + immediates..])?;
+                self.plain(op, &items[1..1 + immediates])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encode an instruction that isn't a block, returning how many of the
+    /// following items were its immediates.
+    fn plain(&mut self, op: &str, rest: &[Sexp]) -> Result<usize, Error> {
+        if let Some(code) = simple_opcode(op) {
+            self.out.extend_from_slice(code);
+            return Ok(0);
+        }
+
+        if let Some((code, align)) = memory_opcode(op) {
+            self.out.push(code);
+
+            let mut offset = 0;
+            let mut used = 0;
+            for imm in rest.iter().take(2) {
+                match imm.as_atom() {
+                    Some(o) if o.starts_with("offset=") => offset = parse_number(&o[7..])? as u64,
+                    Some(a) if a.starts_with("align=") => {}
+                    _ => break,
+                }
+                used += 1;
+            }
+
+            uleb(self.out, align);
+            uleb(self.out, offset);
+            return Ok(used);
+        }
+
+        let imm = rest
+            .first()
+            .ok_or_else(|| format_err!("{} is missing its immediate", op))?;
+
+        match op {
+            "i32.const" => {
+                self.out.push(0x41);
+                sleb(self.out, number(imm)? as i32 as i64);
+            }
+            "i64.const" => {
+                self.out.push(0x42);
+                sleb(self.out, number(imm)?);
+            }
+            "local.get" | "local.set" | "local.tee" => {
+                self.out.push(match op {
+                    "local.get" => 0x20,
+                    "local.set" => 0x21,
+                    _ => 0x22,
+                });
+                let index = self.module.resolve(&self.locals, imm)?;
+                uleb(self.out, index as u64);
+            }
+            "global.get" | "global.set" => {
+                self.out.push(if op == "global.get" { 0x23 } else { 0x24 });
+                let index = self.module.resolve(&self.module.global_names, imm)?;
+                uleb(self.out, index as u64);
+            }
+            "call" => {
+                self.out.push(0x10);
+                let index = self.module.resolve(&self.module.func_names, imm)?;
+                uleb(self.out, index as u64);
+            }
+            "call_indirect" => {
+                let typ = match imm.head() {
+                    Some("type") => self
+                        .module
+                        .resolve(&self.module.type_names, &imm.items()[1])?,
+                    _ => return Err(format_err!("call_indirect needs a (type)")),
+                };
+                self.out.push(0x11);
+                uleb(self.out, typ as u64);
+                self.out.push(0x00);
+            }
+            "br" | "br_if" => {
+                self.out.push(if op == "br" { 0x0c } else { 0x0d });
+                let depth = self.label(imm)?;
+                uleb(self.out, depth as u64);
+            }
+            "br_table" => {
+                let labels = rest
+                    .iter()
+                    .take_while(|r| r.as_atom().is_some())
+                    .map(|l| self.label(l))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.out.push(0x0e);
+                uleb(self.out, labels.len() as u64 - 1);
+                for l in &labels {
+                    uleb(self.out, *l as u64);
+                }
+                return Ok(labels.len());
+            }
+            _ => return Err(format_err!("unknown instruction {}", op)),
+        }
+
+        Ok(1)
+    }
+
+    /// The depth of the block a branch targets.
+    fn label(&self, s: &Sexp) -> Result<usize, Error> {
+        match s.as_atom() {
+            Some(name) if name.starts_with('$') => self
+                .labels
+                .iter()
+                .rev()
+                .position(|l| l.as_deref() == Some(name))
+                .ok_or_else(|| format_err!("unknown label {}", name)),
+            _ => Ok(number(s)? as usize),
+        }
+    }
+}
+
+/// How many of the items after a folded instruction are its immediates rather
+/// than its operands.
+fn immediate_count(op: &str, rest: &[Sexp]) -> usize {
+    match op {
+        _ if simple_opcode(op).is_some() => 0,
+        _ if memory_opcode(op).is_some() => rest
+            .iter()
+            .take_while(|r| {
+                r.as_atom()
+                    .is_some_and(|a| a.starts_with("offset=") || a.starts_with("align="))
+            })
+            .count(),
+        "br_table" => rest.iter().take_while(|r| r.as_atom().is_some()).count(),
+        _ => 1,
+    }
+}
+
+fn opcode_of_block(op: &str) -> u8 {
+    match op {
+        "block" => 0x02,
+        "loop" => 0x03,
+        _ => 0x04,
+    }
+}
+
+fn take_name(items: &[Sexp]) -> (Option<String>, &[Sexp]) {
+    match items.first().and_then(Sexp::as_atom) {
+        Some(name) if name.starts_with('$') => (Some(name.to_owned()), &items[1..]),
+        _ => (None, items),
+    }
+}
+
+/// The block type given by an optional `(result t)`.
+fn take_result(items: &[Sexp]) -> Result<(u8, &[Sexp]), Error> {
+    match items.first() {
+        Some(r) if r.head() == Some("result") => {
+            Ok((ValType::parse(&r.items()[1])?.code(), &items[1..]))
+        }
+        _ => Ok((0x40, items)),
+    }
+}
+
+fn export_name(export: &Sexp) -> Result<String, Error> {
+    match export.items().get(1) {
+        Some(Sexp::Str(name)) => utf8(name),
+        _ => Err(format_err!("bad export {:?}", export)),
+    }
+}
+
+fn utf8(bytes: &[u8]) -> Result<String, Error> {
+    Ok(String::from_utf8(bytes.to_vec())?)
+}
+
+fn number(s: &Sexp) -> Result<i64, Error> {
+    parse_number(
+        s.as_atom()
+            .ok_or_else(|| format_err!("expected a number, got {:?}", s))?,
+    )
+}
+
+fn parse_number(s: &str) -> Result<i64, Error> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s),
+    };
+    let digits = digits.replace('_', "");
+
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16)? as i64,
+        None => digits.parse::<u64>()? as i64,
+    };
+
+    Ok(if negative {
+        value.wrapping_neg()
+    } else {
+        value
+    })
+}
+
+fn simple_opcode(op: &str) -> Option<&'static [u8]> {
+    Some(match op {
+        "unreachable" => &[0x00],
+        "nop" => &[0x01],
+        "return" => &[0x0f],
+        "drop" => &[0x1a],
+        "select" => &[0x1b],
+        "memory.size" => &[0x3f, 0x00],
+        "memory.grow" => &[0x40, 0x00],
+        "memory.copy" => &[0xfc, 0x0a, 0x00, 0x00],
+        "memory.fill" => &[0xfc, 0x0b, 0x00],
+        "i32.eqz" => &[0x45],
+        "i32.eq" => &[0x46],
+        "i32.ne" => &[0x47],
+        "i32.lt_s" => &[0x48],
+        "i32.lt_u" => &[0x49],
+        "i32.gt_s" => &[0x4a],
+        "i32.gt_u" => &[0x4b],
+        "i32.le_s" => &[0x4c],
+        "i32.le_u" => &[0x4d],
+        "i32.ge_s" => &[0x4e],
+        "i32.ge_u" => &[0x4f],
+        "i64.eqz" => &[0x50],
+        "i64.eq" => &[0x51],
+        "i64.ne" => &[0x52],
+        "i64.lt_s" => &[0x53],
+        "i64.lt_u" => &[0x54],
+        "i64.gt_s" => &[0x55],
+        "i64.gt_u" => &[0x56],
+        "i64.le_s" => &[0x57],
+        "i64.le_u" => &[0x58],
+        "i64.ge_s" => &[0x59],
+        "i64.ge_u" => &[0x5a],
+        "i32.add" => &[0x6a],
+        "i32.sub" => &[0x6b],
+        "i32.mul" => &[0x6c],
+        "i32.div_s" => &[0x6d],
+        "i32.div_u" => &[0x6e],
+        "i32.rem_s" => &[0x6f],
+        "i32.rem_u" => &[0x70],
+        "i32.and" => &[0x71],
+        "i32.or" => &[0x72],
+        "i32.xor" => &[0x73],
+        "i32.shl" => &[0x74],
+        "i32.shr_s" => &[0x75],
+        "i32.shr_u" => &[0x76],
+        "i64.add" => &[0x7c],
+        "i64.sub" => &[0x7d],
+        "i64.mul" => &[0x7e],
+        "i64.div_s" => &[0x7f],
+        "i64.div_u" => &[0x80],
+        "i64.rem_s" => &[0x81],
+        "i64.rem_u" => &[0x82],
+        "i64.and" => &[0x83],
+        "i64.or" => &[0x84],
+        "i64.xor" => &[0x85],
+        "i64.shl" => &[0x86],
+        "i64.shr_s" => &[0x87],
+        "i64.shr_u" => &[0x88],
+        "i32.wrap_i64" => &[0xa7],
+        "i64.extend_i32_s" => &[0xac],
+        "i64.extend_i32_u" => &[0xad],
+        _ => return None,
+    })
+}
+
+/// The opcode of a load or store along with its natural alignment.
+fn memory_opcode(op: &str) -> Option<(u8, u64)> {
+    Some(match op {
+        "i32.load" => (0x28, 2),
+        "i64.load" => (0x29, 3),
+        "i32.load8_u" => (0x2d, 0),
+        "i32.store" => (0x36, 2),
+        "i64.store" => (0x37, 3),
+        "i32.store8" => (0x3a, 0),
+        _ => return None,
+    })
+}
+
+fn section(
+    out: &mut Vec<u8>,
+    id: u8,
+    count: usize,
+    f: impl FnOnce(&mut Vec<u8>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    if count == 0 {
+        return Ok(());
+    }
+
+    let mut body = Vec::new();
+    uleb(&mut body, count as u64);
+    f(&mut body)?;
+
+    out.push(id);
+    uleb(out, body.len() as u64);
+    out.extend(body);
+
+    Ok(())
+}
+
+fn limits(out: &mut Vec<u8>, min: u32, max: Option<u32>) {
+    match max {
+        Some(max) => {
+            out.push(0x01);
+            uleb(out, min as u64);
+            uleb(out, max as u64);
+        }
+        None => {
+            out.push(0x00);
+            uleb(out, min as u64);
+        }
+    }
+}
+
+fn name(out: &mut Vec<u8>, s: &str) {
+    uleb(out, s.len() as u64);
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn uleb(out: &mut Vec<u8>, mut v: u64) {
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+
+        if v == 0 {
+            out.push(byte);
+            return;
+        }
+
+        out.push(byte | 0x80);
+    }
+}
+
+fn sleb(out: &mut Vec<u8>, mut v: i64) {
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+
+        if (v == 0 && byte & 0x40 == 0) || (v == -1 && byte & 0x40 != 0) {
+            out.push(byte);
+            return;
+        }
+
+        out.push(byte | 0x80);
+    }
+}
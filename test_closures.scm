@@ -0,0 +1,28 @@
+(define f
+  (lambda (a b c d e)
+    (let ((g (lambda (x) (+ x (+ a (+ b (+ c (+ d e))))))))
+      (+ (g a) (+ (g b) (* a (+ b (+ c (+ d (+ e (g c)))))))))))
+(display (f 1 2 3 4 5))
+
+(define counter
+  (lambda (a b c d)
+    (let ((n 0))
+      (let ((bump (lambda () (set! n (+ n (+ a (+ b (+ c d))))) n)))
+        (let ((_ (bump)))
+          (let ((_ (bump)))
+            (+ n (+ (bump) (+ a (+ b (+ c d)))))))))))
+(display (counter 1 2 3 4))
+
+(define nest
+  (lambda (a b c d e)
+    (+ a (+ (* b (+ c (* d (+ e (f a b c d e)))))
+            (+ a (+ b (+ c (+ d (+ e (* a (+ b (+ c (+ d e)))))))))))))
+(display (nest 1 2 3 4 5))
+(display (nest 5 4 3 2 1))
+
+(define loop
+  (lambda (i a b c d acc)
+    (if (= i 0)
+      acc
+      (loop (- i 1) a b c d (+ acc (+ i (+ a (+ b (+ c (+ d (f a b c d i)))))))))))
+(display (loop 100000 1 2 3 4 0))
@@ -0,0 +1,22 @@
+(define s "hello world")
+
+(display (string-length s))
+(display (string-length ""))
+(display (string-ref s 4))
+(display (substring s 6 11))
+(display (substring s 3 3))
+(display (string-append "foo" "bar"))
+(display (string=? s (string-append "hello " "world")))
+(display (string=? s "hello"))
+(display (string->number "42"))
+(display (string->number "-17"))
+(display (string->number "6/8"))
+(display (string->number "2.5e3"))
+(display (string->number ".5"))
+(display (string->number "abc"))
+(display (string->number "1/0"))
+(display (number->string 123))
+(display (number->string 1/3))
+(display (number->string 0.25))
+(display (car (string->list "ab")))
+(display (cdr (cdr (string->list "ab"))))
@@ -0,0 +1,834 @@
+//! Uses the compiler as a library, the way a host program embedding it would.
+
+use some_scheme_compiler::bench;
+use some_scheme_compiler::bytecode::{self, Constant};
+use some_scheme_compiler::cdsl::{CExpr, CStmt, ToC};
+use some_scheme_compiler::compiler::{Compiler, GeneratedC, PassObserver, Stage};
+use some_scheme_compiler::cont_expr::AExp;
+use some_scheme_compiler::features::Features;
+use some_scheme_compiler::include;
+use some_scheme_compiler::interp::Value;
+use some_scheme_compiler::json::{FromJson, Json, ToJson};
+use some_scheme_compiler::lifted_expr::Program;
+use some_scheme_compiler::lint::{self, Level, Lint, Lints, Resolution};
+use some_scheme_compiler::lsp;
+use some_scheme_compiler::profile;
+use some_scheme_compiler::target::{Endian, TargetConfig};
+use some_scheme_compiler::timing::PassTimes;
+
+use moniker::{BoundTerm, Ignore};
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn compile_to_c() {
+    let c = Compiler::new()
+        .source("(define sq (lambda (x) (* x x))) (display (sq 4))")
+        .optimize(2)
+        .compile_to_c()
+        .unwrap();
+
+    assert!(c.contains("void scheme_entry(void)"));
+    assert!(c.contains("scm_sq"));
+}
+
+#[test]
+fn cross_target() {
+    let arm = TargetConfig::from_triple("armv7-unknown-linux-gnueabihf").unwrap();
+    assert_eq!((arm.word_bits, arm.endian), (32, Endian::Little));
+    assert!(arm.fixnum_fits(-(1 << 30)) && !arm.fixnum_fits(1 << 30));
+    assert_eq!(
+        TargetConfig::from_triple("s390x-ibm-linux").unwrap().endian,
+        Endian::Big
+    );
+    assert!(TargetConfig::from_triple("avr-unknown-none").is_err());
+
+    let c = |target| {
+        Compiler::new()
+            .source("(display '(1 4000000000))")
+            .target(target)
+            .compile_to_c()
+            .unwrap()
+    };
+
+    let x86_64 = c(TargetConfig::from_triple("x86_64-unknown-linux-gnu").unwrap());
+    assert!(x86_64.contains("MAKE_FIXNUM(4000000000)"));
+
+    let arm = c(arm);
+    assert!(arm.contains("INT_OBJ_INIT(4000000000)"));
+    assert!(arm.contains("_Static_assert(sizeof(void *) * 8 == 32,"));
+}
+
+#[test]
+fn features() {
+    let arm = TargetConfig::from_triple("armv7-unknown-linux-gnueabihf").unwrap();
+    let features = Features::new("c", &arm);
+
+    for name in &["r7rs", "c", "armv7", "posix", "linux", "ilp32", "little-endian"] {
+        assert!(features.has(name), "{:?}", features);
+    }
+    assert!(!features.has("lp64"));
+
+    let source = "(display (cond-expand ((and debug (not wasm32)) 1) (else 2)))";
+    let c = |compiler: Compiler| compiler.source(source).compile_to_c().unwrap();
+
+    assert!(c(Compiler::new()).contains("MAKE_FIXNUM(2)"));
+    assert!(c(Compiler::new().feature("debug")).contains("MAKE_FIXNUM(1)"));
+
+    let mut out = Vec::new();
+    Compiler::new()
+        .source("(display (features))")
+        .feature("debug")
+        .eval(&mut out)
+        .unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with("(r7rs ratios some-scheme interpreter "), "{}", out);
+    assert!(out.trim_end().ends_with(" debug)"), "{}", out);
+}
+
+#[test]
+fn inlining() {
+    let source = "(let ((f (lambda (x) (tostring x)))) (f 1) (f 2))";
+    let functions = |threshold| {
+        Compiler::new()
+            .source(source)
+            .inline_threshold(threshold)
+            .compile_to_c()
+            .unwrap()
+            .matches("static void scm_")
+            .count()
+    };
+
+    assert!(functions(12) < functions(0));
+}
+
+#[test]
+fn boxing() {
+    let cells = |source| {
+        Compiler::new()
+            .source(source)
+            .compile_to_c()
+            .unwrap()
+            .matches("OBJECT_CELL_OBJ_NEW")
+            .count()
+    };
+
+    assert_eq!(cells("(display ((lambda (x) (lambda (y) (+ x y))) 1))"), 0);
+    assert!(cells("(display ((lambda (x) (lambda () (set! x 2))) 1))") > 0);
+}
+
+#[test]
+fn environments() {
+    let envs = |source| {
+        Compiler::new()
+            .source(source)
+            .compile_to_c()
+            .unwrap()
+            .matches("OBJECT_ENV_OBJ_NEW")
+            .count()
+    };
+
+    assert_eq!(envs("(tostring (lambda (x) x))"), 0);
+    assert!(envs("(define f (lambda (x) (lambda () x))) (display (f 1))") > 0);
+}
+
+#[test]
+fn static_closures() {
+    let c = Compiler::new()
+        .source("(define f (lambda (_x) (lambda (y) y))) (display (eq? (f 1) (f 2)))")
+        .inline_threshold(0)
+        .compile_to_c()
+        .unwrap();
+
+    assert!(c.contains("scm_f_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_f_lambda_1)"));
+    assert!(!c.contains("OBJECT_CLOSURE_TWO_NEW(var_0,scm_f_lambda_1,"));
+}
+
+#[test]
+fn common_subexpressions() {
+    let c = Compiler::new()
+        .source("(define f (lambda (x) (if x (display 2.5) (display 2.5)))) (f 1)")
+        .compile_to_c()
+        .unwrap();
+
+    assert_eq!(c.matches("OBJECT_FLOAT_OBJ_NEW").count(), 1);
+    assert_eq!(c.matches(",display_k,").count(), 1);
+}
+
+#[test]
+fn self_calls() {
+    let c = |source| Compiler::new().source(source).compile_to_c().unwrap();
+
+    assert!(c("(define (f x) (if x (f #f) 7)) (display (f 1))").contains("goto self_call"));
+    assert!(
+        c("(define (f x) (if (= x 0) x (f (- x 1)))) (display (f 3))")
+            .contains("if (stack_check()){scm_f(")
+    );
+    assert!(!c("(define (f x) (if x (f #f) 7)) (set! f tostring) (f 1)").contains("stack_check"));
+}
+
+#[test]
+fn unboxed_integers() {
+    let source = "(define (sum i acc) (if (< i 10) (sum (+ i 1) (+ acc i)) acc)) \
+                  (define (g x) (+ x 1)) \
+                  (display (sum 0 0)) (display (g 1.5))";
+    let c = |level| {
+        Compiler::new()
+            .source(source)
+            .optimize(level)
+            .compile_to_c()
+            .unwrap()
+    };
+
+    let optimized = c(2);
+    assert_eq!(optimized.matches("INT_ARITH_OBJ_NEW(").count(), 2);
+    assert!(optimized.contains("MAKE_BOOL((obj_int_val("));
+    assert!(!c(1).contains("INT_ARITH_OBJ_NEW("));
+}
+
+#[test]
+fn pretty_c() {
+    let bin = |op: &'static str, left, right| CExpr::BinOp {
+        op: op.into(),
+        left: Arc::new(left),
+        right: Arc::new(right),
+    };
+    let ident = |name: &'static str| CExpr::Ident(name.into());
+
+    let sum = || bin("+", ident("a"), ident("b"));
+    assert_eq!(bin("*", sum(), ident("c")).pretty(), "(a + b) * c");
+    assert_eq!(bin("-", ident("c"), sum()).pretty(), "c - (a + b)");
+    assert_eq!(
+        bin("+", sum(), bin("*", ident("c"), ident("d"))).pretty(),
+        "a + b + c * d"
+    );
+    assert_eq!(
+        bin("=", ident("x"), bin("=", ident("y"), sum())).pretty(),
+        "x = y = a + b"
+    );
+
+    let stmt = CStmt::Switch {
+        cond: ident("n"),
+        cases: vec![(
+            CExpr::LitUInt(0),
+            vec![Arc::new(CStmt::Return(Some(sum()))), Arc::new(CStmt::Break)],
+        )],
+        default: Some(vec![Arc::new(CStmt::If {
+            cond: ident("x"),
+            ift: Arc::new(CStmt::Block(vec![Arc::new(CStmt::Return(None))])),
+            iff: Arc::new(CStmt::Block(vec![])),
+        })]),
+    };
+    assert_eq!(
+        stmt.pretty(),
+        "switch (n) {\ncase 0:\n  return a + b;\n  break;\ndefault:\n  if (x) {\n    return;\n  }\n}"
+    );
+
+    let c = Compiler::new()
+        .source("(define (f x) (if (= x 0) x (f (- x 1)))) (display (f 3))")
+        .pretty(true)
+        .compile_to_c()
+        .unwrap();
+    assert!(c.contains("\n  if (stack_check()) {\n    scm_f("));
+}
+
+#[test]
+fn parallel_codegen() {
+    let source = (0..40)
+        .map(|i| {
+            format!(
+                "(define f{0} (lambda (x) (cons x 'f{0}))) (display (f{0} {0}))",
+                i
+            )
+        })
+        .collect::<String>();
+    let compile = |jobs| {
+        Compiler::new()
+            .source(source.as_str())
+            .inline_threshold(0)
+            .jobs(jobs)
+            .compile_to_c()
+            .unwrap()
+    };
+
+    let c = compile(1);
+    assert_eq!(c, compile(4));
+    assert_eq!(c.matches("SYMBOL_OBJ_INIT(\"f7\")").count(), 1);
+}
+
+#[test]
+fn deep_programs() {
+    // both overflowed the stack of the main thread before the compiler ran on
+    // its own
+    let long_body = "(display 1) ".repeat(1000);
+    let calls = format!(
+        "(define f (lambda (x) (+ x 1))) (display {}0{})",
+        "(f ".repeat(900),
+        ")".repeat(900)
+    );
+
+    for source in &[long_body, calls] {
+        let c = Compiler::new()
+            .source(source.as_str())
+            .compile_to_c()
+            .unwrap();
+        assert!(c.contains("void scheme_entry(void)"));
+    }
+}
+
+#[test]
+fn large_programs() {
+    // each pass took time quadratic in the length of the body, and ran out
+    // of memory well before this
+    let compiler = Compiler::new().source("(display 1) ".repeat(10_000));
+
+    assert!(compiler.lift().unwrap().size() > 100_000);
+    assert!(compiler
+        .compile_to_c()
+        .unwrap()
+        .contains("void scheme_entry(void)"));
+}
+
+#[test]
+fn large_hash_tables() {
+    // each insert and lookup compared the key with every entry before it
+    let source = "(define h (make-hash-table))
+                  (define (fill i)
+                    (if (< i 20000) (let () (hash-table-set! h (cons i \"k\") i) (fill (+ i 1)))))
+                  (define (sum i acc)
+                    (if (< i 20000) (sum (+ i 1) (+ acc (hash-table-ref h (cons i \"k\")))) acc))
+                  (fill 0)
+                  (hash-table-set! h (cons 0 \"k\") 20000)
+                  (sum 0 0)";
+    let value = Compiler::new()
+        .source(source)
+        .eval(&mut Vec::new())
+        .unwrap();
+
+    assert!(matches!(value, Value::Int(200_010_000)), "{}", value);
+}
+
+#[test]
+fn profile() {
+    let c = Compiler::new()
+        .source("(define f (lambda (x) (display x))) (f 1)")
+        .profile(true)
+        .compile_to_c()
+        .unwrap();
+
+    assert!(c.contains("struct profile_entry scheme_profile[]"));
+    assert!(c.contains("(((scheme_profile)[0]).count)++"));
+
+    let entries = profile::parse("3\tscm_f\tf.scm:1\n9\tscm_g\tf.scm:2\n").unwrap();
+    let report = profile::report(entries, 1);
+
+    assert!(report.contains("scm_g"));
+    assert!(!report.contains("scm_f"));
+    assert!(report.ends_with("12 calls in total\n"));
+}
+
+#[test]
+fn checked() {
+    let c = Compiler::new()
+        .source("(define f (lambda (x) (car x))) (f 1)")
+        .checked(true)
+        .compile_to_c()
+        .unwrap();
+
+    assert!(c.contains("__attribute__((weak))"));
+    assert!(c.contains("(scheme_blame)=(\"scm_"));
+}
+
+#[test]
+fn trace() {
+    let c = Compiler::new()
+        .source("(define (f n) (if (= n 0) n (f (- n 1)))) (display (f 3))")
+        .trace(true)
+        .compile_to_c()
+        .unwrap();
+
+    assert!(c.contains("trace_call(\"scm_f\","));
+    // the continuation of a call of one is NULL
+    assert!(c.contains(",NULL);"));
+}
+
+#[test]
+fn lifted_program() {
+    let program = Compiler::new()
+        .source("(define (f n) (if (= n 0) n (f (- n 1)))) (display (f 3))")
+        .inline_threshold(0)
+        .lift()
+        .unwrap();
+
+    let f = program
+        .lambdas()
+        .values()
+        .find(|l| l.name == "scm_f")
+        .unwrap();
+    assert!(program.lambda(f.id).is_some());
+    // f calls itself through the variable it is bound to
+    let captures = program.captures(f.id);
+    assert_eq!(captures.len(), 1);
+    assert_eq!(captures[0].pretty_name.as_deref(), Some("f"));
+
+    // made by the lambda of the toplevel, and called again by the
+    // continuation of the subtraction in its body
+    let callers = program.callers_of(f.id);
+    assert_eq!(callers.len(), 2, "{:?}", callers);
+    assert!(callers.contains(&f.parent));
+    assert!(callers
+        .iter()
+        .flatten()
+        .any(|c| program.lambda(*c).unwrap().name.starts_with("scm_f_lambda")));
+}
+
+#[test]
+fn pass_observer() {
+    #[derive(Default)]
+    struct Passes(Vec<&'static str>);
+
+    impl PassObserver for Passes {
+        fn start(&mut self, pass: &'static str) {
+            self.0.push(pass);
+        }
+
+        fn finish(&mut self, stage: Stage<'_>) {
+            assert_eq!(self.0.last(), Some(&stage.pass()));
+            assert!(stage.size() > 0);
+        }
+    }
+
+    let compiler = Compiler::new()
+        .source("(define (f x) (+ x 1)) (display (f 2))")
+        .optimize(2);
+    let passes = |backend| {
+        let mut passes = Passes::default();
+        compiler
+            .lift_expr(compiler.bind().unwrap(), backend, &mut passes)
+            .unwrap();
+        passes.0
+    };
+
+    let c = passes("c");
+    assert_eq!(
+        c,
+        [
+            "partial-eval",
+            "cps",
+            "shrink",
+            "unbox",
+            "lift",
+            "inline",
+            "dead-lambdas"
+        ]
+    );
+    // only the C does arithmetic on unboxed integers
+    assert!(!passes("wasm32").contains(&"unbox"));
+}
+
+#[test]
+fn pass_times() {
+    let mut times = PassTimes::default();
+
+    // two inputs going through the same passes
+    for nodes in [10, 5] {
+        times.add_time("cps", Duration::from_millis(2));
+        times.add_nodes("cps", nodes);
+        times.add_time("shrink", Duration::from_millis(1));
+        times.add_nodes("shrink", nodes - 4);
+        times.add_time("cc", Duration::from_millis(5));
+    }
+
+    let passes = times.passes();
+    assert_eq!(passes.len(), 3);
+    assert_eq!(passes[0].time, Duration::from_millis(4));
+    assert_eq!(passes[0].nodes, Some(15));
+    assert_eq!(passes[2].nodes, None);
+
+    let report = times.report();
+    assert!(report
+        .lines()
+        .any(|l| l.starts_with("shrink") && l.ends_with("-8")));
+    assert!(report.ends_with("total     16.000ms\n"), "{}", report);
+}
+
+#[test]
+fn bench_report() {
+    let run = |benchmark: &str, opt_level, size, runtime| bench::Run {
+        benchmark: benchmark.to_owned(),
+        opt_level,
+        compile_time: Duration::from_millis(100),
+        size,
+        runtime: Duration::from_millis(runtime),
+    };
+
+    let report = bench::report(&[
+        run("fib", 0, 1000, 300),
+        run("fib", 2, 900, 100),
+        run("tak", 0, 2000, 50),
+    ]);
+    let lines = report.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 4);
+    // each level is compared with the first of its benchmark
+    assert!(lines[2].starts_with("fib") && lines[2].ends_with("0.90x     100.0ms   3.00x"));
+    assert!(lines[3].ends_with("1.00x      50.0ms   1.00x"), "{}", report);
+}
+
+#[test]
+fn included_files() {
+    let path = Path::new("tests/golden/include.scm");
+    let source = std::fs::read_to_string(path).unwrap();
+
+    // square.scm is included twice but watched once
+    assert_eq!(
+        include::included_files(&source, Some(path)),
+        [
+            Path::new("tests/golden/included/shapes.scm"),
+            Path::new("tests/golden/included/square.scm"),
+        ]
+    );
+
+    // whichever clause is chosen, and whether or not it exists yet
+    let files = include::included_files(
+        "(cond-expand (c (include \"a.scm\")) (else (include \"b.scm\")))",
+        None,
+    );
+    assert_eq!(files, [Path::new("a.scm"), Path::new("b.scm")]);
+}
+
+#[test]
+fn lints() {
+    let source = "(define f (lambda (x y _z) (let ((x 1)) 2 x)))\n(display (car 1 2))\n";
+
+    let mut lints = Lints::default();
+    let warnings = lint::check(source, None, &lints)
+        .into_iter()
+        .map(|w| (w.lint, w.line))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        [
+            (Lint::UnusedVariable, 1),
+            (Lint::UnusedVariable, 1),
+            (Lint::DiscardedValue, 1),
+            (Lint::BuiltinArity, 2),
+        ]
+    );
+
+    lints.set("all", Level::Allow).unwrap();
+    lints.set("shadowed-binding", Level::Deny).unwrap();
+    let warnings = lint::check(source, None, &lints);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].level, Level::Deny);
+    assert!(warnings[0].to_string().contains("x shadows"));
+
+    assert!(lints.set("unused", Level::Warn).is_err());
+}
+
+#[test]
+fn references() {
+    let source = "(define (f x) (let ((y x)) (car y)))\n(f z)";
+    let references = lint::references(source)
+        .into_iter()
+        .map(|r| (r.name, r.resolution))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        references,
+        [
+            ("f".to_owned(), Resolution::Global(9..10)),
+            ("x".to_owned(), Resolution::Local(11..12)),
+            ("y".to_owned(), Resolution::Local(21..22)),
+            ("x".to_owned(), Resolution::Local(11..12)),
+            ("car".to_owned(), Resolution::Builtin),
+            ("y".to_owned(), Resolution::Local(21..22)),
+            ("f".to_owned(), Resolution::Global(9..10)),
+            ("z".to_owned(), Resolution::Free),
+        ]
+    );
+}
+
+#[test]
+fn json() {
+    let text = r#"{"a": [1, -2.5e1, true, null], "b": "\u00e9\ud83d\ude00\n"}"#;
+    let value = Json::parse(text).unwrap();
+
+    assert_eq!(value.get("b").and_then(Json::as_str), Some("é😀\n"));
+    assert_eq!(
+        value.to_string(),
+        r#"{"a":[1,-25.0,true,null],"b":"é😀\n"}"#
+    );
+
+    for bad in ["", "[1,", "{\"a\" 1}", "\"\\ud83d\"", "1 2"] {
+        assert!(Json::parse(bad).is_err(), "{}", bad);
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let source = "(define (f x) (if (< x 2) '(1 #t) (cons \"big\" (* x 2.5)))) (display (f 1/2))";
+    let compiler = Compiler::new().source(source);
+
+    // read back from the text, as a tool given a dump would
+    fn round_trip<T: ToJson + FromJson>(ir: &T) -> T {
+        T::from_json(&Json::parse(&ir.to_json().to_string()).unwrap()).unwrap()
+    }
+
+    // variables are made afresh, with the names they were written with
+    let without_ids = |ir: &dyn ToJson| {
+        let mut text = ir.to_json().to_string();
+        while let Some(at) = text.find('$') {
+            let end = text[at + 1..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(text.len(), |end| at + 1 + end);
+            text.replace_range(at..end, "#");
+        }
+        text
+    };
+
+    let parsed = compiler.parse().unwrap();
+    assert_eq!(without_ids(&round_trip(&parsed)), without_ids(&parsed));
+
+    let bound = compiler.bind().unwrap();
+    let read = round_trip(&bound);
+    assert!(read.term_eq(&bound));
+    assert_eq!(without_ids(&read), without_ids(&bound));
+
+    let cps = bound.into_fexpr(Rc::new(AExp::BuiltinIdent(Ignore("halt".into()))));
+    let read = round_trip(&cps);
+    assert!(read.term_eq(&cps));
+    assert_eq!(without_ids(&read), without_ids(&cps));
+
+    let program = compiler.lift().unwrap();
+    let c = |program: &Program| {
+        let target = TargetConfig::from_triple("x86_64-unknown-linux-gnu").unwrap();
+        GeneratedC::new(program, "<string>", None, &HashMap::new(), &target, 1)
+    };
+    let generated = c(&program);
+    assert_eq!(c(&round_trip(&program)).export(), generated.export());
+
+    for decl in generated.decls.iter().chain(&generated.protos) {
+        assert_eq!(round_trip(decl).export(), decl.export());
+    }
+    assert_eq!(
+        round_trip(&generated.main).export(),
+        generated.main.export()
+    );
+
+    assert!(Program::from_json(&Json::parse(r#"{"main": {"kind": "nope"}}"#).unwrap()).is_err());
+}
+
+#[test]
+fn language_server() {
+    let source = "(define (f x)\n  (+ x y))\n(display (f 1))";
+    let messages = [
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#.to_owned(),
+        format!(
+            r#"{{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{{"textDocument":{{"uri":"file:///a.scm","text":{}}}}}}}"#,
+            Json::str(source)
+        ),
+        r#"{"jsonrpc":"2.0","id":2,"method":"textDocument/definition","params":{"textDocument":{"uri":"file:///a.scm"},"position":{"line":1,"character":6}}}"#.to_owned(),
+        r#"{"jsonrpc":"2.0","id":3,"method":"textDocument/hover","params":{"textDocument":{"uri":"file:///a.scm"},"position":{"line":2,"character":11}}}"#.to_owned(),
+        r#"{"jsonrpc":"2.0","id":4,"method":"shutdown"}"#.to_owned(),
+        r#"{"jsonrpc":"2.0","method":"exit"}"#.to_owned(),
+    ];
+    let input = messages
+        .iter()
+        .map(|m| format!("Content-Length: {}\r\n\r\n{}", m.len(), m))
+        .collect::<String>();
+
+    let mut output = Vec::new();
+    assert!(lsp::serve(input.as_bytes(), &mut output).unwrap());
+
+    let output = String::from_utf8(output).unwrap();
+    let replies = output
+        .split("Content-Length: ")
+        .skip(1)
+        .map(|m| Json::parse(m.split_once("\r\n\r\n").unwrap().1).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(replies.len(), 5);
+
+    // y is unbound, pointed at where it is used
+    let diagnostics = replies[1].get("params").unwrap().get("diagnostics").unwrap();
+    assert_eq!(
+        diagnostics.to_string(),
+        r#"[{"range":{"start":{"line":1,"character":7},"end":{"line":1,"character":8}},"severity":1,"source":"some-scheme","message":"unbound variable: y"}]"#
+    );
+
+    let range = replies[2].get("result").unwrap().get("range").unwrap();
+    assert_eq!(
+        range.to_string(),
+        r#"{"start":{"line":0,"character":11},"end":{"line":0,"character":12}}"#
+    );
+
+    let hover = replies[3].get("result").unwrap().get("contents").unwrap();
+    assert_eq!(
+        hover.get("value").and_then(Json::as_str),
+        Some("global f, defined on line 1")
+    );
+
+    // a document that isn't a program is reported where it goes wrong
+    let diagnostics = lsp::diagnostics("(define x 1)\n(display x", None);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].span, 23..23);
+}
+
+#[test]
+fn language_server_bad_messages() {
+    let request = r#"{"jsonrpc":"2.0","id":1,"method":"shutdown"}"#;
+    let input = format!(
+        "Content-Length: 8\r\n\r\n{{\"id\": 1\
+         Content-Length: x\r\n\r\n{}\
+         Content-Length: {}\r\n\r\n{}",
+        request,
+        request.len(),
+        request
+    );
+
+    let mut output = Vec::new();
+    // the input ends without an exit
+    assert!(!lsp::serve(input.as_bytes(), &mut output).unwrap());
+
+    let output = String::from_utf8(output).unwrap();
+    let replies = output
+        .split("Content-Length: ")
+        .skip(1)
+        .map(|m| Json::parse(m.split_once("\r\n\r\n").unwrap().1).unwrap())
+        .collect::<Vec<_>>();
+
+    // the invalid json, then the length that isn't a number, and then the
+    // request after them is still answered
+    assert_eq!(replies.len(), 3);
+    for reply in &replies[..2] {
+        assert_eq!(reply.get("id").unwrap().to_string(), "null");
+        assert_eq!(
+            reply.get("error").unwrap().get("code").unwrap().as_i64(),
+            Some(-32700)
+        );
+    }
+    assert_eq!(
+        replies[2].to_string(),
+        r#"{"jsonrpc":"2.0","id":1,"result":null}"#
+    );
+}
+
+#[test]
+fn eval() {
+    let mut out = Vec::new();
+    let value = Compiler::new()
+        .source("(display \"hi\") (+ 1 2)")
+        .eval(&mut out)
+        .unwrap();
+
+    assert!(matches!(value, Value::Int(3)));
+    assert_eq!(String::from_utf8(out).unwrap(), "hi");
+}
+
+#[test]
+fn errors() {
+    assert!(Compiler::new().source("(").compile_to_c().is_err());
+    assert!(Compiler::new().source("x").compile_to_c().is_err());
+    assert!(Compiler::new()
+        .source("(display -9223372036854775809)")
+        .compile_to_c()
+        .is_err());
+    assert!(Compiler::new()
+        .source("(car 1)")
+        .eval(&mut Vec::new())
+        .is_err());
+
+    // found while binding the program
+    for (source, message) in [
+        (
+            "(define f (case-lambda ((x) x))) (f 1 2)",
+            "no clause of case-lambda f takes 2 arguments",
+        ),
+        (
+            "(define (f) (define a (g)) (define (g) 1) a) (f)",
+            "g is used before it is defined",
+        ),
+    ] {
+        match Compiler::new().source(source).compile_to_c() {
+            Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            Ok(_) => panic!("compiled {}", source),
+        }
+    }
+
+    for (source, message) in [
+        (
+            "(eval '('lambda 'x) (interaction-environment))",
+            "eval: bad syntax: (lambda x)",
+        ),
+        (
+            "(eval 'nowhere (interaction-environment))",
+            "unbound variable: nowhere",
+        ),
+        ("(eval 1 2)", "Argument to eval was not an environment"),
+        ("(yield)", "yield needs the continuation of the call"),
+        ("(parameterize ((1 2)) 3)", "parameterize: 1 is not a parameter"),
+        (
+            "(define (twice f) (f 1 2)) (twice (case-lambda ((x) x)))",
+            "no clause of the case-lambda takes 2 arguments",
+        ),
+        // integers are 64 bits, there are no bignums
+        ("(* 3037000500 3037000500)", "Integer overflow in binary mul"),
+        (
+            "(make-vector -1 0)",
+            "Length given to make-vector was not a non negative integer",
+        ),
+    ] {
+        match Compiler::new().source(source).eval(&mut Vec::new()) {
+            Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            Ok(_) => panic!("evaluated {}", source),
+        }
+    }
+
+    // stopped before it overflows the stack of the test's thread
+    match Compiler::new()
+        .source("(define f (lambda (n) (+ 1 (f n)))) (f 0)")
+        .eval(&mut Vec::new())
+    {
+        Err(e) => assert!(e.to_string().contains("recursion too deep"), "{}", e),
+        Ok(_) => panic!("the recursion ended"),
+    }
+}
+
+#[test]
+fn corrupted_bytecode() {
+    let lifted = Compiler::new()
+        .source("(display (car '(1 2)))")
+        .lift()
+        .unwrap();
+    let program = bytecode::compile(&lifted).unwrap();
+    let bytes = program.to_bytes();
+    assert_eq!(bytecode::Program::from_bytes(&bytes).unwrap(), program);
+
+    let mut huge = program.clone();
+    huge.lambdas[0].slots = 0xffff_fff0;
+    let mut unknown = program.clone();
+    unknown.constants.push(Constant::Builtin("nowhere".into()));
+    // a list of more constants than there are bytes left, its count after
+    // the header, the count of constants and its kind
+    let mut counted = program.clone();
+    counted.constants = vec![Constant::List(vec![])];
+    let mut counted = counted.to_bytes();
+    counted[11..15].copy_from_slice(&u32::MAX.to_le_bytes());
+    let truncated = &bytes[..bytes.len() - 1];
+
+    for (bytes, message) in [
+        (huge.to_bytes(), "more slots than the program fills"),
+        (unknown.to_bytes(), "unknown builtin: nowhere"),
+        (counted, "unexpected end of the bytecode"),
+        (truncated.to_vec(), "unexpected end of the bytecode"),
+    ] {
+        match bytecode::Program::from_bytes(&bytes) {
+            Err(e) => assert!(e.to_string().contains(message), "{}", e),
+            Ok(_) => panic!("loaded a corrupted program"),
+        }
+    }
+}
@@ -0,0 +1,925 @@
+//! Compiles each program in `tests/golden` through the full pipeline, runs the
+//! binary and compares its stdout with the `.expected` file next to it.
+//!
+//! The C each program is compiled to is compared with its snapshot in
+//! `tests/golden/c`, generated for `SNAPSHOT_TRIPLE` so that it is the same on
+//! every machine. Run with `GOLDEN_BLESS=1` to write the current output and C
+//! to the `.expected` files and snapshots instead of comparing.
+//!
+//! The files in `tests/golden/separate` are compiled on their own and linked
+//! into one program, in name order. The files in `tests/golden/included` are
+//! only included by the other programs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const COMPILER: &str = env!("CARGO_BIN_EXE_some-scheme-compiler");
+
+/// The machine the snapshots of the C are generated for.
+const SNAPSHOT_TRIPLE: &str = "x86_64-unknown-linux-gnu";
+
+fn corpus() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+
+    let mut programs = fs::read_dir(dir)
+        .expect("missing tests/golden")
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().is_some_and(|e| e == "scm"))
+        .collect::<Vec<_>>();
+    programs.sort();
+    programs
+}
+
+fn stdout_of(cmd: &mut Command) -> Result<String, String> {
+    let output = cmd.output().map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "exited with {}\nstderr: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The C of one of the programs in `tests/golden`, named relative to it so
+/// that the snapshot doesn't depend on where the repository is.
+fn emit_c(program: &Path) -> Result<String, String> {
+    stdout_of(
+        Command::new(COMPILER)
+            .current_dir(program.parent().unwrap())
+            .arg("-i")
+            .arg(program.file_name().unwrap())
+            .args(["--target-triple", SNAPSHOT_TRIPLE, "--emit", "c", "run"]),
+    )
+}
+
+/// Compile and run one program, returning what it printed.
+fn run_program(program: &Path, out_dir: &Path) -> Result<String, String> {
+    let name = program.file_stem().unwrap().to_string_lossy();
+
+    let binary = out_dir.join(&*name);
+    let _ = fs::remove_file(&binary);
+    stdout_of(
+        Command::new(COMPILER)
+            .arg("-i")
+            .arg(program)
+            .arg("compile")
+            .arg("-o")
+            .arg(&binary),
+    )?;
+
+    if !binary.exists() {
+        return Err("the compiler didn't produce a binary".to_owned());
+    }
+
+    stdout_of(&mut Command::new(&binary))
+}
+
+#[test]
+fn golden() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let bless = std::env::var_os("GOLDEN_BLESS").is_some();
+    let mut failures = Vec::new();
+
+    let snapshot_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/c");
+
+    for program in corpus() {
+        let expected_path = program.with_extension("expected");
+        let snapshot_path = snapshot_dir
+            .join(program.file_name().unwrap())
+            .with_extension("c");
+
+        let output = emit_c(&program).and_then(|c| Ok((c, run_program(&program, &out_dir)?)));
+        let (c, actual) = match output {
+            Ok(output) => output,
+            Err(e) => {
+                failures.push(format!("{}: {}", program.display(), e));
+                continue;
+            }
+        };
+
+        if bless {
+            fs::create_dir_all(&snapshot_dir).unwrap();
+            fs::write(&snapshot_path, c).unwrap();
+            fs::write(&expected_path, actual).unwrap();
+            continue;
+        }
+
+        let snapshot = fs::read_to_string(&snapshot_path).unwrap_or_default();
+
+        if c != snapshot {
+            failures.push(format!(
+                "{}: C differs from {}, run with GOLDEN_BLESS=1 if intended",
+                program.display(),
+                snapshot_path.display()
+            ));
+        }
+
+        let expected = fs::read_to_string(&expected_path).unwrap_or_default();
+
+        if actual != expected {
+            failures.push(format!(
+                "{}: output differs\n--- expected\n{}--- actual\n{}",
+                program.display(),
+                expected,
+                actual
+            ));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "{} golden programs failed:\n\n{}",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+}
+
+#[test]
+fn separate_compilation() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/separate");
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("separate");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let mut inputs = fs::read_dir(&dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().is_some_and(|e| e == "scm"))
+        .collect::<Vec<_>>();
+    inputs.sort();
+
+    let mut compile = Command::new(COMPILER);
+    for input in &inputs {
+        compile.arg("-i").arg(input);
+    }
+
+    let binary = out_dir.join("program");
+    let _ = fs::remove_file(&binary);
+    stdout_of(compile.arg("compile").arg("-o").arg(&binary)).unwrap();
+
+    let actual = stdout_of(&mut Command::new(&binary)).unwrap();
+    let expected_path = dir.join("program.expected");
+
+    if std::env::var_os("GOLDEN_BLESS").is_some() {
+        fs::write(&expected_path, actual).unwrap();
+        return;
+    }
+
+    assert_eq!(actual, fs::read_to_string(&expected_path).unwrap());
+}
+
+#[test]
+fn separate_compilation_cache() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/separate");
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("separate_cache");
+    let cache_dir = out_dir.join("cache");
+    let _ = fs::remove_dir_all(&out_dir);
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let lib = out_dir.join("1_lib.scm");
+    let app = out_dir.join("2_app.scm");
+    fs::copy(dir.join("1_lib.scm"), &lib).unwrap();
+    fs::copy(dir.join("2_app.scm"), &app).unwrap();
+
+    let run = || {
+        stdout_of(
+            Command::new(COMPILER)
+                .arg("-i")
+                .arg(&lib)
+                .arg("-i")
+                .arg(&app)
+                .arg("--cache-dir")
+                .arg(&cache_dir)
+                .arg("run"),
+        )
+        .unwrap()
+    };
+    let entries = || fs::read_dir(&cache_dir).unwrap().count();
+
+    let expected = fs::read_to_string(dir.join("program.expected")).unwrap();
+
+    // the exports, C and object of each file
+    assert_eq!(run(), expected);
+    assert_eq!(entries(), 6);
+
+    assert_eq!(run(), expected);
+    assert_eq!(entries(), 6);
+
+    // only the changed file is compiled again
+    let mut source = fs::read_to_string(&app).unwrap();
+    source.push_str("(display 7) (newline)\n");
+    fs::write(&app, source).unwrap();
+
+    assert_eq!(run(), format!("{}7\n", expected));
+    assert_eq!(entries(), 9);
+}
+
+#[test]
+fn static_library() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("staticlib");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let program = out_dir.join("prog.scm");
+    fs::write(&program, "(display \"from scheme\")\n(newline)\n").unwrap();
+
+    let _ = fs::remove_file(out_dir.join("libprog.a"));
+    stdout_of(
+        Command::new(COMPILER)
+            .arg("-i")
+            .arg(&program)
+            .args(["compile", "--crate-type", "staticlib", "-o"])
+            .arg(out_dir.join("prog")),
+    )
+    .unwrap();
+
+    assert!(out_dir.join("libprog.a").exists());
+    assert!(out_dir.join("prog.h").exists());
+
+    fs::write(
+        out_dir.join("main.c"),
+        "#include <stdio.h>\n\
+         #include \"prog.h\"\n\
+         \n\
+         int main(void) {\n\
+         \x20   printf(\"from c\\n\");\n\
+         \x20   fflush(stdout);\n\
+         \x20   scheme_entry();\n\
+         }\n",
+    )
+    .unwrap();
+
+    let binary = out_dir.join("main");
+    let _ = fs::remove_file(&binary);
+    stdout_of(
+        Command::new("cc")
+            .current_dir(&out_dir)
+            .args(["main.c", "libprog.a", "-lm", "-o"])
+            .arg(&binary),
+    )
+    .unwrap();
+
+    let actual = stdout_of(&mut Command::new(&binary)).unwrap();
+    assert_eq!(actual, "from c\nfrom scheme\n");
+}
+
+#[test]
+fn deterministic_output() {
+    for program in corpus() {
+        for args in [&["--emit", "c"][..], &["--target", "llvm", "--emit", "ll"]] {
+            // some programs use what the llvm target can't compile, its
+            // errors have to be the same too
+            let compile = || {
+                stdout_of(
+                    Command::new(COMPILER)
+                        .arg("-i")
+                        .arg(&program)
+                        .args(args)
+                        .arg("run"),
+                )
+            };
+
+            assert!(
+                compile() == compile(),
+                "{} {:?} differs between runs",
+                program.display(),
+                args
+            );
+        }
+    }
+}
+
+#[test]
+fn runtime_stats() {
+    let program = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/tail.scm");
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("rt_stats");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let binary = out_dir.join("tail");
+    stdout_of(
+        Command::new(COMPILER)
+            .arg("-i")
+            .arg(&program)
+            .arg("compile")
+            .arg("-o")
+            .arg(&binary),
+    )
+    .unwrap();
+
+    let output = Command::new(&binary)
+        .env("SCHEME_RT_STATS", "1")
+        .output()
+        .unwrap();
+    let stats = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success());
+    assert!(stats.contains("\n  closure "), "{}", stats);
+    assert!(stats.contains("collections"), "{}", stats);
+
+    // and nothing without it
+    let output = Command::new(&binary).output().unwrap();
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn heap_dump() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("heap_dump");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let program = out_dir.join("dump.scm");
+    fs::write(
+        &program,
+        "(define kept (cons 1 (cons \"two\" '())))\n\
+         (define (f x) (lambda (y) (cons x y)))\n\
+         (define g (f kept))\n\
+         (dump-heap (car (cdr (command-line))))\n\
+         (display (g 3))\n\
+         (newline)\n",
+    )
+    .unwrap();
+
+    let binary = out_dir.join("dump");
+    stdout_of(
+        Command::new(COMPILER)
+            .arg("-i")
+            .arg(&program)
+            .arg("compile")
+            .arg("-o")
+            .arg(&binary),
+    )
+    .unwrap();
+
+    let json = out_dir.join("heap.json");
+    let output = stdout_of(Command::new(&binary).arg(&json)).unwrap();
+    assert_eq!(output, "((1 two) . 3)\n");
+
+    let dump = fs::read_to_string(&json).unwrap();
+    assert!(dump.starts_with("{\"objects\": ["), "{}", dump);
+    assert!(dump.contains("\"type\": \"string\""), "{}", dump);
+    assert!(dump.contains("\"lambda\": \"scm_f_lambda"), "{}", dump);
+    assert!(dump.trim_end().ends_with("]}"), "{}", dump);
+
+    let dot = out_dir.join("heap.dot");
+    stdout_of(Command::new(&binary).arg(&dot)).unwrap();
+
+    let dump = fs::read_to_string(&dot).unwrap();
+    assert!(dump.starts_with("digraph heap {"), "{}", dump);
+    assert!(dump.contains("[label=\"cons 24\"]"), "{}", dump);
+    assert!(dump.contains("  roots -> "), "{}", dump);
+}
+
+#[test]
+fn heap_dump_on_signal() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("heap_dump_signal");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let program = out_dir.join("forever.scm");
+    fs::write(&program, "(define (loop n) (loop (+ n 1)))\n(loop 0)\n").unwrap();
+
+    let binary = out_dir.join("forever");
+    stdout_of(
+        Command::new(COMPILER)
+            .arg("-i")
+            .arg(&program)
+            .arg("compile")
+            .arg("-o")
+            .arg(&binary),
+    )
+    .unwrap();
+
+    let dot = out_dir.join("heap.dot");
+    let _ = fs::remove_file(&dot);
+    let mut child = Command::new(&binary)
+        .env("SCHEME_HEAP_DUMP", &dot)
+        .spawn()
+        .unwrap();
+
+    // give it time to install the handler before signalling
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    let status = Command::new("kill")
+        .args(["-USR1", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let mut dump = String::new();
+    for _ in 0..100 {
+        dump = fs::read_to_string(&dot).unwrap_or_default();
+        if dump.ends_with("}\n") {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert!(dump.starts_with("digraph heap {"), "{}", dump);
+    assert!(dump.ends_with("}\n"), "{}", dump);
+}
+
+#[test]
+fn runaway_recursion() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("runaway");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let program = out_dir.join("runaway.scm");
+    fs::write(&program, "(define f (lambda (n) (+ 1 (f n))))\n(f 0)\n").unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("-i")
+        .arg(&program)
+        .arg("interpret")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("recursion too deep, in the lambda on line 1"),
+        "{}",
+        stderr
+    );
+
+    let binary = out_dir.join("runaway");
+    stdout_of(
+        Command::new(COMPILER)
+            .arg("-i")
+            .arg(&program)
+            .arg("compile")
+            .arg("-o")
+            .arg(&binary),
+    )
+    .unwrap();
+
+    let output = Command::new(&binary)
+        .env("SCHEME_MAX_HEAP", "100000")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("recursion too deep"), "{}", stderr);
+    assert!(stderr.contains("runaway.scm:1)"), "{}", stderr);
+}
+
+#[test]
+fn run_with_arguments() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("arguments");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let program = out_dir.join("arguments.scm");
+    fs::write(
+        &program,
+        "(display (cdr (command-line)))\n(newline)\n(exit 3)\n(display 4)\n",
+    )
+    .unwrap();
+
+    for target in ["c", "bytecode"] {
+        let output = Command::new(COMPILER)
+            .args(["--target", target, "run"])
+            .arg(&program)
+            .args(["--", "a", "b c"])
+            .output()
+            .unwrap();
+
+        assert_eq!(output.status.code(), Some(3), "{}", target);
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "(a b c)\n",
+            "{}",
+            target
+        );
+    }
+
+    let output = Command::new(COMPILER)
+        .arg("-i")
+        .arg(&program)
+        .args(["interpret", "--", "a"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "(a)\n");
+}
+
+#[test]
+fn uncaught_errors() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("uncaught");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let program = out_dir.join("uncaught.scm");
+    fs::write(
+        &program,
+        "(display \"before\")\n(error \"boom\")\n(display \"after\")\n",
+    )
+    .unwrap();
+
+    let mut runs = vec![vec!["interpret"]];
+    for target in ["c", "bytecode", "llvm"] {
+        if target == "llvm" && Command::new("llc").arg("--version").output().is_err() {
+            continue;
+        }
+        runs.push(vec!["--target", target, "run"]);
+    }
+
+    for args in runs {
+        let output = Command::new(COMPILER)
+            .arg("-i")
+            .arg(&program)
+            .args(&args)
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success(), "{:?}", args);
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "before",
+            "{:?}",
+            args
+        );
+        assert!(
+            stderr.contains("Uncaught exception: boom\n"),
+            "{:?}: {}",
+            args,
+            stderr
+        );
+    }
+}
+
+#[test]
+fn compile_errors_fail() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("compile_errors");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let unbound = out_dir.join("unbound.scm");
+    fs::write(&unbound, "(display x)\n").unwrap();
+    let unparsed = out_dir.join("unparsed.scm");
+    fs::write(&unparsed, "(display (\n").unwrap();
+    let fine = out_dir.join("fine.scm");
+    fs::write(&fine, "(display 1)\n").unwrap();
+    let lambda = out_dir.join("lambda.scm");
+    fs::write(&lambda, "(display #\\\u{3bb})\n").unwrap();
+
+    for (args, cmds, error) in [
+        // compiled separately
+        (vec![&unbound, &fine], &["run"][..], "unbound variable: x"),
+        (vec![&unparsed], &["run", "interpret"], "expected"),
+        (vec![&unbound], &["run", "interpret"], "unbound variable: x"),
+        (vec![&lambda], &["run", "interpret"], "Non-ASCII character"),
+    ] {
+        for cmd in cmds {
+            let output = Command::new(COMPILER)
+                .args(args.iter().flat_map(|p| ["-i".as_ref(), p.as_os_str()]))
+                .arg(cmd)
+                .output()
+                .unwrap();
+
+            assert_eq!(output.status.code(), Some(1), "{:?} {}", args, cmd);
+            assert!(output.stdout.is_empty());
+            assert!(
+                String::from_utf8_lossy(&output.stderr).contains(error),
+                "{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    let output = Command::new(COMPILER)
+        .args(["--cc", "/nonexistent-cc", "run"])
+        .arg(&fine)
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Make failed"));
+}
+
+#[test]
+fn read_data() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("read");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let data = out_dir.join("data.txt");
+    fs::write(
+        &data,
+        "(1 2.5 -3 1/2 foo \"a\\\"b\") #(#t (2 . 3))\n'sym\n(a . (b c))\n\
+         ; comments #| aren't |# data\n#| nor #| are |# these |# #;(a) #;b\n\
+         (#true #;c #false #x1F ; or this\n |a b| #b-1)",
+    )
+    .unwrap();
+
+    let program = out_dir.join("read.scm");
+    fs::write(
+        &program,
+        format!(
+            "(define p (open-input-file \"{}\"))
+             (define (loop)
+               (let ((d (read p)))
+                 (if (null? d) (display \"done\\n\") (let ((_ (write d)))
+                   (newline)
+                   (loop)))))
+             (loop)\n",
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let expected = "(1 2.5 -3 1/2 foo \"a\\\"b\")\n#(#t (2 . 3))\n(quote sym)\n(a b c)\n\
+                    (#t #f 31 |a b| -1)\ndone\n";
+
+    for target in ["c", "bytecode"] {
+        let output = stdout_of(
+            Command::new(COMPILER)
+                .args(["--target", target, "run"])
+                .arg(&program),
+        );
+        assert_eq!(output.as_deref(), Ok(expected), "{}", target);
+    }
+
+    let output = stdout_of(
+        Command::new(COMPILER)
+            .arg("-i")
+            .arg(&program)
+            .arg("interpret"),
+    );
+    assert_eq!(output.as_deref(), Ok(expected));
+}
+
+/// The runtime's reader and the interpreter's, which the vm uses, read the
+/// same data the same way, and reject the same.
+#[test]
+fn read_conformance() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("read_conformance");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let runs: &[&[&str]] = &[
+        &["--target", "c", "run"],
+        &["--target", "llvm", "run"],
+        &["--target", "bytecode", "run"],
+        &["interpret"],
+    ];
+
+    let read = |name: &str, data: &str| {
+        let path = out_dir.join(format!("{}.txt", name));
+        fs::write(&path, data).unwrap();
+
+        let program = out_dir.join(format!("{}.scm", name));
+        fs::write(
+            &program,
+            format!(
+                "(define p (open-input-file \"{}\"))
+                 (define (bytes v i)
+                   (if (= i (bytevector-length v))
+                       '()
+                       (cons (bytevector-u8-ref v i) (bytes v (+ i 1)))))
+                 (define (loop)
+                   (let ((d (read p)))
+                     (if (null? d) (display \"done\\n\")
+                       (let ((_ (write (if (bytevector? d) (cons 'bytevector (bytes d 0)) d))))
+                         (newline)
+                         (loop)))))
+                 (loop)\n",
+                path.display()
+            ),
+        )
+        .unwrap();
+
+        runs.iter()
+            .map(|args| stdout_of(Command::new(COMPILER).arg("-i").arg(&program).args(*args)))
+            .collect::<Vec<_>>()
+    };
+
+    let outputs = read(
+        "data",
+        "#!fold-case ABC \"Keeps CASE\" |Bar| (#T #X1F)\n\
+         #!no-fold-case Upper\n\
+         \"x\\x41;y\\u00e9\\x1F600;\\\\\\\"\\/\"\n\
+         #u8(1 2 255) #u8() (#u8(0) . x)\n\
+         #!fold-case #U8(3) #!no-fold-case #U8(4)\n\
+         #\\a #\\( #\\space #\\x41 |a b| (#\\) . #\\|)\n\
+         #!fold-case #\\A #\\SPACE #!no-fold-case #\\A\n",
+    );
+    let expected = "abc\n\"Keeps CASE\"\nBar\n(#t 31)\nUpper\n\"xAy\u{e9}\u{1f600}\\\\\\\"/\"\n\
+                    (bytevector 1 2 255)\n(bytevector)\n(bytevector . x)\n(bytevector 3)\n\
+                    |#U8|\n(4)\n97\n40\n32\n65\n|a b|\n(41 . 124)\n97\n32\n65\ndone\n";
+
+    for (args, output) in runs.iter().zip(outputs) {
+        assert_eq!(output.as_deref(), Ok(expected), "{:?}", args);
+    }
+
+    // strings are indexed by byte, so no character literal is above ASCII
+    for (i, bad) in [
+        "#!foo",
+        "\"\\q\"",
+        "\"\\x41\"",
+        "#u8(256)",
+        "#u8(a)",
+        "#\\bogus",
+        "#\\\u{3bb}",
+        "#\\x3bb",
+    ]
+    .iter()
+    .enumerate()
+    {
+        for (args, output) in runs.iter().zip(read(&format!("bad{}", i), bad)) {
+            assert!(output.is_err(), "{:?} read {}: {:?}", args, bad, output);
+        }
+    }
+}
+
+#[test]
+fn denied_lints() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("lints");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let program = out_dir.join("lints.scm");
+    fs::write(&program, "(let ((x 1)) (display 2))\n").unwrap();
+
+    let output = Command::new(COMPILER)
+        .arg("-i")
+        .arg(&program)
+        .arg("interpret")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "2");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("warning: unused variable x"));
+
+    let output = Command::new(COMPILER)
+        .arg("-i")
+        .arg(&program)
+        .args(["--deny", "unused-variable", "interpret"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("error: unused variable x"));
+}
+
+/// Run each program with `--target`, returning what went wrong with those that
+/// don't print their `.expected` file.
+fn failures_on_target(target: &str, programs: &[PathBuf]) -> Vec<String> {
+    failures_running(&["--target", target, "run"], programs)
+}
+
+/// Run each program with the compiler given `args`, returning what went wrong
+/// with those that don't print their `.expected` file.
+fn failures_running(args: &[&str], programs: &[PathBuf]) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    for program in programs {
+        let result = stdout_of(Command::new(COMPILER).arg("-i").arg(program).args(args));
+        let expected = fs::read_to_string(program.with_extension("expected")).unwrap();
+
+        match result {
+            Ok(actual) if actual == expected => {}
+            Ok(actual) => failures.push(format!(
+                "{}: output differs\n--- expected\n{}--- actual\n{}",
+                program.display(),
+                expected,
+                actual
+            )),
+            Err(e) => failures.push(format!("{}: {}", program.display(), e)),
+        }
+    }
+
+    failures
+}
+
+/// The programs that only use what the wasm32 runtime implements.
+const WASM_PROGRAMS: &[&str] = &[
+    "cons", "closures", "define", "if", "let", "output", "set", "shadow", "stuff", "tail",
+];
+
+#[test]
+fn wasm_target() {
+    if Command::new("node").arg("--version").output().is_err() {
+        eprintln!("node isn't installed, skipping the wasm32 programs");
+        return;
+    }
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let programs = WASM_PROGRAMS
+        .iter()
+        .map(|name| dir.join(format!("{}.scm", name)))
+        .collect::<Vec<_>>();
+
+    let failures = failures_on_target("wasm32", &programs);
+
+    if !failures.is_empty() {
+        panic!(
+            "{} wasm32 programs failed:\n\n{}",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+}
+
+/// The llvm target can't call foreign functions.
+const NOT_LLVM_PROGRAMS: &[&str] = &["foreign"];
+
+#[test]
+fn llvm_target() {
+    if Command::new("llc").arg("--version").output().is_err() {
+        eprintln!("llc isn't installed, skipping the llvm programs");
+        return;
+    }
+
+    let programs = corpus()
+        .into_iter()
+        .filter(|p| !NOT_LLVM_PROGRAMS.contains(&&*p.file_stem().unwrap().to_string_lossy()))
+        .collect::<Vec<_>>();
+
+    let failures = failures_on_target("llvm", &programs);
+
+    if !failures.is_empty() {
+        panic!(
+            "{} llvm programs failed:\n\n{}",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+}
+
+/// The vm can't call foreign functions.
+const NOT_BYTECODE_PROGRAMS: &[&str] = &["foreign"];
+
+#[test]
+fn bytecode_target() {
+    let programs = corpus()
+        .into_iter()
+        .filter(|p| !NOT_BYTECODE_PROGRAMS.contains(&&*p.file_stem().unwrap().to_string_lossy()))
+        .collect::<Vec<_>>();
+
+    let failures = failures_on_target("bytecode", &programs);
+
+    if !failures.is_empty() {
+        panic!(
+            "{} bytecode programs failed:\n\n{}",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+}
+
+/// The interpreter can't call foreign functions or give `spawn` the
+/// continuation of its call, and those looping a million times are too slow
+/// for it.
+const NOT_INTERPRETER_PROGRAMS: &[&str] = &[
+    "foreign",
+    "threads",
+    "parameterize_threads",
+    "closures",
+    "tail",
+];
+
+#[test]
+fn interpreter() {
+    let programs = corpus()
+        .into_iter()
+        .filter(|p| !NOT_INTERPRETER_PROGRAMS.contains(&&*p.file_stem().unwrap().to_string_lossy()))
+        .collect::<Vec<_>>();
+
+    let failures = failures_running(&["interpret"], &programs);
+
+    if !failures.is_empty() {
+        panic!(
+            "{} programs failed on the interpreter:\n\n{}",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+}
+
+/// A compiled .scmbc file runs the same as running the program directly.
+#[test]
+fn bytecode_files_run() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("bytecode");
+    fs::create_dir_all(&out_dir).unwrap();
+    let output = out_dir.join("stuff.scmbc");
+    let program = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/stuff.scm");
+
+    let status = Command::new(COMPILER)
+        .arg("-i")
+        .arg(&program)
+        .args(["--target", "bytecode", "compile", "-o"])
+        .arg(&output)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let result = stdout_of(Command::new(COMPILER).arg("exec").arg(&output)).unwrap();
+    let expected = fs::read_to_string(program.with_extension("expected")).unwrap();
+
+    assert_eq!(result, expected);
+}
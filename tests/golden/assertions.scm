@@ -0,0 +1,9 @@
+(define message
+  (lambda (thunk)
+    (with-exception-handler
+      (lambda (e) (error-object-message e))
+      thunk)))
+(display (assert (+ 1 2))) (newline)
+(display (check-arg number? 5 'f)) (newline)
+(display (message (lambda () (assert (pair? 3))))) (newline)
+(display (message (lambda () (check-arg pair? (+ 1 2) 'g)))) (newline)
@@ -0,0 +1,285 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+static void scm_message_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_message_lambda_1_closure;
+struct scm_message_lambda_2_env {struct obj *v_k_2;struct obj *v_thunk;};
+static void scm_message_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_message(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_message_closure;
+struct scm_lambda_8_env {struct obj *v_k_3;struct obj *v_rv_3;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_3;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_3_closure;
+struct scm_lambda_9_env {struct obj *v_k_4;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_6_closure;
+struct scm_lambda_39_env {struct obj *v_k_5;struct obj *v_rv_7;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_k_5;struct obj *v_rv_7;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_5;struct obj *v__u7cvalue;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_6;struct obj *v_k_5;struct obj *v__u7cvalue;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_k_6;};
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_6;struct obj *v_rv_34;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_6;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_6;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_10_closure;
+struct scm_lambda_18_env {struct obj *v_k_7;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_14_closure;
+struct scm_lambda_29_env {struct obj *v_k_8;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_23_closure;
+struct scm_lambda_35_env {struct obj *v_k_9;struct obj *v_rv_15;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_9;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_9;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_message;};
+static void scm_lambda_19(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_10;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_25(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_25_closure;
+struct scm_lambda_48_env {struct obj *v_k_11;struct obj *v_rv_20;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_11;struct obj *v_rv_20;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_11;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_11;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_36_closure;
+struct scm_lambda_44_env {struct obj *v_k_12;struct obj *v_rv_23;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_k_12;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_12;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_message;};
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_13;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_13;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_13;struct obj *v_message;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_13;struct obj *v_message;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_13;struct obj *v_message;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_13;struct obj *v_message;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_13;struct obj *v_message;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_13;struct obj *v_message;};
+static void scm_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_lambda_28_inlined_env {struct obj *v_k_6;struct obj *v_rv_34;};
+static void scm_lambda_28_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_inlined_env {struct obj *v_k_6;};
+static void scm_lambda_22_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_message_lambda_1),"scm_message_lambda_1","assertions.scm",4},{(void *)(scm_message_lambda_2),"scm_message_lambda_2","assertions.scm",2},{(void *)(scm_message),"scm_message","assertions.scm",2},{(void *)(scm_lambda_8),"scm_lambda_8","assertions.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","assertions.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","assertions.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","assertions.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","assertions.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","assertions.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","assertions.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","assertions.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","assertions.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","assertions.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","assertions.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","assertions.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","assertions.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","assertions.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","assertions.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","assertions.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","assertions.scm",8},{(void *)(scm_lambda_23),"scm_lambda_23","assertions.scm",8},{(void *)(scm_lambda_35),"scm_lambda_35","assertions.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","assertions.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","assertions.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","assertions.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","assertions.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","assertions.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","assertions.scm",9},{(void *)(scm_lambda_47),"scm_lambda_47","assertions.scm",9},{(void *)(scm_lambda_46),"scm_lambda_46","assertions.scm",9},{(void *)(scm_lambda_45),"scm_lambda_45","assertions.scm",9},{(void *)(scm_lambda_36),"scm_lambda_36","assertions.scm",9},{(void *)(scm_lambda_44),"scm_lambda_44","assertions.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","assertions.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","assertions.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","assertions.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","assertions.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","assertions.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","assertions.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","assertions.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","assertions.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","assertions.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","assertions.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","assertions.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","assertions.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","assertions.scm",1},{(void *)(scm_lambda_28_inlined),"scm_lambda_28_inlined","assertions.scm",1},{(void *)(scm_lambda_22_inlined),"scm_lambda_22_inlined","assertions.scm",1},{NULL}};
+
+#line 4 "assertions.scm"
+static void scm_message_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,error_object_message_k,NULL);call_closure_two(var_2,var_0,var_1);__builtin_unreachable();}
+static struct closure_obj scm_message_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_message_lambda_1);
+
+#line 2 "assertions.scm"
+static void scm_message_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_message_lambda_2_env *env = (struct scm_message_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_thunk,(env)->v_k_2);__builtin_unreachable();}
+
+#line 2 "assertions.scm"
+static void scm_message(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,with_exception_handler_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_message_lambda_2_env );(((struct scm_message_lambda_2_env *)(&((var_3)->env)))->v_k_2)=(var_1);(((struct scm_message_lambda_2_env *)(&((var_3)->env)))->v_thunk)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_message_lambda_2,var_3);call_closure_two(var_2,(struct obj *)(&(scm_message_lambda_1_closure)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_message_closure = CLOSURE_TWO_OBJ_INIT(scm_message);
+
+#line 1 "assertions.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_8_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_8,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_5,var_3);call_closure_two(var_2,MAKE_FIXNUM(3),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_3_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_3);
+
+#line 1 "assertions.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_9,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_6_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_6);
+
+#line 1 "assertions.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));call_closure_two((env)->v_rv_7,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_rv_7)=((env)->v_rv_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_39,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_rv_7)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,(env)->v__u7cvalue,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_inlined_env );(((struct scm_lambda_22_inlined_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22_inlined,var_2);call_closure_two(var_1,(env)->v__u7cvalue,var_3);} else {OBJECT_CLOSURE_TWO_NEW(var_4,error_k,NULL);OBJECT_STRING_OBJ_NEW(var_5,"f: argument 5 failed number? on line 7");OBJECT_ENV_OBJ_NEW(var_6,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_6)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_27_env *)(&((var_6)->env)))->v__u7cvalue)=((env)->v__u7cvalue);OBJECT_CLOSURE_ONE_NEW(var_7,scm_lambda_27,var_6);call_closure_two(var_4,var_5,var_7);}0;__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_number_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_5)=(var_1);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v__u7cvalue)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));call_closure_two((env)->v_rv_34,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_rv_34)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_16,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_4)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_17,var_4);call_closure_two(var_3,MAKE_FIXNUM(5),var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_10_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_10);
+
+#line 1 "assertions.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_18,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_14_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_14);
+
+#line 8 "assertions.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_8);__builtin_unreachable();}
+
+#line 8 "assertions.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,error_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"assertion (pair? 3) failed on line 8");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_4)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_29,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_23_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_23);
+
+#line 1 "assertions.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));call_closure_two((env)->v_rv_15,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_rv_15)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_19(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_24,var_2);call_closure_two(((struct cell_obj *)((env)->v_message))->val,(struct obj *)(&(scm_lambda_23_closure)),var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_25(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_25_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_25);
+
+#line 9 "assertions.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));call_closure_two((env)->v_rv_20,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 9 "assertions.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_48_env *)(&((var_1)->env)))->v_rv_20)=((env)->v_rv_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_48,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 9 "assertions.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_rv_20)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_47,var_2);call_closure_two(var_1,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 9 "assertions.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_11,MAKE_FIXNUM(3));} else {OBJECT_CLOSURE_TWO_NEW(var_1,error_k,NULL);OBJECT_STRING_OBJ_NEW(var_2,"g: argument (+ 1 2) failed pair? on line 9");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_46,var_3);call_closure_two(var_1,var_2,var_4);}0;__builtin_unreachable();}
+
+#line 9 "assertions.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,is_cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_45,var_3);call_closure_two(var_2,MAKE_FIXNUM(3),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_36_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_36);
+
+#line 1 "assertions.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));call_closure_two((env)->v_rv_23,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_rv_23)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_44,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_37,var_2);call_closure_two(((struct cell_obj *)((env)->v_message))->val,(struct obj *)(&(scm_lambda_36_closure)),var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_message)=((env)->v_message);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_32,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_26_env *)(&((var_1)->env)))->v_message)=((env)->v_message);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_26,var_1);call_closure_two((struct obj *)(&(scm_lambda_25_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_message)=((env)->v_message);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_19,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_3)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_20_env *)(&((var_3)->env)))->v_message)=((env)->v_message);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_20,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_message)=((env)->v_message);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_15,var_1);call_closure_two((struct obj *)(&(scm_lambda_14_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_message)=((env)->v_message);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two((struct obj *)(&(scm_lambda_10_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_message)=((env)->v_message);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two((struct obj *)(&(scm_lambda_6_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);(((struct cell_obj *)(var_2))->val)=((struct obj *)(&(scm_message_closure)));GC_WRITE_BARRIER(var_2,((struct cell_obj *)(var_2))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_k_13)=(var_1);(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_message)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_4,var_3);call_closure_two((struct obj *)(&(scm_lambda_3_closure)),NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "assertions.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "assertions.scm"
+static void scm_lambda_28_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_inlined_env *env = (struct scm_lambda_28_inlined_env *)(&((env_in)->env));call_closure_two((env)->v_rv_34,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+static void scm_lambda_22_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_inlined_env *env = (struct scm_lambda_22_inlined_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_inlined_env );(((struct scm_lambda_28_inlined_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_28_inlined_env *)(&((var_2)->env)))->v_rv_34)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28_inlined,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "assertions.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 266 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
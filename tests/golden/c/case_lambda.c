@@ -0,0 +1,474 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm__u7carea_u200_lambda_3_env {struct obj *v_k;struct obj *v_rv_3;};
+static void scm__u7carea_u200_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7carea_u200_lambda_2_env {struct obj *v_k;struct obj *v_r;struct obj *v_rv_3;};
+static void scm__u7carea_u200_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7carea_u200_lambda_1_env {struct obj *v_k;struct obj *v_r;};
+static void scm__u7carea_u200_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u7carea_u200(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7carea_u200_closure;
+struct scm__u7carea_u201_lambda_2_env {struct obj *v_h;struct obj *v_k_2;};
+static void scm__u7carea_u201_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7carea_u201_lambda_1_env {struct obj *v_w;};
+static void scm__u7carea_u201_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u7carea_u201(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7carea_u201_closure;
+static void scm__u7carea_u202(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7carea_u202_closure;
+struct scm_lambda_24_env {struct obj *v_k_5;struct obj *v_rv_6;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_5;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_5;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v__u7carea_u200;};
+static void scm_lambda_15(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_6;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_18_closure;
+struct scm_lambda_36_env {struct obj *v_k_7;struct obj *v_rv_10;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_7;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_7;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_7;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v__u7carea_u201;};
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_8;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_26_closure;
+struct scm_lambda_41_env {struct obj *v_k_9;struct obj *v_rv_15;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_9;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_9;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v__u7carea_u202;};
+static void scm_lambda_30(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_10;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_34(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_34_closure;
+struct scm__u7ccount_u200_lambda_1_env {struct obj *v_k_11;};
+static void scm__u7ccount_u200_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u200_env {struct obj *v__u7ccount_u201;};
+static void scm__u7ccount_u200(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_lambda_8_env {struct obj *v_k_12;struct obj *v_rv_21;};
+static void scm__u7ccount_u201_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_lambda_7_env {struct obj *v_k_12;struct obj *v_rv_21;};
+static void scm__u7ccount_u201_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_lambda_6_env {struct obj *v_acc;struct obj *v_k_12;};
+static void scm__u7ccount_u201_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_lambda_5_env {struct obj *v_acc;struct obj *v_k_12;struct obj *v__u7ccount_u201;};
+static void scm__u7ccount_u201_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_lambda_4_env {struct obj *v_acc;struct obj *v_k_12;struct obj *v__u7ccount_u201;};
+static void scm__u7ccount_u201_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_lambda_3_env {struct obj *parent;};
+static void scm__u7ccount_u201_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_lambda_2_env {struct obj *parent;};
+static void scm__u7ccount_u201_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_lambda_1_env {struct obj *v_acc;struct obj *v_k_12;struct obj *v_n_2;struct obj *v__u7ccount_u201;};
+static void scm__u7ccount_u201_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7ccount_u201_env {struct obj *v_n_2;struct obj *v__u7ccount_u201;};
+static void scm__u7ccount_u201(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_14;struct obj *v_rv_27;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_14;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_14;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v__u7ccount_u200;};
+static void scm_lambda_44(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_k_15;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_47(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_47_closure;
+struct scm_lambda_72_env {struct obj *v_k_16;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_70_closure;
+struct scm_lambda_78_env {struct obj *v_k_19;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_k_19;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_76_env {struct obj *v_k_19;struct obj *v_rv_34;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_19;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_19;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_1_env {struct obj *v_k_17;};
+static void scm_f_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_f(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_f_closure;
+struct scm_lambda_71_env {struct obj *v_k_19;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_19;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_19;struct obj *v_rv_40;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_19;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_k_19;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_k_18;struct obj *v_y;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_63(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_63_closure;
+static void scm_lambda_51(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_51_closure;
+struct scm_lambda_64_env {struct obj *v_k_20;struct obj *v_rv_44;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_20;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_56(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_56_closure;
+struct scm_lambda_61_env {struct obj *v_k_21;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_21;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_21;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_21;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_21;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_21;struct obj *v__u7ccount_u200;struct obj *v__u7ccount_u201;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_21;struct obj *v__u7ccount_u200;struct obj *v__u7ccount_u201;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_21;struct obj *v__u7carea_u202;struct obj *v__u7ccount_u200;struct obj *v__u7ccount_u201;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *parent;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *parent;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *parent;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_k_21;struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;struct obj *v__u7ccount_u200;struct obj *v__u7ccount_u201;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;struct obj *v__u7ccount_u200;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+struct scm_lambda_9_env {struct obj *v_k_26;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_26;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_26;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_26;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_two = SYMBOL_OBJ_INIT("two");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_two),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm__u7carea_u200_lambda_3),"scm__u7carea_u200_lambda_3","case_lambda.scm",3},{(void *)(scm__u7carea_u200_lambda_2),"scm__u7carea_u200_lambda_2","case_lambda.scm",3},{(void *)(scm__u7carea_u200_lambda_1),"scm__u7carea_u200_lambda_1","case_lambda.scm",3},{(void *)(scm__u7carea_u200),"scm__u7carea_u200","case_lambda.scm",3},{(void *)(scm__u7carea_u201_lambda_2),"scm__u7carea_u201_lambda_2","case_lambda.scm",4},{(void *)(scm__u7carea_u201_lambda_1),"scm__u7carea_u201_lambda_1","case_lambda.scm",4},{(void *)(scm__u7carea_u201),"scm__u7carea_u201","case_lambda.scm",4},{(void *)(scm__u7carea_u202),"scm__u7carea_u202","case_lambda.scm",5},{(void *)(scm_lambda_24),"scm_lambda_24","case_lambda.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","case_lambda.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","case_lambda.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","case_lambda.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","case_lambda.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","case_lambda.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","case_lambda.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","case_lambda.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","case_lambda.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","case_lambda.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","case_lambda.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","case_lambda.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","case_lambda.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","case_lambda.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","case_lambda.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","case_lambda.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","case_lambda.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","case_lambda.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","case_lambda.scm",1},{(void *)(scm__u7ccount_u200_lambda_1),"scm__u7ccount_u200_lambda_1","case_lambda.scm",11},{(void *)(scm__u7ccount_u200),"scm__u7ccount_u200","case_lambda.scm",11},{(void *)(scm__u7ccount_u201_lambda_8),"scm__u7ccount_u201_lambda_8","case_lambda.scm",12},{(void *)(scm__u7ccount_u201_lambda_7),"scm__u7ccount_u201_lambda_7","case_lambda.scm",12},{(void *)(scm__u7ccount_u201_lambda_6),"scm__u7ccount_u201_lambda_6","case_lambda.scm",12},{(void *)(scm__u7ccount_u201_lambda_5),"scm__u7ccount_u201_lambda_5","case_lambda.scm",12},{(void *)(scm__u7ccount_u201_lambda_4),"scm__u7ccount_u201_lambda_4","case_lambda.scm",12},{(void *)(scm__u7ccount_u201_lambda_3),"scm__u7ccount_u201_lambda_3","case_lambda.scm",12},{(void *)(scm__u7ccount_u201_lambda_2),"scm__u7ccount_u201_lambda_2","case_lambda.scm",12},{(void *)(scm__u7ccount_u201_lambda_1),"scm__u7ccount_u201_lambda_1","case_lambda.scm",12},{(void *)(scm__u7ccount_u201),"scm__u7ccount_u201","case_lambda.scm",12},{(void *)(scm_lambda_53),"scm_lambda_53","case_lambda.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","case_lambda.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","case_lambda.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","case_lambda.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","case_lambda.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","case_lambda.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","case_lambda.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","case_lambda.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","case_lambda.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","case_lambda.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","case_lambda.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","case_lambda.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","case_lambda.scm",1},{(void *)(scm_f_lambda_1),"scm_f_lambda_1","case_lambda.scm",16},{(void *)(scm_f),"scm_f","case_lambda.scm",16},{(void *)(scm_lambda_71),"scm_lambda_71","case_lambda.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","case_lambda.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","case_lambda.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","case_lambda.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","case_lambda.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","case_lambda.scm",14},{(void *)(scm_lambda_63),"scm_lambda_63","case_lambda.scm",14},{(void *)(scm_lambda_51),"scm_lambda_51","case_lambda.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","case_lambda.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","case_lambda.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","case_lambda.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","case_lambda.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","case_lambda.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","case_lambda.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","case_lambda.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","case_lambda.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","case_lambda.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","case_lambda.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","case_lambda.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","case_lambda.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","case_lambda.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","case_lambda.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","case_lambda.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","case_lambda.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","case_lambda.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","case_lambda.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","case_lambda.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","case_lambda.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","case_lambda.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","case_lambda.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","case_lambda.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","case_lambda.scm",1},{NULL}};
+
+#line 3 "case_lambda.scm"
+static void scm__u7carea_u200_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm__u7carea_u200_lambda_3_env *env = (struct scm__u7carea_u200_lambda_3_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 3 "case_lambda.scm"
+static void scm__u7carea_u200_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm__u7carea_u200_lambda_2_env *env = (struct scm__u7carea_u200_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm__u7carea_u200_lambda_3_env );(((struct scm__u7carea_u200_lambda_3_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm__u7carea_u200_lambda_3_env *)(&((var_1)->env)))->v_rv_3)=((env)->v_rv_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm__u7carea_u200_lambda_3,var_1);call_closure_two(var_0,(env)->v_r,var_2);__builtin_unreachable();}
+
+#line 3 "case_lambda.scm"
+static void scm__u7carea_u200_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm__u7carea_u200_lambda_1_env *env = (struct scm__u7carea_u200_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm__u7carea_u200_lambda_2_env );(((struct scm__u7carea_u200_lambda_2_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm__u7carea_u200_lambda_2_env *)(&((var_2)->env)))->v_r)=((env)->v_r);(((struct scm__u7carea_u200_lambda_2_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm__u7carea_u200_lambda_2,var_2);call_closure_two(var_1,(env)->v_r,var_3);__builtin_unreachable();}
+
+#line 3 "case_lambda.scm"
+static void scm__u7carea_u200(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u7carea_u200_lambda_1_env );(((struct scm__u7carea_u200_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm__u7carea_u200_lambda_1_env *)(&((var_3)->env)))->v_r)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm__u7carea_u200_lambda_1,var_3);call_closure_two(var_2,MAKE_FIXNUM(3),var_4);__builtin_unreachable();}
+static struct closure_obj scm__u7carea_u200_closure = CLOSURE_TWO_OBJ_INIT(scm__u7carea_u200);
+
+#line 4 "case_lambda.scm"
+static void scm__u7carea_u201_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm__u7carea_u201_lambda_2_env *env = (struct scm__u7carea_u201_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_h,(env)->v_k_2);__builtin_unreachable();}
+
+#line 4 "case_lambda.scm"
+static void scm__u7carea_u201_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u7carea_u201_lambda_1_env *env = (struct scm__u7carea_u201_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u7carea_u201_lambda_2_env );(((struct scm__u7carea_u201_lambda_2_env *)(&((var_3)->env)))->v_h)=(var_0);(((struct scm__u7carea_u201_lambda_2_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm__u7carea_u201_lambda_2,var_3);call_closure_two(var_2,(env)->v_w,var_4);__builtin_unreachable();}
+
+#line 4 "case_lambda.scm"
+static void scm__u7carea_u201(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm__u7carea_u201_lambda_1_env );(((struct scm__u7carea_u201_lambda_1_env *)(&((var_2)->env)))->v_w)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm__u7carea_u201_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm__u7carea_u201_closure = CLOSURE_TWO_OBJ_INIT(scm__u7carea_u201);
+
+#line 5 "case_lambda.scm"
+static void scm__u7carea_u202(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,MAKE_FIXNUM(0));__builtin_unreachable();}
+static struct closure_obj scm__u7carea_u202_closure = CLOSURE_TWO_OBJ_INIT(scm__u7carea_u202);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_24,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_20,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_15(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_2)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_17,var_2);call_closure_two(((struct cell_obj *)((env)->v__u7carea_u200))->val,MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_18_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_18);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));call_closure_two((env)->v_rv_10,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_rv_10)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_36,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two(((struct cell_obj *)((env)->v__u7carea_u201))->val,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_29,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_26_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_26);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));call_closure_two((env)->v_rv_15,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_rv_15)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_41,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_37,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_30(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_33,var_2);call_closure_two(((struct cell_obj *)((env)->v__u7carea_u202))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_34(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_38,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_34_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_34);
+
+#line 11 "case_lambda.scm"
+static void scm__u7ccount_u200_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm__u7ccount_u200_lambda_1_env *env = (struct scm__u7ccount_u200_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(0),(env)->v_k_11);__builtin_unreachable();}
+
+#line 11 "case_lambda.scm"
+static void scm__u7ccount_u200(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u7ccount_u200_env *env = (struct scm__u7ccount_u200_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm__u7ccount_u200_lambda_1_env );(((struct scm__u7ccount_u200_lambda_1_env *)(&((var_2)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm__u7ccount_u200_lambda_1,var_2);call_closure_two(((struct cell_obj *)((env)->v__u7ccount_u201))->val,var_0,var_3);__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm__u7ccount_u201_lambda_8_env *env = (struct scm__u7ccount_u201_lambda_8_env *)(&((env_in)->env));call_closure_two((env)->v_rv_21,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm__u7ccount_u201_lambda_7_env *env = (struct scm__u7ccount_u201_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm__u7ccount_u201_lambda_8_env );(((struct scm__u7ccount_u201_lambda_8_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);(((struct scm__u7ccount_u201_lambda_8_env *)(&((var_1)->env)))->v_rv_21)=((env)->v_rv_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm__u7ccount_u201_lambda_8,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm__u7ccount_u201_lambda_6_env *env = (struct scm__u7ccount_u201_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm__u7ccount_u201_lambda_7_env );(((struct scm__u7ccount_u201_lambda_7_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm__u7ccount_u201_lambda_7_env *)(&((var_2)->env)))->v_rv_21)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm__u7ccount_u201_lambda_7,var_2);call_closure_two(var_1,(env)->v_acc,var_3);__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm__u7ccount_u201_lambda_5_env *env = (struct scm__u7ccount_u201_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm__u7ccount_u201_lambda_6_env );(((struct scm__u7ccount_u201_lambda_6_env *)(&((var_1)->env)))->v_acc)=((env)->v_acc);(((struct scm__u7ccount_u201_lambda_6_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm__u7ccount_u201_lambda_6,var_1);struct obj *var_3 = ((struct cell_obj *)((env)->v__u7ccount_u201))->val;struct obj *var_4 = var_0;struct obj *var_5 = var_2;if (stack_check()){scm__u7ccount_u201(var_4,var_5,((struct closure_obj *)(var_3))->env);} else {}call_closure_two(var_3,var_4,var_5);__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm__u7ccount_u201_lambda_4_env *env = (struct scm__u7ccount_u201_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm__u7ccount_u201_lambda_5_env );(((struct scm__u7ccount_u201_lambda_5_env *)(&((var_1)->env)))->v_acc)=((env)->v_acc);(((struct scm__u7ccount_u201_lambda_5_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);(((struct scm__u7ccount_u201_lambda_5_env *)(&((var_1)->env)))->v__u7ccount_u201)=((env)->v__u7ccount_u201);OBJECT_CLOSURE_ONE_NEW(var_2,scm__u7ccount_u201_lambda_5,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm__u7ccount_u201_lambda_3_env *env = (struct scm__u7ccount_u201_lambda_3_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one(((struct scm__u7ccount_u201_lambda_1_env *)(&(((struct env_obj *)(((struct scm__u7ccount_u201_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_12,((struct scm__u7ccount_u201_lambda_1_env *)(&(((struct env_obj *)(((struct scm__u7ccount_u201_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc);} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm__u7ccount_u201_lambda_4_env );(((struct scm__u7ccount_u201_lambda_4_env *)(&((var_2)->env)))->v_acc)=(((struct scm__u7ccount_u201_lambda_1_env *)(&(((struct env_obj *)(((struct scm__u7ccount_u201_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc);(((struct scm__u7ccount_u201_lambda_4_env *)(&((var_2)->env)))->v_k_12)=(((struct scm__u7ccount_u201_lambda_1_env *)(&(((struct env_obj *)(((struct scm__u7ccount_u201_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_12);(((struct scm__u7ccount_u201_lambda_4_env *)(&((var_2)->env)))->v__u7ccount_u201)=(((struct scm__u7ccount_u201_lambda_1_env *)(&(((struct env_obj *)(((struct scm__u7ccount_u201_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u7ccount_u201);OBJECT_CLOSURE_ONE_NEW(var_3,scm__u7ccount_u201_lambda_4,var_2);call_closure_two(var_1,((struct scm__u7ccount_u201_lambda_1_env *)(&(((struct env_obj *)(((struct scm__u7ccount_u201_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n_2,var_3);}0;__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm__u7ccount_u201_lambda_2_env *env = (struct scm__u7ccount_u201_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm__u7ccount_u201_lambda_3_env );(((struct scm__u7ccount_u201_lambda_3_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm__u7ccount_u201_lambda_3,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u7ccount_u201_lambda_1_env *env = (struct scm__u7ccount_u201_lambda_1_env *)(&((env_in)->env));((env)->v_acc)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_12)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u7ccount_u201_lambda_2_env );(((struct scm__u7ccount_u201_lambda_2_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm__u7ccount_u201_lambda_2,var_3);call_closure_two(var_2,(env)->v_n_2,var_4);__builtin_unreachable();}
+
+#line 12 "case_lambda.scm"
+static void scm__u7ccount_u201(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u7ccount_u201_env *env = (struct scm__u7ccount_u201_env *)(&((env_in)->env));((env)->v_n_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm__u7ccount_u201_lambda_1_env );(((struct scm__u7ccount_u201_lambda_1_env *)(&((var_2)->env)))->v_n_2)=((env)->v_n_2);(((struct scm__u7ccount_u201_lambda_1_env *)(&((var_2)->env)))->v__u7ccount_u201)=((env)->v__u7ccount_u201);OBJECT_CLOSURE_TWO_NEW(var_3,scm__u7ccount_u201_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));call_closure_two((env)->v_rv_27,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_53_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_53,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_49,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_44(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_2)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_46,var_2);call_closure_two(((struct cell_obj *)((env)->v__u7ccount_u200))->val,MAKE_FIXNUM(5),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_47(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_50,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_47_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_47);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_72,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_70_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_70);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_78,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_77,var_1);call_closure_two((env)->v_rv_34,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_rv_34)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_76,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_75,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 16 "case_lambda.scm"
+static void scm_f_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_1_env *env = (struct scm_f_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(10),(env)->v_k_17);__builtin_unreachable();}
+
+#line 16 "case_lambda.scm"
+static void scm_f(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_1_env );(((struct scm_f_lambda_1_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_f_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_f_closure = CLOSURE_TWO_OBJ_INIT(scm_f);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_74,var_1);call_closure_two((struct obj *)(&(scm_f_closure)),MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_71,var_1);call_closure_two((struct obj *)(&(scm_lambda_70_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_69,var_1);call_closure_two((env)->v_rv_40,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_rv_40)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_67,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 14 "case_lambda.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_y,(env)->v_k_18);__builtin_unreachable();}
+
+#line 14 "case_lambda.scm"
+static void scm_lambda_63(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_k_18)=(var_1);(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_y)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_66,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_63_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_63);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_51(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_65,var_2);call_closure_two((struct obj *)(&(scm_lambda_63_closure)),MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+static struct closure_obj scm_lambda_51_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_51);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));call_closure_two((env)->v_rv_44,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_rv_44)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_64,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_56(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_60,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_two)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_56_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_56);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_61,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_57,var_1);call_closure_two((struct obj *)(&(scm_lambda_56_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_52,var_1);call_closure_two((struct obj *)(&(scm_lambda_51_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_48,var_1);call_closure_two((struct obj *)(&(scm_lambda_47_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm__u7ccount_u200_env );(((struct scm__u7ccount_u200_env *)(&((var_1)->env)))->v__u7ccount_u201)=((env)->v__u7ccount_u201);OBJECT_CLOSURE_TWO_NEW(var_2,scm__u7ccount_u200,var_1);(((struct cell_obj *)((env)->v__u7ccount_u200))->val)=(var_2);GC_WRITE_BARRIER((env)->v__u7ccount_u200,((struct cell_obj *)((env)->v__u7ccount_u200))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u7ccount_u201_env );(((struct scm__u7ccount_u201_env *)(&((var_3)->env)))->v__u7ccount_u201)=((env)->v__u7ccount_u201);OBJECT_CLOSURE_TWO_NEW(var_4,scm__u7ccount_u201,var_3);(((struct cell_obj *)((env)->v__u7ccount_u201))->val)=(var_4);GC_WRITE_BARRIER((env)->v__u7ccount_u201,((struct cell_obj *)((env)->v__u7ccount_u201))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_5)->env)))->v__u7ccount_u200)=((env)->v__u7ccount_u200);OBJECT_CLOSURE_TWO_NEW(var_6,scm_lambda_44,var_5);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_7)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_8,scm_lambda_45,var_7);call_closure_two(var_6,NULL,var_8);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_35_env *)(&((var_1)->env)))->v__u7ccount_u200)=((env)->v__u7ccount_u200);(((struct scm_lambda_35_env *)(&((var_1)->env)))->v__u7ccount_u201)=((env)->v__u7ccount_u201);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_35,var_1);call_closure_two((struct obj *)(&(scm_lambda_34_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_30,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_31_env *)(&((var_3)->env)))->v__u7ccount_u200)=((env)->v__u7ccount_u200);(((struct scm_lambda_31_env *)(&((var_3)->env)))->v__u7ccount_u201)=((env)->v__u7ccount_u201);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_k_21)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_21);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v__u7carea_u202)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7carea_u202);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v__u7ccount_u200)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7ccount_u200);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v__u7ccount_u201)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7ccount_u201);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_27,var_1);call_closure_two((struct obj *)(&(scm_lambda_26_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_1)->env)))->v__u7carea_u201)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u7carea_u201);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_22,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_19,var_1);call_closure_two((struct obj *)(&(scm_lambda_18_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u7ccount_u201)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_21)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v__u7carea_u200))->val)=((struct obj *)(&(scm__u7carea_u200_closure)));GC_WRITE_BARRIER((env)->v__u7carea_u200,((struct cell_obj *)((env)->v__u7carea_u200))->val);(((struct cell_obj *)((env)->v__u7carea_u201))->val)=((struct obj *)(&(scm__u7carea_u201_closure)));GC_WRITE_BARRIER((env)->v__u7carea_u201,((struct cell_obj *)((env)->v__u7carea_u201))->val);(((struct cell_obj *)((env)->v__u7carea_u202))->val)=((struct obj *)(&(scm__u7carea_u202_closure)));GC_WRITE_BARRIER((env)->v__u7carea_u202,((struct cell_obj *)((env)->v__u7carea_u202))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_15,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_16,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u7ccount_u200)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u7ccount_u200)=((env)->v__u7ccount_u200);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u7carea_u202)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u7carea_u201)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v__u7carea_u200)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_2)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_3,var_2);call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_3);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "case_lambda.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 455 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,1170 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm__u7carea_u200_lambda_3_env {struct obj *v_k;struct obj *v_rv_3;};
+static void scm__u7carea_u200_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7carea_u200_lambda_2_env {struct obj *v_k;struct obj *v_r;struct obj *v_rv_3;};
+static void scm__u7carea_u200_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7carea_u200_lambda_1_env {struct obj *v_k;struct obj *v_r;};
+static void scm__u7carea_u200_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u7carea_u200(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7carea_u200_closure;
+struct scm__u7carea_u201_lambda_2_env {struct obj *v_h;struct obj *v_k_2;};
+static void scm__u7carea_u201_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u7carea_u201_lambda_1_env {struct obj *v_w;};
+static void scm__u7carea_u201_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u7carea_u201(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7carea_u201_closure;
+static void scm__u7carea_u202(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7carea_u202_closure;
+struct scm_lambda_93_env {struct obj *v_area;struct obj *v_k_5;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_area;struct obj *v_k_5;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_area;struct obj *v_k_5;struct obj *v_rv_15;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *parent;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *parent;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_area;struct obj *v_k_5;struct obj *v_rv_15;struct obj *v_rv_12;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *parent;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *parent;struct obj *v_rv_12;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *parent;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_area;struct obj *v_k_5;struct obj *v_rv_15;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *parent;struct obj *v_rv_15;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *parent;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *parent;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_area;struct obj *v_k_5;struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_9_env {struct obj *v_k_6;};
+static void scm_measure_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_measure_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_measure_lambda_7_closure;
+struct scm_measure_lambda_26_env {struct obj *v_k_7;struct obj *v_rv_20;};
+static void scm_measure_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_24_env {struct obj *v_k_7;};
+static void scm_measure_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_22_env {struct obj *v_k_7;};
+static void scm_measure_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_19_env {struct obj *v_k_7;};
+static void scm_measure_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_15_env {struct obj *v_k_7;};
+static void scm_measure_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_12_env {struct obj *v_k_7;};
+static void scm_measure_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_10_env {struct obj *v_f;};
+static void scm_measure_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_16_env {struct obj *v_k_8;};
+static void scm_measure_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_measure_lambda_13(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_measure_lambda_13_closure;
+struct scm_measure_lambda_28_env {struct obj *v_k_9;struct obj *v_rv_27;};
+static void scm_measure_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_27_env {struct obj *v_k_9;};
+static void scm_measure_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_25_env {struct obj *v_k_9;};
+static void scm_measure_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_23_env {struct obj *v_k_9;};
+static void scm_measure_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_20_env {struct obj *v_k_9;};
+static void scm_measure_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_17_env {struct obj *v_f;};
+static void scm_measure_lambda_17(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_21_env {struct obj *v_k_10;};
+static void scm_measure_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_18_env {struct obj *v_k_10;};
+static void scm_measure_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_14_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_measure_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_11_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_measure_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_8_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_measure_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_6_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_measure_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_5_env {struct obj *v_f;struct obj *v_k_10;struct obj *v_rv_38;};
+static void scm_measure_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_4_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_measure_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_3_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_measure_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_2_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_measure_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_measure_lambda_1_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_measure_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_measure(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_measure_closure;
+struct scm_lambda_36_env {struct obj *v_area;struct obj *v_k_11;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_area;struct obj *v_k_11;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_area;struct obj *v_measure;};
+static void scm_lambda_29(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_12;struct obj *v_rv_45;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_12;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_12;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_area;};
+static void scm_lambda_33(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_k_13;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_38(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_38_closure;
+struct scm_lambda_61_env {struct obj *v_k_14;struct obj *v_x;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_55(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_55_closure;
+struct scm_lambda_94_env {struct obj *v_k_15;struct obj *v_y;};
+static void scm_lambda_94(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_85_env {struct obj *v_x_2;};
+static void scm_lambda_85(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_76(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_76_closure;
+struct scm_lambda_135_env {struct obj *v_k_17;struct obj *v_shapes;};
+static void scm_lambda_135(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_127_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_shapes;};
+static void scm_lambda_127(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_120_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_shapes;};
+static void scm_lambda_120(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_shapes;};
+static void scm_lambda_111(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_103_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_shapes;};
+static void scm_lambda_103(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_95_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_rv_59;struct obj *v_shapes;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_86_env {struct obj *parent;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *parent;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *parent;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_rv_59;struct obj *v_shapes;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_shapes;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_shapes;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_17;struct obj *v_rv_62;struct obj *v_shapes;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_area;struct obj *v_k_17;struct obj *v_shapes;};
+static void scm_lambda_43(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_10_env {struct obj *v_k_18;struct obj *v_rv_64;};
+static void scm_each_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_9_env {struct obj *v_k_18;struct obj *v_l;};
+static void scm_each_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_8_env {struct obj *v_f_2;struct obj *v_k_18;struct obj *v_l;};
+static void scm_each_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_7_env {struct obj *v_f_2;struct obj *v_k_18;struct obj *v_l;};
+static void scm_each_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_6_env {struct obj *parent;};
+static void scm_each_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_5_env {struct obj *v_each;struct obj *v_f_2;struct obj *v_k_18;struct obj *v_l;struct obj *v_rv_69;};
+static void scm_each_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_4_env {struct obj *parent;};
+static void scm_each_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_3_env {struct obj *parent;};
+static void scm_each_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_2_env {struct obj *parent;};
+static void scm_each_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_lambda_1_env {struct obj *v_each;struct obj *v_f_2;struct obj *v_k_18;struct obj *v_l;};
+static void scm_each_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_each_env {struct obj *v_each;struct obj *v_f_2;};
+static void scm_each(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_136_env {struct obj *v_k_20;};
+static void scm_lambda_136(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_128_env {struct obj *v_k_20;};
+static void scm_lambda_128(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_121_env {struct obj *v_k_20;struct obj *v_rv_75;};
+static void scm_lambda_121(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_112_env {struct obj *v_k_20;};
+static void scm_lambda_112(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_k_20;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_96_env {struct obj *v_k_20;};
+static void scm_lambda_96(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_k_20;};
+static void scm_lambda_87(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *v_k_20;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_69(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_69_closure;
+struct scm_lambda_70_env {struct obj *v_k_21;struct obj *v_shapes;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_21;struct obj *v_shapes;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_21;struct obj *v_shapes;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_each;struct obj *v_shapes;};
+static void scm_lambda_52(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_113_env {struct obj *v_k_22;struct obj *v_rv_84;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_105_env {struct obj *v_k_22;};
+static void scm_lambda_105(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *v_k_22;};
+static void scm_lambda_97(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_k_22;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_22;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_22;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_22;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_shapes;};
+static void scm_lambda_58(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_k_23;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_65(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_65_closure;
+struct scm_lambda_161_env {struct obj *v_k_24;};
+static void scm_lambda_161(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_159(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_159_closure;
+struct scm_lambda_168_env {struct obj *v_k_25;struct obj *v_rv_93;};
+static void scm_lambda_168(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_167_env {struct obj *v_k_25;};
+static void scm_lambda_167(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_166_env {struct obj *v_k_25;};
+static void scm_lambda_166(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_164_env {struct obj *v_k_25;struct obj *v_rv_104;};
+static void scm_lambda_164(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_162_env {struct obj *v_rv_104;};
+static void scm_lambda_162(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_165_env {struct obj *v_k_26;};
+static void scm_lambda_165(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_163_env {struct obj *v_k_26;};
+static void scm_lambda_163(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_160_env {struct obj *v_k_26;struct obj *v_rv_104;};
+static void scm_lambda_160(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_158_env {struct obj *v_k_26;struct obj *v_rv_104;};
+static void scm_lambda_158(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_157_env {struct obj *v_k_26;struct obj *v_rv_104;struct obj *v_rv_101;};
+static void scm_lambda_157(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_156_env {struct obj *v_k_26;struct obj *v_rv_104;};
+static void scm_lambda_156(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_155_env {struct obj *v_k_26;struct obj *v_rv_104;};
+static void scm_lambda_155(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_152_env {struct obj *v_k_26;struct obj *v_rv_104;};
+static void scm_lambda_152(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_150_env {struct obj *v_k_26;};
+static void scm_lambda_150(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_146_env {struct obj *v_k_26;};
+static void scm_lambda_146(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_142_env {struct obj *v_k_26;struct obj *v_rv_110;};
+static void scm_lambda_142(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_137_env {struct obj *v_k_26;struct obj *v_rv_110;};
+static void scm_lambda_137(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_129_env {struct obj *v_k_26;struct obj *v_rv_110;};
+static void scm_lambda_129(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_122_env {struct obj *v_k_26;struct obj *v_rv_110;struct obj *v__u7cg_u201;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_114_env {struct obj *v_k_26;struct obj *v__u7cg_u201;};
+static void scm_lambda_114(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *v_k_26;struct obj *v__u7cg_u201;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_k_26;struct obj *v__u7cg_u200;struct obj *v__u7cg_u201;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v__u7cg_u200;};
+static void scm_lambda_89(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_k_31;};
+static void scm_lambda_80(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u7cg_u200(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7cg_u200_closure;
+static void scm_lambda_99(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_99_closure;
+static void scm_lambda_90(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_90_closure;
+struct scm_lambda_81_env {struct obj *v_k_31;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_73(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_73_closure;
+static void scm_pick_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_pick_lambda_2_closure;
+struct scm_pick_lambda_15_env {struct obj *v_k_33;struct obj *v_rv_116;};
+static void scm_pick_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_14_env {struct obj *v_c;struct obj *v_k_33;struct obj *v_rv_116;};
+static void scm_pick_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_12_env {struct obj *v_b;struct obj *v_c;struct obj *v_k_33;};
+static void scm_pick_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_10_env {struct obj *v_a_2;struct obj *v_b;};
+static void scm_pick_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_8_env {struct obj *v_a_2;};
+static void scm_pick_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_pick_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_pick_lambda_6_closure;
+struct scm_pick_lambda_13_env {struct obj *v_k_36;};
+static void scm_pick_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_11_env {struct obj *v_k_36;struct obj *v_rv_122;};
+static void scm_pick_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_9_env {struct obj *v_k_36;struct obj *v_rv_122;};
+static void scm_pick_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_7_env {struct obj *v_k_36;struct obj *v_rv_122;};
+static void scm_pick_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_5_env {struct obj *v_k_36;struct obj *v_rv_122;};
+static void scm_pick_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_4_env {struct obj *v_k_36;};
+static void scm_pick_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_3_env {struct obj *v_k_36;};
+static void scm_pick_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pick_lambda_1_env {struct obj *v_k_36;};
+static void scm_pick_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_pick(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_pick_closure;
+struct scm_lambda_115_env {struct obj *v_h_2;struct obj *v_k_37;};
+static void scm_lambda_115(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_107_env {struct obj *v_h_2;struct obj *v_k_37;};
+static void scm_lambda_107(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *v_h_2;struct obj *v_k_37;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_91_env {struct obj *v_h_2;struct obj *v_pick;};
+static void scm_lambda_91(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_147_env {struct obj *v_k_38;struct obj *v_rv_129;};
+static void scm_lambda_147(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_143_env {struct obj *v_k_38;};
+static void scm_lambda_143(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_138_env {struct obj *v_k_38;};
+static void scm_lambda_138(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_130_env {struct obj *v_k_38;};
+static void scm_lambda_130(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_123_env {struct obj *v_k_38;};
+static void scm_lambda_123(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_116_env {struct obj *v_k_38;};
+static void scm_lambda_116(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_108_env {struct obj *v_k_38;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_h_2;};
+static void scm_lambda_101(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_117_env {struct obj *v_k_39;};
+static void scm_lambda_117(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_109(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_109_closure;
+struct scm_lambda_148_env {struct obj *v_k_40;struct obj *v_rv_137;};
+static void scm_lambda_148(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_144_env {struct obj *v_k_40;};
+static void scm_lambda_144(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_139_env {struct obj *v_k_40;};
+static void scm_lambda_139(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_131_env {struct obj *v_k_40;};
+static void scm_lambda_131(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_124_env {struct obj *v_k_40;};
+static void scm_lambda_124(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_h_2;};
+static void scm_lambda_118(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_132_env {struct obj *v_k_41;};
+static void scm_lambda_132(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_125(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_125_closure;
+struct scm_lambda_149_env {struct obj *v_k_42;struct obj *v_rv_143;};
+static void scm_lambda_149(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_145_env {struct obj *v_k_42;};
+static void scm_lambda_145(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_140_env {struct obj *v_k_42;};
+static void scm_lambda_140(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_133_env {struct obj *v__u7carea_u200;};
+static void scm_lambda_133(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_141_env {struct obj *v_k_43;};
+static void scm_lambda_141(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_134_env {struct obj *v_k_43;};
+static void scm_lambda_134(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_126_env {struct obj *v_k_43;struct obj *v__u7carea_u200;};
+static void scm_lambda_126(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_119_env {struct obj *v_k_43;struct obj *v__u7carea_u200;};
+static void scm_lambda_119(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *v_h_2;struct obj *v_k_43;struct obj *v__u7carea_u200;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_102_env {struct obj *v_h_2;struct obj *v_k_43;struct obj *v__u7carea_u200;};
+static void scm_lambda_102(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_92_env {struct obj *v_h_2;struct obj *v_k_43;struct obj *v__u7carea_u200;};
+static void scm_lambda_92(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *parent;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_h_2;struct obj *v_k_43;struct obj *v_pick;struct obj *v__u7carea_u200;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *parent;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *parent;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *parent;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_area;struct obj *v_each;struct obj *v_h_2;struct obj *v_k_43;struct obj *v_pick;struct obj *v_shapes;struct obj *v__u7carea_u200;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *parent;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *parent;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *parent;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_area;struct obj *v_each;struct obj *v_h_2;struct obj *v_k_43;struct obj *v_measure;struct obj *v_pick;struct obj *v_shapes;struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_area;struct obj *v_each;struct obj *v_measure;struct obj *v_pick;struct obj *v_shapes;struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_area;struct obj *v_each;struct obj *v_measure;struct obj *v_shapes;struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_area;struct obj *v_measure;struct obj *v_shapes;struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_area;struct obj *v_measure;struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_area;struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;struct obj *v__u7carea_u202;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v__u7carea_u200;struct obj *v__u7carea_u201;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v__u7carea_u200;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_52;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_52;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_52;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_52;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_52;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_52;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_52;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_52;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static void scm_lambda_99_inlined(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_99_inlined_closure;
+static void scm_lambda_90_inlined(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_90_inlined_closure;
+static struct symbol_obj symbol_one = SYMBOL_OBJ_INIT("one");
+static struct symbol_obj symbol_two = SYMBOL_OBJ_INIT("two");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_one),&(symbol_two),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm__u7carea_u200_lambda_3),"scm__u7carea_u200_lambda_3","case_lambda_values.scm",3},{(void *)(scm__u7carea_u200_lambda_2),"scm__u7carea_u200_lambda_2","case_lambda_values.scm",3},{(void *)(scm__u7carea_u200_lambda_1),"scm__u7carea_u200_lambda_1","case_lambda_values.scm",3},{(void *)(scm__u7carea_u200),"scm__u7carea_u200","case_lambda_values.scm",3},{(void *)(scm__u7carea_u201_lambda_2),"scm__u7carea_u201_lambda_2","case_lambda_values.scm",4},{(void *)(scm__u7carea_u201_lambda_1),"scm__u7carea_u201_lambda_1","case_lambda_values.scm",4},{(void *)(scm__u7carea_u201),"scm__u7carea_u201","case_lambda_values.scm",4},{(void *)(scm__u7carea_u202),"scm__u7carea_u202","case_lambda_values.scm",5},{(void *)(scm_lambda_93),"scm_lambda_93","case_lambda_values.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","case_lambda_values.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","case_lambda_values.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","case_lambda_values.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","case_lambda_values.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","case_lambda_values.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","case_lambda_values.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","case_lambda_values.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","case_lambda_values.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","case_lambda_values.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","case_lambda_values.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","case_lambda_values.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","case_lambda_values.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","case_lambda_values.scm",1},{(void *)(scm_measure_lambda_9),"scm_measure_lambda_9","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_7),"scm_measure_lambda_7","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_26),"scm_measure_lambda_26","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_24),"scm_measure_lambda_24","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_22),"scm_measure_lambda_22","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_19),"scm_measure_lambda_19","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_15),"scm_measure_lambda_15","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_12),"scm_measure_lambda_12","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_10),"scm_measure_lambda_10","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_16),"scm_measure_lambda_16","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_13),"scm_measure_lambda_13","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_28),"scm_measure_lambda_28","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_27),"scm_measure_lambda_27","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_25),"scm_measure_lambda_25","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_23),"scm_measure_lambda_23","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_20),"scm_measure_lambda_20","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_17),"scm_measure_lambda_17","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_21),"scm_measure_lambda_21","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_18),"scm_measure_lambda_18","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_14),"scm_measure_lambda_14","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_11),"scm_measure_lambda_11","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_8),"scm_measure_lambda_8","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_6),"scm_measure_lambda_6","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_5),"scm_measure_lambda_5","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_4),"scm_measure_lambda_4","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_3),"scm_measure_lambda_3","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_2),"scm_measure_lambda_2","case_lambda_values.scm",6},{(void *)(scm_measure_lambda_1),"scm_measure_lambda_1","case_lambda_values.scm",6},{(void *)(scm_measure),"scm_measure","case_lambda_values.scm",6},{(void *)(scm_lambda_36),"scm_lambda_36","case_lambda_values.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","case_lambda_values.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","case_lambda_values.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","case_lambda_values.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","case_lambda_values.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","case_lambda_values.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","case_lambda_values.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","case_lambda_values.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","case_lambda_values.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","case_lambda_values.scm",13},{(void *)(scm_lambda_55),"scm_lambda_55","case_lambda_values.scm",13},{(void *)(scm_lambda_94),"scm_lambda_94","case_lambda_values.scm",13},{(void *)(scm_lambda_85),"scm_lambda_85","case_lambda_values.scm",13},{(void *)(scm_lambda_76),"scm_lambda_76","case_lambda_values.scm",13},{(void *)(scm_lambda_135),"scm_lambda_135","case_lambda_values.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","case_lambda_values.scm",1},{(void *)(scm_lambda_120),"scm_lambda_120","case_lambda_values.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","case_lambda_values.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","case_lambda_values.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","case_lambda_values.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","case_lambda_values.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","case_lambda_values.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","case_lambda_values.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","case_lambda_values.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","case_lambda_values.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","case_lambda_values.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","case_lambda_values.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","case_lambda_values.scm",1},{(void *)(scm_each_lambda_10),"scm_each_lambda_10","case_lambda_values.scm",14},{(void *)(scm_each_lambda_9),"scm_each_lambda_9","case_lambda_values.scm",14},{(void *)(scm_each_lambda_8),"scm_each_lambda_8","case_lambda_values.scm",14},{(void *)(scm_each_lambda_7),"scm_each_lambda_7","case_lambda_values.scm",14},{(void *)(scm_each_lambda_6),"scm_each_lambda_6","case_lambda_values.scm",14},{(void *)(scm_each_lambda_5),"scm_each_lambda_5","case_lambda_values.scm",14},{(void *)(scm_each_lambda_4),"scm_each_lambda_4","case_lambda_values.scm",14},{(void *)(scm_each_lambda_3),"scm_each_lambda_3","case_lambda_values.scm",14},{(void *)(scm_each_lambda_2),"scm_each_lambda_2","case_lambda_values.scm",14},{(void *)(scm_each_lambda_1),"scm_each_lambda_1","case_lambda_values.scm",14},{(void *)(scm_each),"scm_each","case_lambda_values.scm",14},{(void *)(scm_lambda_136),"scm_lambda_136","case_lambda_values.scm",20},{(void *)(scm_lambda_128),"scm_lambda_128","case_lambda_values.scm",20},{(void *)(scm_lambda_121),"scm_lambda_121","case_lambda_values.scm",20},{(void *)(scm_lambda_112),"scm_lambda_112","case_lambda_values.scm",20},{(void *)(scm_lambda_104),"scm_lambda_104","case_lambda_values.scm",20},{(void *)(scm_lambda_96),"scm_lambda_96","case_lambda_values.scm",20},{(void *)(scm_lambda_87),"scm_lambda_87","case_lambda_values.scm",20},{(void *)(scm_lambda_78),"scm_lambda_78","case_lambda_values.scm",20},{(void *)(scm_lambda_69),"scm_lambda_69","case_lambda_values.scm",20},{(void *)(scm_lambda_70),"scm_lambda_70","case_lambda_values.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","case_lambda_values.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","case_lambda_values.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","case_lambda_values.scm",1},{(void *)(scm_lambda_113),"scm_lambda_113","case_lambda_values.scm",1},{(void *)(scm_lambda_105),"scm_lambda_105","case_lambda_values.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","case_lambda_values.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","case_lambda_values.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","case_lambda_values.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","case_lambda_values.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","case_lambda_values.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","case_lambda_values.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","case_lambda_values.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","case_lambda_values.scm",1},{(void *)(scm_lambda_161),"scm_lambda_161","case_lambda_values.scm",1},{(void *)(scm_lambda_159),"scm_lambda_159","case_lambda_values.scm",1},{(void *)(scm_lambda_168),"scm_lambda_168","case_lambda_values.scm",1},{(void *)(scm_lambda_167),"scm_lambda_167","case_lambda_values.scm",1},{(void *)(scm_lambda_166),"scm_lambda_166","case_lambda_values.scm",1},{(void *)(scm_lambda_164),"scm_lambda_164","case_lambda_values.scm",1},{(void *)(scm_lambda_162),"scm_lambda_162","case_lambda_values.scm",1},{(void *)(scm_lambda_165),"scm_lambda_165","case_lambda_values.scm",1},{(void *)(scm_lambda_163),"scm_lambda_163","case_lambda_values.scm",1},{(void *)(scm_lambda_160),"scm_lambda_160","case_lambda_values.scm",1},{(void *)(scm_lambda_158),"scm_lambda_158","case_lambda_values.scm",1},{(void *)(scm_lambda_157),"scm_lambda_157","case_lambda_values.scm",1},{(void *)(scm_lambda_156),"scm_lambda_156","case_lambda_values.scm",1},{(void *)(scm_lambda_155),"scm_lambda_155","case_lambda_values.scm",1},{(void *)(scm_lambda_152),"scm_lambda_152","case_lambda_values.scm",1},{(void *)(scm_lambda_150),"scm_lambda_150","case_lambda_values.scm",1},{(void *)(scm_lambda_146),"scm_lambda_146","case_lambda_values.scm",1},{(void *)(scm_lambda_142),"scm_lambda_142","case_lambda_values.scm",1},{(void *)(scm_lambda_137),"scm_lambda_137","case_lambda_values.scm",1},{(void *)(scm_lambda_129),"scm_lambda_129","case_lambda_values.scm",1},{(void *)(scm_lambda_122),"scm_lambda_122","case_lambda_values.scm",1},{(void *)(scm_lambda_114),"scm_lambda_114","case_lambda_values.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","case_lambda_values.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","case_lambda_values.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","case_lambda_values.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","case_lambda_values.scm",1},{(void *)(scm__u7cg_u200),"scm__u7cg_u200","case_lambda_values.scm",22},{(void *)(scm_lambda_99),"scm_lambda_99","case_lambda_values.scm",22},{(void *)(scm_lambda_90),"scm_lambda_90","case_lambda_values.scm",22},{(void *)(scm_lambda_81),"scm_lambda_81","case_lambda_values.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","case_lambda_values.scm",1},{(void *)(scm_pick_lambda_2),"scm_pick_lambda_2","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_15),"scm_pick_lambda_15","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_14),"scm_pick_lambda_14","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_12),"scm_pick_lambda_12","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_10),"scm_pick_lambda_10","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_8),"scm_pick_lambda_8","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_6),"scm_pick_lambda_6","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_13),"scm_pick_lambda_13","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_11),"scm_pick_lambda_11","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_9),"scm_pick_lambda_9","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_7),"scm_pick_lambda_7","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_5),"scm_pick_lambda_5","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_4),"scm_pick_lambda_4","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_3),"scm_pick_lambda_3","case_lambda_values.scm",25},{(void *)(scm_pick_lambda_1),"scm_pick_lambda_1","case_lambda_values.scm",25},{(void *)(scm_pick),"scm_pick","case_lambda_values.scm",25},{(void *)(scm_lambda_115),"scm_lambda_115","case_lambda_values.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","case_lambda_values.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","case_lambda_values.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","case_lambda_values.scm",1},{(void *)(scm_lambda_147),"scm_lambda_147","case_lambda_values.scm",1},{(void *)(scm_lambda_143),"scm_lambda_143","case_lambda_values.scm",1},{(void *)(scm_lambda_138),"scm_lambda_138","case_lambda_values.scm",1},{(void *)(scm_lambda_130),"scm_lambda_130","case_lambda_values.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","case_lambda_values.scm",1},{(void *)(scm_lambda_116),"scm_lambda_116","case_lambda_values.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","case_lambda_values.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","case_lambda_values.scm",1},{(void *)(scm_lambda_117),"scm_lambda_117","case_lambda_values.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","case_lambda_values.scm",1},{(void *)(scm_lambda_148),"scm_lambda_148","case_lambda_values.scm",1},{(void *)(scm_lambda_144),"scm_lambda_144","case_lambda_values.scm",1},{(void *)(scm_lambda_139),"scm_lambda_139","case_lambda_values.scm",1},{(void *)(scm_lambda_131),"scm_lambda_131","case_lambda_values.scm",1},{(void *)(scm_lambda_124),"scm_lambda_124","case_lambda_values.scm",1},{(void *)(scm_lambda_118),"scm_lambda_118","case_lambda_values.scm",1},{(void *)(scm_lambda_132),"scm_lambda_132","case_lambda_values.scm",1},{(void *)(scm_lambda_125),"scm_lambda_125","case_lambda_values.scm",1},{(void *)(scm_lambda_149),"scm_lambda_149","case_lambda_values.scm",1},{(void *)(scm_lambda_145),"scm_lambda_145","case_lambda_values.scm",1},{(void *)(scm_lambda_140),"scm_lambda_140","case_lambda_values.scm",1},{(void *)(scm_lambda_133),"scm_lambda_133","case_lambda_values.scm",1},{(void *)(scm_lambda_141),"scm_lambda_141","case_lambda_values.scm",1},{(void *)(scm_lambda_134),"scm_lambda_134","case_lambda_values.scm",1},{(void *)(scm_lambda_126),"scm_lambda_126","case_lambda_values.scm",1},{(void *)(scm_lambda_119),"scm_lambda_119","case_lambda_values.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","case_lambda_values.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","case_lambda_values.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","case_lambda_values.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","case_lambda_values.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","case_lambda_values.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","case_lambda_values.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","case_lambda_values.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","case_lambda_values.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","case_lambda_values.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","case_lambda_values.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","case_lambda_values.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","case_lambda_values.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","case_lambda_values.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","case_lambda_values.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","case_lambda_values.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","case_lambda_values.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","case_lambda_values.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","case_lambda_values.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","case_lambda_values.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","case_lambda_values.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","case_lambda_values.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","case_lambda_values.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","case_lambda_values.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","case_lambda_values.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","case_lambda_values.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","case_lambda_values.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","case_lambda_values.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","case_lambda_values.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","case_lambda_values.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","case_lambda_values.scm",1},{(void *)(scm_lambda_99_inlined),"scm_lambda_99_inlined","case_lambda_values.scm",22},{(void *)(scm_lambda_90_inlined),"scm_lambda_90_inlined","case_lambda_values.scm",22},{NULL}};
+
+#line 3 "case_lambda_values.scm"
+static void scm__u7carea_u200_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm__u7carea_u200_lambda_3_env *env = (struct scm__u7carea_u200_lambda_3_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 3 "case_lambda_values.scm"
+static void scm__u7carea_u200_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm__u7carea_u200_lambda_2_env *env = (struct scm__u7carea_u200_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm__u7carea_u200_lambda_3_env );(((struct scm__u7carea_u200_lambda_3_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm__u7carea_u200_lambda_3_env *)(&((var_1)->env)))->v_rv_3)=((env)->v_rv_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm__u7carea_u200_lambda_3,var_1);call_closure_two(var_0,(env)->v_r,var_2);__builtin_unreachable();}
+
+#line 3 "case_lambda_values.scm"
+static void scm__u7carea_u200_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm__u7carea_u200_lambda_1_env *env = (struct scm__u7carea_u200_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm__u7carea_u200_lambda_2_env );(((struct scm__u7carea_u200_lambda_2_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm__u7carea_u200_lambda_2_env *)(&((var_2)->env)))->v_r)=((env)->v_r);(((struct scm__u7carea_u200_lambda_2_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm__u7carea_u200_lambda_2,var_2);call_closure_two(var_1,(env)->v_r,var_3);__builtin_unreachable();}
+
+#line 3 "case_lambda_values.scm"
+static void scm__u7carea_u200(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u7carea_u200_lambda_1_env );(((struct scm__u7carea_u200_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm__u7carea_u200_lambda_1_env *)(&((var_3)->env)))->v_r)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm__u7carea_u200_lambda_1,var_3);call_closure_two(var_2,MAKE_FIXNUM(3),var_4);__builtin_unreachable();}
+static struct closure_obj scm__u7carea_u200_closure = CLOSURE_TWO_OBJ_INIT(scm__u7carea_u200);
+
+#line 4 "case_lambda_values.scm"
+static void scm__u7carea_u201_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm__u7carea_u201_lambda_2_env *env = (struct scm__u7carea_u201_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_h,(env)->v_k_2);__builtin_unreachable();}
+
+#line 4 "case_lambda_values.scm"
+static void scm__u7carea_u201_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u7carea_u201_lambda_1_env *env = (struct scm__u7carea_u201_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u7carea_u201_lambda_2_env );(((struct scm__u7carea_u201_lambda_2_env *)(&((var_3)->env)))->v_h)=(var_0);(((struct scm__u7carea_u201_lambda_2_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm__u7carea_u201_lambda_2,var_3);call_closure_two(var_2,(env)->v_w,var_4);__builtin_unreachable();}
+
+#line 4 "case_lambda_values.scm"
+static void scm__u7carea_u201(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm__u7carea_u201_lambda_1_env );(((struct scm__u7carea_u201_lambda_1_env *)(&((var_2)->env)))->v_w)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm__u7carea_u201_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm__u7carea_u201_closure = CLOSURE_TWO_OBJ_INIT(scm__u7carea_u201);
+
+#line 5 "case_lambda_values.scm"
+static void scm__u7carea_u202(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,MAKE_FIXNUM(0));__builtin_unreachable();}
+static struct closure_obj scm__u7carea_u202_closure = CLOSURE_TWO_OBJ_INIT(scm__u7carea_u202);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_area))->val)=(var_0);GC_WRITE_BARRIER((env)->v_area,((struct cell_obj *)((env)->v_area))->val);call_closure_one((env)->v_k_5,NULL);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,make_case_lambda_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_2)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_93_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_93,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_1)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_84_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_84,var_1);call_closure_two((env)->v_rv_15,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_area)=(((struct scm_lambda_54_env *)(&(((struct env_obj *)(((struct scm_lambda_60_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_area);(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_k_5)=(((struct scm_lambda_54_env *)(&(((struct env_obj *)(((struct scm_lambda_60_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_5);(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_rv_15)=(((struct scm_lambda_54_env *)(&(((struct env_obj *)(((struct scm_lambda_60_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_75,var_1);call_closure_two(((struct scm_lambda_54_env *)(&(((struct env_obj *)(((struct scm_lambda_60_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_12,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_67,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_60,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_area)=(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_area);(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_k_5)=(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_5);(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_rv_15)=(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_15);(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_rv_12)=(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_54,var_1);call_closure_two(var_0,((struct cell_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7carea_u202))->val,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));((env)->v_rv_12)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_50,var_2);call_closure_two(var_1,MAKE_FIXNUM(0),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_45,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_40,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v__u7carea_u201))->val,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));((env)->v_rv_15)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_area)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_28_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_area);(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_5)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_28_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_5);(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_rv_15)=((env)->v_rv_15);(((struct scm_lambda_35_env *)(&((var_2)->env)))->v__u7carea_u201)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_28_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7carea_u201);(((struct scm_lambda_35_env *)(&((var_2)->env)))->v__u7carea_u202)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_28_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7carea_u202);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(var_1,MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_31,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two(var_0,((struct cell_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7carea_u200))->val,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));((env)->v_k_5)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_9_env *env = (struct scm_measure_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_measure_lambda_9_env );(((struct scm_measure_lambda_9_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_measure_lambda_9,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_measure_lambda_7_closure = CLOSURE_TWO_OBJ_INIT(scm_measure_lambda_7);
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_26_env *env = (struct scm_measure_lambda_26_env *)(&((env_in)->env));call_closure_two((env)->v_rv_20,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_24_env *env = (struct scm_measure_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_measure_lambda_26_env );(((struct scm_measure_lambda_26_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_measure_lambda_26_env *)(&((var_2)->env)))->v_rv_20)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_measure_lambda_26,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_22_env *env = (struct scm_measure_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_measure_lambda_24_env );(((struct scm_measure_lambda_24_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_measure_lambda_24,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_19_env *env = (struct scm_measure_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_22_env );(((struct scm_measure_lambda_22_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_22,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_15_env *env = (struct scm_measure_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_19_env );(((struct scm_measure_lambda_19_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_19,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_12_env *env = (struct scm_measure_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_15_env );(((struct scm_measure_lambda_15_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_15,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_measure_lambda_10_env *env = (struct scm_measure_lambda_10_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_measure_lambda_12_env );(((struct scm_measure_lambda_12_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_measure_lambda_12,var_3);call_closure_two(var_2,(env)->v_f,var_4);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_16_env *env = (struct scm_measure_lambda_16_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_13(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_measure_lambda_16_env );(((struct scm_measure_lambda_16_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_measure_lambda_16,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_measure_lambda_13_closure = CLOSURE_TWO_OBJ_INIT(scm_measure_lambda_13);
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_28_env *env = (struct scm_measure_lambda_28_env *)(&((env_in)->env));call_closure_two((env)->v_rv_27,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_27_env *env = (struct scm_measure_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_measure_lambda_28_env );(((struct scm_measure_lambda_28_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_measure_lambda_28_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_measure_lambda_28,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_25_env *env = (struct scm_measure_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_measure_lambda_27_env );(((struct scm_measure_lambda_27_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_3,scm_measure_lambda_27,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_23_env *env = (struct scm_measure_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_25_env );(((struct scm_measure_lambda_25_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_25,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_20_env *env = (struct scm_measure_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_23_env );(((struct scm_measure_lambda_23_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_23,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_17(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_measure_lambda_17_env *env = (struct scm_measure_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_measure_lambda_20_env );(((struct scm_measure_lambda_20_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_measure_lambda_20,var_3);call_closure_two(var_2,(env)->v_f,var_4);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_21_env *env = (struct scm_measure_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_18_env *env = (struct scm_measure_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_measure_lambda_21_env );(((struct scm_measure_lambda_21_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_measure_lambda_21,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_14_env *env = (struct scm_measure_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_17_env );(((struct scm_measure_lambda_17_env *)(&((var_1)->env)))->v_f)=((env)->v_f);OBJECT_CLOSURE_TWO_NEW(var_2,scm_measure_lambda_17,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_measure_lambda_18_env );(((struct scm_measure_lambda_18_env *)(&((var_3)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_4,scm_measure_lambda_18,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_11_env *env = (struct scm_measure_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_14_env );(((struct scm_measure_lambda_14_env *)(&((var_1)->env)))->v_f)=((env)->v_f);(((struct scm_measure_lambda_14_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_14,var_1);call_closure_two((struct obj *)(&(scm_measure_lambda_13_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_8_env *env = (struct scm_measure_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_10_env );(((struct scm_measure_lambda_10_env *)(&((var_1)->env)))->v_f)=((env)->v_f);OBJECT_CLOSURE_TWO_NEW(var_2,scm_measure_lambda_10,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_measure_lambda_11_env );(((struct scm_measure_lambda_11_env *)(&((var_3)->env)))->v_f)=((env)->v_f);(((struct scm_measure_lambda_11_env *)(&((var_3)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_4,scm_measure_lambda_11,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_6_env *env = (struct scm_measure_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_8_env );(((struct scm_measure_lambda_8_env *)(&((var_1)->env)))->v_f)=((env)->v_f);(((struct scm_measure_lambda_8_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_8,var_1);call_closure_two((struct obj *)(&(scm_measure_lambda_7_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_5_env *env = (struct scm_measure_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_6_env );(((struct scm_measure_lambda_6_env *)(&((var_1)->env)))->v_f)=((env)->v_f);(((struct scm_measure_lambda_6_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_6,var_1);call_closure_two((env)->v_rv_38,var_0,var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_4_env *env = (struct scm_measure_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_measure_lambda_5_env );(((struct scm_measure_lambda_5_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm_measure_lambda_5_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_measure_lambda_5_env *)(&((var_2)->env)))->v_rv_38)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_measure_lambda_5,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_3_env *env = (struct scm_measure_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_measure_lambda_4_env );(((struct scm_measure_lambda_4_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm_measure_lambda_4_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_measure_lambda_4,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_2_env *env = (struct scm_measure_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_3_env );(((struct scm_measure_lambda_3_env *)(&((var_1)->env)))->v_f)=((env)->v_f);(((struct scm_measure_lambda_3_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_3,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_measure_lambda_1_env *env = (struct scm_measure_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_measure_lambda_2_env );(((struct scm_measure_lambda_2_env *)(&((var_1)->env)))->v_f)=((env)->v_f);(((struct scm_measure_lambda_2_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_measure_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 6 "case_lambda_values.scm"
+static void scm_measure(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_measure_lambda_1_env );(((struct scm_measure_lambda_1_env *)(&((var_3)->env)))->v_f)=(var_0);(((struct scm_measure_lambda_1_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_measure_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_measure_closure = CLOSURE_TWO_OBJ_INIT(scm_measure);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_area))->val,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_1)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_36_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_36,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_29(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_3)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_32_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_32,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_measure))->val,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));call_closure_two((env)->v_rv_45,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_46_env *)(&((var_2)->env)))->v_rv_45)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_46,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_41,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_33(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_procedure_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_37,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_area))->val,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_38(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_42,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_38_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_38);
+
+#line 13 "case_lambda_values.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_x,(env)->v_k_14);__builtin_unreachable();}
+
+#line 13 "case_lambda_values.scm"
+static void scm_lambda_55(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_k_14)=(var_1);(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_x)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_61,var_3);call_closure_two(var_2,MAKE_FIXNUM(0),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_55_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_55);
+
+#line 13 "case_lambda_values.scm"
+static void scm_lambda_94(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_y,(env)->v_k_15);__builtin_unreachable();}
+
+#line 13 "case_lambda_values.scm"
+static void scm_lambda_85(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_3)->env)))->v_k_15)=(var_1);(((struct scm_lambda_94_env *)(&((var_3)->env)))->v_y)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_94,var_3);call_closure_two(var_2,(env)->v_x_2,var_4);__builtin_unreachable();}
+
+#line 13 "case_lambda_values.scm"
+static void scm_lambda_76(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_2)->env)))->v_x_2)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_85,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm_lambda_76_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_76);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_135(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_135_env *env = (struct scm_lambda_135_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_shapes))->val)=(var_0);GC_WRITE_BARRIER((env)->v_shapes,((struct cell_obj *)((env)->v_shapes))->val);call_closure_one((env)->v_k_17,NULL);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_127(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_135_env );(((struct scm_lambda_135_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_135_env *)(&((var_1)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_135,var_1);call_closure_two((env)->v_rv_62,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_120(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_120_env *env = (struct scm_lambda_120_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_127_env *)(&((var_1)->env)))->v_rv_62)=((env)->v_rv_62);(((struct scm_lambda_127_env *)(&((var_1)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_127,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_111(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_120_env );(((struct scm_lambda_120_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_120_env *)(&((var_2)->env)))->v_rv_62)=((env)->v_rv_62);(((struct scm_lambda_120_env *)(&((var_2)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_120,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_103(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,make_case_lambda_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_111_env *)(&((var_2)->env)))->v_rv_62)=((env)->v_rv_62);(((struct scm_lambda_111_env *)(&((var_2)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_111,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_103_env *)(&((var_1)->env)))->v_rv_62)=((env)->v_rv_62);(((struct scm_lambda_103_env *)(&((var_1)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_103,var_1);call_closure_two((env)->v_rv_59,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_k_17)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_17);(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_rv_62)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_62);(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_rv_59)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_59);(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_shapes)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_95,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_86,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_77,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_76_closure)),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));((env)->v_rv_59)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_rv_62)=((env)->v_rv_62);(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_62,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_56_env *)(&((var_1)->env)))->v_rv_62)=((env)->v_rv_62);(((struct scm_lambda_56_env *)(&((var_1)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_56,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_55_closure)),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));((env)->v_rv_62)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_51_env *)(&((var_2)->env)))->v_rv_62)=((env)->v_rv_62);(((struct scm_lambda_51_env *)(&((var_2)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_51,var_2);call_closure_two(var_1,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_43(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));((env)->v_k_17)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_47,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_area))->val,var_4);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_10_env *env = (struct scm_each_lambda_10_env *)(&((env_in)->env));call_closure_two((env)->v_rv_64,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_9_env *env = (struct scm_each_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_each_lambda_10_env );(((struct scm_each_lambda_10_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_each_lambda_10_env *)(&((var_2)->env)))->v_rv_64)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_each_lambda_10,var_2);call_closure_two(var_1,(env)->v_l,var_3);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_8_env *env = (struct scm_each_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_each_lambda_9_env );(((struct scm_each_lambda_9_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_each_lambda_9_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_ONE_NEW(var_2,scm_each_lambda_9,var_1);call_closure_two(var_0,(env)->v_f_2,var_2);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_7_env *env = (struct scm_each_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_each_lambda_8_env );(((struct scm_each_lambda_8_env *)(&((var_1)->env)))->v_f_2)=((env)->v_f_2);(((struct scm_each_lambda_8_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_each_lambda_8_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_ONE_NEW(var_2,scm_each_lambda_8,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_6_env *env = (struct scm_each_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_each_lambda_7_env );(((struct scm_each_lambda_7_env *)(&((var_2)->env)))->v_f_2)=(((struct scm_each_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_f_2);(((struct scm_each_lambda_7_env *)(&((var_2)->env)))->v_k_18)=(((struct scm_each_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_18);(((struct scm_each_lambda_7_env *)(&((var_2)->env)))->v_l)=(((struct scm_each_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_l);OBJECT_CLOSURE_ONE_NEW(var_3,scm_each_lambda_7,var_2);call_closure_two(var_1,((struct cell_obj *)(((struct scm_each_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_each))->val,var_3);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_5_env *env = (struct scm_each_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_each_lambda_6_env );(((struct scm_each_lambda_6_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_each_lambda_6,var_1);call_closure_two((env)->v_rv_69,var_0,var_2);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_4_env *env = (struct scm_each_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_each_lambda_5_env );(((struct scm_each_lambda_5_env *)(&((var_2)->env)))->v_each)=(((struct scm_each_lambda_1_env *)(&(((struct env_obj *)(((struct scm_each_lambda_2_env *)(&(((struct env_obj *)(((struct scm_each_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_each);(((struct scm_each_lambda_5_env *)(&((var_2)->env)))->v_f_2)=(((struct scm_each_lambda_1_env *)(&(((struct env_obj *)(((struct scm_each_lambda_2_env *)(&(((struct env_obj *)(((struct scm_each_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_f_2);(((struct scm_each_lambda_5_env *)(&((var_2)->env)))->v_k_18)=(((struct scm_each_lambda_1_env *)(&(((struct env_obj *)(((struct scm_each_lambda_2_env *)(&(((struct env_obj *)(((struct scm_each_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_18);(((struct scm_each_lambda_5_env *)(&((var_2)->env)))->v_l)=(((struct scm_each_lambda_1_env *)(&(((struct env_obj *)(((struct scm_each_lambda_2_env *)(&(((struct env_obj *)(((struct scm_each_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_l);(((struct scm_each_lambda_5_env *)(&((var_2)->env)))->v_rv_69)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_each_lambda_5,var_2);call_closure_two(var_1,((struct scm_each_lambda_1_env *)(&(((struct env_obj *)(((struct scm_each_lambda_2_env *)(&(((struct env_obj *)(((struct scm_each_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_l,var_3);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_3_env *env = (struct scm_each_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_each_lambda_4_env );(((struct scm_each_lambda_4_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_each_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_each_lambda_2_env *env = (struct scm_each_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one(((struct scm_each_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_18,NULL);} else {OBJECT_CLOSURE_TWO_NEW(var_1,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_each_lambda_3_env );(((struct scm_each_lambda_3_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_each_lambda_3,var_2);call_closure_two(var_1,((struct scm_each_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_f_2,var_3);}0;__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_each_lambda_1_env *env = (struct scm_each_lambda_1_env *)(&((env_in)->env));((env)->v_l)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_18)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,is_null_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_each_lambda_2_env );(((struct scm_each_lambda_2_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_each_lambda_2,var_3);call_closure_two(var_2,(env)->v_l,var_4);__builtin_unreachable();}
+
+#line 14 "case_lambda_values.scm"
+static void scm_each(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_each_env *env = (struct scm_each_env *)(&((env_in)->env));((env)->v_f_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_each_lambda_1_env );(((struct scm_each_lambda_1_env *)(&((var_2)->env)))->v_each)=((env)->v_each);(((struct scm_each_lambda_1_env *)(&((var_2)->env)))->v_f_2)=((env)->v_f_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_each_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_136(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_136_env *env = (struct scm_lambda_136_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_128(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_128_env *env = (struct scm_lambda_128_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_136_env );(((struct scm_lambda_136_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_136,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_121(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_121_env *env = (struct scm_lambda_121_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_128_env );(((struct scm_lambda_128_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_128,var_1);call_closure_two((env)->v_rv_75,var_0,var_2);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_112(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_121_env );(((struct scm_lambda_121_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_121_env *)(&((var_2)->env)))->v_rv_75)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_121,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_112,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_96(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_104,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_87(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_96,var_1);call_closure_two(var_0,MAKE_FIXNUM(10),var_2);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_87,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 20 "case_lambda_values.scm"
+static void scm_lambda_69(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_78,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_69_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_69);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_shapes))->val,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_70_env *)(&((var_1)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_70,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_69_closure)),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_63,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_52(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_3)->env)))->v_k_21)=(var_1);(((struct scm_lambda_57_env *)(&((var_3)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_57,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_each))->val,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));call_closure_two((env)->v_rv_84,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_105(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_105_env *env = (struct scm_lambda_105_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_113_env *)(&((var_2)->env)))->v_rv_84)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_113,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_97(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_105_env );(((struct scm_lambda_105_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_105,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_97,var_1);call_closure_two(var_0,MAKE_FIXNUM(5),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_88,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_79,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_58(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_shapes))->val,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_65(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_72,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_65_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_65);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_161(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_161_env *env = (struct scm_lambda_161_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_159(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_161_env );(((struct scm_lambda_161_env *)(&((var_3)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_161,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_159_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_159);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_168(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_168_env *env = (struct scm_lambda_168_env *)(&((env_in)->env));call_closure_two((env)->v_rv_93,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_167(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_167_env *env = (struct scm_lambda_167_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_168_env );(((struct scm_lambda_168_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_168_env *)(&((var_2)->env)))->v_rv_93)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_168,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_166(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_166_env *env = (struct scm_lambda_166_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_167_env );(((struct scm_lambda_167_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_167,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_164(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_164_env *env = (struct scm_lambda_164_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_166_env );(((struct scm_lambda_166_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_166,var_1);call_closure_two(var_0,(env)->v_rv_104,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_162(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_162_env *env = (struct scm_lambda_162_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_164_env );(((struct scm_lambda_164_env *)(&((var_3)->env)))->v_k_25)=(var_1);(((struct scm_lambda_164_env *)(&((var_3)->env)))->v_rv_104)=((env)->v_rv_104);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_164,var_3);call_closure_two(var_2,(env)->v_rv_104,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_165(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_165_env *env = (struct scm_lambda_165_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_163(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_163_env *env = (struct scm_lambda_163_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_165_env );(((struct scm_lambda_165_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_165,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_160(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_160_env *env = (struct scm_lambda_160_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_162_env );(((struct scm_lambda_162_env *)(&((var_1)->env)))->v_rv_104)=((env)->v_rv_104);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_162,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_163_env );(((struct scm_lambda_163_env *)(&((var_3)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_163,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_158(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_158_env *env = (struct scm_lambda_158_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_160_env );(((struct scm_lambda_160_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_160_env *)(&((var_1)->env)))->v_rv_104)=((env)->v_rv_104);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_160,var_1);call_closure_two((struct obj *)(&(scm_lambda_159_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_157(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_157_env *env = (struct scm_lambda_157_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_158_env );(((struct scm_lambda_158_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_158_env *)(&((var_1)->env)))->v_rv_104)=((env)->v_rv_104);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_158,var_1);call_closure_two((env)->v_rv_101,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_156(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_156_env *env = (struct scm_lambda_156_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_157_env );(((struct scm_lambda_157_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_157_env *)(&((var_2)->env)))->v_rv_104)=((env)->v_rv_104);(((struct scm_lambda_157_env *)(&((var_2)->env)))->v_rv_101)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_157,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_155(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_155_env *env = (struct scm_lambda_155_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_156_env );(((struct scm_lambda_156_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_156_env *)(&((var_2)->env)))->v_rv_104)=((env)->v_rv_104);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_156,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_152(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_152_env *env = (struct scm_lambda_152_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_155_env );(((struct scm_lambda_155_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_155_env *)(&((var_1)->env)))->v_rv_104)=((env)->v_rv_104);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_155,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_two)),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_150(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_150_env *env = (struct scm_lambda_150_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_152_env );(((struct scm_lambda_152_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_152_env *)(&((var_2)->env)))->v_rv_104)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_152,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_one)),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_146(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_146_env *env = (struct scm_lambda_146_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,make_case_lambda_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_150_env );(((struct scm_lambda_150_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_150,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_142(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_142_env *env = (struct scm_lambda_142_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_146_env );(((struct scm_lambda_146_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_146,var_1);call_closure_two((env)->v_rv_110,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_137(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_137_env *env = (struct scm_lambda_137_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_142_env );(((struct scm_lambda_142_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_142_env *)(&((var_1)->env)))->v_rv_110)=((env)->v_rv_110);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_142,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_129(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_129_env *env = (struct scm_lambda_129_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_137_env );(((struct scm_lambda_137_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_137_env *)(&((var_2)->env)))->v_rv_110)=((env)->v_rv_110);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_137,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_129_env );(((struct scm_lambda_129_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_129_env *)(&((var_1)->env)))->v_rv_110)=((env)->v_rv_110);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_129,var_1);call_closure_two(var_0,(env)->v__u7cg_u201,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_114(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_114_env *env = (struct scm_lambda_114_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_122_env *)(&((var_2)->env)))->v_rv_110)=(var_0);(((struct scm_lambda_122_env *)(&((var_2)->env)))->v__u7cg_u201)=((env)->v__u7cg_u201);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_122,var_2);call_closure_two(var_1,MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_114_env );(((struct scm_lambda_114_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_114_env *)(&((var_2)->env)))->v__u7cg_u201)=((env)->v__u7cg_u201);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_114,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_106_env *)(&((var_1)->env)))->v__u7cg_u201)=((env)->v__u7cg_u201);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_106,var_1);call_closure_two(var_0,(env)->v__u7cg_u200,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_89(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_3)->env)))->v_k_26)=(var_1);(((struct scm_lambda_98_env *)(&((var_3)->env)))->v__u7cg_u200)=((env)->v__u7cg_u200);(((struct scm_lambda_98_env *)(&((var_3)->env)))->v__u7cg_u201)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_98,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_80(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_2)->env)))->v__u7cg_u200)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_89,var_2);call_closure_two(var_3,(struct obj *)(&(scm_lambda_90_inlined_closure)),(env)->v_k_31);__builtin_unreachable();}
+
+#line 22 "case_lambda_values.scm"
+static void scm__u7cg_u200(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,(struct obj *)(&(symbol_one)));__builtin_unreachable();}
+static struct closure_obj scm__u7cg_u200_closure = CLOSURE_TWO_OBJ_INIT(scm__u7cg_u200);
+
+#line 22 "case_lambda_values.scm"
+static void scm_lambda_99(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,(struct obj *)(&(symbol_two)));__builtin_unreachable();}
+static struct closure_obj scm_lambda_99_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_99);
+
+#line 22 "case_lambda_values.scm"
+static void scm_lambda_90(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,(struct obj *)(&(scm_lambda_99_closure)));__builtin_unreachable();}
+static struct closure_obj scm_lambda_90_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_90);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));call_closure_two(var_0,(struct obj *)(&(scm_lambda_90_closure)),(env)->v_k_31);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_73(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_2)->env)))->v_k_31)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_80,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_4)->env)))->v_k_31)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_81,var_4);call_closure_two(var_3,(struct obj *)(&(scm__u7cg_u200_closure)),var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_73_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_73);
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm_pick_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_pick_lambda_2);
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_15_env *env = (struct scm_pick_lambda_15_env *)(&((env_in)->env));call_closure_two((env)->v_rv_116,var_0,(env)->v_k_33);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_14_env *env = (struct scm_pick_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_pick_lambda_15_env );(((struct scm_pick_lambda_15_env *)(&((var_1)->env)))->v_k_33)=((env)->v_k_33);(((struct scm_pick_lambda_15_env *)(&((var_1)->env)))->v_rv_116)=((env)->v_rv_116);OBJECT_CLOSURE_ONE_NEW(var_2,scm_pick_lambda_15,var_1);call_closure_two(var_0,(env)->v_c,var_2);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_12_env *env = (struct scm_pick_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_pick_lambda_14_env );(((struct scm_pick_lambda_14_env *)(&((var_2)->env)))->v_c)=((env)->v_c);(((struct scm_pick_lambda_14_env *)(&((var_2)->env)))->v_k_33)=((env)->v_k_33);(((struct scm_pick_lambda_14_env *)(&((var_2)->env)))->v_rv_116)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_pick_lambda_14,var_2);call_closure_two(var_1,(env)->v_b,var_3);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_pick_lambda_10_env *env = (struct scm_pick_lambda_10_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_pick_lambda_12_env );(((struct scm_pick_lambda_12_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_pick_lambda_12_env *)(&((var_3)->env)))->v_c)=(var_0);(((struct scm_pick_lambda_12_env *)(&((var_3)->env)))->v_k_33)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_pick_lambda_12,var_3);call_closure_two(var_2,(env)->v_a_2,var_4);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_pick_lambda_8_env *env = (struct scm_pick_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_pick_lambda_10_env );(((struct scm_pick_lambda_10_env *)(&((var_2)->env)))->v_a_2)=((env)->v_a_2);(((struct scm_pick_lambda_10_env *)(&((var_2)->env)))->v_b)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_pick_lambda_10,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_pick_lambda_8_env );(((struct scm_pick_lambda_8_env *)(&((var_2)->env)))->v_a_2)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_pick_lambda_8,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm_pick_lambda_6_closure = CLOSURE_TWO_OBJ_INIT(scm_pick_lambda_6);
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_13_env *env = (struct scm_pick_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,make_case_lambda_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_36);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_11_env *env = (struct scm_pick_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_pick_lambda_13_env );(((struct scm_pick_lambda_13_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_2,scm_pick_lambda_13,var_1);call_closure_two((env)->v_rv_122,var_0,var_2);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_9_env *env = (struct scm_pick_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_pick_lambda_11_env );(((struct scm_pick_lambda_11_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);(((struct scm_pick_lambda_11_env *)(&((var_1)->env)))->v_rv_122)=((env)->v_rv_122);OBJECT_CLOSURE_ONE_NEW(var_2,scm_pick_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_7_env *env = (struct scm_pick_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_pick_lambda_9_env );(((struct scm_pick_lambda_9_env *)(&((var_2)->env)))->v_k_36)=((env)->v_k_36);(((struct scm_pick_lambda_9_env *)(&((var_2)->env)))->v_rv_122)=((env)->v_rv_122);OBJECT_CLOSURE_ONE_NEW(var_3,scm_pick_lambda_9,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_5_env *env = (struct scm_pick_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_pick_lambda_7_env );(((struct scm_pick_lambda_7_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);(((struct scm_pick_lambda_7_env *)(&((var_1)->env)))->v_rv_122)=((env)->v_rv_122);OBJECT_CLOSURE_ONE_NEW(var_2,scm_pick_lambda_7,var_1);call_closure_two(var_0,(struct obj *)(&(scm_pick_lambda_6_closure)),var_2);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_4_env *env = (struct scm_pick_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_pick_lambda_5_env );(((struct scm_pick_lambda_5_env *)(&((var_2)->env)))->v_k_36)=((env)->v_k_36);(((struct scm_pick_lambda_5_env *)(&((var_2)->env)))->v_rv_122)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_pick_lambda_5,var_2);call_closure_two(var_1,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_3_env *env = (struct scm_pick_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_pick_lambda_4_env );(((struct scm_pick_lambda_4_env *)(&((var_2)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_3,scm_pick_lambda_4,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_pick_lambda_1_env *env = (struct scm_pick_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_pick_lambda_3_env );(((struct scm_pick_lambda_3_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_2,scm_pick_lambda_3,var_1);call_closure_two(var_0,(struct obj *)(&(scm_pick_lambda_2_closure)),var_2);__builtin_unreachable();}
+
+#line 25 "case_lambda_values.scm"
+static void scm_pick(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_pick_lambda_1_env );(((struct scm_pick_lambda_1_env *)(&((var_3)->env)))->v_k_36)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_pick_lambda_1,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_pick_closure = CLOSURE_TWO_OBJ_INIT(scm_pick);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_115(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_115_env *env = (struct scm_lambda_115_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_h_2))->val)=(var_0);GC_WRITE_BARRIER((env)->v_h_2,((struct cell_obj *)((env)->v_h_2))->val);call_closure_one((env)->v_k_37,NULL);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_107(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_107_env *env = (struct scm_lambda_107_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_115_env );(((struct scm_lambda_115_env *)(&((var_1)->env)))->v_h_2)=((env)->v_h_2);(((struct scm_lambda_115_env *)(&((var_1)->env)))->v_k_37)=((env)->v_k_37);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_115,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_107_env );(((struct scm_lambda_107_env *)(&((var_1)->env)))->v_h_2)=((env)->v_h_2);(((struct scm_lambda_107_env *)(&((var_1)->env)))->v_k_37)=((env)->v_k_37);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_107,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_91(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_3)->env)))->v_h_2)=((env)->v_h_2);(((struct scm_lambda_100_env *)(&((var_3)->env)))->v_k_37)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_100,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_pick))->val,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_147(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_147_env *env = (struct scm_lambda_147_env *)(&((env_in)->env));call_closure_two((env)->v_rv_129,var_0,(env)->v_k_38);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_143(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_143_env *env = (struct scm_lambda_143_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_147_env );(((struct scm_lambda_147_env *)(&((var_2)->env)))->v_k_38)=((env)->v_k_38);(((struct scm_lambda_147_env *)(&((var_2)->env)))->v_rv_129)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_147,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_138(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_138_env *env = (struct scm_lambda_138_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_143_env );(((struct scm_lambda_143_env *)(&((var_2)->env)))->v_k_38)=((env)->v_k_38);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_143,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_130(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_130_env *env = (struct scm_lambda_130_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_138_env );(((struct scm_lambda_138_env *)(&((var_1)->env)))->v_k_38)=((env)->v_k_38);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_138,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_123(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_123_env *env = (struct scm_lambda_123_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_130_env );(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_k_38)=((env)->v_k_38);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_130,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_116(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_116_env *env = (struct scm_lambda_116_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_123_env );(((struct scm_lambda_123_env *)(&((var_1)->env)))->v_k_38)=((env)->v_k_38);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_123,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_116_env );(((struct scm_lambda_116_env *)(&((var_1)->env)))->v_k_38)=((env)->v_k_38);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_116,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_101(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_3)->env)))->v_k_38)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_108,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h_2))->val,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_117(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_117_env *env = (struct scm_lambda_117_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_39);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_109(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_117_env );(((struct scm_lambda_117_env *)(&((var_3)->env)))->v_k_39)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_117,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_109_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_109);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_148(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_148_env *env = (struct scm_lambda_148_env *)(&((env_in)->env));call_closure_two((env)->v_rv_137,var_0,(env)->v_k_40);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_144(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_144_env *env = (struct scm_lambda_144_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_148_env );(((struct scm_lambda_148_env *)(&((var_2)->env)))->v_k_40)=((env)->v_k_40);(((struct scm_lambda_148_env *)(&((var_2)->env)))->v_rv_137)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_148,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_139(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_139_env *env = (struct scm_lambda_139_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_144_env );(((struct scm_lambda_144_env *)(&((var_2)->env)))->v_k_40)=((env)->v_k_40);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_144,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_131(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_131_env *env = (struct scm_lambda_131_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_139_env );(((struct scm_lambda_139_env *)(&((var_1)->env)))->v_k_40)=((env)->v_k_40);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_139,var_1);call_closure_two(var_0,MAKE_FIXNUM(7),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_124(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_124_env *env = (struct scm_lambda_124_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_131_env );(((struct scm_lambda_131_env *)(&((var_1)->env)))->v_k_40)=((env)->v_k_40);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_131,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_118(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,case_lambda_clause_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_124_env );(((struct scm_lambda_124_env *)(&((var_3)->env)))->v_k_40)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_124,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h_2))->val,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_132(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_132_env *env = (struct scm_lambda_132_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_41);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_125(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_132_env );(((struct scm_lambda_132_env *)(&((var_3)->env)))->v_k_41)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_132,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_125_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_125);
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_149(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_149_env *env = (struct scm_lambda_149_env *)(&((env_in)->env));call_closure_two((env)->v_rv_143,var_0,(env)->v_k_42);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_145(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_145_env *env = (struct scm_lambda_145_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_149_env );(((struct scm_lambda_149_env *)(&((var_2)->env)))->v_k_42)=((env)->v_k_42);(((struct scm_lambda_149_env *)(&((var_2)->env)))->v_rv_143)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_149,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_140(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_140_env *env = (struct scm_lambda_140_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_145_env );(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_k_42)=((env)->v_k_42);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_145,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_133(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_133_env *env = (struct scm_lambda_133_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_140_env );(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_k_42)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_140,var_2);call_closure_two(((struct cell_obj *)((env)->v__u7carea_u200))->val,MAKE_FIXNUM(5),var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_141(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_141_env *env = (struct scm_lambda_141_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_43);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_134(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_134_env *env = (struct scm_lambda_134_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_141_env );(((struct scm_lambda_141_env *)(&((var_2)->env)))->v_k_43)=((env)->v_k_43);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_141,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_126(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_126_env *env = (struct scm_lambda_126_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_133_env );(((struct scm_lambda_133_env *)(&((var_1)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_133,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_134_env );(((struct scm_lambda_134_env *)(&((var_3)->env)))->v_k_43)=((env)->v_k_43);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_134,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_119(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_119_env *env = (struct scm_lambda_119_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_126_env );(((struct scm_lambda_126_env *)(&((var_1)->env)))->v_k_43)=((env)->v_k_43);(((struct scm_lambda_126_env *)(&((var_1)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_126,var_1);call_closure_two((struct obj *)(&(scm_lambda_125_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_1)->env)))->v_h_2)=((env)->v_h_2);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_118,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_119_env );(((struct scm_lambda_119_env *)(&((var_3)->env)))->v_k_43)=((env)->v_k_43);(((struct scm_lambda_119_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_119,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_102(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_1)->env)))->v_h_2)=((env)->v_h_2);(((struct scm_lambda_110_env *)(&((var_1)->env)))->v_k_43)=((env)->v_k_43);(((struct scm_lambda_110_env *)(&((var_1)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_110,var_1);call_closure_two((struct obj *)(&(scm_lambda_109_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_92(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_h_2)=((env)->v_h_2);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_101,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_3)->env)))->v_h_2)=((env)->v_h_2);(((struct scm_lambda_102_env *)(&((var_3)->env)))->v_k_43)=((env)->v_k_43);(((struct scm_lambda_102_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_102,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_pick))->val)=((struct obj *)(&(scm_pick_closure)));GC_WRITE_BARRIER(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_pick,((struct cell_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_pick))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_1)->env)))->v_h_2)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_h_2);(((struct scm_lambda_91_env *)(&((var_1)->env)))->v_pick)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_pick);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_91,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_3)->env)))->v_h_2)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_h_2);(((struct scm_lambda_92_env *)(&((var_3)->env)))->v_k_43)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_43);(((struct scm_lambda_92_env *)(&((var_3)->env)))->v__u7carea_u200)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7carea_u200);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_92,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_74,var_1);call_closure_two((struct obj *)(&(scm_lambda_73_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_h_2)=(((struct scm_lambda_39_env *)(&(((struct env_obj *)(((struct scm_lambda_44_env *)(&(((struct env_obj *)(((struct scm_lambda_53_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h_2);(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_k_43)=(((struct scm_lambda_39_env *)(&(((struct env_obj *)(((struct scm_lambda_44_env *)(&(((struct env_obj *)(((struct scm_lambda_53_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_43);(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_pick)=(((struct scm_lambda_39_env *)(&(((struct env_obj *)(((struct scm_lambda_44_env *)(&(((struct env_obj *)(((struct scm_lambda_53_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_pick);(((struct scm_lambda_66_env *)(&((var_1)->env)))->v__u7carea_u200)=(((struct scm_lambda_39_env *)(&(((struct env_obj *)(((struct scm_lambda_44_env *)(&(((struct env_obj *)(((struct scm_lambda_53_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7carea_u200);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_66,var_1);call_closure_two((struct obj *)(&(scm_lambda_65_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_shapes)=(((struct scm_lambda_39_env *)(&(((struct env_obj *)(((struct scm_lambda_44_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_shapes);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_58,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_59,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_each_env );(((struct scm_each_env *)(&((var_1)->env)))->v_each)=(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_each);OBJECT_CLOSURE_TWO_NEW(var_2,scm_each,var_1);(((struct cell_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_each))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_each,((struct cell_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_each))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_each)=(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_each);(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_shapes)=(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_shapes);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_52,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_53,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_1)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_43_env *)(&((var_1)->env)))->v_shapes)=((env)->v_shapes);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_43,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_area)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_area);(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_each)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_each);(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_h_2)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h_2);(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_k_43)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_43);(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_pick)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_pick);(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_shapes)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_shapes);(((struct scm_lambda_39_env *)(&((var_1)->env)))->v__u7carea_u200)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7carea_u200);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_39,var_1);call_closure_two((struct obj *)(&(scm_lambda_38_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_1)->env)))->v_area)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_area);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_33,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_34,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_measure))->val)=((struct obj *)(&(scm_measure_closure)));GC_WRITE_BARRIER(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_measure,((struct cell_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_measure))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_area)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_area);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_measure)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_measure);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_29,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_30,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_h_2)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_43)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v__u7carea_u200))->val)=((struct obj *)(&(scm__u7carea_u200_closure)));GC_WRITE_BARRIER((env)->v__u7carea_u200,((struct cell_obj *)((env)->v__u7carea_u200))->val);(((struct cell_obj *)((env)->v__u7carea_u201))->val)=((struct obj *)(&(scm__u7carea_u201_closure)));GC_WRITE_BARRIER((env)->v__u7carea_u201,((struct cell_obj *)((env)->v__u7carea_u201))->val);(((struct cell_obj *)((env)->v__u7carea_u202))->val)=((struct obj *)(&(scm__u7carea_u202_closure)));GC_WRITE_BARRIER((env)->v__u7carea_u202,((struct cell_obj *)((env)->v__u7carea_u202))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_23_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_23_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_23_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_23,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_24,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_pick)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_each)=((env)->v_each);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_measure)=((env)->v_measure);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_pick)=((env)->v_pick);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_shapes)=((env)->v_shapes);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_18,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_each)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_each)=((env)->v_each);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_measure)=((env)->v_measure);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_shapes)=((env)->v_shapes);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_16,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_shapes)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_measure)=((env)->v_measure);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_shapes)=((env)->v_shapes);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_14,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_measure)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_measure)=((env)->v_measure);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_area)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u7carea_u202)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u7carea_u202)=((env)->v__u7carea_u202);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u7carea_u201)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v__u7carea_u201)=((env)->v__u7carea_u201);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u7carea_u200)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v__u7carea_u200)=((env)->v__u7carea_u200);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_52);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_k_52)=((env)->v_k_52);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_17,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_k_52)=((env)->v_k_52);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_15,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_52)=((env)->v_k_52);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_52)=((env)->v_k_52);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_52)=((env)->v_k_52);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_52)=((env)->v_k_52);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_52)=((env)->v_k_52);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "case_lambda_values.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_52)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 22 "case_lambda_values.scm"
+static void scm_lambda_99_inlined(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,(struct obj *)(&(symbol_two)));__builtin_unreachable();}
+static struct closure_obj scm_lambda_99_inlined_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_99_inlined);
+
+#line 22 "case_lambda_values.scm"
+static void scm_lambda_90_inlined(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,(struct obj *)(&(scm_lambda_99_inlined_closure)));__builtin_unreachable();}
+static struct closure_obj scm_lambda_90_inlined_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_90_inlined);
+
+#line 1 "case_lambda_values.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 1151 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
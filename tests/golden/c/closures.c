@@ -0,0 +1,1034 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_f_lambda_21_env {struct obj *v_k;struct obj *v_rv_15;};
+static void scm_f_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_20_env {struct obj *v_k;struct obj *v_rv_15;struct obj *v_rv_13;};
+static void scm_f_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_19_env {struct obj *parent;};
+static void scm_f_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_18_env {struct obj *parent;};
+static void scm_f_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_17_env {struct obj *v_k;struct obj *v_rv_15;struct obj *v_rv_13;struct obj *v_rv_12;struct obj *v_rv_11;struct obj *v_rv_10;};
+static void scm_f_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_16_env {struct obj *parent;};
+static void scm_f_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_15_env {struct obj *parent;};
+static void scm_f_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_14_env {struct obj *parent;struct obj *v_rv_8;};
+static void scm_f_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_13_env {struct obj *v_c;struct obj *v_e;struct obj *v_g;struct obj *v_k;struct obj *v_rv_15;struct obj *v_rv_13;struct obj *v_rv_12;struct obj *v_rv_11;struct obj *v_rv_10;struct obj *v_rv_9;};
+static void scm_f_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_12_env {struct obj *parent;struct obj *v_rv_10;};
+static void scm_f_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_11_env {struct obj *parent;struct obj *v_rv_11;};
+static void scm_f_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_10_env {struct obj *parent;struct obj *v_rv_12;};
+static void scm_f_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_9_env {struct obj *v_a;struct obj *v_b;struct obj *v_c;struct obj *v_d;struct obj *v_e;struct obj *v_g;struct obj *v_k;struct obj *v_rv_15;struct obj *v_rv_13;};
+static void scm_f_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_8_env {struct obj *parent;};
+static void scm_f_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_7_env {struct obj *parent;struct obj *v_rv_15;};
+static void scm_f_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_6_env {struct obj *parent;};
+static void scm_f_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_5_env {struct obj *v_a;struct obj *v_b;struct obj *v_c;struct obj *v_d;struct obj *v_e;struct obj *v_g;struct obj *v_k;};
+static void scm_f_lambda_5(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_9_env {struct obj *v_k_2;struct obj *v_rv_25;};
+static void scm_g_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_8_env {struct obj *v_k_2;struct obj *v_rv_25;struct obj *v_rv_24;};
+static void scm_g_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_7_env {struct obj *parent;};
+static void scm_g_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_6_env {struct obj *parent;};
+static void scm_g_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_5_env {struct obj *parent;};
+static void scm_g_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_4_env {struct obj *v_d;struct obj *v_e;struct obj *v_k_2;struct obj *v_rv_25;struct obj *v_rv_24;struct obj *v_rv_23;struct obj *v_rv_22;};
+static void scm_g_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_3_env {struct obj *parent;struct obj *v_rv_23;};
+static void scm_g_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_2_env {struct obj *parent;struct obj *v_rv_24;};
+static void scm_g_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_lambda_1_env {struct obj *parent;struct obj *v_rv_25;};
+static void scm_g_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_env {struct obj *v_a;struct obj *v_b;struct obj *v_c;struct obj *v_d;struct obj *v_e;struct obj *v_k_2;};
+static void scm_g(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_4_env {struct obj *v_a;struct obj *v_b;struct obj *v_c;struct obj *v_d;struct obj *v_e;};
+static void scm_f_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_3_env {struct obj *v_a;struct obj *v_b;struct obj *v_c;struct obj *v_d;};
+static void scm_f_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_2_env {struct obj *v_a;struct obj *v_b;struct obj *v_c;};
+static void scm_f_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_1_env {struct obj *v_a;struct obj *v_b;};
+static void scm_f_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_env {struct obj *v_a;};
+static void scm_f(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_k_8;struct obj *v_rv_27;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_8;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_8;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_8;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_k_8;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_k_8;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_8;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_f;};
+static void scm_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_9;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_12_closure;
+struct scm_counter_lambda_17_env {struct obj *v_k_10;struct obj *v_rv_43;};
+static void scm_counter_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_16_env {struct obj *v_k_10;struct obj *v_rv_43;struct obj *v_rv_41;};
+static void scm_counter_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_15_env {struct obj *parent;};
+static void scm_counter_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_14_env {struct obj *parent;};
+static void scm_counter_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_13_env {struct obj *v_d_2;struct obj *v_k_10;struct obj *v_rv_43;struct obj *v_rv_41;struct obj *v_rv_40;struct obj *v_rv_39;};
+static void scm_counter_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_12_env {struct obj *parent;struct obj *v_rv_39;};
+static void scm_counter_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_11_env {struct obj *parent;struct obj *v_rv_40;};
+static void scm_counter_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_10_env {struct obj *parent;struct obj *v_rv_41;};
+static void scm_counter_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_9_env {struct obj *v_a_2;struct obj *v_b_2;struct obj *v_c_2;struct obj *v_d_2;struct obj *v_k_10;struct obj *v_rv_43;};
+static void scm_counter_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_8_env {struct obj *parent;struct obj *v_rv_43;};
+static void scm_counter_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_7_env {struct obj *parent;};
+static void scm_counter_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_6_env {struct obj *parent;};
+static void scm_counter_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_5_env {struct obj *v_a_2;struct obj *v_b_2;struct obj *v_bump;struct obj *v_c_2;struct obj *v_d_2;struct obj *v_k_10;struct obj *v_n;};
+static void scm_counter_lambda_5(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_8_env {struct obj *v_k_11;struct obj *v_n;};
+static void scm_bump_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_7_env {struct obj *v_k_11;struct obj *v_n;struct obj *v_rv_53;};
+static void scm_bump_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_6_env {struct obj *parent;};
+static void scm_bump_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_5_env {struct obj *parent;};
+static void scm_bump_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_4_env {struct obj *v_d_2;struct obj *v_k_11;struct obj *v_n;struct obj *v_rv_53;struct obj *v_rv_52;struct obj *v_rv_51;};
+static void scm_bump_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_3_env {struct obj *parent;struct obj *v_rv_51;};
+static void scm_bump_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_2_env {struct obj *parent;struct obj *v_rv_52;};
+static void scm_bump_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_1_env {struct obj *parent;struct obj *v_rv_53;};
+static void scm_bump_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_env {struct obj *v_a_2;struct obj *v_b_2;struct obj *v_c_2;struct obj *v_d_2;struct obj *v_k_11;struct obj *v_n;};
+static void scm_bump(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_4_env {struct obj *v_a_2;struct obj *v_b_2;struct obj *v_c_2;struct obj *v_d_2;struct obj *v_n;};
+static void scm_counter_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_3_env {struct obj *v_a_2;struct obj *v_b_2;struct obj *v_c_2;struct obj *v_d_2;};
+static void scm_counter_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_2_env {struct obj *v_a_2;struct obj *v_b_2;struct obj *v_c_2;};
+static void scm_counter_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_lambda_1_env {struct obj *v_a_2;struct obj *v_b_2;};
+static void scm_counter_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_counter_env {struct obj *v_a_2;};
+static void scm_counter(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_17;struct obj *v_rv_55;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_17;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_17;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_17;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_17;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_17;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_counter;};
+static void scm_lambda_19(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_18;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_23_closure;
+struct scm_nest_lambda_38_env {struct obj *v_k_19;struct obj *v_rv_94;};
+static void scm_nest_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_37_env {struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_80;};
+static void scm_nest_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_36_env {struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_80;struct obj *v_rv_79;};
+static void scm_nest_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_35_env {struct obj *parent;};
+static void scm_nest_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_34_env {struct obj *parent;};
+static void scm_nest_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_33_env {struct obj *parent;};
+static void scm_nest_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_32_env {struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_80;struct obj *v_rv_79;struct obj *v_rv_78;struct obj *v_rv_77;struct obj *v_rv_76;struct obj *v_rv_75;};
+static void scm_nest_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_31_env {struct obj *parent;};
+static void scm_nest_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_30_env {struct obj *parent;};
+static void scm_nest_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_29_env {struct obj *parent;};
+static void scm_nest_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_28_env {struct obj *v_e_2;struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_80;struct obj *v_rv_79;struct obj *v_rv_78;struct obj *v_rv_77;struct obj *v_rv_76;struct obj *v_rv_75;struct obj *v_rv_74;struct obj *v_rv_73;struct obj *v_rv_72;};
+static void scm_nest_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_27_env {struct obj *parent;struct obj *v_rv_72;};
+static void scm_nest_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_26_env {struct obj *parent;struct obj *v_rv_73;};
+static void scm_nest_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_25_env {struct obj *parent;struct obj *v_rv_74;};
+static void scm_nest_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_24_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_c_3;struct obj *v_d_3;struct obj *v_e_2;struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_80;struct obj *v_rv_79;struct obj *v_rv_78;struct obj *v_rv_77;struct obj *v_rv_76;struct obj *v_rv_75;};
+static void scm_nest_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_23_env {struct obj *parent;struct obj *v_rv_76;};
+static void scm_nest_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_22_env {struct obj *parent;struct obj *v_rv_77;};
+static void scm_nest_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_21_env {struct obj *parent;struct obj *v_rv_78;};
+static void scm_nest_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_20_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_c_3;struct obj *v_d_3;struct obj *v_e_2;struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_80;struct obj *v_rv_79;};
+static void scm_nest_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_19_env {struct obj *parent;struct obj *v_rv_80;};
+static void scm_nest_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_18_env {struct obj *parent;};
+static void scm_nest_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_17_env {struct obj *parent;};
+static void scm_nest_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_16_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_c_3;struct obj *v_d_3;struct obj *v_e_2;struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_93;struct obj *v_rv_92;};
+static void scm_nest_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_15_env {struct obj *parent;};
+static void scm_nest_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_14_env {struct obj *parent;};
+static void scm_nest_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_13_env {struct obj *parent;};
+static void scm_nest_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_12_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_c_3;struct obj *v_d_3;struct obj *v_e_2;struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_93;struct obj *v_rv_92;struct obj *v_rv_91;struct obj *v_rv_90;};
+static void scm_nest_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_11_env {struct obj *parent;};
+static void scm_nest_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_10_env {struct obj *parent;};
+static void scm_nest_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_9_env {struct obj *parent;struct obj *v_rv_90;};
+static void scm_nest_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_8_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_c_3;struct obj *v_d_3;struct obj *v_e_2;struct obj *v_f;struct obj *v_k_19;struct obj *v_rv_94;struct obj *v_rv_93;struct obj *v_rv_92;struct obj *v_rv_91;};
+static void scm_nest_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_7_env {struct obj *parent;struct obj *v_rv_92;};
+static void scm_nest_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_6_env {struct obj *parent;struct obj *v_rv_93;};
+static void scm_nest_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_5_env {struct obj *parent;struct obj *v_rv_94;};
+static void scm_nest_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_4_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_c_3;struct obj *v_d_3;struct obj *v_e_2;struct obj *v_f;struct obj *v_k_19;};
+static void scm_nest_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_3_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_c_3;struct obj *v_d_3;struct obj *v_f;};
+static void scm_nest_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_2_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_c_3;struct obj *v_f;};
+static void scm_nest_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_lambda_1_env {struct obj *v_a_3;struct obj *v_b_3;struct obj *v_f;};
+static void scm_nest_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nest_env {struct obj *v_a_3;struct obj *v_f;};
+static void scm_nest(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_24;struct obj *v_rv_96;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_24;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_24;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_24;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_24;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_24;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_24;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_nest;};
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_25;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_37_closure;
+struct scm_lambda_67_env {struct obj *v_k_26;struct obj *v_rv_104;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_k_26;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_26;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_26;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_26;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_k_26;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_26;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_nest;};
+static void scm_lambda_42(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_27;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_47(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_47_closure;
+struct scm_loop_lambda_31_env {struct obj *v_k_28;struct obj *v_rv_128;};
+static void scm_loop_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_30_env {struct obj *v_k_28;struct obj *v_rv_128;struct obj *v_rv_127;};
+static void scm_loop_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_29_env {struct obj *v_k_28;struct obj *v_rv_128;struct obj *v_rv_127;struct obj *v_rv_126;};
+static void scm_loop_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_28_env {struct obj *parent;};
+static void scm_loop_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_27_env {struct obj *parent;};
+static void scm_loop_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_26_env {struct obj *parent;};
+static void scm_loop_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_25_env {struct obj *v_k_28;struct obj *v_rv_128;struct obj *v_rv_127;struct obj *v_rv_126;struct obj *v_rv_125;struct obj *v_rv_124;struct obj *v_rv_123;struct obj *v_rv_122;};
+static void scm_loop_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_24_env {struct obj *parent;};
+static void scm_loop_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_23_env {struct obj *parent;};
+static void scm_loop_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_22_env {struct obj *parent;};
+static void scm_loop_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_21_env {struct obj *v_b_4;struct obj *v_c_4;struct obj *v_d_4;struct obj *v_i;struct obj *v_k_28;struct obj *v_rv_128;struct obj *v_rv_127;struct obj *v_rv_126;struct obj *v_rv_125;struct obj *v_rv_124;struct obj *v_rv_123;struct obj *v_rv_122;};
+static void scm_loop_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_20_env {struct obj *parent;struct obj *v_rv_122;};
+static void scm_loop_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_19_env {struct obj *parent;struct obj *v_rv_123;};
+static void scm_loop_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_18_env {struct obj *parent;struct obj *v_rv_124;};
+static void scm_loop_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_17_env {struct obj *v_a_4;struct obj *v_b_4;struct obj *v_c_4;struct obj *v_d_4;struct obj *v_f;struct obj *v_i;struct obj *v_k_28;struct obj *v_rv_128;struct obj *v_rv_127;struct obj *v_rv_126;struct obj *v_rv_125;};
+static void scm_loop_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_16_env {struct obj *parent;struct obj *v_rv_126;};
+static void scm_loop_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_15_env {struct obj *parent;struct obj *v_rv_127;};
+static void scm_loop_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_14_env {struct obj *parent;struct obj *v_rv_128;};
+static void scm_loop_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_13_env {struct obj *v_a_4;struct obj *v_acc;struct obj *v_b_4;struct obj *v_c_4;struct obj *v_d_4;struct obj *v_f;struct obj *v_i;struct obj *v_k_28;};
+static void scm_loop_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_12_env {struct obj *parent;};
+static void scm_loop_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_11_env {struct obj *parent;};
+static void scm_loop_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_10_env {struct obj *parent;};
+static void scm_loop_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_9_env {struct obj *v_a_4;struct obj *v_acc;struct obj *v_b_4;struct obj *v_c_4;struct obj *v_d_4;struct obj *v_f;struct obj *v_i;struct obj *v_k_28;struct obj *v_loop;};
+static void scm_loop_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_8_env {struct obj *parent;};
+static void scm_loop_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_7_env {struct obj *parent;};
+static void scm_loop_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_6_env {struct obj *parent;};
+static void scm_loop_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_5_env {struct obj *v_a_4;struct obj *v_acc;struct obj *v_b_4;struct obj *v_c_4;struct obj *v_d_4;struct obj *v_f;struct obj *v_i;struct obj *v_k_28;struct obj *v_loop;};
+static void scm_loop_lambda_5(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_4_env {struct obj *v_a_4;struct obj *v_b_4;struct obj *v_c_4;struct obj *v_d_4;struct obj *v_f;struct obj *v_i;struct obj *v_loop;};
+static void scm_loop_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_3_env {struct obj *v_a_4;struct obj *v_b_4;struct obj *v_c_4;struct obj *v_f;struct obj *v_i;struct obj *v_loop;};
+static void scm_loop_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_2_env {struct obj *v_a_4;struct obj *v_b_4;struct obj *v_f;struct obj *v_i;struct obj *v_loop;};
+static void scm_loop_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_1_env {struct obj *v_a_4;struct obj *v_f;struct obj *v_i;struct obj *v_loop;};
+static void scm_loop_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_env {struct obj *v_f;struct obj *v_i;struct obj *v_loop;};
+static void scm_loop(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_k_34;struct obj *v_rv_138;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_34;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_k_34;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_34;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_34;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_k_34;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_34;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_34;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_loop;};
+static void scm_lambda_56(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_35;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_35;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_f;struct obj *v_k_35;struct obj *v_loop;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_f;struct obj *v_k_35;struct obj *v_loop;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *parent;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *parent;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_f;struct obj *v_k_35;struct obj *v_loop;struct obj *v_nest;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *parent;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *parent;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *parent;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_counter;struct obj *v_f;struct obj *v_k_35;struct obj *v_loop;struct obj *v_nest;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_counter;struct obj *v_f;struct obj *v_k_39;struct obj *v_nest;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_counter;struct obj *v_f;struct obj *v_k_39;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_f;struct obj *v_k_39;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_39;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_39;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_39;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_lambda_7_inlined_env {struct obj *v_k_39;};
+static void scm_lambda_7_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_inlined_env {struct obj *v_k_39;};
+static void scm_lambda_5_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_inlined_inlined_env {struct obj *v_k_39;};
+static void scm_lambda_7_inlined_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_f_lambda_21),"scm_f_lambda_21","closures.scm",2},{(void *)(scm_f_lambda_20),"scm_f_lambda_20","closures.scm",2},{(void *)(scm_f_lambda_19),"scm_f_lambda_19","closures.scm",2},{(void *)(scm_f_lambda_18),"scm_f_lambda_18","closures.scm",2},{(void *)(scm_f_lambda_17),"scm_f_lambda_17","closures.scm",2},{(void *)(scm_f_lambda_16),"scm_f_lambda_16","closures.scm",2},{(void *)(scm_f_lambda_15),"scm_f_lambda_15","closures.scm",2},{(void *)(scm_f_lambda_14),"scm_f_lambda_14","closures.scm",2},{(void *)(scm_f_lambda_13),"scm_f_lambda_13","closures.scm",2},{(void *)(scm_f_lambda_12),"scm_f_lambda_12","closures.scm",2},{(void *)(scm_f_lambda_11),"scm_f_lambda_11","closures.scm",2},{(void *)(scm_f_lambda_10),"scm_f_lambda_10","closures.scm",2},{(void *)(scm_f_lambda_9),"scm_f_lambda_9","closures.scm",2},{(void *)(scm_f_lambda_8),"scm_f_lambda_8","closures.scm",2},{(void *)(scm_f_lambda_7),"scm_f_lambda_7","closures.scm",2},{(void *)(scm_f_lambda_6),"scm_f_lambda_6","closures.scm",2},{(void *)(scm_f_lambda_5),"scm_f_lambda_5","closures.scm",2},{(void *)(scm_g_lambda_9),"scm_g_lambda_9","closures.scm",3},{(void *)(scm_g_lambda_8),"scm_g_lambda_8","closures.scm",3},{(void *)(scm_g_lambda_7),"scm_g_lambda_7","closures.scm",3},{(void *)(scm_g_lambda_6),"scm_g_lambda_6","closures.scm",3},{(void *)(scm_g_lambda_5),"scm_g_lambda_5","closures.scm",3},{(void *)(scm_g_lambda_4),"scm_g_lambda_4","closures.scm",3},{(void *)(scm_g_lambda_3),"scm_g_lambda_3","closures.scm",3},{(void *)(scm_g_lambda_2),"scm_g_lambda_2","closures.scm",3},{(void *)(scm_g_lambda_1),"scm_g_lambda_1","closures.scm",3},{(void *)(scm_g),"scm_g","closures.scm",3},{(void *)(scm_f_lambda_4),"scm_f_lambda_4","closures.scm",2},{(void *)(scm_f_lambda_3),"scm_f_lambda_3","closures.scm",2},{(void *)(scm_f_lambda_2),"scm_f_lambda_2","closures.scm",2},{(void *)(scm_f_lambda_1),"scm_f_lambda_1","closures.scm",2},{(void *)(scm_f),"scm_f","closures.scm",2},{(void *)(scm_lambda_34),"scm_lambda_34","closures.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","closures.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","closures.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","closures.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","closures.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","closures.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","closures.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","closures.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","closures.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","closures.scm",1},{(void *)(scm_counter_lambda_17),"scm_counter_lambda_17","closures.scm",8},{(void *)(scm_counter_lambda_16),"scm_counter_lambda_16","closures.scm",8},{(void *)(scm_counter_lambda_15),"scm_counter_lambda_15","closures.scm",8},{(void *)(scm_counter_lambda_14),"scm_counter_lambda_14","closures.scm",8},{(void *)(scm_counter_lambda_13),"scm_counter_lambda_13","closures.scm",8},{(void *)(scm_counter_lambda_12),"scm_counter_lambda_12","closures.scm",8},{(void *)(scm_counter_lambda_11),"scm_counter_lambda_11","closures.scm",8},{(void *)(scm_counter_lambda_10),"scm_counter_lambda_10","closures.scm",8},{(void *)(scm_counter_lambda_9),"scm_counter_lambda_9","closures.scm",8},{(void *)(scm_counter_lambda_8),"scm_counter_lambda_8","closures.scm",8},{(void *)(scm_counter_lambda_7),"scm_counter_lambda_7","closures.scm",8},{(void *)(scm_counter_lambda_6),"scm_counter_lambda_6","closures.scm",8},{(void *)(scm_counter_lambda_5),"scm_counter_lambda_5","closures.scm",8},{(void *)(scm_bump_lambda_8),"scm_bump_lambda_8","closures.scm",10},{(void *)(scm_bump_lambda_7),"scm_bump_lambda_7","closures.scm",10},{(void *)(scm_bump_lambda_6),"scm_bump_lambda_6","closures.scm",10},{(void *)(scm_bump_lambda_5),"scm_bump_lambda_5","closures.scm",10},{(void *)(scm_bump_lambda_4),"scm_bump_lambda_4","closures.scm",10},{(void *)(scm_bump_lambda_3),"scm_bump_lambda_3","closures.scm",10},{(void *)(scm_bump_lambda_2),"scm_bump_lambda_2","closures.scm",10},{(void *)(scm_bump_lambda_1),"scm_bump_lambda_1","closures.scm",10},{(void *)(scm_bump),"scm_bump","closures.scm",10},{(void *)(scm_counter_lambda_4),"scm_counter_lambda_4","closures.scm",8},{(void *)(scm_counter_lambda_3),"scm_counter_lambda_3","closures.scm",8},{(void *)(scm_counter_lambda_2),"scm_counter_lambda_2","closures.scm",8},{(void *)(scm_counter_lambda_1),"scm_counter_lambda_1","closures.scm",8},{(void *)(scm_counter),"scm_counter","closures.scm",8},{(void *)(scm_lambda_44),"scm_lambda_44","closures.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","closures.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","closures.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","closures.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","closures.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","closures.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","closures.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","closures.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","closures.scm",1},{(void *)(scm_nest_lambda_38),"scm_nest_lambda_38","closures.scm",17},{(void *)(scm_nest_lambda_37),"scm_nest_lambda_37","closures.scm",17},{(void *)(scm_nest_lambda_36),"scm_nest_lambda_36","closures.scm",17},{(void *)(scm_nest_lambda_35),"scm_nest_lambda_35","closures.scm",17},{(void *)(scm_nest_lambda_34),"scm_nest_lambda_34","closures.scm",17},{(void *)(scm_nest_lambda_33),"scm_nest_lambda_33","closures.scm",17},{(void *)(scm_nest_lambda_32),"scm_nest_lambda_32","closures.scm",17},{(void *)(scm_nest_lambda_31),"scm_nest_lambda_31","closures.scm",17},{(void *)(scm_nest_lambda_30),"scm_nest_lambda_30","closures.scm",17},{(void *)(scm_nest_lambda_29),"scm_nest_lambda_29","closures.scm",17},{(void *)(scm_nest_lambda_28),"scm_nest_lambda_28","closures.scm",17},{(void *)(scm_nest_lambda_27),"scm_nest_lambda_27","closures.scm",17},{(void *)(scm_nest_lambda_26),"scm_nest_lambda_26","closures.scm",17},{(void *)(scm_nest_lambda_25),"scm_nest_lambda_25","closures.scm",17},{(void *)(scm_nest_lambda_24),"scm_nest_lambda_24","closures.scm",17},{(void *)(scm_nest_lambda_23),"scm_nest_lambda_23","closures.scm",17},{(void *)(scm_nest_lambda_22),"scm_nest_lambda_22","closures.scm",17},{(void *)(scm_nest_lambda_21),"scm_nest_lambda_21","closures.scm",17},{(void *)(scm_nest_lambda_20),"scm_nest_lambda_20","closures.scm",17},{(void *)(scm_nest_lambda_19),"scm_nest_lambda_19","closures.scm",17},{(void *)(scm_nest_lambda_18),"scm_nest_lambda_18","closures.scm",17},{(void *)(scm_nest_lambda_17),"scm_nest_lambda_17","closures.scm",17},{(void *)(scm_nest_lambda_16),"scm_nest_lambda_16","closures.scm",17},{(void *)(scm_nest_lambda_15),"scm_nest_lambda_15","closures.scm",17},{(void *)(scm_nest_lambda_14),"scm_nest_lambda_14","closures.scm",17},{(void *)(scm_nest_lambda_13),"scm_nest_lambda_13","closures.scm",17},{(void *)(scm_nest_lambda_12),"scm_nest_lambda_12","closures.scm",17},{(void *)(scm_nest_lambda_11),"scm_nest_lambda_11","closures.scm",17},{(void *)(scm_nest_lambda_10),"scm_nest_lambda_10","closures.scm",17},{(void *)(scm_nest_lambda_9),"scm_nest_lambda_9","closures.scm",17},{(void *)(scm_nest_lambda_8),"scm_nest_lambda_8","closures.scm",17},{(void *)(scm_nest_lambda_7),"scm_nest_lambda_7","closures.scm",17},{(void *)(scm_nest_lambda_6),"scm_nest_lambda_6","closures.scm",17},{(void *)(scm_nest_lambda_5),"scm_nest_lambda_5","closures.scm",17},{(void *)(scm_nest_lambda_4),"scm_nest_lambda_4","closures.scm",17},{(void *)(scm_nest_lambda_3),"scm_nest_lambda_3","closures.scm",17},{(void *)(scm_nest_lambda_2),"scm_nest_lambda_2","closures.scm",17},{(void *)(scm_nest_lambda_1),"scm_nest_lambda_1","closures.scm",17},{(void *)(scm_nest),"scm_nest","closures.scm",17},{(void *)(scm_lambda_62),"scm_lambda_62","closures.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","closures.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","closures.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","closures.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","closures.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","closures.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","closures.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","closures.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","closures.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","closures.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","closures.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","closures.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","closures.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","closures.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","closures.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","closures.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","closures.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","closures.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","closures.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","closures.scm",1},{(void *)(scm_loop_lambda_31),"scm_loop_lambda_31","closures.scm",24},{(void *)(scm_loop_lambda_30),"scm_loop_lambda_30","closures.scm",24},{(void *)(scm_loop_lambda_29),"scm_loop_lambda_29","closures.scm",24},{(void *)(scm_loop_lambda_28),"scm_loop_lambda_28","closures.scm",24},{(void *)(scm_loop_lambda_27),"scm_loop_lambda_27","closures.scm",24},{(void *)(scm_loop_lambda_26),"scm_loop_lambda_26","closures.scm",24},{(void *)(scm_loop_lambda_25),"scm_loop_lambda_25","closures.scm",24},{(void *)(scm_loop_lambda_24),"scm_loop_lambda_24","closures.scm",24},{(void *)(scm_loop_lambda_23),"scm_loop_lambda_23","closures.scm",24},{(void *)(scm_loop_lambda_22),"scm_loop_lambda_22","closures.scm",24},{(void *)(scm_loop_lambda_21),"scm_loop_lambda_21","closures.scm",24},{(void *)(scm_loop_lambda_20),"scm_loop_lambda_20","closures.scm",24},{(void *)(scm_loop_lambda_19),"scm_loop_lambda_19","closures.scm",24},{(void *)(scm_loop_lambda_18),"scm_loop_lambda_18","closures.scm",24},{(void *)(scm_loop_lambda_17),"scm_loop_lambda_17","closures.scm",24},{(void *)(scm_loop_lambda_16),"scm_loop_lambda_16","closures.scm",24},{(void *)(scm_loop_lambda_15),"scm_loop_lambda_15","closures.scm",24},{(void *)(scm_loop_lambda_14),"scm_loop_lambda_14","closures.scm",24},{(void *)(scm_loop_lambda_13),"scm_loop_lambda_13","closures.scm",24},{(void *)(scm_loop_lambda_12),"scm_loop_lambda_12","closures.scm",24},{(void *)(scm_loop_lambda_11),"scm_loop_lambda_11","closures.scm",24},{(void *)(scm_loop_lambda_10),"scm_loop_lambda_10","closures.scm",24},{(void *)(scm_loop_lambda_9),"scm_loop_lambda_9","closures.scm",24},{(void *)(scm_loop_lambda_8),"scm_loop_lambda_8","closures.scm",24},{(void *)(scm_loop_lambda_7),"scm_loop_lambda_7","closures.scm",24},{(void *)(scm_loop_lambda_6),"scm_loop_lambda_6","closures.scm",24},{(void *)(scm_loop_lambda_5),"scm_loop_lambda_5","closures.scm",24},{(void *)(scm_loop_lambda_4),"scm_loop_lambda_4","closures.scm",24},{(void *)(scm_loop_lambda_3),"scm_loop_lambda_3","closures.scm",24},{(void *)(scm_loop_lambda_2),"scm_loop_lambda_2","closures.scm",24},{(void *)(scm_loop_lambda_1),"scm_loop_lambda_1","closures.scm",24},{(void *)(scm_loop),"scm_loop","closures.scm",24},{(void *)(scm_lambda_72),"scm_lambda_72","closures.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","closures.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","closures.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","closures.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","closures.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","closures.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","closures.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","closures.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","closures.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","closures.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","closures.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","closures.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","closures.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","closures.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","closures.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","closures.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","closures.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","closures.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","closures.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","closures.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","closures.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","closures.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","closures.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","closures.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","closures.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","closures.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","closures.scm",1},{(void *)(scm_lambda_7_inlined),"scm_lambda_7_inlined","closures.scm",1},{(void *)(scm_lambda_5_inlined),"scm_lambda_5_inlined","closures.scm",1},{(void *)(scm_lambda_7_inlined_inlined),"scm_lambda_7_inlined_inlined","closures.scm",1},{NULL}};
+
+#line 2 "closures.scm"
+static void scm_f_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_21_env *env = (struct scm_f_lambda_21_env *)(&((env_in)->env));call_closure_two((env)->v_rv_15,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_20_env *env = (struct scm_f_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_21_env );(((struct scm_f_lambda_21_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_f_lambda_21_env *)(&((var_1)->env)))->v_rv_15)=((env)->v_rv_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_21,var_1);call_closure_two((env)->v_rv_13,var_0,var_2);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_19_env *env = (struct scm_f_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_20_env );(((struct scm_f_lambda_20_env *)(&((var_1)->env)))->v_k)=(((struct scm_f_lambda_17_env *)(&(((struct env_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k);(((struct scm_f_lambda_20_env *)(&((var_1)->env)))->v_rv_15)=(((struct scm_f_lambda_17_env *)(&(((struct env_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_15);(((struct scm_f_lambda_20_env *)(&((var_1)->env)))->v_rv_13)=(((struct scm_f_lambda_17_env *)(&(((struct env_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_13);OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_20,var_1);call_closure_two(((struct scm_f_lambda_17_env *)(&(((struct env_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_12,var_0,var_2);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_18_env *env = (struct scm_f_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_19_env );(((struct scm_f_lambda_19_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_19,var_1);call_closure_two(((struct scm_f_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_11,var_0,var_2);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_17_env *env = (struct scm_f_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_18_env );(((struct scm_f_lambda_18_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_18,var_1);call_closure_two((env)->v_rv_10,var_0,var_2);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_16_env *env = (struct scm_f_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_17_env );(((struct scm_f_lambda_17_env *)(&((var_1)->env)))->v_k)=(((struct scm_f_lambda_13_env *)(&(((struct env_obj *)(((struct scm_f_lambda_14_env *)(&(((struct env_obj *)(((struct scm_f_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k);(((struct scm_f_lambda_17_env *)(&((var_1)->env)))->v_rv_15)=(((struct scm_f_lambda_13_env *)(&(((struct env_obj *)(((struct scm_f_lambda_14_env *)(&(((struct env_obj *)(((struct scm_f_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_15);(((struct scm_f_lambda_17_env *)(&((var_1)->env)))->v_rv_13)=(((struct scm_f_lambda_13_env *)(&(((struct env_obj *)(((struct scm_f_lambda_14_env *)(&(((struct env_obj *)(((struct scm_f_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_13);(((struct scm_f_lambda_17_env *)(&((var_1)->env)))->v_rv_12)=(((struct scm_f_lambda_13_env *)(&(((struct env_obj *)(((struct scm_f_lambda_14_env *)(&(((struct env_obj *)(((struct scm_f_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_12);(((struct scm_f_lambda_17_env *)(&((var_1)->env)))->v_rv_11)=(((struct scm_f_lambda_13_env *)(&(((struct env_obj *)(((struct scm_f_lambda_14_env *)(&(((struct env_obj *)(((struct scm_f_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_11);(((struct scm_f_lambda_17_env *)(&((var_1)->env)))->v_rv_10)=(((struct scm_f_lambda_13_env *)(&(((struct env_obj *)(((struct scm_f_lambda_14_env *)(&(((struct env_obj *)(((struct scm_f_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_17,var_1);call_closure_two(((struct scm_f_lambda_13_env *)(&(((struct env_obj *)(((struct scm_f_lambda_14_env *)(&(((struct env_obj *)(((struct scm_f_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_9,var_0,var_2);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_15_env *env = (struct scm_f_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_16_env );(((struct scm_f_lambda_16_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_16,var_1);call_closure_two(((struct scm_f_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_8,var_0,var_2);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_14_env *env = (struct scm_f_lambda_14_env *)(&((env_in)->env));((env)->v_rv_8)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_15_env );(((struct scm_f_lambda_15_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_15,var_1);call_closure_two(((struct scm_f_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_g,((struct scm_f_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_c,var_2);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_13_env *env = (struct scm_f_lambda_13_env *)(&((env_in)->env));((env)->v_rv_9)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_14_env );(((struct scm_f_lambda_14_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_14,var_2);call_closure_two(var_1,(env)->v_e,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_12_env *env = (struct scm_f_lambda_12_env *)(&((env_in)->env));((env)->v_rv_10)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_13_env );(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_c)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c);(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_e)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e);(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_g)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_g);(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_k)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k);(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_rv_15)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_15);(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_rv_13)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_13);(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_rv_12)=(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_12);(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_rv_11)=(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_11);(((struct scm_f_lambda_13_env *)(&((var_2)->env)))->v_rv_10)=((env)->v_rv_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_13,var_2);call_closure_two(var_1,((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_11_env *env = (struct scm_f_lambda_11_env *)(&((env_in)->env));((env)->v_rv_11)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_12_env );(((struct scm_f_lambda_12_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_12,var_2);call_closure_two(var_1,((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_c,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_10_env *env = (struct scm_f_lambda_10_env *)(&((env_in)->env));((env)->v_rv_12)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_11_env );(((struct scm_f_lambda_11_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_11,var_2);call_closure_two(var_1,((struct scm_f_lambda_9_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_b,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_9_env *env = (struct scm_f_lambda_9_env *)(&((env_in)->env));((env)->v_rv_13)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_10_env );(((struct scm_f_lambda_10_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_10,var_2);call_closure_two(var_1,(env)->v_a,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_8_env *env = (struct scm_f_lambda_8_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_9_env );(((struct scm_f_lambda_9_env *)(&((var_2)->env)))->v_a)=(((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)(((struct scm_f_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a);(((struct scm_f_lambda_9_env *)(&((var_2)->env)))->v_b)=(((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)(((struct scm_f_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b);(((struct scm_f_lambda_9_env *)(&((var_2)->env)))->v_c)=(((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)(((struct scm_f_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c);(((struct scm_f_lambda_9_env *)(&((var_2)->env)))->v_d)=(((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)(((struct scm_f_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d);(((struct scm_f_lambda_9_env *)(&((var_2)->env)))->v_e)=(((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)(((struct scm_f_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e);(((struct scm_f_lambda_9_env *)(&((var_2)->env)))->v_g)=(((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)(((struct scm_f_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_g);(((struct scm_f_lambda_9_env *)(&((var_2)->env)))->v_k)=(((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)(((struct scm_f_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k);(((struct scm_f_lambda_9_env *)(&((var_2)->env)))->v_rv_15)=(((struct scm_f_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_15);OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_9,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_7_env *env = (struct scm_f_lambda_7_env *)(&((env_in)->env));((env)->v_rv_15)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_8_env );(((struct scm_f_lambda_8_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_8,var_1);call_closure_two(((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_g,((struct scm_f_lambda_5_env *)(&(((struct env_obj *)(((struct scm_f_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_b,var_2);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_6_env *env = (struct scm_f_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_7_env );(((struct scm_f_lambda_7_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_7,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_5(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_5_env *env = (struct scm_f_lambda_5_env *)(&((env_in)->env));((env)->v_g)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_6_env );(((struct scm_f_lambda_6_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_6,var_2);call_closure_two((env)->v_g,(env)->v_a,var_3);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_9_env *env = (struct scm_g_lambda_9_env *)(&((env_in)->env));call_closure_two((env)->v_rv_25,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_8_env *env = (struct scm_g_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_g_lambda_9_env );(((struct scm_g_lambda_9_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_g_lambda_9_env *)(&((var_1)->env)))->v_rv_25)=((env)->v_rv_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_g_lambda_9,var_1);call_closure_two((env)->v_rv_24,var_0,var_2);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_7_env *env = (struct scm_g_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_g_lambda_8_env );(((struct scm_g_lambda_8_env *)(&((var_1)->env)))->v_k_2)=(((struct scm_g_lambda_4_env *)(&(((struct env_obj *)(((struct scm_g_lambda_5_env *)(&(((struct env_obj *)(((struct scm_g_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_2);(((struct scm_g_lambda_8_env *)(&((var_1)->env)))->v_rv_25)=(((struct scm_g_lambda_4_env *)(&(((struct env_obj *)(((struct scm_g_lambda_5_env *)(&(((struct env_obj *)(((struct scm_g_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_25);(((struct scm_g_lambda_8_env *)(&((var_1)->env)))->v_rv_24)=(((struct scm_g_lambda_4_env *)(&(((struct env_obj *)(((struct scm_g_lambda_5_env *)(&(((struct env_obj *)(((struct scm_g_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_g_lambda_8,var_1);call_closure_two(((struct scm_g_lambda_4_env *)(&(((struct env_obj *)(((struct scm_g_lambda_5_env *)(&(((struct env_obj *)(((struct scm_g_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_23,var_0,var_2);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_6_env *env = (struct scm_g_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_g_lambda_7_env );(((struct scm_g_lambda_7_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_g_lambda_7,var_1);call_closure_two(((struct scm_g_lambda_4_env *)(&(((struct env_obj *)(((struct scm_g_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_22,var_0,var_2);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_5_env *env = (struct scm_g_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_g_lambda_6_env );(((struct scm_g_lambda_6_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_g_lambda_6,var_1);call_closure_two(var_0,((struct scm_g_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_e,var_2);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_4_env *env = (struct scm_g_lambda_4_env *)(&((env_in)->env));((env)->v_rv_22)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_g_lambda_5_env );(((struct scm_g_lambda_5_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_g_lambda_5,var_2);call_closure_two(var_1,(env)->v_d,var_3);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_3_env *env = (struct scm_g_lambda_3_env *)(&((env_in)->env));((env)->v_rv_23)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_g_lambda_4_env );(((struct scm_g_lambda_4_env *)(&((var_2)->env)))->v_d)=(((struct scm_g_env *)(&(((struct env_obj *)(((struct scm_g_lambda_1_env *)(&(((struct env_obj *)(((struct scm_g_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d);(((struct scm_g_lambda_4_env *)(&((var_2)->env)))->v_e)=(((struct scm_g_env *)(&(((struct env_obj *)(((struct scm_g_lambda_1_env *)(&(((struct env_obj *)(((struct scm_g_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e);(((struct scm_g_lambda_4_env *)(&((var_2)->env)))->v_k_2)=(((struct scm_g_env *)(&(((struct env_obj *)(((struct scm_g_lambda_1_env *)(&(((struct env_obj *)(((struct scm_g_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_2);(((struct scm_g_lambda_4_env *)(&((var_2)->env)))->v_rv_25)=(((struct scm_g_lambda_1_env *)(&(((struct env_obj *)(((struct scm_g_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_25);(((struct scm_g_lambda_4_env *)(&((var_2)->env)))->v_rv_24)=(((struct scm_g_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_24);(((struct scm_g_lambda_4_env *)(&((var_2)->env)))->v_rv_23)=((env)->v_rv_23);OBJECT_CLOSURE_ONE_NEW(var_3,scm_g_lambda_4,var_2);call_closure_two(var_1,((struct scm_g_env *)(&(((struct env_obj *)(((struct scm_g_lambda_1_env *)(&(((struct env_obj *)(((struct scm_g_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c,var_3);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_2_env *env = (struct scm_g_lambda_2_env *)(&((env_in)->env));((env)->v_rv_24)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_g_lambda_3_env );(((struct scm_g_lambda_3_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_g_lambda_3,var_2);call_closure_two(var_1,((struct scm_g_env *)(&(((struct env_obj *)(((struct scm_g_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_b,var_3);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_1_env *env = (struct scm_g_lambda_1_env *)(&((env_in)->env));((env)->v_rv_25)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_g_lambda_2_env );(((struct scm_g_lambda_2_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_g_lambda_2,var_2);call_closure_two(var_1,((struct scm_g_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_a,var_3);__builtin_unreachable();}
+
+#line 3 "closures.scm"
+static void scm_g(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_g_env *env = (struct scm_g_env *)(&((env_in)->env));((env)->v_k_2)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_g_lambda_1_env );(((struct scm_g_lambda_1_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_g_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_4_env *env = (struct scm_f_lambda_4_env *)(&((env_in)->env));((env)->v_e)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_5_env );(((struct scm_f_lambda_5_env *)(&((var_2)->env)))->v_a)=((env)->v_a);(((struct scm_f_lambda_5_env *)(&((var_2)->env)))->v_b)=((env)->v_b);(((struct scm_f_lambda_5_env *)(&((var_2)->env)))->v_c)=((env)->v_c);(((struct scm_f_lambda_5_env *)(&((var_2)->env)))->v_d)=((env)->v_d);(((struct scm_f_lambda_5_env *)(&((var_2)->env)))->v_e)=((env)->v_e);OBJECT_CLOSURE_TWO_NEW(var_3,scm_f_lambda_5,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_g_env );(((struct scm_g_env *)(&((var_4)->env)))->v_a)=((env)->v_a);(((struct scm_g_env *)(&((var_4)->env)))->v_b)=((env)->v_b);(((struct scm_g_env *)(&((var_4)->env)))->v_c)=((env)->v_c);(((struct scm_g_env *)(&((var_4)->env)))->v_d)=((env)->v_d);(((struct scm_g_env *)(&((var_4)->env)))->v_e)=((env)->v_e);OBJECT_CLOSURE_TWO_NEW(var_5,scm_g,var_4);call_closure_two(var_3,var_5,var_1);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_3_env *env = (struct scm_f_lambda_3_env *)(&((env_in)->env));((env)->v_d)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_4_env );(((struct scm_f_lambda_4_env *)(&((var_2)->env)))->v_a)=((env)->v_a);(((struct scm_f_lambda_4_env *)(&((var_2)->env)))->v_b)=((env)->v_b);(((struct scm_f_lambda_4_env *)(&((var_2)->env)))->v_c)=((env)->v_c);(((struct scm_f_lambda_4_env *)(&((var_2)->env)))->v_d)=((env)->v_d);OBJECT_CLOSURE_TWO_NEW(var_3,scm_f_lambda_4,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_2_env *env = (struct scm_f_lambda_2_env *)(&((env_in)->env));((env)->v_c)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_3_env );(((struct scm_f_lambda_3_env *)(&((var_2)->env)))->v_a)=((env)->v_a);(((struct scm_f_lambda_3_env *)(&((var_2)->env)))->v_b)=((env)->v_b);(((struct scm_f_lambda_3_env *)(&((var_2)->env)))->v_c)=((env)->v_c);OBJECT_CLOSURE_TWO_NEW(var_3,scm_f_lambda_3,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_1_env *env = (struct scm_f_lambda_1_env *)(&((env_in)->env));((env)->v_b)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_2_env );(((struct scm_f_lambda_2_env *)(&((var_2)->env)))->v_a)=((env)->v_a);(((struct scm_f_lambda_2_env *)(&((var_2)->env)))->v_b)=((env)->v_b);OBJECT_CLOSURE_TWO_NEW(var_3,scm_f_lambda_2,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 2 "closures.scm"
+static void scm_f(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_env *env = (struct scm_f_env *)(&((env_in)->env));((env)->v_a)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_1_env );(((struct scm_f_lambda_1_env *)(&((var_2)->env)))->v_a)=((env)->v_a);OBJECT_CLOSURE_TWO_NEW(var_3,scm_f_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));call_closure_two((env)->v_rv_27,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_25,var_1);call_closure_two(var_0,MAKE_FIXNUM(5),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_18,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_14,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_2)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_11,var_2);call_closure_two(((struct cell_obj *)((env)->v_f))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_12_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_12);
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_17_env *env = (struct scm_counter_lambda_17_env *)(&((env_in)->env));call_closure_two((env)->v_rv_43,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_16_env *env = (struct scm_counter_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_counter_lambda_17_env );(((struct scm_counter_lambda_17_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_counter_lambda_17_env *)(&((var_1)->env)))->v_rv_43)=((env)->v_rv_43);OBJECT_CLOSURE_ONE_NEW(var_2,scm_counter_lambda_17,var_1);call_closure_two((env)->v_rv_41,var_0,var_2);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_15_env *env = (struct scm_counter_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_counter_lambda_16_env );(((struct scm_counter_lambda_16_env *)(&((var_1)->env)))->v_k_10)=(((struct scm_counter_lambda_13_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_10);(((struct scm_counter_lambda_16_env *)(&((var_1)->env)))->v_rv_43)=(((struct scm_counter_lambda_13_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_43);(((struct scm_counter_lambda_16_env *)(&((var_1)->env)))->v_rv_41)=(((struct scm_counter_lambda_13_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_41);OBJECT_CLOSURE_ONE_NEW(var_2,scm_counter_lambda_16,var_1);call_closure_two(((struct scm_counter_lambda_13_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_40,var_0,var_2);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_14_env *env = (struct scm_counter_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_counter_lambda_15_env );(((struct scm_counter_lambda_15_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_counter_lambda_15,var_1);call_closure_two(((struct scm_counter_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_39,var_0,var_2);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_13_env *env = (struct scm_counter_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_counter_lambda_14_env );(((struct scm_counter_lambda_14_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_counter_lambda_14,var_1);call_closure_two(var_0,(env)->v_d_2,var_2);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_12_env *env = (struct scm_counter_lambda_12_env *)(&((env_in)->env));((env)->v_rv_39)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_13_env );(((struct scm_counter_lambda_13_env *)(&((var_2)->env)))->v_d_2)=(((struct scm_counter_lambda_9_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_10_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_2);(((struct scm_counter_lambda_13_env *)(&((var_2)->env)))->v_k_10)=(((struct scm_counter_lambda_9_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_10_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_10);(((struct scm_counter_lambda_13_env *)(&((var_2)->env)))->v_rv_43)=(((struct scm_counter_lambda_9_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_10_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_43);(((struct scm_counter_lambda_13_env *)(&((var_2)->env)))->v_rv_41)=(((struct scm_counter_lambda_10_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_41);(((struct scm_counter_lambda_13_env *)(&((var_2)->env)))->v_rv_40)=(((struct scm_counter_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_40);(((struct scm_counter_lambda_13_env *)(&((var_2)->env)))->v_rv_39)=((env)->v_rv_39);OBJECT_CLOSURE_ONE_NEW(var_3,scm_counter_lambda_13,var_2);call_closure_two(var_1,((struct scm_counter_lambda_9_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_10_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_2,var_3);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_11_env *env = (struct scm_counter_lambda_11_env *)(&((env_in)->env));((env)->v_rv_40)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_12_env );(((struct scm_counter_lambda_12_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_counter_lambda_12,var_2);call_closure_two(var_1,((struct scm_counter_lambda_9_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_b_2,var_3);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_10_env *env = (struct scm_counter_lambda_10_env *)(&((env_in)->env));((env)->v_rv_41)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_11_env );(((struct scm_counter_lambda_11_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_counter_lambda_11,var_2);call_closure_two(var_1,((struct scm_counter_lambda_9_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_a_2,var_3);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_9_env *env = (struct scm_counter_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_10_env );(((struct scm_counter_lambda_10_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_counter_lambda_10,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_8_env *env = (struct scm_counter_lambda_8_env *)(&((env_in)->env));((env)->v_rv_43)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_1,struct scm_counter_lambda_9_env );(((struct scm_counter_lambda_9_env *)(&((var_1)->env)))->v_a_2)=(((struct scm_counter_lambda_5_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_6_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_2);(((struct scm_counter_lambda_9_env *)(&((var_1)->env)))->v_b_2)=(((struct scm_counter_lambda_5_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_6_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_2);(((struct scm_counter_lambda_9_env *)(&((var_1)->env)))->v_c_2)=(((struct scm_counter_lambda_5_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_6_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_2);(((struct scm_counter_lambda_9_env *)(&((var_1)->env)))->v_d_2)=(((struct scm_counter_lambda_5_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_6_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_2);(((struct scm_counter_lambda_9_env *)(&((var_1)->env)))->v_k_10)=(((struct scm_counter_lambda_5_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_6_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_10);(((struct scm_counter_lambda_9_env *)(&((var_1)->env)))->v_rv_43)=((env)->v_rv_43);OBJECT_CLOSURE_ONE_NEW(var_2,scm_counter_lambda_9,var_1);call_closure_two(((struct scm_counter_lambda_5_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_6_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_bump,NULL,var_2);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_7_env *env = (struct scm_counter_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_8_env );(((struct scm_counter_lambda_8_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_counter_lambda_8,var_2);call_closure_two(var_1,((struct cell_obj *)(((struct scm_counter_lambda_5_env *)(&(((struct env_obj *)(((struct scm_counter_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n))->val,var_3);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_counter_lambda_6_env *env = (struct scm_counter_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_counter_lambda_7_env );(((struct scm_counter_lambda_7_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_counter_lambda_7,var_1);call_closure_two(((struct scm_counter_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_bump,NULL,var_2);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_5(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_counter_lambda_5_env *env = (struct scm_counter_lambda_5_env *)(&((env_in)->env));((env)->v_bump)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_10)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_6_env );(((struct scm_counter_lambda_6_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_counter_lambda_6,var_2);call_closure_two((env)->v_bump,NULL,var_3);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_8_env *env = (struct scm_bump_lambda_8_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_n))->val)=(var_0);GC_WRITE_BARRIER((env)->v_n,((struct cell_obj *)((env)->v_n))->val);call_closure_one((env)->v_k_11,((struct cell_obj *)((env)->v_n))->val);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_7_env *env = (struct scm_bump_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_bump_lambda_8_env );(((struct scm_bump_lambda_8_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_bump_lambda_8_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_bump_lambda_8,var_1);call_closure_two((env)->v_rv_53,var_0,var_2);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_6_env *env = (struct scm_bump_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_bump_lambda_7_env );(((struct scm_bump_lambda_7_env *)(&((var_1)->env)))->v_k_11)=(((struct scm_bump_lambda_4_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_11);(((struct scm_bump_lambda_7_env *)(&((var_1)->env)))->v_n)=(((struct scm_bump_lambda_4_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n);(((struct scm_bump_lambda_7_env *)(&((var_1)->env)))->v_rv_53)=(((struct scm_bump_lambda_4_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_bump_lambda_7,var_1);call_closure_two(((struct scm_bump_lambda_4_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_52,var_0,var_2);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_5_env *env = (struct scm_bump_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_bump_lambda_6_env );(((struct scm_bump_lambda_6_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_bump_lambda_6,var_1);call_closure_two(((struct scm_bump_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_51,var_0,var_2);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_4_env *env = (struct scm_bump_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_bump_lambda_5_env );(((struct scm_bump_lambda_5_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_bump_lambda_5,var_1);call_closure_two(var_0,(env)->v_d_2,var_2);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_3_env *env = (struct scm_bump_lambda_3_env *)(&((env_in)->env));((env)->v_rv_51)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_bump_lambda_4_env );(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_d_2)=(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_2);(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_k_11)=(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_11);(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_n)=(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_n);(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_rv_53)=(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_53);(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_rv_52)=(((struct scm_bump_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_52);(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_rv_51)=((env)->v_rv_51);OBJECT_CLOSURE_ONE_NEW(var_3,scm_bump_lambda_4,var_2);call_closure_two(var_1,((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_2,var_3);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_2_env *env = (struct scm_bump_lambda_2_env *)(&((env_in)->env));((env)->v_rv_52)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_bump_lambda_3_env );(((struct scm_bump_lambda_3_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_bump_lambda_3,var_2);call_closure_two(var_1,((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_b_2,var_3);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_1_env *env = (struct scm_bump_lambda_1_env *)(&((env_in)->env));((env)->v_rv_53)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_bump_lambda_2_env );(((struct scm_bump_lambda_2_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_bump_lambda_2,var_2);call_closure_two(var_1,((struct scm_bump_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_a_2,var_3);__builtin_unreachable();}
+
+#line 10 "closures.scm"
+static void scm_bump(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_bump_env *env = (struct scm_bump_env *)(&((env_in)->env));((env)->v_k_11)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_bump_lambda_1_env );(((struct scm_bump_lambda_1_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_bump_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_n))->val,var_4);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_counter_lambda_4_env *env = (struct scm_counter_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_n)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_counter_lambda_5_env );(((struct scm_counter_lambda_5_env *)(&((var_3)->env)))->v_a_2)=((env)->v_a_2);(((struct scm_counter_lambda_5_env *)(&((var_3)->env)))->v_b_2)=((env)->v_b_2);(((struct scm_counter_lambda_5_env *)(&((var_3)->env)))->v_c_2)=((env)->v_c_2);(((struct scm_counter_lambda_5_env *)(&((var_3)->env)))->v_d_2)=((env)->v_d_2);(((struct scm_counter_lambda_5_env *)(&((var_3)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_TWO_NEW(var_4,scm_counter_lambda_5,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_bump_env );(((struct scm_bump_env *)(&((var_5)->env)))->v_a_2)=((env)->v_a_2);(((struct scm_bump_env *)(&((var_5)->env)))->v_b_2)=((env)->v_b_2);(((struct scm_bump_env *)(&((var_5)->env)))->v_c_2)=((env)->v_c_2);(((struct scm_bump_env *)(&((var_5)->env)))->v_d_2)=((env)->v_d_2);(((struct scm_bump_env *)(&((var_5)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_TWO_NEW(var_6,scm_bump,var_5);call_closure_two(var_4,var_6,var_1);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_counter_lambda_3_env *env = (struct scm_counter_lambda_3_env *)(&((env_in)->env));((env)->v_d_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_4_env );(((struct scm_counter_lambda_4_env *)(&((var_2)->env)))->v_a_2)=((env)->v_a_2);(((struct scm_counter_lambda_4_env *)(&((var_2)->env)))->v_b_2)=((env)->v_b_2);(((struct scm_counter_lambda_4_env *)(&((var_2)->env)))->v_c_2)=((env)->v_c_2);(((struct scm_counter_lambda_4_env *)(&((var_2)->env)))->v_d_2)=((env)->v_d_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_counter_lambda_4,var_2);call_closure_two(var_3,MAKE_FIXNUM(0),var_1);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_counter_lambda_2_env *env = (struct scm_counter_lambda_2_env *)(&((env_in)->env));((env)->v_c_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_3_env );(((struct scm_counter_lambda_3_env *)(&((var_2)->env)))->v_a_2)=((env)->v_a_2);(((struct scm_counter_lambda_3_env *)(&((var_2)->env)))->v_b_2)=((env)->v_b_2);(((struct scm_counter_lambda_3_env *)(&((var_2)->env)))->v_c_2)=((env)->v_c_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_counter_lambda_3,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_counter_lambda_1_env *env = (struct scm_counter_lambda_1_env *)(&((env_in)->env));((env)->v_b_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_2_env );(((struct scm_counter_lambda_2_env *)(&((var_2)->env)))->v_a_2)=((env)->v_a_2);(((struct scm_counter_lambda_2_env *)(&((var_2)->env)))->v_b_2)=((env)->v_b_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_counter_lambda_2,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 8 "closures.scm"
+static void scm_counter(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_counter_env *env = (struct scm_counter_env *)(&((env_in)->env));((env)->v_a_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_counter_lambda_1_env );(((struct scm_counter_lambda_1_env *)(&((var_2)->env)))->v_a_2)=((env)->v_a_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_counter_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));call_closure_two((env)->v_rv_55,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_rv_55)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_44,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_35,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_31,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_26,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_19(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22,var_2);call_closure_two(((struct cell_obj *)((env)->v_counter))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_27,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_23_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_23);
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_38_env *env = (struct scm_nest_lambda_38_env *)(&((env_in)->env));call_closure_two((env)->v_rv_94,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_37_env *env = (struct scm_nest_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_38_env );(((struct scm_nest_lambda_38_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_nest_lambda_38_env *)(&((var_1)->env)))->v_rv_94)=((env)->v_rv_94);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_38,var_1);call_closure_two((env)->v_rv_80,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_36_env *env = (struct scm_nest_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_37_env );(((struct scm_nest_lambda_37_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_nest_lambda_37_env *)(&((var_1)->env)))->v_rv_94)=((env)->v_rv_94);(((struct scm_nest_lambda_37_env *)(&((var_1)->env)))->v_rv_80)=((env)->v_rv_80);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_37,var_1);call_closure_two((env)->v_rv_79,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_35_env *env = (struct scm_nest_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_36_env );(((struct scm_nest_lambda_36_env *)(&((var_1)->env)))->v_k_19)=(((struct scm_nest_lambda_32_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_33_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_nest_lambda_36_env *)(&((var_1)->env)))->v_rv_94)=(((struct scm_nest_lambda_32_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_33_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_94);(((struct scm_nest_lambda_36_env *)(&((var_1)->env)))->v_rv_80)=(((struct scm_nest_lambda_32_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_33_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_80);(((struct scm_nest_lambda_36_env *)(&((var_1)->env)))->v_rv_79)=(((struct scm_nest_lambda_32_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_33_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_79);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_36,var_1);call_closure_two(((struct scm_nest_lambda_32_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_33_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_78,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_34_env *env = (struct scm_nest_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_35_env );(((struct scm_nest_lambda_35_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_35,var_1);call_closure_two(((struct scm_nest_lambda_32_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_77,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_33_env *env = (struct scm_nest_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_34_env );(((struct scm_nest_lambda_34_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_34,var_1);call_closure_two(((struct scm_nest_lambda_32_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_76,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_32_env *env = (struct scm_nest_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_33_env );(((struct scm_nest_lambda_33_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_33,var_1);call_closure_two((env)->v_rv_75,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_31_env *env = (struct scm_nest_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_32_env );(((struct scm_nest_lambda_32_env *)(&((var_1)->env)))->v_k_19)=(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_nest_lambda_32_env *)(&((var_1)->env)))->v_rv_94)=(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_94);(((struct scm_nest_lambda_32_env *)(&((var_1)->env)))->v_rv_80)=(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_80);(((struct scm_nest_lambda_32_env *)(&((var_1)->env)))->v_rv_79)=(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_79);(((struct scm_nest_lambda_32_env *)(&((var_1)->env)))->v_rv_78)=(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_78);(((struct scm_nest_lambda_32_env *)(&((var_1)->env)))->v_rv_77)=(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_77);(((struct scm_nest_lambda_32_env *)(&((var_1)->env)))->v_rv_76)=(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_76);(((struct scm_nest_lambda_32_env *)(&((var_1)->env)))->v_rv_75)=(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_75);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_32,var_1);call_closure_two(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_30_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_74,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_30_env *env = (struct scm_nest_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_31_env );(((struct scm_nest_lambda_31_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_31,var_1);call_closure_two(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_73,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_29_env *env = (struct scm_nest_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_30_env );(((struct scm_nest_lambda_30_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_30,var_1);call_closure_two(((struct scm_nest_lambda_28_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_72,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_28_env *env = (struct scm_nest_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_29_env );(((struct scm_nest_lambda_29_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_29,var_1);call_closure_two(var_0,(env)->v_e_2,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_27_env *env = (struct scm_nest_lambda_27_env *)(&((env_in)->env));((env)->v_rv_72)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_28_env );(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_e_2)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e_2);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_k_19)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_94)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_94);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_80)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_80);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_79)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_79);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_78)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_78);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_77)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_77);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_76)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_76);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_75)=(((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_75);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_74)=(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_74);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_73)=(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_73);(((struct scm_nest_lambda_28_env *)(&((var_2)->env)))->v_rv_72)=((env)->v_rv_72);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_28,var_2);call_closure_two(var_1,((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_26_env *env = (struct scm_nest_lambda_26_env *)(&((env_in)->env));((env)->v_rv_73)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_27_env );(((struct scm_nest_lambda_27_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_27,var_2);call_closure_two(var_1,((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_c_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_25_env *env = (struct scm_nest_lambda_25_env *)(&((env_in)->env));((env)->v_rv_74)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_26_env );(((struct scm_nest_lambda_26_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_26,var_2);call_closure_two(var_1,((struct scm_nest_lambda_24_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_b_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_24_env *env = (struct scm_nest_lambda_24_env *)(&((env_in)->env));((env)->v_rv_75)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_25_env );(((struct scm_nest_lambda_25_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_25,var_2);call_closure_two(var_1,(env)->v_a_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_23_env *env = (struct scm_nest_lambda_23_env *)(&((env_in)->env));((env)->v_rv_76)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_24_env );(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_a_3)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_3);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_b_3)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_3);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_c_3)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_3);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_d_3)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_3);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_e_2)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e_2);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_k_19)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_rv_94)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_94);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_rv_80)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_80);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_rv_79)=(((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_79);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_rv_78)=(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_78);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_rv_77)=(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_77);(((struct scm_nest_lambda_24_env *)(&((var_2)->env)))->v_rv_76)=((env)->v_rv_76);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_24,var_2);call_closure_two(var_1,((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e_2,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_22_env *env = (struct scm_nest_lambda_22_env *)(&((env_in)->env));((env)->v_rv_77)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_23_env );(((struct scm_nest_lambda_23_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_23,var_2);call_closure_two(var_1,((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_d_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_21_env *env = (struct scm_nest_lambda_21_env *)(&((env_in)->env));((env)->v_rv_78)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_22_env );(((struct scm_nest_lambda_22_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_22,var_2);call_closure_two(var_1,((struct scm_nest_lambda_20_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_c_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_20_env *env = (struct scm_nest_lambda_20_env *)(&((env_in)->env));((env)->v_rv_79)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_21_env );(((struct scm_nest_lambda_21_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_21,var_2);call_closure_two(var_1,(env)->v_b_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_19_env *env = (struct scm_nest_lambda_19_env *)(&((env_in)->env));((env)->v_rv_80)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_20_env );(((struct scm_nest_lambda_20_env *)(&((var_2)->env)))->v_a_3)=(((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_17_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_3);(((struct scm_nest_lambda_20_env *)(&((var_2)->env)))->v_b_3)=(((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_17_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_3);(((struct scm_nest_lambda_20_env *)(&((var_2)->env)))->v_c_3)=(((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_17_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_3);(((struct scm_nest_lambda_20_env *)(&((var_2)->env)))->v_d_3)=(((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_17_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_3);(((struct scm_nest_lambda_20_env *)(&((var_2)->env)))->v_e_2)=(((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_17_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e_2);(((struct scm_nest_lambda_20_env *)(&((var_2)->env)))->v_k_19)=(((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_17_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_nest_lambda_20_env *)(&((var_2)->env)))->v_rv_94)=(((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_17_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_94);(((struct scm_nest_lambda_20_env *)(&((var_2)->env)))->v_rv_80)=((env)->v_rv_80);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_20,var_2);call_closure_two(var_1,((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_17_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_18_env *env = (struct scm_nest_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_19_env );(((struct scm_nest_lambda_19_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_19,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_17_env *env = (struct scm_nest_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_18_env );(((struct scm_nest_lambda_18_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_18,var_1);call_closure_two(((struct scm_nest_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_93,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_16_env *env = (struct scm_nest_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_17_env );(((struct scm_nest_lambda_17_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_17,var_1);call_closure_two((env)->v_rv_92,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_15_env *env = (struct scm_nest_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_16_env );(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_a_3)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_3);(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_b_3)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_3);(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_c_3)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_3);(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_d_3)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_3);(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_e_2)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e_2);(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_k_19)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_rv_94)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_94);(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_rv_93)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_93);(((struct scm_nest_lambda_16_env *)(&((var_1)->env)))->v_rv_92)=(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_92);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_16,var_1);call_closure_two(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_91,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_14_env *env = (struct scm_nest_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_15_env );(((struct scm_nest_lambda_15_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_15,var_1);call_closure_two(((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_90,var_0,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_13_env *env = (struct scm_nest_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_14_env );(((struct scm_nest_lambda_14_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_14,var_1);call_closure_two(var_0,((struct scm_nest_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_e_2,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_12_env *env = (struct scm_nest_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_13_env );(((struct scm_nest_lambda_13_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_13,var_1);call_closure_two(var_0,(env)->v_d_3,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_11_env *env = (struct scm_nest_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_12_env );(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_a_3)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_3);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_b_3)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_3);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_c_3)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_3);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_d_3)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_3);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_e_2)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e_2);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_k_19)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_rv_94)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_94);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_rv_93)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_93);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_rv_92)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_92);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_rv_91)=(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_91);(((struct scm_nest_lambda_12_env *)(&((var_1)->env)))->v_rv_90)=(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_90);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_12,var_1);call_closure_two(var_0,((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_3,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_10_env *env = (struct scm_nest_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_11_env );(((struct scm_nest_lambda_11_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_11,var_1);call_closure_two(var_0,((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_9_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_b_3,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_9_env *env = (struct scm_nest_lambda_9_env *)(&((env_in)->env));((env)->v_rv_90)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_lambda_10_env );(((struct scm_nest_lambda_10_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_nest_lambda_10,var_1);call_closure_two(((struct cell_obj *)(((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_f))->val,((struct scm_nest_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_a_3,var_2);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_8_env *env = (struct scm_nest_lambda_8_env *)(&((env_in)->env));((env)->v_rv_91)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_9_env );(((struct scm_nest_lambda_9_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_9,var_2);call_closure_two(var_1,(env)->v_e_2,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_7_env *env = (struct scm_nest_lambda_7_env *)(&((env_in)->env));((env)->v_rv_92)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_8_env );(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_a_3)=(((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_3);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_b_3)=(((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_3);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_c_3)=(((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_3);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_d_3)=(((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_3);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_e_2)=(((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_e_2);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_f)=(((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_f);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_k_19)=(((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_rv_94)=(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_94);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_rv_93)=(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_93);(((struct scm_nest_lambda_8_env *)(&((var_2)->env)))->v_rv_92)=((env)->v_rv_92);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_8,var_2);call_closure_two(var_1,((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_6_env *env = (struct scm_nest_lambda_6_env *)(&((env_in)->env));((env)->v_rv_93)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_7_env );(((struct scm_nest_lambda_7_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_7,var_2);call_closure_two(var_1,((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)(((struct scm_nest_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_c_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_nest_lambda_5_env *env = (struct scm_nest_lambda_5_env *)(&((env_in)->env));((env)->v_rv_94)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_6_env );(((struct scm_nest_lambda_6_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_nest_lambda_6,var_2);call_closure_two(var_1,((struct scm_nest_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_b_3,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nest_lambda_4_env *env = (struct scm_nest_lambda_4_env *)(&((env_in)->env));((env)->v_e_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_19)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nest_lambda_5_env );(((struct scm_nest_lambda_5_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_nest_lambda_5,var_3);call_closure_two(var_2,(env)->v_a_3,var_4);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nest_lambda_3_env *env = (struct scm_nest_lambda_3_env *)(&((env_in)->env));((env)->v_d_3)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_4_env );(((struct scm_nest_lambda_4_env *)(&((var_2)->env)))->v_a_3)=((env)->v_a_3);(((struct scm_nest_lambda_4_env *)(&((var_2)->env)))->v_b_3)=((env)->v_b_3);(((struct scm_nest_lambda_4_env *)(&((var_2)->env)))->v_c_3)=((env)->v_c_3);(((struct scm_nest_lambda_4_env *)(&((var_2)->env)))->v_d_3)=((env)->v_d_3);(((struct scm_nest_lambda_4_env *)(&((var_2)->env)))->v_f)=((env)->v_f);OBJECT_CLOSURE_TWO_NEW(var_3,scm_nest_lambda_4,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nest_lambda_2_env *env = (struct scm_nest_lambda_2_env *)(&((env_in)->env));((env)->v_c_3)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_3_env );(((struct scm_nest_lambda_3_env *)(&((var_2)->env)))->v_a_3)=((env)->v_a_3);(((struct scm_nest_lambda_3_env *)(&((var_2)->env)))->v_b_3)=((env)->v_b_3);(((struct scm_nest_lambda_3_env *)(&((var_2)->env)))->v_c_3)=((env)->v_c_3);(((struct scm_nest_lambda_3_env *)(&((var_2)->env)))->v_f)=((env)->v_f);OBJECT_CLOSURE_TWO_NEW(var_3,scm_nest_lambda_3,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nest_lambda_1_env *env = (struct scm_nest_lambda_1_env *)(&((env_in)->env));((env)->v_b_3)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_2_env );(((struct scm_nest_lambda_2_env *)(&((var_2)->env)))->v_a_3)=((env)->v_a_3);(((struct scm_nest_lambda_2_env *)(&((var_2)->env)))->v_b_3)=((env)->v_b_3);(((struct scm_nest_lambda_2_env *)(&((var_2)->env)))->v_f)=((env)->v_f);OBJECT_CLOSURE_TWO_NEW(var_3,scm_nest_lambda_2,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 17 "closures.scm"
+static void scm_nest(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nest_env *env = (struct scm_nest_env *)(&((env_in)->env));((env)->v_a_3)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nest_lambda_1_env );(((struct scm_nest_lambda_1_env *)(&((var_2)->env)))->v_a_3)=((env)->v_a_3);(((struct scm_nest_lambda_1_env *)(&((var_2)->env)))->v_f)=((env)->v_f);OBJECT_CLOSURE_TWO_NEW(var_3,scm_nest_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));call_closure_two((env)->v_rv_96,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_rv_96)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_62,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_58,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_54,var_1);call_closure_two(var_0,MAKE_FIXNUM(5),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_49,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_45,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_40,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_36,var_2);call_closure_two(((struct cell_obj *)((env)->v_nest))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_3)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_41,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_37_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_37);
+
+#line 1 "closures.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));call_closure_two((env)->v_rv_104,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_rv_104)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_67,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_65,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_63,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_59,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_55,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_50,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_42(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_2)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_46,var_2);call_closure_two(((struct cell_obj *)((env)->v_nest))->val,MAKE_FIXNUM(5),var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_47(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_k_27)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_51,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_47_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_47);
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_31_env *env = (struct scm_loop_lambda_31_env *)(&((env_in)->env));call_closure_two((env)->v_rv_128,var_0,(env)->v_k_28);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_30_env *env = (struct scm_loop_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_31_env );(((struct scm_loop_lambda_31_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_loop_lambda_31_env *)(&((var_1)->env)))->v_rv_128)=((env)->v_rv_128);OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_31,var_1);call_closure_two((env)->v_rv_127,var_0,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_29_env *env = (struct scm_loop_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_30_env );(((struct scm_loop_lambda_30_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_loop_lambda_30_env *)(&((var_1)->env)))->v_rv_128)=((env)->v_rv_128);(((struct scm_loop_lambda_30_env *)(&((var_1)->env)))->v_rv_127)=((env)->v_rv_127);OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_30,var_1);call_closure_two((env)->v_rv_126,var_0,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_28_env *env = (struct scm_loop_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_29_env );(((struct scm_loop_lambda_29_env *)(&((var_1)->env)))->v_k_28)=(((struct scm_loop_lambda_25_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_26_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_loop_lambda_29_env *)(&((var_1)->env)))->v_rv_128)=(((struct scm_loop_lambda_25_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_26_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_128);(((struct scm_loop_lambda_29_env *)(&((var_1)->env)))->v_rv_127)=(((struct scm_loop_lambda_25_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_26_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_127);(((struct scm_loop_lambda_29_env *)(&((var_1)->env)))->v_rv_126)=(((struct scm_loop_lambda_25_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_26_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_126);OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_29,var_1);call_closure_two(((struct scm_loop_lambda_25_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_26_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_125,var_0,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_27_env *env = (struct scm_loop_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_28_env );(((struct scm_loop_lambda_28_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_28,var_1);call_closure_two(((struct scm_loop_lambda_25_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_124,var_0,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_26_env *env = (struct scm_loop_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_27_env );(((struct scm_loop_lambda_27_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_27,var_1);call_closure_two(((struct scm_loop_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_123,var_0,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_25_env *env = (struct scm_loop_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_26_env );(((struct scm_loop_lambda_26_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_26,var_1);call_closure_two((env)->v_rv_122,var_0,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_24_env *env = (struct scm_loop_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_25_env );(((struct scm_loop_lambda_25_env *)(&((var_1)->env)))->v_k_28)=(((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_loop_lambda_25_env *)(&((var_1)->env)))->v_rv_128)=(((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_128);(((struct scm_loop_lambda_25_env *)(&((var_1)->env)))->v_rv_127)=(((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_127);(((struct scm_loop_lambda_25_env *)(&((var_1)->env)))->v_rv_126)=(((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_126);(((struct scm_loop_lambda_25_env *)(&((var_1)->env)))->v_rv_125)=(((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_125);(((struct scm_loop_lambda_25_env *)(&((var_1)->env)))->v_rv_124)=(((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_124);(((struct scm_loop_lambda_25_env *)(&((var_1)->env)))->v_rv_123)=(((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_123);(((struct scm_loop_lambda_25_env *)(&((var_1)->env)))->v_rv_122)=(((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_122);OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_25,var_1);call_closure_two(var_0,((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_i,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_23_env *env = (struct scm_loop_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_24_env );(((struct scm_loop_lambda_24_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_24,var_1);call_closure_two(var_0,((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_22_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_d_4,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_22_env *env = (struct scm_loop_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_23_env );(((struct scm_loop_lambda_23_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_23,var_1);call_closure_two(var_0,((struct scm_loop_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_c_4,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_21_env *env = (struct scm_loop_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_22_env );(((struct scm_loop_lambda_22_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_22,var_1);call_closure_two(var_0,(env)->v_b_4,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_20_env *env = (struct scm_loop_lambda_20_env *)(&((env_in)->env));((env)->v_rv_122)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_21_env );(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_b_4)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_4);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_c_4)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_4);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_d_4)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_4);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_i)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_i);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_k_28)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_rv_128)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_128);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_rv_127)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_127);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_rv_126)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_126);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_rv_125)=(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_125);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_rv_124)=(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_124);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_rv_123)=(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_123);(((struct scm_loop_lambda_21_env *)(&((var_1)->env)))->v_rv_122)=((env)->v_rv_122);OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_21,var_1);call_closure_two(((struct cell_obj *)(((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_f))->val,((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_19_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_4,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_19_env *env = (struct scm_loop_lambda_19_env *)(&((env_in)->env));((env)->v_rv_123)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_20_env );(((struct scm_loop_lambda_20_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_20,var_2);call_closure_two(var_1,((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_d_4,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_18_env *env = (struct scm_loop_lambda_18_env *)(&((env_in)->env));((env)->v_rv_124)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_19_env );(((struct scm_loop_lambda_19_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_19,var_2);call_closure_two(var_1,((struct scm_loop_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_c_4,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_17_env *env = (struct scm_loop_lambda_17_env *)(&((env_in)->env));((env)->v_rv_125)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_18_env );(((struct scm_loop_lambda_18_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_18,var_2);call_closure_two(var_1,(env)->v_b_4,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_16_env *env = (struct scm_loop_lambda_16_env *)(&((env_in)->env));((env)->v_rv_126)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_17_env );(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_a_4)=(((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_4);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_b_4)=(((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_4);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_c_4)=(((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_4);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_d_4)=(((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_4);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_f)=(((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_f);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_i)=(((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_i);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_k_28)=(((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_rv_128)=(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_128);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_rv_127)=(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_127);(((struct scm_loop_lambda_17_env *)(&((var_2)->env)))->v_rv_126)=((env)->v_rv_126);OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_17,var_2);call_closure_two(var_1,((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_4,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_15_env *env = (struct scm_loop_lambda_15_env *)(&((env_in)->env));((env)->v_rv_127)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_16_env );(((struct scm_loop_lambda_16_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_16,var_2);call_closure_two(var_1,((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_i,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_14_env *env = (struct scm_loop_lambda_14_env *)(&((env_in)->env));((env)->v_rv_128)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_15_env );(((struct scm_loop_lambda_15_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_15,var_2);call_closure_two(var_1,((struct scm_loop_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_acc,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_13_env *env = (struct scm_loop_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_14_env );(((struct scm_loop_lambda_14_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_14,var_1);call_closure_two(var_0,(env)->v_d_4,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_12_env *env = (struct scm_loop_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_13_env );(((struct scm_loop_lambda_13_env *)(&((var_1)->env)))->v_a_4)=(((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_4);(((struct scm_loop_lambda_13_env *)(&((var_1)->env)))->v_acc)=(((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_acc);(((struct scm_loop_lambda_13_env *)(&((var_1)->env)))->v_b_4)=(((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_4);(((struct scm_loop_lambda_13_env *)(&((var_1)->env)))->v_c_4)=(((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_4);(((struct scm_loop_lambda_13_env *)(&((var_1)->env)))->v_d_4)=(((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_4);(((struct scm_loop_lambda_13_env *)(&((var_1)->env)))->v_f)=(((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_f);(((struct scm_loop_lambda_13_env *)(&((var_1)->env)))->v_i)=(((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_i);(((struct scm_loop_lambda_13_env *)(&((var_1)->env)))->v_k_28)=(((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_13,var_1);call_closure_two(var_0,((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_4,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_11_env *env = (struct scm_loop_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_12_env );(((struct scm_loop_lambda_12_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_12,var_1);call_closure_two(var_0,((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_b_4,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_10_env *env = (struct scm_loop_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_11_env );(((struct scm_loop_lambda_11_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_11,var_1);call_closure_two(var_0,((struct scm_loop_lambda_9_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_a_4,var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_9_env *env = (struct scm_loop_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_10_env );(((struct scm_loop_lambda_10_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_10,var_1);struct obj *var_3 = ((struct cell_obj *)((env)->v_loop))->val;struct obj *var_4 = var_0;struct obj *var_5 = var_2;if (stack_check()){scm_loop(var_4,var_5,((struct closure_obj *)(var_3))->env);} else {}call_closure_two(var_3,var_4,var_5);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_8_env *env = (struct scm_loop_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_9_env );(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_a_4)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a_4);(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_acc)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_acc);(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_b_4)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b_4);(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_c_4)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c_4);(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_d_4)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_d_4);(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_f)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_f);(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_i)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_i);(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_k_28)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_loop_lambda_9_env *)(&((var_1)->env)))->v_loop)=(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_7_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_loop);OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_9,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_7_env *env = (struct scm_loop_lambda_7_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one(((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_28,((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc);} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_8_env );(((struct scm_loop_lambda_8_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_8,var_2);call_closure_two(var_1,((struct scm_loop_lambda_5_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_i,var_3);}0;__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_6_env *env = (struct scm_loop_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_7_env );(((struct scm_loop_lambda_7_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_7,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_5(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_loop_lambda_5_env *env = (struct scm_loop_lambda_5_env *)(&((env_in)->env));((env)->v_acc)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_28)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_loop_lambda_6_env );(((struct scm_loop_lambda_6_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_loop_lambda_6,var_3);call_closure_two(var_2,(env)->v_i,var_4);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_loop_lambda_4_env *env = (struct scm_loop_lambda_4_env *)(&((env_in)->env));((env)->v_d_4)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_5_env );(((struct scm_loop_lambda_5_env *)(&((var_2)->env)))->v_a_4)=((env)->v_a_4);(((struct scm_loop_lambda_5_env *)(&((var_2)->env)))->v_b_4)=((env)->v_b_4);(((struct scm_loop_lambda_5_env *)(&((var_2)->env)))->v_c_4)=((env)->v_c_4);(((struct scm_loop_lambda_5_env *)(&((var_2)->env)))->v_d_4)=((env)->v_d_4);(((struct scm_loop_lambda_5_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm_loop_lambda_5_env *)(&((var_2)->env)))->v_i)=((env)->v_i);(((struct scm_loop_lambda_5_env *)(&((var_2)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_TWO_NEW(var_3,scm_loop_lambda_5,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_loop_lambda_3_env *env = (struct scm_loop_lambda_3_env *)(&((env_in)->env));((env)->v_c_4)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_4_env );(((struct scm_loop_lambda_4_env *)(&((var_2)->env)))->v_a_4)=((env)->v_a_4);(((struct scm_loop_lambda_4_env *)(&((var_2)->env)))->v_b_4)=((env)->v_b_4);(((struct scm_loop_lambda_4_env *)(&((var_2)->env)))->v_c_4)=((env)->v_c_4);(((struct scm_loop_lambda_4_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm_loop_lambda_4_env *)(&((var_2)->env)))->v_i)=((env)->v_i);(((struct scm_loop_lambda_4_env *)(&((var_2)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_TWO_NEW(var_3,scm_loop_lambda_4,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_loop_lambda_2_env *env = (struct scm_loop_lambda_2_env *)(&((env_in)->env));((env)->v_b_4)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_3_env );(((struct scm_loop_lambda_3_env *)(&((var_2)->env)))->v_a_4)=((env)->v_a_4);(((struct scm_loop_lambda_3_env *)(&((var_2)->env)))->v_b_4)=((env)->v_b_4);(((struct scm_loop_lambda_3_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm_loop_lambda_3_env *)(&((var_2)->env)))->v_i)=((env)->v_i);(((struct scm_loop_lambda_3_env *)(&((var_2)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_TWO_NEW(var_3,scm_loop_lambda_3,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_loop_lambda_1_env *env = (struct scm_loop_lambda_1_env *)(&((env_in)->env));((env)->v_a_4)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_2_env );(((struct scm_loop_lambda_2_env *)(&((var_2)->env)))->v_a_4)=((env)->v_a_4);(((struct scm_loop_lambda_2_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm_loop_lambda_2_env *)(&((var_2)->env)))->v_i)=((env)->v_i);(((struct scm_loop_lambda_2_env *)(&((var_2)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_TWO_NEW(var_3,scm_loop_lambda_2,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 24 "closures.scm"
+static void scm_loop(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_loop_env *env = (struct scm_loop_env *)(&((env_in)->env));((env)->v_i)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_1_env );(((struct scm_loop_lambda_1_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm_loop_lambda_1_env *)(&((var_2)->env)))->v_i)=((env)->v_i);(((struct scm_loop_lambda_1_env *)(&((var_2)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_TWO_NEW(var_3,scm_loop_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));call_closure_two((env)->v_rv_138,var_0,(env)->v_k_34);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_34)=((env)->v_k_34);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_rv_138)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_70,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_69,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_68,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_66,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_64,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_56(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_2)->env)))->v_k_34)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_60,var_2);call_closure_two(((struct cell_obj *)((env)->v_loop))->val,MAKE_FIXNUM(100000),var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_35);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_k_35)=((env)->v_k_35);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_61,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_env );(((struct scm_loop_env *)(&((var_1)->env)))->v_f)=((env)->v_f);(((struct scm_loop_env *)(&((var_1)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_TWO_NEW(var_2,scm_loop,var_1);(((struct cell_obj *)((env)->v_loop))->val)=(var_2);GC_WRITE_BARRIER((env)->v_loop,((struct cell_obj *)((env)->v_loop))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_3)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_56,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_5)->env)))->v_k_35)=((env)->v_k_35);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_57,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_1)->env)))->v_f)=((env)->v_f);(((struct scm_lambda_48_env *)(&((var_1)->env)))->v_k_35)=((env)->v_k_35);(((struct scm_lambda_48_env *)(&((var_1)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_48,var_1);call_closure_two((struct obj *)(&(scm_lambda_47_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_nest)=(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_nest);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_42,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_3)->env)))->v_f)=(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_f);(((struct scm_lambda_43_env *)(&((var_3)->env)))->v_k_35)=(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_35);(((struct scm_lambda_43_env *)(&((var_3)->env)))->v_loop)=(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_loop);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_43,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_38,var_1);call_closure_two((struct obj *)(&(scm_lambda_37_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nest_env );(((struct scm_nest_env *)(&((var_1)->env)))->v_f)=((env)->v_f);OBJECT_CLOSURE_TWO_NEW(var_2,scm_nest,var_1);(((struct cell_obj *)((env)->v_nest))->val)=(var_2);GC_WRITE_BARRIER((env)->v_nest,((struct cell_obj *)((env)->v_nest))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_3)->env)))->v_nest)=((env)->v_nest);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_32,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_33,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_f)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_f);(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_k_35)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_35);(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_loop)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_loop);(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_nest)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_nest);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_24,var_1);call_closure_two((struct obj *)(&(scm_lambda_23_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_counter_env );OBJECT_CLOSURE_TWO_NEW(var_2,scm_counter,var_1);(((struct cell_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter,((struct cell_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_counter)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_19,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_20,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two((struct obj *)(&(scm_lambda_12_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_loop)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_35)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_env );OBJECT_CLOSURE_TWO_NEW(var_4,scm_f,var_3);(((struct cell_obj *)((env)->v_f))->val)=(var_4);GC_WRITE_BARRIER((env)->v_f,((struct cell_obj *)((env)->v_f))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_5)->env)))->v_f)=((env)->v_f);OBJECT_CLOSURE_TWO_NEW(var_6,scm_lambda_9,var_5);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_7)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_8,scm_lambda_10,var_7);call_closure_two(var_6,NULL,var_8);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_nest)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_f)=((env)->v_f);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_nest)=((env)->v_nest);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_4,NULL,(env)->v_k_39);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_counter)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_f)=((env)->v_f);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_7_inlined_inlined_env );(((struct scm_lambda_7_inlined_inlined_env *)(&((var_5)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_7_inlined_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_f)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_f)=((env)->v_f);(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_5_inlined_env );(((struct scm_lambda_5_inlined_env *)(&((var_5)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_5_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_39);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_39)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_39)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "closures.scm"
+static void scm_lambda_7_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_inlined_env *env = (struct scm_lambda_7_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_39);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_5_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_inlined_env *env = (struct scm_lambda_5_inlined_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_inlined_env );(((struct scm_lambda_7_inlined_env *)(&((var_1)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7_inlined,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+static void scm_lambda_7_inlined_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_inlined_inlined_env *env = (struct scm_lambda_7_inlined_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_39);__builtin_unreachable();}
+
+#line 1 "closures.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 1015 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,425 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm__u28shapes_u29_u20square_lambda_1_env {struct obj *v_k;struct obj *v_x;};
+static void scm__u28shapes_u29_u20square_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u28shapes_u29_u20square(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u28shapes_u29_u20square_closure;
+struct scm_twice_lambda_1_env {struct obj *v_k_2;struct obj *v_n;};
+static void scm_twice_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_twice(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_twice_closure;
+struct scm_lambda_18_env {struct obj *v_k_3;struct obj *v_rv_4;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_3;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_greeting;};
+static void scm_lambda_13(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_4;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_16_closure;
+struct scm_lambda_33_env {struct obj *v_k_5;struct obj *v_rv_7;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_5;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_5;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_5;struct obj *v_twice;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v__u28shapes_u29_u20square;struct obj *v_twice;};
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_6;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_23_closure;
+struct scm_lambda_34_env {struct obj *v_k_7;struct obj *v_rv_12;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_7;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_27(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_27_closure;
+struct scm_lambda_35_env {struct obj *v_k_8;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_31_closure;
+struct scm_lambda_41_env {struct obj *v_k_9;struct obj *v_rv_15;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_9;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_36_closure;
+struct scm_lambda_42_env {struct obj *v_k_10;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_39(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_39_closure;
+struct scm_lambda_48_env {struct obj *v_k_11;struct obj *v_rv_18;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_11;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_43(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_43_closure;
+struct scm_lambda_49_env {struct obj *v_k_12;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_46(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_46_closure;
+struct scm_lambda_55_env {struct obj *v_k_13;struct obj *v_rv_21;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_13;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_50(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_50_closure;
+struct scm_lambda_56_env {struct obj *v_k_14;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_53(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_53_closure;
+struct scm_shout_lambda_1_env {struct obj *v_k_15;};
+static void scm_shout_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_shout(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_shout_closure;
+struct scm_lambda_68_env {struct obj *v_k_16;struct obj *v_rv_25;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_16;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_16;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_shout;};
+static void scm_lambda_59(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_k_17;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_62(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_62_closure;
+struct scm_lambda_71_env {struct obj *v_k_18;struct obj *v_rv_29;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_18;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_66(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_66_closure;
+struct scm_lambda_70_env {struct obj *v_k_19;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_19;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_19;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_19;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_19;struct obj *v_shout;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *parent;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *parent;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v__u28shapes_u29_u20square;struct obj *v_greeting;struct obj *v_k_19;struct obj *v_shout;struct obj *v_twice;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v__u28shapes_u29_u20square;struct obj *v_greeting;struct obj *v_k_23;struct obj *v_twice;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v__u28shapes_u29_u20square;struct obj *v_k_23;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v__u28shapes_u29_u20square;struct obj *v_k_23;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_23;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_23;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_23;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_lambda_7_inlined_env {struct obj *v_k_23;};
+static void scm_lambda_7_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_inlined_env {struct obj *v_k_23;};
+static void scm_lambda_5_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_inlined_inlined_env {struct obj *v_k_23;};
+static void scm_lambda_7_inlined_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj symbol_base = SYMBOL_OBJ_INIT("base");
+static struct symbol_obj symbol_either = SYMBOL_OBJ_INIT("either");
+static struct symbol_obj symbol_nested = SYMBOL_OBJ_INIT("nested");
+static struct symbol_obj symbol_shapes = SYMBOL_OBJ_INIT("shapes");
+static struct symbol_obj symbol_undefined = SYMBOL_OBJ_INIT("undefined");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_base),&(symbol_either),&(symbol_nested),&(symbol_shapes),&(symbol_undefined),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm__u28shapes_u29_u20square_lambda_1),"scm__u28shapes_u29_u20square_lambda_1","cond_expand.scm",4},{(void *)(scm__u28shapes_u29_u20square),"scm__u28shapes_u29_u20square","cond_expand.scm",4},{(void *)(scm_twice_lambda_1),"scm_twice_lambda_1","cond_expand.scm",10},{(void *)(scm_twice),"scm_twice","cond_expand.scm",10},{(void *)(scm_lambda_18),"scm_lambda_18","cond_expand.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","cond_expand.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","cond_expand.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","cond_expand.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","cond_expand.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","cond_expand.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","cond_expand.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","cond_expand.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","cond_expand.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","cond_expand.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","cond_expand.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","cond_expand.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","cond_expand.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","cond_expand.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","cond_expand.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","cond_expand.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","cond_expand.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","cond_expand.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","cond_expand.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","cond_expand.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","cond_expand.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","cond_expand.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","cond_expand.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","cond_expand.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","cond_expand.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","cond_expand.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","cond_expand.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","cond_expand.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","cond_expand.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","cond_expand.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","cond_expand.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","cond_expand.scm",1},{(void *)(scm_shout_lambda_1),"scm_shout_lambda_1","cond_expand.scm",22},{(void *)(scm_shout),"scm_shout","cond_expand.scm",22},{(void *)(scm_lambda_68),"scm_lambda_68","cond_expand.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","cond_expand.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","cond_expand.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","cond_expand.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","cond_expand.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","cond_expand.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","cond_expand.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","cond_expand.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","cond_expand.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","cond_expand.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","cond_expand.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","cond_expand.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","cond_expand.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","cond_expand.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","cond_expand.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","cond_expand.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","cond_expand.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","cond_expand.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","cond_expand.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","cond_expand.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","cond_expand.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","cond_expand.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","cond_expand.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","cond_expand.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","cond_expand.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","cond_expand.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","cond_expand.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","cond_expand.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","cond_expand.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","cond_expand.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","cond_expand.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","cond_expand.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","cond_expand.scm",1},{(void *)(scm_lambda_7_inlined),"scm_lambda_7_inlined","cond_expand.scm",1},{(void *)(scm_lambda_5_inlined),"scm_lambda_5_inlined","cond_expand.scm",1},{(void *)(scm_lambda_7_inlined_inlined),"scm_lambda_7_inlined_inlined","cond_expand.scm",1},{NULL}};
+
+#line 4 "cond_expand.scm"
+static void scm__u28shapes_u29_u20square_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm__u28shapes_u29_u20square_lambda_1_env *env = (struct scm__u28shapes_u29_u20square_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_x,(env)->v_k);__builtin_unreachable();}
+
+#line 4 "cond_expand.scm"
+static void scm__u28shapes_u29_u20square(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u28shapes_u29_u20square_lambda_1_env );(((struct scm__u28shapes_u29_u20square_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm__u28shapes_u29_u20square_lambda_1_env *)(&((var_3)->env)))->v_x)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm__u28shapes_u29_u20square_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm__u28shapes_u29_u20square_closure = CLOSURE_TWO_OBJ_INIT(scm__u28shapes_u29_u20square);
+
+#line 10 "cond_expand.scm"
+static void scm_twice_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_twice_lambda_1_env *env = (struct scm_twice_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_n,(env)->v_k_2);__builtin_unreachable();}
+
+#line 10 "cond_expand.scm"
+static void scm_twice(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_twice_lambda_1_env );(((struct scm_twice_lambda_1_env *)(&((var_3)->env)))->v_k_2)=(var_1);(((struct scm_twice_lambda_1_env *)(&((var_3)->env)))->v_n)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_twice_lambda_1,var_3);call_closure_two(var_2,MAKE_FIXNUM(2),var_4);__builtin_unreachable();}
+static struct closure_obj scm_twice_closure = CLOSURE_TWO_OBJ_INIT(scm_twice);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));call_closure_two((env)->v_rv_4,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_rv_4)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_18,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_13(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_greeting))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_19,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_16_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_16);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));call_closure_two((env)->v_rv_7,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_rv_7)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_33,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_25,var_1);call_closure_two(((struct cell_obj *)((env)->v_twice))->val,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_k_5)=(var_1);(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_twice)=((env)->v_twice);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22,var_2);call_closure_two(((struct cell_obj *)((env)->v__u28shapes_u29_u20square))->val,MAKE_FIXNUM(4),var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_26,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_23_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_23);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));call_closure_two((env)->v_rv_12,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_rv_12)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_27(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_30,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_either)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_27_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_27);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_35,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_31_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_31);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));call_closure_two((env)->v_rv_15,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_rv_15)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_41,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_38,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_shapes)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_36_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_36);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_39(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_42,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_39_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_39);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));call_closure_two((env)->v_rv_18,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_rv_18)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_48,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_43(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_45,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_base)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_43_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_43);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_46(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_49,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_46_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_46);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));call_closure_two((env)->v_rv_21,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_rv_21)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_55,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_50(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_52,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_undefined)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_50_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_50);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_53(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_56,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_53_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_53);
+
+#line 22 "cond_expand.scm"
+static void scm_shout_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_shout_lambda_1_env *env = (struct scm_shout_lambda_1_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"!");call_closure_two(var_0,var_1,(env)->v_k_15);__builtin_unreachable();}
+
+#line 22 "cond_expand.scm"
+static void scm_shout(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,string_append_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_shout_lambda_1_env );(((struct scm_shout_lambda_1_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_shout_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_shout_closure = CLOSURE_TWO_OBJ_INIT(scm_shout);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));call_closure_two((env)->v_rv_25,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_rv_25)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_64,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_59(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_2,"hi");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_61,var_3);call_closure_two(((struct cell_obj *)((env)->v_shout))->val,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_62(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_65,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_62_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_62);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));call_closure_two((env)->v_rv_29,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_rv_29)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_66(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_69,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_nested)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_66_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_66);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_70,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_67,var_1);call_closure_two((struct obj *)(&(scm_lambda_66_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_63,var_1);call_closure_two((struct obj *)(&(scm_lambda_62_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_shout))->val)=((struct obj *)(&(scm_shout_closure)));GC_WRITE_BARRIER((env)->v_shout,((struct cell_obj *)((env)->v_shout))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_59,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_3)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_60,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_54,var_1);call_closure_two((struct obj *)(&(scm_lambda_53_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_51_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_51,var_1);call_closure_two((struct obj *)(&(scm_lambda_50_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_47,var_1);call_closure_two((struct obj *)(&(scm_lambda_46_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_44_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_44,var_1);call_closure_two((struct obj *)(&(scm_lambda_43_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_40_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_40,var_1);call_closure_two((struct obj *)(&(scm_lambda_39_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_37,var_1);call_closure_two((struct obj *)(&(scm_lambda_36_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_32,var_1);call_closure_two((struct obj *)(&(scm_lambda_31_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two((struct obj *)(&(scm_lambda_27_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_24,var_1);call_closure_two((struct obj *)(&(scm_lambda_23_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_1)->env)))->v__u28shapes_u29_u20square)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u28shapes_u29_u20square);(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_twice)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_twice);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_20,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_19)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_shout)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_shout);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_17,var_1);call_closure_two((struct obj *)(&(scm_lambda_16_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_shout)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_19)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v__u28shapes_u29_u20square))->val)=((struct obj *)(&(scm__u28shapes_u29_u20square_closure)));GC_WRITE_BARRIER((env)->v__u28shapes_u29_u20square,((struct cell_obj *)((env)->v__u28shapes_u29_u20square))->val);OBJECT_STRING_OBJ_NEW(var_3,"portable");(((struct cell_obj *)((env)->v_greeting))->val)=(var_3);GC_WRITE_BARRIER((env)->v_greeting,((struct cell_obj *)((env)->v_greeting))->val);(((struct cell_obj *)((env)->v_twice))->val)=((struct obj *)(&(scm_twice_closure)));GC_WRITE_BARRIER((env)->v_twice,((struct cell_obj *)((env)->v_twice))->val);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_4)->env)))->v_greeting)=((env)->v_greeting);OBJECT_CLOSURE_TWO_NEW(var_5,scm_lambda_13,var_4);OBJECT_ENV_OBJ_NEW(var_6,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_6)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_7,scm_lambda_14,var_6);call_closure_two(var_5,NULL,var_7);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_twice)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u28shapes_u29_u20square)=((env)->v__u28shapes_u29_u20square);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_greeting)=((env)->v_greeting);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_twice)=((env)->v_twice);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_4,NULL,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v__u28shapes_u29_u20square)=((env)->v__u28shapes_u29_u20square);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_greeting)=(var_2);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_7_inlined_inlined_env );(((struct scm_lambda_7_inlined_inlined_env *)(&((var_5)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_7_inlined_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u28shapes_u29_u20square)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v__u28shapes_u29_u20square)=((env)->v__u28shapes_u29_u20square);(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_5_inlined_env );(((struct scm_lambda_5_inlined_env *)(&((var_5)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_5_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_7_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_inlined_env *env = (struct scm_lambda_7_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_5_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_inlined_env *env = (struct scm_lambda_5_inlined_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_inlined_env );(((struct scm_lambda_7_inlined_env *)(&((var_1)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7_inlined,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+static void scm_lambda_7_inlined_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_inlined_inlined_env *env = (struct scm_lambda_7_inlined_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "cond_expand.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 406 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,229 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_16_env {struct obj *v_k;struct obj *v_rv_2;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_c;};
+static void scm_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_2;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_10_closure;
+struct scm_lambda_24_env {struct obj *v_k_3;struct obj *v_rv_6;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_3;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_3;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_c;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_4;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_18_closure;
+struct scm_lambda_35_env {struct obj *v_k_5;struct obj *v_rv_10;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_5;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_5;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_5;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_c;};
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_6;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_26_closure;
+struct scm_lambda_38_env {struct obj *v_k_7;struct obj *v_rv_15;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_7;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_7;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_7;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_c;};
+static void scm_lambda_30(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_k_8;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_8;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_c;struct obj *v_k_8;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_c;struct obj *v_k_8;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_c;struct obj *v_k_8;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_c;struct obj *v_k_8;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_c;struct obj *v_k_8;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_c;struct obj *v_k_8;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_c;struct obj *v_k_8;};
+static void scm_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_c;struct obj *v_k_8;struct obj *v_rv_29;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_c;struct obj *v_k_8;struct obj *v_rv_29;};
+static void scm_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_c;struct obj *v_k_8;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_16),"scm_lambda_16","cons.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","cons.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","cons.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","cons.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","cons.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","cons.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","cons.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","cons.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","cons.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","cons.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","cons.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","cons.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","cons.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","cons.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","cons.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","cons.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","cons.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","cons.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","cons.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","cons.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","cons.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","cons.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","cons.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","cons.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","cons.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","cons.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","cons.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","cons.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","cons.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","cons.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","cons.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","cons.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","cons.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","cons.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","cons.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","cons.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","cons.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","cons.scm",1},{NULL}};
+
+#line 1 "cons.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_12,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_9,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_c))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_13,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_10_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_10);
+
+#line 1 "cons.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_24,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_20,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_c))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_18_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_18);
+
+#line 1 "cons.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));call_closure_two((env)->v_rv_10,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_rv_10)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_c))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_29,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_26_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_26);
+
+#line 1 "cons.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));call_closure_two((env)->v_rv_15,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_rv_15)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_37,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_36,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_30(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_c))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_c)=((env)->v_c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_30,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_27,var_1);call_closure_two((struct obj *)(&(scm_lambda_26_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_1)->env)))->v_c)=((env)->v_c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_22,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_19,var_1);call_closure_two((struct obj *)(&(scm_lambda_18_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_c)=((env)->v_c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_14,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two((struct obj *)(&(scm_lambda_10_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_c))->val)=(var_0);GC_WRITE_BARRIER((env)->v_c,((struct cell_obj *)((env)->v_c))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_c)=((env)->v_c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_7,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_1)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_6_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_6,var_1);call_closure_two((env)->v_rv_29,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_rv_29)=((env)->v_rv_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_2)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_4_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_4_env *)(&((var_2)->env)))->v_rv_29)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_4,var_2);call_closure_two(var_1,MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+
+#line 1 "cons.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_CLOSURE_TWO_NEW(var_3,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_c)=(var_2);(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,MAKE_FIXNUM(1),var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "cons.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "cons.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 210 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,764 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_cyclic_lambda_5_env {struct obj *v_k;struct obj *v_rv_4;};
+static void scm_cyclic_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_cyclic_lambda_4_env {struct obj *v_k;struct obj *v_rv_4;};
+static void scm_cyclic_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_cyclic_lambda_3_env {struct obj *v_k;struct obj *v_rv_4;};
+static void scm_cyclic_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_cyclic_lambda_2_env {struct obj *v_k;};
+static void scm_cyclic_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_cyclic_lambda_1_env {struct obj *v_k;struct obj *v_x;};
+static void scm_cyclic_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_cyclic(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_cyclic_closure;
+struct scm_lambda_13_env {struct obj *v_a;struct obj *v_k_2;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_a;struct obj *v_cyclic;};
+static void scm_lambda_11(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_b;struct obj *v_k_3;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_b;struct obj *v_cyclic;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_4;struct obj *v_rv_9;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_4;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_4;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_b;struct obj *v_k_4;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_a;struct obj *v_b;};
+static void scm_lambda_17(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_5;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_20_closure;
+struct scm_lambda_44_env {struct obj *v_k_6;struct obj *v_rv_14;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_6;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_6;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_6;struct obj *v_rv_17;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_cyclic;struct obj *v_k_6;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_a;struct obj *v_cyclic;};
+static void scm_lambda_24(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_7;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_28_closure;
+struct scm_lambda_96_env {struct obj *v_k_8;struct obj *v_rv_20;};
+static void scm_lambda_96(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v_k_8;};
+static void scm_lambda_89(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_82_env {struct obj *v_k_8;};
+static void scm_lambda_82(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_8;struct obj *v_rv_26;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_8;struct obj *v_rv_26;struct obj *v_rv_25;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_8;struct obj *v_rv_26;struct obj *v_rv_25;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_8;struct obj *v_rv_26;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_b;struct obj *v_k_8;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_b;struct obj *v_k_8;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_b;struct obj *v_k_8;struct obj *v_rv_30;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_b;struct obj *v_k_8;struct obj *v_rv_30;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_b;struct obj *v_k_8;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_a;struct obj *v_b;};
+static void scm_lambda_33(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_9;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_37_closure;
+struct scm_lambda_111_env {struct obj *v_k_10;struct obj *v_rv_33;};
+static void scm_lambda_111(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_k_10;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *v_k_10;};
+static void scm_lambda_97(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_90_env {struct obj *v_k_10;struct obj *v_rv_39;};
+static void scm_lambda_90(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *v_k_10;struct obj *v_rv_39;struct obj *v_rv_38;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_76_env {struct obj *v_k_10;struct obj *v_rv_39;struct obj *v_rv_38;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_k_10;struct obj *v_rv_39;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_b;struct obj *v_k_10;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_b;struct obj *v_k_10;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_b;struct obj *v_k_10;struct obj *v_rv_43;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_b;struct obj *v_k_10;struct obj *v_rv_43;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_b;struct obj *v_k_10;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_a;struct obj *v_b;};
+static void scm_lambda_42(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_11;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_47(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_47_closure;
+struct scm_build_lambda_8_env {struct obj *v_k_12;struct obj *v_rv_47;};
+static void scm_build_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_build_lambda_7_env {struct obj *v_acc;struct obj *v_k_12;struct obj *v_rv_47;};
+static void scm_build_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_build_lambda_6_env {struct obj *v_acc;struct obj *v_k_12;struct obj *v_n;};
+static void scm_build_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_build_lambda_5_env {struct obj *v_acc;struct obj *v_build;struct obj *v_k_12;struct obj *v_n;};
+static void scm_build_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_build_lambda_4_env {struct obj *parent;};
+static void scm_build_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_build_lambda_3_env {struct obj *parent;};
+static void scm_build_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_build_lambda_2_env {struct obj *parent;};
+static void scm_build_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_build_lambda_1_env {struct obj *v_acc;struct obj *v_build;struct obj *v_k_12;struct obj *v_n;};
+static void scm_build_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_build_env {struct obj *v_build;struct obj *v_n;};
+static void scm_build(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_105_env {struct obj *v_k_14;struct obj *v_rv_53;};
+static void scm_lambda_105(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_k_14;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_91_env {struct obj *v_k_14;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_k_14;struct obj *v_rv_57;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_k_14;struct obj *v_rv_57;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_build;struct obj *v_k_14;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_build;struct obj *v_k_14;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_build;struct obj *v_k_14;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_build;};
+static void scm_lambda_56(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_k_15;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_61(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_61_closure;
+struct scm_lambda_117_env {struct obj *v_k_16;struct obj *v_rv_62;};
+static void scm_lambda_117(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_112_env {struct obj *v_k_16;};
+static void scm_lambda_112(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *v_k_16;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_99_env {struct obj *v_k_16;struct obj *v_rv_66;};
+static void scm_lambda_99(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_92_env {struct obj *v_k_16;struct obj *v_rv_66;};
+static void scm_lambda_92(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_85_env {struct obj *v_build;struct obj *v_k_16;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *v_build;struct obj *v_k_16;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_build;struct obj *v_k_16;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_build;};
+static void scm_lambda_67(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_17;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_73(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_73_closure;
+struct scm_lambda_86_env {struct obj *v_h;struct obj *v_k_18;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_h;};
+static void scm_lambda_80(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *v_k_19;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_93_env {struct obj *v_a;struct obj *v_k_19;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_a;struct obj *v_h;};
+static void scm_lambda_87(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_123_env {struct obj *v_k_20;struct obj *v_rv_74;};
+static void scm_lambda_123(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_k_20;};
+static void scm_lambda_118(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_113_env {struct obj *v_k_20;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_107_env {struct obj *v_k_20;};
+static void scm_lambda_107(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_b;struct obj *v_k_20;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_94_env {struct obj *v_b;struct obj *v_h;};
+static void scm_lambda_94(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_108_env {struct obj *v_k_21;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_102(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_102_closure;
+struct scm_lambda_130_env {struct obj *v_k_22;struct obj *v_rv_80;};
+static void scm_lambda_130(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_129_env {struct obj *v_k_22;};
+static void scm_lambda_129(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_127_env {struct obj *v_k_22;};
+static void scm_lambda_127(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_124_env {struct obj *v_k_22;};
+static void scm_lambda_124(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_119_env {struct obj *v_k_22;struct obj *v_rv_84;};
+static void scm_lambda_119(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_114_env {struct obj *v_cyclic;struct obj *v_k_22;};
+static void scm_lambda_114(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_109_env {struct obj *v_cyclic;struct obj *v_h;};
+static void scm_lambda_109(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_120_env {struct obj *v_k_23;};
+static void scm_lambda_120(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_115(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_115_closure;
+struct scm_lambda_128_env {struct obj *v_k_24;struct obj *v_rv_87;};
+static void scm_lambda_128(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_125_env {struct obj *v_k_24;};
+static void scm_lambda_125(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_121_env {struct obj *v_a;};
+static void scm_lambda_121(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_126_env {struct obj *v_k_25;};
+static void scm_lambda_126(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_122_env {struct obj *v_k_25;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_116_env {struct obj *v_a;struct obj *v_k_25;};
+static void scm_lambda_116(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *v_a;struct obj *v_k_25;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_103_env {struct obj *parent;};
+static void scm_lambda_103(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_95_env {struct obj *parent;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_a;struct obj *v_b;struct obj *v_cyclic;struct obj *v_h;struct obj *v_k_25;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *parent;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *parent;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *parent;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_a;struct obj *v_b;struct obj *v_build;struct obj *v_cyclic;struct obj *v_h;struct obj *v_k_25;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *parent;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *parent;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *parent;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_a;struct obj *v_b;struct obj *v_build;struct obj *v_cyclic;struct obj *v_h;struct obj *v_k_25;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *parent;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *parent;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *parent;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_a;struct obj *v_b;struct obj *v_build;struct obj *v_cyclic;struct obj *v_h;struct obj *v_k_25;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *parent;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *parent;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *parent;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_a;struct obj *v_b;struct obj *v_build;struct obj *v_cyclic;struct obj *v_h;struct obj *v_k_25;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_a;struct obj *v_b;struct obj *v_build;struct obj *v_cyclic;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_a;struct obj *v_b;struct obj *v_cyclic;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_a;struct obj *v_cyclic;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_cyclic;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_30;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_30;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_30;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_30;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_end = SYMBOL_OBJ_INIT("end");
+static struct symbol_obj symbol_found = SYMBOL_OBJ_INIT("found");
+static struct symbol_obj symbol_missing = SYMBOL_OBJ_INIT("missing");
+static struct symbol_obj symbol_other = SYMBOL_OBJ_INIT("other");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_end),&(symbol_found),&(symbol_missing),&(symbol_other),NULL};
+static struct cons_obj quoted_9419fc85fe59b773 = CONS_OBJ_INIT(MAKE_FIXNUM(0),NULL);
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_cyclic_lambda_5),"scm_cyclic_lambda_5","cycles.scm",2},{(void *)(scm_cyclic_lambda_4),"scm_cyclic_lambda_4","cycles.scm",2},{(void *)(scm_cyclic_lambda_3),"scm_cyclic_lambda_3","cycles.scm",2},{(void *)(scm_cyclic_lambda_2),"scm_cyclic_lambda_2","cycles.scm",2},{(void *)(scm_cyclic_lambda_1),"scm_cyclic_lambda_1","cycles.scm",2},{(void *)(scm_cyclic),"scm_cyclic","cycles.scm",2},{(void *)(scm_lambda_13),"scm_lambda_13","cycles.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","cycles.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","cycles.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","cycles.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","cycles.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","cycles.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","cycles.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","cycles.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","cycles.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","cycles.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","cycles.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","cycles.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","cycles.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","cycles.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","cycles.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","cycles.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","cycles.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","cycles.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","cycles.scm",1},{(void *)(scm_lambda_96),"scm_lambda_96","cycles.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","cycles.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","cycles.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","cycles.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","cycles.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","cycles.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","cycles.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","cycles.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","cycles.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","cycles.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","cycles.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","cycles.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","cycles.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","cycles.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","cycles.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","cycles.scm",1},{(void *)(scm_lambda_104),"scm_lambda_104","cycles.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","cycles.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","cycles.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","cycles.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","cycles.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","cycles.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","cycles.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","cycles.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","cycles.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","cycles.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","cycles.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","cycles.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","cycles.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","cycles.scm",1},{(void *)(scm_build_lambda_8),"scm_build_lambda_8","cycles.scm",16},{(void *)(scm_build_lambda_7),"scm_build_lambda_7","cycles.scm",16},{(void *)(scm_build_lambda_6),"scm_build_lambda_6","cycles.scm",16},{(void *)(scm_build_lambda_5),"scm_build_lambda_5","cycles.scm",16},{(void *)(scm_build_lambda_4),"scm_build_lambda_4","cycles.scm",16},{(void *)(scm_build_lambda_3),"scm_build_lambda_3","cycles.scm",16},{(void *)(scm_build_lambda_2),"scm_build_lambda_2","cycles.scm",16},{(void *)(scm_build_lambda_1),"scm_build_lambda_1","cycles.scm",16},{(void *)(scm_build),"scm_build","cycles.scm",16},{(void *)(scm_lambda_105),"scm_lambda_105","cycles.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","cycles.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","cycles.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","cycles.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","cycles.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","cycles.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","cycles.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","cycles.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","cycles.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","cycles.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","cycles.scm",1},{(void *)(scm_lambda_117),"scm_lambda_117","cycles.scm",1},{(void *)(scm_lambda_112),"scm_lambda_112","cycles.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","cycles.scm",1},{(void *)(scm_lambda_99),"scm_lambda_99","cycles.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","cycles.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","cycles.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","cycles.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","cycles.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","cycles.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","cycles.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","cycles.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","cycles.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","cycles.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","cycles.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","cycles.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","cycles.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","cycles.scm",1},{(void *)(scm_lambda_118),"scm_lambda_118","cycles.scm",1},{(void *)(scm_lambda_113),"scm_lambda_113","cycles.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","cycles.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","cycles.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","cycles.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","cycles.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","cycles.scm",1},{(void *)(scm_lambda_130),"scm_lambda_130","cycles.scm",1},{(void *)(scm_lambda_129),"scm_lambda_129","cycles.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","cycles.scm",1},{(void *)(scm_lambda_124),"scm_lambda_124","cycles.scm",1},{(void *)(scm_lambda_119),"scm_lambda_119","cycles.scm",1},{(void *)(scm_lambda_114),"scm_lambda_114","cycles.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","cycles.scm",1},{(void *)(scm_lambda_120),"scm_lambda_120","cycles.scm",1},{(void *)(scm_lambda_115),"scm_lambda_115","cycles.scm",1},{(void *)(scm_lambda_128),"scm_lambda_128","cycles.scm",1},{(void *)(scm_lambda_125),"scm_lambda_125","cycles.scm",1},{(void *)(scm_lambda_121),"scm_lambda_121","cycles.scm",1},{(void *)(scm_lambda_126),"scm_lambda_126","cycles.scm",1},{(void *)(scm_lambda_122),"scm_lambda_122","cycles.scm",1},{(void *)(scm_lambda_116),"scm_lambda_116","cycles.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","cycles.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","cycles.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","cycles.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","cycles.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","cycles.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","cycles.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","cycles.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","cycles.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","cycles.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","cycles.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","cycles.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","cycles.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","cycles.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","cycles.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","cycles.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","cycles.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","cycles.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","cycles.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","cycles.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","cycles.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","cycles.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","cycles.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","cycles.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","cycles.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","cycles.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","cycles.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","cycles.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","cycles.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","cycles.scm",1},{NULL}};
+
+#line 2 "cycles.scm"
+static void scm_cyclic_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_cyclic_lambda_5_env *env = (struct scm_cyclic_lambda_5_env *)(&((env_in)->env));call_closure_one((env)->v_k,(env)->v_rv_4);__builtin_unreachable();}
+
+#line 2 "cycles.scm"
+static void scm_cyclic_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_cyclic_lambda_4_env *env = (struct scm_cyclic_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_cyclic_lambda_5_env );(((struct scm_cyclic_lambda_5_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_cyclic_lambda_5_env *)(&((var_1)->env)))->v_rv_4)=((env)->v_rv_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_cyclic_lambda_5,var_1);call_closure_two(var_0,(env)->v_rv_4,var_2);__builtin_unreachable();}
+
+#line 2 "cycles.scm"
+static void scm_cyclic_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_cyclic_lambda_3_env *env = (struct scm_cyclic_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_cyclic_lambda_4_env );(((struct scm_cyclic_lambda_4_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_cyclic_lambda_4_env *)(&((var_1)->env)))->v_rv_4)=((env)->v_rv_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_cyclic_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 2 "cycles.scm"
+static void scm_cyclic_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_cyclic_lambda_2_env *env = (struct scm_cyclic_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,vector_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_cyclic_lambda_3_env );(((struct scm_cyclic_lambda_3_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_cyclic_lambda_3_env *)(&((var_2)->env)))->v_rv_4)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_cyclic_lambda_3,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 2 "cycles.scm"
+static void scm_cyclic_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_cyclic_lambda_1_env *env = (struct scm_cyclic_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_cyclic_lambda_2_env );(((struct scm_cyclic_lambda_2_env *)(&((var_1)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_2,scm_cyclic_lambda_2,var_1);call_closure_two(var_0,(env)->v_x,var_2);__builtin_unreachable();}
+
+#line 2 "cycles.scm"
+static void scm_cyclic(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,make_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_cyclic_lambda_1_env );(((struct scm_cyclic_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_cyclic_lambda_1_env *)(&((var_3)->env)))->v_x)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_cyclic_lambda_1,var_3);call_closure_two(var_2,MAKE_FIXNUM(2),var_4);__builtin_unreachable();}
+static struct closure_obj scm_cyclic_closure = CLOSURE_TWO_OBJ_INIT(scm_cyclic);
+
+#line 1 "cycles.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_a))->val)=(var_0);GC_WRITE_BARRIER((env)->v_a,((struct cell_obj *)((env)->v_a))->val);call_closure_one((env)->v_k_2,NULL);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_11(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_13,var_2);call_closure_two(((struct cell_obj *)((env)->v_cyclic))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_b))->val)=(var_0);GC_WRITE_BARRIER((env)->v_b,((struct cell_obj *)((env)->v_b))->val);call_closure_one((env)->v_k_3,NULL);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(((struct cell_obj *)((env)->v_cyclic))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));call_closure_two((env)->v_rv_9,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_rv_9)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_26,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_22,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_b))->val,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_17(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,equal_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_19,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_a))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_20_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_20);
+
+#line 1 "cycles.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));call_closure_two((env)->v_rv_14,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_rv_14)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_44,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_35,var_1);call_closure_two((env)->v_rv_17,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_31_env *)(&((var_1)->env)))->v_rv_17)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_31,var_1);call_closure_two(((struct cell_obj *)((env)->v_cyclic))->val,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_24(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,equal_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_3)->env)))->v_cyclic)=((env)->v_cyclic);(((struct scm_lambda_27_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_27,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_a))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_32,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_28_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_28);
+
+#line 1 "cycles.scm"
+static void scm_lambda_96(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));call_closure_two((env)->v_rv_20,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_89(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_96_env *)(&((var_2)->env)))->v_rv_20)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_96,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_82(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_89,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_82,var_1);call_closure_two((env)->v_rv_26,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_rv_26)=((env)->v_rv_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_75,var_1);call_closure_two((env)->v_rv_25,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_69_env *)(&((var_1)->env)))->v_rv_26)=((env)->v_rv_26);(((struct scm_lambda_69_env *)(&((var_1)->env)))->v_rv_25)=((env)->v_rv_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_69,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_63_env *)(&((var_2)->env)))->v_rv_26)=((env)->v_rv_26);(((struct scm_lambda_63_env *)(&((var_2)->env)))->v_rv_25)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_63,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_end)),var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_58,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_b))->val,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,equal_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_2)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_54_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_54,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_49,var_1);call_closure_two((env)->v_rv_30,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_rv_30)=((env)->v_rv_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_45,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_rv_30)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_40,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_end)),var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_33(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_36,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_a))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_41,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_37_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_37);
+
+#line 1 "cycles.scm"
+static void scm_lambda_111(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));call_closure_two((env)->v_rv_33,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_111_env *)(&((var_2)->env)))->v_rv_33)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_111,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_97(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_104,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_90(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_90_env *env = (struct scm_lambda_90_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_97,var_1);call_closure_two((env)->v_rv_39,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_90_env );(((struct scm_lambda_90_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_90_env *)(&((var_1)->env)))->v_rv_39)=((env)->v_rv_39);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_90,var_1);call_closure_two((env)->v_rv_38,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_83_env *)(&((var_1)->env)))->v_rv_39)=((env)->v_rv_39);(((struct scm_lambda_83_env *)(&((var_1)->env)))->v_rv_38)=((env)->v_rv_38);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_83,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_rv_39)=((env)->v_rv_39);(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_rv_38)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_76,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_other)),var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_rv_39)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_70,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_b))->val,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,equal_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_64,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_1)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_59_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_59,var_1);call_closure_two((env)->v_rv_43,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_1)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_55_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_55_env *)(&((var_1)->env)))->v_rv_43)=((env)->v_rv_43);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_55,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_rv_43)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_50,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_end)),var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_42(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_46,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_a))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_47(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_51,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_47_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_47);
+
+#line 16 "cycles.scm"
+static void scm_build_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_build_lambda_8_env *env = (struct scm_build_lambda_8_env *)(&((env_in)->env));call_closure_two((env)->v_rv_47,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 16 "cycles.scm"
+static void scm_build_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_build_lambda_7_env *env = (struct scm_build_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_build_lambda_8_env );(((struct scm_build_lambda_8_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_build_lambda_8_env *)(&((var_1)->env)))->v_rv_47)=((env)->v_rv_47);OBJECT_CLOSURE_ONE_NEW(var_2,scm_build_lambda_8,var_1);call_closure_two(var_0,(env)->v_acc,var_2);__builtin_unreachable();}
+
+#line 16 "cycles.scm"
+static void scm_build_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_build_lambda_6_env *env = (struct scm_build_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_build_lambda_7_env );(((struct scm_build_lambda_7_env *)(&((var_2)->env)))->v_acc)=((env)->v_acc);(((struct scm_build_lambda_7_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_build_lambda_7_env *)(&((var_2)->env)))->v_rv_47)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_build_lambda_7,var_2);call_closure_two(var_1,(env)->v_n,var_3);__builtin_unreachable();}
+
+#line 16 "cycles.scm"
+static void scm_build_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_build_lambda_5_env *env = (struct scm_build_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_build_lambda_6_env );(((struct scm_build_lambda_6_env *)(&((var_1)->env)))->v_acc)=((env)->v_acc);(((struct scm_build_lambda_6_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_build_lambda_6_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_build_lambda_6,var_1);struct obj *var_3 = ((struct cell_obj *)((env)->v_build))->val;struct obj *var_4 = var_0;struct obj *var_5 = var_2;if (stack_check()){scm_build(var_4,var_5,((struct closure_obj *)(var_3))->env);} else {}call_closure_two(var_3,var_4,var_5);__builtin_unreachable();}
+
+#line 16 "cycles.scm"
+static void scm_build_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_build_lambda_4_env *env = (struct scm_build_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_build_lambda_5_env );(((struct scm_build_lambda_5_env *)(&((var_1)->env)))->v_acc)=(((struct scm_build_lambda_1_env *)(&(((struct env_obj *)(((struct scm_build_lambda_2_env *)(&(((struct env_obj *)(((struct scm_build_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_acc);(((struct scm_build_lambda_5_env *)(&((var_1)->env)))->v_build)=(((struct scm_build_lambda_1_env *)(&(((struct env_obj *)(((struct scm_build_lambda_2_env *)(&(((struct env_obj *)(((struct scm_build_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_build);(((struct scm_build_lambda_5_env *)(&((var_1)->env)))->v_k_12)=(((struct scm_build_lambda_1_env *)(&(((struct env_obj *)(((struct scm_build_lambda_2_env *)(&(((struct env_obj *)(((struct scm_build_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_12);(((struct scm_build_lambda_5_env *)(&((var_1)->env)))->v_n)=(((struct scm_build_lambda_1_env *)(&(((struct env_obj *)(((struct scm_build_lambda_2_env *)(&(((struct env_obj *)(((struct scm_build_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_build_lambda_5,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 16 "cycles.scm"
+static void scm_build_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_build_lambda_3_env *env = (struct scm_build_lambda_3_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one(((struct scm_build_lambda_1_env *)(&(((struct env_obj *)(((struct scm_build_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_12,((struct scm_build_lambda_1_env *)(&(((struct env_obj *)(((struct scm_build_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc);} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_build_lambda_4_env );(((struct scm_build_lambda_4_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_build_lambda_4,var_2);call_closure_two(var_1,((struct scm_build_lambda_1_env *)(&(((struct env_obj *)(((struct scm_build_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n,var_3);}0;__builtin_unreachable();}
+
+#line 16 "cycles.scm"
+static void scm_build_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_build_lambda_2_env *env = (struct scm_build_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_build_lambda_3_env );(((struct scm_build_lambda_3_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_build_lambda_3,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 16 "cycles.scm"
+static void scm_build_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_build_lambda_1_env *env = (struct scm_build_lambda_1_env *)(&((env_in)->env));((env)->v_acc)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_12)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_build_lambda_2_env );(((struct scm_build_lambda_2_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_build_lambda_2,var_3);call_closure_two(var_2,(env)->v_n,var_4);__builtin_unreachable();}
+
+#line 16 "cycles.scm"
+static void scm_build(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_build_env *env = (struct scm_build_env *)(&((env_in)->env));((env)->v_n)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_build_lambda_1_env );(((struct scm_build_lambda_1_env *)(&((var_2)->env)))->v_build)=((env)->v_build);(((struct scm_build_lambda_1_env *)(&((var_2)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_TWO_NEW(var_3,scm_build_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_105(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_105_env *env = (struct scm_lambda_105_env *)(&((env_in)->env));call_closure_two((env)->v_rv_53,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_105_env );(((struct scm_lambda_105_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_105_env *)(&((var_2)->env)))->v_rv_53)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_105,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_98,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_91,var_1);call_closure_two((env)->v_rv_57,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_84_env *)(&((var_1)->env)))->v_rv_57)=((env)->v_rv_57);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_84,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_77_env *)(&((var_1)->env)))->v_rv_57)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_77,var_1);call_closure_two(((struct cell_obj *)((env)->v_build))->val,MAKE_FIXNUM(2000),var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,equal_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_build)=((env)->v_build);(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_1)->env)))->v_build)=((env)->v_build);(((struct scm_lambda_65_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_65,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_56(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_2)->env)))->v_build)=((env)->v_build);(((struct scm_lambda_60_env *)(&((var_2)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_60,var_2);call_closure_two(((struct cell_obj *)((env)->v_build))->val,MAKE_FIXNUM(2000),var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_61(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_66,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_61_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_61);
+
+#line 1 "cycles.scm"
+static void scm_lambda_117(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_117_env *env = (struct scm_lambda_117_env *)(&((env_in)->env));call_closure_two((env)->v_rv_62,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_112(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_117_env );(((struct scm_lambda_117_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_117_env *)(&((var_2)->env)))->v_rv_62)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_117,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_112,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_99(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_106,var_1);call_closure_two((env)->v_rv_66,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_92(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_99_env *)(&((var_1)->env)))->v_rv_66)=((env)->v_rv_66);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_99,var_1);call_closure_two(var_0,(struct obj *)(&(quoted_9419fc85fe59b773)),var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_92_env *)(&((var_1)->env)))->v_rv_66)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_92,var_1);call_closure_two(((struct cell_obj *)((env)->v_build))->val,MAKE_FIXNUM(2000),var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,equal_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_2)->env)))->v_build)=((env)->v_build);(((struct scm_lambda_85_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_85,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_1)->env)))->v_build)=((env)->v_build);(((struct scm_lambda_78_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_78,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_67(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_build)=((env)->v_build);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(((struct cell_obj *)((env)->v_build))->val,MAKE_FIXNUM(2000),var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_73(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_79,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_73_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_73);
+
+#line 1 "cycles.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_h))->val)=(var_0);GC_WRITE_BARRIER((env)->v_h,((struct cell_obj *)((env)->v_h))->val);call_closure_one((env)->v_k_18,NULL);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_80(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,ht_new_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_86,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));call_closure_two(var_0,(struct obj *)(&(symbol_found)),(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_100,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_a))->val,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_87(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_93,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_123(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_123_env *env = (struct scm_lambda_123_env *)(&((env_in)->env));call_closure_two((env)->v_rv_74,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_118(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_123_env );(((struct scm_lambda_123_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_123_env *)(&((var_2)->env)))->v_rv_74)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_123,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_118,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_107(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_107_env *env = (struct scm_lambda_107_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_113,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_missing)),var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_107_env );(((struct scm_lambda_107_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_107,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_b))->val,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_94(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_ref_default_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_101_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_101,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_102(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_3)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_108,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_102_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_102);
+
+#line 1 "cycles.scm"
+static void scm_lambda_130(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_130_env *env = (struct scm_lambda_130_env *)(&((env_in)->env));call_closure_two((env)->v_rv_80,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_129(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_129_env *env = (struct scm_lambda_129_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_130_env );(((struct scm_lambda_130_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_130_env *)(&((var_2)->env)))->v_rv_80)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_130,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_127(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_129_env );(((struct scm_lambda_129_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_129,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_124(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_124_env *env = (struct scm_lambda_124_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_127,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_missing)),var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_119(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_119_env *env = (struct scm_lambda_119_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_124_env );(((struct scm_lambda_124_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_124,var_1);call_closure_two((env)->v_rv_84,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_114(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_114_env *env = (struct scm_lambda_114_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_119_env );(((struct scm_lambda_119_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_119_env *)(&((var_1)->env)))->v_rv_84)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_119,var_1);call_closure_two(((struct cell_obj *)((env)->v_cyclic))->val,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_109(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_109_env *env = (struct scm_lambda_109_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_ref_default_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_114_env );(((struct scm_lambda_114_env *)(&((var_3)->env)))->v_cyclic)=((env)->v_cyclic);(((struct scm_lambda_114_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_114,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_120(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_120_env *env = (struct scm_lambda_120_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_115(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_120_env );(((struct scm_lambda_120_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_120,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_115_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_115);
+
+#line 1 "cycles.scm"
+static void scm_lambda_128(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_128_env *env = (struct scm_lambda_128_env *)(&((env_in)->env));call_closure_two((env)->v_rv_87,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_125(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_125_env *env = (struct scm_lambda_125_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_128_env );(((struct scm_lambda_128_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_128_env *)(&((var_2)->env)))->v_rv_87)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_128,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_121(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_121_env *env = (struct scm_lambda_121_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_125_env );(((struct scm_lambda_125_env *)(&((var_3)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_125,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_a))->val,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_126(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_126_env *env = (struct scm_lambda_126_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_126_env );(((struct scm_lambda_126_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_126,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_116(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_116_env *env = (struct scm_lambda_116_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_121_env );(((struct scm_lambda_121_env *)(&((var_1)->env)))->v_a)=((env)->v_a);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_121,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_122,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_116_env );(((struct scm_lambda_116_env *)(&((var_1)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_116_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_116,var_1);call_closure_two((struct obj *)(&(scm_lambda_115_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_103(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_109_env );(((struct scm_lambda_109_env *)(&((var_1)->env)))->v_cyclic)=(((struct scm_lambda_88_env *)(&(((struct env_obj *)(((struct scm_lambda_95_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_cyclic);(((struct scm_lambda_109_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_88_env *)(&(((struct env_obj *)(((struct scm_lambda_95_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_109,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_3)->env)))->v_a)=(((struct scm_lambda_88_env *)(&(((struct env_obj *)(((struct scm_lambda_95_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_a);(((struct scm_lambda_110_env *)(&((var_3)->env)))->v_k_25)=(((struct scm_lambda_88_env *)(&(((struct env_obj *)(((struct scm_lambda_95_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_110,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_103,var_1);call_closure_two((struct obj *)(&(scm_lambda_102_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_1)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_94_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_94,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_95,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_1)->env)))->v_a)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_74_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a);(((struct scm_lambda_87_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_74_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_87,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_3)->env)))->v_a)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_74_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a);(((struct scm_lambda_88_env *)(&((var_3)->env)))->v_b)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_74_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b);(((struct scm_lambda_88_env *)(&((var_3)->env)))->v_cyclic)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_74_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cyclic);(((struct scm_lambda_88_env *)(&((var_3)->env)))->v_h)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_74_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_lambda_88_env *)(&((var_3)->env)))->v_k_25)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)(((struct scm_lambda_74_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_88,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_68_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_80,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_81,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_74,var_1);call_closure_two((struct obj *)(&(scm_lambda_73_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_build)=((env)->v_build);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_67,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_68,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_a)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)(((struct scm_lambda_48_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a);(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_b)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)(((struct scm_lambda_48_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b);(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_build)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)(((struct scm_lambda_48_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_build);(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_cyclic)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)(((struct scm_lambda_48_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cyclic);(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)(((struct scm_lambda_48_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_k_25)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)(((struct scm_lambda_48_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_62,var_1);call_closure_two((struct obj *)(&(scm_lambda_61_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_build_env );(((struct scm_build_env *)(&((var_1)->env)))->v_build)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_build);OBJECT_CLOSURE_TWO_NEW(var_2,scm_build,var_1);(((struct cell_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_build))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_build,((struct cell_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_build))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_3)->env)))->v_build)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_build);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_56,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_57,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_48,var_1);call_closure_two((struct obj *)(&(scm_lambda_47_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_b)=((env)->v_b);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_42,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_43,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_a)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_b)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_build)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_build);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_cyclic)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cyclic);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_k_25)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_38,var_1);call_closure_two((struct obj *)(&(scm_lambda_37_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_1)->env)))->v_a)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_a);(((struct scm_lambda_33_env *)(&((var_1)->env)))->v_b)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_b);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_33,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_34,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_29,var_1);call_closure_two((struct obj *)(&(scm_lambda_28_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_cyclic)=((env)->v_cyclic);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_24,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_a)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_a);(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_b)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_b);(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_build)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_build);(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_cyclic)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cyclic);(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_k_25)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two((struct obj *)(&(scm_lambda_20_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_a)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_a);(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_b)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_b);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_17,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_18,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_b)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_b);(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_cyclic)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_cyclic);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_14,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_h)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_25)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v_cyclic))->val)=((struct obj *)(&(scm_cyclic_closure)));GC_WRITE_BARRIER((env)->v_cyclic,((struct cell_obj *)((env)->v_cyclic))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_11_env *)(&((var_3)->env)))->v_cyclic)=((env)->v_cyclic);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_11,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_12,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_build)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_build)=((env)->v_build);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_cyclic)=((env)->v_cyclic);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_b)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_cyclic)=((env)->v_cyclic);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_a)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_cyclic)=((env)->v_cyclic);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_cyclic)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_cyclic)=((env)->v_cyclic);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "cycles.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "cycles.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 745 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
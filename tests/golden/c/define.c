@@ -0,0 +1,90 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_13_env {struct obj *v_k;struct obj *v_rv_2;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_b;struct obj *v_k;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_a;struct obj *v_b;};
+static void scm_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_k_2;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_k_2;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_a;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_k_4;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_4;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_13),"scm_lambda_13","define.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","define.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","define.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","define.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","define.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","define.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","define.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","define.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","define.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","define.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","define.scm",1},{NULL}};
+
+#line 1 "define.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_13,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_12,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_b))->val,var_2);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_9,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_a))->val,var_4);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_10,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);(((struct cell_obj *)((env)->v_a))->val)=(MAKE_FIXNUM(1));GC_WRITE_BARRIER((env)->v_a,((struct cell_obj *)((env)->v_a))->val);(((struct cell_obj *)(var_2))->val)=(MAKE_FIXNUM(2));GC_WRITE_BARRIER(var_2,((struct cell_obj *)(var_2))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_lambda_7_env *)(&((var_3)->env)))->v_b)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_7,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_5)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_8,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_a)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_two(var_4,NULL,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "define.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "define.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 71 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
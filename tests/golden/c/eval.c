@@ -0,0 +1,891 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_8_env {struct obj *v_env;struct obj *v_k;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_env;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_k_2;struct obj *v_rv_3;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_2;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_env;};
+static void scm_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_3;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_12_closure;
+struct scm_lambda_29_env {struct obj *v_k_4;struct obj *v_rv_6;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_4;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_4;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_env;struct obj *v_k_4;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_env;};
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_5;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_19(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_19_closure;
+struct scm_lambda_67_env {struct obj *v_k_6;struct obj *v_rv_11;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_6;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_6;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_env;struct obj *v_k_6;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_env;struct obj *v_k_6;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_env;struct obj *v_k_6;struct obj *v_rv_19;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *parent;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *parent;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_env;struct obj *v_k_6;struct obj *v_rv_19;struct obj *v_rv_18;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_env;struct obj *v_k_6;struct obj *v_rv_19;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_env;struct obj *v_k_6;};
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_7;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_27(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_27_closure;
+struct scm_lambda_35_env {struct obj *v_env;struct obj *v_k_8;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_env;};
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_9;struct obj *v_rv_23;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_9;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_9;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_env;struct obj *v_k_9;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_env;};
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_10;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_40(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_40_closure;
+struct scm_lambda_54_env {struct obj *v_k_11;struct obj *v_sq;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_env;struct obj *v_k_11;struct obj *v_sq;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_env;struct obj *v_sq;};
+static void scm_lambda_45(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_12;struct obj *v_sq;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_sq;};
+static void scm_lambda_50(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_13;struct obj *v_rv_31;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_13;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_13;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_sq;};
+static void scm_lambda_56(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_14;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_60(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_60_closure;
+struct scm_lambda_88_env {struct obj *v_f;struct obj *v_k_15;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_82_env {struct obj *v_f;};
+static void scm_lambda_82(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_76(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_76_closure;
+struct scm_lambda_99_env {struct obj *v_env;struct obj *v_k_17;};
+static void scm_lambda_99(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_94_env {struct obj *v_env;struct obj *v_k_17;};
+static void scm_lambda_94(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v_env;struct obj *v_k_17;struct obj *v_rv_41;};
+static void scm_lambda_89(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *parent;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *parent;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_env;struct obj *v_k_17;struct obj *v_rv_41;struct obj *v_rv_40;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_env;struct obj *v_k_17;struct obj *v_rv_41;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_env;struct obj *v_k_17;};
+static void scm_lambda_65(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_121_env {struct obj *v_k_18;struct obj *v_rv_43;};
+static void scm_lambda_121(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_116_env {struct obj *v_k_18;};
+static void scm_lambda_116(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *v_k_18;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_105_env {struct obj *v_env;struct obj *v_k_18;};
+static void scm_lambda_105(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *v_env;struct obj *v_k_18;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_95_env {struct obj *v_env;struct obj *v_k_18;struct obj *v_rv_51;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_90_env {struct obj *parent;};
+static void scm_lambda_90(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *parent;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *v_env;struct obj *v_k_18;struct obj *v_rv_51;struct obj *v_rv_50;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_env;struct obj *v_k_18;struct obj *v_rv_51;struct obj *v_sq;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_env;struct obj *v_k_18;struct obj *v_sq;};
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_19;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_74(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_74_closure;
+struct scm_lambda_85_env {struct obj *v_env;struct obj *v_k_20;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_env;};
+static void scm_lambda_80(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_91_env {struct obj *v_env;struct obj *v_k_21;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_86_env {struct obj *v_env;};
+static void scm_lambda_86(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_k_22;struct obj *v_rv_56;};
+static void scm_lambda_111(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *v_k_22;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_k_22;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_96_env {struct obj *v_env;struct obj *v_k_22;};
+static void scm_lambda_96(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_92_env {struct obj *v_env;};
+static void scm_lambda_92(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_102_env {struct obj *v_k_23;};
+static void scm_lambda_102(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_97(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_97_closure;
+struct scm_lambda_122_env {struct obj *v_k_24;struct obj *v_rv_61;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_117_env {struct obj *v_k_24;};
+static void scm_lambda_117(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_112_env {struct obj *v_k_24;};
+static void scm_lambda_112(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_107_env {struct obj *v_env;struct obj *v_k_24;};
+static void scm_lambda_107(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_103_env {struct obj *v_env;};
+static void scm_lambda_103(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_113_env {struct obj *v_k_25;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_108(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_108_closure;
+struct scm_lambda_131_env {struct obj *v_k_26;struct obj *v_rv_66;};
+static void scm_lambda_131(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_127_env {struct obj *v_k_26;};
+static void scm_lambda_127(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_123_env {struct obj *v_k_26;};
+static void scm_lambda_123(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_env;struct obj *v_k_26;};
+static void scm_lambda_118(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_114_env {struct obj *v_env;};
+static void scm_lambda_114(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_124_env {struct obj *v_k_27;};
+static void scm_lambda_124(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_119(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_119_closure;
+struct scm_lambda_143_env {struct obj *v_k_28;struct obj *v_rv_71;};
+static void scm_lambda_143(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_140_env {struct obj *v_k_28;};
+static void scm_lambda_140(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_136_env {struct obj *v_k_28;};
+static void scm_lambda_136(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_132_env {struct obj *v_k_28;};
+static void scm_lambda_132(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_128_env {struct obj *v_env;struct obj *v_k_28;};
+static void scm_lambda_128(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_125_env {struct obj *v_env;};
+static void scm_lambda_125(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_133_env {struct obj *v_k_29;};
+static void scm_lambda_133(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_129(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_129_closure;
+struct scm_lambda_137_env {struct obj *v_env;struct obj *v_k_30;};
+static void scm_lambda_137(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_134_env {struct obj *v_env;};
+static void scm_lambda_134(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_146_env {struct obj *v_k_31;struct obj *v_rv_78;};
+static void scm_lambda_146(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_145_env {struct obj *v_k_31;};
+static void scm_lambda_145(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_144_env {struct obj *v_k_31;};
+static void scm_lambda_144(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_141_env {struct obj *v_env;struct obj *v_k_31;};
+static void scm_lambda_141(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_138_env {struct obj *v_env;};
+static void scm_lambda_138(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_142_env {struct obj *v_k_32;};
+static void scm_lambda_142(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_139_env {struct obj *v_k_32;};
+static void scm_lambda_139(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_135_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_135(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_130_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_130(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_126_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_126(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_120_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_120(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_115_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_115(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_109_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_109(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_93_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_87(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_env;struct obj *v_k_32;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_env;struct obj *v_k_32;struct obj *v_sq;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_env;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_env;struct obj *v_k_34;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_34;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol__2a_ = SYMBOL_OBJ_INIT("*");
+static struct symbol_obj symbol__2b_ = SYMBOL_OBJ_INIT("+");
+static struct symbol_obj symbol__2d_ = SYMBOL_OBJ_INIT("-");
+static struct symbol_obj symbol__3d_ = SYMBOL_OBJ_INIT("=");
+static struct symbol_obj symbol_a = SYMBOL_OBJ_INIT("a");
+static struct symbol_obj symbol_cons = SYMBOL_OBJ_INIT("cons");
+static struct symbol_obj symbol_count = SYMBOL_OBJ_INIT("count");
+static struct symbol_obj symbol_define = SYMBOL_OBJ_INIT("define");
+static struct symbol_obj symbol_done = SYMBOL_OBJ_INIT("done");
+static struct symbol_obj symbol_env = SYMBOL_OBJ_INIT("env");
+static struct symbol_obj symbol_ev_3f_ = SYMBOL_OBJ_INIT("ev?");
+static struct symbol_obj symbol_if = SYMBOL_OBJ_INIT("if");
+static struct symbol_obj symbol_lambda = SYMBOL_OBJ_INIT("lambda");
+static struct symbol_obj symbol_let = SYMBOL_OBJ_INIT("let");
+static struct symbol_obj symbol_n = SYMBOL_OBJ_INIT("n");
+static struct symbol_obj symbol_od_3f_ = SYMBOL_OBJ_INIT("od?");
+static struct symbol_obj symbol_quote = SYMBOL_OBJ_INIT("quote");
+static struct symbol_obj symbol_set_21_ = SYMBOL_OBJ_INIT("set!");
+static struct symbol_obj symbol_sq = SYMBOL_OBJ_INIT("sq");
+static struct symbol_obj symbol_square = SYMBOL_OBJ_INIT("square");
+static struct symbol_obj symbol_twice = SYMBOL_OBJ_INIT("twice");
+static struct symbol_obj symbol_x = SYMBOL_OBJ_INIT("x");
+static struct symbol_obj symbol_y = SYMBOL_OBJ_INIT("y");
+static struct symbol_obj *scheme_symbols[] = {&(symbol__2a_),&(symbol__2b_),&(symbol__2d_),&(symbol__3d_),&(symbol_a),&(symbol_cons),&(symbol_count),&(symbol_define),&(symbol_done),&(symbol_env),&(symbol_ev_3f_),&(symbol_if),&(symbol_lambda),&(symbol_let),&(symbol_n),&(symbol_od_3f_),&(symbol_quote),&(symbol_set_21_),&(symbol_sq),&(symbol_square),&(symbol_twice),&(symbol_x),&(symbol_y),NULL};
+static struct cons_obj quoted_d7a797455ea5afe5_2 = CONS_OBJ_INIT(MAKE_FIXNUM(1),NULL);
+static struct cons_obj quoted_d7a797455ea5afe5_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_n)),(struct obj *)(&(quoted_d7a797455ea5afe5_2)));
+static struct cons_obj quoted_d7a797455ea5afe5 = CONS_OBJ_INIT((struct obj *)(&(symbol__2d_)),(struct obj *)(&(quoted_d7a797455ea5afe5_1)));
+static struct cons_obj quoted_0340c8a83cc463a6_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_d7a797455ea5afe5)),NULL);
+static struct cons_obj quoted_0340c8a83cc463a6 = CONS_OBJ_INIT((struct obj *)(&(symbol_od_3f_)),(struct obj *)(&(quoted_0340c8a83cc463a6_1)));
+static struct cons_obj quoted_08a264a4e6e14262_1 = CONS_OBJ_INIT(MAKE_FIXNUM(2),NULL);
+static struct cons_obj quoted_08a264a4e6e14262 = CONS_OBJ_INIT((struct obj *)(&(symbol_y)),(struct obj *)(&(quoted_08a264a4e6e14262_1)));
+static struct cons_obj quoted_08aac8eb28970591_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_done)),NULL);
+static struct cons_obj quoted_08aac8eb28970591 = CONS_OBJ_INIT((struct obj *)(&(symbol_quote)),(struct obj *)(&(quoted_08aac8eb28970591_1)));
+static struct cons_obj quoted_0f4756cebdf9d9ac_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),NULL);
+static struct cons_obj quoted_0f4756cebdf9d9ac = CONS_OBJ_INIT((struct obj *)(&(symbol_square)),(struct obj *)(&(quoted_0f4756cebdf9d9ac_1)));
+static struct cons_obj quoted_11139c354eaff2f6_2 = CONS_OBJ_INIT((struct obj *)(&(symbol_a)),NULL);
+static struct cons_obj quoted_11139c354eaff2f6_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_a)),(struct obj *)(&(quoted_11139c354eaff2f6_2)));
+static struct cons_obj quoted_11139c354eaff2f6 = CONS_OBJ_INIT((struct obj *)(&(symbol__2a_)),(struct obj *)(&(quoted_11139c354eaff2f6_1)));
+static struct cons_obj quoted_3f99f7444b4c1c71_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_d7a797455ea5afe5)),NULL);
+static struct cons_obj quoted_3f99f7444b4c1c71 = CONS_OBJ_INIT((struct obj *)(&(symbol_ev_3f_)),(struct obj *)(&(quoted_3f99f7444b4c1c71_1)));
+static struct cons_obj quoted_e259a48846a99e75_3 = CONS_OBJ_INIT((struct obj *)(&(quoted_3f99f7444b4c1c71)),NULL);
+static struct cons_obj quoted_e259a48846a99e75_2 = CONS_OBJ_INIT((struct obj *)(&(scheme_false)),(struct obj *)(&(quoted_e259a48846a99e75_3)));
+static struct cons_obj quoted_a34a27b008eee66e_2 = CONS_OBJ_INIT(MAKE_FIXNUM(0),NULL);
+static struct cons_obj quoted_a34a27b008eee66e_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_n)),(struct obj *)(&(quoted_a34a27b008eee66e_2)));
+static struct cons_obj quoted_a34a27b008eee66e = CONS_OBJ_INIT((struct obj *)(&(symbol__3d_)),(struct obj *)(&(quoted_a34a27b008eee66e_1)));
+static struct cons_obj quoted_e259a48846a99e75_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_a34a27b008eee66e)),(struct obj *)(&(quoted_e259a48846a99e75_2)));
+static struct cons_obj quoted_e259a48846a99e75 = CONS_OBJ_INIT((struct obj *)(&(symbol_if)),(struct obj *)(&(quoted_e259a48846a99e75_1)));
+static struct cons_obj quoted_1bf778c5e35867e8_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_e259a48846a99e75)),NULL);
+static struct cons_obj quoted_8dd68dd26fdf87fe_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_n)),NULL);
+static struct cons_obj quoted_8dd68dd26fdf87fe = CONS_OBJ_INIT((struct obj *)(&(symbol_od_3f_)),(struct obj *)(&(quoted_8dd68dd26fdf87fe_1)));
+static struct cons_obj quoted_1bf778c5e35867e8_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_8dd68dd26fdf87fe)),(struct obj *)(&(quoted_1bf778c5e35867e8_2)));
+static struct cons_obj quoted_1bf778c5e35867e8 = CONS_OBJ_INIT((struct obj *)(&(symbol_define)),(struct obj *)(&(quoted_1bf778c5e35867e8_1)));
+static struct cons_obj quoted_240031b6cb2108df_1 = CONS_OBJ_INIT(MAKE_FIXNUM(1),NULL);
+static struct cons_obj quoted_240031b6cb2108df = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),(struct obj *)(&(quoted_240031b6cb2108df_1)));
+static struct cons_obj quoted_2e13cdff4bb77a24_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_d7a797455ea5afe5)),NULL);
+static struct cons_obj quoted_2e13cdff4bb77a24 = CONS_OBJ_INIT((struct obj *)(&(symbol_count)),(struct obj *)(&(quoted_2e13cdff4bb77a24_1)));
+static struct cons_obj quoted_26b5e8e793abc115_3 = CONS_OBJ_INIT((struct obj *)(&(quoted_2e13cdff4bb77a24)),NULL);
+static struct cons_obj quoted_26b5e8e793abc115_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_08aac8eb28970591)),(struct obj *)(&(quoted_26b5e8e793abc115_3)));
+static struct cons_obj quoted_26b5e8e793abc115_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_a34a27b008eee66e)),(struct obj *)(&(quoted_26b5e8e793abc115_2)));
+static struct cons_obj quoted_26b5e8e793abc115 = CONS_OBJ_INIT((struct obj *)(&(symbol_if)),(struct obj *)(&(quoted_26b5e8e793abc115_1)));
+static struct cons_obj quoted_c5a57968936d3f4d_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_y)),NULL);
+static struct cons_obj quoted_c5a57968936d3f4d = CONS_OBJ_INIT((struct obj *)(&(symbol_quote)),(struct obj *)(&(quoted_c5a57968936d3f4d_1)));
+static struct cons_obj quoted_286f18db84b9165f_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_c5a57968936d3f4d)),NULL);
+static struct cons_obj quoted_286f18db84b9165f_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),(struct obj *)(&(quoted_286f18db84b9165f_2)));
+static struct cons_obj quoted_286f18db84b9165f = CONS_OBJ_INIT((struct obj *)(&(symbol_cons)),(struct obj *)(&(quoted_286f18db84b9165f_1)));
+static struct cons_obj quoted_29657b640bbdbcea_2 = CONS_OBJ_INIT(MAKE_FIXNUM(4),NULL);
+static struct cons_obj quoted_29657b640bbdbcea_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_a)),(struct obj *)(&(quoted_29657b640bbdbcea_2)));
+static struct cons_obj quoted_29657b640bbdbcea = CONS_OBJ_INIT((struct obj *)(&(symbol_define)),(struct obj *)(&(quoted_29657b640bbdbcea_1)));
+static struct cons_obj quoted_4f170b5450278ace_3 = CONS_OBJ_INIT((struct obj *)(&(quoted_0340c8a83cc463a6)),NULL);
+static struct cons_obj quoted_4f170b5450278ace_2 = CONS_OBJ_INIT((struct obj *)(&(scheme_true)),(struct obj *)(&(quoted_4f170b5450278ace_3)));
+static struct cons_obj quoted_4f170b5450278ace_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_a34a27b008eee66e)),(struct obj *)(&(quoted_4f170b5450278ace_2)));
+static struct cons_obj quoted_4f170b5450278ace = CONS_OBJ_INIT((struct obj *)(&(symbol_if)),(struct obj *)(&(quoted_4f170b5450278ace_1)));
+static struct cons_obj quoted_32562308959ca20f_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_4f170b5450278ace)),NULL);
+static struct cons_obj quoted_4d3dd02b99283f42_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_n)),NULL);
+static struct cons_obj quoted_4d3dd02b99283f42 = CONS_OBJ_INIT((struct obj *)(&(symbol_ev_3f_)),(struct obj *)(&(quoted_4d3dd02b99283f42_1)));
+static struct cons_obj quoted_32562308959ca20f_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_4d3dd02b99283f42)),(struct obj *)(&(quoted_32562308959ca20f_2)));
+static struct cons_obj quoted_32562308959ca20f = CONS_OBJ_INIT((struct obj *)(&(symbol_define)),(struct obj *)(&(quoted_32562308959ca20f_1)));
+static struct cons_obj quoted_35b8a65693140a10_3 = CONS_OBJ_INIT((struct obj *)(&(quoted_11139c354eaff2f6)),NULL);
+static struct cons_obj quoted_35b8a65693140a10_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_29657b640bbdbcea)),(struct obj *)(&(quoted_35b8a65693140a10_3)));
+static struct cons_obj quoted_35b8a65693140a10_1 = CONS_OBJ_INIT(NULL,(struct obj *)(&(quoted_35b8a65693140a10_2)));
+static struct cons_obj quoted_35b8a65693140a10 = CONS_OBJ_INIT((struct obj *)(&(symbol_let)),(struct obj *)(&(quoted_35b8a65693140a10_1)));
+static struct cons_obj quoted_52fc589248dd9702_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_26b5e8e793abc115)),NULL);
+static struct cons_obj quoted_7f11227f7627e6af_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_n)),NULL);
+static struct cons_obj quoted_7f11227f7627e6af = CONS_OBJ_INIT((struct obj *)(&(symbol_count)),(struct obj *)(&(quoted_7f11227f7627e6af_1)));
+static struct cons_obj quoted_52fc589248dd9702_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_7f11227f7627e6af)),(struct obj *)(&(quoted_52fc589248dd9702_2)));
+static struct cons_obj quoted_52fc589248dd9702 = CONS_OBJ_INIT((struct obj *)(&(symbol_define)),(struct obj *)(&(quoted_52fc589248dd9702_1)));
+static struct cons_obj quoted_839908cd108785c9_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_08a264a4e6e14262)),NULL);
+static struct cons_obj quoted_839908cd108785c9 = CONS_OBJ_INIT((struct obj *)(&(quoted_240031b6cb2108df)),(struct obj *)(&(quoted_839908cd108785c9_1)));
+static struct cons_obj quoted_94dd394611737cd5_2 = CONS_OBJ_INIT(MAKE_FIXNUM(2),NULL);
+static struct cons_obj quoted_94dd394611737cd5_1 = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_94dd394611737cd5_2)));
+static struct cons_obj quoted_94dd394611737cd5 = CONS_OBJ_INIT((struct obj *)(&(symbol__2b_)),(struct obj *)(&(quoted_94dd394611737cd5_1)));
+static struct cons_obj quoted_9e34eb28edab619e_3 = CONS_OBJ_INIT((struct obj *)(&(quoted_286f18db84b9165f)),NULL);
+static struct cons_obj quoted_a34deecb9bded5b6_2 = CONS_OBJ_INIT((struct obj *)(&(symbol_y)),NULL);
+static struct cons_obj quoted_a34deecb9bded5b6_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),(struct obj *)(&(quoted_a34deecb9bded5b6_2)));
+static struct cons_obj quoted_a34deecb9bded5b6 = CONS_OBJ_INIT((struct obj *)(&(symbol__2b_)),(struct obj *)(&(quoted_a34deecb9bded5b6_1)));
+static struct cons_obj quoted_ff193ae19faedcde_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_a34deecb9bded5b6)),NULL);
+static struct cons_obj quoted_ff193ae19faedcde_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),(struct obj *)(&(quoted_ff193ae19faedcde_2)));
+static struct cons_obj quoted_ff193ae19faedcde = CONS_OBJ_INIT((struct obj *)(&(symbol_set_21_)),(struct obj *)(&(quoted_ff193ae19faedcde_1)));
+static struct cons_obj quoted_9e34eb28edab619e_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_ff193ae19faedcde)),(struct obj *)(&(quoted_9e34eb28edab619e_3)));
+static struct cons_obj quoted_9e34eb28edab619e_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_839908cd108785c9)),(struct obj *)(&(quoted_9e34eb28edab619e_2)));
+static struct cons_obj quoted_9e34eb28edab619e = CONS_OBJ_INIT((struct obj *)(&(symbol_let)),(struct obj *)(&(quoted_9e34eb28edab619e_1)));
+static struct cons_obj quoted_aa866e71c90cebbd_1 = CONS_OBJ_INIT(MAKE_FIXNUM(100000),NULL);
+static struct cons_obj quoted_aa866e71c90cebbd = CONS_OBJ_INIT((struct obj *)(&(symbol_count)),(struct obj *)(&(quoted_aa866e71c90cebbd_1)));
+static struct cons_obj quoted_c67445240d569f4c_2 = CONS_OBJ_INIT(MAKE_FIXNUM(7),NULL);
+static struct cons_obj quoted_c67445240d569f4c_1 = CONS_OBJ_INIT(NULL,(struct obj *)(&(quoted_c67445240d569f4c_2)));
+static struct cons_obj quoted_c67445240d569f4c = CONS_OBJ_INIT((struct obj *)(&(symbol_lambda)),(struct obj *)(&(quoted_c67445240d569f4c_1)));
+static struct cons_obj quoted_c8e1d2852d102a79_1 = CONS_OBJ_INIT(MAKE_FIXNUM(10),NULL);
+static struct cons_obj quoted_c8e1d2852d102a79 = CONS_OBJ_INIT((struct obj *)(&(symbol_ev_3f_)),(struct obj *)(&(quoted_c8e1d2852d102a79_1)));
+static struct cons_obj quoted_d3a1cc143a365d19_2 = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),NULL);
+static struct cons_obj quoted_d3a1cc143a365d19_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),(struct obj *)(&(quoted_d3a1cc143a365d19_2)));
+static struct cons_obj quoted_d3a1cc143a365d19 = CONS_OBJ_INIT((struct obj *)(&(symbol__2a_)),(struct obj *)(&(quoted_d3a1cc143a365d19_1)));
+static struct cons_obj quoted_d8b3a4a4285f922f_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_d3a1cc143a365d19)),NULL);
+static struct cons_obj quoted_d8b3a4a4285f922f_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_0f4756cebdf9d9ac)),(struct obj *)(&(quoted_d8b3a4a4285f922f_2)));
+static struct cons_obj quoted_d8b3a4a4285f922f = CONS_OBJ_INIT((struct obj *)(&(symbol_define)),(struct obj *)(&(quoted_d8b3a4a4285f922f_1)));
+static struct cons_obj quoted_f480483d9a9d9bef_1 = CONS_OBJ_INIT(MAKE_FIXNUM(5),NULL);
+static struct cons_obj quoted_f480483d9a9d9bef = CONS_OBJ_INIT((struct obj *)(&(symbol_square)),(struct obj *)(&(quoted_f480483d9a9d9bef_1)));
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_8),"scm_lambda_8","eval.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","eval.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","eval.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","eval.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","eval.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","eval.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","eval.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","eval.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","eval.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","eval.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","eval.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","eval.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","eval.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","eval.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","eval.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","eval.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","eval.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","eval.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","eval.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","eval.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","eval.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","eval.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","eval.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","eval.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","eval.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","eval.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","eval.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","eval.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","eval.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","eval.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","eval.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","eval.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","eval.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","eval.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","eval.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","eval.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","eval.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","eval.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","eval.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","eval.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","eval.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","eval.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","eval.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","eval.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","eval.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","eval.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","eval.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","eval.scm",9},{(void *)(scm_lambda_82),"scm_lambda_82","eval.scm",9},{(void *)(scm_lambda_76),"scm_lambda_76","eval.scm",9},{(void *)(scm_lambda_99),"scm_lambda_99","eval.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","eval.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","eval.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","eval.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","eval.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","eval.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","eval.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","eval.scm",1},{(void *)(scm_lambda_121),"scm_lambda_121","eval.scm",1},{(void *)(scm_lambda_116),"scm_lambda_116","eval.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","eval.scm",1},{(void *)(scm_lambda_105),"scm_lambda_105","eval.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","eval.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","eval.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","eval.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","eval.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","eval.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","eval.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","eval.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","eval.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","eval.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","eval.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","eval.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","eval.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","eval.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","eval.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","eval.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","eval.scm",1},{(void *)(scm_lambda_96),"scm_lambda_96","eval.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","eval.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","eval.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","eval.scm",1},{(void *)(scm_lambda_122),"scm_lambda_122","eval.scm",1},{(void *)(scm_lambda_117),"scm_lambda_117","eval.scm",1},{(void *)(scm_lambda_112),"scm_lambda_112","eval.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","eval.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","eval.scm",1},{(void *)(scm_lambda_113),"scm_lambda_113","eval.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","eval.scm",1},{(void *)(scm_lambda_131),"scm_lambda_131","eval.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","eval.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","eval.scm",1},{(void *)(scm_lambda_118),"scm_lambda_118","eval.scm",1},{(void *)(scm_lambda_114),"scm_lambda_114","eval.scm",1},{(void *)(scm_lambda_124),"scm_lambda_124","eval.scm",1},{(void *)(scm_lambda_119),"scm_lambda_119","eval.scm",1},{(void *)(scm_lambda_143),"scm_lambda_143","eval.scm",1},{(void *)(scm_lambda_140),"scm_lambda_140","eval.scm",1},{(void *)(scm_lambda_136),"scm_lambda_136","eval.scm",1},{(void *)(scm_lambda_132),"scm_lambda_132","eval.scm",1},{(void *)(scm_lambda_128),"scm_lambda_128","eval.scm",1},{(void *)(scm_lambda_125),"scm_lambda_125","eval.scm",1},{(void *)(scm_lambda_133),"scm_lambda_133","eval.scm",1},{(void *)(scm_lambda_129),"scm_lambda_129","eval.scm",1},{(void *)(scm_lambda_137),"scm_lambda_137","eval.scm",1},{(void *)(scm_lambda_134),"scm_lambda_134","eval.scm",1},{(void *)(scm_lambda_146),"scm_lambda_146","eval.scm",1},{(void *)(scm_lambda_145),"scm_lambda_145","eval.scm",1},{(void *)(scm_lambda_144),"scm_lambda_144","eval.scm",1},{(void *)(scm_lambda_141),"scm_lambda_141","eval.scm",1},{(void *)(scm_lambda_138),"scm_lambda_138","eval.scm",1},{(void *)(scm_lambda_142),"scm_lambda_142","eval.scm",1},{(void *)(scm_lambda_139),"scm_lambda_139","eval.scm",1},{(void *)(scm_lambda_135),"scm_lambda_135","eval.scm",1},{(void *)(scm_lambda_130),"scm_lambda_130","eval.scm",1},{(void *)(scm_lambda_126),"scm_lambda_126","eval.scm",1},{(void *)(scm_lambda_120),"scm_lambda_120","eval.scm",1},{(void *)(scm_lambda_115),"scm_lambda_115","eval.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","eval.scm",1},{(void *)(scm_lambda_104),"scm_lambda_104","eval.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","eval.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","eval.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","eval.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","eval.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","eval.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","eval.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","eval.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","eval.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","eval.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","eval.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","eval.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","eval.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","eval.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","eval.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","eval.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","eval.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","eval.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","eval.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","eval.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","eval.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","eval.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","eval.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","eval.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","eval.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","eval.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","eval.scm",1},{NULL}};
+
+#line 1 "eval.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_env)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_14,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_11,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_env))->val,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_12_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_12);
+
+#line 1 "eval.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_18,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_94dd394611737cd5)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_19(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_22,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_19_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_19);
+
+#line 1 "eval.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));call_closure_two((env)->v_rv_11,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_rv_11)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_67,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_62,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_58,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_52_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_52,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_47,var_1);call_closure_two((env)->v_rv_19,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_k_6)=(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_6);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_rv_19)=(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_42,var_1);call_closure_two(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_18,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_38,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));((env)->v_rv_18)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));((env)->v_rv_19)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_rv_19)=((env)->v_rv_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(var_1,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));((env)->v_k_6)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_CLOSURE_TWO_NEW(var_3,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_4)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_26_env *)(&((var_4)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_26,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_27(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_27_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_27);
+
+#line 1 "eval.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_35_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_35,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_d8b3a4a4285f922f)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));call_closure_two((env)->v_rv_23,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_53_env *)(&((var_2)->env)))->v_rv_23)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_53,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_48,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_43,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_39,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_f480483d9a9d9bef)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_40(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_40_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_40);
+
+#line 1 "eval.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_sq))->val)=(var_0);GC_WRITE_BARRIER((env)->v_sq,((struct cell_obj *)((env)->v_sq))->val);call_closure_one((env)->v_k_11,NULL);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_54,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_45(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_49_env *)(&((var_3)->env)))->v_k_11)=(var_1);(((struct scm_lambda_49_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_49,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_square)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_sq))->val,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_50(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_3)->env)))->v_k_12)=(var_1);(((struct scm_lambda_55_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_55,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_sq)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));call_closure_two((env)->v_rv_31,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_rv_31)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_63,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_56(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_59,var_2);call_closure_two(((struct cell_obj *)((env)->v_sq))->val,MAKE_FIXNUM(6),var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_60(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_60_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_60);
+
+#line 9 "eval.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));call_closure_two((env)->v_f,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 9 "eval.scm"
+static void scm_lambda_82(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm_lambda_88_env *)(&((var_2)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_88,var_2);call_closure_two((env)->v_f,var_0,var_3);__builtin_unreachable();}
+
+#line 9 "eval.scm"
+static void scm_lambda_76(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_2)->env)))->v_f)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_82,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm_lambda_76_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_76);
+
+#line 1 "eval.scm"
+static void scm_lambda_99(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_94(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_99_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_99,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_89(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_94_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_94,var_1);call_closure_two((env)->v_rv_41,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_89_env *)(&((var_1)->env)))->v_k_17)=(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_17);(((struct scm_lambda_89_env *)(&((var_1)->env)))->v_rv_41)=(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_41);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_89,var_1);call_closure_two(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_40,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_83,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));((env)->v_rv_40)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_77,var_2);call_closure_two(var_1,(struct obj *)(&(scm_lambda_76_closure)),var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));((env)->v_rv_41)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_rv_41)=((env)->v_rv_41);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_twice)),var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_65(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));((env)->v_k_17)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_69,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_define)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_121(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_121_env *env = (struct scm_lambda_121_env *)(&((env_in)->env));call_closure_two((env)->v_rv_43,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_116(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_116_env *env = (struct scm_lambda_116_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_121_env );(((struct scm_lambda_121_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_121_env *)(&((var_2)->env)))->v_rv_43)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_121,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_116_env );(((struct scm_lambda_116_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_116,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_105(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_105_env *env = (struct scm_lambda_105_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_110,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_105_env );(((struct scm_lambda_105_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_105_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_105,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_100_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_100,var_1);call_closure_two((env)->v_rv_51,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_90(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_90_env *env = (struct scm_lambda_90_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_84_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_k_18)=(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_84_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_18);(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_rv_51)=(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_84_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_51);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_95,var_1);call_closure_two(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_84_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_50,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_90_env );(((struct scm_lambda_90_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_90,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));((env)->v_rv_50)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_84,var_2);call_closure_two(var_1,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));((env)->v_rv_51)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_rv_51)=((env)->v_rv_51);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_78,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_sq))->val,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));((env)->v_k_18)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_73_env *)(&((var_3)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_73_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_73,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_twice)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_74(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_79,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_74_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_74);
+
+#line 1 "eval.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_80(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_85_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_85,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_32562308959ca20f)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_86(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_91_env *)(&((var_3)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_91,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_1bf778c5e35867e8)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_111(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));call_closure_two((env)->v_rv_56,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_111_env *)(&((var_2)->env)))->v_rv_56)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_111,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_106,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_96(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_101,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_92(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_96_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_96,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_c8e1d2852d102a79)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_102(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_97(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_102,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_97_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_97);
+
+#line 1 "eval.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));call_closure_two((env)->v_rv_61,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_117(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_117_env *env = (struct scm_lambda_117_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_122_env *)(&((var_2)->env)))->v_rv_61)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_122,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_112(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_117_env );(((struct scm_lambda_117_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_117,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_107(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_107_env *env = (struct scm_lambda_107_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_112,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_103(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_107_env );(((struct scm_lambda_107_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_107_env *)(&((var_3)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_107,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_9e34eb28edab619e)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_108(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_3)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_113,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_108_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_108);
+
+#line 1 "eval.scm"
+static void scm_lambda_131(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_131_env *env = (struct scm_lambda_131_env *)(&((env_in)->env));call_closure_two((env)->v_rv_66,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_127(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_131_env );(((struct scm_lambda_131_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_131_env *)(&((var_2)->env)))->v_rv_66)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_131,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_123(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_123_env *env = (struct scm_lambda_123_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_127,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_118(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_123_env );(((struct scm_lambda_123_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_123,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_114(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_114_env *env = (struct scm_lambda_114_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_118_env *)(&((var_3)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_118,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_35b8a65693140a10)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_124(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_124_env *env = (struct scm_lambda_124_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_119(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_124_env );(((struct scm_lambda_124_env *)(&((var_3)->env)))->v_k_27)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_124,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_119_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_119);
+
+#line 1 "eval.scm"
+static void scm_lambda_143(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_143_env *env = (struct scm_lambda_143_env *)(&((env_in)->env));call_closure_two((env)->v_rv_71,var_0,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_140(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_140_env *env = (struct scm_lambda_140_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_143_env );(((struct scm_lambda_143_env *)(&((var_2)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_lambda_143_env *)(&((var_2)->env)))->v_rv_71)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_143,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_136(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_136_env *env = (struct scm_lambda_136_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_140_env );(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_140,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_132(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_132_env *env = (struct scm_lambda_132_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_136_env );(((struct scm_lambda_136_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_136,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_128(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_128_env *env = (struct scm_lambda_128_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_132_env );(((struct scm_lambda_132_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_132,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_125(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_125_env *env = (struct scm_lambda_125_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_128_env );(((struct scm_lambda_128_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_128_env *)(&((var_3)->env)))->v_k_28)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_128,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_c67445240d569f4c)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_133(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_133_env *env = (struct scm_lambda_133_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_29);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_129(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_133_env );(((struct scm_lambda_133_env *)(&((var_3)->env)))->v_k_29)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_133,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_129_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_129);
+
+#line 1 "eval.scm"
+static void scm_lambda_137(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_137_env *env = (struct scm_lambda_137_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_134(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_134_env *env = (struct scm_lambda_134_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_137_env );(((struct scm_lambda_137_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_137_env *)(&((var_3)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_137,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_52fc589248dd9702)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_146(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_146_env *env = (struct scm_lambda_146_env *)(&((env_in)->env));call_closure_two((env)->v_rv_78,var_0,(env)->v_k_31);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_145(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_145_env *env = (struct scm_lambda_145_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_146_env );(((struct scm_lambda_146_env *)(&((var_2)->env)))->v_k_31)=((env)->v_k_31);(((struct scm_lambda_146_env *)(&((var_2)->env)))->v_rv_78)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_146,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_144(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_144_env *env = (struct scm_lambda_144_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_145_env );(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_k_31)=((env)->v_k_31);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_145,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_141(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_141_env *env = (struct scm_lambda_141_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_144_env );(((struct scm_lambda_144_env *)(&((var_1)->env)))->v_k_31)=((env)->v_k_31);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_144,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_138(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_138_env *env = (struct scm_lambda_138_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_141_env );(((struct scm_lambda_141_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_141_env *)(&((var_3)->env)))->v_k_31)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_141,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_aa866e71c90cebbd)),var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_142(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_142_env *env = (struct scm_lambda_142_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_32);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_139(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_139_env *env = (struct scm_lambda_139_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_142_env );(((struct scm_lambda_142_env *)(&((var_2)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_142,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_135(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_135_env *env = (struct scm_lambda_135_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_138_env );(((struct scm_lambda_138_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_138,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_139_env );(((struct scm_lambda_139_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_139,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_130(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_130_env *env = (struct scm_lambda_130_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_134_env );(((struct scm_lambda_134_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_134,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_135_env );(((struct scm_lambda_135_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_135_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_135,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_126(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_126_env *env = (struct scm_lambda_126_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_130_env );(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_130,var_1);call_closure_two((struct obj *)(&(scm_lambda_129_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_120(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_120_env *env = (struct scm_lambda_120_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_125_env );(((struct scm_lambda_125_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_125,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_126_env );(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_126,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_115(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_115_env *env = (struct scm_lambda_115_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_120_env );(((struct scm_lambda_120_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_120_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_120,var_1);call_closure_two((struct obj *)(&(scm_lambda_119_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_109(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_109_env *env = (struct scm_lambda_109_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_114_env );(((struct scm_lambda_114_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_114,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_115_env );(((struct scm_lambda_115_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_115_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_115,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_109_env );(((struct scm_lambda_109_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_109_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_109,var_1);call_closure_two((struct obj *)(&(scm_lambda_108_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_103,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_104_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_104,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_98_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_98,var_1);call_closure_two((struct obj *)(&(scm_lambda_97_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_87(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_92,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_93,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_86,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_87_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_87,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_80,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_81_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_81,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_75,var_1);call_closure_two((struct obj *)(&(scm_lambda_74_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_70_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_70,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_71_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_71,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_65,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_66,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_61,var_1);call_closure_two((struct obj *)(&(scm_lambda_60_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_56,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_57_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_57_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_57,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_50,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_51,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_45,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_46,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_41,var_1);call_closure_two((struct obj *)(&(scm_lambda_40_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_36,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_37,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_32,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two((struct obj *)(&(scm_lambda_27_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_23,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_24,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_20,var_1);call_closure_two((struct obj *)(&(scm_lambda_19_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_16,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two((struct obj *)(&(scm_lambda_12_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_9,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_10,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_env))->val)=(var_0);GC_WRITE_BARRIER((env)->v_env,((struct cell_obj *)((env)->v_env))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_6,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_7_env *)(&((var_3)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_7_env *)(&((var_3)->env)))->v_sq)=((env)->v_sq);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_7,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_CLOSURE_TWO_NEW(var_3,interaction_environment_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_4)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_5_env *)(&((var_4)->env)))->v_k_32)=(var_1);(((struct scm_lambda_5_env *)(&((var_4)->env)))->v_sq)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_5,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_env)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_two(var_4,NULL,(env)->v_k_34);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_34);__builtin_unreachable();}
+
+#line 1 "eval.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_34)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_34)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "eval.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 872 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
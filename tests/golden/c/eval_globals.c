@@ -0,0 +1,576 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_13_env {struct obj *v_k;struct obj *v_x_2;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_x_2;};
+static void scm_lambda_11(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_env;struct obj *v_k_2;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_env;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_env;struct obj *v_k_3;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_env;};
+static void scm_lambda_17(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_4;struct obj *v_rv_5;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_4;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_4;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_env;struct obj *v_k_4;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_env;};
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_5;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_23_closure;
+struct scm_square_lambda_1_env {struct obj *v_k_6;struct obj *v_n;};
+static void scm_square_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_square(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_square_closure;
+struct scm_lambda_33_env {struct obj *v_k_7;struct obj *v_square;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_square;};
+static void scm_lambda_30(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_8;struct obj *v_rv_12;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_8;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_8;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_env;struct obj *v_k_8;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_env;};
+static void scm_lambda_34(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_9;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_37_closure;
+struct scm_lambda_44_env {struct obj *v_k_10;struct obj *v_x_2;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_x_2;};
+static void scm_lambda_41(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_11;struct obj *v_rv_18;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_11;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_11;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_env;struct obj *v_k_11;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_env;};
+static void scm_lambda_45(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_12;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_49(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_49_closure;
+struct scm_bump_x_lambda_3_env {struct obj *v_k_13;struct obj *v_x_2;};
+static void scm_bump_x_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_x_lambda_2_env {struct obj *v_k_13;struct obj *v_x_2;};
+static void scm_bump_x_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_x_lambda_1_env {struct obj *v_k_13;struct obj *v_x_2;};
+static void scm_bump_x_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_x_env {struct obj *v_x_2;};
+static void scm_bump_x(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_bump_x;struct obj *v_k_14;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_bump_x;};
+static void scm_lambda_56(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_15;struct obj *v_rv_27;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_15;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_15;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_env;struct obj *v_k_15;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_env;};
+static void scm_lambda_62(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_16;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_65(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_65_closure;
+static void scm_shadowed(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_shadowed_closure;
+struct scm_lambda_75_env {struct obj *v_k_18;struct obj *v_shadowed;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_shadowed;};
+static void scm_lambda_72(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v_k_19;struct obj *v_rv_33;};
+static void scm_lambda_89(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_85_env {struct obj *v_k_19;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_k_19;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *v_env;struct obj *v_k_19;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_76_env {struct obj *v_env;};
+static void scm_lambda_76(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_82_env {struct obj *v_k_20;};
+static void scm_lambda_82(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_79(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_79_closure;
+struct scm_lambda_98_env {struct obj *v_k_21;struct obj *v_rv_38;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_94_env {struct obj *v_k_21;};
+static void scm_lambda_94(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_90_env {struct obj *v_k_21;};
+static void scm_lambda_90(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_86_env {struct obj *v_env;struct obj *v_k_21;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *v_env;};
+static void scm_lambda_83(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_91_env {struct obj *v_k_22;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_87(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_87_closure;
+struct scm_lambda_95_env {struct obj *v_env;struct obj *v_k_23;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_92_env {struct obj *v_env;};
+static void scm_lambda_92(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_103_env {struct obj *v_k_24;struct obj *v_rv_44;};
+static void scm_lambda_103(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_102_env {struct obj *v_k_24;};
+static void scm_lambda_102(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_k_24;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_99_env {struct obj *v_env;struct obj *v_k_24;};
+static void scm_lambda_99(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_96_env {struct obj *v_env;};
+static void scm_lambda_96(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *v_k_25;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *v_k_25;};
+static void scm_lambda_97(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_93_env {struct obj *v_env;struct obj *v_k_25;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_env;struct obj *v_k_25;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_env;struct obj *v_k_25;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_env;struct obj *v_k_25;};
+static void scm_lambda_80(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_env;struct obj *v_k_25;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_env;struct obj *v_k_25;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_env;struct obj *v_k_25;struct obj *v_shadowed;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_env;struct obj *v_k_25;struct obj *v_shadowed;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_env;struct obj *v_k_25;struct obj *v_shadowed;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_bump_x;struct obj *v_env;struct obj *v_k_25;struct obj *v_shadowed;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *parent;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *parent;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *parent;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_bump_x;struct obj *v_env;struct obj *v_k_25;struct obj *v_shadowed;struct obj *v_x_2;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *parent;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *parent;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *parent;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_bump_x;struct obj *v_env;struct obj *v_k_25;struct obj *v_shadowed;struct obj *v_square;struct obj *v_x_2;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *parent;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *parent;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *parent;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_bump_x;struct obj *v_env;struct obj *v_k_25;struct obj *v_shadowed;struct obj *v_square;struct obj *v_x_2;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_bump_x;struct obj *v_env;struct obj *v_square;struct obj *v_x_2;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_env;struct obj *v_square;struct obj *v_x_2;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_env;struct obj *v_x_2;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_x_2;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_30;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_30;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_30;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_30;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol__2a_ = SYMBOL_OBJ_INIT("*");
+static struct symbol_obj symbol_bump_21_ = SYMBOL_OBJ_INIT("bump!");
+static struct symbol_obj symbol_define = SYMBOL_OBJ_INIT("define");
+static struct symbol_obj symbol_env = SYMBOL_OBJ_INIT("env");
+static struct symbol_obj symbol_shadowed = SYMBOL_OBJ_INIT("shadowed");
+static struct symbol_obj symbol_square = SYMBOL_OBJ_INIT("square");
+static struct symbol_obj symbol_x = SYMBOL_OBJ_INIT("x");
+static struct symbol_obj symbol_y = SYMBOL_OBJ_INIT("y");
+static struct symbol_obj *scheme_symbols[] = {&(symbol__2a_),&(symbol_bump_21_),&(symbol_define),&(symbol_env),&(symbol_shadowed),&(symbol_square),&(symbol_x),&(symbol_y),NULL};
+static struct cons_obj quoted_0f4756cebdf9d9ac_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),NULL);
+static struct cons_obj quoted_0f4756cebdf9d9ac = CONS_OBJ_INIT((struct obj *)(&(symbol_square)),(struct obj *)(&(quoted_0f4756cebdf9d9ac_1)));
+static struct cons_obj quoted_957e582b3bfbb3ec_2 = CONS_OBJ_INIT(MAKE_FIXNUM(2),NULL);
+static struct cons_obj quoted_957e582b3bfbb3ec_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_x)),(struct obj *)(&(quoted_957e582b3bfbb3ec_2)));
+static struct cons_obj quoted_957e582b3bfbb3ec = CONS_OBJ_INIT((struct obj *)(&(symbol__2a_)),(struct obj *)(&(quoted_957e582b3bfbb3ec_1)));
+static struct cons_obj quoted_0feddb7f8455a3c4_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_957e582b3bfbb3ec)),NULL);
+static struct cons_obj quoted_0feddb7f8455a3c4_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_y)),(struct obj *)(&(quoted_0feddb7f8455a3c4_2)));
+static struct cons_obj quoted_0feddb7f8455a3c4 = CONS_OBJ_INIT((struct obj *)(&(symbol_define)),(struct obj *)(&(quoted_0feddb7f8455a3c4_1)));
+static struct cons_obj quoted_17968510c1b44d87_1 = CONS_OBJ_INIT(MAKE_FIXNUM(1),NULL);
+static struct cons_obj quoted_17968510c1b44d87 = CONS_OBJ_INIT((struct obj *)(&(symbol_shadowed)),(struct obj *)(&(quoted_17968510c1b44d87_1)));
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_13),"scm_lambda_13","eval_globals.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","eval_globals.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","eval_globals.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","eval_globals.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","eval_globals.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","eval_globals.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","eval_globals.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","eval_globals.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","eval_globals.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","eval_globals.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","eval_globals.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","eval_globals.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","eval_globals.scm",1},{(void *)(scm_square_lambda_1),"scm_square_lambda_1","eval_globals.scm",4},{(void *)(scm_square),"scm_square","eval_globals.scm",4},{(void *)(scm_lambda_33),"scm_lambda_33","eval_globals.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","eval_globals.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","eval_globals.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","eval_globals.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","eval_globals.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","eval_globals.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","eval_globals.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","eval_globals.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","eval_globals.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","eval_globals.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","eval_globals.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","eval_globals.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","eval_globals.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","eval_globals.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","eval_globals.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","eval_globals.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","eval_globals.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","eval_globals.scm",1},{(void *)(scm_bump_x_lambda_3),"scm_bump_x_lambda_3","eval_globals.scm",8},{(void *)(scm_bump_x_lambda_2),"scm_bump_x_lambda_2","eval_globals.scm",8},{(void *)(scm_bump_x_lambda_1),"scm_bump_x_lambda_1","eval_globals.scm",8},{(void *)(scm_bump_x),"scm_bump_x","eval_globals.scm",8},{(void *)(scm_lambda_59),"scm_lambda_59","eval_globals.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","eval_globals.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","eval_globals.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","eval_globals.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","eval_globals.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","eval_globals.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","eval_globals.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","eval_globals.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","eval_globals.scm",1},{(void *)(scm_shadowed),"scm_shadowed","eval_globals.scm",11},{(void *)(scm_lambda_75),"scm_lambda_75","eval_globals.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","eval_globals.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","eval_globals.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","eval_globals.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","eval_globals.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","eval_globals.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","eval_globals.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","eval_globals.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","eval_globals.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","eval_globals.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","eval_globals.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","eval_globals.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","eval_globals.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","eval_globals.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","eval_globals.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","eval_globals.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","eval_globals.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","eval_globals.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","eval_globals.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","eval_globals.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","eval_globals.scm",1},{(void *)(scm_lambda_99),"scm_lambda_99","eval_globals.scm",1},{(void *)(scm_lambda_96),"scm_lambda_96","eval_globals.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","eval_globals.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","eval_globals.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","eval_globals.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","eval_globals.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","eval_globals.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","eval_globals.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","eval_globals.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","eval_globals.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","eval_globals.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","eval_globals.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","eval_globals.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","eval_globals.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","eval_globals.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","eval_globals.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","eval_globals.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","eval_globals.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","eval_globals.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","eval_globals.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","eval_globals.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","eval_globals.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","eval_globals.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","eval_globals.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","eval_globals.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","eval_globals.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","eval_globals.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","eval_globals.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","eval_globals.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","eval_globals.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","eval_globals.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","eval_globals.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","eval_globals.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","eval_globals.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","eval_globals.scm",1},{NULL}};
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_x_2))->val,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_11(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_13,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_x)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_env))->val)=(var_0);GC_WRITE_BARRIER((env)->v_env,((struct cell_obj *)((env)->v_env))->val);call_closure_one((env)->v_k_2,NULL);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_environment_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_16,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_17(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_19,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_env)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));call_closure_two((env)->v_rv_5,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_rv_5)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_25,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_22_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_22,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_x)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_26,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_23_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_23);
+
+#line 4 "eval_globals.scm"
+static void scm_square_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_square_lambda_1_env *env = (struct scm_square_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_n,(env)->v_k_6);__builtin_unreachable();}
+
+#line 4 "eval_globals.scm"
+static void scm_square(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_square_lambda_1_env );(((struct scm_square_lambda_1_env *)(&((var_3)->env)))->v_k_6)=(var_1);(((struct scm_square_lambda_1_env *)(&((var_3)->env)))->v_n)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_square_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_square_closure = CLOSURE_TWO_OBJ_INIT(scm_square);
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_square))->val,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_30(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_7)=(var_1);(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_square)=((env)->v_square);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_square)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));call_closure_two((env)->v_rv_12,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_rv_12)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_47,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_43,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_39,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_34(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_36,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_0f4756cebdf9d9ac)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_40,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_37_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_37);
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_x_2))->val,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_41(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_x_2))->val)=(MAKE_FIXNUM(11));GC_WRITE_BARRIER((env)->v_x_2,((struct cell_obj *)((env)->v_x_2))->val);OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_10)=(var_1);(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_x)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));call_closure_two((env)->v_rv_18,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_rv_18)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_58,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_55,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_51,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_45(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_48_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_48,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_x)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_49(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_52,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_49_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_49);
+
+#line 8 "eval_globals.scm"
+static void scm_bump_x_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_bump_x_lambda_3_env *env = (struct scm_bump_x_lambda_3_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_x_2))->val,(env)->v_k_13);__builtin_unreachable();}
+
+#line 8 "eval_globals.scm"
+static void scm_bump_x_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_bump_x_lambda_2_env *env = (struct scm_bump_x_lambda_2_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_x_2))->val)=(var_0);GC_WRITE_BARRIER((env)->v_x_2,((struct cell_obj *)((env)->v_x_2))->val);OBJECT_CLOSURE_TWO_NEW(var_1,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_bump_x_lambda_3_env );(((struct scm_bump_x_lambda_3_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_bump_x_lambda_3_env *)(&((var_2)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_bump_x_lambda_3,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_x)),var_3);__builtin_unreachable();}
+
+#line 8 "eval_globals.scm"
+static void scm_bump_x_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_bump_x_lambda_1_env *env = (struct scm_bump_x_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_bump_x_lambda_2_env );(((struct scm_bump_x_lambda_2_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_bump_x_lambda_2_env *)(&((var_1)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_bump_x_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 8 "eval_globals.scm"
+static void scm_bump_x(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_bump_x_env *env = (struct scm_bump_x_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_bump_x_lambda_1_env );(((struct scm_bump_x_lambda_1_env *)(&((var_3)->env)))->v_k_13)=(var_1);(((struct scm_bump_x_lambda_1_env *)(&((var_3)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_bump_x_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_x_2))->val,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_bump_x))->val,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_56(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_3)->env)))->v_bump_x)=((env)->v_bump_x);(((struct scm_lambda_59_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_59,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_bump_21_)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));call_closure_two((env)->v_rv_27,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_74,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_67,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_62(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_x)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_65(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_68,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_65_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_65);
+
+#line 11 "eval_globals.scm"
+static void scm_shadowed(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){(var_0)=(MAKE_FIXNUM(5));call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm_shadowed_closure = CLOSURE_TWO_OBJ_INIT(scm_shadowed);
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_shadowed))->val,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_72(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_3)->env)))->v_k_18)=(var_1);(((struct scm_lambda_75_env *)(&((var_3)->env)))->v_shadowed)=((env)->v_shadowed);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_75,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_shadowed)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_89(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));call_closure_two((env)->v_rv_33,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_rv_33)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_89,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_85,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_81,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_76(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_78_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_78,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_17968510c1b44d87)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_82(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_79(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_82,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_79_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_79);
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));call_closure_two((env)->v_rv_38,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_94(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_98_env *)(&((var_2)->env)))->v_rv_38)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_98,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_90(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_90_env *env = (struct scm_lambda_90_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_94,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_90_env );(((struct scm_lambda_90_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_90,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_83(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_86,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_x)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_87(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_91,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_87_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_87);
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_92(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_95_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_95,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_0feddb7f8455a3c4)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_103(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));call_closure_two((env)->v_rv_44,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_102(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_103_env *)(&((var_2)->env)))->v_rv_44)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_103,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_102,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_99(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_101,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_96(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_99_env *)(&((var_3)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_99,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_y)),var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_97(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_100,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_96,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_97,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_92,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_93,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_88,var_1);call_closure_two((struct obj *)(&(scm_lambda_87_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_80(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_83,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_84_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_84,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_80_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_80,var_1);call_closure_two((struct obj *)(&(scm_lambda_79_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_76,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_77_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_77,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_shadowed))->val)=((struct obj *)(&(scm_shadowed_closure)));GC_WRITE_BARRIER((env)->v_shadowed,((struct cell_obj *)((env)->v_shadowed))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_1)->env)))->v_shadowed)=((env)->v_shadowed);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_72,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_73_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_73,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_shadowed)=((env)->v_shadowed);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_66,var_1);call_closure_two((struct obj *)(&(scm_lambda_65_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_env)=((env)->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_62,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_shadowed)=((env)->v_shadowed);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_63,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_shadowed)=((env)->v_shadowed);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_61,var_1);call_closure_two(((struct cell_obj *)((env)->v_bump_x))->val,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_bump_x_env );(((struct scm_bump_x_env *)(&((var_1)->env)))->v_x_2)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_x_2);OBJECT_CLOSURE_TWO_NEW(var_2,scm_bump_x,var_1);(((struct cell_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_bump_x))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_bump_x,((struct cell_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_bump_x))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_3)->env)))->v_bump_x)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_bump_x);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_56,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_5)->env)))->v_bump_x)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_bump_x);(((struct scm_lambda_57_env *)(&((var_5)->env)))->v_env)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_57_env *)(&((var_5)->env)))->v_k_25)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);(((struct scm_lambda_57_env *)(&((var_5)->env)))->v_shadowed)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_shadowed);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_57,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_50,var_1);call_closure_two((struct obj *)(&(scm_lambda_49_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_45,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_46,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_41,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_42,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_bump_x)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_bump_x);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_k_25)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_shadowed)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_shadowed);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_x_2)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_x_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_38,var_1);call_closure_two((struct obj *)(&(scm_lambda_37_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_34,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_35,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_square))->val)=((struct obj *)(&(scm_square_closure)));GC_WRITE_BARRIER(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_square,((struct cell_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_square))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_square)=(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_square);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_30,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_24,var_1);call_closure_two((struct obj *)(&(scm_lambda_23_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_20,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_bump_x)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_bump_x);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_env)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_25)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_shadowed)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_shadowed);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_square)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_square);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_x_2)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_x_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_17,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_18,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_14,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_shadowed)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_25)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v_x_2))->val)=(MAKE_FIXNUM(10));GC_WRITE_BARRIER((env)->v_x_2,((struct cell_obj *)((env)->v_x_2))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_3)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_11,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_12,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_bump_x)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_bump_x)=((env)->v_bump_x);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_square)=((env)->v_square);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_square)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_square)=((env)->v_square);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_env)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_x_2)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "eval_globals.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "eval_globals.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 557 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,325 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+static void scm_safe_div_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_safe_div_lambda_2_closure;
+struct scm_safe_div_lambda_10_env {struct obj *v_k_2;struct obj *v_rv_3;};
+static void scm_safe_div_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_safe_div_lambda_9_env {struct obj *v_k_2;struct obj *v_rv_3;};
+static void scm_safe_div_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_safe_div_lambda_7_env {struct obj *v_a;struct obj *v_k_2;};
+static void scm_safe_div_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_safe_div_lambda_8_env {struct obj *v_b;struct obj *v_k_2;};
+static void scm_safe_div_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_safe_div_lambda_6_env {struct obj *v_a;struct obj *v_b;struct obj *v_k_2;};
+static void scm_safe_div_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_safe_div_lambda_5_env {struct obj *v_a;struct obj *v_b;struct obj *v_k_2;};
+static void scm_safe_div_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_safe_div_lambda_4_env {struct obj *v_a;struct obj *v_b;};
+static void scm_safe_div_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_safe_div_lambda_3_env {struct obj *v_a;struct obj *v_b;struct obj *v_k_3;};
+static void scm_safe_div_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_safe_div_lambda_1_env {struct obj *v_a;};
+static void scm_safe_div_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_safe_div(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_safe_div_closure;
+struct scm_lambda_16_env {struct obj *v_k_5;struct obj *v_rv_9;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k_5;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_k_5;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_5;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_safe_div;};
+static void scm_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_6;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_6_closure;
+struct scm_lambda_26_env {struct obj *v_k_7;struct obj *v_rv_14;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_7;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_7;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_7;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_safe_div;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_k_8;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_14_closure;
+struct scm_lambda_27_env {struct obj *v_k_9;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_22_closure;
+struct scm_lambda_38_env {struct obj *v_k_10;struct obj *v_rv_20;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_10;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_28_closure;
+struct scm_lambda_39_env {struct obj *v_k_11;struct obj *v_rv_22;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_k_11;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_11;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_11;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_19(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_19_closure;
+struct scm_lambda_30_env {struct obj *v_k_12;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_24(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_24_closure;
+static void scm_lambda_35(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_35_closure;
+struct scm_lambda_42_env {struct obj *v_k_14;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_40(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_40_closure;
+struct scm_lambda_44_env {struct obj *v_k_15;struct obj *v_rv_28;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_15;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_15;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_15;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_31_closure;
+struct scm_lambda_37_env {struct obj *v_k_16;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_16;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_16;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_16;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_16;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_16;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_16;struct obj *v_safe_div;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_16;struct obj *v_safe_div;};
+static void scm_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_two = SYMBOL_OBJ_INIT("two");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_two),NULL};
+static struct cons_obj quoted_425cae510dd2bc6f_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_two)),NULL);
+static struct cons_obj quoted_425cae510dd2bc6f = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_425cae510dd2bc6f_1)));
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_safe_div_lambda_2),"scm_safe_div_lambda_2","exceptions.scm",4},{(void *)(scm_safe_div_lambda_10),"scm_safe_div_lambda_10","exceptions.scm",5},{(void *)(scm_safe_div_lambda_9),"scm_safe_div_lambda_9","exceptions.scm",5},{(void *)(scm_safe_div_lambda_7),"scm_safe_div_lambda_7","exceptions.scm",5},{(void *)(scm_safe_div_lambda_8),"scm_safe_div_lambda_8","exceptions.scm",5},{(void *)(scm_safe_div_lambda_6),"scm_safe_div_lambda_6","exceptions.scm",5},{(void *)(scm_safe_div_lambda_5),"scm_safe_div_lambda_5","exceptions.scm",5},{(void *)(scm_safe_div_lambda_4),"scm_safe_div_lambda_4","exceptions.scm",5},{(void *)(scm_safe_div_lambda_3),"scm_safe_div_lambda_3","exceptions.scm",2},{(void *)(scm_safe_div_lambda_1),"scm_safe_div_lambda_1","exceptions.scm",2},{(void *)(scm_safe_div),"scm_safe_div","exceptions.scm",2},{(void *)(scm_lambda_16),"scm_lambda_16","exceptions.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","exceptions.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","exceptions.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","exceptions.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","exceptions.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","exceptions.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","exceptions.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","exceptions.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","exceptions.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","exceptions.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","exceptions.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","exceptions.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","exceptions.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","exceptions.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","exceptions.scm",10},{(void *)(scm_lambda_22),"scm_lambda_22","exceptions.scm",10},{(void *)(scm_lambda_38),"scm_lambda_38","exceptions.scm",11},{(void *)(scm_lambda_33),"scm_lambda_33","exceptions.scm",11},{(void *)(scm_lambda_28),"scm_lambda_28","exceptions.scm",11},{(void *)(scm_lambda_39),"scm_lambda_39","exceptions.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","exceptions.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","exceptions.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","exceptions.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","exceptions.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","exceptions.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","exceptions.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","exceptions.scm",14},{(void *)(scm_lambda_42),"scm_lambda_42","exceptions.scm",15},{(void *)(scm_lambda_40),"scm_lambda_40","exceptions.scm",15},{(void *)(scm_lambda_44),"scm_lambda_44","exceptions.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","exceptions.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","exceptions.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","exceptions.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","exceptions.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","exceptions.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","exceptions.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","exceptions.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","exceptions.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","exceptions.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","exceptions.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","exceptions.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","exceptions.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","exceptions.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","exceptions.scm",1},{NULL}};
+
+#line 4 "exceptions.scm"
+static void scm_safe_div_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,error_object_message_k,NULL);call_closure_two(var_2,var_0,var_1);__builtin_unreachable();}
+static struct closure_obj scm_safe_div_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_safe_div_lambda_2);
+
+#line 5 "exceptions.scm"
+static void scm_safe_div_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_safe_div_lambda_10_env *env = (struct scm_safe_div_lambda_10_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 5 "exceptions.scm"
+static void scm_safe_div_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_safe_div_lambda_9_env *env = (struct scm_safe_div_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_safe_div_lambda_10_env );(((struct scm_safe_div_lambda_10_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_safe_div_lambda_10_env *)(&((var_1)->env)))->v_rv_3)=((env)->v_rv_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_safe_div_lambda_10,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 5 "exceptions.scm"
+static void scm_safe_div_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_safe_div_lambda_7_env *env = (struct scm_safe_div_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_safe_div_lambda_9_env );(((struct scm_safe_div_lambda_9_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_safe_div_lambda_9_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_safe_div_lambda_9,var_2);call_closure_two(var_1,(env)->v_a,var_3);__builtin_unreachable();}
+
+#line 5 "exceptions.scm"
+static void scm_safe_div_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_safe_div_lambda_8_env *env = (struct scm_safe_div_lambda_8_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_b,(env)->v_k_2);__builtin_unreachable();}
+
+#line 5 "exceptions.scm"
+static void scm_safe_div_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_safe_div_lambda_6_env *env = (struct scm_safe_div_lambda_6_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){OBJECT_CLOSURE_TWO_NEW(var_1,error_k,NULL);OBJECT_STRING_OBJ_NEW(var_2,"division by zero");OBJECT_ENV_OBJ_NEW(var_3,struct scm_safe_div_lambda_7_env );(((struct scm_safe_div_lambda_7_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_safe_div_lambda_7_env *)(&((var_3)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_safe_div_lambda_7,var_3);call_closure_two(var_1,var_2,var_4);} else {OBJECT_CLOSURE_TWO_NEW(var_5,div_k,NULL);OBJECT_ENV_OBJ_NEW(var_6,struct scm_safe_div_lambda_8_env );(((struct scm_safe_div_lambda_8_env *)(&((var_6)->env)))->v_b)=((env)->v_b);(((struct scm_safe_div_lambda_8_env *)(&((var_6)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_7,scm_safe_div_lambda_8,var_6);call_closure_two(var_5,(env)->v_a,var_7);}0;__builtin_unreachable();}
+
+#line 5 "exceptions.scm"
+static void scm_safe_div_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_safe_div_lambda_5_env *env = (struct scm_safe_div_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_safe_div_lambda_6_env );(((struct scm_safe_div_lambda_6_env *)(&((var_1)->env)))->v_a)=((env)->v_a);(((struct scm_safe_div_lambda_6_env *)(&((var_1)->env)))->v_b)=((env)->v_b);(((struct scm_safe_div_lambda_6_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_safe_div_lambda_6,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 5 "exceptions.scm"
+static void scm_safe_div_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_safe_div_lambda_4_env *env = (struct scm_safe_div_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_safe_div_lambda_5_env );(((struct scm_safe_div_lambda_5_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_safe_div_lambda_5_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_safe_div_lambda_5_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_safe_div_lambda_5,var_3);call_closure_two(var_2,(env)->v_b,var_4);__builtin_unreachable();}
+
+#line 2 "exceptions.scm"
+static void scm_safe_div_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_safe_div_lambda_3_env *env = (struct scm_safe_div_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_safe_div_lambda_4_env );(((struct scm_safe_div_lambda_4_env *)(&((var_1)->env)))->v_a)=((env)->v_a);(((struct scm_safe_div_lambda_4_env *)(&((var_1)->env)))->v_b)=((env)->v_b);OBJECT_CLOSURE_TWO_NEW(var_2,scm_safe_div_lambda_4,var_1);call_closure_two(var_0,var_2,(env)->v_k_3);__builtin_unreachable();}
+
+#line 2 "exceptions.scm"
+static void scm_safe_div_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_safe_div_lambda_1_env *env = (struct scm_safe_div_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,with_exception_handler_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_safe_div_lambda_3_env );(((struct scm_safe_div_lambda_3_env *)(&((var_3)->env)))->v_a)=((env)->v_a);(((struct scm_safe_div_lambda_3_env *)(&((var_3)->env)))->v_b)=(var_0);(((struct scm_safe_div_lambda_3_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_safe_div_lambda_3,var_3);call_closure_two(var_2,(struct obj *)(&(scm_safe_div_lambda_2_closure)),var_4);__builtin_unreachable();}
+
+#line 2 "exceptions.scm"
+static void scm_safe_div(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_safe_div_lambda_1_env );(((struct scm_safe_div_lambda_1_env *)(&((var_2)->env)))->v_a)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_safe_div_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm_safe_div_closure = CLOSURE_TWO_OBJ_INIT(scm_safe_div);
+
+#line 1 "exceptions.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));call_closure_two((env)->v_rv_9,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_rv_9)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_12,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_8,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_2)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_5,var_2);call_closure_two(((struct cell_obj *)((env)->v_safe_div))->val,MAKE_FIXNUM(10),var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_9,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_6_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_6);
+
+#line 1 "exceptions.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));call_closure_two((env)->v_rv_14,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_rv_14)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_26,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_21,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_17,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_13,var_2);call_closure_two(((struct cell_obj *)((env)->v_safe_div))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_18,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_14_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_14);
+
+#line 10 "exceptions.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(2),(env)->v_k_9);__builtin_unreachable();}
+
+#line 10 "exceptions.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_27,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_22_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_22);
+
+#line 11 "exceptions.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));call_closure_two((env)->v_rv_20,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 11 "exceptions.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,raise_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_rv_20)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,MAKE_FIXNUM(20),var_3);__builtin_unreachable();}
+
+#line 11 "exceptions.scm"
+static void scm_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_28_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_28);
+
+#line 1 "exceptions.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));call_closure_two((env)->v_rv_22,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_rv_22)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_29,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_28_closure)),var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_19(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,with_exception_handler_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,(struct obj *)(&(scm_lambda_22_closure)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_19_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_19);
+
+#line 1 "exceptions.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_24(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_30,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_24_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_24);
+
+#line 14 "exceptions.scm"
+static void scm_lambda_35(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,error_object_irritants_k,NULL);call_closure_two(var_2,var_0,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_35_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_35);
+
+#line 15 "exceptions.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));call_closure_two(var_0,(struct obj *)(&(quoted_425cae510dd2bc6f)),(env)->v_k_14);__builtin_unreachable();}
+
+#line 15 "exceptions.scm"
+static void scm_lambda_40(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,error_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"bad");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_4)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_42,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_40_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_40);
+
+#line 1 "exceptions.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));call_closure_two((env)->v_rv_28,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_rv_28)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_44,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_43,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_41,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_40_closure)),var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,with_exception_handler_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_36,var_3);call_closure_two(var_2,(struct obj *)(&(scm_lambda_35_closure)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_31_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_31);
+
+#line 1 "exceptions.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_37,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_32,var_1);call_closure_two((struct obj *)(&(scm_lambda_31_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_25,var_1);call_closure_two((struct obj *)(&(scm_lambda_24_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_20,var_1);call_closure_two((struct obj *)(&(scm_lambda_19_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_15,var_1);call_closure_two((struct obj *)(&(scm_lambda_14_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_1)->env)))->v_safe_div)=((env)->v_safe_div);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_10,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_3)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_11,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_safe_div)=((env)->v_safe_div);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two((struct obj *)(&(scm_lambda_6_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "exceptions.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);(((struct cell_obj *)(var_2))->val)=((struct obj *)(&(scm_safe_div_closure)));GC_WRITE_BARRIER(var_2,((struct cell_obj *)(var_2))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_3)->env)))->v_safe_div)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_3,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_5)->env)))->v_k_16)=(var_1);(((struct scm_lambda_4_env *)(&((var_5)->env)))->v_safe_div)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_4,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "exceptions.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "exceptions.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 306 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
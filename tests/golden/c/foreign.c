@@ -0,0 +1,561 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_abs_lambda_2_env {struct obj *v_k;};
+static void scm_abs_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_abs_lambda_1_env {struct obj *v_k;};
+static void scm_abs_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_abs(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_abs_closure;
+struct scm_labs_lambda_2_env {struct obj *v_k_2;};
+static void scm_labs_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_labs_lambda_1_env {struct obj *v_k_2;};
+static void scm_labs_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_labs(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_labs_closure;
+struct scm_atof_lambda_2_env {struct obj *v_k_3;};
+static void scm_atof_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_atof_lambda_1_env {struct obj *v_k_3;};
+static void scm_atof_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_atof(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_atof_closure;
+struct scm_strcmp_lambda_5_env {struct obj *v_k_4;};
+static void scm_strcmp_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_strcmp_lambda_4_env {struct obj *v_k_4;struct obj *v_rv_10;};
+static void scm_strcmp_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_strcmp_lambda_3_env {struct obj *v_k_4;struct obj *v_rv_10;};
+static void scm_strcmp_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_strcmp_lambda_2_env {struct obj *v_k_4;struct obj *v__u7carg1;};
+static void scm_strcmp_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_strcmp_lambda_1_env {struct obj *v__u7carg0_4;};
+static void scm_strcmp_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_strcmp(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_strcmp_closure;
+struct scm_puts_lambda_2_env {struct obj *v_k_6;};
+static void scm_puts_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_puts_lambda_1_env {struct obj *v_k_6;};
+static void scm_puts_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_puts(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_puts_closure;
+static void scm_rand(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_rand_closure;
+struct scm_lambda_34_env {struct obj *v_k_8;struct obj *v_rv_14;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_8;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_8;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_abs;};
+static void scm_lambda_25(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_9;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_28_closure;
+struct scm_lambda_42_env {struct obj *v_k_10;struct obj *v_rv_18;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_10;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_10;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_labs;};
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_11;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_36_closure;
+struct scm_lambda_50_env {struct obj *v_k_12;struct obj *v_rv_22;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_12;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_12;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_atof;};
+static void scm_lambda_40(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_13;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_44(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_44_closure;
+struct scm_lambda_72_env {struct obj *v_k_14;struct obj *v_rv_26;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_14;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_14;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_14;struct obj *v_rv_30;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_14;struct obj *v_rv_30;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_14;struct obj *v_strcmp;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_strcmp;};
+static void scm_lambda_48(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_15;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_52(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_52_closure;
+struct scm_lambda_82_env {struct obj *v_k_16;struct obj *v_rv_33;};
+static void scm_lambda_82(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *v_k_16;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_k_16;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_16;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_16;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_16;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_strcmp;};
+static void scm_lambda_56(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_17;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_60(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_60_closure;
+struct scm_lambda_83_env {struct obj *v_k_18;struct obj *v_rv_40;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_18;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_18;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_18;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_rand;};
+static void scm_lambda_65(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_19;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_70_closure;
+struct scm_magnitude_lambda_2_env {struct obj *v_k_20;};
+static void scm_magnitude_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_magnitude_lambda_1_env {struct obj *v_k_20;};
+static void scm_magnitude_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_magnitude_env {struct obj *v_abs;};
+static void scm_magnitude(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v_k_21;struct obj *v_rv_47;};
+static void scm_lambda_89(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_k_21;};
+static void scm_lambda_87(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_k_21;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_magnitude;};
+static void scm_lambda_80(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_k_22;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_85(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_85_closure;
+struct scm_lambda_86_env {struct obj *v_k_23;struct obj *v_puts;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_k_23;struct obj *v_puts;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_abs;struct obj *v_k_23;struct obj *v_magnitude;struct obj *v_puts;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *parent;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *parent;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *parent;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_abs;struct obj *v_k_23;struct obj *v_magnitude;struct obj *v_puts;struct obj *v_rand;struct obj *v_strcmp;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *parent;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *parent;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *parent;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_abs;struct obj *v_atof;struct obj *v_k_23;struct obj *v_magnitude;struct obj *v_puts;struct obj *v_rand;struct obj *v_strcmp;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *parent;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *parent;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *parent;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_abs;struct obj *v_atof;struct obj *v_k_23;struct obj *v_labs;struct obj *v_magnitude;struct obj *v_puts;struct obj *v_rand;struct obj *v_strcmp;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_abs;struct obj *v_atof;struct obj *v_labs;struct obj *v_puts;struct obj *v_rand;struct obj *v_strcmp;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_abs;struct obj *v_atof;struct obj *v_labs;struct obj *v_puts;struct obj *v_strcmp;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_abs;struct obj *v_atof;struct obj *v_labs;struct obj *v_strcmp;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_abs;struct obj *v_atof;struct obj *v_labs;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_abs;struct obj *v_labs;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_abs;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_30;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_30;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_30;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_30;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_30;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_30;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+int abs(int );
+static void foreign_abs_k(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+double atof(const char * );
+static void foreign_atof_k(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+long labs(long );
+static void foreign_labs_k(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+int puts(const char * );
+static void foreign_puts_k(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+int rand(void );
+static void foreign_rand_k(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+int strcmp(const char * , const char * );
+static void foreign_strcmp_k(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_abs_lambda_2),"scm_abs_lambda_2","foreign.scm",1},{(void *)(scm_abs_lambda_1),"scm_abs_lambda_1","foreign.scm",1},{(void *)(scm_abs),"scm_abs","foreign.scm",1},{(void *)(scm_labs_lambda_2),"scm_labs_lambda_2","foreign.scm",2},{(void *)(scm_labs_lambda_1),"scm_labs_lambda_1","foreign.scm",2},{(void *)(scm_labs),"scm_labs","foreign.scm",2},{(void *)(scm_atof_lambda_2),"scm_atof_lambda_2","foreign.scm",3},{(void *)(scm_atof_lambda_1),"scm_atof_lambda_1","foreign.scm",3},{(void *)(scm_atof),"scm_atof","foreign.scm",3},{(void *)(scm_strcmp_lambda_5),"scm_strcmp_lambda_5","foreign.scm",4},{(void *)(scm_strcmp_lambda_4),"scm_strcmp_lambda_4","foreign.scm",4},{(void *)(scm_strcmp_lambda_3),"scm_strcmp_lambda_3","foreign.scm",4},{(void *)(scm_strcmp_lambda_2),"scm_strcmp_lambda_2","foreign.scm",4},{(void *)(scm_strcmp_lambda_1),"scm_strcmp_lambda_1","foreign.scm",4},{(void *)(scm_strcmp),"scm_strcmp","foreign.scm",4},{(void *)(scm_puts_lambda_2),"scm_puts_lambda_2","foreign.scm",5},{(void *)(scm_puts_lambda_1),"scm_puts_lambda_1","foreign.scm",5},{(void *)(scm_puts),"scm_puts","foreign.scm",5},{(void *)(scm_rand),"scm_rand","foreign.scm",6},{(void *)(scm_lambda_34),"scm_lambda_34","foreign.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","foreign.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","foreign.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","foreign.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","foreign.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","foreign.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","foreign.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","foreign.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","foreign.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","foreign.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","foreign.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","foreign.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","foreign.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","foreign.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","foreign.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","foreign.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","foreign.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","foreign.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","foreign.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","foreign.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","foreign.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","foreign.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","foreign.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","foreign.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","foreign.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","foreign.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","foreign.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","foreign.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","foreign.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","foreign.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","foreign.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","foreign.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","foreign.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","foreign.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","foreign.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","foreign.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","foreign.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","foreign.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","foreign.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","foreign.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","foreign.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","foreign.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","foreign.scm",1},{(void *)(scm_magnitude_lambda_2),"scm_magnitude_lambda_2","foreign.scm",13},{(void *)(scm_magnitude_lambda_1),"scm_magnitude_lambda_1","foreign.scm",13},{(void *)(scm_magnitude),"scm_magnitude","foreign.scm",13},{(void *)(scm_lambda_89),"scm_lambda_89","foreign.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","foreign.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","foreign.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","foreign.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","foreign.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","foreign.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","foreign.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","foreign.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","foreign.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","foreign.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","foreign.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","foreign.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","foreign.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","foreign.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","foreign.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","foreign.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","foreign.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","foreign.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","foreign.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","foreign.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","foreign.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","foreign.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","foreign.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","foreign.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","foreign.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","foreign.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","foreign.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","foreign.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","foreign.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","foreign.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","foreign.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","foreign.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","foreign.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","foreign.scm",1},{NULL}};
+
+#line 1 "foreign.scm"
+static void scm_abs_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_abs_lambda_2_env *env = (struct scm_abs_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,foreign_abs_k,NULL);call_closure_two(var_1,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_abs_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_abs_lambda_1_env *env = (struct scm_abs_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_abs_lambda_2_env );(((struct scm_abs_lambda_2_env *)(&((var_1)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_2,scm_abs_lambda_2,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_abs(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_abs_lambda_1_env );(((struct scm_abs_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_abs_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_abs_closure = CLOSURE_TWO_OBJ_INIT(scm_abs);
+
+#line 2 "foreign.scm"
+static void scm_labs_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_labs_lambda_2_env *env = (struct scm_labs_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,foreign_labs_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 2 "foreign.scm"
+static void scm_labs_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_labs_lambda_1_env *env = (struct scm_labs_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_labs_lambda_2_env );(((struct scm_labs_lambda_2_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_labs_lambda_2,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 2 "foreign.scm"
+static void scm_labs(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_labs_lambda_1_env );(((struct scm_labs_lambda_1_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_labs_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_labs_closure = CLOSURE_TWO_OBJ_INIT(scm_labs);
+
+#line 3 "foreign.scm"
+static void scm_atof_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_atof_lambda_2_env *env = (struct scm_atof_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,foreign_atof_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 3 "foreign.scm"
+static void scm_atof_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_atof_lambda_1_env *env = (struct scm_atof_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_atof_lambda_2_env );(((struct scm_atof_lambda_2_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_atof_lambda_2,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 3 "foreign.scm"
+static void scm_atof(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_atof_lambda_1_env );(((struct scm_atof_lambda_1_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_atof_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_atof_closure = CLOSURE_TWO_OBJ_INIT(scm_atof);
+
+#line 4 "foreign.scm"
+static void scm_strcmp_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_strcmp_lambda_5_env *env = (struct scm_strcmp_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,foreign_strcmp_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 4 "foreign.scm"
+static void scm_strcmp_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_strcmp_lambda_4_env *env = (struct scm_strcmp_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_strcmp_lambda_5_env );(((struct scm_strcmp_lambda_5_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_strcmp_lambda_5,var_1);call_closure_two((env)->v_rv_10,var_0,var_2);__builtin_unreachable();}
+
+#line 4 "foreign.scm"
+static void scm_strcmp_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_strcmp_lambda_3_env *env = (struct scm_strcmp_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_strcmp_lambda_4_env );(((struct scm_strcmp_lambda_4_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_strcmp_lambda_4_env *)(&((var_1)->env)))->v_rv_10)=((env)->v_rv_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_strcmp_lambda_4,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 4 "foreign.scm"
+static void scm_strcmp_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_strcmp_lambda_2_env *env = (struct scm_strcmp_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_strcmp_lambda_3_env );(((struct scm_strcmp_lambda_3_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_strcmp_lambda_3_env *)(&((var_2)->env)))->v_rv_10)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_strcmp_lambda_3,var_2);call_closure_two(var_1,(env)->v__u7carg1,var_3);__builtin_unreachable();}
+
+#line 4 "foreign.scm"
+static void scm_strcmp_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_strcmp_lambda_1_env *env = (struct scm_strcmp_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_strcmp_lambda_2_env );(((struct scm_strcmp_lambda_2_env *)(&((var_3)->env)))->v_k_4)=(var_1);(((struct scm_strcmp_lambda_2_env *)(&((var_3)->env)))->v__u7carg1)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_strcmp_lambda_2,var_3);call_closure_two(var_2,(env)->v__u7carg0_4,var_4);__builtin_unreachable();}
+
+#line 4 "foreign.scm"
+static void scm_strcmp(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_strcmp_lambda_1_env );(((struct scm_strcmp_lambda_1_env *)(&((var_2)->env)))->v__u7carg0_4)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_strcmp_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm_strcmp_closure = CLOSURE_TWO_OBJ_INIT(scm_strcmp);
+
+#line 5 "foreign.scm"
+static void scm_puts_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_puts_lambda_2_env *env = (struct scm_puts_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,foreign_puts_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 5 "foreign.scm"
+static void scm_puts_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_puts_lambda_1_env *env = (struct scm_puts_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_puts_lambda_2_env );(((struct scm_puts_lambda_2_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_puts_lambda_2,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 5 "foreign.scm"
+static void scm_puts(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_puts_lambda_1_env );(((struct scm_puts_lambda_1_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_puts_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_puts_closure = CLOSURE_TWO_OBJ_INIT(scm_puts);
+
+#line 6 "foreign.scm"
+static void scm_rand(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,foreign_rand_k,NULL);call_closure_two(var_2,NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_rand_closure = CLOSURE_TWO_OBJ_INIT(scm_rand);
+
+#line 1 "foreign.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));call_closure_two((env)->v_rv_14,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_rv_14)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_25(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_2)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_27,var_2);call_closure_two(((struct cell_obj *)((env)->v_abs))->val,MAKE_FIXNUM(-42),var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_28_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_28);
+
+#line 1 "foreign.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));call_closure_two((env)->v_rv_18,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_rv_18)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(((struct cell_obj *)((env)->v_labs))->val,MAKE_FIXNUM(-100000000000),var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_39,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_36_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_36);
+
+#line 1 "foreign.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));call_closure_two((env)->v_rv_22,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_rv_22)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_50,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_46,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_40(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_2,"2.5");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_43,var_3);call_closure_two(((struct cell_obj *)((env)->v_atof))->val,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_44(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_47,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_44_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_44);
+
+#line 1 "foreign.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));call_closure_two((env)->v_rv_26,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_67,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_62,var_1);call_closure_two((env)->v_rv_30,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"same");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_rv_30)=((env)->v_rv_30);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_58,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"same");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_54_env *)(&((var_2)->env)))->v_rv_30)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_54,var_2);call_closure_two(((struct cell_obj *)((env)->v_strcmp))->val,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_48(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_k_14)=(var_1);(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_strcmp)=((env)->v_strcmp);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_51,var_3);call_closure_two(var_2,MAKE_FIXNUM(0),var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_52(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_55,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_52_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_52);
+
+#line 1 "foreign.scm"
+static void scm_lambda_82(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));call_closure_two((env)->v_rv_33,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_82_env *)(&((var_2)->env)))->v_rv_33)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_82,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_78,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_73,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,lt_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"b");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_63,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_56(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_2,"a");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_59,var_3);call_closure_two(((struct cell_obj *)((env)->v_strcmp))->val,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_60(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_60_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_60);
+
+#line 1 "foreign.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));call_closure_two((env)->v_rv_40,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_83_env *)(&((var_2)->env)))->v_rv_40)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_83,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_79,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,is_number_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_74,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_65(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_2)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_69,var_2);call_closure_two(((struct cell_obj *)((env)->v_rand))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_75,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_70_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_70);
+
+#line 13 "foreign.scm"
+static void scm_magnitude_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_magnitude_lambda_2_env *env = (struct scm_magnitude_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(1),(env)->v_k_20);__builtin_unreachable();}
+
+#line 13 "foreign.scm"
+static void scm_magnitude_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_magnitude_lambda_1_env *env = (struct scm_magnitude_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_magnitude_lambda_2_env );(((struct scm_magnitude_lambda_2_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_3,scm_magnitude_lambda_2,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 13 "foreign.scm"
+static void scm_magnitude(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_magnitude_env *env = (struct scm_magnitude_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_magnitude_lambda_1_env );(((struct scm_magnitude_lambda_1_env *)(&((var_2)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_magnitude_lambda_1,var_2);call_closure_two(((struct cell_obj *)((env)->v_abs))->val,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_89(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));call_closure_two((env)->v_rv_47,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_87(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_rv_47)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_89,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_87,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_80(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_2)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_84,var_2);call_closure_two(((struct cell_obj *)((env)->v_magnitude))->val,MAKE_FIXNUM(-9),var_3);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_85(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_88,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_85_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_85);
+
+#line 1 "foreign.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"printed by C");call_closure_two(((struct cell_obj *)((env)->v_puts))->val,var_1,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_1)->env)))->v_k_23)=((env)->v_k_23);(((struct scm_lambda_86_env *)(&((var_1)->env)))->v_puts)=((env)->v_puts);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_86,var_1);call_closure_two((struct obj *)(&(scm_lambda_85_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_magnitude_env );(((struct scm_magnitude_env *)(&((var_1)->env)))->v_abs)=((env)->v_abs);OBJECT_CLOSURE_TWO_NEW(var_2,scm_magnitude,var_1);(((struct cell_obj *)((env)->v_magnitude))->val)=(var_2);GC_WRITE_BARRIER((env)->v_magnitude,((struct cell_obj *)((env)->v_magnitude))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_3)->env)))->v_magnitude)=((env)->v_magnitude);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_80,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_5)->env)))->v_k_23)=((env)->v_k_23);(((struct scm_lambda_81_env *)(&((var_5)->env)))->v_puts)=((env)->v_puts);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_81,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_abs)=(((struct scm_lambda_53_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_61_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_abs);(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_k_23)=(((struct scm_lambda_53_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_61_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_23);(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_magnitude)=(((struct scm_lambda_53_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_61_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_magnitude);(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_puts)=(((struct scm_lambda_53_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_61_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_puts);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_71,var_1);call_closure_two((struct obj *)(&(scm_lambda_70_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_1)->env)))->v_rand)=(((struct scm_lambda_53_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rand);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_65,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_66,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_61,var_1);call_closure_two((struct obj *)(&(scm_lambda_60_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_1)->env)))->v_strcmp)=((env)->v_strcmp);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_56,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_57,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_abs)=(((struct scm_lambda_37_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_abs);(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_k_23)=(((struct scm_lambda_37_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_23);(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_magnitude)=(((struct scm_lambda_37_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_magnitude);(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_puts)=(((struct scm_lambda_37_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_puts);(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_rand)=(((struct scm_lambda_37_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rand);(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_strcmp)=(((struct scm_lambda_37_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)(((struct scm_lambda_45_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_strcmp);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_53,var_1);call_closure_two((struct obj *)(&(scm_lambda_52_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_1)->env)))->v_strcmp)=(((struct scm_lambda_37_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_strcmp);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_48,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_49,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_45,var_1);call_closure_two((struct obj *)(&(scm_lambda_44_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_1)->env)))->v_atof)=((env)->v_atof);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_40,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_41,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_abs)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_26_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_abs);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_atof)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_26_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_atof);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_k_23)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_26_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_23);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_magnitude)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_26_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_magnitude);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_puts)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_26_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_puts);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_rand)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_26_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rand);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_strcmp)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_26_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_strcmp);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_37,var_1);call_closure_two((struct obj *)(&(scm_lambda_36_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_labs)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_labs);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_32,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_29,var_1);call_closure_two((struct obj *)(&(scm_lambda_28_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_magnitude)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_23)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v_abs))->val)=((struct obj *)(&(scm_abs_closure)));GC_WRITE_BARRIER((env)->v_abs,((struct cell_obj *)((env)->v_abs))->val);(((struct cell_obj *)((env)->v_labs))->val)=((struct obj *)(&(scm_labs_closure)));GC_WRITE_BARRIER((env)->v_labs,((struct cell_obj *)((env)->v_labs))->val);(((struct cell_obj *)((env)->v_atof))->val)=((struct obj *)(&(scm_atof_closure)));GC_WRITE_BARRIER((env)->v_atof,((struct cell_obj *)((env)->v_atof))->val);(((struct cell_obj *)((env)->v_strcmp))->val)=((struct obj *)(&(scm_strcmp_closure)));GC_WRITE_BARRIER((env)->v_strcmp,((struct cell_obj *)((env)->v_strcmp))->val);(((struct cell_obj *)((env)->v_puts))->val)=((struct obj *)(&(scm_puts_closure)));GC_WRITE_BARRIER((env)->v_puts,((struct cell_obj *)((env)->v_puts))->val);(((struct cell_obj *)((env)->v_rand))->val)=((struct obj *)(&(scm_rand_closure)));GC_WRITE_BARRIER((env)->v_rand,((struct cell_obj *)((env)->v_rand))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_abs)=((env)->v_abs);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_25,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_26,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_rand)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_abs)=((env)->v_abs);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_atof)=((env)->v_atof);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_labs)=((env)->v_labs);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_puts)=((env)->v_puts);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_rand)=((env)->v_rand);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_strcmp)=((env)->v_strcmp);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_14,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_puts)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_abs)=((env)->v_abs);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_atof)=((env)->v_atof);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_labs)=((env)->v_labs);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_puts)=((env)->v_puts);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_strcmp)=((env)->v_strcmp);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_strcmp)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_abs)=((env)->v_abs);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_atof)=((env)->v_atof);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_labs)=((env)->v_labs);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_strcmp)=((env)->v_strcmp);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_atof)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_abs)=((env)->v_abs);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_atof)=((env)->v_atof);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_labs)=((env)->v_labs);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_labs)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_abs)=((env)->v_abs);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_labs)=((env)->v_labs);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_abs)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_abs)=((env)->v_abs);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+static void foreign_abs_k(struct obj *args, struct obj *k, struct env_obj *env){int arg_0 = (foreign_to_int)((foreign_next_arg)(&(args)),"abs");FOREIGN_RETURN_INT(k,(abs)(arg_0));__builtin_unreachable();}
+static void foreign_atof_k(struct obj *args, struct obj *k, struct env_obj *env){const char * arg_0 = (foreign_to_string)((foreign_next_arg)(&(args)),"atof");FOREIGN_RETURN_DOUBLE(k,(atof)(arg_0));__builtin_unreachable();}
+static void foreign_labs_k(struct obj *args, struct obj *k, struct env_obj *env){long arg_0 = (foreign_to_long)((foreign_next_arg)(&(args)),"labs");FOREIGN_RETURN_INT(k,(labs)(arg_0));__builtin_unreachable();}
+static void foreign_puts_k(struct obj *args, struct obj *k, struct env_obj *env){const char * arg_0 = (foreign_to_string)((foreign_next_arg)(&(args)),"puts");FOREIGN_RETURN_INT(k,(puts)(arg_0));__builtin_unreachable();}
+static void foreign_rand_k(struct obj *args, struct obj *k, struct env_obj *env){FOREIGN_RETURN_INT(k,(rand)());__builtin_unreachable();}
+static void foreign_strcmp_k(struct obj *args, struct obj *k, struct env_obj *env){const char * arg_0 = (foreign_to_string)((foreign_next_arg)(&(args)),"strcmp");const char * arg_1 = (foreign_to_string)((foreign_next_arg)(&(args)),"strcmp");FOREIGN_RETURN_INT(k,(strcmp)(arg_0,arg_1));__builtin_unreachable();}
+
+#line 1 "foreign.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 542 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,795 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_23_env {struct obj *v_h;struct obj *v_k;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_h;};
+static void scm_lambda_21(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_17_env {struct obj *v_cell;struct obj *v_k_2;};
+static void scm_fill_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_14_env {struct obj *v_cell;struct obj *v_k_2;};
+static void scm_fill_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_12_env {struct obj *v_cell;struct obj *v_n;};
+static void scm_fill_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_25_env {struct obj *v_k_3;struct obj *v_rv_6;};
+static void scm_fill_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_24_env {struct obj *v_k_3;struct obj *v_rv_6;};
+static void scm_fill_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_23_env {struct obj *v_k_3;struct obj *v_n;};
+static void scm_fill_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_22_env {struct obj *v_k_3;struct obj *v_n;struct obj *v_rv_9;};
+static void scm_fill_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_20_env {struct obj *v_k_3;struct obj *v_n;struct obj *v_rv_9;};
+static void scm_fill_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_18_env {struct obj *v_k_3;struct obj *v_n;};
+static void scm_fill_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_15_env {struct obj *v_h;struct obj *v_n;};
+static void scm_fill_lambda_15(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_21_env {struct obj *v_fill;struct obj *v_k_4;};
+static void scm_fill_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_19_env {struct obj *v_fill;struct obj *v_k_4;};
+static void scm_fill_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_16_env {struct obj *v_fill;struct obj *v_k_4;struct obj *v_n;};
+static void scm_fill_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_13_env {struct obj *v_fill;struct obj *v_h;struct obj *v_k_4;struct obj *v_n;};
+static void scm_fill_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_11_env {struct obj *parent;};
+static void scm_fill_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_10_env {struct obj *parent;};
+static void scm_fill_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_9_env {struct obj *parent;};
+static void scm_fill_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_8_env {struct obj *v_cell;struct obj *v_fill;struct obj *v_h;struct obj *v_k_4;struct obj *v_n;struct obj *v_rv_21;struct obj *v_rv_19;struct obj *v_rv_18;};
+static void scm_fill_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_7_env {struct obj *parent;struct obj *v_rv_18;};
+static void scm_fill_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_6_env {struct obj *parent;struct obj *v_rv_19;};
+static void scm_fill_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_5_env {struct obj *parent;};
+static void scm_fill_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_4_env {struct obj *v_cell;struct obj *v_fill;struct obj *v_h;struct obj *v_k_4;struct obj *v_n;struct obj *v_rv_21;struct obj *v_v;};
+static void scm_fill_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_3_env {struct obj *parent;};
+static void scm_fill_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_2_env {struct obj *parent;};
+static void scm_fill_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_lambda_1_env {struct obj *parent;};
+static void scm_fill_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_fill_env {struct obj *v_cell;struct obj *v_fill;struct obj *v_h;struct obj *v_k_4;struct obj *v_n;struct obj *v_v;};
+static void scm_fill(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_length_lambda_6_env {struct obj *v_k_5;struct obj *v_rv_27;};
+static void scm_length_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_length_lambda_5_env {struct obj *v_k_5;struct obj *v_rv_27;};
+static void scm_length_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_length_lambda_4_env {struct obj *v_acc;struct obj *v_k_5;};
+static void scm_length_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_length_lambda_3_env {struct obj *v_acc;struct obj *v_k_5;struct obj *v_length;};
+static void scm_length_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_length_lambda_2_env {struct obj *parent;};
+static void scm_length_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_length_lambda_1_env {struct obj *v_acc;struct obj *v_k_5;struct obj *v_l;struct obj *v_length;};
+static void scm_length_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_length_env {struct obj *v_l;struct obj *v_length;};
+static void scm_length(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_lambda_7_env {struct obj *v_k_7;struct obj *v_rv_33;};
+static void scm_sum_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_lambda_6_env {struct obj *v_k_7;struct obj *v_rv_33;struct obj *v_rv_32;};
+static void scm_sum_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_lambda_5_env {struct obj *v_k_7;struct obj *v_l_2;struct obj *v_rv_33;};
+static void scm_sum_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_lambda_4_env {struct obj *v_acc_2;struct obj *v_k_7;struct obj *v_l_2;};
+static void scm_sum_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_lambda_3_env {struct obj *parent;};
+static void scm_sum_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_lambda_2_env {struct obj *parent;};
+static void scm_sum_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_lambda_1_env {struct obj *v_acc_2;struct obj *v_k_7;struct obj *v_l_2;struct obj *v_sum;};
+static void scm_sum_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_env {struct obj *v_l_2;struct obj *v_sum;};
+static void scm_sum(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_9;struct obj *v_rv_37;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_9;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_9;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_fill;};
+static void scm_lambda_30(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_10;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_33(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_33_closure;
+struct scm_lambda_61_env {struct obj *v_k_11;struct obj *v_rv_41;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_11;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_11;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_11;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_11;struct obj *v_length;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_11;struct obj *v_length;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_length;struct obj *v_v;};
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_12;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_41(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_41_closure;
+struct scm_lambda_72_env {struct obj *v_k_13;struct obj *v_rv_48;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_13;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_13;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_13;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_13;struct obj *v_sum;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_13;struct obj *v_sum;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_sum;struct obj *v_v;};
+static void scm_lambda_45(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_14;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_49(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_49_closure;
+struct scm_lambda_73_env {struct obj *v_k_15;struct obj *v_rv_55;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_15;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_15;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_15;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_cell;struct obj *v_length;};
+static void scm_lambda_54(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_16;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_59(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_59_closure;
+struct scm_lambda_82_env {struct obj *v_k_17;struct obj *v_rv_60;};
+static void scm_lambda_82(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *v_k_17;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_17;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_17;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_cell;struct obj *v_sum;};
+static void scm_lambda_65(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_18;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_70_closure;
+struct scm_lambda_96_env {struct obj *v_k_19;struct obj *v_rv_65;};
+static void scm_lambda_96(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_92_env {struct obj *v_k_19;};
+static void scm_lambda_92(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_k_19;};
+static void scm_lambda_87(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *v_k_19;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_19;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_76_env {struct obj *v_h;};
+static void scm_lambda_76(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_k_20;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_80(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_80_closure;
+struct scm_lambda_101_env {struct obj *v_count;struct obj *v_k_21;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *v_count;struct obj *v_k_21;};
+static void scm_lambda_97(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_93_env {struct obj *v_count;};
+static void scm_lambda_93(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_counter;struct obj *v_k_23;};
+static void scm_lambda_88(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v_counter;struct obj *v_k_23;};
+static void scm_lambda_89(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_85_env {struct obj *v_counter;};
+static void scm_lambda_85(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_3_env {struct obj *v_k_24;};
+static void scm_count_up_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_6_env {struct obj *v_count_up;struct obj *v_k_24;};
+static void scm_count_up_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_5_env {struct obj *v_count_up;struct obj *v_k_24;};
+static void scm_count_up_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_4_env {struct obj *v_count_up;struct obj *v_k_24;struct obj *v_n_3;};
+static void scm_count_up_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_2_env {struct obj *parent;};
+static void scm_count_up_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_1_env {struct obj *parent;};
+static void scm_count_up_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_env {struct obj *v_count_up;struct obj *v_counter;struct obj *v_k_24;struct obj *v_n_3;};
+static void scm_count_up(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *v_k_25;struct obj *v_rv_80;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_102_env {struct obj *v_k_25;};
+static void scm_lambda_102(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_k_25;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_94_env {struct obj *v_count_up;};
+static void scm_lambda_94(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_103_env {struct obj *v_k_26;};
+static void scm_lambda_103(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_99(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_99_closure;
+struct scm_lambda_112_env {struct obj *v_k_27;struct obj *v_rv_84;};
+static void scm_lambda_112(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_k_27;};
+static void scm_lambda_111(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *v_k_27;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_109_env {struct obj *v_k_27;};
+static void scm_lambda_109(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_107_env {struct obj *v_k_27;struct obj *v_length;};
+static void scm_lambda_107(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_counter;struct obj *v_length;};
+static void scm_lambda_104(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_108_env {struct obj *v_k_28;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_105_env {struct obj *v_k_28;};
+static void scm_lambda_105(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *v_counter;struct obj *v_k_28;struct obj *v_length;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_95_env {struct obj *v_counter;struct obj *v_k_28;struct obj *v_length;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_86_env {struct obj *v_count_up;struct obj *v_counter;struct obj *v_k_28;struct obj *v_length;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *parent;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *parent;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *parent;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_count_up;struct obj *v_counter;struct obj *v_h;struct obj *v_k_28;struct obj *v_length;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *parent;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *parent;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *parent;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_cell;struct obj *v_count_up;struct obj *v_counter;struct obj *v_h;struct obj *v_k_28;struct obj *v_length;struct obj *v_sum;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *parent;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *parent;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *parent;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_cell;struct obj *v_count_up;struct obj *v_counter;struct obj *v_h;struct obj *v_k_28;struct obj *v_length;struct obj *v_sum;struct obj *v_v;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *parent;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *parent;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *parent;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_cell;struct obj *v_count_up;struct obj *v_counter;struct obj *v_fill;struct obj *v_h;struct obj *v_k_28;struct obj *v_length;struct obj *v_sum;struct obj *v_v;};
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_cell;struct obj *v_counter;struct obj *v_fill;struct obj *v_h;struct obj *v_length;struct obj *v_sum;struct obj *v_v;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_cell;struct obj *v_fill;struct obj *v_h;struct obj *v_length;struct obj *v_sum;struct obj *v_v;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_cell;struct obj *v_fill;struct obj *v_h;struct obj *v_length;struct obj *v_v;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_cell;struct obj *v_fill;struct obj *v_h;struct obj *v_v;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_cell;struct obj *v_h;struct obj *v_v;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_cell;struct obj *v_v;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_v;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_36;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_36;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_36;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_36;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_36;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_36;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_36;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_done = SYMBOL_OBJ_INIT("done");
+static struct symbol_obj symbol_last = SYMBOL_OBJ_INIT("last");
+static struct symbol_obj symbol_missing = SYMBOL_OBJ_INIT("missing");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_done),&(symbol_last),&(symbol_missing),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_23),"scm_lambda_23","generations.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","generations.scm",1},{(void *)(scm_fill_lambda_17),"scm_fill_lambda_17","generations.scm",6},{(void *)(scm_fill_lambda_14),"scm_fill_lambda_14","generations.scm",6},{(void *)(scm_fill_lambda_12),"scm_fill_lambda_12","generations.scm",6},{(void *)(scm_fill_lambda_25),"scm_fill_lambda_25","generations.scm",6},{(void *)(scm_fill_lambda_24),"scm_fill_lambda_24","generations.scm",6},{(void *)(scm_fill_lambda_23),"scm_fill_lambda_23","generations.scm",6},{(void *)(scm_fill_lambda_22),"scm_fill_lambda_22","generations.scm",6},{(void *)(scm_fill_lambda_20),"scm_fill_lambda_20","generations.scm",6},{(void *)(scm_fill_lambda_18),"scm_fill_lambda_18","generations.scm",6},{(void *)(scm_fill_lambda_15),"scm_fill_lambda_15","generations.scm",6},{(void *)(scm_fill_lambda_21),"scm_fill_lambda_21","generations.scm",6},{(void *)(scm_fill_lambda_19),"scm_fill_lambda_19","generations.scm",6},{(void *)(scm_fill_lambda_16),"scm_fill_lambda_16","generations.scm",6},{(void *)(scm_fill_lambda_13),"scm_fill_lambda_13","generations.scm",6},{(void *)(scm_fill_lambda_11),"scm_fill_lambda_11","generations.scm",6},{(void *)(scm_fill_lambda_10),"scm_fill_lambda_10","generations.scm",6},{(void *)(scm_fill_lambda_9),"scm_fill_lambda_9","generations.scm",6},{(void *)(scm_fill_lambda_8),"scm_fill_lambda_8","generations.scm",6},{(void *)(scm_fill_lambda_7),"scm_fill_lambda_7","generations.scm",6},{(void *)(scm_fill_lambda_6),"scm_fill_lambda_6","generations.scm",6},{(void *)(scm_fill_lambda_5),"scm_fill_lambda_5","generations.scm",6},{(void *)(scm_fill_lambda_4),"scm_fill_lambda_4","generations.scm",6},{(void *)(scm_fill_lambda_3),"scm_fill_lambda_3","generations.scm",6},{(void *)(scm_fill_lambda_2),"scm_fill_lambda_2","generations.scm",6},{(void *)(scm_fill_lambda_1),"scm_fill_lambda_1","generations.scm",6},{(void *)(scm_fill),"scm_fill","generations.scm",6},{(void *)(scm_length_lambda_6),"scm_length_lambda_6","generations.scm",16},{(void *)(scm_length_lambda_5),"scm_length_lambda_5","generations.scm",16},{(void *)(scm_length_lambda_4),"scm_length_lambda_4","generations.scm",16},{(void *)(scm_length_lambda_3),"scm_length_lambda_3","generations.scm",16},{(void *)(scm_length_lambda_2),"scm_length_lambda_2","generations.scm",16},{(void *)(scm_length_lambda_1),"scm_length_lambda_1","generations.scm",16},{(void *)(scm_length),"scm_length","generations.scm",16},{(void *)(scm_sum_lambda_7),"scm_sum_lambda_7","generations.scm",22},{(void *)(scm_sum_lambda_6),"scm_sum_lambda_6","generations.scm",22},{(void *)(scm_sum_lambda_5),"scm_sum_lambda_5","generations.scm",22},{(void *)(scm_sum_lambda_4),"scm_sum_lambda_4","generations.scm",22},{(void *)(scm_sum_lambda_3),"scm_sum_lambda_3","generations.scm",22},{(void *)(scm_sum_lambda_2),"scm_sum_lambda_2","generations.scm",22},{(void *)(scm_sum_lambda_1),"scm_sum_lambda_1","generations.scm",22},{(void *)(scm_sum),"scm_sum","generations.scm",22},{(void *)(scm_lambda_39),"scm_lambda_39","generations.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","generations.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","generations.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","generations.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","generations.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","generations.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","generations.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","generations.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","generations.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","generations.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","generations.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","generations.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","generations.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","generations.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","generations.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","generations.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","generations.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","generations.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","generations.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","generations.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","generations.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","generations.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","generations.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","generations.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","generations.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","generations.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","generations.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","generations.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","generations.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","generations.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","generations.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","generations.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","generations.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","generations.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","generations.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","generations.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","generations.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","generations.scm",1},{(void *)(scm_lambda_96),"scm_lambda_96","generations.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","generations.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","generations.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","generations.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","generations.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","generations.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","generations.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","generations.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","generations.scm",36},{(void *)(scm_lambda_97),"scm_lambda_97","generations.scm",36},{(void *)(scm_lambda_93),"scm_lambda_93","generations.scm",36},{(void *)(scm_lambda_88),"scm_lambda_88","generations.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","generations.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","generations.scm",1},{(void *)(scm_count_up_lambda_3),"scm_count_up_lambda_3","generations.scm",41},{(void *)(scm_count_up_lambda_6),"scm_count_up_lambda_6","generations.scm",41},{(void *)(scm_count_up_lambda_5),"scm_count_up_lambda_5","generations.scm",41},{(void *)(scm_count_up_lambda_4),"scm_count_up_lambda_4","generations.scm",41},{(void *)(scm_count_up_lambda_2),"scm_count_up_lambda_2","generations.scm",41},{(void *)(scm_count_up_lambda_1),"scm_count_up_lambda_1","generations.scm",41},{(void *)(scm_count_up),"scm_count_up","generations.scm",41},{(void *)(scm_lambda_106),"scm_lambda_106","generations.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","generations.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","generations.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","generations.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","generations.scm",1},{(void *)(scm_lambda_99),"scm_lambda_99","generations.scm",1},{(void *)(scm_lambda_112),"scm_lambda_112","generations.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","generations.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","generations.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","generations.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","generations.scm",1},{(void *)(scm_lambda_104),"scm_lambda_104","generations.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","generations.scm",1},{(void *)(scm_lambda_105),"scm_lambda_105","generations.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","generations.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","generations.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","generations.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","generations.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","generations.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","generations.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","generations.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","generations.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","generations.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","generations.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","generations.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","generations.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","generations.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","generations.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","generations.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","generations.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","generations.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","generations.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","generations.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","generations.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","generations.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","generations.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","generations.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","generations.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","generations.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","generations.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","generations.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","generations.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","generations.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","generations.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","generations.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","generations.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","generations.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","generations.scm",1},{NULL}};
+
+#line 1 "generations.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_h))->val)=(var_0);GC_WRITE_BARRIER((env)->v_h,((struct cell_obj *)((env)->v_h))->val);call_closure_one((env)->v_k,NULL);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_21(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,ht_new_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_17_env *env = (struct scm_fill_lambda_17_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_cell))->val)=(var_0);GC_WRITE_BARRIER((env)->v_cell,((struct cell_obj *)((env)->v_cell))->val);call_closure_one((env)->v_k_2,NULL);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_14_env *env = (struct scm_fill_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_17_env );(((struct scm_fill_lambda_17_env *)(&((var_1)->env)))->v_cell)=((env)->v_cell);(((struct scm_fill_lambda_17_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_17,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_cell))->val,var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_fill_lambda_12_env *env = (struct scm_fill_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_fill_lambda_14_env );(((struct scm_fill_lambda_14_env *)(&((var_3)->env)))->v_cell)=((env)->v_cell);(((struct scm_fill_lambda_14_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_fill_lambda_14,var_3);call_closure_two(var_2,(env)->v_n,var_4);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_25_env *env = (struct scm_fill_lambda_25_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_24_env *env = (struct scm_fill_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_25_env );(((struct scm_fill_lambda_25_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_fill_lambda_25_env *)(&((var_1)->env)))->v_rv_6)=((env)->v_rv_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_25,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_23_env *env = (struct scm_fill_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_fill_lambda_24_env );(((struct scm_fill_lambda_24_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_fill_lambda_24_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_fill_lambda_24,var_2);call_closure_two(var_1,(env)->v_n,var_3);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_22_env *env = (struct scm_fill_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_23_env );(((struct scm_fill_lambda_23_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_fill_lambda_23_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_23,var_1);call_closure_two((env)->v_rv_9,var_0,var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_20_env *env = (struct scm_fill_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_22_env );(((struct scm_fill_lambda_22_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_fill_lambda_22_env *)(&((var_1)->env)))->v_n)=((env)->v_n);(((struct scm_fill_lambda_22_env *)(&((var_1)->env)))->v_rv_9)=((env)->v_rv_9);OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_22,var_1);call_closure_two(var_0,MAKE_FIXNUM(8),var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_18_env *env = (struct scm_fill_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,mod_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_fill_lambda_20_env );(((struct scm_fill_lambda_20_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_fill_lambda_20_env *)(&((var_2)->env)))->v_n)=((env)->v_n);(((struct scm_fill_lambda_20_env *)(&((var_2)->env)))->v_rv_9)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_fill_lambda_20,var_2);call_closure_two(var_1,(env)->v_n,var_3);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_15(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_fill_lambda_15_env *env = (struct scm_fill_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_fill_lambda_18_env );(((struct scm_fill_lambda_18_env *)(&((var_3)->env)))->v_k_3)=(var_1);(((struct scm_fill_lambda_18_env *)(&((var_3)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_4,scm_fill_lambda_18,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_21_env *env = (struct scm_fill_lambda_21_env *)(&((env_in)->env));struct obj *var_1 = ((struct cell_obj *)((env)->v_fill))->val;struct obj *var_2 = var_0;struct obj *var_3 = (env)->v_k_4;if (stack_check()){scm_fill(var_2,var_3,((struct closure_obj *)(var_1))->env);} else {}call_closure_two(var_1,var_2,var_3);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_19_env *env = (struct scm_fill_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_21_env );(((struct scm_fill_lambda_21_env *)(&((var_1)->env)))->v_fill)=((env)->v_fill);(((struct scm_fill_lambda_21_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_21,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_16_env *env = (struct scm_fill_lambda_16_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_fill_lambda_19_env );(((struct scm_fill_lambda_19_env *)(&((var_2)->env)))->v_fill)=((env)->v_fill);(((struct scm_fill_lambda_19_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_fill_lambda_19,var_2);call_closure_two(var_1,(env)->v_n,var_3);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_13_env *env = (struct scm_fill_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_15_env );(((struct scm_fill_lambda_15_env *)(&((var_1)->env)))->v_h)=((env)->v_h);(((struct scm_fill_lambda_15_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_TWO_NEW(var_2,scm_fill_lambda_15,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_fill_lambda_16_env );(((struct scm_fill_lambda_16_env *)(&((var_3)->env)))->v_fill)=((env)->v_fill);(((struct scm_fill_lambda_16_env *)(&((var_3)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_fill_lambda_16_env *)(&((var_3)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_4,scm_fill_lambda_16,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_11_env *env = (struct scm_fill_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_12_env );(((struct scm_fill_lambda_12_env *)(&((var_1)->env)))->v_cell)=(((struct scm_fill_lambda_8_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_9_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cell);(((struct scm_fill_lambda_12_env *)(&((var_1)->env)))->v_n)=(((struct scm_fill_lambda_8_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_9_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_n);OBJECT_CLOSURE_TWO_NEW(var_2,scm_fill_lambda_12,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_fill_lambda_13_env );(((struct scm_fill_lambda_13_env *)(&((var_3)->env)))->v_fill)=(((struct scm_fill_lambda_8_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_9_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fill);(((struct scm_fill_lambda_13_env *)(&((var_3)->env)))->v_h)=(((struct scm_fill_lambda_8_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_9_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_fill_lambda_13_env *)(&((var_3)->env)))->v_k_4)=(((struct scm_fill_lambda_8_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_9_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_4);(((struct scm_fill_lambda_13_env *)(&((var_3)->env)))->v_n)=(((struct scm_fill_lambda_8_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_9_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_n);OBJECT_CLOSURE_ONE_NEW(var_4,scm_fill_lambda_13,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_10_env *env = (struct scm_fill_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_11_env );(((struct scm_fill_lambda_11_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_11,var_1);call_closure_two(((struct scm_fill_lambda_8_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_9_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_19,var_0,var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_9_env *env = (struct scm_fill_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_10_env );(((struct scm_fill_lambda_10_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_10,var_1);call_closure_two(((struct scm_fill_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_18,var_0,var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_8_env *env = (struct scm_fill_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_9_env );(((struct scm_fill_lambda_9_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_9,var_1);call_closure_two(var_0,(env)->v_rv_21,var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_7_env *env = (struct scm_fill_lambda_7_env *)(&((env_in)->env));((env)->v_rv_18)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,vector_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_fill_lambda_8_env );(((struct scm_fill_lambda_8_env *)(&((var_2)->env)))->v_cell)=(((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_5_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cell);(((struct scm_fill_lambda_8_env *)(&((var_2)->env)))->v_fill)=(((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_5_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fill);(((struct scm_fill_lambda_8_env *)(&((var_2)->env)))->v_h)=(((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_5_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_fill_lambda_8_env *)(&((var_2)->env)))->v_k_4)=(((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_5_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_4);(((struct scm_fill_lambda_8_env *)(&((var_2)->env)))->v_n)=(((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_5_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_n);(((struct scm_fill_lambda_8_env *)(&((var_2)->env)))->v_rv_21)=(((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_5_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_rv_21);(((struct scm_fill_lambda_8_env *)(&((var_2)->env)))->v_rv_19)=(((struct scm_fill_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_19);(((struct scm_fill_lambda_8_env *)(&((var_2)->env)))->v_rv_18)=((env)->v_rv_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_fill_lambda_8,var_2);call_closure_two(var_1,((struct cell_obj *)(((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_5_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_6_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v))->val,var_3);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_6_env *env = (struct scm_fill_lambda_6_env *)(&((env_in)->env));((env)->v_rv_19)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_fill_lambda_7_env );(((struct scm_fill_lambda_7_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_fill_lambda_7,var_2);call_closure_two(var_1,((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n,var_3);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_5_env *env = (struct scm_fill_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_6_env );(((struct scm_fill_lambda_6_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_6,var_1);call_closure_two(var_0,((struct scm_fill_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_21,var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_4_env *env = (struct scm_fill_lambda_4_env *)(&((env_in)->env));((env)->v_rv_21)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,vector_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_fill_lambda_5_env );(((struct scm_fill_lambda_5_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_fill_lambda_5,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_v))->val,var_3);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_3_env *env = (struct scm_fill_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_4_env );(((struct scm_fill_lambda_4_env *)(&((var_1)->env)))->v_cell)=(((struct scm_fill_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_1_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cell);(((struct scm_fill_lambda_4_env *)(&((var_1)->env)))->v_fill)=(((struct scm_fill_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_1_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fill);(((struct scm_fill_lambda_4_env *)(&((var_1)->env)))->v_h)=(((struct scm_fill_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_1_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_fill_lambda_4_env *)(&((var_1)->env)))->v_k_4)=(((struct scm_fill_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_1_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_4);(((struct scm_fill_lambda_4_env *)(&((var_1)->env)))->v_n)=(((struct scm_fill_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_1_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_n);(((struct scm_fill_lambda_4_env *)(&((var_1)->env)))->v_v)=(((struct scm_fill_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_1_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_2_env *env = (struct scm_fill_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one(((struct scm_fill_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_4,(struct obj *)(&(symbol_done)));} else {OBJECT_CLOSURE_TWO_NEW(var_1,mod_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_fill_lambda_3_env );(((struct scm_fill_lambda_3_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_fill_lambda_3,var_2);call_closure_two(var_1,((struct scm_fill_env *)(&(((struct env_obj *)(((struct scm_fill_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n,var_3);}0;__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_fill_lambda_1_env *env = (struct scm_fill_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_lambda_2_env );(((struct scm_fill_lambda_2_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_fill_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 6 "generations.scm"
+static void scm_fill(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_fill_env *env = (struct scm_fill_env *)(&((env_in)->env));((env)->v_n)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_4)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_fill_lambda_1_env );(((struct scm_fill_lambda_1_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_fill_lambda_1,var_3);call_closure_two(var_2,(env)->v_n,var_4);__builtin_unreachable();}
+
+#line 16 "generations.scm"
+static void scm_length_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_length_lambda_6_env *env = (struct scm_length_lambda_6_env *)(&((env_in)->env));call_closure_two((env)->v_rv_27,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 16 "generations.scm"
+static void scm_length_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_length_lambda_5_env *env = (struct scm_length_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_length_lambda_6_env );(((struct scm_length_lambda_6_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_length_lambda_6_env *)(&((var_1)->env)))->v_rv_27)=((env)->v_rv_27);OBJECT_CLOSURE_ONE_NEW(var_2,scm_length_lambda_6,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 16 "generations.scm"
+static void scm_length_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_length_lambda_4_env *env = (struct scm_length_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_length_lambda_5_env );(((struct scm_length_lambda_5_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_length_lambda_5_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_length_lambda_5,var_2);call_closure_two(var_1,(env)->v_acc,var_3);__builtin_unreachable();}
+
+#line 16 "generations.scm"
+static void scm_length_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_length_lambda_3_env *env = (struct scm_length_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_length_lambda_4_env );(((struct scm_length_lambda_4_env *)(&((var_1)->env)))->v_acc)=((env)->v_acc);(((struct scm_length_lambda_4_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_length_lambda_4,var_1);struct obj *var_3 = ((struct cell_obj *)((env)->v_length))->val;struct obj *var_4 = var_0;struct obj *var_5 = var_2;if (stack_check()){scm_length(var_4,var_5,((struct closure_obj *)(var_3))->env);} else {}call_closure_two(var_3,var_4,var_5);__builtin_unreachable();}
+
+#line 16 "generations.scm"
+static void scm_length_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_length_lambda_2_env *env = (struct scm_length_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one(((struct scm_length_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_5,((struct scm_length_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_acc);} else {OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_length_lambda_3_env );(((struct scm_length_lambda_3_env *)(&((var_2)->env)))->v_acc)=(((struct scm_length_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_acc);(((struct scm_length_lambda_3_env *)(&((var_2)->env)))->v_k_5)=(((struct scm_length_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_5);(((struct scm_length_lambda_3_env *)(&((var_2)->env)))->v_length)=(((struct scm_length_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_length);OBJECT_CLOSURE_ONE_NEW(var_3,scm_length_lambda_3,var_2);call_closure_two(var_1,((struct scm_length_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_l,var_3);}0;__builtin_unreachable();}
+
+#line 16 "generations.scm"
+static void scm_length_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_length_lambda_1_env *env = (struct scm_length_lambda_1_env *)(&((env_in)->env));((env)->v_acc)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_5)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,is_null_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_length_lambda_2_env );(((struct scm_length_lambda_2_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_length_lambda_2,var_3);call_closure_two(var_2,(env)->v_l,var_4);__builtin_unreachable();}
+
+#line 16 "generations.scm"
+static void scm_length(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_length_env *env = (struct scm_length_env *)(&((env_in)->env));((env)->v_l)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_length_lambda_1_env );(((struct scm_length_lambda_1_env *)(&((var_2)->env)))->v_l)=((env)->v_l);(((struct scm_length_lambda_1_env *)(&((var_2)->env)))->v_length)=((env)->v_length);OBJECT_CLOSURE_TWO_NEW(var_3,scm_length_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 22 "generations.scm"
+static void scm_sum_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_sum_lambda_7_env *env = (struct scm_sum_lambda_7_env *)(&((env_in)->env));call_closure_two((env)->v_rv_33,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 22 "generations.scm"
+static void scm_sum_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_sum_lambda_6_env *env = (struct scm_sum_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_lambda_7_env );(((struct scm_sum_lambda_7_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_sum_lambda_7_env *)(&((var_1)->env)))->v_rv_33)=((env)->v_rv_33);OBJECT_CLOSURE_ONE_NEW(var_2,scm_sum_lambda_7,var_1);call_closure_two((env)->v_rv_32,var_0,var_2);__builtin_unreachable();}
+
+#line 22 "generations.scm"
+static void scm_sum_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_sum_lambda_5_env *env = (struct scm_sum_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_sum_lambda_6_env );(((struct scm_sum_lambda_6_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_sum_lambda_6_env *)(&((var_2)->env)))->v_rv_33)=((env)->v_rv_33);(((struct scm_sum_lambda_6_env *)(&((var_2)->env)))->v_rv_32)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_sum_lambda_6,var_2);call_closure_two(var_1,(env)->v_l_2,var_3);__builtin_unreachable();}
+
+#line 22 "generations.scm"
+static void scm_sum_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_sum_lambda_4_env *env = (struct scm_sum_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_sum_lambda_5_env );(((struct scm_sum_lambda_5_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_sum_lambda_5_env *)(&((var_2)->env)))->v_l_2)=((env)->v_l_2);(((struct scm_sum_lambda_5_env *)(&((var_2)->env)))->v_rv_33)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_sum_lambda_5,var_2);call_closure_two(var_1,(env)->v_acc_2,var_3);__builtin_unreachable();}
+
+#line 22 "generations.scm"
+static void scm_sum_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_sum_lambda_3_env *env = (struct scm_sum_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_lambda_4_env );(((struct scm_sum_lambda_4_env *)(&((var_1)->env)))->v_acc_2)=(((struct scm_sum_lambda_1_env *)(&(((struct env_obj *)(((struct scm_sum_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc_2);(((struct scm_sum_lambda_4_env *)(&((var_1)->env)))->v_k_7)=(((struct scm_sum_lambda_1_env *)(&(((struct env_obj *)(((struct scm_sum_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_7);(((struct scm_sum_lambda_4_env *)(&((var_1)->env)))->v_l_2)=(((struct scm_sum_lambda_1_env *)(&(((struct env_obj *)(((struct scm_sum_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_l_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_sum_lambda_4,var_1);struct obj *var_3 = ((struct cell_obj *)(((struct scm_sum_lambda_1_env *)(&(((struct env_obj *)(((struct scm_sum_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_sum))->val;struct obj *var_4 = var_0;struct obj *var_5 = var_2;if (stack_check()){scm_sum(var_4,var_5,((struct closure_obj *)(var_3))->env);} else {}call_closure_two(var_3,var_4,var_5);__builtin_unreachable();}
+
+#line 22 "generations.scm"
+static void scm_sum_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_sum_lambda_2_env *env = (struct scm_sum_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one(((struct scm_sum_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_7,((struct scm_sum_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_acc_2);} else {OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_sum_lambda_3_env );(((struct scm_sum_lambda_3_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_sum_lambda_3,var_2);call_closure_two(var_1,((struct scm_sum_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_l_2,var_3);}0;__builtin_unreachable();}
+
+#line 22 "generations.scm"
+static void scm_sum_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_sum_lambda_1_env *env = (struct scm_sum_lambda_1_env *)(&((env_in)->env));((env)->v_acc_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_7)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,is_null_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_sum_lambda_2_env );(((struct scm_sum_lambda_2_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_sum_lambda_2,var_3);call_closure_two(var_2,(env)->v_l_2,var_4);__builtin_unreachable();}
+
+#line 22 "generations.scm"
+static void scm_sum(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_sum_env *env = (struct scm_sum_env *)(&((env_in)->env));((env)->v_l_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_sum_lambda_1_env );(((struct scm_sum_lambda_1_env *)(&((var_2)->env)))->v_l_2)=((env)->v_l_2);(((struct scm_sum_lambda_1_env *)(&((var_2)->env)))->v_sum)=((env)->v_sum);OBJECT_CLOSURE_TWO_NEW(var_3,scm_sum_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));call_closure_two((env)->v_rv_37,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_rv_37)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_30(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(((struct cell_obj *)((env)->v_fill))->val,MAKE_FIXNUM(10000),var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_33(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_36,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_33_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_33);
+
+#line 1 "generations.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));call_closure_two((env)->v_rv_41,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_rv_41)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_61,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_56,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_51,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_47,var_1);call_closure_two(((struct cell_obj *)((env)->v_length))->val,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_43_env *)(&((var_1)->env)))->v_length)=((env)->v_length);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_43,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,vector_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_3)->env)))->v_k_11)=(var_1);(((struct scm_lambda_40_env *)(&((var_3)->env)))->v_length)=((env)->v_length);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_40,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_v))->val,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_41(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_41_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_41);
+
+#line 1 "generations.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));call_closure_two((env)->v_rv_48,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_rv_48)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_67,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_62,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_57,var_1);call_closure_two(((struct cell_obj *)((env)->v_sum))->val,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_52_env *)(&((var_1)->env)))->v_sum)=((env)->v_sum);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_52,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_45(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,vector_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_3)->env)))->v_k_13)=(var_1);(((struct scm_lambda_48_env *)(&((var_3)->env)))->v_sum)=((env)->v_sum);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_48,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_v))->val,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_49(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_53,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_49_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_49);
+
+#line 1 "generations.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));call_closure_two((env)->v_rv_55,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_rv_55)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_73,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_63,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_54(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_58,var_2);call_closure_two(((struct cell_obj *)((env)->v_length))->val,((struct cell_obj *)((env)->v_cell))->val,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_59(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_59_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_59);
+
+#line 1 "generations.scm"
+static void scm_lambda_82(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));call_closure_two((env)->v_rv_60,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_82_env *)(&((var_2)->env)))->v_rv_60)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_82,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_78,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_74,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_65(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_2)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_69,var_2);call_closure_two(((struct cell_obj *)((env)->v_sum))->val,((struct cell_obj *)((env)->v_cell))->val,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_75,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_70_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_70);
+
+#line 1 "generations.scm"
+static void scm_lambda_96(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));call_closure_two((env)->v_rv_65,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_92(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_96_env *)(&((var_2)->env)))->v_rv_65)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_96,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_87(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_92,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_87,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_missing)),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_83,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_76(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_ref_default_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_79,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_80(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_84,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_80_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_80);
+
+#line 36 "generations.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_count))->val)=(var_0);GC_WRITE_BARRIER((env)->v_count,((struct cell_obj *)((env)->v_count))->val);call_closure_one((env)->v_k_21,((struct cell_obj *)((env)->v_count))->val);__builtin_unreachable();}
+
+#line 36 "generations.scm"
+static void scm_lambda_97(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_count)=((env)->v_count);(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_101,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_count))->val,var_2);__builtin_unreachable();}
+
+#line 36 "generations.scm"
+static void scm_lambda_93(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_3)->env)))->v_count)=((env)->v_count);(((struct scm_lambda_97_env *)(&((var_3)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_97,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_88(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_count)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_93,var_3);(((struct cell_obj *)((env)->v_counter))->val)=(var_4);GC_WRITE_BARRIER((env)->v_counter,((struct cell_obj *)((env)->v_counter))->val);call_closure_one((env)->v_k_23,NULL);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_89(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_counter))->val)=(var_0);GC_WRITE_BARRIER((env)->v_counter,((struct cell_obj *)((env)->v_counter))->val);call_closure_one((env)->v_k_23,NULL);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_85(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_2)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_88_env *)(&((var_2)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_88,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_4)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_89_env *)(&((var_4)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_89,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+
+#line 41 "generations.scm"
+static void scm_count_up_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_3_env *env = (struct scm_count_up_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 41 "generations.scm"
+static void scm_count_up_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_6_env *env = (struct scm_count_up_lambda_6_env *)(&((env_in)->env));struct obj *var_1 = ((struct cell_obj *)((env)->v_count_up))->val;struct obj *var_2 = var_0;struct obj *var_3 = (env)->v_k_24;if (stack_check()){scm_count_up(var_2,var_3,((struct closure_obj *)(var_1))->env);} else {}call_closure_two(var_1,var_2,var_3);__builtin_unreachable();}
+
+#line 41 "generations.scm"
+static void scm_count_up_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_5_env *env = (struct scm_count_up_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_up_lambda_6_env );(((struct scm_count_up_lambda_6_env *)(&((var_1)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_count_up_lambda_6_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_up_lambda_6,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 41 "generations.scm"
+static void scm_count_up_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_4_env *env = (struct scm_count_up_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_up_lambda_5_env );(((struct scm_count_up_lambda_5_env *)(&((var_2)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_count_up_lambda_5_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_3,scm_count_up_lambda_5,var_2);call_closure_two(var_1,(env)->v_n_3,var_3);__builtin_unreachable();}
+
+#line 41 "generations.scm"
+static void scm_count_up_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_2_env *env = (struct scm_count_up_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_up_lambda_3_env );(((struct scm_count_up_lambda_3_env *)(&((var_1)->env)))->v_k_24)=(((struct scm_count_up_env *)(&(((struct env_obj *)(((struct scm_count_up_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_up_lambda_3,var_1);call_closure_two(((struct cell_obj *)(((struct scm_count_up_env *)(&(((struct env_obj *)(((struct scm_count_up_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter))->val,MAKE_FIXNUM(0),var_2);} else {OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_up_lambda_4_env );(((struct scm_count_up_lambda_4_env *)(&((var_3)->env)))->v_count_up)=(((struct scm_count_up_env *)(&(((struct env_obj *)(((struct scm_count_up_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_up);(((struct scm_count_up_lambda_4_env *)(&((var_3)->env)))->v_k_24)=(((struct scm_count_up_env *)(&(((struct env_obj *)(((struct scm_count_up_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_24);(((struct scm_count_up_lambda_4_env *)(&((var_3)->env)))->v_n_3)=(((struct scm_count_up_env *)(&(((struct env_obj *)(((struct scm_count_up_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n_3);OBJECT_CLOSURE_ONE_NEW(var_4,scm_count_up_lambda_4,var_3);call_closure_two(((struct cell_obj *)(((struct scm_count_up_env *)(&(((struct env_obj *)(((struct scm_count_up_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter))->val,((struct scm_count_up_env *)(&(((struct env_obj *)(((struct scm_count_up_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n_3,var_4);}0;__builtin_unreachable();}
+
+#line 41 "generations.scm"
+static void scm_count_up_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_1_env *env = (struct scm_count_up_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_up_lambda_2_env );(((struct scm_count_up_lambda_2_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_up_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 41 "generations.scm"
+static void scm_count_up(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_up_env *env = (struct scm_count_up_env *)(&((env_in)->env));((env)->v_n_3)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_24)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_up_lambda_1_env );(((struct scm_count_up_lambda_1_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_count_up_lambda_1,var_3);call_closure_two(var_2,(env)->v_n_3,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));call_closure_two((env)->v_rv_80,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_102(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_106_env *)(&((var_2)->env)))->v_rv_80)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_106,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_102,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_94(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_2)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_98,var_2);call_closure_two(((struct cell_obj *)((env)->v_count_up))->val,MAKE_FIXNUM(10000),var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_103(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_99(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_3)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_103,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_99_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_99);
+
+#line 1 "generations.scm"
+static void scm_lambda_112(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));call_closure_two((env)->v_rv_84,var_0,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_111(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_k_27)=((env)->v_k_27);(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_rv_84)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_112,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_2)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_111,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_109(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_109_env *env = (struct scm_lambda_109_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_1)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_110,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_107(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_107_env *env = (struct scm_lambda_107_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_109_env );(((struct scm_lambda_109_env *)(&((var_1)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_109,var_1);call_closure_two(((struct cell_obj *)((env)->v_length))->val,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_104(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_107_env );(((struct scm_lambda_107_env *)(&((var_2)->env)))->v_k_27)=(var_1);(((struct scm_lambda_107_env *)(&((var_2)->env)))->v_length)=((env)->v_length);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_107,var_2);call_closure_two(((struct cell_obj *)((env)->v_counter))->val,(struct obj *)(&(symbol_last)),var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_105(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_105_env *env = (struct scm_lambda_105_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_2)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_108,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_1)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_104_env *)(&((var_1)->env)))->v_length)=((env)->v_length);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_104,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_105_env );(((struct scm_lambda_105_env *)(&((var_3)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_105,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_1)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_100_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_lambda_100_env *)(&((var_1)->env)))->v_length)=((env)->v_length);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_100,var_1);call_closure_two((struct obj *)(&(scm_lambda_99_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_up_env );(((struct scm_count_up_env *)(&((var_1)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_count_up_env *)(&((var_1)->env)))->v_counter)=((env)->v_counter);OBJECT_CLOSURE_TWO_NEW(var_2,scm_count_up,var_1);(((struct cell_obj *)((env)->v_count_up))->val)=(var_2);GC_WRITE_BARRIER((env)->v_count_up,((struct cell_obj *)((env)->v_count_up))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_3)->env)))->v_count_up)=((env)->v_count_up);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_94,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_5)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_95_env *)(&((var_5)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_lambda_95_env *)(&((var_5)->env)))->v_length)=((env)->v_length);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_95,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_1)->env)))->v_counter)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)(((struct scm_lambda_71_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_85,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_count_up)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)(((struct scm_lambda_71_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_up);(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_counter)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)(((struct scm_lambda_71_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter);(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_k_28)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)(((struct scm_lambda_71_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_length)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)(((struct scm_lambda_71_env *)(&(((struct env_obj *)(((struct scm_lambda_77_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_length);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_86,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_81,var_1);call_closure_two((struct obj *)(&(scm_lambda_80_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_76,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_77,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_71,var_1);call_closure_two((struct obj *)(&(scm_lambda_70_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_1)->env)))->v_cell)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cell);(((struct scm_lambda_65_env *)(&((var_1)->env)))->v_sum)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_65,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_count_up)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_up);(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_counter)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter);(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_h)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_k_28)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_length)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_length);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_66,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_60,var_1);call_closure_two((struct obj *)(&(scm_lambda_59_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_cell)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_cell);(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_length)=(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_length);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_54,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_55,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_50,var_1);call_closure_two((struct obj *)(&(scm_lambda_49_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_sum)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum);(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_45,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_cell)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cell);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_count_up)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_up);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_counter)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_h)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_k_28)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_length)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_length);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_sum)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_46,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_42,var_1);call_closure_two((struct obj *)(&(scm_lambda_41_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_length)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_length);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_37,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_38,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_34,var_1);call_closure_two((struct obj *)(&(scm_lambda_33_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_fill_env );(((struct scm_fill_env *)(&((var_1)->env)))->v_cell)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cell);(((struct scm_fill_env *)(&((var_1)->env)))->v_fill)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fill);(((struct scm_fill_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_fill_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_fill,var_1);(((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fill))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fill,((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fill))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_length_env );(((struct scm_length_env *)(&((var_3)->env)))->v_length)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_length);OBJECT_CLOSURE_TWO_NEW(var_4,scm_length,var_3);(((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_length))->val)=(var_4);GC_WRITE_BARRIER(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_length,((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_length))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_sum_env );(((struct scm_sum_env *)(&((var_5)->env)))->v_sum)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum);OBJECT_CLOSURE_TWO_NEW(var_6,scm_sum,var_5);(((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum))->val)=(var_6);GC_WRITE_BARRIER(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum,((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum))->val);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_7)->env)))->v_fill)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fill);OBJECT_CLOSURE_TWO_NEW(var_8,scm_lambda_30,var_7);OBJECT_ENV_OBJ_NEW(var_9,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_9)->env)))->v_cell)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_cell);(((struct scm_lambda_31_env *)(&((var_9)->env)))->v_count_up)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_up);(((struct scm_lambda_31_env *)(&((var_9)->env)))->v_counter)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter);(((struct scm_lambda_31_env *)(&((var_9)->env)))->v_h)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_lambda_31_env *)(&((var_9)->env)))->v_k_28)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_28);(((struct scm_lambda_31_env *)(&((var_9)->env)))->v_length)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_length);(((struct scm_lambda_31_env *)(&((var_9)->env)))->v_sum)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum);(((struct scm_lambda_31_env *)(&((var_9)->env)))->v_v)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);OBJECT_CLOSURE_ONE_NEW(var_10,scm_lambda_31,var_9);call_closure_two(var_8,NULL,var_10);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v,((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v))->val);(((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_cell))->val)=(NULL);GC_WRITE_BARRIER(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_cell,((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_cell))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_h)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_21,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_22,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_18,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_count_up)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_28)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_3,make_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_4)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_17,var_4);call_closure_two(var_3,MAKE_FIXNUM(4),var_5);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_counter)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_cell)=((env)->v_cell);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_fill)=((env)->v_fill);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_length)=((env)->v_length);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_sum)=((env)->v_sum);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_16,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_sum)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_cell)=((env)->v_cell);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_fill)=((env)->v_fill);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_length)=((env)->v_length);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_sum)=((env)->v_sum);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_14,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_length)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_cell)=((env)->v_cell);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_fill)=((env)->v_fill);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_length)=((env)->v_length);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_fill)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_cell)=((env)->v_cell);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_fill)=((env)->v_fill);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_h)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_cell)=((env)->v_cell);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_cell)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_cell)=((env)->v_cell);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_v)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_36);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_15,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_36)=((env)->v_k_36);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "generations.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_36)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "generations.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 776 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
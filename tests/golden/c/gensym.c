@@ -0,0 +1,1097 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_list2_lambda_4_env {struct obj *v_k;struct obj *v_rv_3;};
+static void scm_list2_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list2_lambda_3_env {struct obj *v_k;struct obj *v_rv_3;};
+static void scm_list2_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list2_lambda_2_env {struct obj *v_b;struct obj *v_k;};
+static void scm_list2_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list2_lambda_1_env {struct obj *v_a;};
+static void scm_list2_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_list2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_list2_closure;
+struct scm_lambda_19_env {struct obj *v_k_3;struct obj *v_list2;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_list2;};
+static void scm_lambda_17(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list3_lambda_5_env {struct obj *v_k_4;struct obj *v_rv_7;};
+static void scm_list3_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list3_lambda_4_env {struct obj *v_c;struct obj *v_k_4;struct obj *v_rv_7;};
+static void scm_list3_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list3_lambda_3_env {struct obj *parent;};
+static void scm_list3_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list3_lambda_2_env {struct obj *v_a_2;struct obj *v_b_2;struct obj *v_c;struct obj *v_k_4;struct obj *v_list2;};
+static void scm_list3_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list3_lambda_1_env {struct obj *v_a_2;struct obj *v_b_2;struct obj *v_list2;};
+static void scm_list3_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_list3_env {struct obj *v_list2;};
+static void scm_list3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_7;struct obj *v_list3;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_list3;};
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_g;struct obj *v_k_8;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_g;};
+static void scm_lambda_25(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_g;struct obj *v_k_9;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_g;};
+static void scm_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_10;struct obj *v_rv_12;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_10;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_g;};
+static void scm_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_11;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_34(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_34_closure;
+struct scm_lambda_47_env {struct obj *v_k_12;struct obj *v_rv_15;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_12;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_12;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_38(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_38_closure;
+struct scm_lambda_44_env {struct obj *v_k_13;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_41(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_41_closure;
+struct scm_lambda_55_env {struct obj *v_k_14;struct obj *v_rv_19;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_14;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_14;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_45(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_45_closure;
+struct scm_lambda_52_env {struct obj *v_k_15;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_49(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_49_closure;
+struct scm_lambda_63_env {struct obj *v_k_16;struct obj *v_rv_23;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_16;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_16;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_g;};
+static void scm_lambda_53(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_17;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_57(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_57_closure;
+struct scm_lambda_75_env {struct obj *v_k_18;struct obj *v_rv_27;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_18;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_18;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_g;struct obj *v_k_18;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_g;};
+static void scm_lambda_61(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_19;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_65(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_65_closure;
+struct scm_lambda_89_env {struct obj *v_k_20;struct obj *v_rv_32;};
+static void scm_lambda_89(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_k_20;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_k_20;};
+static void scm_lambda_80(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_76_env {struct obj *v_k_20;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_k_20;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_69(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_69_closure;
+struct scm_lambda_77_env {struct obj *v_k_21;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_73(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_73_closure;
+struct scm_lambda_94_env {struct obj *v_k_22;struct obj *v_rv_38;};
+static void scm_lambda_94(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_90_env {struct obj *v_k_22;};
+static void scm_lambda_90(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_85_env {struct obj *v_k_22;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_k_22;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_78(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_78_closure;
+struct scm_lambda_86_env {struct obj *v_k_23;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_82(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_82_closure;
+struct scm_lambda_91_env {struct obj *v_env;struct obj *v_k_24;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_env;};
+static void scm_lambda_87(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_95_env {struct obj *v_env;struct obj *v_k_25;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_92_env {struct obj *v_env;};
+static void scm_lambda_92(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_k_26;struct obj *v_v;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_96_env {struct obj *v_v;};
+static void scm_lambda_96(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_k_27;struct obj *v_v;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_99_env {struct obj *v_v;};
+static void scm_lambda_99(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_116_env {struct obj *v_env;struct obj *v_k_28;};
+static void scm_lambda_116(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_env;struct obj *v_k_28;};
+static void scm_lambda_111(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_107_env {struct obj *v_env;struct obj *v_k_28;};
+static void scm_lambda_107(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_env;struct obj *v_k_28;struct obj *v_v;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_102_env {struct obj *v_env;struct obj *v_list3;struct obj *v_v;};
+static void scm_lambda_102(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_132_env {struct obj *v_env;struct obj *v_k_29;};
+static void scm_lambda_132(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_127_env {struct obj *v_env;struct obj *v_k_29;};
+static void scm_lambda_127(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_122_env {struct obj *v_env;struct obj *v_k_29;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_117_env {struct obj *v_env;struct obj *v_k_29;struct obj *v_rv_55;};
+static void scm_lambda_117(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_112_env {struct obj *v_env;struct obj *v_k_29;struct obj *v_rv_55;};
+static void scm_lambda_112(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_108_env {struct obj *v_env;struct obj *v_k_29;struct obj *v_v;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_105_env {struct obj *v_env;struct obj *v_list3;struct obj *v_v;};
+static void scm_lambda_105(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_128_env {struct obj *v_k_30;struct obj *v_rv_57;};
+static void scm_lambda_128(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_123_env {struct obj *v_k_30;};
+static void scm_lambda_123(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_k_30;};
+static void scm_lambda_118(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_113_env {struct obj *v_env;struct obj *v_k_30;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_109_env {struct obj *v_env;struct obj *v_v;};
+static void scm_lambda_109(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_119_env {struct obj *v_k_31;};
+static void scm_lambda_119(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_114(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_114_closure;
+struct scm_lambda_124_env {struct obj *v_if_var;struct obj *v_k_32;};
+static void scm_lambda_124(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_120_env {struct obj *v_if_var;};
+static void scm_lambda_120(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_129_env {struct obj *v_if_var;struct obj *v_k_33;};
+static void scm_lambda_129(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_125_env {struct obj *v_if_var;};
+static void scm_lambda_125(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_181_env {struct obj *v_k_34;struct obj *v_rv_64;};
+static void scm_lambda_181(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_175_env {struct obj *v_k_34;};
+static void scm_lambda_175(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_169_env {struct obj *v_k_34;};
+static void scm_lambda_169(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_163_env {struct obj *v_env;struct obj *v_k_34;};
+static void scm_lambda_163(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_158_env {struct obj *v_env;struct obj *v_k_34;};
+static void scm_lambda_158(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_154_env {struct obj *v_env;struct obj *v_if_var;struct obj *v_k_34;};
+static void scm_lambda_154(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_150_env {struct obj *parent;};
+static void scm_lambda_150(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_145_env {struct obj *v_env;struct obj *v_if_var;struct obj *v_k_34;struct obj *v_rv_73;};
+static void scm_lambda_145(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_140_env {struct obj *parent;};
+static void scm_lambda_140(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_136_env {struct obj *parent;};
+static void scm_lambda_136(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_133_env {struct obj *parent;struct obj *v_rv_73;};
+static void scm_lambda_133(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_130_env {struct obj *v_env;struct obj *v_if_var;struct obj *v_k_34;struct obj *v_list2;struct obj *v_list3;};
+static void scm_lambda_130(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_137_env {struct obj *v_k_35;};
+static void scm_lambda_137(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_134(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_134_closure;
+struct scm_lambda_151_env {struct obj *v_k_36;struct obj *v__u7cconverter;};
+static void scm_lambda_151(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_141(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_141_closure;
+static void scm__u7cconverter(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7cconverter_closure;
+struct scm_lambda_142_env {struct obj *v_k_38;struct obj *v_p;};
+static void scm_lambda_142(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_138_env {struct obj *v_p;};
+static void scm_lambda_138(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_147_env {struct obj *v_k_39;struct obj *v_p;};
+static void scm_lambda_147(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_143_env {struct obj *v_p;};
+static void scm_lambda_143(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_155_env {struct obj *v_k_40;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_155(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_152_env {struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_152(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_191_env {struct obj *v_k_41;};
+static void scm_lambda_191(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_186_env {struct obj *v_k_41;struct obj *v_rv_84;};
+static void scm_lambda_186(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_182_env {struct obj *v_rv_84;struct obj *v__u7cparameter0;};
+static void scm_lambda_182(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_187_env {struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_187(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_194_env {struct obj *v_k_43;};
+static void scm_lambda_194(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_192_env {struct obj *v__u7cparameter0;};
+static void scm_lambda_192(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_188_env {struct obj *v_k_44;struct obj *v__u7cparameter0;};
+static void scm_lambda_188(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_183_env {struct obj *v_k_44;struct obj *v__u7c_u20value0_u7c;struct obj *v__u7cparameter0;};
+static void scm_lambda_183(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_176_env {struct obj *v_k_44;struct obj *v__u7c_u20value0_u7c;struct obj *v__u7cparameter0;};
+static void scm_lambda_176(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_170_env {struct obj *parent;};
+static void scm_lambda_170(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_164_env {struct obj *v_k_44;struct obj *v__u7c_u20value0_u7c;struct obj *v__u7cparameter0;struct obj *v__u7cvalue0;};
+static void scm_lambda_164(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_159_env {struct obj *v__u7c_u20value0_u7c;struct obj *v__u7cparameter0;};
+static void scm_lambda_159(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_177_env {struct obj *v_k_46;struct obj *v_rv_87;};
+static void scm_lambda_177(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_171_env {struct obj *v_k_46;};
+static void scm_lambda_171(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_165_env {struct obj *v_k_46;};
+static void scm_lambda_165(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_160_env {struct obj *v_k_46;};
+static void scm_lambda_160(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_156_env {struct obj *v_p;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_156(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_166_env {struct obj *v_k_47;};
+static void scm_lambda_166(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_161(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_161_closure;
+struct scm_lambda_189_env {struct obj *v_env;struct obj *v_k_48;};
+static void scm_lambda_189(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_184_env {struct obj *v_env;struct obj *v_k_48;};
+static void scm_lambda_184(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_178_env {struct obj *v_env;struct obj *v_k_48;};
+static void scm_lambda_178(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_172_env {struct obj *v_env;struct obj *v_k_48;};
+static void scm_lambda_172(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_167_env {struct obj *v_env;struct obj *v_list3;};
+static void scm_lambda_167(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_199_env {struct obj *v_k_49;struct obj *v_rv_96;};
+static void scm_lambda_199(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_198_env {struct obj *v_k_49;};
+static void scm_lambda_198(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_197_env {struct obj *v_k_49;};
+static void scm_lambda_197(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_196_env {struct obj *v_env;struct obj *v_k_49;};
+static void scm_lambda_196(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_195_env {struct obj *v_env;struct obj *v_k_49;};
+static void scm_lambda_195(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_193_env {struct obj *v_env;struct obj *v_k_49;struct obj *v_rv_102;};
+static void scm_lambda_193(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_190_env {struct obj *v_env;struct obj *v_k_49;struct obj *v_rv_102;};
+static void scm_lambda_190(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_185_env {struct obj *v_env;struct obj *v_k_49;struct obj *v_list2;};
+static void scm_lambda_185(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_179_env {struct obj *v_env;struct obj *v_k_49;struct obj *v_list2;};
+static void scm_lambda_179(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_173_env {struct obj *v_env;struct obj *v_list2;struct obj *v_list3;};
+static void scm_lambda_173(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_180_env {struct obj *v_k_50;};
+static void scm_lambda_180(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_174_env {struct obj *v_k_50;};
+static void scm_lambda_174(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_168_env {struct obj *v_env;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;};
+static void scm_lambda_168(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_162_env {struct obj *parent;};
+static void scm_lambda_162(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_157_env {struct obj *parent;};
+static void scm_lambda_157(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_153_env {struct obj *parent;};
+static void scm_lambda_153(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_144_env {struct obj *v_env;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_144(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_139_env {struct obj *parent;};
+static void scm_lambda_139(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_135_env {struct obj *parent;};
+static void scm_lambda_135(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_131_env {struct obj *parent;};
+static void scm_lambda_131(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_126_env {struct obj *v_env;struct obj *v_if_var;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_126(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_121_env {struct obj *parent;};
+static void scm_lambda_121(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_115_env {struct obj *parent;};
+static void scm_lambda_115(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *parent;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *v_env;struct obj *v_if_var;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v_v;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_103_env {struct obj *parent;};
+static void scm_lambda_103(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *parent;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *parent;};
+static void scm_lambda_97(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_93_env {struct obj *v_env;struct obj *v_if_var;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v_v;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *parent;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *parent;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *parent;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_env;struct obj *v_if_var;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v_v;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *parent;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *parent;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *parent;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_env;struct obj *v_g;struct obj *v_if_var;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v_v;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *parent;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *parent;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *parent;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_env;struct obj *v_g;struct obj *v_if_var;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v_v;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *parent;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *parent;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *parent;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_env;struct obj *v_g;struct obj *v_if_var;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v_v;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *parent;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *parent;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *parent;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_env;struct obj *v_g;struct obj *v_if_var;struct obj *v_k_50;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v_v;struct obj *v__u7c_u20value0_u7c;};
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_env;struct obj *v_g;struct obj *v_if_var;struct obj *v_list2;struct obj *v_list3;struct obj *v_p;struct obj *v_v;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_env;struct obj *v_g;struct obj *v_if_var;struct obj *v_list2;struct obj *v_list3;struct obj *v_v;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_env;struct obj *v_g;struct obj *v_list2;struct obj *v_list3;struct obj *v_v;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_env;struct obj *v_g;struct obj *v_list2;struct obj *v_list3;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_g;struct obj *v_list2;struct obj *v_list3;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_list2;struct obj *v_list3;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_list2;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_58;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_58;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_58;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_58;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_58;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_58;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_58;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_cons = SYMBOL_OBJ_INIT("cons");
+static struct symbol_obj symbol_define = SYMBOL_OBJ_INIT("define");
+static struct symbol_obj symbol_env = SYMBOL_OBJ_INIT("env");
+static struct symbol_obj symbol_g = SYMBOL_OBJ_INIT("g");
+static struct symbol_obj symbol_if_2d_var = SYMBOL_OBJ_INIT("if-var");
+static struct symbol_obj symbol_let = SYMBOL_OBJ_INIT("let");
+static struct symbol_obj symbol_list2 = SYMBOL_OBJ_INIT("list2");
+static struct symbol_obj symbol_list3 = SYMBOL_OBJ_INIT("list3");
+static struct symbol_obj symbol_loop = SYMBOL_OBJ_INIT("loop");
+static struct symbol_obj symbol_p = SYMBOL_OBJ_INIT("p");
+static struct symbol_obj symbol_quote = SYMBOL_OBJ_INIT("quote");
+static struct symbol_obj symbol_v = SYMBOL_OBJ_INIT("v");
+static struct symbol_obj symbol_x = SYMBOL_OBJ_INIT("x");
+static struct symbol_obj symbol__7c__20_value0_7c_ = SYMBOL_OBJ_INIT("| value0|");
+static struct symbol_obj symbol__7c_eval_20_0 = SYMBOL_OBJ_INIT("|eval 0");
+static struct symbol_obj *scheme_symbols[] = {&(symbol__7c__20_value0_7c_),&(symbol__7c_eval_20_0),&(symbol_cons),&(symbol_define),&(symbol_env),&(symbol_g),&(symbol_if_2d_var),&(symbol_let),&(symbol_list2),&(symbol_list3),&(symbol_loop),&(symbol_p),&(symbol_quote),&(symbol_v),&(symbol_x),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_list2_lambda_4),"scm_list2_lambda_4","gensym.scm",2},{(void *)(scm_list2_lambda_3),"scm_list2_lambda_3","gensym.scm",2},{(void *)(scm_list2_lambda_2),"scm_list2_lambda_2","gensym.scm",2},{(void *)(scm_list2_lambda_1),"scm_list2_lambda_1","gensym.scm",2},{(void *)(scm_list2),"scm_list2","gensym.scm",2},{(void *)(scm_lambda_19),"scm_lambda_19","gensym.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","gensym.scm",1},{(void *)(scm_list3_lambda_5),"scm_list3_lambda_5","gensym.scm",3},{(void *)(scm_list3_lambda_4),"scm_list3_lambda_4","gensym.scm",3},{(void *)(scm_list3_lambda_3),"scm_list3_lambda_3","gensym.scm",3},{(void *)(scm_list3_lambda_2),"scm_list3_lambda_2","gensym.scm",3},{(void *)(scm_list3_lambda_1),"scm_list3_lambda_1","gensym.scm",3},{(void *)(scm_list3),"scm_list3","gensym.scm",3},{(void *)(scm_lambda_24),"scm_lambda_24","gensym.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","gensym.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","gensym.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","gensym.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","gensym.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","gensym.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","gensym.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","gensym.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","gensym.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","gensym.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","gensym.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","gensym.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","gensym.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","gensym.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","gensym.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","gensym.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","gensym.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","gensym.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","gensym.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","gensym.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","gensym.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","gensym.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","gensym.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","gensym.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","gensym.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","gensym.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","gensym.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","gensym.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","gensym.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","gensym.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","gensym.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","gensym.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","gensym.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","gensym.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","gensym.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","gensym.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","gensym.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","gensym.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","gensym.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","gensym.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","gensym.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","gensym.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","gensym.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","gensym.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","gensym.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","gensym.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","gensym.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","gensym.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","gensym.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","gensym.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","gensym.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","gensym.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","gensym.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","gensym.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","gensym.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","gensym.scm",1},{(void *)(scm_lambda_96),"scm_lambda_96","gensym.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","gensym.scm",1},{(void *)(scm_lambda_99),"scm_lambda_99","gensym.scm",1},{(void *)(scm_lambda_116),"scm_lambda_116","gensym.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","gensym.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","gensym.scm",1},{(void *)(scm_lambda_104),"scm_lambda_104","gensym.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","gensym.scm",1},{(void *)(scm_lambda_132),"scm_lambda_132","gensym.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","gensym.scm",1},{(void *)(scm_lambda_122),"scm_lambda_122","gensym.scm",1},{(void *)(scm_lambda_117),"scm_lambda_117","gensym.scm",1},{(void *)(scm_lambda_112),"scm_lambda_112","gensym.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","gensym.scm",1},{(void *)(scm_lambda_105),"scm_lambda_105","gensym.scm",1},{(void *)(scm_lambda_128),"scm_lambda_128","gensym.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","gensym.scm",1},{(void *)(scm_lambda_118),"scm_lambda_118","gensym.scm",1},{(void *)(scm_lambda_113),"scm_lambda_113","gensym.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","gensym.scm",1},{(void *)(scm_lambda_119),"scm_lambda_119","gensym.scm",1},{(void *)(scm_lambda_114),"scm_lambda_114","gensym.scm",1},{(void *)(scm_lambda_124),"scm_lambda_124","gensym.scm",1},{(void *)(scm_lambda_120),"scm_lambda_120","gensym.scm",1},{(void *)(scm_lambda_129),"scm_lambda_129","gensym.scm",1},{(void *)(scm_lambda_125),"scm_lambda_125","gensym.scm",1},{(void *)(scm_lambda_181),"scm_lambda_181","gensym.scm",1},{(void *)(scm_lambda_175),"scm_lambda_175","gensym.scm",1},{(void *)(scm_lambda_169),"scm_lambda_169","gensym.scm",1},{(void *)(scm_lambda_163),"scm_lambda_163","gensym.scm",1},{(void *)(scm_lambda_158),"scm_lambda_158","gensym.scm",1},{(void *)(scm_lambda_154),"scm_lambda_154","gensym.scm",1},{(void *)(scm_lambda_150),"scm_lambda_150","gensym.scm",1},{(void *)(scm_lambda_145),"scm_lambda_145","gensym.scm",1},{(void *)(scm_lambda_140),"scm_lambda_140","gensym.scm",1},{(void *)(scm_lambda_136),"scm_lambda_136","gensym.scm",1},{(void *)(scm_lambda_133),"scm_lambda_133","gensym.scm",1},{(void *)(scm_lambda_130),"scm_lambda_130","gensym.scm",1},{(void *)(scm_lambda_137),"scm_lambda_137","gensym.scm",1},{(void *)(scm_lambda_134),"scm_lambda_134","gensym.scm",1},{(void *)(scm_lambda_151),"scm_lambda_151","gensym.scm",1},{(void *)(scm_lambda_141),"scm_lambda_141","gensym.scm",1},{(void *)(scm__u7cconverter),"scm__u7cconverter","gensym.scm",23},{(void *)(scm_lambda_142),"scm_lambda_142","gensym.scm",1},{(void *)(scm_lambda_138),"scm_lambda_138","gensym.scm",1},{(void *)(scm_lambda_147),"scm_lambda_147","gensym.scm",1},{(void *)(scm_lambda_143),"scm_lambda_143","gensym.scm",1},{(void *)(scm_lambda_155),"scm_lambda_155","gensym.scm",1},{(void *)(scm_lambda_152),"scm_lambda_152","gensym.scm",1},{(void *)(scm_lambda_191),"scm_lambda_191","gensym.scm",25},{(void *)(scm_lambda_186),"scm_lambda_186","gensym.scm",25},{(void *)(scm_lambda_182),"scm_lambda_182","gensym.scm",25},{(void *)(scm_lambda_187),"scm_lambda_187","gensym.scm",25},{(void *)(scm_lambda_194),"scm_lambda_194","gensym.scm",25},{(void *)(scm_lambda_192),"scm_lambda_192","gensym.scm",25},{(void *)(scm_lambda_188),"scm_lambda_188","gensym.scm",1},{(void *)(scm_lambda_183),"scm_lambda_183","gensym.scm",1},{(void *)(scm_lambda_176),"scm_lambda_176","gensym.scm",1},{(void *)(scm_lambda_170),"scm_lambda_170","gensym.scm",1},{(void *)(scm_lambda_164),"scm_lambda_164","gensym.scm",1},{(void *)(scm_lambda_159),"scm_lambda_159","gensym.scm",1},{(void *)(scm_lambda_177),"scm_lambda_177","gensym.scm",1},{(void *)(scm_lambda_171),"scm_lambda_171","gensym.scm",1},{(void *)(scm_lambda_165),"scm_lambda_165","gensym.scm",1},{(void *)(scm_lambda_160),"scm_lambda_160","gensym.scm",1},{(void *)(scm_lambda_156),"scm_lambda_156","gensym.scm",1},{(void *)(scm_lambda_166),"scm_lambda_166","gensym.scm",1},{(void *)(scm_lambda_161),"scm_lambda_161","gensym.scm",1},{(void *)(scm_lambda_189),"scm_lambda_189","gensym.scm",1},{(void *)(scm_lambda_184),"scm_lambda_184","gensym.scm",1},{(void *)(scm_lambda_178),"scm_lambda_178","gensym.scm",1},{(void *)(scm_lambda_172),"scm_lambda_172","gensym.scm",1},{(void *)(scm_lambda_167),"scm_lambda_167","gensym.scm",1},{(void *)(scm_lambda_199),"scm_lambda_199","gensym.scm",1},{(void *)(scm_lambda_198),"scm_lambda_198","gensym.scm",1},{(void *)(scm_lambda_197),"scm_lambda_197","gensym.scm",1},{(void *)(scm_lambda_196),"scm_lambda_196","gensym.scm",1},{(void *)(scm_lambda_195),"scm_lambda_195","gensym.scm",1},{(void *)(scm_lambda_193),"scm_lambda_193","gensym.scm",1},{(void *)(scm_lambda_190),"scm_lambda_190","gensym.scm",1},{(void *)(scm_lambda_185),"scm_lambda_185","gensym.scm",1},{(void *)(scm_lambda_179),"scm_lambda_179","gensym.scm",1},{(void *)(scm_lambda_173),"scm_lambda_173","gensym.scm",1},{(void *)(scm_lambda_180),"scm_lambda_180","gensym.scm",1},{(void *)(scm_lambda_174),"scm_lambda_174","gensym.scm",1},{(void *)(scm_lambda_168),"scm_lambda_168","gensym.scm",1},{(void *)(scm_lambda_162),"scm_lambda_162","gensym.scm",1},{(void *)(scm_lambda_157),"scm_lambda_157","gensym.scm",1},{(void *)(scm_lambda_153),"scm_lambda_153","gensym.scm",1},{(void *)(scm_lambda_144),"scm_lambda_144","gensym.scm",1},{(void *)(scm_lambda_139),"scm_lambda_139","gensym.scm",1},{(void *)(scm_lambda_135),"scm_lambda_135","gensym.scm",1},{(void *)(scm_lambda_131),"scm_lambda_131","gensym.scm",1},{(void *)(scm_lambda_126),"scm_lambda_126","gensym.scm",1},{(void *)(scm_lambda_121),"scm_lambda_121","gensym.scm",1},{(void *)(scm_lambda_115),"scm_lambda_115","gensym.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","gensym.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","gensym.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","gensym.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","gensym.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","gensym.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","gensym.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","gensym.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","gensym.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","gensym.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","gensym.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","gensym.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","gensym.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","gensym.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","gensym.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","gensym.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","gensym.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","gensym.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","gensym.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","gensym.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","gensym.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","gensym.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","gensym.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","gensym.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","gensym.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","gensym.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","gensym.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","gensym.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","gensym.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","gensym.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","gensym.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","gensym.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","gensym.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","gensym.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","gensym.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","gensym.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","gensym.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","gensym.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","gensym.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","gensym.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","gensym.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","gensym.scm",1},{NULL}};
+
+#line 2 "gensym.scm"
+static void scm_list2_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_list2_lambda_4_env *env = (struct scm_list2_lambda_4_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 2 "gensym.scm"
+static void scm_list2_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_list2_lambda_3_env *env = (struct scm_list2_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_list2_lambda_4_env );(((struct scm_list2_lambda_4_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_list2_lambda_4_env *)(&((var_1)->env)))->v_rv_3)=((env)->v_rv_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_list2_lambda_4,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 2 "gensym.scm"
+static void scm_list2_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_list2_lambda_2_env *env = (struct scm_list2_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_list2_lambda_3_env );(((struct scm_list2_lambda_3_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_list2_lambda_3_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_list2_lambda_3,var_2);call_closure_two(var_1,(env)->v_b,var_3);__builtin_unreachable();}
+
+#line 2 "gensym.scm"
+static void scm_list2_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_list2_lambda_1_env *env = (struct scm_list2_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_list2_lambda_2_env );(((struct scm_list2_lambda_2_env *)(&((var_3)->env)))->v_b)=(var_0);(((struct scm_list2_lambda_2_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_list2_lambda_2,var_3);call_closure_two(var_2,(env)->v_a,var_4);__builtin_unreachable();}
+
+#line 2 "gensym.scm"
+static void scm_list2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_list2_lambda_1_env );(((struct scm_list2_lambda_1_env *)(&((var_2)->env)))->v_a)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_list2_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm_list2_closure = CLOSURE_TWO_OBJ_INIT(scm_list2);
+
+#line 1 "gensym.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_list2))->val,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_17(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_k_3)=(var_1);(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_19,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_list2)),var_4);__builtin_unreachable();}
+
+#line 3 "gensym.scm"
+static void scm_list3_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_list3_lambda_5_env *env = (struct scm_list3_lambda_5_env *)(&((env_in)->env));call_closure_two((env)->v_rv_7,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 3 "gensym.scm"
+static void scm_list3_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_list3_lambda_4_env *env = (struct scm_list3_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_list3_lambda_5_env );(((struct scm_list3_lambda_5_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_list3_lambda_5_env *)(&((var_1)->env)))->v_rv_7)=((env)->v_rv_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_list3_lambda_5,var_1);call_closure_two(var_0,(env)->v_c,var_2);__builtin_unreachable();}
+
+#line 3 "gensym.scm"
+static void scm_list3_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_list3_lambda_3_env *env = (struct scm_list3_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_list3_lambda_4_env );(((struct scm_list3_lambda_4_env *)(&((var_1)->env)))->v_c)=(((struct scm_list3_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_c);(((struct scm_list3_lambda_4_env *)(&((var_1)->env)))->v_k_4)=(((struct scm_list3_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_4);(((struct scm_list3_lambda_4_env *)(&((var_1)->env)))->v_rv_7)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_list3_lambda_4,var_1);call_closure_two(((struct cell_obj *)(((struct scm_list3_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_list2))->val,((struct scm_list3_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_b_2,var_2);__builtin_unreachable();}
+
+#line 3 "gensym.scm"
+static void scm_list3_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_list3_lambda_2_env *env = (struct scm_list3_lambda_2_env *)(&((env_in)->env));((env)->v_c)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_4)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_list3_lambda_3_env );(((struct scm_list3_lambda_3_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_list3_lambda_3,var_3);call_closure_two(var_2,(env)->v_a_2,var_4);__builtin_unreachable();}
+
+#line 3 "gensym.scm"
+static void scm_list3_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_list3_lambda_1_env *env = (struct scm_list3_lambda_1_env *)(&((env_in)->env));((env)->v_b_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_list3_lambda_2_env );(((struct scm_list3_lambda_2_env *)(&((var_2)->env)))->v_a_2)=((env)->v_a_2);(((struct scm_list3_lambda_2_env *)(&((var_2)->env)))->v_b_2)=((env)->v_b_2);(((struct scm_list3_lambda_2_env *)(&((var_2)->env)))->v_list2)=((env)->v_list2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_list3_lambda_2,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 3 "gensym.scm"
+static void scm_list3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_list3_env *env = (struct scm_list3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_list3_lambda_1_env );(((struct scm_list3_lambda_1_env *)(&((var_2)->env)))->v_a_2)=(var_0);(((struct scm_list3_lambda_1_env *)(&((var_2)->env)))->v_list2)=((env)->v_list2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_list3_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_list3))->val,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_k_7)=(var_1);(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_list3)=((env)->v_list3);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_24,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_list3)),var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_g))->val)=(var_0);GC_WRITE_BARRIER((env)->v_g,((struct cell_obj *)((env)->v_g))->val);call_closure_one((env)->v_k_8,NULL);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_25(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,gensym_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_lambda_27_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_27,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_g))->val,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_lambda_30_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_30,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_g)),var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));call_closure_two((env)->v_rv_12,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_rv_12)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_36,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_g))->val,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_34(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_37,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_34_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_34);
+
+#line 1 "gensym.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));call_closure_two((env)->v_rv_15,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_rv_15)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_47,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_43,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_38(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,gensym_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"tmp");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_4)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_40,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_38_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_38);
+
+#line 1 "gensym.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_41(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_41_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_41);
+
+#line 1 "gensym.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));call_closure_two((env)->v_rv_19,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_rv_19)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_55,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_51,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_45(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,gensym_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_48,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_loop)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_45_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_45);
+
+#line 1 "gensym.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_49(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_52,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_49_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_49);
+
+#line 1 "gensym.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));call_closure_two((env)->v_rv_23,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_63_env *)(&((var_2)->env)))->v_rv_23)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_63,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_59,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_53(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_symbol_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_56,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_g))->val,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_57(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_60,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_57_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_57);
+
+#line 1 "gensym.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));call_closure_two((env)->v_rv_27,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_75_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_75,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_67,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_g))->val,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_61(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_g))->val,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_65(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_68,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_65_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_65);
+
+#line 1 "gensym.scm"
+static void scm_lambda_89(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));call_closure_two((env)->v_rv_32,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_rv_32)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_89,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_80(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_84,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_80,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_x)),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_76,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_69(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,string_to_uninterned_symbol_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"x");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_4)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_72,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_69_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_69);
+
+#line 1 "gensym.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_73(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_3)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_77,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_73_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_73);
+
+#line 1 "gensym.scm"
+static void scm_lambda_94(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));call_closure_two((env)->v_rv_38,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_90(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_90_env *env = (struct scm_lambda_90_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_rv_38)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_94,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_90_env );(((struct scm_lambda_90_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_90,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,symbol_to_string_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_85,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_78(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,string_to_uninterned_symbol_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"hello");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_4)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_81,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_78_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_78);
+
+#line 1 "gensym.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_82(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_86,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_82_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_82);
+
+#line 1 "gensym.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_env))->val)=(var_0);GC_WRITE_BARRIER((env)->v_env,((struct cell_obj *)((env)->v_env))->val);call_closure_one((env)->v_k_24,NULL);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_87(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_environment_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_91_env *)(&((var_3)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_91,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_92(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_95_env *)(&((var_3)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_95,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_env)),var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_v))->val)=(var_0);GC_WRITE_BARRIER((env)->v_v,((struct cell_obj *)((env)->v_v))->val);call_closure_one((env)->v_k_26,NULL);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_96(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,gensym_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"v");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_4)->env)))->v_k_26)=(var_1);(((struct scm_lambda_98_env *)(&((var_4)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_98,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_v))->val,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_99(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_3)->env)))->v_k_27)=(var_1);(((struct scm_lambda_101_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_101,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_v)),var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_116(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_116_env *env = (struct scm_lambda_116_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_111(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_116_env );(((struct scm_lambda_116_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_116_env *)(&((var_2)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_116,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_107(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_107_env *env = (struct scm_lambda_107_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_111_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_111,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_107_env );(((struct scm_lambda_107_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_107_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_107,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_v))->val,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_102(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_104_env *)(&((var_2)->env)))->v_k_28)=(var_1);(((struct scm_lambda_104_env *)(&((var_2)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_104,var_2);call_closure_two(((struct cell_obj *)((env)->v_list3))->val,(struct obj *)(&(symbol_define)),var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_132(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_132_env *env = (struct scm_lambda_132_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_29);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_127(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_132_env );(((struct scm_lambda_132_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_132_env *)(&((var_2)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_132,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_127_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_127,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_117(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_117_env *env = (struct scm_lambda_117_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_122_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_122,var_1);call_closure_two((env)->v_rv_55,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_112(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,string_to_symbol_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_117_env );(((struct scm_lambda_117_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_117_env *)(&((var_2)->env)))->v_k_29)=((env)->v_k_29);(((struct scm_lambda_117_env *)(&((var_2)->env)))->v_rv_55)=((env)->v_rv_55);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_117,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,symbol_to_string_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_k_29)=((env)->v_k_29);(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_rv_55)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_112,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_v))->val,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_105(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_105_env *env = (struct scm_lambda_105_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_108_env *)(&((var_2)->env)))->v_k_29)=(var_1);(((struct scm_lambda_108_env *)(&((var_2)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_108,var_2);call_closure_two(((struct cell_obj *)((env)->v_list3))->val,(struct obj *)(&(symbol_define)),var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_128(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_128_env *env = (struct scm_lambda_128_env *)(&((env_in)->env));call_closure_two((env)->v_rv_57,var_0,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_123(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_123_env *env = (struct scm_lambda_123_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_128_env );(((struct scm_lambda_128_env *)(&((var_2)->env)))->v_k_30)=((env)->v_k_30);(((struct scm_lambda_128_env *)(&((var_2)->env)))->v_rv_57)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_128,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_118(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_123_env );(((struct scm_lambda_123_env *)(&((var_2)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_123,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_1)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_118,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_109(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_109_env *env = (struct scm_lambda_109_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_113_env *)(&((var_3)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_113,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_v))->val,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_119(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_119_env *env = (struct scm_lambda_119_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_31);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_114(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_119_env );(((struct scm_lambda_119_env *)(&((var_3)->env)))->v_k_31)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_119,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_114_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_114);
+
+#line 1 "gensym.scm"
+static void scm_lambda_124(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_124_env *env = (struct scm_lambda_124_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_if_var))->val)=(var_0);GC_WRITE_BARRIER((env)->v_if_var,((struct cell_obj *)((env)->v_if_var))->val);call_closure_one((env)->v_k_32,NULL);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_120(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_120_env *env = (struct scm_lambda_120_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,string_to_uninterned_symbol_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"if");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_124_env );(((struct scm_lambda_124_env *)(&((var_4)->env)))->v_if_var)=((env)->v_if_var);(((struct scm_lambda_124_env *)(&((var_4)->env)))->v_k_32)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_124,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_129(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_129_env *env = (struct scm_lambda_129_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_if_var))->val,(env)->v_k_33);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_125(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_125_env *env = (struct scm_lambda_125_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_129_env );(((struct scm_lambda_129_env *)(&((var_3)->env)))->v_if_var)=((env)->v_if_var);(((struct scm_lambda_129_env *)(&((var_3)->env)))->v_k_33)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_129,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_if_2d_var)),var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_181(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_181_env *env = (struct scm_lambda_181_env *)(&((env_in)->env));call_closure_two((env)->v_rv_64,var_0,(env)->v_k_34);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_175(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_175_env *env = (struct scm_lambda_175_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_181_env );(((struct scm_lambda_181_env *)(&((var_2)->env)))->v_k_34)=((env)->v_k_34);(((struct scm_lambda_181_env *)(&((var_2)->env)))->v_rv_64)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_181,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_169(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_169_env *env = (struct scm_lambda_169_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_175_env );(((struct scm_lambda_175_env *)(&((var_2)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_175,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_163(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_163_env *env = (struct scm_lambda_163_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_169_env );(((struct scm_lambda_169_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_169,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_158(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_158_env *env = (struct scm_lambda_158_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_163_env );(((struct scm_lambda_163_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_163_env *)(&((var_2)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_163,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_154(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_154_env *env = (struct scm_lambda_154_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_158_env );(((struct scm_lambda_158_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_158_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_158,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_if_var))->val,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_150(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_150_env *env = (struct scm_lambda_150_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_154_env );(((struct scm_lambda_154_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_145_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_env);(((struct scm_lambda_154_env *)(&((var_1)->env)))->v_if_var)=(((struct scm_lambda_145_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_if_var);(((struct scm_lambda_154_env *)(&((var_1)->env)))->v_k_34)=(((struct scm_lambda_145_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_154,var_1);call_closure_two(((struct scm_lambda_145_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_73,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_145(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_145_env *env = (struct scm_lambda_145_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_150_env );(((struct scm_lambda_150_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_150,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_140(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_140_env *env = (struct scm_lambda_140_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_145_env );(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_env)=(((struct scm_lambda_130_env *)(&(((struct env_obj *)(((struct scm_lambda_133_env *)(&(((struct env_obj *)(((struct scm_lambda_136_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_if_var)=(((struct scm_lambda_130_env *)(&(((struct env_obj *)(((struct scm_lambda_133_env *)(&(((struct env_obj *)(((struct scm_lambda_136_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_k_34)=(((struct scm_lambda_130_env *)(&(((struct env_obj *)(((struct scm_lambda_133_env *)(&(((struct env_obj *)(((struct scm_lambda_136_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_34);(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_rv_73)=(((struct scm_lambda_133_env *)(&(((struct env_obj *)(((struct scm_lambda_136_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_73);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_145,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_136(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_136_env *env = (struct scm_lambda_136_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_140_env );(((struct scm_lambda_140_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_140,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_133(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_133_env *env = (struct scm_lambda_133_env *)(&((env_in)->env));((env)->v_rv_73)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_136_env );(((struct scm_lambda_136_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_136,var_1);call_closure_two(((struct cell_obj *)(((struct scm_lambda_130_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_list2))->val,((struct cell_obj *)(((struct scm_lambda_130_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_if_var))->val,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_130(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_130_env *env = (struct scm_lambda_130_env *)(&((env_in)->env));((env)->v_k_34)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_133_env );(((struct scm_lambda_133_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_133,var_2);call_closure_two(((struct cell_obj *)((env)->v_list3))->val,(struct obj *)(&(symbol_let)),var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_137(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_137_env *env = (struct scm_lambda_137_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_35);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_134(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_137_env );(((struct scm_lambda_137_env *)(&((var_3)->env)))->v_k_35)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_137,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_134_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_134);
+
+#line 1 "gensym.scm"
+static void scm_lambda_151(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_151_env *env = (struct scm_lambda_151_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v__u7cconverter,(env)->v_k_36);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_141(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,make_parameter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_151_env );(((struct scm_lambda_151_env *)(&((var_3)->env)))->v_k_36)=(var_1);(((struct scm_lambda_151_env *)(&((var_3)->env)))->v__u7cconverter)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_151,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_141_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_141);
+
+#line 23 "gensym.scm"
+static void scm__u7cconverter(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm__u7cconverter_closure = CLOSURE_TWO_OBJ_INIT(scm__u7cconverter);
+
+#line 1 "gensym.scm"
+static void scm_lambda_142(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_142_env *env = (struct scm_lambda_142_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_p))->val)=(var_0);GC_WRITE_BARRIER((env)->v_p,((struct cell_obj *)((env)->v_p))->val);call_closure_one((env)->v_k_38,NULL);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_138(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_138_env *env = (struct scm_lambda_138_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_142_env );(((struct scm_lambda_142_env *)(&((var_2)->env)))->v_k_38)=(var_1);(((struct scm_lambda_142_env *)(&((var_2)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_142,var_2);call_closure_two((struct obj *)(&(scm_lambda_141_closure)),(struct obj *)(&(scm__u7cconverter_closure)),var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_147(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_147_env *env = (struct scm_lambda_147_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_p))->val,(env)->v_k_39);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_143(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_143_env *env = (struct scm_lambda_143_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_147_env );(((struct scm_lambda_147_env *)(&((var_3)->env)))->v_k_39)=(var_1);(((struct scm_lambda_147_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_147,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_p)),var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_155(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_155_env *env = (struct scm_lambda_155_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v__u7c_u20value0_u7c))->val,(env)->v_k_40);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_152(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_152_env *env = (struct scm_lambda_152_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,interaction_define_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_155_env );(((struct scm_lambda_155_env *)(&((var_3)->env)))->v_k_40)=(var_1);(((struct scm_lambda_155_env *)(&((var_3)->env)))->v__u7c_u20value0_u7c)=((env)->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_155,var_3);call_closure_two(var_2,(struct obj *)(&(symbol__7c__20_value0_7c_)),var_4);__builtin_unreachable();}
+
+#line 25 "gensym.scm"
+static void scm_lambda_191(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_191_env *env = (struct scm_lambda_191_env *)(&((env_in)->env));call_closure_one((env)->v_k_41,NULL);__builtin_unreachable();}
+
+#line 25 "gensym.scm"
+static void scm_lambda_186(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_186_env *env = (struct scm_lambda_186_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_191_env );(((struct scm_lambda_191_env *)(&((var_1)->env)))->v_k_41)=((env)->v_k_41);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_191,var_1);call_closure_two(var_0,(env)->v_rv_84,var_2);__builtin_unreachable();}
+
+#line 25 "gensym.scm"
+static void scm_lambda_182(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_182_env *env = (struct scm_lambda_182_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_186_env );(((struct scm_lambda_186_env *)(&((var_3)->env)))->v_k_41)=(var_1);(((struct scm_lambda_186_env *)(&((var_3)->env)))->v_rv_84)=((env)->v_rv_84);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_186,var_3);call_closure_two(var_2,(env)->v__u7cparameter0,var_4);__builtin_unreachable();}
+
+#line 25 "gensym.scm"
+static void scm_lambda_187(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_187_env *env = (struct scm_lambda_187_env *)(&((env_in)->env));call_closure_one(var_1,((struct cell_obj *)((env)->v__u7c_u20value0_u7c))->val);__builtin_unreachable();}
+
+#line 25 "gensym.scm"
+static void scm_lambda_194(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_194_env *env = (struct scm_lambda_194_env *)(&((env_in)->env));call_closure_one((env)->v_k_43,NULL);__builtin_unreachable();}
+
+#line 25 "gensym.scm"
+static void scm_lambda_192(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_192_env *env = (struct scm_lambda_192_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_194_env );(((struct scm_lambda_194_env *)(&((var_3)->env)))->v_k_43)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_194,var_3);call_closure_two(var_2,(env)->v__u7cparameter0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_188(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_188_env *env = (struct scm_lambda_188_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_192_env );(((struct scm_lambda_192_env *)(&((var_1)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_192,var_1);call_closure_two(var_0,var_2,(env)->v_k_44);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_183(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_183_env *env = (struct scm_lambda_183_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_187_env );(((struct scm_lambda_187_env *)(&((var_1)->env)))->v__u7c_u20value0_u7c)=((env)->v__u7c_u20value0_u7c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_187,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_188_env );(((struct scm_lambda_188_env *)(&((var_3)->env)))->v_k_44)=((env)->v_k_44);(((struct scm_lambda_188_env *)(&((var_3)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_188,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_176(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_176_env *env = (struct scm_lambda_176_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_182_env );(((struct scm_lambda_182_env *)(&((var_2)->env)))->v_rv_84)=(var_0);(((struct scm_lambda_182_env *)(&((var_2)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_182,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_183_env );(((struct scm_lambda_183_env *)(&((var_4)->env)))->v_k_44)=((env)->v_k_44);(((struct scm_lambda_183_env *)(&((var_4)->env)))->v__u7c_u20value0_u7c)=((env)->v__u7c_u20value0_u7c);(((struct scm_lambda_183_env *)(&((var_4)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_183,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_170(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_170_env *env = (struct scm_lambda_170_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_176_env );(((struct scm_lambda_176_env *)(&((var_1)->env)))->v_k_44)=(((struct scm_lambda_164_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_44);(((struct scm_lambda_176_env *)(&((var_1)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_164_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7c_u20value0_u7c);(((struct scm_lambda_176_env *)(&((var_1)->env)))->v__u7cparameter0)=(((struct scm_lambda_164_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_176,var_1);call_closure_two(var_0,((struct scm_lambda_164_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cvalue0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_164(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_164_env *env = (struct scm_lambda_164_env *)(&((env_in)->env));((env)->v__u7cvalue0)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_44)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_170_env );(((struct scm_lambda_170_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_170,var_3);call_closure_two(var_2,(env)->v__u7cparameter0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_159(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_159_env *env = (struct scm_lambda_159_env *)(&((env_in)->env));((env)->v__u7cparameter0)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_164_env );(((struct scm_lambda_164_env *)(&((var_2)->env)))->v__u7c_u20value0_u7c)=((env)->v__u7c_u20value0_u7c);(((struct scm_lambda_164_env *)(&((var_2)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_164,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_177(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_177_env *env = (struct scm_lambda_177_env *)(&((env_in)->env));call_closure_two((env)->v_rv_87,var_0,(env)->v_k_46);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_171(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_171_env *env = (struct scm_lambda_171_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_177_env );(((struct scm_lambda_177_env *)(&((var_2)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_177_env *)(&((var_2)->env)))->v_rv_87)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_177,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_165(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_165_env *env = (struct scm_lambda_165_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_171_env );(((struct scm_lambda_171_env *)(&((var_2)->env)))->v_k_46)=((env)->v_k_46);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_171,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_160(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_160_env *env = (struct scm_lambda_160_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_165_env );(((struct scm_lambda_165_env *)(&((var_1)->env)))->v_k_46)=((env)->v_k_46);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_165,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_156(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_156_env *env = (struct scm_lambda_156_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_159_env );(((struct scm_lambda_159_env *)(&((var_2)->env)))->v__u7c_u20value0_u7c)=((env)->v__u7c_u20value0_u7c);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_159,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_160_env );(((struct scm_lambda_160_env *)(&((var_4)->env)))->v_k_46)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_160,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_p))->val,var_5);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_166(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_166_env *env = (struct scm_lambda_166_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_47);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_161(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_166_env );(((struct scm_lambda_166_env *)(&((var_3)->env)))->v_k_47)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_166,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_161_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_161);
+
+#line 1 "gensym.scm"
+static void scm_lambda_189(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_189_env *env = (struct scm_lambda_189_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,(env)->v_k_48);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_184(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_184_env *env = (struct scm_lambda_184_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_189_env );(((struct scm_lambda_189_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_189_env *)(&((var_2)->env)))->v_k_48)=((env)->v_k_48);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_189,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_178(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_178_env *env = (struct scm_lambda_178_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_184_env );(((struct scm_lambda_184_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_184_env *)(&((var_1)->env)))->v_k_48)=((env)->v_k_48);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_184,var_1);call_closure_two(var_0,MAKE_FIXNUM(7),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_172(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_172_env *env = (struct scm_lambda_172_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_178_env );(((struct scm_lambda_178_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_178_env *)(&((var_1)->env)))->v_k_48)=((env)->v_k_48);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_178,var_1);call_closure_two(var_0,(struct obj *)(&(symbol__7c_eval_20_0)),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_167(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_167_env *env = (struct scm_lambda_167_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_172_env );(((struct scm_lambda_172_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_172_env *)(&((var_2)->env)))->v_k_48)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_172,var_2);call_closure_two(((struct cell_obj *)((env)->v_list3))->val,(struct obj *)(&(symbol_define)),var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_199(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_199_env *env = (struct scm_lambda_199_env *)(&((env_in)->env));call_closure_two((env)->v_rv_96,var_0,(env)->v_k_49);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_198(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_198_env *env = (struct scm_lambda_198_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_199_env );(((struct scm_lambda_199_env *)(&((var_2)->env)))->v_k_49)=((env)->v_k_49);(((struct scm_lambda_199_env *)(&((var_2)->env)))->v_rv_96)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_199,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_197(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_197_env *env = (struct scm_lambda_197_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_198_env );(((struct scm_lambda_198_env *)(&((var_2)->env)))->v_k_49)=((env)->v_k_49);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_198,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_196(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_196_env *env = (struct scm_lambda_196_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_197_env );(((struct scm_lambda_197_env *)(&((var_1)->env)))->v_k_49)=((env)->v_k_49);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_197,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_env))->val,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_195(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_195_env *env = (struct scm_lambda_195_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,eval_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_196_env );(((struct scm_lambda_196_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_196_env *)(&((var_2)->env)))->v_k_49)=((env)->v_k_49);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_196,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_193(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_193_env *env = (struct scm_lambda_193_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_195_env );(((struct scm_lambda_195_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_195_env *)(&((var_1)->env)))->v_k_49)=((env)->v_k_49);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_195,var_1);call_closure_two((env)->v_rv_102,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_190(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_190_env *env = (struct scm_lambda_190_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_193_env );(((struct scm_lambda_193_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_193_env *)(&((var_1)->env)))->v_k_49)=((env)->v_k_49);(((struct scm_lambda_193_env *)(&((var_1)->env)))->v_rv_102)=((env)->v_rv_102);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_193,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_x)),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_185(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_185_env *env = (struct scm_lambda_185_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_190_env );(((struct scm_lambda_190_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_190_env *)(&((var_1)->env)))->v_k_49)=((env)->v_k_49);(((struct scm_lambda_190_env *)(&((var_1)->env)))->v_rv_102)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_190,var_1);call_closure_two(((struct cell_obj *)((env)->v_list2))->val,(struct obj *)(&(symbol_quote)),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_179(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_179_env *env = (struct scm_lambda_179_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_185_env );(((struct scm_lambda_185_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_185_env *)(&((var_1)->env)))->v_k_49)=((env)->v_k_49);(((struct scm_lambda_185_env *)(&((var_1)->env)))->v_list2)=((env)->v_list2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_185,var_1);call_closure_two(var_0,(struct obj *)(&(symbol__7c_eval_20_0)),var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_173(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_173_env *env = (struct scm_lambda_173_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_179_env );(((struct scm_lambda_179_env *)(&((var_2)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_179_env *)(&((var_2)->env)))->v_k_49)=(var_1);(((struct scm_lambda_179_env *)(&((var_2)->env)))->v_list2)=((env)->v_list2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_179,var_2);call_closure_two(((struct cell_obj *)((env)->v_list3))->val,(struct obj *)(&(symbol_cons)),var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_180(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_180_env *env = (struct scm_lambda_180_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_50);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_174(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_174_env *env = (struct scm_lambda_174_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_180_env );(((struct scm_lambda_180_env *)(&((var_2)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_180,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_168(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_168_env *env = (struct scm_lambda_168_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_173_env );(((struct scm_lambda_173_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_173_env *)(&((var_1)->env)))->v_list2)=((env)->v_list2);(((struct scm_lambda_173_env *)(&((var_1)->env)))->v_list3)=((env)->v_list3);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_173,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_174_env );(((struct scm_lambda_174_env *)(&((var_3)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_174,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_162(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_162_env *env = (struct scm_lambda_162_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_167_env );(((struct scm_lambda_167_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_144_env *)(&(((struct env_obj *)(((struct scm_lambda_153_env *)(&(((struct env_obj *)(((struct scm_lambda_157_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_167_env *)(&((var_1)->env)))->v_list3)=(((struct scm_lambda_144_env *)(&(((struct env_obj *)(((struct scm_lambda_153_env *)(&(((struct env_obj *)(((struct scm_lambda_157_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_167,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_168_env );(((struct scm_lambda_168_env *)(&((var_3)->env)))->v_env)=(((struct scm_lambda_144_env *)(&(((struct env_obj *)(((struct scm_lambda_153_env *)(&(((struct env_obj *)(((struct scm_lambda_157_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_168_env *)(&((var_3)->env)))->v_k_50)=(((struct scm_lambda_144_env *)(&(((struct env_obj *)(((struct scm_lambda_153_env *)(&(((struct env_obj *)(((struct scm_lambda_157_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_168_env *)(&((var_3)->env)))->v_list2)=(((struct scm_lambda_144_env *)(&(((struct env_obj *)(((struct scm_lambda_153_env *)(&(((struct env_obj *)(((struct scm_lambda_157_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_168_env *)(&((var_3)->env)))->v_list3)=(((struct scm_lambda_144_env *)(&(((struct env_obj *)(((struct scm_lambda_153_env *)(&(((struct env_obj *)(((struct scm_lambda_157_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_168,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_157(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_157_env *env = (struct scm_lambda_157_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_162_env );(((struct scm_lambda_162_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_162,var_1);call_closure_two((struct obj *)(&(scm_lambda_161_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_153(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_153_env *env = (struct scm_lambda_153_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_156_env );(((struct scm_lambda_156_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_144_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_p);(((struct scm_lambda_156_env *)(&((var_1)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_144_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_156,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_157_env );(((struct scm_lambda_157_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_157,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_144(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_144_env *env = (struct scm_lambda_144_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v__u7c_u20value0_u7c))->val)=(MAKE_FIXNUM(5));GC_WRITE_BARRIER((env)->v__u7c_u20value0_u7c,((struct cell_obj *)((env)->v__u7c_u20value0_u7c))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_152_env );(((struct scm_lambda_152_env *)(&((var_1)->env)))->v__u7c_u20value0_u7c)=((env)->v__u7c_u20value0_u7c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_152,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_153_env );(((struct scm_lambda_153_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_153,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_139(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_139_env *env = (struct scm_lambda_139_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_143_env );(((struct scm_lambda_143_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_126_env *)(&(((struct env_obj *)(((struct scm_lambda_131_env *)(&(((struct env_obj *)(((struct scm_lambda_135_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_143,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_144_env );(((struct scm_lambda_144_env *)(&((var_3)->env)))->v_env)=(((struct scm_lambda_126_env *)(&(((struct env_obj *)(((struct scm_lambda_131_env *)(&(((struct env_obj *)(((struct scm_lambda_135_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_144_env *)(&((var_3)->env)))->v_k_50)=(((struct scm_lambda_126_env *)(&(((struct env_obj *)(((struct scm_lambda_131_env *)(&(((struct env_obj *)(((struct scm_lambda_135_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_144_env *)(&((var_3)->env)))->v_list2)=(((struct scm_lambda_126_env *)(&(((struct env_obj *)(((struct scm_lambda_131_env *)(&(((struct env_obj *)(((struct scm_lambda_135_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_144_env *)(&((var_3)->env)))->v_list3)=(((struct scm_lambda_126_env *)(&(((struct env_obj *)(((struct scm_lambda_131_env *)(&(((struct env_obj *)(((struct scm_lambda_135_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_144_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_126_env *)(&(((struct env_obj *)(((struct scm_lambda_131_env *)(&(((struct env_obj *)(((struct scm_lambda_135_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_144_env *)(&((var_3)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_126_env *)(&(((struct env_obj *)(((struct scm_lambda_131_env *)(&(((struct env_obj *)(((struct scm_lambda_135_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_144,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_135(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_135_env *env = (struct scm_lambda_135_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_138_env );(((struct scm_lambda_138_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_126_env *)(&(((struct env_obj *)(((struct scm_lambda_131_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_138,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_139_env );(((struct scm_lambda_139_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_139,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_131(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_131_env *env = (struct scm_lambda_131_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_135_env );(((struct scm_lambda_135_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_135,var_1);call_closure_two((struct obj *)(&(scm_lambda_134_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_126(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_126_env *env = (struct scm_lambda_126_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_130_env );(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_if_var)=((env)->v_if_var);(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_list2)=((env)->v_list2);(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_list3)=((env)->v_list3);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_130,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_131_env );(((struct scm_lambda_131_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_131,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_121(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_121_env *env = (struct scm_lambda_121_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_125_env );(((struct scm_lambda_125_env *)(&((var_1)->env)))->v_if_var)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)(((struct scm_lambda_115_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_125,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_126_env );(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_env)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)(((struct scm_lambda_115_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_if_var)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)(((struct scm_lambda_115_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_k_50)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)(((struct scm_lambda_115_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_list2)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)(((struct scm_lambda_115_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_list3)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)(((struct scm_lambda_115_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)(((struct scm_lambda_115_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_126_env *)(&((var_3)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)(((struct scm_lambda_115_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_126,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_115(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_115_env *env = (struct scm_lambda_115_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_120_env );(((struct scm_lambda_120_env *)(&((var_1)->env)))->v_if_var)=(((struct scm_lambda_106_env *)(&(((struct env_obj *)(((struct scm_lambda_110_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_if_var);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_120,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_121_env );(((struct scm_lambda_121_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_121,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_115_env );(((struct scm_lambda_115_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_115,var_1);call_closure_two((struct obj *)(&(scm_lambda_114_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_109_env );(((struct scm_lambda_109_env *)(&((var_1)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_109_env *)(&((var_1)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_109,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_110,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_103(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_105_env );(((struct scm_lambda_105_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_105_env *)(&((var_1)->env)))->v_list3)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_105_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_105,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_env)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_if_var)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_k_50)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_list2)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_list3)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_v)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_106,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_102_env *)(&((var_1)->env)))->v_list3)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_102_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_97_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_102,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_103,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_97(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_99,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_100,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_1)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_96,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_97,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_92,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_env)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_if_var)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_k_50)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_list2)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_list3)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_v)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_93,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_74_env *)(&(((struct env_obj *)(((struct scm_lambda_79_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_env);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_87,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_88,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_83,var_1);call_closure_two((struct obj *)(&(scm_lambda_82_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_79,var_1);call_closure_two((struct obj *)(&(scm_lambda_78_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_58_env *)(&(((struct env_obj *)(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_if_var)=(((struct scm_lambda_58_env *)(&(((struct env_obj *)(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_k_50)=(((struct scm_lambda_58_env *)(&(((struct env_obj *)(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_list2)=(((struct scm_lambda_58_env *)(&(((struct env_obj *)(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_list3)=(((struct scm_lambda_58_env *)(&(((struct env_obj *)(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_58_env *)(&(((struct env_obj *)(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_58_env *)(&(((struct env_obj *)(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_74_env *)(&((var_1)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_58_env *)(&(((struct env_obj *)(((struct scm_lambda_62_env *)(&(((struct env_obj *)(((struct scm_lambda_66_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_74,var_1);call_closure_two((struct obj *)(&(scm_lambda_73_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_70,var_1);call_closure_two((struct obj *)(&(scm_lambda_69_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_66,var_1);call_closure_two((struct obj *)(&(scm_lambda_65_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_g)=((env)->v_g);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_61,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_62,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_g)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_g);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_if_var)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_k_50)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_list2)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_list3)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)(((struct scm_lambda_50_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_58,var_1);call_closure_two((struct obj *)(&(scm_lambda_57_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_g)=(((struct scm_lambda_42_env *)(&(((struct env_obj *)(((struct scm_lambda_46_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_g);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_53,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_54,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_50,var_1);call_closure_two((struct obj *)(&(scm_lambda_49_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_46,var_1);call_closure_two((struct obj *)(&(scm_lambda_45_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_env)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_g)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_g);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_if_var)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_k_50)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_list2)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_list3)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_42_env *)(&((var_1)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_32_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_42,var_1);call_closure_two((struct obj *)(&(scm_lambda_41_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_39,var_1);call_closure_two((struct obj *)(&(scm_lambda_38_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_35,var_1);call_closure_two((struct obj *)(&(scm_lambda_34_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_1)->env)))->v_g)=((env)->v_g);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_31,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_32,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_g)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_g);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_28,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_env)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_env);(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_g)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_g);(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_if_var)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_if_var);(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_k_50)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_50);(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_list2)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list2);(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_list3)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_list3);(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_v)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_29_env *)(&((var_3)->env)))->v__u7c_u20value0_u7c)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u7c_u20value0_u7c);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_29,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_1)->env)))->v_g)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_g);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_25,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_26,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_list3_env );(((struct scm_list3_env *)(&((var_1)->env)))->v_list2)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_list2);OBJECT_CLOSURE_TWO_NEW(var_2,scm_list3,var_1);(((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_list3))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_list3,((struct cell_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_list3))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_3)->env)))->v_list3)=(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_list3);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_22,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_23,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u7c_u20value0_u7c)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_50)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v_list2))->val)=((struct obj *)(&(scm_list2_closure)));GC_WRITE_BARRIER((env)->v_list2,((struct cell_obj *)((env)->v_list2))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_17,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_18,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_p)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_if_var)=((env)->v_if_var);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_list3)=((env)->v_list3);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_16,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_if_var)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_if_var)=((env)->v_if_var);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_list3)=((env)->v_list3);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_14,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_v)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_list3)=((env)->v_list3);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_env)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_env)=((env)->v_env);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_list3)=((env)->v_list3);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_g)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_list3)=((env)->v_list3);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_list3)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_list3)=((env)->v_list3);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_list2)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_list2)=((env)->v_list2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_58);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_k_58)=((env)->v_k_58);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_15,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_58)=((env)->v_k_58);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_58)=((env)->v_k_58);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_58)=((env)->v_k_58);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_58)=((env)->v_k_58);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_58)=((env)->v_k_58);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "gensym.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_58)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "gensym.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 1078 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
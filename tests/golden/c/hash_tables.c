@@ -0,0 +1,354 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_9_env {struct obj *v_k;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_k;};
+static void scm_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_h;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_2;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_k_2;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_h;};
+static void scm_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_3;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_k_3;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_h;};
+static void scm_lambda_11(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_4;struct obj *v_rv_8;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_4;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_4;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_k_4;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_h;};
+static void scm_lambda_15(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_5;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_19(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_19_closure;
+struct scm_lambda_38_env {struct obj *v_k_6;struct obj *v_rv_13;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_k_6;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_6;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_6;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_h;};
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_7;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_27(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_27_closure;
+struct scm_lambda_51_env {struct obj *v_k_8;struct obj *v_rv_18;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_8;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_8;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_8;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_8;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_h;};
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_9;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_36_closure;
+struct scm_lambda_44_env {struct obj *v_k_10;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_h;};
+static void scm_lambda_41(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_11;struct obj *v_rv_25;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_11;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_11;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_11;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_11;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_h;};
+static void scm_lambda_45(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_12;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_49(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_49_closure;
+struct scm_lambda_62_env {struct obj *v_k_13;struct obj *v_rv_31;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_13;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_13;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_h;};
+static void scm_lambda_54(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_14;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_14;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_h;struct obj *v_k_14;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_gone = SYMBOL_OBJ_INIT("gone");
+static struct symbol_obj symbol_missing = SYMBOL_OBJ_INIT("missing");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_gone),&(symbol_missing),NULL};
+static struct cons_obj quoted_7b1824f3753c6df8_1 = CONS_OBJ_INIT(MAKE_FIXNUM(2),NULL);
+static struct cons_obj quoted_7b1824f3753c6df8 = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_7b1824f3753c6df8_1)));
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_9),"scm_lambda_9","hash_tables.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","hash_tables.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","hash_tables.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","hash_tables.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","hash_tables.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","hash_tables.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","hash_tables.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","hash_tables.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","hash_tables.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","hash_tables.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","hash_tables.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","hash_tables.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","hash_tables.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","hash_tables.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","hash_tables.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","hash_tables.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","hash_tables.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","hash_tables.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","hash_tables.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","hash_tables.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","hash_tables.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","hash_tables.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","hash_tables.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","hash_tables.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","hash_tables.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","hash_tables.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","hash_tables.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","hash_tables.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","hash_tables.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","hash_tables.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","hash_tables.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","hash_tables.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","hash_tables.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","hash_tables.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","hash_tables.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","hash_tables.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","hash_tables.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","hash_tables.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","hash_tables.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","hash_tables.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","hash_tables.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","hash_tables.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","hash_tables.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","hash_tables.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","hash_tables.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","hash_tables.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","hash_tables.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","hash_tables.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","hash_tables.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","hash_tables.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","hash_tables.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","hash_tables.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","hash_tables.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","hash_tables.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","hash_tables.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","hash_tables.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","hash_tables.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","hash_tables.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","hash_tables.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","hash_tables.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","hash_tables.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","hash_tables.scm",1},{NULL}};
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(1),(env)->v_k);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"a");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_2)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_9,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_6,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(2),(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(var_0,(struct obj *)(&(quoted_7b1824f3753c6df8)),var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_10,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(3),(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"a");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_17,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_11(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_14,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));call_closure_two((env)->v_rv_8,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_rv_8)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"a");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_21,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_15(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_18,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_19(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_22,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_19_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_19);
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));call_closure_two((env)->v_rv_13,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_rv_13)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_30,var_1);call_closure_two(var_0,(struct obj *)(&(quoted_7b1824f3753c6df8)),var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_26,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_27(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_27_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_27);
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));call_closure_two((env)->v_rv_18,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_51_env *)(&((var_2)->env)))->v_rv_18)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_51,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_47,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_43,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_39,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_missing)),var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_ref_default_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_35,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_40,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_36_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_36);
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"a");call_closure_two(var_0,var_1,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_41(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_delete_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));call_closure_two((env)->v_rv_25,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_rv_25)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_61,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_59,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_56,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_gone)),var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"a");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_52,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_45(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,hash_table_ref_default_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_48,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_49(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_53,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_49_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_49);
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));call_closure_two((env)->v_rv_31,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_rv_31)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_62,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_60,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_54(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_hash_table_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_57,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_h))->val,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_58,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_54,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_55,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_1)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_50_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_50,var_1);call_closure_two((struct obj *)(&(scm_lambda_49_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_45,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_46_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_46,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_41,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_42_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_42,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_37,var_1);call_closure_two((struct obj *)(&(scm_lambda_36_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_32,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two((struct obj *)(&(scm_lambda_27_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_23,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_24,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_20,var_1);call_closure_two((struct obj *)(&(scm_lambda_19_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_15,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_16,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_11,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_12,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_7,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_h))->val)=(var_0);GC_WRITE_BARRIER((env)->v_h,((struct cell_obj *)((env)->v_h))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_1)->env)))->v_h)=((env)->v_h);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_4,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_lambda_5_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_5,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_CLOSURE_TWO_NEW(var_3,ht_new_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_h)=(var_2);(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "hash_tables.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "hash_tables.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 335 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,107 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_10_env {struct obj *v_k;struct obj *v_rv_2;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_5(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_5_closure;
+struct scm_lambda_14_env {struct obj *v_k_2;struct obj *v_rv_4;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_2;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_8_closure;
+struct scm_lambda_16_env {struct obj *v_k_3;struct obj *v_rv_6;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_3;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_3;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_k_3;};
+static void scm_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_3;};
+static void scm_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_3;struct obj *v_rv_11;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_k_3;};
+static void scm_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_10),"scm_lambda_10","if.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","if.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","if.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","if.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","if.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","if.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","if.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","if.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","if.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","if.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","if.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","if.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","if.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","if.scm",1},{NULL}};
+
+#line 1 "if.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_lambda_10_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_10,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_5(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"good\012");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_4)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_7,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_5_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_5);
+
+#line 1 "if.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));call_closure_two((env)->v_rv_4,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_rv_4)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_14,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"good\012");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_4)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_11,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_8_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_8);
+
+#line 1 "if.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_2,"good\012");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_1,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two((struct obj *)(&(scm_lambda_8_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_6,var_1);call_closure_two((struct obj *)(&(scm_lambda_5_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_4,var_1);call_closure_two((env)->v_rv_11,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_3_env *)(&((var_2)->env)))->v_rv_11)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_3,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "if.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"good\012");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_4)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_2,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "if.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 88 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
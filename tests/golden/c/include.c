@@ -0,0 +1,246 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_square_lambda_1_env {struct obj *v_k;struct obj *v_x;};
+static void scm_square_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_square(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_square_closure;
+struct scm_area_lambda_2_env {struct obj *v_h;struct obj *v_k_2;};
+static void scm_area_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_area_lambda_1_env {struct obj *v_w;};
+static void scm_area_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_area(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_area_closure;
+struct scm_lambda_18_env {struct obj *v_k_4;struct obj *v_rv_4;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_k_4;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_4;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_square_2;};
+static void scm_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_5;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_12_closure;
+struct scm_lambda_29_env {struct obj *v_k_6;struct obj *v_rv_8;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_6;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_6;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_6;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_area;};
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_7;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_20_closure;
+struct scm_square_2_lambda_1_env {struct obj *v_k_8;struct obj *v_x_2;};
+static void scm_square_2_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_square_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_square_2_closure;
+struct scm_cube_lambda_3_env {struct obj *v_k_9;struct obj *v_rv_14;};
+static void scm_cube_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_cube_lambda_2_env {struct obj *v_k_9;struct obj *v_square;struct obj *v_x_3;};
+static void scm_cube_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_cube_lambda_1_env {struct obj *v_x_3;};
+static void scm_cube_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_cube(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_cube_closure;
+struct scm_lambda_33_env {struct obj *v_k_11;struct obj *v_rv_16;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_11;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_11;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_cube;};
+static void scm_lambda_27(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_12;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_12;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_cube;struct obj *v_k_12;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_cube;struct obj *v_k_12;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_area;struct obj *v_cube;struct obj *v_k_12;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_area;struct obj *v_cube;struct obj *v_k_12;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_area;struct obj *v_square_2;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_15;struct obj *v_square_2;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_k_15;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_15;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_15;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_lambda_5_inlined_env {struct obj *v_k_15;};
+static void scm_lambda_5_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_square_lambda_1),"scm_square_lambda_1","include.scm",3},{(void *)(scm_square),"scm_square","include.scm",3},{(void *)(scm_area_lambda_2),"scm_area_lambda_2","include.scm",6},{(void *)(scm_area_lambda_1),"scm_area_lambda_1","include.scm",6},{(void *)(scm_area),"scm_area","include.scm",6},{(void *)(scm_lambda_18),"scm_lambda_18","include.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","include.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","include.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","include.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","include.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","include.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","include.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","include.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","include.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","include.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","include.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","include.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","include.scm",1},{(void *)(scm_square_2_lambda_1),"scm_square_2_lambda_1","include.scm",14},{(void *)(scm_square_2),"scm_square_2","include.scm",14},{(void *)(scm_cube_lambda_3),"scm_cube_lambda_3","include.scm",12},{(void *)(scm_cube_lambda_2),"scm_cube_lambda_2","include.scm",12},{(void *)(scm_cube_lambda_1),"scm_cube_lambda_1","include.scm",12},{(void *)(scm_cube),"scm_cube","include.scm",12},{(void *)(scm_lambda_33),"scm_lambda_33","include.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","include.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","include.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","include.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","include.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","include.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","include.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","include.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","include.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","include.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","include.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","include.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","include.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","include.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","include.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","include.scm",1},{(void *)(scm_lambda_5_inlined),"scm_lambda_5_inlined","include.scm",1},{NULL}};
+
+#line 3 "include.scm"
+static void scm_square_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_square_lambda_1_env *env = (struct scm_square_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_x,(env)->v_k);__builtin_unreachable();}
+
+#line 3 "include.scm"
+static void scm_square(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_square_lambda_1_env );(((struct scm_square_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_square_lambda_1_env *)(&((var_3)->env)))->v_x)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_square_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_square_closure = CLOSURE_TWO_OBJ_INIT(scm_square);
+
+#line 6 "include.scm"
+static void scm_area_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_area_lambda_2_env *env = (struct scm_area_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_h,(env)->v_k_2);__builtin_unreachable();}
+
+#line 6 "include.scm"
+static void scm_area_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_area_lambda_1_env *env = (struct scm_area_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_area_lambda_2_env );(((struct scm_area_lambda_2_env *)(&((var_3)->env)))->v_h)=(var_0);(((struct scm_area_lambda_2_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_area_lambda_2,var_3);call_closure_two(var_2,(env)->v_w,var_4);__builtin_unreachable();}
+
+#line 6 "include.scm"
+static void scm_area(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_area_lambda_1_env );(((struct scm_area_lambda_1_env *)(&((var_2)->env)))->v_w)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_area_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm_area_closure = CLOSURE_TWO_OBJ_INIT(scm_area);
+
+#line 1 "include.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));call_closure_two((env)->v_rv_4,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_rv_4)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_18,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_14,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_2)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_11,var_2);call_closure_two(((struct cell_obj *)((env)->v_square_2))->val,MAKE_FIXNUM(9),var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_12_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_12);
+
+#line 1 "include.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));call_closure_two((env)->v_rv_8,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_rv_8)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_26,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_22,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_2)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_19,var_2);call_closure_two(((struct cell_obj *)((env)->v_area))->val,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_20_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_20);
+
+#line 14 "include.scm"
+static void scm_square_2_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_square_2_lambda_1_env *env = (struct scm_square_2_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_x_2,(env)->v_k_8);__builtin_unreachable();}
+
+#line 14 "include.scm"
+static void scm_square_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_square_2_lambda_1_env );(((struct scm_square_2_lambda_1_env *)(&((var_3)->env)))->v_k_8)=(var_1);(((struct scm_square_2_lambda_1_env *)(&((var_3)->env)))->v_x_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_square_2_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_square_2_closure = CLOSURE_TWO_OBJ_INIT(scm_square_2);
+
+#line 12 "include.scm"
+static void scm_cube_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_cube_lambda_3_env *env = (struct scm_cube_lambda_3_env *)(&((env_in)->env));call_closure_two((env)->v_rv_14,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 12 "include.scm"
+static void scm_cube_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_cube_lambda_2_env *env = (struct scm_cube_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_cube_lambda_3_env );(((struct scm_cube_lambda_3_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_cube_lambda_3_env *)(&((var_1)->env)))->v_rv_14)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_cube_lambda_3,var_1);call_closure_two(((struct cell_obj *)((env)->v_square))->val,(env)->v_x_3,var_2);__builtin_unreachable();}
+
+#line 12 "include.scm"
+static void scm_cube_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_cube_lambda_1_env *env = (struct scm_cube_lambda_1_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);(((struct cell_obj *)(var_2))->val)=((struct obj *)(&(scm_square_2_closure)));GC_WRITE_BARRIER(var_2,((struct cell_obj *)(var_2))->val);OBJECT_CLOSURE_TWO_NEW(var_3,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_cube_lambda_2_env );(((struct scm_cube_lambda_2_env *)(&((var_4)->env)))->v_k_9)=(var_1);(((struct scm_cube_lambda_2_env *)(&((var_4)->env)))->v_square)=(var_2);(((struct scm_cube_lambda_2_env *)(&((var_4)->env)))->v_x_3)=((env)->v_x_3);OBJECT_CLOSURE_ONE_NEW(var_5,scm_cube_lambda_2,var_4);call_closure_two(var_3,(env)->v_x_3,var_5);__builtin_unreachable();}
+
+#line 12 "include.scm"
+static void scm_cube(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_cube_lambda_1_env );(((struct scm_cube_lambda_1_env *)(&((var_2)->env)))->v_x_3)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_cube_lambda_1,var_2);call_closure_two(var_3,NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_cube_closure = CLOSURE_TWO_OBJ_INIT(scm_cube);
+
+#line 1 "include.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));call_closure_two((env)->v_rv_16,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_rv_16)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_33,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_27(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(((struct cell_obj *)((env)->v_cube))->val,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_31,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_cube))->val)=((struct obj *)(&(scm_cube_closure)));GC_WRITE_BARRIER((env)->v_cube,((struct cell_obj *)((env)->v_cube))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_cube)=((env)->v_cube);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_27,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_3)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_28,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_cube)=((env)->v_cube);(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two((struct obj *)(&(scm_lambda_20_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_1)->env)))->v_area)=((env)->v_area);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_16,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_cube)=((env)->v_cube);(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_cube)=((env)->v_cube);(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two((struct obj *)(&(scm_lambda_12_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);(((struct cell_obj *)((env)->v_square_2))->val)=((struct obj *)(&(scm_square_closure)));GC_WRITE_BARRIER((env)->v_square_2,((struct cell_obj *)((env)->v_square_2))->val);(((struct cell_obj *)((env)->v_area))->val)=((struct obj *)(&(scm_area_closure)));GC_WRITE_BARRIER((env)->v_area,((struct cell_obj *)((env)->v_area))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_square_2)=((env)->v_square_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_9,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_5)->env)))->v_area)=((env)->v_area);(((struct scm_lambda_10_env *)(&((var_5)->env)))->v_cube)=(var_2);(((struct scm_lambda_10_env *)(&((var_5)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_10,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_area)=(var_2);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_square_2)=((env)->v_square_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_two(var_4,NULL,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_square_2)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_5_inlined_env );(((struct scm_lambda_5_inlined_env *)(&((var_5)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_5_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "include.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "include.scm"
+static void scm_lambda_5_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_inlined_env *env = (struct scm_lambda_5_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "include.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 227 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,554 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_twice_lambda_6_env {struct obj *v_k;struct obj *v_rv_4;};
+static void scm_twice_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_twice_lambda_5_env {struct obj *v_double;struct obj *v_k;struct obj *v_rv_4;};
+static void scm_twice_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_twice_lambda_4_env {struct obj *v_double;struct obj *v_k;struct obj *v_rv_4;};
+static void scm_twice_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_twice_lambda_3_env {struct obj *v_double;struct obj *v_k;struct obj *v_x;};
+static void scm_twice_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_twice_lambda_2_env {struct obj *v_double;struct obj *v_k;struct obj *v_x;};
+static void scm_twice_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_twice_lambda_1_env {struct obj *v_x;};
+static void scm_twice_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_double_lambda_1_env {struct obj *v_k_2;struct obj *v_y;};
+static void scm_double_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_double(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_double_closure;
+static void scm_twice(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_twice_closure;
+struct scm_lambda_22_env {struct obj *v_k_4;struct obj *v_rv_8;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_k_4;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_4;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_twice;};
+static void scm_lambda_13(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_5;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_16_closure;
+struct scm_shadowed_lambda_5_env {struct obj *v_k_6;struct obj *v_x_2;};
+static void scm_shadowed_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_shadowed_lambda_4_env {struct obj *v_k_6;struct obj *v_x_2;};
+static void scm_shadowed_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_shadowed_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_shadowed_lambda_3_closure;
+struct scm_shadowed_lambda_2_env {struct obj *v_k_7;};
+static void scm_shadowed_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_shadowed_lambda_1_env {struct obj *v_k_7;};
+static void scm_shadowed_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_shadowed(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_shadowed_closure;
+struct scm_lambda_32_env {struct obj *v_k_8;struct obj *v_rv_16;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_8;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_8;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_shadowed;};
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_9;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_26_closure;
+struct scm_same_lambda_2_env {struct obj *v_f;struct obj *v_k_10;};
+static void scm_same_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_same_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_same_lambda_1_closure;
+static void scm_f(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_f_closure;
+static void scm_same(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_same_closure;
+struct scm_lambda_42_env {struct obj *v_k_13;struct obj *v_rv_21;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_13;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_13;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_same;};
+static void scm_lambda_33(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_14;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_36_closure;
+struct scm_fresh_lambda_3_env {struct obj *v_k_15;};
+static void scm_fresh_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_fresh(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_fresh_closure;
+struct scm_lambda_52_env {struct obj *v_k_16;struct obj *v_rv_26;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_16;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_16;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_fresh;};
+static void scm_lambda_43(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_17;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_46(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_46_closure;
+struct scm_adder_lambda_5_env {struct obj *v_k_18;struct obj *v_rv_30;};
+static void scm_adder_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_adder_lambda_4_env {struct obj *v_add;struct obj *v_k_18;};
+static void scm_adder_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_adder_lambda_3_env {struct obj *v_add;struct obj *v_k_18;struct obj *v_n;};
+static void scm_adder_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_adder_lambda_2_env {struct obj *v_add;struct obj *v_k_18;struct obj *v_n;};
+static void scm_adder_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_adder_lambda_1_env {struct obj *v_n;};
+static void scm_adder_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_add_lambda_1_env {struct obj *v_k_19;struct obj *v_n;};
+static void scm_add_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_add_env {struct obj *v_n;};
+static void scm_add(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_adder(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_adder_closure;
+struct scm_lambda_62_env {struct obj *v_k_21;struct obj *v_rv_35;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_21;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_21;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_adder;};
+static void scm_lambda_53(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_22;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_56(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_56_closure;
+static void scm_choose(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_choose_closure;
+struct scm_lambda_72_env {struct obj *v_k_24;struct obj *v_rv_39;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_24;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_k_24;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_choose;};
+static void scm_lambda_63(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_25;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_66(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_66_closure;
+struct scm_lambda_76_env {struct obj *v_k_26;struct obj *v_rv_43;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_26;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_k_26;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_choose;};
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_27;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_27;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_choose;struct obj *v_k_27;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_choose;struct obj *v_k_27;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_choose;struct obj *v_k_27;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_choose;struct obj *v_k_27;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_adder;struct obj *v_choose;struct obj *v_k_27;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_adder;struct obj *v_choose;struct obj *v_k_27;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *parent;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *parent;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_adder;struct obj *v_choose;struct obj *v_fresh;struct obj *v_k_27;struct obj *v_same;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *parent;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *parent;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *parent;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_adder;struct obj *v_choose;struct obj *v_fresh;struct obj *v_k_27;struct obj *v_same;struct obj *v_shadowed;struct obj *v_twice;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_adder;struct obj *v_fresh;struct obj *v_same;struct obj *v_shadowed;struct obj *v_twice;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_fresh;struct obj *v_same;struct obj *v_shadowed;struct obj *v_twice;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_same;struct obj *v_shadowed;struct obj *v_twice;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_shadowed;struct obj *v_twice;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+struct scm_lambda_11_env {struct obj *v_k_33;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_33;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_33;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_33;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_33;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static void scm_make_lambda_1_inlined(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_make_lambda_1_inlined_closure;
+static void scm_make_lambda_1_inlined_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_make_lambda_1_inlined_2_closure;
+struct scm_k_lambda_1_inlined_env {struct obj *v_k_23;};
+static void scm_k_lambda_1_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_k_lambda_1_inlined_2_env {struct obj *v_k_23;};
+static void scm_k_lambda_1_inlined_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_twice_lambda_6),"scm_twice_lambda_6","inline.scm",2},{(void *)(scm_twice_lambda_5),"scm_twice_lambda_5","inline.scm",2},{(void *)(scm_twice_lambda_4),"scm_twice_lambda_4","inline.scm",2},{(void *)(scm_twice_lambda_3),"scm_twice_lambda_3","inline.scm",2},{(void *)(scm_twice_lambda_2),"scm_twice_lambda_2","inline.scm",2},{(void *)(scm_twice_lambda_1),"scm_twice_lambda_1","inline.scm",2},{(void *)(scm_double_lambda_1),"scm_double_lambda_1","inline.scm",3},{(void *)(scm_double),"scm_double","inline.scm",3},{(void *)(scm_twice),"scm_twice","inline.scm",2},{(void *)(scm_lambda_22),"scm_lambda_22","inline.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","inline.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","inline.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","inline.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","inline.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","inline.scm",1},{(void *)(scm_shadowed_lambda_5),"scm_shadowed_lambda_5","inline.scm",8},{(void *)(scm_shadowed_lambda_4),"scm_shadowed_lambda_4","inline.scm",8},{(void *)(scm_shadowed_lambda_3),"scm_shadowed_lambda_3","inline.scm",8},{(void *)(scm_shadowed_lambda_2),"scm_shadowed_lambda_2","inline.scm",8},{(void *)(scm_shadowed_lambda_1),"scm_shadowed_lambda_1","inline.scm",8},{(void *)(scm_shadowed),"scm_shadowed","inline.scm",8},{(void *)(scm_lambda_32),"scm_lambda_32","inline.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","inline.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","inline.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","inline.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","inline.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","inline.scm",1},{(void *)(scm_same_lambda_2),"scm_same_lambda_2","inline.scm",15},{(void *)(scm_same_lambda_1),"scm_same_lambda_1","inline.scm",15},{(void *)(scm_f),"scm_f","inline.scm",16},{(void *)(scm_same),"scm_same","inline.scm",15},{(void *)(scm_lambda_42),"scm_lambda_42","inline.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","inline.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","inline.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","inline.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","inline.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","inline.scm",1},{(void *)(scm_fresh_lambda_3),"scm_fresh_lambda_3","inline.scm",21},{(void *)(scm_fresh),"scm_fresh","inline.scm",21},{(void *)(scm_lambda_52),"scm_lambda_52","inline.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","inline.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","inline.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","inline.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","inline.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","inline.scm",1},{(void *)(scm_adder_lambda_5),"scm_adder_lambda_5","inline.scm",27},{(void *)(scm_adder_lambda_4),"scm_adder_lambda_4","inline.scm",27},{(void *)(scm_adder_lambda_3),"scm_adder_lambda_3","inline.scm",27},{(void *)(scm_adder_lambda_2),"scm_adder_lambda_2","inline.scm",27},{(void *)(scm_adder_lambda_1),"scm_adder_lambda_1","inline.scm",27},{(void *)(scm_add_lambda_1),"scm_add_lambda_1","inline.scm",28},{(void *)(scm_add),"scm_add","inline.scm",28},{(void *)(scm_adder),"scm_adder","inline.scm",27},{(void *)(scm_lambda_62),"scm_lambda_62","inline.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","inline.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","inline.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","inline.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","inline.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","inline.scm",1},{(void *)(scm_choose),"scm_choose","inline.scm",35},{(void *)(scm_lambda_72),"scm_lambda_72","inline.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","inline.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","inline.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","inline.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","inline.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","inline.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","inline.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","inline.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","inline.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","inline.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","inline.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","inline.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","inline.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","inline.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","inline.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","inline.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","inline.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","inline.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","inline.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","inline.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","inline.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","inline.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","inline.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","inline.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","inline.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","inline.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","inline.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","inline.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","inline.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","inline.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","inline.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","inline.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","inline.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","inline.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","inline.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","inline.scm",1},{(void *)(scm_make_lambda_1_inlined),"scm_make_lambda_1_inlined","inline.scm",22},{(void *)(scm_make_lambda_1_inlined_2),"scm_make_lambda_1_inlined_2","inline.scm",22},{(void *)(scm_k_lambda_1_inlined),"scm_k_lambda_1_inlined","inline.scm",35},{(void *)(scm_k_lambda_1_inlined_2),"scm_k_lambda_1_inlined_2","inline.scm",35},{NULL}};
+
+#line 2 "inline.scm"
+static void scm_twice_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_twice_lambda_6_env *env = (struct scm_twice_lambda_6_env *)(&((env_in)->env));call_closure_two((env)->v_rv_4,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 2 "inline.scm"
+static void scm_twice_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_twice_lambda_5_env *env = (struct scm_twice_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_twice_lambda_6_env );(((struct scm_twice_lambda_6_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_twice_lambda_6_env *)(&((var_1)->env)))->v_rv_4)=((env)->v_rv_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_twice_lambda_6,var_1);call_closure_two((env)->v_double,var_0,var_2);__builtin_unreachable();}
+
+#line 2 "inline.scm"
+static void scm_twice_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_twice_lambda_4_env *env = (struct scm_twice_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_twice_lambda_5_env );(((struct scm_twice_lambda_5_env *)(&((var_1)->env)))->v_double)=((env)->v_double);(((struct scm_twice_lambda_5_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_twice_lambda_5_env *)(&((var_1)->env)))->v_rv_4)=((env)->v_rv_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_twice_lambda_5,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 2 "inline.scm"
+static void scm_twice_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_twice_lambda_3_env *env = (struct scm_twice_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_twice_lambda_4_env );(((struct scm_twice_lambda_4_env *)(&((var_2)->env)))->v_double)=((env)->v_double);(((struct scm_twice_lambda_4_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_twice_lambda_4_env *)(&((var_2)->env)))->v_rv_4)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_twice_lambda_4,var_2);call_closure_two(var_1,(env)->v_x,var_3);__builtin_unreachable();}
+
+#line 2 "inline.scm"
+static void scm_twice_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_twice_lambda_2_env *env = (struct scm_twice_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_twice_lambda_3_env );(((struct scm_twice_lambda_3_env *)(&((var_2)->env)))->v_double)=((env)->v_double);(((struct scm_twice_lambda_3_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_twice_lambda_3_env *)(&((var_2)->env)))->v_x)=((env)->v_x);OBJECT_CLOSURE_ONE_NEW(var_3,scm_twice_lambda_3,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 2 "inline.scm"
+static void scm_twice_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_twice_lambda_1_env *env = (struct scm_twice_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_twice_lambda_2_env );(((struct scm_twice_lambda_2_env *)(&((var_2)->env)))->v_double)=(var_0);(((struct scm_twice_lambda_2_env *)(&((var_2)->env)))->v_k)=(var_1);(((struct scm_twice_lambda_2_env *)(&((var_2)->env)))->v_x)=((env)->v_x);OBJECT_CLOSURE_ONE_NEW(var_3,scm_twice_lambda_2,var_2);call_closure_two(var_0,(env)->v_x,var_3);__builtin_unreachable();}
+
+#line 3 "inline.scm"
+static void scm_double_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_double_lambda_1_env *env = (struct scm_double_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_y,(env)->v_k_2);__builtin_unreachable();}
+
+#line 3 "inline.scm"
+static void scm_double(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_double_lambda_1_env );(((struct scm_double_lambda_1_env *)(&((var_3)->env)))->v_k_2)=(var_1);(((struct scm_double_lambda_1_env *)(&((var_3)->env)))->v_y)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_double_lambda_1,var_3);call_closure_two(var_2,MAKE_FIXNUM(2),var_4);__builtin_unreachable();}
+static struct closure_obj scm_double_closure = CLOSURE_TWO_OBJ_INIT(scm_double);
+
+#line 2 "inline.scm"
+static void scm_twice(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_twice_lambda_1_env );(((struct scm_twice_lambda_1_env *)(&((var_2)->env)))->v_x)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_twice_lambda_1,var_2);call_closure_two(var_3,(struct obj *)(&(scm_double_closure)),var_1);__builtin_unreachable();}
+static struct closure_obj scm_twice_closure = CLOSURE_TWO_OBJ_INIT(scm_twice);
+
+#line 1 "inline.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));call_closure_two((env)->v_rv_8,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_rv_8)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_18,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_13(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_2)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_15,var_2);call_closure_two(((struct cell_obj *)((env)->v_twice))->val,MAKE_FIXNUM(5),var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_19,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_16_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_16);
+
+#line 8 "inline.scm"
+static void scm_shadowed_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_shadowed_lambda_5_env *env = (struct scm_shadowed_lambda_5_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_x_2))->val)=(var_0);GC_WRITE_BARRIER((env)->v_x_2,((struct cell_obj *)((env)->v_x_2))->val);call_closure_one((env)->v_k_6,((struct cell_obj *)((env)->v_x_2))->val);__builtin_unreachable();}
+
+#line 8 "inline.scm"
+static void scm_shadowed_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_shadowed_lambda_4_env *env = (struct scm_shadowed_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_shadowed_lambda_5_env );(((struct scm_shadowed_lambda_5_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_shadowed_lambda_5_env *)(&((var_1)->env)))->v_x_2)=((env)->v_x_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_shadowed_lambda_5,var_1);call_closure_two(var_0,MAKE_FIXNUM(10),var_2);__builtin_unreachable();}
+
+#line 8 "inline.scm"
+static void scm_shadowed_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_CLOSURE_TWO_NEW(var_3,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_shadowed_lambda_4_env );(((struct scm_shadowed_lambda_4_env *)(&((var_4)->env)))->v_k_6)=(var_1);(((struct scm_shadowed_lambda_4_env *)(&((var_4)->env)))->v_x_2)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_5,scm_shadowed_lambda_4,var_4);call_closure_two(var_3,((struct cell_obj *)(var_2))->val,var_5);__builtin_unreachable();}
+static struct closure_obj scm_shadowed_lambda_3_closure = CLOSURE_TWO_OBJ_INIT(scm_shadowed_lambda_3);
+
+#line 8 "inline.scm"
+static void scm_shadowed_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_shadowed_lambda_2_env *env = (struct scm_shadowed_lambda_2_env *)(&((env_in)->env));call_closure_two((struct obj *)(&(scm_shadowed_lambda_3_closure)),var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 8 "inline.scm"
+static void scm_shadowed_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_shadowed_lambda_1_env *env = (struct scm_shadowed_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_shadowed_lambda_2_env );(((struct scm_shadowed_lambda_2_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_shadowed_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 8 "inline.scm"
+static void scm_shadowed(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_shadowed_lambda_1_env );(((struct scm_shadowed_lambda_1_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_shadowed_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_shadowed_closure = CLOSURE_TWO_OBJ_INIT(scm_shadowed);
+
+#line 1 "inline.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));call_closure_two((env)->v_rv_16,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_rv_16)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two(((struct cell_obj *)((env)->v_shadowed))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_29,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_26_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_26);
+
+#line 15 "inline.scm"
+static void scm_same_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_same_lambda_2_env *env = (struct scm_same_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_f,(env)->v_k_10);__builtin_unreachable();}
+
+#line 15 "inline.scm"
+static void scm_same_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_same_lambda_2_env );(((struct scm_same_lambda_2_env *)(&((var_3)->env)))->v_f)=(var_0);(((struct scm_same_lambda_2_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_same_lambda_2,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_same_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_same_lambda_1);
+
+#line 16 "inline.scm"
+static void scm_f(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm_f_closure = CLOSURE_TWO_OBJ_INIT(scm_f);
+
+#line 15 "inline.scm"
+static void scm_same(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_same_lambda_1_closure)),(struct obj *)(&(scm_f_closure)),var_1);__builtin_unreachable();}
+static struct closure_obj scm_same_closure = CLOSURE_TWO_OBJ_INIT(scm_same);
+
+#line 1 "inline.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));call_closure_two((env)->v_rv_21,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_rv_21)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_33(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(((struct cell_obj *)((env)->v_same))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_39,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_36_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_36);
+
+#line 21 "inline.scm"
+static void scm_fresh_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_fresh_lambda_3_env *env = (struct scm_fresh_lambda_3_env *)(&((env_in)->env));call_closure_two(var_0,(struct obj *)(&(scm_make_lambda_1_inlined_closure)),(env)->v_k_15);__builtin_unreachable();}
+
+#line 21 "inline.scm"
+static void scm_fresh(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_fresh_lambda_3_env );(((struct scm_fresh_lambda_3_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_fresh_lambda_3,var_3);call_closure_two(var_2,(struct obj *)(&(scm_make_lambda_1_inlined_2_closure)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_fresh_closure = CLOSURE_TWO_OBJ_INIT(scm_fresh);
+
+#line 1 "inline.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));call_closure_two((env)->v_rv_26,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_52_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_52,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_48,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_43(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_2)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_45,var_2);call_closure_two(((struct cell_obj *)((env)->v_fresh))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_46(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_49,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_46_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_46);
+
+#line 27 "inline.scm"
+static void scm_adder_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_adder_lambda_5_env *env = (struct scm_adder_lambda_5_env *)(&((env_in)->env));call_closure_two((env)->v_rv_30,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 27 "inline.scm"
+static void scm_adder_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_adder_lambda_4_env *env = (struct scm_adder_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_adder_lambda_5_env );(((struct scm_adder_lambda_5_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_adder_lambda_5_env *)(&((var_1)->env)))->v_rv_30)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_adder_lambda_5,var_1);call_closure_two((env)->v_add,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 27 "inline.scm"
+static void scm_adder_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_adder_lambda_3_env *env = (struct scm_adder_lambda_3_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_n))->val)=(MAKE_FIXNUM(100));GC_WRITE_BARRIER((env)->v_n,((struct cell_obj *)((env)->v_n))->val);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_adder_lambda_4_env );(((struct scm_adder_lambda_4_env *)(&((var_2)->env)))->v_add)=((env)->v_add);(((struct scm_adder_lambda_4_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_adder_lambda_4,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 27 "inline.scm"
+static void scm_adder_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_adder_lambda_2_env *env = (struct scm_adder_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_adder_lambda_3_env );(((struct scm_adder_lambda_3_env *)(&((var_1)->env)))->v_add)=((env)->v_add);(((struct scm_adder_lambda_3_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_adder_lambda_3_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_adder_lambda_3,var_1);call_closure_two((env)->v_add,var_0,var_2);__builtin_unreachable();}
+
+#line 27 "inline.scm"
+static void scm_adder_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_adder_lambda_1_env *env = (struct scm_adder_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_adder_lambda_2_env );(((struct scm_adder_lambda_2_env *)(&((var_2)->env)))->v_add)=(var_0);(((struct scm_adder_lambda_2_env *)(&((var_2)->env)))->v_k_18)=(var_1);(((struct scm_adder_lambda_2_env *)(&((var_2)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_3,scm_adder_lambda_2,var_2);call_closure_two(var_0,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 28 "inline.scm"
+static void scm_add_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_add_lambda_1_env *env = (struct scm_add_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_n))->val,(env)->v_k_19);__builtin_unreachable();}
+
+#line 28 "inline.scm"
+static void scm_add(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_add_env *env = (struct scm_add_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_add_lambda_1_env );(((struct scm_add_lambda_1_env *)(&((var_3)->env)))->v_k_19)=(var_1);(((struct scm_add_lambda_1_env *)(&((var_3)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_4,scm_add_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 27 "inline.scm"
+static void scm_adder(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_adder_lambda_1_env );(((struct scm_adder_lambda_1_env *)(&((var_3)->env)))->v_n)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_adder_lambda_1,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_add_env );(((struct scm_add_env *)(&((var_5)->env)))->v_n)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_6,scm_add,var_5);call_closure_two(var_4,var_6,var_1);__builtin_unreachable();}
+static struct closure_obj scm_adder_closure = CLOSURE_TWO_OBJ_INIT(scm_adder);
+
+#line 1 "inline.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));call_closure_two((env)->v_rv_35,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_rv_35)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_62,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_58,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_53(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_55,var_2);call_closure_two(((struct cell_obj *)((env)->v_adder))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_56(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_59,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_56_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_56);
+
+#line 35 "inline.scm"
+static void scm_choose(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);if (obj_is_truthy(var_0)){OBJECT_ENV_OBJ_NEW(var_3,struct scm_k_lambda_1_inlined_env );(((struct scm_k_lambda_1_inlined_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_k_lambda_1_inlined,var_3);call_closure_two(var_2,MAKE_FIXNUM(10),var_4);} else {OBJECT_ENV_OBJ_NEW(var_5,struct scm_k_lambda_1_inlined_2_env );(((struct scm_k_lambda_1_inlined_2_env *)(&((var_5)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_6,scm_k_lambda_1_inlined_2,var_5);call_closure_two(var_2,MAKE_FIXNUM(20),var_6);}0;__builtin_unreachable();}
+static struct closure_obj scm_choose_closure = CLOSURE_TWO_OBJ_INIT(scm_choose);
+
+#line 1 "inline.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));call_closure_two((env)->v_rv_39,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_rv_39)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_63(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_65,var_2);call_closure_two(((struct cell_obj *)((env)->v_choose))->val,MAKE_BOOL(1),var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_66(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_69,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_66_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_66);
+
+#line 1 "inline.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));call_closure_two((env)->v_rv_43,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_rv_43)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_76,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_75,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_73,var_2);call_closure_two(((struct cell_obj *)((env)->v_choose))->val,MAKE_BOOL(0),var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_74,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_1)->env)))->v_choose)=((env)->v_choose);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_70,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_3)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_71,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_choose)=((env)->v_choose);(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_67,var_1);call_closure_two((struct obj *)(&(scm_lambda_66_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_choose))->val)=((struct obj *)(&(scm_choose_closure)));GC_WRITE_BARRIER((env)->v_choose,((struct cell_obj *)((env)->v_choose))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_choose)=((env)->v_choose);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_63,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_choose)=((env)->v_choose);(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_1)->env)))->v_choose)=((env)->v_choose);(((struct scm_lambda_57_env *)(&((var_1)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_57,var_1);call_closure_two((struct obj *)(&(scm_lambda_56_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_adder))->val)=((struct obj *)(&(scm_adder_closure)));GC_WRITE_BARRIER((env)->v_adder,((struct cell_obj *)((env)->v_adder))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_adder)=((env)->v_adder);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_53,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_choose)=((env)->v_choose);(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_54,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_adder)=((env)->v_adder);(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_choose)=((env)->v_choose);(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_47,var_1);call_closure_two((struct obj *)(&(scm_lambda_46_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_fresh))->val)=((struct obj *)(&(scm_fresh_closure)));GC_WRITE_BARRIER(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_fresh,((struct cell_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_fresh))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_1)->env)))->v_fresh)=(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_fresh);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_43,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_adder)=(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_adder);(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_choose)=(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_choose);(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_27)=(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_34_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_37,var_1);call_closure_two((struct obj *)(&(scm_lambda_36_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_same))->val)=((struct obj *)(&(scm_same_closure)));GC_WRITE_BARRIER((env)->v_same,((struct cell_obj *)((env)->v_same))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_1)->env)))->v_same)=((env)->v_same);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_33,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_34,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_adder)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_adder);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_choose)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_choose);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_fresh)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_fresh);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_k_27)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_27);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_same)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_same);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_27,var_1);call_closure_two((struct obj *)(&(scm_lambda_26_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_shadowed))->val)=((struct obj *)(&(scm_shadowed_closure)));GC_WRITE_BARRIER(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_shadowed,((struct cell_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_shadowed))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_shadowed)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_shadowed);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_23,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_24,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_17,var_1);call_closure_two((struct obj *)(&(scm_lambda_16_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_choose)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_27)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v_twice))->val)=((struct obj *)(&(scm_twice_closure)));GC_WRITE_BARRIER((env)->v_twice,((struct cell_obj *)((env)->v_twice))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_twice)=((env)->v_twice);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_13,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_14,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_adder)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_adder)=((env)->v_adder);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_fresh)=((env)->v_fresh);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_same)=((env)->v_same);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_shadowed)=((env)->v_shadowed);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_twice)=((env)->v_twice);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_fresh)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_fresh)=((env)->v_fresh);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_same)=((env)->v_same);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_shadowed)=((env)->v_shadowed);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_twice)=((env)->v_twice);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_same)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_same)=((env)->v_same);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_shadowed)=((env)->v_shadowed);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_twice)=((env)->v_twice);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_shadowed)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_shadowed)=((env)->v_shadowed);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_twice)=((env)->v_twice);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_twice)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "inline.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_33);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_2)->env)))->v_k_33)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_3,var_2);call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_3);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 22 "inline.scm"
+static void scm_make_lambda_1_inlined(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm_make_lambda_1_inlined_closure = CLOSURE_TWO_OBJ_INIT(scm_make_lambda_1_inlined);
+
+#line 22 "inline.scm"
+static void scm_make_lambda_1_inlined_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm_make_lambda_1_inlined_2_closure = CLOSURE_TWO_OBJ_INIT(scm_make_lambda_1_inlined_2);
+
+#line 35 "inline.scm"
+static void scm_k_lambda_1_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_k_lambda_1_inlined_env *env = (struct scm_k_lambda_1_inlined_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(1),(env)->v_k_23);__builtin_unreachable();}
+
+#line 35 "inline.scm"
+static void scm_k_lambda_1_inlined_2(struct obj *var_0, struct env_obj *env_in){struct scm_k_lambda_1_inlined_2_env *env = (struct scm_k_lambda_1_inlined_2_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(1),(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "inline.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 535 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,457 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_g_lambda_1_env {struct obj *v_k;struct obj *v_z;};
+static void scm_g_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_g_env {struct obj *v_y;};
+static void scm_g(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_16_env {struct obj *v_k_2;struct obj *v_w;};
+static void scm_f_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_14_env {struct obj *v_g;struct obj *v_w;};
+static void scm_f_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_22_env {struct obj *v_k_3;struct obj *v_rv_4;};
+static void scm_f_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_19_env {struct obj *v_k_3;};
+static void scm_f_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_17_env {struct obj *v_w;};
+static void scm_f_lambda_17(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_23_env {struct obj *v_k_4;};
+static void scm_f_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_f_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_f_lambda_20_closure;
+struct scm_h_lambda_1_env {struct obj *v_k_5;};
+static void scm_h_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_h(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_h_closure;
+struct scm_f_lambda_28_env {struct obj *parent;};
+static void scm_f_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_21_env {struct obj *parent;};
+static void scm_f_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_18_env {struct obj *v_g;struct obj *v_h;struct obj *v_k_6;struct obj *v_v;struct obj *v_w;struct obj *v_x;};
+static void scm_f_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_15_env {struct obj *parent;};
+static void scm_f_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_11_env {struct obj *parent;};
+static void scm_f_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_10_env {struct obj *parent;};
+static void scm_f_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_9_env {struct obj *v_g;struct obj *v_h;struct obj *v_k_6;struct obj *v_v;struct obj *v_w;struct obj *v_x;struct obj *v_y;};
+static void scm_f_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_7_env {struct obj *v_g;struct obj *v_h;struct obj *v_w;struct obj *v_x;struct obj *v_y;};
+static void scm_f_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_5_env {struct obj *v_g;struct obj *v_w;struct obj *v_x;struct obj *v_y;};
+static void scm_f_lambda_5(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_3_env {struct obj *v_g;struct obj *v_x;struct obj *v_y;};
+static void scm_f_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_1_env {struct obj *v_x;struct obj *v_y;};
+static void scm_f_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_8_env {struct obj *v_k_11;};
+static void scm_f_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_6_env {struct obj *v_k_11;};
+static void scm_f_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_4_env {struct obj *v_k_11;};
+static void scm_f_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_2_env {struct obj *v_k_11;};
+static void scm_f_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_env {struct obj *v_x;};
+static void scm_f(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_k_12;struct obj *v_rv_18;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k_12;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_12;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_f;};
+static void scm_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_13;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_10_closure;
+struct scm_lambda_20_env {struct obj *v_k_14;struct obj *v_rv_22;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_14;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_y_3;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_15;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_18_closure;
+struct scm_k_lambda_1_env {struct obj *v_k_16;struct obj *v_z_2;};
+static void scm_k_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_k_env {struct obj *v_y_2;};
+static void scm_k(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_y_2;};
+static void scm_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_24(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_24_closure;
+struct scm_lambda_38_env {struct obj *v_k_20;struct obj *v_rv_26;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_20;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_20;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_20;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_22_closure;
+struct scm_lambda_30_env {struct obj *v_k_21;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_26_closure;
+struct scm_even_lambda_4_env {struct obj *v_k_22;struct obj *v_odd;};
+static void scm_even_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_even_lambda_3_env {struct obj *v_k_22;struct obj *v_odd;};
+static void scm_even_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_even_lambda_2_env {struct obj *v_k_22;struct obj *v_n;struct obj *v_odd;};
+static void scm_even_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_even_lambda_1_env {struct obj *v_k_22;struct obj *v_n;struct obj *v_odd;};
+static void scm_even_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_even_env {struct obj *v_odd;};
+static void scm_even(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_odd_lambda_4_env {struct obj *v_even;struct obj *v_k_23;};
+static void scm_odd_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_odd_lambda_3_env {struct obj *v_even;struct obj *v_k_23;};
+static void scm_odd_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_odd_lambda_2_env {struct obj *v_even;struct obj *v_k_23;struct obj *v_n_2;};
+static void scm_odd_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_odd_lambda_1_env {struct obj *v_even;struct obj *v_k_23;struct obj *v_n_2;};
+static void scm_odd_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_odd_env {struct obj *v_even;};
+static void scm_odd(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_24;struct obj *v_rv_39;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_24;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_24;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_b;struct obj *v_even;};
+static void scm_lambda_44(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_25;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_25;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_b;struct obj *v_even;};
+static void scm_lambda_39(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_b;struct obj *v_k_28;};
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_28;};
+static void scm_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_28;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_28;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_28;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_28;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_28;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_28;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_28;struct obj *v_y_3;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_k_28;struct obj *v_y_3;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_y_3;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_k_30;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_30;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_f_lambda_30_inlined_env {struct obj *v_k_6;struct obj *v_rv_53;};
+static void scm_f_lambda_30_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_f_lambda_29_inlined_env {struct obj *v_g;struct obj *v_k_6;struct obj *v_x;};
+static void scm_f_lambda_29_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_inlined_env {struct obj *v_k_28;};
+static void scm_lambda_37_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_g_lambda_1),"scm_g_lambda_1","internal_defines.scm",4},{(void *)(scm_g),"scm_g","internal_defines.scm",4},{(void *)(scm_f_lambda_16),"scm_f_lambda_16","internal_defines.scm",2},{(void *)(scm_f_lambda_14),"scm_f_lambda_14","internal_defines.scm",2},{(void *)(scm_f_lambda_22),"scm_f_lambda_22","internal_defines.scm",2},{(void *)(scm_f_lambda_19),"scm_f_lambda_19","internal_defines.scm",2},{(void *)(scm_f_lambda_17),"scm_f_lambda_17","internal_defines.scm",2},{(void *)(scm_f_lambda_23),"scm_f_lambda_23","internal_defines.scm",2},{(void *)(scm_f_lambda_20),"scm_f_lambda_20","internal_defines.scm",2},{(void *)(scm_h_lambda_1),"scm_h_lambda_1","internal_defines.scm",7},{(void *)(scm_h),"scm_h","internal_defines.scm",7},{(void *)(scm_f_lambda_28),"scm_f_lambda_28","internal_defines.scm",2},{(void *)(scm_f_lambda_21),"scm_f_lambda_21","internal_defines.scm",2},{(void *)(scm_f_lambda_18),"scm_f_lambda_18","internal_defines.scm",2},{(void *)(scm_f_lambda_15),"scm_f_lambda_15","internal_defines.scm",2},{(void *)(scm_f_lambda_11),"scm_f_lambda_11","internal_defines.scm",2},{(void *)(scm_f_lambda_10),"scm_f_lambda_10","internal_defines.scm",2},{(void *)(scm_f_lambda_9),"scm_f_lambda_9","internal_defines.scm",2},{(void *)(scm_f_lambda_7),"scm_f_lambda_7","internal_defines.scm",2},{(void *)(scm_f_lambda_5),"scm_f_lambda_5","internal_defines.scm",2},{(void *)(scm_f_lambda_3),"scm_f_lambda_3","internal_defines.scm",2},{(void *)(scm_f_lambda_1),"scm_f_lambda_1","internal_defines.scm",2},{(void *)(scm_f_lambda_8),"scm_f_lambda_8","internal_defines.scm",2},{(void *)(scm_f_lambda_6),"scm_f_lambda_6","internal_defines.scm",2},{(void *)(scm_f_lambda_4),"scm_f_lambda_4","internal_defines.scm",2},{(void *)(scm_f_lambda_2),"scm_f_lambda_2","internal_defines.scm",2},{(void *)(scm_f),"scm_f","internal_defines.scm",2},{(void *)(scm_lambda_16),"scm_lambda_16","internal_defines.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","internal_defines.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","internal_defines.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","internal_defines.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","internal_defines.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","internal_defines.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","internal_defines.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","internal_defines.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","internal_defines.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","internal_defines.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","internal_defines.scm",1},{(void *)(scm_k_lambda_1),"scm_k_lambda_1","internal_defines.scm",12},{(void *)(scm_k),"scm_k","internal_defines.scm",12},{(void *)(scm_lambda_28),"scm_lambda_28","internal_defines.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","internal_defines.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","internal_defines.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","internal_defines.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","internal_defines.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","internal_defines.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","internal_defines.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","internal_defines.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","internal_defines.scm",1},{(void *)(scm_even_lambda_4),"scm_even_lambda_4","internal_defines.scm",15},{(void *)(scm_even_lambda_3),"scm_even_lambda_3","internal_defines.scm",15},{(void *)(scm_even_lambda_2),"scm_even_lambda_2","internal_defines.scm",15},{(void *)(scm_even_lambda_1),"scm_even_lambda_1","internal_defines.scm",15},{(void *)(scm_even),"scm_even","internal_defines.scm",15},{(void *)(scm_odd_lambda_4),"scm_odd_lambda_4","internal_defines.scm",16},{(void *)(scm_odd_lambda_3),"scm_odd_lambda_3","internal_defines.scm",16},{(void *)(scm_odd_lambda_2),"scm_odd_lambda_2","internal_defines.scm",16},{(void *)(scm_odd_lambda_1),"scm_odd_lambda_1","internal_defines.scm",16},{(void *)(scm_odd),"scm_odd","internal_defines.scm",16},{(void *)(scm_lambda_49),"scm_lambda_49","internal_defines.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","internal_defines.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","internal_defines.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","internal_defines.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","internal_defines.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","internal_defines.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","internal_defines.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","internal_defines.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","internal_defines.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","internal_defines.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","internal_defines.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","internal_defines.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","internal_defines.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","internal_defines.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","internal_defines.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","internal_defines.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","internal_defines.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","internal_defines.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","internal_defines.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","internal_defines.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","internal_defines.scm",1},{(void *)(scm_f_lambda_30_inlined),"scm_f_lambda_30_inlined","internal_defines.scm",2},{(void *)(scm_f_lambda_29_inlined),"scm_f_lambda_29_inlined","internal_defines.scm",2},{(void *)(scm_lambda_37_inlined),"scm_lambda_37_inlined","internal_defines.scm",1},{NULL}};
+
+#line 4 "internal_defines.scm"
+static void scm_g_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_g_lambda_1_env *env = (struct scm_g_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_z,(env)->v_k);__builtin_unreachable();}
+
+#line 4 "internal_defines.scm"
+static void scm_g(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_g_env *env = (struct scm_g_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_g_lambda_1_env );(((struct scm_g_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_g_lambda_1_env *)(&((var_3)->env)))->v_z)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_g_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_y))->val,var_4);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_16_env *env = (struct scm_f_lambda_16_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_w))->val)=(var_0);GC_WRITE_BARRIER((env)->v_w,((struct cell_obj *)((env)->v_w))->val);call_closure_one((env)->v_k_2,NULL);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_14_env *env = (struct scm_f_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_16_env );(((struct scm_f_lambda_16_env *)(&((var_2)->env)))->v_k_2)=(var_1);(((struct scm_f_lambda_16_env *)(&((var_2)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_16,var_2);call_closure_two(((struct cell_obj *)((env)->v_g))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_22_env *env = (struct scm_f_lambda_22_env *)(&((env_in)->env));call_closure_two((env)->v_rv_4,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_19_env *env = (struct scm_f_lambda_19_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_22_env );(((struct scm_f_lambda_22_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_f_lambda_22_env *)(&((var_2)->env)))->v_rv_4)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_22,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_17(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_17_env *env = (struct scm_f_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_19_env );(((struct scm_f_lambda_19_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_f_lambda_19,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_w))->val,var_4);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_23_env *env = (struct scm_f_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_23_env );(((struct scm_f_lambda_23_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_f_lambda_23,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_f_lambda_20_closure = CLOSURE_TWO_OBJ_INIT(scm_f_lambda_20);
+
+#line 7 "internal_defines.scm"
+static void scm_h_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_h_lambda_1_env *env = (struct scm_h_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(10),(env)->v_k_5);__builtin_unreachable();}
+
+#line 7 "internal_defines.scm"
+static void scm_h(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_h_lambda_1_env );(((struct scm_h_lambda_1_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_h_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_h_closure = CLOSURE_TWO_OBJ_INIT(scm_h);
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_28_env *env = (struct scm_f_lambda_28_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)(((struct scm_f_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)(((struct scm_f_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v,((struct cell_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)(((struct scm_f_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v))->val);OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_29_inlined_env );(((struct scm_f_lambda_29_inlined_env *)(&((var_2)->env)))->v_g)=(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)(((struct scm_f_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_g);(((struct scm_f_lambda_29_inlined_env *)(&((var_2)->env)))->v_k_6)=(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)(((struct scm_f_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_6);(((struct scm_f_lambda_29_inlined_env *)(&((var_2)->env)))->v_x)=(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)(((struct scm_f_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_x);OBJECT_CLOSURE_ONE_NEW(var_3,scm_f_lambda_29_inlined,var_2);call_closure_two(var_1,((struct cell_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)(((struct scm_f_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v))->val,var_3);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_21_env *env = (struct scm_f_lambda_21_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_h))->val)=((struct obj *)(&(scm_h_closure)));GC_WRITE_BARRIER(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_h,((struct cell_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_h))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_28_env );(((struct scm_f_lambda_28_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_28,var_1);call_closure_two(((struct cell_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_h))->val,((struct cell_obj *)(((struct scm_f_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_w))->val,var_2);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_18_env *env = (struct scm_f_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_21_env );(((struct scm_f_lambda_21_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_21,var_1);call_closure_two((struct obj *)(&(scm_f_lambda_20_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_15_env *env = (struct scm_f_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_17_env );(((struct scm_f_lambda_17_env *)(&((var_1)->env)))->v_w)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_w);OBJECT_CLOSURE_TWO_NEW(var_2,scm_f_lambda_17,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_18_env );(((struct scm_f_lambda_18_env *)(&((var_3)->env)))->v_g)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_g);(((struct scm_f_lambda_18_env *)(&((var_3)->env)))->v_h)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_h);(((struct scm_f_lambda_18_env *)(&((var_3)->env)))->v_k_6)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_6);(((struct scm_f_lambda_18_env *)(&((var_3)->env)))->v_v)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_f_lambda_18_env *)(&((var_3)->env)))->v_w)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_w);(((struct scm_f_lambda_18_env *)(&((var_3)->env)))->v_x)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)(((struct scm_f_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_x);OBJECT_CLOSURE_ONE_NEW(var_4,scm_f_lambda_18,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_11_env *env = (struct scm_f_lambda_11_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_y))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_y,((struct cell_obj *)(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_y))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_g_env );(((struct scm_g_env *)(&((var_1)->env)))->v_y)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_y);OBJECT_CLOSURE_TWO_NEW(var_2,scm_g,var_1);(((struct cell_obj *)(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_g))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_g,((struct cell_obj *)(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_g))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_14_env );(((struct scm_f_lambda_14_env *)(&((var_3)->env)))->v_g)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_g);(((struct scm_f_lambda_14_env *)(&((var_3)->env)))->v_w)=(((struct scm_f_lambda_9_env *)(&(((struct env_obj *)(((struct scm_f_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_w);OBJECT_CLOSURE_TWO_NEW(var_4,scm_f_lambda_14,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_f_lambda_15_env );(((struct scm_f_lambda_15_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_f_lambda_15,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_10_env *env = (struct scm_f_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_11_env );(((struct scm_f_lambda_11_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_11,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_9_env *env = (struct scm_f_lambda_9_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_v)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_6)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_3,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_f_lambda_10_env );(((struct scm_f_lambda_10_env *)(&((var_4)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_5,scm_f_lambda_10,var_4);call_closure_two(var_3,(env)->v_x,var_5);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_7_env *env = (struct scm_f_lambda_7_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_h)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_9_env );(((struct scm_f_lambda_9_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_f_lambda_9_env *)(&((var_3)->env)))->v_h)=((env)->v_h);(((struct scm_f_lambda_9_env *)(&((var_3)->env)))->v_w)=((env)->v_w);(((struct scm_f_lambda_9_env *)(&((var_3)->env)))->v_x)=((env)->v_x);(((struct scm_f_lambda_9_env *)(&((var_3)->env)))->v_y)=((env)->v_y);OBJECT_CLOSURE_TWO_NEW(var_4,scm_f_lambda_9,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_5(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_5_env *env = (struct scm_f_lambda_5_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_w)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_7_env );(((struct scm_f_lambda_7_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_f_lambda_7_env *)(&((var_3)->env)))->v_w)=((env)->v_w);(((struct scm_f_lambda_7_env *)(&((var_3)->env)))->v_x)=((env)->v_x);(((struct scm_f_lambda_7_env *)(&((var_3)->env)))->v_y)=((env)->v_y);OBJECT_CLOSURE_TWO_NEW(var_4,scm_f_lambda_7,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_3_env *env = (struct scm_f_lambda_3_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_g)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_5_env );(((struct scm_f_lambda_5_env *)(&((var_3)->env)))->v_g)=((env)->v_g);(((struct scm_f_lambda_5_env *)(&((var_3)->env)))->v_x)=((env)->v_x);(((struct scm_f_lambda_5_env *)(&((var_3)->env)))->v_y)=((env)->v_y);OBJECT_CLOSURE_TWO_NEW(var_4,scm_f_lambda_5,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_lambda_1_env *env = (struct scm_f_lambda_1_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_y)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_lambda_3_env );(((struct scm_f_lambda_3_env *)(&((var_3)->env)))->v_x)=((env)->v_x);(((struct scm_f_lambda_3_env *)(&((var_3)->env)))->v_y)=((env)->v_y);OBJECT_CLOSURE_TWO_NEW(var_4,scm_f_lambda_3,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_8_env *env = (struct scm_f_lambda_8_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_11);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_6_env *env = (struct scm_f_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_8_env );(((struct scm_f_lambda_8_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_8,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_4_env *env = (struct scm_f_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_6_env );(((struct scm_f_lambda_6_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_6,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_2_env *env = (struct scm_f_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_4_env );(((struct scm_f_lambda_4_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_4,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_f_env *env = (struct scm_f_env *)(&((env_in)->env));((env)->v_x)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_f_lambda_1_env );(((struct scm_f_lambda_1_env *)(&((var_2)->env)))->v_x)=((env)->v_x);OBJECT_CLOSURE_TWO_NEW(var_3,scm_f_lambda_1,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_f_lambda_2_env );(((struct scm_f_lambda_2_env *)(&((var_4)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_f_lambda_2,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));call_closure_two((env)->v_rv_18,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_rv_18)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_12,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_2)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_9,var_2);call_closure_two(((struct cell_obj *)((env)->v_f))->val,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_13,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_10_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_10);
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));call_closure_two((env)->v_rv_22,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_rv_22)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_20,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_y_3))->val,var_4);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_18_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_18);
+
+#line 12 "internal_defines.scm"
+static void scm_k_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_k_lambda_1_env *env = (struct scm_k_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_z_2,(env)->v_k_16);__builtin_unreachable();}
+
+#line 12 "internal_defines.scm"
+static void scm_k(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_k_env *env = (struct scm_k_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_k_lambda_1_env );(((struct scm_k_lambda_1_env *)(&((var_3)->env)))->v_k_16)=(var_1);(((struct scm_k_lambda_1_env *)(&((var_3)->env)))->v_z_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_k_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_y_2))->val,var_4);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_y_2))->val)=(MAKE_FIXNUM(5));GC_WRITE_BARRIER((env)->v_y_2,((struct cell_obj *)((env)->v_y_2))->val);OBJECT_ENV_OBJ_NEW(var_2,struct scm_k_env );(((struct scm_k_env *)(&((var_2)->env)))->v_y_2)=((env)->v_y_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_k,var_2);(var_0)=(var_3);call_closure_two(var_0,MAKE_FIXNUM(2),var_1);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_24(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_3)->env)))->v_y_2)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_28,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_24_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_24);
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));call_closure_two((env)->v_rv_26,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_29,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two((struct obj *)(&(scm_lambda_24_closure)),NULL,var_3);__builtin_unreachable();}
+static struct closure_obj scm_lambda_22_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_22);
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_3)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_30,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_26_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_26);
+
+#line 15 "internal_defines.scm"
+static void scm_even_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_even_lambda_4_env *env = (struct scm_even_lambda_4_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_odd))->val,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 15 "internal_defines.scm"
+static void scm_even_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_even_lambda_3_env *env = (struct scm_even_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_even_lambda_4_env );(((struct scm_even_lambda_4_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_even_lambda_4_env *)(&((var_1)->env)))->v_odd)=((env)->v_odd);OBJECT_CLOSURE_ONE_NEW(var_2,scm_even_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 15 "internal_defines.scm"
+static void scm_even_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_even_lambda_2_env *env = (struct scm_even_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_22,MAKE_BOOL(1));} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_even_lambda_3_env );(((struct scm_even_lambda_3_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_even_lambda_3_env *)(&((var_2)->env)))->v_odd)=((env)->v_odd);OBJECT_CLOSURE_ONE_NEW(var_3,scm_even_lambda_3,var_2);call_closure_two(var_1,(env)->v_n,var_3);}0;__builtin_unreachable();}
+
+#line 15 "internal_defines.scm"
+static void scm_even_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_even_lambda_1_env *env = (struct scm_even_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_even_lambda_2_env );(((struct scm_even_lambda_2_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_even_lambda_2_env *)(&((var_1)->env)))->v_n)=((env)->v_n);(((struct scm_even_lambda_2_env *)(&((var_1)->env)))->v_odd)=((env)->v_odd);OBJECT_CLOSURE_ONE_NEW(var_2,scm_even_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 15 "internal_defines.scm"
+static void scm_even(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_even_env *env = (struct scm_even_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_even_lambda_1_env );(((struct scm_even_lambda_1_env *)(&((var_3)->env)))->v_k_22)=(var_1);(((struct scm_even_lambda_1_env *)(&((var_3)->env)))->v_n)=(var_0);(((struct scm_even_lambda_1_env *)(&((var_3)->env)))->v_odd)=((env)->v_odd);OBJECT_CLOSURE_ONE_NEW(var_4,scm_even_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 16 "internal_defines.scm"
+static void scm_odd_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_odd_lambda_4_env *env = (struct scm_odd_lambda_4_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_even))->val,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 16 "internal_defines.scm"
+static void scm_odd_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_odd_lambda_3_env *env = (struct scm_odd_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_odd_lambda_4_env );(((struct scm_odd_lambda_4_env *)(&((var_1)->env)))->v_even)=((env)->v_even);(((struct scm_odd_lambda_4_env *)(&((var_1)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_2,scm_odd_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 16 "internal_defines.scm"
+static void scm_odd_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_odd_lambda_2_env *env = (struct scm_odd_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_23,MAKE_BOOL(0));} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_odd_lambda_3_env );(((struct scm_odd_lambda_3_env *)(&((var_2)->env)))->v_even)=((env)->v_even);(((struct scm_odd_lambda_3_env *)(&((var_2)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_3,scm_odd_lambda_3,var_2);call_closure_two(var_1,(env)->v_n_2,var_3);}0;__builtin_unreachable();}
+
+#line 16 "internal_defines.scm"
+static void scm_odd_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_odd_lambda_1_env *env = (struct scm_odd_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_odd_lambda_2_env );(((struct scm_odd_lambda_2_env *)(&((var_1)->env)))->v_even)=((env)->v_even);(((struct scm_odd_lambda_2_env *)(&((var_1)->env)))->v_k_23)=((env)->v_k_23);(((struct scm_odd_lambda_2_env *)(&((var_1)->env)))->v_n_2)=((env)->v_n_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_odd_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 16 "internal_defines.scm"
+static void scm_odd(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_odd_env *env = (struct scm_odd_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_odd_lambda_1_env );(((struct scm_odd_lambda_1_env *)(&((var_3)->env)))->v_even)=((env)->v_even);(((struct scm_odd_lambda_1_env *)(&((var_3)->env)))->v_k_23)=(var_1);(((struct scm_odd_lambda_1_env *)(&((var_3)->env)))->v_n_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_odd_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));call_closure_two((env)->v_rv_39,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_rv_39)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_49,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_48,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_44(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_2)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_46,var_2);call_closure_two(((struct cell_obj *)((env)->v_even))->val,((struct cell_obj *)((env)->v_b))->val,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_47,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_39(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);(((struct cell_obj *)((env)->v_b))->val)=(MAKE_FIXNUM(2));GC_WRITE_BARRIER((env)->v_b,((struct cell_obj *)((env)->v_b))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_even_env );(((struct scm_even_env *)(&((var_3)->env)))->v_odd)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_even,var_3);(((struct cell_obj *)((env)->v_even))->val)=(var_4);GC_WRITE_BARRIER((env)->v_even,((struct cell_obj *)((env)->v_even))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_odd_env );(((struct scm_odd_env *)(&((var_5)->env)))->v_even)=((env)->v_even);OBJECT_CLOSURE_TWO_NEW(var_6,scm_odd,var_5);(((struct cell_obj *)(var_2))->val)=(var_6);GC_WRITE_BARRIER(var_2,((struct cell_obj *)(var_2))->val);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_7)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_44_env *)(&((var_7)->env)))->v_even)=((env)->v_even);OBJECT_CLOSURE_TWO_NEW(var_8,scm_lambda_44,var_7);OBJECT_ENV_OBJ_NEW(var_9,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_9)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_10,scm_lambda_45,var_9);call_closure_two(var_8,NULL,var_10);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_b)=((env)->v_b);(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_even)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_39,var_3);call_closure_two(var_4,NULL,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_b)=(var_2);(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_36,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_37_inlined_env );(((struct scm_lambda_37_inlined_env *)(&((var_5)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_37_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_37,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_31,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_3)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_32,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_27,var_1);call_closure_two((struct obj *)(&(scm_lambda_26_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_23,var_1);call_closure_two((struct obj *)(&(scm_lambda_22_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_19,var_1);call_closure_two((struct obj *)(&(scm_lambda_18_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_y_3)=((env)->v_y_3);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_14,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_y_3)=((env)->v_y_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two((struct obj *)(&(scm_lambda_10_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);(((struct cell_obj *)((env)->v_y_3))->val)=(MAKE_FIXNUM(100));GC_WRITE_BARRIER((env)->v_y_3,((struct cell_obj *)((env)->v_y_3))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_f_env );OBJECT_CLOSURE_TWO_NEW(var_4,scm_f,var_3);(((struct cell_obj *)(var_2))->val)=(var_4);GC_WRITE_BARRIER(var_2,((struct cell_obj *)(var_2))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_5)->env)))->v_f)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_6,scm_lambda_7,var_5);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_7)->env)))->v_k_28)=(var_1);(((struct scm_lambda_8_env *)(&((var_7)->env)))->v_y_3)=((env)->v_y_3);OBJECT_CLOSURE_ONE_NEW(var_8,scm_lambda_8,var_7);call_closure_two(var_6,NULL,var_8);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_y_3)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_two(var_4,NULL,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_30_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_30_inlined_env *env = (struct scm_f_lambda_30_inlined_env *)(&((env_in)->env));call_closure_two((env)->v_rv_53,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 2 "internal_defines.scm"
+static void scm_f_lambda_29_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_f_lambda_29_inlined_env *env = (struct scm_f_lambda_29_inlined_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_f_lambda_30_inlined_env );(((struct scm_f_lambda_30_inlined_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_f_lambda_30_inlined_env *)(&((var_1)->env)))->v_rv_53)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_f_lambda_30_inlined,var_1);call_closure_two(((struct cell_obj *)((env)->v_g))->val,(env)->v_x,var_2);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+static void scm_lambda_37_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_inlined_env *env = (struct scm_lambda_37_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "internal_defines.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 438 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,449 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_shout_lambda_1_env {struct obj *v_k;};
+static void scm_shout_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_shout(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_shout_closure;
+struct scm_lambda_14_env {struct obj *v_k_2;struct obj *v_rv_3;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_k_2;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_2;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_shout;};
+static void scm_lambda_5(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_3;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_8_closure;
+struct scm_lambda_18_env {struct obj *v_k_4;struct obj *v_rv_7;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_4;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_12_closure;
+struct scm_lambda_19_env {struct obj *v_k_5;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_16_closure;
+struct scm_lambda_25_env {struct obj *v_k_6;struct obj *v_rv_10;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_6;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_20_closure;
+struct scm_lambda_26_env {struct obj *v_k_7;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_23_closure;
+struct scm_lambda_32_env {struct obj *v_k_8;struct obj *v_rv_13;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_8;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_27(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_27_closure;
+struct scm_lambda_33_env {struct obj *v_k_9;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_30(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_30_closure;
+struct scm_lambda_39_env {struct obj *v_k_10;struct obj *v_rv_16;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_10;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_34(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_34_closure;
+struct scm_lambda_40_env {struct obj *v_k_11;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_37_closure;
+struct scm_lambda_56_env {struct obj *v_k_12;struct obj *v_rv_19;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_12;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_12;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_12;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_43(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_13;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_46(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_46_closure;
+struct scm_lambda_57_env {struct obj *v_k_14;struct obj *v_rv_24;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_14;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_50(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_50_closure;
+struct scm_lambda_58_env {struct obj *v_k_15;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_54(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_54_closure;
+struct scm_lambda_72_env {struct obj *v_k_16;struct obj *v_rv_27;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_16;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_16;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_16;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_59(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_59_closure;
+struct scm_lambda_65_env {struct obj *v_k_17;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_62(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_62_closure;
+struct scm_lambda_73_env {struct obj *v_k_18;struct obj *v_rv_32;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_18;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_66(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_66_closure;
+struct scm_lambda_74_env {struct obj *v_k_19;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_70_closure;
+struct scm_lambda_79_env {struct obj *v_k_20;struct obj *v_rv_35;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_k_20;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_75(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_75_closure;
+struct scm_lambda_78_env {struct obj *v_k_21;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_76_env {struct obj *v_k_21;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_21;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_21;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_21;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_21;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_21;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_21;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_21;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_21;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_k_21;struct obj *v__u7ctwo_u20words_u7c;};
+static void scm_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_shout;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_k_23;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_23;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_foo = SYMBOL_OBJ_INIT("foo");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_foo),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_shout_lambda_1),"scm_shout_lambda_1","lexical.scm",5},{(void *)(scm_shout),"scm_shout","lexical.scm",5},{(void *)(scm_lambda_14),"scm_lambda_14","lexical.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","lexical.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","lexical.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","lexical.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","lexical.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","lexical.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","lexical.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","lexical.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","lexical.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","lexical.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","lexical.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","lexical.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","lexical.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","lexical.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","lexical.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","lexical.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","lexical.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","lexical.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","lexical.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","lexical.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","lexical.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","lexical.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","lexical.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","lexical.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","lexical.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","lexical.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","lexical.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","lexical.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","lexical.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","lexical.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","lexical.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","lexical.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","lexical.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","lexical.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","lexical.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","lexical.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","lexical.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","lexical.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","lexical.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","lexical.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","lexical.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","lexical.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","lexical.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","lexical.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","lexical.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","lexical.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","lexical.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","lexical.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","lexical.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","lexical.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","lexical.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","lexical.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","lexical.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","lexical.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","lexical.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","lexical.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","lexical.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","lexical.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","lexical.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","lexical.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","lexical.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","lexical.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","lexical.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","lexical.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","lexical.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","lexical.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","lexical.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","lexical.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","lexical.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","lexical.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","lexical.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","lexical.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","lexical.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","lexical.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","lexical.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","lexical.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","lexical.scm",1},{NULL}};
+
+#line 5 "lexical.scm"
+static void scm_shout_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_shout_lambda_1_env *env = (struct scm_shout_lambda_1_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"!");call_closure_two(var_0,var_1,(env)->v_k);__builtin_unreachable();}
+
+#line 5 "lexical.scm"
+static void scm_shout(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,string_append_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_shout_lambda_1_env );(((struct scm_shout_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_shout_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm_shout_closure = CLOSURE_TWO_OBJ_INIT(scm_shout);
+
+#line 1 "lexical.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_14,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_10,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_5(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_2,"Hi");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_7,var_3);call_closure_two(((struct cell_obj *)((env)->v_shout))->val,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_11,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_8_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_8);
+
+#line 1 "lexical.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));call_closure_two((env)->v_rv_7,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_rv_7)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_18,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,MAKE_BOOL(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_12_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_12);
+
+#line 1 "lexical.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_19,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_16_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_16);
+
+#line 1 "lexical.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));call_closure_two((env)->v_rv_10,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_rv_10)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_22,var_3);call_closure_two(var_2,MAKE_BOOL(0),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_20_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_20);
+
+#line 1 "lexical.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_26,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_23_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_23);
+
+#line 1 "lexical.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));call_closure_two((env)->v_rv_13,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_rv_13)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_27(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_29,var_3);call_closure_two(var_2,MAKE_FIXNUM(46),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_27_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_27);
+
+#line 1 "lexical.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_30(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_30_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_30);
+
+#line 1 "lexical.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));call_closure_two((env)->v_rv_16,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_rv_16)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_34(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_36,var_3);call_closure_two(var_2,MAKE_FIXNUM(47),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_34_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_34);
+
+#line 1 "lexical.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_40,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_37_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_37);
+
+#line 1 "lexical.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));call_closure_two((env)->v_rv_19,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_56_env *)(&((var_2)->env)))->v_rv_19)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_56,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_52,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_48,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_43(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_45,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v__u7ctwo_u20words_u7c))->val,var_4);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_46(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_49,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_46_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_46);
+
+#line 1 "lexical.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));call_closure_two((env)->v_rv_24,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_57_env *)(&((var_2)->env)))->v_rv_24)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_57,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_50(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"a b");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_4)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_53,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_50_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_50);
+
+#line 1 "lexical.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_54(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_58,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_54_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_54);
+
+#line 1 "lexical.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));call_closure_two((env)->v_rv_27,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_64,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_foo)),var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_59(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_61,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_foo)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_59_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_59);
+
+#line 1 "lexical.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_62(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_65,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_62_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_62);
+
+#line 1 "lexical.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));call_closure_two((env)->v_rv_32,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_rv_32)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_73,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_66(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"hello");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_4)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_69,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_66_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_66);
+
+#line 1 "lexical.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_74,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_70_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_70);
+
+#line 1 "lexical.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));call_closure_two((env)->v_rv_35,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_79_env *)(&((var_2)->env)))->v_rv_35)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_79,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_75(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"Hello");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_4)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_77,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_75_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_75);
+
+#line 1 "lexical.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_78,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_76,var_1);call_closure_two((struct obj *)(&(scm_lambda_75_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_71,var_1);call_closure_two((struct obj *)(&(scm_lambda_70_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_67,var_1);call_closure_two((struct obj *)(&(scm_lambda_66_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_63,var_1);call_closure_two((struct obj *)(&(scm_lambda_62_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_60,var_1);call_closure_two((struct obj *)(&(scm_lambda_59_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_55,var_1);call_closure_two((struct obj *)(&(scm_lambda_54_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_51,var_1);call_closure_two((struct obj *)(&(scm_lambda_50_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_47,var_1);call_closure_two((struct obj *)(&(scm_lambda_46_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v__u7ctwo_u20words_u7c))->val)=(MAKE_FIXNUM(2));GC_WRITE_BARRIER((env)->v__u7ctwo_u20words_u7c,((struct cell_obj *)((env)->v__u7ctwo_u20words_u7c))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_43,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_38,var_1);call_closure_two((struct obj *)(&(scm_lambda_37_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_35_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_35,var_1);call_closure_two((struct obj *)(&(scm_lambda_34_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_31_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_31,var_1);call_closure_two((struct obj *)(&(scm_lambda_30_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_28_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two((struct obj *)(&(scm_lambda_27_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_24_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_24,var_1);call_closure_two((struct obj *)(&(scm_lambda_23_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_21_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two((struct obj *)(&(scm_lambda_20_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_17_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_17,var_1);call_closure_two((struct obj *)(&(scm_lambda_16_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_13_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two((struct obj *)(&(scm_lambda_12_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_9_env *)(&((var_1)->env)))->v__u7ctwo_u20words_u7c)=((env)->v__u7ctwo_u20words_u7c);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two((struct obj *)(&(scm_lambda_8_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);(((struct cell_obj *)((env)->v_shout))->val)=((struct obj *)(&(scm_shout_closure)));GC_WRITE_BARRIER((env)->v_shout,((struct cell_obj *)((env)->v_shout))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_3)->env)))->v_shout)=((env)->v_shout);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_5,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_5)->env)))->v_k_21)=(var_1);(((struct scm_lambda_6_env *)(&((var_5)->env)))->v__u7ctwo_u20words_u7c)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_6,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_shout)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_two(var_4,NULL,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "lexical.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "lexical.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 430 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,449 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm__u28util_u20counter_u29_u20make_counter_lambda_4_env {struct obj *v_k;struct obj *v_n;};
+static void scm__u28util_u20counter_u29_u20make_counter_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u28util_u20counter_u29_u20make_counter_lambda_3_env {struct obj *v__u28util_u20counter_u29_u20step;struct obj *v_k;struct obj *v_n;};
+static void scm__u28util_u20counter_u29_u20make_counter_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u28util_u20counter_u29_u20make_counter_lambda_2_env {struct obj *v__u28util_u20counter_u29_u20step;struct obj *v_n;};
+static void scm__u28util_u20counter_u29_u20make_counter_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u28util_u20counter_u29_u20make_counter_lambda_1_env {struct obj *v__u28util_u20counter_u29_u20step;};
+static void scm__u28util_u20counter_u29_u20make_counter_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u28util_u20counter_u29_u20make_counter_env {struct obj *v__u28util_u20counter_u29_u20step;};
+static void scm__u28util_u20counter_u29_u20make_counter(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u28util_u20math_u29_u20square_lambda_1_env {struct obj *v_k_4;struct obj *v_x;};
+static void scm__u28util_u20math_u29_u20square_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u28util_u20math_u29_u20square(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u28util_u20math_u29_u20square_closure;
+struct scm__u28util_u20math_u29_u20twice_lambda_2_env {struct obj *v_f;struct obj *v_k_5;};
+static void scm__u28util_u20math_u29_u20twice_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm__u28util_u20math_u29_u20twice_lambda_1_env {struct obj *v_f;};
+static void scm__u28util_u20math_u29_u20twice_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u28util_u20math_u29_u20twice(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u28util_u20math_u29_u20twice_closure;
+struct scm_lambda_36_env {struct obj *v_k_7;struct obj *v_rv_6;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_7;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_7;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v__u28util_u20counter_u29_u20step;struct obj *v_k_7;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v__u28util_u20counter_u29_u20step;struct obj *v__u28util_u20math_u29_u20step;};
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_8;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_26_closure;
+struct scm_lambda_33_env {struct obj *v_c;struct obj *v_k_9;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v__u28util_u20counter_u29_u20make_counter;struct obj *v_c;};
+static void scm_lambda_30(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_10;struct obj *v_rv_12;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_k_10;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_10;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_c;};
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_11;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_40(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_40_closure;
+struct scm_lambda_50_env {struct obj *v_k_12;struct obj *v_rv_16;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_12;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v__u28util_u20counter_u29_u20step;};
+static void scm_lambda_44(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_13;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_48(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_48_closure;
+struct scm_lambda_61_env {struct obj *v_k_14;struct obj *v_rv_19;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_14;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_14;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v__u28util_u20math_u29_u20square;};
+static void scm_lambda_52(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_15;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_55(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_55_closure;
+struct scm_lambda_72_env {struct obj *v_k_16;struct obj *v_rv_23;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_16;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_k_16;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_16;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v__u28util_u20math_u29_u20square;struct obj *v__u28util_u20math_u29_u20twice;};
+static void scm_lambda_59(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_k_17;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_63(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_63_closure;
+struct scm_lambda_76_env {struct obj *v_k_18;struct obj *v_rv_28;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_k_18;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_step;};
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_k_19;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_74(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_74_closure;
+struct scm_lambda_82_env {struct obj *v_k_20;struct obj *v_rv_31;};
+static void scm_lambda_82(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_k_20;};
+static void scm_lambda_80(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_78(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_78_closure;
+struct scm_lambda_81_env {struct obj *v_k_21;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_21;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_21;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_21;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_21;struct obj *v_step;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_21;struct obj *v_step;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *parent;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *parent;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v__u28util_u20math_u29_u20square;struct obj *v__u28util_u20math_u29_u20twice;struct obj *v_k_21;struct obj *v_step;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *parent;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *parent;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *parent;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v__u28util_u20counter_u29_u20step;struct obj *v__u28util_u20math_u29_u20square;struct obj *v__u28util_u20math_u29_u20twice;struct obj *v_c;struct obj *v_k_21;struct obj *v_step;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *parent;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *parent;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *parent;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v__u28util_u20counter_u29_u20make_counter;struct obj *v__u28util_u20counter_u29_u20step;struct obj *v__u28util_u20math_u29_u20square;struct obj *v__u28util_u20math_u29_u20step;struct obj *v__u28util_u20math_u29_u20twice;struct obj *v_c;struct obj *v_k_21;struct obj *v_step;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v__u28util_u20counter_u29_u20make_counter;struct obj *v__u28util_u20counter_u29_u20step;struct obj *v__u28util_u20math_u29_u20square;struct obj *v__u28util_u20math_u29_u20step;struct obj *v__u28util_u20math_u29_u20twice;struct obj *v_c;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v__u28util_u20counter_u29_u20make_counter;struct obj *v__u28util_u20counter_u29_u20step;struct obj *v__u28util_u20math_u29_u20square;struct obj *v__u28util_u20math_u29_u20step;struct obj *v__u28util_u20math_u29_u20twice;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v__u28util_u20counter_u29_u20make_counter;struct obj *v__u28util_u20counter_u29_u20step;struct obj *v__u28util_u20math_u29_u20square;struct obj *v__u28util_u20math_u29_u20step;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v__u28util_u20counter_u29_u20make_counter;struct obj *v__u28util_u20counter_u29_u20step;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v__u28util_u20counter_u29_u20make_counter;struct obj *v__u28util_u20counter_u29_u20step;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v__u28util_u20counter_u29_u20step;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_28;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_28;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_28;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_28;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_28;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_28;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm__u28util_u20counter_u29_u20make_counter_lambda_4),"scm__u28util_u20counter_u29_u20make_counter_lambda_4","libraries.scm",9},{(void *)(scm__u28util_u20counter_u29_u20make_counter_lambda_3),"scm__u28util_u20counter_u29_u20make_counter_lambda_3","libraries.scm",9},{(void *)(scm__u28util_u20counter_u29_u20make_counter_lambda_2),"scm__u28util_u20counter_u29_u20make_counter_lambda_2","libraries.scm",9},{(void *)(scm__u28util_u20counter_u29_u20make_counter_lambda_1),"scm__u28util_u20counter_u29_u20make_counter_lambda_1","libraries.scm",7},{(void *)(scm__u28util_u20counter_u29_u20make_counter),"scm__u28util_u20counter_u29_u20make_counter","libraries.scm",7},{(void *)(scm__u28util_u20math_u29_u20square_lambda_1),"scm__u28util_u20math_u29_u20square_lambda_1","libraries.scm",16},{(void *)(scm__u28util_u20math_u29_u20square),"scm__u28util_u20math_u29_u20square","libraries.scm",16},{(void *)(scm__u28util_u20math_u29_u20twice_lambda_2),"scm__u28util_u20math_u29_u20twice_lambda_2","libraries.scm",17},{(void *)(scm__u28util_u20math_u29_u20twice_lambda_1),"scm__u28util_u20math_u29_u20twice_lambda_1","libraries.scm",17},{(void *)(scm__u28util_u20math_u29_u20twice),"scm__u28util_u20math_u29_u20twice","libraries.scm",17},{(void *)(scm_lambda_36),"scm_lambda_36","libraries.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","libraries.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","libraries.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","libraries.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","libraries.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","libraries.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","libraries.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","libraries.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","libraries.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","libraries.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","libraries.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","libraries.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","libraries.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","libraries.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","libraries.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","libraries.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","libraries.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","libraries.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","libraries.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","libraries.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","libraries.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","libraries.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","libraries.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","libraries.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","libraries.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","libraries.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","libraries.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","libraries.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","libraries.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","libraries.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","libraries.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","libraries.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","libraries.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","libraries.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","libraries.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","libraries.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","libraries.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","libraries.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","libraries.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","libraries.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","libraries.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","libraries.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","libraries.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","libraries.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","libraries.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","libraries.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","libraries.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","libraries.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","libraries.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","libraries.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","libraries.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","libraries.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","libraries.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","libraries.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","libraries.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","libraries.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","libraries.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","libraries.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","libraries.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","libraries.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","libraries.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","libraries.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","libraries.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","libraries.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","libraries.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","libraries.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","libraries.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","libraries.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","libraries.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","libraries.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","libraries.scm",1},{NULL}};
+
+#line 9 "libraries.scm"
+static void scm__u28util_u20counter_u29_u20make_counter_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm__u28util_u20counter_u29_u20make_counter_lambda_4_env *env = (struct scm__u28util_u20counter_u29_u20make_counter_lambda_4_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_n))->val)=(var_0);GC_WRITE_BARRIER((env)->v_n,((struct cell_obj *)((env)->v_n))->val);call_closure_one((env)->v_k,((struct cell_obj *)((env)->v_n))->val);__builtin_unreachable();}
+
+#line 9 "libraries.scm"
+static void scm__u28util_u20counter_u29_u20make_counter_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm__u28util_u20counter_u29_u20make_counter_lambda_3_env *env = (struct scm__u28util_u20counter_u29_u20make_counter_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm__u28util_u20counter_u29_u20make_counter_lambda_4_env );(((struct scm__u28util_u20counter_u29_u20make_counter_lambda_4_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm__u28util_u20counter_u29_u20make_counter_lambda_4_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm__u28util_u20counter_u29_u20make_counter_lambda_4,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v__u28util_u20counter_u29_u20step))->val,var_2);__builtin_unreachable();}
+
+#line 9 "libraries.scm"
+static void scm__u28util_u20counter_u29_u20make_counter_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u28util_u20counter_u29_u20make_counter_lambda_2_env *env = (struct scm__u28util_u20counter_u29_u20make_counter_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u28util_u20counter_u29_u20make_counter_lambda_3_env );(((struct scm__u28util_u20counter_u29_u20make_counter_lambda_3_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);(((struct scm__u28util_u20counter_u29_u20make_counter_lambda_3_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm__u28util_u20counter_u29_u20make_counter_lambda_3_env *)(&((var_3)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_4,scm__u28util_u20counter_u29_u20make_counter_lambda_3,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_n))->val,var_4);__builtin_unreachable();}
+
+#line 7 "libraries.scm"
+static void scm__u28util_u20counter_u29_u20make_counter_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u28util_u20counter_u29_u20make_counter_lambda_1_env *env = (struct scm__u28util_u20counter_u29_u20make_counter_lambda_1_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u28util_u20counter_u29_u20make_counter_lambda_2_env );(((struct scm__u28util_u20counter_u29_u20make_counter_lambda_2_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);(((struct scm__u28util_u20counter_u29_u20make_counter_lambda_2_env *)(&((var_3)->env)))->v_n)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm__u28util_u20counter_u29_u20make_counter_lambda_2,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 7 "libraries.scm"
+static void scm__u28util_u20counter_u29_u20make_counter(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u28util_u20counter_u29_u20make_counter_env *env = (struct scm__u28util_u20counter_u29_u20make_counter_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm__u28util_u20counter_u29_u20make_counter_lambda_1_env );(((struct scm__u28util_u20counter_u29_u20make_counter_lambda_1_env *)(&((var_2)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);OBJECT_CLOSURE_TWO_NEW(var_3,scm__u28util_u20counter_u29_u20make_counter_lambda_1,var_2);call_closure_two(var_3,MAKE_FIXNUM(0),var_1);__builtin_unreachable();}
+
+#line 16 "libraries.scm"
+static void scm__u28util_u20math_u29_u20square_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm__u28util_u20math_u29_u20square_lambda_1_env *env = (struct scm__u28util_u20math_u29_u20square_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_x,(env)->v_k_4);__builtin_unreachable();}
+
+#line 16 "libraries.scm"
+static void scm__u28util_u20math_u29_u20square(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u28util_u20math_u29_u20square_lambda_1_env );(((struct scm__u28util_u20math_u29_u20square_lambda_1_env *)(&((var_3)->env)))->v_k_4)=(var_1);(((struct scm__u28util_u20math_u29_u20square_lambda_1_env *)(&((var_3)->env)))->v_x)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm__u28util_u20math_u29_u20square_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm__u28util_u20math_u29_u20square_closure = CLOSURE_TWO_OBJ_INIT(scm__u28util_u20math_u29_u20square);
+
+#line 17 "libraries.scm"
+static void scm__u28util_u20math_u29_u20twice_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm__u28util_u20math_u29_u20twice_lambda_2_env *env = (struct scm__u28util_u20math_u29_u20twice_lambda_2_env *)(&((env_in)->env));call_closure_two((env)->v_f,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 17 "libraries.scm"
+static void scm__u28util_u20math_u29_u20twice_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm__u28util_u20math_u29_u20twice_lambda_1_env *env = (struct scm__u28util_u20math_u29_u20twice_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm__u28util_u20math_u29_u20twice_lambda_2_env );(((struct scm__u28util_u20math_u29_u20twice_lambda_2_env *)(&((var_2)->env)))->v_f)=((env)->v_f);(((struct scm__u28util_u20math_u29_u20twice_lambda_2_env *)(&((var_2)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm__u28util_u20math_u29_u20twice_lambda_2,var_2);call_closure_two((env)->v_f,var_0,var_3);__builtin_unreachable();}
+
+#line 17 "libraries.scm"
+static void scm__u28util_u20math_u29_u20twice(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm__u28util_u20math_u29_u20twice_lambda_1_env );(((struct scm__u28util_u20math_u29_u20twice_lambda_1_env *)(&((var_2)->env)))->v_f)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm__u28util_u20math_u29_u20twice_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm__u28util_u20math_u29_u20twice_closure = CLOSURE_TWO_OBJ_INIT(scm__u28util_u20math_u29_u20twice);
+
+#line 1 "libraries.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_36,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v__u28util_u20counter_u29_u20step))->val,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v__u28util_u20math_u29_u20step))->val,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_29,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_26_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_26);
+
+#line 1 "libraries.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_c))->val)=(var_0);GC_WRITE_BARRIER((env)->v_c,((struct cell_obj *)((env)->v_c))->val);call_closure_one((env)->v_k_9,NULL);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_30(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_33,var_2);call_closure_two(((struct cell_obj *)((env)->v__u28util_u20counter_u29_u20make_counter))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));call_closure_two((env)->v_rv_12,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_46_env *)(&((var_2)->env)))->v_rv_12)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_46,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(((struct cell_obj *)((env)->v_c))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_40(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_43,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_40_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_40);
+
+#line 1 "libraries.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));call_closure_two((env)->v_rv_16,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_rv_16)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_50,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_44(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_47,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v__u28util_u20counter_u29_u20step))->val,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_48(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_51,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_48_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_48);
+
+#line 1 "libraries.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));call_closure_two((env)->v_rv_19,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_rv_19)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_61,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_57,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_52(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_2)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_54,var_2);call_closure_two(((struct cell_obj *)((env)->v__u28util_u20math_u29_u20square))->val,MAKE_FIXNUM(7),var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_55(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_58,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_55_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_55);
+
+#line 1 "libraries.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));call_closure_two((env)->v_rv_23,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_rv_23)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_69,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_65,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_59(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_62,var_2);call_closure_two(((struct cell_obj *)((env)->v__u28util_u20math_u29_u20twice))->val,((struct cell_obj *)((env)->v__u28util_u20math_u29_u20square))->val,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_63(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_66,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_63_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_63);
+
+#line 1 "libraries.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));call_closure_two((env)->v_rv_28,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_rv_28)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_76,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_73,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_step))->val,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_74(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_77,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_74_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_74);
+
+#line 1 "libraries.scm"
+static void scm_lambda_82(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));call_closure_two((env)->v_rv_31,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_80(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_82_env *)(&((var_2)->env)))->v_rv_31)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_82,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_78(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_80,var_3);call_closure_two(var_2,MAKE_FIXNUM(5),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_78_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_78);
+
+#line 1 "libraries.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_81,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_79,var_1);call_closure_two((struct obj *)(&(scm_lambda_78_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_75,var_1);call_closure_two((struct obj *)(&(scm_lambda_74_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_step))->val)=(MAKE_FIXNUM(100));GC_WRITE_BARRIER((env)->v_step,((struct cell_obj *)((env)->v_step))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_1)->env)))->v_step)=((env)->v_step);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_70,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_3)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_71,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_64_env *)(&((var_1)->env)))->v_step)=((env)->v_step);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_64,var_1);call_closure_two((struct obj *)(&(scm_lambda_63_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_1)->env)))->v__u28util_u20math_u29_u20square)=(((struct scm_lambda_49_env *)(&(((struct env_obj *)(((struct scm_lambda_53_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u28util_u20math_u29_u20square);(((struct scm_lambda_59_env *)(&((var_1)->env)))->v__u28util_u20math_u29_u20twice)=(((struct scm_lambda_49_env *)(&(((struct env_obj *)(((struct scm_lambda_53_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u28util_u20math_u29_u20twice);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_59,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_3)->env)))->v_k_21)=(((struct scm_lambda_49_env *)(&(((struct env_obj *)(((struct scm_lambda_53_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_21);(((struct scm_lambda_60_env *)(&((var_3)->env)))->v_step)=(((struct scm_lambda_49_env *)(&(((struct env_obj *)(((struct scm_lambda_53_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_step);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_60,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_56,var_1);call_closure_two((struct obj *)(&(scm_lambda_55_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_1)->env)))->v__u28util_u20math_u29_u20square)=((env)->v__u28util_u20math_u29_u20square);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_52,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_53,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_1)->env)))->v__u28util_u20math_u29_u20square)=(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u28util_u20math_u29_u20square);(((struct scm_lambda_49_env *)(&((var_1)->env)))->v__u28util_u20math_u29_u20twice)=(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u28util_u20math_u29_u20twice);(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_k_21)=(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_21);(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_step)=(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_41_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_step);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_49,var_1);call_closure_two((struct obj *)(&(scm_lambda_48_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_1)->env)))->v__u28util_u20counter_u29_u20step)=(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u28util_u20counter_u29_u20step);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_44,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_45,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_41,var_1);call_closure_two((struct obj *)(&(scm_lambda_40_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_c)=((env)->v_c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_37,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_38,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->v__u28util_u20counter_u29_u20step)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u28util_u20counter_u29_u20step);(((struct scm_lambda_35_env *)(&((var_1)->env)))->v__u28util_u20math_u29_u20square)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u28util_u20math_u29_u20square);(((struct scm_lambda_35_env *)(&((var_1)->env)))->v__u28util_u20math_u29_u20twice)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v__u28util_u20math_u29_u20twice);(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_c)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c);(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_k_21)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_21);(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_step)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_step);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_35,var_1);call_closure_two(((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c))->val,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v__u28util_u20counter_u29_u20make_counter)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u28util_u20counter_u29_u20make_counter);(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_c)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_24_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_30,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_27,var_1);call_closure_two((struct obj *)(&(scm_lambda_26_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_step)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_21)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v__u28util_u20counter_u29_u20step))->val)=(MAKE_FIXNUM(1));GC_WRITE_BARRIER((env)->v__u28util_u20counter_u29_u20step,((struct cell_obj *)((env)->v__u28util_u20counter_u29_u20step))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u28util_u20counter_u29_u20make_counter_env );(((struct scm__u28util_u20counter_u29_u20make_counter_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);OBJECT_CLOSURE_TWO_NEW(var_4,scm__u28util_u20counter_u29_u20make_counter,var_3);(((struct cell_obj *)((env)->v__u28util_u20counter_u29_u20make_counter))->val)=(var_4);GC_WRITE_BARRIER((env)->v__u28util_u20counter_u29_u20make_counter,((struct cell_obj *)((env)->v__u28util_u20counter_u29_u20make_counter))->val);(((struct cell_obj *)((env)->v__u28util_u20math_u29_u20step))->val)=(MAKE_FIXNUM(10));GC_WRITE_BARRIER((env)->v__u28util_u20math_u29_u20step,((struct cell_obj *)((env)->v__u28util_u20math_u29_u20step))->val);(((struct cell_obj *)((env)->v__u28util_u20math_u29_u20square))->val)=((struct obj *)(&(scm__u28util_u20math_u29_u20square_closure)));GC_WRITE_BARRIER((env)->v__u28util_u20math_u29_u20square,((struct cell_obj *)((env)->v__u28util_u20math_u29_u20square))->val);(((struct cell_obj *)((env)->v__u28util_u20math_u29_u20twice))->val)=((struct obj *)(&(scm__u28util_u20math_u29_u20twice_closure)));GC_WRITE_BARRIER((env)->v__u28util_u20math_u29_u20twice,((struct cell_obj *)((env)->v__u28util_u20math_u29_u20twice))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_5)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);(((struct scm_lambda_23_env *)(&((var_5)->env)))->v__u28util_u20math_u29_u20step)=((env)->v__u28util_u20math_u29_u20step);OBJECT_CLOSURE_TWO_NEW(var_6,scm_lambda_23,var_5);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_7)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_8,scm_lambda_24,var_7);call_closure_two(var_6,NULL,var_8);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_c)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20make_counter)=((env)->v__u28util_u20counter_u29_u20make_counter);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20square)=((env)->v__u28util_u20math_u29_u20square);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20step)=((env)->v__u28util_u20math_u29_u20step);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20twice)=((env)->v__u28util_u20math_u29_u20twice);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_c)=((env)->v_c);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_14,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u28util_u20math_u29_u20twice)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20make_counter)=((env)->v__u28util_u20counter_u29_u20make_counter);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20square)=((env)->v__u28util_u20math_u29_u20square);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20step)=((env)->v__u28util_u20math_u29_u20step);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20twice)=((env)->v__u28util_u20math_u29_u20twice);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u28util_u20math_u29_u20square)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20make_counter)=((env)->v__u28util_u20counter_u29_u20make_counter);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20square)=((env)->v__u28util_u20math_u29_u20square);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20step)=((env)->v__u28util_u20math_u29_u20step);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20make_counter)=((env)->v__u28util_u20counter_u29_u20make_counter);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u28util_u20math_u29_u20step)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u28util_u20counter_u29_u20make_counter)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20make_counter)=((env)->v__u28util_u20counter_u29_u20make_counter);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v__u28util_u20counter_u29_u20step)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v__u28util_u20counter_u29_u20step)=((env)->v__u28util_u20counter_u29_u20step);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_28)=((env)->v_k_28);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "libraries.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_28)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "libraries.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 430 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,361 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_make_counter_lambda_4_env {struct obj *v_k;struct obj *v_n;};
+static void scm_make_counter_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_counter_lambda_3_env {struct obj *v_k;struct obj *v_n;};
+static void scm_make_counter_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_counter_lambda_2_env {struct obj *v_n;};
+static void scm_make_counter_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_make_counter_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_make_counter_lambda_1_closure;
+static void scm_make_counter(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_make_counter_closure;
+struct scm_lambda_11_env {struct obj *v_c;struct obj *v_k_4;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_c;struct obj *v_make_counter;};
+static void scm_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_5;struct obj *v_rv_5;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_5;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_k_5;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_c;};
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_6;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_19(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_19_closure;
+struct scm_sum_to_lambda_11_env {struct obj *v_i;struct obj *v_k_7;};
+static void scm_sum_to_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_8_env {struct obj *v_i;struct obj *v_k_7;};
+static void scm_sum_to_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_6_env {struct obj *v_i;struct obj *v_limit;};
+static void scm_sum_to_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_15_env {struct obj *v_k_8;struct obj *v_rv_11;};
+static void scm_sum_to_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_12_env {struct obj *v_k_8;};
+static void scm_sum_to_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_9_env {struct obj *v_i;};
+static void scm_sum_to_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_16_env {struct obj *v_k_9;};
+static void scm_sum_to_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_sum_to_lambda_13(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_sum_to_lambda_13_closure;
+struct scm_sum_to_lambda_14_env {struct obj *v_k_10;struct obj *v_total;};
+static void scm_sum_to_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_10_env {struct obj *v_k_10;struct obj *v_total;};
+static void scm_sum_to_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_7_env {struct obj *v_i;struct obj *v_k_10;struct obj *v_total;};
+static void scm_sum_to_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_5_env {struct obj *parent;};
+static void scm_sum_to_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_4_env {struct obj *parent;};
+static void scm_sum_to_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_3_env {struct obj *v_i;struct obj *v_k_10;struct obj *v_limit;struct obj *v_total;};
+static void scm_sum_to_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_1_env {struct obj *v_k_12;struct obj *v_limit;struct obj *v_total;};
+static void scm_sum_to_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_2_env {struct obj *v_k_12;};
+static void scm_sum_to_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_env {struct obj *v_limit;};
+static void scm_sum_to(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_13;struct obj *v_rv_20;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_13;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_13;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_sum_to;};
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_14;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_29(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_29_closure;
+struct scm_adder_lambda_2_env {struct obj *v_k_15;struct obj *v_y;};
+static void scm_adder_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_adder_lambda_1_env {struct obj *v_x;};
+static void scm_adder_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_adder(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_adder_closure;
+struct scm_lambda_42_env {struct obj *v_k_17;struct obj *v_rv_25;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_17;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_17;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_17;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_adder;};
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_18;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_18;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_adder;struct obj *v_k_18;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_adder;struct obj *v_k_18;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_adder;struct obj *v_k_18;struct obj *v_sum_to;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_adder;struct obj *v_k_18;struct obj *v_sum_to;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *parent;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *parent;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *parent;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_adder;struct obj *v_c;struct obj *v_k_18;struct obj *v_make_counter;struct obj *v_sum_to;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_c;struct obj *v_k_22;struct obj *v_make_counter;struct obj *v_sum_to;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_c;struct obj *v_k_22;struct obj *v_make_counter;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_k_22;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_22;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_22;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_22;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_lambda_7_inlined_env {struct obj *v_k_22;};
+static void scm_lambda_7_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_inlined_env {struct obj *v_k_22;};
+static void scm_lambda_5_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_inlined_inlined_env {struct obj *v_k_22;};
+static void scm_lambda_7_inlined_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_make_counter_lambda_4),"scm_make_counter_lambda_4","mutation.scm",4},{(void *)(scm_make_counter_lambda_3),"scm_make_counter_lambda_3","mutation.scm",4},{(void *)(scm_make_counter_lambda_2),"scm_make_counter_lambda_2","mutation.scm",4},{(void *)(scm_make_counter_lambda_1),"scm_make_counter_lambda_1","mutation.scm",2},{(void *)(scm_make_counter),"scm_make_counter","mutation.scm",2},{(void *)(scm_lambda_11),"scm_lambda_11","mutation.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","mutation.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","mutation.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","mutation.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","mutation.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","mutation.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","mutation.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","mutation.scm",1},{(void *)(scm_sum_to_lambda_11),"scm_sum_to_lambda_11","mutation.scm",12},{(void *)(scm_sum_to_lambda_8),"scm_sum_to_lambda_8","mutation.scm",12},{(void *)(scm_sum_to_lambda_6),"scm_sum_to_lambda_6","mutation.scm",12},{(void *)(scm_sum_to_lambda_15),"scm_sum_to_lambda_15","mutation.scm",12},{(void *)(scm_sum_to_lambda_12),"scm_sum_to_lambda_12","mutation.scm",12},{(void *)(scm_sum_to_lambda_9),"scm_sum_to_lambda_9","mutation.scm",12},{(void *)(scm_sum_to_lambda_16),"scm_sum_to_lambda_16","mutation.scm",12},{(void *)(scm_sum_to_lambda_13),"scm_sum_to_lambda_13","mutation.scm",12},{(void *)(scm_sum_to_lambda_14),"scm_sum_to_lambda_14","mutation.scm",12},{(void *)(scm_sum_to_lambda_10),"scm_sum_to_lambda_10","mutation.scm",12},{(void *)(scm_sum_to_lambda_7),"scm_sum_to_lambda_7","mutation.scm",12},{(void *)(scm_sum_to_lambda_5),"scm_sum_to_lambda_5","mutation.scm",12},{(void *)(scm_sum_to_lambda_4),"scm_sum_to_lambda_4","mutation.scm",12},{(void *)(scm_sum_to_lambda_3),"scm_sum_to_lambda_3","mutation.scm",12},{(void *)(scm_sum_to_lambda_1),"scm_sum_to_lambda_1","mutation.scm",12},{(void *)(scm_sum_to_lambda_2),"scm_sum_to_lambda_2","mutation.scm",12},{(void *)(scm_sum_to),"scm_sum_to","mutation.scm",12},{(void *)(scm_lambda_35),"scm_lambda_35","mutation.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","mutation.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","mutation.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","mutation.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","mutation.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","mutation.scm",1},{(void *)(scm_adder_lambda_2),"scm_adder_lambda_2","mutation.scm",19},{(void *)(scm_adder_lambda_1),"scm_adder_lambda_1","mutation.scm",19},{(void *)(scm_adder),"scm_adder","mutation.scm",19},{(void *)(scm_lambda_42),"scm_lambda_42","mutation.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","mutation.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","mutation.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","mutation.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","mutation.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","mutation.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","mutation.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","mutation.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","mutation.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","mutation.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","mutation.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","mutation.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","mutation.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","mutation.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","mutation.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","mutation.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","mutation.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","mutation.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","mutation.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","mutation.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","mutation.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","mutation.scm",1},{(void *)(scm_lambda_7_inlined),"scm_lambda_7_inlined","mutation.scm",1},{(void *)(scm_lambda_5_inlined),"scm_lambda_5_inlined","mutation.scm",1},{(void *)(scm_lambda_7_inlined_inlined),"scm_lambda_7_inlined_inlined","mutation.scm",1},{NULL}};
+
+#line 4 "mutation.scm"
+static void scm_make_counter_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_make_counter_lambda_4_env *env = (struct scm_make_counter_lambda_4_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_n))->val)=(var_0);GC_WRITE_BARRIER((env)->v_n,((struct cell_obj *)((env)->v_n))->val);call_closure_one((env)->v_k,((struct cell_obj *)((env)->v_n))->val);__builtin_unreachable();}
+
+#line 4 "mutation.scm"
+static void scm_make_counter_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_make_counter_lambda_3_env *env = (struct scm_make_counter_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_make_counter_lambda_4_env );(((struct scm_make_counter_lambda_4_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_make_counter_lambda_4_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_make_counter_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 4 "mutation.scm"
+static void scm_make_counter_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_make_counter_lambda_2_env *env = (struct scm_make_counter_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_make_counter_lambda_3_env );(((struct scm_make_counter_lambda_3_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_make_counter_lambda_3_env *)(&((var_3)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_4,scm_make_counter_lambda_3,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_n))->val,var_4);__builtin_unreachable();}
+
+#line 2 "mutation.scm"
+static void scm_make_counter_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_make_counter_lambda_2_env );(((struct scm_make_counter_lambda_2_env *)(&((var_3)->env)))->v_n)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_make_counter_lambda_2,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+static struct closure_obj scm_make_counter_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_make_counter_lambda_1);
+
+#line 2 "mutation.scm"
+static void scm_make_counter(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_make_counter_lambda_1_closure)),MAKE_FIXNUM(0),var_1);__builtin_unreachable();}
+static struct closure_obj scm_make_counter_closure = CLOSURE_TWO_OBJ_INIT(scm_make_counter);
+
+#line 1 "mutation.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_c))->val)=(var_0);GC_WRITE_BARRIER((env)->v_c,((struct cell_obj *)((env)->v_c))->val);call_closure_one((env)->v_k_4,NULL);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_2)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_11_env *)(&((var_2)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_11,var_2);call_closure_two(((struct cell_obj *)((env)->v_make_counter))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));call_closure_two((env)->v_rv_5,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_rv_5)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_21,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_18,var_2);call_closure_two(((struct cell_obj *)((env)->v_c))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_19(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_22,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_19_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_19);
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_11_env *env = (struct scm_sum_to_lambda_11_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_i))->val)=(var_0);GC_WRITE_BARRIER((env)->v_i,((struct cell_obj *)((env)->v_i))->val);call_closure_one((env)->v_k_7,NULL);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_8_env *env = (struct scm_sum_to_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_lambda_11_env );(((struct scm_sum_to_lambda_11_env *)(&((var_1)->env)))->v_i)=((env)->v_i);(((struct scm_sum_to_lambda_11_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_sum_to_lambda_11,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_sum_to_lambda_6_env *env = (struct scm_sum_to_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_sum_to_lambda_8_env );(((struct scm_sum_to_lambda_8_env *)(&((var_3)->env)))->v_i)=((env)->v_i);(((struct scm_sum_to_lambda_8_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_sum_to_lambda_8,var_3);call_closure_two(var_2,(env)->v_limit,var_4);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_15_env *env = (struct scm_sum_to_lambda_15_env *)(&((env_in)->env));call_closure_two((env)->v_rv_11,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_12_env *env = (struct scm_sum_to_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_sum_to_lambda_15_env );(((struct scm_sum_to_lambda_15_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_sum_to_lambda_15_env *)(&((var_2)->env)))->v_rv_11)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_sum_to_lambda_15,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_sum_to_lambda_9_env *env = (struct scm_sum_to_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_sum_to_lambda_12_env );(((struct scm_sum_to_lambda_12_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_sum_to_lambda_12,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_i))->val,var_4);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_16_env *env = (struct scm_sum_to_lambda_16_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_13(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_sum_to_lambda_16_env );(((struct scm_sum_to_lambda_16_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_sum_to_lambda_16,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_sum_to_lambda_13_closure = CLOSURE_TWO_OBJ_INIT(scm_sum_to_lambda_13);
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_14_env *env = (struct scm_sum_to_lambda_14_env *)(&((env_in)->env));call_closure_one((env)->v_k_10,((struct cell_obj *)((env)->v_total))->val);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_10_env *env = (struct scm_sum_to_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_lambda_14_env );(((struct scm_sum_to_lambda_14_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_sum_to_lambda_14_env *)(&((var_1)->env)))->v_total)=((env)->v_total);OBJECT_CLOSURE_ONE_NEW(var_2,scm_sum_to_lambda_14,var_1);call_closure_two((struct obj *)(&(scm_sum_to_lambda_13_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_7_env *env = (struct scm_sum_to_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_lambda_9_env );(((struct scm_sum_to_lambda_9_env *)(&((var_1)->env)))->v_i)=((env)->v_i);OBJECT_CLOSURE_TWO_NEW(var_2,scm_sum_to_lambda_9,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_sum_to_lambda_10_env );(((struct scm_sum_to_lambda_10_env *)(&((var_3)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_sum_to_lambda_10_env *)(&((var_3)->env)))->v_total)=((env)->v_total);OBJECT_CLOSURE_ONE_NEW(var_4,scm_sum_to_lambda_10,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_5_env *env = (struct scm_sum_to_lambda_5_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_total))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_total,((struct cell_obj *)(((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_total))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_lambda_6_env );(((struct scm_sum_to_lambda_6_env *)(&((var_1)->env)))->v_i)=(((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_i);(((struct scm_sum_to_lambda_6_env *)(&((var_1)->env)))->v_limit)=(((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_limit);OBJECT_CLOSURE_TWO_NEW(var_2,scm_sum_to_lambda_6,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_sum_to_lambda_7_env );(((struct scm_sum_to_lambda_7_env *)(&((var_3)->env)))->v_i)=(((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_i);(((struct scm_sum_to_lambda_7_env *)(&((var_3)->env)))->v_k_10)=(((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_10);(((struct scm_sum_to_lambda_7_env *)(&((var_3)->env)))->v_total)=(((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_total);OBJECT_CLOSURE_ONE_NEW(var_4,scm_sum_to_lambda_7,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_4_env *env = (struct scm_sum_to_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_lambda_5_env );(((struct scm_sum_to_lambda_5_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_sum_to_lambda_5,var_1);call_closure_two(var_0,((struct scm_sum_to_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_limit,var_2);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_sum_to_lambda_3_env *env = (struct scm_sum_to_lambda_3_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_i)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_10)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_3,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_sum_to_lambda_4_env );(((struct scm_sum_to_lambda_4_env *)(&((var_4)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_5,scm_sum_to_lambda_4,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_total))->val,var_5);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_sum_to_lambda_1_env *env = (struct scm_sum_to_lambda_1_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_total)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_sum_to_lambda_3_env );(((struct scm_sum_to_lambda_3_env *)(&((var_3)->env)))->v_limit)=((env)->v_limit);(((struct scm_sum_to_lambda_3_env *)(&((var_3)->env)))->v_total)=((env)->v_total);OBJECT_CLOSURE_TWO_NEW(var_4,scm_sum_to_lambda_3,var_3);call_closure_two(var_4,MAKE_FIXNUM(0),(env)->v_k_12);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_2_env *env = (struct scm_sum_to_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(0),(env)->v_k_12);__builtin_unreachable();}
+
+#line 12 "mutation.scm"
+static void scm_sum_to(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_sum_to_env *env = (struct scm_sum_to_env *)(&((env_in)->env));((env)->v_limit)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_sum_to_lambda_1_env );(((struct scm_sum_to_lambda_1_env *)(&((var_2)->env)))->v_k_12)=(var_1);(((struct scm_sum_to_lambda_1_env *)(&((var_2)->env)))->v_limit)=((env)->v_limit);OBJECT_CLOSURE_TWO_NEW(var_3,scm_sum_to_lambda_1,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_sum_to_lambda_2_env );(((struct scm_sum_to_lambda_2_env *)(&((var_4)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_sum_to_lambda_2,var_4);call_closure_two(var_3,MAKE_FIXNUM(0),var_5);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));call_closure_two((env)->v_rv_20,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_rv_20)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_31,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28,var_2);call_closure_two(((struct cell_obj *)((env)->v_sum_to))->val,MAKE_FIXNUM(10),var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_29(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_32,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_29_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_29);
+
+#line 19 "mutation.scm"
+static void scm_adder_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_adder_lambda_2_env *env = (struct scm_adder_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v_y,(env)->v_k_15);__builtin_unreachable();}
+
+#line 19 "mutation.scm"
+static void scm_adder_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_adder_lambda_1_env *env = (struct scm_adder_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_adder_lambda_2_env );(((struct scm_adder_lambda_2_env *)(&((var_3)->env)))->v_k_15)=(var_1);(((struct scm_adder_lambda_2_env *)(&((var_3)->env)))->v_y)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_adder_lambda_2,var_3);call_closure_two(var_2,(env)->v_x,var_4);__builtin_unreachable();}
+
+#line 19 "mutation.scm"
+static void scm_adder(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_adder_lambda_1_env );(((struct scm_adder_lambda_1_env *)(&((var_2)->env)))->v_x)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_adder_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+static struct closure_obj scm_adder_closure = CLOSURE_TWO_OBJ_INIT(scm_adder);
+
+#line 1 "mutation.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));call_closure_two((env)->v_rv_25,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_rv_25)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_41,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_40,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(((struct cell_obj *)((env)->v_adder))->val,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_adder))->val)=((struct obj *)(&(scm_adder_closure)));GC_WRITE_BARRIER((env)->v_adder,((struct cell_obj *)((env)->v_adder))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_1)->env)))->v_adder)=((env)->v_adder);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_36,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_37,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_adder)=((env)->v_adder);(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_30,var_1);call_closure_two((struct obj *)(&(scm_lambda_29_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_env );OBJECT_CLOSURE_TWO_NEW(var_2,scm_sum_to,var_1);(((struct cell_obj *)((env)->v_sum_to))->val)=(var_2);GC_WRITE_BARRIER((env)->v_sum_to,((struct cell_obj *)((env)->v_sum_to))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_3)->env)))->v_sum_to)=((env)->v_sum_to);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_26,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_5)->env)))->v_adder)=((env)->v_adder);(((struct scm_lambda_27_env *)(&((var_5)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_27,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_adder)=((env)->v_adder);(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_sum_to)=((env)->v_sum_to);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_20,var_1);call_closure_two((struct obj *)(&(scm_lambda_19_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_1)->env)))->v_c)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_c);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_16,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_adder)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_adder);(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_18)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_18);(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_sum_to)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_13_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum_to);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_15,var_1);call_closure_two(((struct cell_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_c))->val,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(((struct cell_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_c))->val,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_adder)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_18)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v_make_counter))->val)=((struct obj *)(&(scm_make_counter_closure)));GC_WRITE_BARRIER((env)->v_make_counter,((struct cell_obj *)((env)->v_make_counter))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_make_counter)=((env)->v_make_counter);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_9,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_10,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_sum_to)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_make_counter)=((env)->v_make_counter);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_sum_to)=((env)->v_sum_to);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_4,NULL,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_c)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_c)=((env)->v_c);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_make_counter)=((env)->v_make_counter);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_7_inlined_inlined_env );(((struct scm_lambda_7_inlined_inlined_env *)(&((var_5)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_7_inlined_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_make_counter)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_5_inlined_env );(((struct scm_lambda_5_inlined_env *)(&((var_5)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_5_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "mutation.scm"
+static void scm_lambda_7_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_inlined_env *env = (struct scm_lambda_7_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_5_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_inlined_env *env = (struct scm_lambda_5_inlined_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_inlined_env );(((struct scm_lambda_7_inlined_env *)(&((var_1)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7_inlined,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+static void scm_lambda_7_inlined_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_inlined_inlined_env *env = (struct scm_lambda_7_inlined_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "mutation.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 342 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,595 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_my_even_p_lambda_4_env {struct obj *v_k;struct obj *v_my_odd_p;};
+static void scm_my_even_p_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_even_p_lambda_3_env {struct obj *v_k;struct obj *v_my_odd_p;};
+static void scm_my_even_p_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_even_p_lambda_2_env {struct obj *v_k;struct obj *v_my_odd_p;struct obj *v_n;};
+static void scm_my_even_p_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_even_p_lambda_1_env {struct obj *v_k;struct obj *v_my_odd_p;struct obj *v_n;};
+static void scm_my_even_p_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_even_p_env {struct obj *v_my_odd_p;};
+static void scm_my_even_p(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_odd_p_lambda_4_env {struct obj *v_k_2;struct obj *v_my_even_p;};
+static void scm_my_odd_p_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_odd_p_lambda_3_env {struct obj *v_k_2;struct obj *v_my_even_p;};
+static void scm_my_odd_p_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_odd_p_lambda_2_env {struct obj *v_k_2;struct obj *v_my_even_p;struct obj *v_n_2;};
+static void scm_my_odd_p_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_odd_p_lambda_1_env {struct obj *v_k_2;struct obj *v_my_even_p;struct obj *v_n_2;};
+static void scm_my_odd_p_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_my_odd_p_env {struct obj *v_my_even_p;};
+static void scm_my_odd_p(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_3;struct obj *v_rv_10;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_3;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_3;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_my_even_p;};
+static void scm_lambda_17(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_4;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_20_closure;
+struct scm_lambda_34_env {struct obj *v_k_5;struct obj *v_rv_14;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_5;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_5;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_my_odd_p;};
+static void scm_lambda_24(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_6;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_28_closure;
+struct scm_lambda_42_env {struct obj *v_k_7;struct obj *v_rv_18;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_7;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_7;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_my_even_p;};
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_8;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_36_closure;
+struct scm_count_down_lambda_6_env {struct obj *v_k_9;struct obj *v_rv_24;};
+static void scm_count_down_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_lambda_5_env {struct obj *v_count_up;struct obj *v_k_9;struct obj *v_rv_24;};
+static void scm_count_down_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_lambda_4_env {struct obj *v_count_up;struct obj *v_k_9;struct obj *v_rv_24;};
+static void scm_count_down_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_lambda_3_env {struct obj *v_count_up;struct obj *v_k_9;struct obj *v_n_3;};
+static void scm_count_down_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_lambda_2_env {struct obj *v_count_up;struct obj *v_k_9;struct obj *v_n_3;};
+static void scm_count_down_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_lambda_1_env {struct obj *v_count_up;struct obj *v_k_9;struct obj *v_n_3;};
+static void scm_count_down_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_env {struct obj *v_count_up;};
+static void scm_count_down(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_8_env {struct obj *v_k_10;struct obj *v_rv_30;};
+static void scm_count_up_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_7_env {struct obj *v_count_down;struct obj *v_k_10;struct obj *v_rv_30;};
+static void scm_count_up_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_6_env {struct obj *v_count_down;struct obj *v_k_10;struct obj *v_rv_30;};
+static void scm_count_up_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_5_env {struct obj *v_count_down;struct obj *v_k_10;struct obj *v_n_4;};
+static void scm_count_up_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_4_env {struct obj *v_count_down;struct obj *v_k_10;struct obj *v_n_4;};
+static void scm_count_up_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_3_env {struct obj *v_count_down;struct obj *v_k_10;struct obj *v_n_4;};
+static void scm_count_up_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_2_env {struct obj *v_count_down;struct obj *v_k_10;struct obj *v_n_4;};
+static void scm_count_up_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_lambda_1_env {struct obj *v_count_down;struct obj *v_k_10;struct obj *v_n_4;};
+static void scm_count_up_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_up_env {struct obj *v_count_down;};
+static void scm_count_up(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_11;struct obj *v_rv_36;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_k_11;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_11;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_count_down;};
+static void scm_lambda_45(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_12;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_48(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_48_closure;
+struct scm_first_env {struct obj *v_second;};
+static void scm_first(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_second(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_second_closure;
+struct scm_lambda_66_env {struct obj *v_k_15;struct obj *v_rv_40;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_15;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_15;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_first;};
+static void scm_lambda_57(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_16;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_60(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_60_closure;
+struct scm_ping_lambda_4_env {struct obj *v_k_17;struct obj *v_pong;};
+static void scm_ping_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_ping_lambda_3_env {struct obj *v_k_17;struct obj *v_pong;};
+static void scm_ping_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_ping_lambda_2_env {struct obj *v_k_17;struct obj *v_n_5;struct obj *v_pong;};
+static void scm_ping_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_ping_lambda_1_env {struct obj *v_k_17;struct obj *v_n_5;struct obj *v_pong;};
+static void scm_ping_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_ping_env {struct obj *v_pong;};
+static void scm_ping(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pong_lambda_4_env {struct obj *v_k_18;struct obj *v_ping;};
+static void scm_pong_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pong_lambda_3_env {struct obj *v_k_18;struct obj *v_ping;};
+static void scm_pong_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pong_lambda_2_env {struct obj *v_k_18;struct obj *v_n_6;struct obj *v_ping;};
+static void scm_pong_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pong_lambda_1_env {struct obj *v_k_18;struct obj *v_n_6;struct obj *v_ping;};
+static void scm_pong_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_pong_env {struct obj *v_ping;};
+static void scm_pong(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_outer_lambda_3_env {struct obj *v_n_7;struct obj *v_ping;};
+static void scm_outer_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_outer_lambda_1_env {struct obj *v_k_21;struct obj *v_n_7;};
+static void scm_outer_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_outer_lambda_2_env {struct obj *v_k_21;};
+static void scm_outer_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_outer(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_outer_closure;
+struct scm_lambda_76_env {struct obj *v_k_22;struct obj *v_rv_53;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_k_22;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_22;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_outer;};
+static void scm_lambda_67(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_k_23;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_70_closure;
+struct scm_lambda_80_env {struct obj *v_k_24;struct obj *v_rv_57;};
+static void scm_lambda_80(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_24;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_k_24;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_outer;};
+static void scm_lambda_74(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *v_k_25;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_25;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_25;struct obj *v_outer;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_25;struct obj *v_outer;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_25;struct obj *v_outer;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_25;struct obj *v_outer;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_first;struct obj *v_k_25;struct obj *v_outer;struct obj *v_second;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *parent;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *parent;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *parent;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_count_down;struct obj *v_count_up;struct obj *v_first;struct obj *v_k_25;struct obj *v_my_even_p;struct obj *v_outer;struct obj *v_second;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *parent;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *parent;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *parent;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_count_down;struct obj *v_count_up;struct obj *v_first;struct obj *v_k_25;struct obj *v_my_even_p;struct obj *v_my_odd_p;struct obj *v_outer;struct obj *v_second;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_count_down;struct obj *v_count_up;struct obj *v_first;struct obj *v_my_even_p;struct obj *v_my_odd_p;struct obj *v_second;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_count_down;struct obj *v_count_up;struct obj *v_first;struct obj *v_my_even_p;struct obj *v_my_odd_p;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_count_down;struct obj *v_count_up;struct obj *v_my_even_p;struct obj *v_my_odd_p;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_count_down;struct obj *v_my_even_p;struct obj *v_my_odd_p;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_my_even_p;struct obj *v_my_odd_p;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_my_even_p;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_32;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_32;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_32;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_32;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_32;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_32;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_ping = SYMBOL_OBJ_INIT("ping");
+static struct symbol_obj symbol_pong = SYMBOL_OBJ_INIT("pong");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_ping),&(symbol_pong),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_my_even_p_lambda_4),"scm_my_even_p_lambda_4","mutual_recursion.scm",1},{(void *)(scm_my_even_p_lambda_3),"scm_my_even_p_lambda_3","mutual_recursion.scm",1},{(void *)(scm_my_even_p_lambda_2),"scm_my_even_p_lambda_2","mutual_recursion.scm",1},{(void *)(scm_my_even_p_lambda_1),"scm_my_even_p_lambda_1","mutual_recursion.scm",1},{(void *)(scm_my_even_p),"scm_my_even_p","mutual_recursion.scm",1},{(void *)(scm_my_odd_p_lambda_4),"scm_my_odd_p_lambda_4","mutual_recursion.scm",4},{(void *)(scm_my_odd_p_lambda_3),"scm_my_odd_p_lambda_3","mutual_recursion.scm",4},{(void *)(scm_my_odd_p_lambda_2),"scm_my_odd_p_lambda_2","mutual_recursion.scm",4},{(void *)(scm_my_odd_p_lambda_1),"scm_my_odd_p_lambda_1","mutual_recursion.scm",4},{(void *)(scm_my_odd_p),"scm_my_odd_p","mutual_recursion.scm",4},{(void *)(scm_lambda_26),"scm_lambda_26","mutual_recursion.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","mutual_recursion.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","mutual_recursion.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","mutual_recursion.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","mutual_recursion.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","mutual_recursion.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","mutual_recursion.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","mutual_recursion.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","mutual_recursion.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","mutual_recursion.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","mutual_recursion.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","mutual_recursion.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","mutual_recursion.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","mutual_recursion.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","mutual_recursion.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","mutual_recursion.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","mutual_recursion.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","mutual_recursion.scm",1},{(void *)(scm_count_down_lambda_6),"scm_count_down_lambda_6","mutual_recursion.scm",11},{(void *)(scm_count_down_lambda_5),"scm_count_down_lambda_5","mutual_recursion.scm",11},{(void *)(scm_count_down_lambda_4),"scm_count_down_lambda_4","mutual_recursion.scm",11},{(void *)(scm_count_down_lambda_3),"scm_count_down_lambda_3","mutual_recursion.scm",11},{(void *)(scm_count_down_lambda_2),"scm_count_down_lambda_2","mutual_recursion.scm",11},{(void *)(scm_count_down_lambda_1),"scm_count_down_lambda_1","mutual_recursion.scm",11},{(void *)(scm_count_down),"scm_count_down","mutual_recursion.scm",11},{(void *)(scm_count_up_lambda_8),"scm_count_up_lambda_8","mutual_recursion.scm",14},{(void *)(scm_count_up_lambda_7),"scm_count_up_lambda_7","mutual_recursion.scm",14},{(void *)(scm_count_up_lambda_6),"scm_count_up_lambda_6","mutual_recursion.scm",14},{(void *)(scm_count_up_lambda_5),"scm_count_up_lambda_5","mutual_recursion.scm",14},{(void *)(scm_count_up_lambda_4),"scm_count_up_lambda_4","mutual_recursion.scm",14},{(void *)(scm_count_up_lambda_3),"scm_count_up_lambda_3","mutual_recursion.scm",14},{(void *)(scm_count_up_lambda_2),"scm_count_up_lambda_2","mutual_recursion.scm",14},{(void *)(scm_count_up_lambda_1),"scm_count_up_lambda_1","mutual_recursion.scm",14},{(void *)(scm_count_up),"scm_count_up","mutual_recursion.scm",14},{(void *)(scm_lambda_54),"scm_lambda_54","mutual_recursion.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","mutual_recursion.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","mutual_recursion.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","mutual_recursion.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","mutual_recursion.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","mutual_recursion.scm",1},{(void *)(scm_first),"scm_first","mutual_recursion.scm",19},{(void *)(scm_second),"scm_second","mutual_recursion.scm",20},{(void *)(scm_lambda_66),"scm_lambda_66","mutual_recursion.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","mutual_recursion.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","mutual_recursion.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","mutual_recursion.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","mutual_recursion.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","mutual_recursion.scm",1},{(void *)(scm_ping_lambda_4),"scm_ping_lambda_4","mutual_recursion.scm",24},{(void *)(scm_ping_lambda_3),"scm_ping_lambda_3","mutual_recursion.scm",24},{(void *)(scm_ping_lambda_2),"scm_ping_lambda_2","mutual_recursion.scm",24},{(void *)(scm_ping_lambda_1),"scm_ping_lambda_1","mutual_recursion.scm",24},{(void *)(scm_ping),"scm_ping","mutual_recursion.scm",24},{(void *)(scm_pong_lambda_4),"scm_pong_lambda_4","mutual_recursion.scm",25},{(void *)(scm_pong_lambda_3),"scm_pong_lambda_3","mutual_recursion.scm",25},{(void *)(scm_pong_lambda_2),"scm_pong_lambda_2","mutual_recursion.scm",25},{(void *)(scm_pong_lambda_1),"scm_pong_lambda_1","mutual_recursion.scm",25},{(void *)(scm_pong),"scm_pong","mutual_recursion.scm",25},{(void *)(scm_outer_lambda_3),"scm_outer_lambda_3","mutual_recursion.scm",23},{(void *)(scm_outer_lambda_1),"scm_outer_lambda_1","mutual_recursion.scm",23},{(void *)(scm_outer_lambda_2),"scm_outer_lambda_2","mutual_recursion.scm",23},{(void *)(scm_outer),"scm_outer","mutual_recursion.scm",23},{(void *)(scm_lambda_76),"scm_lambda_76","mutual_recursion.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","mutual_recursion.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","mutual_recursion.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","mutual_recursion.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","mutual_recursion.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","mutual_recursion.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","mutual_recursion.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","mutual_recursion.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","mutual_recursion.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","mutual_recursion.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","mutual_recursion.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","mutual_recursion.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","mutual_recursion.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","mutual_recursion.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","mutual_recursion.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","mutual_recursion.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","mutual_recursion.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","mutual_recursion.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","mutual_recursion.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","mutual_recursion.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","mutual_recursion.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","mutual_recursion.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","mutual_recursion.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","mutual_recursion.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","mutual_recursion.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","mutual_recursion.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","mutual_recursion.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","mutual_recursion.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","mutual_recursion.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","mutual_recursion.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","mutual_recursion.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","mutual_recursion.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","mutual_recursion.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","mutual_recursion.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","mutual_recursion.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","mutual_recursion.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","mutual_recursion.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","mutual_recursion.scm",1},{NULL}};
+
+#line 1 "mutual_recursion.scm"
+static void scm_my_even_p_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_my_even_p_lambda_4_env *env = (struct scm_my_even_p_lambda_4_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_my_odd_p))->val,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_my_even_p_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_my_even_p_lambda_3_env *env = (struct scm_my_even_p_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_my_even_p_lambda_4_env );(((struct scm_my_even_p_lambda_4_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_my_even_p_lambda_4_env *)(&((var_1)->env)))->v_my_odd_p)=((env)->v_my_odd_p);OBJECT_CLOSURE_ONE_NEW(var_2,scm_my_even_p_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_my_even_p_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_my_even_p_lambda_2_env *env = (struct scm_my_even_p_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k,MAKE_BOOL(1));} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_my_even_p_lambda_3_env );(((struct scm_my_even_p_lambda_3_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_my_even_p_lambda_3_env *)(&((var_2)->env)))->v_my_odd_p)=((env)->v_my_odd_p);OBJECT_CLOSURE_ONE_NEW(var_3,scm_my_even_p_lambda_3,var_2);call_closure_two(var_1,(env)->v_n,var_3);}0;__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_my_even_p_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_my_even_p_lambda_1_env *env = (struct scm_my_even_p_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_my_even_p_lambda_2_env );(((struct scm_my_even_p_lambda_2_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_my_even_p_lambda_2_env *)(&((var_1)->env)))->v_my_odd_p)=((env)->v_my_odd_p);(((struct scm_my_even_p_lambda_2_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_my_even_p_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_my_even_p(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_my_even_p_env *env = (struct scm_my_even_p_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_my_even_p_lambda_1_env );(((struct scm_my_even_p_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_my_even_p_lambda_1_env *)(&((var_3)->env)))->v_my_odd_p)=((env)->v_my_odd_p);(((struct scm_my_even_p_lambda_1_env *)(&((var_3)->env)))->v_n)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_my_even_p_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 4 "mutual_recursion.scm"
+static void scm_my_odd_p_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_my_odd_p_lambda_4_env *env = (struct scm_my_odd_p_lambda_4_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_my_even_p))->val,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 4 "mutual_recursion.scm"
+static void scm_my_odd_p_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_my_odd_p_lambda_3_env *env = (struct scm_my_odd_p_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_my_odd_p_lambda_4_env );(((struct scm_my_odd_p_lambda_4_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_my_odd_p_lambda_4_env *)(&((var_1)->env)))->v_my_even_p)=((env)->v_my_even_p);OBJECT_CLOSURE_ONE_NEW(var_2,scm_my_odd_p_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 4 "mutual_recursion.scm"
+static void scm_my_odd_p_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_my_odd_p_lambda_2_env *env = (struct scm_my_odd_p_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_2,MAKE_BOOL(0));} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_my_odd_p_lambda_3_env );(((struct scm_my_odd_p_lambda_3_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_my_odd_p_lambda_3_env *)(&((var_2)->env)))->v_my_even_p)=((env)->v_my_even_p);OBJECT_CLOSURE_ONE_NEW(var_3,scm_my_odd_p_lambda_3,var_2);call_closure_two(var_1,(env)->v_n_2,var_3);}0;__builtin_unreachable();}
+
+#line 4 "mutual_recursion.scm"
+static void scm_my_odd_p_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_my_odd_p_lambda_1_env *env = (struct scm_my_odd_p_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_my_odd_p_lambda_2_env );(((struct scm_my_odd_p_lambda_2_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_my_odd_p_lambda_2_env *)(&((var_1)->env)))->v_my_even_p)=((env)->v_my_even_p);(((struct scm_my_odd_p_lambda_2_env *)(&((var_1)->env)))->v_n_2)=((env)->v_n_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_my_odd_p_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 4 "mutual_recursion.scm"
+static void scm_my_odd_p(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_my_odd_p_env *env = (struct scm_my_odd_p_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_my_odd_p_lambda_1_env );(((struct scm_my_odd_p_lambda_1_env *)(&((var_3)->env)))->v_k_2)=(var_1);(((struct scm_my_odd_p_lambda_1_env *)(&((var_3)->env)))->v_my_even_p)=((env)->v_my_even_p);(((struct scm_my_odd_p_lambda_1_env *)(&((var_3)->env)))->v_n_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_my_odd_p_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));call_closure_two((env)->v_rv_10,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_rv_10)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_26,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_17(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_2)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_19,var_2);call_closure_two(((struct cell_obj *)((env)->v_my_even_p))->val,MAKE_FIXNUM(10),var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_20_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_20);
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));call_closure_two((env)->v_rv_14,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_rv_14)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_24(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_2)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_27,var_2);call_closure_two(((struct cell_obj *)((env)->v_my_odd_p))->val,MAKE_FIXNUM(7),var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_28_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_28);
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));call_closure_two((env)->v_rv_18,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_rv_18)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(((struct cell_obj *)((env)->v_my_even_p))->val,MAKE_FIXNUM(100001),var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_39,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_36_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_36);
+
+#line 11 "mutual_recursion.scm"
+static void scm_count_down_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_6_env *env = (struct scm_count_down_lambda_6_env *)(&((env_in)->env));call_closure_two((env)->v_rv_24,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 11 "mutual_recursion.scm"
+static void scm_count_down_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_5_env *env = (struct scm_count_down_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_down_lambda_6_env );(((struct scm_count_down_lambda_6_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_count_down_lambda_6_env *)(&((var_1)->env)))->v_rv_24)=((env)->v_rv_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_down_lambda_6,var_1);call_closure_two(((struct cell_obj *)((env)->v_count_up))->val,var_0,var_2);__builtin_unreachable();}
+
+#line 11 "mutual_recursion.scm"
+static void scm_count_down_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_4_env *env = (struct scm_count_down_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_down_lambda_5_env );(((struct scm_count_down_lambda_5_env *)(&((var_1)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_count_down_lambda_5_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_count_down_lambda_5_env *)(&((var_1)->env)))->v_rv_24)=((env)->v_rv_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_down_lambda_5,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 11 "mutual_recursion.scm"
+static void scm_count_down_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_3_env *env = (struct scm_count_down_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_down_lambda_4_env );(((struct scm_count_down_lambda_4_env *)(&((var_2)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_count_down_lambda_4_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_count_down_lambda_4_env *)(&((var_2)->env)))->v_rv_24)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_count_down_lambda_4,var_2);call_closure_two(var_1,(env)->v_n_3,var_3);__builtin_unreachable();}
+
+#line 11 "mutual_recursion.scm"
+static void scm_count_down_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_2_env *env = (struct scm_count_down_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_9,NULL);} else {OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_down_lambda_3_env );(((struct scm_count_down_lambda_3_env *)(&((var_2)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_count_down_lambda_3_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_count_down_lambda_3_env *)(&((var_2)->env)))->v_n_3)=((env)->v_n_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_count_down_lambda_3,var_2);call_closure_two(var_1,(env)->v_n_3,var_3);}0;__builtin_unreachable();}
+
+#line 11 "mutual_recursion.scm"
+static void scm_count_down_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_1_env *env = (struct scm_count_down_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_down_lambda_2_env );(((struct scm_count_down_lambda_2_env *)(&((var_1)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_count_down_lambda_2_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_count_down_lambda_2_env *)(&((var_1)->env)))->v_n_3)=((env)->v_n_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_down_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 11 "mutual_recursion.scm"
+static void scm_count_down(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_down_env *env = (struct scm_count_down_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_down_lambda_1_env );(((struct scm_count_down_lambda_1_env *)(&((var_3)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_count_down_lambda_1_env *)(&((var_3)->env)))->v_k_9)=(var_1);(((struct scm_count_down_lambda_1_env *)(&((var_3)->env)))->v_n_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_count_down_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_8_env *env = (struct scm_count_up_lambda_8_env *)(&((env_in)->env));call_closure_two((env)->v_rv_30,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_7_env *env = (struct scm_count_up_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_up_lambda_8_env );(((struct scm_count_up_lambda_8_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_up_lambda_8_env *)(&((var_1)->env)))->v_rv_30)=((env)->v_rv_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_up_lambda_8,var_1);call_closure_two(((struct cell_obj *)((env)->v_count_down))->val,var_0,var_2);__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_6_env *env = (struct scm_count_up_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_up_lambda_7_env );(((struct scm_count_up_lambda_7_env *)(&((var_1)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_up_lambda_7_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_up_lambda_7_env *)(&((var_1)->env)))->v_rv_30)=((env)->v_rv_30);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_up_lambda_7,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_5_env *env = (struct scm_count_up_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_up_lambda_6_env );(((struct scm_count_up_lambda_6_env *)(&((var_2)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_up_lambda_6_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_up_lambda_6_env *)(&((var_2)->env)))->v_rv_30)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_count_up_lambda_6,var_2);call_closure_two(var_1,(env)->v_n_4,var_3);__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_4_env *env = (struct scm_count_up_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_up_lambda_5_env );(((struct scm_count_up_lambda_5_env *)(&((var_2)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_up_lambda_5_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_up_lambda_5_env *)(&((var_2)->env)))->v_n_4)=((env)->v_n_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_count_up_lambda_5,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_3_env *env = (struct scm_count_up_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_up_lambda_4_env );(((struct scm_count_up_lambda_4_env *)(&((var_1)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_up_lambda_4_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_up_lambda_4_env *)(&((var_1)->env)))->v_n_4)=((env)->v_n_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_up_lambda_4,var_1);call_closure_two(var_0,(env)->v_n_4,var_2);__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_2_env *env = (struct scm_count_up_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_10,NULL);} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_up_lambda_3_env );(((struct scm_count_up_lambda_3_env *)(&((var_2)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_up_lambda_3_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_up_lambda_3_env *)(&((var_2)->env)))->v_n_4)=((env)->v_n_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_count_up_lambda_3,var_2);call_closure_two(var_1,MAKE_FIXNUM(0),var_3);}0;__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_count_up_lambda_1_env *env = (struct scm_count_up_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_up_lambda_2_env );(((struct scm_count_up_lambda_2_env *)(&((var_1)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_up_lambda_2_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_up_lambda_2_env *)(&((var_1)->env)))->v_n_4)=((env)->v_n_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_up_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 14 "mutual_recursion.scm"
+static void scm_count_up(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_up_env *env = (struct scm_count_up_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_up_lambda_1_env );(((struct scm_count_up_lambda_1_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_up_lambda_1_env *)(&((var_3)->env)))->v_k_10)=(var_1);(((struct scm_count_up_lambda_1_env *)(&((var_3)->env)))->v_n_4)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_count_up_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));call_closure_two((env)->v_rv_36,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_54_env *)(&((var_2)->env)))->v_rv_36)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_54,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_50,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_45(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_47,var_2);call_closure_two(((struct cell_obj *)((env)->v_count_down))->val,MAKE_FIXNUM(4),var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_48(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_51,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_48_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_48);
+
+#line 19 "mutual_recursion.scm"
+static void scm_first(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_first_env *env = (struct scm_first_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_second))->val,NULL,var_1);__builtin_unreachable();}
+
+#line 20 "mutual_recursion.scm"
+static void scm_second(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,MAKE_FIXNUM(2));__builtin_unreachable();}
+static struct closure_obj scm_second_closure = CLOSURE_TWO_OBJ_INIT(scm_second);
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));call_closure_two((env)->v_rv_40,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_66_env *)(&((var_2)->env)))->v_rv_40)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_66,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_62,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_57(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_59,var_2);call_closure_two(((struct cell_obj *)((env)->v_first))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_60(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_63,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_60_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_60);
+
+#line 24 "mutual_recursion.scm"
+static void scm_ping_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_ping_lambda_4_env *env = (struct scm_ping_lambda_4_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_pong))->val,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 24 "mutual_recursion.scm"
+static void scm_ping_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_ping_lambda_3_env *env = (struct scm_ping_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_ping_lambda_4_env );(((struct scm_ping_lambda_4_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_ping_lambda_4_env *)(&((var_1)->env)))->v_pong)=((env)->v_pong);OBJECT_CLOSURE_ONE_NEW(var_2,scm_ping_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 24 "mutual_recursion.scm"
+static void scm_ping_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_ping_lambda_2_env *env = (struct scm_ping_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_17,(struct obj *)(&(symbol_ping)));} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_ping_lambda_3_env );(((struct scm_ping_lambda_3_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_ping_lambda_3_env *)(&((var_2)->env)))->v_pong)=((env)->v_pong);OBJECT_CLOSURE_ONE_NEW(var_3,scm_ping_lambda_3,var_2);call_closure_two(var_1,(env)->v_n_5,var_3);}0;__builtin_unreachable();}
+
+#line 24 "mutual_recursion.scm"
+static void scm_ping_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_ping_lambda_1_env *env = (struct scm_ping_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_ping_lambda_2_env );(((struct scm_ping_lambda_2_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_ping_lambda_2_env *)(&((var_1)->env)))->v_n_5)=((env)->v_n_5);(((struct scm_ping_lambda_2_env *)(&((var_1)->env)))->v_pong)=((env)->v_pong);OBJECT_CLOSURE_ONE_NEW(var_2,scm_ping_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 24 "mutual_recursion.scm"
+static void scm_ping(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_ping_env *env = (struct scm_ping_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_ping_lambda_1_env );(((struct scm_ping_lambda_1_env *)(&((var_3)->env)))->v_k_17)=(var_1);(((struct scm_ping_lambda_1_env *)(&((var_3)->env)))->v_n_5)=(var_0);(((struct scm_ping_lambda_1_env *)(&((var_3)->env)))->v_pong)=((env)->v_pong);OBJECT_CLOSURE_ONE_NEW(var_4,scm_ping_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 25 "mutual_recursion.scm"
+static void scm_pong_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_pong_lambda_4_env *env = (struct scm_pong_lambda_4_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_ping))->val,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 25 "mutual_recursion.scm"
+static void scm_pong_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_pong_lambda_3_env *env = (struct scm_pong_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_pong_lambda_4_env );(((struct scm_pong_lambda_4_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_pong_lambda_4_env *)(&((var_1)->env)))->v_ping)=((env)->v_ping);OBJECT_CLOSURE_ONE_NEW(var_2,scm_pong_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 25 "mutual_recursion.scm"
+static void scm_pong_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_pong_lambda_2_env *env = (struct scm_pong_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_18,(struct obj *)(&(symbol_pong)));} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_pong_lambda_3_env );(((struct scm_pong_lambda_3_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_pong_lambda_3_env *)(&((var_2)->env)))->v_ping)=((env)->v_ping);OBJECT_CLOSURE_ONE_NEW(var_3,scm_pong_lambda_3,var_2);call_closure_two(var_1,(env)->v_n_6,var_3);}0;__builtin_unreachable();}
+
+#line 25 "mutual_recursion.scm"
+static void scm_pong_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_pong_lambda_1_env *env = (struct scm_pong_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_pong_lambda_2_env );(((struct scm_pong_lambda_2_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_pong_lambda_2_env *)(&((var_1)->env)))->v_n_6)=((env)->v_n_6);(((struct scm_pong_lambda_2_env *)(&((var_1)->env)))->v_ping)=((env)->v_ping);OBJECT_CLOSURE_ONE_NEW(var_2,scm_pong_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 25 "mutual_recursion.scm"
+static void scm_pong(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_pong_env *env = (struct scm_pong_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_pong_lambda_1_env );(((struct scm_pong_lambda_1_env *)(&((var_3)->env)))->v_k_18)=(var_1);(((struct scm_pong_lambda_1_env *)(&((var_3)->env)))->v_n_6)=(var_0);(((struct scm_pong_lambda_1_env *)(&((var_3)->env)))->v_ping)=((env)->v_ping);OBJECT_CLOSURE_ONE_NEW(var_4,scm_pong_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 23 "mutual_recursion.scm"
+static void scm_outer_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_outer_lambda_3_env *env = (struct scm_outer_lambda_3_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_ping_env );(((struct scm_ping_env *)(&((var_3)->env)))->v_pong)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_ping,var_3);(((struct cell_obj *)((env)->v_ping))->val)=(var_4);GC_WRITE_BARRIER((env)->v_ping,((struct cell_obj *)((env)->v_ping))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_pong_env );(((struct scm_pong_env *)(&((var_5)->env)))->v_ping)=((env)->v_ping);OBJECT_CLOSURE_TWO_NEW(var_6,scm_pong,var_5);(((struct cell_obj *)(var_2))->val)=(var_6);GC_WRITE_BARRIER(var_2,((struct cell_obj *)(var_2))->val);call_closure_two(((struct cell_obj *)((env)->v_ping))->val,(env)->v_n_7,var_1);__builtin_unreachable();}
+
+#line 23 "mutual_recursion.scm"
+static void scm_outer_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_outer_lambda_1_env *env = (struct scm_outer_lambda_1_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_outer_lambda_3_env );(((struct scm_outer_lambda_3_env *)(&((var_3)->env)))->v_n_7)=((env)->v_n_7);(((struct scm_outer_lambda_3_env *)(&((var_3)->env)))->v_ping)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_outer_lambda_3,var_3);call_closure_two(var_4,NULL,(env)->v_k_21);__builtin_unreachable();}
+
+#line 23 "mutual_recursion.scm"
+static void scm_outer_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_outer_lambda_2_env *env = (struct scm_outer_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_21);__builtin_unreachable();}
+
+#line 23 "mutual_recursion.scm"
+static void scm_outer(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_outer_lambda_1_env );(((struct scm_outer_lambda_1_env *)(&((var_2)->env)))->v_k_21)=(var_1);(((struct scm_outer_lambda_1_env *)(&((var_2)->env)))->v_n_7)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_outer_lambda_1,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_outer_lambda_2_env );(((struct scm_outer_lambda_2_env *)(&((var_4)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_outer_lambda_2,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_outer_closure = CLOSURE_TWO_OBJ_INIT(scm_outer);
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));call_closure_two((env)->v_rv_53,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_rv_53)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_76,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_67(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_2)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_69,var_2);call_closure_two(((struct cell_obj *)((env)->v_outer))->val,MAKE_FIXNUM(3),var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_73,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_70_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_70);
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_80(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));call_closure_two((env)->v_rv_57,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_80_env *)(&((var_2)->env)))->v_rv_57)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_80,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_79,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_74(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_2)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_77,var_2);call_closure_two(((struct cell_obj *)((env)->v_outer))->val,MAKE_FIXNUM(4),var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_78,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_outer)=((env)->v_outer);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_74,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_75,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_outer)=((env)->v_outer);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_71,var_1);call_closure_two((struct obj *)(&(scm_lambda_70_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_outer))->val)=((struct obj *)(&(scm_outer_closure)));GC_WRITE_BARRIER((env)->v_outer,((struct cell_obj *)((env)->v_outer))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_outer)=((env)->v_outer);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_67,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_3)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_68_env *)(&((var_3)->env)))->v_outer)=((env)->v_outer);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_68,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_outer)=((env)->v_outer);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_61,var_1);call_closure_two((struct obj *)(&(scm_lambda_60_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_first_env );(((struct scm_first_env *)(&((var_1)->env)))->v_second)=((env)->v_second);OBJECT_CLOSURE_TWO_NEW(var_2,scm_first,var_1);(((struct cell_obj *)((env)->v_first))->val)=(var_2);GC_WRITE_BARRIER((env)->v_first,((struct cell_obj *)((env)->v_first))->val);(((struct cell_obj *)((env)->v_second))->val)=((struct obj *)(&(scm_second_closure)));GC_WRITE_BARRIER((env)->v_second,((struct cell_obj *)((env)->v_second))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_3)->env)))->v_first)=((env)->v_first);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_57,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_5)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_58_env *)(&((var_5)->env)))->v_outer)=((env)->v_outer);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_58,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_first)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)(((struct scm_lambda_37_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_first);(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_k_25)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)(((struct scm_lambda_37_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_outer)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)(((struct scm_lambda_37_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_outer);(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_second)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)(((struct scm_lambda_37_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_second);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_49,var_1);call_closure_two((struct obj *)(&(scm_lambda_48_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_down_env );(((struct scm_count_down_env *)(&((var_1)->env)))->v_count_up)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_up);OBJECT_CLOSURE_TWO_NEW(var_2,scm_count_down,var_1);(((struct cell_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_down))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_down,((struct cell_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_down))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_up_env );(((struct scm_count_up_env *)(&((var_3)->env)))->v_count_down)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_down);OBJECT_CLOSURE_TWO_NEW(var_4,scm_count_up,var_3);(((struct cell_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_up))->val)=(var_4);GC_WRITE_BARRIER(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_up,((struct cell_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_up))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_5)->env)))->v_count_down)=(((struct scm_lambda_29_env *)(&(((struct env_obj *)(((struct scm_lambda_33_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count_down);OBJECT_CLOSURE_TWO_NEW(var_6,scm_lambda_45,var_5);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_7)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_8,scm_lambda_46,var_7);call_closure_two(var_6,NULL,var_8);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_37,var_1);call_closure_two((struct obj *)(&(scm_lambda_36_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_my_even_p)=((env)->v_my_even_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_32,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_count_down)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_down);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_count_up)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_up);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_first)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_first);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_k_25)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_25);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_my_even_p)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_my_even_p);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_outer)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_outer);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_second)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_second);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_29,var_1);call_closure_two((struct obj *)(&(scm_lambda_28_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_my_odd_p)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_18_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_my_odd_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_24,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two((struct obj *)(&(scm_lambda_20_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_outer)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_25)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_my_even_p_env );(((struct scm_my_even_p_env *)(&((var_3)->env)))->v_my_odd_p)=((env)->v_my_odd_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_my_even_p,var_3);(((struct cell_obj *)((env)->v_my_even_p))->val)=(var_4);GC_WRITE_BARRIER((env)->v_my_even_p,((struct cell_obj *)((env)->v_my_even_p))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_my_odd_p_env );(((struct scm_my_odd_p_env *)(&((var_5)->env)))->v_my_even_p)=((env)->v_my_even_p);OBJECT_CLOSURE_TWO_NEW(var_6,scm_my_odd_p,var_5);(((struct cell_obj *)((env)->v_my_odd_p))->val)=(var_6);GC_WRITE_BARRIER((env)->v_my_odd_p,((struct cell_obj *)((env)->v_my_odd_p))->val);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_7)->env)))->v_my_even_p)=((env)->v_my_even_p);OBJECT_CLOSURE_TWO_NEW(var_8,scm_lambda_17,var_7);OBJECT_ENV_OBJ_NEW(var_9,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_9)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_10,scm_lambda_18,var_9);call_closure_two(var_8,NULL,var_10);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_second)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_first)=((env)->v_first);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_my_even_p)=((env)->v_my_even_p);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_my_odd_p)=((env)->v_my_odd_p);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_second)=((env)->v_second);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_14,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_first)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_first)=((env)->v_first);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_my_even_p)=((env)->v_my_even_p);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_my_odd_p)=((env)->v_my_odd_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_count_up)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_count_up)=((env)->v_count_up);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_my_even_p)=((env)->v_my_even_p);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_my_odd_p)=((env)->v_my_odd_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_count_down)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_my_even_p)=((env)->v_my_even_p);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_my_odd_p)=((env)->v_my_odd_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_my_odd_p)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_my_even_p)=((env)->v_my_even_p);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_my_odd_p)=((env)->v_my_odd_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_my_even_p)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_my_even_p)=((env)->v_my_even_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_32);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "mutual_recursion.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_32)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "mutual_recursion.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 576 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
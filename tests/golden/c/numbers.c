@@ -0,0 +1,1043 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_7_env {struct obj *v_k;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_5(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_5_closure;
+struct scm_lambda_13_env {struct obj *v_k_2;struct obj *v_rv_3;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_k_2;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_8_closure;
+struct scm_lambda_14_env {struct obj *v_k_3;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_11(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_11_closure;
+struct scm_lambda_20_env {struct obj *v_k_4;struct obj *v_rv_6;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_4;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_15(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_15_closure;
+struct scm_lambda_21_env {struct obj *v_k_5;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_18_closure;
+struct scm_lambda_27_env {struct obj *v_k_6;struct obj *v_rv_9;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_6;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_22_closure;
+struct scm_lambda_28_env {struct obj *v_k_7;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_25(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_25_closure;
+struct scm_lambda_42_env {struct obj *v_k_8;struct obj *v_rv_12;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_8;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_k_8;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_8;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_29(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_29_closure;
+struct scm_lambda_35_env {struct obj *v_k_9;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_32_closure;
+struct scm_lambda_43_env {struct obj *v_k_10;struct obj *v_rv_17;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_10;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_36(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_36_closure;
+struct scm_lambda_44_env {struct obj *v_k_11;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_40(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_40_closure;
+struct scm_lambda_50_env {struct obj *v_k_12;struct obj *v_rv_20;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_12;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_45(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_45_closure;
+struct scm_lambda_51_env {struct obj *v_k_13;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_48(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_48_closure;
+struct scm_lambda_57_env {struct obj *v_k_14;struct obj *v_rv_23;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_14;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_52(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_52_closure;
+struct scm_lambda_58_env {struct obj *v_k_15;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_55(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_55_closure;
+struct scm_lambda_64_env {struct obj *v_k_16;struct obj *v_rv_26;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_16;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_59(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_59_closure;
+struct scm_lambda_65_env {struct obj *v_k_17;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_62(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_62_closure;
+struct scm_lambda_71_env {struct obj *v_k_18;struct obj *v_rv_29;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_18;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_66(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_66_closure;
+struct scm_lambda_72_env {struct obj *v_k_19;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_69(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_69_closure;
+struct scm_lambda_78_env {struct obj *v_k_20;struct obj *v_rv_32;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_20;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_73(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_73_closure;
+struct scm_lambda_79_env {struct obj *v_k_21;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_76(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_76_closure;
+struct scm_lambda_85_env {struct obj *v_k_22;struct obj *v_rv_35;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_82_env {struct obj *v_k_22;};
+static void scm_lambda_82(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_80(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_80_closure;
+struct scm_lambda_86_env {struct obj *v_k_23;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_83(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_83_closure;
+struct scm_lambda_92_env {struct obj *v_k_24;struct obj *v_rv_38;};
+static void scm_lambda_92(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v_k_24;};
+static void scm_lambda_89(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_87(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_87_closure;
+struct scm_lambda_93_env {struct obj *v_k_25;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_90(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_90_closure;
+struct scm_lambda_103_env {struct obj *v_k_26;struct obj *v_rv_41;};
+static void scm_lambda_103(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_99_env {struct obj *v_k_26;};
+static void scm_lambda_99(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_96_env {struct obj *v_k_26;};
+static void scm_lambda_96(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_94(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_94_closure;
+struct scm_lambda_100_env {struct obj *v_k_27;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_97(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_97_closure;
+struct scm_lambda_107_env {struct obj *v_k_28;struct obj *v_rv_45;};
+static void scm_lambda_107(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_k_28;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_101(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_101_closure;
+struct scm_lambda_108_env {struct obj *v_k_29;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_105(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_105_closure;
+struct scm_lambda_114_env {struct obj *v_k_30;struct obj *v_rv_48;};
+static void scm_lambda_114(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_k_30;};
+static void scm_lambda_111(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_109(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_109_closure;
+struct scm_lambda_115_env {struct obj *v_k_31;};
+static void scm_lambda_115(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_112(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_112_closure;
+struct scm_lambda_121_env {struct obj *v_k_32;struct obj *v_rv_51;};
+static void scm_lambda_121(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_k_32;};
+static void scm_lambda_118(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_116(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_116_closure;
+struct scm_lambda_122_env {struct obj *v_k_33;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_119(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_119_closure;
+struct scm_lambda_128_env {struct obj *v_k_34;struct obj *v_rv_54;};
+static void scm_lambda_128(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_125_env {struct obj *v_k_34;};
+static void scm_lambda_125(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_123(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_123_closure;
+struct scm_lambda_129_env {struct obj *v_k_35;};
+static void scm_lambda_129(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_126(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_126_closure;
+struct scm_lambda_135_env {struct obj *v_k_36;struct obj *v_rv_57;};
+static void scm_lambda_135(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_132_env {struct obj *v_k_36;};
+static void scm_lambda_132(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_130(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_130_closure;
+struct scm_lambda_136_env {struct obj *v_k_37;};
+static void scm_lambda_136(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_133(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_133_closure;
+struct scm_lambda_142_env {struct obj *v_k_38;struct obj *v_rv_60;};
+static void scm_lambda_142(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_139_env {struct obj *v_k_38;};
+static void scm_lambda_139(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_137(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_137_closure;
+struct scm_lambda_143_env {struct obj *v_k_39;};
+static void scm_lambda_143(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_140(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_140_closure;
+struct scm_lambda_149_env {struct obj *v_k_40;struct obj *v_rv_63;};
+static void scm_lambda_149(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_146_env {struct obj *v_k_40;};
+static void scm_lambda_146(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_144(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_144_closure;
+struct scm_lambda_150_env {struct obj *v_k_41;};
+static void scm_lambda_150(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_147(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_147_closure;
+struct scm_lambda_156_env {struct obj *v_k_42;struct obj *v_rv_66;};
+static void scm_lambda_156(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_153_env {struct obj *v_k_42;};
+static void scm_lambda_153(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_151(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_151_closure;
+struct scm_lambda_157_env {struct obj *v_k_43;};
+static void scm_lambda_157(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_154(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_154_closure;
+struct scm_lambda_163_env {struct obj *v_k_44;struct obj *v_rv_69;};
+static void scm_lambda_163(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_160_env {struct obj *v_k_44;};
+static void scm_lambda_160(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_158(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_158_closure;
+struct scm_lambda_164_env {struct obj *v_k_45;};
+static void scm_lambda_164(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_161(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_161_closure;
+struct scm_lambda_170_env {struct obj *v_k_46;struct obj *v_rv_72;};
+static void scm_lambda_170(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_167_env {struct obj *v_k_46;};
+static void scm_lambda_167(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_165(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_165_closure;
+struct scm_lambda_171_env {struct obj *v_k_47;};
+static void scm_lambda_171(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_168(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_168_closure;
+struct scm_lambda_177_env {struct obj *v_k_48;struct obj *v_rv_75;};
+static void scm_lambda_177(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_174_env {struct obj *v_k_48;};
+static void scm_lambda_174(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_172(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_172_closure;
+struct scm_lambda_178_env {struct obj *v_k_49;};
+static void scm_lambda_178(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_175(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_175_closure;
+struct scm_lambda_184_env {struct obj *v_k_50;struct obj *v_rv_78;};
+static void scm_lambda_184(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_181_env {struct obj *v_k_50;};
+static void scm_lambda_181(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_179(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_179_closure;
+struct scm_lambda_185_env {struct obj *v_k_51;};
+static void scm_lambda_185(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_182(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_182_closure;
+struct scm_lambda_190_env {struct obj *v_k_52;struct obj *v_rv_81;};
+static void scm_lambda_190(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_188_env {struct obj *v_k_52;};
+static void scm_lambda_188(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_186(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_186_closure;
+struct scm_lambda_189_env {struct obj *v_k_53;};
+static void scm_lambda_189(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_187_env {struct obj *v_k_53;};
+static void scm_lambda_187(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_183_env {struct obj *v_k_53;};
+static void scm_lambda_183(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_180_env {struct obj *v_k_53;};
+static void scm_lambda_180(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_176_env {struct obj *v_k_53;};
+static void scm_lambda_176(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_173_env {struct obj *v_k_53;};
+static void scm_lambda_173(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_169_env {struct obj *v_k_53;};
+static void scm_lambda_169(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_166_env {struct obj *v_k_53;};
+static void scm_lambda_166(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_162_env {struct obj *v_k_53;};
+static void scm_lambda_162(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_159_env {struct obj *v_k_53;};
+static void scm_lambda_159(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_155_env {struct obj *v_k_53;};
+static void scm_lambda_155(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_152_env {struct obj *v_k_53;};
+static void scm_lambda_152(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_148_env {struct obj *v_k_53;};
+static void scm_lambda_148(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_145_env {struct obj *v_k_53;};
+static void scm_lambda_145(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_141_env {struct obj *v_k_53;};
+static void scm_lambda_141(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_138_env {struct obj *v_k_53;};
+static void scm_lambda_138(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_134_env {struct obj *v_k_53;};
+static void scm_lambda_134(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_131_env {struct obj *v_k_53;};
+static void scm_lambda_131(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_127_env {struct obj *v_k_53;};
+static void scm_lambda_127(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_124_env {struct obj *v_k_53;};
+static void scm_lambda_124(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_120_env {struct obj *v_k_53;};
+static void scm_lambda_120(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_117_env {struct obj *v_k_53;};
+static void scm_lambda_117(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_113_env {struct obj *v_k_53;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *v_k_53;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *v_k_53;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_102_env {struct obj *v_k_53;};
+static void scm_lambda_102(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_k_53;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_95_env {struct obj *v_k_53;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_91_env {struct obj *v_k_53;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_k_53;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_k_53;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_k_53;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_k_53;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_53;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_k_53;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_53;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_53;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_53;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_53;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_53;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_53;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_53;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_53;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_53;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_53;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_53;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_53;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_53;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_53;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_k_53;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k_53;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_53;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_k_53;};
+static void scm_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_53;};
+static void scm_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_53;struct obj *v_rv_137;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_k_53;};
+static void scm_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct cons_obj quoted_af0e453ca543a07a_2 = CONS_OBJ_INIT(MAKE_FIXNUM(-10),NULL);
+static struct float_obj quoted_af0e453ca543a07a_item_1 = FLOAT_OBJ_INIT(-0.25);
+static struct cons_obj quoted_af0e453ca543a07a_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_af0e453ca543a07a_item_1)),(struct obj *)(&(quoted_af0e453ca543a07a_2)));
+static struct cons_obj quoted_af0e453ca543a07a = CONS_OBJ_INIT(MAKE_FIXNUM(-1),(struct obj *)(&(quoted_af0e453ca543a07a_1)));
+static struct cons_obj quoted_b6d6bfa45ad6d6d3_1 = CONS_OBJ_INIT(MAKE_FIXNUM(-2),NULL);
+static struct cons_obj quoted_b6d6bfa45ad6d6d3 = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_b6d6bfa45ad6d6d3_1)));
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_7),"scm_lambda_7","numbers.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","numbers.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","numbers.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","numbers.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","numbers.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","numbers.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","numbers.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","numbers.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","numbers.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","numbers.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","numbers.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","numbers.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","numbers.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","numbers.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","numbers.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","numbers.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","numbers.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","numbers.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","numbers.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","numbers.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","numbers.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","numbers.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","numbers.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","numbers.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","numbers.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","numbers.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","numbers.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","numbers.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","numbers.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","numbers.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","numbers.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","numbers.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","numbers.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","numbers.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","numbers.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","numbers.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","numbers.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","numbers.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","numbers.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","numbers.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","numbers.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","numbers.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","numbers.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","numbers.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","numbers.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","numbers.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","numbers.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","numbers.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","numbers.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","numbers.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","numbers.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","numbers.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","numbers.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","numbers.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","numbers.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","numbers.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","numbers.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","numbers.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","numbers.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","numbers.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","numbers.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","numbers.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","numbers.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","numbers.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","numbers.scm",1},{(void *)(scm_lambda_99),"scm_lambda_99","numbers.scm",1},{(void *)(scm_lambda_96),"scm_lambda_96","numbers.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","numbers.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","numbers.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","numbers.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","numbers.scm",1},{(void *)(scm_lambda_104),"scm_lambda_104","numbers.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","numbers.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","numbers.scm",1},{(void *)(scm_lambda_105),"scm_lambda_105","numbers.scm",1},{(void *)(scm_lambda_114),"scm_lambda_114","numbers.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","numbers.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","numbers.scm",1},{(void *)(scm_lambda_115),"scm_lambda_115","numbers.scm",1},{(void *)(scm_lambda_112),"scm_lambda_112","numbers.scm",1},{(void *)(scm_lambda_121),"scm_lambda_121","numbers.scm",1},{(void *)(scm_lambda_118),"scm_lambda_118","numbers.scm",1},{(void *)(scm_lambda_116),"scm_lambda_116","numbers.scm",1},{(void *)(scm_lambda_122),"scm_lambda_122","numbers.scm",1},{(void *)(scm_lambda_119),"scm_lambda_119","numbers.scm",1},{(void *)(scm_lambda_128),"scm_lambda_128","numbers.scm",1},{(void *)(scm_lambda_125),"scm_lambda_125","numbers.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","numbers.scm",1},{(void *)(scm_lambda_129),"scm_lambda_129","numbers.scm",1},{(void *)(scm_lambda_126),"scm_lambda_126","numbers.scm",1},{(void *)(scm_lambda_135),"scm_lambda_135","numbers.scm",1},{(void *)(scm_lambda_132),"scm_lambda_132","numbers.scm",1},{(void *)(scm_lambda_130),"scm_lambda_130","numbers.scm",1},{(void *)(scm_lambda_136),"scm_lambda_136","numbers.scm",1},{(void *)(scm_lambda_133),"scm_lambda_133","numbers.scm",1},{(void *)(scm_lambda_142),"scm_lambda_142","numbers.scm",1},{(void *)(scm_lambda_139),"scm_lambda_139","numbers.scm",1},{(void *)(scm_lambda_137),"scm_lambda_137","numbers.scm",1},{(void *)(scm_lambda_143),"scm_lambda_143","numbers.scm",1},{(void *)(scm_lambda_140),"scm_lambda_140","numbers.scm",1},{(void *)(scm_lambda_149),"scm_lambda_149","numbers.scm",1},{(void *)(scm_lambda_146),"scm_lambda_146","numbers.scm",1},{(void *)(scm_lambda_144),"scm_lambda_144","numbers.scm",1},{(void *)(scm_lambda_150),"scm_lambda_150","numbers.scm",1},{(void *)(scm_lambda_147),"scm_lambda_147","numbers.scm",1},{(void *)(scm_lambda_156),"scm_lambda_156","numbers.scm",1},{(void *)(scm_lambda_153),"scm_lambda_153","numbers.scm",1},{(void *)(scm_lambda_151),"scm_lambda_151","numbers.scm",1},{(void *)(scm_lambda_157),"scm_lambda_157","numbers.scm",1},{(void *)(scm_lambda_154),"scm_lambda_154","numbers.scm",1},{(void *)(scm_lambda_163),"scm_lambda_163","numbers.scm",1},{(void *)(scm_lambda_160),"scm_lambda_160","numbers.scm",1},{(void *)(scm_lambda_158),"scm_lambda_158","numbers.scm",1},{(void *)(scm_lambda_164),"scm_lambda_164","numbers.scm",1},{(void *)(scm_lambda_161),"scm_lambda_161","numbers.scm",1},{(void *)(scm_lambda_170),"scm_lambda_170","numbers.scm",1},{(void *)(scm_lambda_167),"scm_lambda_167","numbers.scm",1},{(void *)(scm_lambda_165),"scm_lambda_165","numbers.scm",1},{(void *)(scm_lambda_171),"scm_lambda_171","numbers.scm",1},{(void *)(scm_lambda_168),"scm_lambda_168","numbers.scm",1},{(void *)(scm_lambda_177),"scm_lambda_177","numbers.scm",1},{(void *)(scm_lambda_174),"scm_lambda_174","numbers.scm",1},{(void *)(scm_lambda_172),"scm_lambda_172","numbers.scm",1},{(void *)(scm_lambda_178),"scm_lambda_178","numbers.scm",1},{(void *)(scm_lambda_175),"scm_lambda_175","numbers.scm",1},{(void *)(scm_lambda_184),"scm_lambda_184","numbers.scm",1},{(void *)(scm_lambda_181),"scm_lambda_181","numbers.scm",1},{(void *)(scm_lambda_179),"scm_lambda_179","numbers.scm",1},{(void *)(scm_lambda_185),"scm_lambda_185","numbers.scm",1},{(void *)(scm_lambda_182),"scm_lambda_182","numbers.scm",1},{(void *)(scm_lambda_190),"scm_lambda_190","numbers.scm",1},{(void *)(scm_lambda_188),"scm_lambda_188","numbers.scm",1},{(void *)(scm_lambda_186),"scm_lambda_186","numbers.scm",1},{(void *)(scm_lambda_189),"scm_lambda_189","numbers.scm",1},{(void *)(scm_lambda_187),"scm_lambda_187","numbers.scm",1},{(void *)(scm_lambda_183),"scm_lambda_183","numbers.scm",1},{(void *)(scm_lambda_180),"scm_lambda_180","numbers.scm",1},{(void *)(scm_lambda_176),"scm_lambda_176","numbers.scm",1},{(void *)(scm_lambda_173),"scm_lambda_173","numbers.scm",1},{(void *)(scm_lambda_169),"scm_lambda_169","numbers.scm",1},{(void *)(scm_lambda_166),"scm_lambda_166","numbers.scm",1},{(void *)(scm_lambda_162),"scm_lambda_162","numbers.scm",1},{(void *)(scm_lambda_159),"scm_lambda_159","numbers.scm",1},{(void *)(scm_lambda_155),"scm_lambda_155","numbers.scm",1},{(void *)(scm_lambda_152),"scm_lambda_152","numbers.scm",1},{(void *)(scm_lambda_148),"scm_lambda_148","numbers.scm",1},{(void *)(scm_lambda_145),"scm_lambda_145","numbers.scm",1},{(void *)(scm_lambda_141),"scm_lambda_141","numbers.scm",1},{(void *)(scm_lambda_138),"scm_lambda_138","numbers.scm",1},{(void *)(scm_lambda_134),"scm_lambda_134","numbers.scm",1},{(void *)(scm_lambda_131),"scm_lambda_131","numbers.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","numbers.scm",1},{(void *)(scm_lambda_124),"scm_lambda_124","numbers.scm",1},{(void *)(scm_lambda_120),"scm_lambda_120","numbers.scm",1},{(void *)(scm_lambda_117),"scm_lambda_117","numbers.scm",1},{(void *)(scm_lambda_113),"scm_lambda_113","numbers.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","numbers.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","numbers.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","numbers.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","numbers.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","numbers.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","numbers.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","numbers.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","numbers.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","numbers.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","numbers.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","numbers.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","numbers.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","numbers.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","numbers.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","numbers.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","numbers.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","numbers.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","numbers.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","numbers.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","numbers.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","numbers.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","numbers.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","numbers.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","numbers.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","numbers.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","numbers.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","numbers.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","numbers.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","numbers.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","numbers.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","numbers.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","numbers.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","numbers.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","numbers.scm",1},{NULL}};
+
+#line 1 "numbers.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_5(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_7,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_5_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_5);
+
+#line 1 "numbers.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_13,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_10,var_3);call_closure_two(var_2,MAKE_FIXNUM(-2),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_8_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_8);
+
+#line 1 "numbers.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_11(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_14,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_11_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_11);
+
+#line 1 "numbers.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_20,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_15(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,-0.5);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_4)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_17,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_15_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_15);
+
+#line 1 "numbers.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_18_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_18);
+
+#line 1 "numbers.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));call_closure_two((env)->v_rv_9,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_27_env *)(&((var_2)->env)))->v_rv_9)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_27,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_24,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_b6d6bfa45ad6d6d3)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_22_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_22);
+
+#line 1 "numbers.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_25(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_28,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_25_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_25);
+
+#line 1 "numbers.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));call_closure_two((env)->v_rv_12,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_rv_12)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_29(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_b6d6bfa45ad6d6d3)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_29_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_29);
+
+#line 1 "numbers.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_35,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_32_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_32);
+
+#line 1 "numbers.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));call_closure_two((env)->v_rv_17,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_rv_17)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_43,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_36(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,1000.0);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_4)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_39,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_36_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_36);
+
+#line 1 "numbers.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_40(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_40_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_40);
+
+#line 1 "numbers.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));call_closure_two((env)->v_rv_20,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_rv_20)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_50,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_45(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,-0.02);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_4)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_47,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_45_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_45);
+
+#line 1 "numbers.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_48(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_51,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_48_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_48);
+
+#line 1 "numbers.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));call_closure_two((env)->v_rv_23,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_57_env *)(&((var_2)->env)))->v_rv_23)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_57,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_52(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_54,var_3);call_closure_two(var_2,MAKE_BOOL(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_52_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_52);
+
+#line 1 "numbers.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_55(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_58,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_55_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_55);
+
+#line 1 "numbers.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));call_closure_two((env)->v_rv_26,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_64,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_59(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_61,var_3);call_closure_two(var_2,MAKE_FIXNUM(-31),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_59_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_59);
+
+#line 1 "numbers.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_62(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_65,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_62_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_62);
+
+#line 1 "numbers.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));call_closure_two((env)->v_rv_29,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_rv_29)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_66(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_68,var_3);call_closure_two(var_2,MAKE_FIXNUM(-1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_66_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_66);
+
+#line 1 "numbers.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_69(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_72,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_69_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_69);
+
+#line 1 "numbers.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));call_closure_two((env)->v_rv_32,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_78_env *)(&((var_2)->env)))->v_rv_32)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_78,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_73(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_75,var_3);call_closure_two(var_2,MAKE_FIXNUM(15),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_73_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_73);
+
+#line 1 "numbers.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_76(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_3)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_79,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_76_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_76);
+
+#line 1 "numbers.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));call_closure_two((env)->v_rv_35,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_82(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_85_env *)(&((var_2)->env)))->v_rv_35)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_85,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_80(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_RATIONAL_OBJ_NEW(var_3,-1,2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_4)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_82,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_80_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_80);
+
+#line 1 "numbers.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_83(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_86,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_83_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_83);
+
+#line 1 "numbers.scm"
+static void scm_lambda_92(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));call_closure_two((env)->v_rv_38,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_89(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_92_env *)(&((var_2)->env)))->v_rv_38)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_92,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_87(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_3)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_89,var_3);call_closure_two(var_2,MAKE_FIXNUM(0),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_87_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_87);
+
+#line 1 "numbers.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_90(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_93,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_90_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_90);
+
+#line 1 "numbers.scm"
+static void scm_lambda_103(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));call_closure_two((env)->v_rv_41,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_99(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_103_env *)(&((var_2)->env)))->v_rv_41)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_103,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_96(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_99,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_94(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,list_to_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_3)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_96,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_af0e453ca543a07a)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_94_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_94);
+
+#line 1 "numbers.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_97(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_3)->env)))->v_k_27)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_100,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_97_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_97);
+
+#line 1 "numbers.scm"
+static void scm_lambda_107(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_107_env *env = (struct scm_lambda_107_env *)(&((env_in)->env));call_closure_two((env)->v_rv_45,var_0,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_107_env );(((struct scm_lambda_107_env *)(&((var_2)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_lambda_107_env *)(&((var_2)->env)))->v_rv_45)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_107,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_101(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_INT_OBJ_NEW(var_3,(-9223372036854775807 - 1));OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_4)->env)))->v_k_28)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_104,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_101_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_101);
+
+#line 1 "numbers.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_29);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_105(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_3)->env)))->v_k_29)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_108,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_105_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_105);
+
+#line 1 "numbers.scm"
+static void scm_lambda_114(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_114_env *env = (struct scm_lambda_114_env *)(&((env_in)->env));call_closure_two((env)->v_rv_48,var_0,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_111(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_114_env );(((struct scm_lambda_114_env *)(&((var_2)->env)))->v_k_30)=((env)->v_k_30);(((struct scm_lambda_114_env *)(&((var_2)->env)))->v_rv_48)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_114,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_109(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_3)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_111,var_3);call_closure_two(var_2,MAKE_FIXNUM(-31),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_109_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_109);
+
+#line 1 "numbers.scm"
+static void scm_lambda_115(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_115_env *env = (struct scm_lambda_115_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_31);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_112(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_115_env );(((struct scm_lambda_115_env *)(&((var_3)->env)))->v_k_31)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_115,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_112_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_112);
+
+#line 1 "numbers.scm"
+static void scm_lambda_121(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_121_env *env = (struct scm_lambda_121_env *)(&((env_in)->env));call_closure_two((env)->v_rv_51,var_0,(env)->v_k_32);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_118(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_121_env );(((struct scm_lambda_121_env *)(&((var_2)->env)))->v_k_32)=((env)->v_k_32);(((struct scm_lambda_121_env *)(&((var_2)->env)))->v_rv_51)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_121,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_116(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,-1000.0);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_4)->env)))->v_k_32)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_118,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_116_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_116);
+
+#line 1 "numbers.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_33);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_119(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_3)->env)))->v_k_33)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_122,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_119_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_119);
+
+#line 1 "numbers.scm"
+static void scm_lambda_128(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_128_env *env = (struct scm_lambda_128_env *)(&((env_in)->env));call_closure_two((env)->v_rv_54,var_0,(env)->v_k_34);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_125(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_125_env *env = (struct scm_lambda_125_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_128_env );(((struct scm_lambda_128_env *)(&((var_2)->env)))->v_k_34)=((env)->v_k_34);(((struct scm_lambda_128_env *)(&((var_2)->env)))->v_rv_54)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_128,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_123(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_125_env );(((struct scm_lambda_125_env *)(&((var_3)->env)))->v_k_34)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_125,var_3);call_closure_two(var_2,MAKE_BOOL(0),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_123_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_123);
+
+#line 1 "numbers.scm"
+static void scm_lambda_129(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_129_env *env = (struct scm_lambda_129_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_35);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_126(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_129_env );(((struct scm_lambda_129_env *)(&((var_3)->env)))->v_k_35)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_129,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_126_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_126);
+
+#line 1 "numbers.scm"
+static void scm_lambda_135(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_135_env *env = (struct scm_lambda_135_env *)(&((env_in)->env));call_closure_two((env)->v_rv_57,var_0,(env)->v_k_36);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_132(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_132_env *env = (struct scm_lambda_132_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_135_env );(((struct scm_lambda_135_env *)(&((var_2)->env)))->v_k_36)=((env)->v_k_36);(((struct scm_lambda_135_env *)(&((var_2)->env)))->v_rv_57)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_135,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_130(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,100.0);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_132_env );(((struct scm_lambda_132_env *)(&((var_4)->env)))->v_k_36)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_132,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_130_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_130);
+
+#line 1 "numbers.scm"
+static void scm_lambda_136(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_136_env *env = (struct scm_lambda_136_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_37);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_133(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_136_env );(((struct scm_lambda_136_env *)(&((var_3)->env)))->v_k_37)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_136,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_133_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_133);
+
+#line 1 "numbers.scm"
+static void scm_lambda_142(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_142_env *env = (struct scm_lambda_142_env *)(&((env_in)->env));call_closure_two((env)->v_rv_60,var_0,(env)->v_k_38);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_139(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_139_env *env = (struct scm_lambda_139_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_142_env );(((struct scm_lambda_142_env *)(&((var_2)->env)))->v_k_38)=((env)->v_k_38);(((struct scm_lambda_142_env *)(&((var_2)->env)))->v_rv_60)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_142,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_137(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,0.1);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_139_env );(((struct scm_lambda_139_env *)(&((var_4)->env)))->v_k_38)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_139,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_137_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_137);
+
+#line 1 "numbers.scm"
+static void scm_lambda_143(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_143_env *env = (struct scm_lambda_143_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_39);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_140(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_143_env );(((struct scm_lambda_143_env *)(&((var_3)->env)))->v_k_39)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_143,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_140_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_140);
+
+#line 1 "numbers.scm"
+static void scm_lambda_149(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_149_env *env = (struct scm_lambda_149_env *)(&((env_in)->env));call_closure_two((env)->v_rv_63,var_0,(env)->v_k_40);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_146(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_146_env *env = (struct scm_lambda_146_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_149_env );(((struct scm_lambda_149_env *)(&((var_2)->env)))->v_k_40)=((env)->v_k_40);(((struct scm_lambda_149_env *)(&((var_2)->env)))->v_rv_63)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_149,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_144(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,123.456);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_146_env );(((struct scm_lambda_146_env *)(&((var_4)->env)))->v_k_40)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_146,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_144_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_144);
+
+#line 1 "numbers.scm"
+static void scm_lambda_150(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_150_env *env = (struct scm_lambda_150_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_41);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_147(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_150_env );(((struct scm_lambda_150_env *)(&((var_3)->env)))->v_k_41)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_150,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_147_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_147);
+
+#line 1 "numbers.scm"
+static void scm_lambda_156(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_156_env *env = (struct scm_lambda_156_env *)(&((env_in)->env));call_closure_two((env)->v_rv_66,var_0,(env)->v_k_42);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_153(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_153_env *env = (struct scm_lambda_153_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_156_env );(((struct scm_lambda_156_env *)(&((var_2)->env)))->v_k_42)=((env)->v_k_42);(((struct scm_lambda_156_env *)(&((var_2)->env)))->v_rv_66)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_156,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_151(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,1e20);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_153_env );(((struct scm_lambda_153_env *)(&((var_4)->env)))->v_k_42)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_153,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_151_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_151);
+
+#line 1 "numbers.scm"
+static void scm_lambda_157(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_157_env *env = (struct scm_lambda_157_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_43);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_154(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_157_env );(((struct scm_lambda_157_env *)(&((var_3)->env)))->v_k_43)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_157,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_154_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_154);
+
+#line 1 "numbers.scm"
+static void scm_lambda_163(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_163_env *env = (struct scm_lambda_163_env *)(&((env_in)->env));call_closure_two((env)->v_rv_69,var_0,(env)->v_k_44);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_160(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_160_env *env = (struct scm_lambda_160_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_163_env );(((struct scm_lambda_163_env *)(&((var_2)->env)))->v_k_44)=((env)->v_k_44);(((struct scm_lambda_163_env *)(&((var_2)->env)))->v_rv_69)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_163,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_158(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,1e21);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_160_env );(((struct scm_lambda_160_env *)(&((var_4)->env)))->v_k_44)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_160,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_158_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_158);
+
+#line 1 "numbers.scm"
+static void scm_lambda_164(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_164_env *env = (struct scm_lambda_164_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_45);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_161(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_164_env );(((struct scm_lambda_164_env *)(&((var_3)->env)))->v_k_45)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_164,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_161_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_161);
+
+#line 1 "numbers.scm"
+static void scm_lambda_170(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_170_env *env = (struct scm_lambda_170_env *)(&((env_in)->env));call_closure_two((env)->v_rv_72,var_0,(env)->v_k_46);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_167(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_167_env *env = (struct scm_lambda_167_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_170_env );(((struct scm_lambda_170_env *)(&((var_2)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_170_env *)(&((var_2)->env)))->v_rv_72)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_170,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_165(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,1.5e-7);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_167_env );(((struct scm_lambda_167_env *)(&((var_4)->env)))->v_k_46)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_167,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_165_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_165);
+
+#line 1 "numbers.scm"
+static void scm_lambda_171(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_171_env *env = (struct scm_lambda_171_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_47);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_168(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_171_env );(((struct scm_lambda_171_env *)(&((var_3)->env)))->v_k_47)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_171,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_168_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_168);
+
+#line 1 "numbers.scm"
+static void scm_lambda_177(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_177_env *env = (struct scm_lambda_177_env *)(&((env_in)->env));call_closure_two((env)->v_rv_75,var_0,(env)->v_k_48);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_174(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_174_env *env = (struct scm_lambda_174_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_177_env );(((struct scm_lambda_177_env *)(&((var_2)->env)))->v_k_48)=((env)->v_k_48);(((struct scm_lambda_177_env *)(&((var_2)->env)))->v_rv_75)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_177,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_172(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,1e-6);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_174_env );(((struct scm_lambda_174_env *)(&((var_4)->env)))->v_k_48)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_174,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_172_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_172);
+
+#line 1 "numbers.scm"
+static void scm_lambda_178(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_178_env *env = (struct scm_lambda_178_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_49);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_175(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_178_env );(((struct scm_lambda_178_env *)(&((var_3)->env)))->v_k_49)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_178,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_175_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_175);
+
+#line 1 "numbers.scm"
+static void scm_lambda_184(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_184_env *env = (struct scm_lambda_184_env *)(&((env_in)->env));call_closure_two((env)->v_rv_78,var_0,(env)->v_k_50);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_181(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_181_env *env = (struct scm_lambda_181_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_184_env );(((struct scm_lambda_184_env *)(&((var_2)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_184_env *)(&((var_2)->env)))->v_rv_78)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_184,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_179(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,-0.0);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_181_env );(((struct scm_lambda_181_env *)(&((var_4)->env)))->v_k_50)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_181,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_179_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_179);
+
+#line 1 "numbers.scm"
+static void scm_lambda_185(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_185_env *env = (struct scm_lambda_185_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_51);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_182(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_185_env );(((struct scm_lambda_185_env *)(&((var_3)->env)))->v_k_51)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_185,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_182_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_182);
+
+#line 1 "numbers.scm"
+static void scm_lambda_190(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_190_env *env = (struct scm_lambda_190_env *)(&((env_in)->env));call_closure_two((env)->v_rv_81,var_0,(env)->v_k_52);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_188(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_188_env *env = (struct scm_lambda_188_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_190_env );(((struct scm_lambda_190_env *)(&((var_2)->env)))->v_k_52)=((env)->v_k_52);(((struct scm_lambda_190_env *)(&((var_2)->env)))->v_rv_81)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_190,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_186(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,1.2345678901234567e20);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_188_env );(((struct scm_lambda_188_env *)(&((var_4)->env)))->v_k_52)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_188,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_186_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_186);
+
+#line 1 "numbers.scm"
+static void scm_lambda_189(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_189_env *env = (struct scm_lambda_189_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_53);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_187(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_187_env *env = (struct scm_lambda_187_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_189_env );(((struct scm_lambda_189_env *)(&((var_2)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_189,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_183(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_183_env *env = (struct scm_lambda_183_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_187_env );(((struct scm_lambda_187_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_187,var_1);call_closure_two((struct obj *)(&(scm_lambda_186_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_180(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_180_env *env = (struct scm_lambda_180_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_183_env );(((struct scm_lambda_183_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_183,var_1);call_closure_two((struct obj *)(&(scm_lambda_182_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_176(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_176_env *env = (struct scm_lambda_176_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_180_env );(((struct scm_lambda_180_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_180,var_1);call_closure_two((struct obj *)(&(scm_lambda_179_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_173(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_173_env *env = (struct scm_lambda_173_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_176_env );(((struct scm_lambda_176_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_176,var_1);call_closure_two((struct obj *)(&(scm_lambda_175_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_169(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_169_env *env = (struct scm_lambda_169_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_173_env );(((struct scm_lambda_173_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_173,var_1);call_closure_two((struct obj *)(&(scm_lambda_172_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_166(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_166_env *env = (struct scm_lambda_166_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_169_env );(((struct scm_lambda_169_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_169,var_1);call_closure_two((struct obj *)(&(scm_lambda_168_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_162(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_162_env *env = (struct scm_lambda_162_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_166_env );(((struct scm_lambda_166_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_166,var_1);call_closure_two((struct obj *)(&(scm_lambda_165_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_159(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_159_env *env = (struct scm_lambda_159_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_162_env );(((struct scm_lambda_162_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_162,var_1);call_closure_two((struct obj *)(&(scm_lambda_161_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_155(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_155_env *env = (struct scm_lambda_155_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_159_env );(((struct scm_lambda_159_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_159,var_1);call_closure_two((struct obj *)(&(scm_lambda_158_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_152(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_152_env *env = (struct scm_lambda_152_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_155_env );(((struct scm_lambda_155_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_155,var_1);call_closure_two((struct obj *)(&(scm_lambda_154_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_148(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_148_env *env = (struct scm_lambda_148_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_152_env );(((struct scm_lambda_152_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_152,var_1);call_closure_two((struct obj *)(&(scm_lambda_151_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_145(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_145_env *env = (struct scm_lambda_145_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_148_env );(((struct scm_lambda_148_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_148,var_1);call_closure_two((struct obj *)(&(scm_lambda_147_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_141(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_141_env *env = (struct scm_lambda_141_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_145_env );(((struct scm_lambda_145_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_145,var_1);call_closure_two((struct obj *)(&(scm_lambda_144_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_138(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_138_env *env = (struct scm_lambda_138_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_141_env );(((struct scm_lambda_141_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_141,var_1);call_closure_two((struct obj *)(&(scm_lambda_140_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_134(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_134_env *env = (struct scm_lambda_134_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_138_env );(((struct scm_lambda_138_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_138,var_1);call_closure_two((struct obj *)(&(scm_lambda_137_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_131(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_131_env *env = (struct scm_lambda_131_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_134_env );(((struct scm_lambda_134_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_134,var_1);call_closure_two((struct obj *)(&(scm_lambda_133_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_127(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_131_env );(((struct scm_lambda_131_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_131,var_1);call_closure_two((struct obj *)(&(scm_lambda_130_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_124(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_124_env *env = (struct scm_lambda_124_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_127,var_1);call_closure_two((struct obj *)(&(scm_lambda_126_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_120(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_120_env *env = (struct scm_lambda_120_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_124_env );(((struct scm_lambda_124_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_124,var_1);call_closure_two((struct obj *)(&(scm_lambda_123_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_117(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_117_env *env = (struct scm_lambda_117_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_120_env );(((struct scm_lambda_120_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_120,var_1);call_closure_two((struct obj *)(&(scm_lambda_119_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_117_env );(((struct scm_lambda_117_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_117,var_1);call_closure_two((struct obj *)(&(scm_lambda_116_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_113,var_1);call_closure_two((struct obj *)(&(scm_lambda_112_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_110,var_1);call_closure_two((struct obj *)(&(scm_lambda_109_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_102(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_106,var_1);call_closure_two((struct obj *)(&(scm_lambda_105_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_102,var_1);call_closure_two((struct obj *)(&(scm_lambda_101_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_98,var_1);call_closure_two((struct obj *)(&(scm_lambda_97_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_95,var_1);call_closure_two((struct obj *)(&(scm_lambda_94_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_91,var_1);call_closure_two((struct obj *)(&(scm_lambda_90_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_88,var_1);call_closure_two((struct obj *)(&(scm_lambda_87_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_84,var_1);call_closure_two((struct obj *)(&(scm_lambda_83_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_81,var_1);call_closure_two((struct obj *)(&(scm_lambda_80_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_77,var_1);call_closure_two((struct obj *)(&(scm_lambda_76_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_74,var_1);call_closure_two((struct obj *)(&(scm_lambda_73_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_70,var_1);call_closure_two((struct obj *)(&(scm_lambda_69_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_67,var_1);call_closure_two((struct obj *)(&(scm_lambda_66_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_63,var_1);call_closure_two((struct obj *)(&(scm_lambda_62_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_60,var_1);call_closure_two((struct obj *)(&(scm_lambda_59_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_56,var_1);call_closure_two((struct obj *)(&(scm_lambda_55_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_53,var_1);call_closure_two((struct obj *)(&(scm_lambda_52_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_49,var_1);call_closure_two((struct obj *)(&(scm_lambda_48_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_46,var_1);call_closure_two((struct obj *)(&(scm_lambda_45_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_41,var_1);call_closure_two((struct obj *)(&(scm_lambda_40_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_37,var_1);call_closure_two((struct obj *)(&(scm_lambda_36_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_33,var_1);call_closure_two((struct obj *)(&(scm_lambda_32_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_30,var_1);call_closure_two((struct obj *)(&(scm_lambda_29_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_26,var_1);call_closure_two((struct obj *)(&(scm_lambda_25_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_23,var_1);call_closure_two((struct obj *)(&(scm_lambda_22_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_19,var_1);call_closure_two((struct obj *)(&(scm_lambda_18_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_16,var_1);call_closure_two((struct obj *)(&(scm_lambda_15_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_12,var_1);call_closure_two((struct obj *)(&(scm_lambda_11_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two((struct obj *)(&(scm_lambda_8_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_6,var_1);call_closure_two((struct obj *)(&(scm_lambda_5_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_4,var_1);call_closure_two((env)->v_rv_137,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_2)->env)))->v_k_53)=((env)->v_k_53);(((struct scm_lambda_3_env *)(&((var_2)->env)))->v_rv_137)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_3,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "numbers.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_3)->env)))->v_k_53)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_2,var_3);call_closure_two(var_2,MAKE_FIXNUM(-5),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "numbers.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 1024 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
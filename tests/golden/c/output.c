@@ -0,0 +1,190 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_9_env {struct obj *v_k;struct obj *v_rv_2;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_k;};
+static void scm_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_4_closure;
+struct scm_lambda_10_env {struct obj *v_k_2;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_7_closure;
+struct scm_lambda_13_env {struct obj *v_k_3;struct obj *v_out;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_out;};
+static void scm_lambda_11(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_4;struct obj *v_rv_6;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_4;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_4;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_4;struct obj *v_rv_10;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_4;struct obj *v_rv_10;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_k_4;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_16_closure;
+struct scm_lambda_22_env {struct obj *v_k_5;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_19(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_19_closure;
+struct scm_lambda_29_env {struct obj *v_k_6;struct obj *v_rv_13;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_6;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_out;};
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_7;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_7;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_7;struct obj *v_out;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_7;struct obj *v_out;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_7;struct obj *v_out;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k_7;struct obj *v_out;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_k_7;struct obj *v_out;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_7;struct obj *v_out;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_7;struct obj *v_out;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_9),"scm_lambda_9","output.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","output.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","output.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","output.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","output.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","output.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","output.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","output.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","output.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","output.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","output.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","output.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","output.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","output.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","output.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","output.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","output.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","output.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","output.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","output.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","output.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","output.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","output.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","output.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","output.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","output.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","output.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","output.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","output.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","output.scm",1},{NULL}};
+
+#line 1 "output.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_lambda_9_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_9,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"to the current port");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_4)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_6,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_4_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_4);
+
+#line 1 "output.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_10,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_7_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_7);
+
+#line 1 "output.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_out))->val,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_11(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"to the port given");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_4)->env)))->v_k_3)=(var_1);(((struct scm_lambda_13_env *)(&((var_4)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_13,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_31,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two((env)->v_rv_10,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_25_env *)(&((var_1)->env)))->v_rv_10)=((env)->v_rv_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_25,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_STRING_OBJ_NEW(var_2,"two");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_rv_10)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_1,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_18,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_16_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_16);
+
+#line 1 "output.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_19(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_22,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_19_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_19);
+
+#line 1 "output.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));call_closure_two((env)->v_rv_13,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_rv_13)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_26,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_out))->val,var_4);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_27,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_23,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_24,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_20,var_1);call_closure_two((struct obj *)(&(scm_lambda_19_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_17,var_1);call_closure_two((struct obj *)(&(scm_lambda_16_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_15_env *)(&((var_2)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_15,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_out))->val,var_3);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_11,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_12,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_8_env *)(&((var_1)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_8,var_1);call_closure_two((struct obj *)(&(scm_lambda_7_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_out))->val)=(var_0);GC_WRITE_BARRIER((env)->v_out,((struct cell_obj *)((env)->v_out))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two((struct obj *)(&(scm_lambda_4_closure)),NULL,var_2);__builtin_unreachable();}
+
+#line 1 "output.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_CLOSURE_TWO_NEW(var_3,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_7)=(var_1);(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_out)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "output.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "output.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 171 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
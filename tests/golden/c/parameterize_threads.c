@@ -0,0 +1,864 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_8_env {struct obj *v_k;struct obj *v__u7cconverter;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_3_closure;
+static void scm__u7cconverter(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7cconverter_closure;
+struct scm_lambda_34_env {struct obj *v_k_3;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_3;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_3;struct obj *v_rv_5;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_3;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k_3;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_p;};
+static void scm_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_4;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_4;struct obj *v_rv_18;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_rv_18;struct obj *v__u7cparameter0;};
+static void scm_lambda_35(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_94_env {struct obj *v_k_5;struct obj *v_rv_10;};
+static void scm_lambda_94(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_k_5;};
+static void scm_lambda_80(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_5;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_p;};
+static void scm_lambda_60(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_k_6;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_6;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_6;struct obj *v_p;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_p;};
+static void scm_lambda_44(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_7;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v__u7cparameter0;};
+static void scm_lambda_53(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_9;struct obj *v__u7cparameter0;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_9;struct obj *v_p;struct obj *v__u7cparameter0;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_9;struct obj *v_p;struct obj *v__u7cparameter0;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_9;struct obj *v_p;struct obj *v__u7cparameter0;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_9;struct obj *v_p;};
+static void scm_lambda_13(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_k_9;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_p;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_10;struct obj *v_rv_22;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_10;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_10;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_p;};
+static void scm_lambda_15(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_11;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_20_closure;
+struct scm_lambda_95_env {struct obj *v_k_12;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_k_12;struct obj *v_rv_36;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_rv_36;struct obj *v__u7cparameter0_2;};
+static void scm_lambda_71(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_156_env {struct obj *v_k_13;struct obj *v_rv_28;};
+static void scm_lambda_156(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_146_env {struct obj *v_k_13;};
+static void scm_lambda_146(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_129_env {struct obj *v_k_13;};
+static void scm_lambda_129(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_p;};
+static void scm_lambda_111(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_130_env {struct obj *v_k_14;};
+static void scm_lambda_130(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_112_env {struct obj *v_k_14;};
+static void scm_lambda_112(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_96_env {struct obj *v_k_14;struct obj *v_p;};
+static void scm_lambda_96(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_82_env {struct obj *v_p;};
+static void scm_lambda_82(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_113_env {struct obj *v_k_15;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *v__u7cparameter0_2;};
+static void scm_lambda_97(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *v_k_17;struct obj *v__u7cparameter0_2;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_k_17;struct obj *v_p;struct obj *v__u7cparameter0_2;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_17;struct obj *v_p;struct obj *v__u7cparameter0_2;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_17;struct obj *v_p;struct obj *v__u7cparameter0_2;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_17;struct obj *v_p;};
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_17;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_p;};
+static void scm_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_k_18;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_k_18;struct obj *v_rv_51;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_rv_51;struct obj *v__u7cparameter0_3;};
+static void scm_lambda_73(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_157_env {struct obj *v_k_19;struct obj *v_rv_42;};
+static void scm_lambda_157(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_147_env {struct obj *v_k_19;};
+static void scm_lambda_147(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_131_env {struct obj *v_k_19;};
+static void scm_lambda_131(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_114_env {struct obj *v_p;};
+static void scm_lambda_114(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_148_env {struct obj *v_k_20;};
+static void scm_lambda_148(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_132(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_132_closure;
+struct scm_lambda_133_env {struct obj *v_k_21;};
+static void scm_lambda_133(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_115_env {struct obj *v_k_21;};
+static void scm_lambda_115(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_99_env {struct obj *v_k_21;struct obj *v_p;};
+static void scm_lambda_99(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_85_env {struct obj *v_p;};
+static void scm_lambda_85(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_116_env {struct obj *v_k_22;};
+static void scm_lambda_116(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *v__u7cparameter0_3;};
+static void scm_lambda_100(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_86_env {struct obj *v_k_24;struct obj *v__u7cparameter0_3;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_24;struct obj *v_p;struct obj *v__u7cparameter0_3;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_24;struct obj *v_p;struct obj *v__u7cparameter0_3;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_24;struct obj *v_p;struct obj *v__u7cparameter0_3;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_24;struct obj *v_p;};
+static void scm_lambda_39(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_24;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_p;};
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_k_25;struct obj *v_rv_55;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_25;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_25;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_p;};
+static void scm_lambda_41(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_26;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_49(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_49_closure;
+struct scm_lambda_117_env {struct obj *v_k_27;};
+static void scm_lambda_117(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_k_27;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_k_27;struct obj *v_rv_61;};
+static void scm_lambda_87(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_27;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_66(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_66_closure;
+struct scm_lambda_134_env {struct obj *v_k_28;struct obj *v_rv_63;};
+static void scm_lambda_134(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_k_28;};
+static void scm_lambda_118(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_102(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_102_closure;
+struct scm_lambda_119_env {struct obj *v_k_29;};
+static void scm_lambda_119(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_103_env {struct obj *v_k_29;};
+static void scm_lambda_103(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_k_29;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_76(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_76_closure;
+struct scm_lambda_149_env {struct obj *v_k_30;struct obj *v_rv_68;};
+static void scm_lambda_149(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_135_env {struct obj *v_k_30;};
+static void scm_lambda_135(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_120(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_120_closure;
+struct scm_lambda_150_env {struct obj *v_k_31;};
+static void scm_lambda_150(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_136(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_136_closure;
+struct scm_lambda_137_env {struct obj *v_k_32;};
+static void scm_lambda_137(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_121_env {struct obj *v_k_32;};
+static void scm_lambda_121(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_k_32;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_89(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_89_closure;
+struct scm_lambda_158_env {struct obj *v_k_33;struct obj *v_rv_74;};
+static void scm_lambda_158(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_151_env {struct obj *v_k_33;};
+static void scm_lambda_151(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_138(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_138_closure;
+struct scm_lambda_152_env {struct obj *v_k_34;};
+static void scm_lambda_152(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_139_env {struct obj *v_k_34;};
+static void scm_lambda_139(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_122_env {struct obj *v_k_34;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_105(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_105_closure;
+struct scm_lambda_140_env {struct obj *v_k_35;struct obj *v_rv_79;};
+static void scm_lambda_140(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_123_env {struct obj *v_k_35;};
+static void scm_lambda_123(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *v_k_35;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_90_env {struct obj *v_k_35;};
+static void scm_lambda_90(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_k_35;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_67(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_67_closure;
+struct scm_lambda_91_env {struct obj *v_k_36;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_78(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_78_closure;
+static void scm_lambda_107(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_107_closure;
+struct scm_lambda_167_env {struct obj *v_k_38;};
+static void scm_lambda_167(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_164_env {struct obj *v_k_38;struct obj *v_rv_89;};
+static void scm_lambda_164(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_162_env {struct obj *v_rv_89;struct obj *v__u7cparameter0_4;};
+static void scm_lambda_162(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_165(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_165_closure;
+struct scm_lambda_169_env {struct obj *v_k_40;};
+static void scm_lambda_169(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_168_env {struct obj *v__u7cparameter0_4;};
+static void scm_lambda_168(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_166_env {struct obj *v_k_42;struct obj *v__u7cparameter0_4;};
+static void scm_lambda_166(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_163_env {struct obj *v_k_42;struct obj *v__u7cparameter0_4;};
+static void scm_lambda_163(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_161_env {struct obj *v_k_42;struct obj *v__u7cparameter0_4;};
+static void scm_lambda_161(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_159_env {struct obj *v_k_42;struct obj *v__u7cparameter0_4;};
+static void scm_lambda_159(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_141_env {struct obj *v_k_42;};
+static void scm_lambda_141(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_142_env {struct obj *v_k_42;};
+static void scm_lambda_142(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_124_env {struct obj *v_p;};
+static void scm_lambda_124(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_154_env {struct obj *v_k_43;struct obj *v_rv_93;};
+static void scm_lambda_154(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_143_env {struct obj *v_k_43;};
+static void scm_lambda_143(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_125_env {struct obj *v_k_43;};
+static void scm_lambda_125(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_108_env {struct obj *v_k_43;struct obj *v_p;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_92_env {struct obj *v_p;};
+static void scm_lambda_92(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_126_env {struct obj *v_k_44;};
+static void scm_lambda_126(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_109(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_109_closure;
+struct scm_lambda_160_env {struct obj *v_k_45;struct obj *v_rv_98;};
+static void scm_lambda_160(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_155_env {struct obj *v_k_45;};
+static void scm_lambda_155(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_144_env {struct obj *v_k_45;};
+static void scm_lambda_144(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_127_env {struct obj *v_p;};
+static void scm_lambda_127(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_145_env {struct obj *v_k_46;};
+static void scm_lambda_145(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_128_env {struct obj *v_k_46;};
+static void scm_lambda_128(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_93_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_46;struct obj *v_p;};
+static void scm_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_8),"scm_lambda_8","parameterize_threads.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","parameterize_threads.scm",1},{(void *)(scm__u7cconverter),"scm__u7cconverter","parameterize_threads.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","parameterize_threads.scm",2},{(void *)(scm_lambda_28),"scm_lambda_28","parameterize_threads.scm",2},{(void *)(scm_lambda_22),"scm_lambda_22","parameterize_threads.scm",2},{(void *)(scm_lambda_17),"scm_lambda_17","parameterize_threads.scm",2},{(void *)(scm_lambda_12),"scm_lambda_12","parameterize_threads.scm",2},{(void *)(scm_lambda_9),"scm_lambda_9","parameterize_threads.scm",2},{(void *)(scm_lambda_51),"scm_lambda_51","parameterize_threads.scm",3},{(void *)(scm_lambda_43),"scm_lambda_43","parameterize_threads.scm",3},{(void *)(scm_lambda_35),"scm_lambda_35","parameterize_threads.scm",3},{(void *)(scm_lambda_94),"scm_lambda_94","parameterize_threads.scm",3},{(void *)(scm_lambda_80),"scm_lambda_80","parameterize_threads.scm",3},{(void *)(scm_lambda_69),"scm_lambda_69","parameterize_threads.scm",3},{(void *)(scm_lambda_60),"scm_lambda_60","parameterize_threads.scm",3},{(void *)(scm_lambda_70),"scm_lambda_70","parameterize_threads.scm",3},{(void *)(scm_lambda_61),"scm_lambda_61","parameterize_threads.scm",3},{(void *)(scm_lambda_52),"scm_lambda_52","parameterize_threads.scm",3},{(void *)(scm_lambda_44),"scm_lambda_44","parameterize_threads.scm",3},{(void *)(scm_lambda_62),"scm_lambda_62","parameterize_threads.scm",3},{(void *)(scm_lambda_53),"scm_lambda_53","parameterize_threads.scm",3},{(void *)(scm_lambda_45),"scm_lambda_45","parameterize_threads.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","parameterize_threads.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","parameterize_threads.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","parameterize_threads.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","parameterize_threads.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","parameterize_threads.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","parameterize_threads.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","parameterize_threads.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","parameterize_threads.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","parameterize_threads.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","parameterize_threads.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","parameterize_threads.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","parameterize_threads.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","parameterize_threads.scm",8},{(void *)(scm_lambda_81),"scm_lambda_81","parameterize_threads.scm",8},{(void *)(scm_lambda_71),"scm_lambda_71","parameterize_threads.scm",8},{(void *)(scm_lambda_156),"scm_lambda_156","parameterize_threads.scm",8},{(void *)(scm_lambda_146),"scm_lambda_146","parameterize_threads.scm",8},{(void *)(scm_lambda_129),"scm_lambda_129","parameterize_threads.scm",8},{(void *)(scm_lambda_111),"scm_lambda_111","parameterize_threads.scm",8},{(void *)(scm_lambda_130),"scm_lambda_130","parameterize_threads.scm",8},{(void *)(scm_lambda_112),"scm_lambda_112","parameterize_threads.scm",8},{(void *)(scm_lambda_96),"scm_lambda_96","parameterize_threads.scm",8},{(void *)(scm_lambda_82),"scm_lambda_82","parameterize_threads.scm",8},{(void *)(scm_lambda_113),"scm_lambda_113","parameterize_threads.scm",8},{(void *)(scm_lambda_97),"scm_lambda_97","parameterize_threads.scm",8},{(void *)(scm_lambda_83),"scm_lambda_83","parameterize_threads.scm",7},{(void *)(scm_lambda_72),"scm_lambda_72","parameterize_threads.scm",7},{(void *)(scm_lambda_63),"scm_lambda_63","parameterize_threads.scm",7},{(void *)(scm_lambda_54),"scm_lambda_54","parameterize_threads.scm",7},{(void *)(scm_lambda_37),"scm_lambda_37","parameterize_threads.scm",7},{(void *)(scm_lambda_38),"scm_lambda_38","parameterize_threads.scm",7},{(void *)(scm_lambda_31),"scm_lambda_31","parameterize_threads.scm",7},{(void *)(scm_lambda_98),"scm_lambda_98","parameterize_threads.scm",11},{(void *)(scm_lambda_84),"scm_lambda_84","parameterize_threads.scm",11},{(void *)(scm_lambda_73),"scm_lambda_73","parameterize_threads.scm",11},{(void *)(scm_lambda_157),"scm_lambda_157","parameterize_threads.scm",11},{(void *)(scm_lambda_147),"scm_lambda_147","parameterize_threads.scm",11},{(void *)(scm_lambda_131),"scm_lambda_131","parameterize_threads.scm",11},{(void *)(scm_lambda_114),"scm_lambda_114","parameterize_threads.scm",11},{(void *)(scm_lambda_148),"scm_lambda_148","parameterize_threads.scm",11},{(void *)(scm_lambda_132),"scm_lambda_132","parameterize_threads.scm",11},{(void *)(scm_lambda_133),"scm_lambda_133","parameterize_threads.scm",11},{(void *)(scm_lambda_115),"scm_lambda_115","parameterize_threads.scm",11},{(void *)(scm_lambda_99),"scm_lambda_99","parameterize_threads.scm",11},{(void *)(scm_lambda_85),"scm_lambda_85","parameterize_threads.scm",11},{(void *)(scm_lambda_116),"scm_lambda_116","parameterize_threads.scm",11},{(void *)(scm_lambda_100),"scm_lambda_100","parameterize_threads.scm",11},{(void *)(scm_lambda_86),"scm_lambda_86","parameterize_threads.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","parameterize_threads.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","parameterize_threads.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","parameterize_threads.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","parameterize_threads.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","parameterize_threads.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","parameterize_threads.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","parameterize_threads.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","parameterize_threads.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","parameterize_threads.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","parameterize_threads.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","parameterize_threads.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","parameterize_threads.scm",1},{(void *)(scm_lambda_117),"scm_lambda_117","parameterize_threads.scm",16},{(void *)(scm_lambda_101),"scm_lambda_101","parameterize_threads.scm",16},{(void *)(scm_lambda_87),"scm_lambda_87","parameterize_threads.scm",16},{(void *)(scm_lambda_75),"scm_lambda_75","parameterize_threads.scm",16},{(void *)(scm_lambda_66),"scm_lambda_66","parameterize_threads.scm",16},{(void *)(scm_lambda_134),"scm_lambda_134","parameterize_threads.scm",19},{(void *)(scm_lambda_118),"scm_lambda_118","parameterize_threads.scm",19},{(void *)(scm_lambda_102),"scm_lambda_102","parameterize_threads.scm",19},{(void *)(scm_lambda_119),"scm_lambda_119","parameterize_threads.scm",19},{(void *)(scm_lambda_103),"scm_lambda_103","parameterize_threads.scm",19},{(void *)(scm_lambda_88),"scm_lambda_88","parameterize_threads.scm",19},{(void *)(scm_lambda_76),"scm_lambda_76","parameterize_threads.scm",19},{(void *)(scm_lambda_149),"scm_lambda_149","parameterize_threads.scm",20},{(void *)(scm_lambda_135),"scm_lambda_135","parameterize_threads.scm",20},{(void *)(scm_lambda_120),"scm_lambda_120","parameterize_threads.scm",20},{(void *)(scm_lambda_150),"scm_lambda_150","parameterize_threads.scm",20},{(void *)(scm_lambda_136),"scm_lambda_136","parameterize_threads.scm",20},{(void *)(scm_lambda_137),"scm_lambda_137","parameterize_threads.scm",20},{(void *)(scm_lambda_121),"scm_lambda_121","parameterize_threads.scm",20},{(void *)(scm_lambda_104),"scm_lambda_104","parameterize_threads.scm",20},{(void *)(scm_lambda_89),"scm_lambda_89","parameterize_threads.scm",20},{(void *)(scm_lambda_158),"scm_lambda_158","parameterize_threads.scm",21},{(void *)(scm_lambda_151),"scm_lambda_151","parameterize_threads.scm",21},{(void *)(scm_lambda_138),"scm_lambda_138","parameterize_threads.scm",21},{(void *)(scm_lambda_152),"scm_lambda_152","parameterize_threads.scm",21},{(void *)(scm_lambda_139),"scm_lambda_139","parameterize_threads.scm",21},{(void *)(scm_lambda_122),"scm_lambda_122","parameterize_threads.scm",21},{(void *)(scm_lambda_105),"scm_lambda_105","parameterize_threads.scm",21},{(void *)(scm_lambda_140),"scm_lambda_140","parameterize_threads.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","parameterize_threads.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","parameterize_threads.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","parameterize_threads.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","parameterize_threads.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","parameterize_threads.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","parameterize_threads.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","parameterize_threads.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","parameterize_threads.scm",24},{(void *)(scm_lambda_167),"scm_lambda_167","parameterize_threads.scm",26},{(void *)(scm_lambda_164),"scm_lambda_164","parameterize_threads.scm",26},{(void *)(scm_lambda_162),"scm_lambda_162","parameterize_threads.scm",26},{(void *)(scm_lambda_165),"scm_lambda_165","parameterize_threads.scm",26},{(void *)(scm_lambda_169),"scm_lambda_169","parameterize_threads.scm",26},{(void *)(scm_lambda_168),"scm_lambda_168","parameterize_threads.scm",26},{(void *)(scm_lambda_166),"scm_lambda_166","parameterize_threads.scm",25},{(void *)(scm_lambda_163),"scm_lambda_163","parameterize_threads.scm",25},{(void *)(scm_lambda_161),"scm_lambda_161","parameterize_threads.scm",25},{(void *)(scm_lambda_159),"scm_lambda_159","parameterize_threads.scm",25},{(void *)(scm_lambda_141),"scm_lambda_141","parameterize_threads.scm",25},{(void *)(scm_lambda_142),"scm_lambda_142","parameterize_threads.scm",25},{(void *)(scm_lambda_124),"scm_lambda_124","parameterize_threads.scm",25},{(void *)(scm_lambda_154),"scm_lambda_154","parameterize_threads.scm",1},{(void *)(scm_lambda_143),"scm_lambda_143","parameterize_threads.scm",1},{(void *)(scm_lambda_125),"scm_lambda_125","parameterize_threads.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","parameterize_threads.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","parameterize_threads.scm",1},{(void *)(scm_lambda_126),"scm_lambda_126","parameterize_threads.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","parameterize_threads.scm",1},{(void *)(scm_lambda_160),"scm_lambda_160","parameterize_threads.scm",1},{(void *)(scm_lambda_155),"scm_lambda_155","parameterize_threads.scm",1},{(void *)(scm_lambda_144),"scm_lambda_144","parameterize_threads.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","parameterize_threads.scm",1},{(void *)(scm_lambda_145),"scm_lambda_145","parameterize_threads.scm",1},{(void *)(scm_lambda_128),"scm_lambda_128","parameterize_threads.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","parameterize_threads.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","parameterize_threads.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","parameterize_threads.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","parameterize_threads.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","parameterize_threads.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","parameterize_threads.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","parameterize_threads.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","parameterize_threads.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","parameterize_threads.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","parameterize_threads.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","parameterize_threads.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","parameterize_threads.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","parameterize_threads.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","parameterize_threads.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","parameterize_threads.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","parameterize_threads.scm",1},{NULL}};
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v__u7cconverter,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,make_parameter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v__u7cconverter)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_3_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_3);
+
+#line 1 "parameterize_threads.scm"
+static void scm__u7cconverter(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm__u7cconverter_closure = CLOSURE_TWO_OBJ_INIT(scm__u7cconverter);
+
+#line 2 "parameterize_threads.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 2 "parameterize_threads.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_34,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 2 "parameterize_threads.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_28,var_1);call_closure_two((env)->v_rv_5,var_0,var_2);__builtin_unreachable();}
+
+#line 2 "parameterize_threads.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_rv_5)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 2 "parameterize_threads.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_17,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 2 "parameterize_threads.scm"
+static void scm_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_12,var_2);call_closure_two(((struct cell_obj *)((env)->v_p))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));call_closure_one((env)->v_k_4,NULL);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_51,var_1);call_closure_two(var_0,(env)->v_rv_18,var_2);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_35(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_3)->env)))->v_k_4)=(var_1);(((struct scm_lambda_43_env *)(&((var_3)->env)))->v_rv_18)=((env)->v_rv_18);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_43,var_3);call_closure_two(var_2,(env)->v__u7cparameter0,var_4);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_94(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));call_closure_two((env)->v_rv_10,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_80(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_rv_10)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_94,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_80,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_60(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_2)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_69,var_2);call_closure_two(((struct cell_obj *)((env)->v_p))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_70,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_60,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_61,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_44(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_k_6)=(var_1);(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_52,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));call_closure_one((env)->v_k_7,NULL);__builtin_unreachable();}
+
+#line 3 "parameterize_threads.scm"
+static void scm_lambda_53(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_62,var_3);call_closure_two(var_2,(env)->v__u7cparameter0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_53,var_1);call_closure_two(var_0,var_2,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_44,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_3)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_45_env *)(&((var_3)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_45,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_rv_18)=(var_0);(((struct scm_lambda_35_env *)(&((var_2)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_35,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_4)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_36_env *)(&((var_4)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_36_env *)(&((var_4)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_36,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_29_env *)(&((var_1)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_29,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_13(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_23_env *)(&((var_3)->env)))->v__u7cparameter0)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(2),(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_k_9)=(var_1);(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_13,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_4)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_14,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_p))->val,var_5);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));call_closure_two((env)->v_rv_22,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_30_env *)(&((var_2)->env)))->v_rv_22)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_30,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_24,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_15(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_2)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_19,var_2);call_closure_two(((struct cell_obj *)((env)->v_p))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_20_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_20);
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));call_closure_one((env)->v_k_12,NULL);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_95,var_1);call_closure_two(var_0,(env)->v_rv_36,var_2);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_71(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_3)->env)))->v_k_12)=(var_1);(((struct scm_lambda_81_env *)(&((var_3)->env)))->v_rv_36)=((env)->v_rv_36);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_81,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_2,var_4);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_156(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_156_env *env = (struct scm_lambda_156_env *)(&((env_in)->env));call_closure_two((env)->v_rv_28,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_146(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_146_env *env = (struct scm_lambda_146_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_156_env );(((struct scm_lambda_156_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_156_env *)(&((var_2)->env)))->v_rv_28)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_156,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_129(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_129_env *env = (struct scm_lambda_129_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_146_env );(((struct scm_lambda_146_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_146,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_111(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_129_env );(((struct scm_lambda_129_env *)(&((var_2)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_129,var_2);call_closure_two(((struct cell_obj *)((env)->v_p))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_130(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_130_env *env = (struct scm_lambda_130_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_112(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_130_env );(((struct scm_lambda_130_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_130,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_96(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_111,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_3)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_112,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_82(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_3)->env)))->v_k_14)=(var_1);(((struct scm_lambda_96_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_96,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));call_closure_one((env)->v_k_15,NULL);__builtin_unreachable();}
+
+#line 8 "parameterize_threads.scm"
+static void scm_lambda_97(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_113,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_2,var_4);__builtin_unreachable();}
+
+#line 7 "parameterize_threads.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_1)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_97,var_1);call_closure_two(var_0,var_2,(env)->v_k_17);__builtin_unreachable();}
+
+#line 7 "parameterize_threads.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_82,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_3)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_83_env *)(&((var_3)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_83,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 7 "parameterize_threads.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_rv_36)=(var_0);(((struct scm_lambda_71_env *)(&((var_2)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_71,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_4)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_72_env *)(&((var_4)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_72_env *)(&((var_4)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_72,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 7 "parameterize_threads.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_63_env *)(&((var_1)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_63,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 7 "parameterize_threads.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_54_env *)(&((var_3)->env)))->v__u7cparameter0_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_54,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 7 "parameterize_threads.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(3),(env)->v_k_17);__builtin_unreachable();}
+
+#line 7 "parameterize_threads.scm"
+static void scm_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_k_17)=(var_1);(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_37,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_4)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_38,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_p))->val,var_5);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));call_closure_one((env)->v_k_18,NULL);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_98,var_1);call_closure_two(var_0,(env)->v_rv_51,var_2);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_73(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_3)->env)))->v_k_18)=(var_1);(((struct scm_lambda_84_env *)(&((var_3)->env)))->v_rv_51)=((env)->v_rv_51);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_84,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_3,var_4);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_157(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_157_env *env = (struct scm_lambda_157_env *)(&((env_in)->env));call_closure_two((env)->v_rv_42,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_147(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_147_env *env = (struct scm_lambda_147_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_157_env );(((struct scm_lambda_157_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_157_env *)(&((var_2)->env)))->v_rv_42)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_157,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_131(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_131_env *env = (struct scm_lambda_131_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_147_env );(((struct scm_lambda_147_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_147,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_114(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_114_env *env = (struct scm_lambda_114_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_131_env );(((struct scm_lambda_131_env *)(&((var_2)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_131,var_2);call_closure_two(((struct cell_obj *)((env)->v_p))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_148(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_148_env *env = (struct scm_lambda_148_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_132(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_148_env );(((struct scm_lambda_148_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_148,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_132_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_132);
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_133(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_133_env *env = (struct scm_lambda_133_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,yield_k,NULL);call_closure_two(var_1,NULL,(env)->v_k_21);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_115(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_115_env *env = (struct scm_lambda_115_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_133_env );(((struct scm_lambda_133_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_133,var_1);call_closure_two((struct obj *)(&(scm_lambda_132_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_99(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_114_env );(((struct scm_lambda_114_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_114,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_115_env );(((struct scm_lambda_115_env *)(&((var_3)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_115,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_85(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_3)->env)))->v_k_21)=(var_1);(((struct scm_lambda_99_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_99,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_116(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_116_env *env = (struct scm_lambda_116_env *)(&((env_in)->env));call_closure_one((env)->v_k_22,NULL);__builtin_unreachable();}
+
+#line 11 "parameterize_threads.scm"
+static void scm_lambda_100(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_116_env );(((struct scm_lambda_116_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_116,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_3,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_1)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_100,var_1);call_closure_two(var_0,var_2,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_85,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_86_env *)(&((var_3)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_86,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_rv_51)=(var_0);(((struct scm_lambda_73_env *)(&((var_2)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_73,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_4)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_74_env *)(&((var_4)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_74_env *)(&((var_4)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_74,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_64_env *)(&((var_1)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_64_env *)(&((var_1)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_64,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_39(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_3)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_55_env *)(&((var_3)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_55_env *)(&((var_3)->env)))->v__u7cparameter0_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_55,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(4),(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_24)=(var_1);(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_39,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_4)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_40,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_p))->val,var_5);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));call_closure_two((env)->v_rv_55,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_rv_55)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_65,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_56,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_41(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_48,var_2);call_closure_two(((struct cell_obj *)((env)->v_p))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_49(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_3)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_57,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_49_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_49);
+
+#line 16 "parameterize_threads.scm"
+static void scm_lambda_117(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_117_env *env = (struct scm_lambda_117_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_27);__builtin_unreachable();}
+
+#line 16 "parameterize_threads.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_117_env );(((struct scm_lambda_117_env *)(&((var_2)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_117,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 16 "parameterize_threads.scm"
+static void scm_lambda_87(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_k_27)=((env)->v_k_27);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_101,var_1);call_closure_two((env)->v_rv_61,var_0,var_2);__builtin_unreachable();}
+
+#line 16 "parameterize_threads.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_2)->env)))->v_k_27)=((env)->v_k_27);(((struct scm_lambda_87_env *)(&((var_2)->env)))->v_rv_61)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_87,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 16 "parameterize_threads.scm"
+static void scm_lambda_66(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"other thread");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_4)->env)))->v_k_27)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_75,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_66_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_66);
+
+#line 19 "parameterize_threads.scm"
+static void scm_lambda_134(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_134_env *env = (struct scm_lambda_134_env *)(&((env_in)->env));call_closure_two((env)->v_rv_63,var_0,(env)->v_k_28);__builtin_unreachable();}
+
+#line 19 "parameterize_threads.scm"
+static void scm_lambda_118(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_134_env );(((struct scm_lambda_134_env *)(&((var_2)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_lambda_134_env *)(&((var_2)->env)))->v_rv_63)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_134,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 19 "parameterize_threads.scm"
+static void scm_lambda_102(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"before");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_4)->env)))->v_k_28)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_118,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_102_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_102);
+
+#line 19 "parameterize_threads.scm"
+static void scm_lambda_119(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_119_env *env = (struct scm_lambda_119_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_29);__builtin_unreachable();}
+
+#line 19 "parameterize_threads.scm"
+static void scm_lambda_103(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_119_env );(((struct scm_lambda_119_env *)(&((var_2)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_119,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 19 "parameterize_threads.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_103,var_1);call_closure_two((struct obj *)(&(scm_lambda_102_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 19 "parameterize_threads.scm"
+static void scm_lambda_76(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_3)->env)))->v_k_29)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_88,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_76_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_76);
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_149(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_149_env *env = (struct scm_lambda_149_env *)(&((env_in)->env));call_closure_two((env)->v_rv_68,var_0,(env)->v_k_30);__builtin_unreachable();}
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_135(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_135_env *env = (struct scm_lambda_135_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_149_env );(((struct scm_lambda_149_env *)(&((var_2)->env)))->v_k_30)=((env)->v_k_30);(((struct scm_lambda_149_env *)(&((var_2)->env)))->v_rv_68)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_149,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_120(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"during");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_135_env );(((struct scm_lambda_135_env *)(&((var_4)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_135,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_120_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_120);
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_150(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_150_env *env = (struct scm_lambda_150_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_31);__builtin_unreachable();}
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_136(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_150_env );(((struct scm_lambda_150_env *)(&((var_3)->env)))->v_k_31)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_150,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_136_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_136);
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_137(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_137_env *env = (struct scm_lambda_137_env *)(&((env_in)->env));call_closure_one((env)->v_k_32,MAKE_FIXNUM(5));__builtin_unreachable();}
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_121(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_121_env *env = (struct scm_lambda_121_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_137_env );(((struct scm_lambda_137_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_137,var_1);call_closure_two((struct obj *)(&(scm_lambda_136_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_121_env );(((struct scm_lambda_121_env *)(&((var_1)->env)))->v_k_32)=((env)->v_k_32);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_121,var_1);call_closure_two((struct obj *)(&(scm_lambda_120_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 20 "parameterize_threads.scm"
+static void scm_lambda_89(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_3)->env)))->v_k_32)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_104,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_89_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_89);
+
+#line 21 "parameterize_threads.scm"
+static void scm_lambda_158(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_158_env *env = (struct scm_lambda_158_env *)(&((env_in)->env));call_closure_two((env)->v_rv_74,var_0,(env)->v_k_33);__builtin_unreachable();}
+
+#line 21 "parameterize_threads.scm"
+static void scm_lambda_151(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_151_env *env = (struct scm_lambda_151_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_158_env );(((struct scm_lambda_158_env *)(&((var_2)->env)))->v_k_33)=((env)->v_k_33);(((struct scm_lambda_158_env *)(&((var_2)->env)))->v_rv_74)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_158,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 21 "parameterize_threads.scm"
+static void scm_lambda_138(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"after");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_151_env );(((struct scm_lambda_151_env *)(&((var_4)->env)))->v_k_33)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_151,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_138_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_138);
+
+#line 21 "parameterize_threads.scm"
+static void scm_lambda_152(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_152_env *env = (struct scm_lambda_152_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_34);__builtin_unreachable();}
+
+#line 21 "parameterize_threads.scm"
+static void scm_lambda_139(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_139_env *env = (struct scm_lambda_139_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_152_env );(((struct scm_lambda_152_env *)(&((var_2)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_152,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 21 "parameterize_threads.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_139_env );(((struct scm_lambda_139_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_139,var_1);call_closure_two((struct obj *)(&(scm_lambda_138_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 21 "parameterize_threads.scm"
+static void scm_lambda_105(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_3)->env)))->v_k_34)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_122,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_105_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_105);
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_140(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_140_env *env = (struct scm_lambda_140_env *)(&((env_in)->env));call_closure_two((env)->v_rv_79,var_0,(env)->v_k_35);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_123(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_123_env *env = (struct scm_lambda_123_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_140_env );(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_k_35)=((env)->v_k_35);(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_rv_79)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_140,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_123_env );(((struct scm_lambda_123_env *)(&((var_2)->env)))->v_k_35)=((env)->v_k_35);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_123,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_90(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_90_env *env = (struct scm_lambda_90_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_1)->env)))->v_k_35)=((env)->v_k_35);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_106,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_105_closure)),var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_90_env );(((struct scm_lambda_90_env *)(&((var_1)->env)))->v_k_35)=((env)->v_k_35);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_90,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_89_closure)),var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_67(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_3)->env)))->v_k_35)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_77,var_3);call_closure_two(var_2,(struct obj *)(&(scm_lambda_76_closure)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_67_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_67);
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_36);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_78(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_3)->env)))->v_k_36)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_91,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_78_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_78);
+
+#line 24 "parameterize_threads.scm"
+static void scm_lambda_107(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm_lambda_107_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_107);
+
+#line 26 "parameterize_threads.scm"
+static void scm_lambda_167(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_167_env *env = (struct scm_lambda_167_env *)(&((env_in)->env));call_closure_one((env)->v_k_38,NULL);__builtin_unreachable();}
+
+#line 26 "parameterize_threads.scm"
+static void scm_lambda_164(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_164_env *env = (struct scm_lambda_164_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_167_env );(((struct scm_lambda_167_env *)(&((var_1)->env)))->v_k_38)=((env)->v_k_38);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_167,var_1);call_closure_two(var_0,(env)->v_rv_89,var_2);__builtin_unreachable();}
+
+#line 26 "parameterize_threads.scm"
+static void scm_lambda_162(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_162_env *env = (struct scm_lambda_162_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_164_env );(((struct scm_lambda_164_env *)(&((var_3)->env)))->v_k_38)=(var_1);(((struct scm_lambda_164_env *)(&((var_3)->env)))->v_rv_89)=((env)->v_rv_89);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_164,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_4,var_4);__builtin_unreachable();}
+
+#line 26 "parameterize_threads.scm"
+static void scm_lambda_165(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,raise_k,NULL);call_closure_two(var_2,MAKE_FIXNUM(7),var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_165_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_165);
+
+#line 26 "parameterize_threads.scm"
+static void scm_lambda_169(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_169_env *env = (struct scm_lambda_169_env *)(&((env_in)->env));call_closure_one((env)->v_k_40,NULL);__builtin_unreachable();}
+
+#line 26 "parameterize_threads.scm"
+static void scm_lambda_168(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_168_env *env = (struct scm_lambda_168_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_169_env );(((struct scm_lambda_169_env *)(&((var_3)->env)))->v_k_40)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_169,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_4,var_4);__builtin_unreachable();}
+
+#line 25 "parameterize_threads.scm"
+static void scm_lambda_166(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_166_env *env = (struct scm_lambda_166_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_168_env );(((struct scm_lambda_168_env *)(&((var_1)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_168,var_1);call_closure_two(var_0,var_2,(env)->v_k_42);__builtin_unreachable();}
+
+#line 25 "parameterize_threads.scm"
+static void scm_lambda_163(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_163_env *env = (struct scm_lambda_163_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_166_env );(((struct scm_lambda_166_env *)(&((var_1)->env)))->v_k_42)=((env)->v_k_42);(((struct scm_lambda_166_env *)(&((var_1)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_166,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_165_closure)),var_2);__builtin_unreachable();}
+
+#line 25 "parameterize_threads.scm"
+static void scm_lambda_161(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_161_env *env = (struct scm_lambda_161_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_162_env );(((struct scm_lambda_162_env *)(&((var_2)->env)))->v_rv_89)=(var_0);(((struct scm_lambda_162_env *)(&((var_2)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_162,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_163_env );(((struct scm_lambda_163_env *)(&((var_4)->env)))->v_k_42)=((env)->v_k_42);(((struct scm_lambda_163_env *)(&((var_4)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_163,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 25 "parameterize_threads.scm"
+static void scm_lambda_159(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_159_env *env = (struct scm_lambda_159_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_161_env );(((struct scm_lambda_161_env *)(&((var_1)->env)))->v_k_42)=((env)->v_k_42);(((struct scm_lambda_161_env *)(&((var_1)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_161,var_1);call_closure_two(var_0,MAKE_FIXNUM(6),var_2);__builtin_unreachable();}
+
+#line 25 "parameterize_threads.scm"
+static void scm_lambda_141(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_141_env *env = (struct scm_lambda_141_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_159_env );(((struct scm_lambda_159_env *)(&((var_3)->env)))->v_k_42)=((env)->v_k_42);(((struct scm_lambda_159_env *)(&((var_3)->env)))->v__u7cparameter0_4)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_159,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 25 "parameterize_threads.scm"
+static void scm_lambda_142(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_142_env *env = (struct scm_lambda_142_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(6),(env)->v_k_42);__builtin_unreachable();}
+
+#line 25 "parameterize_threads.scm"
+static void scm_lambda_124(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_124_env *env = (struct scm_lambda_124_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_141_env );(((struct scm_lambda_141_env *)(&((var_2)->env)))->v_k_42)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_141,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_142_env );(((struct scm_lambda_142_env *)(&((var_4)->env)))->v_k_42)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_142,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_p))->val,var_5);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_154(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_154_env *env = (struct scm_lambda_154_env *)(&((env_in)->env));call_closure_two((env)->v_rv_93,var_0,(env)->v_k_43);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_143(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_143_env *env = (struct scm_lambda_143_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_154_env );(((struct scm_lambda_154_env *)(&((var_2)->env)))->v_k_43)=((env)->v_k_43);(((struct scm_lambda_154_env *)(&((var_2)->env)))->v_rv_93)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_154,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_125(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_125_env *env = (struct scm_lambda_125_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_143_env );(((struct scm_lambda_143_env *)(&((var_2)->env)))->v_k_43)=((env)->v_k_43);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_143,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_124_env );(((struct scm_lambda_124_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_124,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_125_env );(((struct scm_lambda_125_env *)(&((var_3)->env)))->v_k_43)=((env)->v_k_43);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_125,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_92(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,with_exception_handler_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_3)->env)))->v_k_43)=(var_1);(((struct scm_lambda_108_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_108,var_3);call_closure_two(var_2,(struct obj *)(&(scm_lambda_107_closure)),var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_126(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_126_env *env = (struct scm_lambda_126_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_44);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_109(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_126_env );(((struct scm_lambda_126_env *)(&((var_3)->env)))->v_k_44)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_126,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_109_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_109);
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_160(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_160_env *env = (struct scm_lambda_160_env *)(&((env_in)->env));call_closure_two((env)->v_rv_98,var_0,(env)->v_k_45);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_155(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_155_env *env = (struct scm_lambda_155_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_160_env );(((struct scm_lambda_160_env *)(&((var_2)->env)))->v_k_45)=((env)->v_k_45);(((struct scm_lambda_160_env *)(&((var_2)->env)))->v_rv_98)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_160,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_144(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_144_env *env = (struct scm_lambda_144_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_155_env );(((struct scm_lambda_155_env *)(&((var_2)->env)))->v_k_45)=((env)->v_k_45);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_155,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_127(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_144_env );(((struct scm_lambda_144_env *)(&((var_2)->env)))->v_k_45)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_144,var_2);call_closure_two(((struct cell_obj *)((env)->v_p))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_145(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_145_env *env = (struct scm_lambda_145_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_46);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_128(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_128_env *env = (struct scm_lambda_128_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_145_env );(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_k_46)=((env)->v_k_46);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_145,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_127,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_128_env );(((struct scm_lambda_128_env *)(&((var_3)->env)))->v_k_46)=((env)->v_k_46);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_128,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_1)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_110_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_110,var_1);call_closure_two((struct obj *)(&(scm_lambda_109_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_92,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_93_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_93,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_1)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_79_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_79,var_1);call_closure_two((struct obj *)(&(scm_lambda_78_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_1)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_68_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_68,var_1);call_closure_two((struct obj *)(&(scm_lambda_67_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,spawn_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_59,var_2);call_closure_two(var_1,(struct obj *)(&(scm_lambda_66_closure)),var_3);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_1)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_50_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_50,var_1);call_closure_two((struct obj *)(&(scm_lambda_49_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_41,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_3)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_42_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_42,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_32,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,spawn_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_31,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_4)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_27_env *)(&((var_4)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_27,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_21_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two((struct obj *)(&(scm_lambda_20_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_15,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_16,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_10,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_3)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_11_env *)(&((var_3)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_11,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_p))->val)=(var_0);GC_WRITE_BARRIER((env)->v_p,((struct cell_obj *)((env)->v_p))->val);OBJECT_CLOSURE_TWO_NEW(var_1,spawn_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_2)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_9,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_4)->env)))->v_k_46)=((env)->v_k_46);(((struct scm_lambda_7_env *)(&((var_4)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_7,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_k_46)=(var_1);(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_p)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_4,var_3);call_closure_two((struct obj *)(&(scm_lambda_3_closure)),(struct obj *)(&(scm__u7cconverter_closure)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "parameterize_threads.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "parameterize_threads.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 845 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,1208 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_18_env {struct obj *v_k;struct obj *v__u7cconverter;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_13(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_13_closure;
+static void scm__u7cconverter(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7cconverter_closure;
+struct scm_lambda_26_env {struct obj *v_k_3;struct obj *v_rv_3;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_3;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_3;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_radix;};
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_4;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_20_closure;
+struct scm_lambda_58_env {struct obj *v_k_5;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_5;struct obj *v_rv_11;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_rv_11;struct obj *v__u7cparameter0;};
+static void scm_lambda_47(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_radix;};
+static void scm_lambda_53(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_7;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v__u7cparameter0;};
+static void scm_lambda_59(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_8;struct obj *v__u7cparameter0;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_8;struct obj *v_radix;struct obj *v__u7cparameter0;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_8;struct obj *v_radix;struct obj *v__u7cparameter0;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *parent;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_8;struct obj *v_radix;struct obj *v__u7cparameter0;struct obj *v__u7cvalue0;};
+static void scm_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_radix;struct obj *v__u7cparameter0;};
+static void scm_lambda_27(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_k_10;struct obj *v_rv_14;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_10;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_10;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_10;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_radix;};
+static void scm_lambda_24(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_11;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_29(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_29_closure;
+struct scm_lambda_49_env {struct obj *v_k_12;struct obj *v_rv_19;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_12;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_12;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_radix;};
+static void scm_lambda_34(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_13;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_39(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_39_closure;
+struct scm_nested_lambda_11_env {struct obj *v_k_14;};
+static void scm_nested_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_8_env {struct obj *v_k_14;struct obj *v_rv_47;};
+static void scm_nested_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_6_env {struct obj *v_rv_47;struct obj *v__u7cparameter0_3;};
+static void scm_nested_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_20_env {struct obj *v_k_15;};
+static void scm_nested_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_nested_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_nested_lambda_18_closure;
+struct scm_nested_lambda_33_env {struct obj *v_k_16;};
+static void scm_nested_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_30_env {struct obj *v_k_16;struct obj *v_rv_35;};
+static void scm_nested_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_28_env {struct obj *v_rv_35;struct obj *v__u7cparameter0_2;};
+static void scm_nested_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_40_env {struct obj *v_k_17;};
+static void scm_nested_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_39_env {struct obj *v_k_17;};
+static void scm_nested_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_38_env {struct obj *v_k_17;struct obj *v_rv_30;};
+static void scm_nested_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_36_env {struct obj *v_k_17;};
+static void scm_nested_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_34_env {struct obj *v_k_17;};
+static void scm_nested_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_31_env {struct obj *v_radix;};
+static void scm_nested_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_37_env {struct obj *v_k_18;};
+static void scm_nested_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_35_env {struct obj *v__u7cparameter0_2;};
+static void scm_nested_lambda_35(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_32_env {struct obj *v_k_20;struct obj *v__u7cparameter0_2;};
+static void scm_nested_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_29_env {struct obj *v_k_20;struct obj *v_radix;struct obj *v__u7cparameter0_2;};
+static void scm_nested_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_27_env {struct obj *v_k_20;struct obj *v_radix;struct obj *v__u7cparameter0_2;};
+static void scm_nested_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_26_env {struct obj *v_k_20;struct obj *v_radix;struct obj *v__u7cparameter0_2;};
+static void scm_nested_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_23_env {struct obj *v_k_20;struct obj *v_radix;};
+static void scm_nested_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_24_env {struct obj *v_k_20;};
+static void scm_nested_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_21_env {struct obj *v_radix;};
+static void scm_nested_lambda_21(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_22_env {struct obj *v_k_21;struct obj *v_radix;};
+static void scm_nested_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_19_env {struct obj *v_k_21;struct obj *v_radix;};
+static void scm_nested_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_17_env {struct obj *v_k_21;struct obj *v_radix;};
+static void scm_nested_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_16_env {struct obj *v_k_21;struct obj *v_radix;struct obj *v_rv_42;};
+static void scm_nested_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_14_env {struct obj *v_k_21;struct obj *v_radix;};
+static void scm_nested_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_12_env {struct obj *v_k_21;struct obj *v_radix;};
+static void scm_nested_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_9_env {struct obj *v_radix;};
+static void scm_nested_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_15_env {struct obj *v_k_22;};
+static void scm_nested_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_13_env {struct obj *v__u7cparameter0_3;};
+static void scm_nested_lambda_13(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_10_env {struct obj *v_k_24;struct obj *v__u7cparameter0_3;};
+static void scm_nested_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_7_env {struct obj *v_k_24;struct obj *v_radix;struct obj *v__u7cparameter0_3;};
+static void scm_nested_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_5_env {struct obj *v_k_24;struct obj *v_radix;struct obj *v__u7cparameter0_3;};
+static void scm_nested_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_4_env {struct obj *v_k_24;struct obj *v_radix;struct obj *v__u7cparameter0_3;};
+static void scm_nested_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_1_env {struct obj *v_k_24;struct obj *v_radix;};
+static void scm_nested_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_lambda_2_env {struct obj *v_k_24;};
+static void scm_nested_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_nested_env {struct obj *v_radix;};
+static void scm_nested(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_k_25;struct obj *v_rv_51;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_25;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_25;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_nested;};
+static void scm_lambda_50(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_26;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_56(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_56_closure;
+struct scm_lambda_74_env {struct obj *v_k_27;struct obj *v__u7cconverter_2;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_k_27;struct obj *v__u7cconverter_2;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_66(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_66_closure;
+struct scm__u7cconverter_2_lambda_1_env {struct obj *v_k_28;};
+static void scm__u7cconverter_2_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm__u7cconverter_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm__u7cconverter_2_closure;
+struct scm_lambda_67_env {struct obj *v_k_29;struct obj *v_width;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_width;};
+static void scm_lambda_62(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_30;struct obj *v_rv_59;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_30;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_30;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_width;};
+static void scm_lambda_68(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_76_env {struct obj *v_k_31;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_72(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_72_closure;
+struct scm_lambda_173_env {struct obj *v_k_32;struct obj *v__u7cvalue1;};
+static void scm_lambda_173(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_159_env {struct obj *v__u7cparameter1;struct obj *v__u7cvalue1;};
+static void scm_lambda_159(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_160_env {struct obj *v_k_33;};
+static void scm_lambda_160(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_149_env {struct obj *v_k_33;struct obj *v__u7cparameter1;struct obj *v__u7cvalue1;};
+static void scm_lambda_149(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_141_env {struct obj *parent;};
+static void scm_lambda_141(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_132_env {struct obj *v_k_33;struct obj *v_rv_76;struct obj *v__u7cparameter0_4;struct obj *v__u7cparameter1;struct obj *v__u7cvalue1;};
+static void scm_lambda_132(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_174_env {struct obj *v_k_34;struct obj *v_rv_67;};
+static void scm_lambda_174(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_161_env {struct obj *v_k_34;struct obj *v_radix;};
+static void scm_lambda_161(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_150_env {struct obj *v_k_34;struct obj *v_radix;};
+static void scm_lambda_150(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_142_env {struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_142(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_176_env {struct obj *v_k_35;};
+static void scm_lambda_176(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_162_env {struct obj *v_k_35;struct obj *v__u7cparameter0_4;};
+static void scm_lambda_162(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_151_env {struct obj *v__u7cparameter0_4;struct obj *v__u7cparameter1;};
+static void scm_lambda_151(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_143_env {struct obj *v_k_36;struct obj *v__u7cparameter0_4;struct obj *v__u7cparameter1;};
+static void scm_lambda_143(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_133_env {struct obj *parent;};
+static void scm_lambda_133(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_123_env {struct obj *v_k_36;struct obj *v_radix;struct obj *v_rv_76;struct obj *v_width;struct obj *v__u7cparameter0_4;struct obj *v__u7cparameter1;struct obj *v__u7cvalue1;};
+static void scm_lambda_123(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_134_env {struct obj *v_k_37;struct obj *v_rv_75;};
+static void scm_lambda_134(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_124_env {struct obj *v_k_37;struct obj *v_rv_75;struct obj *v__u7cvalue1_2;};
+static void scm_lambda_124(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_115_env {struct obj *v_k_37;struct obj *v__u7cparameter1;struct obj *v__u7cvalue1_2;};
+static void scm_lambda_115(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_107_env {struct obj *parent;struct obj *v_rv_76;};
+static void scm_lambda_107(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *parent;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_94_env {struct obj *v_k_37;struct obj *v_radix;struct obj *v_width;struct obj *v__u7cparameter0_4;struct obj *v__u7cparameter1;struct obj *v__u7cvalue0_2;struct obj *v__u7cvalue1_2;};
+static void scm_lambda_94(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v_radix;struct obj *v_width;struct obj *v__u7cparameter0_4;struct obj *v__u7cparameter1;struct obj *v__u7cvalue0_2;};
+static void scm_lambda_89(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_radix;struct obj *v_width;struct obj *v__u7cparameter0_4;struct obj *v__u7cvalue0_2;};
+static void scm_lambda_84(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_radix;struct obj *v_width;struct obj *v__u7cparameter0_4;};
+static void scm_lambda_80(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_108_env {struct obj *v_k_41;struct obj *v_rv_79;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_k_41;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_95_env {struct obj *v_k_41;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_90_env {struct obj *v_k_41;};
+static void scm_lambda_90(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_85_env {struct obj *v_k_41;struct obj *v_radix;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_k_41;struct obj *v_radix;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_77(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_86_env {struct obj *v_k_42;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_82(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_82_closure;
+struct scm_lambda_102_env {struct obj *v_k_43;struct obj *v_rv_86;};
+static void scm_lambda_102(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_96_env {struct obj *v_k_43;};
+static void scm_lambda_96(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_91_env {struct obj *v_k_43;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_width;};
+static void scm_lambda_87(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *v_k_44;};
+static void scm_lambda_97(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_92(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_92_closure;
+struct scm_lambda_103_env {struct obj *v_radix;};
+static void scm_lambda_103(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_177_env {struct obj *v_k_46;};
+static void scm_lambda_177(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_163_env {struct obj *v_k_46;struct obj *v_rv_94;};
+static void scm_lambda_163(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_152_env {struct obj *v_rv_94;struct obj *v__u7cparameter0_5;};
+static void scm_lambda_152(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_164(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_164_closure;
+struct scm_lambda_186_env {struct obj *v_k_48;};
+static void scm_lambda_186(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_178_env {struct obj *v__u7cparameter0_5;};
+static void scm_lambda_178(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_165_env {struct obj *v_k_50;struct obj *v__u7cparameter0_5;};
+static void scm_lambda_165(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_153_env {struct obj *v_k_50;struct obj *v__u7cparameter0_5;};
+static void scm_lambda_153(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_144_env {struct obj *v_k_50;struct obj *v__u7cparameter0_5;};
+static void scm_lambda_144(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_135_env {struct obj *v_k_50;struct obj *v__u7cparameter0_5;};
+static void scm_lambda_135(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_116_env {struct obj *v_k_50;};
+static void scm_lambda_116(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_117_env {struct obj *v_k_50;};
+static void scm_lambda_117(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_109_env {struct obj *v_radix;};
+static void scm_lambda_109(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_126_env {struct obj *v_k_51;struct obj *v_rv_98;};
+static void scm_lambda_126(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_k_51;};
+static void scm_lambda_118(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *v_k_51;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_k_51;struct obj *v_radix;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_radix;};
+static void scm_lambda_98(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_k_52;};
+static void scm_lambda_111(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_105(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_105_closure;
+struct scm_lambda_179_env {struct obj *v_k_53;};
+static void scm_lambda_179(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_166_env {struct obj *v_k_53;struct obj *v_rv_107;};
+static void scm_lambda_166(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_154_env {struct obj *v_rv_107;struct obj *v__u7cparameter0_6;};
+static void scm_lambda_154(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_180_env {struct obj *v_radix;};
+static void scm_lambda_180(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_167_env {struct obj *v_radix;};
+static void scm_lambda_167(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_187_env {struct obj *v_k_56;};
+static void scm_lambda_187(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_181_env {struct obj *v__u7cparameter0_6;};
+static void scm_lambda_181(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_168_env {struct obj *v_k_57;struct obj *v__u7cparameter0_6;};
+static void scm_lambda_168(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_155_env {struct obj *v_k_57;struct obj *v_radix;struct obj *v__u7cparameter0_6;};
+static void scm_lambda_155(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_145_env {struct obj *v_k_57;struct obj *v_radix;struct obj *v__u7cparameter0_6;};
+static void scm_lambda_145(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_136_env {struct obj *parent;};
+static void scm_lambda_136(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_127_env {struct obj *v_k_57;struct obj *v_radix;struct obj *v__u7cparameter0_6;struct obj *v__u7cvalue0_3;};
+static void scm_lambda_127(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_119_env {struct obj *v_get_radix;struct obj *v_k_59;struct obj *v_radix;struct obj *v__u7cparameter0_6;};
+static void scm_lambda_119(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_128_env {struct obj *v_get_radix;struct obj *v_k_59;};
+static void scm_lambda_128(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_120_env {struct obj *v_get_radix;struct obj *v_k_59;};
+static void scm_lambda_120(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_112_env {struct obj *v_get_radix;struct obj *v_radix;};
+static void scm_lambda_112(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_146_env {struct obj *v_k_60;struct obj *v_rv_112;};
+static void scm_lambda_146(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_137_env {struct obj *v_k_60;};
+static void scm_lambda_137(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_129_env {struct obj *v_k_60;};
+static void scm_lambda_129(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_121_env {struct obj *v_get_radix;};
+static void scm_lambda_121(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_138_env {struct obj *v_k_61;};
+static void scm_lambda_138(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_130(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_130_closure;
+struct scm_lambda_188_env {struct obj *v_k_62;struct obj *v_rv_116;};
+static void scm_lambda_188(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_182_env {struct obj *v_k_62;};
+static void scm_lambda_182(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_169_env {struct obj *v_k_62;};
+static void scm_lambda_169(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_156_env {struct obj *v_k_62;struct obj *v_radix;};
+static void scm_lambda_156(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_147_env {struct obj *v_p;struct obj *v_radix;};
+static void scm_lambda_147(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_170_env {struct obj *v_k_63;};
+static void scm_lambda_170(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_157(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_157_closure;
+struct scm_lambda_193_env {struct obj *v_k_64;struct obj *v_rv_121;};
+static void scm_lambda_193(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_189_env {struct obj *v_k_64;};
+static void scm_lambda_189(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_183_env {struct obj *v_k_64;};
+static void scm_lambda_183(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_171_env {struct obj *v_radix;};
+static void scm_lambda_171(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_190_env {struct obj *v_k_65;};
+static void scm_lambda_190(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_184(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_184_closure;
+struct scm_lambda_198_env {struct obj *v_k_66;struct obj *v_rv_125;};
+static void scm_lambda_198(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_196_env {struct obj *v_k_66;};
+static void scm_lambda_196(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_194_env {struct obj *v_make_parameter_count;};
+static void scm_lambda_194(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_197_env {struct obj *v_k_67;};
+static void scm_lambda_197(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_195_env {struct obj *v_k_67;};
+static void scm_lambda_195(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_185_env {struct obj *v_k_67;struct obj *v_make_parameter_count;};
+static void scm_lambda_185(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_172_env {struct obj *v_k_67;struct obj *v_make_parameter_count;};
+static void scm_lambda_172(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_158_env {struct obj *v_k_67;struct obj *v_make_parameter_count;struct obj *v_radix;};
+static void scm_lambda_158(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_148_env {struct obj *v_k_67;struct obj *v_make_parameter_count;struct obj *v_radix;};
+static void scm_lambda_148(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_131_env {struct obj *parent;};
+static void scm_lambda_131(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_122_env {struct obj *v_k_67;struct obj *v_make_parameter_count;struct obj *v_p;struct obj *v_radix;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_113_env {struct obj *parent;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *parent;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_99_env {struct obj *parent;};
+static void scm_lambda_99(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_93_env {struct obj *v_get_radix;struct obj *v_k_67;struct obj *v_make_parameter_count;struct obj *v_p;struct obj *v_radix;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *parent;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *parent;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *parent;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_get_radix;struct obj *v_k_67;struct obj *v_make_parameter_count;struct obj *v_p;struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *parent;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *parent;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *parent;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_get_radix;struct obj *v_k_67;struct obj *v_make_parameter_count;struct obj *v_p;struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *parent;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *parent;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *parent;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_get_radix;struct obj *v_k_67;struct obj *v_make_parameter_count;struct obj *v_nested;struct obj *v_p;struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *parent;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *parent;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *parent;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_get_radix;struct obj *v_k_67;struct obj *v_make_parameter_count;struct obj *v_nested;struct obj *v_p;struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_get_radix;struct obj *v_nested;struct obj *v_p;struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_get_radix;struct obj *v_nested;struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_nested;struct obj *v_radix;struct obj *v_width;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_nested;struct obj *v_radix;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_radix;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_73;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_73;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_73;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_73;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_73;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_lambda_128_inlined_env {struct obj *v_get_radix;struct obj *v_k_59;};
+static void scm_lambda_128_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj symbol_oops = SYMBOL_OBJ_INIT("oops");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_oops),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_18),"scm_lambda_18","parameters.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","parameters.scm",1},{(void *)(scm__u7cconverter),"scm__u7cconverter","parameters.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","parameters.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","parameters.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","parameters.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","parameters.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","parameters.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","parameters.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","parameters.scm",3},{(void *)(scm_lambda_52),"scm_lambda_52","parameters.scm",3},{(void *)(scm_lambda_47),"scm_lambda_47","parameters.scm",3},{(void *)(scm_lambda_53),"scm_lambda_53","parameters.scm",3},{(void *)(scm_lambda_64),"scm_lambda_64","parameters.scm",3},{(void *)(scm_lambda_59),"scm_lambda_59","parameters.scm",3},{(void *)(scm_lambda_54),"scm_lambda_54","parameters.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","parameters.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","parameters.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","parameters.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","parameters.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","parameters.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","parameters.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","parameters.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","parameters.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","parameters.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","parameters.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","parameters.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","parameters.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","parameters.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","parameters.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","parameters.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","parameters.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","parameters.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","parameters.scm",1},{(void *)(scm_nested_lambda_11),"scm_nested_lambda_11","parameters.scm",6},{(void *)(scm_nested_lambda_8),"scm_nested_lambda_8","parameters.scm",6},{(void *)(scm_nested_lambda_6),"scm_nested_lambda_6","parameters.scm",6},{(void *)(scm_nested_lambda_20),"scm_nested_lambda_20","parameters.scm",6},{(void *)(scm_nested_lambda_18),"scm_nested_lambda_18","parameters.scm",6},{(void *)(scm_nested_lambda_33),"scm_nested_lambda_33","parameters.scm",8},{(void *)(scm_nested_lambda_30),"scm_nested_lambda_30","parameters.scm",8},{(void *)(scm_nested_lambda_28),"scm_nested_lambda_28","parameters.scm",8},{(void *)(scm_nested_lambda_40),"scm_nested_lambda_40","parameters.scm",8},{(void *)(scm_nested_lambda_39),"scm_nested_lambda_39","parameters.scm",8},{(void *)(scm_nested_lambda_38),"scm_nested_lambda_38","parameters.scm",8},{(void *)(scm_nested_lambda_36),"scm_nested_lambda_36","parameters.scm",8},{(void *)(scm_nested_lambda_34),"scm_nested_lambda_34","parameters.scm",8},{(void *)(scm_nested_lambda_31),"scm_nested_lambda_31","parameters.scm",8},{(void *)(scm_nested_lambda_37),"scm_nested_lambda_37","parameters.scm",8},{(void *)(scm_nested_lambda_35),"scm_nested_lambda_35","parameters.scm",8},{(void *)(scm_nested_lambda_32),"scm_nested_lambda_32","parameters.scm",6},{(void *)(scm_nested_lambda_29),"scm_nested_lambda_29","parameters.scm",6},{(void *)(scm_nested_lambda_27),"scm_nested_lambda_27","parameters.scm",6},{(void *)(scm_nested_lambda_26),"scm_nested_lambda_26","parameters.scm",6},{(void *)(scm_nested_lambda_23),"scm_nested_lambda_23","parameters.scm",6},{(void *)(scm_nested_lambda_24),"scm_nested_lambda_24","parameters.scm",6},{(void *)(scm_nested_lambda_21),"scm_nested_lambda_21","parameters.scm",6},{(void *)(scm_nested_lambda_22),"scm_nested_lambda_22","parameters.scm",6},{(void *)(scm_nested_lambda_19),"scm_nested_lambda_19","parameters.scm",6},{(void *)(scm_nested_lambda_17),"scm_nested_lambda_17","parameters.scm",6},{(void *)(scm_nested_lambda_16),"scm_nested_lambda_16","parameters.scm",6},{(void *)(scm_nested_lambda_14),"scm_nested_lambda_14","parameters.scm",6},{(void *)(scm_nested_lambda_12),"scm_nested_lambda_12","parameters.scm",6},{(void *)(scm_nested_lambda_9),"scm_nested_lambda_9","parameters.scm",6},{(void *)(scm_nested_lambda_15),"scm_nested_lambda_15","parameters.scm",6},{(void *)(scm_nested_lambda_13),"scm_nested_lambda_13","parameters.scm",6},{(void *)(scm_nested_lambda_10),"scm_nested_lambda_10","parameters.scm",5},{(void *)(scm_nested_lambda_7),"scm_nested_lambda_7","parameters.scm",5},{(void *)(scm_nested_lambda_5),"scm_nested_lambda_5","parameters.scm",5},{(void *)(scm_nested_lambda_4),"scm_nested_lambda_4","parameters.scm",5},{(void *)(scm_nested_lambda_1),"scm_nested_lambda_1","parameters.scm",5},{(void *)(scm_nested_lambda_2),"scm_nested_lambda_2","parameters.scm",5},{(void *)(scm_nested),"scm_nested","parameters.scm",5},{(void *)(scm_lambda_65),"scm_lambda_65","parameters.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","parameters.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","parameters.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","parameters.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","parameters.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","parameters.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","parameters.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","parameters.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","parameters.scm",1},{(void *)(scm__u7cconverter_2_lambda_1),"scm__u7cconverter_2_lambda_1","parameters.scm",11},{(void *)(scm__u7cconverter_2),"scm__u7cconverter_2","parameters.scm",11},{(void *)(scm_lambda_67),"scm_lambda_67","parameters.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","parameters.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","parameters.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","parameters.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","parameters.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","parameters.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","parameters.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","parameters.scm",1},{(void *)(scm_lambda_173),"scm_lambda_173","parameters.scm",13},{(void *)(scm_lambda_159),"scm_lambda_159","parameters.scm",13},{(void *)(scm_lambda_160),"scm_lambda_160","parameters.scm",13},{(void *)(scm_lambda_149),"scm_lambda_149","parameters.scm",13},{(void *)(scm_lambda_141),"scm_lambda_141","parameters.scm",13},{(void *)(scm_lambda_132),"scm_lambda_132","parameters.scm",13},{(void *)(scm_lambda_174),"scm_lambda_174","parameters.scm",13},{(void *)(scm_lambda_161),"scm_lambda_161","parameters.scm",13},{(void *)(scm_lambda_150),"scm_lambda_150","parameters.scm",13},{(void *)(scm_lambda_142),"scm_lambda_142","parameters.scm",13},{(void *)(scm_lambda_176),"scm_lambda_176","parameters.scm",13},{(void *)(scm_lambda_162),"scm_lambda_162","parameters.scm",13},{(void *)(scm_lambda_151),"scm_lambda_151","parameters.scm",13},{(void *)(scm_lambda_143),"scm_lambda_143","parameters.scm",1},{(void *)(scm_lambda_133),"scm_lambda_133","parameters.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","parameters.scm",1},{(void *)(scm_lambda_134),"scm_lambda_134","parameters.scm",1},{(void *)(scm_lambda_124),"scm_lambda_124","parameters.scm",1},{(void *)(scm_lambda_115),"scm_lambda_115","parameters.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","parameters.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","parameters.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","parameters.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","parameters.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","parameters.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","parameters.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","parameters.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","parameters.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","parameters.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","parameters.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","parameters.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","parameters.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","parameters.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","parameters.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","parameters.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","parameters.scm",1},{(void *)(scm_lambda_96),"scm_lambda_96","parameters.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","parameters.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","parameters.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","parameters.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","parameters.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","parameters.scm",17},{(void *)(scm_lambda_177),"scm_lambda_177","parameters.scm",18},{(void *)(scm_lambda_163),"scm_lambda_163","parameters.scm",18},{(void *)(scm_lambda_152),"scm_lambda_152","parameters.scm",18},{(void *)(scm_lambda_164),"scm_lambda_164","parameters.scm",18},{(void *)(scm_lambda_186),"scm_lambda_186","parameters.scm",18},{(void *)(scm_lambda_178),"scm_lambda_178","parameters.scm",18},{(void *)(scm_lambda_165),"scm_lambda_165","parameters.scm",18},{(void *)(scm_lambda_153),"scm_lambda_153","parameters.scm",18},{(void *)(scm_lambda_144),"scm_lambda_144","parameters.scm",18},{(void *)(scm_lambda_135),"scm_lambda_135","parameters.scm",18},{(void *)(scm_lambda_116),"scm_lambda_116","parameters.scm",18},{(void *)(scm_lambda_117),"scm_lambda_117","parameters.scm",18},{(void *)(scm_lambda_109),"scm_lambda_109","parameters.scm",18},{(void *)(scm_lambda_126),"scm_lambda_126","parameters.scm",1},{(void *)(scm_lambda_118),"scm_lambda_118","parameters.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","parameters.scm",1},{(void *)(scm_lambda_104),"scm_lambda_104","parameters.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","parameters.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","parameters.scm",1},{(void *)(scm_lambda_105),"scm_lambda_105","parameters.scm",1},{(void *)(scm_lambda_179),"scm_lambda_179","parameters.scm",19},{(void *)(scm_lambda_166),"scm_lambda_166","parameters.scm",19},{(void *)(scm_lambda_154),"scm_lambda_154","parameters.scm",19},{(void *)(scm_lambda_180),"scm_lambda_180","parameters.scm",19},{(void *)(scm_lambda_167),"scm_lambda_167","parameters.scm",19},{(void *)(scm_lambda_187),"scm_lambda_187","parameters.scm",19},{(void *)(scm_lambda_181),"scm_lambda_181","parameters.scm",19},{(void *)(scm_lambda_168),"scm_lambda_168","parameters.scm",1},{(void *)(scm_lambda_155),"scm_lambda_155","parameters.scm",1},{(void *)(scm_lambda_145),"scm_lambda_145","parameters.scm",1},{(void *)(scm_lambda_136),"scm_lambda_136","parameters.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","parameters.scm",1},{(void *)(scm_lambda_119),"scm_lambda_119","parameters.scm",1},{(void *)(scm_lambda_128),"scm_lambda_128","parameters.scm",1},{(void *)(scm_lambda_120),"scm_lambda_120","parameters.scm",1},{(void *)(scm_lambda_112),"scm_lambda_112","parameters.scm",1},{(void *)(scm_lambda_146),"scm_lambda_146","parameters.scm",1},{(void *)(scm_lambda_137),"scm_lambda_137","parameters.scm",1},{(void *)(scm_lambda_129),"scm_lambda_129","parameters.scm",1},{(void *)(scm_lambda_121),"scm_lambda_121","parameters.scm",1},{(void *)(scm_lambda_138),"scm_lambda_138","parameters.scm",1},{(void *)(scm_lambda_130),"scm_lambda_130","parameters.scm",1},{(void *)(scm_lambda_188),"scm_lambda_188","parameters.scm",1},{(void *)(scm_lambda_182),"scm_lambda_182","parameters.scm",1},{(void *)(scm_lambda_169),"scm_lambda_169","parameters.scm",1},{(void *)(scm_lambda_156),"scm_lambda_156","parameters.scm",1},{(void *)(scm_lambda_147),"scm_lambda_147","parameters.scm",1},{(void *)(scm_lambda_170),"scm_lambda_170","parameters.scm",1},{(void *)(scm_lambda_157),"scm_lambda_157","parameters.scm",1},{(void *)(scm_lambda_193),"scm_lambda_193","parameters.scm",1},{(void *)(scm_lambda_189),"scm_lambda_189","parameters.scm",1},{(void *)(scm_lambda_183),"scm_lambda_183","parameters.scm",1},{(void *)(scm_lambda_171),"scm_lambda_171","parameters.scm",1},{(void *)(scm_lambda_190),"scm_lambda_190","parameters.scm",1},{(void *)(scm_lambda_184),"scm_lambda_184","parameters.scm",1},{(void *)(scm_lambda_198),"scm_lambda_198","parameters.scm",1},{(void *)(scm_lambda_196),"scm_lambda_196","parameters.scm",1},{(void *)(scm_lambda_194),"scm_lambda_194","parameters.scm",1},{(void *)(scm_lambda_197),"scm_lambda_197","parameters.scm",1},{(void *)(scm_lambda_195),"scm_lambda_195","parameters.scm",1},{(void *)(scm_lambda_185),"scm_lambda_185","parameters.scm",1},{(void *)(scm_lambda_172),"scm_lambda_172","parameters.scm",1},{(void *)(scm_lambda_158),"scm_lambda_158","parameters.scm",1},{(void *)(scm_lambda_148),"scm_lambda_148","parameters.scm",1},{(void *)(scm_lambda_131),"scm_lambda_131","parameters.scm",1},{(void *)(scm_lambda_122),"scm_lambda_122","parameters.scm",1},{(void *)(scm_lambda_113),"scm_lambda_113","parameters.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","parameters.scm",1},{(void *)(scm_lambda_99),"scm_lambda_99","parameters.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","parameters.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","parameters.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","parameters.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","parameters.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","parameters.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","parameters.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","parameters.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","parameters.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","parameters.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","parameters.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","parameters.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","parameters.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","parameters.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","parameters.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","parameters.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","parameters.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","parameters.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","parameters.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","parameters.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","parameters.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","parameters.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","parameters.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","parameters.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","parameters.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","parameters.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","parameters.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","parameters.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","parameters.scm",1},{(void *)(scm_lambda_128_inlined),"scm_lambda_128_inlined","parameters.scm",1},{NULL}};
+
+#line 1 "parameters.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v__u7cconverter,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_13(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,make_parameter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_lambda_18_env *)(&((var_3)->env)))->v__u7cconverter)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_18,var_3);call_closure_two(var_2,MAKE_FIXNUM(10),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_13_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_13);
+
+#line 1 "parameters.scm"
+static void scm__u7cconverter(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,var_0);__builtin_unreachable();}
+static struct closure_obj scm__u7cconverter_closure = CLOSURE_TWO_OBJ_INIT(scm__u7cconverter);
+
+#line 1 "parameters.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));call_closure_two((env)->v_rv_3,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_rv_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_26,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_2)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_19,var_2);call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_20_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_20);
+
+#line 3 "parameters.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));call_closure_one((env)->v_k_5,NULL);__builtin_unreachable();}
+
+#line 3 "parameters.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_58,var_1);call_closure_two(var_0,(env)->v_rv_11,var_2);__builtin_unreachable();}
+
+#line 3 "parameters.scm"
+static void scm_lambda_47(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_k_5)=(var_1);(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_rv_11)=((env)->v_rv_11);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_52,var_3);call_closure_two(var_2,(env)->v__u7cparameter0,var_4);__builtin_unreachable();}
+
+#line 3 "parameters.scm"
+static void scm_lambda_53(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,var_1);__builtin_unreachable();}
+
+#line 3 "parameters.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));call_closure_one((env)->v_k_7,NULL);__builtin_unreachable();}
+
+#line 3 "parameters.scm"
+static void scm_lambda_59(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,(env)->v__u7cparameter0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_1)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_59,var_1);call_closure_two(var_0,var_2,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_53,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_54_env *)(&((var_3)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_54,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_2)->env)))->v_rv_11)=(var_0);(((struct scm_lambda_47_env *)(&((var_2)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_47,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_4)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_48_env *)(&((var_4)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_48_env *)(&((var_4)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_48,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_k_8)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_8);(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix);(((struct scm_lambda_41_env *)(&((var_1)->env)))->v__u7cparameter0)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cparameter0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_41,var_1);call_closure_two(var_0,((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cvalue0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));((env)->v__u7cvalue0)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_8)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_36,var_3);call_closure_two(var_2,(env)->v__u7cparameter0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_27(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));((env)->v__u7cparameter0)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_31_env *)(&((var_2)->env)))->v__u7cparameter0)=((env)->v__u7cparameter0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_31,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));call_closure_two((env)->v_rv_14,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_rv_14)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_37,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_32,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_24(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_27,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_4)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_28,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_radix))->val,var_5);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_29(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_29_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_29);
+
+#line 1 "parameters.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));call_closure_two((env)->v_rv_19,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_rv_19)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_49,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_43,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_34(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_39(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_44,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_39_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_39);
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_11_env *env = (struct scm_nested_lambda_11_env *)(&((env_in)->env));call_closure_one((env)->v_k_14,NULL);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_8_env *env = (struct scm_nested_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_11_env );(((struct scm_nested_lambda_11_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nested_lambda_11,var_1);call_closure_two(var_0,(env)->v_rv_47,var_2);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_6_env *env = (struct scm_nested_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_8_env );(((struct scm_nested_lambda_8_env *)(&((var_3)->env)))->v_k_14)=(var_1);(((struct scm_nested_lambda_8_env *)(&((var_3)->env)))->v_rv_47)=((env)->v_rv_47);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_8,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_3,var_4);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_20_env *env = (struct scm_nested_lambda_20_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_20_env );(((struct scm_nested_lambda_20_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_20,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_nested_lambda_18_closure = CLOSURE_TWO_OBJ_INIT(scm_nested_lambda_18);
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_33_env *env = (struct scm_nested_lambda_33_env *)(&((env_in)->env));call_closure_one((env)->v_k_16,NULL);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_30_env *env = (struct scm_nested_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_33_env );(((struct scm_nested_lambda_33_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nested_lambda_33,var_1);call_closure_two(var_0,(env)->v_rv_35,var_2);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_28_env *env = (struct scm_nested_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_30_env );(((struct scm_nested_lambda_30_env *)(&((var_3)->env)))->v_k_16)=(var_1);(((struct scm_nested_lambda_30_env *)(&((var_3)->env)))->v_rv_35)=((env)->v_rv_35);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_30,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_2,var_4);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_40_env *env = (struct scm_nested_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_39_env *env = (struct scm_nested_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_40_env );(((struct scm_nested_lambda_40_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nested_lambda_40,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_38_env *env = (struct scm_nested_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_39_env );(((struct scm_nested_lambda_39_env *)(&((var_1)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nested_lambda_39,var_1);call_closure_two((env)->v_rv_30,var_0,var_2);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_36_env *env = (struct scm_nested_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_38_env );(((struct scm_nested_lambda_38_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_nested_lambda_38_env *)(&((var_2)->env)))->v_rv_30)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nested_lambda_38,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_34_env *env = (struct scm_nested_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_36_env );(((struct scm_nested_lambda_36_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nested_lambda_36,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_31_env *env = (struct scm_nested_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_34_env );(((struct scm_nested_lambda_34_env *)(&((var_2)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nested_lambda_34,var_2);call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_37_env *env = (struct scm_nested_lambda_37_env *)(&((env_in)->env));call_closure_one((env)->v_k_18,NULL);__builtin_unreachable();}
+
+#line 8 "parameters.scm"
+static void scm_nested_lambda_35(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_35_env *env = (struct scm_nested_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_37_env );(((struct scm_nested_lambda_37_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_37,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_2,var_4);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_32_env *env = (struct scm_nested_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_35_env );(((struct scm_nested_lambda_35_env *)(&((var_1)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_TWO_NEW(var_2,scm_nested_lambda_35,var_1);call_closure_two(var_0,var_2,(env)->v_k_20);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_29_env *env = (struct scm_nested_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_31_env );(((struct scm_nested_lambda_31_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_nested_lambda_31,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_32_env );(((struct scm_nested_lambda_32_env *)(&((var_3)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_nested_lambda_32_env *)(&((var_3)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_32,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_27_env *env = (struct scm_nested_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_28_env );(((struct scm_nested_lambda_28_env *)(&((var_2)->env)))->v_rv_35)=(var_0);(((struct scm_nested_lambda_28_env *)(&((var_2)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_nested_lambda_28,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_nested_lambda_29_env );(((struct scm_nested_lambda_29_env *)(&((var_4)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_nested_lambda_29_env *)(&((var_4)->env)))->v_radix)=((env)->v_radix);(((struct scm_nested_lambda_29_env *)(&((var_4)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_ONE_NEW(var_5,scm_nested_lambda_29,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_26_env *env = (struct scm_nested_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_27_env );(((struct scm_nested_lambda_27_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_nested_lambda_27_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);(((struct scm_nested_lambda_27_env *)(&((var_1)->env)))->v__u7cparameter0_2)=((env)->v__u7cparameter0_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nested_lambda_27,var_1);call_closure_two(var_0,MAKE_FIXNUM(16),var_2);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_23_env *env = (struct scm_nested_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_26_env );(((struct scm_nested_lambda_26_env *)(&((var_3)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_nested_lambda_26_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);(((struct scm_nested_lambda_26_env *)(&((var_3)->env)))->v__u7cparameter0_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_26,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_24_env *env = (struct scm_nested_lambda_24_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(16),(env)->v_k_20);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_21(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_21_env *env = (struct scm_nested_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_23_env );(((struct scm_nested_lambda_23_env *)(&((var_2)->env)))->v_k_20)=(var_1);(((struct scm_nested_lambda_23_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_3,scm_nested_lambda_23,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_nested_lambda_24_env );(((struct scm_nested_lambda_24_env *)(&((var_4)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_nested_lambda_24,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_radix))->val,var_5);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_22_env *env = (struct scm_nested_lambda_22_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,(env)->v_k_21);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_19_env *env = (struct scm_nested_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_21_env );(((struct scm_nested_lambda_21_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_nested_lambda_21,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_22_env );(((struct scm_nested_lambda_22_env *)(&((var_3)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_nested_lambda_22_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_22,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_17_env *env = (struct scm_nested_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_19_env );(((struct scm_nested_lambda_19_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_nested_lambda_19_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nested_lambda_19,var_1);call_closure_two((struct obj *)(&(scm_nested_lambda_18_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_16_env *env = (struct scm_nested_lambda_16_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_17_env );(((struct scm_nested_lambda_17_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_nested_lambda_17_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nested_lambda_17,var_1);call_closure_two((env)->v_rv_42,var_0,var_2);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_14_env *env = (struct scm_nested_lambda_14_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_16_env );(((struct scm_nested_lambda_16_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_nested_lambda_16_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);(((struct scm_nested_lambda_16_env *)(&((var_2)->env)))->v_rv_42)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nested_lambda_16,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_12_env *env = (struct scm_nested_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_14_env );(((struct scm_nested_lambda_14_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_nested_lambda_14_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nested_lambda_14,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_9_env *env = (struct scm_nested_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_12_env );(((struct scm_nested_lambda_12_env *)(&((var_2)->env)))->v_k_21)=(var_1);(((struct scm_nested_lambda_12_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_3,scm_nested_lambda_12,var_2);call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_15_env *env = (struct scm_nested_lambda_15_env *)(&((env_in)->env));call_closure_one((env)->v_k_22,NULL);__builtin_unreachable();}
+
+#line 6 "parameters.scm"
+static void scm_nested_lambda_13(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_13_env *env = (struct scm_nested_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_15_env );(((struct scm_nested_lambda_15_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_15,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_3,var_4);__builtin_unreachable();}
+
+#line 5 "parameters.scm"
+static void scm_nested_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_10_env *env = (struct scm_nested_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_13_env );(((struct scm_nested_lambda_13_env *)(&((var_1)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_TWO_NEW(var_2,scm_nested_lambda_13,var_1);call_closure_two(var_0,var_2,(env)->v_k_24);__builtin_unreachable();}
+
+#line 5 "parameters.scm"
+static void scm_nested_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_7_env *env = (struct scm_nested_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_9_env );(((struct scm_nested_lambda_9_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_nested_lambda_9,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_10_env );(((struct scm_nested_lambda_10_env *)(&((var_3)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_nested_lambda_10_env *)(&((var_3)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_10,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 5 "parameters.scm"
+static void scm_nested_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_5_env *env = (struct scm_nested_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_6_env );(((struct scm_nested_lambda_6_env *)(&((var_2)->env)))->v_rv_47)=(var_0);(((struct scm_nested_lambda_6_env *)(&((var_2)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_TWO_NEW(var_3,scm_nested_lambda_6,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_nested_lambda_7_env );(((struct scm_nested_lambda_7_env *)(&((var_4)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_nested_lambda_7_env *)(&((var_4)->env)))->v_radix)=((env)->v_radix);(((struct scm_nested_lambda_7_env *)(&((var_4)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_ONE_NEW(var_5,scm_nested_lambda_7,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 5 "parameters.scm"
+static void scm_nested_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_4_env *env = (struct scm_nested_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_lambda_5_env );(((struct scm_nested_lambda_5_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_nested_lambda_5_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);(((struct scm_nested_lambda_5_env *)(&((var_1)->env)))->v__u7cparameter0_3)=((env)->v__u7cparameter0_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_nested_lambda_5,var_1);call_closure_two(var_0,MAKE_FIXNUM(8),var_2);__builtin_unreachable();}
+
+#line 5 "parameters.scm"
+static void scm_nested_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_lambda_1_env *env = (struct scm_nested_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_nested_lambda_4_env );(((struct scm_nested_lambda_4_env *)(&((var_3)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_nested_lambda_4_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);(((struct scm_nested_lambda_4_env *)(&((var_3)->env)))->v__u7cparameter0_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_nested_lambda_4,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 5 "parameters.scm"
+static void scm_nested_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_nested_lambda_2_env *env = (struct scm_nested_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(8),(env)->v_k_24);__builtin_unreachable();}
+
+#line 5 "parameters.scm"
+static void scm_nested(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_nested_env *env = (struct scm_nested_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_nested_lambda_1_env );(((struct scm_nested_lambda_1_env *)(&((var_2)->env)))->v_k_24)=(var_1);(((struct scm_nested_lambda_1_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_3,scm_nested_lambda_1,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_nested_lambda_2_env );(((struct scm_nested_lambda_2_env *)(&((var_4)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_nested_lambda_2,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_radix))->val,var_5);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));call_closure_two((env)->v_rv_51,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_rv_51)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_65,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_60,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_50(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_55,var_2);call_closure_two(((struct cell_obj *)((env)->v_nested))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_56(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_61,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_56_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_56);
+
+#line 1 "parameters.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v__u7cconverter_2,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,make_parameter_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_k_27)=((env)->v_k_27);(((struct scm_lambda_74_env *)(&((var_2)->env)))->v__u7cconverter_2)=((env)->v__u7cconverter_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_74,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_66(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_k_27)=(var_1);(((struct scm_lambda_70_env *)(&((var_2)->env)))->v__u7cconverter_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_70,var_2);call_closure_two(var_0,MAKE_FIXNUM(4),var_3);__builtin_unreachable();}
+static struct closure_obj scm_lambda_66_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_66);
+
+#line 11 "parameters.scm"
+static void scm__u7cconverter_2_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm__u7cconverter_2_lambda_1_env *env = (struct scm__u7cconverter_2_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(2),(env)->v_k_28);__builtin_unreachable();}
+
+#line 11 "parameters.scm"
+static void scm__u7cconverter_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,mul_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm__u7cconverter_2_lambda_1_env );(((struct scm__u7cconverter_2_lambda_1_env *)(&((var_3)->env)))->v_k_28)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm__u7cconverter_2_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+static struct closure_obj scm__u7cconverter_2_closure = CLOSURE_TWO_OBJ_INIT(scm__u7cconverter_2);
+
+#line 1 "parameters.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_width))->val)=(var_0);GC_WRITE_BARRIER((env)->v_width,((struct cell_obj *)((env)->v_width))->val);call_closure_one((env)->v_k_29,NULL);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_62(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_k_29)=(var_1);(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_width)=((env)->v_width);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_67,var_2);call_closure_two((struct obj *)(&(scm_lambda_66_closure)),(struct obj *)(&(scm__u7cconverter_2_closure)),var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));call_closure_two((env)->v_rv_59,var_0,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_2)->env)))->v_k_30)=((env)->v_k_30);(((struct scm_lambda_79_env *)(&((var_2)->env)))->v_rv_59)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_79,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_2)->env)))->v_k_30)=((env)->v_k_30);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_75,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_68(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(((struct cell_obj *)((env)->v_width))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_31);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_72(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_3)->env)))->v_k_31)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_76,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_72_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_72);
+
+#line 13 "parameters.scm"
+static void scm_lambda_173(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_173_env *env = (struct scm_lambda_173_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v__u7cvalue1,(env)->v_k_32);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_159(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_159_env *env = (struct scm_lambda_159_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_173_env );(((struct scm_lambda_173_env *)(&((var_3)->env)))->v_k_32)=(var_1);(((struct scm_lambda_173_env *)(&((var_3)->env)))->v__u7cvalue1)=((env)->v__u7cvalue1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_173,var_3);call_closure_two(var_2,(env)->v__u7cparameter1,var_4);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_160(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_160_env *env = (struct scm_lambda_160_env *)(&((env_in)->env));call_closure_one((env)->v_k_33,NULL);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_149(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_149_env *env = (struct scm_lambda_149_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_159_env );(((struct scm_lambda_159_env *)(&((var_1)->env)))->v__u7cparameter1)=((env)->v__u7cparameter1);(((struct scm_lambda_159_env *)(&((var_1)->env)))->v__u7cvalue1)=((env)->v__u7cvalue1);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_159,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_160_env );(((struct scm_lambda_160_env *)(&((var_3)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_160,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_141(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_141_env *env = (struct scm_lambda_141_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_149_env );(((struct scm_lambda_149_env *)(&((var_1)->env)))->v_k_33)=(((struct scm_lambda_132_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_33);(((struct scm_lambda_149_env *)(&((var_1)->env)))->v__u7cparameter1)=(((struct scm_lambda_132_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cparameter1);(((struct scm_lambda_149_env *)(&((var_1)->env)))->v__u7cvalue1)=(((struct scm_lambda_132_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cvalue1);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_149,var_1);call_closure_two(var_0,((struct scm_lambda_132_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_76,var_2);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_132(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_132_env *env = (struct scm_lambda_132_env *)(&((env_in)->env));((env)->v_k_33)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_141_env );(((struct scm_lambda_141_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_141,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_4,var_4);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_174(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_174_env *env = (struct scm_lambda_174_env *)(&((env_in)->env));call_closure_two((env)->v_rv_67,var_0,(env)->v_k_34);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_161(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_161_env *env = (struct scm_lambda_161_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_174_env );(((struct scm_lambda_174_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);(((struct scm_lambda_174_env *)(&((var_1)->env)))->v_rv_67)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_174,var_1);call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,var_2);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_150(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_150_env *env = (struct scm_lambda_150_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_161_env );(((struct scm_lambda_161_env *)(&((var_2)->env)))->v_k_34)=((env)->v_k_34);(((struct scm_lambda_161_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_161,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_142(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_142_env *env = (struct scm_lambda_142_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_150_env );(((struct scm_lambda_150_env *)(&((var_2)->env)))->v_k_34)=(var_1);(((struct scm_lambda_150_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_150,var_2);call_closure_two(((struct cell_obj *)((env)->v_width))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_176(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_176_env *env = (struct scm_lambda_176_env *)(&((env_in)->env));call_closure_one((env)->v_k_35,NULL);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_162(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_162_env *env = (struct scm_lambda_162_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_176_env );(((struct scm_lambda_176_env *)(&((var_2)->env)))->v_k_35)=((env)->v_k_35);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_176,var_2);call_closure_two(var_1,(env)->v__u7cparameter0_4,var_3);__builtin_unreachable();}
+
+#line 13 "parameters.scm"
+static void scm_lambda_151(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_151_env *env = (struct scm_lambda_151_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_162_env );(((struct scm_lambda_162_env *)(&((var_3)->env)))->v_k_35)=(var_1);(((struct scm_lambda_162_env *)(&((var_3)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_162,var_3);call_closure_two(var_2,(env)->v__u7cparameter1,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_143(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_143_env *env = (struct scm_lambda_143_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_151_env );(((struct scm_lambda_151_env *)(&((var_1)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);(((struct scm_lambda_151_env *)(&((var_1)->env)))->v__u7cparameter1)=((env)->v__u7cparameter1);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_151,var_1);call_closure_two(var_0,var_2,(env)->v_k_36);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_133(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_133_env *env = (struct scm_lambda_133_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_142_env );(((struct scm_lambda_142_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_123_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix);(((struct scm_lambda_142_env *)(&((var_1)->env)))->v_width)=(((struct scm_lambda_123_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_width);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_142,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_143_env );(((struct scm_lambda_143_env *)(&((var_3)->env)))->v_k_36)=(((struct scm_lambda_123_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_36);(((struct scm_lambda_143_env *)(&((var_3)->env)))->v__u7cparameter0_4)=(((struct scm_lambda_123_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cparameter0_4);(((struct scm_lambda_143_env *)(&((var_3)->env)))->v__u7cparameter1)=(((struct scm_lambda_123_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cparameter1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_143,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_123(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_123_env *env = (struct scm_lambda_123_env *)(&((env_in)->env));((env)->v__u7cvalue1)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_36)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_132_env );(((struct scm_lambda_132_env *)(&((var_3)->env)))->v_rv_76)=((env)->v_rv_76);(((struct scm_lambda_132_env *)(&((var_3)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);(((struct scm_lambda_132_env *)(&((var_3)->env)))->v__u7cparameter1)=((env)->v__u7cparameter1);(((struct scm_lambda_132_env *)(&((var_3)->env)))->v__u7cvalue1)=((env)->v__u7cvalue1);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_132,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_133_env );(((struct scm_lambda_133_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_133,var_5);call_closure_two(var_2,var_4,var_6);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_134(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_134_env *env = (struct scm_lambda_134_env *)(&((env_in)->env));call_closure_two((env)->v_rv_75,var_0,(env)->v_k_37);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_124(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_124_env *env = (struct scm_lambda_124_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_134_env );(((struct scm_lambda_134_env *)(&((var_1)->env)))->v_k_37)=((env)->v_k_37);(((struct scm_lambda_134_env *)(&((var_1)->env)))->v_rv_75)=((env)->v_rv_75);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_134,var_1);call_closure_two(var_0,(env)->v__u7cvalue1_2,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_115(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_115_env *env = (struct scm_lambda_115_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_124_env );(((struct scm_lambda_124_env *)(&((var_2)->env)))->v_k_37)=((env)->v_k_37);(((struct scm_lambda_124_env *)(&((var_2)->env)))->v_rv_75)=(var_0);(((struct scm_lambda_124_env *)(&((var_2)->env)))->v__u7cvalue1_2)=((env)->v__u7cvalue1_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_124,var_2);call_closure_two(var_1,(env)->v__u7cparameter1,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_107(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_107_env *env = (struct scm_lambda_107_env *)(&((env_in)->env));((env)->v_rv_76)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_115_env );(((struct scm_lambda_115_env *)(&((var_1)->env)))->v_k_37)=(((struct scm_lambda_94_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_37);(((struct scm_lambda_115_env *)(&((var_1)->env)))->v__u7cparameter1)=(((struct scm_lambda_94_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u7cparameter1);(((struct scm_lambda_115_env *)(&((var_1)->env)))->v__u7cvalue1_2)=(((struct scm_lambda_94_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u7cvalue1_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_115,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_123_env );(((struct scm_lambda_123_env *)(&((var_3)->env)))->v_radix)=(((struct scm_lambda_94_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_radix);(((struct scm_lambda_123_env *)(&((var_3)->env)))->v_rv_76)=((env)->v_rv_76);(((struct scm_lambda_123_env *)(&((var_3)->env)))->v_width)=(((struct scm_lambda_94_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_width);(((struct scm_lambda_123_env *)(&((var_3)->env)))->v__u7cparameter0_4)=(((struct scm_lambda_94_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u7cparameter0_4);(((struct scm_lambda_123_env *)(&((var_3)->env)))->v__u7cparameter1)=(((struct scm_lambda_94_env *)(&(((struct env_obj *)(((struct scm_lambda_100_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v__u7cparameter1);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_123,var_3);call_closure_one(var_2,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_107_env );(((struct scm_lambda_107_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_107,var_1);call_closure_two(var_0,((struct scm_lambda_94_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cvalue0_2,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_94(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));((env)->v__u7cvalue1_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_37)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_100,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_4,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_89(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));((env)->v__u7cparameter1)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_width)=((env)->v_width);(((struct scm_lambda_94_env *)(&((var_2)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);(((struct scm_lambda_94_env *)(&((var_2)->env)))->v__u7cparameter1)=((env)->v__u7cparameter1);(((struct scm_lambda_94_env *)(&((var_2)->env)))->v__u7cvalue0_2)=((env)->v__u7cvalue0_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_94,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_84(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));((env)->v__u7cvalue0_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_width)=((env)->v_width);(((struct scm_lambda_89_env *)(&((var_2)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);(((struct scm_lambda_89_env *)(&((var_2)->env)))->v__u7cvalue0_2)=((env)->v__u7cvalue0_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_89,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_80(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));((env)->v__u7cparameter0_4)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_84_env *)(&((var_2)->env)))->v_width)=((env)->v_width);(((struct scm_lambda_84_env *)(&((var_2)->env)))->v__u7cparameter0_4)=((env)->v__u7cparameter0_4);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_84,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));call_closure_two((env)->v_rv_79,var_0,(env)->v_k_41);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_2)->env)))->v_k_41)=((env)->v_k_41);(((struct scm_lambda_108_env *)(&((var_2)->env)))->v_rv_79)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_108,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_2)->env)))->v_k_41)=((env)->v_k_41);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_101,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_90(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_90_env *env = (struct scm_lambda_90_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_1)->env)))->v_k_41)=((env)->v_k_41);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_95,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_90_env );(((struct scm_lambda_90_env *)(&((var_1)->env)))->v_k_41)=((env)->v_k_41);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_90,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_radix))->val,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_1)->env)))->v_k_41)=((env)->v_k_41);(((struct scm_lambda_85_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_85,var_1);call_closure_two(var_0,MAKE_FIXNUM(5),var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_77(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_80_env *)(&((var_2)->env)))->v_width)=((env)->v_width);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_80,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_4)->env)))->v_k_41)=(var_1);(((struct scm_lambda_81_env *)(&((var_4)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_81,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_width))->val,var_5);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_42);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_82(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_k_42)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_86,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_82_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_82);
+
+#line 1 "parameters.scm"
+static void scm_lambda_102(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));call_closure_two((env)->v_rv_86,var_0,(env)->v_k_43);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_96(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_96_env *env = (struct scm_lambda_96_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_2)->env)))->v_k_43)=((env)->v_k_43);(((struct scm_lambda_102_env *)(&((var_2)->env)))->v_rv_86)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_102,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_96_env );(((struct scm_lambda_96_env *)(&((var_2)->env)))->v_k_43)=((env)->v_k_43);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_96,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_87(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_2)->env)))->v_k_43)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_91,var_2);call_closure_two(((struct cell_obj *)((env)->v_width))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_97(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_44);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_92(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_3)->env)))->v_k_44)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_97,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_92_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_92);
+
+#line 17 "parameters.scm"
+static void scm_lambda_103(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,var_1);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_177(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_177_env *env = (struct scm_lambda_177_env *)(&((env_in)->env));call_closure_one((env)->v_k_46,NULL);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_163(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_163_env *env = (struct scm_lambda_163_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_177_env );(((struct scm_lambda_177_env *)(&((var_1)->env)))->v_k_46)=((env)->v_k_46);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_177,var_1);call_closure_two(var_0,(env)->v_rv_94,var_2);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_152(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_152_env *env = (struct scm_lambda_152_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_163_env );(((struct scm_lambda_163_env *)(&((var_3)->env)))->v_k_46)=(var_1);(((struct scm_lambda_163_env *)(&((var_3)->env)))->v_rv_94)=((env)->v_rv_94);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_163,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_5,var_4);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_164(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,raise_k,NULL);call_closure_two(var_2,(struct obj *)(&(symbol_oops)),var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_164_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_164);
+
+#line 18 "parameters.scm"
+static void scm_lambda_186(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_186_env *env = (struct scm_lambda_186_env *)(&((env_in)->env));call_closure_one((env)->v_k_48,NULL);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_178(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_178_env *env = (struct scm_lambda_178_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_186_env );(((struct scm_lambda_186_env *)(&((var_3)->env)))->v_k_48)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_186,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_5,var_4);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_165(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_165_env *env = (struct scm_lambda_165_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_178_env );(((struct scm_lambda_178_env *)(&((var_1)->env)))->v__u7cparameter0_5)=((env)->v__u7cparameter0_5);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_178,var_1);call_closure_two(var_0,var_2,(env)->v_k_50);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_153(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_153_env *env = (struct scm_lambda_153_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_165_env );(((struct scm_lambda_165_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_165_env *)(&((var_1)->env)))->v__u7cparameter0_5)=((env)->v__u7cparameter0_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_165,var_1);call_closure_two(var_0,(struct obj *)(&(scm_lambda_164_closure)),var_2);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_144(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_144_env *env = (struct scm_lambda_144_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_152_env );(((struct scm_lambda_152_env *)(&((var_2)->env)))->v_rv_94)=(var_0);(((struct scm_lambda_152_env *)(&((var_2)->env)))->v__u7cparameter0_5)=((env)->v__u7cparameter0_5);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_152,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_153_env );(((struct scm_lambda_153_env *)(&((var_4)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_153_env *)(&((var_4)->env)))->v__u7cparameter0_5)=((env)->v__u7cparameter0_5);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_153,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_135(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_135_env *env = (struct scm_lambda_135_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_144_env );(((struct scm_lambda_144_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_144_env *)(&((var_1)->env)))->v__u7cparameter0_5)=((env)->v__u7cparameter0_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_144,var_1);call_closure_two(var_0,MAKE_FIXNUM(7),var_2);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_116(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_116_env *env = (struct scm_lambda_116_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_135_env );(((struct scm_lambda_135_env *)(&((var_3)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_135_env *)(&((var_3)->env)))->v__u7cparameter0_5)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_135,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_117(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_117_env *env = (struct scm_lambda_117_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(7),(env)->v_k_50);__builtin_unreachable();}
+
+#line 18 "parameters.scm"
+static void scm_lambda_109(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_109_env *env = (struct scm_lambda_109_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_116_env );(((struct scm_lambda_116_env *)(&((var_2)->env)))->v_k_50)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_116,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_117_env );(((struct scm_lambda_117_env *)(&((var_4)->env)))->v_k_50)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_117,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_radix))->val,var_5);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_126(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_126_env *env = (struct scm_lambda_126_env *)(&((env_in)->env));call_closure_two((env)->v_rv_98,var_0,(env)->v_k_51);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_118(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_126_env );(((struct scm_lambda_126_env *)(&((var_2)->env)))->v_k_51)=((env)->v_k_51);(((struct scm_lambda_126_env *)(&((var_2)->env)))->v_rv_98)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_126,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_2)->env)))->v_k_51)=((env)->v_k_51);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_118,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_109_env );(((struct scm_lambda_109_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_109,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_3)->env)))->v_k_51)=((env)->v_k_51);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_110,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_98(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,with_exception_handler_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_103,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_5)->env)))->v_k_51)=(var_1);(((struct scm_lambda_104_env *)(&((var_5)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_104,var_5);call_closure_two(var_2,var_4,var_6);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_111(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_52);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_105(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_3)->env)))->v_k_52)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_111,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_105_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_105);
+
+#line 19 "parameters.scm"
+static void scm_lambda_179(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_179_env *env = (struct scm_lambda_179_env *)(&((env_in)->env));call_closure_one((env)->v_k_53,NULL);__builtin_unreachable();}
+
+#line 19 "parameters.scm"
+static void scm_lambda_166(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_166_env *env = (struct scm_lambda_166_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_179_env );(((struct scm_lambda_179_env *)(&((var_1)->env)))->v_k_53)=((env)->v_k_53);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_179,var_1);call_closure_two(var_0,(env)->v_rv_107,var_2);__builtin_unreachable();}
+
+#line 19 "parameters.scm"
+static void scm_lambda_154(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_154_env *env = (struct scm_lambda_154_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_push_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_166_env );(((struct scm_lambda_166_env *)(&((var_3)->env)))->v_k_53)=(var_1);(((struct scm_lambda_166_env *)(&((var_3)->env)))->v_rv_107)=((env)->v_rv_107);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_166,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_6,var_4);__builtin_unreachable();}
+
+#line 19 "parameters.scm"
+static void scm_lambda_180(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_180_env *env = (struct scm_lambda_180_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_radix))->val,NULL,var_1);__builtin_unreachable();}
+
+#line 19 "parameters.scm"
+static void scm_lambda_167(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_167_env *env = (struct scm_lambda_167_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_180_env );(((struct scm_lambda_180_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_180,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 19 "parameters.scm"
+static void scm_lambda_187(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_187_env *env = (struct scm_lambda_187_env *)(&((env_in)->env));call_closure_one((env)->v_k_56,NULL);__builtin_unreachable();}
+
+#line 19 "parameters.scm"
+static void scm_lambda_181(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_181_env *env = (struct scm_lambda_181_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,parameter_pop_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_187_env );(((struct scm_lambda_187_env *)(&((var_3)->env)))->v_k_56)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_187,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_6,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_168(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_168_env *env = (struct scm_lambda_168_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_181_env );(((struct scm_lambda_181_env *)(&((var_1)->env)))->v__u7cparameter0_6)=((env)->v__u7cparameter0_6);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_181,var_1);call_closure_two(var_0,var_2,(env)->v_k_57);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_155(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_155_env *env = (struct scm_lambda_155_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_167_env );(((struct scm_lambda_167_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_167,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_168_env );(((struct scm_lambda_168_env *)(&((var_3)->env)))->v_k_57)=((env)->v_k_57);(((struct scm_lambda_168_env *)(&((var_3)->env)))->v__u7cparameter0_6)=((env)->v__u7cparameter0_6);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_168,var_3);call_closure_two(var_0,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_145(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_145_env *env = (struct scm_lambda_145_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,dynamic_wind_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_154_env );(((struct scm_lambda_154_env *)(&((var_2)->env)))->v_rv_107)=(var_0);(((struct scm_lambda_154_env *)(&((var_2)->env)))->v__u7cparameter0_6)=((env)->v__u7cparameter0_6);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_154,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_155_env );(((struct scm_lambda_155_env *)(&((var_4)->env)))->v_k_57)=((env)->v_k_57);(((struct scm_lambda_155_env *)(&((var_4)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_155_env *)(&((var_4)->env)))->v__u7cparameter0_6)=((env)->v__u7cparameter0_6);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_155,var_4);call_closure_two(var_1,var_3,var_5);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_136(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_136_env *env = (struct scm_lambda_136_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_145_env );(((struct scm_lambda_145_env *)(&((var_1)->env)))->v_k_57)=(((struct scm_lambda_127_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_57);(((struct scm_lambda_145_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_127_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix);(((struct scm_lambda_145_env *)(&((var_1)->env)))->v__u7cparameter0_6)=(((struct scm_lambda_127_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cparameter0_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_145,var_1);call_closure_two(var_0,((struct scm_lambda_127_env *)(&(((struct env_obj *)((env)->parent))->env)))->v__u7cvalue0_3,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_127(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));((env)->v__u7cvalue0_3)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_57)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,parameter_converter_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_136_env );(((struct scm_lambda_136_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_136,var_3);call_closure_two(var_2,(env)->v__u7cparameter0_6,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_119(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_119_env *env = (struct scm_lambda_119_env *)(&((env_in)->env));((env)->v__u7cparameter0_6)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_127_env *)(&((var_2)->env)))->v__u7cparameter0_6)=((env)->v__u7cparameter0_6);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_127,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_128_inlined_env );(((struct scm_lambda_128_inlined_env *)(&((var_4)->env)))->v_get_radix)=((env)->v_get_radix);(((struct scm_lambda_128_inlined_env *)(&((var_4)->env)))->v_k_59)=((env)->v_k_59);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_128_inlined,var_4);call_closure_two(var_3,MAKE_FIXNUM(12),var_5);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_128(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_128_env *env = (struct scm_lambda_128_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_get_radix))->val)=(var_0);GC_WRITE_BARRIER((env)->v_get_radix,((struct cell_obj *)((env)->v_get_radix))->val);call_closure_one((env)->v_k_59,NULL);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_120(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_120_env *env = (struct scm_lambda_120_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_128_env );(((struct scm_lambda_128_env *)(&((var_1)->env)))->v_get_radix)=((env)->v_get_radix);(((struct scm_lambda_128_env *)(&((var_1)->env)))->v_k_59)=((env)->v_k_59);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_128,var_1);call_closure_two(var_0,MAKE_FIXNUM(12),var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_112(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_119_env );(((struct scm_lambda_119_env *)(&((var_2)->env)))->v_get_radix)=((env)->v_get_radix);(((struct scm_lambda_119_env *)(&((var_2)->env)))->v_k_59)=(var_1);(((struct scm_lambda_119_env *)(&((var_2)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_119,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_120_env );(((struct scm_lambda_120_env *)(&((var_4)->env)))->v_get_radix)=((env)->v_get_radix);(((struct scm_lambda_120_env *)(&((var_4)->env)))->v_k_59)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_120,var_4);call_closure_two(var_3,((struct cell_obj *)((env)->v_radix))->val,var_5);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_146(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_146_env *env = (struct scm_lambda_146_env *)(&((env_in)->env));call_closure_two((env)->v_rv_112,var_0,(env)->v_k_60);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_137(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_137_env *env = (struct scm_lambda_137_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_146_env );(((struct scm_lambda_146_env *)(&((var_2)->env)))->v_k_60)=((env)->v_k_60);(((struct scm_lambda_146_env *)(&((var_2)->env)))->v_rv_112)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_146,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_129(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_129_env *env = (struct scm_lambda_129_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_137_env );(((struct scm_lambda_137_env *)(&((var_2)->env)))->v_k_60)=((env)->v_k_60);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_137,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_121(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_121_env *env = (struct scm_lambda_121_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_129_env );(((struct scm_lambda_129_env *)(&((var_2)->env)))->v_k_60)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_129,var_2);call_closure_two(((struct cell_obj *)((env)->v_get_radix))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_138(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_138_env *env = (struct scm_lambda_138_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_61);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_130(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_138_env );(((struct scm_lambda_138_env *)(&((var_3)->env)))->v_k_61)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_138,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_130_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_130);
+
+#line 1 "parameters.scm"
+static void scm_lambda_188(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_188_env *env = (struct scm_lambda_188_env *)(&((env_in)->env));call_closure_two((env)->v_rv_116,var_0,(env)->v_k_62);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_182(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_182_env *env = (struct scm_lambda_182_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_188_env );(((struct scm_lambda_188_env *)(&((var_2)->env)))->v_k_62)=((env)->v_k_62);(((struct scm_lambda_188_env *)(&((var_2)->env)))->v_rv_116)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_188,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_169(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_169_env *env = (struct scm_lambda_169_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_182_env );(((struct scm_lambda_182_env *)(&((var_2)->env)))->v_k_62)=((env)->v_k_62);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_182,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_156(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_156_env *env = (struct scm_lambda_156_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_169_env );(((struct scm_lambda_169_env *)(&((var_1)->env)))->v_k_62)=((env)->v_k_62);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_169,var_1);call_closure_two(var_0,((struct cell_obj *)((env)->v_radix))->val,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_147(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_147_env *env = (struct scm_lambda_147_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_156_env );(((struct scm_lambda_156_env *)(&((var_3)->env)))->v_k_62)=(var_1);(((struct scm_lambda_156_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_156,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_p))->val,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_170(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_170_env *env = (struct scm_lambda_170_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_63);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_157(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_170_env );(((struct scm_lambda_170_env *)(&((var_3)->env)))->v_k_63)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_170,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_157_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_157);
+
+#line 1 "parameters.scm"
+static void scm_lambda_193(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_193_env *env = (struct scm_lambda_193_env *)(&((env_in)->env));call_closure_two((env)->v_rv_121,var_0,(env)->v_k_64);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_189(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_189_env *env = (struct scm_lambda_189_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_193_env );(((struct scm_lambda_193_env *)(&((var_2)->env)))->v_k_64)=((env)->v_k_64);(((struct scm_lambda_193_env *)(&((var_2)->env)))->v_rv_121)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_193,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_183(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_183_env *env = (struct scm_lambda_183_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_189_env );(((struct scm_lambda_189_env *)(&((var_2)->env)))->v_k_64)=((env)->v_k_64);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_189,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_171(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_171_env *env = (struct scm_lambda_171_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_procedure_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_183_env );(((struct scm_lambda_183_env *)(&((var_3)->env)))->v_k_64)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_183,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_radix))->val,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_190(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_190_env *env = (struct scm_lambda_190_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_65);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_184(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_190_env );(((struct scm_lambda_190_env *)(&((var_3)->env)))->v_k_65)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_190,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_184_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_184);
+
+#line 1 "parameters.scm"
+static void scm_lambda_198(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_198_env *env = (struct scm_lambda_198_env *)(&((env_in)->env));call_closure_two((env)->v_rv_125,var_0,(env)->v_k_66);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_196(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_196_env *env = (struct scm_lambda_196_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_198_env );(((struct scm_lambda_198_env *)(&((var_2)->env)))->v_k_66)=((env)->v_k_66);(((struct scm_lambda_198_env *)(&((var_2)->env)))->v_rv_125)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_198,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_194(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_194_env *env = (struct scm_lambda_194_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_196_env );(((struct scm_lambda_196_env *)(&((var_3)->env)))->v_k_66)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_196,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_make_parameter_count))->val,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_197(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_197_env *env = (struct scm_lambda_197_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_67);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_195(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_195_env *env = (struct scm_lambda_195_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_197_env );(((struct scm_lambda_197_env *)(&((var_2)->env)))->v_k_67)=((env)->v_k_67);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_197,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_185(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_185_env *env = (struct scm_lambda_185_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_make_parameter_count))->val)=(MAKE_FIXNUM(1));GC_WRITE_BARRIER((env)->v_make_parameter_count,((struct cell_obj *)((env)->v_make_parameter_count))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_194_env );(((struct scm_lambda_194_env *)(&((var_1)->env)))->v_make_parameter_count)=((env)->v_make_parameter_count);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_194,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_195_env );(((struct scm_lambda_195_env *)(&((var_3)->env)))->v_k_67)=((env)->v_k_67);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_195,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_172(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_172_env *env = (struct scm_lambda_172_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_185_env );(((struct scm_lambda_185_env *)(&((var_1)->env)))->v_k_67)=((env)->v_k_67);(((struct scm_lambda_185_env *)(&((var_1)->env)))->v_make_parameter_count)=((env)->v_make_parameter_count);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_185,var_1);call_closure_two((struct obj *)(&(scm_lambda_184_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_158(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_158_env *env = (struct scm_lambda_158_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_171_env );(((struct scm_lambda_171_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_171,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_172_env );(((struct scm_lambda_172_env *)(&((var_3)->env)))->v_k_67)=((env)->v_k_67);(((struct scm_lambda_172_env *)(&((var_3)->env)))->v_make_parameter_count)=((env)->v_make_parameter_count);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_172,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_148(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_148_env *env = (struct scm_lambda_148_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_158_env );(((struct scm_lambda_158_env *)(&((var_1)->env)))->v_k_67)=((env)->v_k_67);(((struct scm_lambda_158_env *)(&((var_1)->env)))->v_make_parameter_count)=((env)->v_make_parameter_count);(((struct scm_lambda_158_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_158,var_1);call_closure_two((struct obj *)(&(scm_lambda_157_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_131(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_131_env *env = (struct scm_lambda_131_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_p))->val)=(((struct cell_obj *)(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix))->val);GC_WRITE_BARRIER(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_p,((struct cell_obj *)(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_p))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_147_env );(((struct scm_lambda_147_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_p);(((struct scm_lambda_147_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_147,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_148_env );(((struct scm_lambda_148_env *)(&((var_3)->env)))->v_k_67)=(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_67);(((struct scm_lambda_148_env *)(&((var_3)->env)))->v_make_parameter_count)=(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_make_parameter_count);(((struct scm_lambda_148_env *)(&((var_3)->env)))->v_radix)=(((struct scm_lambda_122_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_148,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_131_env );(((struct scm_lambda_131_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_131,var_1);call_closure_two((struct obj *)(&(scm_lambda_130_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_121_env );(((struct scm_lambda_121_env *)(&((var_1)->env)))->v_get_radix)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_99_env *)(&(((struct env_obj *)(((struct scm_lambda_106_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_get_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_121,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_3)->env)))->v_k_67)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_99_env *)(&(((struct env_obj *)(((struct scm_lambda_106_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_67);(((struct scm_lambda_122_env *)(&((var_3)->env)))->v_make_parameter_count)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_99_env *)(&(((struct env_obj *)(((struct scm_lambda_106_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_make_parameter_count);(((struct scm_lambda_122_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_99_env *)(&(((struct env_obj *)(((struct scm_lambda_106_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_122_env *)(&((var_3)->env)))->v_radix)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_99_env *)(&(((struct env_obj *)(((struct scm_lambda_106_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_radix);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_122,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_1)->env)))->v_get_radix)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_99_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_get_radix);(((struct scm_lambda_112_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_93_env *)(&(((struct env_obj *)(((struct scm_lambda_99_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_112,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_113,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_99(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_106,var_1);call_closure_two((struct obj *)(&(scm_lambda_105_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_98,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_99,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_1)->env)))->v_get_radix)=(((struct scm_lambda_73_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_get_radix);(((struct scm_lambda_93_env *)(&((var_1)->env)))->v_k_67)=(((struct scm_lambda_73_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_67);(((struct scm_lambda_93_env *)(&((var_1)->env)))->v_make_parameter_count)=(((struct scm_lambda_73_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_make_parameter_count);(((struct scm_lambda_93_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_73_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_93_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_73_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)(((struct scm_lambda_83_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_radix);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_93,var_1);call_closure_two((struct obj *)(&(scm_lambda_92_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_1)->env)))->v_width)=(((struct scm_lambda_73_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_width);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_87,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_88,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_83,var_1);call_closure_two((struct obj *)(&(scm_lambda_82_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_1)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_77_env *)(&((var_1)->env)))->v_width)=((env)->v_width);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_77,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_78,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_1)->env)))->v_get_radix)=(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_63_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_get_radix);(((struct scm_lambda_73_env *)(&((var_1)->env)))->v_k_67)=(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_63_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_67);(((struct scm_lambda_73_env *)(&((var_1)->env)))->v_make_parameter_count)=(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_63_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_make_parameter_count);(((struct scm_lambda_73_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_63_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_73_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_63_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_radix);(((struct scm_lambda_73_env *)(&((var_1)->env)))->v_width)=(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)(((struct scm_lambda_63_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_width);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_73,var_1);call_closure_two((struct obj *)(&(scm_lambda_72_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_1)->env)))->v_width)=(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_57_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_width);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_68,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_69,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_width)=(((struct scm_lambda_51_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_width);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_62,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_63,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_57,var_1);call_closure_two((struct obj *)(&(scm_lambda_56_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_nested_env );(((struct scm_nested_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_nested,var_1);(((struct cell_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_nested))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_nested,((struct cell_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_nested))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_3)->env)))->v_nested)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_nested);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_50,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_5)->env)))->v_get_radix)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_get_radix);(((struct scm_lambda_51_env *)(&((var_5)->env)))->v_k_67)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_67);(((struct scm_lambda_51_env *)(&((var_5)->env)))->v_make_parameter_count)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_make_parameter_count);(((struct scm_lambda_51_env *)(&((var_5)->env)))->v_p)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_51_env *)(&((var_5)->env)))->v_radix)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_radix);(((struct scm_lambda_51_env *)(&((var_5)->env)))->v_width)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)(((struct scm_lambda_30_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_width);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_51,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_40,var_1);call_closure_two((struct obj *)(&(scm_lambda_39_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_34,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_35,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_30,var_1);call_closure_two((struct obj *)(&(scm_lambda_29_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_24,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_get_radix)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_get_radix);(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_k_67)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_67);(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_make_parameter_count)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_make_parameter_count);(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_nested)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_nested);(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_radix)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_radix);(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_width)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_17_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_width);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two((struct obj *)(&(scm_lambda_20_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix,((struct cell_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_1)->env)))->v_radix)=(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_radix);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_16,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_make_parameter_count)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_67)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_14,var_3);call_closure_two((struct obj *)(&(scm_lambda_13_closure)),(struct obj *)(&(scm__u7cconverter_closure)),var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_p)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_get_radix)=((env)->v_get_radix);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_nested)=((env)->v_nested);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_width)=((env)->v_width);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_get_radix)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_get_radix)=((env)->v_get_radix);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_nested)=((env)->v_nested);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_width)=((env)->v_width);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_width)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_nested)=((env)->v_nested);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_width)=((env)->v_width);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_nested)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_nested)=((env)->v_nested);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_radix)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_radix)=((env)->v_radix);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_73);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_73)=((env)->v_k_73);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_73)=((env)->v_k_73);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_73)=((env)->v_k_73);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_73)=((env)->v_k_73);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_73)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "parameters.scm"
+static void scm_lambda_128_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_128_inlined_env *env = (struct scm_lambda_128_inlined_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_get_radix))->val)=(var_0);GC_WRITE_BARRIER((env)->v_get_radix,((struct cell_obj *)((env)->v_get_radix))->val);call_closure_one((env)->v_k_59,NULL);__builtin_unreachable();}
+
+#line 1 "parameters.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 1189 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
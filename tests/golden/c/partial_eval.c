@@ -0,0 +1,479 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_16_env {struct obj *v_k;struct obj *v_rv_2;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_squares;};
+static void scm_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_2;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_10_closure;
+struct scm_lambda_28_env {struct obj *v_k_3;struct obj *v_rv_6;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_3;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_3;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_3;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_squares;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_4;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_18_closure;
+struct scm_lambda_29_env {struct obj *v_k_5;struct obj *v_rv_11;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_5;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_22_closure;
+struct scm_lambda_30_env {struct obj *v_k_6;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_26_closure;
+struct scm_lambda_36_env {struct obj *v_k_7;struct obj *v_rv_14;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_7;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_31_closure;
+struct scm_lambda_37_env {struct obj *v_k_8;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_34(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_34_closure;
+struct scm_lambda_59_env {struct obj *v_k_9;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_52(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_52_closure;
+struct scm_lambda_75_env {struct obj *v_k_10;struct obj *v_rv_18;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_10;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_60(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_60_closure;
+struct scm_lambda_68_env {struct obj *v_k_11;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_11;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_11;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_11;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *v_k_11;struct obj *v_rv_24;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_11;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_38(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_38_closure;
+struct scm_lambda_76_env {struct obj *v_k_12;struct obj *v_rv_26;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_12;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_n;};
+static void scm_lambda_62(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_k_13;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_13;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_13;struct obj *v_n;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_13;struct obj *v_n;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_44(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_44_closure;
+struct scm_lambda_55_env {struct obj *v_k_14;struct obj *v_rv_32;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_14;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_45(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_45_closure;
+struct scm_lambda_56_env {struct obj *v_k_15;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_50(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_50_closure;
+struct scm_lambda_71_env {struct obj *v_k_16;struct obj *v_rv_35;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_16;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_57(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_57_closure;
+struct scm_lambda_72_env {struct obj *v_k_17;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_65(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_65_closure;
+struct scm_lambda_80_env {struct obj *v_k_18;struct obj *v_rv_38;};
+static void scm_lambda_80(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_k_18;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_73(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_73_closure;
+struct scm_lambda_81_env {struct obj *v_k_19;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_78(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_78_closure;
+struct scm_lambda_86_env {struct obj *v_k_20;struct obj *v_rv_41;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_k_20;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_82(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_82_closure;
+struct scm_lambda_85_env {struct obj *v_k_21;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *v_k_21;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_21;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_21;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_k_21;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_21;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_21;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_21;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_k_21;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_21;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_21;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_21;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_21;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_21;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_21;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_21;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_21;struct obj *v_squares;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_k_21;struct obj *v_squares;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_k_21;struct obj *v_squares;};
+static void scm_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_21;struct obj *v_rv_63;struct obj *v_squares;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_21;struct obj *v_rv_63;struct obj *v_squares;};
+static void scm_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_21;struct obj *v_squares;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_sym = SYMBOL_OBJ_INIT("sym");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_sym),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_16),"scm_lambda_16","partial_eval.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","partial_eval.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","partial_eval.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","partial_eval.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","partial_eval.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","partial_eval.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","partial_eval.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","partial_eval.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","partial_eval.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","partial_eval.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","partial_eval.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","partial_eval.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","partial_eval.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","partial_eval.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","partial_eval.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","partial_eval.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","partial_eval.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","partial_eval.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","partial_eval.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","partial_eval.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","partial_eval.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","partial_eval.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","partial_eval.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","partial_eval.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","partial_eval.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","partial_eval.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","partial_eval.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","partial_eval.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","partial_eval.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","partial_eval.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","partial_eval.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","partial_eval.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","partial_eval.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","partial_eval.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","partial_eval.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","partial_eval.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","partial_eval.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","partial_eval.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","partial_eval.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","partial_eval.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","partial_eval.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","partial_eval.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","partial_eval.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","partial_eval.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","partial_eval.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","partial_eval.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","partial_eval.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","partial_eval.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","partial_eval.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","partial_eval.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","partial_eval.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","partial_eval.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","partial_eval.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","partial_eval.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","partial_eval.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","partial_eval.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","partial_eval.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","partial_eval.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","partial_eval.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","partial_eval.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","partial_eval.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","partial_eval.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","partial_eval.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","partial_eval.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","partial_eval.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","partial_eval.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","partial_eval.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","partial_eval.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","partial_eval.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","partial_eval.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","partial_eval.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","partial_eval.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","partial_eval.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","partial_eval.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","partial_eval.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","partial_eval.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","partial_eval.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","partial_eval.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","partial_eval.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","partial_eval.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","partial_eval.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","partial_eval.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","partial_eval.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","partial_eval.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","partial_eval.scm",1},{NULL}};
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_12,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_9,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_squares))->val,var_4);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_13,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_10_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_10);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_24,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_20,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_squares))->val,var_4);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_18_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_18);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));call_closure_two((env)->v_rv_11,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_rv_11)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"less");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_4)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_25,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_22_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_22);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_30,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_26_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_26);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));call_closure_two((env)->v_rv_14,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_rv_14)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_36,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"false");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_4)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_33,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_31_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_31);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_34(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_37,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_34_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_34);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_52(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_59,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_52_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_52);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));call_closure_two((env)->v_rv_18,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_75_env *)(&((var_2)->env)))->v_rv_18)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_75,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_60(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"7.5");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_4)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_67,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_60_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_60);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_61,var_1);call_closure_two((struct obj *)(&(scm_lambda_60_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_53,var_1);call_closure_two((struct obj *)(&(scm_lambda_52_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_1)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_47,var_1);call_closure_two((env)->v_rv_24,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_rv_24)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_43,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_38(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_RATIONAL_OBJ_NEW(var_3,5,2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_4)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_40,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_38_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_38);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));call_closure_two((env)->v_rv_26,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_76,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_62(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_69,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_n))->val,var_4);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_70,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_n))->val)=(var_0);GC_WRITE_BARRIER((env)->v_n,((struct cell_obj *)((env)->v_n))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_62,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_63,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_54,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_44(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_CLOSURE_TWO_NEW(var_3,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_4)->env)))->v_k_13)=(var_1);(((struct scm_lambda_48_env *)(&((var_4)->env)))->v_n)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_48,var_4);call_closure_two(var_3,((struct cell_obj *)(var_2))->val,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_44_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_44);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));call_closure_two((env)->v_rv_32,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_rv_32)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_55,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_45(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"constant");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_4)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_49,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_45_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_45);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_50(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_56,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_50_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_50);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));call_closure_two((env)->v_rv_35,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_71_env *)(&((var_2)->env)))->v_rv_35)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_71,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_57(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,MAKE_FIXNUM(4),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_57_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_57);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_65(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_72,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_65_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_65);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_80(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));call_closure_two((env)->v_rv_38,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_80_env *)(&((var_2)->env)))->v_rv_38)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_80,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_73(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_77,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_sym)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_73_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_73);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_78(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_81,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_78_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_78);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));call_closure_two((env)->v_rv_41,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_86_env *)(&((var_2)->env)))->v_rv_41)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_86,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_82(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_84,var_3);call_closure_two(var_2,MAKE_BOOL(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_82_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_82);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_85,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_83,var_1);call_closure_two((struct obj *)(&(scm_lambda_82_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_79,var_1);call_closure_two((struct obj *)(&(scm_lambda_78_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_74,var_1);call_closure_two((struct obj *)(&(scm_lambda_73_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_66,var_1);call_closure_two((struct obj *)(&(scm_lambda_65_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_58,var_1);call_closure_two((struct obj *)(&(scm_lambda_57_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_51,var_1);call_closure_two((struct obj *)(&(scm_lambda_50_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_46,var_1);call_closure_two((struct obj *)(&(scm_lambda_45_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_42,var_1);call_closure_two((struct obj *)(&(scm_lambda_44_closure)),MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_39,var_1);call_closure_two((struct obj *)(&(scm_lambda_38_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_35,var_1);call_closure_two((struct obj *)(&(scm_lambda_34_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_32,var_1);call_closure_two((struct obj *)(&(scm_lambda_31_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_27,var_1);call_closure_two((struct obj *)(&(scm_lambda_26_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_23,var_1);call_closure_two((struct obj *)(&(scm_lambda_22_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_19,var_1);call_closure_two((struct obj *)(&(scm_lambda_18_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_squares)=((env)->v_squares);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_14,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_21)=((env)->v_k_21);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_squares)=((env)->v_squares);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two((struct obj *)(&(scm_lambda_10_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_squares))->val)=(var_0);GC_WRITE_BARRIER((env)->v_squares,((struct cell_obj *)((env)->v_squares))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_squares)=((env)->v_squares);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_7,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_squares)=((env)->v_squares);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_6_env *)(&((var_1)->env)))->v_squares)=((env)->v_squares);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_6,var_1);call_closure_two((env)->v_rv_63,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_rv_63)=((env)->v_rv_63);(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_squares)=((env)->v_squares);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,MAKE_FIXNUM(13),var_2);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_4_env *)(&((var_2)->env)))->v_rv_63)=(var_0);(((struct scm_lambda_4_env *)(&((var_2)->env)))->v_squares)=((env)->v_squares);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_4,var_2);call_closure_two(var_1,MAKE_FIXNUM(9),var_3);__builtin_unreachable();}
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_CLOSURE_TWO_NEW(var_3,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_21)=(var_1);(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_squares)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,MAKE_FIXNUM(4),var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "partial_eval.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "partial_eval.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 460 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,821 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_15_env {struct obj *v_k;struct obj *v_rv_2;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_10_closure;
+struct scm_lambda_16_env {struct obj *v_k_2;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_13(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_13_closure;
+struct scm_lambda_39_env {struct obj *v_k_3;struct obj *v_rv_5;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_3;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_3;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_3;struct obj *v_rv_9;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_3;struct obj *v_rv_9;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_3;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_17(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_17_closure;
+struct scm_lambda_23_env {struct obj *v_k_4;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_20(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_20_closure;
+struct scm_lambda_31_env {struct obj *v_k_5;struct obj *v_rv_12;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_5;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_24(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_24_closure;
+struct scm_lambda_32_env {struct obj *v_k_6;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_28_closure;
+struct scm_lambda_73_env {struct obj *v_k_7;struct obj *v_rv_15;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_7;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_7;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_7;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_7;struct obj *v_rv_23;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_7;struct obj *v_rv_23;struct obj *v_rv_22;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_48_env {struct obj *v_k_7;struct obj *v_rv_23;struct obj *v_rv_22;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_7;struct obj *v_rv_23;struct obj *v_rv_22;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_7;struct obj *v_rv_23;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_7;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_33(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_33_closure;
+struct scm_lambda_41_env {struct obj *v_k_8;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_37_closure;
+struct scm_lambda_49_env {struct obj *v_k_9;struct obj *v_rv_26;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_9;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_42(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_42_closure;
+struct scm_lambda_50_env {struct obj *v_k_10;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_46(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_46_closure;
+struct scm_lambda_54_env {struct obj *v_k_11;struct obj *v_out;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_out;};
+static void scm_lambda_51(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_84_env {struct obj *v_k_12;struct obj *v_out;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_79_env {struct obj *v_k_12;struct obj *v_out;};
+static void scm_lambda_79(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_12;struct obj *v_out;struct obj *v_rv_33;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_12;struct obj *v_out;struct obj *v_rv_33;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_65_env {struct obj *v_k_12;struct obj *v_out;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_12;struct obj *v_out;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_out;};
+static void scm_lambda_58(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_13;struct obj *v_v;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_k_13;struct obj *v_v;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_v;};
+static void scm_lambda_66(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_k_14;struct obj *v_v;};
+static void scm_lambda_80(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_76_env {struct obj *v_k_14;struct obj *v_v;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_v;};
+static void scm_lambda_71(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_k_15;struct obj *v_rv_43;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_98_env {struct obj *v_k_15;struct obj *v_rv_43;struct obj *v_rv_42;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_94_env {struct obj *v_k_15;struct obj *v_rv_43;struct obj *v_rv_42;};
+static void scm_lambda_94(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_89_env {struct obj *v_k_15;struct obj *v_rv_43;};
+static void scm_lambda_89(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_85_env {struct obj *v_k_15;struct obj *v_v;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_k_15;struct obj *v_v;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_77_env {struct obj *v_v;};
+static void scm_lambda_77(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_90_env {struct obj *v_k_16;struct obj *v_rv_46;};
+static void scm_lambda_90(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_86_env {struct obj *v_k_16;};
+static void scm_lambda_86(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_82_env {struct obj *v_v;};
+static void scm_lambda_82(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_91_env {struct obj *v_k_17;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_87(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_87_closure;
+struct scm_lambda_95_env {struct obj *v_k_18;struct obj *v_out;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_92_env {struct obj *v_out;struct obj *v_v;};
+static void scm_lambda_92(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_105_env {struct obj *v_k_19;struct obj *v_w;};
+static void scm_lambda_105(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_102_env {struct obj *v_k_19;struct obj *v_w;};
+static void scm_lambda_102(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_99_env {struct obj *v_w;};
+static void scm_lambda_99(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_k_20;struct obj *v_l;};
+static void scm_lambda_118(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_113_env {struct obj *v_k_20;struct obj *v_l;struct obj *v_rv_54;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_109_env {struct obj *v_k_20;struct obj *v_l;struct obj *v_rv_54;};
+static void scm_lambda_109(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_106_env {struct obj *v_k_20;struct obj *v_l;struct obj *v_w;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_103_env {struct obj *v_l;struct obj *v_w;};
+static void scm_lambda_103(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_114_env {struct obj *v_k_21;struct obj *v_l;};
+static void scm_lambda_114(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_110_env {struct obj *v_k_21;struct obj *v_l;};
+static void scm_lambda_110(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_107_env {struct obj *v_l;struct obj *v_w;};
+static void scm_lambda_107(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_119_env {struct obj *v_k_22;struct obj *v_rv_58;};
+static void scm_lambda_119(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_115_env {struct obj *v_k_22;};
+static void scm_lambda_115(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_l;};
+static void scm_lambda_111(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_120_env {struct obj *v_k_23;};
+static void scm_lambda_120(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_116(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_116_closure;
+struct scm_lambda_143_env {struct obj *v_k_24;struct obj *v_rv_61;};
+static void scm_lambda_143(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_139_env {struct obj *v_k_24;};
+static void scm_lambda_139(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_134_env {struct obj *v_k_24;};
+static void scm_lambda_134(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_130_env {struct obj *v_k_24;struct obj *v_rv_65;};
+static void scm_lambda_130(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_126_env {struct obj *v_k_24;struct obj *v_rv_65;};
+static void scm_lambda_126(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_123_env {struct obj *v_k_24;struct obj *v_w;};
+static void scm_lambda_123(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_121_env {struct obj *v_w;};
+static void scm_lambda_121(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_127_env {struct obj *v_k_25;};
+static void scm_lambda_127(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_124(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_124_closure;
+struct scm_lambda_149_env {struct obj *v_k_26;struct obj *v_rv_68;};
+static void scm_lambda_149(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_148_env {struct obj *v_k_26;};
+static void scm_lambda_148(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_147_env {struct obj *v_k_26;};
+static void scm_lambda_147(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_146_env {struct obj *v_k_26;struct obj *v_rv_74;};
+static void scm_lambda_146(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_144_env {struct obj *v_k_26;struct obj *v_rv_74;struct obj *v_rv_73;};
+static void scm_lambda_144(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_140_env {struct obj *v_k_26;struct obj *v_rv_74;struct obj *v_rv_73;};
+static void scm_lambda_140(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_135_env {struct obj *v_k_26;struct obj *v_rv_74;};
+static void scm_lambda_135(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_131_env {struct obj *v_k_26;};
+static void scm_lambda_131(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_128(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_128_closure;
+struct scm_lambda_136_env {struct obj *v_k_27;};
+static void scm_lambda_136(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_132(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_132_closure;
+struct scm_lambda_145_env {struct obj *v_k_28;struct obj *v_rv_77;};
+static void scm_lambda_145(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_141_env {struct obj *v_k_28;};
+static void scm_lambda_141(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_137(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_137_closure;
+struct scm_lambda_142_env {struct obj *v_k_29;};
+static void scm_lambda_142(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_138_env {struct obj *v_k_29;};
+static void scm_lambda_138(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_133_env {struct obj *v_k_29;};
+static void scm_lambda_133(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_129_env {struct obj *v_k_29;};
+static void scm_lambda_129(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_125_env {struct obj *v_k_29;};
+static void scm_lambda_125(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_122_env {struct obj *v_k_29;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_117_env {struct obj *v_k_29;struct obj *v_w;};
+static void scm_lambda_117(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_112_env {struct obj *v_k_29;struct obj *v_w;};
+static void scm_lambda_112(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_108_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_w;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_w;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_100_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_w;};
+static void scm_lambda_100(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_w;};
+static void scm_lambda_97(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_93_env {struct obj *parent;};
+static void scm_lambda_93(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_out;struct obj *v_v;struct obj *v_w;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *parent;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_78_env {struct obj *parent;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *parent;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_out;struct obj *v_v;struct obj *v_w;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *parent;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *parent;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *parent;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_out;struct obj *v_v;struct obj *v_w;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *parent;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *parent;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *parent;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_out;struct obj *v_v;struct obj *v_w;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *parent;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *parent;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *parent;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_out;struct obj *v_v;struct obj *v_w;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *parent;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *parent;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *parent;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_k_29;struct obj *v_l;struct obj *v_out;struct obj *v_v;struct obj *v_w;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_k_33;struct obj *v_out;struct obj *v_v;struct obj *v_w;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_33;struct obj *v_out;struct obj *v_v;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_k_33;struct obj *v_out;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_33;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_33;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_33;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_lambda_7_inlined_env {struct obj *v_k_33;};
+static void scm_lambda_7_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_inlined_env {struct obj *v_k_33;};
+static void scm_lambda_5_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_inlined_inlined_env {struct obj *v_k_33;};
+static void scm_lambda_7_inlined_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj symbol__23_hash = SYMBOL_OBJ_INIT("#hash");
+static struct symbol_obj symbol_1x = SYMBOL_OBJ_INIT("1x");
+static struct symbol_obj symbol_b = SYMBOL_OBJ_INIT("b");
+static struct symbol_obj symbol_c = SYMBOL_OBJ_INIT("c");
+static struct symbol_obj symbol_end = SYMBOL_OBJ_INIT("end");
+static struct symbol_obj symbol_plain = SYMBOL_OBJ_INIT("plain");
+static struct symbol_obj symbol_two_20_words = SYMBOL_OBJ_INIT("two words");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_1x),&(symbol__23_hash),&(symbol_b),&(symbol_c),&(symbol_end),&(symbol_plain),&(symbol_two_20_words),NULL};
+static struct string_obj quoted_26987bf595c9678c_item_2 = STRING_OBJ_INIT("d\\e");
+static struct cons_obj quoted_26987bf595c9678c_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_26987bf595c9678c_item_2)),NULL);
+static struct cons_obj quoted_26987bf595c9678c_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_c)),(struct obj *)(&(quoted_26987bf595c9678c_2)));
+static struct string_obj quoted_26987bf595c9678c_item_0 = STRING_OBJ_INIT("a\"b");
+static struct cons_obj quoted_26987bf595c9678c = CONS_OBJ_INIT((struct obj *)(&(quoted_26987bf595c9678c_item_0)),(struct obj *)(&(quoted_26987bf595c9678c_1)));
+static struct cons_obj quoted_51e0379dc32d8d38_2 = CONS_OBJ_INIT(MAKE_FIXNUM(3),NULL);
+static struct cons_obj quoted_51e0379dc32d8d38_1 = CONS_OBJ_INIT(MAKE_FIXNUM(2),(struct obj *)(&(quoted_51e0379dc32d8d38_2)));
+static struct cons_obj quoted_51e0379dc32d8d38 = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_51e0379dc32d8d38_1)));
+static struct string_obj quoted_53bc4659da7ebf0c_item_0 = STRING_OBJ_INIT("x");
+static struct cons_obj quoted_53bc4659da7ebf0c = CONS_OBJ_INIT((struct obj *)(&(quoted_53bc4659da7ebf0c_item_0)),NULL);
+static struct cons_obj quoted_af56c6e838f673b6_1 = CONS_OBJ_INIT((struct obj *)(&(scheme_true)),NULL);
+static struct float_obj quoted_af56c6e838f673b6_item_0 = FLOAT_OBJ_INIT(1.5);
+static struct cons_obj quoted_af56c6e838f673b6 = CONS_OBJ_INIT((struct obj *)(&(quoted_af56c6e838f673b6_item_0)),(struct obj *)(&(quoted_af56c6e838f673b6_1)));
+static struct cons_obj quoted_bb2664dec2ea9b84_3 = CONS_OBJ_INIT(NULL,NULL);
+static struct cons_obj quoted_bb2664dec2ea9b84_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_af56c6e838f673b6)),(struct obj *)(&(quoted_bb2664dec2ea9b84_3)));
+static struct cons_obj quoted_bb2664dec2ea9b84_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_b)),(struct obj *)(&(quoted_bb2664dec2ea9b84_2)));
+static struct string_obj quoted_bb2664dec2ea9b84_item_0 = STRING_OBJ_INIT("a");
+static struct cons_obj quoted_bb2664dec2ea9b84 = CONS_OBJ_INIT((struct obj *)(&(quoted_bb2664dec2ea9b84_item_0)),(struct obj *)(&(quoted_bb2664dec2ea9b84_1)));
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_15),"scm_lambda_15","printer.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","printer.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","printer.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","printer.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","printer.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","printer.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","printer.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","printer.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","printer.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","printer.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","printer.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","printer.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","printer.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","printer.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","printer.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","printer.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","printer.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","printer.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","printer.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","printer.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","printer.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","printer.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","printer.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","printer.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","printer.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","printer.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","printer.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","printer.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","printer.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","printer.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","printer.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","printer.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","printer.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","printer.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","printer.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","printer.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","printer.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","printer.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","printer.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","printer.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","printer.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","printer.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","printer.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","printer.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","printer.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","printer.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","printer.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","printer.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","printer.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","printer.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","printer.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","printer.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","printer.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","printer.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","printer.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","printer.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","printer.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","printer.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","printer.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","printer.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","printer.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","printer.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","printer.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","printer.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","printer.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","printer.scm",1},{(void *)(scm_lambda_105),"scm_lambda_105","printer.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","printer.scm",1},{(void *)(scm_lambda_99),"scm_lambda_99","printer.scm",1},{(void *)(scm_lambda_118),"scm_lambda_118","printer.scm",1},{(void *)(scm_lambda_113),"scm_lambda_113","printer.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","printer.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","printer.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","printer.scm",1},{(void *)(scm_lambda_114),"scm_lambda_114","printer.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","printer.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","printer.scm",1},{(void *)(scm_lambda_119),"scm_lambda_119","printer.scm",1},{(void *)(scm_lambda_115),"scm_lambda_115","printer.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","printer.scm",1},{(void *)(scm_lambda_120),"scm_lambda_120","printer.scm",1},{(void *)(scm_lambda_116),"scm_lambda_116","printer.scm",1},{(void *)(scm_lambda_143),"scm_lambda_143","printer.scm",1},{(void *)(scm_lambda_139),"scm_lambda_139","printer.scm",1},{(void *)(scm_lambda_134),"scm_lambda_134","printer.scm",1},{(void *)(scm_lambda_130),"scm_lambda_130","printer.scm",1},{(void *)(scm_lambda_126),"scm_lambda_126","printer.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","printer.scm",1},{(void *)(scm_lambda_121),"scm_lambda_121","printer.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","printer.scm",1},{(void *)(scm_lambda_124),"scm_lambda_124","printer.scm",1},{(void *)(scm_lambda_149),"scm_lambda_149","printer.scm",1},{(void *)(scm_lambda_148),"scm_lambda_148","printer.scm",1},{(void *)(scm_lambda_147),"scm_lambda_147","printer.scm",1},{(void *)(scm_lambda_146),"scm_lambda_146","printer.scm",1},{(void *)(scm_lambda_144),"scm_lambda_144","printer.scm",1},{(void *)(scm_lambda_140),"scm_lambda_140","printer.scm",1},{(void *)(scm_lambda_135),"scm_lambda_135","printer.scm",1},{(void *)(scm_lambda_131),"scm_lambda_131","printer.scm",1},{(void *)(scm_lambda_128),"scm_lambda_128","printer.scm",1},{(void *)(scm_lambda_136),"scm_lambda_136","printer.scm",1},{(void *)(scm_lambda_132),"scm_lambda_132","printer.scm",1},{(void *)(scm_lambda_145),"scm_lambda_145","printer.scm",1},{(void *)(scm_lambda_141),"scm_lambda_141","printer.scm",1},{(void *)(scm_lambda_137),"scm_lambda_137","printer.scm",1},{(void *)(scm_lambda_142),"scm_lambda_142","printer.scm",1},{(void *)(scm_lambda_138),"scm_lambda_138","printer.scm",1},{(void *)(scm_lambda_133),"scm_lambda_133","printer.scm",1},{(void *)(scm_lambda_129),"scm_lambda_129","printer.scm",1},{(void *)(scm_lambda_125),"scm_lambda_125","printer.scm",1},{(void *)(scm_lambda_122),"scm_lambda_122","printer.scm",1},{(void *)(scm_lambda_117),"scm_lambda_117","printer.scm",1},{(void *)(scm_lambda_112),"scm_lambda_112","printer.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","printer.scm",1},{(void *)(scm_lambda_104),"scm_lambda_104","printer.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","printer.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","printer.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","printer.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","printer.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","printer.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","printer.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","printer.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","printer.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","printer.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","printer.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","printer.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","printer.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","printer.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","printer.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","printer.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","printer.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","printer.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","printer.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","printer.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","printer.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","printer.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","printer.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","printer.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","printer.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","printer.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","printer.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","printer.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","printer.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","printer.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","printer.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","printer.scm",1},{(void *)(scm_lambda_7_inlined),"scm_lambda_7_inlined","printer.scm",1},{(void *)(scm_lambda_5_inlined),"scm_lambda_5_inlined","printer.scm",1},{(void *)(scm_lambda_7_inlined_inlined),"scm_lambda_7_inlined_inlined","printer.scm",1},{NULL}};
+
+#line 1 "printer.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_lambda_15_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_15,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_12,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_51e0379dc32d8d38)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_10_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_10);
+
+#line 1 "printer.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_13(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_16,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_13_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_13);
+
+#line 1 "printer.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));call_closure_two((env)->v_rv_5,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_rv_5)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_35,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_30,var_1);call_closure_two((env)->v_rv_9,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_26_env *)(&((var_1)->env)))->v_rv_9)=((env)->v_rv_9);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_26,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_22_env *)(&((var_2)->env)))->v_rv_9)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_22,var_2);call_closure_two(var_1,MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_17(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_19,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_17_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_17);
+
+#line 1 "printer.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_20(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_23,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_20_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_20);
+
+#line 1 "printer.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));call_closure_two((env)->v_rv_12,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_rv_12)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_31,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_24(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_27,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_bb2664dec2ea9b84)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_24_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_24);
+
+#line 1 "printer.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_32,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_28_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_28);
+
+#line 1 "printer.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));call_closure_two((env)->v_rv_15,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_rv_15)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_73,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,list_to_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_64,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_60,var_1);call_closure_two((env)->v_rv_23,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_57_env *)(&((var_1)->env)))->v_rv_23)=((env)->v_rv_23);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_57,var_1);call_closure_two((env)->v_rv_22,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_rv_23)=((env)->v_rv_23);(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_rv_22)=((env)->v_rv_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_53,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_rv_23)=((env)->v_rv_23);(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_rv_22)=((env)->v_rv_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_48,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,list_to_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_rv_23)=((env)->v_rv_23);(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_rv_22)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_44,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_STRING_OBJ_NEW(var_2,"c");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_3)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_40_env *)(&((var_3)->env)))->v_rv_23)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_40,var_3);call_closure_two(var_1,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_33(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_36,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_33_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_33);
+
+#line 1 "printer.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_41,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_37_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_37);
+
+#line 1 "printer.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));call_closure_two((env)->v_rv_26,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_49,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_42(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_CLOSURE_TWO_NEW(var_3,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_4)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_45,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_42_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_42);
+
+#line 1 "printer.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_46(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_50,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_46_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_46);
+
+#line 1 "printer.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_out))->val,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_51(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,write_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_k_11)=(var_1);(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_54,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_26987bf595c9678c)),var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_out))->val,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_79(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_79_env *env = (struct scm_lambda_79_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,write_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_84_env *)(&((var_2)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_84,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_79_env );(((struct scm_lambda_79_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_79_env *)(&((var_1)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_79,var_1);call_closure_two((env)->v_rv_33,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"z");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_out)=((env)->v_out);(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_rv_33)=((env)->v_rv_33);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_74,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_STRING_OBJ_NEW(var_2,"y");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_out)=((env)->v_out);(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_rv_33)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_69,var_3);call_closure_two(var_1,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_65,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_58(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,list_to_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_k_12)=(var_1);(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_61,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_53bc4659da7ebf0c)),var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_v))->val)=(var_0);GC_WRITE_BARRIER((env)->v_v,((struct cell_obj *)((env)->v_v))->val);call_closure_one((env)->v_k_13,NULL);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_75,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_66(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,make_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_3)->env)))->v_k_13)=(var_1);(((struct scm_lambda_70_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_70,var_3);call_closure_two(var_2,MAKE_FIXNUM(3),var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_80(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_v))->val,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_1)->env)))->v_k_14)=((env)->v_k_14);(((struct scm_lambda_80_env *)(&((var_1)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_80,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_71(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,vector_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_3)->env)))->v_k_14)=(var_1);(((struct scm_lambda_76_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_76,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_v))->val,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));call_closure_two((env)->v_rv_43,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_rv_43)=((env)->v_rv_43);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_101,var_1);call_closure_two((env)->v_rv_42,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_94(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_98_env *)(&((var_1)->env)))->v_rv_43)=((env)->v_rv_43);(((struct scm_lambda_98_env *)(&((var_1)->env)))->v_rv_42)=((env)->v_rv_42);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_98,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_89(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_89_env *env = (struct scm_lambda_89_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_rv_43)=((env)->v_rv_43);(((struct scm_lambda_94_env *)(&((var_2)->env)))->v_rv_42)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_94,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_end)),var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_89_env );(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_89_env *)(&((var_2)->env)))->v_rv_43)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_89,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_v))->val,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_85_env *)(&((var_1)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_85,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_77(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,vector_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_3)->env)))->v_k_15)=(var_1);(((struct scm_lambda_81_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_81,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_v))->val,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_90(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_90_env *env = (struct scm_lambda_90_env *)(&((env_in)->env));call_closure_two((env)->v_rv_46,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_86(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_86_env *env = (struct scm_lambda_86_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_90_env );(((struct scm_lambda_90_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_lambda_90_env *)(&((var_2)->env)))->v_rv_46)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_90,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_82(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_82_env *env = (struct scm_lambda_82_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_86_env );(((struct scm_lambda_86_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_86,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_v))->val,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_87(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_91,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_87_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_87);
+
+#line 1 "printer.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_out))->val,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_92(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,write_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_3)->env)))->v_k_18)=(var_1);(((struct scm_lambda_95_env *)(&((var_3)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_95,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_v))->val,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_105(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_105_env *env = (struct scm_lambda_105_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_w))->val)=(var_0);GC_WRITE_BARRIER((env)->v_w,((struct cell_obj *)((env)->v_w))->val);call_closure_one((env)->v_k_19,NULL);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_102(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_105_env );(((struct scm_lambda_105_env *)(&((var_1)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_105_env *)(&((var_1)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_105,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_99(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,make_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_3)->env)))->v_k_19)=(var_1);(((struct scm_lambda_102_env *)(&((var_3)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_102,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_118(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_l))->val)=(var_0);GC_WRITE_BARRIER((env)->v_l,((struct cell_obj *)((env)->v_l))->val);call_closure_one((env)->v_k_20,NULL);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_118_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_118,var_1);call_closure_two((env)->v_rv_54,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_109(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_109_env *env = (struct scm_lambda_109_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_1)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_113_env *)(&((var_1)->env)))->v_l)=((env)->v_l);(((struct scm_lambda_113_env *)(&((var_1)->env)))->v_rv_54)=((env)->v_rv_54);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_113,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_109_env );(((struct scm_lambda_109_env *)(&((var_2)->env)))->v_k_20)=((env)->v_k_20);(((struct scm_lambda_109_env *)(&((var_2)->env)))->v_l)=((env)->v_l);(((struct scm_lambda_109_env *)(&((var_2)->env)))->v_rv_54)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_109,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_w))->val,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_103(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_103_env *env = (struct scm_lambda_103_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_k_20)=(var_1);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_l)=((env)->v_l);(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_106,var_3);call_closure_two(var_2,MAKE_FIXNUM(1),var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_114(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_114_env *env = (struct scm_lambda_114_env *)(&((env_in)->env));call_closure_two(var_0,((struct cell_obj *)((env)->v_l))->val,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_110(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_110_env *env = (struct scm_lambda_110_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_114_env );(((struct scm_lambda_114_env *)(&((var_1)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_114_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_114,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_107(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_107_env *env = (struct scm_lambda_107_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,vector_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_110_env );(((struct scm_lambda_110_env *)(&((var_3)->env)))->v_k_21)=(var_1);(((struct scm_lambda_110_env *)(&((var_3)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_110,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_w))->val,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_119(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_119_env *env = (struct scm_lambda_119_env *)(&((env_in)->env));call_closure_two((env)->v_rv_58,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_115(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_115_env *env = (struct scm_lambda_115_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_119_env );(((struct scm_lambda_119_env *)(&((var_2)->env)))->v_k_22)=((env)->v_k_22);(((struct scm_lambda_119_env *)(&((var_2)->env)))->v_rv_58)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_119,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_111(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_115_env );(((struct scm_lambda_115_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_115,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_l))->val,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_120(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_120_env *env = (struct scm_lambda_120_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_116(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_120_env );(((struct scm_lambda_120_env *)(&((var_3)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_120,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_116_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_116);
+
+#line 1 "printer.scm"
+static void scm_lambda_143(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_143_env *env = (struct scm_lambda_143_env *)(&((env_in)->env));call_closure_two((env)->v_rv_61,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_139(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_139_env *env = (struct scm_lambda_139_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_143_env );(((struct scm_lambda_143_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_143_env *)(&((var_2)->env)))->v_rv_61)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_143,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_134(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_134_env *env = (struct scm_lambda_134_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_139_env );(((struct scm_lambda_139_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_139,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_130(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_130_env *env = (struct scm_lambda_130_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_134_env );(((struct scm_lambda_134_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_134,var_1);call_closure_two((env)->v_rv_65,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_126(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_126_env *env = (struct scm_lambda_126_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_130_env );(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_130_env *)(&((var_1)->env)))->v_rv_65)=((env)->v_rv_65);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_130,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_123(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_123_env *env = (struct scm_lambda_123_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_126_env );(((struct scm_lambda_126_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_126_env *)(&((var_2)->env)))->v_rv_65)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_126,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_w))->val,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_121(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_121_env *env = (struct scm_lambda_121_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_123_env );(((struct scm_lambda_123_env *)(&((var_3)->env)))->v_k_24)=(var_1);(((struct scm_lambda_123_env *)(&((var_3)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_123,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_w))->val,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_127(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_124(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_3)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_127,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_124_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_124);
+
+#line 1 "printer.scm"
+static void scm_lambda_149(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_149_env *env = (struct scm_lambda_149_env *)(&((env_in)->env));call_closure_two((env)->v_rv_68,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_148(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_148_env *env = (struct scm_lambda_148_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_149_env );(((struct scm_lambda_149_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_149_env *)(&((var_2)->env)))->v_rv_68)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_149,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_147(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_147_env *env = (struct scm_lambda_147_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,write_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_148_env );(((struct scm_lambda_148_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_148,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_146(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_146_env *env = (struct scm_lambda_146_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_147_env );(((struct scm_lambda_147_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_147,var_1);call_closure_two((env)->v_rv_74,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_144(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_144_env *env = (struct scm_lambda_144_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_146_env );(((struct scm_lambda_146_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_146_env *)(&((var_1)->env)))->v_rv_74)=((env)->v_rv_74);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_146,var_1);call_closure_two((env)->v_rv_73,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_140(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_140_env *env = (struct scm_lambda_140_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_144_env );(((struct scm_lambda_144_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_144_env *)(&((var_1)->env)))->v_rv_74)=((env)->v_rv_74);(((struct scm_lambda_144_env *)(&((var_1)->env)))->v_rv_73)=((env)->v_rv_73);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_144,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_1x)),var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_135(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_135_env *env = (struct scm_lambda_135_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_140_env );(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_rv_74)=((env)->v_rv_74);(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_rv_73)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_140,var_2);call_closure_two(var_1,(struct obj *)(&(symbol__23_hash)),var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_131(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_131_env *env = (struct scm_lambda_131_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_135_env );(((struct scm_lambda_135_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_lambda_135_env *)(&((var_2)->env)))->v_rv_74)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_135,var_2);call_closure_two(var_1,(struct obj *)(&(symbol_plain)),var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_128(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_131_env );(((struct scm_lambda_131_env *)(&((var_3)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_131,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_two_20_words)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_128_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_128);
+
+#line 1 "printer.scm"
+static void scm_lambda_136(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_136_env *env = (struct scm_lambda_136_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_132(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_136_env );(((struct scm_lambda_136_env *)(&((var_3)->env)))->v_k_27)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_136,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_132_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_132);
+
+#line 1 "printer.scm"
+static void scm_lambda_145(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_145_env *env = (struct scm_lambda_145_env *)(&((env_in)->env));call_closure_two((env)->v_rv_77,var_0,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_141(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_141_env *env = (struct scm_lambda_141_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_145_env );(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_lambda_145_env *)(&((var_2)->env)))->v_rv_77)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_145,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_137(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_141_env );(((struct scm_lambda_141_env *)(&((var_3)->env)))->v_k_28)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_141,var_3);call_closure_two(var_2,(struct obj *)(&(symbol_two_20_words)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_137_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_137);
+
+#line 1 "printer.scm"
+static void scm_lambda_142(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_142_env *env = (struct scm_lambda_142_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_29);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_138(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_138_env *env = (struct scm_lambda_138_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_142_env );(((struct scm_lambda_142_env *)(&((var_2)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_142,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_133(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_133_env *env = (struct scm_lambda_133_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_138_env );(((struct scm_lambda_138_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_138,var_1);call_closure_two((struct obj *)(&(scm_lambda_137_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_129(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_129_env *env = (struct scm_lambda_129_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_133_env );(((struct scm_lambda_133_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_133,var_1);call_closure_two((struct obj *)(&(scm_lambda_132_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_125(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_125_env *env = (struct scm_lambda_125_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_129_env );(((struct scm_lambda_129_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_129,var_1);call_closure_two((struct obj *)(&(scm_lambda_128_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_125_env );(((struct scm_lambda_125_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_125,var_1);call_closure_two((struct obj *)(&(scm_lambda_124_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_117(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_117_env *env = (struct scm_lambda_117_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_121_env );(((struct scm_lambda_121_env *)(&((var_1)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_121,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_3)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_122,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_112(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_117_env );(((struct scm_lambda_117_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);(((struct scm_lambda_117_env *)(&((var_1)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_117,var_1);call_closure_two((struct obj *)(&(scm_lambda_116_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_111,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_3)->env)))->v_k_29)=((env)->v_k_29);(((struct scm_lambda_112_env *)(&((var_3)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_112,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_107_env );(((struct scm_lambda_107_env *)(&((var_1)->env)))->v_l)=((env)->v_l);(((struct scm_lambda_107_env *)(&((var_1)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_107,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_3)->env)))->v_k_29)=((env)->v_k_29);(((struct scm_lambda_108_env *)(&((var_3)->env)))->v_l)=((env)->v_l);(((struct scm_lambda_108_env *)(&((var_3)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_108,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_100(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_100_env *env = (struct scm_lambda_100_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_103_env );(((struct scm_lambda_103_env *)(&((var_1)->env)))->v_l)=((env)->v_l);(((struct scm_lambda_103_env *)(&((var_1)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_103,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_3)->env)))->v_k_29)=((env)->v_k_29);(((struct scm_lambda_104_env *)(&((var_3)->env)))->v_l)=((env)->v_l);(((struct scm_lambda_104_env *)(&((var_3)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_104,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_97(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_1)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_99,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_100_env );(((struct scm_lambda_100_env *)(&((var_3)->env)))->v_k_29)=((env)->v_k_29);(((struct scm_lambda_100_env *)(&((var_3)->env)))->v_l)=((env)->v_l);(((struct scm_lambda_100_env *)(&((var_3)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_100,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_93(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_93_env *env = (struct scm_lambda_93_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_2)->env)))->v_k_29)=(((struct scm_lambda_88_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_29);(((struct scm_lambda_97_env *)(&((var_2)->env)))->v_l)=(((struct scm_lambda_88_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_l);(((struct scm_lambda_97_env *)(&((var_2)->env)))->v_w)=(((struct scm_lambda_88_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_w);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_97,var_2);call_closure_two(var_1,((struct cell_obj *)(((struct scm_lambda_88_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_out))->val,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_1)->env)))->v_out)=((env)->v_out);(((struct scm_lambda_92_env *)(&((var_1)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_92,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_93_env );(((struct scm_lambda_93_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_93,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_k_29)=(((struct scm_lambda_67_env *)(&(((struct env_obj *)(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_29);(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_l)=(((struct scm_lambda_67_env *)(&(((struct env_obj *)(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_l);(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_out)=(((struct scm_lambda_67_env *)(&(((struct env_obj *)(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_out);(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_67_env *)(&(((struct env_obj *)(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_88_env *)(&((var_1)->env)))->v_w)=(((struct scm_lambda_67_env *)(&(((struct env_obj *)(((struct scm_lambda_72_env *)(&(((struct env_obj *)(((struct scm_lambda_78_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_w);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_88,var_1);call_closure_two((struct obj *)(&(scm_lambda_87_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_82_env );(((struct scm_lambda_82_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_67_env *)(&(((struct env_obj *)(((struct scm_lambda_72_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_82,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_83,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_67_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_77,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_78,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_71,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_72,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_52_env *)(&(((struct env_obj *)(((struct scm_lambda_56_env *)(&(((struct env_obj *)(((struct scm_lambda_59_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_66,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_3)->env)))->v_k_29)=(((struct scm_lambda_52_env *)(&(((struct env_obj *)(((struct scm_lambda_56_env *)(&(((struct env_obj *)(((struct scm_lambda_59_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_29);(((struct scm_lambda_67_env *)(&((var_3)->env)))->v_l)=(((struct scm_lambda_52_env *)(&(((struct env_obj *)(((struct scm_lambda_56_env *)(&(((struct env_obj *)(((struct scm_lambda_59_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_l);(((struct scm_lambda_67_env *)(&((var_3)->env)))->v_out)=(((struct scm_lambda_52_env *)(&(((struct env_obj *)(((struct scm_lambda_56_env *)(&(((struct env_obj *)(((struct scm_lambda_59_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_out);(((struct scm_lambda_67_env *)(&((var_3)->env)))->v_v)=(((struct scm_lambda_52_env *)(&(((struct env_obj *)(((struct scm_lambda_56_env *)(&(((struct env_obj *)(((struct scm_lambda_59_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_67_env *)(&((var_3)->env)))->v_w)=(((struct scm_lambda_52_env *)(&(((struct env_obj *)(((struct scm_lambda_56_env *)(&(((struct env_obj *)(((struct scm_lambda_59_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_67,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_63,var_2);call_closure_two(var_1,((struct cell_obj *)(((struct scm_lambda_52_env *)(&(((struct env_obj *)(((struct scm_lambda_56_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_out))->val,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_out)=(((struct scm_lambda_52_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_out);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_58,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_59,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_56,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_out))->val,var_3);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_1)->env)))->v_out)=(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_out);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_51,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_k_29)=(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_29);(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_l)=(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_l);(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_out)=(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_out);(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_v)=(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_w)=(((struct scm_lambda_34_env *)(&(((struct env_obj *)(((struct scm_lambda_38_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_w);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_52,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_47,var_1);call_closure_two((struct obj *)(&(scm_lambda_46_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_43,var_1);call_closure_two((struct obj *)(&(scm_lambda_42_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_38,var_1);call_closure_two((struct obj *)(&(scm_lambda_37_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_k_29)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_29);(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_l)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_l);(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_out)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_out);(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_w)=(((struct scm_lambda_18_env *)(&(((struct env_obj *)(((struct scm_lambda_21_env *)(&(((struct env_obj *)(((struct scm_lambda_25_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_w);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_34,var_1);call_closure_two((struct obj *)(&(scm_lambda_33_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_29,var_1);call_closure_two((struct obj *)(&(scm_lambda_28_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_25,var_1);call_closure_two((struct obj *)(&(scm_lambda_24_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_21,var_1);call_closure_two((struct obj *)(&(scm_lambda_20_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_1)->env)))->v_k_29)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_9_env *)(&(((struct env_obj *)(((struct scm_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_29);(((struct scm_lambda_18_env *)(&((var_1)->env)))->v_l)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_9_env *)(&(((struct env_obj *)(((struct scm_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_l);(((struct scm_lambda_18_env *)(&((var_1)->env)))->v_out)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_9_env *)(&(((struct env_obj *)(((struct scm_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_out);(((struct scm_lambda_18_env *)(&((var_1)->env)))->v_v)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_9_env *)(&(((struct env_obj *)(((struct scm_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_v);(((struct scm_lambda_18_env *)(&((var_1)->env)))->v_w)=(((struct scm_lambda_8_env *)(&(((struct env_obj *)(((struct scm_lambda_9_env *)(&(((struct env_obj *)(((struct scm_lambda_11_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_w);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_18,var_1);call_closure_two((struct obj *)(&(scm_lambda_17_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_14,var_1);call_closure_two((struct obj *)(&(scm_lambda_13_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_out))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_out,((struct cell_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_out))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two((struct obj *)(&(scm_lambda_10_closure)),NULL,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_l)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_29)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_3,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_4)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_9,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_w)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_out)=((env)->v_out);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_v)=((env)->v_v);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_w)=((env)->v_w);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_two(var_4,NULL,(env)->v_k_33);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_v)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_k_33)=((env)->v_k_33);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_out)=((env)->v_out);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_v)=((env)->v_v);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_7_inlined_inlined_env );(((struct scm_lambda_7_inlined_inlined_env *)(&((var_5)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_7_inlined_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_out)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_k_33)=((env)->v_k_33);(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_out)=((env)->v_out);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_5_inlined_env );(((struct scm_lambda_5_inlined_env *)(&((var_5)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_5_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_33);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_33)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_33)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "printer.scm"
+static void scm_lambda_7_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_inlined_env *env = (struct scm_lambda_7_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_33);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_5_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_inlined_env *env = (struct scm_lambda_5_inlined_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_inlined_env );(((struct scm_lambda_7_inlined_env *)(&((var_1)->env)))->v_k_33)=((env)->v_k_33);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7_inlined,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+static void scm_lambda_7_inlined_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_inlined_inlined_env *env = (struct scm_lambda_7_inlined_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_33);__builtin_unreachable();}
+
+#line 1 "printer.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 802 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
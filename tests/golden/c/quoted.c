@@ -0,0 +1,392 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+static void scm_data(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_data_closure;
+struct scm_lambda_16_env {struct obj *v_k_2;struct obj *v_rv_2;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_k_2;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_2;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_data;};
+static void scm_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_3;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_10_closure;
+struct scm_lambda_48_env {struct obj *v_k_4;struct obj *v_rv_6;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_k_4;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_4;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_4;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_4;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_4;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_4;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_4;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_data;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_5;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_18_closure;
+struct scm_lambda_43_env {struct obj *v_k_6;struct obj *v_rv_15;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_6;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_k_6;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_6;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_6;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_22_closure;
+struct scm_lambda_30_env {struct obj *v_k_7;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_26_closure;
+struct scm_lambda_60_env {struct obj *v_k_8;struct obj *v_rv_21;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_8;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_8;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *v_k_8;struct obj *v_rv_27;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_8;struct obj *v_rv_27;struct obj *v_rv_26;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_8;struct obj *v_rv_27;struct obj *v_rv_26;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_8;struct obj *v_rv_27;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_8;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_l;};
+static void scm_lambda_35(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_9;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_40(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_40_closure;
+struct scm_count_lambda_4_env {struct obj *v_k_10;struct obj *v_rv_31;};
+static void scm_count_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_lambda_3_env {struct obj *v_count;struct obj *v_k_10;struct obj *v_rv_31;};
+static void scm_count_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_lambda_2_env {struct obj *v_count;struct obj *v_k_10;struct obj *v_xs;};
+static void scm_count_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_lambda_1_env {struct obj *v_count;struct obj *v_k_10;struct obj *v_xs;};
+static void scm_count_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_env {struct obj *v_count;};
+static void scm_count(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_11;struct obj *v_rv_34;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_11;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_11;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_count;};
+static void scm_lambda_50(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_12;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_12;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_count;struct obj *v_k_12;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_count;struct obj *v_k_12;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_count;struct obj *v_k_12;struct obj *v_l;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_count;struct obj *v_k_12;struct obj *v_l;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_count;struct obj *v_k_12;struct obj *v_l;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_count;struct obj *v_k_12;struct obj *v_l;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *parent;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *parent;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_count;struct obj *v_data;struct obj *v_k_12;struct obj *v_l;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_data;struct obj *v_k_15;struct obj *v_l;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_data;struct obj *v_k_15;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_15;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_15;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+struct scm_lambda_5_inlined_env {struct obj *v_k_15;};
+static void scm_lambda_5_inlined(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct symbol_obj symbol_a = SYMBOL_OBJ_INIT("a");
+static struct symbol_obj symbol_b = SYMBOL_OBJ_INIT("b");
+static struct symbol_obj symbol_nested = SYMBOL_OBJ_INIT("nested");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_a),&(symbol_b),&(symbol_nested),NULL};
+static struct cons_obj quoted_1e2e0356946ea828_1 = CONS_OBJ_INIT((struct obj *)(&(symbol_b)),NULL);
+static struct cons_obj quoted_1e2e0356946ea828 = CONS_OBJ_INIT((struct obj *)(&(symbol_nested)),(struct obj *)(&(quoted_1e2e0356946ea828_1)));
+static struct cons_obj quoted_14c3b2238347efdb_5 = CONS_OBJ_INIT((struct obj *)(&(quoted_1e2e0356946ea828)),NULL);
+static struct cons_obj quoted_14c3b2238347efdb_4 = CONS_OBJ_INIT((struct obj *)(&(scheme_false)),(struct obj *)(&(quoted_14c3b2238347efdb_5)));
+static struct cons_obj quoted_14c3b2238347efdb_3 = CONS_OBJ_INIT((struct obj *)(&(scheme_true)),(struct obj *)(&(quoted_14c3b2238347efdb_4)));
+static struct cons_obj quoted_14c3b2238347efdb_2 = CONS_OBJ_INIT((struct obj *)(&(symbol_a)),(struct obj *)(&(quoted_14c3b2238347efdb_3)));
+static struct int_obj quoted_f1073be9324eb9e4_item_4 = INT_OBJ_INIT(4611686018427387904);
+static struct cons_obj quoted_f1073be9324eb9e4_4 = CONS_OBJ_INIT((struct obj *)(&(quoted_f1073be9324eb9e4_item_4)),NULL);
+static struct rational_obj quoted_f1073be9324eb9e4_item_3 = RATIONAL_OBJ_INIT(1,3);
+static struct cons_obj quoted_f1073be9324eb9e4_3 = CONS_OBJ_INIT((struct obj *)(&(quoted_f1073be9324eb9e4_item_3)),(struct obj *)(&(quoted_f1073be9324eb9e4_4)));
+static struct float_obj quoted_f1073be9324eb9e4_item_2 = FLOAT_OBJ_INIT(2.5);
+static struct cons_obj quoted_f1073be9324eb9e4_2 = CONS_OBJ_INIT((struct obj *)(&(quoted_f1073be9324eb9e4_item_2)),(struct obj *)(&(quoted_f1073be9324eb9e4_3)));
+static struct string_obj quoted_f1073be9324eb9e4_item_1 = STRING_OBJ_INIT("x");
+static struct cons_obj quoted_f1073be9324eb9e4_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_f1073be9324eb9e4_item_1)),(struct obj *)(&(quoted_f1073be9324eb9e4_2)));
+static struct cons_obj quoted_f1073be9324eb9e4 = CONS_OBJ_INIT(MAKE_FIXNUM(2),(struct obj *)(&(quoted_f1073be9324eb9e4_1)));
+static struct cons_obj quoted_14c3b2238347efdb_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_f1073be9324eb9e4)),(struct obj *)(&(quoted_14c3b2238347efdb_2)));
+static struct cons_obj quoted_14c3b2238347efdb = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_14c3b2238347efdb_1)));
+static struct cons_obj quoted_6420d13c8f25c16f_9 = CONS_OBJ_INIT(MAKE_FIXNUM(10),NULL);
+static struct cons_obj quoted_6420d13c8f25c16f_8 = CONS_OBJ_INIT(MAKE_FIXNUM(9),(struct obj *)(&(quoted_6420d13c8f25c16f_9)));
+static struct cons_obj quoted_6420d13c8f25c16f_7 = CONS_OBJ_INIT(MAKE_FIXNUM(8),(struct obj *)(&(quoted_6420d13c8f25c16f_8)));
+static struct cons_obj quoted_6420d13c8f25c16f_6 = CONS_OBJ_INIT(MAKE_FIXNUM(7),(struct obj *)(&(quoted_6420d13c8f25c16f_7)));
+static struct cons_obj quoted_6420d13c8f25c16f_5 = CONS_OBJ_INIT(MAKE_FIXNUM(6),(struct obj *)(&(quoted_6420d13c8f25c16f_6)));
+static struct cons_obj quoted_6420d13c8f25c16f_4 = CONS_OBJ_INIT(MAKE_FIXNUM(5),(struct obj *)(&(quoted_6420d13c8f25c16f_5)));
+static struct cons_obj quoted_6420d13c8f25c16f_3 = CONS_OBJ_INIT(MAKE_FIXNUM(4),(struct obj *)(&(quoted_6420d13c8f25c16f_4)));
+static struct cons_obj quoted_6420d13c8f25c16f_2 = CONS_OBJ_INIT(MAKE_FIXNUM(3),(struct obj *)(&(quoted_6420d13c8f25c16f_3)));
+static struct cons_obj quoted_6420d13c8f25c16f_1 = CONS_OBJ_INIT(MAKE_FIXNUM(2),(struct obj *)(&(quoted_6420d13c8f25c16f_2)));
+static struct cons_obj quoted_6420d13c8f25c16f = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_6420d13c8f25c16f_1)));
+static struct cons_obj quoted_7b1824f3753c6df8_1 = CONS_OBJ_INIT(MAKE_FIXNUM(2),NULL);
+static struct cons_obj quoted_7b1824f3753c6df8 = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_7b1824f3753c6df8_1)));
+static struct cons_obj quoted_8ec3de55083225c5_2 = CONS_OBJ_INIT(MAKE_FIXNUM(3),NULL);
+static struct string_obj quoted_8ec3de55083225c5_item_1 = STRING_OBJ_INIT("two");
+static struct cons_obj quoted_8ec3de55083225c5_1 = CONS_OBJ_INIT((struct obj *)(&(quoted_8ec3de55083225c5_item_1)),(struct obj *)(&(quoted_8ec3de55083225c5_2)));
+static struct cons_obj quoted_8ec3de55083225c5 = CONS_OBJ_INIT(MAKE_FIXNUM(1),(struct obj *)(&(quoted_8ec3de55083225c5_1)));
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_data),"scm_data","quoted.scm",2},{(void *)(scm_lambda_16),"scm_lambda_16","quoted.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","quoted.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","quoted.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","quoted.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","quoted.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","quoted.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","quoted.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","quoted.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","quoted.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","quoted.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","quoted.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","quoted.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","quoted.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","quoted.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","quoted.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","quoted.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","quoted.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","quoted.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","quoted.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","quoted.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","quoted.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","quoted.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","quoted.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","quoted.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","quoted.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","quoted.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","quoted.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","quoted.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","quoted.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","quoted.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","quoted.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","quoted.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","quoted.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","quoted.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","quoted.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","quoted.scm",1},{(void *)(scm_count_lambda_4),"scm_count_lambda_4","quoted.scm",8},{(void *)(scm_count_lambda_3),"scm_count_lambda_3","quoted.scm",8},{(void *)(scm_count_lambda_2),"scm_count_lambda_2","quoted.scm",8},{(void *)(scm_count_lambda_1),"scm_count_lambda_1","quoted.scm",8},{(void *)(scm_count),"scm_count","quoted.scm",8},{(void *)(scm_lambda_58),"scm_lambda_58","quoted.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","quoted.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","quoted.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","quoted.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","quoted.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","quoted.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","quoted.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","quoted.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","quoted.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","quoted.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","quoted.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","quoted.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","quoted.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","quoted.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","quoted.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","quoted.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","quoted.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","quoted.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","quoted.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","quoted.scm",1},{(void *)(scm_lambda_5_inlined),"scm_lambda_5_inlined","quoted.scm",1},{NULL}};
+
+#line 2 "quoted.scm"
+static void scm_data(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_one(var_1,(struct obj *)(&(quoted_14c3b2238347efdb)));__builtin_unreachable();}
+static struct closure_obj scm_data_closure = CLOSURE_TWO_OBJ_INIT(scm_data);
+
+#line 1 "quoted.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_12,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_2)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_9,var_2);call_closure_two(((struct cell_obj *)((env)->v_data))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_13,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_10_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_10);
+
+#line 1 "quoted.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_48,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_37,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_33,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_24,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_20,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_2)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_17,var_2);call_closure_two(((struct cell_obj *)((env)->v_data))->val,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_21,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_18_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_18);
+
+#line 1 "quoted.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));call_closure_two((env)->v_rv_15,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_43_env *)(&((var_2)->env)))->v_rv_15)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_43,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_34,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,vector_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,list_to_vector_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,(struct obj *)(&(quoted_8ec3de55083225c5)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_22_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_22);
+
+#line 1 "quoted.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_30,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_26_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_26);
+
+#line 1 "quoted.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));call_closure_two((env)->v_rv_21,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_60_env *)(&((var_2)->env)))->v_rv_21)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_60,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_59,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_57,var_1);call_closure_two((env)->v_rv_27,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_55_env *)(&((var_1)->env)))->v_rv_27)=((env)->v_rv_27);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_55,var_1);call_closure_two((env)->v_rv_26,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_52_env *)(&((var_1)->env)))->v_rv_27)=((env)->v_rv_27);(((struct scm_lambda_52_env *)(&((var_1)->env)))->v_rv_26)=((env)->v_rv_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_52,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_rv_27)=((env)->v_rv_27);(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_49,var_2);call_closure_two(var_1,MAKE_FIXNUM(2),var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_44,var_2);call_closure_two(var_1,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_35(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,equal_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_39,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_l))->val,var_4);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_40(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_45,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_40_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_40);
+
+#line 8 "quoted.scm"
+static void scm_count_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_count_lambda_4_env *env = (struct scm_count_lambda_4_env *)(&((env_in)->env));call_closure_two((env)->v_rv_31,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 8 "quoted.scm"
+static void scm_count_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_count_lambda_3_env *env = (struct scm_count_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_lambda_4_env );(((struct scm_count_lambda_4_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_lambda_4_env *)(&((var_1)->env)))->v_rv_31)=((env)->v_rv_31);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_lambda_4,var_1);struct obj *var_3 = ((struct cell_obj *)((env)->v_count))->val;struct obj *var_4 = var_0;struct obj *var_5 = var_2;if (stack_check()){scm_count(var_4,var_5,((struct closure_obj *)(var_3))->env);} else {}call_closure_two(var_3,var_4,var_5);__builtin_unreachable();}
+
+#line 8 "quoted.scm"
+static void scm_count_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_count_lambda_2_env *env = (struct scm_count_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_lambda_3_env );(((struct scm_count_lambda_3_env *)(&((var_2)->env)))->v_count)=((env)->v_count);(((struct scm_count_lambda_3_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_lambda_3_env *)(&((var_2)->env)))->v_rv_31)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_count_lambda_3,var_2);call_closure_two(var_1,(env)->v_xs,var_3);__builtin_unreachable();}
+
+#line 8 "quoted.scm"
+static void scm_count_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_count_lambda_1_env *env = (struct scm_count_lambda_1_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_10,MAKE_FIXNUM(0));} else {OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_lambda_2_env );(((struct scm_count_lambda_2_env *)(&((var_2)->env)))->v_count)=((env)->v_count);(((struct scm_count_lambda_2_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_count_lambda_2_env *)(&((var_2)->env)))->v_xs)=((env)->v_xs);OBJECT_CLOSURE_ONE_NEW(var_3,scm_count_lambda_2,var_2);call_closure_two(var_1,MAKE_FIXNUM(1),var_3);}0;__builtin_unreachable();}
+
+#line 8 "quoted.scm"
+static void scm_count(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_env *env = (struct scm_count_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_null_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_lambda_1_env );(((struct scm_count_lambda_1_env *)(&((var_3)->env)))->v_count)=((env)->v_count);(((struct scm_count_lambda_1_env *)(&((var_3)->env)))->v_k_10)=(var_1);(((struct scm_count_lambda_1_env *)(&((var_3)->env)))->v_xs)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_count_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));call_closure_two((env)->v_rv_34,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_58_env *)(&((var_2)->env)))->v_rv_34)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_58,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_56,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_50(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_2)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_53,var_2);call_closure_two(((struct cell_obj *)((env)->v_count))->val,(struct obj *)(&(quoted_6420d13c8f25c16f)),var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_54,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_env );(((struct scm_count_env *)(&((var_1)->env)))->v_count)=((env)->v_count);OBJECT_CLOSURE_TWO_NEW(var_2,scm_count,var_1);(((struct cell_obj *)((env)->v_count))->val)=(var_2);GC_WRITE_BARRIER((env)->v_count,((struct cell_obj *)((env)->v_count))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_3)->env)))->v_count)=((env)->v_count);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_50,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_5)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_51,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_count)=((env)->v_count);(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_41,var_1);call_closure_two((struct obj *)(&(scm_lambda_40_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_l))->val)=((struct obj *)(&(quoted_7b1824f3753c6df8)));GC_WRITE_BARRIER((env)->v_l,((struct cell_obj *)((env)->v_l))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_35,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_count)=((env)->v_count);(((struct scm_lambda_36_env *)(&((var_3)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_36,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_count)=((env)->v_count);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_27,var_1);call_closure_two((struct obj *)(&(scm_lambda_26_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_count)=((env)->v_count);(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_23,var_1);call_closure_two((struct obj *)(&(scm_lambda_22_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_count)=((env)->v_count);(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);(((struct scm_lambda_19_env *)(&((var_1)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_19,var_1);call_closure_two((struct obj *)(&(scm_lambda_18_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_data)=(((struct scm_lambda_6_env *)(&(((struct env_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_data);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_14,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_count)=(((struct scm_lambda_6_env *)(&(((struct env_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_count);(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_12)=(((struct scm_lambda_6_env *)(&(((struct env_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_12);(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_l)=(((struct scm_lambda_6_env *)(&(((struct env_obj *)(((struct scm_lambda_8_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_l);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two((struct obj *)(&(scm_lambda_10_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_count)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_12)=(var_1);GC_WRITE_BARRIER(env_in,var_1);(((struct cell_obj *)((env)->v_data))->val)=((struct obj *)(&(scm_data_closure)));GC_WRITE_BARRIER((env)->v_data,((struct cell_obj *)((env)->v_data))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_3)->env)))->v_data)=((env)->v_data);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_7,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_5)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_8,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_l)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_data)=((env)->v_data);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_l)=((env)->v_l);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_two(var_4,NULL,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_data)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_data)=((env)->v_data);(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_5_inlined_env );(((struct scm_lambda_5_inlined_env *)(&((var_5)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_5_inlined,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "quoted.scm"
+static void scm_lambda_5_inlined(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_inlined_env *env = (struct scm_lambda_5_inlined_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "quoted.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 373 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,498 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_make_point_lambda_7_env {struct obj *v_k;struct obj *v__u7cy;};
+static void scm_make_point_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_point_lambda_6_env {struct obj *v_k;struct obj *v__u7cy;};
+static void scm_make_point_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_point_lambda_5_env {struct obj *v_k;struct obj *v__u7cy;};
+static void scm_make_point_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_point_lambda_4_env {struct obj *v_k;struct obj *v__u7cx;struct obj *v__u7cy;};
+static void scm_make_point_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_point_lambda_3_env {struct obj *v_k;struct obj *v__u7cx;struct obj *v__u7cy;};
+static void scm_make_point_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_point_lambda_2_env {struct obj *v_k;struct obj *v__u7cx;struct obj *v__u7cy;};
+static void scm_make_point_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_point_lambda_1_env {struct obj *v_point;struct obj *v__u7cx;};
+static void scm_make_point_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_point_env {struct obj *v_point;};
+static void scm_make_point(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_p_lambda_1_env {struct obj *v_k_3;struct obj *v__u7cobj;};
+static void scm_point_p_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_p_env {struct obj *v_point;};
+static void scm_point_p(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_x_lambda_4_env {struct obj *v_k_4;};
+static void scm_point_x_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_x_lambda_3_env {struct obj *v_k_4;};
+static void scm_point_x_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_x_lambda_2_env {struct obj *v_k_4;struct obj *v__u7crecord;};
+static void scm_point_x_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_x_lambda_1_env {struct obj *v_k_4;struct obj *v__u7crecord;};
+static void scm_point_x_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_x_env {struct obj *v_point;};
+static void scm_point_x(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_set_point_x_x_lambda_6_env {struct obj *v_k_5;struct obj *v__u7cvalue;};
+static void scm_set_point_x_x_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_set_point_x_x_lambda_5_env {struct obj *v_k_5;struct obj *v__u7cvalue;};
+static void scm_set_point_x_x_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_set_point_x_x_lambda_4_env {struct obj *v_k_5;struct obj *v__u7cvalue;};
+static void scm_set_point_x_x_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_set_point_x_x_lambda_3_env {struct obj *v_k_5;struct obj *v__u7crecord_2;struct obj *v__u7cvalue;};
+static void scm_set_point_x_x_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_set_point_x_x_lambda_2_env {struct obj *v_k_5;struct obj *v__u7crecord_2;struct obj *v__u7cvalue;};
+static void scm_set_point_x_x_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_set_point_x_x_lambda_1_env {struct obj *v_point;struct obj *v__u7crecord_2;};
+static void scm_set_point_x_x_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_set_point_x_x_env {struct obj *v_point;};
+static void scm_set_point_x_x(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_y_lambda_4_env {struct obj *v_k_7;};
+static void scm_point_y_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_y_lambda_3_env {struct obj *v_k_7;};
+static void scm_point_y_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_y_lambda_2_env {struct obj *v_k_7;struct obj *v__u7crecord_3;};
+static void scm_point_y_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_y_lambda_1_env {struct obj *v_k_7;struct obj *v__u7crecord_3;};
+static void scm_point_y_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_point_y_env {struct obj *v_point;};
+static void scm_point_y(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_8;struct obj *v_p;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_8;struct obj *v_p;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_make_point;struct obj *v_p;};
+static void scm_lambda_27(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_9;struct obj *v_rv_24;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_9;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_9;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_p;struct obj *v_point_x;};
+static void scm_lambda_30(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_10;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_34(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_34_closure;
+struct scm_lambda_48_env {struct obj *v_k_11;struct obj *v_rv_28;};
+static void scm_lambda_48(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_11;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_11;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_p;struct obj *v_point_y;};
+static void scm_lambda_38(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_45_env {struct obj *v_k_12;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_42(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_42_closure;
+struct scm_lambda_56_env {struct obj *v_k_13;struct obj *v_rv_32;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_52_env {struct obj *v_k_13;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_13;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_p;struct obj *v_point_p;};
+static void scm_lambda_46(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_k_14;};
+static void scm_lambda_53(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_50(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_50_closure;
+struct scm_lambda_64_env {struct obj *v_k_15;struct obj *v_rv_36;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_15;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_57_env {struct obj *v_k_15;};
+static void scm_lambda_57(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_point_p;};
+static void scm_lambda_54(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_k_16;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_58(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_58_closure;
+struct scm_lambda_65_env {struct obj *v_k_17;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_p;struct obj *v_set_point_x_x;};
+static void scm_lambda_62(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_74_env {struct obj *v_k_18;struct obj *v_rv_41;};
+static void scm_lambda_74(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_k_18;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_k_18;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_18;struct obj *v_rv_44;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_70_env {struct obj *v_k_18;struct obj *v_p;struct obj *v_point_y;};
+static void scm_lambda_70(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_18;struct obj *v_p;struct obj *v_point_y;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_p;struct obj *v_point_x;struct obj *v_point_y;};
+static void scm_lambda_66(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_19;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_67_env {struct obj *v_k_19;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_19;struct obj *v_p;struct obj *v_point_x;struct obj *v_point_y;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *parent;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_55_env {struct obj *parent;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *parent;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_19;struct obj *v_p;struct obj *v_point_x;struct obj *v_point_y;struct obj *v_point_p;struct obj *v_set_point_x_x;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *parent;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *parent;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *parent;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_31_env {struct obj *v_k_19;struct obj *v_p;struct obj *v_point_x;struct obj *v_point_y;struct obj *v_point_p;struct obj *v_set_point_x_x;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *parent;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *parent;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *parent;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_k_19;struct obj *v_make_point;struct obj *v_p;struct obj *v_point;struct obj *v_point_x;struct obj *v_point_y;struct obj *v_point_p;struct obj *v_set_point_x_x;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *v_make_point;struct obj *v_point;struct obj *v_point_x;struct obj *v_point_y;struct obj *v_point_p;struct obj *v_set_point_x_x;};
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_make_point;struct obj *v_point;struct obj *v_point_x;struct obj *v_point_p;struct obj *v_set_point_x_x;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_make_point;struct obj *v_point;struct obj *v_point_x;struct obj *v_point_p;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_make_point;struct obj *v_point;struct obj *v_point_p;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_make_point;struct obj *v_point;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_point;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_26;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_26;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_26;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_26;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_26;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_26;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj symbol_point = SYMBOL_OBJ_INIT("point");
+static struct symbol_obj symbol_point_2d_x = SYMBOL_OBJ_INIT("point-x");
+static struct symbol_obj symbol_point_2d_y = SYMBOL_OBJ_INIT("point-y");
+static struct symbol_obj symbol_set_2d_point_2d_x_21_ = SYMBOL_OBJ_INIT("set-point-x!");
+static struct symbol_obj *scheme_symbols[] = {&(symbol_point),&(symbol_point_2d_x),&(symbol_point_2d_y),&(symbol_set_2d_point_2d_x_21_),NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_make_point_lambda_7),"scm_make_point_lambda_7","records.scm",1},{(void *)(scm_make_point_lambda_6),"scm_make_point_lambda_6","records.scm",1},{(void *)(scm_make_point_lambda_5),"scm_make_point_lambda_5","records.scm",1},{(void *)(scm_make_point_lambda_4),"scm_make_point_lambda_4","records.scm",1},{(void *)(scm_make_point_lambda_3),"scm_make_point_lambda_3","records.scm",1},{(void *)(scm_make_point_lambda_2),"scm_make_point_lambda_2","records.scm",1},{(void *)(scm_make_point_lambda_1),"scm_make_point_lambda_1","records.scm",1},{(void *)(scm_make_point),"scm_make_point","records.scm",1},{(void *)(scm_point_p_lambda_1),"scm_point_p_lambda_1","records.scm",1},{(void *)(scm_point_p),"scm_point_p","records.scm",1},{(void *)(scm_point_x_lambda_4),"scm_point_x_lambda_4","records.scm",1},{(void *)(scm_point_x_lambda_3),"scm_point_x_lambda_3","records.scm",1},{(void *)(scm_point_x_lambda_2),"scm_point_x_lambda_2","records.scm",1},{(void *)(scm_point_x_lambda_1),"scm_point_x_lambda_1","records.scm",1},{(void *)(scm_point_x),"scm_point_x","records.scm",1},{(void *)(scm_set_point_x_x_lambda_6),"scm_set_point_x_x_lambda_6","records.scm",1},{(void *)(scm_set_point_x_x_lambda_5),"scm_set_point_x_x_lambda_5","records.scm",1},{(void *)(scm_set_point_x_x_lambda_4),"scm_set_point_x_x_lambda_4","records.scm",1},{(void *)(scm_set_point_x_x_lambda_3),"scm_set_point_x_x_lambda_3","records.scm",1},{(void *)(scm_set_point_x_x_lambda_2),"scm_set_point_x_x_lambda_2","records.scm",1},{(void *)(scm_set_point_x_x_lambda_1),"scm_set_point_x_x_lambda_1","records.scm",1},{(void *)(scm_set_point_x_x),"scm_set_point_x_x","records.scm",1},{(void *)(scm_point_y_lambda_4),"scm_point_y_lambda_4","records.scm",1},{(void *)(scm_point_y_lambda_3),"scm_point_y_lambda_3","records.scm",1},{(void *)(scm_point_y_lambda_2),"scm_point_y_lambda_2","records.scm",1},{(void *)(scm_point_y_lambda_1),"scm_point_y_lambda_1","records.scm",1},{(void *)(scm_point_y),"scm_point_y","records.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","records.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","records.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","records.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","records.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","records.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","records.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","records.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","records.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","records.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","records.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","records.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","records.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","records.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","records.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","records.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","records.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","records.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","records.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","records.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","records.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","records.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","records.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","records.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","records.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","records.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","records.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","records.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","records.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","records.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","records.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","records.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","records.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","records.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","records.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","records.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","records.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","records.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","records.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","records.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","records.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","records.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","records.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","records.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","records.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","records.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","records.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","records.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","records.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","records.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","records.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","records.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","records.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","records.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","records.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","records.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","records.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","records.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","records.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","records.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","records.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","records.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","records.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","records.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","records.scm",1},{NULL}};
+
+#line 1 "records.scm"
+static void scm_make_point_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_make_point_lambda_7_env *env = (struct scm_make_point_lambda_7_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v__u7cy,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_make_point_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_make_point_lambda_6_env *env = (struct scm_make_point_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_make_point_lambda_7_env );(((struct scm_make_point_lambda_7_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_make_point_lambda_7_env *)(&((var_1)->env)))->v__u7cy)=((env)->v__u7cy);OBJECT_CLOSURE_ONE_NEW(var_2,scm_make_point_lambda_7,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_make_point_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_make_point_lambda_5_env *env = (struct scm_make_point_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,record_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_make_point_lambda_6_env );(((struct scm_make_point_lambda_6_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_make_point_lambda_6_env *)(&((var_2)->env)))->v__u7cy)=((env)->v__u7cy);OBJECT_CLOSURE_ONE_NEW(var_3,scm_make_point_lambda_6,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_make_point_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_make_point_lambda_4_env *env = (struct scm_make_point_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_make_point_lambda_5_env );(((struct scm_make_point_lambda_5_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_make_point_lambda_5_env *)(&((var_1)->env)))->v__u7cy)=((env)->v__u7cy);OBJECT_CLOSURE_ONE_NEW(var_2,scm_make_point_lambda_5,var_1);call_closure_two(var_0,(env)->v__u7cx,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_make_point_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_make_point_lambda_3_env *env = (struct scm_make_point_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_make_point_lambda_4_env );(((struct scm_make_point_lambda_4_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_make_point_lambda_4_env *)(&((var_1)->env)))->v__u7cx)=((env)->v__u7cx);(((struct scm_make_point_lambda_4_env *)(&((var_1)->env)))->v__u7cy)=((env)->v__u7cy);OBJECT_CLOSURE_ONE_NEW(var_2,scm_make_point_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_make_point_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_make_point_lambda_2_env *env = (struct scm_make_point_lambda_2_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,record_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_make_point_lambda_3_env );(((struct scm_make_point_lambda_3_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_make_point_lambda_3_env *)(&((var_2)->env)))->v__u7cx)=((env)->v__u7cx);(((struct scm_make_point_lambda_3_env *)(&((var_2)->env)))->v__u7cy)=((env)->v__u7cy);OBJECT_CLOSURE_ONE_NEW(var_3,scm_make_point_lambda_3,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_make_point_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_make_point_lambda_1_env *env = (struct scm_make_point_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,make_record_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_make_point_lambda_2_env );(((struct scm_make_point_lambda_2_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_make_point_lambda_2_env *)(&((var_3)->env)))->v__u7cx)=((env)->v__u7cx);(((struct scm_make_point_lambda_2_env *)(&((var_3)->env)))->v__u7cy)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_make_point_lambda_2,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_point))->val,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_make_point(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_make_point_env *env = (struct scm_make_point_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_make_point_lambda_1_env );(((struct scm_make_point_lambda_1_env *)(&((var_2)->env)))->v_point)=((env)->v_point);(((struct scm_make_point_lambda_1_env *)(&((var_2)->env)))->v__u7cx)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_make_point_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_p_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_point_p_lambda_1_env *env = (struct scm_point_p_lambda_1_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v__u7cobj,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_p(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_point_p_env *env = (struct scm_point_p_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_record_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_point_p_lambda_1_env );(((struct scm_point_p_lambda_1_env *)(&((var_3)->env)))->v_k_3)=(var_1);(((struct scm_point_p_lambda_1_env *)(&((var_3)->env)))->v__u7cobj)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_point_p_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_point))->val,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_x_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_point_x_lambda_4_env *env = (struct scm_point_x_lambda_4_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(0),(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_x_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_point_x_lambda_3_env *env = (struct scm_point_x_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,record_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_point_x_lambda_4_env );(((struct scm_point_x_lambda_4_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_point_x_lambda_4,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_x_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_point_x_lambda_2_env *env = (struct scm_point_x_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_point_x_lambda_3_env );(((struct scm_point_x_lambda_3_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_point_x_lambda_3,var_1);call_closure_two(var_0,(env)->v__u7crecord,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_x_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_point_x_lambda_1_env *env = (struct scm_point_x_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_point_x_lambda_2_env );(((struct scm_point_x_lambda_2_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_point_x_lambda_2_env *)(&((var_1)->env)))->v__u7crecord)=((env)->v__u7crecord);OBJECT_CLOSURE_ONE_NEW(var_2,scm_point_x_lambda_2,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_point_2d_x)),var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_x(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_point_x_env *env = (struct scm_point_x_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,record_check_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_point_x_lambda_1_env );(((struct scm_point_x_lambda_1_env *)(&((var_3)->env)))->v_k_4)=(var_1);(((struct scm_point_x_lambda_1_env *)(&((var_3)->env)))->v__u7crecord)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_point_x_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_point))->val,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_set_point_x_x_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_set_point_x_x_lambda_6_env *env = (struct scm_set_point_x_x_lambda_6_env *)(&((env_in)->env));call_closure_two(var_0,(env)->v__u7cvalue,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_set_point_x_x_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_set_point_x_x_lambda_5_env *env = (struct scm_set_point_x_x_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_set_point_x_x_lambda_6_env );(((struct scm_set_point_x_x_lambda_6_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_set_point_x_x_lambda_6_env *)(&((var_1)->env)))->v__u7cvalue)=((env)->v__u7cvalue);OBJECT_CLOSURE_ONE_NEW(var_2,scm_set_point_x_x_lambda_6,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_set_point_x_x_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_set_point_x_x_lambda_4_env *env = (struct scm_set_point_x_x_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,record_set_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_set_point_x_x_lambda_5_env );(((struct scm_set_point_x_x_lambda_5_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_set_point_x_x_lambda_5_env *)(&((var_2)->env)))->v__u7cvalue)=((env)->v__u7cvalue);OBJECT_CLOSURE_ONE_NEW(var_3,scm_set_point_x_x_lambda_5,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_set_point_x_x_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_set_point_x_x_lambda_3_env *env = (struct scm_set_point_x_x_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_set_point_x_x_lambda_4_env );(((struct scm_set_point_x_x_lambda_4_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_set_point_x_x_lambda_4_env *)(&((var_1)->env)))->v__u7cvalue)=((env)->v__u7cvalue);OBJECT_CLOSURE_ONE_NEW(var_2,scm_set_point_x_x_lambda_4,var_1);call_closure_two(var_0,(env)->v__u7crecord_2,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_set_point_x_x_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_set_point_x_x_lambda_2_env *env = (struct scm_set_point_x_x_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_set_point_x_x_lambda_3_env );(((struct scm_set_point_x_x_lambda_3_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_set_point_x_x_lambda_3_env *)(&((var_1)->env)))->v__u7crecord_2)=((env)->v__u7crecord_2);(((struct scm_set_point_x_x_lambda_3_env *)(&((var_1)->env)))->v__u7cvalue)=((env)->v__u7cvalue);OBJECT_CLOSURE_ONE_NEW(var_2,scm_set_point_x_x_lambda_3,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_set_2d_point_2d_x_21_)),var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_set_point_x_x_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_set_point_x_x_lambda_1_env *env = (struct scm_set_point_x_x_lambda_1_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,record_check_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_set_point_x_x_lambda_2_env );(((struct scm_set_point_x_x_lambda_2_env *)(&((var_3)->env)))->v_k_5)=(var_1);(((struct scm_set_point_x_x_lambda_2_env *)(&((var_3)->env)))->v__u7crecord_2)=((env)->v__u7crecord_2);(((struct scm_set_point_x_x_lambda_2_env *)(&((var_3)->env)))->v__u7cvalue)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_set_point_x_x_lambda_2,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_point))->val,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_set_point_x_x(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_set_point_x_x_env *env = (struct scm_set_point_x_x_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_set_point_x_x_lambda_1_env );(((struct scm_set_point_x_x_lambda_1_env *)(&((var_2)->env)))->v_point)=((env)->v_point);(((struct scm_set_point_x_x_lambda_1_env *)(&((var_2)->env)))->v__u7crecord_2)=(var_0);OBJECT_CLOSURE_TWO_NEW(var_3,scm_set_point_x_x_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_y_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_point_y_lambda_4_env *env = (struct scm_point_y_lambda_4_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(1),(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_y_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_point_y_lambda_3_env *env = (struct scm_point_y_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,record_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_point_y_lambda_4_env );(((struct scm_point_y_lambda_4_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_point_y_lambda_4,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_y_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_point_y_lambda_2_env *env = (struct scm_point_y_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_point_y_lambda_3_env );(((struct scm_point_y_lambda_3_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_point_y_lambda_3,var_1);call_closure_two(var_0,(env)->v__u7crecord_3,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_y_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_point_y_lambda_1_env *env = (struct scm_point_y_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_point_y_lambda_2_env );(((struct scm_point_y_lambda_2_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_point_y_lambda_2_env *)(&((var_1)->env)))->v__u7crecord_3)=((env)->v__u7crecord_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_point_y_lambda_2,var_1);call_closure_two(var_0,(struct obj *)(&(symbol_point_2d_y)),var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_point_y(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_point_y_env *env = (struct scm_point_y_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,record_check_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_point_y_lambda_1_env );(((struct scm_point_y_lambda_1_env *)(&((var_3)->env)))->v_k_7)=(var_1);(((struct scm_point_y_lambda_1_env *)(&((var_3)->env)))->v__u7crecord_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_point_y_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_point))->val,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_p))->val)=(var_0);GC_WRITE_BARRIER((env)->v_p,((struct cell_obj *)((env)->v_p))->val);call_closure_one((env)->v_k_8,NULL);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_32,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_27(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_8)=(var_1);(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_p)=((env)->v_p);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(((struct cell_obj *)((env)->v_make_point))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));call_closure_two((env)->v_rv_24,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_rv_24)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_40,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_36,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_30(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_33,var_2);call_closure_two(((struct cell_obj *)((env)->v_point_x))->val,((struct cell_obj *)((env)->v_p))->val,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_34(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_37,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_34_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_34);
+
+#line 1 "records.scm"
+static void scm_lambda_48(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_48_env *env = (struct scm_lambda_48_env *)(&((env_in)->env));call_closure_two((env)->v_rv_28,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_48_env );(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_48_env *)(&((var_2)->env)))->v_rv_28)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_48,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_44,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_38(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_41,var_2);call_closure_two(((struct cell_obj *)((env)->v_point_y))->val,((struct cell_obj *)((env)->v_p))->val,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_42(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_45,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_42_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_42);
+
+#line 1 "records.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));call_closure_two((env)->v_rv_32,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_56_env *)(&((var_2)->env)))->v_rv_32)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_56,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_52,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_46(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_2)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_49,var_2);call_closure_two(((struct cell_obj *)((env)->v_point_p))->val,((struct cell_obj *)((env)->v_p))->val,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_53(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_50(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_53,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_50_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_50);
+
+#line 1 "records.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));call_closure_two((env)->v_rv_36,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_64_env *)(&((var_2)->env)))->v_rv_36)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_64,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_57(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_57_env *env = (struct scm_lambda_57_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_60,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_54(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_57_env );(((struct scm_lambda_57_env *)(&((var_2)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_57,var_2);call_closure_two(((struct cell_obj *)((env)->v_point_p))->val,MAKE_FIXNUM(5),var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_58(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_61,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_58_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_58);
+
+#line 1 "records.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));call_closure_two(var_0,MAKE_FIXNUM(10),(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_62(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_65,var_2);call_closure_two(((struct cell_obj *)((env)->v_set_point_x_x))->val,((struct cell_obj *)((env)->v_p))->val,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_74(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_74_env *env = (struct scm_lambda_74_env *)(&((env_in)->env));call_closure_two((env)->v_rv_41,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_74_env );(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_74_env *)(&((var_2)->env)))->v_rv_41)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_74,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_73,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_72,var_1);call_closure_two((env)->v_rv_44,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_70(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_70_env *env = (struct scm_lambda_70_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_rv_44)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_71,var_1);call_closure_two(((struct cell_obj *)((env)->v_point_y))->val,((struct cell_obj *)((env)->v_p))->val,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_70_env );(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_k_18)=((env)->v_k_18);(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_70_env *)(&((var_2)->env)))->v_point_y)=((env)->v_point_y);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_70,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_66(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_18)=(var_1);(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_point_y)=((env)->v_point_y);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(((struct cell_obj *)((env)->v_point_x))->val,((struct cell_obj *)((env)->v_p))->val,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_69,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_p)=((env)->v_p);(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_point_x)=((env)->v_point_x);(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_point_y)=((env)->v_point_y);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_66,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_3)->env)))->v_k_19)=((env)->v_k_19);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_67,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_47_env *)(&(((struct env_obj *)(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_62_env *)(&((var_1)->env)))->v_set_point_x_x)=(((struct scm_lambda_47_env *)(&(((struct env_obj *)(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_set_point_x_x);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_62,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_k_19)=(((struct scm_lambda_47_env *)(&(((struct env_obj *)(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_47_env *)(&(((struct env_obj *)(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_point_x)=(((struct scm_lambda_47_env *)(&(((struct env_obj *)(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_x);(((struct scm_lambda_63_env *)(&((var_3)->env)))->v_point_y)=(((struct scm_lambda_47_env *)(&(((struct env_obj *)(((struct scm_lambda_51_env *)(&(((struct env_obj *)(((struct scm_lambda_55_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_y);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_63,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_59,var_1);call_closure_two((struct obj *)(&(scm_lambda_58_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_1)->env)))->v_point_p)=(((struct scm_lambda_47_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_point_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_54,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_55,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_51,var_1);call_closure_two((struct obj *)(&(scm_lambda_50_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_46_env *)(&((var_1)->env)))->v_point_p)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_46,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_k_19)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_point_x)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_x);(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_point_y)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_y);(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_point_p)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_p);(((struct scm_lambda_47_env *)(&((var_3)->env)))->v_set_point_x_x)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)(((struct scm_lambda_35_env *)(&(((struct env_obj *)(((struct scm_lambda_39_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_set_point_x_x);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_47,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_43,var_1);call_closure_two((struct obj *)(&(scm_lambda_42_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_p);(((struct scm_lambda_38_env *)(&((var_1)->env)))->v_point_y)=(((struct scm_lambda_31_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_point_y);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_38,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_39,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_35,var_1);call_closure_two((struct obj *)(&(scm_lambda_34_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_p)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_point_x)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_x);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_30,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_k_19)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_19);(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_p)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_p);(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_point_x)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_x);(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_point_y)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_y);(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_point_p)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_point_p);(((struct scm_lambda_31_env *)(&((var_3)->env)))->v_set_point_x_x)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)(((struct scm_lambda_16_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_set_point_x_x);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_31,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point,((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_make_point_env );(((struct scm_make_point_env *)(&((var_1)->env)))->v_point)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point);OBJECT_CLOSURE_TWO_NEW(var_2,scm_make_point,var_1);(((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_make_point))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_make_point,((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_make_point))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_point_p_env );(((struct scm_point_p_env *)(&((var_3)->env)))->v_point)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point);OBJECT_CLOSURE_TWO_NEW(var_4,scm_point_p,var_3);(((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_p))->val)=(var_4);GC_WRITE_BARRIER(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_p,((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_p))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_point_x_env );(((struct scm_point_x_env *)(&((var_5)->env)))->v_point)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point);OBJECT_CLOSURE_TWO_NEW(var_6,scm_point_x,var_5);(((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_x))->val)=(var_6);GC_WRITE_BARRIER(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_x,((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_x))->val);OBJECT_ENV_OBJ_NEW(var_7,struct scm_set_point_x_x_env );(((struct scm_set_point_x_x_env *)(&((var_7)->env)))->v_point)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point);OBJECT_CLOSURE_TWO_NEW(var_8,scm_set_point_x_x,var_7);(((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_set_point_x_x))->val)=(var_8);GC_WRITE_BARRIER(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_set_point_x_x,((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_set_point_x_x))->val);OBJECT_ENV_OBJ_NEW(var_9,struct scm_point_y_env );(((struct scm_point_y_env *)(&((var_9)->env)))->v_point)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point);OBJECT_CLOSURE_TWO_NEW(var_10,scm_point_y,var_9);(((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_y))->val)=(var_10);GC_WRITE_BARRIER(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_y,((struct cell_obj *)(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_point_y))->val);OBJECT_ENV_OBJ_NEW(var_11,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_11)->env)))->v_make_point)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_make_point);(((struct scm_lambda_27_env *)(&((var_11)->env)))->v_p)=(((struct scm_lambda_14_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_p);OBJECT_CLOSURE_TWO_NEW(var_12,scm_lambda_27,var_11);OBJECT_ENV_OBJ_NEW(var_13,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_13)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_14,scm_lambda_28,var_13);call_closure_two(var_12,NULL,var_14);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_16,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_p)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_19)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_3,make_record_type_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_4)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_15,var_4);call_closure_two(var_3,(struct obj *)(&(symbol_point)),var_5);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_point_y)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_make_point)=((env)->v_make_point);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_point)=((env)->v_point);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_point_x)=((env)->v_point_x);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_point_y)=((env)->v_point_y);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_point_p)=((env)->v_point_p);(((struct scm_lambda_14_env *)(&((var_3)->env)))->v_set_point_x_x)=((env)->v_set_point_x_x);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_14,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_set_point_x_x)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_make_point)=((env)->v_make_point);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_point)=((env)->v_point);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_point_x)=((env)->v_point_x);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_point_p)=((env)->v_point_p);(((struct scm_lambda_12_env *)(&((var_3)->env)))->v_set_point_x_x)=((env)->v_set_point_x_x);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_12,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_point_x)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_make_point)=((env)->v_make_point);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_point)=((env)->v_point);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_point_x)=((env)->v_point_x);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_point_p)=((env)->v_point_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_point_p)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_make_point)=((env)->v_make_point);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_point)=((env)->v_point);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_point_p)=((env)->v_point_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_make_point)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_make_point)=((env)->v_make_point);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_point)=((env)->v_point);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_point)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_point)=((env)->v_point);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "records.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "records.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 479 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,1083 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_lambda_12_env {struct obj *v_k;struct obj *v_rv_2;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_k;};
+static void scm_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_s;};
+static void scm_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_2;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_6_closure;
+struct scm_lambda_16_env {struct obj *v_k_3;struct obj *v_rv_6;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_3;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_10_closure;
+struct scm_lambda_17_env {struct obj *v_k_4;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_14_closure;
+struct scm_lambda_31_env {struct obj *v_k_5;struct obj *v_rv_9;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_5;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_5;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_5;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_s;};
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_6;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_21(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_21_closure;
+struct scm_lambda_45_env {struct obj *v_k_7;struct obj *v_rv_14;};
+static void scm_lambda_45(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_7;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_7;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_7;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_7;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_s;};
+static void scm_lambda_25(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_8;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_29(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_29_closure;
+struct scm_lambda_55_env {struct obj *v_k_9;struct obj *v_rv_20;};
+static void scm_lambda_55(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_50_env {struct obj *v_k_9;};
+static void scm_lambda_50(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *v_k_9;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_9;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_9;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_34_env {struct obj *v_s;};
+static void scm_lambda_34(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_k_10;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_38(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_38_closure;
+struct scm_lambda_51_env {struct obj *v_k_11;struct obj *v_rv_26;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_47_env {struct obj *v_k_11;};
+static void scm_lambda_47(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_43(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_43_closure;
+struct scm_lambda_52_env {struct obj *v_k_12;};
+static void scm_lambda_52(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_48(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_48_closure;
+struct scm_lambda_67_env {struct obj *v_k_13;struct obj *v_rv_29;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_13;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_13;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_13;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_53_env {struct obj *v_s;};
+static void scm_lambda_53(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_14;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_57(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_57_closure;
+struct scm_lambda_76_env {struct obj *v_k_15;struct obj *v_rv_34;};
+static void scm_lambda_76(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_72_env {struct obj *v_k_15;};
+static void scm_lambda_72(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_15;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_15;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_61_env {struct obj *v_s;};
+static void scm_lambda_61(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_16;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_65(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_65_closure;
+struct scm_lambda_77_env {struct obj *v_k_17;struct obj *v_rv_39;};
+static void scm_lambda_77(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_73_env {struct obj *v_k_17;};
+static void scm_lambda_73(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_70(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_70_closure;
+struct scm_lambda_78_env {struct obj *v_k_18;};
+static void scm_lambda_78(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_74(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_74_closure;
+struct scm_lambda_84_env {struct obj *v_k_19;struct obj *v_rv_42;};
+static void scm_lambda_84(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_81_env {struct obj *v_k_19;};
+static void scm_lambda_81(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_79(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_79_closure;
+struct scm_lambda_85_env {struct obj *v_k_20;};
+static void scm_lambda_85(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_82(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_82_closure;
+struct scm_lambda_91_env {struct obj *v_k_21;struct obj *v_rv_45;};
+static void scm_lambda_91(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_88_env {struct obj *v_k_21;};
+static void scm_lambda_88(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_86(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_86_closure;
+struct scm_lambda_92_env {struct obj *v_k_22;};
+static void scm_lambda_92(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_89(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_89_closure;
+struct scm_lambda_98_env {struct obj *v_k_23;struct obj *v_rv_48;};
+static void scm_lambda_98(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_95_env {struct obj *v_k_23;};
+static void scm_lambda_95(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_93(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_93_closure;
+struct scm_lambda_99_env {struct obj *v_k_24;};
+static void scm_lambda_99(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_96(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_96_closure;
+struct scm_lambda_105_env {struct obj *v_k_25;struct obj *v_rv_51;};
+static void scm_lambda_105(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_102_env {struct obj *v_k_25;};
+static void scm_lambda_102(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_100(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_100_closure;
+struct scm_lambda_106_env {struct obj *v_k_26;};
+static void scm_lambda_106(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_103(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_103_closure;
+struct scm_lambda_112_env {struct obj *v_k_27;struct obj *v_rv_54;};
+static void scm_lambda_112(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_109_env {struct obj *v_k_27;};
+static void scm_lambda_109(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_107(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_107_closure;
+struct scm_lambda_113_env {struct obj *v_k_28;};
+static void scm_lambda_113(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_110(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_110_closure;
+struct scm_lambda_119_env {struct obj *v_k_29;struct obj *v_rv_57;};
+static void scm_lambda_119(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_116_env {struct obj *v_k_29;};
+static void scm_lambda_116(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_114(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_114_closure;
+struct scm_lambda_120_env {struct obj *v_k_30;};
+static void scm_lambda_120(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_117(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_117_closure;
+struct scm_lambda_126_env {struct obj *v_k_31;struct obj *v_rv_60;};
+static void scm_lambda_126(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_123_env {struct obj *v_k_31;};
+static void scm_lambda_123(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_121(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_121_closure;
+struct scm_lambda_127_env {struct obj *v_k_32;};
+static void scm_lambda_127(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_124(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_124_closure;
+struct scm_lambda_133_env {struct obj *v_k_33;struct obj *v_rv_63;};
+static void scm_lambda_133(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_130_env {struct obj *v_k_33;};
+static void scm_lambda_130(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_128(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_128_closure;
+struct scm_lambda_134_env {struct obj *v_k_34;};
+static void scm_lambda_134(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_131(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_131_closure;
+struct scm_lambda_140_env {struct obj *v_k_35;struct obj *v_rv_66;};
+static void scm_lambda_140(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_137_env {struct obj *v_k_35;};
+static void scm_lambda_137(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_135(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_135_closure;
+struct scm_lambda_141_env {struct obj *v_k_36;};
+static void scm_lambda_141(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_138(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_138_closure;
+struct scm_lambda_155_env {struct obj *v_k_37;struct obj *v_rv_69;};
+static void scm_lambda_155(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_151_env {struct obj *v_k_37;};
+static void scm_lambda_151(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_147_env {struct obj *v_k_37;};
+static void scm_lambda_147(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_144_env {struct obj *v_k_37;};
+static void scm_lambda_144(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_142(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_142_closure;
+struct scm_lambda_148_env {struct obj *v_k_38;};
+static void scm_lambda_148(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_145(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_145_closure;
+struct scm_lambda_169_env {struct obj *v_k_39;struct obj *v_rv_74;};
+static void scm_lambda_169(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_164_env {struct obj *v_k_39;};
+static void scm_lambda_164(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_160_env {struct obj *v_k_39;};
+static void scm_lambda_160(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_156_env {struct obj *v_k_39;};
+static void scm_lambda_156(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_152_env {struct obj *v_k_39;};
+static void scm_lambda_152(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_149(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_149_closure;
+struct scm_lambda_157_env {struct obj *v_k_40;};
+static void scm_lambda_157(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_153(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_153_closure;
+struct scm_lambda_165_env {struct obj *v_k_41;struct obj *v_rv_80;};
+static void scm_lambda_165(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_161_env {struct obj *v_k_41;};
+static void scm_lambda_161(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_158(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_158_closure;
+struct scm_lambda_166_env {struct obj *v_k_42;};
+static void scm_lambda_166(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_162(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_162_closure;
+struct scm_lambda_173_env {struct obj *v_k_43;struct obj *v_rv_83;};
+static void scm_lambda_173(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_170_env {struct obj *v_k_43;};
+static void scm_lambda_170(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_167(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_167_closure;
+struct scm_lambda_174_env {struct obj *v_k_44;};
+static void scm_lambda_174(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_171(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_171_closure;
+struct scm_lambda_180_env {struct obj *v_k_45;struct obj *v_rv_86;};
+static void scm_lambda_180(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_177_env {struct obj *v_k_45;};
+static void scm_lambda_177(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_175(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_175_closure;
+struct scm_lambda_181_env {struct obj *v_k_46;};
+static void scm_lambda_181(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_178(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_178_closure;
+struct scm_lambda_201_env {struct obj *v_k_47;struct obj *v_rv_89;};
+static void scm_lambda_201(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_200_env {struct obj *v_k_47;};
+static void scm_lambda_200(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_199_env {struct obj *v_k_47;};
+static void scm_lambda_199(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_198_env {struct obj *v_k_47;struct obj *v_rv_97;};
+static void scm_lambda_198(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_197_env {struct obj *v_k_47;struct obj *v_rv_97;struct obj *v_rv_96;};
+static void scm_lambda_197(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_196_env {struct obj *parent;};
+static void scm_lambda_196(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_194_env {struct obj *parent;};
+static void scm_lambda_194(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_191_env {struct obj *v_k_47;struct obj *v_rv_97;struct obj *v_rv_96;struct obj *v_rv_95;};
+static void scm_lambda_191(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_187_env {struct obj *v_k_47;struct obj *v_rv_97;struct obj *v_rv_96;};
+static void scm_lambda_187(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_184_env {struct obj *v_k_47;struct obj *v_rv_97;};
+static void scm_lambda_184(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_182_env {struct obj *v_k_47;};
+static void scm_lambda_182(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_188_env {struct obj *v_k_48;};
+static void scm_lambda_188(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_185(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_185_closure;
+struct scm_lambda_195_env {struct obj *v_k_49;struct obj *v_rv_100;};
+static void scm_lambda_195(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_192_env {struct obj *v_k_49;};
+static void scm_lambda_192(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_189(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_189_closure;
+struct scm_lambda_193_env {struct obj *v_k_50;};
+static void scm_lambda_193(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_190_env {struct obj *v_k_50;};
+static void scm_lambda_190(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_186_env {struct obj *v_k_50;};
+static void scm_lambda_186(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_183_env {struct obj *v_k_50;};
+static void scm_lambda_183(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_179_env {struct obj *v_k_50;};
+static void scm_lambda_179(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_176_env {struct obj *v_k_50;};
+static void scm_lambda_176(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_172_env {struct obj *v_k_50;};
+static void scm_lambda_172(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_168_env {struct obj *v_k_50;};
+static void scm_lambda_168(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_163_env {struct obj *v_k_50;};
+static void scm_lambda_163(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_159_env {struct obj *v_k_50;};
+static void scm_lambda_159(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_154_env {struct obj *v_k_50;};
+static void scm_lambda_154(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_150_env {struct obj *v_k_50;};
+static void scm_lambda_150(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_146_env {struct obj *v_k_50;};
+static void scm_lambda_146(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_143_env {struct obj *v_k_50;};
+static void scm_lambda_143(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_139_env {struct obj *v_k_50;};
+static void scm_lambda_139(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_136_env {struct obj *v_k_50;};
+static void scm_lambda_136(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_132_env {struct obj *v_k_50;};
+static void scm_lambda_132(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_129_env {struct obj *v_k_50;};
+static void scm_lambda_129(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_125_env {struct obj *v_k_50;};
+static void scm_lambda_125(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_122_env {struct obj *v_k_50;};
+static void scm_lambda_122(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_118_env {struct obj *v_k_50;};
+static void scm_lambda_118(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_115_env {struct obj *v_k_50;};
+static void scm_lambda_115(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_111_env {struct obj *v_k_50;};
+static void scm_lambda_111(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_108_env {struct obj *v_k_50;};
+static void scm_lambda_108(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_104_env {struct obj *v_k_50;};
+static void scm_lambda_104(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_101_env {struct obj *v_k_50;};
+static void scm_lambda_101(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_97_env {struct obj *v_k_50;};
+static void scm_lambda_97(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_94_env {struct obj *v_k_50;};
+static void scm_lambda_94(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_90_env {struct obj *v_k_50;};
+static void scm_lambda_90(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_87_env {struct obj *v_k_50;};
+static void scm_lambda_87(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_83_env {struct obj *v_k_50;};
+static void scm_lambda_83(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_80_env {struct obj *v_k_50;};
+static void scm_lambda_80(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_75_env {struct obj *v_k_50;};
+static void scm_lambda_75(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_71_env {struct obj *v_k_50;};
+static void scm_lambda_71(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_k_50;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_62_env {struct obj *v_k_50;};
+static void scm_lambda_62(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_49_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_49(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_35(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_k_50;struct obj *v_s;};
+static void scm_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_lambda_12),"scm_lambda_12","string.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","string.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","string.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","string.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","string.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","string.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","string.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","string.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","string.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","string.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","string.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","string.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","string.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","string.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","string.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","string.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","string.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","string.scm",1},{(void *)(scm_lambda_45),"scm_lambda_45","string.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","string.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","string.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","string.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","string.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","string.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","string.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","string.scm",1},{(void *)(scm_lambda_55),"scm_lambda_55","string.scm",1},{(void *)(scm_lambda_50),"scm_lambda_50","string.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","string.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","string.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","string.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","string.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","string.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","string.scm",1},{(void *)(scm_lambda_51),"scm_lambda_51","string.scm",1},{(void *)(scm_lambda_47),"scm_lambda_47","string.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","string.scm",1},{(void *)(scm_lambda_52),"scm_lambda_52","string.scm",1},{(void *)(scm_lambda_48),"scm_lambda_48","string.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","string.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","string.scm",1},{(void *)(scm_lambda_59),"scm_lambda_59","string.scm",1},{(void *)(scm_lambda_56),"scm_lambda_56","string.scm",1},{(void *)(scm_lambda_53),"scm_lambda_53","string.scm",1},{(void *)(scm_lambda_60),"scm_lambda_60","string.scm",1},{(void *)(scm_lambda_57),"scm_lambda_57","string.scm",1},{(void *)(scm_lambda_76),"scm_lambda_76","string.scm",1},{(void *)(scm_lambda_72),"scm_lambda_72","string.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","string.scm",1},{(void *)(scm_lambda_64),"scm_lambda_64","string.scm",1},{(void *)(scm_lambda_61),"scm_lambda_61","string.scm",1},{(void *)(scm_lambda_69),"scm_lambda_69","string.scm",1},{(void *)(scm_lambda_65),"scm_lambda_65","string.scm",1},{(void *)(scm_lambda_77),"scm_lambda_77","string.scm",1},{(void *)(scm_lambda_73),"scm_lambda_73","string.scm",1},{(void *)(scm_lambda_70),"scm_lambda_70","string.scm",1},{(void *)(scm_lambda_78),"scm_lambda_78","string.scm",1},{(void *)(scm_lambda_74),"scm_lambda_74","string.scm",1},{(void *)(scm_lambda_84),"scm_lambda_84","string.scm",1},{(void *)(scm_lambda_81),"scm_lambda_81","string.scm",1},{(void *)(scm_lambda_79),"scm_lambda_79","string.scm",1},{(void *)(scm_lambda_85),"scm_lambda_85","string.scm",1},{(void *)(scm_lambda_82),"scm_lambda_82","string.scm",1},{(void *)(scm_lambda_91),"scm_lambda_91","string.scm",1},{(void *)(scm_lambda_88),"scm_lambda_88","string.scm",1},{(void *)(scm_lambda_86),"scm_lambda_86","string.scm",1},{(void *)(scm_lambda_92),"scm_lambda_92","string.scm",1},{(void *)(scm_lambda_89),"scm_lambda_89","string.scm",1},{(void *)(scm_lambda_98),"scm_lambda_98","string.scm",1},{(void *)(scm_lambda_95),"scm_lambda_95","string.scm",1},{(void *)(scm_lambda_93),"scm_lambda_93","string.scm",1},{(void *)(scm_lambda_99),"scm_lambda_99","string.scm",1},{(void *)(scm_lambda_96),"scm_lambda_96","string.scm",1},{(void *)(scm_lambda_105),"scm_lambda_105","string.scm",1},{(void *)(scm_lambda_102),"scm_lambda_102","string.scm",1},{(void *)(scm_lambda_100),"scm_lambda_100","string.scm",1},{(void *)(scm_lambda_106),"scm_lambda_106","string.scm",1},{(void *)(scm_lambda_103),"scm_lambda_103","string.scm",1},{(void *)(scm_lambda_112),"scm_lambda_112","string.scm",1},{(void *)(scm_lambda_109),"scm_lambda_109","string.scm",1},{(void *)(scm_lambda_107),"scm_lambda_107","string.scm",1},{(void *)(scm_lambda_113),"scm_lambda_113","string.scm",1},{(void *)(scm_lambda_110),"scm_lambda_110","string.scm",1},{(void *)(scm_lambda_119),"scm_lambda_119","string.scm",1},{(void *)(scm_lambda_116),"scm_lambda_116","string.scm",1},{(void *)(scm_lambda_114),"scm_lambda_114","string.scm",1},{(void *)(scm_lambda_120),"scm_lambda_120","string.scm",1},{(void *)(scm_lambda_117),"scm_lambda_117","string.scm",1},{(void *)(scm_lambda_126),"scm_lambda_126","string.scm",1},{(void *)(scm_lambda_123),"scm_lambda_123","string.scm",1},{(void *)(scm_lambda_121),"scm_lambda_121","string.scm",1},{(void *)(scm_lambda_127),"scm_lambda_127","string.scm",1},{(void *)(scm_lambda_124),"scm_lambda_124","string.scm",1},{(void *)(scm_lambda_133),"scm_lambda_133","string.scm",1},{(void *)(scm_lambda_130),"scm_lambda_130","string.scm",1},{(void *)(scm_lambda_128),"scm_lambda_128","string.scm",1},{(void *)(scm_lambda_134),"scm_lambda_134","string.scm",1},{(void *)(scm_lambda_131),"scm_lambda_131","string.scm",1},{(void *)(scm_lambda_140),"scm_lambda_140","string.scm",1},{(void *)(scm_lambda_137),"scm_lambda_137","string.scm",1},{(void *)(scm_lambda_135),"scm_lambda_135","string.scm",1},{(void *)(scm_lambda_141),"scm_lambda_141","string.scm",1},{(void *)(scm_lambda_138),"scm_lambda_138","string.scm",1},{(void *)(scm_lambda_155),"scm_lambda_155","string.scm",1},{(void *)(scm_lambda_151),"scm_lambda_151","string.scm",1},{(void *)(scm_lambda_147),"scm_lambda_147","string.scm",1},{(void *)(scm_lambda_144),"scm_lambda_144","string.scm",1},{(void *)(scm_lambda_142),"scm_lambda_142","string.scm",1},{(void *)(scm_lambda_148),"scm_lambda_148","string.scm",1},{(void *)(scm_lambda_145),"scm_lambda_145","string.scm",1},{(void *)(scm_lambda_169),"scm_lambda_169","string.scm",1},{(void *)(scm_lambda_164),"scm_lambda_164","string.scm",1},{(void *)(scm_lambda_160),"scm_lambda_160","string.scm",1},{(void *)(scm_lambda_156),"scm_lambda_156","string.scm",1},{(void *)(scm_lambda_152),"scm_lambda_152","string.scm",1},{(void *)(scm_lambda_149),"scm_lambda_149","string.scm",1},{(void *)(scm_lambda_157),"scm_lambda_157","string.scm",1},{(void *)(scm_lambda_153),"scm_lambda_153","string.scm",1},{(void *)(scm_lambda_165),"scm_lambda_165","string.scm",1},{(void *)(scm_lambda_161),"scm_lambda_161","string.scm",1},{(void *)(scm_lambda_158),"scm_lambda_158","string.scm",1},{(void *)(scm_lambda_166),"scm_lambda_166","string.scm",1},{(void *)(scm_lambda_162),"scm_lambda_162","string.scm",1},{(void *)(scm_lambda_173),"scm_lambda_173","string.scm",1},{(void *)(scm_lambda_170),"scm_lambda_170","string.scm",1},{(void *)(scm_lambda_167),"scm_lambda_167","string.scm",1},{(void *)(scm_lambda_174),"scm_lambda_174","string.scm",1},{(void *)(scm_lambda_171),"scm_lambda_171","string.scm",1},{(void *)(scm_lambda_180),"scm_lambda_180","string.scm",1},{(void *)(scm_lambda_177),"scm_lambda_177","string.scm",1},{(void *)(scm_lambda_175),"scm_lambda_175","string.scm",1},{(void *)(scm_lambda_181),"scm_lambda_181","string.scm",1},{(void *)(scm_lambda_178),"scm_lambda_178","string.scm",1},{(void *)(scm_lambda_201),"scm_lambda_201","string.scm",1},{(void *)(scm_lambda_200),"scm_lambda_200","string.scm",1},{(void *)(scm_lambda_199),"scm_lambda_199","string.scm",1},{(void *)(scm_lambda_198),"scm_lambda_198","string.scm",1},{(void *)(scm_lambda_197),"scm_lambda_197","string.scm",1},{(void *)(scm_lambda_196),"scm_lambda_196","string.scm",1},{(void *)(scm_lambda_194),"scm_lambda_194","string.scm",1},{(void *)(scm_lambda_191),"scm_lambda_191","string.scm",1},{(void *)(scm_lambda_187),"scm_lambda_187","string.scm",1},{(void *)(scm_lambda_184),"scm_lambda_184","string.scm",1},{(void *)(scm_lambda_182),"scm_lambda_182","string.scm",1},{(void *)(scm_lambda_188),"scm_lambda_188","string.scm",1},{(void *)(scm_lambda_185),"scm_lambda_185","string.scm",1},{(void *)(scm_lambda_195),"scm_lambda_195","string.scm",1},{(void *)(scm_lambda_192),"scm_lambda_192","string.scm",1},{(void *)(scm_lambda_189),"scm_lambda_189","string.scm",1},{(void *)(scm_lambda_193),"scm_lambda_193","string.scm",1},{(void *)(scm_lambda_190),"scm_lambda_190","string.scm",1},{(void *)(scm_lambda_186),"scm_lambda_186","string.scm",1},{(void *)(scm_lambda_183),"scm_lambda_183","string.scm",1},{(void *)(scm_lambda_179),"scm_lambda_179","string.scm",1},{(void *)(scm_lambda_176),"scm_lambda_176","string.scm",1},{(void *)(scm_lambda_172),"scm_lambda_172","string.scm",1},{(void *)(scm_lambda_168),"scm_lambda_168","string.scm",1},{(void *)(scm_lambda_163),"scm_lambda_163","string.scm",1},{(void *)(scm_lambda_159),"scm_lambda_159","string.scm",1},{(void *)(scm_lambda_154),"scm_lambda_154","string.scm",1},{(void *)(scm_lambda_150),"scm_lambda_150","string.scm",1},{(void *)(scm_lambda_146),"scm_lambda_146","string.scm",1},{(void *)(scm_lambda_143),"scm_lambda_143","string.scm",1},{(void *)(scm_lambda_139),"scm_lambda_139","string.scm",1},{(void *)(scm_lambda_136),"scm_lambda_136","string.scm",1},{(void *)(scm_lambda_132),"scm_lambda_132","string.scm",1},{(void *)(scm_lambda_129),"scm_lambda_129","string.scm",1},{(void *)(scm_lambda_125),"scm_lambda_125","string.scm",1},{(void *)(scm_lambda_122),"scm_lambda_122","string.scm",1},{(void *)(scm_lambda_118),"scm_lambda_118","string.scm",1},{(void *)(scm_lambda_115),"scm_lambda_115","string.scm",1},{(void *)(scm_lambda_111),"scm_lambda_111","string.scm",1},{(void *)(scm_lambda_108),"scm_lambda_108","string.scm",1},{(void *)(scm_lambda_104),"scm_lambda_104","string.scm",1},{(void *)(scm_lambda_101),"scm_lambda_101","string.scm",1},{(void *)(scm_lambda_97),"scm_lambda_97","string.scm",1},{(void *)(scm_lambda_94),"scm_lambda_94","string.scm",1},{(void *)(scm_lambda_90),"scm_lambda_90","string.scm",1},{(void *)(scm_lambda_87),"scm_lambda_87","string.scm",1},{(void *)(scm_lambda_83),"scm_lambda_83","string.scm",1},{(void *)(scm_lambda_80),"scm_lambda_80","string.scm",1},{(void *)(scm_lambda_75),"scm_lambda_75","string.scm",1},{(void *)(scm_lambda_71),"scm_lambda_71","string.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","string.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","string.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","string.scm",1},{(void *)(scm_lambda_54),"scm_lambda_54","string.scm",1},{(void *)(scm_lambda_49),"scm_lambda_49","string.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","string.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","string.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","string.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","string.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","string.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","string.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","string.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","string.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","string.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","string.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","string.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","string.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","string.scm",1},{NULL}};
+
+#line 1 "string.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_lambda_12_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_12,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_2)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_8,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,string_length_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_5,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_s))->val,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_9,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_6_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_6);
+
+#line 1 "string.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_13,var_3);call_closure_two(var_2,MAKE_FIXNUM(0),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_10_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_10);
+
+#line 1 "string.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_4)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_14_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_14);
+
+#line 1 "string.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));call_closure_two((env)->v_rv_9,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_rv_9)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_31,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_27,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_23,var_1);call_closure_two(var_0,MAKE_FIXNUM(4),var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,string_ref_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_3)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_20,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_s))->val,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_21(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_24,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_21_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_21);
+
+#line 1 "string.scm"
+static void scm_lambda_45(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_45_env *env = (struct scm_lambda_45_env *)(&((env_in)->env));call_closure_two((env)->v_rv_14,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_45_env );(((struct scm_lambda_45_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_45_env *)(&((var_2)->env)))->v_rv_14)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_45,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_40,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_36,var_1);call_closure_two(var_0,MAKE_FIXNUM(11),var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_32,var_1);call_closure_two(var_0,MAKE_FIXNUM(6),var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_25(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,substring_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_28,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_s))->val,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_29(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_3)->env)))->v_k_8)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_33,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_29_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_29);
+
+#line 1 "string.scm"
+static void scm_lambda_55(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_55_env *env = (struct scm_lambda_55_env *)(&((env_in)->env));call_closure_two((env)->v_rv_20,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_50(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_50_env *env = (struct scm_lambda_50_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_55_env );(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_55_env *)(&((var_2)->env)))->v_rv_20)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_55,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_50_env );(((struct scm_lambda_50_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_50,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_46,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_41,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_34(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,substring_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_37,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_s))->val,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_38(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_42,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_38_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_38);
+
+#line 1 "string.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));call_closure_two((env)->v_rv_26,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_47(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_47_env *env = (struct scm_lambda_47_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_51_env *)(&((var_2)->env)))->v_rv_26)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_51,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_43(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"foobar");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_47_env );(((struct scm_lambda_47_env *)(&((var_4)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_47,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_43_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_43);
+
+#line 1 "string.scm"
+static void scm_lambda_52(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_52_env *env = (struct scm_lambda_52_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_48(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_52_env );(((struct scm_lambda_52_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_52,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_48_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_48);
+
+#line 1 "string.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));call_closure_two((env)->v_rv_29,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_rv_29)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_67,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_63,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"hello world");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_59,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_53(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_53_env *env = (struct scm_lambda_53_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_string_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_3)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_56,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_s))->val,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_57(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_60,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_57_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_57);
+
+#line 1 "string.scm"
+static void scm_lambda_76(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_76_env *env = (struct scm_lambda_76_env *)(&((env_in)->env));call_closure_two((env)->v_rv_34,var_0,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_72(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_72_env *env = (struct scm_lambda_72_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_76_env );(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);(((struct scm_lambda_76_env *)(&((var_2)->env)))->v_rv_34)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_76,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_72_env );(((struct scm_lambda_72_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_72,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"hello");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_61(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,is_string_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_3)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_64,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_s))->val,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_65(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_3)->env)))->v_k_16)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_69,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_65_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_65);
+
+#line 1 "string.scm"
+static void scm_lambda_77(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_77_env *env = (struct scm_lambda_77_env *)(&((env_in)->env));call_closure_two((env)->v_rv_39,var_0,(env)->v_k_17);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_73(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_73_env *env = (struct scm_lambda_73_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_77_env );(((struct scm_lambda_77_env *)(&((var_2)->env)))->v_k_17)=((env)->v_k_17);(((struct scm_lambda_77_env *)(&((var_2)->env)))->v_rv_39)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_77,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_70(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_73_env );(((struct scm_lambda_73_env *)(&((var_3)->env)))->v_k_17)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_73,var_3);call_closure_two(var_2,MAKE_FIXNUM(42),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_70_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_70);
+
+#line 1 "string.scm"
+static void scm_lambda_78(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_78_env *env = (struct scm_lambda_78_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_18);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_74(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_78_env );(((struct scm_lambda_78_env *)(&((var_3)->env)))->v_k_18)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_78,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_74_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_74);
+
+#line 1 "string.scm"
+static void scm_lambda_84(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_84_env *env = (struct scm_lambda_84_env *)(&((env_in)->env));call_closure_two((env)->v_rv_42,var_0,(env)->v_k_19);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_81(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_81_env *env = (struct scm_lambda_81_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_84_env );(((struct scm_lambda_84_env *)(&((var_2)->env)))->v_k_19)=((env)->v_k_19);(((struct scm_lambda_84_env *)(&((var_2)->env)))->v_rv_42)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_84,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_79(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_81_env );(((struct scm_lambda_81_env *)(&((var_3)->env)))->v_k_19)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_81,var_3);call_closure_two(var_2,MAKE_FIXNUM(-17),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_79_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_79);
+
+#line 1 "string.scm"
+static void scm_lambda_85(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_85_env *env = (struct scm_lambda_85_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_20);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_82(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_85_env );(((struct scm_lambda_85_env *)(&((var_3)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_85,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_82_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_82);
+
+#line 1 "string.scm"
+static void scm_lambda_91(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_91_env *env = (struct scm_lambda_91_env *)(&((env_in)->env));call_closure_two((env)->v_rv_45,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_88(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_88_env *env = (struct scm_lambda_88_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_91_env );(((struct scm_lambda_91_env *)(&((var_2)->env)))->v_k_21)=((env)->v_k_21);(((struct scm_lambda_91_env *)(&((var_2)->env)))->v_rv_45)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_91,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_86(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_RATIONAL_OBJ_NEW(var_3,3,4);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_88_env );(((struct scm_lambda_88_env *)(&((var_4)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_88,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_86_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_86);
+
+#line 1 "string.scm"
+static void scm_lambda_92(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_92_env *env = (struct scm_lambda_92_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_89(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_92_env );(((struct scm_lambda_92_env *)(&((var_3)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_92,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_89_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_89);
+
+#line 1 "string.scm"
+static void scm_lambda_98(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_98_env *env = (struct scm_lambda_98_env *)(&((env_in)->env));call_closure_two((env)->v_rv_48,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_95(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_95_env *env = (struct scm_lambda_95_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_98_env );(((struct scm_lambda_98_env *)(&((var_2)->env)))->v_k_23)=((env)->v_k_23);(((struct scm_lambda_98_env *)(&((var_2)->env)))->v_rv_48)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_98,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_93(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,2500.0);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_95_env );(((struct scm_lambda_95_env *)(&((var_4)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_95,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_93_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_93);
+
+#line 1 "string.scm"
+static void scm_lambda_99(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_99_env *env = (struct scm_lambda_99_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_96(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_99_env );(((struct scm_lambda_99_env *)(&((var_3)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_99,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_96_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_96);
+
+#line 1 "string.scm"
+static void scm_lambda_105(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_105_env *env = (struct scm_lambda_105_env *)(&((env_in)->env));call_closure_two((env)->v_rv_51,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_102(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_102_env *env = (struct scm_lambda_102_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_105_env );(((struct scm_lambda_105_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);(((struct scm_lambda_105_env *)(&((var_2)->env)))->v_rv_51)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_105,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_100(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_FLOAT_OBJ_NEW(var_3,0.5);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_102_env );(((struct scm_lambda_102_env *)(&((var_4)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_102,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_100_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_100);
+
+#line 1 "string.scm"
+static void scm_lambda_106(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_106_env *env = (struct scm_lambda_106_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_103(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_106_env );(((struct scm_lambda_106_env *)(&((var_3)->env)))->v_k_26)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_106,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_103_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_103);
+
+#line 1 "string.scm"
+static void scm_lambda_112(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_112_env *env = (struct scm_lambda_112_env *)(&((env_in)->env));call_closure_two((env)->v_rv_54,var_0,(env)->v_k_27);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_109(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_109_env *env = (struct scm_lambda_109_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_112_env );(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_k_27)=((env)->v_k_27);(((struct scm_lambda_112_env *)(&((var_2)->env)))->v_rv_54)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_112,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_107(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_109_env );(((struct scm_lambda_109_env *)(&((var_3)->env)))->v_k_27)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_109,var_3);call_closure_two(var_2,MAKE_BOOL(0),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_107_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_107);
+
+#line 1 "string.scm"
+static void scm_lambda_113(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_113_env *env = (struct scm_lambda_113_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_110(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_113_env );(((struct scm_lambda_113_env *)(&((var_3)->env)))->v_k_28)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_113,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_110_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_110);
+
+#line 1 "string.scm"
+static void scm_lambda_119(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_119_env *env = (struct scm_lambda_119_env *)(&((env_in)->env));call_closure_two((env)->v_rv_57,var_0,(env)->v_k_29);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_116(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_116_env *env = (struct scm_lambda_116_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_119_env );(((struct scm_lambda_119_env *)(&((var_2)->env)))->v_k_29)=((env)->v_k_29);(((struct scm_lambda_119_env *)(&((var_2)->env)))->v_rv_57)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_119,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_114(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_116_env );(((struct scm_lambda_116_env *)(&((var_3)->env)))->v_k_29)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_116,var_3);call_closure_two(var_2,MAKE_BOOL(0),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_114_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_114);
+
+#line 1 "string.scm"
+static void scm_lambda_120(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_120_env *env = (struct scm_lambda_120_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_30);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_117(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_120_env );(((struct scm_lambda_120_env *)(&((var_3)->env)))->v_k_30)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_120,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_117_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_117);
+
+#line 1 "string.scm"
+static void scm_lambda_126(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_126_env *env = (struct scm_lambda_126_env *)(&((env_in)->env));call_closure_two((env)->v_rv_60,var_0,(env)->v_k_31);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_123(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_123_env *env = (struct scm_lambda_123_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_126_env );(((struct scm_lambda_126_env *)(&((var_2)->env)))->v_k_31)=((env)->v_k_31);(((struct scm_lambda_126_env *)(&((var_2)->env)))->v_rv_60)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_126,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_121(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"123");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_123_env );(((struct scm_lambda_123_env *)(&((var_4)->env)))->v_k_31)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_123,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_121_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_121);
+
+#line 1 "string.scm"
+static void scm_lambda_127(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_127_env *env = (struct scm_lambda_127_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_32);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_124(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_127_env );(((struct scm_lambda_127_env *)(&((var_3)->env)))->v_k_32)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_127,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_124_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_124);
+
+#line 1 "string.scm"
+static void scm_lambda_133(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_133_env *env = (struct scm_lambda_133_env *)(&((env_in)->env));call_closure_two((env)->v_rv_63,var_0,(env)->v_k_33);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_130(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_130_env *env = (struct scm_lambda_130_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_133_env );(((struct scm_lambda_133_env *)(&((var_2)->env)))->v_k_33)=((env)->v_k_33);(((struct scm_lambda_133_env *)(&((var_2)->env)))->v_rv_63)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_133,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_128(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"1/3");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_130_env );(((struct scm_lambda_130_env *)(&((var_4)->env)))->v_k_33)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_130,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_128_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_128);
+
+#line 1 "string.scm"
+static void scm_lambda_134(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_134_env *env = (struct scm_lambda_134_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_34);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_131(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_134_env );(((struct scm_lambda_134_env *)(&((var_3)->env)))->v_k_34)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_134,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_131_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_131);
+
+#line 1 "string.scm"
+static void scm_lambda_140(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_140_env *env = (struct scm_lambda_140_env *)(&((env_in)->env));call_closure_two((env)->v_rv_66,var_0,(env)->v_k_35);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_137(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_137_env *env = (struct scm_lambda_137_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_140_env );(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_k_35)=((env)->v_k_35);(((struct scm_lambda_140_env *)(&((var_2)->env)))->v_rv_66)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_140,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_135(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"0.25");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_137_env );(((struct scm_lambda_137_env *)(&((var_4)->env)))->v_k_35)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_137,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_135_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_135);
+
+#line 1 "string.scm"
+static void scm_lambda_141(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_141_env *env = (struct scm_lambda_141_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_36);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_138(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_141_env );(((struct scm_lambda_141_env *)(&((var_3)->env)))->v_k_36)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_141,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_138_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_138);
+
+#line 1 "string.scm"
+static void scm_lambda_155(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_155_env *env = (struct scm_lambda_155_env *)(&((env_in)->env));call_closure_two((env)->v_rv_69,var_0,(env)->v_k_37);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_151(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_151_env *env = (struct scm_lambda_151_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_155_env );(((struct scm_lambda_155_env *)(&((var_2)->env)))->v_k_37)=((env)->v_k_37);(((struct scm_lambda_155_env *)(&((var_2)->env)))->v_rv_69)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_155,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_147(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_147_env *env = (struct scm_lambda_147_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_151_env );(((struct scm_lambda_151_env *)(&((var_2)->env)))->v_k_37)=((env)->v_k_37);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_151,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_144(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_144_env *env = (struct scm_lambda_144_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_147_env );(((struct scm_lambda_147_env *)(&((var_2)->env)))->v_k_37)=((env)->v_k_37);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_147,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_142(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,string_to_list_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"ab");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_144_env );(((struct scm_lambda_144_env *)(&((var_4)->env)))->v_k_37)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_144,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_142_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_142);
+
+#line 1 "string.scm"
+static void scm_lambda_148(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_148_env *env = (struct scm_lambda_148_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_38);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_145(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_148_env );(((struct scm_lambda_148_env *)(&((var_3)->env)))->v_k_38)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_148,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_145_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_145);
+
+#line 1 "string.scm"
+static void scm_lambda_169(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_169_env *env = (struct scm_lambda_169_env *)(&((env_in)->env));call_closure_two((env)->v_rv_74,var_0,(env)->v_k_39);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_164(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_164_env *env = (struct scm_lambda_164_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_169_env );(((struct scm_lambda_169_env *)(&((var_2)->env)))->v_k_39)=((env)->v_k_39);(((struct scm_lambda_169_env *)(&((var_2)->env)))->v_rv_74)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_169,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_160(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_160_env *env = (struct scm_lambda_160_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_164_env );(((struct scm_lambda_164_env *)(&((var_2)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_164,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_156(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_156_env *env = (struct scm_lambda_156_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_160_env );(((struct scm_lambda_160_env *)(&((var_2)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_160,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_152(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_152_env *env = (struct scm_lambda_152_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_156_env );(((struct scm_lambda_156_env *)(&((var_2)->env)))->v_k_39)=((env)->v_k_39);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_156,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_149(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,string_to_list_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"ab");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_152_env );(((struct scm_lambda_152_env *)(&((var_4)->env)))->v_k_39)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_152,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_149_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_149);
+
+#line 1 "string.scm"
+static void scm_lambda_157(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_157_env *env = (struct scm_lambda_157_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_40);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_153(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_157_env );(((struct scm_lambda_157_env *)(&((var_3)->env)))->v_k_40)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_157,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_153_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_153);
+
+#line 1 "string.scm"
+static void scm_lambda_165(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_165_env *env = (struct scm_lambda_165_env *)(&((env_in)->env));call_closure_two((env)->v_rv_80,var_0,(env)->v_k_41);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_161(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_161_env *env = (struct scm_lambda_161_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_165_env );(((struct scm_lambda_165_env *)(&((var_2)->env)))->v_k_41)=((env)->v_k_41);(((struct scm_lambda_165_env *)(&((var_2)->env)))->v_rv_80)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_165,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_158(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"xAy\303\251 \"q\" \\ \316\273");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_161_env );(((struct scm_lambda_161_env *)(&((var_4)->env)))->v_k_41)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_161,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_158_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_158);
+
+#line 1 "string.scm"
+static void scm_lambda_166(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_166_env *env = (struct scm_lambda_166_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_42);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_162(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_166_env );(((struct scm_lambda_166_env *)(&((var_3)->env)))->v_k_42)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_166,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_162_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_162);
+
+#line 1 "string.scm"
+static void scm_lambda_173(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_173_env *env = (struct scm_lambda_173_env *)(&((env_in)->env));call_closure_two((env)->v_rv_83,var_0,(env)->v_k_43);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_170(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_170_env *env = (struct scm_lambda_170_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_173_env );(((struct scm_lambda_173_env *)(&((var_2)->env)))->v_k_43)=((env)->v_k_43);(((struct scm_lambda_173_env *)(&((var_2)->env)))->v_rv_83)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_173,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_167(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_170_env );(((struct scm_lambda_170_env *)(&((var_3)->env)))->v_k_43)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_170,var_3);call_closure_two(var_2,MAKE_FIXNUM(2),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_167_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_167);
+
+#line 1 "string.scm"
+static void scm_lambda_174(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_174_env *env = (struct scm_lambda_174_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_44);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_171(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_174_env );(((struct scm_lambda_174_env *)(&((var_3)->env)))->v_k_44)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_174,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_171_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_171);
+
+#line 1 "string.scm"
+static void scm_lambda_180(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_180_env *env = (struct scm_lambda_180_env *)(&((env_in)->env));call_closure_two((env)->v_rv_86,var_0,(env)->v_k_45);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_177(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_177_env *env = (struct scm_lambda_177_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_180_env );(((struct scm_lambda_180_env *)(&((var_2)->env)))->v_k_45)=((env)->v_k_45);(((struct scm_lambda_180_env *)(&((var_2)->env)))->v_rv_86)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_180,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_175(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_177_env );(((struct scm_lambda_177_env *)(&((var_3)->env)))->v_k_45)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_177,var_3);call_closure_two(var_2,MAKE_BOOL(1),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_175_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_175);
+
+#line 1 "string.scm"
+static void scm_lambda_181(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_181_env *env = (struct scm_lambda_181_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_46);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_178(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_181_env );(((struct scm_lambda_181_env *)(&((var_3)->env)))->v_k_46)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_181,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_178_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_178);
+
+#line 1 "string.scm"
+static void scm_lambda_201(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_201_env *env = (struct scm_lambda_201_env *)(&((env_in)->env));call_closure_two((env)->v_rv_89,var_0,(env)->v_k_47);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_200(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_200_env *env = (struct scm_lambda_200_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_201_env );(((struct scm_lambda_201_env *)(&((var_2)->env)))->v_k_47)=((env)->v_k_47);(((struct scm_lambda_201_env *)(&((var_2)->env)))->v_rv_89)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_201,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_199(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_199_env *env = (struct scm_lambda_199_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_200_env );(((struct scm_lambda_200_env *)(&((var_2)->env)))->v_k_47)=((env)->v_k_47);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_200,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_198(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_198_env *env = (struct scm_lambda_198_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_199_env );(((struct scm_lambda_199_env *)(&((var_1)->env)))->v_k_47)=((env)->v_k_47);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_199,var_1);call_closure_two((env)->v_rv_97,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_197(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_197_env *env = (struct scm_lambda_197_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_198_env );(((struct scm_lambda_198_env *)(&((var_1)->env)))->v_k_47)=((env)->v_k_47);(((struct scm_lambda_198_env *)(&((var_1)->env)))->v_rv_97)=((env)->v_rv_97);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_198,var_1);call_closure_two((env)->v_rv_96,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_196(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_196_env *env = (struct scm_lambda_196_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_197_env );(((struct scm_lambda_197_env *)(&((var_1)->env)))->v_k_47)=(((struct scm_lambda_191_env *)(&(((struct env_obj *)(((struct scm_lambda_194_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_47);(((struct scm_lambda_197_env *)(&((var_1)->env)))->v_rv_97)=(((struct scm_lambda_191_env *)(&(((struct env_obj *)(((struct scm_lambda_194_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_97);(((struct scm_lambda_197_env *)(&((var_1)->env)))->v_rv_96)=(((struct scm_lambda_191_env *)(&(((struct env_obj *)(((struct scm_lambda_194_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_96);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_197,var_1);call_closure_two(((struct scm_lambda_191_env *)(&(((struct env_obj *)(((struct scm_lambda_194_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_95,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_194(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_194_env *env = (struct scm_lambda_194_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_196_env );(((struct scm_lambda_196_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_196,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_191(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_191_env *env = (struct scm_lambda_191_env *)(&((env_in)->env));((env)->v_rv_95)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_194_env );(((struct scm_lambda_194_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_194,var_2);call_closure_two(var_1,MAKE_FIXNUM(34),var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_187(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_187_env *env = (struct scm_lambda_187_env *)(&((env_in)->env));((env)->v_rv_96)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_191_env );(((struct scm_lambda_191_env *)(&((var_2)->env)))->v_k_47)=((env)->v_k_47);(((struct scm_lambda_191_env *)(&((var_2)->env)))->v_rv_97)=((env)->v_rv_97);(((struct scm_lambda_191_env *)(&((var_2)->env)))->v_rv_96)=((env)->v_rv_96);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_191,var_2);call_closure_two(var_1,MAKE_FIXNUM(126),var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_184(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_184_env *env = (struct scm_lambda_184_env *)(&((env_in)->env));((env)->v_rv_97)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_187_env );(((struct scm_lambda_187_env *)(&((var_2)->env)))->v_k_47)=((env)->v_k_47);(((struct scm_lambda_187_env *)(&((var_2)->env)))->v_rv_97)=((env)->v_rv_97);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_187,var_2);call_closure_two(var_1,MAKE_FIXNUM(32),var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_182(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_182_env *env = (struct scm_lambda_182_env *)(&((env_in)->env));((env)->v_k_47)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_184_env );(((struct scm_lambda_184_env *)(&((var_3)->env)))->v_k_47)=((env)->v_k_47);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_184,var_3);call_closure_two(var_2,MAKE_FIXNUM(40),var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_188(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_188_env *env = (struct scm_lambda_188_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_48);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_185(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_188_env );(((struct scm_lambda_188_env *)(&((var_3)->env)))->v_k_48)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_188,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_185_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_185);
+
+#line 1 "string.scm"
+static void scm_lambda_195(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_195_env *env = (struct scm_lambda_195_env *)(&((env_in)->env));call_closure_two((env)->v_rv_100,var_0,(env)->v_k_49);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_192(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_192_env *env = (struct scm_lambda_192_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_195_env );(((struct scm_lambda_195_env *)(&((var_2)->env)))->v_k_49)=((env)->v_k_49);(((struct scm_lambda_195_env *)(&((var_2)->env)))->v_rv_100)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_195,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_189(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_192_env );(((struct scm_lambda_192_env *)(&((var_3)->env)))->v_k_49)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_192,var_3);call_closure_two(var_2,MAKE_FIXNUM(206),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_189_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_189);
+
+#line 1 "string.scm"
+static void scm_lambda_193(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_193_env *env = (struct scm_lambda_193_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_50);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_190(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_190_env *env = (struct scm_lambda_190_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_193_env );(((struct scm_lambda_193_env *)(&((var_2)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_193,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_186(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_186_env *env = (struct scm_lambda_186_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_190_env );(((struct scm_lambda_190_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_190,var_1);call_closure_two((struct obj *)(&(scm_lambda_189_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_183(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_183_env *env = (struct scm_lambda_183_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_186_env );(((struct scm_lambda_186_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_186,var_1);call_closure_two((struct obj *)(&(scm_lambda_185_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_179(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_179_env *env = (struct scm_lambda_179_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_182_env );OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_182,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_183_env );(((struct scm_lambda_183_env *)(&((var_3)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_183,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_176(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_176_env *env = (struct scm_lambda_176_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_179_env );(((struct scm_lambda_179_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_179,var_1);call_closure_two((struct obj *)(&(scm_lambda_178_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_172(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_172_env *env = (struct scm_lambda_172_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_176_env );(((struct scm_lambda_176_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_176,var_1);call_closure_two((struct obj *)(&(scm_lambda_175_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_168(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_168_env *env = (struct scm_lambda_168_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_172_env );(((struct scm_lambda_172_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_172,var_1);call_closure_two((struct obj *)(&(scm_lambda_171_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_163(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_163_env *env = (struct scm_lambda_163_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_168_env );(((struct scm_lambda_168_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_168,var_1);call_closure_two((struct obj *)(&(scm_lambda_167_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_159(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_159_env *env = (struct scm_lambda_159_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_163_env );(((struct scm_lambda_163_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_163,var_1);call_closure_two((struct obj *)(&(scm_lambda_162_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_154(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_154_env *env = (struct scm_lambda_154_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_159_env );(((struct scm_lambda_159_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_159,var_1);call_closure_two((struct obj *)(&(scm_lambda_158_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_150(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_150_env *env = (struct scm_lambda_150_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_154_env );(((struct scm_lambda_154_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_154,var_1);call_closure_two((struct obj *)(&(scm_lambda_153_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_146(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_146_env *env = (struct scm_lambda_146_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_150_env );(((struct scm_lambda_150_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_150,var_1);call_closure_two((struct obj *)(&(scm_lambda_149_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_143(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_143_env *env = (struct scm_lambda_143_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_146_env );(((struct scm_lambda_146_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_146,var_1);call_closure_two((struct obj *)(&(scm_lambda_145_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_139(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_139_env *env = (struct scm_lambda_139_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_143_env );(((struct scm_lambda_143_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_143,var_1);call_closure_two((struct obj *)(&(scm_lambda_142_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_136(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_136_env *env = (struct scm_lambda_136_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_139_env );(((struct scm_lambda_139_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_139,var_1);call_closure_two((struct obj *)(&(scm_lambda_138_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_132(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_132_env *env = (struct scm_lambda_132_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_136_env );(((struct scm_lambda_136_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_136,var_1);call_closure_two((struct obj *)(&(scm_lambda_135_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_129(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_129_env *env = (struct scm_lambda_129_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_132_env );(((struct scm_lambda_132_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_132,var_1);call_closure_two((struct obj *)(&(scm_lambda_131_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_125(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_125_env *env = (struct scm_lambda_125_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_129_env );(((struct scm_lambda_129_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_129,var_1);call_closure_two((struct obj *)(&(scm_lambda_128_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_122(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_122_env *env = (struct scm_lambda_122_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_125_env );(((struct scm_lambda_125_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_125,var_1);call_closure_two((struct obj *)(&(scm_lambda_124_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_118(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_118_env *env = (struct scm_lambda_118_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_122_env );(((struct scm_lambda_122_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_122,var_1);call_closure_two((struct obj *)(&(scm_lambda_121_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_115(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_115_env *env = (struct scm_lambda_115_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_118_env );(((struct scm_lambda_118_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_118,var_1);call_closure_two((struct obj *)(&(scm_lambda_117_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_111(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_111_env *env = (struct scm_lambda_111_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_115_env );(((struct scm_lambda_115_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_115,var_1);call_closure_two((struct obj *)(&(scm_lambda_114_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_108(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_108_env *env = (struct scm_lambda_108_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_111_env );(((struct scm_lambda_111_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_111,var_1);call_closure_two((struct obj *)(&(scm_lambda_110_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_104(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_104_env *env = (struct scm_lambda_104_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_108_env );(((struct scm_lambda_108_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_108,var_1);call_closure_two((struct obj *)(&(scm_lambda_107_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_101(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_101_env *env = (struct scm_lambda_101_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_104_env );(((struct scm_lambda_104_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_104,var_1);call_closure_two((struct obj *)(&(scm_lambda_103_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_97(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_97_env *env = (struct scm_lambda_97_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_101_env );(((struct scm_lambda_101_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_101,var_1);call_closure_two((struct obj *)(&(scm_lambda_100_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_94(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_94_env *env = (struct scm_lambda_94_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_97_env );(((struct scm_lambda_97_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_97,var_1);call_closure_two((struct obj *)(&(scm_lambda_96_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_90(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_90_env *env = (struct scm_lambda_90_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_94_env );(((struct scm_lambda_94_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_94,var_1);call_closure_two((struct obj *)(&(scm_lambda_93_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_87(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_87_env *env = (struct scm_lambda_87_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_90_env );(((struct scm_lambda_90_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_90,var_1);call_closure_two((struct obj *)(&(scm_lambda_89_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_83(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_83_env *env = (struct scm_lambda_83_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_87_env );(((struct scm_lambda_87_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_87,var_1);call_closure_two((struct obj *)(&(scm_lambda_86_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_80(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_80_env *env = (struct scm_lambda_80_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_83_env );(((struct scm_lambda_83_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_83,var_1);call_closure_two((struct obj *)(&(scm_lambda_82_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_75(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_75_env *env = (struct scm_lambda_75_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_80_env );(((struct scm_lambda_80_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_80,var_1);call_closure_two((struct obj *)(&(scm_lambda_79_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_71(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_71_env *env = (struct scm_lambda_71_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_75_env );(((struct scm_lambda_75_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_75,var_1);call_closure_two((struct obj *)(&(scm_lambda_74_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_71_env );(((struct scm_lambda_71_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_71,var_1);call_closure_two((struct obj *)(&(scm_lambda_70_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_62(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_62_env *env = (struct scm_lambda_62_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_66,var_1);call_closure_two((struct obj *)(&(scm_lambda_65_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_61,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_62_env );(((struct scm_lambda_62_env *)(&((var_3)->env)))->v_k_50)=((env)->v_k_50);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_62,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_58_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_58,var_1);call_closure_two((struct obj *)(&(scm_lambda_57_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_49(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_49_env *env = (struct scm_lambda_49_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_53_env );(((struct scm_lambda_53_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_53,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_54_env *)(&((var_3)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_54,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_49_env );(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_49_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_49,var_1);call_closure_two((struct obj *)(&(scm_lambda_48_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_44_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_44,var_1);call_closure_two((struct obj *)(&(scm_lambda_43_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_35(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_39_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_39,var_1);call_closure_two((struct obj *)(&(scm_lambda_38_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_34,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_3)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_35_env *)(&((var_3)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_35,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_30,var_1);call_closure_two((struct obj *)(&(scm_lambda_29_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_25,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_3)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_26_env *)(&((var_3)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_26,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_22_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_22,var_1);call_closure_two((struct obj *)(&(scm_lambda_21_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_18,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_19_env *)(&((var_3)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_19,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_15_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_15,var_1);call_closure_two((struct obj *)(&(scm_lambda_14_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_11_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_11,var_1);call_closure_two((struct obj *)(&(scm_lambda_10_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_50)=((env)->v_k_50);(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_s)=((env)->v_s);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two((struct obj *)(&(scm_lambda_6_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "string.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_STRING_OBJ_NEW(var_3,"hello world");(((struct cell_obj *)(var_2))->val)=(var_3);GC_WRITE_BARRIER(var_2,((struct cell_obj *)(var_2))->val);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_s)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_5,scm_lambda_3,var_4);OBJECT_ENV_OBJ_NEW(var_6,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_6)->env)))->v_k_50)=(var_1);(((struct scm_lambda_4_env *)(&((var_6)->env)))->v_s)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_7,scm_lambda_4,var_6);call_closure_two(var_5,NULL,var_7);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "string.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_1);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "string.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 1064 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
@@ -0,0 +1,296 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_go_lambda_8_env {struct obj *v_k;};
+static void scm_go_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_go_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_go_lambda_6_closure;
+struct scm_go_lambda_7_env {struct obj *v_go;struct obj *v_k_2;struct obj *v_rv_6;};
+static void scm_go_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_go_lambda_5_env {struct obj *v_go;struct obj *v_k_2;struct obj *v_rv_6;};
+static void scm_go_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_go_lambda_4_env {struct obj *parent;};
+static void scm_go_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_go_lambda_3_env {struct obj *v_go;struct obj *v_k_2;struct obj *v_rv_6;struct obj *v_rv_5;};
+static void scm_go_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_go_lambda_2_env {struct obj *v_go;struct obj *v_k_2;struct obj *v_rv_6;};
+static void scm_go_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_go_lambda_1_env {struct obj *v_go;struct obj *v_k_2;};
+static void scm_go_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_go_env {struct obj *v_go;struct obj *v_k_2;};
+static void scm_go(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_box_lambda_1_env {struct obj *v_initial;};
+static void scm_make_box_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_box_lambda_3_env {struct obj *v_initial;};
+static void scm_make_box_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_make_box_lambda_2_env {struct obj *v_initial;struct obj *v_k_5;};
+static void scm_make_box_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_make_box(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_make_box_closure;
+struct scm_lambda_34_env {struct obj *v_k_6;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_32(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_32_closure;
+struct scm_lambda_42_env {struct obj *v_k_7;struct obj *v_rv_11;};
+static void scm_lambda_42(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_7;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_7;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_rv_22;};
+static void scm_lambda_37(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_8;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_8;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_8;struct obj *v_rv_22;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_33_env {struct obj *v_k_8;struct obj *v_rv_22;struct obj *v_rv_21;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_8;struct obj *v_rv_22;struct obj *v_rv_21;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *parent;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_26_env {struct obj *v_k_8;struct obj *v_rv_22;struct obj *v_rv_21;struct obj *v_rv_19;};
+static void scm_lambda_26(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_k_8;struct obj *v_rv_22;struct obj *v_rv_21;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_18_env {struct obj *v_k_8;struct obj *v_rv_22;struct obj *v_rv_21;};
+static void scm_lambda_18(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_k_8;struct obj *v_rv_23;struct obj *v_rv_22;};
+static void scm_lambda_14(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_k_8;};
+static void scm_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_8;struct obj *v_make_box;};
+static void scm_lambda_9(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *v_k_9;struct obj *v_rv_25;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *v_k_9;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_12(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_12_closure;
+struct scm_lambda_20_env {struct obj *v_k_10;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_16(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_16_closure;
+struct scm_lambda_31_env {struct obj *v_k_11;struct obj *v_rv_28;};
+static void scm_lambda_31(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_11;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_k_11;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_11;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_21(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_21_closure;
+struct scm_lambda_25_env {struct obj *v_k_12;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_22_env {struct obj *v_k_12;};
+static void scm_lambda_22(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_12;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_12;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_k_12;};
+static void scm_lambda_10(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_k_12;struct obj *v_make_box;};
+static void scm_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_go;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_go;struct obj *v_k_14;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_14;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_go_lambda_8),"scm_go_lambda_8","stuff.scm",1},{(void *)(scm_go_lambda_6),"scm_go_lambda_6","stuff.scm",1},{(void *)(scm_go_lambda_7),"scm_go_lambda_7","stuff.scm",1},{(void *)(scm_go_lambda_5),"scm_go_lambda_5","stuff.scm",1},{(void *)(scm_go_lambda_4),"scm_go_lambda_4","stuff.scm",1},{(void *)(scm_go_lambda_3),"scm_go_lambda_3","stuff.scm",1},{(void *)(scm_go_lambda_2),"scm_go_lambda_2","stuff.scm",1},{(void *)(scm_go_lambda_1),"scm_go_lambda_1","stuff.scm",1},{(void *)(scm_go),"scm_go","stuff.scm",1},{(void *)(scm_make_box_lambda_1),"scm_make_box_lambda_1","stuff.scm",11},{(void *)(scm_make_box_lambda_3),"scm_make_box_lambda_3","stuff.scm",12},{(void *)(scm_make_box_lambda_2),"scm_make_box_lambda_2","stuff.scm",10},{(void *)(scm_make_box),"scm_make_box","stuff.scm",10},{(void *)(scm_lambda_34),"scm_lambda_34","stuff.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","stuff.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","stuff.scm",1},{(void *)(scm_lambda_41),"scm_lambda_41","stuff.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","stuff.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","stuff.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","stuff.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","stuff.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","stuff.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","stuff.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","stuff.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","stuff.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","stuff.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","stuff.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","stuff.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","stuff.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","stuff.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","stuff.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","stuff.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","stuff.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","stuff.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","stuff.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","stuff.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","stuff.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","stuff.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","stuff.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","stuff.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","stuff.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","stuff.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","stuff.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","stuff.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","stuff.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","stuff.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","stuff.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","stuff.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","stuff.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","stuff.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","stuff.scm",1},{NULL}};
+
+#line 1 "stuff.scm"
+static void scm_go_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_go_lambda_8_env *env = (struct scm_go_lambda_8_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_go_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_go_lambda_8_env );(((struct scm_go_lambda_8_env *)(&((var_3)->env)))->v_k)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_go_lambda_8,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_go_lambda_6_closure = CLOSURE_TWO_OBJ_INIT(scm_go_lambda_6);
+
+#line 1 "stuff.scm"
+static void scm_go_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_go_lambda_7_env *env = (struct scm_go_lambda_7_env *)(&((env_in)->env));struct obj *var_1 = ((struct cell_obj *)((env)->v_go))->val;struct obj *var_2 = (env)->v_rv_6;struct obj *var_3 = (env)->v_k_2;if (stack_check()){scm_go(var_2,var_3,((struct closure_obj *)(var_1))->env);} else {}call_closure_two(var_1,var_2,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_go_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_go_lambda_5_env *env = (struct scm_go_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_go_lambda_7_env );(((struct scm_go_lambda_7_env *)(&((var_1)->env)))->v_go)=((env)->v_go);(((struct scm_go_lambda_7_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_go_lambda_7_env *)(&((var_1)->env)))->v_rv_6)=((env)->v_rv_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_go_lambda_7,var_1);call_closure_two((struct obj *)(&(scm_go_lambda_6_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_go_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_go_lambda_4_env *env = (struct scm_go_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_go_lambda_5_env );(((struct scm_go_lambda_5_env *)(&((var_1)->env)))->v_go)=(((struct scm_go_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_go);(((struct scm_go_lambda_5_env *)(&((var_1)->env)))->v_k_2)=(((struct scm_go_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_2);(((struct scm_go_lambda_5_env *)(&((var_1)->env)))->v_rv_6)=(((struct scm_go_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_6);OBJECT_CLOSURE_ONE_NEW(var_2,scm_go_lambda_5,var_1);call_closure_two(((struct scm_go_lambda_3_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_5,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_go_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_go_lambda_3_env *env = (struct scm_go_lambda_3_env *)(&((env_in)->env));((env)->v_rv_5)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_go_lambda_4_env );(((struct scm_go_lambda_4_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_go_lambda_4,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_go_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_go_lambda_2_env *env = (struct scm_go_lambda_2_env *)(&((env_in)->env));((env)->v_rv_6)=(var_0);GC_WRITE_BARRIER(env_in,var_0);if (obj_is_truthy((env)->v_rv_6)){OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_go_lambda_3_env );(((struct scm_go_lambda_3_env *)(&((var_2)->env)))->v_go)=((env)->v_go);(((struct scm_go_lambda_3_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_go_lambda_3_env *)(&((var_2)->env)))->v_rv_6)=((env)->v_rv_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_go_lambda_3,var_2);call_closure_two(var_1,(env)->v_rv_6,var_3);} else {call_closure_one((env)->v_k_2,NULL);}0;__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_go_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_go_lambda_1_env *env = (struct scm_go_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_go_lambda_2_env );(((struct scm_go_lambda_2_env *)(&((var_1)->env)))->v_go)=((env)->v_go);(((struct scm_go_lambda_2_env *)(&((var_1)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_go_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_go(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_go_env *env = (struct scm_go_env *)(&((env_in)->env));((env)->v_k_2)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_go_lambda_1_env );(((struct scm_go_lambda_1_env *)(&((var_3)->env)))->v_go)=((env)->v_go);(((struct scm_go_lambda_1_env *)(&((var_3)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_4,scm_go_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 11 "stuff.scm"
+static void scm_make_box_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_make_box_lambda_1_env *env = (struct scm_make_box_lambda_1_env *)(&((env_in)->env));call_closure_one(var_1,((struct cell_obj *)((env)->v_initial))->val);__builtin_unreachable();}
+
+#line 12 "stuff.scm"
+static void scm_make_box_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_make_box_lambda_3_env *env = (struct scm_make_box_lambda_3_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_initial))->val)=(var_0);GC_WRITE_BARRIER((env)->v_initial,((struct cell_obj *)((env)->v_initial))->val);call_closure_one(var_1,NULL);__builtin_unreachable();}
+
+#line 10 "stuff.scm"
+static void scm_make_box_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_make_box_lambda_2_env *env = (struct scm_make_box_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_make_box_lambda_3_env );(((struct scm_make_box_lambda_3_env *)(&((var_1)->env)))->v_initial)=((env)->v_initial);OBJECT_CLOSURE_TWO_NEW(var_2,scm_make_box_lambda_3,var_1);call_closure_two(var_0,var_2,(env)->v_k_5);__builtin_unreachable();}
+
+#line 10 "stuff.scm"
+static void scm_make_box(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_CLOSURE_TWO_NEW(var_3,cons_k,NULL);OBJECT_ENV_OBJ_NEW(var_4,struct scm_make_box_lambda_1_env );(((struct scm_make_box_lambda_1_env *)(&((var_4)->env)))->v_initial)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_5,scm_make_box_lambda_1,var_4);OBJECT_ENV_OBJ_NEW(var_6,struct scm_make_box_lambda_2_env );(((struct scm_make_box_lambda_2_env *)(&((var_6)->env)))->v_initial)=(var_2);(((struct scm_make_box_lambda_2_env *)(&((var_6)->env)))->v_k_5)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_7,scm_make_box_lambda_2,var_6);call_closure_two(var_3,var_5,var_7);__builtin_unreachable();}
+static struct closure_obj scm_make_box_closure = CLOSURE_TWO_OBJ_INIT(scm_make_box);
+
+#line 1 "stuff.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_32(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_3)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_34,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_32_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_32);
+
+#line 1 "stuff.scm"
+static void scm_lambda_42(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));call_closure_two((env)->v_rv_11,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);(((struct scm_lambda_42_env *)(&((var_2)->env)))->v_rv_11)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_42,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_k_7)=((env)->v_k_7);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_41,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_37(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two((env)->v_rv_22,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_8);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_40,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_1)->env)))->v_rv_22)=((env)->v_rv_22);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_37,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_3)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_38,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_36_env *)(&((var_1)->env)))->v_rv_22)=((env)->v_rv_22);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_36,var_1);call_closure_two((env)->v_rv_21,MAKE_FIXNUM(23),var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_33_env *)(&((var_1)->env)))->v_rv_22)=((env)->v_rv_22);(((struct scm_lambda_33_env *)(&((var_1)->env)))->v_rv_21)=((env)->v_rv_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_33,var_1);call_closure_two((struct obj *)(&(scm_lambda_32_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_k_8)=(((struct scm_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_8);(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_rv_22)=(((struct scm_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_22);(((struct scm_lambda_30_env *)(&((var_1)->env)))->v_rv_21)=(((struct scm_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_30,var_1);call_closure_two(((struct scm_lambda_26_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_19,var_0,var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_26(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_26_env *env = (struct scm_lambda_26_env *)(&((env_in)->env));((env)->v_rv_19)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_26_env );(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_rv_22)=((env)->v_rv_22);(((struct scm_lambda_26_env *)(&((var_2)->env)))->v_rv_21)=((env)->v_rv_21);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_26,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_18(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_18_env *env = (struct scm_lambda_18_env *)(&((env_in)->env));((env)->v_rv_21)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_rv_22)=((env)->v_rv_22);(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_rv_21)=((env)->v_rv_21);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_23,var_1);call_closure_two((env)->v_rv_22,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_14(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));((env)->v_rv_22)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,cdr_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_18_env );(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_18_env *)(&((var_2)->env)))->v_rv_22)=((env)->v_rv_22);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_18,var_2);call_closure_two(var_1,(env)->v_rv_23,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,car_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);(((struct scm_lambda_14_env *)(&((var_2)->env)))->v_rv_23)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_14,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_9(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));((env)->v_k_8)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_2)->env)))->v_k_8)=((env)->v_k_8);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_11,var_2);call_closure_two(((struct cell_obj *)((env)->v_make_box))->val,MAKE_FIXNUM(1),var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));call_closure_two((env)->v_rv_25,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_19_env *)(&((var_2)->env)))->v_rv_25)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_19,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_12(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,MAKE_FIXNUM(3),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_12_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_12);
+
+#line 1 "stuff.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_16(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_20,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_16_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_16);
+
+#line 1 "stuff.scm"
+static void scm_lambda_31(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_31_env *env = (struct scm_lambda_31_env *)(&((env_in)->env));call_closure_two((env)->v_rv_28,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_31_env );(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_31_env *)(&((var_2)->env)))->v_rv_28)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_31,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_29,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1,"b");OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_27,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_21(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,string_concat_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"a");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_4)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_24,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_21_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_21);
+
+#line 1 "stuff.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_22(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_22_env *env = (struct scm_lambda_22_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_22_env );(((struct scm_lambda_22_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_22,var_1);call_closure_two((struct obj *)(&(scm_lambda_21_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_17,var_1);call_closure_two((struct obj *)(&(scm_lambda_16_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_10(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_1)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_13,var_1);call_closure_two((struct obj *)(&(scm_lambda_12_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_make_box))->val)=((struct obj *)(&(scm_make_box_closure)));GC_WRITE_BARRIER((env)->v_make_box,((struct cell_obj *)((env)->v_make_box))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_make_box)=((env)->v_make_box);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_9,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_k_12)=((env)->v_k_12);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_10,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_go_env );(((struct scm_go_env *)(&((var_3)->env)))->v_go)=((env)->v_go);OBJECT_CLOSURE_TWO_NEW(var_4,scm_go,var_3);(((struct cell_obj *)((env)->v_go))->val)=(var_4);GC_WRITE_BARRIER((env)->v_go,((struct cell_obj *)((env)->v_go))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_5)->env)))->v_k_12)=(var_1);(((struct scm_lambda_6_env *)(&((var_5)->env)))->v_make_box)=(var_2);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_6,var_5);call_closure_two(((struct cell_obj *)((env)->v_go))->val,MAKE_FIXNUM(10),var_6);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_go)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_go)=((env)->v_go);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_two(var_4,NULL,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "stuff.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );(((struct scm_lambda_2_env *)(&((var_2)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "stuff.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 277 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
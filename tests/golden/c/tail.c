@@ -0,0 +1,308 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_count_down_lambda_4_env {struct obj *v_count_down;struct obj *v_k;};
+static void scm_count_down_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_lambda_3_env {struct obj *v_count_down;struct obj *v_k;};
+static void scm_count_down_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_lambda_2_env {struct obj *v_count_down;struct obj *v_k;struct obj *v_n;};
+static void scm_count_down_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_lambda_1_env {struct obj *v_count_down;struct obj *v_k;struct obj *v_n;};
+static void scm_count_down_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_down_env {struct obj *v_count_down;};
+static void scm_count_down(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_2;struct obj *v_rv_6;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_k_2;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_2;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_count_down;};
+static void scm_lambda_11(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_3;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_14_closure;
+struct scm_is_even_p_lambda_4_env {struct obj *v_is_odd_p;struct obj *v_k_4;};
+static void scm_is_even_p_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_even_p_lambda_3_env {struct obj *v_is_odd_p;struct obj *v_k_4;};
+static void scm_is_even_p_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_even_p_lambda_2_env {struct obj *v_is_odd_p;struct obj *v_k_4;struct obj *v_n_2;};
+static void scm_is_even_p_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_even_p_lambda_1_env {struct obj *v_is_odd_p;struct obj *v_k_4;struct obj *v_n_2;};
+static void scm_is_even_p_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_even_p_env {struct obj *v_is_odd_p;};
+static void scm_is_even_p(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_odd_p_lambda_4_env {struct obj *v_is_even_p;struct obj *v_k_5;};
+static void scm_is_odd_p_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_odd_p_lambda_3_env {struct obj *v_is_even_p;struct obj *v_k_5;};
+static void scm_is_odd_p_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_odd_p_lambda_2_env {struct obj *v_is_even_p;struct obj *v_k_5;struct obj *v_n_3;};
+static void scm_is_odd_p_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_odd_p_lambda_1_env {struct obj *v_is_even_p;struct obj *v_k_5;struct obj *v_n_3;};
+static void scm_is_odd_p_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_is_odd_p_env {struct obj *v_is_even_p;};
+static void scm_is_odd_p(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *v_k_6;struct obj *v_rv_18;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_28_env {struct obj *v_k_6;};
+static void scm_lambda_28(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_25_env {struct obj *v_k_6;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_is_even_p;};
+static void scm_lambda_23(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *v_k_7;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_26(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_26_closure;
+struct scm_sum_to_lambda_6_env {struct obj *parent;};
+static void scm_sum_to_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_5_env {struct obj *parent;};
+static void scm_sum_to_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_4_env {struct obj *v_acc;struct obj *v_k_8;struct obj *v_n_4;struct obj *v_rv_25;struct obj *v_sum_to;};
+static void scm_sum_to_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_3_env {struct obj *parent;};
+static void scm_sum_to_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_2_env {struct obj *parent;struct obj *v_rv_25;};
+static void scm_sum_to_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_lambda_1_env {struct obj *parent;};
+static void scm_sum_to_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_sum_to_env {struct obj *v_acc;struct obj *v_k_8;struct obj *v_n_4;struct obj *v_sum_to;};
+static void scm_sum_to(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_k_9;struct obj *v_rv_28;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_k_9;};
+static void scm_lambda_39(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_37_env {struct obj *v_k_9;};
+static void scm_lambda_37(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_35_env {struct obj *v_sum_to;};
+static void scm_lambda_35(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_38_env {struct obj *v_k_10;};
+static void scm_lambda_38(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_36_env {struct obj *v_k_10;};
+static void scm_lambda_36(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *v_acc;struct obj *v_k_10;struct obj *v_sum_to;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_acc;struct obj *v_k_10;struct obj *v_sum_to;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *parent;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *parent;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_acc;struct obj *v_count_down;struct obj *v_is_even_p;struct obj *v_is_odd_p;struct obj *v_k_10;struct obj *v_sum_to;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_acc;struct obj *v_count_down;struct obj *v_is_even_p;struct obj *v_is_odd_p;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_count_down;struct obj *v_is_even_p;struct obj *v_is_odd_p;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_count_down;struct obj *v_is_even_p;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_2_closure;
+struct scm_lambda_9_env {struct obj *v_k_15;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_15;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_15;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_15;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_count_down_lambda_4),"scm_count_down_lambda_4","tail.scm",2},{(void *)(scm_count_down_lambda_3),"scm_count_down_lambda_3","tail.scm",2},{(void *)(scm_count_down_lambda_2),"scm_count_down_lambda_2","tail.scm",2},{(void *)(scm_count_down_lambda_1),"scm_count_down_lambda_1","tail.scm",2},{(void *)(scm_count_down),"scm_count_down","tail.scm",2},{(void *)(scm_lambda_20),"scm_lambda_20","tail.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","tail.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","tail.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","tail.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","tail.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","tail.scm",1},{(void *)(scm_is_even_p_lambda_4),"scm_is_even_p_lambda_4","tail.scm",10},{(void *)(scm_is_even_p_lambda_3),"scm_is_even_p_lambda_3","tail.scm",10},{(void *)(scm_is_even_p_lambda_2),"scm_is_even_p_lambda_2","tail.scm",10},{(void *)(scm_is_even_p_lambda_1),"scm_is_even_p_lambda_1","tail.scm",10},{(void *)(scm_is_even_p),"scm_is_even_p","tail.scm",10},{(void *)(scm_is_odd_p_lambda_4),"scm_is_odd_p_lambda_4","tail.scm",14},{(void *)(scm_is_odd_p_lambda_3),"scm_is_odd_p_lambda_3","tail.scm",14},{(void *)(scm_is_odd_p_lambda_2),"scm_is_odd_p_lambda_2","tail.scm",14},{(void *)(scm_is_odd_p_lambda_1),"scm_is_odd_p_lambda_1","tail.scm",14},{(void *)(scm_is_odd_p),"scm_is_odd_p","tail.scm",14},{(void *)(scm_lambda_32),"scm_lambda_32","tail.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","tail.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","tail.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","tail.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","tail.scm",1},{(void *)(scm_lambda_26),"scm_lambda_26","tail.scm",1},{(void *)(scm_sum_to_lambda_6),"scm_sum_to_lambda_6","tail.scm",22},{(void *)(scm_sum_to_lambda_5),"scm_sum_to_lambda_5","tail.scm",22},{(void *)(scm_sum_to_lambda_4),"scm_sum_to_lambda_4","tail.scm",22},{(void *)(scm_sum_to_lambda_3),"scm_sum_to_lambda_3","tail.scm",22},{(void *)(scm_sum_to_lambda_2),"scm_sum_to_lambda_2","tail.scm",22},{(void *)(scm_sum_to_lambda_1),"scm_sum_to_lambda_1","tail.scm",22},{(void *)(scm_sum_to),"scm_sum_to","tail.scm",22},{(void *)(scm_lambda_40),"scm_lambda_40","tail.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","tail.scm",1},{(void *)(scm_lambda_37),"scm_lambda_37","tail.scm",1},{(void *)(scm_lambda_35),"scm_lambda_35","tail.scm",1},{(void *)(scm_lambda_38),"scm_lambda_38","tail.scm",1},{(void *)(scm_lambda_36),"scm_lambda_36","tail.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","tail.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","tail.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","tail.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","tail.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","tail.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","tail.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","tail.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","tail.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","tail.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","tail.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","tail.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","tail.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","tail.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","tail.scm",1},{NULL}};
+
+#line 2 "tail.scm"
+static void scm_count_down_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_4_env *env = (struct scm_count_down_lambda_4_env *)(&((env_in)->env));struct obj *var_1 = ((struct cell_obj *)((env)->v_count_down))->val;struct obj *var_2 = var_0;struct obj *var_3 = (env)->v_k;if (stack_check()){scm_count_down(var_2,var_3,((struct closure_obj *)(var_1))->env);} else {}call_closure_two(var_1,var_2,var_3);__builtin_unreachable();}
+
+#line 2 "tail.scm"
+static void scm_count_down_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_3_env *env = (struct scm_count_down_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_down_lambda_4_env );(((struct scm_count_down_lambda_4_env *)(&((var_1)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_down_lambda_4_env *)(&((var_1)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_down_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 2 "tail.scm"
+static void scm_count_down_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_2_env *env = (struct scm_count_down_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){OBJECT_STRING_OBJ_NEW(var_1,"done");call_closure_one((env)->v_k,var_1);} else {OBJECT_CLOSURE_TWO_NEW(var_2,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_down_lambda_3_env );(((struct scm_count_down_lambda_3_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_down_lambda_3_env *)(&((var_3)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_4,scm_count_down_lambda_3,var_3);call_closure_two(var_2,(env)->v_n,var_4);}0;__builtin_unreachable();}
+
+#line 2 "tail.scm"
+static void scm_count_down_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_count_down_lambda_1_env *env = (struct scm_count_down_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_down_lambda_2_env );(((struct scm_count_down_lambda_2_env *)(&((var_1)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_down_lambda_2_env *)(&((var_1)->env)))->v_k)=((env)->v_k);(((struct scm_count_down_lambda_2_env *)(&((var_1)->env)))->v_n)=((env)->v_n);OBJECT_CLOSURE_ONE_NEW(var_2,scm_count_down_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 2 "tail.scm"
+static void scm_count_down(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_down_env *env = (struct scm_count_down_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_down_lambda_1_env );(((struct scm_count_down_lambda_1_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_count_down_lambda_1_env *)(&((var_3)->env)))->v_k)=(var_1);(((struct scm_count_down_lambda_1_env *)(&((var_3)->env)))->v_n)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_count_down_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));call_closure_two((env)->v_rv_6,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);(((struct scm_lambda_20_env *)(&((var_2)->env)))->v_rv_6)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_20,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_2)->env)))->v_k_2)=((env)->v_k_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_16,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_11(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_2)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_13,var_2);call_closure_two(((struct cell_obj *)((env)->v_count_down))->val,MAKE_FIXNUM(1000000),var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_3)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_14_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_14);
+
+#line 10 "tail.scm"
+static void scm_is_even_p_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_is_even_p_lambda_4_env *env = (struct scm_is_even_p_lambda_4_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_is_odd_p))->val,var_0,(env)->v_k_4);__builtin_unreachable();}
+
+#line 10 "tail.scm"
+static void scm_is_even_p_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_is_even_p_lambda_3_env *env = (struct scm_is_even_p_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_is_even_p_lambda_4_env );(((struct scm_is_even_p_lambda_4_env *)(&((var_1)->env)))->v_is_odd_p)=((env)->v_is_odd_p);(((struct scm_is_even_p_lambda_4_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_2,scm_is_even_p_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 10 "tail.scm"
+static void scm_is_even_p_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_is_even_p_lambda_2_env *env = (struct scm_is_even_p_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_4,MAKE_BOOL(1));} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_is_even_p_lambda_3_env );(((struct scm_is_even_p_lambda_3_env *)(&((var_2)->env)))->v_is_odd_p)=((env)->v_is_odd_p);(((struct scm_is_even_p_lambda_3_env *)(&((var_2)->env)))->v_k_4)=((env)->v_k_4);OBJECT_CLOSURE_ONE_NEW(var_3,scm_is_even_p_lambda_3,var_2);call_closure_two(var_1,(env)->v_n_2,var_3);}0;__builtin_unreachable();}
+
+#line 10 "tail.scm"
+static void scm_is_even_p_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_is_even_p_lambda_1_env *env = (struct scm_is_even_p_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_is_even_p_lambda_2_env );(((struct scm_is_even_p_lambda_2_env *)(&((var_1)->env)))->v_is_odd_p)=((env)->v_is_odd_p);(((struct scm_is_even_p_lambda_2_env *)(&((var_1)->env)))->v_k_4)=((env)->v_k_4);(((struct scm_is_even_p_lambda_2_env *)(&((var_1)->env)))->v_n_2)=((env)->v_n_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_is_even_p_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 10 "tail.scm"
+static void scm_is_even_p(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_is_even_p_env *env = (struct scm_is_even_p_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_is_even_p_lambda_1_env );(((struct scm_is_even_p_lambda_1_env *)(&((var_3)->env)))->v_is_odd_p)=((env)->v_is_odd_p);(((struct scm_is_even_p_lambda_1_env *)(&((var_3)->env)))->v_k_4)=(var_1);(((struct scm_is_even_p_lambda_1_env *)(&((var_3)->env)))->v_n_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_is_even_p_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 14 "tail.scm"
+static void scm_is_odd_p_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_is_odd_p_lambda_4_env *env = (struct scm_is_odd_p_lambda_4_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_is_even_p))->val,var_0,(env)->v_k_5);__builtin_unreachable();}
+
+#line 14 "tail.scm"
+static void scm_is_odd_p_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_is_odd_p_lambda_3_env *env = (struct scm_is_odd_p_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_is_odd_p_lambda_4_env );(((struct scm_is_odd_p_lambda_4_env *)(&((var_1)->env)))->v_is_even_p)=((env)->v_is_even_p);(((struct scm_is_odd_p_lambda_4_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_2,scm_is_odd_p_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 14 "tail.scm"
+static void scm_is_odd_p_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_is_odd_p_lambda_2_env *env = (struct scm_is_odd_p_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_5,MAKE_BOOL(0));} else {OBJECT_CLOSURE_TWO_NEW(var_1,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_is_odd_p_lambda_3_env );(((struct scm_is_odd_p_lambda_3_env *)(&((var_2)->env)))->v_is_even_p)=((env)->v_is_even_p);(((struct scm_is_odd_p_lambda_3_env *)(&((var_2)->env)))->v_k_5)=((env)->v_k_5);OBJECT_CLOSURE_ONE_NEW(var_3,scm_is_odd_p_lambda_3,var_2);call_closure_two(var_1,(env)->v_n_3,var_3);}0;__builtin_unreachable();}
+
+#line 14 "tail.scm"
+static void scm_is_odd_p_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_is_odd_p_lambda_1_env *env = (struct scm_is_odd_p_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_is_odd_p_lambda_2_env );(((struct scm_is_odd_p_lambda_2_env *)(&((var_1)->env)))->v_is_even_p)=((env)->v_is_even_p);(((struct scm_is_odd_p_lambda_2_env *)(&((var_1)->env)))->v_k_5)=((env)->v_k_5);(((struct scm_is_odd_p_lambda_2_env *)(&((var_1)->env)))->v_n_3)=((env)->v_n_3);OBJECT_CLOSURE_ONE_NEW(var_2,scm_is_odd_p_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 14 "tail.scm"
+static void scm_is_odd_p(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_is_odd_p_env *env = (struct scm_is_odd_p_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_is_odd_p_lambda_1_env );(((struct scm_is_odd_p_lambda_1_env *)(&((var_3)->env)))->v_is_even_p)=((env)->v_is_even_p);(((struct scm_is_odd_p_lambda_1_env *)(&((var_3)->env)))->v_k_5)=(var_1);(((struct scm_is_odd_p_lambda_1_env *)(&((var_3)->env)))->v_n_3)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_4,scm_is_odd_p_lambda_1,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));call_closure_two((env)->v_rv_18,var_0,(env)->v_k_6);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_28(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_28_env *env = (struct scm_lambda_28_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);(((struct scm_lambda_32_env *)(&((var_2)->env)))->v_rv_18)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_32,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_28_env );(((struct scm_lambda_28_env *)(&((var_2)->env)))->v_k_6)=((env)->v_k_6);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_28,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_23(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_2)->env)))->v_k_6)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_25,var_2);call_closure_two(((struct cell_obj *)((env)->v_is_even_p))->val,MAKE_FIXNUM(1000001),var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_26(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_3)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_29,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_26_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_26);
+
+#line 22 "tail.scm"
+static void scm_sum_to_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_6_env *env = (struct scm_sum_to_lambda_6_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc,((struct cell_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc))->val);struct obj *var_1 = ((struct cell_obj *)(((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_sum_to))->val;struct obj *var_2 = ((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_rv_25;struct obj *var_3 = ((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_5_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_8;if (stack_check()){scm_sum_to(var_2,var_3,((struct closure_obj *)(var_1))->env);} else {}call_closure_two(var_1,var_2,var_3);__builtin_unreachable();}
+
+#line 22 "tail.scm"
+static void scm_sum_to_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_5_env *env = (struct scm_sum_to_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_lambda_6_env );(((struct scm_sum_to_lambda_6_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_sum_to_lambda_6,var_1);call_closure_two(var_0,((struct scm_sum_to_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_n_4,var_2);__builtin_unreachable();}
+
+#line 22 "tail.scm"
+static void scm_sum_to_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_4_env *env = (struct scm_sum_to_lambda_4_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_one((env)->v_k_8,((struct cell_obj *)((env)->v_acc))->val);} else {OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_sum_to_lambda_5_env );(((struct scm_sum_to_lambda_5_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_sum_to_lambda_5,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_acc))->val,var_3);}0;__builtin_unreachable();}
+
+#line 22 "tail.scm"
+static void scm_sum_to_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_3_env *env = (struct scm_sum_to_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_lambda_4_env );(((struct scm_sum_to_lambda_4_env *)(&((var_1)->env)))->v_acc)=(((struct scm_sum_to_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_1_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_acc);(((struct scm_sum_to_lambda_4_env *)(&((var_1)->env)))->v_k_8)=(((struct scm_sum_to_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_1_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_8);(((struct scm_sum_to_lambda_4_env *)(&((var_1)->env)))->v_n_4)=(((struct scm_sum_to_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_1_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_n_4);(((struct scm_sum_to_lambda_4_env *)(&((var_1)->env)))->v_rv_25)=(((struct scm_sum_to_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_25);(((struct scm_sum_to_lambda_4_env *)(&((var_1)->env)))->v_sum_to)=(((struct scm_sum_to_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_1_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_sum_to);OBJECT_CLOSURE_ONE_NEW(var_2,scm_sum_to_lambda_4,var_1);call_closure_two(var_0,MAKE_FIXNUM(0),var_2);__builtin_unreachable();}
+
+#line 22 "tail.scm"
+static void scm_sum_to_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_2_env *env = (struct scm_sum_to_lambda_2_env *)(&((env_in)->env));((env)->v_rv_25)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,num_eq_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_sum_to_lambda_3_env );(((struct scm_sum_to_lambda_3_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_sum_to_lambda_3,var_2);call_closure_two(var_1,((struct scm_sum_to_env *)(&(((struct env_obj *)(((struct scm_sum_to_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_n_4,var_3);__builtin_unreachable();}
+
+#line 22 "tail.scm"
+static void scm_sum_to_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_sum_to_lambda_1_env *env = (struct scm_sum_to_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_lambda_2_env );(((struct scm_sum_to_lambda_2_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_sum_to_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 22 "tail.scm"
+static void scm_sum_to(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_sum_to_env *env = (struct scm_sum_to_env *)(&((env_in)->env));((env)->v_n_4)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_8)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,sub_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_sum_to_lambda_1_env );(((struct scm_sum_to_lambda_1_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_sum_to_lambda_1,var_3);call_closure_two(var_2,(env)->v_n_4,var_4);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));call_closure_two((env)->v_rv_28,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_39(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);(((struct scm_lambda_40_env *)(&((var_2)->env)))->v_rv_28)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_40,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_37(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_37_env *env = (struct scm_lambda_37_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_2)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_39,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_35(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_35_env *env = (struct scm_lambda_35_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_37_env );(((struct scm_lambda_37_env *)(&((var_2)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_37,var_2);call_closure_two(((struct cell_obj *)((env)->v_sum_to))->val,MAKE_FIXNUM(1000000),var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_38(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_38_env *env = (struct scm_lambda_38_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_36(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_36_env *env = (struct scm_lambda_36_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_38_env );(((struct scm_lambda_38_env *)(&((var_2)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_38,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));(((struct cell_obj *)((env)->v_acc))->val)=(MAKE_FIXNUM(0));GC_WRITE_BARRIER((env)->v_acc,((struct cell_obj *)((env)->v_acc))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_sum_to_env );(((struct scm_sum_to_env *)(&((var_1)->env)))->v_acc)=((env)->v_acc);(((struct scm_sum_to_env *)(&((var_1)->env)))->v_sum_to)=((env)->v_sum_to);OBJECT_CLOSURE_TWO_NEW(var_2,scm_sum_to,var_1);(((struct cell_obj *)((env)->v_sum_to))->val)=(var_2);GC_WRITE_BARRIER((env)->v_sum_to,((struct cell_obj *)((env)->v_sum_to))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_35_env );(((struct scm_lambda_35_env *)(&((var_3)->env)))->v_sum_to)=((env)->v_sum_to);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_35,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_36_env );(((struct scm_lambda_36_env *)(&((var_5)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_36,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_acc)=((env)->v_acc);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);(((struct scm_lambda_27_env *)(&((var_1)->env)))->v_sum_to)=((env)->v_sum_to);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_27,var_1);call_closure_two((struct obj *)(&(scm_lambda_26_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_is_even_p_env );(((struct scm_is_even_p_env *)(&((var_1)->env)))->v_is_odd_p)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_odd_p);OBJECT_CLOSURE_TWO_NEW(var_2,scm_is_even_p,var_1);(((struct cell_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_even_p))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_even_p,((struct cell_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_even_p))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_is_odd_p_env );(((struct scm_is_odd_p_env *)(&((var_3)->env)))->v_is_even_p)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_even_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_is_odd_p,var_3);(((struct cell_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_odd_p))->val)=(var_4);GC_WRITE_BARRIER(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_odd_p,((struct cell_obj *)(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_odd_p))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_5)->env)))->v_is_even_p)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_is_even_p);OBJECT_CLOSURE_TWO_NEW(var_6,scm_lambda_23,var_5);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_7)->env)))->v_acc)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_acc);(((struct scm_lambda_24_env *)(&((var_7)->env)))->v_k_10)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_10);(((struct scm_lambda_24_env *)(&((var_7)->env)))->v_sum_to)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_sum_to);OBJECT_CLOSURE_ONE_NEW(var_8,scm_lambda_24,var_7);call_closure_two(var_6,NULL,var_8);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_15,var_1);call_closure_two((struct obj *)(&(scm_lambda_14_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_sum_to)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_10)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_down_env );(((struct scm_count_down_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);OBJECT_CLOSURE_TWO_NEW(var_4,scm_count_down,var_3);(((struct cell_obj *)((env)->v_count_down))->val)=(var_4);GC_WRITE_BARRIER((env)->v_count_down,((struct cell_obj *)((env)->v_count_down))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_5)->env)))->v_count_down)=((env)->v_count_down);OBJECT_CLOSURE_TWO_NEW(var_6,scm_lambda_11,var_5);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_7)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_8,scm_lambda_12,var_7);call_closure_two(var_6,NULL,var_8);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_acc)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_acc)=((env)->v_acc);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_is_even_p)=((env)->v_is_even_p);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_is_odd_p)=((env)->v_is_odd_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_is_odd_p)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_is_even_p)=((env)->v_is_even_p);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_is_odd_p)=((env)->v_is_odd_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_is_even_p)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_count_down)=((env)->v_count_down);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_is_even_p)=((env)->v_is_even_p);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CELL_OBJ_NEW(var_2,var_0);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_count_down)=(var_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_2_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_2);
+
+#line 1 "tail.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_15);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_15)=((env)->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "tail.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_2)->env)))->v_k_15)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_3,var_2);call_closure_two((struct obj *)(&(scm_lambda_2_closure)),NULL,var_3);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "tail.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 289 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
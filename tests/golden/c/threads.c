@@ -0,0 +1,579 @@
+
+#include <stdlib.h>
+#include <string.h>
+#include "base.h"
+#include "builtin.h"
+#include "compiled_result.h"
+_Static_assert(sizeof(void *) * 8 == 64, "generated for x86_64-unknown-linux-gnu, with 64 bit words");
+_Static_assert(__BYTE_ORDER__ == __ORDER_LITTLE_ENDIAN__, "generated for x86_64-unknown-linux-gnu, which is little endian");
+struct scm_loop_lambda_6_env {struct obj *v_k;struct obj *v_rv_2;};
+static void scm_loop_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_5_env {struct obj *v_k;};
+static void scm_loop_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_4_env {struct obj *v_k;};
+static void scm_loop_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_3_env {struct obj *v_k;};
+static void scm_loop_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_2_env {struct obj *parent;};
+static void scm_loop_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_lambda_1_env {struct obj *parent;};
+static void scm_loop_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_env {struct obj *v_i;struct obj *v_k;struct obj *v_n;struct obj *v_name;struct obj *v_step;};
+static void scm_loop(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_9_env {struct obj *v_k_2;};
+static void scm_step_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_step_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_step_lambda_7_closure;
+struct scm_step_lambda_13_env {struct obj *v_k_3;struct obj *v_loop;};
+static void scm_step_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_12_env {struct obj *v_k_3;struct obj *v_loop;};
+static void scm_step_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_11_env {struct obj *v_i_2;struct obj *v_k_3;struct obj *v_loop;};
+static void scm_step_lambda_11(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_8_env {struct obj *v_i_2;struct obj *v_k_3;struct obj *v_loop;};
+static void scm_step_lambda_8(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_6_env {struct obj *v_i_2;struct obj *v_k_3;struct obj *v_loop;};
+static void scm_step_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_5_env {struct obj *parent;};
+static void scm_step_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_4_env {struct obj *v_i_2;struct obj *v_k_3;struct obj *v_loop;struct obj *v_rv_14;};
+static void scm_step_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_3_env {struct obj *v_i_2;struct obj *v_k_3;struct obj *v_loop;};
+static void scm_step_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_2_env {struct obj *parent;};
+static void scm_step_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_lambda_1_env {struct obj *v_i_2;struct obj *v_k_3;struct obj *v_loop;struct obj *v_rv_17;};
+static void scm_step_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_step_env {struct obj *v_i_2;struct obj *v_k_3;struct obj *v_loop;struct obj *v_name;};
+static void scm_step(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_worker_lambda_7_env {struct obj *v_loop;};
+static void scm_worker_lambda_7(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_worker_lambda_4_env {struct obj *v_loop;struct obj *v_n;struct obj *v_name;struct obj *v_step;};
+static void scm_worker_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_worker_lambda_2_env {struct obj *v_k_7;struct obj *v_loop;struct obj *v_n;struct obj *v_name;};
+static void scm_worker_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_worker_lambda_3_env {struct obj *v_k_7;};
+static void scm_worker_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_worker_lambda_1_env {struct obj *v_n;struct obj *v_name;};
+static void scm_worker_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_worker_env {struct obj *v_name;};
+static void scm_worker(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_16_env {struct obj *v_k_9;};
+static void scm_lambda_16(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_13_env {struct obj *v_k_9;};
+static void scm_lambda_13(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_11_env {struct obj *v_worker;};
+static void scm_lambda_11(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_20_env {struct obj *v_k_10;};
+static void scm_lambda_20(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_17_env {struct obj *v_k_10;};
+static void scm_lambda_17(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_14_env {struct obj *v_worker;};
+static void scm_lambda_14(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_24_env {struct obj *v_k_11;struct obj *v_rv_24;};
+static void scm_lambda_24(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_21_env {struct obj *v_k_11;};
+static void scm_lambda_21(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_18(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_18_closure;
+struct scm_lambda_25_env {struct obj *v_k_12;};
+static void scm_lambda_25(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_22(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_22_closure;
+struct scm_lambda_33_env {struct obj *v_k_13;struct obj *v_rv_27;};
+static void scm_lambda_33(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_30_env {struct obj *v_k_13;};
+static void scm_lambda_30(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_28(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_28_closure;
+struct scm_lambda_34_env {struct obj *v_k_14;};
+static void scm_lambda_34(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_31(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_31_closure;
+struct scm_loop_2_lambda_2_env {struct obj *v_bump;struct obj *v_i_3;struct obj *v_k_15;};
+static void scm_loop_2_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_2_lambda_1_env {struct obj *parent;};
+static void scm_loop_2_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_loop_2_env {struct obj *v_bump;struct obj *v_i_3;struct obj *v_k_15;struct obj *v_n_2;};
+static void scm_loop_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_6_env {struct obj *v_k_16;struct obj *v_loop_2;};
+static void scm_bump_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_5_env {struct obj *v_k_16;struct obj *v_loop_2;};
+static void scm_bump_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_4_env {struct obj *v_i_4;struct obj *v_k_16;struct obj *v_loop_2;};
+static void scm_bump_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_2_env {struct obj *parent;};
+static void scm_bump_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_lambda_1_env {struct obj *parent;};
+static void scm_bump_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_bump_env {struct obj *v_counter;struct obj *v_i_4;struct obj *v_k_16;struct obj *v_loop_2;};
+static void scm_bump(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_to_lambda_6_env {struct obj *v_loop_2;};
+static void scm_count_to_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_to_lambda_3_env {struct obj *v_bump;struct obj *v_counter;struct obj *v_loop_2;struct obj *v_n_2;};
+static void scm_count_to_lambda_3(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_to_lambda_1_env {struct obj *v_counter;struct obj *v_k_20;struct obj *v_loop_2;struct obj *v_n_2;};
+static void scm_count_to_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_to_lambda_2_env {struct obj *v_k_20;};
+static void scm_count_to_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_count_to_env {struct obj *v_counter;struct obj *v_n_2;};
+static void scm_count_to(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_41_env {struct obj *v_k_21;};
+static void scm_lambda_41(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_39_env {struct obj *v_count_to;};
+static void scm_lambda_39(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_44_env {struct obj *v_k_22;};
+static void scm_lambda_44(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_42_env {struct obj *v_count_to;};
+static void scm_lambda_42(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_68_env {struct obj *v_k_23;};
+static void scm_lambda_68(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_64_env {struct obj *v_k_23;};
+static void scm_lambda_64(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_59_env {struct obj *v_k_23;struct obj *v_rv_42;};
+static void scm_lambda_59(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_54_env {struct obj *v_k_23;};
+static void scm_lambda_54(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_50(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_50_closure;
+struct scm_lambda_65_env {struct obj *v_k_24;struct obj *v_rv_44;};
+static void scm_lambda_65(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_60_env {struct obj *v_k_24;};
+static void scm_lambda_60(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_55(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_55_closure;
+struct scm_lambda_61_env {struct obj *v_k_25;};
+static void scm_lambda_61(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_56_env {struct obj *v_k_25;};
+static void scm_lambda_56(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_51_env {struct obj *v_k_25;};
+static void scm_lambda_51(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_47(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_47_closure;
+struct scm_report_lambda_6_env {struct obj *v_k_26;};
+static void scm_report_lambda_6(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_report_lambda_5_env {struct obj *v_k_26;};
+static void scm_report_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_report_lambda_4_env {struct obj *v_k_26;struct obj *v_rv_51;};
+static void scm_report_lambda_4(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_report_lambda_3_env {struct obj *v_k_26;};
+static void scm_report_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_report_lambda_2_env {struct obj *v_counter;struct obj *v_k_26;struct obj *v_report_later;};
+static void scm_report_lambda_2(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_report_lambda_1_env {struct obj *v_counter;struct obj *v_k_26;struct obj *v_report_later;};
+static void scm_report_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_report_env {struct obj *v_counter;struct obj *v_report_later;};
+static void scm_report(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_report_later_lambda_1_env {struct obj *v_k_27;struct obj *v_report;};
+static void scm_report_later_lambda_1(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_report_later_env {struct obj *v_report;};
+static void scm_report_later(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_69_env {struct obj *v_k_28;struct obj *v_rv_56;};
+static void scm_lambda_69(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_66_env {struct obj *v_k_28;};
+static void scm_lambda_66(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_62(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_62_closure;
+struct scm_lambda_67_env {struct obj *v_k_29;};
+static void scm_lambda_67(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_63_env {struct obj *v_k_29;};
+static void scm_lambda_63(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_58_env {struct obj *v_k_29;};
+static void scm_lambda_58(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_46_env {struct obj *parent;};
+static void scm_lambda_46(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_43_env {struct obj *parent;};
+static void scm_lambda_43(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_40_env {struct obj *v_count_to;struct obj *v_counter;struct obj *v_k_29;struct obj *v_report;struct obj *v_report_later;};
+static void scm_lambda_40(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_32_env {struct obj *parent;};
+static void scm_lambda_32(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_29_env {struct obj *parent;};
+static void scm_lambda_29(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_27_env {struct obj *parent;};
+static void scm_lambda_27(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_23_env {struct obj *v_count_to;struct obj *v_counter;struct obj *v_k_29;struct obj *v_report;struct obj *v_report_later;};
+static void scm_lambda_23(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_19_env {struct obj *parent;};
+static void scm_lambda_19(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_15_env {struct obj *parent;};
+static void scm_lambda_15(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_12_env {struct obj *parent;};
+static void scm_lambda_12(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_10_env {struct obj *v_count_to;struct obj *v_counter;struct obj *v_k_29;struct obj *v_report;struct obj *v_report_later;struct obj *v_worker;};
+static void scm_lambda_10(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_8_env {struct obj *v_count_to;struct obj *v_counter;struct obj *v_report;struct obj *v_worker;};
+static void scm_lambda_8(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_6_env {struct obj *v_count_to;struct obj *v_counter;struct obj *v_worker;};
+static void scm_lambda_6(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_4_env {struct obj *v_counter;struct obj *v_worker;};
+static void scm_lambda_4(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_2_env {struct obj *v_worker;};
+static void scm_lambda_2(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_9_env {struct obj *v_k_34;};
+static void scm_lambda_9(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_7_env {struct obj *v_k_34;};
+static void scm_lambda_7(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_5_env {struct obj *v_k_34;};
+static void scm_lambda_5(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+struct scm_lambda_3_env {struct obj *v_k_34;};
+static void scm_lambda_3(struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static void scm_lambda_1(struct obj *, struct obj *, struct env_obj *)__attribute__((noreturn)) ;
+static struct closure_obj scm_lambda_1_closure;
+static struct symbol_obj *scheme_symbols[] = {NULL};
+static struct lambda_info scheme_lambdas[] = {{(void *)(scm_loop_lambda_6),"scm_loop_lambda_6","threads.scm",2},{(void *)(scm_loop_lambda_5),"scm_loop_lambda_5","threads.scm",2},{(void *)(scm_loop_lambda_4),"scm_loop_lambda_4","threads.scm",2},{(void *)(scm_loop_lambda_3),"scm_loop_lambda_3","threads.scm",2},{(void *)(scm_loop_lambda_2),"scm_loop_lambda_2","threads.scm",2},{(void *)(scm_loop_lambda_1),"scm_loop_lambda_1","threads.scm",2},{(void *)(scm_loop),"scm_loop","threads.scm",2},{(void *)(scm_step_lambda_9),"scm_step_lambda_9","threads.scm",6},{(void *)(scm_step_lambda_7),"scm_step_lambda_7","threads.scm",6},{(void *)(scm_step_lambda_13),"scm_step_lambda_13","threads.scm",6},{(void *)(scm_step_lambda_12),"scm_step_lambda_12","threads.scm",6},{(void *)(scm_step_lambda_11),"scm_step_lambda_11","threads.scm",6},{(void *)(scm_step_lambda_8),"scm_step_lambda_8","threads.scm",6},{(void *)(scm_step_lambda_6),"scm_step_lambda_6","threads.scm",6},{(void *)(scm_step_lambda_5),"scm_step_lambda_5","threads.scm",6},{(void *)(scm_step_lambda_4),"scm_step_lambda_4","threads.scm",6},{(void *)(scm_step_lambda_3),"scm_step_lambda_3","threads.scm",6},{(void *)(scm_step_lambda_2),"scm_step_lambda_2","threads.scm",6},{(void *)(scm_step_lambda_1),"scm_step_lambda_1","threads.scm",6},{(void *)(scm_step),"scm_step","threads.scm",6},{(void *)(scm_worker_lambda_7),"scm_worker_lambda_7","threads.scm",10},{(void *)(scm_worker_lambda_4),"scm_worker_lambda_4","threads.scm",1},{(void *)(scm_worker_lambda_2),"scm_worker_lambda_2","threads.scm",1},{(void *)(scm_worker_lambda_3),"scm_worker_lambda_3","threads.scm",1},{(void *)(scm_worker_lambda_1),"scm_worker_lambda_1","threads.scm",1},{(void *)(scm_worker),"scm_worker","threads.scm",1},{(void *)(scm_lambda_16),"scm_lambda_16","threads.scm",1},{(void *)(scm_lambda_13),"scm_lambda_13","threads.scm",1},{(void *)(scm_lambda_11),"scm_lambda_11","threads.scm",1},{(void *)(scm_lambda_20),"scm_lambda_20","threads.scm",1},{(void *)(scm_lambda_17),"scm_lambda_17","threads.scm",1},{(void *)(scm_lambda_14),"scm_lambda_14","threads.scm",1},{(void *)(scm_lambda_24),"scm_lambda_24","threads.scm",1},{(void *)(scm_lambda_21),"scm_lambda_21","threads.scm",1},{(void *)(scm_lambda_18),"scm_lambda_18","threads.scm",1},{(void *)(scm_lambda_25),"scm_lambda_25","threads.scm",1},{(void *)(scm_lambda_22),"scm_lambda_22","threads.scm",1},{(void *)(scm_lambda_33),"scm_lambda_33","threads.scm",1},{(void *)(scm_lambda_30),"scm_lambda_30","threads.scm",1},{(void *)(scm_lambda_28),"scm_lambda_28","threads.scm",1},{(void *)(scm_lambda_34),"scm_lambda_34","threads.scm",1},{(void *)(scm_lambda_31),"scm_lambda_31","threads.scm",1},{(void *)(scm_loop_2_lambda_2),"scm_loop_2_lambda_2","threads.scm",18},{(void *)(scm_loop_2_lambda_1),"scm_loop_2_lambda_1","threads.scm",18},{(void *)(scm_loop_2),"scm_loop_2","threads.scm",18},{(void *)(scm_bump_lambda_6),"scm_bump_lambda_6","threads.scm",20},{(void *)(scm_bump_lambda_5),"scm_bump_lambda_5","threads.scm",20},{(void *)(scm_bump_lambda_4),"scm_bump_lambda_4","threads.scm",20},{(void *)(scm_bump_lambda_2),"scm_bump_lambda_2","threads.scm",20},{(void *)(scm_bump_lambda_1),"scm_bump_lambda_1","threads.scm",20},{(void *)(scm_bump),"scm_bump","threads.scm",20},{(void *)(scm_count_to_lambda_6),"scm_count_to_lambda_6","threads.scm",24},{(void *)(scm_count_to_lambda_3),"scm_count_to_lambda_3","threads.scm",17},{(void *)(scm_count_to_lambda_1),"scm_count_to_lambda_1","threads.scm",17},{(void *)(scm_count_to_lambda_2),"scm_count_to_lambda_2","threads.scm",17},{(void *)(scm_count_to),"scm_count_to","threads.scm",17},{(void *)(scm_lambda_41),"scm_lambda_41","threads.scm",1},{(void *)(scm_lambda_39),"scm_lambda_39","threads.scm",1},{(void *)(scm_lambda_44),"scm_lambda_44","threads.scm",1},{(void *)(scm_lambda_42),"scm_lambda_42","threads.scm",1},{(void *)(scm_lambda_68),"scm_lambda_68","threads.scm",28},{(void *)(scm_lambda_64),"scm_lambda_64","threads.scm",28},{(void *)(scm_lambda_59),"scm_lambda_59","threads.scm",28},{(void *)(scm_lambda_54),"scm_lambda_54","threads.scm",28},{(void *)(scm_lambda_50),"scm_lambda_50","threads.scm",28},{(void *)(scm_lambda_65),"scm_lambda_65","threads.scm",27},{(void *)(scm_lambda_60),"scm_lambda_60","threads.scm",27},{(void *)(scm_lambda_55),"scm_lambda_55","threads.scm",27},{(void *)(scm_lambda_61),"scm_lambda_61","threads.scm",27},{(void *)(scm_lambda_56),"scm_lambda_56","threads.scm",27},{(void *)(scm_lambda_51),"scm_lambda_51","threads.scm",27},{(void *)(scm_lambda_47),"scm_lambda_47","threads.scm",27},{(void *)(scm_report_lambda_6),"scm_report_lambda_6","threads.scm",30},{(void *)(scm_report_lambda_5),"scm_report_lambda_5","threads.scm",30},{(void *)(scm_report_lambda_4),"scm_report_lambda_4","threads.scm",30},{(void *)(scm_report_lambda_3),"scm_report_lambda_3","threads.scm",30},{(void *)(scm_report_lambda_2),"scm_report_lambda_2","threads.scm",30},{(void *)(scm_report_lambda_1),"scm_report_lambda_1","threads.scm",30},{(void *)(scm_report),"scm_report","threads.scm",30},{(void *)(scm_report_later_lambda_1),"scm_report_later_lambda_1","threads.scm",32},{(void *)(scm_report_later),"scm_report_later","threads.scm",32},{(void *)(scm_lambda_69),"scm_lambda_69","threads.scm",1},{(void *)(scm_lambda_66),"scm_lambda_66","threads.scm",1},{(void *)(scm_lambda_62),"scm_lambda_62","threads.scm",1},{(void *)(scm_lambda_67),"scm_lambda_67","threads.scm",1},{(void *)(scm_lambda_63),"scm_lambda_63","threads.scm",1},{(void *)(scm_lambda_58),"scm_lambda_58","threads.scm",1},{(void *)(scm_lambda_46),"scm_lambda_46","threads.scm",1},{(void *)(scm_lambda_43),"scm_lambda_43","threads.scm",1},{(void *)(scm_lambda_40),"scm_lambda_40","threads.scm",1},{(void *)(scm_lambda_32),"scm_lambda_32","threads.scm",1},{(void *)(scm_lambda_29),"scm_lambda_29","threads.scm",1},{(void *)(scm_lambda_27),"scm_lambda_27","threads.scm",1},{(void *)(scm_lambda_23),"scm_lambda_23","threads.scm",1},{(void *)(scm_lambda_19),"scm_lambda_19","threads.scm",1},{(void *)(scm_lambda_15),"scm_lambda_15","threads.scm",1},{(void *)(scm_lambda_12),"scm_lambda_12","threads.scm",1},{(void *)(scm_lambda_10),"scm_lambda_10","threads.scm",1},{(void *)(scm_lambda_8),"scm_lambda_8","threads.scm",1},{(void *)(scm_lambda_6),"scm_lambda_6","threads.scm",1},{(void *)(scm_lambda_4),"scm_lambda_4","threads.scm",1},{(void *)(scm_lambda_2),"scm_lambda_2","threads.scm",1},{(void *)(scm_lambda_9),"scm_lambda_9","threads.scm",1},{(void *)(scm_lambda_7),"scm_lambda_7","threads.scm",1},{(void *)(scm_lambda_5),"scm_lambda_5","threads.scm",1},{(void *)(scm_lambda_3),"scm_lambda_3","threads.scm",1},{(void *)(scm_lambda_1),"scm_lambda_1","threads.scm",1},{NULL}};
+
+#line 2 "threads.scm"
+static void scm_loop_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_6_env *env = (struct scm_loop_lambda_6_env *)(&((env_in)->env));call_closure_two((env)->v_rv_2,var_0,(env)->v_k);__builtin_unreachable();}
+
+#line 2 "threads.scm"
+static void scm_loop_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_5_env *env = (struct scm_loop_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_6_env );(((struct scm_loop_lambda_6_env *)(&((var_2)->env)))->v_k)=((env)->v_k);(((struct scm_loop_lambda_6_env *)(&((var_2)->env)))->v_rv_2)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_6,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 2 "threads.scm"
+static void scm_loop_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_4_env *env = (struct scm_loop_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_5_env );(((struct scm_loop_lambda_5_env *)(&((var_2)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_5,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 2 "threads.scm"
+static void scm_loop_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_3_env *env = (struct scm_loop_lambda_3_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_1," done\012");OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_4_env );(((struct scm_loop_lambda_4_env *)(&((var_2)->env)))->v_k)=((env)->v_k);OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_4,var_2);call_closure_two(var_0,var_1,var_3);__builtin_unreachable();}
+
+#line 2 "threads.scm"
+static void scm_loop_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_2_env *env = (struct scm_loop_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_two(((struct cell_obj *)(((struct scm_loop_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_step))->val,((struct scm_loop_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_i,((struct scm_loop_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k);} else {OBJECT_CLOSURE_TWO_NEW(var_1,string_append_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_loop_lambda_3_env );(((struct scm_loop_lambda_3_env *)(&((var_2)->env)))->v_k)=(((struct scm_loop_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k);OBJECT_CLOSURE_ONE_NEW(var_3,scm_loop_lambda_3,var_2);call_closure_two(var_1,((struct scm_loop_env *)(&(((struct env_obj *)(((struct scm_loop_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_name,var_3);}0;__builtin_unreachable();}
+
+#line 2 "threads.scm"
+static void scm_loop_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_loop_lambda_1_env *env = (struct scm_loop_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_lambda_2_env );(((struct scm_loop_lambda_2_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_lambda_2,var_1);call_closure_two(var_0,((struct scm_loop_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_n,var_2);__builtin_unreachable();}
+
+#line 2 "threads.scm"
+static void scm_loop(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_loop_env *env = (struct scm_loop_env *)(&((env_in)->env));((env)->v_i)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,lt_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_loop_lambda_1_env );(((struct scm_loop_lambda_1_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_loop_lambda_1,var_3);call_closure_two(var_2,(env)->v_i,var_4);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_9_env *env = (struct scm_step_lambda_9_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_2);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_step_lambda_9_env );(((struct scm_step_lambda_9_env *)(&((var_3)->env)))->v_k_2)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_step_lambda_9,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_step_lambda_7_closure = CLOSURE_TWO_OBJ_INIT(scm_step_lambda_7);
+
+#line 6 "threads.scm"
+static void scm_step_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_13_env *env = (struct scm_step_lambda_13_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_loop))->val,var_0,(env)->v_k_3);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_12_env *env = (struct scm_step_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_step_lambda_13_env );(((struct scm_step_lambda_13_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_step_lambda_13_env *)(&((var_1)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_ONE_NEW(var_2,scm_step_lambda_13,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_11(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_11_env *env = (struct scm_step_lambda_11_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_step_lambda_12_env );(((struct scm_step_lambda_12_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_step_lambda_12_env *)(&((var_2)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_ONE_NEW(var_3,scm_step_lambda_12,var_2);call_closure_two(var_1,(env)->v_i_2,var_3);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_8(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_8_env *env = (struct scm_step_lambda_8_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_step_lambda_11_env );(((struct scm_step_lambda_11_env *)(&((var_2)->env)))->v_i_2)=((env)->v_i_2);(((struct scm_step_lambda_11_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_step_lambda_11_env *)(&((var_2)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_ONE_NEW(var_3,scm_step_lambda_11,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_6_env *env = (struct scm_step_lambda_6_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_step_lambda_8_env );(((struct scm_step_lambda_8_env *)(&((var_1)->env)))->v_i_2)=((env)->v_i_2);(((struct scm_step_lambda_8_env *)(&((var_1)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_step_lambda_8_env *)(&((var_1)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_ONE_NEW(var_2,scm_step_lambda_8,var_1);call_closure_two((struct obj *)(&(scm_step_lambda_7_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_5_env *env = (struct scm_step_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_step_lambda_6_env );(((struct scm_step_lambda_6_env *)(&((var_1)->env)))->v_i_2)=(((struct scm_step_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_i_2);(((struct scm_step_lambda_6_env *)(&((var_1)->env)))->v_k_3)=(((struct scm_step_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_3);(((struct scm_step_lambda_6_env *)(&((var_1)->env)))->v_loop)=(((struct scm_step_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_loop);OBJECT_CLOSURE_ONE_NEW(var_2,scm_step_lambda_6,var_1);call_closure_two(((struct scm_step_lambda_4_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_14,var_0,var_2);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_4_env *env = (struct scm_step_lambda_4_env *)(&((env_in)->env));((env)->v_rv_14)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_step_lambda_5_env );(((struct scm_step_lambda_5_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_step_lambda_5,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_3_env *env = (struct scm_step_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_step_lambda_4_env );(((struct scm_step_lambda_4_env *)(&((var_2)->env)))->v_i_2)=((env)->v_i_2);(((struct scm_step_lambda_4_env *)(&((var_2)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_step_lambda_4_env *)(&((var_2)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_ONE_NEW(var_3,scm_step_lambda_4,var_2);call_closure_two(var_1,var_0,var_3);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_2_env *env = (struct scm_step_lambda_2_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_step_lambda_3_env );(((struct scm_step_lambda_3_env *)(&((var_1)->env)))->v_i_2)=(((struct scm_step_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_i_2);(((struct scm_step_lambda_3_env *)(&((var_1)->env)))->v_k_3)=(((struct scm_step_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_3);(((struct scm_step_lambda_3_env *)(&((var_1)->env)))->v_loop)=(((struct scm_step_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_loop);OBJECT_CLOSURE_ONE_NEW(var_2,scm_step_lambda_3,var_1);call_closure_two(((struct scm_step_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_rv_17,var_0,var_2);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_step_lambda_1_env *env = (struct scm_step_lambda_1_env *)(&((env_in)->env));((env)->v_rv_17)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_CLOSURE_TWO_NEW(var_1,number_to_string_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_step_lambda_2_env );(((struct scm_step_lambda_2_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_step_lambda_2,var_2);call_closure_two(var_1,(env)->v_i_2,var_3);__builtin_unreachable();}
+
+#line 6 "threads.scm"
+static void scm_step(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_step_env *env = (struct scm_step_env *)(&((env_in)->env));((env)->v_i_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_3)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,string_append_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_step_lambda_1_env );(((struct scm_step_lambda_1_env *)(&((var_3)->env)))->v_i_2)=((env)->v_i_2);(((struct scm_step_lambda_1_env *)(&((var_3)->env)))->v_k_3)=((env)->v_k_3);(((struct scm_step_lambda_1_env *)(&((var_3)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_ONE_NEW(var_4,scm_step_lambda_1,var_3);call_closure_two(var_2,(env)->v_name,var_4);__builtin_unreachable();}
+
+#line 10 "threads.scm"
+static void scm_worker_lambda_7(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_worker_lambda_7_env *env = (struct scm_worker_lambda_7_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_loop))->val,MAKE_FIXNUM(0),var_1);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_worker_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_worker_lambda_4_env *env = (struct scm_worker_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_step)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_loop_env );(((struct scm_loop_env *)(&((var_3)->env)))->v_n)=((env)->v_n);(((struct scm_loop_env *)(&((var_3)->env)))->v_name)=((env)->v_name);(((struct scm_loop_env *)(&((var_3)->env)))->v_step)=((env)->v_step);OBJECT_CLOSURE_TWO_NEW(var_4,scm_loop,var_3);(((struct cell_obj *)((env)->v_loop))->val)=(var_4);GC_WRITE_BARRIER((env)->v_loop,((struct cell_obj *)((env)->v_loop))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_step_env );(((struct scm_step_env *)(&((var_5)->env)))->v_loop)=((env)->v_loop);(((struct scm_step_env *)(&((var_5)->env)))->v_name)=((env)->v_name);OBJECT_CLOSURE_TWO_NEW(var_6,scm_step,var_5);(((struct cell_obj *)((env)->v_step))->val)=(var_6);GC_WRITE_BARRIER((env)->v_step,((struct cell_obj *)((env)->v_step))->val);OBJECT_ENV_OBJ_NEW(var_7,struct scm_worker_lambda_7_env );(((struct scm_worker_lambda_7_env *)(&((var_7)->env)))->v_loop)=((env)->v_loop);OBJECT_CLOSURE_TWO_NEW(var_8,scm_worker_lambda_7,var_7);call_closure_one(var_1,var_8);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_worker_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_worker_lambda_2_env *env = (struct scm_worker_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_loop)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_worker_lambda_4_env );(((struct scm_worker_lambda_4_env *)(&((var_3)->env)))->v_loop)=((env)->v_loop);(((struct scm_worker_lambda_4_env *)(&((var_3)->env)))->v_n)=((env)->v_n);(((struct scm_worker_lambda_4_env *)(&((var_3)->env)))->v_name)=((env)->v_name);OBJECT_CLOSURE_TWO_NEW(var_4,scm_worker_lambda_4,var_3);call_closure_two(var_4,NULL,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_worker_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_worker_lambda_3_env *env = (struct scm_worker_lambda_3_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_7);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_worker_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_worker_lambda_1_env *env = (struct scm_worker_lambda_1_env *)(&((env_in)->env));((env)->v_n)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_worker_lambda_2_env );(((struct scm_worker_lambda_2_env *)(&((var_2)->env)))->v_k_7)=(var_1);(((struct scm_worker_lambda_2_env *)(&((var_2)->env)))->v_n)=((env)->v_n);(((struct scm_worker_lambda_2_env *)(&((var_2)->env)))->v_name)=((env)->v_name);OBJECT_CLOSURE_TWO_NEW(var_3,scm_worker_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_worker_lambda_3_env );(((struct scm_worker_lambda_3_env *)(&((var_4)->env)))->v_k_7)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_worker_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_worker(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_worker_env *env = (struct scm_worker_env *)(&((env_in)->env));((env)->v_name)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_worker_lambda_1_env );(((struct scm_worker_lambda_1_env *)(&((var_2)->env)))->v_name)=((env)->v_name);OBJECT_CLOSURE_TWO_NEW(var_3,scm_worker_lambda_1,var_2);call_closure_one(var_1,var_3);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_16(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_16_env *env = (struct scm_lambda_16_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,spawn_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_9);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_13(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_13_env *env = (struct scm_lambda_13_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_16_env );(((struct scm_lambda_16_env *)(&((var_1)->env)))->v_k_9)=((env)->v_k_9);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_16,var_1);call_closure_two(var_0,MAKE_FIXNUM(3),var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_11(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_11_env *env = (struct scm_lambda_11_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_2,"a");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_13_env );(((struct scm_lambda_13_env *)(&((var_3)->env)))->v_k_9)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_13,var_3);call_closure_two(((struct cell_obj *)((env)->v_worker))->val,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_20(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_20_env *env = (struct scm_lambda_20_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,spawn_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_10);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_17(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_17_env *env = (struct scm_lambda_17_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_20_env );(((struct scm_lambda_20_env *)(&((var_1)->env)))->v_k_10)=((env)->v_k_10);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_20,var_1);call_closure_two(var_0,MAKE_FIXNUM(2),var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_14(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_14_env *env = (struct scm_lambda_14_env *)(&((env_in)->env));OBJECT_STRING_OBJ_NEW(var_2,"b");OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_17_env );(((struct scm_lambda_17_env *)(&((var_3)->env)))->v_k_10)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_17,var_3);call_closure_two(((struct cell_obj *)((env)->v_worker))->val,var_2,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_24(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_24_env *env = (struct scm_lambda_24_env *)(&((env_in)->env));call_closure_two((env)->v_rv_24,var_0,(env)->v_k_11);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_21(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_21_env *env = (struct scm_lambda_21_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_24_env );(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_k_11)=((env)->v_k_11);(((struct scm_lambda_24_env *)(&((var_2)->env)))->v_rv_24)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_24,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_18(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"main");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_21_env );(((struct scm_lambda_21_env *)(&((var_4)->env)))->v_k_11)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_21,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_18_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_18);
+
+#line 1 "threads.scm"
+static void scm_lambda_25(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_25_env *env = (struct scm_lambda_25_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_12);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_22(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_25_env );(((struct scm_lambda_25_env *)(&((var_3)->env)))->v_k_12)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_25,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_22_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_22);
+
+#line 1 "threads.scm"
+static void scm_lambda_33(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_33_env *env = (struct scm_lambda_33_env *)(&((env_in)->env));call_closure_two((env)->v_rv_27,var_0,(env)->v_k_13);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_30(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_30_env *env = (struct scm_lambda_30_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_33_env );(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_k_13)=((env)->v_k_13);(((struct scm_lambda_33_env *)(&((var_2)->env)))->v_rv_27)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_33,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_28(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"main again");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_30_env );(((struct scm_lambda_30_env *)(&((var_4)->env)))->v_k_13)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_30,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_28_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_28);
+
+#line 1 "threads.scm"
+static void scm_lambda_34(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_34_env *env = (struct scm_lambda_34_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_14);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_31(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_34_env );(((struct scm_lambda_34_env *)(&((var_3)->env)))->v_k_14)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_34,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_31_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_31);
+
+#line 18 "threads.scm"
+static void scm_loop_2_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_loop_2_lambda_2_env *env = (struct scm_loop_2_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_two(((struct cell_obj *)((env)->v_bump))->val,(env)->v_i_3,(env)->v_k_15);} else {call_closure_one((env)->v_k_15,(env)->v_i_3);}0;__builtin_unreachable();}
+
+#line 18 "threads.scm"
+static void scm_loop_2_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_loop_2_lambda_1_env *env = (struct scm_loop_2_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_loop_2_lambda_2_env );(((struct scm_loop_2_lambda_2_env *)(&((var_1)->env)))->v_bump)=(((struct scm_loop_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_bump);(((struct scm_loop_2_lambda_2_env *)(&((var_1)->env)))->v_i_3)=(((struct scm_loop_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_i_3);(((struct scm_loop_2_lambda_2_env *)(&((var_1)->env)))->v_k_15)=(((struct scm_loop_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_k_15);OBJECT_CLOSURE_ONE_NEW(var_2,scm_loop_2_lambda_2,var_1);call_closure_two(var_0,((struct scm_loop_2_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_n_2,var_2);__builtin_unreachable();}
+
+#line 18 "threads.scm"
+static void scm_loop_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_loop_2_env *env = (struct scm_loop_2_env *)(&((env_in)->env));((env)->v_i_3)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_15)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,lt_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_loop_2_lambda_1_env );(((struct scm_loop_2_lambda_1_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_loop_2_lambda_1,var_3);call_closure_two(var_2,(env)->v_i_3,var_4);__builtin_unreachable();}
+
+#line 20 "threads.scm"
+static void scm_bump_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_6_env *env = (struct scm_bump_lambda_6_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_loop_2))->val,var_0,(env)->v_k_16);__builtin_unreachable();}
+
+#line 20 "threads.scm"
+static void scm_bump_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_5_env *env = (struct scm_bump_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_bump_lambda_6_env );(((struct scm_bump_lambda_6_env *)(&((var_1)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_bump_lambda_6_env *)(&((var_1)->env)))->v_loop_2)=((env)->v_loop_2);OBJECT_CLOSURE_ONE_NEW(var_2,scm_bump_lambda_6,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 20 "threads.scm"
+static void scm_bump_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_4_env *env = (struct scm_bump_lambda_4_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_bump_lambda_5_env );(((struct scm_bump_lambda_5_env *)(&((var_2)->env)))->v_k_16)=((env)->v_k_16);(((struct scm_bump_lambda_5_env *)(&((var_2)->env)))->v_loop_2)=((env)->v_loop_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_bump_lambda_5,var_2);call_closure_two(var_1,(env)->v_i_4,var_3);__builtin_unreachable();}
+
+#line 20 "threads.scm"
+static void scm_bump_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_2_env *env = (struct scm_bump_lambda_2_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter))->val)=(var_0);GC_WRITE_BARRIER(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter,((struct cell_obj *)(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter))->val);OBJECT_CLOSURE_TWO_NEW(var_1,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_bump_lambda_4_env );(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_i_4)=(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_i_4);(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_k_16)=(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_16);(((struct scm_bump_lambda_4_env *)(&((var_2)->env)))->v_loop_2)=(((struct scm_bump_env *)(&(((struct env_obj *)(((struct scm_bump_lambda_1_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_loop_2);OBJECT_CLOSURE_ONE_NEW(var_3,scm_bump_lambda_4,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 20 "threads.scm"
+static void scm_bump_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_bump_lambda_1_env *env = (struct scm_bump_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_bump_lambda_2_env );(((struct scm_bump_lambda_2_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_bump_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(1),var_2);__builtin_unreachable();}
+
+#line 20 "threads.scm"
+static void scm_bump(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_bump_env *env = (struct scm_bump_env *)(&((env_in)->env));((env)->v_i_4)=(var_0);GC_WRITE_BARRIER(env_in,var_0);((env)->v_k_16)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_CLOSURE_TWO_NEW(var_2,add_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_bump_lambda_1_env );(((struct scm_bump_lambda_1_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_bump_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_counter))->val,var_4);__builtin_unreachable();}
+
+#line 24 "threads.scm"
+static void scm_count_to_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_to_lambda_6_env *env = (struct scm_count_to_lambda_6_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_loop_2))->val,MAKE_FIXNUM(0),var_1);__builtin_unreachable();}
+
+#line 17 "threads.scm"
+static void scm_count_to_lambda_3(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_to_lambda_3_env *env = (struct scm_count_to_lambda_3_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_bump)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_loop_2_env );(((struct scm_loop_2_env *)(&((var_3)->env)))->v_bump)=((env)->v_bump);(((struct scm_loop_2_env *)(&((var_3)->env)))->v_n_2)=((env)->v_n_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_loop_2,var_3);(((struct cell_obj *)((env)->v_loop_2))->val)=(var_4);GC_WRITE_BARRIER((env)->v_loop_2,((struct cell_obj *)((env)->v_loop_2))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_bump_env );(((struct scm_bump_env *)(&((var_5)->env)))->v_counter)=((env)->v_counter);(((struct scm_bump_env *)(&((var_5)->env)))->v_loop_2)=((env)->v_loop_2);OBJECT_CLOSURE_TWO_NEW(var_6,scm_bump,var_5);(((struct cell_obj *)((env)->v_bump))->val)=(var_6);GC_WRITE_BARRIER((env)->v_bump,((struct cell_obj *)((env)->v_bump))->val);OBJECT_ENV_OBJ_NEW(var_7,struct scm_count_to_lambda_6_env );(((struct scm_count_to_lambda_6_env *)(&((var_7)->env)))->v_loop_2)=((env)->v_loop_2);OBJECT_CLOSURE_TWO_NEW(var_8,scm_count_to_lambda_6,var_7);call_closure_one(var_1,var_8);__builtin_unreachable();}
+
+#line 17 "threads.scm"
+static void scm_count_to_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_to_lambda_1_env *env = (struct scm_count_to_lambda_1_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_loop_2)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_count_to_lambda_3_env );(((struct scm_count_to_lambda_3_env *)(&((var_3)->env)))->v_counter)=((env)->v_counter);(((struct scm_count_to_lambda_3_env *)(&((var_3)->env)))->v_loop_2)=((env)->v_loop_2);(((struct scm_count_to_lambda_3_env *)(&((var_3)->env)))->v_n_2)=((env)->v_n_2);OBJECT_CLOSURE_TWO_NEW(var_4,scm_count_to_lambda_3,var_3);call_closure_two(var_4,NULL,(env)->v_k_20);__builtin_unreachable();}
+
+#line 17 "threads.scm"
+static void scm_count_to_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_count_to_lambda_2_env *env = (struct scm_count_to_lambda_2_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_20);__builtin_unreachable();}
+
+#line 17 "threads.scm"
+static void scm_count_to(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_count_to_env *env = (struct scm_count_to_env *)(&((env_in)->env));((env)->v_n_2)=(var_0);GC_WRITE_BARRIER(env_in,var_0);OBJECT_ENV_OBJ_NEW(var_2,struct scm_count_to_lambda_1_env );(((struct scm_count_to_lambda_1_env *)(&((var_2)->env)))->v_counter)=((env)->v_counter);(((struct scm_count_to_lambda_1_env *)(&((var_2)->env)))->v_k_20)=(var_1);(((struct scm_count_to_lambda_1_env *)(&((var_2)->env)))->v_n_2)=((env)->v_n_2);OBJECT_CLOSURE_TWO_NEW(var_3,scm_count_to_lambda_1,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_count_to_lambda_2_env );(((struct scm_count_to_lambda_2_env *)(&((var_4)->env)))->v_k_20)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_count_to_lambda_2,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_41(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_41_env *env = (struct scm_lambda_41_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,spawn_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_21);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_39(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_39_env *env = (struct scm_lambda_39_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_41_env );(((struct scm_lambda_41_env *)(&((var_2)->env)))->v_k_21)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_41,var_2);call_closure_two(((struct cell_obj *)((env)->v_count_to))->val,MAKE_FIXNUM(10000),var_3);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_44(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_44_env *env = (struct scm_lambda_44_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,spawn_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_22);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_42(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_42_env *env = (struct scm_lambda_42_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_44_env );(((struct scm_lambda_44_env *)(&((var_2)->env)))->v_k_22)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_44,var_2);call_closure_two(((struct cell_obj *)((env)->v_count_to))->val,MAKE_FIXNUM(10000),var_3);__builtin_unreachable();}
+
+#line 28 "threads.scm"
+static void scm_lambda_68(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_68_env *env = (struct scm_lambda_68_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_23);__builtin_unreachable();}
+
+#line 28 "threads.scm"
+static void scm_lambda_64(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_64_env *env = (struct scm_lambda_64_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_68_env );(((struct scm_lambda_68_env *)(&((var_2)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_68,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 28 "threads.scm"
+static void scm_lambda_59(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_59_env *env = (struct scm_lambda_59_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_64_env );(((struct scm_lambda_64_env *)(&((var_1)->env)))->v_k_23)=((env)->v_k_23);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_64,var_1);call_closure_two((env)->v_rv_42,var_0,var_2);__builtin_unreachable();}
+
+#line 28 "threads.scm"
+static void scm_lambda_54(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_54_env *env = (struct scm_lambda_54_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_59_env );(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_k_23)=((env)->v_k_23);(((struct scm_lambda_59_env *)(&((var_2)->env)))->v_rv_42)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_59,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 28 "threads.scm"
+static void scm_lambda_50(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"spawned by a thread");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_54_env );(((struct scm_lambda_54_env *)(&((var_4)->env)))->v_k_23)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_54,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_50_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_50);
+
+#line 27 "threads.scm"
+static void scm_lambda_65(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_65_env *env = (struct scm_lambda_65_env *)(&((env_in)->env));call_closure_two((env)->v_rv_44,var_0,(env)->v_k_24);__builtin_unreachable();}
+
+#line 27 "threads.scm"
+static void scm_lambda_60(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_60_env *env = (struct scm_lambda_60_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_65_env );(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_k_24)=((env)->v_k_24);(((struct scm_lambda_65_env *)(&((var_2)->env)))->v_rv_44)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_65,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 27 "threads.scm"
+static void scm_lambda_55(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"spawner");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_60_env );(((struct scm_lambda_60_env *)(&((var_4)->env)))->v_k_24)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_60,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_55_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_55);
+
+#line 27 "threads.scm"
+static void scm_lambda_61(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_61_env *env = (struct scm_lambda_61_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_25);__builtin_unreachable();}
+
+#line 27 "threads.scm"
+static void scm_lambda_56(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_56_env *env = (struct scm_lambda_56_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_61_env );(((struct scm_lambda_61_env *)(&((var_2)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_61,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 27 "threads.scm"
+static void scm_lambda_51(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_51_env *env = (struct scm_lambda_51_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_56_env );(((struct scm_lambda_56_env *)(&((var_1)->env)))->v_k_25)=((env)->v_k_25);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_56,var_1);call_closure_two((struct obj *)(&(scm_lambda_55_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 27 "threads.scm"
+static void scm_lambda_47(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,spawn_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_51_env );(((struct scm_lambda_51_env *)(&((var_3)->env)))->v_k_25)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_51,var_3);call_closure_two(var_2,(struct obj *)(&(scm_lambda_50_closure)),var_4);__builtin_unreachable();}
+static struct closure_obj scm_lambda_47_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_47);
+
+#line 30 "threads.scm"
+static void scm_report_lambda_6(struct obj *var_0, struct env_obj *env_in){struct scm_report_lambda_6_env *env = (struct scm_report_lambda_6_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_26);__builtin_unreachable();}
+
+#line 30 "threads.scm"
+static void scm_report_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_report_lambda_5_env *env = (struct scm_report_lambda_5_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_report_lambda_6_env );(((struct scm_report_lambda_6_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_report_lambda_6,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 30 "threads.scm"
+static void scm_report_lambda_4(struct obj *var_0, struct env_obj *env_in){struct scm_report_lambda_4_env *env = (struct scm_report_lambda_4_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_report_lambda_5_env );(((struct scm_report_lambda_5_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_2,scm_report_lambda_5,var_1);call_closure_two((env)->v_rv_51,var_0,var_2);__builtin_unreachable();}
+
+#line 30 "threads.scm"
+static void scm_report_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_report_lambda_3_env *env = (struct scm_report_lambda_3_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_report_lambda_4_env );(((struct scm_report_lambda_4_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_report_lambda_4_env *)(&((var_2)->env)))->v_rv_51)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_report_lambda_4,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 30 "threads.scm"
+static void scm_report_lambda_2(struct obj *var_0, struct env_obj *env_in){struct scm_report_lambda_2_env *env = (struct scm_report_lambda_2_env *)(&((env_in)->env));if (obj_is_truthy(var_0)){call_closure_two(((struct cell_obj *)((env)->v_report_later))->val,NULL,(env)->v_k_26);} else {OBJECT_CLOSURE_TWO_NEW(var_1,display_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_report_lambda_3_env );(((struct scm_report_lambda_3_env *)(&((var_2)->env)))->v_k_26)=((env)->v_k_26);OBJECT_CLOSURE_ONE_NEW(var_3,scm_report_lambda_3,var_2);call_closure_two(var_1,((struct cell_obj *)((env)->v_counter))->val,var_3);}0;__builtin_unreachable();}
+
+#line 30 "threads.scm"
+static void scm_report_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_report_lambda_1_env *env = (struct scm_report_lambda_1_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_report_lambda_2_env );(((struct scm_report_lambda_2_env *)(&((var_1)->env)))->v_counter)=((env)->v_counter);(((struct scm_report_lambda_2_env *)(&((var_1)->env)))->v_k_26)=((env)->v_k_26);(((struct scm_report_lambda_2_env *)(&((var_1)->env)))->v_report_later)=((env)->v_report_later);OBJECT_CLOSURE_ONE_NEW(var_2,scm_report_lambda_2,var_1);call_closure_two(var_0,MAKE_FIXNUM(20000),var_2);__builtin_unreachable();}
+
+#line 30 "threads.scm"
+static void scm_report(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_report_env *env = (struct scm_report_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,lt_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_report_lambda_1_env );(((struct scm_report_lambda_1_env *)(&((var_3)->env)))->v_counter)=((env)->v_counter);(((struct scm_report_lambda_1_env *)(&((var_3)->env)))->v_k_26)=(var_1);(((struct scm_report_lambda_1_env *)(&((var_3)->env)))->v_report_later)=((env)->v_report_later);OBJECT_CLOSURE_ONE_NEW(var_4,scm_report_lambda_1,var_3);call_closure_two(var_2,((struct cell_obj *)((env)->v_counter))->val,var_4);__builtin_unreachable();}
+
+#line 32 "threads.scm"
+static void scm_report_later_lambda_1(struct obj *var_0, struct env_obj *env_in){struct scm_report_later_lambda_1_env *env = (struct scm_report_later_lambda_1_env *)(&((env_in)->env));call_closure_two(((struct cell_obj *)((env)->v_report))->val,NULL,(env)->v_k_27);__builtin_unreachable();}
+
+#line 32 "threads.scm"
+static void scm_report_later(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_report_later_env *env = (struct scm_report_later_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_2,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_3,struct scm_report_later_lambda_1_env );(((struct scm_report_later_lambda_1_env *)(&((var_3)->env)))->v_k_27)=(var_1);(((struct scm_report_later_lambda_1_env *)(&((var_3)->env)))->v_report)=((env)->v_report);OBJECT_CLOSURE_ONE_NEW(var_4,scm_report_later_lambda_1,var_3);call_closure_two(var_2,NULL,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_69(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_69_env *env = (struct scm_lambda_69_env *)(&((env_in)->env));call_closure_two((env)->v_rv_56,var_0,(env)->v_k_28);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_66(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_66_env *env = (struct scm_lambda_66_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_69_env );(((struct scm_lambda_69_env *)(&((var_2)->env)))->v_k_28)=((env)->v_k_28);(((struct scm_lambda_69_env *)(&((var_2)->env)))->v_rv_56)=(var_0);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_69,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_62(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_CLOSURE_TWO_NEW(var_2,display_k,NULL);OBJECT_STRING_OBJ_NEW(var_3,"main done");OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_66_env );(((struct scm_lambda_66_env *)(&((var_4)->env)))->v_k_28)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_66,var_4);call_closure_two(var_2,var_3,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_62_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_62);
+
+#line 1 "threads.scm"
+static void scm_lambda_67(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_67_env *env = (struct scm_lambda_67_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,newline_k,NULL);call_closure_two(var_1,var_0,(env)->v_k_29);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_63(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_63_env *env = (struct scm_lambda_63_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,current_output_port_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_67_env );(((struct scm_lambda_67_env *)(&((var_2)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_67,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_58(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_58_env *env = (struct scm_lambda_58_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_63_env );(((struct scm_lambda_63_env *)(&((var_1)->env)))->v_k_29)=((env)->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_63,var_1);call_closure_two((struct obj *)(&(scm_lambda_62_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_46(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_46_env *env = (struct scm_lambda_46_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_report_env );(((struct scm_report_env *)(&((var_1)->env)))->v_counter)=(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_counter);(((struct scm_report_env *)(&((var_1)->env)))->v_report_later)=(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report_later);OBJECT_CLOSURE_TWO_NEW(var_2,scm_report,var_1);(((struct cell_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report,((struct cell_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_report_later_env );(((struct scm_report_later_env *)(&((var_3)->env)))->v_report)=(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report);OBJECT_CLOSURE_TWO_NEW(var_4,scm_report_later,var_3);(((struct cell_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report_later))->val)=(var_4);GC_WRITE_BARRIER(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report_later,((struct cell_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report_later))->val);OBJECT_CLOSURE_TWO_NEW(var_5,spawn_k,NULL);OBJECT_ENV_OBJ_NEW(var_6,struct scm_lambda_58_env );(((struct scm_lambda_58_env *)(&((var_6)->env)))->v_k_29)=(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_k_29);OBJECT_CLOSURE_ONE_NEW(var_7,scm_lambda_58,var_6);call_closure_two(var_5,((struct cell_obj *)(((struct scm_lambda_40_env *)(&(((struct env_obj *)(((struct scm_lambda_43_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->v_report))->val,var_7);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_43(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_43_env *env = (struct scm_lambda_43_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,spawn_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_46_env );(((struct scm_lambda_46_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_46,var_2);call_closure_two(var_1,(struct obj *)(&(scm_lambda_47_closure)),var_3);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_40(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_40_env *env = (struct scm_lambda_40_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_42_env );(((struct scm_lambda_42_env *)(&((var_1)->env)))->v_count_to)=((env)->v_count_to);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_42,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_43_env );(((struct scm_lambda_43_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_43,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_32(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_32_env *env = (struct scm_lambda_32_env *)(&((env_in)->env));(((struct cell_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter))->val)=(MAKE_FIXNUM(0));GC_WRITE_BARRIER(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter,((struct cell_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter))->val);OBJECT_ENV_OBJ_NEW(var_1,struct scm_count_to_env );(((struct scm_count_to_env *)(&((var_1)->env)))->v_counter)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter);OBJECT_CLOSURE_TWO_NEW(var_2,scm_count_to,var_1);(((struct cell_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_to))->val)=(var_2);GC_WRITE_BARRIER(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_to,((struct cell_obj *)(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_to))->val);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_39_env );(((struct scm_lambda_39_env *)(&((var_3)->env)))->v_count_to)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_to);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_39,var_3);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_40_env );(((struct scm_lambda_40_env *)(&((var_5)->env)))->v_count_to)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_to);(((struct scm_lambda_40_env *)(&((var_5)->env)))->v_counter)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter);(((struct scm_lambda_40_env *)(&((var_5)->env)))->v_k_29)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_29);(((struct scm_lambda_40_env *)(&((var_5)->env)))->v_report)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_report);(((struct scm_lambda_40_env *)(&((var_5)->env)))->v_report_later)=(((struct scm_lambda_23_env *)(&(((struct env_obj *)(((struct scm_lambda_27_env *)(&(((struct env_obj *)(((struct scm_lambda_29_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_report_later);OBJECT_CLOSURE_ONE_NEW(var_6,scm_lambda_40,var_5);call_closure_two(var_4,NULL,var_6);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_29(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_29_env *env = (struct scm_lambda_29_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_32_env );(((struct scm_lambda_32_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_32,var_1);call_closure_two((struct obj *)(&(scm_lambda_31_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_27(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_27_env *env = (struct scm_lambda_27_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_29_env );(((struct scm_lambda_29_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_29,var_1);call_closure_two((struct obj *)(&(scm_lambda_28_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_23(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_23_env *env = (struct scm_lambda_23_env *)(&((env_in)->env));OBJECT_CLOSURE_TWO_NEW(var_1,yield_k,NULL);OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_27_env );(((struct scm_lambda_27_env *)(&((var_2)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_3,scm_lambda_27,var_2);call_closure_two(var_1,NULL,var_3);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_19(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_19_env *env = (struct scm_lambda_19_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_23_env );(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_count_to)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_count_to);(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_counter)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_counter);(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_k_29)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_k_29);(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_report)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_report);(((struct scm_lambda_23_env *)(&((var_1)->env)))->v_report_later)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)(((struct scm_lambda_12_env *)(&(((struct env_obj *)(((struct scm_lambda_15_env *)(&(((struct env_obj *)((env)->parent))->env)))->parent))->env)))->parent))->env)))->v_report_later);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_23,var_1);call_closure_two((struct obj *)(&(scm_lambda_22_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_15(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_15_env *env = (struct scm_lambda_15_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_19_env );(((struct scm_lambda_19_env *)(&((var_1)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_19,var_1);call_closure_two((struct obj *)(&(scm_lambda_18_closure)),var_0,var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_12(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_12_env *env = (struct scm_lambda_12_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_14_env );(((struct scm_lambda_14_env *)(&((var_1)->env)))->v_worker)=(((struct scm_lambda_10_env *)(&(((struct env_obj *)((env)->parent))->env)))->v_worker);OBJECT_CLOSURE_TWO_NEW(var_2,scm_lambda_14,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_15_env );(((struct scm_lambda_15_env *)(&((var_3)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_4,scm_lambda_15,var_3);call_closure_two(var_2,var_0,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_10(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_10_env *env = (struct scm_lambda_10_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_report_later)=(var_2);GC_WRITE_BARRIER(env_in,var_2);((env)->v_k_29)=(var_1);GC_WRITE_BARRIER(env_in,var_1);OBJECT_ENV_OBJ_NEW(var_3,struct scm_worker_env );OBJECT_CLOSURE_TWO_NEW(var_4,scm_worker,var_3);(((struct cell_obj *)((env)->v_worker))->val)=(var_4);GC_WRITE_BARRIER((env)->v_worker,((struct cell_obj *)((env)->v_worker))->val);OBJECT_ENV_OBJ_NEW(var_5,struct scm_lambda_11_env );(((struct scm_lambda_11_env *)(&((var_5)->env)))->v_worker)=((env)->v_worker);OBJECT_CLOSURE_TWO_NEW(var_6,scm_lambda_11,var_5);OBJECT_ENV_OBJ_NEW(var_7,struct scm_lambda_12_env );(((struct scm_lambda_12_env *)(&((var_7)->env)))->parent)=((struct obj *)(env_in));OBJECT_CLOSURE_ONE_NEW(var_8,scm_lambda_12,var_7);call_closure_two(var_6,NULL,var_8);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_8(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_8_env *env = (struct scm_lambda_8_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_report)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_10_env );(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_count_to)=((env)->v_count_to);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_report)=((env)->v_report);(((struct scm_lambda_10_env *)(&((var_3)->env)))->v_worker)=((env)->v_worker);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_10,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_6(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_6_env *env = (struct scm_lambda_6_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_count_to)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_8_env );(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_count_to)=((env)->v_count_to);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_8_env *)(&((var_3)->env)))->v_worker)=((env)->v_worker);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_8,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_4(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_4_env *env = (struct scm_lambda_4_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_counter)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_6_env );(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_counter)=((env)->v_counter);(((struct scm_lambda_6_env *)(&((var_3)->env)))->v_worker)=((env)->v_worker);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_6,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_2(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){struct scm_lambda_2_env *env = (struct scm_lambda_2_env *)(&((env_in)->env));OBJECT_CELL_OBJ_NEW(var_2,var_0);((env)->v_worker)=(var_2);GC_WRITE_BARRIER(env_in,var_2);OBJECT_ENV_OBJ_NEW(var_3,struct scm_lambda_4_env );(((struct scm_lambda_4_env *)(&((var_3)->env)))->v_worker)=((env)->v_worker);OBJECT_CLOSURE_TWO_NEW(var_4,scm_lambda_4,var_3);call_closure_one(var_1,var_4);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_9(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_9_env *env = (struct scm_lambda_9_env *)(&((env_in)->env));call_closure_two(var_0,NULL,(env)->v_k_34);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_7(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_7_env *env = (struct scm_lambda_7_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_9_env );(((struct scm_lambda_9_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_9,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_5(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_5_env *env = (struct scm_lambda_5_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_7_env );(((struct scm_lambda_7_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_7,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_3(struct obj *var_0, struct env_obj *env_in){struct scm_lambda_3_env *env = (struct scm_lambda_3_env *)(&((env_in)->env));OBJECT_ENV_OBJ_NEW(var_1,struct scm_lambda_5_env );(((struct scm_lambda_5_env *)(&((var_1)->env)))->v_k_34)=((env)->v_k_34);OBJECT_CLOSURE_ONE_NEW(var_2,scm_lambda_5,var_1);call_closure_two(var_0,NULL,var_2);__builtin_unreachable();}
+
+#line 1 "threads.scm"
+static void scm_lambda_1(struct obj *var_0, struct obj *var_1, struct env_obj *env_in){OBJECT_ENV_OBJ_NEW(var_2,struct scm_lambda_2_env );OBJECT_CLOSURE_TWO_NEW(var_3,scm_lambda_2,var_2);OBJECT_ENV_OBJ_NEW(var_4,struct scm_lambda_3_env );(((struct scm_lambda_3_env *)(&((var_4)->env)))->v_k_34)=(var_1);OBJECT_CLOSURE_ONE_NEW(var_5,scm_lambda_3,var_4);call_closure_two(var_3,NULL,var_5);__builtin_unreachable();}
+static struct closure_obj scm_lambda_1_closure = CLOSURE_TWO_OBJ_INIT(scm_lambda_1);
+
+#line 1 "threads.scm"
+void main_lambda(struct obj *input_obj, struct env_obj *input_env){OBJECT_CLOSURE_ONE_NEW(var_0,halt_k,NULL);call_closure_two((struct obj *)(&(scm_lambda_1_closure)),NULL,var_0);__builtin_unreachable();}
+#line 560 "compiled_result.c"
+void scheme_entry(void) {
+  struct closure_obj initial_closure = object_closure_one_new(main_lambda, NULL);
+  struct thunk initial_thunk = {
+    .closr = &initial_closure,
+    .one = {NULL},
+  };
+
+  struct thunk *thnk_heap = malloc(sizeof(struct thunk));
+  memcpy(thnk_heap, &initial_thunk, sizeof(struct thunk));
+  symbol_register_static(scheme_symbols);
+  lambda_info_register(scheme_lambdas);
+  scheme_start(thnk_heap);
+}
+
+#ifndef SOMESCHEME_NO_MAIN
+int main(int argc, char **argv) {
+  scheme_set_args(argc, argv);
+  scheme_entry();
+}
+#endif
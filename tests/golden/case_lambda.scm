@@ -0,0 +1,18 @@
+(define area
+  (case-lambda
+    ((r) (* 3 (* r r)))
+    ((w h) (* w h))
+    (() 0)))
+(display (area 2)) (newline)
+(display (area 3 4)) (newline)
+(display (area)) (newline)
+(define count
+  (case-lambda
+    ((n) (count n 0))
+    ((n acc) (if (= n 0) acc (count (- n 1) (+ acc 1))))))
+(display (count 5)) (newline)
+(let ((f (case-lambda ((x) x) ((x y) (+ x y)))))
+  (display (f 1 2)) (newline)
+  (let ((f (lambda (a) (* a 10))))
+    (display (f 4)) (newline)))
+(display ((case-lambda ((_x) 'one) ((_x _y) 'two)) 1 2)) (newline)
@@ -0,0 +1,29 @@
+(define area
+  (case-lambda
+    ((r) (* 3 (* r r)))
+    ((w h) (* w h))
+    (() 0)))
+(define (measure f)
+  (display (f 2)) (newline)
+  (display (f 3 4)) (newline)
+  (display (f)) (newline))
+(measure area)
+(display (procedure? area)) (newline)
+(define shapes
+  (cons area (cons (case-lambda ((x) (- 0 x)) ((x y) (- x y))) '())))
+(define (each f l)
+  (if (null? l)
+      '()
+      (let ()
+        (f (car l))
+        (each f (cdr l)))))
+(each (lambda (f) (display (f 10 3)) (newline)) shapes)
+(display ((car (cdr shapes)) 5)) (newline)
+(let ((g (case-lambda ((_x) 'one) ((_x _y) 'two))))
+  (display (cons (g 1) (g 1 2))) (newline)
+  (display (eq? g g)) (newline))
+(define (pick) (case-lambda ((a) a) ((a b c) (+ a (+ b c)))))
+(define h (pick))
+(display (h 1 2 3)) (newline)
+(display (h 7)) (newline)
+(display (area 5)) (newline)
@@ -0,0 +1,23 @@
+(define-library (shapes)
+  (export square)
+  (cond-expand
+    (r7rs (begin (define (square x) (* x x))))
+    (else (begin (define (square x) x)))))
+(import (shapes))
+(cond-expand
+  ((and r7rs (not some-other-scheme))
+   (define greeting "portable")
+   (define (twice n) (* 2 n)))
+  (else
+   ;; never read as a program, only as data
+   (define-syntax twice (syntax-rules () ((_ n) `(,n ,n))))))
+(display greeting) (newline)
+(display (twice (square 4))) (newline)
+(display (cond-expand ((or some-other-scheme some-scheme) 'either) (else 'neither))) (newline)
+(display (cond-expand ((library (shapes)) 'shapes) (else 'no-shapes))) (newline)
+(display (cond-expand ((library (scheme base)) 'base) (else 'no-base))) (newline)
+(display (cond-expand ((library (not defined)) 'defined) (else 'undefined))) (newline)
+(cond-expand (some-other-scheme (display 'other) (newline)))
+(cond-expand (r7rs (include-ci "included/shout.scm")))
+(display (shout "hi")) (newline)
+(display (cond-expand (r7rs (cond-expand (ratios 'nested))))) (newline)
@@ -0,0 +1,6 @@
+(define c (cons 1 (cons 2 3)))
+
+(display (car c)) (newline)
+(display (cdr c)) (newline)
+(display (car (cdr c))) (newline)
+(display (cdr (cdr c))) (newline)
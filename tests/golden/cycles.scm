@@ -0,0 +1,28 @@
+(define cyclic
+  (lambda (x)
+    (let ((v (make-vector 2 x)))
+      (vector-set! v 1 v)
+      v)))
+
+(define a (cyclic 1))
+(define b (cyclic 1))
+
+(display (equal? a b)) (newline)
+(display (equal? a (cyclic 2))) (newline)
+(display (equal? '(a 'end) '(b 'end))) (newline)
+(display (equal? '(a 'end) '(b 'other))) (newline)
+
+(define build
+  (lambda (n acc)
+    (if (= n 0)
+        acc
+        (build (- n 1) (cons n acc)))))
+
+(display (equal? (build 2000 '()) (build 2000 '()))) (newline)
+(display (equal? (build 2000 '()) (build 2000 '(0)))) (newline)
+
+(define h (make-hash-table))
+(hash-table-set! h a 'found)
+(display (hash-table-ref/default h b 'missing)) (newline)
+(display (hash-table-ref/default h (cyclic 2) 'missing)) (newline)
+(display a) (newline)
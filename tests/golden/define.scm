@@ -0,0 +1,4 @@
+(define a 1)
+(define b 2)
+
+(display (+ a b)) (newline)
@@ -0,0 +1,18 @@
+(define env (interaction-environment))
+(display env) (newline)
+(display (eval '('+ 1 2) env)) (newline)
+(display (eval '(+ 1 2) env)) (newline)
+(eval '('define '('square 'x) '('* 'x 'x)) env)
+(display (eval '('square 5) env)) (newline)
+(define sq (eval 'square env))
+(display (sq 6)) (newline)
+(eval (cons 'define (cons 'twice (cons (lambda (f) (lambda (x) (f (f x)))) null))) env)
+(display (eval '('twice sq 3) env)) (newline)
+(eval '('define '('ev? 'n) '('if '('= 'n 0) #t '('od? '('- 'n 1)))) env)
+(eval '('define '('od? 'n) '('if '('= 'n 0) #f '('ev? '('- 'n 1)))) env)
+(display (eval '('ev? 10) env)) (newline)
+(display (eval '('let '('('x 1) '('y 2)) '('set! 'x '('+ 'x 'y)) '('cons 'x '('quote 'y))) env)) (newline)
+(display (eval '('let '() '('define 'a 4) '('* 'a 'a)) env)) (newline)
+(display ((eval '('lambda '() 7) env))) (newline)
+(eval '('define '('count 'n) '('if '('= 'n 0) '('quote 'done) '('count '('- 'n 1)))) env)
+(display (eval '('count 100000) env)) (newline)
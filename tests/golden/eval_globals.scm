@@ -0,0 +1,15 @@
+(define x 10)
+(define env (interaction-environment))
+(display (eval 'x env)) (newline)
+(define (square n) (* n n))
+(display (eval '('square 'x) env)) (newline)
+(set! x 11)
+(display (eval 'x env)) (newline)
+(define (bump!) (set! x (+ x 1)))
+(bump!)
+(display (eval 'x env)) (newline)
+(define (shadowed x) (set! x 5) x)
+(display (eval '('shadowed 1) env)) (newline)
+(display (eval 'x env)) (newline)
+(eval '('define 'y '('* 'x 2)) env)
+(display (eval 'y env)) (newline)
@@ -0,0 +1,15 @@
+(define safe-div
+  (lambda (a b)
+    (with-exception-handler
+      (lambda (e) (error-object-message e))
+      (lambda () (if (= b 0) (error "division by zero" a) (/ a b))))))
+(display (safe-div 10 2)) (newline)
+(display (safe-div 1 0)) (newline)
+(display
+  (with-exception-handler
+    (lambda (e) (* e 2))
+    (lambda () (+ 1 (raise 20))))) (newline)
+(display
+  (with-exception-handler
+    (lambda (e) (error-object-irritants e))
+    (lambda () (error "bad" 1 'two)))) (newline)
@@ -0,0 +1,15 @@
+(define-foreign abs (int) -> int)
+(define-foreign labs (long) -> long)
+(define-foreign atof (string) -> double)
+(define-foreign strcmp (string string) -> int)
+(define-foreign puts (string) -> int)
+(define-foreign rand () -> int)
+(display (abs (- 0 42))) (newline)
+(display (labs (- 0 100000000000))) (newline)
+(display (atof "2.5")) (newline)
+(display (= 0 (strcmp "same" "same"))) (newline)
+(display (< (strcmp "a" "b") 0)) (newline)
+(display (number? (rand))) (newline)
+(define magnitude (lambda (x) (+ (abs x) 1)))
+(display (magnitude (- 0 9))) (newline)
+(puts "printed by C")
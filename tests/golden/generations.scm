@@ -0,0 +1,48 @@
+(define v (make-vector 4 '()))
+(define cell '())
+(define h (make-hash-table))
+
+(define fill
+  (lambda (n)
+    (if (= n 0)
+        'done
+        (let ((i (% n 4)))
+          (vector-set! v i (cons n (vector-ref v i)))
+          (set! cell (cons n cell))
+          (hash-table-set! h (% n 8) (cons n '()))
+          (fill (- n 1))))))
+
+(define length
+  (lambda (l acc)
+    (if (null? l)
+        acc
+        (length (cdr l) (+ acc 1)))))
+
+(define sum
+  (lambda (l acc)
+    (if (null? l)
+        acc
+        (sum (cdr l) (+ acc (car l))))))
+
+(display (fill 10000)) (newline)
+(display (length (vector-ref v 0) 0)) (newline)
+(display (sum (vector-ref v 1) 0)) (newline)
+(display (length cell 0)) (newline)
+(display (sum cell 0)) (newline)
+(display (hash-table-ref/default h 3 'missing)) (newline)
+
+(define counter
+  (let ((count '()))
+    (lambda (n)
+      (set! count (cons n count))
+      count)))
+
+(define count-up
+  (lambda (n)
+    (if (= n 0)
+        (car (counter 0))
+        (let ((seen (counter n)))
+          (count-up (- n 1))))))
+
+(display (count-up 10000)) (newline)
+(display (length (counter 'last) 0)) (newline)
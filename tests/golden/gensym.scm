@@ -0,0 +1,27 @@
+; uninterned symbols are only ever eq? to themselves
+(define (list2 a b) (cons a (cons b '())))
+(define (list3 a b c) (cons a (list2 b c)))
+(define g (gensym))
+(display g) (newline)
+(display (gensym "tmp")) (newline)
+(display (gensym 'loop)) (newline)
+(display (symbol? g)) (newline)
+(display (eq? g g)) (newline)
+(display (eq? (string->uninterned-symbol "x") 'x)) (newline)
+(display (symbol->string (string->uninterned-symbol "hello"))) (newline)
+
+; as variables of eval they are neither keywords nor the interned name
+(define env (interaction-environment))
+(define v (gensym "v"))
+(eval (list3 'define v 1) env)
+(eval (list3 'define (string->symbol (symbol->string v)) 2) env)
+(display (eval v env)) (newline)
+(define if-var (string->uninterned-symbol "if"))
+(display (eval (list3 'let (cons (list2 if-var 3) '()) if-var) env)) (newline)
+
+; nor can a name of the program or of eval capture those the compiler makes
+(define p (make-parameter 1))
+(define | value0| 5)
+(display (parameterize ((p 2)) | value0|)) (newline)
+(eval (list3 'define (string->symbol "|eval 0") 7) env)
+(display (eval (list3 'cons (string->symbol "|eval 0") (list2 'quote 'x)) env)) (newline)
@@ -0,0 +1,10 @@
+(define h (make-hash-table))
+(hash-table-set! h "a" 1)
+(hash-table-set! h '(1 2) 2)
+(hash-table-set! h "a" 3)
+(display (hash-table-ref h "a")) (newline)
+(display (hash-table-ref h '(1 2))) (newline)
+(display (hash-table-ref/default h 'missing 0)) (newline)
+(hash-table-delete! h "a")
+(display (hash-table-ref/default h "a" 'gone)) (newline)
+(display (hash-table? h)) (newline)
@@ -0,0 +1,17 @@
+(if 1
+  (display "good\n")
+  (display "bad\n"))
+
+(if (- 1 1)
+ (display "bad\n")
+ (display "good\n"))
+
+(if ((lambda () 1))
+ (display "good\n")
+ (display "bad\n"))
+
+(if 0
+ (display "bad\n"))
+
+(if 1
+ (display "good\n"))
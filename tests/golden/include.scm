@@ -0,0 +1,8 @@
+(include "included/shapes.scm")
+(display (square 9)) (newline)
+(display (area 3 4)) (newline)
+(define cube
+  (lambda (x)
+    (include "included/square.scm")
+    (* x (square x))))
+(display (cube 3)) (newline)
@@ -0,0 +1,2 @@
+(include "square.scm")
+(define area (lambda (w h) (* w h)))
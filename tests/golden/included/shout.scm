@@ -0,0 +1 @@
+(DEFINE (Shout X) (STRING-APPEND X "!"))
@@ -0,0 +1 @@
+(define square (lambda (x) (* x x)))
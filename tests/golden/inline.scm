@@ -0,0 +1,39 @@
+(define twice
+  (lambda (x)
+    (let ((double (lambda (y) (* 2 y))))
+      (+ (double x) (double (+ x 1))))))
+(display (twice 5)) (newline)
+
+(define shadowed
+  (lambda (x)
+    (let ((x (+ x 1)))
+      (set! x (* x 10))
+      x)))
+(display (shadowed 1)) (newline)
+
+(define same
+  (lambda ()
+    (let ((f (lambda (y) y)))
+      (eq? f f))))
+(display (same)) (newline)
+
+(define fresh
+  (lambda ()
+    (let ((make (lambda () (lambda (y) y))))
+      (eq? (make) (make)))))
+(display (fresh)) (newline)
+
+(define adder
+  (lambda (n)
+    (let ((add (lambda (x) (+ x n))))
+      (let ((result (add (add 1))))
+        (set! n 100)
+        (+ result (add 0))))))
+(display (adder 1)) (newline)
+
+(define choose
+  (lambda (c)
+    (let ((k (lambda (v) (+ v 1))))
+      (if c (k 10) (k 20)))))
+(display (choose #t)) (newline)
+(display (choose #f)) (newline)
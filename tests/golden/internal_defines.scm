@@ -0,0 +1,17 @@
+(define y 100)
+(define (f x)
+  (define y (* x 2))
+  (define (g z) (+ y z))
+  (define w (g 1))
+  (display w) (newline)
+  (define (h a) (* a 10))
+  (define v (h w))
+  (+ v (g x)))
+(display (f 3)) (newline)
+(display y) (newline)
+(display ((lambda (x) (define y (+ x 1)) (define (k z) (* y z)) (k 2)) 4)) (newline)
+(let ((a 1))
+  (define b (+ a 1))
+  (define (even n) (if (= n 0) #t (odd (- n 1))))
+  (define (odd n) (if (= n 0) #f (even (- n 1))))
+  (display (even b)) (newline))
@@ -0,0 +1,4 @@
+((lambda ()
+  (let ((a 1) (b 2))
+    (display a) (newline)
+    (display b) (newline))))
@@ -0,0 +1,17 @@
+; the lexical syntax the grammar doesn't see
+#| a block comment, #| which nests |#
+   (display "not shown") |#
+(include-ci "included/shout.scm")
+(display (shout "Hi")) (newline)
+(display #true) (newline)
+(display #false) (newline)
+(display (+ #;(display "not shown") #x1F #o17)) (newline)
+(display (+ #b101 #D42)) (newline)
+(define |two words| 2)
+(display (+ |two words| 1)) (newline)
+(display (symbol->string '|a b|)) (newline)
+(display (eq? '|foo| 'foo)) (newline)
+#!fold-case
+(DISPLAY (Symbol->String 'Hello)) (NEWLINE)
+#!no-fold-case
+(display (symbol->string 'Hello)) (newline)
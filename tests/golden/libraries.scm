@@ -0,0 +1,32 @@
+(define-library (util counter)
+  (export make-counter (rename step default-step))
+  (import (scheme base))
+  (begin
+    (define step 1)
+    (define make-counter
+      (lambda ()
+        (let ((n 0))
+          (lambda () (set! n (+ n step)) n))))))
+
+(define-library (util math)
+  (export square twice step)
+  (import (only (util counter) default-step))
+  (begin
+    (define step 10)
+    (define square (lambda (x) (* x x)))
+    (define twice (lambda (f x) (f (f x))))
+    (display (+ step default-step)) (newline)))
+
+(import (scheme base)
+        (prefix (except (util math) step) m:)
+        (rename (util counter) (make-counter counter)))
+
+(define c (counter))
+(c)
+(display (c)) (newline)
+(display default-step) (newline)
+(display (m:square 7)) (newline)
+(display (m:twice m:square 3)) (newline)
+(define step 100)
+(display step) (newline)
+(display ((lambda (m:square) m:square) 5)) (newline)
@@ -0,0 +1,20 @@
+(define make-counter
+  (lambda ()
+    (let ((n 0))
+      (lambda ()
+        (set! n (+ n 1))
+        n))))
+(define c (make-counter))
+(c)
+(c)
+(display (c)) (newline)
+(define sum-to
+  (lambda (limit)
+    (let ((total 0) (i 0))
+      (set! total (+ total limit))
+      (set! i (- limit 1))
+      (display i) (newline)
+      total)))
+(display (sum-to 10)) (newline)
+(define adder (lambda (x) (lambda (y) (+ x y))))
+(display ((adder 3) 4)) (newline)
@@ -0,0 +1,29 @@
+(define (my-even? n)
+  (if (= n 0) #t (my-odd? (- n 1))))
+
+(define (my-odd? n)
+  (if (= n 0) #f (my-even? (- n 1))))
+
+(display (my-even? 10)) (newline)
+(display (my-odd? 7)) (newline)
+(display (my-even? 100001)) (newline)
+
+(define (count-down n)
+  (if (= n 0) '() (cons n (count-up (- n 1)))))
+
+(define (count-up n)
+  (if (= n 0) '() (cons (- 0 n) (count-down (- n 1)))))
+
+(display (count-down 4)) (newline)
+
+(define (first) (second))
+(define (second) 2)
+(display (first)) (newline)
+
+(define (outer n)
+  (define (ping n) (if (= n 0) 'ping (pong (- n 1))))
+  (define (pong n) (if (= n 0) 'pong (ping (- n 1))))
+  (ping n))
+
+(display (outer 3)) (newline)
+(display (outer 4)) (newline)
@@ -0,0 +1,27 @@
+(display -5) (newline)
+(display (+ -5 +3)) (newline)
+(display -0.5) (newline)
+(display '(1 -2)) (newline)
+(display (car (cdr '(1 -2)))) (newline)
+(display 1e3) (newline)
+(display -2E-2) (newline)
+(display (= 1e3 1000)) (newline)
+(display #x-1F) (newline)
+(display #b-1) (newline)
+(display #o+17) (newline)
+(display -1/2) (newline)
+(display (- -1/2 -1/2)) (newline)
+(display #(-1 -0.25 #d-10)) (newline)
+(display -9223372036854775808) (newline)
+(display (string->number "#x-1F")) (newline)
+(display (string->number "-1e3")) (newline)
+(display (number? (string->number "#x--1"))) (newline)
+(display 100.0) (newline)
+(display 0.1) (newline)
+(display 123.456) (newline)
+(display 1e20) (newline)
+(display 1e21) (newline)
+(display 1.5e-7) (newline)
+(display 0.000001) (newline)
+(display -0.0) (newline)
+(display 1.2345678901234567e20) (newline)
@@ -0,0 +1,9 @@
+(define out (current-output-port))
+(display "to the current port")
+(newline)
+(display "to the port given" out)
+(newline out)
+(display (cons 1 (cons "two" '())))
+(newline)
+(display out)
+(newline)
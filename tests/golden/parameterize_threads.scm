@@ -0,0 +1,28 @@
+(define p (make-parameter 1))
+(spawn (lambda () (display (p)) (newline)))
+(parameterize ((p 2))
+  (yield)
+  (display (p)) (newline))
+(display (p)) (newline)
+(spawn (lambda ()
+  (parameterize ((p 3))
+    (yield)
+    (display (p)) (newline))))
+(parameterize ((p 4))
+  (yield)
+  (display (p)) (newline)
+  (yield))
+(display (p)) (newline)
+(spawn (lambda () (display "other thread") (newline)))
+(display
+  (dynamic-wind
+    (lambda () (yield) (display "before") (newline))
+    (lambda () (yield) (display "during") (newline) 5)
+    (lambda () (yield) (display "after") (newline)))) (newline)
+(display
+  (with-exception-handler
+    (lambda (e) e)
+    (lambda ()
+      (parameterize ((p 6))
+        (raise 7))))) (newline)
+(display (p)) (newline)
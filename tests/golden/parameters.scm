@@ -0,0 +1,25 @@
+(define radix (make-parameter 10))
+(display (radix)) (newline)
+(display (parameterize ((radix 2)) (radix))) (newline)
+(display (radix)) (newline)
+(define (nested)
+  (parameterize ((radix 8))
+    (display (radix)) (newline)
+    (parameterize ((radix 16)) (display (radix)) (newline))
+    (radix)))
+(display (nested)) (newline)
+(define width (make-parameter 4 (lambda (x) (* x 2))))
+(display (width)) (newline)
+(display (parameterize ((width 5) (radix 3)) (+ (width) (radix)))) (newline)
+(display (width)) (newline)
+(display
+  (with-exception-handler
+    (lambda (_e) (radix))
+    (lambda () (parameterize ((radix 7)) (raise 'oops))))) (newline)
+(define get-radix (parameterize ((radix 12)) (lambda () (radix))))
+(display (get-radix)) (newline)
+(define p radix)
+(display (eq? p radix)) (newline)
+(display (procedure? radix)) (newline)
+(define make-parameter-count 1)
+(display make-parameter-count) (newline)
@@ -0,0 +1,17 @@
+(define squares
+  (let ((a 2) (b 3))
+    (cons (* a a) (cons (* b b) (+ (* a a) (* b b))))))
+(display (car squares)) (newline)
+(display (cdr (cdr squares))) (newline)
+(display (if (< 1 2) "less" "more")) (newline)
+(display (if 0 "true" "false")) (newline)
+(let ((x 5))
+  (display (/ x 2)) (newline)
+  (display (number->string (* x 1.5))) (newline))
+(let ((n 1))
+  (set! n (+ n 1))
+  (display n) (newline))
+(display (string-append "con" "stant")) (newline)
+(display (string-length "four")) (newline)
+(display (string->symbol "sym")) (newline)
+(display (equal? (+ 1 2) 3)) (newline)
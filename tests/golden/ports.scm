@@ -0,0 +1,17 @@
+(define out (current-output-port))
+(write "to the current port")
+(newline)
+(write "to the port given" out)
+(newline out)
+(write '|two words|)
+(display " ")
+(display '|two words| out)
+(newline)
+; stdin is empty while the tests run, so each read is at its end
+(display (null? (read-char)))
+(display (null? (read-char (current-input-port))))
+(display (null? (read-line)))
+(display (null? (read-line (current-input-port))))
+(display (null? (read)))
+(display (null? (read (current-input-port))))
+(newline)
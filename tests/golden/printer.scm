@@ -0,0 +1,28 @@
+(define out (current-output-port))
+
+(display '(1 2 3)) (newline)
+(display (cons 1 (cons 2 3))) (newline)
+(display '("a" 'b '(1.5 #t) null)) (newline)
+(display #(1 "c" #())) (newline)
+(display car) (newline)
+
+(write '("a\"b" 'c "d\\e") out)
+(newline out)
+(write (cons #("x") (cons "y" "z")) out)
+(newline out)
+
+(define v (make-vector 3 0))
+(vector-set! v 1 v)
+(vector-set! v 2 '(v 'end))
+(display v) (newline)
+(write v out)
+(newline out)
+
+(define w (make-vector 1 0))
+(define l '(1 w))
+(vector-set! w 0 l)
+(display l) (newline)
+(display '(w w)) (newline)
+(write (cons '|two words| (cons 'plain (cons '|#hash| '|1x|))) (current-output-port))
+(newline (current-output-port))
+(display '|two words|) (newline)
@@ -0,0 +1,8 @@
+(define count 0)
+(define p (delay (let ((_ (set! count (+ count 1)))) (* 6 7))))
+(display (promise? p)) (newline)
+(display (force p)) (newline)
+(display (force p)) (newline)
+(display count) (newline)
+(display (force (make-promise 3))) (newline)
+(display (force 4)) (newline)
@@ -0,0 +1,9 @@
+; quoted lists of constants are made once, when the program starts
+(define (data) '(1 '(2 "x" 2.5 1/3 4611686018427387904) 'a #t #f '('nested 'b)))
+(display (data)) (newline)
+(display (car (cdr (cdr (car (cdr (data))))))) (newline)
+(display (vector-ref #(1 "two" 3) 1)) (newline)
+(define l '(1 2))
+(display (equal? l (cons 1 (cons 2 '())))) (newline)
+(define (count xs) (if (null? xs) 0 (+ 1 (count (cdr xs)))))
+(display (count '(1 2 3 4 5 6 7 8 9 10))) (newline)
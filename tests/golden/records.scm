@@ -0,0 +1,13 @@
+(define-record-type point
+  (make-point x y)
+  point?
+  (x point-x set-point-x!)
+  (y point-y))
+
+(define p (make-point 1 2))
+(display (point-x p)) (newline)
+(display (point-y p)) (newline)
+(display (point? p)) (newline)
+(display (point? 5)) (newline)
+(set-point-x! p 10)
+(display (+ (point-x p) (point-y p))) (newline)
@@ -0,0 +1,6 @@
+(define (lib-even? n) (if (= n 0) #t (app-odd? (- n 1))))
+(define counter 0)
+(define bump (lambda (n) (set! counter (+ counter n)) counter))
+(define list-sum (lambda (xs) (if (null? xs) 0 (+ (car xs) (list-sum (cdr xs))))))
+(define tag 'hello)
+(display (bump 1)) (newline)
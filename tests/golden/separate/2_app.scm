@@ -0,0 +1,8 @@
+(display (list-sum (cons 1 (cons 2 (cons 3 '()))))) (newline)
+(display (bump 10)) (newline)
+(display counter) (newline)
+(display (eq? tag 'hello)) (newline)
+(define go (lambda (i) (if (< i 30000) (go (+ i 1)) i)))
+(display (go 0)) (newline)
+(define (app-odd? n) (if (= n 0) #f (lib-even? (- n 1))))
+(display (lib-even? 10)) (newline)
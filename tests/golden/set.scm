@@ -0,0 +1,5 @@
+((lambda (a)
+   (display a) (newline)
+   (set! a 2)
+   (display a) (newline)
+  ) 0)
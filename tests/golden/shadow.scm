@@ -0,0 +1,10 @@
+(define x 1)
+(define f (lambda (x) (lambda (y) (let ((x (+ x y))) x))))
+(display ((f 10) 5)) (newline)
+(display x) (newline)
+(define x 100)
+(display x) (newline)
+(let ((x 2))
+  (let ((g (lambda () x)))
+    (let ((x 3))
+      (display (+ x (g))) (newline))))
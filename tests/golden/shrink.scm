@@ -0,0 +1,8 @@
+(let ((g (lambda (x) (+ x 1))))
+  (let ((h (lambda (x) (g x))))
+    (display (h (h 1))) (newline)))
+(define k (lambda (x) (* x 2)))
+(define j (lambda (x) (k x)))
+(display (j 4)) (newline)
+(set! k (lambda (x) (* x 3)))
+(display (j 4)) (newline)
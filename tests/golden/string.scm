@@ -0,0 +1,27 @@
+(define s "hello world")
+
+(display (string-length s)) (newline)
+(display (string-length "")) (newline)
+(display (string-ref s 4)) (newline)
+(display (substring s 6 11)) (newline)
+(display (substring s 3 3)) (newline)
+(display (string-append "foo" "bar")) (newline)
+(display (string=? s (string-append "hello " "world"))) (newline)
+(display (string=? s "hello")) (newline)
+(display (string->number "42")) (newline)
+(display (string->number "-17")) (newline)
+(display (string->number "6/8")) (newline)
+(display (string->number "2.5e3")) (newline)
+(display (string->number ".5")) (newline)
+(display (string->number "abc")) (newline)
+(display (string->number "1/0")) (newline)
+(display (number->string 123)) (newline)
+(display (number->string 1/3)) (newline)
+(display (number->string 0.25)) (newline)
+(display (car (string->list "ab"))) (newline)
+(display (cdr (cdr (string->list "ab")))) (newline)
+(display "x\x41;y\u00e9 \"q\" \\ \x3bb;") (newline)
+(display (string-length "\x41;\a")) (newline)
+(display (= (string-ref "a(" 0) #\a)) (newline)
+(display (cons #\( (cons #\space (cons #\x7e (cons #\" '()))))) (newline)
+(display (string-ref "\x3bb;" 0)) (newline)
@@ -0,0 +1,22 @@
+(define go (lambda (i)
+            (let ((ii (- i 1)))
+             (if ii
+                (let ()
+                  (display ii) (newline)
+                  (go ii))))))
+
+(go 10)
+
+(define make-box (lambda (initial)
+                  (cons (lambda () initial)
+                        (lambda (v) (set! initial v)))))
+
+(let ((box-fns (make-box 1)))
+  (let ((get (car box-fns)))
+   (let ((set (cdr box-fns)))
+    (display (get)) (newline)
+    (set 23)
+    (display (get)) (newline))))
+
+(display (^ 1 2)) (newline)
+(display (string-concat "a" "b")) (newline)
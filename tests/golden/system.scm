@@ -0,0 +1,7 @@
+(display (string? (car (command-line)))) (newline)
+(display (cdr (command-line))) (newline)
+(display (get-environment-variable "SOME_SCHEME_UNSET_VARIABLE")) (newline)
+(display (string? (get-environment-variable "PATH"))) (newline)
+(display (> (current-second) 1000000000)) (newline)
+(exit 0)
+(display "not reached") (newline)
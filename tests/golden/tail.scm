@@ -0,0 +1,29 @@
+(define count-down
+  (lambda (n)
+    (if (= n 0)
+        "done"
+        (count-down (- n 1)))))
+
+(display (count-down 1000000)) (newline)
+
+(define is-even?
+  (lambda (n)
+    (if (= n 0) #t (is-odd? (- n 1)))))
+
+(define is-odd?
+  (lambda (n)
+    (if (= n 0) #f (is-even? (- n 1)))))
+
+(display (is-even? 1000001)) (newline)
+
+(define acc 0)
+
+(define sum-to
+  (lambda (n)
+    (let ((next (- n 1)))
+      (if (= n 0)
+          acc
+          (let ((_ (set! acc (+ acc n))))
+            (sum-to next))))))
+
+(display (sum-to 1000000)) (newline)
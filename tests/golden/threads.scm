@@ -0,0 +1,36 @@
+(define (worker name n)
+  (define (loop i)
+    (if (< i n)
+        (step i)
+        (display (string-append name " done\n"))))
+  (define (step i)
+    (display (string-append name (number->string i))) (newline)
+    (yield)
+    (loop (+ i 1)))
+  (lambda () (loop 0)))
+(spawn (worker "a" 3))
+(spawn (worker "b" 2))
+(display "main") (newline)
+(yield)
+(display "main again") (newline)
+(define counter 0)
+(define (count-to n)
+  (define (loop i)
+    (if (< i n) (bump i) i))
+  (define (bump i)
+    (set! counter (+ counter 1))
+    (yield)
+    (loop (+ i 1)))
+  (lambda () (loop 0)))
+(spawn (count-to 10000))
+(spawn (count-to 10000))
+(spawn (lambda ()
+  (spawn (lambda () (display "spawned by a thread") (newline)))
+  (display "spawner") (newline)))
+(define (report)
+  (if (< counter 20000) (report-later) (let () (display counter) (newline))))
+(define (report-later)
+  (yield)
+  (report))
+(spawn report)
+(display "main done") (newline)
@@ -0,0 +1,204 @@
+//! Generates random well formed programs and checks that compiling them
+//! through every pass, or running them on the bytecode vm, prints the same as
+//! running them on the interpreter, which works directly on the bound
+//! expression. A pass that panics shows up
+//! as the compiler exiting unsuccessfully.
+//!
+//! Set `PROPERTY_SEED` to rerun a single failing case.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const COMPILER: &str = env!("CARGO_BIN_EXE_some-scheme-compiler");
+
+const CASES: u64 = 8;
+const EXPRS_PER_CASE: usize = 12;
+const MAX_DEPTH: usize = 4;
+
+/// xorshift64*, plenty for picking productions
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+struct Gen {
+    rng: Rng,
+    next_var: usize,
+}
+
+impl Gen {
+    fn fresh(&mut self) -> String {
+        self.next_var += 1;
+        // reuse a few names so that shadowing gets exercised
+        format!("v{}", self.next_var % 5)
+    }
+
+    /// An expression evaluating to an integer, using only the variables in
+    /// `scope`, which all hold integers.
+    fn int_expr(&mut self, scope: &[String], depth: usize) -> String {
+        let leaf = depth >= MAX_DEPTH;
+
+        match self.rng.below(if leaf { 2 } else { 10 }) {
+            0 | 1 if !scope.is_empty() && self.rng.below(2) == 0 => {
+                scope[self.rng.below(scope.len() as u64) as usize].clone()
+            }
+            0 | 1 => self.rng.below(100).to_string(),
+            2 => {
+                let op = ["+", "-"][self.rng.below(2) as usize];
+                let a = self.int_expr(scope, depth + 1);
+                let b = self.int_expr(scope, depth + 1);
+                format!("({} {} {})", op, a, b)
+            }
+            3 => {
+                // keep products small so nothing overflows a fixnum
+                let a = self.int_expr(scope, depth + 1);
+                format!("(* {} {})", a, self.rng.below(4))
+            }
+            4 => {
+                let cmp = ["<", "=", ">="][self.rng.below(3) as usize];
+                let a = self.int_expr(scope, depth + 1);
+                let b = self.int_expr(scope, depth + 1);
+                let t = self.int_expr(scope, depth + 1);
+                let f = self.int_expr(scope, depth + 1);
+                format!("(if ({} {} {}) {} {})", cmp, a, b, t, f)
+            }
+            5 => {
+                let name = self.fresh();
+                let value = self.int_expr(scope, depth + 1);
+                let body = self.int_expr(&extend(scope, &name), depth + 1);
+                format!("(let (({} {})) {})", name, value, body)
+            }
+            6 => {
+                let x = self.fresh();
+                let y = self.fresh();
+                let inner = extend(&extend(scope, &x), &y);
+                let body = self.int_expr(&inner, depth + 1);
+                let a = self.int_expr(scope, depth + 1);
+                let b = self.int_expr(scope, depth + 1);
+                format!("((lambda ({} {}) {}) {} {})", x, y, body, a, b)
+            }
+            7 => {
+                // a closure called more than once
+                let f = format!("f{}", depth);
+                let x = self.fresh();
+                let body = self.int_expr(&extend(scope, &x), depth + 1);
+                let a = self.int_expr(scope, depth + 1);
+                let b = self.int_expr(scope, depth + 1);
+                format!(
+                    "(let (({} (lambda ({}) {}))) (+ ({} {}) ({} {})))",
+                    f, x, body, f, a, f, b
+                )
+            }
+            8 => {
+                let name = self.fresh();
+                let inner = extend(scope, &name);
+                let value = self.int_expr(scope, depth + 1);
+                let new_value = self.int_expr(&inner, depth + 1);
+                let body = self.int_expr(&inner, depth + 1);
+                format!(
+                    "(let (({} {})) (let ((_ (set! {} {}))) {}))",
+                    name, value, name, new_value, body
+                )
+            }
+            _ => {
+                // a counter closing over a mutated variable
+                let n = self.fresh();
+                let step = self.int_expr(scope, depth + 1);
+                format!(
+                    "(let (({n} 0)) (let ((bump (lambda () (set! {n} (+ {n} {s})) {n}))) (let ((_ (bump))) (bump))))",
+                    n = n,
+                    s = step
+                )
+            }
+        }
+    }
+}
+
+fn extend(scope: &[String], name: &str) -> Vec<String> {
+    let mut scope = scope
+        .iter()
+        .filter(|n| *n != name)
+        .cloned()
+        .collect::<Vec<_>>();
+    scope.push(name.to_owned());
+    scope
+}
+
+fn program(seed: u64) -> String {
+    let mut gen = Gen {
+        rng: Rng::new(seed),
+        next_var: 0,
+    };
+
+    (0..EXPRS_PER_CASE)
+        .map(|_| format!("(display {})\n", gen.int_expr(&[], 0)))
+        .collect()
+}
+
+fn output_of(source: &str, args: &[&str]) -> Result<String, String> {
+    let mut child = Command::new(COMPILER)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{} exited with {}\nstderr: {}",
+            args.join(" "),
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[test]
+fn compiled_matches_interpreter() {
+    let seeds = match std::env::var("PROPERTY_SEED") {
+        Ok(seed) => vec![seed.parse().expect("PROPERTY_SEED should be a number")],
+        Err(_) => (1..=CASES).collect(),
+    };
+
+    for seed in seeds {
+        let source = program(seed);
+
+        let interpreted = output_of(&source, &["interpret"]);
+        let compiled = output_of(&source, &["run"]);
+        let bytecode = output_of(&source, &["--target", "bytecode", "run"]);
+
+        match (interpreted, compiled, bytecode) {
+            (Ok(i), Ok(c), Ok(b)) if i == c && i == b => {}
+            (i, c, b) => panic!(
+                "seed {} differs\nprogram:\n{}\ninterpreted: {:?}\ncompiled: {:?}\nbytecode: {:?}",
+                seed, source, i, c, b
+            ),
+        }
+    }
+}